@@ -0,0 +1,4 @@
+debugger;
+debugger;
+var a = 1;
+if (a == 1) {}
@@ -0,0 +1,2 @@
+import { b } from "./b";
+console.log(b);
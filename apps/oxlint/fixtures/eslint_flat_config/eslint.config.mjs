@@ -0,0 +1,23 @@
+import js from "@eslint/js";
+import globals from "globals";
+
+export default [
+  js.configs.recommended,
+  { ignores: ["ignored/**"] },
+  {
+    languageOptions: {
+      globals: { ...globals.browser },
+    },
+    rules: {
+      "no-debugger": "error",
+      "no-console": "warn",
+      "some-plugin/unknown-rule": "error",
+    },
+  },
+  {
+    files: ["**/*.test.js"],
+    rules: {
+      "no-console": "off",
+    },
+  },
+];
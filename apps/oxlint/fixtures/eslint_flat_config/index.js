@@ -0,0 +1,2 @@
+debugger;
+console.log(window.location);
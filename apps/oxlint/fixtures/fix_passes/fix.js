@@ -0,0 +1,2 @@
+const a = [...[...[...foo]]];
+const b = Boolean(Boolean(Boolean(c)));
@@ -0,0 +1,18 @@
+if (a == null) {}
+
+/* oxlint eqeqeq: ["error", "smart"], no-console: error -- legacy code below */
+
+if (a == null) {}
+if (a == b) {}
+console.log(a);
+
+/* eslint no-console: "off", some-plugin/unknown-rule: "error" */
+
+console.log(a);
+
+/* oxlint unknown-rule: "error" */
+/* oxlint eqeqeq: ["error" "smart"] */
+
+/* eslint max-params: "error" -- keeps max from the config */
+
+foo(function (a, b) { return a + b; });
@@ -0,0 +1,2 @@
+// oxlint-disable-next-line import/no-unused-modules
+export const disabled = 1;
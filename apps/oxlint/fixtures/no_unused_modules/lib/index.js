@@ -0,0 +1,2 @@
+export const api = 1;
+export const internal = 2;
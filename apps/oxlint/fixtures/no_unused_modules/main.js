@@ -0,0 +1,9 @@
+import { used } from "./utils.js";
+import * as namespace from "./namespace.js";
+import "./side-effect.js";
+import "./disabled.js";
+import { api } from "./lib/index.js";
+
+export { reexported } from "./reexport.js";
+
+console.log(used, namespace, api);
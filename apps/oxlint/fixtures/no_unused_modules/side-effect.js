@@ -0,0 +1 @@
+export const effect = 1;
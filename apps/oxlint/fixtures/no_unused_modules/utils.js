@@ -0,0 +1,5 @@
+export function used() {}
+
+export function unused() {}
+
+export default used;
@@ -0,0 +1,2 @@
+export type Chained = 1;
+export type NotChained = 2;
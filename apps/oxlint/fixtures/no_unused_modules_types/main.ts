@@ -0,0 +1,9 @@
+import type { UsedType } from "./types";
+import { Merged } from "./merged";
+import type { Chained } from "./barrel";
+
+const merged: Merged = { a: "a", b: "b" };
+const used: UsedType = "used";
+const chained: Chained = 1;
+
+console.log(merged, used, chained, Merged.c);
@@ -0,0 +1,16 @@
+export interface Merged {
+  a: string;
+}
+export interface Merged {
+  b: string;
+}
+export namespace Merged {
+  export const c = 1;
+}
+
+export interface UnusedMerged {
+  a: string;
+}
+export interface UnusedMerged {
+  b: string;
+}
@@ -0,0 +1,5 @@
+export type UsedType = string;
+export type UnusedType = number;
+export interface UnusedInterface {
+  a: string;
+}
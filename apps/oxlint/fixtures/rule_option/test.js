@@ -0,0 +1,3 @@
+debugger;
+if (a == null) {}
+if (a == b) {}
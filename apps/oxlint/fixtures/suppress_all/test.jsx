@@ -0,0 +1,9 @@
+var a = 1;
+debugger;
+export const s = `
+${eval(a)}`;
+export const C = () => (
+  <div>
+    {[a, 2].map((x) => <span>{x}</span>)}
+  </div>
+);
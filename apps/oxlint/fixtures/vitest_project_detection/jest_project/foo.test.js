@@ -0,0 +1,3 @@
+test("foo", () => {
+  vi.mock("./foo");
+});
@@ -194,6 +194,12 @@ function deserializeExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -406,6 +412,12 @@ function deserializeArrayExpressionElement(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -562,6 +574,12 @@ function deserializePropertyKey(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -895,6 +913,12 @@ function deserializeArgument(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -1846,6 +1870,12 @@ function deserializeForStatementInit(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -3177,6 +3207,12 @@ function deserializeExportDefaultDeclarationKind(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -3227,6 +3263,57 @@ function deserializeV8IntrinsicExpression(pos) {
   return node;
 }
 
+function deserializePipelineExpression(pos) {
+  let start = deserializeU32(pos),
+    end = deserializeU32(pos + 4),
+    previousParent = parent,
+    node = (parent = {
+      __proto__: NodeProto,
+      type: "PipelineExpression",
+      head: null,
+      body: null,
+      start,
+      end,
+      range: [start, end],
+      parent,
+    });
+  node.head = deserializeExpression(pos + 8);
+  node.body = deserializeExpression(pos + 24);
+  parent = previousParent;
+  return node;
+}
+
+function deserializeTopicReference(pos) {
+  let start = deserializeU32(pos),
+    end = deserializeU32(pos + 4);
+  return {
+    __proto__: NodeProto,
+    type: "TopicReference",
+    start,
+    end,
+    range: [start, end],
+    parent,
+  };
+}
+
+function deserializeDoExpression(pos) {
+  let start = deserializeU32(pos),
+    end = deserializeU32(pos + 4),
+    previousParent = parent,
+    node = (parent = {
+      __proto__: NodeProto,
+      type: "DoExpression",
+      body: null,
+      start,
+      end,
+      range: [start, end],
+      parent,
+    });
+  node.body = deserializeBoxBlockStatement(pos + 8);
+  parent = previousParent;
+  return node;
+}
+
 function deserializeBooleanLiteral(pos) {
   let value = deserializeBool(pos + 8),
     start = deserializeU32(pos),
@@ -3697,6 +3784,12 @@ function deserializeJSXExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -6170,6 +6263,18 @@ function deserializeBoxV8IntrinsicExpression(pos) {
   return deserializeV8IntrinsicExpression(uint32[pos >> 2]);
 }
 
+function deserializeBoxPipelineExpression(pos) {
+  return deserializePipelineExpression(uint32[pos >> 2]);
+}
+
+function deserializeBoxTopicReference(pos) {
+  return deserializeTopicReference(uint32[pos >> 2]);
+}
+
+function deserializeBoxDoExpression(pos) {
+  return deserializeDoExpression(uint32[pos >> 2]);
+}
+
 function deserializeVecArrayExpressionElement(pos) {
   let arr = [],
     pos32 = pos >> 2;
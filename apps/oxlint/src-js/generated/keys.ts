@@ -56,6 +56,7 @@ export default freeze({
   Super: $EMPTY,
   TemplateElement: $EMPTY,
   ThisExpression: $EMPTY,
+  TopicReference: $EMPTY,
   JSXClosingFragment: $EMPTY,
   JSXEmptyExpression: $EMPTY,
   JSXIdentifier: $EMPTY,
@@ -98,6 +99,7 @@ export default freeze({
   ConditionalExpression: TEST__CONSEQUENT__ALTERNATE,
   ContinueStatement: LABEL,
   Decorator: EXPRESSION,
+  DoExpression: BODY,
   DoWhileStatement: freeze(["body", "test"]),
   ExportAllDeclaration: freeze(["exported", "source", "attributes"]),
   ExportDefaultDeclaration: freeze(["declaration"]),
@@ -126,6 +128,7 @@ export default freeze({
   ObjectExpression: freeze(["properties"]),
   ObjectPattern: freeze(["decorators", "properties", "typeAnnotation"]),
   ParenthesizedExpression: EXPRESSION,
+  PipelineExpression: freeze(["head", "body"]),
   Program: BODY,
   Property: KEY__VALUE,
   PropertyDefinition: DECORATORS__KEY__TYPE_ANNOTATION__VALUE,
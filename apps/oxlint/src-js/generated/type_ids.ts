@@ -10,191 +10,194 @@ export const NODE_TYPE_IDS_MAP = new Map([
   ["Super", 4],
   ["TemplateElement", 5],
   ["ThisExpression", 6],
-  ["JSXClosingFragment", 7],
-  ["JSXEmptyExpression", 8],
-  ["JSXIdentifier", 9],
-  ["JSXOpeningFragment", 10],
-  ["JSXText", 11],
-  ["TSAnyKeyword", 12],
-  ["TSBigIntKeyword", 13],
-  ["TSBooleanKeyword", 14],
-  ["TSIntrinsicKeyword", 15],
-  ["TSJSDocUnknownType", 16],
-  ["TSNeverKeyword", 17],
-  ["TSNullKeyword", 18],
-  ["TSNumberKeyword", 19],
-  ["TSObjectKeyword", 20],
-  ["TSStringKeyword", 21],
-  ["TSSymbolKeyword", 22],
-  ["TSThisType", 23],
-  ["TSUndefinedKeyword", 24],
-  ["TSUnknownKeyword", 25],
-  ["TSVoidKeyword", 26],
-  ["AccessorProperty", 27],
-  ["ArrayExpression", 28],
-  ["ArrayPattern", 29],
-  ["ArrowFunctionExpression", 30],
-  ["AssignmentExpression", 31],
-  ["AssignmentPattern", 32],
-  ["AwaitExpression", 33],
-  ["BinaryExpression", 34],
-  ["BlockStatement", 35],
-  ["BreakStatement", 36],
-  ["CallExpression", 37],
-  ["CatchClause", 38],
-  ["ChainExpression", 39],
-  ["ClassBody", 40],
-  ["ClassDeclaration", 41],
-  ["ClassExpression", 42],
-  ["ConditionalExpression", 43],
-  ["ContinueStatement", 44],
-  ["Decorator", 45],
-  ["DoWhileStatement", 46],
-  ["ExportAllDeclaration", 47],
-  ["ExportDefaultDeclaration", 48],
-  ["ExportNamedDeclaration", 49],
-  ["ExportSpecifier", 50],
-  ["ExpressionStatement", 51],
-  ["ForInStatement", 52],
-  ["ForOfStatement", 53],
-  ["ForStatement", 54],
-  ["FunctionDeclaration", 55],
-  ["FunctionExpression", 56],
-  ["Identifier", 57],
-  ["IfStatement", 58],
-  ["ImportAttribute", 59],
-  ["ImportDeclaration", 60],
-  ["ImportDefaultSpecifier", 61],
-  ["ImportExpression", 62],
-  ["ImportNamespaceSpecifier", 63],
-  ["ImportSpecifier", 64],
-  ["LabeledStatement", 65],
-  ["LogicalExpression", 66],
-  ["MemberExpression", 67],
-  ["MetaProperty", 68],
-  ["MethodDefinition", 69],
-  ["NewExpression", 70],
-  ["ObjectExpression", 71],
-  ["ObjectPattern", 72],
-  ["ParenthesizedExpression", 73],
-  ["Program", 74],
-  ["Property", 75],
-  ["PropertyDefinition", 76],
-  ["RestElement", 77],
-  ["ReturnStatement", 78],
-  ["SequenceExpression", 79],
-  ["SpreadElement", 80],
-  ["StaticBlock", 81],
-  ["SwitchCase", 82],
-  ["SwitchStatement", 83],
-  ["TaggedTemplateExpression", 84],
-  ["TemplateLiteral", 85],
-  ["ThrowStatement", 86],
-  ["TryStatement", 87],
-  ["UnaryExpression", 88],
-  ["UpdateExpression", 89],
-  ["V8IntrinsicExpression", 90],
-  ["VariableDeclaration", 91],
-  ["VariableDeclarator", 92],
-  ["WhileStatement", 93],
-  ["WithStatement", 94],
-  ["YieldExpression", 95],
-  ["JSXAttribute", 96],
-  ["JSXClosingElement", 97],
-  ["JSXElement", 98],
-  ["JSXExpressionContainer", 99],
-  ["JSXFragment", 100],
-  ["JSXMemberExpression", 101],
-  ["JSXNamespacedName", 102],
-  ["JSXOpeningElement", 103],
-  ["JSXSpreadAttribute", 104],
-  ["JSXSpreadChild", 105],
-  ["TSAbstractAccessorProperty", 106],
-  ["TSAbstractMethodDefinition", 107],
-  ["TSAbstractPropertyDefinition", 108],
-  ["TSArrayType", 109],
-  ["TSAsExpression", 110],
-  ["TSCallSignatureDeclaration", 111],
-  ["TSClassImplements", 112],
-  ["TSConditionalType", 113],
-  ["TSConstructSignatureDeclaration", 114],
-  ["TSConstructorType", 115],
-  ["TSDeclareFunction", 116],
-  ["TSEmptyBodyFunctionExpression", 117],
-  ["TSEnumBody", 118],
-  ["TSEnumDeclaration", 119],
-  ["TSEnumMember", 120],
-  ["TSExportAssignment", 121],
-  ["TSExternalModuleReference", 122],
-  ["TSFunctionType", 123],
-  ["TSImportEqualsDeclaration", 124],
-  ["TSImportType", 125],
-  ["TSIndexSignature", 126],
-  ["TSIndexedAccessType", 127],
-  ["TSInferType", 128],
-  ["TSInstantiationExpression", 129],
-  ["TSInterfaceBody", 130],
-  ["TSInterfaceDeclaration", 131],
-  ["TSInterfaceHeritage", 132],
-  ["TSIntersectionType", 133],
-  ["TSJSDocNonNullableType", 134],
-  ["TSJSDocNullableType", 135],
-  ["TSLiteralType", 136],
-  ["TSMappedType", 137],
-  ["TSMethodSignature", 138],
-  ["TSModuleBlock", 139],
-  ["TSModuleDeclaration", 140],
-  ["TSNamedTupleMember", 141],
-  ["TSNamespaceExportDeclaration", 142],
-  ["TSNonNullExpression", 143],
-  ["TSOptionalType", 144],
-  ["TSParameterProperty", 145],
-  ["TSParenthesizedType", 146],
-  ["TSPropertySignature", 147],
-  ["TSQualifiedName", 148],
-  ["TSRestType", 149],
-  ["TSSatisfiesExpression", 150],
-  ["TSTemplateLiteralType", 151],
-  ["TSTupleType", 152],
-  ["TSTypeAliasDeclaration", 153],
-  ["TSTypeAnnotation", 154],
-  ["TSTypeAssertion", 155],
-  ["TSTypeLiteral", 156],
-  ["TSTypeOperator", 157],
-  ["TSTypeParameter", 158],
-  ["TSTypeParameterDeclaration", 159],
-  ["TSTypeParameterInstantiation", 160],
-  ["TSTypePredicate", 161],
-  ["TSTypeQuery", 162],
-  ["TSTypeReference", 163],
-  ["TSUnionType", 164],
-  ["onCodePathStart", 165],
-  ["onCodePathEnd", 166],
-  ["onCodePathSegmentStart", 167],
-  ["onCodePathSegmentEnd", 168],
-  ["onUnreachableCodePathSegmentStart", 169],
-  ["onUnreachableCodePathSegmentEnd", 170],
-  ["onCodePathSegmentLoop", 171],
+  ["TopicReference", 7],
+  ["JSXClosingFragment", 8],
+  ["JSXEmptyExpression", 9],
+  ["JSXIdentifier", 10],
+  ["JSXOpeningFragment", 11],
+  ["JSXText", 12],
+  ["TSAnyKeyword", 13],
+  ["TSBigIntKeyword", 14],
+  ["TSBooleanKeyword", 15],
+  ["TSIntrinsicKeyword", 16],
+  ["TSJSDocUnknownType", 17],
+  ["TSNeverKeyword", 18],
+  ["TSNullKeyword", 19],
+  ["TSNumberKeyword", 20],
+  ["TSObjectKeyword", 21],
+  ["TSStringKeyword", 22],
+  ["TSSymbolKeyword", 23],
+  ["TSThisType", 24],
+  ["TSUndefinedKeyword", 25],
+  ["TSUnknownKeyword", 26],
+  ["TSVoidKeyword", 27],
+  ["AccessorProperty", 28],
+  ["ArrayExpression", 29],
+  ["ArrayPattern", 30],
+  ["ArrowFunctionExpression", 31],
+  ["AssignmentExpression", 32],
+  ["AssignmentPattern", 33],
+  ["AwaitExpression", 34],
+  ["BinaryExpression", 35],
+  ["BlockStatement", 36],
+  ["BreakStatement", 37],
+  ["CallExpression", 38],
+  ["CatchClause", 39],
+  ["ChainExpression", 40],
+  ["ClassBody", 41],
+  ["ClassDeclaration", 42],
+  ["ClassExpression", 43],
+  ["ConditionalExpression", 44],
+  ["ContinueStatement", 45],
+  ["Decorator", 46],
+  ["DoExpression", 47],
+  ["DoWhileStatement", 48],
+  ["ExportAllDeclaration", 49],
+  ["ExportDefaultDeclaration", 50],
+  ["ExportNamedDeclaration", 51],
+  ["ExportSpecifier", 52],
+  ["ExpressionStatement", 53],
+  ["ForInStatement", 54],
+  ["ForOfStatement", 55],
+  ["ForStatement", 56],
+  ["FunctionDeclaration", 57],
+  ["FunctionExpression", 58],
+  ["Identifier", 59],
+  ["IfStatement", 60],
+  ["ImportAttribute", 61],
+  ["ImportDeclaration", 62],
+  ["ImportDefaultSpecifier", 63],
+  ["ImportExpression", 64],
+  ["ImportNamespaceSpecifier", 65],
+  ["ImportSpecifier", 66],
+  ["LabeledStatement", 67],
+  ["LogicalExpression", 68],
+  ["MemberExpression", 69],
+  ["MetaProperty", 70],
+  ["MethodDefinition", 71],
+  ["NewExpression", 72],
+  ["ObjectExpression", 73],
+  ["ObjectPattern", 74],
+  ["ParenthesizedExpression", 75],
+  ["PipelineExpression", 76],
+  ["Program", 77],
+  ["Property", 78],
+  ["PropertyDefinition", 79],
+  ["RestElement", 80],
+  ["ReturnStatement", 81],
+  ["SequenceExpression", 82],
+  ["SpreadElement", 83],
+  ["StaticBlock", 84],
+  ["SwitchCase", 85],
+  ["SwitchStatement", 86],
+  ["TaggedTemplateExpression", 87],
+  ["TemplateLiteral", 88],
+  ["ThrowStatement", 89],
+  ["TryStatement", 90],
+  ["UnaryExpression", 91],
+  ["UpdateExpression", 92],
+  ["V8IntrinsicExpression", 93],
+  ["VariableDeclaration", 94],
+  ["VariableDeclarator", 95],
+  ["WhileStatement", 96],
+  ["WithStatement", 97],
+  ["YieldExpression", 98],
+  ["JSXAttribute", 99],
+  ["JSXClosingElement", 100],
+  ["JSXElement", 101],
+  ["JSXExpressionContainer", 102],
+  ["JSXFragment", 103],
+  ["JSXMemberExpression", 104],
+  ["JSXNamespacedName", 105],
+  ["JSXOpeningElement", 106],
+  ["JSXSpreadAttribute", 107],
+  ["JSXSpreadChild", 108],
+  ["TSAbstractAccessorProperty", 109],
+  ["TSAbstractMethodDefinition", 110],
+  ["TSAbstractPropertyDefinition", 111],
+  ["TSArrayType", 112],
+  ["TSAsExpression", 113],
+  ["TSCallSignatureDeclaration", 114],
+  ["TSClassImplements", 115],
+  ["TSConditionalType", 116],
+  ["TSConstructSignatureDeclaration", 117],
+  ["TSConstructorType", 118],
+  ["TSDeclareFunction", 119],
+  ["TSEmptyBodyFunctionExpression", 120],
+  ["TSEnumBody", 121],
+  ["TSEnumDeclaration", 122],
+  ["TSEnumMember", 123],
+  ["TSExportAssignment", 124],
+  ["TSExternalModuleReference", 125],
+  ["TSFunctionType", 126],
+  ["TSImportEqualsDeclaration", 127],
+  ["TSImportType", 128],
+  ["TSIndexSignature", 129],
+  ["TSIndexedAccessType", 130],
+  ["TSInferType", 131],
+  ["TSInstantiationExpression", 132],
+  ["TSInterfaceBody", 133],
+  ["TSInterfaceDeclaration", 134],
+  ["TSInterfaceHeritage", 135],
+  ["TSIntersectionType", 136],
+  ["TSJSDocNonNullableType", 137],
+  ["TSJSDocNullableType", 138],
+  ["TSLiteralType", 139],
+  ["TSMappedType", 140],
+  ["TSMethodSignature", 141],
+  ["TSModuleBlock", 142],
+  ["TSModuleDeclaration", 143],
+  ["TSNamedTupleMember", 144],
+  ["TSNamespaceExportDeclaration", 145],
+  ["TSNonNullExpression", 146],
+  ["TSOptionalType", 147],
+  ["TSParameterProperty", 148],
+  ["TSParenthesizedType", 149],
+  ["TSPropertySignature", 150],
+  ["TSQualifiedName", 151],
+  ["TSRestType", 152],
+  ["TSSatisfiesExpression", 153],
+  ["TSTemplateLiteralType", 154],
+  ["TSTupleType", 155],
+  ["TSTypeAliasDeclaration", 156],
+  ["TSTypeAnnotation", 157],
+  ["TSTypeAssertion", 158],
+  ["TSTypeLiteral", 159],
+  ["TSTypeOperator", 160],
+  ["TSTypeParameter", 161],
+  ["TSTypeParameterDeclaration", 162],
+  ["TSTypeParameterInstantiation", 163],
+  ["TSTypePredicate", 164],
+  ["TSTypeQuery", 165],
+  ["TSTypeReference", 166],
+  ["TSUnionType", 167],
+  ["onCodePathStart", 168],
+  ["onCodePathEnd", 169],
+  ["onCodePathSegmentStart", 170],
+  ["onCodePathSegmentEnd", 171],
+  ["onUnreachableCodePathSegmentStart", 172],
+  ["onUnreachableCodePathSegmentEnd", 173],
+  ["onCodePathSegmentLoop", 174],
 ]);
 
 /** Count of all node types (both leaf and non-leaf nodes) */
-export const NODE_TYPES_COUNT = 165;
+export const NODE_TYPES_COUNT = 168;
 
 /** Count of leaf node types */
-export const LEAF_NODE_TYPES_COUNT = 27;
+export const LEAF_NODE_TYPES_COUNT = 28;
 
 /** Total count of node types and CFG events */
-export const TYPE_IDS_COUNT = 172;
+export const TYPE_IDS_COUNT = 175;
 
 /** Type IDs which match `:statement` selector class */
 export const STATEMENT_NODE_TYPE_IDS = [
-  0, 1, 35, 36, 41, 44, 46, 47, 48, 49, 51, 52, 53, 54, 55, 58, 60, 65, 78, 83, 86, 87, 91, 93, 94,
-  111, 114, 119, 124, 131, 140, 142, 153, 159,
+  0, 1, 36, 37, 42, 45, 48, 49, 50, 51, 53, 54, 55, 56, 57, 60, 62, 67, 81, 86, 89, 90, 94, 96, 97,
+  114, 117, 122, 127, 134, 143, 145, 156, 162,
 ];
 
 /** Type IDs which match `:declaration` selector class */
 export const DECLARATION_NODE_TYPE_IDS = [
-  41, 47, 48, 49, 55, 60, 91, 111, 114, 119, 124, 131, 140, 142, 153, 159,
+  42, 49, 50, 51, 57, 62, 94, 114, 117, 122, 127, 134, 143, 145, 156, 162,
 ];
 
 /**
@@ -202,8 +205,8 @@ export const DECLARATION_NODE_TYPE_IDS = [
  * Only *may* match because `Identifier` nodes only match this class if their parent is not a `MetaProperty`.
  */
 export const PATTERN_NODE_TYPE_IDS = [
-  2, 6, 8, 28, 29, 30, 31, 32, 33, 34, 37, 39, 42, 43, 56, 57, 62, 66, 67, 68, 70, 71, 72, 73, 79,
-  84, 85, 88, 89, 90, 95, 101, 110, 117, 129, 143, 150, 156,
+  2, 6, 9, 29, 30, 31, 32, 33, 34, 35, 38, 40, 43, 44, 47, 58, 59, 64, 68, 69, 70, 72, 73, 74, 75,
+  76, 82, 87, 88, 91, 92, 93, 98, 104, 113, 120, 132, 146, 153, 159,
 ];
 
 /**
@@ -211,9 +214,9 @@ export const PATTERN_NODE_TYPE_IDS = [
  * Only *may* match because `Identifier` nodes only match this class if their parent is not a `MetaProperty`.
  */
 export const EXPRESSION_NODE_TYPE_IDS = [
-  2, 6, 8, 28, 30, 31, 33, 34, 37, 39, 42, 43, 56, 57, 62, 66, 67, 68, 70, 71, 73, 79, 84, 85, 88,
-  89, 90, 95, 101, 110, 117, 129, 143, 150, 156,
+  2, 6, 9, 29, 31, 32, 34, 35, 38, 40, 43, 44, 47, 58, 59, 64, 68, 69, 70, 72, 73, 75, 76, 82, 87,
+  88, 91, 92, 93, 98, 104, 113, 120, 132, 146, 153, 159,
 ];
 
 /** Type IDs which match `:function` selector class */
-export const FUNCTION_NODE_TYPE_IDS = [30, 55, 56];
+export const FUNCTION_NODE_TYPE_IDS = [31, 57, 58];
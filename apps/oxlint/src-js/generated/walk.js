@@ -47,6 +47,9 @@ function walkNode(node, visitors) {
       case "ThisExpression":
         walkThisExpression(node, visitors);
         break;
+      case "TopicReference":
+        walkTopicReference(node, visitors);
+        break;
       case "JSXClosingFragment":
         walkJSXClosingFragment(node, visitors);
         break;
@@ -164,6 +167,9 @@ function walkNode(node, visitors) {
       case "Decorator":
         walkDecorator(node, visitors);
         break;
+      case "DoExpression":
+        walkDoExpression(node, visitors);
+        break;
       case "DoWhileStatement":
         walkDoWhileStatement(node, visitors);
         break;
@@ -248,6 +254,9 @@ function walkNode(node, visitors) {
       case "ParenthesizedExpression":
         walkParenthesizedExpression(node, visitors);
         break;
+      case "PipelineExpression":
+        walkPipelineExpression(node, visitors);
+        break;
       case "Program":
         walkProgram(node, visitors);
         break;
@@ -559,108 +568,113 @@ function walkThisExpression(node, visitors) {
   visit !== null && visit(node);
 }
 
-function walkJSXClosingFragment(node, visitors) {
+function walkTopicReference(node, visitors) {
   let visit = visitors[7];
   visit !== null && visit(node);
 }
 
-function walkJSXEmptyExpression(node, visitors) {
+function walkJSXClosingFragment(node, visitors) {
   let visit = visitors[8];
   visit !== null && visit(node);
 }
 
-function walkJSXIdentifier(node, visitors) {
+function walkJSXEmptyExpression(node, visitors) {
   let visit = visitors[9];
   visit !== null && visit(node);
 }
 
-function walkJSXOpeningFragment(node, visitors) {
+function walkJSXIdentifier(node, visitors) {
   let visit = visitors[10];
   visit !== null && visit(node);
 }
 
-function walkJSXText(node, visitors) {
+function walkJSXOpeningFragment(node, visitors) {
   let visit = visitors[11];
   visit !== null && visit(node);
 }
 
-function walkTSAnyKeyword(node, visitors) {
+function walkJSXText(node, visitors) {
   let visit = visitors[12];
   visit !== null && visit(node);
 }
 
-function walkTSBigIntKeyword(node, visitors) {
+function walkTSAnyKeyword(node, visitors) {
   let visit = visitors[13];
   visit !== null && visit(node);
 }
 
-function walkTSBooleanKeyword(node, visitors) {
+function walkTSBigIntKeyword(node, visitors) {
   let visit = visitors[14];
   visit !== null && visit(node);
 }
 
-function walkTSIntrinsicKeyword(node, visitors) {
+function walkTSBooleanKeyword(node, visitors) {
   let visit = visitors[15];
   visit !== null && visit(node);
 }
 
-function walkTSJSDocUnknownType(node, visitors) {
+function walkTSIntrinsicKeyword(node, visitors) {
   let visit = visitors[16];
   visit !== null && visit(node);
 }
 
-function walkTSNeverKeyword(node, visitors) {
+function walkTSJSDocUnknownType(node, visitors) {
   let visit = visitors[17];
   visit !== null && visit(node);
 }
 
-function walkTSNullKeyword(node, visitors) {
+function walkTSNeverKeyword(node, visitors) {
   let visit = visitors[18];
   visit !== null && visit(node);
 }
 
-function walkTSNumberKeyword(node, visitors) {
+function walkTSNullKeyword(node, visitors) {
   let visit = visitors[19];
   visit !== null && visit(node);
 }
 
-function walkTSObjectKeyword(node, visitors) {
+function walkTSNumberKeyword(node, visitors) {
   let visit = visitors[20];
   visit !== null && visit(node);
 }
 
-function walkTSStringKeyword(node, visitors) {
+function walkTSObjectKeyword(node, visitors) {
   let visit = visitors[21];
   visit !== null && visit(node);
 }
 
-function walkTSSymbolKeyword(node, visitors) {
+function walkTSStringKeyword(node, visitors) {
   let visit = visitors[22];
   visit !== null && visit(node);
 }
 
-function walkTSThisType(node, visitors) {
+function walkTSSymbolKeyword(node, visitors) {
   let visit = visitors[23];
   visit !== null && visit(node);
 }
 
-function walkTSUndefinedKeyword(node, visitors) {
+function walkTSThisType(node, visitors) {
   let visit = visitors[24];
   visit !== null && visit(node);
 }
 
-function walkTSUnknownKeyword(node, visitors) {
+function walkTSUndefinedKeyword(node, visitors) {
   let visit = visitors[25];
   visit !== null && visit(node);
 }
 
-function walkTSVoidKeyword(node, visitors) {
+function walkTSUnknownKeyword(node, visitors) {
   let visit = visitors[26];
   visit !== null && visit(node);
 }
 
+function walkTSVoidKeyword(node, visitors) {
+  let visit = visitors[27];
+  visit !== null && visit(node);
+}
+
 function walkAccessorProperty(node, visitors) {
-  let enterExit = visitors[27],
+  let enterExit = visitors[28],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -679,7 +693,7 @@ function walkAccessorProperty(node, visitors) {
 }
 
 function walkArrayExpression(node, visitors) {
-  let enterExit = visitors[28],
+  let enterExit = visitors[29],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -695,7 +709,7 @@ function walkArrayExpression(node, visitors) {
 }
 
 function walkArrayPattern(node, visitors) {
-  let enterExit = visitors[29],
+  let enterExit = visitors[30],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -713,7 +727,7 @@ function walkArrayPattern(node, visitors) {
 }
 
 function walkArrowFunctionExpression(node, visitors) {
-  let enterExit = visitors[30],
+  let enterExit = visitors[31],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -732,7 +746,7 @@ function walkArrowFunctionExpression(node, visitors) {
 }
 
 function walkAssignmentExpression(node, visitors) {
-  let enterExit = visitors[31],
+  let enterExit = visitors[32],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -749,7 +763,7 @@ function walkAssignmentExpression(node, visitors) {
 }
 
 function walkAssignmentPattern(node, visitors) {
-  let enterExit = visitors[32],
+  let enterExit = visitors[33],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -768,7 +782,7 @@ function walkAssignmentPattern(node, visitors) {
 }
 
 function walkAwaitExpression(node, visitors) {
-  let enterExit = visitors[33],
+  let enterExit = visitors[34],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -784,7 +798,7 @@ function walkAwaitExpression(node, visitors) {
 }
 
 function walkBinaryExpression(node, visitors) {
-  let enterExit = visitors[34],
+  let enterExit = visitors[35],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -801,7 +815,7 @@ function walkBinaryExpression(node, visitors) {
 }
 
 function walkBlockStatement(node, visitors) {
-  let enterExit = visitors[35],
+  let enterExit = visitors[36],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -817,7 +831,7 @@ function walkBlockStatement(node, visitors) {
 }
 
 function walkBreakStatement(node, visitors) {
-  let enterExit = visitors[36],
+  let enterExit = visitors[37],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -833,7 +847,7 @@ function walkBreakStatement(node, visitors) {
 }
 
 function walkCallExpression(node, visitors) {
-  let enterExit = visitors[37],
+  let enterExit = visitors[38],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -851,7 +865,7 @@ function walkCallExpression(node, visitors) {
 }
 
 function walkCatchClause(node, visitors) {
-  let enterExit = visitors[38],
+  let enterExit = visitors[39],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -868,7 +882,7 @@ function walkCatchClause(node, visitors) {
 }
 
 function walkChainExpression(node, visitors) {
-  let enterExit = visitors[39],
+  let enterExit = visitors[40],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -884,7 +898,7 @@ function walkChainExpression(node, visitors) {
 }
 
 function walkClassBody(node, visitors) {
-  let enterExit = visitors[40],
+  let enterExit = visitors[41],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -900,7 +914,7 @@ function walkClassBody(node, visitors) {
 }
 
 function walkClassDeclaration(node, visitors) {
-  let enterExit = visitors[41],
+  let enterExit = visitors[42],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -922,7 +936,7 @@ function walkClassDeclaration(node, visitors) {
 }
 
 function walkClassExpression(node, visitors) {
-  let enterExit = visitors[42],
+  let enterExit = visitors[43],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -944,7 +958,7 @@ function walkClassExpression(node, visitors) {
 }
 
 function walkConditionalExpression(node, visitors) {
-  let enterExit = visitors[43],
+  let enterExit = visitors[44],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -962,7 +976,7 @@ function walkConditionalExpression(node, visitors) {
 }
 
 function walkContinueStatement(node, visitors) {
-  let enterExit = visitors[44],
+  let enterExit = visitors[45],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -978,7 +992,7 @@ function walkContinueStatement(node, visitors) {
 }
 
 function walkDecorator(node, visitors) {
-  let enterExit = visitors[45],
+  let enterExit = visitors[46],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -993,8 +1007,24 @@ function walkDecorator(node, visitors) {
   exit !== null && exit(node);
 }
 
+function walkDoExpression(node, visitors) {
+  let enterExit = visitors[47],
+    exit = null,
+    enter;
+  if (enterExit !== null) {
+    ({ enter, exit } = enterExit);
+    enter !== null && enter(node);
+  }
+  ancestors.unshift(node);
+  let ancestorsLen = DEBUG ? ancestors.length : 0;
+  walkNode(node.body, visitors);
+  debugCheckAncestorsOnExit(ancestorsLen, node);
+  ancestors.shift();
+  exit !== null && exit(node);
+}
+
 function walkDoWhileStatement(node, visitors) {
-  let enterExit = visitors[46],
+  let enterExit = visitors[48],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1011,7 +1041,7 @@ function walkDoWhileStatement(node, visitors) {
 }
 
 function walkExportAllDeclaration(node, visitors) {
-  let enterExit = visitors[47],
+  let enterExit = visitors[49],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1029,7 +1059,7 @@ function walkExportAllDeclaration(node, visitors) {
 }
 
 function walkExportDefaultDeclaration(node, visitors) {
-  let enterExit = visitors[48],
+  let enterExit = visitors[50],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1045,7 +1075,7 @@ function walkExportDefaultDeclaration(node, visitors) {
 }
 
 function walkExportNamedDeclaration(node, visitors) {
-  let enterExit = visitors[49],
+  let enterExit = visitors[51],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1064,7 +1094,7 @@ function walkExportNamedDeclaration(node, visitors) {
 }
 
 function walkExportSpecifier(node, visitors) {
-  let enterExit = visitors[50],
+  let enterExit = visitors[52],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1081,7 +1111,7 @@ function walkExportSpecifier(node, visitors) {
 }
 
 function walkExpressionStatement(node, visitors) {
-  let enterExit = visitors[51],
+  let enterExit = visitors[53],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1097,7 +1127,7 @@ function walkExpressionStatement(node, visitors) {
 }
 
 function walkForInStatement(node, visitors) {
-  let enterExit = visitors[52],
+  let enterExit = visitors[54],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1115,7 +1145,7 @@ function walkForInStatement(node, visitors) {
 }
 
 function walkForOfStatement(node, visitors) {
-  let enterExit = visitors[53],
+  let enterExit = visitors[55],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1133,7 +1163,7 @@ function walkForOfStatement(node, visitors) {
 }
 
 function walkForStatement(node, visitors) {
-  let enterExit = visitors[54],
+  let enterExit = visitors[56],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1152,7 +1182,7 @@ function walkForStatement(node, visitors) {
 }
 
 function walkFunctionDeclaration(node, visitors) {
-  let enterExit = visitors[55],
+  let enterExit = visitors[57],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1172,7 +1202,7 @@ function walkFunctionDeclaration(node, visitors) {
 }
 
 function walkFunctionExpression(node, visitors) {
-  let enterExit = visitors[56],
+  let enterExit = visitors[58],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1192,7 +1222,7 @@ function walkFunctionExpression(node, visitors) {
 }
 
 function walkIdentifier(node, visitors) {
-  let enterExit = visitors[57],
+  let enterExit = visitors[59],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1209,7 +1239,7 @@ function walkIdentifier(node, visitors) {
 }
 
 function walkIfStatement(node, visitors) {
-  let enterExit = visitors[58],
+  let enterExit = visitors[60],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1227,7 +1257,7 @@ function walkIfStatement(node, visitors) {
 }
 
 function walkImportAttribute(node, visitors) {
-  let enterExit = visitors[59],
+  let enterExit = visitors[61],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1244,7 +1274,7 @@ function walkImportAttribute(node, visitors) {
 }
 
 function walkImportDeclaration(node, visitors) {
-  let enterExit = visitors[60],
+  let enterExit = visitors[62],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1262,7 +1292,7 @@ function walkImportDeclaration(node, visitors) {
 }
 
 function walkImportDefaultSpecifier(node, visitors) {
-  let enterExit = visitors[61],
+  let enterExit = visitors[63],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1278,7 +1308,7 @@ function walkImportDefaultSpecifier(node, visitors) {
 }
 
 function walkImportExpression(node, visitors) {
-  let enterExit = visitors[62],
+  let enterExit = visitors[64],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1295,7 +1325,7 @@ function walkImportExpression(node, visitors) {
 }
 
 function walkImportNamespaceSpecifier(node, visitors) {
-  let enterExit = visitors[63],
+  let enterExit = visitors[65],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1311,7 +1341,7 @@ function walkImportNamespaceSpecifier(node, visitors) {
 }
 
 function walkImportSpecifier(node, visitors) {
-  let enterExit = visitors[64],
+  let enterExit = visitors[66],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1328,7 +1358,7 @@ function walkImportSpecifier(node, visitors) {
 }
 
 function walkLabeledStatement(node, visitors) {
-  let enterExit = visitors[65],
+  let enterExit = visitors[67],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1345,7 +1375,7 @@ function walkLabeledStatement(node, visitors) {
 }
 
 function walkLogicalExpression(node, visitors) {
-  let enterExit = visitors[66],
+  let enterExit = visitors[68],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1362,7 +1392,7 @@ function walkLogicalExpression(node, visitors) {
 }
 
 function walkMemberExpression(node, visitors) {
-  let enterExit = visitors[67],
+  let enterExit = visitors[69],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1379,7 +1409,7 @@ function walkMemberExpression(node, visitors) {
 }
 
 function walkMetaProperty(node, visitors) {
-  let enterExit = visitors[68],
+  let enterExit = visitors[70],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1396,7 +1426,7 @@ function walkMetaProperty(node, visitors) {
 }
 
 function walkMethodDefinition(node, visitors) {
-  let enterExit = visitors[69],
+  let enterExit = visitors[71],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1414,7 +1444,7 @@ function walkMethodDefinition(node, visitors) {
 }
 
 function walkNewExpression(node, visitors) {
-  let enterExit = visitors[70],
+  let enterExit = visitors[72],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1432,7 +1462,7 @@ function walkNewExpression(node, visitors) {
 }
 
 function walkObjectExpression(node, visitors) {
-  let enterExit = visitors[71],
+  let enterExit = visitors[73],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1448,7 +1478,7 @@ function walkObjectExpression(node, visitors) {
 }
 
 function walkObjectPattern(node, visitors) {
-  let enterExit = visitors[72],
+  let enterExit = visitors[74],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1466,7 +1496,7 @@ function walkObjectPattern(node, visitors) {
 }
 
 function walkParenthesizedExpression(node, visitors) {
-  let enterExit = visitors[73],
+  let enterExit = visitors[75],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1481,8 +1511,25 @@ function walkParenthesizedExpression(node, visitors) {
   exit !== null && exit(node);
 }
 
+function walkPipelineExpression(node, visitors) {
+  let enterExit = visitors[76],
+    exit = null,
+    enter;
+  if (enterExit !== null) {
+    ({ enter, exit } = enterExit);
+    enter !== null && enter(node);
+  }
+  ancestors.unshift(node);
+  let ancestorsLen = DEBUG ? ancestors.length : 0;
+  walkNode(node.head, visitors);
+  walkNode(node.body, visitors);
+  debugCheckAncestorsOnExit(ancestorsLen, node);
+  ancestors.shift();
+  exit !== null && exit(node);
+}
+
 function walkProgram(node, visitors) {
-  let enterExit = visitors[74],
+  let enterExit = visitors[77],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1498,7 +1545,7 @@ function walkProgram(node, visitors) {
 }
 
 function walkProperty(node, visitors) {
-  let enterExit = visitors[75],
+  let enterExit = visitors[78],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1515,7 +1562,7 @@ function walkProperty(node, visitors) {
 }
 
 function walkPropertyDefinition(node, visitors) {
-  let enterExit = visitors[76],
+  let enterExit = visitors[79],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1534,7 +1581,7 @@ function walkPropertyDefinition(node, visitors) {
 }
 
 function walkRestElement(node, visitors) {
-  let enterExit = visitors[77],
+  let enterExit = visitors[80],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1552,7 +1599,7 @@ function walkRestElement(node, visitors) {
 }
 
 function walkReturnStatement(node, visitors) {
-  let enterExit = visitors[78],
+  let enterExit = visitors[81],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1568,7 +1615,7 @@ function walkReturnStatement(node, visitors) {
 }
 
 function walkSequenceExpression(node, visitors) {
-  let enterExit = visitors[79],
+  let enterExit = visitors[82],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1584,7 +1631,7 @@ function walkSequenceExpression(node, visitors) {
 }
 
 function walkSpreadElement(node, visitors) {
-  let enterExit = visitors[80],
+  let enterExit = visitors[83],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1600,7 +1647,7 @@ function walkSpreadElement(node, visitors) {
 }
 
 function walkStaticBlock(node, visitors) {
-  let enterExit = visitors[81],
+  let enterExit = visitors[84],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1616,7 +1663,7 @@ function walkStaticBlock(node, visitors) {
 }
 
 function walkSwitchCase(node, visitors) {
-  let enterExit = visitors[82],
+  let enterExit = visitors[85],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1633,7 +1680,7 @@ function walkSwitchCase(node, visitors) {
 }
 
 function walkSwitchStatement(node, visitors) {
-  let enterExit = visitors[83],
+  let enterExit = visitors[86],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1650,7 +1697,7 @@ function walkSwitchStatement(node, visitors) {
 }
 
 function walkTaggedTemplateExpression(node, visitors) {
-  let enterExit = visitors[84],
+  let enterExit = visitors[87],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1668,7 +1715,7 @@ function walkTaggedTemplateExpression(node, visitors) {
 }
 
 function walkTemplateLiteral(node, visitors) {
-  let enterExit = visitors[85],
+  let enterExit = visitors[88],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1685,7 +1732,7 @@ function walkTemplateLiteral(node, visitors) {
 }
 
 function walkThrowStatement(node, visitors) {
-  let enterExit = visitors[86],
+  let enterExit = visitors[89],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1701,7 +1748,7 @@ function walkThrowStatement(node, visitors) {
 }
 
 function walkTryStatement(node, visitors) {
-  let enterExit = visitors[87],
+  let enterExit = visitors[90],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1719,7 +1766,7 @@ function walkTryStatement(node, visitors) {
 }
 
 function walkUnaryExpression(node, visitors) {
-  let enterExit = visitors[88],
+  let enterExit = visitors[91],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1735,7 +1782,7 @@ function walkUnaryExpression(node, visitors) {
 }
 
 function walkUpdateExpression(node, visitors) {
-  let enterExit = visitors[89],
+  let enterExit = visitors[92],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1751,7 +1798,7 @@ function walkUpdateExpression(node, visitors) {
 }
 
 function walkV8IntrinsicExpression(node, visitors) {
-  let enterExit = visitors[90],
+  let enterExit = visitors[93],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1768,7 +1815,7 @@ function walkV8IntrinsicExpression(node, visitors) {
 }
 
 function walkVariableDeclaration(node, visitors) {
-  let enterExit = visitors[91],
+  let enterExit = visitors[94],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1784,7 +1831,7 @@ function walkVariableDeclaration(node, visitors) {
 }
 
 function walkVariableDeclarator(node, visitors) {
-  let enterExit = visitors[92],
+  let enterExit = visitors[95],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1801,7 +1848,7 @@ function walkVariableDeclarator(node, visitors) {
 }
 
 function walkWhileStatement(node, visitors) {
-  let enterExit = visitors[93],
+  let enterExit = visitors[96],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1818,7 +1865,7 @@ function walkWhileStatement(node, visitors) {
 }
 
 function walkWithStatement(node, visitors) {
-  let enterExit = visitors[94],
+  let enterExit = visitors[97],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1835,7 +1882,7 @@ function walkWithStatement(node, visitors) {
 }
 
 function walkYieldExpression(node, visitors) {
-  let enterExit = visitors[95],
+  let enterExit = visitors[98],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1851,7 +1898,7 @@ function walkYieldExpression(node, visitors) {
 }
 
 function walkJSXAttribute(node, visitors) {
-  let enterExit = visitors[96],
+  let enterExit = visitors[99],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1868,7 +1915,7 @@ function walkJSXAttribute(node, visitors) {
 }
 
 function walkJSXClosingElement(node, visitors) {
-  let enterExit = visitors[97],
+  let enterExit = visitors[100],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1884,7 +1931,7 @@ function walkJSXClosingElement(node, visitors) {
 }
 
 function walkJSXElement(node, visitors) {
-  let enterExit = visitors[98],
+  let enterExit = visitors[101],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1902,7 +1949,7 @@ function walkJSXElement(node, visitors) {
 }
 
 function walkJSXExpressionContainer(node, visitors) {
-  let enterExit = visitors[99],
+  let enterExit = visitors[102],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1918,7 +1965,7 @@ function walkJSXExpressionContainer(node, visitors) {
 }
 
 function walkJSXFragment(node, visitors) {
-  let enterExit = visitors[100],
+  let enterExit = visitors[103],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1936,7 +1983,7 @@ function walkJSXFragment(node, visitors) {
 }
 
 function walkJSXMemberExpression(node, visitors) {
-  let enterExit = visitors[101],
+  let enterExit = visitors[104],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1953,7 +2000,7 @@ function walkJSXMemberExpression(node, visitors) {
 }
 
 function walkJSXNamespacedName(node, visitors) {
-  let enterExit = visitors[102],
+  let enterExit = visitors[105],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1970,7 +2017,7 @@ function walkJSXNamespacedName(node, visitors) {
 }
 
 function walkJSXOpeningElement(node, visitors) {
-  let enterExit = visitors[103],
+  let enterExit = visitors[106],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1988,7 +2035,7 @@ function walkJSXOpeningElement(node, visitors) {
 }
 
 function walkJSXSpreadAttribute(node, visitors) {
-  let enterExit = visitors[104],
+  let enterExit = visitors[107],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2004,7 +2051,7 @@ function walkJSXSpreadAttribute(node, visitors) {
 }
 
 function walkJSXSpreadChild(node, visitors) {
-  let enterExit = visitors[105],
+  let enterExit = visitors[108],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2020,7 +2067,7 @@ function walkJSXSpreadChild(node, visitors) {
 }
 
 function walkTSAbstractAccessorProperty(node, visitors) {
-  let enterExit = visitors[106],
+  let enterExit = visitors[109],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2038,7 +2085,7 @@ function walkTSAbstractAccessorProperty(node, visitors) {
 }
 
 function walkTSAbstractMethodDefinition(node, visitors) {
-  let enterExit = visitors[107],
+  let enterExit = visitors[110],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2055,7 +2102,7 @@ function walkTSAbstractMethodDefinition(node, visitors) {
 }
 
 function walkTSAbstractPropertyDefinition(node, visitors) {
-  let enterExit = visitors[108],
+  let enterExit = visitors[111],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2073,7 +2120,7 @@ function walkTSAbstractPropertyDefinition(node, visitors) {
 }
 
 function walkTSArrayType(node, visitors) {
-  let enterExit = visitors[109],
+  let enterExit = visitors[112],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2089,7 +2136,7 @@ function walkTSArrayType(node, visitors) {
 }
 
 function walkTSAsExpression(node, visitors) {
-  let enterExit = visitors[110],
+  let enterExit = visitors[113],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2106,7 +2153,7 @@ function walkTSAsExpression(node, visitors) {
 }
 
 function walkTSCallSignatureDeclaration(node, visitors) {
-  let enterExit = visitors[111],
+  let enterExit = visitors[114],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2124,7 +2171,7 @@ function walkTSCallSignatureDeclaration(node, visitors) {
 }
 
 function walkTSClassImplements(node, visitors) {
-  let enterExit = visitors[112],
+  let enterExit = visitors[115],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2141,7 +2188,7 @@ function walkTSClassImplements(node, visitors) {
 }
 
 function walkTSConditionalType(node, visitors) {
-  let enterExit = visitors[113],
+  let enterExit = visitors[116],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2160,7 +2207,7 @@ function walkTSConditionalType(node, visitors) {
 }
 
 function walkTSConstructSignatureDeclaration(node, visitors) {
-  let enterExit = visitors[114],
+  let enterExit = visitors[117],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2178,7 +2225,7 @@ function walkTSConstructSignatureDeclaration(node, visitors) {
 }
 
 function walkTSConstructorType(node, visitors) {
-  let enterExit = visitors[115],
+  let enterExit = visitors[118],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2196,7 +2243,7 @@ function walkTSConstructorType(node, visitors) {
 }
 
 function walkTSDeclareFunction(node, visitors) {
-  let enterExit = visitors[116],
+  let enterExit = visitors[119],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2216,7 +2263,7 @@ function walkTSDeclareFunction(node, visitors) {
 }
 
 function walkTSEmptyBodyFunctionExpression(node, visitors) {
-  let enterExit = visitors[117],
+  let enterExit = visitors[120],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2235,7 +2282,7 @@ function walkTSEmptyBodyFunctionExpression(node, visitors) {
 }
 
 function walkTSEnumBody(node, visitors) {
-  let enterExit = visitors[118],
+  let enterExit = visitors[121],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2251,7 +2298,7 @@ function walkTSEnumBody(node, visitors) {
 }
 
 function walkTSEnumDeclaration(node, visitors) {
-  let enterExit = visitors[119],
+  let enterExit = visitors[122],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2268,7 +2315,7 @@ function walkTSEnumDeclaration(node, visitors) {
 }
 
 function walkTSEnumMember(node, visitors) {
-  let enterExit = visitors[120],
+  let enterExit = visitors[123],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2285,7 +2332,7 @@ function walkTSEnumMember(node, visitors) {
 }
 
 function walkTSExportAssignment(node, visitors) {
-  let enterExit = visitors[121],
+  let enterExit = visitors[124],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2301,7 +2348,7 @@ function walkTSExportAssignment(node, visitors) {
 }
 
 function walkTSExternalModuleReference(node, visitors) {
-  let enterExit = visitors[122],
+  let enterExit = visitors[125],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2317,7 +2364,7 @@ function walkTSExternalModuleReference(node, visitors) {
 }
 
 function walkTSFunctionType(node, visitors) {
-  let enterExit = visitors[123],
+  let enterExit = visitors[126],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2335,7 +2382,7 @@ function walkTSFunctionType(node, visitors) {
 }
 
 function walkTSImportEqualsDeclaration(node, visitors) {
-  let enterExit = visitors[124],
+  let enterExit = visitors[127],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2352,7 +2399,7 @@ function walkTSImportEqualsDeclaration(node, visitors) {
 }
 
 function walkTSImportType(node, visitors) {
-  let enterExit = visitors[125],
+  let enterExit = visitors[128],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2371,7 +2418,7 @@ function walkTSImportType(node, visitors) {
 }
 
 function walkTSIndexSignature(node, visitors) {
-  let enterExit = visitors[126],
+  let enterExit = visitors[129],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2388,7 +2435,7 @@ function walkTSIndexSignature(node, visitors) {
 }
 
 function walkTSIndexedAccessType(node, visitors) {
-  let enterExit = visitors[127],
+  let enterExit = visitors[130],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2405,7 +2452,7 @@ function walkTSIndexedAccessType(node, visitors) {
 }
 
 function walkTSInferType(node, visitors) {
-  let enterExit = visitors[128],
+  let enterExit = visitors[131],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2421,7 +2468,7 @@ function walkTSInferType(node, visitors) {
 }
 
 function walkTSInstantiationExpression(node, visitors) {
-  let enterExit = visitors[129],
+  let enterExit = visitors[132],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2438,7 +2485,7 @@ function walkTSInstantiationExpression(node, visitors) {
 }
 
 function walkTSInterfaceBody(node, visitors) {
-  let enterExit = visitors[130],
+  let enterExit = visitors[133],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2454,7 +2501,7 @@ function walkTSInterfaceBody(node, visitors) {
 }
 
 function walkTSInterfaceDeclaration(node, visitors) {
-  let enterExit = visitors[131],
+  let enterExit = visitors[134],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2473,7 +2520,7 @@ function walkTSInterfaceDeclaration(node, visitors) {
 }
 
 function walkTSInterfaceHeritage(node, visitors) {
-  let enterExit = visitors[132],
+  let enterExit = visitors[135],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2490,7 +2537,7 @@ function walkTSInterfaceHeritage(node, visitors) {
 }
 
 function walkTSIntersectionType(node, visitors) {
-  let enterExit = visitors[133],
+  let enterExit = visitors[136],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2506,7 +2553,7 @@ function walkTSIntersectionType(node, visitors) {
 }
 
 function walkTSJSDocNonNullableType(node, visitors) {
-  let enterExit = visitors[134],
+  let enterExit = visitors[137],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2522,7 +2569,7 @@ function walkTSJSDocNonNullableType(node, visitors) {
 }
 
 function walkTSJSDocNullableType(node, visitors) {
-  let enterExit = visitors[135],
+  let enterExit = visitors[138],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2538,7 +2585,7 @@ function walkTSJSDocNullableType(node, visitors) {
 }
 
 function walkTSLiteralType(node, visitors) {
-  let enterExit = visitors[136],
+  let enterExit = visitors[139],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2554,7 +2601,7 @@ function walkTSLiteralType(node, visitors) {
 }
 
 function walkTSMappedType(node, visitors) {
-  let enterExit = visitors[137],
+  let enterExit = visitors[140],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2573,7 +2620,7 @@ function walkTSMappedType(node, visitors) {
 }
 
 function walkTSMethodSignature(node, visitors) {
-  let enterExit = visitors[138],
+  let enterExit = visitors[141],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2592,7 +2639,7 @@ function walkTSMethodSignature(node, visitors) {
 }
 
 function walkTSModuleBlock(node, visitors) {
-  let enterExit = visitors[139],
+  let enterExit = visitors[142],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2608,7 +2655,7 @@ function walkTSModuleBlock(node, visitors) {
 }
 
 function walkTSModuleDeclaration(node, visitors) {
-  let enterExit = visitors[140],
+  let enterExit = visitors[143],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2625,7 +2672,7 @@ function walkTSModuleDeclaration(node, visitors) {
 }
 
 function walkTSNamedTupleMember(node, visitors) {
-  let enterExit = visitors[141],
+  let enterExit = visitors[144],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2642,7 +2689,7 @@ function walkTSNamedTupleMember(node, visitors) {
 }
 
 function walkTSNamespaceExportDeclaration(node, visitors) {
-  let enterExit = visitors[142],
+  let enterExit = visitors[145],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2658,7 +2705,7 @@ function walkTSNamespaceExportDeclaration(node, visitors) {
 }
 
 function walkTSNonNullExpression(node, visitors) {
-  let enterExit = visitors[143],
+  let enterExit = visitors[146],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2674,7 +2721,7 @@ function walkTSNonNullExpression(node, visitors) {
 }
 
 function walkTSOptionalType(node, visitors) {
-  let enterExit = visitors[144],
+  let enterExit = visitors[147],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2690,7 +2737,7 @@ function walkTSOptionalType(node, visitors) {
 }
 
 function walkTSParameterProperty(node, visitors) {
-  let enterExit = visitors[145],
+  let enterExit = visitors[148],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2707,7 +2754,7 @@ function walkTSParameterProperty(node, visitors) {
 }
 
 function walkTSParenthesizedType(node, visitors) {
-  let enterExit = visitors[146],
+  let enterExit = visitors[149],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2723,7 +2770,7 @@ function walkTSParenthesizedType(node, visitors) {
 }
 
 function walkTSPropertySignature(node, visitors) {
-  let enterExit = visitors[147],
+  let enterExit = visitors[150],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2740,7 +2787,7 @@ function walkTSPropertySignature(node, visitors) {
 }
 
 function walkTSQualifiedName(node, visitors) {
-  let enterExit = visitors[148],
+  let enterExit = visitors[151],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2757,7 +2804,7 @@ function walkTSQualifiedName(node, visitors) {
 }
 
 function walkTSRestType(node, visitors) {
-  let enterExit = visitors[149],
+  let enterExit = visitors[152],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2773,7 +2820,7 @@ function walkTSRestType(node, visitors) {
 }
 
 function walkTSSatisfiesExpression(node, visitors) {
-  let enterExit = visitors[150],
+  let enterExit = visitors[153],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2790,7 +2837,7 @@ function walkTSSatisfiesExpression(node, visitors) {
 }
 
 function walkTSTemplateLiteralType(node, visitors) {
-  let enterExit = visitors[151],
+  let enterExit = visitors[154],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2807,7 +2854,7 @@ function walkTSTemplateLiteralType(node, visitors) {
 }
 
 function walkTSTupleType(node, visitors) {
-  let enterExit = visitors[152],
+  let enterExit = visitors[155],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2823,7 +2870,7 @@ function walkTSTupleType(node, visitors) {
 }
 
 function walkTSTypeAliasDeclaration(node, visitors) {
-  let enterExit = visitors[153],
+  let enterExit = visitors[156],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2841,7 +2888,7 @@ function walkTSTypeAliasDeclaration(node, visitors) {
 }
 
 function walkTSTypeAnnotation(node, visitors) {
-  let enterExit = visitors[154],
+  let enterExit = visitors[157],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2857,7 +2904,7 @@ function walkTSTypeAnnotation(node, visitors) {
 }
 
 function walkTSTypeAssertion(node, visitors) {
-  let enterExit = visitors[155],
+  let enterExit = visitors[158],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2874,7 +2921,7 @@ function walkTSTypeAssertion(node, visitors) {
 }
 
 function walkTSTypeLiteral(node, visitors) {
-  let enterExit = visitors[156],
+  let enterExit = visitors[159],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2890,7 +2937,7 @@ function walkTSTypeLiteral(node, visitors) {
 }
 
 function walkTSTypeOperator(node, visitors) {
-  let enterExit = visitors[157],
+  let enterExit = visitors[160],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2906,7 +2953,7 @@ function walkTSTypeOperator(node, visitors) {
 }
 
 function walkTSTypeParameter(node, visitors) {
-  let enterExit = visitors[158],
+  let enterExit = visitors[161],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2924,7 +2971,7 @@ function walkTSTypeParameter(node, visitors) {
 }
 
 function walkTSTypeParameterDeclaration(node, visitors) {
-  let enterExit = visitors[159],
+  let enterExit = visitors[162],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2940,7 +2987,7 @@ function walkTSTypeParameterDeclaration(node, visitors) {
 }
 
 function walkTSTypeParameterInstantiation(node, visitors) {
-  let enterExit = visitors[160],
+  let enterExit = visitors[163],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2956,7 +3003,7 @@ function walkTSTypeParameterInstantiation(node, visitors) {
 }
 
 function walkTSTypePredicate(node, visitors) {
-  let enterExit = visitors[161],
+  let enterExit = visitors[164],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2973,7 +3020,7 @@ function walkTSTypePredicate(node, visitors) {
 }
 
 function walkTSTypeQuery(node, visitors) {
-  let enterExit = visitors[162],
+  let enterExit = visitors[165],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2990,7 +3037,7 @@ function walkTSTypeQuery(node, visitors) {
 }
 
 function walkTSTypeReference(node, visitors) {
-  let enterExit = visitors[163],
+  let enterExit = visitors[166],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -3007,7 +3054,7 @@ function walkTSTypeReference(node, visitors) {
 }
 
 function walkTSUnionType(node, visitors) {
-  let enterExit = visitors[164],
+  let enterExit = visitors[167],
     exit = null,
     enter;
   if (enterExit !== null) {
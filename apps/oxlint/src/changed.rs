@@ -0,0 +1,248 @@
+use std::{
+    ffi::OsStr,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::Arc,
+};
+
+use rayon::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::Allocator;
+use oxc_linter::{RuntimeFileSystem, loader::PartialLoader, read_to_arena_str, read_to_string};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_resolver::{ResolveOptions, Resolver};
+use oxc_span::SourceType;
+
+/// Files changed in the git repository containing `cwd`.
+///
+/// Includes files changed since the merge base of `since` and `HEAD`, uncommitted changes, and
+/// untracked files. Deleted files are not included.
+///
+/// # Errors
+///
+/// Returns the error output of `git` if it fails, e.g. `cwd` is not inside a git repository or
+/// `since` is not a valid ref.
+pub fn changed_files(cwd: &Path, since: &str) -> Result<FxHashSet<PathBuf>, String> {
+    let merge_base = git(cwd, &["merge-base", since, "HEAD"])?;
+    let merge_base = merge_base.trim();
+    let changed =
+        git(cwd, &["diff", "--name-only", "-z", "--relative", "--diff-filter=d", merge_base])?;
+    let untracked = git(cwd, &["ls-files", "-z", "--others", "--exclude-standard"])?;
+    Ok(changed
+        .split('\0')
+        .chain(untracked.split('\0'))
+        .filter(|file| !file.is_empty())
+        .map(|file| cwd.join(file))
+        .collect())
+}
+
+/// Files staged in the git repository containing `cwd`, with their contents in the index.
+///
+/// Deleted files are not included.
+///
+/// # Errors
+///
+/// Returns the error output of `git` if it fails, e.g. `cwd` is not inside a git repository.
+pub fn staged_files(cwd: &Path) -> Result<FxHashMap<PathBuf, String>, String> {
+    let staged =
+        git(cwd, &["diff", "--cached", "--name-only", "-z", "--relative", "--diff-filter=d"])?;
+    let files = staged.split('\0').filter(|file| !file.is_empty()).collect::<Vec<_>>();
+    if files.is_empty() {
+        return Ok(FxHashMap::default());
+    }
+
+    // Read all blobs with a single `git` process, `:./<file>` is `<file>` in the index.
+    let mut child = Command::new("git")
+        .args(["cat-file", "--batch"])
+        .current_dir(cwd)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("Failed to run git: {err}"))?;
+    let mut stdin = child.stdin.take().unwrap();
+    let input = files.iter().flat_map(|file| [":./", file, "\n"]).collect::<String>();
+    // Write from another thread, as `git` blocks once its output is not read.
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let output = child.wait_with_output().map_err(|err| format!("Failed to run git: {err}"))?;
+    let _ = writer.join();
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+    }
+
+    let mut staged = FxHashMap::default();
+    let mut output = output.stdout.as_slice();
+    for file in files {
+        let Some(header_end) = output.iter().position(|&b| b == b'\n') else { break };
+        let header = String::from_utf8_lossy(&output[..header_end]);
+        output = &output[header_end + 1..];
+        // e.g. `<sha> blob <size>`, or `<object> missing` for submodules
+        let mut parts = header.rsplitn(3, ' ');
+        let (Some(size), Some("blob")) = (parts.next(), parts.next()) else { continue };
+        let Ok(size) = size.parse::<usize>() else { break };
+        let Some(blob) = output.get(..size) else { break };
+        staged.insert(cwd.join(file), String::from_utf8_lossy(blob).into_owned());
+        // The blob is followed by a newline.
+        output = output.get(size + 1..).unwrap_or_default();
+    }
+    Ok(staged)
+}
+
+/// File system used with `--staged`.
+///
+/// Serves the contents in the git index for staged files, and reads all other files (e.g.
+/// modules imported by staged files) from disk.
+pub struct StagedFileSystem {
+    files: FxHashMap<PathBuf, String>,
+}
+
+impl StagedFileSystem {
+    pub fn new(files: FxHashMap<PathBuf, String>) -> Self {
+        Self { files }
+    }
+
+    pub fn contains(&self, path: &Path) -> bool {
+        self.files.contains_key(path)
+    }
+}
+
+impl RuntimeFileSystem for StagedFileSystem {
+    fn read_to_arena_str<'a>(
+        &'a self,
+        path: &Path,
+        allocator: &'a Allocator,
+    ) -> Result<&'a str, io::Error> {
+        match self.files.get(path) {
+            Some(source_text) => Ok(source_text),
+            None => read_to_arena_str(path, allocator),
+        }
+    }
+
+    fn write_file(&self, path: &Path, content: &str) -> Result<(), io::Error> {
+        // Fix options are rejected together with `--staged`
+        debug_assert!(!self.contains(path), "staged files should not be written to disk");
+        fs::write(path, content)
+    }
+}
+
+fn git(cwd: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(cwd)
+        .output()
+        .map_err(|err| format!("Failed to run git: {err}"))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Adds files in `paths` that import any file in `changed`, directly or transitively, to
+/// `changed`.
+///
+/// Only static imports and re-exports are followed. Imports that resolve to files outside of
+/// `paths` (e.g. in `node_modules`) are ignored.
+pub fn add_dependents(paths: &[Arc<OsStr>], changed: &mut FxHashSet<PathBuf>) {
+    let resolver = Resolver::new(ResolveOptions {
+        extensions: [".js", ".jsx", ".ts", ".tsx", ".mjs", ".mts", ".cjs", ".cts", ".json"]
+            .into_iter()
+            .map(ToString::to_string)
+            .collect(),
+        extension_alias: vec![
+            (".js".into(), vec![".js".into(), ".ts".into(), ".tsx".into()]),
+            (".mjs".into(), vec![".mjs".into(), ".mts".into()]),
+            (".cjs".into(), vec![".cjs".into(), ".cts".into()]),
+        ],
+        // Keep paths as they are, so they match the paths found by the walker.
+        symlinks: false,
+        ..ResolveOptions::default()
+    });
+
+    let imports = paths
+        .par_iter()
+        .map(|path| {
+            let path = Path::new(path);
+            (path, resolved_imports(&resolver, path))
+        })
+        .collect::<Vec<_>>();
+
+    let mut importers = FxHashMap::<&Path, Vec<&Path>>::default();
+    for (path, imports) in &imports {
+        for import in imports {
+            importers.entry(import.as_path()).or_default().push(path);
+        }
+    }
+
+    let mut queue = changed.iter().cloned().collect::<Vec<_>>();
+    while let Some(path) = queue.pop() {
+        let Some(importers) = importers.get(path.as_path()) else { continue };
+        for importer in importers {
+            if changed.insert(importer.to_path_buf()) {
+                queue.push(importer.to_path_buf());
+            }
+        }
+    }
+}
+
+fn resolved_imports(resolver: &Resolver, path: &Path) -> Vec<PathBuf> {
+    let (Ok(source_text), Some(dir)) = (read_to_string(path), path.parent()) else {
+        return vec![];
+    };
+    let sources = if let Ok(source_type) = SourceType::from_path(path) {
+        vec![(source_text.as_str(), source_type)]
+    } else {
+        let ext = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+        PartialLoader::parse(ext, &source_text)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|source| (source.source_text, source.source_type))
+            .collect()
+    };
+
+    let allocator = Allocator::default();
+    let mut imports = vec![];
+    for (source_text, source_type) in sources {
+        let ret = Parser::new(&allocator, source_text, source_type)
+            .with_options(ParseOptions {
+                allow_return_outside_function: true,
+                ..Default::default()
+            })
+            .parse();
+        for specifier in ret.module_record.requested_modules.keys() {
+            if let Ok(resolution) = resolver.resolve(dir, specifier) {
+                imports.push(resolution.into_path_buf());
+            }
+        }
+    }
+    imports
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, ffi::OsStr, sync::Arc};
+
+    use rustc_hash::FxHashSet;
+
+    use super::add_dependents;
+
+    #[test]
+    fn test_add_dependents() {
+        let dir = env::current_dir().unwrap().join("fixtures/changed");
+        let paths = ["a.ts", "b.ts", "c.ts", "d.ts"]
+            .map(|file| Arc::<OsStr>::from(dir.join(file).as_os_str()));
+
+        let mut changed = FxHashSet::from_iter([dir.join("a.ts")]);
+        add_dependents(&paths, &mut changed);
+
+        let mut changed = changed.into_iter().collect::<Vec<_>>();
+        changed.sort_unstable();
+        assert_eq!(changed, ["a.ts", "b.ts", "c.ts"].map(|file| dir.join(file)).to_vec());
+
+        let mut changed = FxHashSet::from_iter([dir.join("c.ts")]);
+        add_dependents(&paths, &mut changed);
+        assert_eq!(changed, FxHashSet::from_iter([dir.join("c.ts")]));
+    }
+}
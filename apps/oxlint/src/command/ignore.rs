@@ -3,7 +3,7 @@ use std::ffi::OsString;
 use bpaf::{Bpaf, doc::Style};
 
 pub const NO_IGNORE_HELP: &[(&str, Style)] = &[
-    ("Disable excluding files from `.eslintignore` files, ", Style::Text),
+    ("Disable excluding files from `.eslintignore` and `.oxlintignore` files, ", Style::Text),
     ("--ignore-path", Style::Literal),
     (" flags and ", Style::Text),
     ("--ignore-pattern", Style::Literal),
@@ -14,6 +14,8 @@ pub const NO_IGNORE_HELP: &[(&str, Style)] = &[
 #[derive(Debug, Clone, Bpaf)]
 pub struct IgnoreOptions {
     /// Specify the file to use as your `.eslintignore`
+    ///
+    /// Files with this name and `.oxlintignore` files are read in every linted directory.
     #[bpaf(argument("PATH"), fallback(".eslintignore".into()), hide_usage)]
     pub ignore_path: OsString,
 
@@ -1,9 +1,13 @@
-use std::path::PathBuf;
+use std::{
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+};
 
 use bpaf::Bpaf;
-use oxc_linter::{AllowWarnDeny, FixKind, LintPlugins};
+use oxc_linter::{AllowWarnDeny, FixKind, FixOnly, LintPlugins};
+use rustc_hash::FxHasher;
 
-use crate::output_formatter::OutputFormat;
+use crate::{daemon::default_socket_dir, output_formatter::OutputFormat};
 
 use super::{
     MiscOptions, PATHS_ERROR_MESSAGE, VERSION,
@@ -20,6 +24,11 @@ pub struct LintCommand {
     #[bpaf(external(lint_filter), map(LintFilter::into_tuple), many, hide_usage)]
     pub filter: Vec<(AllowWarnDeny, String)>,
 
+    /// Configure rules like in a configuration file, e.g. `--rule 'eqeqeq: [warn, smart]'`.
+    /// Rules can be separated by commas. Applied after `-A`, `-W` and `-D`.
+    #[bpaf(argument("RULE"), many, hide_usage)]
+    pub rule: Vec<String>,
+
     #[bpaf(external)]
     pub enable_plugins: EnablePlugins,
 
@@ -32,6 +41,15 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub warning_options: WarningOptions,
 
+    #[bpaf(external)]
+    pub baseline_options: BaselineOptions,
+
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
+    #[bpaf(external)]
+    pub changed_options: ChangedOptions,
+
     #[bpaf(external)]
     pub output_options: OutputOptions,
 
@@ -43,6 +61,14 @@ pub struct LintCommand {
     #[bpaf(long("lsp"), switch, hide_usage)]
     pub lsp: bool,
 
+    /// Lint again whenever a file which can be linted, a configuration file or an ignore file
+    /// changes, until the process is killed. JS plugins are not supported.
+    #[bpaf(long("watch"), switch, hide_usage)]
+    pub watch: bool,
+
+    #[bpaf(external)]
+    pub daemon_options: DaemonOptions,
+
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
@@ -61,6 +87,13 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub inline_config_options: InlineConfigOptions,
 
+    /// Lint source text read from stdin, as if it was the file at PATH.
+    /// PATH determines the source type, the configuration that applies,
+    /// and how files such as `.vue` are split into scripts. It doesn't need to exist.
+    /// Cannot be combined with PATH arguments or fix options.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub stdin_filename: Option<PathBuf>,
+
     /// Single file, single path or list of paths
     #[bpaf(positional("PATH"), many, guard(validate_paths, PATHS_ERROR_MESSAGE))]
     pub paths: Vec<PathBuf>,
@@ -114,9 +147,11 @@ impl LintCommand {
 #[derive(Debug, Clone, Bpaf)]
 pub struct BasicOptions {
     /// Oxlint configuration file
-    ///  * only `.json` extension is supported
+    ///  * `.json` files and ESLint flat configs (`eslint.config.js`) are supported
     ///  * you can use comments in configuration files.
     ///  * tries to be compatible with ESLint v8's format
+    ///  * an ESLint flat config or legacy config (`.eslintrc.json`) is statically converted,
+    ///    and entries without an oxlint equivalent are reported
     ///
     /// If not provided, Oxlint will look for `.oxlintrc.json` in the current working directory.
     #[bpaf(long, short, argument("./.oxlintrc.json"))]
@@ -127,7 +162,8 @@ pub struct BasicOptions {
     #[bpaf(argument("./tsconfig.json"), hide_usage)]
     pub tsconfig: Option<PathBuf>,
 
-    /// Initialize oxlint configuration with default values
+    /// Initialize oxlint configuration with default values, or by migrating the ESLint config
+    /// (`eslint.config.js` or `.eslintrc.*`) of the current working directory
     #[bpaf(switch, hide_usage)]
     pub init: bool,
 }
@@ -193,13 +229,37 @@ pub struct FixOptions {
     /// Apply dangerous fixes and suggestions
     #[bpaf(switch, hide_usage)]
     pub fix_dangerously: bool,
+
+    /// How often a file is fixed and linted again at most, to apply fixes which conflict with
+    /// other fixes or are only reported after other fixes are applied. Defaults to 10.
+    #[bpaf(argument("INT"), hide_usage)]
+    pub max_fix_passes: Option<u8>,
+
+    /// Compute fixes without writing them to disk, and print them as a unified diff instead.
+    /// Fixes the same issues as `--fix`, unless combined with other fix options.
+    #[bpaf(switch, hide_usage)]
+    pub fix_dry_run: bool,
+
+    /// Write the diff of `--fix-dry-run` to a patch file at PATH instead of printing it.
+    /// Implies `--fix-dry-run`.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub fix_patch: Option<PathBuf>,
+
+    /// Only apply the fixes of the given comma-separated rules, e.g. `--fix-only=no-debugger,eslint/no-var`.
+    /// Fixes the same issues as `--fix`, unless combined with other fix options.
+    #[bpaf(argument("RULES"), many, hide_usage)]
+    pub fix_only: Vec<String>,
 }
 
 impl FixOptions {
     pub fn fix_kind(&self) -> FixKind {
         let mut kind = FixKind::None;
 
-        if self.fix {
+        if self.fix
+            || ((self.is_dry_run() || !self.fix_only.is_empty())
+                && !self.fix_suggestions
+                && !self.fix_dangerously)
+        {
             kind.set(FixKind::SafeFix, true);
         }
 
@@ -218,7 +278,30 @@ impl FixOptions {
     }
 
     pub fn is_enabled(&self) -> bool {
-        self.fix || self.fix_suggestions || self.fix_dangerously
+        self.fix
+            || self.fix_suggestions
+            || self.fix_dangerously
+            || self.is_dry_run()
+            || !self.fix_only.is_empty()
+    }
+
+    /// Rules whose fixes are applied, if restricted with `--fix-only`.
+    pub fn fix_only(&self) -> Option<FixOnly> {
+        if self.fix_only.is_empty() {
+            return None;
+        }
+        Some(FixOnly::new(
+            self.fix_only
+                .iter()
+                .flat_map(|rules| rules.split(','))
+                .map(str::trim)
+                .filter(|rule| !rule.is_empty()),
+        ))
+    }
+
+    /// Whether fixes are only computed, not written to disk.
+    pub fn is_dry_run(&self) -> bool {
+        self.fix_dry_run || self.fix_patch.is_some()
     }
 }
 
@@ -239,13 +322,126 @@ pub struct WarningOptions {
     pub max_warnings: Option<usize>,
 }
 
+/// Baseline
+#[derive(Debug, Clone, Bpaf)]
+pub struct BaselineOptions {
+    /// Write all current rule violations to the baseline file instead of reporting them.
+    /// Use this to adopt oxlint on an existing codebase without fixing every violation first.
+    #[bpaf(switch, hide_usage)]
+    pub generate_baseline: bool,
+
+    /// Path of the baseline file. When the file exists, violations recorded in it are not
+    /// reported, so only new violations fail the run.
+    #[bpaf(argument("./.oxlint-baseline.json"), hide_usage)]
+    pub baseline: Option<PathBuf>,
+
+    /// Insert `// oxlint-disable-next-line <rule> -- TODO` comments above all current rule
+    /// violations instead of reporting them. Fixes of rules are not applied.
+    /// Combine with `--fix-dry-run` to print the comments as a diff.
+    #[bpaf(switch, hide_usage)]
+    pub suppress_all: bool,
+}
+
+impl BaselineOptions {
+    pub const DEFAULT_BASELINE: &str = ".oxlint-baseline.json";
+}
+
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint files that changed since they were last linted without any diagnostics.
+    /// The cache is discarded when the configuration or the oxlint version changes.
+    /// Not used with type-aware linting, the import plugin, or JS plugins,
+    /// since their results depend on more than the contents of a single file.
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path of the cache file
+    #[bpaf(argument("./.oxlintcache"), hide_usage)]
+    pub cache_location: Option<PathBuf>,
+}
+
+impl CacheOptions {
+    pub const DEFAULT_CACHE_LOCATION: &str = ".oxlintcache";
+}
+
+/// Changed Files
+#[derive(Debug, Clone, Bpaf)]
+pub struct ChangedOptions {
+    /// Only lint files with uncommitted changes in git, including untracked files
+    #[bpaf(switch, hide_usage)]
+    pub changed: bool,
+
+    /// Only lint files changed in git since the merge base of REF and `HEAD`,
+    /// including uncommitted changes and untracked files
+    #[bpaf(argument("REF"), hide_usage)]
+    pub since: Option<String>,
+
+    /// With `--changed` or `--since`, also lint files that import a changed file,
+    /// directly or through other files
+    #[bpaf(switch, hide_usage)]
+    pub include_dependents: bool,
+
+    /// Only lint files staged in git, as they are in the index rather than the working tree.
+    /// For pre-commit hooks. Cannot be combined with `--changed`, `--since` or fix options.
+    #[bpaf(switch, hide_usage)]
+    pub staged: bool,
+}
+
+impl ChangedOptions {
+    /// The git ref to compare against, if only changed files should be linted.
+    pub fn since(&self) -> Option<&str> {
+        self.since.as_deref().or(self.changed.then_some("HEAD"))
+    }
+}
+
+/// Daemon
+#[derive(Debug, Clone, Bpaf)]
+pub struct DaemonOptions {
+    /// Start a daemon which lints the files of lint requests sent to a local socket.
+    /// Parsed configuration files are kept between requests, and reloaded when they change.
+    /// Source files are parsed again for every request. JS plugins are not supported.
+    /// Only processes of the same user can connect to the daemon.
+    #[bpaf(switch, hide_usage)]
+    pub daemon: bool,
+
+    /// Send the lint to a running daemon, or lint in this process if none is running
+    #[bpaf(switch, hide_usage)]
+    pub use_daemon: bool,
+
+    /// Path of the socket of the daemon.
+    /// Defaults to a path unique to the working directory, in `$XDG_RUNTIME_DIR`,
+    /// or a directory in the temporary directory that only the current user can access.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub daemon_socket: Option<PathBuf>,
+}
+
+impl DaemonOptions {
+    /// The path of the socket used by the daemon for `cwd`.
+    pub fn socket_path(&self, cwd: &Path) -> PathBuf {
+        if let Some(daemon_socket) = &self.daemon_socket {
+            return cwd.join(daemon_socket);
+        }
+        let mut hasher = FxHasher::default();
+        cwd.hash(&mut hasher);
+        default_socket_dir().join(format!("oxlint-{VERSION}-{:016x}.sock", hasher.finish()))
+    }
+}
+
 /// Output
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
     /// Use a specific output format. Possible values:
-    /// `checkstyle`, `default`, `github`, `gitlab`, `json`, `junit`, `stylish`, `unix`
+    /// `checkstyle`, `default`, `github`, `gitlab`, `grouped`, `json`, `junit`, `stylish`,
+    /// `unix`,
+    /// or the name of a reporter registered by the program embedding oxlint
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
+
+    /// Show a status line with the files linted, diagnostics so far and estimated time remaining.
+    /// Only shown when stderr is a terminal.
+    #[bpaf(switch, hide_usage)]
+    pub progress: bool,
 }
 
 /// Enable/Disable Plugins
@@ -320,6 +516,22 @@ pub struct EnablePlugins {
     /// Enable the vue plugin and detect vue usage problems
     #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
     pub vue_plugin: OverrideToggle,
+
+    /// Enable the security plugin and detect code which is vulnerable to injection attacks
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub security_plugin: OverrideToggle,
+
+    /// Enable the sonarjs plugin and detect code which is difficult to understand or duplicated
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub sonarjs_plugin: OverrideToggle,
+
+    /// Enable the regexp plugin and detect problems in regular expressions
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub regexp_plugin: OverrideToggle,
+
+    /// Enable the i18next plugin and detect hard-coded strings which should be translated
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub i18next_plugin: OverrideToggle,
 }
 
 /// Enables or disables a boolean option, or leaves it unset.
@@ -395,6 +607,10 @@ impl EnablePlugins {
         self.promise_plugin.inspect(|yes| plugins.set(LintPlugins::PROMISE, yes));
         self.node_plugin.inspect(|yes| plugins.set(LintPlugins::NODE, yes));
         self.vue_plugin.inspect(|yes| plugins.set(LintPlugins::VUE, yes));
+        self.security_plugin.inspect(|yes| plugins.set(LintPlugins::SECURITY, yes));
+        self.sonarjs_plugin.inspect(|yes| plugins.set(LintPlugins::SONARJS, yes));
+        self.regexp_plugin.inspect(|yes| plugins.set(LintPlugins::REGEXP, yes));
+        self.i18next_plugin.inspect(|yes| plugins.set(LintPlugins::I18NEXT, yes));
 
         // Without this, jest plugins adapted to vitest will not be enabled.
         if self.vitest_plugin.is_enabled() && self.jest_plugin.is_not_set() {
@@ -406,7 +622,8 @@ impl EnablePlugins {
 #[derive(Debug, Clone, PartialEq, Eq, Bpaf)]
 pub enum ReportUnusedDirectives {
     WithoutSeverity(
-        /// Report directive comments like `// oxlint-disable-line`, when no errors would have been reported on that line anyway
+        /// Report directive comments like `// oxlint-disable-line`, when no errors would have been reported on that line anyway.
+        /// Use with `--fix` to remove them.
         // More information at <https://eslint.org/docs/latest/use/command-line-interface#--report-unused-disable-directives>
         #[bpaf(long("report-unused-disable-directives"), switch, hide_usage)]
         bool,
@@ -504,11 +721,140 @@ mod warning_options {
     }
 }
 
+#[cfg(test)]
+mod baseline_options {
+    use std::path::PathBuf;
+
+    use super::{BaselineOptions, lint_command};
+
+    fn get_baseline_options(arg: &str) -> BaselineOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap().baseline_options
+    }
+
+    #[test]
+    fn default() {
+        let options = get_baseline_options(".");
+        assert!(!options.generate_baseline);
+        assert_eq!(options.baseline, None);
+        assert!(!options.suppress_all);
+    }
+
+    #[test]
+    fn generate_baseline() {
+        let options = get_baseline_options("--generate-baseline .");
+        assert!(options.generate_baseline);
+    }
+
+    #[test]
+    fn baseline() {
+        let options = get_baseline_options("--baseline baseline.json .");
+        assert_eq!(options.baseline, Some(PathBuf::from("baseline.json")));
+    }
+
+    #[test]
+    fn suppress_all() {
+        let options = get_baseline_options("--suppress-all .");
+        assert!(options.suppress_all);
+    }
+}
+
+#[cfg(test)]
+mod cache_options {
+    use std::path::PathBuf;
+
+    use super::{CacheOptions, lint_command};
+
+    fn get_cache_options(arg: &str) -> CacheOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap().cache_options
+    }
+
+    #[test]
+    fn default() {
+        let options = get_cache_options(".");
+        assert!(!options.cache);
+        assert_eq!(options.cache_location, None);
+    }
+
+    #[test]
+    fn cache() {
+        let options = get_cache_options("--cache --cache-location cache.json .");
+        assert!(options.cache);
+        assert_eq!(options.cache_location, Some(PathBuf::from("cache.json")));
+    }
+}
+
+#[cfg(test)]
+mod changed_options {
+    use super::{ChangedOptions, lint_command};
+
+    fn get_changed_options(arg: &str) -> ChangedOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap().changed_options
+    }
+
+    #[test]
+    fn default() {
+        let options = get_changed_options(".");
+        assert_eq!(options.since(), None);
+        assert!(!options.include_dependents);
+    }
+
+    #[test]
+    fn changed() {
+        let options = get_changed_options("--changed .");
+        assert_eq!(options.since(), Some("HEAD"));
+    }
+
+    #[test]
+    fn since() {
+        let options = get_changed_options("--since main --include-dependents .");
+        assert_eq!(options.since(), Some("main"));
+        assert!(options.include_dependents);
+    }
+
+    #[test]
+    fn staged() {
+        let options = get_changed_options("--staged .");
+        assert!(options.staged);
+        assert_eq!(options.since(), None);
+    }
+}
+
+#[cfg(test)]
+mod daemon_options {
+    use std::path::Path;
+
+    use super::{DaemonOptions, lint_command};
+
+    fn get_daemon_options(arg: &str) -> DaemonOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap().daemon_options
+    }
+
+    #[test]
+    fn default() {
+        let options = get_daemon_options(".");
+        assert!(!options.daemon);
+        assert!(!options.use_daemon);
+        assert_eq!(options.socket_path(Path::new("/a")), options.socket_path(Path::new("/a")));
+        assert_ne!(options.socket_path(Path::new("/a")), options.socket_path(Path::new("/b")));
+    }
+
+    #[test]
+    fn daemon_socket() {
+        let options = get_daemon_options("--use-daemon --daemon-socket oxlint.sock .");
+        assert!(options.use_daemon);
+        assert_eq!(options.socket_path(Path::new("/a")), Path::new("/a/oxlint.sock"));
+    }
+}
+
 #[cfg(test)]
 mod lint_options {
     use std::{fs::File, path::PathBuf};
 
-    use oxc_linter::AllowWarnDeny;
+    use oxc_linter::{AllowWarnDeny, FixKind, FixOnly};
 
     use super::{LintCommand, OutputFormat, lint_command};
 
@@ -580,6 +926,13 @@ mod lint_options {
         );
     }
 
+    #[test]
+    fn rule() {
+        let options = get_lint_options("--rule no-debugger:error --rule eqeqeq:[warn,smart] src");
+        assert_eq!(options.rule, ["no-debugger:error", "eqeqeq:[warn,smart]"]);
+        assert_eq!(options.paths, [PathBuf::from("src")]);
+    }
+
     #[test]
     fn format() {
         let options = get_lint_options("-f json");
@@ -588,12 +941,47 @@ mod lint_options {
     }
 
     #[test]
-    fn format_error() {
-        let args = "-f asdf".split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
-        let result = lint_command().run_inner(args.as_slice());
-        assert!(result.is_err_and(
-            |err| err.unwrap_stderr() == "couldn't parse `asdf`: 'asdf' is not a known format"
-        ));
+    fn format_custom() {
+        let options = get_lint_options("-f asdf");
+        assert_eq!(options.output_options.format, OutputFormat::Custom("asdf".to_string()));
+    }
+
+    #[test]
+    fn max_fix_passes() {
+        let options = get_lint_options("--fix --max-fix-passes=3 test.js");
+        assert_eq!(options.fix_options.max_fix_passes, Some(3));
+    }
+
+    #[test]
+    fn fix_dry_run() {
+        let options = get_lint_options("--fix-dry-run test.js");
+        assert!(options.fix_options.is_dry_run());
+        assert_eq!(options.fix_options.fix_kind(), FixKind::SafeFix);
+
+        let options = get_lint_options("--fix-dry-run --fix-suggestions test.js");
+        assert_eq!(options.fix_options.fix_kind(), FixKind::Suggestion);
+
+        let options = get_lint_options("--fix-patch=fixes.patch test.js");
+        assert!(options.fix_options.is_dry_run());
+        assert_eq!(options.fix_options.fix_patch, Some(PathBuf::from("fixes.patch")));
+    }
+
+    #[test]
+    fn fix_only() {
+        let options =
+            get_lint_options("--fix-only=no-debugger,eslint/no-var --fix-only no-console");
+        assert!(options.fix_options.is_enabled());
+        assert_eq!(options.fix_options.fix_kind(), FixKind::SafeFix);
+        assert_eq!(
+            options.fix_options.fix_only(),
+            Some(FixOnly::new(["no-debugger", "eslint/no-var", "no-console"]))
+        );
+
+        let options = get_lint_options("--fix-only=no-debugger --fix-suggestions");
+        assert_eq!(options.fix_options.fix_kind(), FixKind::Suggestion);
+
+        let options = get_lint_options("--fix");
+        assert_eq!(options.fix_options.fix_only(), None);
     }
 
     #[test]
@@ -1,13 +1,16 @@
 mod ignore;
 mod lint;
 
-use std::path::PathBuf;
+use std::{path::PathBuf, str::FromStr};
 
-use bpaf::Bpaf;
+use bpaf::{Args, Bpaf};
 
 pub use self::{
     ignore::IgnoreOptions,
-    lint::{LintCommand, OutputOptions, ReportUnusedDirectives, WarningOptions, lint_command},
+    lint::{
+        BaselineOptions, CacheOptions, ChangedOptions, DaemonOptions, LintCommand, OutputOptions,
+        ReportUnusedDirectives, WarningOptions, lint_command,
+    },
 };
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -27,6 +30,71 @@ pub struct MiscOptions {
     /// When present, no linting is performed and only config-related options are valid.
     #[bpaf(switch, hide_usage)]
     pub print_config: bool,
+
+    /// Print the time spent in each lint rule, slowest rules first.
+    /// With `--format=json`, the timings are included in the JSON output.
+    #[bpaf(switch, hide_usage)]
+    pub timing: bool,
+
+    /// Print the JSON schema of configuration files, with the options of every rule.
+    /// When present, no linting is performed.
+    #[bpaf(switch, hide_usage)]
+    pub print_schema: bool,
+
+    /// Print the documentation of RULE: its description and the schema of its options.
+    /// With `--format=json`, the documentation is printed as JSON.
+    /// Requires oxlint to be built with the `ruledocs` feature.
+    /// When present, no linting is performed.
+    #[bpaf(argument("RULE"), hide_usage)]
+    pub explain: Option<String>,
+
+    /// Print a script which completes the options of oxlint in SHELL:
+    /// `bash`, `zsh`, `fish` or `elvish`.
+    /// When present, no linting is performed.
+    #[bpaf(argument("SHELL"), hide_usage)]
+    pub completions: Option<Shell>,
+}
+
+/// Shells which `--completions` prints a script for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    Elvish,
+}
+
+impl FromStr for Shell {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bash" => Ok(Self::Bash),
+            "zsh" => Ok(Self::Zsh),
+            "fish" => Ok(Self::Fish),
+            "elvish" => Ok(Self::Elvish),
+            _ => Err(format!(
+                "'{s}' is not a known shell, expected `bash`, `zsh`, `fish` or `elvish`"
+            )),
+        }
+    }
+}
+
+impl Shell {
+    /// Prints the completion script for this shell, and exits the process.
+    ///
+    /// The script completes by running `oxlint` itself, so the completions always match the
+    /// installed version.
+    pub fn print_completions(self) {
+        let args: &[&str] = match self {
+            Self::Bash => &["--bpaf-complete-style-bash"],
+            Self::Zsh => &["--bpaf-complete-style-zsh"],
+            Self::Fish => &["--bpaf-complete-style-fish"],
+            Self::Elvish => &["--bpaf-complete-style-elvish"],
+        };
+        // bpaf prints the script and exits when it sees these arguments, if the program has a name.
+        let _ = lint::lint_command().run_inner(Args::from(args).set_name("oxlint"));
+    }
 }
 
 #[expect(clippy::ptr_arg)]
@@ -42,7 +110,7 @@ const PATHS_ERROR_MESSAGE: &str = "PATH must not contain \"..\"";
 
 #[cfg(test)]
 mod misc_options {
-    use super::{MiscOptions, lint::lint_command};
+    use super::{MiscOptions, Shell, lint::lint_command};
 
     fn get_misc_options(arg: &str) -> MiscOptions {
         let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -60,4 +128,29 @@ mod misc_options {
         let options = get_misc_options("--threads 4 .");
         assert_eq!(options.threads, Some(4));
     }
+
+    #[test]
+    fn timing() {
+        let options = get_misc_options("--timing .");
+        assert!(options.timing);
+    }
+
+    #[test]
+    fn print_schema() {
+        let options = get_misc_options("--print-schema");
+        assert!(options.print_schema);
+    }
+
+    #[test]
+    fn explain() {
+        let options = get_misc_options("--explain typescript/no-explicit-any");
+        assert_eq!(options.explain.as_deref(), Some("typescript/no-explicit-any"));
+    }
+
+    #[test]
+    fn completions() {
+        let options = get_misc_options("--completions zsh");
+        assert_eq!(options.completions, Some(Shell::Zsh));
+        assert!("powershell".parse::<Shell>().is_err());
+    }
 }
@@ -0,0 +1,665 @@
+use std::{
+    env,
+    ffi::OsString,
+    io::{self, Write},
+    panic::{AssertUnwindSafe, catch_unwind},
+    path::{Path, PathBuf},
+    process::{ExitCode, Termination},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use bpaf::ParseFailure;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_linter::{FileChange, FileChangeKind, FileStamp, FileWatcher, Oxlintrc, file_stamp};
+
+use crate::{
+    cli::{CliRunResult, CliRunner, LintCommand, lint_command},
+    lint::print_and_flush_stdout,
+    watch::ignore_filter,
+};
+
+pub use platform::default_socket_dir;
+
+/// A lint request sent to the daemon, as a single line of JSON.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DaemonRequest {
+    /// Working directory of the client. Paths in `args` are relative to it.
+    pub cwd: PathBuf,
+    /// Command line arguments, as they would be passed to `oxlint`.
+    pub args: Vec<String>,
+    /// Source text for `--stdin-filename`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stdin: Option<String>,
+}
+
+/// The response of the daemon to a [`DaemonRequest`], as a single line of JSON.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DaemonResponse {
+    /// Whether `oxlint` would have exited successfully.
+    pub success: bool,
+    /// Everything `oxlint` would have printed to stdout.
+    pub output: String,
+}
+
+/// Parsed configuration files, reused until the file changes on disk.
+///
+/// Files in `extends` are not cached, they are read again when the configuration is built.
+#[derive(Debug, Default)]
+pub struct ConfigFileCache {
+    files: Mutex<FxHashMap<PathBuf, (FileStamp, Oxlintrc)>>,
+}
+
+impl ConfigFileCache {
+    /// # Errors
+    ///
+    /// * The file can't be read or parsed
+    ///
+    /// # Panics
+    ///
+    /// * The cache is poisoned
+    pub fn load(&self, path: &Path) -> Result<Oxlintrc, OxcDiagnostic> {
+        let stamp = file_stamp(path);
+        let mut files = self.files.lock().unwrap();
+        if let Some(stamp) = stamp
+            && let Some((cached_stamp, oxlintrc)) = files.get(path)
+            && *cached_stamp == stamp
+        {
+            return Ok(oxlintrc.clone());
+        }
+        let oxlintrc = Oxlintrc::from_file(path)?;
+        match stamp {
+            Some(stamp) => {
+                files.insert(path.to_path_buf(), (stamp, oxlintrc.clone()));
+            }
+            None => {
+                files.remove(path);
+            }
+        }
+        Ok(oxlintrc)
+    }
+
+    /// Parses the cached files among `changes` again, so that the next request doesn't wait for
+    /// it, and forgets the removed ones.
+    ///
+    /// # Panics
+    ///
+    /// * The cache is poisoned
+    pub fn refresh(&self, changes: &[FileChange]) {
+        for change in changes {
+            if !self.files.lock().unwrap().contains_key(&change.path) {
+                continue;
+            }
+            if change.kind == FileChangeKind::Removed {
+                self.files.lock().unwrap().remove(&change.path);
+            } else {
+                // An invalid file is reported by the next request which loads it.
+                let _ = self.load(&change.path);
+            }
+        }
+    }
+}
+
+/// Lint as `oxlint` would with the arguments of `request`, and collect its output.
+pub fn handle_request(
+    request: DaemonRequest,
+    config_cache: &Arc<ConfigFileCache>,
+) -> DaemonResponse {
+    let args = request.args.into_iter().map(OsString::from).collect::<Vec<_>>();
+    let mut command = match lint_command().run_inner(&*args) {
+        Ok(command) => command,
+        Err(ParseFailure::Stdout(message, full)) => {
+            return DaemonResponse { success: true, output: message.monochrome(full) + "\n" };
+        }
+        Err(ParseFailure::Completion(output)) => return DaemonResponse { success: true, output },
+        Err(ParseFailure::Stderr(message)) => {
+            return DaemonResponse {
+                success: false,
+                output: format!("Error: {}\n", message.monochrome(true)),
+            };
+        }
+    };
+    if command.lsp
+        || command.watch
+        || command.daemon_options.daemon
+        || command.misc_options.completions.is_some()
+    {
+        return DaemonResponse {
+            success: false,
+            output:
+                "`--lsp`, `--watch`, `--daemon` and `--completions` cannot be sent to a daemon\n"
+                    .to_string(),
+        };
+    }
+
+    // The status line would be drawn on the daemon's stderr, not the client's.
+    command.output_options.progress = false;
+
+    // The daemon's own stdin must not be read for `--stdin-filename`.
+    let stdin = command.stdin_filename.is_some().then(|| request.stdin.unwrap_or_default());
+    let mut runner = CliRunner::new(command, None)
+        .with_cwd(request.cwd)
+        .with_config_cache(Arc::clone(config_cache));
+    if let Some(stdin) = stdin {
+        runner = runner.with_stdin(stdin);
+    }
+
+    let mut output = Vec::new();
+    // A panic while linting shouldn't take down the daemon, and the other clients using it.
+    let result = catch_unwind(AssertUnwindSafe(|| runner.run(&mut output)));
+    let mut output = String::from_utf8_lossy(&output).into_owned();
+    let success = if let Ok(result) = result {
+        result.report() == ExitCode::SUCCESS
+    } else {
+        output.push_str("oxlint panicked while linting, see the output of the daemon\n");
+        false
+    };
+    DaemonResponse { success, output }
+}
+
+/// Removes the options which only concern the client from its command line arguments.
+fn request_args(mut args: impl Iterator<Item = String>) -> Vec<String> {
+    let mut request_args = vec![];
+    while let Some(arg) = args.next() {
+        if arg == "--use-daemon" || arg.starts_with("--daemon-socket=") {
+            continue;
+        }
+        if arg == "--daemon-socket" {
+            args.next();
+            continue;
+        }
+        request_args.push(arg);
+    }
+    request_args
+}
+
+/// Send the lint to the daemon for the working directory.
+///
+/// Returns `None` if no daemon is running, so that the caller can lint in this process.
+pub fn run_client(command: &LintCommand, stdout: &mut dyn Write) -> Option<CliRunResult> {
+    let cwd = env::current_dir().ok()?;
+    let socket = command.daemon_options.socket_path(&cwd);
+    let connection = match platform::connect(&socket) {
+        Ok(connection) => connection,
+        // Another user may have bound the socket to pose as the daemon.
+        Err(err) if err.kind() == io::ErrorKind::PermissionDenied => {
+            print_and_flush_stdout(
+                stdout,
+                &format!("Failed to lint with the daemon at {}: {err}\n", socket.display()),
+            );
+            return Some(CliRunResult::DaemonFailed);
+        }
+        Err(_) => return None,
+    };
+
+    let stdin = if command.stdin_filename.is_some() {
+        match io::read_to_string(io::stdin()) {
+            Ok(stdin) => Some(stdin),
+            Err(err) => {
+                print_and_flush_stdout(
+                    stdout,
+                    &format!("Failed to read source text from stdin: {err}\n"),
+                );
+                return Some(CliRunResult::InvalidOptionStdin);
+            }
+        }
+    } else {
+        None
+    };
+    let args = request_args(env::args_os().skip(1).map(|arg| arg.to_string_lossy().into_owned()));
+    let request = DaemonRequest { cwd, args, stdin };
+
+    match platform::send(&connection, &request) {
+        Ok(response) => {
+            print_and_flush_stdout(stdout, &response.output);
+            Some(if response.success {
+                CliRunResult::LintSucceeded
+            } else {
+                CliRunResult::DaemonLintFailed
+            })
+        }
+        Err(err) => {
+            print_and_flush_stdout(
+                stdout,
+                &format!("Failed to lint with the daemon at {}: {err}\n", socket.display()),
+            );
+            Some(CliRunResult::DaemonFailed)
+        }
+    }
+}
+
+/// Run the daemon for the working directory, until the process is killed.
+pub fn run_daemon(command: &LintCommand, stdout: &mut dyn Write) -> CliRunResult {
+    let Ok(cwd) = env::current_dir() else {
+        print_and_flush_stdout(stdout, "Failed to get current working directory\n");
+        return CliRunResult::DaemonFailed;
+    };
+    let socket = command.daemon_options.socket_path(&cwd);
+    let private_dir = command.daemon_options.daemon_socket.is_none();
+    let config_cache = Arc::new(ConfigFileCache::default());
+    // Without a watcher, changed files are still noticed by their stamps when they are loaded.
+    let is_ignored = ignore_filter(&cwd, &command.ignore_options, None);
+    match FileWatcher::new(&cwd, is_ignored) {
+        Ok(mut watcher) => {
+            let config_cache = Arc::clone(&config_cache);
+            thread::spawn(move || {
+                loop {
+                    let changes = watcher.wait();
+                    if changes.is_empty() {
+                        break;
+                    }
+                    config_cache.refresh(&changes);
+                }
+            });
+        }
+        Err(err) => {
+            print_and_flush_stdout(
+                stdout,
+                &format!("Failed to watch configuration files: {err}\n"),
+            );
+        }
+    }
+    match platform::serve(&socket, private_dir, &config_cache, stdout) {
+        Ok(()) => CliRunResult::None,
+        Err(err) => {
+            print_and_flush_stdout(
+                stdout,
+                &format!("Failed to run the daemon at {}: {err}\n", socket.display()),
+            );
+            CliRunResult::DaemonFailed
+        }
+    }
+}
+
+#[cfg(unix)]
+mod platform {
+    use std::{
+        env,
+        fs::{self, DirBuilder},
+        io::{self, BufRead, BufReader, ErrorKind, Write},
+        os::unix::{
+            fs::{DirBuilderExt, MetadataExt, PermissionsExt},
+            io::AsRawFd,
+            net::{UnixListener, UnixStream},
+        },
+        path::{Path, PathBuf},
+        sync::Arc,
+        thread,
+        time::Duration,
+    };
+
+    use super::{ConfigFileCache, DaemonRequest, DaemonResponse, handle_request};
+    use crate::lint::print_and_flush_stdout;
+
+    /// How long the daemon waits for a client to send its request, or to read the response.
+    const TIMEOUT: Duration = Duration::from_secs(30);
+
+    /// A directory only the current user can access: `$XDG_RUNTIME_DIR`, or a directory in the
+    /// temporary directory created by [`serve`].
+    pub fn default_socket_dir() -> PathBuf {
+        match env::var_os("XDG_RUNTIME_DIR") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => env::temp_dir().join(format!("oxlint-{}", current_uid())),
+        }
+    }
+
+    fn current_uid() -> u32 {
+        // SAFETY: `getuid` is always successful.
+        unsafe { libc::getuid() }
+    }
+
+    /// The user ID of the process at the other end of `stream`.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn peer_uid(stream: &UnixStream) -> io::Result<u32> {
+        let mut credentials = libc::ucred { pid: 0, uid: 0, gid: 0 };
+        #[expect(clippy::cast_possible_truncation)]
+        let mut len = size_of::<libc::ucred>() as libc::socklen_t;
+        // SAFETY: `credentials` and `len` are valid for writes of `len` bytes.
+        let result = unsafe {
+            libc::getsockopt(
+                stream.as_raw_fd(),
+                libc::SOL_SOCKET,
+                libc::SO_PEERCRED,
+                (&raw mut credentials).cast(),
+                &raw mut len,
+            )
+        };
+        if result != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(credentials.uid)
+    }
+
+    /// The user ID of the process at the other end of `stream`.
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    fn peer_uid(stream: &UnixStream) -> io::Result<u32> {
+        let mut uid = 0;
+        let mut gid = 0;
+        // SAFETY: `uid` and `gid` are valid for writes.
+        if unsafe { libc::getpeereid(stream.as_raw_fd(), &raw mut uid, &raw mut gid) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(uid)
+    }
+
+    /// Only processes of the current user may talk to the daemon, or pose as it.
+    fn check_peer(stream: &UnixStream) -> io::Result<()> {
+        if peer_uid(stream)? == current_uid() {
+            Ok(())
+        } else {
+            Err(io::Error::new(ErrorKind::PermissionDenied, "the peer is run by another user"))
+        }
+    }
+
+    /// Create `dir` if needed, and check that only the current user can access it.
+    fn create_private_dir(dir: &Path) -> io::Result<()> {
+        if !dir.exists() {
+            DirBuilder::new().recursive(true).mode(0o700).create(dir)?;
+        }
+        let metadata = fs::metadata(dir)?;
+        if metadata.uid() != current_uid() || metadata.mode() & 0o077 != 0 {
+            return Err(io::Error::new(
+                ErrorKind::PermissionDenied,
+                format!("{} is accessible by other users", dir.display()),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn connect(socket: &Path) -> io::Result<UnixStream> {
+        let stream = UnixStream::connect(socket)?;
+        check_peer(&stream)?;
+        Ok(stream)
+    }
+
+    pub fn send(stream: &UnixStream, request: &DaemonRequest) -> io::Result<DaemonResponse> {
+        write_line(stream, request)?;
+        let response = read_line(stream)?.ok_or_else(|| {
+            io::Error::new(ErrorKind::UnexpectedEof, "the daemon closed the connection")
+        })?;
+        Ok(response?)
+    }
+
+    /// Serve lint requests on `socket`. If `private_dir` is set, the directory of the socket is
+    /// created so that only the current user can access it.
+    pub fn serve(
+        socket: &Path,
+        private_dir: bool,
+        config_cache: &Arc<ConfigFileCache>,
+        stdout: &mut dyn Write,
+    ) -> io::Result<()> {
+        if let Some(parent) = socket.parent() {
+            if private_dir {
+                create_private_dir(parent)?;
+            } else {
+                fs::create_dir_all(parent)?;
+            }
+        }
+        if UnixStream::connect(socket).is_ok() {
+            return Err(io::Error::new(ErrorKind::AddrInUse, "a daemon is already running"));
+        }
+        // A daemon which was killed leaves its socket behind.
+        if socket.exists() {
+            fs::remove_file(socket)?;
+        }
+        let listener = UnixListener::bind(socket)?;
+        fs::set_permissions(socket, fs::Permissions::from_mode(0o600))?;
+        print_and_flush_stdout(stdout, &format!("Listening on {}\n", socket.display()));
+
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else { continue };
+            let config_cache = Arc::clone(config_cache);
+            // A slow client must not hold up the others. Linting shares the thread pool.
+            thread::spawn(move || {
+                // A client which disconnects early doesn't concern other clients.
+                let _ = check_peer(&stream)
+                    .and_then(|()| stream.set_read_timeout(Some(TIMEOUT)))
+                    .and_then(|()| stream.set_write_timeout(Some(TIMEOUT)))
+                    .and_then(|()| serve_connection(&stream, &config_cache));
+            });
+        }
+        Ok(())
+    }
+
+    pub fn serve_connection(
+        stream: &UnixStream,
+        config_cache: &Arc<ConfigFileCache>,
+    ) -> io::Result<()> {
+        let Some(request) = read_line(stream)? else {
+            return Ok(());
+        };
+        let response = match request {
+            Ok(request) => handle_request(request, config_cache),
+            Err(err) => DaemonResponse {
+                success: false,
+                output: format!("Invalid request to the daemon: {err}\n"),
+            },
+        };
+        write_line(stream, &response)
+    }
+
+    fn write_line(mut stream: &UnixStream, value: &impl serde::Serialize) -> io::Result<()> {
+        serde_json::to_writer(&mut stream, value)?;
+        stream.write_all(b"\n")?;
+        stream.flush()
+    }
+
+    /// Reads a line of JSON, or `None` if the connection was closed.
+    fn read_line<T: serde::de::DeserializeOwned>(
+        stream: &UnixStream,
+    ) -> io::Result<Option<serde_json::Result<T>>> {
+        let mut line = String::new();
+        if BufReader::new(stream).read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::from_str(&line)))
+    }
+}
+
+#[cfg(not(unix))]
+mod platform {
+    use std::{
+        env,
+        io::{self, ErrorKind, Write},
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+
+    use super::{ConfigFileCache, DaemonRequest, DaemonResponse};
+
+    pub struct Connection;
+
+    fn unsupported() -> io::Error {
+        io::Error::new(ErrorKind::Unsupported, "the daemon is only supported on Unix")
+    }
+
+    pub fn connect(_socket: &Path) -> io::Result<Connection> {
+        Err(unsupported())
+    }
+
+    pub fn send(_connection: &Connection, _request: &DaemonRequest) -> io::Result<DaemonResponse> {
+        Err(unsupported())
+    }
+
+    pub fn default_socket_dir() -> PathBuf {
+        env::temp_dir()
+    }
+
+    pub fn serve(
+        _socket: &Path,
+        _private_dir: bool,
+        _config_cache: &Arc<ConfigFileCache>,
+        _stdout: &mut dyn Write,
+    ) -> io::Result<()> {
+        Err(unsupported())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, sync::Arc};
+
+    use oxc_linter::{FileChange, FileChangeKind};
+
+    use super::{ConfigFileCache, DaemonRequest, handle_request, request_args};
+
+    fn request(args: &[&str]) -> DaemonRequest {
+        DaemonRequest {
+            cwd: env::current_dir().unwrap().join("fixtures/linter"),
+            args: args.iter().map(ToString::to_string).collect(),
+            stdin: None,
+        }
+    }
+
+    #[test]
+    fn lint() {
+        let config_cache = Arc::new(ConfigFileCache::default());
+        let response = handle_request(request(&["debugger.js"]), &config_cache);
+        assert!(response.success);
+        assert!(response.output.contains("eslint(no-debugger)"));
+
+        let response =
+            handle_request(request(&["-D", "no-debugger", "debugger.js"]), &config_cache);
+        assert!(!response.success);
+    }
+
+    #[test]
+    fn lint_stdin() {
+        let config_cache = Arc::new(ConfigFileCache::default());
+        let mut request = request(&["--stdin-filename", "test.js"]);
+        request.stdin = Some("debugger;".to_string());
+        let response = handle_request(request, &config_cache);
+        assert!(response.output.contains("eslint(no-debugger)"));
+    }
+
+    #[test]
+    fn reject_daemon_options() {
+        let config_cache = Arc::new(ConfigFileCache::default());
+        for args in [&["--daemon"], &["--lsp"], &["--watch"]] {
+            let response = handle_request(request(args), &config_cache);
+            assert!(!response.success);
+        }
+    }
+
+    #[test]
+    fn reload_changed_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".oxlintrc.json");
+        let config_cache = ConfigFileCache::default();
+
+        fs::write(&path, r#"{ "ignorePatterns": ["dist"] }"#).unwrap();
+        assert_eq!(config_cache.load(&path).unwrap().ignore_patterns, ["dist"]);
+        assert_eq!(config_cache.load(&path).unwrap().ignore_patterns, ["dist"]);
+
+        fs::write(&path, r#"{ "ignorePatterns": ["dist", "build"] }"#).unwrap();
+        assert_eq!(config_cache.load(&path).unwrap().ignore_patterns, ["dist", "build"]);
+
+        fs::remove_file(&path).unwrap();
+        assert!(config_cache.load(&path).is_err());
+    }
+
+    #[test]
+    fn refresh_changed_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".oxlintrc.json");
+        let other_path = dir.path().join("a/.oxlintrc.json");
+        let config_cache = ConfigFileCache::default();
+        let cached_paths = || {
+            let mut paths = config_cache.files.lock().unwrap().keys().cloned().collect::<Vec<_>>();
+            paths.sort_unstable();
+            paths
+        };
+
+        fs::write(&path, r#"{ "ignorePatterns": ["dist"] }"#).unwrap();
+        config_cache.load(&path).unwrap();
+        fs::write(&path, r#"{ "ignorePatterns": ["dist", "build"] }"#).unwrap();
+        config_cache.refresh(&[
+            FileChange::new(&path, FileChangeKind::Changed),
+            FileChange::new(&other_path, FileChangeKind::Created),
+        ]);
+        // only cached files are parsed again
+        assert_eq!(cached_paths(), std::slice::from_ref(&path));
+        let ignore_patterns = config_cache.files.lock().unwrap()[&path].1.ignore_patterns.clone();
+        assert_eq!(ignore_patterns, ["dist", "build"]);
+
+        fs::remove_file(&path).unwrap();
+        config_cache.refresh(&[FileChange::new(&path, FileChangeKind::Removed)]);
+        assert!(cached_paths().is_empty());
+    }
+
+    #[test]
+    fn strip_client_options() {
+        let args = [
+            "--use-daemon",
+            "--daemon-socket",
+            "a.sock",
+            "--daemon-socket=b.sock",
+            "-D",
+            "eqeqeq",
+            "src",
+        ];
+        assert_eq!(request_args(args.into_iter().map(String::from)), ["-D", "eqeqeq", "src"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn serve_connection() {
+        use std::{os::unix::net::UnixStream, thread};
+
+        use super::platform;
+
+        let (client, server) = UnixStream::pair().unwrap();
+        let daemon = thread::spawn(move || {
+            let config_cache = Arc::new(ConfigFileCache::default());
+            platform::serve_connection(&server, &config_cache).unwrap();
+        });
+        let response = platform::send(&client, &request(&["debugger.js"])).unwrap();
+        daemon.join().unwrap();
+        assert!(response.success);
+        assert!(response.output.contains("eslint(no-debugger)"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn serve_clients_concurrently() {
+        use std::{
+            fs,
+            io::sink,
+            os::unix::{fs::PermissionsExt, net::UnixStream},
+            thread,
+            time::Duration,
+        };
+
+        use super::platform;
+
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("daemon/oxlint.sock");
+        let serve_socket = socket.clone();
+        thread::spawn(move || {
+            let config_cache = Arc::new(ConfigFileCache::default());
+            platform::serve(&serve_socket, true, &config_cache, &mut sink())
+        });
+        // The permissions are set right after the socket is created.
+        let socket_mode = || fs::metadata(&socket).map(|m| m.permissions().mode() & 0o777).ok();
+        for _ in 0..500 {
+            if socket_mode() == Some(0o600) {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(socket_mode(), Some(0o600));
+        let dir_mode = fs::metadata(socket.parent().unwrap()).unwrap().permissions().mode();
+        assert_eq!(dir_mode & 0o777, 0o700);
+
+        // A client which never sends its request doesn't block the others.
+        let _stalled = UnixStream::connect(&socket).unwrap();
+        let client = platform::connect(&socket).unwrap();
+        let response = platform::send(&client, &request(&["debugger.js"])).unwrap();
+        assert!(response.success);
+    }
+}
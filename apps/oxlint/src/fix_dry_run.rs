@@ -0,0 +1,115 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use cow_utils::CowUtils;
+use rustc_hash::FxHashMap;
+use similar::TextDiff;
+
+use oxc_allocator::Allocator;
+use oxc_linter::{RuntimeFileSystem, read_to_string};
+
+/// File system used with `--fix-dry-run`.
+///
+/// Fixed files are kept in memory instead of being written to disk, so the fixes can be
+/// printed as a unified diff with [`DryRunFileSystem::diff`].
+/// Files are read from `inner`, unless they were already fixed.
+pub struct DryRunFileSystem<'a> {
+    inner: &'a (dyn RuntimeFileSystem + Sync + Send),
+    cwd: PathBuf,
+    /// Original and fixed source text of each fixed file.
+    fixed_files: Mutex<FxHashMap<PathBuf, (String, String)>>,
+}
+
+impl<'a> DryRunFileSystem<'a> {
+    pub fn new(inner: &'a (dyn RuntimeFileSystem + Sync + Send), cwd: PathBuf) -> Self {
+        Self { inner, cwd, fixed_files: Mutex::default() }
+    }
+
+    /// Unified diff of all fixed files, sorted by path. Paths are relative to the working
+    /// directory, prefixed with `a/` and `b/`, so the diff can be applied with `git apply`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a thread panicked while writing a file.
+    pub fn diff(&self) -> String {
+        let fixed_files = self.fixed_files.lock().expect("fixed files mutex poisoned");
+        let mut fixed_files = fixed_files.iter().collect::<Vec<_>>();
+        fixed_files.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut diff = String::new();
+        for (path, (original, fixed)) in fixed_files {
+            let path = path.strip_prefix(&self.cwd).unwrap_or(path).to_string_lossy();
+            let path = path.cow_replace('\\', "/");
+            let text_diff = TextDiff::from_lines(original, fixed);
+            diff.push_str(
+                &text_diff
+                    .unified_diff()
+                    .header(&format!("a/{path}"), &format!("b/{path}"))
+                    .missing_newline_hint(true)
+                    .to_string(),
+            );
+        }
+        diff
+    }
+}
+
+impl RuntimeFileSystem for DryRunFileSystem<'_> {
+    fn read_to_arena_str<'a>(
+        &'a self,
+        path: &Path,
+        allocator: &'a Allocator,
+    ) -> Result<&'a str, io::Error> {
+        let fixed_files = self.fixed_files.lock().expect("fixed files mutex poisoned");
+        if let Some((_, fixed)) = fixed_files.get(path) {
+            return Ok(allocator.alloc_str(fixed));
+        }
+        drop(fixed_files);
+        self.inner.read_to_arena_str(path, allocator)
+    }
+
+    fn write_file(&self, path: &Path, content: &str) -> Result<(), io::Error> {
+        let mut fixed_files = self.fixed_files.lock().expect("fixed files mutex poisoned");
+        if let Some((_, fixed)) = fixed_files.get_mut(path) {
+            content.clone_into(fixed);
+        } else {
+            let original = read_to_string(path)?;
+            fixed_files.insert(path.to_path_buf(), (original, content.to_string()));
+        }
+        Ok(())
+    }
+
+    fn supports_read_ahead(&self) -> bool {
+        self.inner.supports_read_ahead()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_linter::{OsFileSystem, RuntimeFileSystem, read_to_string};
+
+    use super::DryRunFileSystem;
+
+    #[test]
+    fn diff_of_fixed_files() {
+        let cwd = std::env::current_dir().unwrap();
+        let file_system = DryRunFileSystem::new(&OsFileSystem, cwd.clone());
+        let path = cwd.join("fixtures/fix_dry_run/fix.js");
+
+        file_system.write_file(&path, "foo();\nbar();\n").unwrap();
+        file_system.write_file(&path, "foo();\nbaz();\n").unwrap();
+
+        assert_eq!(
+            file_system.diff(),
+            "--- a/fixtures/fix_dry_run/fix.js\n+++ b/fixtures/fix_dry_run/fix.js\n@@ -1,3 +1,2 @@\n foo();\n-debugger\n-bar();\n+baz();\n"
+        );
+        assert!(
+            read_to_string(Path::new("fixtures/fix_dry_run/fix.js")).unwrap().contains("debugger"),
+            "file should not be written"
+        );
+    }
+}
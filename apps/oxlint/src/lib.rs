@@ -1,20 +1,34 @@
 // Ignore dead code warnings when building `tasks/website`, which disables `napi` Cargo feature
 #![cfg_attr(not(feature = "napi"), allow(dead_code))]
 
+mod changed;
 mod command;
+mod daemon;
+mod fix_dry_run;
 mod init;
 mod lint;
 mod lsp;
 mod output_formatter;
+mod progress;
 mod result;
+mod stdin;
 mod walk;
+mod watch;
 
 #[cfg(test)]
 mod tester;
 
 /// Re-exported CLI-related items for use in `tasks/website`.
 pub mod cli {
-    pub use super::{command::*, init::*, lint::CliRunner, lsp::run_lsp, result::CliRunResult};
+    pub use super::{
+        command::*,
+        daemon::{run_client, run_daemon},
+        init::*,
+        lint::CliRunner,
+        lsp::run_lsp,
+        result::CliRunResult,
+        watch::run_watch,
+    };
 }
 
 // Only include code to run linter when the `napi` feature is enabled.
@@ -1,29 +1,44 @@
 use std::{
+    cell::RefCell,
     env,
     ffi::OsStr,
     fs,
-    io::{ErrorKind, Write},
+    io::{self, ErrorKind, IsTerminal, Read, Write},
     path::{Path, PathBuf, absolute},
-    sync::Arc,
+    rc::Rc,
+    sync::{Arc, OnceLock},
     time::Instant,
 };
 
 use cow_utils::CowUtils;
-use ignore::{gitignore::Gitignore, overrides::OverrideBuilder};
+use ignore::overrides::OverrideBuilder;
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde_json::Value;
 
-use oxc_diagnostics::{DiagnosticSender, DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
+use oxc_diagnostics::{
+    DiagnosticFilter, DiagnosticSender, DiagnosticService, Error, GraphicalReportHandler,
+    NamedSource, OxcDiagnostic,
+};
 use oxc_linter::{
-    AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, ExternalLinter, ExternalPluginStore,
-    InvalidFilterKind, LintFilter, LintOptions, LintRunner, LintServiceOptions, Linter, Oxlintrc,
+    AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, EslintFlatConfig, EslintrcConfig,
+    ExternalLinter, ExternalPluginStore, FixKind, InvalidFilterKind, LintBaseline, LintBudgets,
+    LintCache, LintFilter, LintFilterKind, LintOptions, LintRunner, LintServiceOptions, Linter,
+    OsFileSystem, Oxlintrc, ReporterRegistry, RuleTimings, configure_rules, rules::RuleEnum,
     table::RuleTable,
 };
 
 use crate::{
-    cli::{CliRunResult, LintCommand, MiscOptions, ReportUnusedDirectives, WarningOptions},
-    output_formatter::{LintCommandInfo, OutputFormat, OutputFormatter},
-    walk::Walk,
+    changed::{self, StagedFileSystem},
+    cli::{
+        BaselineOptions, CacheOptions, CliRunResult, LintCommand, MiscOptions,
+        ReportUnusedDirectives, WarningOptions,
+    },
+    daemon::ConfigFileCache,
+    fix_dry_run::DryRunFileSystem,
+    output_formatter::{LintCommandInfo, OutputFormat, OutputFormatter, render_rule_timings},
+    progress::{self, Progress, ProgressReporter},
+    stdin::StdinFileSystem,
+    walk::{IgnoreMatcher, Walk},
 };
 use oxc_linter::LintIgnoreMatcher;
 
@@ -32,6 +47,14 @@ pub struct CliRunner {
     options: LintCommand,
     cwd: PathBuf,
     external_linter: Option<ExternalLinter>,
+    /// Source text for `--stdin-filename`. Read from stdin if not set.
+    stdin: Option<String>,
+    /// Reporters which can be selected with `--format`, in addition to the built-in formats.
+    reporters: ReporterRegistry,
+    /// Configuration files parsed by earlier runs, in the daemon.
+    config_cache: Option<Arc<ConfigFileCache>>,
+    /// Receives the matcher of the `ignorePatterns` of the configuration files, for `--watch`.
+    ignore_matcher_output: Option<Arc<OnceLock<LintIgnoreMatcher>>>,
 }
 
 impl CliRunner {
@@ -41,31 +64,131 @@ impl CliRunner {
             options,
             cwd: env::current_dir().expect("Failed to get current working directory"),
             external_linter,
+            stdin: None,
+            reporters: ReporterRegistry::default(),
+            config_cache: None,
+            ignore_matcher_output: None,
         }
     }
 
     /// # Panics
     pub fn run(self, stdout: &mut dyn Write) -> CliRunResult {
-        let format_str = self.options.output_options.format;
-        let output_formatter = OutputFormatter::new(format_str);
+        if self.options.misc_options.print_schema {
+            print_and_flush_stdout(stdout, &Oxlintrc::generate_schema_json_with_rules());
+            print_and_flush_stdout(stdout, "\n");
+            return CliRunResult::PrintSchemaResult;
+        }
+
+        #[cfg(feature = "ruledocs")]
+        if let Some(name) = &self.options.misc_options.explain {
+            let Some(docs) = oxc_linter::rule_docs::RuleDocs::find(name) else {
+                print_and_flush_stdout(stdout, &format!("Rule `{name}` not found\n"));
+                return CliRunResult::ExplainRuleNotFound;
+            };
+            let output = if self.options.output_options.format == OutputFormat::Json {
+                serde_json::to_string_pretty(&docs).expect("Failed to serialize rule docs") + "\n"
+            } else {
+                docs.to_markdown()
+            };
+            print_and_flush_stdout(stdout, &output);
+            return CliRunResult::ExplainRuleResult;
+        }
+
+        #[cfg(not(feature = "ruledocs"))]
+        if self.options.misc_options.explain.is_some() {
+            print_and_flush_stdout(
+                stdout,
+                "`--explain` is not available, oxlint was built without the `ruledocs` feature\n",
+            );
+            return CliRunResult::ExplainRuleNotFound;
+        }
+
+        let format_str = self.options.output_options.format.clone();
+        let output_formatter = match OutputFormatter::new(&format_str, &self.reporters) {
+            Ok(output_formatter) => output_formatter,
+            Err(error) => {
+                print_and_flush_stdout(stdout, &format!("{error}\n"));
+                return CliRunResult::InvalidOptionFormat;
+            }
+        };
 
         let LintCommand {
             paths,
             filter,
+            rule,
             basic_options,
             warning_options,
+            baseline_options,
+            cache_options,
+            changed_options,
             ignore_options,
             fix_options,
             enable_plugins,
             misc_options,
             disable_nested_config,
             inline_config_options,
+            stdin_filename,
             ..
         } = self.options;
 
         let external_linter = self.external_linter.as_ref();
 
         let mut paths = paths;
+
+        let stdin = if let Some(stdin_filename) = stdin_filename {
+            let error = if !paths.is_empty() {
+                Some("`--stdin-filename` cannot be combined with PATH arguments")
+            } else if !fix_options.fix_kind().is_none() || baseline_options.suppress_all {
+                Some("`--stdin-filename` cannot be combined with fix options")
+            } else if self.options.type_aware {
+                Some("`--stdin-filename` cannot be combined with `--type-aware`")
+            } else {
+                None
+            };
+            if let Some(error) = error {
+                print_and_flush_stdout(stdout, &format!("{error}\n"));
+                return CliRunResult::InvalidOptionStdin;
+            }
+
+            let source_text = if let Some(source_text) = self.stdin {
+                source_text
+            } else {
+                let mut source_text = String::new();
+                if let Err(err) = io::stdin().read_to_string(&mut source_text) {
+                    print_and_flush_stdout(
+                        stdout,
+                        &format!("Failed to read source text from stdin: {err}\n"),
+                    );
+                    return CliRunResult::InvalidOptionStdin;
+                }
+                source_text
+            };
+            // Pass the path on, so that ignore patterns apply to it as they would to a PATH
+            // argument.
+            paths.push(stdin_filename.clone());
+            Some(StdinFileSystem::new(self.cwd.join(stdin_filename), source_text))
+        } else {
+            None
+        };
+
+        if changed_options.staged {
+            let error = if changed_options.since().is_some() {
+                Some("`--staged` cannot be combined with `--changed` or `--since`")
+            } else if stdin.is_some() {
+                Some("`--staged` cannot be combined with `--stdin-filename`")
+            } else if !fix_options.fix_kind().is_none() || baseline_options.suppress_all {
+                Some("`--staged` cannot be combined with fix options")
+            } else if self.options.type_aware {
+                Some("`--staged` cannot be combined with `--type-aware`")
+            } else {
+                None
+            };
+            if let Some(error) = error {
+                print_and_flush_stdout(stdout, &format!("{error}\n"));
+                return CliRunResult::InvalidOptionStaged;
+            }
+        }
+
         let provided_path_count = paths.len();
         let now = Instant::now();
 
@@ -77,30 +200,92 @@ impl CliRunner {
             }
         };
 
+        let rules = match Self::get_rules(&rule) {
+            Ok(rules) => rules,
+            Err(message) => {
+                print_and_flush_stdout(stdout, &message);
+                return CliRunResult::InvalidOptionRule;
+            }
+        };
+
         let handler = if cfg!(any(test, feature = "testing")) {
             GraphicalReportHandler::new_themed(miette::GraphicalTheme::none())
         } else {
             GraphicalReportHandler::new()
         };
 
-        let config_search_result =
-            Self::find_oxlint_config(&self.cwd, basic_options.config.as_ref());
+        let eslint_config = match basic_options.config.as_ref() {
+            Some(config)
+                if EslintFlatConfig::is_flat_config_path(config)
+                    || EslintrcConfig::is_eslintrc_path(config) =>
+            {
+                Some(self.cwd.join(config))
+            }
+            // `--init` migrates the ESLint config of the project, if there is one.
+            None if basic_options.init => Self::find_eslint_config(&self.cwd),
+            _ => None,
+        };
+
+        let config_search_result = if let Some(eslint_config) = &eslint_config {
+            // Unmapped entries are free-form text, so don't mix them into machine-readable output.
+            let report_unmapped = !misc_options.silent
+                && !misc_options.print_config
+                && format_str == OutputFormat::Default;
+            Self::load_eslint_config(stdout, &handler, eslint_config, report_unmapped)
+        } else {
+            Self::find_oxlint_config(
+                &self.cwd,
+                basic_options.config.as_ref(),
+                self.config_cache.as_deref(),
+            )
+        };
 
         let mut oxlintrc = match config_search_result {
             Ok(config) => config,
             Err(err) => {
+                let report = match &eslint_config {
+                    None => {
+                        let path = basic_options
+                            .config
+                            .as_deref()
+                            .unwrap_or_else(|| Self::DEFAULT_OXLINTRC.as_ref());
+                        Self::render_config_report(&handler, &self.cwd, path, err)
+                    }
+                    Some(_) => render_report(&handler, &err),
+                };
                 print_and_flush_stdout(
                     stdout,
-                    &format!(
-                        "Failed to parse oxlint configuration file.\n{}\n",
-                        render_report(&handler, &err)
-                    ),
+                    &format!("Failed to parse oxlint configuration file.\n{report}\n"),
                 );
 
                 return CliRunResult::InvalidOptionConfig;
             }
         };
 
+        // Unknown rules are ignored when linting, so point them out.
+        if eslint_config.is_none()
+            && !misc_options.silent
+            && !misc_options.print_config
+            && format_str == OutputFormat::Default
+            && let Ok(source_text) = fs::read_to_string(&oxlintrc.path)
+        {
+            let warnings = Oxlintrc::validate(&source_text);
+            if !warnings.is_empty() {
+                let path = oxlintrc.path.strip_prefix(&self.cwd).unwrap_or(&oxlintrc.path);
+                let (entries, are) =
+                    if warnings.len() == 1 { ("entry", "is") } else { ("entries", "are") };
+                let mut message = format!(
+                    "{} {entries} in the oxlint config {are} not recognized and will be ignored.\n",
+                    warnings.len()
+                );
+                for warning in warnings {
+                    message
+                        .push_str(&Self::render_config_report(&handler, &self.cwd, path, warning));
+                }
+                print_and_flush_stdout(stdout, &message);
+            }
+        }
+
         let mut override_builder = None;
 
         if !ignore_options.no_ignore {
@@ -123,7 +308,7 @@ impl CliRunner {
             // To accommodate this, unless `--no-ignore` is passed,
             // pre-filter the paths.
             if !paths.is_empty() {
-                let (ignore, _err) = Gitignore::new(&ignore_options.ignore_path);
+                let mut ignore = IgnoreMatcher::new(&self.cwd, &ignore_options);
 
                 paths.retain_mut(|p| {
                     // Try to prepend cwd to all paths
@@ -136,8 +321,7 @@ impl CliRunner {
                     if path.is_dir() {
                         true
                     } else {
-                        !(builder.matched(p, false).is_ignore()
-                            || ignore.matched(path, false).is_ignore())
+                        !(builder.matched(&*p, false).is_ignore() || ignore.is_ignored(p, false))
                     }
                 });
             }
@@ -149,11 +333,15 @@ impl CliRunner {
             // If explicit paths were provided, but all have been
             // filtered, return early.
             if provided_path_count > 0 {
+                if let Some(begin) = output_formatter.begin_run() {
+                    print_and_flush_stdout(stdout, &begin);
+                }
                 if let Some(end) = output_formatter.lint_command_info(&LintCommandInfo {
                     number_of_files: 0,
                     number_of_rules: None,
                     threads_count: rayon::current_num_threads(),
                     start_time: now.elapsed(),
+                    rule_timings: misc_options.timing.then(Vec::new),
                 }) {
                     print_and_flush_stdout(stdout, &end);
                 }
@@ -164,8 +352,55 @@ impl CliRunner {
             paths.push(self.cwd.clone());
         }
 
-        let walker = Walk::new(&paths, &ignore_options, override_builder);
-        let mut paths = walker.paths();
+        // The status line would be mixed into the output if stderr isn't a terminal.
+        let progress = (self.options.output_options.progress && io::stderr().is_terminal())
+            .then(|| Arc::new(Progress::default()));
+        // Stopped between walking and linting, so errors in between aren't mixed with it.
+        let scan_reporter = progress.clone().map(ProgressReporter::start);
+
+        // The file passed with `--stdin-filename` doesn't need to exist, so it isn't walked.
+        let mut paths = if let Some(stdin) = &stdin {
+            vec![stdin.path().as_os_str().into()]
+        } else {
+            Walk::new(&self.cwd, &paths, &ignore_options, override_builder)
+                .with_progress(progress.clone())
+                .paths()
+        };
+        drop(scan_reporter);
+
+        if let Some(since) = changed_options.since() {
+            let mut changed = match changed::changed_files(&self.cwd, since) {
+                Ok(changed) => changed,
+                Err(err) => {
+                    print_and_flush_stdout(
+                        stdout,
+                        &format!("Failed to get changed files from git.\n{}\n", err.trim_end()),
+                    );
+                    return CliRunResult::InvalidOptionSince;
+                }
+            };
+            if changed_options.include_dependents {
+                changed::add_dependents(&paths, &mut changed);
+            }
+            paths.retain(|path| changed.contains(Path::new(path)));
+        }
+
+        let staged = if changed_options.staged {
+            let staged = match changed::staged_files(&self.cwd) {
+                Ok(staged) => StagedFileSystem::new(staged),
+                Err(err) => {
+                    print_and_flush_stdout(
+                        stdout,
+                        &format!("Failed to get staged files from git.\n{}\n", err.trim_end()),
+                    );
+                    return CliRunResult::InvalidOptionStaged;
+                }
+            };
+            paths.retain(|path| staged.contains(Path::new(path)));
+            Some(staged)
+        } else {
+            None
+        };
 
         // NAPI tests build `oxlint` with `testing` feature enabled.
         // In NAPI tests, sort file paths if oxlint is run with `--threads 1`.
@@ -186,16 +421,20 @@ impl CliRunner {
             basic_options.config.is_none();
 
         let mut nested_ignore_patterns = Vec::new();
+        let mut enabled_rule_names = Self::enabled_rule_names(&filters, &rules);
 
         let nested_configs = if search_for_nested_configs {
             match Self::get_nested_configs(
                 stdout,
                 &handler,
                 &filters,
+                &rules,
                 &paths,
                 external_linter,
                 &mut external_plugin_store,
                 &mut nested_ignore_patterns,
+                &mut enabled_rule_names,
+                self.config_cache.as_deref(),
             ) {
                 Ok(v) => v,
                 Err(v) => return v,
@@ -204,9 +443,13 @@ impl CliRunner {
             FxHashMap::default()
         };
 
-        let ignore_matcher = {
-            LintIgnoreMatcher::new(&oxlintrc.ignore_patterns, &self.cwd, nested_ignore_patterns)
+        let ignore_matcher =
+            LintIgnoreMatcher::new(&oxlintrc.ignore_patterns, &self.cwd, nested_ignore_patterns);
+        let ignore_matcher = match &self.ignore_matcher_output {
+            Some(output) => output.get_or_init(|| ignore_matcher),
+            None => &ignore_matcher,
         };
+        let budgets = Rc::new(RefCell::new(LintBudgets::new(&oxlintrc.budgets)));
 
         {
             let mut plugins = oxlintrc.plugins.unwrap_or_default();
@@ -239,6 +482,10 @@ impl CliRunner {
             }
         }
         .with_filters(&filters);
+        enabled_rule_names.extend(config_builder.enabled_rule_names.iter().cloned());
+        let config_builder = rules.iter().fold(config_builder, |builder, (rule, severity)| {
+            builder.with_rule(rule.clone(), *severity)
+        });
 
         // If no external rules, discard `ExternalLinter`
         let mut external_linter = self.external_linter;
@@ -271,8 +518,18 @@ impl CliRunner {
                     config_file
                 };
 
-                if fs::write(Self::DEFAULT_OXLINTRC, configuration).is_ok() {
-                    print_and_flush_stdout(stdout, "Configuration file created\n");
+                if fs::write(self.cwd.join(Self::DEFAULT_OXLINTRC), configuration).is_ok() {
+                    let message = match &eslint_config {
+                        Some(eslint_config) => format!(
+                            "Configuration file created from {}\n",
+                            eslint_config
+                                .strip_prefix(&self.cwd)
+                                .unwrap_or(eslint_config)
+                                .display()
+                        ),
+                        None => "Configuration file created\n".to_string(),
+                    };
+                    print_and_flush_stdout(stdout, &message);
                     return CliRunResult::ConfigFileInitSucceeded;
                 }
 
@@ -286,7 +543,23 @@ impl CliRunner {
         // the same functionality.
         let use_cross_module = config_builder.plugins().has_import()
             || nested_configs.values().any(|config| config.plugins().has_import());
-        let mut options = LintServiceOptions::new(self.cwd).with_cross_module(use_cross_module);
+        let baseline_path = self.cwd.join(
+            baseline_options
+                .baseline
+                .as_deref()
+                .unwrap_or_else(|| Path::new(BaselineOptions::DEFAULT_BASELINE)),
+        );
+        let cache_path = self.cwd.join(
+            cache_options
+                .cache_location
+                .as_deref()
+                .unwrap_or_else(|| Path::new(CacheOptions::DEFAULT_CACHE_LOCATION)),
+        );
+        let mut options =
+            LintServiceOptions::new(self.cwd.clone()).with_cross_module(use_cross_module);
+        if let Some(progress) = &progress {
+            options = options.with_linted_files(progress.linted());
+        }
 
         let lint_config = match config_builder.build(&mut external_plugin_store) {
             Ok(config) => config,
@@ -310,6 +583,27 @@ impl CliRunner {
         let (mut diagnostic_service, tx_error) =
             Self::get_diagnostic_service(&output_formatter, &warning_options, &misc_options);
 
+        let baseline = if baseline_options.generate_baseline {
+            Some(LintBaseline::default())
+        } else if baseline_options.baseline.is_some() || baseline_path.is_file() {
+            match LintBaseline::from_file(&baseline_path) {
+                Ok(baseline) => Some(baseline),
+                Err(err) => {
+                    print_and_flush_stdout(
+                        stdout,
+                        &format!(
+                            "Failed to load baseline file.\n{}\n",
+                            render_report(&handler, &err)
+                        ),
+                    );
+                    return CliRunResult::InvalidOptionBaseline;
+                }
+            }
+        } else {
+            None
+        };
+        let baseline = baseline.map(|baseline| Rc::new(RefCell::new(baseline)));
+
         let config_store = ConfigStore::new(lint_config, nested_configs, external_plugin_store);
 
         // If the user requested `--rules`, print a CLI-specific table that
@@ -352,17 +646,70 @@ impl CliRunner {
             }
         }
 
-        let files_to_lint = paths
+        let mut files_to_lint = paths
             .into_iter()
             .filter(|path| !ignore_matcher.should_ignore(Path::new(path)))
             .collect::<Vec<Arc<OsStr>>>();
 
         let has_external_linter = external_linter.is_some();
-        let linter = Linter::new(LintOptions::default(), config_store, external_linter)
-            .with_fix(fix_options.fix_kind())
+        // JSON output includes the fixes and suggestions of diagnostics, unless they are applied.
+        let report_fixes = format_str == OutputFormat::Json
+            && fix_options.fix_kind().is_none()
+            && !baseline_options.suppress_all;
+        let fix_kind = if report_fixes { FixKind::All } else { fix_options.fix_kind() };
+        let mut linter = Linter::new(LintOptions::default(), config_store, external_linter)
+            .with_fix(fix_kind)
+            .with_report_fixes(report_fixes)
+            .with_suppress_all(baseline_options.suppress_all)
             .with_report_unused_directives(report_unused_directives);
+        if let Some(fix_only) = fix_options.fix_only() {
+            linter = linter.with_fix_only(fix_only);
+        }
+        if let Some(max_fix_passes) = fix_options.max_fix_passes {
+            linter = linter.with_max_fix_passes(max_fix_passes);
+        }
+        let rule_timings = misc_options.timing.then(|| Arc::new(RuleTimings::default()));
+        if let Some(rule_timings) = &rule_timings {
+            linter = linter.with_timings(Arc::clone(rule_timings));
+        }
 
         let number_of_files = files_to_lint.len();
+
+        // Results of type-aware rules, the import plugin and JS plugins depend on more than the
+        // contents of the linted file, so they can't be cached per file.
+        let use_cache = cache_options.cache
+            && !self.options.type_aware
+            && !use_cross_module
+            && !has_external_linter
+            && stdin.is_none()
+            && staged.is_none();
+        let cache = use_cache.then(|| {
+            let key = LintCache::key(env!("CARGO_PKG_VERSION"), &linter);
+            let mut cache = LintCache::load(&cache_path, key);
+            cache.retain_changed(options.cwd(), &mut files_to_lint);
+            Rc::new(RefCell::new(cache))
+        });
+        if let Some(progress) = &progress {
+            progress.set_total(files_to_lint.len());
+        }
+
+        // The cache sees diagnostics before the baseline suppresses them, so files with baseline
+        // violations are always linted again.
+        if let Some(cache) = &cache {
+            diagnostic_service =
+                diagnostic_service.with_filter(Box::new(CacheFilter { cache: Rc::clone(cache) }));
+        }
+        if let Some(baseline) = &baseline {
+            diagnostic_service = diagnostic_service.with_filter(Box::new(BaselineFilter {
+                baseline: Rc::clone(baseline),
+                generate: baseline_options.generate_baseline,
+            }));
+        }
+        // Diagnostics suppressed by the baseline don't count towards budgets.
+        if !budgets.borrow().is_empty() {
+            diagnostic_service = diagnostic_service
+                .with_filter(Box::new(BudgetFilter { budgets: Rc::clone(&budgets) }));
+        }
         let tsconfig = basic_options.tsconfig;
         if let Some(path) = tsconfig.as_ref() {
             if path.is_file() {
@@ -384,13 +731,37 @@ impl CliRunner {
 
         let number_of_rules = linter.number_of_rules(self.options.type_aware);
 
+        // Type-aware rules are skipped without `--type-aware`, which is easy to miss for rules
+        // that are turned on by name. Printed to stderr so it doesn't mix with the report.
+        if !self.options.type_aware && !misc_options.silent {
+            let type_aware_rules = linter
+                .configured_type_aware_rule_names()
+                .into_iter()
+                .filter(|name| {
+                    name.rsplit_once('/')
+                        .is_some_and(|(_, rule_name)| enabled_rule_names.contains(rule_name))
+                })
+                .collect::<Vec<_>>();
+            if !type_aware_rules.is_empty() {
+                let _ = writeln!(
+                    io::stderr(),
+                    "Type-aware rules are skipped without `--type-aware`: {}",
+                    type_aware_rules.join(", ")
+                );
+            }
+        }
+
         // Create the LintRunner
-        // TODO: Add a warning message if `tsgolint` cannot be found, but type-aware rules are enabled
         let lint_runner = match LintRunner::builder(options, linter)
             .with_type_aware(self.options.type_aware)
             .with_type_check(self.options.type_check)
             .with_silent(misc_options.silent)
-            .with_fix_kind(fix_options.fix_kind())
+            // With `--suppress-all`, neither type-aware fixes nor unused directive fixes are applied.
+            .with_fix_kind(if baseline_options.suppress_all {
+                FixKind::None
+            } else {
+                fix_options.fix_kind()
+            })
             .build()
         {
             Ok(runner) => runner,
@@ -403,7 +774,24 @@ impl CliRunner {
         // Configure the file system for external linter if needed.
         // When using the copy-to-fixed-allocator approach (cross-module + JS plugins),
         // we use `OsFileSystem` instead of `RawTransferFileSystem`, because we use standard allocators for parsing.
-        let file_system = if has_external_linter {
+        let file_system = if let Some(stdin) = &stdin {
+            // JS plugins may need source text at the start of a fixed-size allocator,
+            // which `StdinFileSystem` doesn't do.
+            if has_external_linter {
+                print_and_flush_stdout(
+                    stdout,
+                    "`--stdin-filename` cannot be combined with JS plugins\n",
+                );
+                return CliRunResult::InvalidOptionStdin;
+            }
+            Some(stdin as &(dyn oxc_linter::RuntimeFileSystem + Sync + Send))
+        } else if let Some(staged) = &staged {
+            if has_external_linter {
+                print_and_flush_stdout(stdout, "`--staged` cannot be combined with JS plugins\n");
+                return CliRunResult::InvalidOptionStaged;
+            }
+            Some(staged as &(dyn oxc_linter::RuntimeFileSystem + Sync + Send))
+        } else if has_external_linter {
             #[cfg(all(feature = "napi", target_pointer_width = "64", target_endian = "little"))]
             if use_cross_module {
                 // Use standard file system - source text will be copied to fixed-size allocator later
@@ -428,9 +816,28 @@ impl CliRunner {
             None
         };
 
+        // With `--fix-dry-run`, fixed files are kept in memory instead of being written to disk.
+        let dry_run = fix_options
+            .is_dry_run()
+            .then(|| DryRunFileSystem::new(file_system.unwrap_or(&OsFileSystem), self.cwd.clone()));
+        let file_system = dry_run
+            .as_ref()
+            .map(|dry_run| dry_run as &(dyn oxc_linter::RuntimeFileSystem + Sync + Send))
+            .or(file_system);
+
+        let progress_reporter = progress.clone().map(ProgressReporter::start);
+        let tx_error = match &progress {
+            Some(progress) => progress::count_diagnostics(Arc::clone(progress), tx_error),
+            None => tx_error,
+        };
+
         match lint_runner.lint_files(&files_to_lint, tx_error.clone(), file_system) {
             Ok(lint_runner) => {
-                lint_runner.report_unused_directives(report_unused_directives, &tx_error);
+                lint_runner.report_unused_directives(
+                    report_unused_directives,
+                    file_system,
+                    &tx_error,
+                );
             }
             Err(err) => {
                 print_and_flush_stdout(stdout, &err);
@@ -439,24 +846,101 @@ impl CliRunner {
         }
 
         drop(tx_error);
+        drop(progress_reporter);
+
+        if let Some(dry_run) = &dry_run {
+            let diff = dry_run.diff();
+            if let Some(fix_patch) = &fix_options.fix_patch {
+                if fs::write(self.cwd.join(fix_patch), diff).is_err() {
+                    print_and_flush_stdout(stdout, "Failed to write patch file\n");
+                    return CliRunResult::FixPatchFileWriteFailed;
+                }
+            } else {
+                print_and_flush_stdout(stdout, &diff);
+            }
+        }
+
+        if let Some(begin) = output_formatter.begin_run() {
+            print_and_flush_stdout(stdout, &begin);
+        }
 
         let diagnostic_result = diagnostic_service.run(stdout);
 
+        if let Some(cache) = cache
+            && cache.take().save(&cache_path).is_err()
+            && !misc_options.silent
+            && format_str == OutputFormat::Default
+        {
+            print_and_flush_stdout(stdout, "Failed to write lint cache\n");
+        }
+
+        if baseline_options.generate_baseline
+            && let Some(baseline) = baseline
+        {
+            let baseline = baseline.borrow();
+            if fs::write(&baseline_path, baseline.to_json()).is_err() {
+                print_and_flush_stdout(stdout, "Failed to write baseline file\n");
+                return CliRunResult::BaselineFileWriteFailed;
+            }
+            if !misc_options.silent && format_str == OutputFormat::Default {
+                print_and_flush_stdout(
+                    stdout,
+                    &format!(
+                        "Baseline with {} violations written to {}\n",
+                        baseline.len(),
+                        baseline_options
+                            .baseline
+                            .as_deref()
+                            .unwrap_or_else(|| Path::new(BaselineOptions::DEFAULT_BASELINE))
+                            .to_string_lossy()
+                            .cow_replace('\\', "/")
+                    ),
+                );
+            }
+        }
+
+        let rule_timings = rule_timings.map(|rule_timings| rule_timings.sorted());
         if let Some(end) = output_formatter.lint_command_info(&LintCommandInfo {
             number_of_files,
             number_of_rules,
             threads_count: rayon::current_num_threads(),
             start_time: now.elapsed(),
+            rule_timings: rule_timings.clone(),
         }) {
             print_and_flush_stdout(stdout, &end);
         }
 
+        // JSON output includes the timings in the JSON object.
+        if let Some(rule_timings) = &rule_timings
+            && format_str != OutputFormat::Json
+        {
+            print_and_flush_stdout(stdout, &render_rule_timings(rule_timings));
+        }
+
+        let budgets = budgets.borrow();
+        let mut exceeded_budgets = budgets.exceeded().peekable();
+        let budgets_exceeded = exceeded_budgets.peek().is_some();
+        if !misc_options.silent && format_str == OutputFormat::Default {
+            for (name, count, budget) in exceeded_budgets {
+                print_and_flush_stdout(
+                    stdout,
+                    &format!(
+                        "Budget of {budget} warning{} for `{name}` exceeded: found {count} warning{}.\n",
+                        if budget == 1 { "" } else { "s" },
+                        if count == 1 { "" } else { "s" }
+                    ),
+                );
+            }
+        }
+
         if diagnostic_result.errors_count() > 0 {
             CliRunResult::LintFoundErrors
         } else if warning_options.deny_warnings && diagnostic_result.warnings_count() > 0 {
             CliRunResult::LintNoWarningsAllowed
         } else if diagnostic_result.max_warnings_exceeded() {
             CliRunResult::LintMaxWarningsExceeded
+        } else if budgets_exceeded {
+            CliRunResult::LintBudgetExceeded
         } else {
             CliRunResult::LintSucceeded
         }
@@ -472,6 +956,35 @@ impl CliRunner {
         self
     }
 
+    /// Make `reporters` available as output formats, e.g. `--format=<name>`.
+    #[must_use]
+    pub fn with_reporters(mut self, reporters: ReporterRegistry) -> Self {
+        self.reporters = reporters;
+        self
+    }
+
+    /// Use `source_text` instead of reading stdin for `--stdin-filename`.
+    #[must_use]
+    pub fn with_stdin(mut self, source_text: String) -> Self {
+        self.stdin = Some(source_text);
+        self
+    }
+
+    /// Read configuration files through `config_cache`, instead of parsing them on every run.
+    #[must_use]
+    pub fn with_config_cache(mut self, config_cache: Arc<ConfigFileCache>) -> Self {
+        self.config_cache = Some(config_cache);
+        self
+    }
+
+    /// Set the matcher of the `ignorePatterns` of the configuration files in `output`, so that
+    /// `--watch` ignores the same files as the linter.
+    #[must_use]
+    pub fn with_ignore_matcher_output(mut self, output: Arc<OnceLock<LintIgnoreMatcher>>) -> Self {
+        self.ignore_matcher_output = Some(output);
+        self
+    }
+
     fn get_diagnostic_service(
         reporter: &OutputFormatter,
         warning_options: &WarningOptions,
@@ -487,6 +1000,41 @@ impl CliRunner {
         )
     }
 
+    /// Parses the rules configured with `--rule`.
+    fn get_rules(rule_args: &[String]) -> Result<Vec<(RuleEnum, AllowWarnDeny)>, String> {
+        let mut rules = vec![];
+        for rule_arg in rule_args {
+            match configure_rules(rule_arg) {
+                Ok(configured_rules) => rules.extend(configured_rules),
+                Err(error) => {
+                    return Err(format!("Failed to parse --rule `{rule_arg}`: {error}\n"));
+                }
+            }
+        }
+        Ok(rules)
+    }
+
+    /// The names of the rules turned on by name with `-W`/`-D` or `--rule`.
+    fn enabled_rule_names(
+        filters: &[LintFilter],
+        rules: &[(RuleEnum, AllowWarnDeny)],
+    ) -> FxHashSet<String> {
+        let filter_names = filters
+            .iter()
+            .filter(|filter| filter.severity().is_warn_deny())
+            .filter_map(|filter| match filter.kind() {
+                LintFilterKind::Generic(rule_name) | LintFilterKind::Rule(_, rule_name) => {
+                    Some(rule_name.to_string())
+                }
+                LintFilterKind::All | LintFilterKind::Category(_) => None,
+            });
+        let rule_names = rules
+            .iter()
+            .filter(|(_, severity)| severity.is_warn_deny())
+            .map(|(rule, _)| rule.name().to_string());
+        filter_names.chain(rule_names).collect()
+    }
+
     // moved into a separate function for readability, but it's only ever used
     // in one place.
     fn get_filters(
@@ -531,10 +1079,13 @@ impl CliRunner {
         stdout: &mut dyn Write,
         handler: &GraphicalReportHandler,
         filters: &Vec<LintFilter>,
+        rules: &[(RuleEnum, AllowWarnDeny)],
         paths: &Vec<Arc<OsStr>>,
         external_linter: Option<&ExternalLinter>,
         external_plugin_store: &mut ExternalPluginStore,
         nested_ignore_patterns: &mut Vec<(Vec<String>, PathBuf)>,
+        enabled_rule_names: &mut FxHashSet<String>,
+        config_cache: Option<&ConfigFileCache>,
     ) -> Result<FxHashMap<PathBuf, Config>, CliRunResult> {
         // TODO(perf): benchmark whether or not it is worth it to store the configurations on a
         // per-file or per-directory basis, to avoid calling `.parent()` on every path.
@@ -561,7 +1112,7 @@ impl CliRunner {
         }
         for directory in directories {
             #[expect(clippy::match_same_arms)]
-            match Self::find_oxlint_config_in_directory(directory) {
+            match Self::find_oxlint_config_in_directory(directory, config_cache) {
                 Ok(Some(v)) => {
                     nested_oxlintrc.insert(directory, v);
                 }
@@ -599,6 +1150,10 @@ impl CliRunner {
                 }
             }
             .with_filters(filters);
+            enabled_rule_names.extend(builder.enabled_rule_names.iter().cloned());
+            let builder = rules.iter().fold(builder, |builder, (rule, severity)| {
+                builder.with_rule(rule.clone(), *severity)
+            });
 
             let config = match builder.build(external_plugin_store) {
                 Ok(config) => config,
@@ -623,27 +1178,110 @@ impl CliRunner {
     // when config is provided, but not found, an String with the formatted error is returned, else the oxlintrc config file is returned
     // when no config is provided, it will search for the default file names in the current working directory
     // when no file is found, the default configuration is returned
-    fn find_oxlint_config(cwd: &Path, config: Option<&PathBuf>) -> Result<Oxlintrc, OxcDiagnostic> {
+    fn find_oxlint_config(
+        cwd: &Path,
+        config: Option<&PathBuf>,
+        config_cache: Option<&ConfigFileCache>,
+    ) -> Result<Oxlintrc, OxcDiagnostic> {
         let path: &Path = config.map_or(Self::DEFAULT_OXLINTRC.as_ref(), PathBuf::as_ref);
         let full_path = cwd.join(path);
 
         if config.is_some() || full_path.exists() {
-            return Oxlintrc::from_file(&full_path);
+            return Self::read_oxlint_config(&full_path, config_cache);
         }
         Ok(Oxlintrc::default())
     }
 
+    /// Renders a diagnostic of an oxlint config file, with the source of the file it is labeled in.
+    fn render_config_report(
+        handler: &GraphicalReportHandler,
+        cwd: &Path,
+        path: &Path,
+        diagnostic: OxcDiagnostic,
+    ) -> String {
+        let report = match fs::read_to_string(cwd.join(path)) {
+            Ok(source_text) if diagnostic.labels.is_some() => {
+                diagnostic.with_source_code(NamedSource::new(path.to_string_lossy(), source_text))
+            }
+            _ => Error::new(diagnostic),
+        };
+        let mut rendered = String::new();
+        handler.render_report(&mut rendered, report.as_ref()).unwrap();
+        rendered
+    }
+
+    /// Looks in a directory for an ESLint config, flat configs first.
+    fn find_eslint_config(dir: &Path) -> Option<PathBuf> {
+        EslintFlatConfig::FILE_NAMES
+            .iter()
+            .chain(&EslintrcConfig::FILE_NAMES)
+            .map(|name| dir.join(name))
+            .find(|path| path.is_file())
+    }
+
+    /// Converts an ESLint flat config (`eslint.config.js`) or legacy config (`.eslintrc.json`)
+    /// into an oxlint config.
+    /// Entries which have no oxlint equivalent are printed as warnings if `report_unmapped` is `true`.
+    fn load_eslint_config(
+        stdout: &mut dyn Write,
+        handler: &GraphicalReportHandler,
+        path: &Path,
+        report_unmapped: bool,
+    ) -> Result<Oxlintrc, OxcDiagnostic> {
+        let (oxlintrc, unmapped, source_text) = if EslintFlatConfig::is_flat_config_path(path) {
+            let EslintFlatConfig { oxlintrc, unmapped, source_text } =
+                EslintFlatConfig::from_file(path)?;
+            (oxlintrc, unmapped, Some(source_text))
+        } else {
+            let EslintrcConfig { oxlintrc, unmapped } = EslintrcConfig::from_file(path)?;
+            (oxlintrc, unmapped, None)
+        };
+
+        if report_unmapped && !unmapped.is_empty() {
+            let source = source_text
+                .map(|source_text| Arc::new(NamedSource::new(path.to_string_lossy(), source_text)));
+            let entries = if unmapped.len() == 1 { "entry" } else { "entries" };
+            let mut message = format!(
+                "{} {entries} in the ESLint config could not be mapped to oxlint and will be ignored.\n",
+                unmapped.len()
+            );
+            for diagnostic in unmapped {
+                let report = match &source {
+                    Some(source) => diagnostic.with_source_code(Arc::clone(source)),
+                    None => Error::new(diagnostic),
+                };
+                handler.render_report(&mut message, report.as_ref()).unwrap();
+            }
+            print_and_flush_stdout(stdout, &message);
+        }
+
+        Ok(oxlintrc)
+    }
+
     /// Looks in a directory for an oxlint config file, returns the oxlint config if it exists
     /// and returns `Err` if none exists or the file is invalid. Does not apply the default
     /// config file.
-    fn find_oxlint_config_in_directory(dir: &Path) -> Result<Option<Oxlintrc>, OxcDiagnostic> {
+    fn find_oxlint_config_in_directory(
+        dir: &Path,
+        config_cache: Option<&ConfigFileCache>,
+    ) -> Result<Option<Oxlintrc>, OxcDiagnostic> {
         let possible_config_path = dir.join(Self::DEFAULT_OXLINTRC);
         if possible_config_path.is_file() {
-            Oxlintrc::from_file(&possible_config_path).map(Some)
+            Self::read_oxlint_config(&possible_config_path, config_cache).map(Some)
         } else {
             Ok(None)
         }
     }
+
+    fn read_oxlint_config(
+        path: &Path,
+        config_cache: Option<&ConfigFileCache>,
+    ) -> Result<Oxlintrc, OxcDiagnostic> {
+        match config_cache {
+            Some(config_cache) => config_cache.load(path),
+            None => Oxlintrc::from_file(path),
+        }
+    }
 }
 
 pub fn print_and_flush_stdout(stdout: &mut dyn Write, message: &str) {
@@ -660,6 +1298,44 @@ fn check_for_writer_error(error: std::io::Error) -> Result<(), std::io::Error> {
     }
 }
 
+/// Marks files that reported diagnostics, so they aren't cached.
+struct CacheFilter {
+    cache: Rc<RefCell<LintCache>>,
+}
+
+impl DiagnosticFilter for CacheFilter {
+    fn is_suppressed(&mut self, error: &Error) -> bool {
+        self.cache.borrow_mut().record_diagnostic(error);
+        false
+    }
+}
+
+/// Records diagnostics into a [`LintBaseline`] (`--generate-baseline`),
+/// or suppresses diagnostics already present in it.
+struct BaselineFilter {
+    baseline: Rc<RefCell<LintBaseline>>,
+    generate: bool,
+}
+
+impl DiagnosticFilter for BaselineFilter {
+    fn is_suppressed(&mut self, error: &Error) -> bool {
+        let mut baseline = self.baseline.borrow_mut();
+        if self.generate { baseline.record(error) } else { baseline.suppress(error) }
+    }
+}
+
+/// Counts warnings towards the [`LintBudgets`] from the configuration file.
+struct BudgetFilter {
+    budgets: Rc<RefCell<LintBudgets>>,
+}
+
+impl DiagnosticFilter for BudgetFilter {
+    fn is_suppressed(&mut self, error: &Error) -> bool {
+        self.budgets.borrow_mut().record(error);
+        false
+    }
+}
+
 fn render_report(handler: &GraphicalReportHandler, diagnostic: &OxcDiagnostic) -> String {
     let mut err = String::new();
     handler.render_report(&mut err, diagnostic).unwrap();
@@ -668,10 +1344,14 @@ fn render_report(handler: &GraphicalReportHandler, diagnostic: &OxcDiagnostic) -
 
 #[cfg(test)]
 mod test {
-    use std::{fs, path::PathBuf};
+    use std::{
+        fs,
+        path::PathBuf,
+        process::{ExitCode, Termination},
+    };
 
     use super::CliRunner;
-    use crate::tester::Tester;
+    use crate::{result::CliRunResult, tester::Tester};
 
     // lints the full directory of fixtures,
     // so do not snapshot it, test only
@@ -1053,6 +1733,95 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_fix_embedded_crlf() {
+        let dir = tempfile::tempdir().unwrap();
+        let files = [
+            (
+                "App.vue",
+                "<template>\r\n  <div />\r\n</template>\r\n<script>\r\ndebugger;\r\nconst a = [...[...[...b]]];\r\n</script>\r\n<script setup lang=\"ts\">\r\nconst c = Boolean(Boolean(d));\r\n</script>\r\n",
+                "<template>\r\n  <div />\r\n</template>\r\n<script>\r\n\r\nconst a = [...b];\r\n</script>\r\n<script setup lang=\"ts\">\r\nconst c = Boolean(d);\r\n</script>\r\n",
+            ),
+            (
+                "App.svelte",
+                "<script>\r\n  debugger;\r\n</script>\r\n<div>{a}</div>\r\n",
+                "<script>\r\n  \r\n</script>\r\n<div>{a}</div>\r\n",
+            ),
+            (
+                "App.astro",
+                "---\r\nconst a = [...[...b]];\r\n---\r\n<div />\r\n<script>debugger;</script>\r\n",
+                "---\r\nconst a = [...b];\r\n---\r\n<div />\r\n<script></script>\r\n",
+            ),
+            (
+                "App.mpx",
+                "<script>\r\nconst a = Boolean(Boolean(b));\r\n</script>\r\n",
+                "<script>\r\nconst a = Boolean(b);\r\n</script>\r\n",
+            ),
+        ];
+        for (name, before, _) in files {
+            fs::write(dir.path().join(name), before).unwrap();
+        }
+
+        let args = &["-D", "unicorn/no-useless-spread", "-D", "no-extra-boolean-cast", "--fix"];
+        Tester::new().with_cwd(dir.path().to_path_buf()).test(args);
+        for (name, _, after) in files {
+            assert_eq!(fs::read_to_string(dir.path().join(name)).unwrap(), after, "{name}");
+        }
+
+        // Disable comments use the line breaks of the file.
+        let source =
+            "<div />\r\n<script>debugger;</script>\r\n<script>\r\n  debugger;\r\n</script>\r\n";
+        fs::write(dir.path().join("App.vue"), source).unwrap();
+        Tester::new().with_cwd(dir.path().to_path_buf()).test(&["--suppress-all", "App.vue"]);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("App.vue")).unwrap(),
+            "<div />\r\n<script>\r\n// oxlint-disable-next-line no-debugger -- TODO\r\ndebugger;</script>\r\n<script>\r\n  // oxlint-disable-next-line no-debugger -- TODO\r\n  debugger;\r\n</script>\r\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_passes() {
+        // Nested fixes conflict, so each pass only applies the outermost one.
+        let args_1 = &["--fix-dry-run", "fix.js"];
+        let args_2 = &["--fix-dry-run", "--max-fix-passes=1", "fix.js"];
+        Tester::new()
+            .with_cwd("fixtures/fix_passes".into())
+            .test_and_snapshot_multiple(&[args_1, args_2]);
+    }
+
+    #[test]
+    fn test_report_unused_directives_fix() {
+        let args = &[
+            "-c",
+            ".oxlintrc.json",
+            "--report-unused-disable-directives",
+            "--fix-dry-run",
+            "test.js",
+            "test.vue",
+        ];
+        Tester::new().with_cwd("fixtures/report_unused_directives".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_fix_only() {
+        let args_1 = &["--fix-dry-run", "--fix-only=no-extra-boolean-cast", "fix.js"];
+        let args_2 = &["--fix-dry-run", "--fix-only=unicorn/no-useless-spread", "fix.js"];
+        Tester::new()
+            .with_cwd("fixtures/fix_passes".into())
+            .test_and_snapshot_multiple(&[args_1, args_2]);
+    }
+
+    #[test]
+    fn test_fix_dry_run() {
+        let args = &["--fix-dry-run", "fix.js"];
+        Tester::new().with_cwd("fixtures/fix_dry_run".into()).test_and_snapshot(args);
+
+        #[expect(clippy::disallowed_methods)]
+        let content =
+            fs::read_to_string("fixtures/fix_dry_run/fix.js").unwrap().replace("\r\n", "\n");
+        assert_eq!(content, "foo();\ndebugger\nbar();\n");
+    }
+
     #[test]
     fn test_print_config_ban_all_rules() {
         let args = &["-A", "all", "--print-config"];
@@ -1075,14 +1844,30 @@ mod test {
 
     #[test]
     fn test_init_config() {
-        assert!(!fs::exists(CliRunner::DEFAULT_OXLINTRC).unwrap());
+        let dir = tempfile::tempdir().unwrap();
+
+        let output = Tester::new().with_cwd(dir.path().to_path_buf()).test_output(&["--init"]);
+        assert_eq!(output, "Configuration file created\n");
 
-        let args = &["--init"];
-        Tester::new().with_cwd("fixtures".into()).test(args);
+        assert!(fs::exists(dir.path().join(CliRunner::DEFAULT_OXLINTRC)).unwrap());
+    }
 
-        assert!(fs::exists(CliRunner::DEFAULT_OXLINTRC).unwrap());
+    #[test]
+    fn test_init_config_from_eslintrc() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join(".eslintrc.json"),
+            r#"{ "extends": "eslint:recommended", "rules": { "eqeqeq": "error", "unknown": "warn" } }"#,
+        )
+        .unwrap();
 
-        fs::remove_file(CliRunner::DEFAULT_OXLINTRC).unwrap();
+        let output = Tester::new().with_cwd(dir.path().to_path_buf()).test_output(&["--init"]);
+        assert_eq!(output, "Configuration file created from .eslintrc.json\n");
+
+        let config = fs::read_to_string(dir.path().join(CliRunner::DEFAULT_OXLINTRC)).unwrap();
+        let config: serde_json::Value = serde_json::from_str(&config).unwrap();
+        assert_eq!(config["rules"]["eqeqeq"], "deny");
+        assert!(config["rules"].get("unknown").is_none());
     }
 
     #[test]
@@ -1193,6 +1978,14 @@ mod test {
         Tester::new().with_cwd("fixtures/disable_vitest_rules".into()).test_and_snapshot(args);
     }
 
+    #[test]
+    // Vitest rules are skipped for the files of packages which only depend on Jest,
+    // Jest rules run for the test files of every package.
+    fn test_vitest_project_detection() {
+        let args = &["-c", ".oxlintrc.json", "jest_project", "vitest_project"];
+        Tester::new().with_cwd("fixtures/vitest_project_detection".into()).test_and_snapshot(args);
+    }
+
     #[test]
     fn test_two_rules_with_same_rule_name_from_different_plugins() {
         // Issue: <https://github.com/oxc-project/oxc/issues/8485>
@@ -1281,23 +2074,289 @@ mod test {
         Tester::new().with_cwd("fixtures".into()).test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_eslint_flat_config() {
+        let args = &["-c", "eslint.config.mjs"];
+        Tester::new().with_cwd("fixtures/eslint_flat_config".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_baseline() {
+        let args = &["--baseline", "baseline.json", "test.js"];
+        Tester::new().with_cwd("fixtures/baseline".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_rule_option() {
+        let args_1 = &[
+            "-A",
+            "all",
+            "--rule",
+            "no-debugger: error",
+            "--rule",
+            "eqeqeq: [warn, smart]",
+            "test.js",
+        ];
+        let args_2 = &["--rule", "no-debugger: off, eqeqeq: warn", "test.js"];
+        let args_3 = &["--rule", "no-such-rule: error", "test.js"];
+        Tester::new()
+            .with_cwd("fixtures/rule_option".into())
+            .test_and_snapshot_multiple(&[args_1, args_2, args_3]);
+    }
+
+    #[test]
+    fn test_budgets() {
+        let args_1 = &["test.js"];
+        let args_2 = &["-c", ".oxlintrc-within-budget.json", "test.js"];
+        Tester::new()
+            .with_cwd("fixtures/budgets".into())
+            .test_and_snapshot_multiple(&[args_1, args_2]);
+    }
+
+    #[test]
+    fn test_inline_config() {
+        let args = &["test.js"];
+        Tester::new().with_cwd("fixtures/inline_config".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_no_unused_modules() {
+        let args_1 = &[];
+        let args_2 = &["-c", ".oxlintrc-modules-only.json"];
+        Tester::new()
+            .with_cwd("fixtures/no_unused_modules".into())
+            .test_and_snapshot_multiple(&[args_1, args_2]);
+    }
+
+    #[test]
+    fn test_no_unused_modules_types() {
+        let args = &[];
+        Tester::new().with_cwd("fixtures/no_unused_modules_types".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_suppress_all() {
+        let args = &["--suppress-all", "--fix-dry-run", "test.jsx", "test.vue"];
+        Tester::new().with_cwd("fixtures/suppress_all".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_generate_baseline() {
+        let dir = tempfile::tempdir().unwrap();
+        let baseline = dir.path().join("generated.json");
+
+        let args = &["--generate-baseline", "--baseline", baseline.to_str().unwrap(), "test.js"];
+        Tester::new().with_cwd("fixtures/baseline".into()).test(args);
+
+        let generated = fs::read_to_string(baseline).unwrap();
+        // Both `debugger` statements share a fingerprint.
+        assert!(generated.contains(r#""count": 2"#));
+        assert!(generated.contains(r#""rule": "eslint(no-unused-vars)""#));
+    }
+
+    #[test]
+    fn test_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = dir.path().join(".oxlintcache");
+        let args = ["--cache", "--cache-location", cache.to_str().unwrap()];
+        let run = || {
+            let options = crate::cli::lint_command().run_inner(args.as_slice()).unwrap();
+            let mut output = Vec::new();
+            CliRunner::new(options, None)
+                .with_cwd(std::env::current_dir().unwrap().join("fixtures/cache"))
+                .run(&mut output);
+            String::from_utf8(output).unwrap()
+        };
+
+        let uncached = run();
+        let contents = fs::read_to_string(&cache).unwrap();
+        // Files with diagnostics are not cached.
+        assert!(contents.contains("clean.js"));
+        assert!(!contents.contains("dirty.js"));
+
+        // Cached runs report the same diagnostics.
+        let cached = run();
+        for output in [&uncached, &cached] {
+            assert!(output.contains("[dirty.js:1:1]"), "{output}");
+            assert!(output.contains("Found 1 warning and 0 errors."), "{output}");
+        }
+    }
+
+    #[test]
+    fn test_stdin() {
+        let source_text = "<script>\nif (a == b) {\n  debugger;\n}\n</script>\n";
+        let args_1 = &["--stdin-filename", "App.mpx"];
+        // Nested configs apply based on the given path.
+        let args_2 = &["--stdin-filename", "nested/App.mpx"];
+        Tester::new()
+            .with_cwd("fixtures/stdin".into())
+            .with_stdin(source_text)
+            .test_and_snapshot_multiple(&[args_1, args_2]);
+    }
+
+    #[test]
+    fn test_stdin_invalid_options() {
+        let args_1 = &["--stdin-filename", "test.js", "--fix"];
+        let args_2 = &["--stdin-filename", "test.js", "test.js"];
+        Tester::new()
+            .with_cwd("fixtures/stdin".into())
+            .with_stdin("debugger;")
+            .test_and_snapshot_multiple(&[args_1, args_2]);
+    }
+
+    #[test]
+    fn test_print_schema() {
+        let output = Tester::new().test_output(&["--print-schema"]);
+        let schema: serde_json::Value = serde_json::from_str(&output).unwrap();
+        let rules = &schema["definitions"]["DummyRuleMap"]["properties"];
+        assert!(rules["eqeqeq"]["anyOf"].is_array());
+        assert!(rules["typescript/no-explicit-any"].is_object());
+    }
+
+    #[test]
+    #[cfg(feature = "ruledocs")]
+    fn test_explain() {
+        let output = Tester::new().test_output(&["--explain", "eslint/no-debugger"]);
+        assert!(output.starts_with("# eslint/no-debugger\n\nCategory: correctness\n"));
+        assert!(output.contains("### What it does"));
+
+        let output = Tester::new().test_output(&[
+            "--explain",
+            "@typescript-eslint/array-type",
+            "-f",
+            "json",
+        ]);
+        let docs: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(docs["plugin"], "typescript");
+        assert!(docs["options"].is_object());
+
+        let output = Tester::new().test_output(&["--explain", "no-such-rule"]);
+        assert_eq!(output, "Rule `no-such-rule` not found\n");
+
+        let options = crate::cli::lint_command().run_inner(&["--explain", "no-such-rule"]).unwrap();
+        let result = CliRunner::new(options, None).run(&mut Vec::new());
+        assert!(matches!(result, CliRunResult::ExplainRuleNotFound));
+        assert_eq!(result.report(), ExitCode::FAILURE);
+    }
+
+    #[test]
+    #[cfg(not(feature = "ruledocs"))]
+    fn test_explain_without_ruledocs() {
+        let options =
+            crate::cli::lint_command().run_inner(&["--explain", "eslint/no-debugger"]).unwrap();
+        let result = CliRunner::new(options, None).run(&mut Vec::new());
+        assert!(matches!(result, CliRunResult::ExplainRuleNotFound));
+        assert_eq!(result.report(), ExitCode::FAILURE);
+    }
+
+    #[test]
+    fn test_validate_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let tester = Tester::new().with_cwd(dir.path().to_path_buf());
+
+        fs::write(dir.path().join(".oxlintrc.json"), "{\n  \"rulez\": {}\n}\n").unwrap();
+        let output = tester.test_output(&[]);
+        assert!(output.starts_with("Failed to parse oxlint configuration file."));
+        assert!(output.contains("Unknown key `rulez`"));
+        assert!(output.contains("[.oxlintrc.json:2:3]"));
+        assert!(output.contains("Did you mean `rules`?"));
+
+        // Unknown rules are reported without failing, unless the output is silenced.
+        fs::write(dir.path().join(".oxlintrc.json"), r#"{ "rules": { "no-debuger": "error" } }"#)
+            .unwrap();
+        fs::write(dir.path().join("index.js"), "debugger;\n").unwrap();
+        let silent = tester.test_output(&[]);
+        assert!(!silent.contains("no-debuger"), "{silent}");
+
+        let options = crate::cli::lint_command().run_inner(&[] as &[&str]).unwrap();
+        let mut output = Vec::new();
+        CliRunner::new(options, None).with_cwd(dir.path().to_path_buf()).run(&mut output);
+        let output = String::from_utf8(output).unwrap();
+        assert!(
+            output.starts_with(
+                "1 entry in the oxlint config is not recognized and will be ignored.\n"
+            )
+        );
+        assert!(output.contains("Unknown rule `no-debuger`"));
+        assert!(output.contains("Did you mean `no-debugger`?"));
+
+        fs::write(
+            dir.path().join(".oxlintrc.json"),
+            r#"{ "rules": { "no-debuger": "error", "no-consol": "error" } }"#,
+        )
+        .unwrap();
+        let options = crate::cli::lint_command().run_inner(&[] as &[&str]).unwrap();
+        let mut output = Vec::new();
+        CliRunner::new(options, None).with_cwd(dir.path().to_path_buf()).run(&mut output);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.starts_with(
+            "2 entries in the oxlint config are not recognized and will be ignored.\n"
+        ));
+    }
+
+    #[test]
+    fn test_staged() {
+        use std::process::Command;
+
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let status = Command::new("git").args(args).current_dir(dir.path()).status().unwrap();
+            assert!(status.success());
+        };
+        git(&["init", "-q"]);
+        fs::write(dir.path().join("staged.js"), "debugger;\n").unwrap();
+        fs::write(dir.path().join("App.vue"), "<script>\ndebugger;\n</script>\n").unwrap();
+        git(&["add", "staged.js", "App.vue"]);
+        // The working tree differs from the index, only the index is linted.
+        fs::write(dir.path().join("staged.js"), "let a = 1;\n").unwrap();
+        fs::write(dir.path().join("unstaged.js"), "debugger;\n").unwrap();
+
+        let output = Tester::new().with_cwd(dir.path().to_path_buf()).test_output(&[
+            "--staged",
+            "-A",
+            "all",
+            "-D",
+            "no-debugger",
+        ]);
+        assert!(output.contains("Found 0 warnings and 2 errors."), "{output}");
+        assert!(output.contains("on 2 files"), "{output}");
+
+        let output = Tester::new().with_cwd(dir.path().to_path_buf()).test_output(&[
+            "--staged",
+            "-A",
+            "all",
+            "-D",
+            "no-debugger",
+            "unstaged.js",
+        ]);
+        assert!(output.contains("on 0 files"), "{output}");
+    }
+
+    #[test]
+    fn test_staged_invalid_options() {
+        for args in [&["--staged", "--fix"], &["--staged", "--changed"]] {
+            let output = Tester::new().test_output(args);
+            assert!(output.starts_with("`--staged` cannot be combined with"), "{output}");
+        }
+    }
+
     #[test]
     fn test_config_path_with_parent_references() {
         let cwd = std::env::current_dir().unwrap();
 
         // Test case 1: Invalid path that should fail
         let invalid_config = PathBuf::from("child/../../fixtures/linter/eslintrc.json");
-        let result = CliRunner::find_oxlint_config(&cwd, Some(&invalid_config));
+        let result = CliRunner::find_oxlint_config(&cwd, Some(&invalid_config), None);
         assert!(result.is_err(), "Expected config lookup to fail with invalid path");
 
         // Test case 2: Valid path that should pass
         let valid_config = PathBuf::from("fixtures/linter/eslintrc.json");
-        let result = CliRunner::find_oxlint_config(&cwd, Some(&valid_config));
+        let result = CliRunner::find_oxlint_config(&cwd, Some(&valid_config), None);
         assert!(result.is_ok(), "Expected config lookup to succeed with valid path");
 
         // Test case 3: Valid path using parent directory (..) syntax that should pass
         let valid_parent_config = PathBuf::from("fixtures/linter/../linter/eslintrc.json");
-        let result = CliRunner::find_oxlint_config(&cwd, Some(&valid_parent_config));
+        let result = CliRunner::find_oxlint_config(&cwd, Some(&valid_parent_config), None);
         assert!(result.is_ok(), "Expected config lookup to succeed with parent directory syntax");
 
         // Verify the resolved path is correct
@@ -1407,6 +2466,12 @@ mod test {
         Tester::new().with_cwd("fixtures/tsgolint".into()).test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_tsgolint_rules_without_type_aware() {
+        let args = &["-c", "config-test.json", "non-tsgolint.ts"];
+        Tester::new().with_cwd("fixtures/tsgolint".into()).test_and_snapshot(args);
+    }
+
     #[test]
     #[cfg(not(target_endian = "big"))]
     fn test_tsgolint_config() {
@@ -1,6 +1,9 @@
 use std::io::BufWriter;
 
-use oxlint::cli::{CliRunResult, CliRunner, init_miette, init_tracing, lint_command, run_lsp};
+use oxlint::cli::{
+    CliRunResult, CliRunner, init_miette, init_tracing, lint_command, run_client, run_daemon,
+    run_lsp, run_watch,
+};
 
 #[tokio::main]
 async fn main() -> CliRunResult {
@@ -13,14 +16,37 @@ async fn main() -> CliRunResult {
         return CliRunResult::LintSucceeded;
     }
 
+    // If --completions is set, print the completion script
+    if let Some(shell) = command.misc_options.completions {
+        shell.print_completions();
+        return CliRunResult::None;
+    }
+
+    // stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
+    // See `https://github.com/rust-lang/rust/issues/60673`.
+    let mut stdout = BufWriter::new(std::io::stdout());
+
+    // If --use-daemon flag is set, send the lint to a running daemon
+    if command.daemon_options.use_daemon
+        && let Some(result) = run_client(&command, &mut stdout)
+    {
+        return result;
+    }
+
     init_tracing();
     init_miette();
 
     command.handle_threads();
 
-    // stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
-    // See `https://github.com/rust-lang/rust/issues/60673`.
-    let mut stdout = BufWriter::new(std::io::stdout());
+    // If --daemon flag is set, serve lint requests until the process is killed
+    if command.daemon_options.daemon {
+        return run_daemon(&command, &mut stdout);
+    }
+
+    // If --watch flag is set, lint again on every change until the process is killed
+    if command.watch {
+        return run_watch(&command, &mut stdout);
+    }
 
     // Run without external linter (no JS plugins)
     CliRunner::new(command, None).run(&mut stdout)
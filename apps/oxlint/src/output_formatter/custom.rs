@@ -0,0 +1,136 @@
+use std::{cell::RefCell, mem, rc::Rc};
+
+use oxc_diagnostics::{
+    Error,
+    reporter::{DiagnosticReporter, DiagnosticResult},
+};
+use oxc_linter::{LintRunSummary, Reporter};
+
+use crate::output_formatter::{InternalFormatter, LintCommandInfo};
+
+/// Output formatter for a [`Reporter`] registered with
+/// [`CliRunner::with_reporters`](crate::cli::CliRunner::with_reporters).
+pub struct CustomOutputFormatter {
+    state: Rc<RefCell<CustomReporterState>>,
+}
+
+struct CustomReporterState {
+    reporter: Box<dyn Reporter>,
+    /// Diagnostics of the file currently being reported.
+    diagnostics: Vec<Error>,
+    result: DiagnosticResult,
+}
+
+impl CustomOutputFormatter {
+    pub fn new(reporter: Box<dyn Reporter>) -> Self {
+        Self {
+            state: Rc::new(RefCell::new(CustomReporterState {
+                reporter,
+                diagnostics: vec![],
+                result: DiagnosticResult::default(),
+            })),
+        }
+    }
+}
+
+impl InternalFormatter for CustomOutputFormatter {
+    fn begin_run(&self) -> Option<String> {
+        self.state.borrow_mut().reporter.begin_run()
+    }
+
+    fn lint_command_info(&self, lint_command_info: &LintCommandInfo) -> Option<String> {
+        let mut state = self.state.borrow_mut();
+        let summary = LintRunSummary {
+            number_of_files: lint_command_info.number_of_files,
+            number_of_rules: lint_command_info.number_of_rules,
+            threads_count: lint_command_info.threads_count,
+            duration: lint_command_info.start_time,
+            warnings_count: state.result.warnings_count(),
+            errors_count: state.result.errors_count(),
+        };
+        state.reporter.end_run(&summary)
+    }
+
+    fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        Box::new(CustomDiagnosticReporter(Rc::clone(&self.state)))
+    }
+}
+
+/// Collects the diagnostics of each file and passes them on to the [`Reporter`].
+struct CustomDiagnosticReporter(Rc<RefCell<CustomReporterState>>);
+
+impl DiagnosticReporter for CustomDiagnosticReporter {
+    fn finish(&mut self, result: &DiagnosticResult) -> Option<String> {
+        self.0.borrow_mut().result = DiagnosticResult::new(
+            result.warnings_count(),
+            result.errors_count(),
+            result.max_warnings_exceeded(),
+        );
+        None
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.0.borrow_mut().diagnostics.push(error);
+        None
+    }
+
+    fn finish_file(&mut self) -> Option<String> {
+        let mut state = self.0.borrow_mut();
+        if state.diagnostics.is_empty() {
+            return None;
+        }
+        let diagnostics = mem::take(&mut state.diagnostics);
+        state.reporter.report_file(diagnostics)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{Error, OxcDiagnostic, reporter::DiagnosticResult};
+    use oxc_linter::{LintRunSummary, Reporter};
+
+    use super::CustomOutputFormatter;
+    use crate::output_formatter::{InternalFormatter, LintCommandInfo};
+
+    #[derive(Default)]
+    struct TestReporter;
+
+    impl Reporter for TestReporter {
+        fn begin_run(&mut self) -> Option<String> {
+            Some("begin\n".to_string())
+        }
+
+        fn report_file(&mut self, diagnostics: Vec<Error>) -> Option<String> {
+            Some(format!("file: {}\n", diagnostics.len()))
+        }
+
+        fn end_run(&mut self, summary: &LintRunSummary) -> Option<String> {
+            Some(format!(
+                "end: {} files, {} warnings, {} errors\n",
+                summary.number_of_files, summary.warnings_count, summary.errors_count
+            ))
+        }
+    }
+
+    #[test]
+    fn reports_files_and_summary() {
+        let formatter = CustomOutputFormatter::new(Box::new(TestReporter));
+        let mut reporter = formatter.get_diagnostic_reporter();
+
+        assert_eq!(formatter.begin_run().as_deref(), Some("begin\n"));
+        assert_eq!(reporter.render_error(Error::new(OxcDiagnostic::warn("a"))), None);
+        assert_eq!(reporter.render_error(Error::new(OxcDiagnostic::error("b"))), None);
+        assert_eq!(reporter.finish_file().as_deref(), Some("file: 2\n"));
+        assert_eq!(reporter.finish_file(), None);
+        assert_eq!(reporter.finish(&DiagnosticResult::new(1, 1, false)), None);
+
+        let end = formatter.lint_command_info(&LintCommandInfo {
+            number_of_files: 3,
+            number_of_rules: None,
+            threads_count: 1,
+            start_time: std::time::Duration::ZERO,
+            rule_timings: None,
+        });
+        assert_eq!(end.as_deref(), Some("end: 3 files, 1 warnings, 1 errors\n"));
+    }
+}
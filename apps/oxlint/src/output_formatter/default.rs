@@ -180,6 +180,7 @@ mod test {
             number_of_rules: Some(10),
             threads_count: 12,
             start_time: Duration::new(1, 0),
+            rule_timings: None,
         });
 
         assert!(result.is_some());
@@ -197,6 +198,7 @@ mod test {
             number_of_rules: None,
             threads_count: 12,
             start_time: Duration::new(1, 0),
+            rule_timings: None,
         });
 
         assert!(result.is_some());
@@ -0,0 +1,174 @@
+use std::fmt::Write;
+
+use oxc_diagnostics::{
+    Error, Severity,
+    reporter::{DiagnosticReporter, DiagnosticResult, Info},
+};
+use rustc_hash::FxHashMap;
+
+use crate::output_formatter::InternalFormatter;
+
+#[derive(Debug, Default)]
+pub struct GroupedOutputFormatter;
+
+impl InternalFormatter for GroupedOutputFormatter {
+    fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        Box::new(GroupedReporter::default())
+    }
+}
+
+/// Reporter to output the diagnostics grouped by rule, with the rules reporting the most
+/// problems first.
+#[derive(Default)]
+struct GroupedReporter {
+    diagnostics: Vec<Error>,
+}
+
+impl DiagnosticReporter for GroupedReporter {
+    fn finish(&mut self, _: &DiagnosticResult) -> Option<String> {
+        if self.diagnostics.is_empty() {
+            return None;
+        }
+        Some(format_grouped(&self.diagnostics))
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+struct RuleGroup {
+    errors: usize,
+    warnings: usize,
+    /// Each diagnostic with its severity label.
+    lines: Vec<(Info, &'static str)>,
+}
+
+fn format_grouped(diagnostics: &[Error]) -> String {
+    let mut groups: FxHashMap<Option<String>, RuleGroup> = FxHashMap::default();
+    for diagnostic in diagnostics {
+        let is_error = diagnostic.severity() == Some(Severity::Error);
+        let info = Info::new(diagnostic);
+        let group = groups.entry(info.rule_id.clone()).or_insert_with(|| RuleGroup {
+            errors: 0,
+            warnings: 0,
+            lines: vec![],
+        });
+        if is_error {
+            group.errors += 1;
+        } else {
+            group.warnings += 1;
+        }
+        group.lines.push((info, if is_error { "error" } else { "warning" }));
+    }
+
+    // Most problems first. Diagnostics which don't belong to a rule come last.
+    let mut groups = groups.into_iter().collect::<Vec<_>>();
+    groups.sort_unstable_by(|(a_rule, a), (b_rule, b)| {
+        a_rule
+            .is_none()
+            .cmp(&b_rule.is_none())
+            .then_with(|| (b.errors + b.warnings).cmp(&(a.errors + a.warnings)))
+            .then_with(|| a_rule.cmp(b_rule))
+    });
+
+    let mut output = String::new();
+    let mut total_errors = 0;
+    let mut total_warnings = 0;
+    for (rule_id, mut group) in groups {
+        total_errors += group.errors;
+        total_warnings += group.warnings;
+
+        let rule_id = rule_id.as_deref().unwrap_or("(no rule)");
+        writeln!(output, "\n{rule_id}: {}", format_counts(group.errors, group.warnings)).unwrap();
+
+        group.lines.sort_unstable_by(|(a, _), (b, _)| {
+            a.filename.cmp(&b.filename).then_with(|| a.start.cmp(&b.start))
+        });
+        let locations = group
+            .lines
+            .iter()
+            .map(|(info, _)| format!("{}:{}:{}", info.filename, info.start.line, info.start.column))
+            .collect::<Vec<_>>();
+        let width = locations.iter().map(String::len).max().unwrap_or(0);
+        for (location, (info, severity)) in locations.iter().zip(&group.lines) {
+            writeln!(output, "  {location:width$}  {severity:7}  {}", info.message).unwrap();
+        }
+    }
+
+    writeln!(output, "\n{}", format_counts(total_errors, total_warnings)).unwrap();
+    output
+}
+
+/// e.g. `3 problems (1 error, 2 warnings)`
+fn format_counts(errors: usize, warnings: usize) -> String {
+    let total = errors + warnings;
+    format!(
+        "{total} problem{} ({errors} error{}, {warnings} warning{})",
+        if total == 1 { "" } else { "s" },
+        if errors == 1 { "" } else { "s" },
+        if warnings == 1 { "" } else { "s" }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{
+        NamedSource, OxcDiagnostic,
+        reporter::{DiagnosticReporter, DiagnosticResult},
+    };
+    use oxc_span::Span;
+
+    use super::GroupedReporter;
+
+    #[test]
+    fn reporter_finish_empty() {
+        let mut reporter = GroupedReporter::default();
+
+        let result = reporter.finish(&DiagnosticResult::default());
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn reporter_groups_by_rule() {
+        let mut reporter = GroupedReporter::default();
+        let source = "debugger;\ndebugger;\n";
+
+        reporter.render_error(
+            OxcDiagnostic::warn("`debugger` statement is not allowed")
+                .with_error_code("eslint", "no-debugger")
+                .with_label(Span::new(10, 19))
+                .with_source_code(NamedSource::new("b.js", source)),
+        );
+        reporter.render_error(
+            OxcDiagnostic::error("Unexpected var")
+                .with_error_code("eslint", "no-var")
+                .with_label(Span::new(0, 8))
+                .with_source_code(NamedSource::new("a.js", source)),
+        );
+        reporter.render_error(
+            OxcDiagnostic::warn("`debugger` statement is not allowed")
+                .with_error_code("eslint", "no-debugger")
+                .with_label(Span::new(0, 9))
+                .with_source_code(NamedSource::new("b.js", source)),
+        );
+
+        let result = reporter.finish(&DiagnosticResult::default()).unwrap();
+
+        assert_eq!(
+            result,
+            "
+eslint(no-debugger): 2 problems (0 errors, 2 warnings)
+  b.js:1:1  warning  `debugger` statement is not allowed
+  b.js:2:1  warning  `debugger` statement is not allowed
+
+eslint(no-var): 1 problem (1 error, 0 warnings)
+  a.js:1:1  error    Unexpected var
+
+3 problems (1 error, 2 warnings)
+"
+        );
+    }
+}
@@ -7,7 +7,7 @@ use oxc_diagnostics::{
     Error,
     reporter::{DiagnosticReporter, DiagnosticResult},
 };
-use oxc_linter::{RuleCategory, rules::RULES};
+use oxc_linter::{DiagnosticWithFixes, RuleCategory, rules::RULES};
 
 use crate::output_formatter::InternalFormatter;
 
@@ -42,13 +42,28 @@ impl InternalFormatter for JsonOutputFormatter {
         let number_of_rules =
             lint_command_info.number_of_rules.map_or("null".to_string(), |x| x.to_string());
         let start_time = lint_command_info.start_time.as_secs_f64();
+        let rule_timings =
+            lint_command_info.rule_timings.as_ref().map_or_else(String::new, |timings| {
+                let timings = timings
+                    .iter()
+                    .map(|timing| RuleTimingJson {
+                        rule: format!("{}/{}", timing.plugin_name, timing.rule_name),
+                        time_ms: timing.duration.as_secs_f64() * 1000.0,
+                        calls: timing.calls,
+                    })
+                    .collect::<Vec<_>>();
+                format!(
+                    ",\n              \"rule_timings\": {}",
+                    serde_json::to_string(&timings).expect("Failed to serialize")
+                )
+            });
 
         Some(format!(
             r#"{{ "diagnostics": {},
               "number_of_files": {},
               "number_of_rules": {},
               "threads_count": {},
-              "start_time": {}
+              "start_time": {}{}
             }}
             "#,
             diagnostics,
@@ -56,6 +71,7 @@ impl InternalFormatter for JsonOutputFormatter {
             number_of_rules,
             lint_command_info.threads_count,
             start_time,
+            rule_timings,
         ))
     }
 
@@ -103,6 +119,30 @@ impl JsonReporter {
     }
 }
 
+/// Time spent in a rule, see `--timing`.
+#[derive(Debug, Serialize)]
+struct RuleTimingJson {
+    /// Rule name, e.g. `eslint/no-debugger`.
+    rule: String,
+    time_ms: f64,
+    calls: usize,
+}
+
+/// A text edit which fixes a diagnostic, or a suggestion for it.
+#[derive(Debug, Serialize)]
+struct FixJson<'a> {
+    message: Option<&'a str>,
+    /// UTF-8 byte offset of the replaced text.
+    offset: u32,
+    /// Length of the replaced text in UTF-8 bytes.
+    length: u32,
+    /// Replacement text.
+    content: &'a str,
+    /// Whether the fix may break the code, and is only applied with `--fix-dangerously`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    dangerous: bool,
+}
+
 /// <https://github.com/fregante/eslint-formatters/tree/ae1fd9748596447d1fd09625c33d9e7ba9a3d06d/packages/eslint-formatter-json>
 fn format_json(diagnostics: &mut Vec<Error>) -> String {
     let handler = JSONReportHandler::new();
@@ -111,6 +151,41 @@ fn format_json(diagnostics: &mut Vec<Error>) -> String {
         .map(|error| {
             let mut output = String::new();
             handler.render_report(&mut output, error.as_ref()).unwrap();
+
+            // Add the fixes and suggestions to the object rendered by `miette`, so tools can
+            // apply them.
+            let diagnostic = error.downcast_ref::<DiagnosticWithFixes>();
+            let fixes = diagnostic.map_or(&[][..], |diagnostic| diagnostic.fixes());
+            let fixes = fixes
+                .iter()
+                .map(|fix| FixJson {
+                    message: fix.message.as_deref(),
+                    offset: fix.span.start,
+                    length: fix.span.size(),
+                    content: &fix.content,
+                    dangerous: fix.kind.is_dangerous(),
+                })
+                .collect::<Vec<_>>();
+            let suggestions = diagnostic.map_or(&[][..], |diagnostic| diagnostic.suggestions());
+            let suggestions = suggestions
+                .iter()
+                .map(|suggestion| FixJson {
+                    message: Some(&suggestion.message),
+                    offset: suggestion.fix.span.start,
+                    length: suggestion.fix.span.size(),
+                    content: &suggestion.fix.content,
+                    dangerous: suggestion.fix.kind.is_dangerous(),
+                })
+                .collect::<Vec<_>>();
+            debug_assert!(output.ends_with('}'));
+            output.pop();
+            output.push_str(",\"fixes\": ");
+            output.push_str(&serde_json::to_string(&fixes).expect("Failed to serialize"));
+            if !suggestions.is_empty() {
+                output.push_str(",\"suggestions\": ");
+                output.push_str(&serde_json::to_string(&suggestions).expect("Failed to serialize"));
+            }
+            output.push('}');
             output
         })
         .collect::<Vec<_>>()
@@ -123,8 +198,10 @@ mod test {
     use std::time::Duration;
 
     use oxc_diagnostics::{NamedSource, OxcDiagnostic, reporter::DiagnosticResult};
+    use oxc_linter::{Fix, FixKind, Message, PossibleFixes, Suggestion};
     use oxc_span::Span;
 
+    use super::format_json;
     use crate::output_formatter::{InternalFormatter, LintCommandInfo, json::JsonOutputFormatter};
 
     #[test]
@@ -151,11 +228,51 @@ mod test {
                 number_of_rules: Some(0),
                 start_time: Duration::new(0, 0),
                 threads_count: 1,
+                rule_timings: None,
             })
             .unwrap();
         assert_eq!(
             &output,
-            "{ \"diagnostics\": [{\"message\": \"error message\",\"severity\": \"warning\",\"causes\": [],\"filename\": \"file://test.ts\",\"labels\": [{\"span\": {\"offset\": 0,\"length\": 8,\"line\": 1,\"column\": 1}}],\"related\": []}],\n              \"number_of_files\": 0,\n              \"number_of_rules\": 0,\n              \"threads_count\": 1,\n              \"start_time\": 0\n            }\n            "
+            "{ \"diagnostics\": [{\"message\": \"error message\",\"severity\": \"warning\",\"causes\": [],\"filename\": \"file://test.ts\",\"labels\": [{\"span\": {\"offset\": 0,\"length\": 8,\"line\": 1,\"column\": 1}}],\"related\": [],\"fixes\": []}],\n              \"number_of_files\": 0,\n              \"number_of_rules\": 0,\n              \"threads_count\": 1,\n              \"start_time\": 0\n            }\n            "
         );
     }
+
+    #[test]
+    fn suggestions() {
+        let message = Message::new(
+            OxcDiagnostic::warn("Unexpected var").with_label(Span::new(0, 3)),
+            PossibleFixes::None,
+        )
+        .with_suggestions(vec![Suggestion::new(
+            "Replace with `let`",
+            Fix::new("let", Span::new(0, 3)),
+        )]);
+        let mut diagnostics =
+            Message::wrap_messages("/cwd", "/cwd/test.js", "var a;", vec![message]);
+
+        let output = format_json(&mut diagnostics);
+
+        assert!(output.ends_with(
+            "\"fixes\": [],\"suggestions\": [{\"message\":\"Replace with `let`\",\"offset\":0,\"length\":3,\"content\":\"let\"}]}]"
+        ));
+    }
+
+    #[test]
+    fn dangerous_fixes() {
+        let message = Message::new(
+            OxcDiagnostic::warn("Unexpected var").with_label(Span::new(0, 3)),
+            PossibleFixes::Multiple(vec![
+                Fix::new("let", Span::new(0, 3)),
+                Fix::delete(Span::new(0, 6)).with_kind(FixKind::DangerousFix),
+            ]),
+        );
+        let mut diagnostics =
+            Message::wrap_messages("/cwd", "/cwd/test.js", "var a;", vec![message]);
+
+        let output = format_json(&mut diagnostics);
+
+        assert!(output.ends_with(
+            "\"fixes\": [{\"message\":null,\"offset\":0,\"length\":3,\"content\":\"let\"},{\"message\":null,\"offset\":0,\"length\":6,\"content\":\"\",\"dangerous\":true}]}]"
+        ));
+    }
 }
@@ -1,7 +1,9 @@
 mod checkstyle;
+mod custom;
 mod default;
 mod github;
 mod gitlab;
+mod grouped;
 mod json;
 mod junit;
 mod stylish;
@@ -12,17 +14,20 @@ use std::str::FromStr;
 use std::time::Duration;
 
 use checkstyle::CheckStyleOutputFormatter;
+use custom::CustomOutputFormatter;
 use github::GithubOutputFormatter;
 use gitlab::GitlabOutputFormatter;
+use grouped::GroupedOutputFormatter;
 use junit::JUnitOutputFormatter;
 use stylish::StylishOutputFormatter;
 use unix::UnixOutputFormatter;
 
 use oxc_diagnostics::reporter::DiagnosticReporter;
+use oxc_linter::{ReporterRegistry, RuleTiming};
 
 use crate::output_formatter::{default::DefaultOutputFormatter, json::JsonOutputFormatter};
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub enum OutputFormat {
     Default,
     /// GitHub Check Annotation
@@ -34,6 +39,11 @@ pub enum OutputFormat {
     Checkstyle,
     Stylish,
     JUnit,
+    /// Diagnostics grouped by rule, rules with the most problems first.
+    Grouped,
+    /// A [`Reporter`](oxc_linter::Reporter) registered with
+    /// [`CliRunner::with_reporters`](crate::cli::CliRunner::with_reporters).
+    Custom(String),
 }
 
 impl FromStr for OutputFormat {
@@ -49,7 +59,9 @@ impl FromStr for OutputFormat {
             "gitlab" => Ok(Self::Gitlab),
             "stylish" => Ok(Self::Stylish),
             "junit" => Ok(Self::JUnit),
-            _ => Err(format!("'{s}' is not a known format")),
+            "grouped" => Ok(Self::Grouped),
+            // Custom formats are only known once the CLI runs, see [`OutputFormatter::new`].
+            _ => Ok(Self::Custom(s.to_string())),
         }
     }
 }
@@ -66,11 +78,18 @@ pub struct LintCommandInfo {
     pub threads_count: usize,
     /// Some reporters want to output the duration it took to finished the task
     pub start_time: Duration,
+    /// Time spent in each rule, slowest first, if requested with `--timing`.
+    pub rule_timings: Option<Vec<RuleTiming>>,
 }
 
 /// An Interface for the different output formats.
 /// The Formatter is then managed by [`OutputFormatter`].
 trait InternalFormatter {
+    /// At the start of the Lint command the Formatter can output extra information.
+    fn begin_run(&self) -> Option<String> {
+        None
+    }
+
     /// Print all available rules by oxlint
     fn all_rules(&self) -> Option<String> {
         None
@@ -86,17 +105,73 @@ trait InternalFormatter {
     fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter>;
 }
 
+/// Renders the time spent in each rule as a Markdown table, in the order of `timings`.
+pub fn render_rule_timings(timings: &[RuleTiming]) -> String {
+    let total = timings.iter().map(|timing| timing.duration.as_secs_f64()).sum::<f64>();
+    let rows = timings
+        .iter()
+        .map(|timing| {
+            let relative =
+                if total > 0.0 { timing.duration.as_secs_f64() / total * 100.0 } else { 0.0 };
+            [
+                format!("{}/{}", timing.plugin_name, timing.rule_name),
+                format!("{:.3}", timing.duration.as_secs_f64() * 1000.0),
+                format!("{relative:.1}%"),
+                timing.calls.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let header = ["Rule", "Time (ms)", "Relative", "Calls"];
+    let widths: [usize; 4] = std::array::from_fn(|column| {
+        rows.iter().map(|row| row[column].len()).chain([header[column].len()]).max().unwrap_or(0)
+    });
+
+    let row = |cells: [&str; 4]| {
+        format!(
+            "{:<w0$} | {:>w1$} | {:>w2$} | {:>w3$}\n",
+            cells[0],
+            cells[1],
+            cells[2],
+            cells[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        )
+    };
+    let separator = format!(
+        ":{}|{}:|{}:|{}:\n",
+        "-".repeat(widths[0]),
+        "-".repeat(widths[1] + 1),
+        "-".repeat(widths[2] + 1),
+        "-".repeat(widths[3]),
+    );
+    let mut output = row(header);
+    output.push_str(&separator);
+    for cells in &rows {
+        output.push_str(&row([&cells[0], &cells[1], &cells[2], &cells[3]]));
+    }
+    output
+}
+
 pub struct OutputFormatter {
     internal: Box<dyn InternalFormatter>,
 }
 
 impl OutputFormatter {
-    pub fn new(format: OutputFormat) -> Self {
-        Self { internal: Self::get_internal_formatter(format) }
+    /// # Errors
+    ///
+    /// Returns an error if `format` is a custom format which is not registered in `reporters`.
+    pub fn new(format: &OutputFormat, reporters: &ReporterRegistry) -> Result<Self, String> {
+        Ok(Self { internal: Self::get_internal_formatter(format, reporters)? })
     }
 
-    fn get_internal_formatter(format: OutputFormat) -> Box<dyn InternalFormatter> {
-        match format {
+    fn get_internal_formatter(
+        format: &OutputFormat,
+        reporters: &ReporterRegistry,
+    ) -> Result<Box<dyn InternalFormatter>, String> {
+        Ok(match format {
             OutputFormat::Json => Box::<JsonOutputFormatter>::default(),
             OutputFormat::Checkstyle => Box::<CheckStyleOutputFormatter>::default(),
             OutputFormat::Github => Box::new(GithubOutputFormatter),
@@ -105,7 +180,17 @@ impl OutputFormatter {
             OutputFormat::Default => Box::new(DefaultOutputFormatter),
             OutputFormat::Stylish => Box::<StylishOutputFormatter>::default(),
             OutputFormat::JUnit => Box::<JUnitOutputFormatter>::default(),
-        }
+            OutputFormat::Grouped => Box::<GroupedOutputFormatter>::default(),
+            OutputFormat::Custom(name) => match reporters.create(name) {
+                Some(reporter) => Box::new(CustomOutputFormatter::new(reporter)),
+                None => return Err(format!("'{name}' is not a known format")),
+            },
+        })
+    }
+
+    /// At the start of the Lint command we may output extra information.
+    pub fn begin_run(&self) -> Option<String> {
+        self.internal.begin_run()
     }
 
     /// Print all available rules by oxlint
@@ -128,6 +213,12 @@ impl OutputFormatter {
 
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
+    use oxc_diagnostics::{Error, reporter::Info};
+    use oxc_linter::{LintRunSummary, Reporter, ReporterRegistry, RuleTiming};
+
+    use super::render_rule_timings;
     use crate::tester::Tester;
 
     const TEST_CWD: &str = "fixtures/output_formatter_diagnostic";
@@ -177,4 +268,72 @@ mod test {
 
         Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
     }
+
+    #[test]
+    fn test_output_formatter_diagnostic_grouped() {
+        let args = &["--format=grouped", "test.js"];
+
+        Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
+    }
+
+    #[derive(Default)]
+    struct SummaryReporter;
+
+    impl Reporter for SummaryReporter {
+        fn begin_run(&mut self) -> Option<String> {
+            Some("Lint results:\n".to_string())
+        }
+
+        fn report_file(&mut self, diagnostics: Vec<Error>) -> Option<String> {
+            let filename = Info::new(&diagnostics[0]).filename;
+            Some(format!("{filename}: {} problems\n", diagnostics.len()))
+        }
+
+        fn end_run(&mut self, summary: &LintRunSummary) -> Option<String> {
+            Some(format!(
+                "{} files, {} warnings, {} errors\n",
+                summary.number_of_files, summary.warnings_count, summary.errors_count
+            ))
+        }
+    }
+
+    #[test]
+    fn test_output_formatter_diagnostic_custom() {
+        let args = &["--format=summary", "test.js"];
+        let reporters =
+            ReporterRegistry::default().with_reporter("summary", || Box::new(SummaryReporter));
+
+        Tester::new().with_cwd(TEST_CWD.into()).with_reporters(reporters).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_output_formatter_diagnostic_unknown() {
+        let args = &["--format=unknown", "test.js"];
+
+        Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_render_rule_timings() {
+        let timing = |plugin_name, rule_name, micros, calls| RuleTiming {
+            plugin_name,
+            rule_name,
+            duration: Duration::from_micros(micros),
+            calls,
+        };
+        let timings = [
+            timing("eslint", "no-unused-vars", 3000, 12),
+            timing("eslint", "no-debugger", 1000, 1234),
+        ];
+
+        assert_eq!(
+            render_rule_timings(&timings),
+            "\
+Rule                  | Time (ms) | Relative | Calls
+:---------------------|----------:|---------:|-----:
+eslint/no-unused-vars |     3.000 |    75.0% |    12
+eslint/no-debugger    |     1.000 |    25.0% |  1234
+"
+        );
+    }
 }
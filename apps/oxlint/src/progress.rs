@@ -0,0 +1,145 @@
+use std::{
+    io::{self, Write},
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use oxc_diagnostics::{DiagnosticSender, Error};
+
+/// Interval between two redraws of the status line.
+const REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+
+const BAR_WIDTH: usize = 24;
+
+/// Counters of a lint run shown with `--progress`, updated by the walker and the linter threads.
+#[derive(Debug, Default)]
+pub struct Progress {
+    scanned: AtomicUsize,
+    /// Number of files to lint. `0` while files are still being collected.
+    total: AtomicUsize,
+    /// Shared with the linter, see [`oxc_linter::LintServiceOptions::with_linted_files`].
+    linted: Arc<AtomicUsize>,
+    diagnostics: AtomicUsize,
+}
+
+impl Progress {
+    pub fn file_scanned(&self) {
+        self.scanned.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn set_total(&self, total: usize) {
+        self.total.store(total, Ordering::Relaxed);
+    }
+
+    pub fn linted(&self) -> Arc<AtomicUsize> {
+        Arc::clone(&self.linted)
+    }
+
+    fn status_line(&self, elapsed: Duration) -> String {
+        let total = self.total.load(Ordering::Relaxed);
+        if total == 0 {
+            return format!("Scanning... {} files found", self.scanned.load(Ordering::Relaxed));
+        }
+        let linted = self.linted.load(Ordering::Relaxed).min(total);
+        let diagnostics = self.diagnostics.load(Ordering::Relaxed);
+        let filled = BAR_WIDTH * linted / total;
+        let mut line = format!(
+            "[{}{}] {linted}/{total} files linted, {diagnostics} diagnostics, {} elapsed",
+            "#".repeat(filled),
+            "-".repeat(BAR_WIDTH - filled),
+            format_seconds(elapsed.as_secs()),
+        );
+        if linted > 0 && linted < total {
+            let remaining = elapsed.as_secs() * (total - linted) as u64 / linted as u64;
+            line.push_str(", ETA ");
+            line.push_str(&format_seconds(remaining));
+        }
+        line
+    }
+}
+
+fn format_seconds(seconds: u64) -> String {
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Redraws the status line of a [`Progress`] on stderr until dropped, then clears it.
+pub struct ProgressReporter {
+    stop: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ProgressReporter {
+    pub fn start(progress: Arc<Progress>) -> Self {
+        let (stop, stopped) = mpsc::channel::<()>();
+        let thread = thread::spawn(move || {
+            let start = Instant::now();
+            let mut stderr = io::stderr();
+            while stopped.recv_timeout(REFRESH_INTERVAL) == Err(mpsc::RecvTimeoutError::Timeout) {
+                let line = progress.status_line(start.elapsed());
+                // Errors are ignored, the status line is only informative.
+                let _ = write!(stderr, "\r{line}\x1b[K");
+                let _ = stderr.flush();
+            }
+            let _ = write!(stderr, "\r\x1b[K");
+            let _ = stderr.flush();
+        });
+        Self { stop: Some(stop), thread: Some(thread) }
+    }
+}
+
+impl Drop for ProgressReporter {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Forwards diagnostics to `tx_error`, counting them for the status line.
+pub fn count_diagnostics(progress: Arc<Progress>, tx_error: DiagnosticSender) -> DiagnosticSender {
+    let (sender, receiver) = mpsc::channel::<Vec<Error>>();
+    thread::spawn(move || {
+        for diagnostics in receiver {
+            progress.diagnostics.fetch_add(diagnostics.len(), Ordering::Relaxed);
+            if tx_error.send(diagnostics).is_err() {
+                break;
+            }
+        }
+    });
+    sender
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::atomic::Ordering, time::Duration};
+
+    use super::Progress;
+
+    #[test]
+    fn status_line() {
+        let progress = Progress::default();
+        progress.file_scanned();
+        progress.file_scanned();
+        assert_eq!(progress.status_line(Duration::ZERO), "Scanning... 2 files found");
+
+        progress.set_total(4);
+        progress.linted.store(1, Ordering::Relaxed);
+        progress.diagnostics.store(3, Ordering::Relaxed);
+        assert_eq!(
+            progress.status_line(Duration::from_secs(10)),
+            "[######------------------] 1/4 files linted, 3 diagnostics, 00:10 elapsed, ETA 00:30"
+        );
+
+        progress.linted.store(5, Ordering::Relaxed);
+        assert_eq!(
+            progress.status_line(Duration::from_secs(75)),
+            "[########################] 4/4 files linted, 3 diagnostics, 01:15 elapsed"
+        );
+    }
+}
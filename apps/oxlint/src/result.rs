@@ -8,15 +8,30 @@ pub enum CliRunResult {
     InvalidOptionSeverityWithoutFilter,
     InvalidOptionSeverityWithoutPluginName,
     InvalidOptionSeverityWithoutRuleName,
+    InvalidOptionBaseline,
+    InvalidOptionSince,
+    InvalidOptionStdin,
+    InvalidOptionStaged,
+    InvalidOptionFormat,
+    InvalidOptionRule,
     LintSucceeded,
     LintFoundErrors,
     LintMaxWarningsExceeded,
+    LintBudgetExceeded,
     LintNoWarningsAllowed,
     LintNoFilesFound,
     PrintConfigResult,
+    PrintSchemaResult,
+    ExplainRuleResult,
+    ExplainRuleNotFound,
     ConfigFileInitFailed,
     ConfigFileInitSucceeded,
+    BaselineFileWriteFailed,
+    FixPatchFileWriteFailed,
     TsGoLintError,
+    DaemonFailed,
+    DaemonLintFailed,
+    WatchFailed,
 }
 
 impl Termination for CliRunResult {
@@ -24,6 +39,8 @@ impl Termination for CliRunResult {
         match self {
             Self::None
             | Self::PrintConfigResult
+            | Self::PrintSchemaResult
+            | Self::ExplainRuleResult
             | Self::ConfigFileInitSucceeded
             | Self::LintSucceeded
             // ToDo: when oxc_linter (config) validates the configuration, we can use exit_code = 1 to fail
@@ -32,12 +49,25 @@ impl Termination for CliRunResult {
             | Self::LintFoundErrors
             | Self::LintNoWarningsAllowed
             | Self::LintMaxWarningsExceeded
+            | Self::LintBudgetExceeded
             | Self::InvalidOptionConfig
             | Self::InvalidOptionTsConfig
             | Self::InvalidOptionSeverityWithoutFilter
             | Self::InvalidOptionSeverityWithoutPluginName
             | Self::InvalidOptionSeverityWithoutRuleName
-            | Self::TsGoLintError => ExitCode::FAILURE,
+            | Self::InvalidOptionBaseline
+            | Self::InvalidOptionSince
+            | Self::InvalidOptionStdin
+            | Self::InvalidOptionStaged
+            | Self::InvalidOptionFormat
+            | Self::InvalidOptionRule
+            | Self::ExplainRuleNotFound
+            | Self::BaselineFileWriteFailed
+            | Self::FixPatchFileWriteFailed
+            | Self::TsGoLintError
+            | Self::DaemonFailed
+            | Self::DaemonLintFailed
+            | Self::WatchFailed => ExitCode::FAILURE,
         }
     }
 }
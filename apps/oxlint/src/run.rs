@@ -129,11 +129,40 @@ async fn lint_impl(
         return CliRunResult::LintSucceeded;
     }
 
+    // If --completions is set, print the completion script
+    if let Some(shell) = command.misc_options.completions {
+        shell.print_completions();
+        return CliRunResult::None;
+    }
+
+    // stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
+    // See `https://github.com/rust-lang/rust/issues/60673`.
+    let mut stdout = BufWriter::new(std::io::stdout());
+
+    // If --use-daemon flag is set, send the lint to a running daemon
+    if command.daemon_options.use_daemon
+        && let Some(result) = crate::daemon::run_client(&command, &mut stdout)
+    {
+        return result;
+    }
+
     init_tracing();
     init_miette();
 
     command.handle_threads();
 
+    // If --daemon flag is set, serve lint requests until the process is killed.
+    // JS plugins are not supported by the daemon.
+    if command.daemon_options.daemon {
+        return crate::daemon::run_daemon(&command, &mut stdout);
+    }
+
+    // If --watch flag is set, lint again on every change until the process is killed.
+    // JS plugins are not supported in watch mode.
+    if command.watch {
+        return crate::watch::run_watch(&command, &mut stdout);
+    }
+
     // JS plugins are only supported on 64-bit little-endian platforms at present
     #[cfg(all(target_pointer_width = "64", target_endian = "little"))]
     let external_linter =
@@ -144,10 +173,6 @@ async fn lint_impl(
         None
     };
 
-    // stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
-    // See `https://github.com/rust-lang/rust/issues/60673`.
-    let mut stdout = BufWriter::new(std::io::stdout());
-
     CliRunner::new(command, external_linter).run(&mut stdout)
 }
 
@@ -0,0 +1,42 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use oxc_allocator::Allocator;
+use oxc_linter::{RuntimeFileSystem, read_to_arena_str};
+
+/// File system used with `--stdin-filename`.
+///
+/// Serves source text read from stdin for `path`, which doesn't need to exist on disk.
+/// All other files (e.g. modules imported by the source text) are read from disk.
+pub struct StdinFileSystem {
+    path: PathBuf,
+    source_text: String,
+}
+
+impl StdinFileSystem {
+    pub fn new(path: PathBuf, source_text: String) -> Self {
+        Self { path, source_text }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl RuntimeFileSystem for StdinFileSystem {
+    fn read_to_arena_str<'a>(
+        &'a self,
+        path: &Path,
+        allocator: &'a Allocator,
+    ) -> Result<&'a str, io::Error> {
+        if path == self.path { Ok(&self.source_text) } else { read_to_arena_str(path, allocator) }
+    }
+
+    fn write_file(&self, path: &Path, content: &str) -> Result<(), io::Error> {
+        // Fix options are rejected together with `--stdin-filename`
+        debug_assert!(path != self.path, "source text from stdin should not be written to disk");
+        fs::write(path, content)
+    }
+}
@@ -3,10 +3,14 @@ use std::{env, path::PathBuf};
 use cow_utils::CowUtils;
 use lazy_regex::Regex;
 
+use oxc_linter::ReporterRegistry;
+
 use crate::cli::{CliRunner, lint_command};
 
 pub struct Tester {
     cwd: PathBuf,
+    stdin: Option<String>,
+    reporters: ReporterRegistry,
 }
 
 impl Tester {
@@ -18,7 +22,7 @@ impl Tester {
         // do not unwrap because we can set it only one time.
         let _ = rayon::ThreadPoolBuilder::new().num_threads(1).build_global();
 
-        Self { cwd }
+        Self { cwd, stdin: None, reporters: ReporterRegistry::default() }
     }
 
     pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
@@ -26,6 +30,18 @@ impl Tester {
         self
     }
 
+    /// Source text passed to `--stdin-filename`.
+    pub fn with_stdin(mut self, source_text: &str) -> Self {
+        self.stdin = Some(source_text.to_string());
+        self
+    }
+
+    /// Reporters available with `--format`.
+    pub fn with_reporters(mut self, reporters: ReporterRegistry) -> Self {
+        self.reporters = reporters;
+        self
+    }
+
     pub fn test(&self, args: &[&str]) {
         let mut new_args = vec!["--silent"];
         new_args.extend(args);
@@ -97,7 +113,13 @@ impl Tester {
                 format!("working directory: {}\n", relative_dir.to_str().unwrap()).as_bytes(),
             );
             output.extend_from_slice(b"----------\n");
-            let result = CliRunner::new(options, None).with_cwd(self.cwd.clone()).run(&mut output);
+            let mut runner = CliRunner::new(options, None)
+                .with_cwd(self.cwd.clone())
+                .with_reporters(self.reporters.clone());
+            if let Some(stdin) = &self.stdin {
+                runner = runner.with_stdin(stdin.clone());
+            }
+            let result = runner.run(&mut output);
 
             output.extend_from_slice(b"----------\n");
             output.extend_from_slice(format!("CLI result: {result:?}\n").as_bytes());
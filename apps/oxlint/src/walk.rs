@@ -1,9 +1,140 @@
-use std::{ffi::OsStr, path::PathBuf, sync::Arc, sync::mpsc};
-
-use ignore::{DirEntry, overrides::Override};
+use std::{
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
+    sync::Arc,
+    sync::mpsc,
+};
+
+use ignore::{
+    DirEntry, Match,
+    gitignore::{Gitignore, GitignoreBuilder},
+    overrides::Override,
+};
 use oxc_linter::LINTABLE_EXTENSIONS;
+use rustc_hash::FxHashMap;
+
+use crate::{cli::IgnoreOptions, progress::Progress};
+
+/// Ignore file read in every directory, in addition to the one named by `--ignore-path`.
+pub const OXLINTIGNORE_FILE: &str = ".oxlintignore";
 
-use crate::cli::IgnoreOptions;
+/// Names of the ignore files read in every directory. Patterns of a later file take precedence.
+///
+/// `--ignore-path` is one of them if it is a bare file name, like the default `.eslintignore`.
+fn ignore_file_names(options: &IgnoreOptions) -> Vec<&OsStr> {
+    let mut names = vec![];
+    if is_bare_file_name(&options.ignore_path) {
+        names.push(options.ignore_path.as_os_str());
+    }
+    if options.ignore_path != OXLINTIGNORE_FILE {
+        names.push(OsStr::new(OXLINTIGNORE_FILE));
+    }
+    names
+}
+
+fn is_bare_file_name(path: &OsStr) -> bool {
+    Path::new(path).file_name() == Some(path)
+}
+
+/// The single file passed with `--ignore-path` if it isn't a bare file name. Its patterns are
+/// relative to its own directory.
+fn ignore_path_file(cwd: &Path, options: &IgnoreOptions) -> Option<Gitignore> {
+    if options.ignore_path.is_empty() || is_bare_file_name(&options.ignore_path) {
+        return None;
+    }
+    let (ignore_file, _err) = Gitignore::new(cwd.join(&options.ignore_path));
+    Some(ignore_file)
+}
+
+/// Whether `path` or one of its parent directories is excluded by a `--ignore-path` file.
+fn is_ignored_by_file(ignore_file: &Gitignore, path: &Path, is_dir: bool) -> bool {
+    path.starts_with(ignore_file.path())
+        && ignore_file.matched_path_or_any_parents(path, is_dir).is_ignore()
+}
+
+/// Matches paths against ignore files the same way [`Walk`] does while traversing, for paths
+/// which are linted without being walked, e.g. files passed on the command line.
+///
+/// Ignore files in deeper directories take precedence, `!` negations re-include paths, and, as in
+/// gitignore, a path can't be re-included if one of its parent directories is excluded.
+pub struct IgnoreMatcher {
+    names: Vec<OsString>,
+    ignore_file: Option<Gitignore>,
+    /// Combined ignore files of each directory.
+    dirs: FxHashMap<PathBuf, Gitignore>,
+    ignored_dirs: FxHashMap<PathBuf, bool>,
+}
+
+impl IgnoreMatcher {
+    pub fn new(cwd: &Path, options: &IgnoreOptions) -> Self {
+        Self {
+            names: ignore_file_names(options).into_iter().map(OsStr::to_os_string).collect(),
+            ignore_file: ignore_path_file(cwd, options),
+            dirs: FxHashMap::default(),
+            ignored_dirs: FxHashMap::default(),
+        }
+    }
+
+    /// Matches all ignore files read by [`Walk`], i.e. `.gitignore` files as well, which are read
+    /// even with `--no-ignore`.
+    pub fn for_walk(cwd: &Path, options: &IgnoreOptions) -> Self {
+        let mut matcher = if options.no_ignore {
+            Self {
+                names: vec![],
+                ignore_file: None,
+                dirs: FxHashMap::default(),
+                ignored_dirs: FxHashMap::default(),
+            }
+        } else {
+            Self::new(cwd, options)
+        };
+        // Patterns of the other ignore files take precedence.
+        matcher.names.insert(0, OsString::from(".gitignore"));
+        matcher
+    }
+
+    /// `path` must be absolute.
+    pub fn is_ignored(&mut self, path: &Path, is_dir: bool) -> bool {
+        if let Some(parent) = path.parent()
+            && self.is_dir_ignored(parent)
+        {
+            return true;
+        }
+        if self.ignore_file.as_ref().is_some_and(|file| is_ignored_by_file(file, path, is_dir)) {
+            return true;
+        }
+        for dir in path.ancestors().skip(1) {
+            match self.dir_ignore(dir).matched(path, is_dir) {
+                Match::Ignore(_) => return true,
+                Match::Whitelist(_) => return false,
+                Match::None => {}
+            }
+        }
+        false
+    }
+
+    fn is_dir_ignored(&mut self, dir: &Path) -> bool {
+        if let Some(ignored) = self.ignored_dirs.get(dir) {
+            return *ignored;
+        }
+        let ignored = self.is_ignored(dir, true);
+        self.ignored_dirs.insert(dir.to_path_buf(), ignored);
+        ignored
+    }
+
+    fn dir_ignore(&mut self, dir: &Path) -> &Gitignore {
+        self.dirs.entry(dir.to_path_buf()).or_insert_with(|| {
+            let mut builder = GitignoreBuilder::new(dir);
+            for name in &self.names {
+                let file = dir.join(name);
+                if file.is_file() {
+                    builder.add(file);
+                }
+            }
+            builder.build().unwrap_or_else(|_| Gitignore::empty())
+        })
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct Extensions(pub Vec<&'static str>);
@@ -18,11 +149,13 @@ pub struct Walk {
     inner: ignore::WalkParallel,
     /// The file extensions to include during the traversal.
     extensions: Extensions,
+    progress: Option<Arc<Progress>>,
 }
 
 struct WalkBuilder {
     sender: mpsc::Sender<Vec<Arc<OsStr>>>,
     extensions: Extensions,
+    progress: Option<Arc<Progress>>,
 }
 
 impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
@@ -31,6 +164,7 @@ impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
             paths: vec![],
             sender: self.sender.clone(),
             extensions: self.extensions.clone(),
+            progress: self.progress.clone(),
         })
     }
 }
@@ -39,6 +173,7 @@ struct WalkCollector {
     paths: Vec<Arc<OsStr>>,
     sender: mpsc::Sender<Vec<Arc<OsStr>>>,
     extensions: Extensions,
+    progress: Option<Arc<Progress>>,
 }
 
 impl Drop for WalkCollector {
@@ -59,6 +194,9 @@ impl ignore::ParallelVisitor for WalkCollector {
                 }
                 if Walk::is_wanted_entry(&entry, &self.extensions) {
                     self.paths.push(entry.path().as_os_str().into());
+                    if let Some(progress) = &self.progress {
+                        progress.file_scanned();
+                    }
                 }
                 ignore::WalkState::Continue
             }
@@ -70,6 +208,7 @@ impl Walk {
     /// Will not canonicalize paths.
     /// # Panics
     pub fn new(
+        cwd: &Path,
         paths: &[PathBuf],
         options: &IgnoreOptions,
         override_builder: Option<Override>,
@@ -90,7 +229,15 @@ impl Walk {
         }
 
         if !options.no_ignore {
-            inner.add_custom_ignore_filename(&options.ignore_path);
+            for name in ignore_file_names(options) {
+                inner.add_custom_ignore_filename(name);
+            }
+            if let Some(ignore_file) = ignore_path_file(cwd, options) {
+                inner.filter_entry(move |entry| {
+                    let is_dir = entry.file_type().is_some_and(|ty| ty.is_dir());
+                    !is_ignored_by_file(&ignore_file, entry.path(), is_dir)
+                });
+            }
 
             if let Some(override_builder) = override_builder {
                 inner.overrides(override_builder);
@@ -105,17 +252,24 @@ impl Walk {
             .hidden(false)
             .require_git(false)
             .build_parallel();
-        Self { inner, extensions: Extensions::default() }
+        Self { inner, extensions: Extensions::default(), progress: None }
     }
 
     pub fn paths(self) -> Vec<Arc<OsStr>> {
         let (sender, receiver) = mpsc::channel::<Vec<Arc<OsStr>>>();
-        let mut builder = WalkBuilder { sender, extensions: self.extensions };
+        let mut builder =
+            WalkBuilder { sender, extensions: self.extensions, progress: self.progress };
         self.inner.visit(&mut builder);
         drop(builder);
         receiver.into_iter().flatten().collect()
     }
 
+    /// Count the collected files in `progress`.
+    pub fn with_progress(mut self, progress: Option<Arc<Progress>>) -> Self {
+        self.progress = progress;
+        self
+    }
+
     #[cfg_attr(not(test), expect(dead_code))]
     pub fn with_extensions(mut self, extensions: Extensions) -> Self {
         self.extensions = extensions;
@@ -145,7 +299,7 @@ mod test {
 
     use ignore::overrides::OverrideBuilder;
 
-    use super::{Extensions, Walk};
+    use super::{Extensions, IgnoreMatcher, Walk};
     use crate::cli::IgnoreOptions;
 
     #[test]
@@ -160,7 +314,7 @@ mod test {
 
         let override_builder = OverrideBuilder::new("/").build().unwrap();
 
-        let mut paths = Walk::new(&fixtures, &ignore_options, Some(override_builder))
+        let mut paths = Walk::new(&fixture, &fixtures, &ignore_options, Some(override_builder))
             .with_extensions(Extensions(["js", "vue"].to_vec()))
             .paths()
             .into_iter()
@@ -199,21 +353,25 @@ mod test {
 
         let override_builder = OverrideBuilder::new(temp_path).build().unwrap();
 
-        let mut paths =
-            Walk::new(&[temp_path.to_path_buf()], &ignore_options, Some(override_builder))
-                .with_extensions(Extensions(["js"].to_vec()))
-                .paths()
-                .into_iter()
-                .map(|path| {
-                    Path::new(&path)
-                        .strip_prefix(temp_path)
-                        .unwrap()
-                        .file_name()
-                        .unwrap()
-                        .to_string_lossy()
-                        .to_string()
-                })
-                .collect::<Vec<_>>();
+        let mut paths = Walk::new(
+            temp_path,
+            &[temp_path.to_path_buf()],
+            &ignore_options,
+            Some(override_builder),
+        )
+        .with_extensions(Extensions(["js"].to_vec()))
+        .paths()
+        .into_iter()
+        .map(|path| {
+            Path::new(&path)
+                .strip_prefix(temp_path)
+                .unwrap()
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .to_string()
+        })
+        .collect::<Vec<_>>();
 
         paths.sort();
 
@@ -221,4 +379,99 @@ mod test {
         // Without .git_ignore(true) and .require_git(false), both files would be found
         assert_eq!(paths, vec!["included.js"]);
     }
+
+    fn write_nested_oxlintignore_fixture(root: &Path) {
+        fs::create_dir_all(root.join("src/generated")).unwrap();
+        fs::create_dir_all(root.join("build")).unwrap();
+        for file in ["index.js", "src/a.js", "src/b.js", "src/generated/c.js", "build/keep.js"] {
+            fs::write(root.join(file), "debugger;").unwrap();
+        }
+        fs::write(root.join(".oxlintignore"), "build/\nsrc/*.js\n").unwrap();
+        fs::write(root.join("src/.oxlintignore"), "!b.js\ngenerated\n").unwrap();
+        // Can't re-include a file whose parent directory is excluded.
+        fs::write(root.join("build/.oxlintignore"), "!keep.js\n").unwrap();
+    }
+
+    #[test]
+    fn test_nested_oxlintignore() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        write_nested_oxlintignore_fixture(root);
+
+        let ignore_options = IgnoreOptions {
+            no_ignore: false,
+            ignore_path: OsString::from(".eslintignore"),
+            ignore_pattern: vec![],
+        };
+
+        let mut paths = Walk::new(root, &[root.to_path_buf()], &ignore_options, None)
+            .paths()
+            .into_iter()
+            .map(|path| Path::new(&path).strip_prefix(root).unwrap().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(paths, vec!["index.js", "src/b.js"]);
+
+        let mut matcher = IgnoreMatcher::new(root, &ignore_options);
+        for (file, ignored) in [
+            ("index.js", false),
+            ("src/a.js", true),
+            ("src/b.js", false),
+            ("src/generated/c.js", true),
+            ("build/keep.js", true),
+        ] {
+            assert_eq!(matcher.is_ignored(&root.join(file), false), ignored, "{file}");
+        }
+    }
+
+    #[test]
+    fn test_ignore_path_relative_to_its_directory() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("config")).unwrap();
+        fs::write(root.join("a.js"), "debugger;").unwrap();
+        fs::write(root.join("config/a.js"), "debugger;").unwrap();
+        fs::write(root.join("config/ignore"), "/a.js\n").unwrap();
+
+        let ignore_options = IgnoreOptions {
+            no_ignore: false,
+            ignore_path: OsString::from("config/ignore"),
+            ignore_pattern: vec![],
+        };
+        let paths = Walk::new(root, &[root.to_path_buf()], &ignore_options, None)
+            .paths()
+            .into_iter()
+            .map(|path| Path::new(&path).strip_prefix(root).unwrap().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec!["a.js"]);
+
+        let mut matcher = IgnoreMatcher::new(root, &ignore_options);
+        assert!(matcher.is_ignored(&root.join("config/a.js"), false));
+        assert!(!matcher.is_ignored(&root.join("a.js"), false));
+    }
+
+    #[test]
+    fn test_ignore_matcher_for_walk() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join(".gitignore"), "dist\n").unwrap();
+        fs::write(root.join(".oxlintignore"), "a.js\n").unwrap();
+
+        let mut ignore_options = IgnoreOptions {
+            no_ignore: false,
+            ignore_path: OsString::from(".eslintignore"),
+            ignore_pattern: vec![],
+        };
+        let mut matcher = IgnoreMatcher::for_walk(root, &ignore_options);
+        assert!(matcher.is_ignored(&root.join("dist"), true));
+        assert!(matcher.is_ignored(&root.join("dist/b.js"), false));
+        assert!(matcher.is_ignored(&root.join("a.js"), false));
+        assert!(!matcher.is_ignored(&root.join("b.js"), false));
+
+        // `.gitignore` files are read even with `--no-ignore`, like by `Walk`
+        ignore_options.no_ignore = true;
+        let mut matcher = IgnoreMatcher::for_walk(root, &ignore_options);
+        assert!(matcher.is_ignored(&root.join("dist/b.js"), false));
+        assert!(!matcher.is_ignored(&root.join("a.js"), false));
+    }
 }
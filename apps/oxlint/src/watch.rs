@@ -0,0 +1,164 @@
+use std::{
+    env,
+    ffi::OsStr,
+    io::Write,
+    path::Path,
+    sync::{Arc, OnceLock},
+};
+
+use ignore::{Match, overrides::OverrideBuilder};
+
+use oxc_linter::{FileChange, FileWatcher, LintIgnoreMatcher, WatchedFileKind};
+
+use crate::{
+    cli::{CliRunResult, CliRunner, IgnoreOptions, LintCommand},
+    lint::print_and_flush_stdout,
+    walk::IgnoreMatcher,
+};
+
+/// Lint, then lint again whenever a file which can be linted, a configuration file or an ignore
+/// file changes, until the process is killed.
+pub fn run_watch(command: &LintCommand, stdout: &mut dyn Write) -> CliRunResult {
+    let Ok(cwd) = env::current_dir() else {
+        print_and_flush_stdout(stdout, "Failed to get current working directory\n");
+        return CliRunResult::WatchFailed;
+    };
+    let config = command.basic_options.config.as_ref().map(|config| cwd.join(config));
+    let tsconfig = command.basic_options.tsconfig.as_ref().map(|tsconfig| cwd.join(tsconfig));
+    let is_relevant = |change: &FileChange| {
+        WatchedFileKind::from_path(&change.path) != WatchedFileKind::Other
+            || config.as_ref().is_some_and(|config| *config == change.path)
+            || tsconfig.as_ref().is_some_and(|tsconfig| *tsconfig == change.path)
+    };
+    // Which files are ignored depends on the configuration and the ignore files.
+    let changes_ignored_files = |change: &FileChange| {
+        matches!(
+            WatchedFileKind::from_path(&change.path),
+            WatchedFileKind::LintConfig | WatchedFileKind::IgnoreFile
+        ) || config.as_ref().is_some_and(|config| *config == change.path)
+    };
+
+    let mut watcher: Option<FileWatcher> = None;
+    loop {
+        let ignore_matcher = Arc::new(OnceLock::new());
+        CliRunner::new(command.clone(), None)
+            .with_ignore_matcher_output(Arc::clone(&ignore_matcher))
+            .run(stdout);
+        let mut file_watcher = if let Some(mut file_watcher) = watcher.take() {
+            // Ignore the files written by the lint itself, e.g. by `--fix`.
+            file_watcher.poll();
+            file_watcher
+        } else {
+            let is_ignored = ignore_filter(&cwd, &command.ignore_options, Some(ignore_matcher));
+            match FileWatcher::new(&cwd, is_ignored) {
+                Ok(file_watcher) => file_watcher,
+                Err(err) => {
+                    print_and_flush_stdout(stdout, &format!("Failed to watch files: {err}\n"));
+                    return CliRunResult::WatchFailed;
+                }
+            }
+        };
+        print_and_flush_stdout(stdout, "\nWatching for file changes...\n");
+
+        let changes = loop {
+            let changes = file_watcher.wait();
+            if changes.is_empty() {
+                print_and_flush_stdout(stdout, "Stopped watching files\n");
+                return CliRunResult::WatchFailed;
+            }
+            let changes = changes.into_iter().filter(is_relevant).collect::<Vec<_>>();
+            if !changes.is_empty() {
+                break changes;
+            }
+        };
+        let message = match changes.as_slice() {
+            [change] => display_path(&change.path, &cwd),
+            changes => format!("{} files", changes.len()),
+        };
+        print_and_flush_stdout(stdout, &format!("{message} changed, linting again\n\n"));
+        if !changes.iter().any(changes_ignored_files) {
+            watcher = Some(file_watcher);
+        }
+    }
+}
+
+/// Whether the changes of a path, given whether it is a directory, are ignored by a
+/// [`FileWatcher`], because the linter ignores the path.
+///
+/// Paths are ignored the same way as while linting, by `.gitignore` and ignore files,
+/// `--ignore-pattern` and the `ignorePatterns` of the configuration files in `ignore_matcher`.
+/// Configuration and ignore files are only ignored if their directory is.
+pub fn ignore_filter(
+    cwd: &Path,
+    options: &IgnoreOptions,
+    ignore_matcher: Option<Arc<OnceLock<LintIgnoreMatcher>>>,
+) -> impl FnMut(&Path, bool) -> bool + Send + 'static {
+    let mut ignore_files = IgnoreMatcher::for_walk(cwd, options);
+    let ignore_patterns = (!options.no_ignore).then(|| {
+        let mut builder = OverrideBuilder::new(cwd);
+        for pattern in &options.ignore_pattern {
+            // Meaning of ignore pattern is reversed
+            // <https://docs.rs/ignore/latest/ignore/overrides/struct.OverrideBuilder.html#method.add>
+            let _ = builder.add(&format!("!{pattern}"));
+        }
+        builder.build().ok()
+    });
+    move |path, is_dir| {
+        // `.git` directories are not walked either.
+        if is_dir && path.file_name() == Some(OsStr::new(".git")) {
+            return true;
+        }
+        if ignore_files.is_ignored(path, is_dir) {
+            return true;
+        }
+        if !is_dir
+            && !matches!(
+                WatchedFileKind::from_path(path),
+                WatchedFileKind::Source | WatchedFileKind::Other
+            )
+        {
+            return false;
+        }
+        ignore_patterns.iter().flatten().any(|ignore_patterns| {
+            matches!(ignore_patterns.matched(path, is_dir), Match::Ignore(_))
+        }) || ignore_matcher
+            .as_ref()
+            .and_then(|ignore_matcher| ignore_matcher.get())
+            .is_some_and(|ignore_matcher| ignore_matcher.should_ignore(path))
+    }
+}
+
+fn display_path(path: &Path, cwd: &Path) -> String {
+    path.strip_prefix(cwd).unwrap_or(path).display().to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{ffi::OsString, fs};
+
+    use super::ignore_filter;
+    use crate::cli::IgnoreOptions;
+
+    #[test]
+    fn test_ignore_filter() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join(".gitignore"), "node_modules\n").unwrap();
+        fs::write(root.join(".oxlintignore"), "*.min.js\n").unwrap();
+
+        let ignore_options = IgnoreOptions {
+            no_ignore: false,
+            ignore_path: OsString::from(".eslintignore"),
+            ignore_pattern: vec!["generated".to_string(), "*.json".to_string()],
+        };
+        let mut is_ignored = ignore_filter(root, &ignore_options, None);
+        assert!(is_ignored(&root.join(".git"), true));
+        assert!(is_ignored(&root.join("node_modules"), true));
+        assert!(is_ignored(&root.join("generated"), true));
+        assert!(is_ignored(&root.join("a.min.js"), false));
+        assert!(!is_ignored(&root.join("a.js"), false));
+        // configuration files are watched, unless their directory is ignored
+        assert!(!is_ignored(&root.join("tsconfig.json"), false));
+        assert!(is_ignored(&root.join("node_modules/a/package.json"), false));
+    }
+}
@@ -1,14 +1,16 @@
 #![warn(missing_docs)]
+use std::hash::Hasher;
+
 use bitflags::bitflags;
 
 use oxc_allocator::{Allocator, CloneIn};
 use oxc_ast_macros::ast;
 use oxc_estree::ESTree;
-use oxc_span::{ContentEq, Span};
+use oxc_span::{ContentEq, ContentHash, Span};
 
 /// Indicates a line or block comment.
 #[ast]
-#[generate_derive(CloneIn, ContentEq, ESTree)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
 #[estree(no_rename_variants, no_ts_def)]
 pub enum CommentKind {
@@ -25,7 +27,7 @@ pub enum CommentKind {
 
 /// Information about a comment's position relative to a token.
 #[ast]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
 pub enum CommentPosition {
     /// Comments prior to a token until another token or trailing comment.
@@ -48,7 +50,7 @@ pub enum CommentPosition {
 
 /// Annotation comment that has special meaning.
 #[ast]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
 pub enum CommentContent {
     /// No Annotation
@@ -115,6 +117,12 @@ impl ContentEq for CommentNewlines {
     }
 }
 
+impl ContentHash for CommentNewlines {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u8(self.bits());
+    }
+}
+
 impl<'alloc> CloneIn<'alloc> for CommentNewlines {
     type Cloned = Self;
 
@@ -125,7 +133,7 @@ impl<'alloc> CloneIn<'alloc> for CommentNewlines {
 
 /// A comment in source code.
 #[ast]
-#[generate_derive(CloneIn, ContentEq, ESTree)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
 #[estree(add_fields(value = CommentValue), no_ts_def, no_parent)]
 pub struct Comment {
@@ -25,7 +25,7 @@ use std::cell::Cell;
 use oxc_allocator::{Box, CloneIn, Dummy, GetAddress, TakeIn, UnstableAddress, Vec};
 use oxc_ast_macros::ast;
 use oxc_estree::ESTree;
-use oxc_span::{Atom, ContentEq, GetSpan, GetSpanMut, SourceType, Span};
+use oxc_span::{Atom, ContentEq, ContentHash, GetSpan, GetSpanMut, SourceType, Span};
 use oxc_syntax::{
     operator::{
         AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
@@ -45,7 +45,17 @@ use super::{macros::inherit_variants, *};
     strict_if = self.source_type.is_strict() || self.has_use_strict_directive(),
 )]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(field_order(body, source_type, hashbang, span), via = ProgramConverter)]
 pub struct Program<'a> {
     pub span: Span,
@@ -72,7 +82,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ContentHash, ESTree)]
 pub enum Expression<'a> {
     /// See [`BooleanLiteral`] for AST node details.
     BooleanLiteral(Box<'a, BooleanLiteral>) = 0,
@@ -161,6 +171,13 @@ pub enum Expression<'a> {
     /// See [`V8IntrinsicExpression`] for AST node details.
     V8IntrinsicExpression(Box<'a, V8IntrinsicExpression<'a>>) = 39,
 
+    /// See [`PipelineExpression`] for AST node details.
+    PipelineExpression(Box<'a, PipelineExpression<'a>>) = 40,
+    /// See [`TopicReference`] for AST node details.
+    TopicReference(Box<'a, TopicReference>) = 41,
+    /// See [`DoExpression`] for AST node details.
+    DoExpression(Box<'a, DoExpression<'a>>) = 42,
+
     // `MemberExpression` variants added here by `inherit_variants!` macro
     @inherit MemberExpression
 }
@@ -214,6 +231,9 @@ macro_rules! match_expression {
             | $ty::StaticMemberExpression(_)
             | $ty::PrivateFieldExpression(_)
             | $ty::V8IntrinsicExpression(_)
+            | $ty::PipelineExpression(_)
+            | $ty::TopicReference(_)
+            | $ty::DoExpression(_)
     };
 }
 pub use match_expression;
@@ -225,7 +245,17 @@ pub use match_expression;
 /// digits, `$`, or `_`.
 #[ast(visit)]
 #[derive(Debug, Clone)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(
     rename = "Identifier",
     add_fields(decorators = TsEmptyArray, optional = TsFalse, typeAnnotation = TsNull),
@@ -244,7 +274,17 @@ pub struct IdentifierName<'a> {
 /// See: [13.1 Identifiers](https://tc39.es/ecma262/#sec-identifiers)
 #[ast(visit)]
 #[derive(Debug, Clone)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(
     rename = "Identifier",
     add_fields(decorators = TsEmptyArray, optional = TsFalse, typeAnnotation = TsNull),
@@ -273,7 +313,17 @@ pub struct IdentifierReference<'a> {
 /// Also see other examples in docs for [`BindingPattern`].
 #[ast(visit)]
 #[derive(Debug, Clone)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(
     rename = "Identifier",
     add_fields(decorators = TsEmptyArray, optional = TsFalse, typeAnnotation = TsNull),
@@ -300,7 +350,17 @@ pub struct BindingIdentifier<'a> {
 /// See: [13.1 Identifiers](https://tc39.es/ecma262/#sec-identifiers)
 #[ast(visit)]
 #[derive(Debug, Clone)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(
     rename = "Identifier",
     add_fields(decorators = TsEmptyArray, optional = TsFalse, typeAnnotation = TsNull),
@@ -317,7 +377,17 @@ pub struct LabelIdentifier<'a> {
 /// Represents a `this` expression, which is a reference to the current object.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ThisExpression {
     pub span: Span,
 }
@@ -327,7 +397,17 @@ pub struct ThisExpression {
 /// Represents an array literal, which can include elements, spread elements, or null values.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ArrayExpression<'a> {
     pub span: Span,
     pub elements: Vec<'a, ArrayExpressionElement<'a>>,
@@ -341,7 +421,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ContentHash, ESTree)]
 pub enum ArrayExpressionElement<'a> {
     /// `...[3, 4]` in `const array = [1, 2, ...[3, 4], null];`
     SpreadElement(Box<'a, SpreadElement<'a>>) = 64,
@@ -360,7 +440,17 @@ pub enum ArrayExpressionElement<'a> {
 /// Array Expression Elision Element
 #[ast(visit)]
 #[derive(Debug, Clone)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(via = Null)]
 pub struct Elision {
     pub span: Span,
@@ -372,7 +462,17 @@ pub struct Elision {
 /// or computed properties.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ObjectExpression<'a> {
     pub span: Span,
     /// Properties declared in the object
@@ -382,7 +482,17 @@ pub struct ObjectExpression<'a> {
 /// Represents a property in an object literal.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    GetAddress,
+    ContentEq,
+    ContentHash,
+    ESTree
+)]
 pub enum ObjectPropertyKind<'a> {
     /// `a: 1` in `const obj = { a: 1 };`
     ObjectProperty(Box<'a, ObjectProperty<'a>>) = 0,
@@ -395,7 +505,17 @@ pub enum ObjectPropertyKind<'a> {
 /// Represents a property in an object literal.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(rename = "Property", add_fields(optional = TsFalse))]
 pub struct ObjectProperty<'a> {
     pub span: Span,
@@ -415,7 +535,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ContentHash, ESTree)]
 pub enum PropertyKey<'a> {
     /// `a` in `const obj = { a: 1 }; obj.a;`
     StaticIdentifier(Box<'a, IdentifierName<'a>>) = 64,
@@ -429,7 +549,7 @@ pub enum PropertyKey<'a> {
 /// Represents the kind of property in an object literal or class.
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, Dummy, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, ContentEq, ContentHash, ESTree)]
 pub enum PropertyKind {
     /// `a: 1` in `const obj = { a: 1 };`
     Init = 0,
@@ -444,7 +564,17 @@ pub enum PropertyKind {
 /// Represents a template literal, which can include quasi elements and expression elements.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct TemplateLiteral<'a> {
     pub span: Span,
     pub quasis: Vec<'a, TemplateElement<'a>>,
@@ -459,7 +589,17 @@ pub struct TemplateLiteral<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct TaggedTemplateExpression<'a> {
     pub span: Span,
     pub tag: Expression<'a>,
@@ -473,7 +613,17 @@ pub struct TaggedTemplateExpression<'a> {
 /// Represents a quasi element in a template literal.
 #[ast(visit)]
 #[derive(Debug, Clone)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(via = TemplateElementConverter)]
 pub struct TemplateElement<'a> {
     pub span: Span,
@@ -492,7 +642,7 @@ pub struct TemplateElement<'a> {
 /// See [template-strings-cooked-vs-raw](https://exploringjs.com/js/book/ch_template-literals.html#template-strings-cooked-vs-raw)
 #[ast]
 #[derive(Debug, Clone)]
-#[generate_derive(CloneIn, Dummy, TakeIn, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, TakeIn, ContentEq, ContentHash, ESTree)]
 #[estree(no_type)]
 pub struct TemplateElementValue<'a> {
     /// A raw interpretation where backslashes do not have special meaning.
@@ -512,7 +662,17 @@ pub struct TemplateElementValue<'a> {
 /// <https://tc39.es/ecma262/#prod-MemberExpression>
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    GetAddress,
+    ContentEq,
+    ContentHash,
+    ESTree
+)]
 pub enum MemberExpression<'a> {
     /// `ar[0]` in `const ar = [1, 2]; ar[0];`
     ComputedMemberExpression(Box<'a, ComputedMemberExpression<'a>>) = 48,
@@ -538,7 +698,17 @@ pub use match_member_expression;
 /// Represents a computed member access expression, which can include an object and an expression.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(rename = "MemberExpression", add_fields(computed = True))]
 pub struct ComputedMemberExpression<'a> {
     pub span: Span,
@@ -553,7 +723,17 @@ pub struct ComputedMemberExpression<'a> {
 /// Represents a static member access expression, which can include an object and a property.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(rename = "MemberExpression", add_fields(computed = False))]
 pub struct StaticMemberExpression<'a> {
     pub span: Span,
@@ -567,7 +747,17 @@ pub struct StaticMemberExpression<'a> {
 /// Represents a private field access expression, which can include an object and a private identifier.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(rename = "MemberExpression", add_fields(computed = False))]
 pub struct PrivateFieldExpression<'a> {
     pub span: Span,
@@ -595,7 +785,17 @@ pub struct PrivateFieldExpression<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct CallExpression<'a> {
     pub span: Span,
     pub callee: Expression<'a>,
@@ -623,7 +823,17 @@ pub struct CallExpression<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct NewExpression<'a> {
     pub span: Span,
     pub callee: Expression<'a>,
@@ -641,7 +851,17 @@ pub struct NewExpression<'a> {
 /// Represents a meta property. The following syntaxes are supported. `import.meta`, `new.target`.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct MetaProperty<'a> {
     pub span: Span,
     pub meta: IdentifierName<'a>,
@@ -653,7 +873,17 @@ pub struct MetaProperty<'a> {
 /// Represents a spread element, which can include an argument.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct SpreadElement<'a> {
     pub span: Span,
     /// The expression being spread.
@@ -668,7 +898,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ContentHash, ESTree)]
 pub enum Argument<'a> {
     /// `...[1, 2]` in `const arr = [...[1, 2]];`
     SpreadElement(Box<'a, SpreadElement<'a>>) = 64,
@@ -683,7 +913,17 @@ pub enum Argument<'a> {
 /// The following syntaxes are supported: `++a`, `a++`, `--a`, `a--`.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct UpdateExpression<'a> {
     pub span: Span,
     pub operator: UpdateOperator,
@@ -697,7 +937,17 @@ pub struct UpdateExpression<'a> {
 /// The following syntaxes are supported: `+a`, `-a`, `~a`, `!a`, `delete a`, `void a`, `typeof a`.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(add_fields(prefix = True))]
 pub struct UnaryExpression<'a> {
     pub span: Span,
@@ -710,7 +960,17 @@ pub struct UnaryExpression<'a> {
 /// Represents a binary expression, which include a left expression, an operator, and a right expression.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct BinaryExpression<'a> {
     pub span: Span,
     pub left: Expression<'a>,
@@ -721,7 +981,17 @@ pub struct BinaryExpression<'a> {
 /// `#brand in obj` in `class Foo { #brand; static isFoo(obj) { return #brand in obj; } }`.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(rename = "BinaryExpression", add_fields(operator = In), field_order(left, operator, right, span))]
 pub struct PrivateInExpression<'a> {
     pub span: Span,
@@ -735,7 +1005,17 @@ pub struct PrivateInExpression<'a> {
 /// The following syntaxes are supported: `||`, `&&` and `??`.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct LogicalExpression<'a> {
     pub span: Span,
     pub left: Expression<'a>,
@@ -748,7 +1028,17 @@ pub struct LogicalExpression<'a> {
 /// Represents a conditional expression, which includes a test, a consequent, and an alternate.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ConditionalExpression<'a> {
     pub span: Span,
     pub test: Expression<'a>,
@@ -761,7 +1051,17 @@ pub struct ConditionalExpression<'a> {
 /// Represents an assignment expression, which includes an operator, a target, and an expression.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct AssignmentExpression<'a> {
     pub span: Span,
     pub operator: AssignmentOperator,
@@ -778,7 +1078,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ContentHash, ESTree)]
 pub enum AssignmentTarget<'a> {
     // `SimpleAssignmentTarget` variants added here by `inherit_variants!` macro
     @inherit SimpleAssignmentTarget
@@ -795,7 +1095,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ContentHash, ESTree)]
 pub enum SimpleAssignmentTarget<'a> {
     AssignmentTargetIdentifier(Box<'a, IdentifierReference<'a>>) = 0,
     TSAsExpression(Box<'a, TSAsExpression<'a>>) = 1,
@@ -845,7 +1145,17 @@ pub use match_simple_assignment_target;
 
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    GetAddress,
+    ContentEq,
+    ContentHash,
+    ESTree
+)]
 pub enum AssignmentTargetPattern<'a> {
     ArrayAssignmentTarget(Box<'a, ArrayAssignmentTarget<'a>>) = 8,
     ObjectAssignmentTarget(Box<'a, ObjectAssignmentTarget<'a>>) = 9,
@@ -865,7 +1175,17 @@ pub use match_assignment_target_pattern;
 /// Represents an array assignment target, which can include elements and a rest element.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(
     rename = "ArrayPattern",
     add_fields(decorators = TsEmptyArray, optional = TsFalse, typeAnnotation = TsNull),
@@ -883,7 +1203,17 @@ pub struct ArrayAssignmentTarget<'a> {
 /// Represents an object assignment target, which can include properties and a rest element.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(
     rename = "ObjectPattern",
     add_fields(decorators = TsEmptyArray, optional = TsFalse, typeAnnotation = TsNull),
@@ -901,7 +1231,17 @@ pub struct ObjectAssignmentTarget<'a> {
 /// Represents rest element in an `ArrayAssignmentTarget` or `ObjectAssignmentTarget`.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(
     rename = "RestElement",
     add_fields(decorators = TsEmptyArray, optional = TsFalse, typeAnnotation = TsNull, value = TsNull),
@@ -921,7 +1261,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ContentHash, ESTree)]
 pub enum AssignmentTargetMaybeDefault<'a> {
     AssignmentTargetWithDefault(Box<'a, AssignmentTargetWithDefault<'a>>) = 16,
     // `AssignmentTarget` variants added here by `inherit_variants!` macro
@@ -931,7 +1271,17 @@ pub enum AssignmentTargetMaybeDefault<'a> {
 
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(
     rename = "AssignmentPattern",
     add_fields(decorators = TsEmptyArray, optional = TsFalse, typeAnnotation = TsNull),
@@ -947,7 +1297,17 @@ pub struct AssignmentTargetWithDefault<'a> {
 
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    GetAddress,
+    ContentEq,
+    ContentHash,
+    ESTree
+)]
 pub enum AssignmentTargetProperty<'a> {
     AssignmentTargetPropertyIdentifier(Box<'a, AssignmentTargetPropertyIdentifier<'a>>) = 0,
     AssignmentTargetPropertyProperty(Box<'a, AssignmentTargetPropertyProperty<'a>>) = 1,
@@ -959,7 +1319,17 @@ pub enum AssignmentTargetProperty<'a> {
 /// and an optional init expression.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(
     rename = "Property",
     add_fields(kind = Init, method = False, shorthand = True, computed = False, optional = TsFalse),
@@ -978,7 +1348,17 @@ pub struct AssignmentTargetPropertyIdentifier<'a> {
 /// Represents an assignment target property property, which includes a name and a binding.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(
     rename = "Property",
     add_fields(kind = Init, method = False, shorthand = False, optional = TsFalse),
@@ -1009,7 +1389,17 @@ pub struct AssignmentTargetPropertyProperty<'a> {
 /// Represents a sequence expression.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct SequenceExpression<'a> {
     pub span: Span,
     pub expressions: Vec<'a, Expression<'a>>,
@@ -1020,7 +1410,17 @@ pub struct SequenceExpression<'a> {
 /// Represents a super expression.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct Super {
     pub span: Span,
 }
@@ -1030,7 +1430,17 @@ pub struct Super {
 /// Represents an await expression, which can include an argument.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct AwaitExpression<'a> {
     pub span: Span,
     pub argument: Expression<'a>,
@@ -1041,7 +1451,17 @@ pub struct AwaitExpression<'a> {
 /// Represents a chain expression, which can include an expression.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ChainExpression<'a> {
     pub span: Span,
     pub expression: ChainElement<'a>,
@@ -1055,7 +1475,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ContentHash, ESTree)]
 pub enum ChainElement<'a> {
     CallExpression(Box<'a, CallExpression<'a>>) = 0,
     /// `foo?.baz!` or `foo?.[bar]!`
@@ -1070,7 +1490,17 @@ pub enum ChainElement<'a> {
 /// Represents a parenthesized expression, which can include an expression.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(via = ParenthesizedExpressionConverter)]
 pub struct ParenthesizedExpression<'a> {
     pub span: Span,
@@ -1086,7 +1516,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ContentHash, ESTree)]
 pub enum Statement<'a> {
     // Statements
     BlockStatement(Box<'a, BlockStatement<'a>>) = 0,
@@ -1119,7 +1549,17 @@ pub enum Statement<'a> {
 /// Represents a directive statement, which can include a string literal.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(rename = "ExpressionStatement")]
 pub struct Directive<'a> {
     pub span: Span,
@@ -1134,7 +1574,17 @@ pub struct Directive<'a> {
 /// Represents a hashbang directive, which can include a value.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct Hashbang<'a> {
     pub span: Span,
     pub value: Atom<'a>,
@@ -1146,7 +1596,17 @@ pub struct Hashbang<'a> {
 #[ast(visit)]
 #[scope]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct BlockStatement<'a> {
     pub span: Span,
     pub body: Vec<'a, Statement<'a>>,
@@ -1156,7 +1616,17 @@ pub struct BlockStatement<'a> {
 /// Declarations and the Variable Statement
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    GetAddress,
+    ContentEq,
+    ContentHash,
+    ESTree
+)]
 pub enum Declaration<'a> {
     VariableDeclaration(Box<'a, VariableDeclaration<'a>>) = 32,
     #[visit(args(flags = ScopeFlags::Function))]
@@ -1193,7 +1663,17 @@ pub use match_declaration;
 /// Represents a variable declaration, which can include a kind, declarations, and modifiers.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct VariableDeclaration<'a> {
     pub span: Span,
     pub kind: VariableDeclarationKind,
@@ -1204,7 +1684,7 @@ pub struct VariableDeclaration<'a> {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, Dummy, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, ContentEq, ContentHash, ESTree)]
 pub enum VariableDeclarationKind {
     Var = 0,
     Let = 1,
@@ -1224,7 +1704,17 @@ pub enum VariableDeclarationKind {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(via = VariableDeclaratorConverter)]
 pub struct VariableDeclarator<'a> {
     pub span: Span,
@@ -1242,7 +1732,17 @@ pub struct VariableDeclarator<'a> {
 /// Empty Statement
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct EmptyStatement {
     pub span: Span,
 }
@@ -1250,7 +1750,17 @@ pub struct EmptyStatement {
 /// Expression Statement
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(add_fields(directive = ExpressionStatementDirective))] // Only in TS AST
 pub struct ExpressionStatement<'a> {
     pub span: Span,
@@ -1260,7 +1770,17 @@ pub struct ExpressionStatement<'a> {
 /// If Statement
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct IfStatement<'a> {
     pub span: Span,
     pub test: Expression<'a>,
@@ -1271,7 +1791,17 @@ pub struct IfStatement<'a> {
 /// Do-While Statement
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct DoWhileStatement<'a> {
     pub span: Span,
     pub body: Statement<'a>,
@@ -1281,7 +1811,17 @@ pub struct DoWhileStatement<'a> {
 /// While Statement
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct WhileStatement<'a> {
     pub span: Span,
     pub test: Expression<'a>,
@@ -1292,7 +1832,17 @@ pub struct WhileStatement<'a> {
 #[ast(visit)]
 #[scope]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ForStatement<'a> {
     pub span: Span,
     pub init: Option<ForStatementInit<'a>>,
@@ -1310,7 +1860,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ContentHash, ESTree)]
 pub enum ForStatementInit<'a> {
     VariableDeclaration(Box<'a, VariableDeclaration<'a>>) = 64,
     // `Expression` variants added here by `inherit_variants!` macro
@@ -1322,7 +1872,17 @@ pub enum ForStatementInit<'a> {
 #[ast(visit)]
 #[scope]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ForInStatement<'a> {
     pub span: Span,
     pub left: ForStatementLeft<'a>,
@@ -1339,7 +1899,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ContentHash, ESTree)]
 pub enum ForStatementLeft<'a> {
     VariableDeclaration(Box<'a, VariableDeclaration<'a>>) = 16,
     // `AssignmentTarget` variants added here by `inherit_variants!` macro
@@ -1351,7 +1911,17 @@ pub enum ForStatementLeft<'a> {
 #[ast(visit)]
 #[scope]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ForOfStatement<'a> {
     pub span: Span,
     pub r#await: bool,
@@ -1364,7 +1934,17 @@ pub struct ForOfStatement<'a> {
 /// Continue Statement
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ContinueStatement<'a> {
     pub span: Span,
     pub label: Option<LabelIdentifier<'a>>,
@@ -1373,7 +1953,17 @@ pub struct ContinueStatement<'a> {
 /// Break Statement
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct BreakStatement<'a> {
     pub span: Span,
     pub label: Option<LabelIdentifier<'a>>,
@@ -1382,7 +1972,17 @@ pub struct BreakStatement<'a> {
 /// Return Statement
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ReturnStatement<'a> {
     pub span: Span,
     pub argument: Option<Expression<'a>>,
@@ -1392,7 +1992,17 @@ pub struct ReturnStatement<'a> {
 #[ast(visit)]
 #[scope(flags = ScopeFlags::With)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct WithStatement<'a> {
     pub span: Span,
     pub object: Expression<'a>,
@@ -1405,7 +2015,17 @@ pub struct WithStatement<'a> {
 #[ast(visit)]
 #[scope]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct SwitchStatement<'a> {
     pub span: Span,
     pub discriminant: Expression<'a>,
@@ -1416,7 +2036,17 @@ pub struct SwitchStatement<'a> {
 
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct SwitchCase<'a> {
     pub span: Span,
     pub test: Option<Expression<'a>>,
@@ -1426,7 +2056,17 @@ pub struct SwitchCase<'a> {
 /// Labelled Statement
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct LabeledStatement<'a> {
     pub span: Span,
     pub label: LabelIdentifier<'a>,
@@ -1442,7 +2082,17 @@ pub struct LabeledStatement<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ThrowStatement<'a> {
     pub span: Span,
     /// The expression being thrown, e.g. `err` in `throw err;`
@@ -1466,7 +2116,17 @@ pub struct ThrowStatement<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct TryStatement<'a> {
     pub span: Span,
     /// Statements in the `try` block
@@ -1492,7 +2152,17 @@ pub struct TryStatement<'a> {
 #[ast(visit)]
 #[scope(flags = ScopeFlags::CatchClause)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct CatchClause<'a> {
     pub span: Span,
     /// The caught error parameter, e.g. `e` in `catch (e) {}`
@@ -1517,7 +2187,17 @@ pub struct CatchClause<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(no_type, via = CatchParameterConverter)]
 pub struct CatchParameter<'a> {
     #[estree(skip)]
@@ -1539,14 +2219,34 @@ pub struct CatchParameter<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct DebuggerStatement {
     pub span: Span,
 }
 
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    GetAddress,
+    ContentEq,
+    ContentHash,
+    ESTree
+)]
 pub enum BindingPattern<'a> {
     /// `x` in `const x = 1;`.
     ///
@@ -1600,7 +2300,17 @@ pub enum BindingPattern<'a> {
 /// See other examples in docs for [`BindingPattern`].
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(
     add_fields(decorators = TsEmptyArray, optional = TsFalse, typeAnnotation = TsNull),
     field_order(decorators, left, right, optional, typeAnnotation, span),
@@ -1616,7 +2326,17 @@ pub struct AssignmentPattern<'a> {
 /// See other examples in docs for [`BindingPattern`].
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(
     add_fields(decorators = TsEmptyArray, optional = TsFalse, typeAnnotation = TsNull),
     field_order(decorators, properties, optional, typeAnnotation, span),
@@ -1630,7 +2350,17 @@ pub struct ObjectPattern<'a> {
 
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(
     rename = "Property",
     add_fields(kind = Init, method = False, optional = TsFalse),
@@ -1649,7 +2379,17 @@ pub struct BindingProperty<'a> {
 /// See other examples in docs for [`BindingPattern`].
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(
     add_fields(decorators = TsEmptyArray, optional = TsFalse, typeAnnotation = TsNull),
     field_order(decorators, elements, optional, typeAnnotation, span),
@@ -1672,7 +2412,17 @@ pub struct ArrayPattern<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(
     rename = "RestElement",
     add_fields(decorators = TsEmptyArray, optional = TsFalse, typeAnnotation = TsNull, value = TsNull),
@@ -1726,7 +2476,17 @@ pub struct BindingRestElement<'a> {
     strict_if = self.has_use_strict_directive(),
 )]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 // https://github.com/estree/estree/blob/master/es5.md#patterns
 // https://github.com/DefinitelyTyped/DefinitelyTyped/blob/cd61c555bfc93e985b313263a42ed78074570d08/types/estree/index.d.ts#L411
 #[estree(
@@ -1809,7 +2569,7 @@ pub struct Function<'a> {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, Dummy, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, ContentEq, ContentHash, ESTree)]
 #[estree(no_rename_variants)]
 pub enum FunctionType {
     FunctionDeclaration = 0,
@@ -1822,7 +2582,17 @@ pub enum FunctionType {
 /// <https://tc39.es/ecma262/#prod-FormalParameters>
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(
     via = FormalParametersConverter,
     add_ts_def = "
@@ -1848,7 +2618,17 @@ pub struct FormalParameters<'a> {
 
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 // Pluralize as `FormalParameterList` to avoid naming clash with `FormalParameters`.
 #[plural(FormalParameterList)]
 #[estree(
@@ -1898,7 +2678,7 @@ pub struct FormalParameter<'a> {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, Dummy, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, ContentEq, ContentHash, ESTree)]
 #[estree(no_rename_variants, no_ts_def)]
 pub enum FormalParameterKind {
     /// <https://tc39.es/ecma262/#prod-FormalParameters>
@@ -1916,7 +2696,16 @@ pub enum FormalParameterKind {
 /// Wrapper around [`BindingRestElement`] with optional type annotation for TypeScript.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    UnstableAddress
+)]
 pub struct FormalParameterRest<'a> {
     pub span: Span,
     pub rest: BindingRestElement<'a>,
@@ -1926,7 +2715,17 @@ pub struct FormalParameterRest<'a> {
 /// <https://tc39.es/ecma262/#prod-FunctionBody>
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(rename = "BlockStatement")]
 pub struct FunctionBody<'a> {
     pub span: Span,
@@ -1943,7 +2742,17 @@ pub struct FunctionBody<'a> {
     strict_if = self.has_use_strict_directive(),
 )]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(add_fields(id = Null, generator = False))]
 pub struct ArrowFunctionExpression<'a> {
     pub span: Span,
@@ -1977,7 +2786,17 @@ pub struct ArrowFunctionExpression<'a> {
 /// Generator Function Definitions
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct YieldExpression<'a> {
     pub span: Span,
     pub delegate: bool,
@@ -1988,7 +2807,17 @@ pub struct YieldExpression<'a> {
 #[ast(visit)]
 #[scope(flags = ScopeFlags::StrictMode)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct Class<'a> {
     pub span: Span,
     pub r#type: ClassType,
@@ -2060,7 +2889,7 @@ pub struct Class<'a> {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, Dummy, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, ContentEq, ContentHash, ESTree)]
 #[estree(no_rename_variants)]
 pub enum ClassType {
     /// Class declaration statement
@@ -2078,7 +2907,17 @@ pub enum ClassType {
 
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ClassBody<'a> {
     pub span: Span,
     pub body: Vec<'a, ClassElement<'a>>,
@@ -2104,7 +2943,17 @@ pub struct ClassBody<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    GetAddress,
+    ContentEq,
+    ContentHash,
+    ESTree
+)]
 pub enum ClassElement<'a> {
     StaticBlock(Box<'a, StaticBlock<'a>>) = 0,
     /// Class Methods
@@ -2126,7 +2975,17 @@ pub enum ClassElement<'a> {
 
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct MethodDefinition<'a> {
     pub span: Span,
     /// Method definition type
@@ -2155,7 +3014,7 @@ pub struct MethodDefinition<'a> {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, Dummy, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, ContentEq, ContentHash, ESTree)]
 #[estree(no_rename_variants)]
 pub enum MethodDefinitionType {
     MethodDefinition = 0,
@@ -2164,7 +3023,17 @@ pub enum MethodDefinitionType {
 
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct PropertyDefinition<'a> {
     pub span: Span,
     pub r#type: PropertyDefinitionType,
@@ -2253,7 +3122,7 @@ pub struct PropertyDefinition<'a> {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, Dummy, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, ContentEq, ContentHash, ESTree)]
 #[estree(no_rename_variants)]
 pub enum PropertyDefinitionType {
     PropertyDefinition = 0,
@@ -2262,7 +3131,7 @@ pub enum PropertyDefinitionType {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, Dummy, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, ContentEq, ContentHash, ESTree)]
 pub enum MethodDefinitionKind {
     /// Class constructor
     Constructor = 0,
@@ -2279,7 +3148,17 @@ pub enum MethodDefinitionKind {
 /// See: [MDN - Private class fields](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Private_class_fields)
 #[ast(visit)]
 #[derive(Debug, Clone)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct PrivateIdentifier<'a> {
     pub span: Span,
     pub name: Atom<'a>,
@@ -2301,7 +3180,17 @@ pub struct PrivateIdentifier<'a> {
 #[ast(visit)]
 #[scope(flags = ScopeFlags::ClassStaticBlock)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct StaticBlock<'a> {
     pub span: Span,
     pub body: Vec<'a, Statement<'a>>,
@@ -2333,7 +3222,17 @@ pub struct StaticBlock<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    GetAddress,
+    ContentEq,
+    ContentHash,
+    ESTree
+)]
 pub enum ModuleDeclaration<'a> {
     /// `import hello from './world.js';`
     /// `import * as t from './world.js';`
@@ -2368,7 +3267,7 @@ pub use match_module_declaration;
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, Dummy, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, ContentEq, ContentHash, ESTree)]
 #[estree(no_rename_variants)]
 pub enum AccessorPropertyType {
     AccessorProperty = 0,
@@ -2385,7 +3284,17 @@ pub enum AccessorPropertyType {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(add_fields(declare = TsFalse, optional = TsFalse, readonly = TsFalse))]
 pub struct AccessorProperty<'a> {
     pub span: Span,
@@ -2433,7 +3342,17 @@ pub struct AccessorProperty<'a> {
 
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ImportExpression<'a> {
     pub span: Span,
     pub source: Expression<'a>,
@@ -2443,7 +3362,17 @@ pub struct ImportExpression<'a> {
 
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ImportDeclaration<'a> {
     pub span: Span,
     /// `None` for `import 'foo'`, `Some([])` for `import {} from 'foo'`
@@ -2466,7 +3395,7 @@ pub struct ImportDeclaration<'a> {
 /// <https://github.com/estree/estree/blob/2b48e56efc223ea477a45b5e034039934c5791fa/stage3/source-phase-imports.md>
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, Dummy, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, ContentEq, ContentHash, ESTree)]
 pub enum ImportPhase {
     Source = 0,
     Defer = 1,
@@ -2474,7 +3403,17 @@ pub enum ImportPhase {
 
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    GetAddress,
+    ContentEq,
+    ContentHash,
+    ESTree
+)]
 pub enum ImportDeclarationSpecifier<'a> {
     /// `import {imported} from "source";`
     /// `import {imported as local} from "source";`
@@ -2500,7 +3439,17 @@ pub enum ImportDeclarationSpecifier<'a> {
 /// and have the same `Span`.
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ImportSpecifier<'a> {
     pub span: Span,
     /// Imported symbol.
@@ -2524,7 +3473,17 @@ pub struct ImportSpecifier<'a> {
 ///
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ImportDefaultSpecifier<'a> {
     pub span: Span,
     /// The name of the imported symbol.
@@ -2539,7 +3498,17 @@ pub struct ImportDefaultSpecifier<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ImportNamespaceSpecifier<'a> {
     pub span: Span,
     pub local: BindingIdentifier<'a>,
@@ -2547,7 +3516,17 @@ pub struct ImportNamespaceSpecifier<'a> {
 
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(no_type, no_ts_def)]
 pub struct WithClause<'a> {
     #[estree(skip)]
@@ -2560,7 +3539,7 @@ pub struct WithClause<'a> {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, Dummy, ContentEq)]
+#[generate_derive(CloneIn, Dummy, ContentEq, ContentHash)]
 #[estree(no_ts_def)]
 pub enum WithClauseKeyword {
     With = 0,
@@ -2569,7 +3548,17 @@ pub enum WithClauseKeyword {
 
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ImportAttribute<'a> {
     pub span: Span,
     pub key: ImportAttributeKey<'a>,
@@ -2578,7 +3567,7 @@ pub struct ImportAttribute<'a> {
 
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ContentHash, ESTree)]
 pub enum ImportAttributeKey<'a> {
     Identifier(IdentifierName<'a>) = 0,
     StringLiteral(StringLiteral<'a>) = 1,
@@ -2597,7 +3586,17 @@ pub enum ImportAttributeKey<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ExportNamedDeclaration<'a> {
     pub span: Span,
     pub declaration: Option<Declaration<'a>>,
@@ -2622,7 +3621,17 @@ pub struct ExportNamedDeclaration<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(add_fields(exportKind = TsValue))]
 pub struct ExportDefaultDeclaration<'a> {
     pub span: Span,
@@ -2640,7 +3649,17 @@ pub struct ExportDefaultDeclaration<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ExportAllDeclaration<'a> {
     pub span: Span,
     /// If this declaration is re-named
@@ -2670,7 +3689,17 @@ pub struct ExportAllDeclaration<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct ExportSpecifier<'a> {
     pub span: Span,
     pub local: ModuleExportName<'a>,
@@ -2687,7 +3716,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ContentHash, ESTree)]
 pub enum ExportDefaultDeclarationKind<'a> {
     #[visit(args(flags = ScopeFlags::Function))]
     FunctionDeclaration(Box<'a, Function<'a>>) = 64,
@@ -2709,7 +3738,7 @@ pub enum ExportDefaultDeclarationKind<'a> {
 /// * <https://github.com/tc39/ecma262/pull/2154>
 #[ast(visit)]
 #[derive(Debug, Clone)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ContentHash, ESTree)]
 pub enum ModuleExportName<'a> {
     IdentifierName(IdentifierName<'a>) = 0,
     /// For `local` in `ExportSpecifier`: `foo` in `export { foo }`
@@ -2721,9 +3750,86 @@ pub enum ModuleExportName<'a> {
 /// See: [runtime.h](https://github.com/v8/v8/blob/5fe0aa3bc79c0a9d3ad546b79211f07105f09585/src/runtime/runtime.h#L43)
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct V8IntrinsicExpression<'a> {
     pub span: Span,
     pub name: IdentifierName<'a>,
     pub arguments: Vec<'a, Argument<'a>>,
 }
+
+/// `a |> f(%)` in `const b = a |> f(%);`
+///
+/// Hack-style pipeline, which evaluates `body` with the topic reference `%` bound to the value of
+/// `head`. Only parsed with the experimental `allow_pipeline_operator` parser option.
+/// See: [proposal-pipeline-operator](https://github.com/tc39/proposal-pipeline-operator)
+#[ast(visit)]
+#[derive(Debug)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
+pub struct PipelineExpression<'a> {
+    pub span: Span,
+    pub head: Expression<'a>,
+    pub body: Expression<'a>,
+}
+
+/// `%` in `a |> f(%)`
+///
+/// The value of the head of the enclosing [`PipelineExpression`].
+#[ast(visit)]
+#[derive(Debug)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
+pub struct TopicReference {
+    pub span: Span,
+}
+
+/// `do { ... }` in `let a = do { if (b) { 1 } else { 2 } };`
+///
+/// Evaluates to the completion value of its block. Only parsed with the experimental
+/// `allow_do_expressions` parser option.
+/// See: [proposal-do-expressions](https://github.com/tc39/proposal-do-expressions)
+#[ast(visit)]
+#[derive(Debug)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
+pub struct DoExpression<'a> {
+    pub span: Span,
+    pub body: Box<'a, BlockStatement<'a>>,
+}
@@ -7,7 +7,7 @@
 use oxc_allocator::{Box, CloneIn, Dummy, GetAddress, TakeIn, UnstableAddress, Vec};
 use oxc_ast_macros::ast;
 use oxc_estree::ESTree;
-use oxc_span::{Atom, ContentEq, GetSpan, GetSpanMut, Span};
+use oxc_span::{Atom, ContentEq, ContentHash, GetSpan, GetSpanMut, Span};
 
 use super::{inherit_variants, js::*, literal::*, ts::*};
 
@@ -32,7 +32,17 @@ use super::{inherit_variants, js::*, literal::*, ts::*};
 /// See: [JSX Syntax](https://facebook.github.io/jsx/)
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct JSXElement<'a> {
     /// Node location in source code
     pub span: Span,
@@ -64,7 +74,17 @@ pub struct JSXElement<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(add_fields(selfClosing = JSXOpeningElementSelfClosing))]
 pub struct JSXOpeningElement<'a> {
     /// Node location in source code
@@ -91,7 +111,17 @@ pub struct JSXOpeningElement<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct JSXClosingElement<'a> {
     /// Node location in source code
     pub span: Span,
@@ -109,7 +139,17 @@ pub struct JSXClosingElement<'a> {
 /// See: [`React.Fragment`](https://react.dev/reference/react/Fragment)
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct JSXFragment<'a> {
     /// Node location in source code
     pub span: Span,
@@ -124,7 +164,17 @@ pub struct JSXFragment<'a> {
 /// JSX Opening Fragment (`<>`)
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 #[estree(add_fields(attributes = JsEmptyArray, selfClosing = JsFalse))]
 pub struct JSXOpeningFragment {
     /// Node location in source code
@@ -134,7 +184,17 @@ pub struct JSXOpeningFragment {
 /// JSX Closing Fragment (`</>`)
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct JSXClosingFragment {
     /// Node location in source code
     pub span: Span,
@@ -143,7 +203,17 @@ pub struct JSXClosingFragment {
 /// JSX Element Name
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    GetAddress,
+    ContentEq,
+    ContentHash,
+    ESTree
+)]
 pub enum JSXElementName<'a> {
     /// `<div />`
     Identifier(Box<'a, JSXIdentifier<'a>>) = 0,
@@ -168,7 +238,17 @@ pub enum JSXElementName<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct JSXNamespacedName<'a> {
     /// Node location in source code
     pub span: Span,
@@ -195,7 +275,17 @@ pub struct JSXNamespacedName<'a> {
 /// [`member expression`]: JSXMemberExpressionObject::MemberExpression
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct JSXMemberExpression<'a> {
     /// Node location in source code
     pub span: Span,
@@ -223,7 +313,17 @@ pub struct JSXMemberExpression<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    GetAddress,
+    ContentEq,
+    ContentHash,
+    ESTree
+)]
 pub enum JSXMemberExpressionObject<'a> {
     /// `<Apple.Orange />`
     #[estree(via = JSXElementIdentifierReference)]
@@ -250,7 +350,17 @@ pub enum JSXMemberExpressionObject<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct JSXExpressionContainer<'a> {
     /// Node location in source code
     pub span: Span,
@@ -267,7 +377,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ContentHash, ESTree)]
 pub enum JSXExpression<'a> {
     /// An empty expression
     ///
@@ -285,7 +395,17 @@ pub enum JSXExpression<'a> {
 /// An empty JSX expression (`{}`)
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct JSXEmptyExpression {
     /// Node location in source code
     pub span: Span,
@@ -304,7 +424,17 @@ pub struct JSXEmptyExpression {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    GetAddress,
+    ContentEq,
+    ContentHash,
+    ESTree
+)]
 pub enum JSXAttributeItem<'a> {
     /// A `key="value"` attribute
     Attribute(Box<'a, JSXAttribute<'a>>) = 0,
@@ -325,7 +455,17 @@ pub enum JSXAttributeItem<'a> {
 /// //                 name ^^^ ^^^^ value
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct JSXAttribute<'a> {
     /// Node location in source code
     pub span: Span,
@@ -346,7 +486,17 @@ pub struct JSXAttribute<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct JSXSpreadAttribute<'a> {
     /// Node location in source code
     pub span: Span,
@@ -371,7 +521,17 @@ pub struct JSXSpreadAttribute<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    GetAddress,
+    ContentEq,
+    ContentHash,
+    ESTree
+)]
 pub enum JSXAttributeName<'a> {
     /// An attribute name without a namespace prefix, e.g. `foo` in `foo="bar"`.
     Identifier(Box<'a, JSXIdentifier<'a>>) = 0,
@@ -399,7 +559,17 @@ pub enum JSXAttributeName<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    GetAddress,
+    ContentEq,
+    ContentHash,
+    ESTree
+)]
 pub enum JSXAttributeValue<'a> {
     /// `<Component foo="bar" />`
     StringLiteral(Box<'a, StringLiteral<'a>>) = 0,
@@ -418,7 +588,17 @@ pub enum JSXAttributeValue<'a> {
 /// [`IdentifierName`]: super::IdentifierName
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct JSXIdentifier<'a> {
     /// Node location in source code
     pub span: Span,
@@ -434,7 +614,17 @@ pub struct JSXIdentifier<'a> {
 /// Part of a [`JSXElement`].
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    GetAddress,
+    ContentEq,
+    ContentHash,
+    ESTree
+)]
 pub enum JSXChild<'a> {
     /// `<Foo>Some Text</Foo>`
     Text(Box<'a, JSXText<'a>>) = 0,
@@ -453,7 +643,17 @@ pub enum JSXChild<'a> {
 /// Variant of [`JSXChild`] that represents an object spread (`{...expression}`).
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct JSXSpreadChild<'a> {
     /// Node location in source code
     pub span: Span,
@@ -473,7 +673,17 @@ pub struct JSXSpreadChild<'a> {
 /// ```
 #[ast(visit)]
 #[derive(Debug)]
-#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+#[generate_derive(
+    CloneIn,
+    Dummy,
+    TakeIn,
+    GetSpan,
+    GetSpanMut,
+    ContentEq,
+    ContentHash,
+    ESTree,
+    UnstableAddress
+)]
 pub struct JSXText<'a> {
     /// Node location in source code
     pub span: Span,
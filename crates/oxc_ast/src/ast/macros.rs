@@ -173,6 +173,12 @@ macro_rules! inherit_variants {
                 TSInstantiationExpression(Box<'a, TSInstantiationExpression<'a>>) = 38,
                 /// Inherited from [`Expression`]
                 V8IntrinsicExpression(Box<'a, V8IntrinsicExpression<'a>>) = 39,
+                /// Inherited from [`Expression`]
+                PipelineExpression(Box<'a, PipelineExpression<'a>>) = 40,
+                /// Inherited from [`Expression`]
+                TopicReference(Box<'a, TopicReference>) = 41,
+                /// Inherited from [`Expression`]
+                DoExpression(Box<'a, DoExpression<'a>>) = 42,
 
                 // Inherited from `MemberExpression`
                 @inherit MemberExpression
@@ -231,6 +237,9 @@ macro_rules! inherit_variants {
                 TSNonNullExpression,
                 TSInstantiationExpression,
                 V8IntrinsicExpression,
+                PipelineExpression,
+                TopicReference,
+                DoExpression,
                 ComputedMemberExpression,
                 StaticMemberExpression,
                 PrivateFieldExpression,
@@ -5,7 +5,7 @@ use std::{
 };
 
 use oxc_span::{Atom, GetSpan, Span};
-use oxc_syntax::{operator::UnaryOperator, scope::ScopeFlags};
+use oxc_syntax::{line_terminator::is_line_terminator, operator::UnaryOperator, scope::ScopeFlags};
 
 use crate::ast::*;
 
@@ -19,6 +19,29 @@ impl Program<'_> {
     pub fn has_use_strict_directive(&self) -> bool {
         self.directives.iter().any(Directive::is_use_strict)
     }
+
+    /// Returns `true` if the source text begins with a byte order mark (U+FEFF).
+    pub fn has_bom(&self) -> bool {
+        self.source_text.starts_with('\u{feff}')
+    }
+
+    /// Offset of the start of the code, after the byte order mark and the hashbang line.
+    ///
+    /// Text to add to the top of the file should be inserted at this offset, instead of at 0.
+    pub fn code_start(&self) -> u32 {
+        let Some(hashbang) = &self.hashbang else {
+            return if self.has_bom() { 3 } else { 0 };
+        };
+        let rest = &self.source_text[hashbang.span.end as usize..];
+        let line_terminator_len = match rest.chars().next() {
+            Some('\r') if rest.starts_with("\r\n") => 2,
+            Some(c) if is_line_terminator(c) => c.len_utf8(),
+            _ => 0,
+        };
+        #[expect(clippy::cast_possible_truncation)]
+        let code_start = hashbang.span.end + line_terminator_len as u32;
+        code_start
+    }
 }
 
 impl<'a> Expression<'a> {
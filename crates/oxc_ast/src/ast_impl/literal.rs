@@ -3,11 +3,12 @@
 use std::{
     borrow::Cow,
     fmt::{self, Display},
+    hash::Hasher,
 };
 
 use oxc_allocator::{Allocator, CloneIn, Dummy};
 use oxc_data_structures::inline_string::InlineString;
-use oxc_span::ContentEq;
+use oxc_span::{ContentEq, ContentHash};
 
 use crate::ast::*;
 
@@ -131,6 +132,23 @@ impl Display for BigIntLiteral<'_> {
 }
 
 impl<'a> RegExpLiteral<'a> {
+    /// The parsed pattern.
+    ///
+    /// Returns the pattern parsed by the parser when it was run with `parse_regular_expression`,
+    /// and only parses the pattern string otherwise. Parsed patterns are allocated in `allocator`.
+    ///
+    /// # Errors
+    /// Returns an error if the pattern is invalid.
+    pub fn pattern<'b>(
+        &'b self,
+        allocator: &'a Allocator,
+    ) -> oxc_diagnostics::Result<&'b oxc_regular_expression::ast::Pattern<'a>> {
+        match &self.regex.pattern.pattern {
+            Some(pattern) => Ok(pattern),
+            None => Ok(allocator.alloc(self.parse_pattern(allocator)?)),
+        }
+    }
+
     /// Parse the pattern string.
     ///
     /// # Errors
@@ -169,6 +187,12 @@ impl ContentEq for RegExpFlags {
     }
 }
 
+impl ContentHash for RegExpFlags {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u8(self.bits());
+    }
+}
+
 impl<'alloc> CloneIn<'alloc> for RegExpFlags {
     type Cloned = Self;
 
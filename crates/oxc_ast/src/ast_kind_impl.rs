@@ -283,6 +283,9 @@ impl<'a> AstKind<'a> {
             Expression::TSNonNullExpression(e) => Self::TSNonNullExpression(e),
             Expression::TSInstantiationExpression(e) => Self::TSInstantiationExpression(e),
             Expression::V8IntrinsicExpression(e) => Self::V8IntrinsicExpression(e),
+            Expression::PipelineExpression(e) => Self::PipelineExpression(e),
+            Expression::TopicReference(e) => Self::TopicReference(e),
+            Expression::DoExpression(e) => Self::DoExpression(e),
         }
     }
 
@@ -594,6 +597,9 @@ impl AstKind<'_> {
             Self::TSInterfaceBody(_) => "TSInterfaceBody".into(),
             Self::TSIndexSignature(_) => "TSIndexSignature".into(),
             Self::V8IntrinsicExpression(_) => "V8IntrinsicExpression".into(),
+            Self::PipelineExpression(_) => "PipelineExpression".into(),
+            Self::TopicReference(_) => "TopicReference".into(),
+            Self::DoExpression(_) => "DoExpression".into(),
 
             Self::JSDocNullableType(_) => "JSDocNullableType".into(),
             Self::JSDocNonNullableType(_) => "JSDocNonNullableType".into(),
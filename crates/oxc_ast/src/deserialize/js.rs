@@ -0,0 +1,1185 @@
+use serde_json::Value;
+
+use oxc_allocator::{Box, Vec};
+use oxc_span::{Atom, SPAN, Span};
+use oxc_syntax::operator::{
+    AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
+};
+
+use crate::{NONE, ast::*};
+
+use super::{
+    ESTreeDeserializer, Result, array_field, bool_field, expect_type, field, node_type,
+    optional_field,
+};
+
+const ASSIGNMENT_OPERATORS: [AssignmentOperator; 16] = [
+    AssignmentOperator::Assign,
+    AssignmentOperator::Addition,
+    AssignmentOperator::Subtraction,
+    AssignmentOperator::Multiplication,
+    AssignmentOperator::Division,
+    AssignmentOperator::Remainder,
+    AssignmentOperator::Exponential,
+    AssignmentOperator::ShiftLeft,
+    AssignmentOperator::ShiftRight,
+    AssignmentOperator::ShiftRightZeroFill,
+    AssignmentOperator::BitwiseOR,
+    AssignmentOperator::BitwiseXOR,
+    AssignmentOperator::BitwiseAnd,
+    AssignmentOperator::LogicalOr,
+    AssignmentOperator::LogicalAnd,
+    AssignmentOperator::LogicalNullish,
+];
+
+const BINARY_OPERATORS: [BinaryOperator; 22] = [
+    BinaryOperator::Equality,
+    BinaryOperator::Inequality,
+    BinaryOperator::StrictEquality,
+    BinaryOperator::StrictInequality,
+    BinaryOperator::LessThan,
+    BinaryOperator::LessEqualThan,
+    BinaryOperator::GreaterThan,
+    BinaryOperator::GreaterEqualThan,
+    BinaryOperator::Addition,
+    BinaryOperator::Subtraction,
+    BinaryOperator::Multiplication,
+    BinaryOperator::Division,
+    BinaryOperator::Remainder,
+    BinaryOperator::Exponential,
+    BinaryOperator::ShiftLeft,
+    BinaryOperator::ShiftRight,
+    BinaryOperator::ShiftRightZeroFill,
+    BinaryOperator::BitwiseOR,
+    BinaryOperator::BitwiseXOR,
+    BinaryOperator::BitwiseAnd,
+    BinaryOperator::In,
+    BinaryOperator::Instanceof,
+];
+
+const LOGICAL_OPERATORS: [LogicalOperator; 3] =
+    [LogicalOperator::Or, LogicalOperator::And, LogicalOperator::Coalesce];
+
+const UNARY_OPERATORS: [UnaryOperator; 7] = [
+    UnaryOperator::UnaryPlus,
+    UnaryOperator::UnaryNegation,
+    UnaryOperator::LogicalNot,
+    UnaryOperator::BitwiseNot,
+    UnaryOperator::Typeof,
+    UnaryOperator::Void,
+    UnaryOperator::Delete,
+];
+
+const UPDATE_OPERATORS: [UpdateOperator; 2] =
+    [UpdateOperator::Increment, UpdateOperator::Decrement];
+
+impl<'a> ESTreeDeserializer<'a> {
+    /// Body of a program or function, with its leading directives split from its statements.
+    pub fn body(
+        &self,
+        nodes: &[Value],
+    ) -> Result<(Vec<'a, Directive<'a>>, Vec<'a, Statement<'a>>)> {
+        let directive_count = nodes
+            .iter()
+            .take_while(|node| {
+                node_type(node) == Some("ExpressionStatement")
+                    && node.get("directive").is_some_and(Value::is_string)
+            })
+            .count();
+        let (directives, statements) = nodes.split_at(directive_count);
+        let directives = self.vec(directives, |this, node| {
+            let expression = this.string_literal(field(node, "expression")?)?;
+            let directive = this.str_field(node, "directive")?;
+            Ok(this.ast.directive(this.span(node), expression, directive))
+        })?;
+        Ok((directives, self.statements(statements)?))
+    }
+
+    fn statements(&self, nodes: &[Value]) -> Result<Vec<'a, Statement<'a>>> {
+        self.vec(nodes, Self::statement)
+    }
+
+    fn statement(&self, node: &Value) -> Result<Statement<'a>> {
+        let span = self.span(node);
+        let statement = match node_type(node) {
+            Some("ExpressionStatement") => {
+                self.ast.statement_expression(span, self.expression(field(node, "expression")?)?)
+            }
+            Some("BlockStatement") => {
+                self.ast.statement_block(span, self.statements(array_field(node, "body")?)?)
+            }
+            Some("EmptyStatement") => self.ast.statement_empty(span),
+            Some("DebuggerStatement") => self.ast.statement_debugger(span),
+            Some("WithStatement") => self.ast.statement_with(
+                span,
+                self.expression(field(node, "object")?)?,
+                self.statement(field(node, "body")?)?,
+            ),
+            Some("ReturnStatement") => {
+                self.ast.statement_return(span, self.optional_expression(node, "argument")?)
+            }
+            Some("LabeledStatement") => self.ast.statement_labeled(
+                span,
+                self.label_identifier(field(node, "label")?)?,
+                self.statement(field(node, "body")?)?,
+            ),
+            Some("BreakStatement") => self.ast.statement_break(span, self.optional_label(node)?),
+            Some("ContinueStatement") => {
+                self.ast.statement_continue(span, self.optional_label(node)?)
+            }
+            Some("IfStatement") => self.ast.statement_if(
+                span,
+                self.expression(field(node, "test")?)?,
+                self.statement(field(node, "consequent")?)?,
+                optional_field(node, "alternate").map(|node| self.statement(node)).transpose()?,
+            ),
+            Some("SwitchStatement") => self.ast.statement_switch(
+                span,
+                self.expression(field(node, "discriminant")?)?,
+                self.vec(array_field(node, "cases")?, |this, case| {
+                    expect_type(case, "SwitchCase")?;
+                    Ok(this.ast.switch_case(
+                        this.span(case),
+                        this.optional_expression(case, "test")?,
+                        this.statements(array_field(case, "consequent")?)?,
+                    ))
+                })?,
+            ),
+            Some("ThrowStatement") => {
+                self.ast.statement_throw(span, self.expression(field(node, "argument")?)?)
+            }
+            Some("TryStatement") => {
+                let handler = optional_field(node, "handler")
+                    .map(|handler| {
+                        expect_type(handler, "CatchClause")?;
+                        let param = optional_field(handler, "param")
+                            .map(|param| {
+                                Ok(self.ast.catch_parameter(
+                                    self.span(param),
+                                    self.binding_pattern(param)?,
+                                    NONE,
+                                ))
+                            })
+                            .transpose()?;
+                        let body = self.block_statement(field(handler, "body")?)?;
+                        Ok(self.ast.alloc_catch_clause(self.span(handler), param, body))
+                    })
+                    .transpose()?;
+                let finalizer = optional_field(node, "finalizer")
+                    .map(|finalizer| self.block_statement(finalizer))
+                    .transpose()?;
+                self.ast.statement_try(
+                    span,
+                    self.block_statement(field(node, "block")?)?,
+                    handler,
+                    finalizer,
+                )
+            }
+            Some("WhileStatement") => self.ast.statement_while(
+                span,
+                self.expression(field(node, "test")?)?,
+                self.statement(field(node, "body")?)?,
+            ),
+            Some("DoWhileStatement") => self.ast.statement_do_while(
+                span,
+                self.statement(field(node, "body")?)?,
+                self.expression(field(node, "test")?)?,
+            ),
+            Some("ForStatement") => {
+                let init = optional_field(node, "init")
+                    .map(|init| {
+                        Ok(if node_type(init) == Some("VariableDeclaration") {
+                            ForStatementInit::VariableDeclaration(self.variable_declaration(init)?)
+                        } else {
+                            ForStatementInit::from(self.expression(init)?)
+                        })
+                    })
+                    .transpose()?;
+                self.ast.statement_for(
+                    span,
+                    init,
+                    self.optional_expression(node, "test")?,
+                    self.optional_expression(node, "update")?,
+                    self.statement(field(node, "body")?)?,
+                )
+            }
+            Some("ForInStatement") => self.ast.statement_for_in(
+                span,
+                self.for_statement_left(field(node, "left")?)?,
+                self.expression(field(node, "right")?)?,
+                self.statement(field(node, "body")?)?,
+            ),
+            Some("ForOfStatement") => self.ast.statement_for_of(
+                span,
+                bool_field(node, "await"),
+                self.for_statement_left(field(node, "left")?)?,
+                self.expression(field(node, "right")?)?,
+                self.statement(field(node, "body")?)?,
+            ),
+            Some("VariableDeclaration" | "FunctionDeclaration" | "ClassDeclaration") => {
+                Statement::from(self.declaration(node)?)
+            }
+            Some("ImportDeclaration") => Statement::from(self.import_declaration(node)?),
+            Some("ExportNamedDeclaration") => Statement::from(self.export_named_declaration(node)?),
+            Some("ExportDefaultDeclaration") => {
+                Statement::from(self.export_default_declaration(node)?)
+            }
+            Some("ExportAllDeclaration") => Statement::from(self.export_all_declaration(node)?),
+            _ => return Err(self.unsupported(node, "a statement")),
+        };
+        Ok(statement)
+    }
+
+    fn block_statement(&self, node: &Value) -> Result<Box<'a, BlockStatement<'a>>> {
+        expect_type(node, "BlockStatement")?;
+        let body = self.statements(array_field(node, "body")?)?;
+        Ok(self.ast.alloc_block_statement(self.span(node), body))
+    }
+
+    fn optional_label(&self, node: &Value) -> Result<Option<LabelIdentifier<'a>>> {
+        optional_field(node, "label").map(|label| self.label_identifier(label)).transpose()
+    }
+
+    fn declaration(&self, node: &Value) -> Result<Declaration<'a>> {
+        let declaration = match node_type(node) {
+            Some("VariableDeclaration") => {
+                Declaration::VariableDeclaration(self.variable_declaration(node)?)
+            }
+            Some("FunctionDeclaration") => Declaration::FunctionDeclaration(self.function(
+                node,
+                FunctionType::FunctionDeclaration,
+                FormalParameterKind::FormalParameter,
+            )?),
+            Some("ClassDeclaration") => {
+                Declaration::ClassDeclaration(self.class(node, ClassType::ClassDeclaration)?)
+            }
+            _ => return Err(self.unsupported(node, "a declaration")),
+        };
+        Ok(declaration)
+    }
+
+    fn variable_declaration(&self, node: &Value) -> Result<Box<'a, VariableDeclaration<'a>>> {
+        let kind = match node.get("kind").and_then(Value::as_str) {
+            Some("var") => VariableDeclarationKind::Var,
+            Some("let") => VariableDeclarationKind::Let,
+            Some("const") => VariableDeclarationKind::Const,
+            Some("using") => VariableDeclarationKind::Using,
+            Some("await using") => VariableDeclarationKind::AwaitUsing,
+            _ => return Err(self.invalid(node, "unknown `kind`")),
+        };
+        let declarations = self.vec(array_field(node, "declarations")?, |this, declarator| {
+            expect_type(declarator, "VariableDeclarator")?;
+            Ok(this.ast.variable_declarator(
+                this.span(declarator),
+                kind,
+                this.binding_pattern(field(declarator, "id")?)?,
+                NONE,
+                this.optional_expression(declarator, "init")?,
+                false,
+            ))
+        })?;
+        Ok(self.ast.alloc_variable_declaration(self.span(node), kind, declarations, false))
+    }
+
+    fn for_statement_left(&self, node: &Value) -> Result<ForStatementLeft<'a>> {
+        if node_type(node) == Some("VariableDeclaration") {
+            Ok(ForStatementLeft::VariableDeclaration(self.variable_declaration(node)?))
+        } else {
+            Ok(ForStatementLeft::from(self.assignment_target(node)?))
+        }
+    }
+
+    fn expression(&self, node: &Value) -> Result<Expression<'a>> {
+        let span = self.span(node);
+        let expression = match node_type(node) {
+            Some("Identifier") => self.ast.expression_identifier(span, self.identifier(node)?),
+            Some("Literal") => self.literal(node)?,
+            Some("TemplateLiteral") => {
+                Expression::TemplateLiteral(self.ast.alloc(self.template_literal(node)?))
+            }
+            Some("TaggedTemplateExpression") => self.ast.expression_tagged_template(
+                span,
+                self.expression(field(node, "tag")?)?,
+                NONE,
+                self.template_literal(field(node, "quasi")?)?,
+            ),
+            Some("ThisExpression") => self.ast.expression_this(span),
+            Some("Super") => self.ast.expression_super(span),
+            Some("ArrayExpression") => {
+                let elements = self.vec(array_field(node, "elements")?, |this, element| {
+                    Ok(match node_type(element) {
+                        _ if element.is_null() => this.ast.array_expression_element_elision(SPAN),
+                        Some("SpreadElement") => this.ast.array_expression_element_spread_element(
+                            this.span(element),
+                            this.expression(field(element, "argument")?)?,
+                        ),
+                        _ => ArrayExpressionElement::from(this.expression(element)?),
+                    })
+                })?;
+                self.ast.expression_array(span, elements)
+            }
+            Some("ObjectExpression") => {
+                let properties =
+                    self.vec(array_field(node, "properties")?, Self::object_property)?;
+                self.ast.expression_object(span, properties)
+            }
+            Some("FunctionExpression") => Expression::FunctionExpression(self.function(
+                node,
+                FunctionType::FunctionExpression,
+                FormalParameterKind::FormalParameter,
+            )?),
+            Some("ArrowFunctionExpression") => {
+                let expression = bool_field(node, "expression");
+                let params =
+                    self.formal_parameters(node, FormalParameterKind::ArrowFormalParameters)?;
+                let body = field(node, "body")?;
+                let body = if expression {
+                    let expression = self.expression(body)?;
+                    let statement = self.ast.statement_expression(self.span(body), expression);
+                    self.ast.alloc_function_body(
+                        self.span(body),
+                        self.ast.vec(),
+                        self.ast.vec1(statement),
+                    )
+                } else {
+                    self.function_body(body)?
+                };
+                self.ast.expression_arrow_function(
+                    span,
+                    expression,
+                    bool_field(node, "async"),
+                    NONE,
+                    params,
+                    NONE,
+                    body,
+                )
+            }
+            Some("ClassExpression") => {
+                Expression::ClassExpression(self.class(node, ClassType::ClassExpression)?)
+            }
+            Some("UnaryExpression") => self.ast.expression_unary(
+                span,
+                self.operator(node, &UNARY_OPERATORS, UnaryOperator::as_str)?,
+                self.expression(field(node, "argument")?)?,
+            ),
+            Some("UpdateExpression") => self.ast.expression_update(
+                span,
+                self.operator(node, &UPDATE_OPERATORS, UpdateOperator::as_str)?,
+                bool_field(node, "prefix"),
+                self.simple_assignment_target(field(node, "argument")?)?,
+            ),
+            Some("BinaryExpression") => {
+                let left = field(node, "left")?;
+                let operator = self.operator(node, &BINARY_OPERATORS, BinaryOperator::as_str)?;
+                let right = self.expression(field(node, "right")?)?;
+                if node_type(left) == Some("PrivateIdentifier") && operator == BinaryOperator::In {
+                    self.ast.expression_private_in(span, self.private_identifier(left)?, right)
+                } else {
+                    self.ast.expression_binary(span, self.expression(left)?, operator, right)
+                }
+            }
+            Some("LogicalExpression") => self.ast.expression_logical(
+                span,
+                self.expression(field(node, "left")?)?,
+                self.operator(node, &LOGICAL_OPERATORS, LogicalOperator::as_str)?,
+                self.expression(field(node, "right")?)?,
+            ),
+            Some("AssignmentExpression") => self.ast.expression_assignment(
+                span,
+                self.operator(node, &ASSIGNMENT_OPERATORS, AssignmentOperator::as_str)?,
+                self.assignment_target(field(node, "left")?)?,
+                self.expression(field(node, "right")?)?,
+            ),
+            Some("ConditionalExpression") => self.ast.expression_conditional(
+                span,
+                self.expression(field(node, "test")?)?,
+                self.expression(field(node, "consequent")?)?,
+                self.expression(field(node, "alternate")?)?,
+            ),
+            Some("CallExpression") => self.ast.expression_call(
+                span,
+                self.expression(field(node, "callee")?)?,
+                NONE,
+                self.arguments(node)?,
+                bool_field(node, "optional"),
+            ),
+            Some("NewExpression") => self.ast.expression_new(
+                span,
+                self.expression(field(node, "callee")?)?,
+                NONE,
+                self.arguments(node)?,
+            ),
+            Some("MemberExpression") => Expression::from(self.member_expression(node)?),
+            Some("ChainExpression") => {
+                let expression = field(node, "expression")?;
+                let element = match node_type(expression) {
+                    Some("CallExpression") => self.ast.chain_element_call_expression(
+                        self.span(expression),
+                        self.expression(field(expression, "callee")?)?,
+                        NONE,
+                        self.arguments(expression)?,
+                        bool_field(expression, "optional"),
+                    ),
+                    Some("MemberExpression") => {
+                        ChainElement::from(self.member_expression(expression)?)
+                    }
+                    _ => return Err(self.unsupported(expression, "a call or member expression")),
+                };
+                self.ast.expression_chain(span, element)
+            }
+            Some("SequenceExpression") => self.ast.expression_sequence(
+                span,
+                self.vec(array_field(node, "expressions")?, Self::expression)?,
+            ),
+            Some("AwaitExpression") => {
+                self.ast.expression_await(span, self.expression(field(node, "argument")?)?)
+            }
+            Some("YieldExpression") => self.ast.expression_yield(
+                span,
+                bool_field(node, "delegate"),
+                self.optional_expression(node, "argument")?,
+            ),
+            Some("MetaProperty") => self.ast.expression_meta_property(
+                span,
+                self.identifier_name(field(node, "meta")?)?,
+                self.identifier_name(field(node, "property")?)?,
+            ),
+            Some("ImportExpression") => {
+                let phase = match node.get("phase").and_then(Value::as_str) {
+                    Some("source") => Some(ImportPhase::Source),
+                    Some("defer") => Some(ImportPhase::Defer),
+                    _ => None,
+                };
+                self.ast.expression_import(
+                    span,
+                    self.expression(field(node, "source")?)?,
+                    self.optional_expression(node, "options")?,
+                    phase,
+                )
+            }
+            Some("ParenthesizedExpression") => self
+                .ast
+                .expression_parenthesized(span, self.expression(field(node, "expression")?)?),
+            _ => return Err(self.unsupported(node, "an expression")),
+        };
+        Ok(expression)
+    }
+
+    fn optional_expression(&self, node: &Value, key: &str) -> Result<Option<Expression<'a>>> {
+        optional_field(node, key).map(|node| self.expression(node)).transpose()
+    }
+
+    /// Operator of `node`, one of `operators`.
+    fn operator<T: Copy>(
+        &self,
+        node: &Value,
+        operators: &[T],
+        as_str: fn(T) -> &'static str,
+    ) -> Result<T> {
+        let operator = node.get("operator").and_then(Value::as_str);
+        operators
+            .iter()
+            .copied()
+            .find(|&candidate| Some(as_str(candidate)) == operator)
+            .ok_or_else(|| self.invalid(node, "unknown `operator`"))
+    }
+
+    fn literal(&self, node: &Value) -> Result<Expression<'a>> {
+        let span = self.span(node);
+        let raw = node.get("raw").and_then(Value::as_str);
+        let raw_atom = raw.map(|raw| Atom::from(self.ast.str(raw)));
+
+        if let Some(regex) = optional_field(node, "regex") {
+            let pattern = self.str_field(regex, "pattern")?;
+            let mut flags = RegExpFlags::empty();
+            for flag in self.str_field(regex, "flags")?.chars() {
+                flags |= RegExpFlags::try_from(flag)
+                    .map_err(|_| self.invalid(node, "unknown regular expression flag"))?;
+            }
+            let regex = RegExp {
+                pattern: RegExpPattern { text: Atom::from(pattern), pattern: None },
+                flags,
+            };
+            return Ok(self.ast.expression_reg_exp_literal(span, regex, raw_atom));
+        }
+
+        if let Some(bigint) = optional_field(node, "bigint") {
+            let text =
+                bigint.as_str().ok_or_else(|| self.invalid(node, "`bigint` must be a string"))?;
+            let base = bigint_base(raw.unwrap_or(text));
+            let (digits, radix) = match bigint_base(text) {
+                BigintBase::Decimal => (text, 10),
+                BigintBase::Binary => (&text[2..], 2),
+                BigintBase::Octal => (&text[2..], 8),
+                BigintBase::Hex => (&text[2..], 16),
+            };
+            let value = bigint_to_decimal(digits, radix)
+                .ok_or_else(|| self.invalid(node, "invalid `bigint`"))?;
+            return Ok(self.ast.expression_big_int_literal(
+                span,
+                self.ast.str(&value),
+                raw_atom,
+                base,
+            ));
+        }
+
+        let expression = match field(node, "value") {
+            Err(_) | Ok(Value::Null) => self.ast.expression_null_literal(span),
+            Ok(Value::Bool(value)) => self.ast.expression_boolean_literal(span, *value),
+            Ok(Value::Number(number)) => {
+                let value = number.as_f64().ok_or_else(|| self.invalid(node, "invalid `value`"))?;
+                self.ast.expression_numeric_literal(span, value, raw_atom, number_base(raw, value))
+            }
+            Ok(Value::String(value)) => {
+                self.ast.expression_string_literal(span, self.ast.str(value), raw_atom)
+            }
+            Ok(_) => return Err(self.invalid(node, "unknown `value`")),
+        };
+        Ok(expression)
+    }
+
+    fn string_literal(&self, node: &Value) -> Result<StringLiteral<'a>> {
+        expect_type(node, "Literal")?;
+        let value = self.str_field(node, "value")?;
+        let raw = node.get("raw").and_then(Value::as_str).map(|raw| Atom::from(self.ast.str(raw)));
+        Ok(self.ast.string_literal(self.span(node), value, raw))
+    }
+
+    fn template_literal(&self, node: &Value) -> Result<TemplateLiteral<'a>> {
+        expect_type(node, "TemplateLiteral")?;
+        let quasis = self.vec(array_field(node, "quasis")?, |this, quasi| {
+            expect_type(quasi, "TemplateElement")?;
+            let value = field(quasi, "value")?;
+            let value = TemplateElementValue {
+                raw: Atom::from(this.str_field(value, "raw")?),
+                cooked: value
+                    .get("cooked")
+                    .and_then(Value::as_str)
+                    .map(|cooked| Atom::from(this.ast.str(cooked))),
+            };
+            Ok(this.ast.template_element(this.span(quasi), value, bool_field(quasi, "tail")))
+        })?;
+        let expressions = self.vec(array_field(node, "expressions")?, Self::expression)?;
+        Ok(self.ast.template_literal(self.span(node), quasis, expressions))
+    }
+
+    fn object_property(&self, node: &Value) -> Result<ObjectPropertyKind<'a>> {
+        let span = self.span(node);
+        match node_type(node) {
+            Some("Property") => {
+                let kind = match node.get("kind").and_then(Value::as_str) {
+                    Some("init") => PropertyKind::Init,
+                    Some("get") => PropertyKind::Get,
+                    Some("set") => PropertyKind::Set,
+                    _ => return Err(self.invalid(node, "unknown `kind`")),
+                };
+                let computed = bool_field(node, "computed");
+                Ok(self.ast.object_property_kind_object_property(
+                    span,
+                    kind,
+                    self.property_key(field(node, "key")?, computed)?,
+                    self.expression(field(node, "value")?)?,
+                    bool_field(node, "method"),
+                    bool_field(node, "shorthand"),
+                    computed,
+                ))
+            }
+            Some("SpreadElement") => Ok(self.ast.object_property_kind_spread_property(
+                span,
+                self.expression(field(node, "argument")?)?,
+            )),
+            _ => Err(self.unsupported(node, "a property")),
+        }
+    }
+
+    fn property_key(&self, node: &Value, computed: bool) -> Result<PropertyKey<'a>> {
+        match node_type(node) {
+            Some("Identifier") if !computed => {
+                Ok(self.ast.property_key_static_identifier(self.span(node), self.identifier(node)?))
+            }
+            Some("PrivateIdentifier") => Ok(self
+                .ast
+                .property_key_private_identifier(self.span(node), self.str_field(node, "name")?)),
+            _ => Ok(PropertyKey::from(self.expression(node)?)),
+        }
+    }
+
+    fn arguments(&self, node: &Value) -> Result<Vec<'a, Argument<'a>>> {
+        self.vec(array_field(node, "arguments")?, |this, argument| {
+            if node_type(argument) == Some("SpreadElement") {
+                Ok(this.ast.argument_spread_element(
+                    this.span(argument),
+                    this.expression(field(argument, "argument")?)?,
+                ))
+            } else {
+                Ok(Argument::from(this.expression(argument)?))
+            }
+        })
+    }
+
+    fn member_expression(&self, node: &Value) -> Result<MemberExpression<'a>> {
+        expect_type(node, "MemberExpression")?;
+        let span = self.span(node);
+        let object = self.expression(field(node, "object")?)?;
+        let property = field(node, "property")?;
+        let optional = bool_field(node, "optional");
+        Ok(if bool_field(node, "computed") {
+            self.ast.member_expression_computed(span, object, self.expression(property)?, optional)
+        } else if node_type(property) == Some("PrivateIdentifier") {
+            self.ast.member_expression_private_field_expression(
+                span,
+                object,
+                self.private_identifier(property)?,
+                optional,
+            )
+        } else {
+            self.ast.member_expression_static(
+                span,
+                object,
+                self.identifier_name(property)?,
+                optional,
+            )
+        })
+    }
+
+    /// Name of an `Identifier` node.
+    fn identifier(&self, node: &Value) -> Result<&'a str> {
+        if node_type(node) != Some("Identifier") {
+            return Err(self.unsupported(node, "an identifier"));
+        }
+        self.str_field(node, "name")
+    }
+
+    fn identifier_name(&self, node: &Value) -> Result<IdentifierName<'a>> {
+        Ok(self.ast.identifier_name(self.span(node), self.identifier(node)?))
+    }
+
+    fn identifier_reference(&self, node: &Value) -> Result<IdentifierReference<'a>> {
+        Ok(self.ast.identifier_reference(self.span(node), self.identifier(node)?))
+    }
+
+    fn binding_identifier(&self, node: &Value) -> Result<BindingIdentifier<'a>> {
+        Ok(self.ast.binding_identifier(self.span(node), self.identifier(node)?))
+    }
+
+    fn label_identifier(&self, node: &Value) -> Result<LabelIdentifier<'a>> {
+        Ok(self.ast.label_identifier(self.span(node), self.identifier(node)?))
+    }
+
+    fn private_identifier(&self, node: &Value) -> Result<PrivateIdentifier<'a>> {
+        expect_type(node, "PrivateIdentifier")?;
+        Ok(self.ast.private_identifier(self.span(node), self.str_field(node, "name")?))
+    }
+
+    fn binding_pattern(&self, node: &Value) -> Result<BindingPattern<'a>> {
+        let span = self.span(node);
+        let pattern = match node_type(node) {
+            Some("Identifier") => {
+                self.ast.binding_pattern_binding_identifier(span, self.identifier(node)?)
+            }
+            Some("ObjectPattern") => {
+                let mut properties = self.ast.vec();
+                let mut rest = None;
+                for property in array_field(node, "properties")? {
+                    match node_type(property) {
+                        Some("RestElement") => rest = Some(self.binding_rest_element(property)?),
+                        Some("Property") => {
+                            let computed = bool_field(property, "computed");
+                            properties.push(self.ast.binding_property(
+                                self.span(property),
+                                self.property_key(field(property, "key")?, computed)?,
+                                self.binding_pattern(field(property, "value")?)?,
+                                bool_field(property, "shorthand"),
+                                computed,
+                            ));
+                        }
+                        _ => return Err(self.unsupported(property, "a property")),
+                    }
+                }
+                self.ast.binding_pattern_object_pattern(span, properties, rest)
+            }
+            Some("ArrayPattern") => {
+                let mut elements = self.ast.vec();
+                let mut rest = None;
+                for element in array_field(node, "elements")? {
+                    match node_type(element) {
+                        _ if element.is_null() => elements.push(None),
+                        Some("RestElement") => rest = Some(self.binding_rest_element(element)?),
+                        _ => elements.push(Some(self.binding_pattern(element)?)),
+                    }
+                }
+                self.ast.binding_pattern_array_pattern(span, elements, rest)
+            }
+            Some("AssignmentPattern") => self.ast.binding_pattern_assignment_pattern(
+                span,
+                self.binding_pattern(field(node, "left")?)?,
+                self.expression(field(node, "right")?)?,
+            ),
+            _ => return Err(self.unsupported(node, "a binding pattern")),
+        };
+        Ok(pattern)
+    }
+
+    fn binding_rest_element(&self, node: &Value) -> Result<Box<'a, BindingRestElement<'a>>> {
+        let argument = self.binding_pattern(field(node, "argument")?)?;
+        Ok(self.ast.alloc_binding_rest_element(self.span(node), argument))
+    }
+
+    fn assignment_target(&self, node: &Value) -> Result<AssignmentTarget<'a>> {
+        let span = self.span(node);
+        let target = match node_type(node) {
+            Some("ObjectPattern") => {
+                let mut properties = self.ast.vec();
+                let mut rest = None;
+                for property in array_field(node, "properties")? {
+                    match node_type(property) {
+                        Some("RestElement") => rest = Some(self.assignment_target_rest(property)?),
+                        Some("Property") => {
+                            properties.push(self.assignment_target_property(property)?);
+                        }
+                        _ => return Err(self.unsupported(property, "a property")),
+                    }
+                }
+                AssignmentTarget::from(
+                    self.ast
+                        .assignment_target_pattern_object_assignment_target(span, properties, rest),
+                )
+            }
+            Some("ArrayPattern") => {
+                let mut elements = self.ast.vec();
+                let mut rest = None;
+                for element in array_field(node, "elements")? {
+                    match node_type(element) {
+                        _ if element.is_null() => elements.push(None),
+                        Some("RestElement") => rest = Some(self.assignment_target_rest(element)?),
+                        _ => elements.push(Some(self.assignment_target_maybe_default(element)?)),
+                    }
+                }
+                AssignmentTarget::from(
+                    self.ast
+                        .assignment_target_pattern_array_assignment_target(span, elements, rest),
+                )
+            }
+            _ => AssignmentTarget::from(self.simple_assignment_target(node)?),
+        };
+        Ok(target)
+    }
+
+    fn assignment_target_property(&self, node: &Value) -> Result<AssignmentTargetProperty<'a>> {
+        let span = self.span(node);
+        let key = field(node, "key")?;
+        let value = field(node, "value")?;
+        if bool_field(node, "shorthand") && node_type(key) == Some("Identifier") {
+            let init = if node_type(value) == Some("AssignmentPattern") {
+                Some(self.expression(field(value, "right")?)?)
+            } else {
+                None
+            };
+            Ok(self.ast.assignment_target_property_assignment_target_property_identifier(
+                span,
+                self.identifier_reference(key)?,
+                init,
+            ))
+        } else {
+            let computed = bool_field(node, "computed");
+            Ok(self.ast.assignment_target_property_assignment_target_property_property(
+                span,
+                self.property_key(key, computed)?,
+                self.assignment_target_maybe_default(value)?,
+                computed,
+            ))
+        }
+    }
+
+    fn assignment_target_maybe_default(
+        &self,
+        node: &Value,
+    ) -> Result<AssignmentTargetMaybeDefault<'a>> {
+        if node_type(node) == Some("AssignmentPattern") {
+            Ok(self.ast.assignment_target_maybe_default_assignment_target_with_default(
+                self.span(node),
+                self.assignment_target(field(node, "left")?)?,
+                self.expression(field(node, "right")?)?,
+            ))
+        } else {
+            Ok(AssignmentTargetMaybeDefault::from(self.assignment_target(node)?))
+        }
+    }
+
+    fn assignment_target_rest(&self, node: &Value) -> Result<Box<'a, AssignmentTargetRest<'a>>> {
+        let target = self.assignment_target(field(node, "argument")?)?;
+        Ok(self.ast.alloc_assignment_target_rest(self.span(node), target))
+    }
+
+    fn simple_assignment_target(&self, node: &Value) -> Result<SimpleAssignmentTarget<'a>> {
+        match node_type(node) {
+            Some("Identifier") => {
+                Ok(self.ast.simple_assignment_target_assignment_target_identifier(
+                    self.span(node),
+                    self.identifier(node)?,
+                ))
+            }
+            Some("MemberExpression") => {
+                Ok(SimpleAssignmentTarget::from(self.member_expression(node)?))
+            }
+            _ => Err(self.unsupported(node, "an assignment target")),
+        }
+    }
+
+    fn function(
+        &self,
+        node: &Value,
+        r#type: FunctionType,
+        params_kind: FormalParameterKind,
+    ) -> Result<Box<'a, Function<'a>>> {
+        let id = optional_field(node, "id").map(|id| self.binding_identifier(id)).transpose()?;
+        let params = self.formal_parameters(node, params_kind)?;
+        let body = self.function_body(field(node, "body")?)?;
+        Ok(self.ast.alloc_function(
+            self.span(node),
+            r#type,
+            id,
+            bool_field(node, "generator"),
+            bool_field(node, "async"),
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+        ))
+    }
+
+    fn function_body(&self, node: &Value) -> Result<Box<'a, FunctionBody<'a>>> {
+        expect_type(node, "BlockStatement")?;
+        let (directives, statements) = self.body(array_field(node, "body")?)?;
+        Ok(self.ast.alloc_function_body(self.span(node), directives, statements))
+    }
+
+    /// `params` of a function node.
+    fn formal_parameters(
+        &self,
+        node: &Value,
+        kind: FormalParameterKind,
+    ) -> Result<Box<'a, FormalParameters<'a>>> {
+        let mut items = self.ast.vec();
+        let mut rest = None;
+        for param in array_field(node, "params")? {
+            let span = self.span(param);
+            match node_type(param) {
+                Some("RestElement") => {
+                    let element = self.binding_rest_element(param)?.unbox();
+                    rest = Some(self.ast.alloc_formal_parameter_rest(span, element, NONE));
+                }
+                Some("AssignmentPattern") => {
+                    let pattern = self.binding_pattern(field(param, "left")?)?;
+                    let initializer = self.ast.alloc(self.expression(field(param, "right")?)?);
+                    items.push(self.formal_parameter(span, pattern, Some(initializer)));
+                }
+                _ => items.push(self.formal_parameter(span, self.binding_pattern(param)?, None)),
+            }
+        }
+        Ok(self.ast.alloc_formal_parameters(SPAN, kind, items, rest))
+    }
+
+    fn formal_parameter(
+        &self,
+        span: Span,
+        pattern: BindingPattern<'a>,
+        initializer: Option<Box<'a, Expression<'a>>>,
+    ) -> FormalParameter<'a> {
+        self.ast.formal_parameter(
+            span,
+            self.ast.vec(),
+            pattern,
+            NONE,
+            initializer,
+            false,
+            None,
+            false,
+            false,
+        )
+    }
+
+    fn class(&self, node: &Value, r#type: ClassType) -> Result<Box<'a, Class<'a>>> {
+        let id = optional_field(node, "id").map(|id| self.binding_identifier(id)).transpose()?;
+        let body = field(node, "body")?;
+        expect_type(body, "ClassBody")?;
+        let elements = self.vec(array_field(body, "body")?, Self::class_element)?;
+        let body = self.ast.alloc_class_body(self.span(body), elements);
+        Ok(self.ast.alloc_class(
+            self.span(node),
+            r#type,
+            self.decorators(node)?,
+            id,
+            NONE,
+            self.optional_expression(node, "superClass")?,
+            NONE,
+            self.ast.vec(),
+            body,
+            false,
+            false,
+        ))
+    }
+
+    fn class_element(&self, node: &Value) -> Result<ClassElement<'a>> {
+        let span = self.span(node);
+        let computed = bool_field(node, "computed");
+        let is_static = bool_field(node, "static");
+        let element = match node_type(node) {
+            Some("MethodDefinition") => {
+                let kind = match node.get("kind").and_then(Value::as_str) {
+                    Some("constructor") => MethodDefinitionKind::Constructor,
+                    Some("method") => MethodDefinitionKind::Method,
+                    Some("get") => MethodDefinitionKind::Get,
+                    Some("set") => MethodDefinitionKind::Set,
+                    _ => return Err(self.invalid(node, "unknown `kind`")),
+                };
+                let value = self.function(
+                    field(node, "value")?,
+                    FunctionType::FunctionExpression,
+                    FormalParameterKind::UniqueFormalParameters,
+                )?;
+                self.ast.class_element_method_definition(
+                    span,
+                    MethodDefinitionType::MethodDefinition,
+                    self.decorators(node)?,
+                    self.property_key(field(node, "key")?, computed)?,
+                    value,
+                    kind,
+                    computed,
+                    is_static,
+                    false,
+                    false,
+                    None,
+                )
+            }
+            Some("PropertyDefinition") => self.ast.class_element_property_definition(
+                span,
+                PropertyDefinitionType::PropertyDefinition,
+                self.decorators(node)?,
+                self.property_key(field(node, "key")?, computed)?,
+                NONE,
+                self.optional_expression(node, "value")?,
+                computed,
+                is_static,
+                false,
+                false,
+                false,
+                false,
+                false,
+                None,
+            ),
+            Some("AccessorProperty") => self.ast.class_element_accessor_property(
+                span,
+                AccessorPropertyType::AccessorProperty,
+                self.decorators(node)?,
+                self.property_key(field(node, "key")?, computed)?,
+                NONE,
+                self.optional_expression(node, "value")?,
+                computed,
+                is_static,
+                false,
+                false,
+                None,
+            ),
+            Some("StaticBlock") => self
+                .ast
+                .class_element_static_block(span, self.statements(array_field(node, "body")?)?),
+            _ => return Err(self.unsupported(node, "a class element")),
+        };
+        Ok(element)
+    }
+
+    /// `decorators` of a class or class element node, which is not in ESTree but commonly added.
+    fn decorators(&self, node: &Value) -> Result<Vec<'a, Decorator<'a>>> {
+        let Some(decorators) = node.get("decorators").and_then(Value::as_array) else {
+            return Ok(self.ast.vec());
+        };
+        self.vec(decorators, |this, decorator| {
+            expect_type(decorator, "Decorator")?;
+            let expression = this.expression(field(decorator, "expression")?)?;
+            Ok(this.ast.decorator(this.span(decorator), expression))
+        })
+    }
+
+    fn import_declaration(&self, node: &Value) -> Result<ModuleDeclaration<'a>> {
+        let specifiers = self.vec(array_field(node, "specifiers")?, |this, specifier| {
+            let span = this.span(specifier);
+            let local = this.binding_identifier(field(specifier, "local")?)?;
+            Ok(match node_type(specifier) {
+                Some("ImportSpecifier") => this.ast.import_declaration_specifier_import_specifier(
+                    span,
+                    this.module_export_name(field(specifier, "imported")?, false)?,
+                    local,
+                    ImportOrExportKind::Value,
+                ),
+                Some("ImportDefaultSpecifier") => {
+                    this.ast.import_declaration_specifier_import_default_specifier(span, local)
+                }
+                Some("ImportNamespaceSpecifier") => {
+                    this.ast.import_declaration_specifier_import_namespace_specifier(span, local)
+                }
+                _ => return Err(this.unsupported(specifier, "an import specifier")),
+            })
+        })?;
+        // `import "a"` has no specifiers, and `import {} from "a"` has an empty list of them.
+        // ESTree does not distinguish the two, so the first is assumed.
+        let specifiers = if specifiers.is_empty() { None } else { Some(specifiers) };
+        let phase = match node.get("phase").and_then(Value::as_str) {
+            Some("source") => Some(ImportPhase::Source),
+            Some("defer") => Some(ImportPhase::Defer),
+            _ => None,
+        };
+        Ok(self.ast.module_declaration_import_declaration(
+            self.span(node),
+            specifiers,
+            self.string_literal(field(node, "source")?)?,
+            phase,
+            self.with_clause(node)?,
+            ImportOrExportKind::Value,
+        ))
+    }
+
+    fn export_named_declaration(&self, node: &Value) -> Result<ModuleDeclaration<'a>> {
+        let declaration =
+            optional_field(node, "declaration").map(|node| self.declaration(node)).transpose()?;
+        let source =
+            optional_field(node, "source").map(|node| self.string_literal(node)).transpose()?;
+        let specifiers = self.vec(array_field(node, "specifiers")?, |this, specifier| {
+            expect_type(specifier, "ExportSpecifier")?;
+            Ok(this.ast.export_specifier(
+                this.span(specifier),
+                this.module_export_name(field(specifier, "local")?, source.is_none())?,
+                this.module_export_name(field(specifier, "exported")?, false)?,
+                ImportOrExportKind::Value,
+            ))
+        })?;
+        Ok(self.ast.module_declaration_export_named_declaration(
+            self.span(node),
+            declaration,
+            specifiers,
+            source,
+            ImportOrExportKind::Value,
+            self.with_clause(node)?,
+        ))
+    }
+
+    fn export_default_declaration(&self, node: &Value) -> Result<ModuleDeclaration<'a>> {
+        let declaration = field(node, "declaration")?;
+        let declaration = match node_type(declaration) {
+            Some("FunctionDeclaration") => {
+                ExportDefaultDeclarationKind::FunctionDeclaration(self.function(
+                    declaration,
+                    FunctionType::FunctionDeclaration,
+                    FormalParameterKind::FormalParameter,
+                )?)
+            }
+            Some("ClassDeclaration") => ExportDefaultDeclarationKind::ClassDeclaration(
+                self.class(declaration, ClassType::ClassDeclaration)?,
+            ),
+            _ => ExportDefaultDeclarationKind::from(self.expression(declaration)?),
+        };
+        Ok(self.ast.module_declaration_export_default_declaration(self.span(node), declaration))
+    }
+
+    fn export_all_declaration(&self, node: &Value) -> Result<ModuleDeclaration<'a>> {
+        let exported = optional_field(node, "exported")
+            .map(|exported| self.module_export_name(exported, false))
+            .transpose()?;
+        Ok(self.ast.module_declaration_export_all_declaration(
+            self.span(node),
+            exported,
+            self.string_literal(field(node, "source")?)?,
+            self.with_clause(node)?,
+            ImportOrExportKind::Value,
+        ))
+    }
+
+    /// `a` in `export { a }` is a reference to a local, unlike other names in specifiers.
+    fn module_export_name(&self, node: &Value, is_reference: bool) -> Result<ModuleExportName<'a>> {
+        let span = self.span(node);
+        match node_type(node) {
+            Some("Identifier") if is_reference => {
+                Ok(self.ast.module_export_name_identifier_reference(span, self.identifier(node)?))
+            }
+            Some("Identifier") => {
+                Ok(self.ast.module_export_name_identifier_name(span, self.identifier(node)?))
+            }
+            _ => {
+                let StringLiteral { span, value, raw, .. } = self.string_literal(node)?;
+                Ok(self.ast.module_export_name_string_literal(span, value, raw))
+            }
+        }
+    }
+
+    /// `attributes` of an import or export node.
+    fn with_clause(&self, node: &Value) -> Result<Option<Box<'a, WithClause<'a>>>> {
+        let attributes = match node.get("attributes").and_then(Value::as_array) {
+            Some(attributes) if !attributes.is_empty() => attributes,
+            _ => return Ok(None),
+        };
+        let entries = self.vec(attributes, |this, attribute| {
+            expect_type(attribute, "ImportAttribute")?;
+            let key = field(attribute, "key")?;
+            let key = if node_type(key) == Some("Identifier") {
+                ImportAttributeKey::Identifier(this.identifier_name(key)?)
+            } else {
+                ImportAttributeKey::StringLiteral(this.string_literal(key)?)
+            };
+            let value = this.string_literal(field(attribute, "value")?)?;
+            Ok(this.ast.import_attribute(this.span(attribute), key, value))
+        })?;
+        Ok(Some(self.ast.alloc_with_clause(SPAN, WithClauseKeyword::With, entries)))
+    }
+}
+
+/// Base of a number literal, from its `raw` text if it has one.
+fn number_base(raw: Option<&str>, value: f64) -> NumberBase {
+    let Some(raw) = raw else {
+        return if value.fract() == 0.0 { NumberBase::Decimal } else { NumberBase::Float };
+    };
+    match raw.get(..2) {
+        Some("0x" | "0X") => NumberBase::Hex,
+        Some("0o" | "0O") => NumberBase::Octal,
+        Some("0b" | "0B") => NumberBase::Binary,
+        _ if raw.contains(['.', 'e', 'E']) => NumberBase::Float,
+        _ => NumberBase::Decimal,
+    }
+}
+
+/// Base of a BigInt literal, from its text with or without the `n` suffix.
+fn bigint_base(text: &str) -> BigintBase {
+    match text.get(..2) {
+        Some("0x" | "0X") => BigintBase::Hex,
+        Some("0o" | "0O") => BigintBase::Octal,
+        Some("0b" | "0B") => BigintBase::Binary,
+        _ => BigintBase::Decimal,
+    }
+}
+
+/// Convert the digits of a BigInt in `radix` to base 10, or `None` if they are not valid.
+fn bigint_to_decimal(digits: &str, radix: u32) -> Option<String> {
+    let digits = digits.strip_suffix('n').unwrap_or(digits);
+    if digits.is_empty() {
+        return None;
+    }
+    // Base 10 digits of the value so far, least significant first
+    let mut decimal = vec![0u8];
+    for c in digits.chars().filter(|&c| c != '_') {
+        let mut carry = c.to_digit(radix)?;
+        for digit in &mut decimal {
+            let n = u32::from(*digit) * radix + carry;
+            *digit = (n % 10) as u8;
+            carry = n / 10;
+        }
+        while carry > 0 {
+            decimal.push((carry % 10) as u8);
+            carry /= 10;
+        }
+    }
+    while decimal.len() > 1 && decimal.last() == Some(&0) {
+        decimal.pop();
+    }
+    Some(decimal.iter().rev().map(|&digit| char::from(b'0' + digit)).collect())
+}
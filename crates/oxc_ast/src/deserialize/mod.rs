@@ -0,0 +1,168 @@
+//! Deserialization of ESTree JSON into an AST.
+
+use serde_json::Value;
+
+use oxc_allocator::{Allocator, Vec};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{SPAN, SourceType, Span};
+
+use crate::{AstBuilder, ast::*};
+
+mod js;
+
+type Result<T> = std::result::Result<T, OxcDiagnostic>;
+
+impl<'a> Program<'a> {
+    /// Build an AST from ESTree JSON, e.g. as produced by [`Program::to_estree_js_json`] or by
+    /// other JavaScript tools.
+    ///
+    /// Standard ESTree JavaScript nodes are supported, up to ES2025. TypeScript and JSX nodes are not.
+    ///
+    /// `source_text` is the source text which the `start` and `end` (or `range`) fields of the nodes
+    /// refer to. Pass an empty string if there is none, and nodes get empty spans.
+    ///
+    /// The AST has no scopes, symbols or references. Run `oxc_semantic` on it before passes which
+    /// need them, e.g. the minifier.
+    ///
+    /// # Errors
+    /// If the JSON is invalid, or contains a node which is not valid ESTree or is not supported.
+    pub fn from_estree_json(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        json: &str,
+    ) -> Result<Self> {
+        let root: Value = serde_json::from_str(json)
+            .map_err(|err| OxcDiagnostic::error(format!("Invalid ESTree JSON: {err}")))?;
+        let deserializer = ESTreeDeserializer { ast: AstBuilder::new(allocator), source_text };
+        deserializer.program(&root)
+    }
+}
+
+/// Builds AST nodes from ESTree JSON nodes.
+struct ESTreeDeserializer<'a> {
+    ast: AstBuilder<'a>,
+    source_text: &'a str,
+}
+
+impl<'a> ESTreeDeserializer<'a> {
+    fn program(&self, node: &Value) -> Result<Program<'a>> {
+        expect_type(node, "Program")?;
+        let source_type = match node.get("sourceType").and_then(Value::as_str) {
+            Some("script") => SourceType::cjs(),
+            _ => SourceType::mjs(),
+        };
+        let hashbang = optional_field(node, "hashbang")
+            .map(|hashbang| {
+                let value = self.str_field(hashbang, "value")?;
+                Ok(self.ast.hashbang(self.span(hashbang), value))
+            })
+            .transpose()?;
+        let (directives, body) = self.body(array_field(node, "body")?)?;
+        Ok(self.ast.program(
+            self.span(node),
+            source_type,
+            self.source_text,
+            self.ast.vec(),
+            hashbang,
+            directives,
+            body,
+        ))
+    }
+
+    /// Span of `node`, or an empty span if it is not within the source text.
+    fn span(&self, node: &Value) -> Span {
+        let offset =
+            |key| node.get(key).and_then(Value::as_u64).and_then(|n| u32::try_from(n).ok());
+        let range = match (offset("start"), offset("end")) {
+            (Some(start), Some(end)) => Some((start, end)),
+            _ => node.get("range").and_then(Value::as_array).and_then(|range| {
+                match range.as_slice() {
+                    [start, end] => Some((
+                        u32::try_from(start.as_u64()?).ok()?,
+                        u32::try_from(end.as_u64()?).ok()?,
+                    )),
+                    _ => None,
+                }
+            }),
+        };
+        match range {
+            Some((start, end)) if start <= end && end as usize <= self.source_text.len() => {
+                Span::new(start, end)
+            }
+            _ => SPAN,
+        }
+    }
+
+    /// String field of `node`, allocated in the arena.
+    fn str_field(&self, node: &Value, key: &str) -> Result<&'a str> {
+        match node.get(key).and_then(Value::as_str) {
+            Some(value) => Ok(self.ast.str(value)),
+            None => Err(self.invalid(node, &format!("`{key}` must be a string"))),
+        }
+    }
+
+    fn invalid(&self, node: &Value, message: &str) -> OxcDiagnostic {
+        let node_type = node.get("type").and_then(Value::as_str).unwrap_or("unknown");
+        OxcDiagnostic::error(format!("Invalid ESTree `{node_type}` node: {message}"))
+            .with_label(self.span(node))
+    }
+
+    fn unsupported(&self, node: &Value, expected: &str) -> OxcDiagnostic {
+        match node.get("type").and_then(Value::as_str) {
+            Some(node_type) => OxcDiagnostic::error(format!(
+                "Unexpected ESTree `{node_type}` node, expected {expected}"
+            ))
+            .with_label(self.span(node)),
+            None => OxcDiagnostic::error(format!("Expected {expected}, found `{node}`")),
+        }
+    }
+
+    /// Build each node of `nodes` with `f`.
+    fn vec<T>(
+        &self,
+        nodes: &[Value],
+        mut f: impl FnMut(&Self, &Value) -> Result<T>,
+    ) -> Result<Vec<'a, T>> {
+        let mut vec = self.ast.vec_with_capacity(nodes.len());
+        for node in nodes {
+            vec.push(f(self, node)?);
+        }
+        Ok(vec)
+    }
+}
+
+fn node_type(node: &Value) -> Option<&str> {
+    node.get("type").and_then(Value::as_str)
+}
+
+fn expect_type(node: &Value, expected: &str) -> Result<()> {
+    if node_type(node) == Some(expected) {
+        Ok(())
+    } else {
+        Err(OxcDiagnostic::error(format!("Expected ESTree `{expected}` node")))
+    }
+}
+
+fn field<'v>(node: &'v Value, key: &str) -> Result<&'v Value> {
+    optional_field(node, key).ok_or_else(|| {
+        let node_type = node_type(node).unwrap_or("unknown");
+        OxcDiagnostic::error(format!("Invalid ESTree `{node_type}` node: missing `{key}`"))
+    })
+}
+
+/// Field of `node`, or `None` if it is missing or `null`.
+fn optional_field<'v>(node: &'v Value, key: &str) -> Option<&'v Value> {
+    node.get(key).filter(|value| !value.is_null())
+}
+
+fn array_field<'v>(node: &'v Value, key: &str) -> Result<&'v [Value]> {
+    field(node, key)?.as_array().map(std::vec::Vec::as_slice).ok_or_else(|| {
+        let node_type = node_type(node).unwrap_or("unknown");
+        OxcDiagnostic::error(format!("Invalid ESTree `{node_type}` node: `{key}` must be an array"))
+    })
+}
+
+/// Boolean field of `node`, or `false` if it is missing.
+fn bool_field(node: &Value, key: &str) -> bool {
+    node.get(key).and_then(Value::as_bool).unwrap_or(false)
+}
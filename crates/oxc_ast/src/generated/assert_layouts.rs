@@ -859,6 +859,24 @@ const _: () = {
     assert!(offset_of!(V8IntrinsicExpression, name) == 8);
     assert!(offset_of!(V8IntrinsicExpression, arguments) == 32);
 
+    // Padding: 0 bytes
+    assert!(size_of::<PipelineExpression>() == 40);
+    assert!(align_of::<PipelineExpression>() == 8);
+    assert!(offset_of!(PipelineExpression, span) == 0);
+    assert!(offset_of!(PipelineExpression, head) == 8);
+    assert!(offset_of!(PipelineExpression, body) == 24);
+
+    // Padding: 0 bytes
+    assert!(size_of::<TopicReference>() == 8);
+    assert!(align_of::<TopicReference>() == 8);
+    assert!(offset_of!(TopicReference, span) == 0);
+
+    // Padding: 0 bytes
+    assert!(size_of::<DoExpression>() == 16);
+    assert!(align_of::<DoExpression>() == 8);
+    assert!(offset_of!(DoExpression, span) == 0);
+    assert!(offset_of!(DoExpression, body) == 8);
+
     // Padding: 7 bytes
     assert!(size_of::<BooleanLiteral>() == 16);
     assert!(align_of::<BooleanLiteral>() == 8);
@@ -2480,6 +2498,24 @@ const _: () = if cfg!(target_family = "wasm") || align_of::<u64>() == 8 {
     assert!(offset_of!(V8IntrinsicExpression, name) == 8);
     assert!(offset_of!(V8IntrinsicExpression, arguments) == 24);
 
+    // Padding: 0 bytes
+    assert!(size_of::<PipelineExpression>() == 24);
+    assert!(align_of::<PipelineExpression>() == 4);
+    assert!(offset_of!(PipelineExpression, span) == 0);
+    assert!(offset_of!(PipelineExpression, head) == 8);
+    assert!(offset_of!(PipelineExpression, body) == 16);
+
+    // Padding: 0 bytes
+    assert!(size_of::<TopicReference>() == 8);
+    assert!(align_of::<TopicReference>() == 4);
+    assert!(offset_of!(TopicReference, span) == 0);
+
+    // Padding: 0 bytes
+    assert!(size_of::<DoExpression>() == 12);
+    assert!(align_of::<DoExpression>() == 4);
+    assert!(offset_of!(DoExpression, span) == 0);
+    assert!(offset_of!(DoExpression, body) == 8);
+
     // Padding: 3 bytes
     assert!(size_of::<BooleanLiteral>() == 12);
     assert!(align_of::<BooleanLiteral>() == 4);
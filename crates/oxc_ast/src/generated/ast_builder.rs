@@ -1217,6 +1217,50 @@ impl<'a> AstBuilder<'a> {
         )
     }
 
+    /// Build an [`Expression::PipelineExpression`].
+    ///
+    /// This node contains a [`PipelineExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `head`
+    /// * `body`
+    #[inline]
+    pub fn expression_pipeline(
+        self,
+        span: Span,
+        head: Expression<'a>,
+        body: Expression<'a>,
+    ) -> Expression<'a> {
+        Expression::PipelineExpression(self.alloc_pipeline_expression(span, head, body))
+    }
+
+    /// Build an [`Expression::TopicReference`].
+    ///
+    /// This node contains a [`TopicReference`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn expression_topic_reference(self, span: Span) -> Expression<'a> {
+        Expression::TopicReference(self.alloc_topic_reference(span))
+    }
+
+    /// Build an [`Expression::DoExpression`].
+    ///
+    /// This node contains a [`DoExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `body`
+    #[inline]
+    pub fn expression_do<T1>(self, span: Span, body: T1) -> Expression<'a>
+    where
+        T1: IntoIn<'a, Box<'a, BlockStatement<'a>>>,
+    {
+        Expression::DoExpression(self.alloc_do_expression(span, body))
+    }
+
     /// Build an [`IdentifierName`].
     ///
     /// If you want the built node to be allocated in the memory arena,
@@ -8563,6 +8607,100 @@ impl<'a> AstBuilder<'a> {
         Box::new_in(self.v_8_intrinsic_expression(span, name, arguments), self.allocator)
     }
 
+    /// Build a [`PipelineExpression`].
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`AstBuilder::alloc_pipeline_expression`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `head`
+    /// * `body`
+    #[inline]
+    pub fn pipeline_expression(
+        self,
+        span: Span,
+        head: Expression<'a>,
+        body: Expression<'a>,
+    ) -> PipelineExpression<'a> {
+        PipelineExpression { span, head, body }
+    }
+
+    /// Build a [`PipelineExpression`], and store it in the memory arena.
+    ///
+    /// Returns a [`Box`] containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`AstBuilder::pipeline_expression`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `head`
+    /// * `body`
+    #[inline]
+    pub fn alloc_pipeline_expression(
+        self,
+        span: Span,
+        head: Expression<'a>,
+        body: Expression<'a>,
+    ) -> Box<'a, PipelineExpression<'a>> {
+        Box::new_in(self.pipeline_expression(span, head, body), self.allocator)
+    }
+
+    /// Build a [`TopicReference`].
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`AstBuilder::alloc_topic_reference`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn topic_reference(self, span: Span) -> TopicReference {
+        TopicReference { span }
+    }
+
+    /// Build a [`TopicReference`], and store it in the memory arena.
+    ///
+    /// Returns a [`Box`] containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`AstBuilder::topic_reference`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn alloc_topic_reference(self, span: Span) -> Box<'a, TopicReference> {
+        Box::new_in(self.topic_reference(span), self.allocator)
+    }
+
+    /// Build a [`DoExpression`].
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`AstBuilder::alloc_do_expression`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `body`
+    #[inline]
+    pub fn do_expression<T1>(self, span: Span, body: T1) -> DoExpression<'a>
+    where
+        T1: IntoIn<'a, Box<'a, BlockStatement<'a>>>,
+    {
+        DoExpression { span, body: body.into_in(self.allocator) }
+    }
+
+    /// Build a [`DoExpression`], and store it in the memory arena.
+    ///
+    /// Returns a [`Box`] containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`AstBuilder::do_expression`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `body`
+    #[inline]
+    pub fn alloc_do_expression<T1>(self, span: Span, body: T1) -> Box<'a, DoExpression<'a>>
+    where
+        T1: IntoIn<'a, Box<'a, BlockStatement<'a>>>,
+    {
+        Box::new_in(self.do_expression(span, body), self.allocator)
+    }
+
     /// Build a [`BooleanLiteral`].
     ///
     /// If you want the built node to be allocated in the memory arena,
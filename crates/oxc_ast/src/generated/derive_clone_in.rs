@@ -146,6 +146,13 @@ impl<'new_alloc> CloneIn<'new_alloc> for Expression<'_> {
             Self::V8IntrinsicExpression(it) => {
                 Expression::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::PipelineExpression(it) => {
+                Expression::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                Expression::TopicReference(CloneIn::clone_in(it, allocator))
+            }
+            Self::DoExpression(it) => Expression::DoExpression(CloneIn::clone_in(it, allocator)),
             Self::ComputedMemberExpression(it) => {
                 Expression::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -280,6 +287,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for Expression<'_> {
             Self::V8IntrinsicExpression(it) => Expression::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::PipelineExpression(it) => {
+                Expression::PipelineExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                Expression::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::DoExpression(it) => {
+                Expression::DoExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => Expression::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -526,6 +542,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for ArrayExpressionElement<'_> {
             Self::V8IntrinsicExpression(it) => {
                 ArrayExpressionElement::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::PipelineExpression(it) => {
+                ArrayExpressionElement::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                ArrayExpressionElement::TopicReference(CloneIn::clone_in(it, allocator))
+            }
+            Self::DoExpression(it) => {
+                ArrayExpressionElement::DoExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 ArrayExpressionElement::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -668,6 +693,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for ArrayExpressionElement<'_> {
             Self::V8IntrinsicExpression(it) => ArrayExpressionElement::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::PipelineExpression(it) => ArrayExpressionElement::PipelineExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::TopicReference(it) => ArrayExpressionElement::TopicReference(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::DoExpression(it) => ArrayExpressionElement::DoExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
             Self::ComputedMemberExpression(it) => ArrayExpressionElement::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -876,6 +910,13 @@ impl<'new_alloc> CloneIn<'new_alloc> for PropertyKey<'_> {
             Self::V8IntrinsicExpression(it) => {
                 PropertyKey::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::PipelineExpression(it) => {
+                PropertyKey::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                PropertyKey::TopicReference(CloneIn::clone_in(it, allocator))
+            }
+            Self::DoExpression(it) => PropertyKey::DoExpression(CloneIn::clone_in(it, allocator)),
             Self::ComputedMemberExpression(it) => {
                 PropertyKey::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -1016,6 +1057,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for PropertyKey<'_> {
             Self::V8IntrinsicExpression(it) => PropertyKey::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::PipelineExpression(it) => {
+                PropertyKey::PipelineExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                PropertyKey::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::DoExpression(it) => {
+                PropertyKey::DoExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => PropertyKey::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -1407,6 +1457,11 @@ impl<'new_alloc> CloneIn<'new_alloc> for Argument<'_> {
             Self::V8IntrinsicExpression(it) => {
                 Argument::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::PipelineExpression(it) => {
+                Argument::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => Argument::TopicReference(CloneIn::clone_in(it, allocator)),
+            Self::DoExpression(it) => Argument::DoExpression(CloneIn::clone_in(it, allocator)),
             Self::ComputedMemberExpression(it) => {
                 Argument::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -1542,6 +1597,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for Argument<'_> {
             Self::V8IntrinsicExpression(it) => {
                 Argument::V8IntrinsicExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
             }
+            Self::PipelineExpression(it) => {
+                Argument::PipelineExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                Argument::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::DoExpression(it) => {
+                Argument::DoExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => Argument::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -2873,6 +2937,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for ForStatementInit<'_> {
             Self::V8IntrinsicExpression(it) => {
                 ForStatementInit::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::PipelineExpression(it) => {
+                ForStatementInit::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                ForStatementInit::TopicReference(CloneIn::clone_in(it, allocator))
+            }
+            Self::DoExpression(it) => {
+                ForStatementInit::DoExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 ForStatementInit::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -3010,6 +3083,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for ForStatementInit<'_> {
             Self::V8IntrinsicExpression(it) => ForStatementInit::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::PipelineExpression(it) => ForStatementInit::PipelineExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::TopicReference(it) => {
+                ForStatementInit::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::DoExpression(it) => {
+                ForStatementInit::DoExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => ForStatementInit::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -4588,6 +4670,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for ExportDefaultDeclarationKind<'_> {
             Self::V8IntrinsicExpression(it) => ExportDefaultDeclarationKind::V8IntrinsicExpression(
                 CloneIn::clone_in(it, allocator),
             ),
+            Self::PipelineExpression(it) => {
+                ExportDefaultDeclarationKind::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                ExportDefaultDeclarationKind::TopicReference(CloneIn::clone_in(it, allocator))
+            }
+            Self::DoExpression(it) => {
+                ExportDefaultDeclarationKind::DoExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 ExportDefaultDeclarationKind::ComputedMemberExpression(CloneIn::clone_in(
                     it, allocator,
@@ -4747,6 +4838,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for ExportDefaultDeclarationKind<'_> {
             Self::V8IntrinsicExpression(it) => ExportDefaultDeclarationKind::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::PipelineExpression(it) => ExportDefaultDeclarationKind::PipelineExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::TopicReference(it) => ExportDefaultDeclarationKind::TopicReference(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::DoExpression(it) => ExportDefaultDeclarationKind::DoExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
             Self::ComputedMemberExpression(it) => {
                 ExportDefaultDeclarationKind::ComputedMemberExpression(
                     CloneIn::clone_in_with_semantic_ids(it, allocator),
@@ -4818,6 +4918,56 @@ impl<'new_alloc> CloneIn<'new_alloc> for V8IntrinsicExpression<'_> {
     }
 }
 
+impl<'new_alloc> CloneIn<'new_alloc> for PipelineExpression<'_> {
+    type Cloned = PipelineExpression<'new_alloc>;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        PipelineExpression {
+            span: CloneIn::clone_in(&self.span, allocator),
+            head: CloneIn::clone_in(&self.head, allocator),
+            body: CloneIn::clone_in(&self.body, allocator),
+        }
+    }
+
+    fn clone_in_with_semantic_ids(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        PipelineExpression {
+            span: CloneIn::clone_in_with_semantic_ids(&self.span, allocator),
+            head: CloneIn::clone_in_with_semantic_ids(&self.head, allocator),
+            body: CloneIn::clone_in_with_semantic_ids(&self.body, allocator),
+        }
+    }
+}
+
+impl<'new_alloc> CloneIn<'new_alloc> for TopicReference {
+    type Cloned = TopicReference;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        TopicReference { span: CloneIn::clone_in(&self.span, allocator) }
+    }
+
+    fn clone_in_with_semantic_ids(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        TopicReference { span: CloneIn::clone_in_with_semantic_ids(&self.span, allocator) }
+    }
+}
+
+impl<'new_alloc> CloneIn<'new_alloc> for DoExpression<'_> {
+    type Cloned = DoExpression<'new_alloc>;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        DoExpression {
+            span: CloneIn::clone_in(&self.span, allocator),
+            body: CloneIn::clone_in(&self.body, allocator),
+        }
+    }
+
+    fn clone_in_with_semantic_ids(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        DoExpression {
+            span: CloneIn::clone_in_with_semantic_ids(&self.span, allocator),
+            body: CloneIn::clone_in_with_semantic_ids(&self.body, allocator),
+        }
+    }
+}
+
 impl<'new_alloc> CloneIn<'new_alloc> for BooleanLiteral {
     type Cloned = BooleanLiteral;
 
@@ -5332,6 +5482,13 @@ impl<'new_alloc> CloneIn<'new_alloc> for JSXExpression<'_> {
             Self::V8IntrinsicExpression(it) => {
                 JSXExpression::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::PipelineExpression(it) => {
+                JSXExpression::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                JSXExpression::TopicReference(CloneIn::clone_in(it, allocator))
+            }
+            Self::DoExpression(it) => JSXExpression::DoExpression(CloneIn::clone_in(it, allocator)),
             Self::ComputedMemberExpression(it) => {
                 JSXExpression::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -5469,6 +5626,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for JSXExpression<'_> {
             Self::V8IntrinsicExpression(it) => JSXExpression::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::PipelineExpression(it) => JSXExpression::PipelineExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::TopicReference(it) => {
+                JSXExpression::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::DoExpression(it) => {
+                JSXExpression::DoExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => JSXExpression::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -0,0 +1,4218 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/derives/content_hash.rs`.
+
+use std::hash::Hasher;
+
+use oxc_span::ContentHash;
+
+use crate::ast::comment::*;
+use crate::ast::js::*;
+use crate::ast::jsx::*;
+use crate::ast::literal::*;
+use crate::ast::ts::*;
+
+impl ContentHash for Program<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.source_type, state);
+        ContentHash::content_hash(&self.hashbang, state);
+        ContentHash::content_hash(&self.directives, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for Expression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::BooleanLiteral(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NullLiteral(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NumericLiteral(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BigIntLiteral(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::RegExpLiteral(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StringLiteral(it) => {
+                state.write_u8(5);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TemplateLiteral(it) => {
+                state.write_u8(6);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Identifier(it) => {
+                state.write_u8(7);
+                ContentHash::content_hash(it, state);
+            }
+            Self::MetaProperty(it) => {
+                state.write_u8(8);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Super(it) => {
+                state.write_u8(9);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrayExpression(it) => {
+                state.write_u8(10);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrowFunctionExpression(it) => {
+                state.write_u8(11);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AssignmentExpression(it) => {
+                state.write_u8(12);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AwaitExpression(it) => {
+                state.write_u8(13);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BinaryExpression(it) => {
+                state.write_u8(14);
+                ContentHash::content_hash(it, state);
+            }
+            Self::CallExpression(it) => {
+                state.write_u8(15);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ChainExpression(it) => {
+                state.write_u8(16);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ClassExpression(it) => {
+                state.write_u8(17);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ConditionalExpression(it) => {
+                state.write_u8(18);
+                ContentHash::content_hash(it, state);
+            }
+            Self::FunctionExpression(it) => {
+                state.write_u8(19);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ImportExpression(it) => {
+                state.write_u8(20);
+                ContentHash::content_hash(it, state);
+            }
+            Self::LogicalExpression(it) => {
+                state.write_u8(21);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NewExpression(it) => {
+                state.write_u8(22);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ObjectExpression(it) => {
+                state.write_u8(23);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ParenthesizedExpression(it) => {
+                state.write_u8(24);
+                ContentHash::content_hash(it, state);
+            }
+            Self::SequenceExpression(it) => {
+                state.write_u8(25);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TaggedTemplateExpression(it) => {
+                state.write_u8(26);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ThisExpression(it) => {
+                state.write_u8(27);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UnaryExpression(it) => {
+                state.write_u8(28);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UpdateExpression(it) => {
+                state.write_u8(29);
+                ContentHash::content_hash(it, state);
+            }
+            Self::YieldExpression(it) => {
+                state.write_u8(30);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateInExpression(it) => {
+                state.write_u8(31);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSXElement(it) => {
+                state.write_u8(32);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSXFragment(it) => {
+                state.write_u8(33);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSAsExpression(it) => {
+                state.write_u8(34);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                state.write_u8(35);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeAssertion(it) => {
+                state.write_u8(36);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNonNullExpression(it) => {
+                state.write_u8(37);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSInstantiationExpression(it) => {
+                state.write_u8(38);
+                ContentHash::content_hash(it, state);
+            }
+            Self::V8IntrinsicExpression(it) => {
+                state.write_u8(39);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PipelineExpression(it) => {
+                state.write_u8(40);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TopicReference(it) => {
+                state.write_u8(41);
+                ContentHash::content_hash(it, state);
+            }
+            Self::DoExpression(it) => {
+                state.write_u8(42);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ComputedMemberExpression(it) => {
+                state.write_u8(48);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StaticMemberExpression(it) => {
+                state.write_u8(49);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateFieldExpression(it) => {
+                state.write_u8(50);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for IdentifierName<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.name, state);
+    }
+}
+
+impl ContentHash for IdentifierReference<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.name, state);
+    }
+}
+
+impl ContentHash for BindingIdentifier<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.name, state);
+    }
+}
+
+impl ContentHash for LabelIdentifier<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.name, state);
+    }
+}
+
+impl ContentHash for ThisExpression {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for ArrayExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.elements, state);
+    }
+}
+
+impl ContentHash for ArrayExpressionElement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::SpreadElement(it) => {
+                state.write_u8(64);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Elision(it) => {
+                state.write_u8(65);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BooleanLiteral(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NullLiteral(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NumericLiteral(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BigIntLiteral(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::RegExpLiteral(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StringLiteral(it) => {
+                state.write_u8(5);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TemplateLiteral(it) => {
+                state.write_u8(6);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Identifier(it) => {
+                state.write_u8(7);
+                ContentHash::content_hash(it, state);
+            }
+            Self::MetaProperty(it) => {
+                state.write_u8(8);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Super(it) => {
+                state.write_u8(9);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrayExpression(it) => {
+                state.write_u8(10);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrowFunctionExpression(it) => {
+                state.write_u8(11);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AssignmentExpression(it) => {
+                state.write_u8(12);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AwaitExpression(it) => {
+                state.write_u8(13);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BinaryExpression(it) => {
+                state.write_u8(14);
+                ContentHash::content_hash(it, state);
+            }
+            Self::CallExpression(it) => {
+                state.write_u8(15);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ChainExpression(it) => {
+                state.write_u8(16);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ClassExpression(it) => {
+                state.write_u8(17);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ConditionalExpression(it) => {
+                state.write_u8(18);
+                ContentHash::content_hash(it, state);
+            }
+            Self::FunctionExpression(it) => {
+                state.write_u8(19);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ImportExpression(it) => {
+                state.write_u8(20);
+                ContentHash::content_hash(it, state);
+            }
+            Self::LogicalExpression(it) => {
+                state.write_u8(21);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NewExpression(it) => {
+                state.write_u8(22);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ObjectExpression(it) => {
+                state.write_u8(23);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ParenthesizedExpression(it) => {
+                state.write_u8(24);
+                ContentHash::content_hash(it, state);
+            }
+            Self::SequenceExpression(it) => {
+                state.write_u8(25);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TaggedTemplateExpression(it) => {
+                state.write_u8(26);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ThisExpression(it) => {
+                state.write_u8(27);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UnaryExpression(it) => {
+                state.write_u8(28);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UpdateExpression(it) => {
+                state.write_u8(29);
+                ContentHash::content_hash(it, state);
+            }
+            Self::YieldExpression(it) => {
+                state.write_u8(30);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateInExpression(it) => {
+                state.write_u8(31);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSXElement(it) => {
+                state.write_u8(32);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSXFragment(it) => {
+                state.write_u8(33);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSAsExpression(it) => {
+                state.write_u8(34);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                state.write_u8(35);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeAssertion(it) => {
+                state.write_u8(36);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNonNullExpression(it) => {
+                state.write_u8(37);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSInstantiationExpression(it) => {
+                state.write_u8(38);
+                ContentHash::content_hash(it, state);
+            }
+            Self::V8IntrinsicExpression(it) => {
+                state.write_u8(39);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PipelineExpression(it) => {
+                state.write_u8(40);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TopicReference(it) => {
+                state.write_u8(41);
+                ContentHash::content_hash(it, state);
+            }
+            Self::DoExpression(it) => {
+                state.write_u8(42);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ComputedMemberExpression(it) => {
+                state.write_u8(48);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StaticMemberExpression(it) => {
+                state.write_u8(49);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateFieldExpression(it) => {
+                state.write_u8(50);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for Elision {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for ObjectExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.properties, state);
+    }
+}
+
+impl ContentHash for ObjectPropertyKind<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::ObjectProperty(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::SpreadProperty(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for ObjectProperty<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.kind, state);
+        ContentHash::content_hash(&self.key, state);
+        ContentHash::content_hash(&self.value, state);
+        ContentHash::content_hash(&self.method, state);
+        ContentHash::content_hash(&self.shorthand, state);
+        ContentHash::content_hash(&self.computed, state);
+    }
+}
+
+impl ContentHash for PropertyKey<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::StaticIdentifier(it) => {
+                state.write_u8(64);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateIdentifier(it) => {
+                state.write_u8(65);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BooleanLiteral(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NullLiteral(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NumericLiteral(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BigIntLiteral(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::RegExpLiteral(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StringLiteral(it) => {
+                state.write_u8(5);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TemplateLiteral(it) => {
+                state.write_u8(6);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Identifier(it) => {
+                state.write_u8(7);
+                ContentHash::content_hash(it, state);
+            }
+            Self::MetaProperty(it) => {
+                state.write_u8(8);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Super(it) => {
+                state.write_u8(9);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrayExpression(it) => {
+                state.write_u8(10);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrowFunctionExpression(it) => {
+                state.write_u8(11);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AssignmentExpression(it) => {
+                state.write_u8(12);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AwaitExpression(it) => {
+                state.write_u8(13);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BinaryExpression(it) => {
+                state.write_u8(14);
+                ContentHash::content_hash(it, state);
+            }
+            Self::CallExpression(it) => {
+                state.write_u8(15);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ChainExpression(it) => {
+                state.write_u8(16);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ClassExpression(it) => {
+                state.write_u8(17);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ConditionalExpression(it) => {
+                state.write_u8(18);
+                ContentHash::content_hash(it, state);
+            }
+            Self::FunctionExpression(it) => {
+                state.write_u8(19);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ImportExpression(it) => {
+                state.write_u8(20);
+                ContentHash::content_hash(it, state);
+            }
+            Self::LogicalExpression(it) => {
+                state.write_u8(21);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NewExpression(it) => {
+                state.write_u8(22);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ObjectExpression(it) => {
+                state.write_u8(23);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ParenthesizedExpression(it) => {
+                state.write_u8(24);
+                ContentHash::content_hash(it, state);
+            }
+            Self::SequenceExpression(it) => {
+                state.write_u8(25);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TaggedTemplateExpression(it) => {
+                state.write_u8(26);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ThisExpression(it) => {
+                state.write_u8(27);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UnaryExpression(it) => {
+                state.write_u8(28);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UpdateExpression(it) => {
+                state.write_u8(29);
+                ContentHash::content_hash(it, state);
+            }
+            Self::YieldExpression(it) => {
+                state.write_u8(30);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateInExpression(it) => {
+                state.write_u8(31);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSXElement(it) => {
+                state.write_u8(32);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSXFragment(it) => {
+                state.write_u8(33);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSAsExpression(it) => {
+                state.write_u8(34);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                state.write_u8(35);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeAssertion(it) => {
+                state.write_u8(36);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNonNullExpression(it) => {
+                state.write_u8(37);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSInstantiationExpression(it) => {
+                state.write_u8(38);
+                ContentHash::content_hash(it, state);
+            }
+            Self::V8IntrinsicExpression(it) => {
+                state.write_u8(39);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PipelineExpression(it) => {
+                state.write_u8(40);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TopicReference(it) => {
+                state.write_u8(41);
+                ContentHash::content_hash(it, state);
+            }
+            Self::DoExpression(it) => {
+                state.write_u8(42);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ComputedMemberExpression(it) => {
+                state.write_u8(48);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StaticMemberExpression(it) => {
+                state.write_u8(49);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateFieldExpression(it) => {
+                state.write_u8(50);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for PropertyKind {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Init => state.write_u8(0),
+            Self::Get => state.write_u8(1),
+            Self::Set => state.write_u8(2),
+        }
+    }
+}
+
+impl ContentHash for TemplateLiteral<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.quasis, state);
+        ContentHash::content_hash(&self.expressions, state);
+    }
+}
+
+impl ContentHash for TaggedTemplateExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.tag, state);
+        ContentHash::content_hash(&self.type_arguments, state);
+        ContentHash::content_hash(&self.quasi, state);
+    }
+}
+
+impl ContentHash for TemplateElement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.value, state);
+        ContentHash::content_hash(&self.tail, state);
+        ContentHash::content_hash(&self.lone_surrogates, state);
+    }
+}
+
+impl ContentHash for TemplateElementValue<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.raw, state);
+        ContentHash::content_hash(&self.cooked, state);
+    }
+}
+
+impl ContentHash for MemberExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::ComputedMemberExpression(it) => {
+                state.write_u8(48);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StaticMemberExpression(it) => {
+                state.write_u8(49);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateFieldExpression(it) => {
+                state.write_u8(50);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for ComputedMemberExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.object, state);
+        ContentHash::content_hash(&self.expression, state);
+        ContentHash::content_hash(&self.optional, state);
+    }
+}
+
+impl ContentHash for StaticMemberExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.object, state);
+        ContentHash::content_hash(&self.property, state);
+        ContentHash::content_hash(&self.optional, state);
+    }
+}
+
+impl ContentHash for PrivateFieldExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.object, state);
+        ContentHash::content_hash(&self.field, state);
+        ContentHash::content_hash(&self.optional, state);
+    }
+}
+
+impl ContentHash for CallExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.callee, state);
+        ContentHash::content_hash(&self.type_arguments, state);
+        ContentHash::content_hash(&self.arguments, state);
+        ContentHash::content_hash(&self.optional, state);
+        ContentHash::content_hash(&self.pure, state);
+    }
+}
+
+impl ContentHash for NewExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.callee, state);
+        ContentHash::content_hash(&self.type_arguments, state);
+        ContentHash::content_hash(&self.arguments, state);
+        ContentHash::content_hash(&self.pure, state);
+    }
+}
+
+impl ContentHash for MetaProperty<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.meta, state);
+        ContentHash::content_hash(&self.property, state);
+    }
+}
+
+impl ContentHash for SpreadElement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.argument, state);
+    }
+}
+
+impl ContentHash for Argument<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::SpreadElement(it) => {
+                state.write_u8(64);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BooleanLiteral(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NullLiteral(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NumericLiteral(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BigIntLiteral(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::RegExpLiteral(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StringLiteral(it) => {
+                state.write_u8(5);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TemplateLiteral(it) => {
+                state.write_u8(6);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Identifier(it) => {
+                state.write_u8(7);
+                ContentHash::content_hash(it, state);
+            }
+            Self::MetaProperty(it) => {
+                state.write_u8(8);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Super(it) => {
+                state.write_u8(9);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrayExpression(it) => {
+                state.write_u8(10);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrowFunctionExpression(it) => {
+                state.write_u8(11);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AssignmentExpression(it) => {
+                state.write_u8(12);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AwaitExpression(it) => {
+                state.write_u8(13);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BinaryExpression(it) => {
+                state.write_u8(14);
+                ContentHash::content_hash(it, state);
+            }
+            Self::CallExpression(it) => {
+                state.write_u8(15);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ChainExpression(it) => {
+                state.write_u8(16);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ClassExpression(it) => {
+                state.write_u8(17);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ConditionalExpression(it) => {
+                state.write_u8(18);
+                ContentHash::content_hash(it, state);
+            }
+            Self::FunctionExpression(it) => {
+                state.write_u8(19);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ImportExpression(it) => {
+                state.write_u8(20);
+                ContentHash::content_hash(it, state);
+            }
+            Self::LogicalExpression(it) => {
+                state.write_u8(21);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NewExpression(it) => {
+                state.write_u8(22);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ObjectExpression(it) => {
+                state.write_u8(23);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ParenthesizedExpression(it) => {
+                state.write_u8(24);
+                ContentHash::content_hash(it, state);
+            }
+            Self::SequenceExpression(it) => {
+                state.write_u8(25);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TaggedTemplateExpression(it) => {
+                state.write_u8(26);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ThisExpression(it) => {
+                state.write_u8(27);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UnaryExpression(it) => {
+                state.write_u8(28);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UpdateExpression(it) => {
+                state.write_u8(29);
+                ContentHash::content_hash(it, state);
+            }
+            Self::YieldExpression(it) => {
+                state.write_u8(30);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateInExpression(it) => {
+                state.write_u8(31);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSXElement(it) => {
+                state.write_u8(32);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSXFragment(it) => {
+                state.write_u8(33);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSAsExpression(it) => {
+                state.write_u8(34);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                state.write_u8(35);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeAssertion(it) => {
+                state.write_u8(36);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNonNullExpression(it) => {
+                state.write_u8(37);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSInstantiationExpression(it) => {
+                state.write_u8(38);
+                ContentHash::content_hash(it, state);
+            }
+            Self::V8IntrinsicExpression(it) => {
+                state.write_u8(39);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PipelineExpression(it) => {
+                state.write_u8(40);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TopicReference(it) => {
+                state.write_u8(41);
+                ContentHash::content_hash(it, state);
+            }
+            Self::DoExpression(it) => {
+                state.write_u8(42);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ComputedMemberExpression(it) => {
+                state.write_u8(48);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StaticMemberExpression(it) => {
+                state.write_u8(49);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateFieldExpression(it) => {
+                state.write_u8(50);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for UpdateExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.operator, state);
+        ContentHash::content_hash(&self.prefix, state);
+        ContentHash::content_hash(&self.argument, state);
+    }
+}
+
+impl ContentHash for UnaryExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.operator, state);
+        ContentHash::content_hash(&self.argument, state);
+    }
+}
+
+impl ContentHash for BinaryExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.left, state);
+        ContentHash::content_hash(&self.operator, state);
+        ContentHash::content_hash(&self.right, state);
+    }
+}
+
+impl ContentHash for PrivateInExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.left, state);
+        ContentHash::content_hash(&self.right, state);
+    }
+}
+
+impl ContentHash for LogicalExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.left, state);
+        ContentHash::content_hash(&self.operator, state);
+        ContentHash::content_hash(&self.right, state);
+    }
+}
+
+impl ContentHash for ConditionalExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.test, state);
+        ContentHash::content_hash(&self.consequent, state);
+        ContentHash::content_hash(&self.alternate, state);
+    }
+}
+
+impl ContentHash for AssignmentExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.operator, state);
+        ContentHash::content_hash(&self.left, state);
+        ContentHash::content_hash(&self.right, state);
+    }
+}
+
+impl ContentHash for AssignmentTarget<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::AssignmentTargetIdentifier(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSAsExpression(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNonNullExpression(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeAssertion(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ComputedMemberExpression(it) => {
+                state.write_u8(48);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StaticMemberExpression(it) => {
+                state.write_u8(49);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateFieldExpression(it) => {
+                state.write_u8(50);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrayAssignmentTarget(it) => {
+                state.write_u8(8);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ObjectAssignmentTarget(it) => {
+                state.write_u8(9);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for SimpleAssignmentTarget<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::AssignmentTargetIdentifier(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSAsExpression(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNonNullExpression(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeAssertion(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ComputedMemberExpression(it) => {
+                state.write_u8(48);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StaticMemberExpression(it) => {
+                state.write_u8(49);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateFieldExpression(it) => {
+                state.write_u8(50);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for AssignmentTargetPattern<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::ArrayAssignmentTarget(it) => {
+                state.write_u8(8);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ObjectAssignmentTarget(it) => {
+                state.write_u8(9);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for ArrayAssignmentTarget<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.elements, state);
+        ContentHash::content_hash(&self.rest, state);
+    }
+}
+
+impl ContentHash for ObjectAssignmentTarget<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.properties, state);
+        ContentHash::content_hash(&self.rest, state);
+    }
+}
+
+impl ContentHash for AssignmentTargetRest<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.target, state);
+    }
+}
+
+impl ContentHash for AssignmentTargetMaybeDefault<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::AssignmentTargetWithDefault(it) => {
+                state.write_u8(16);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AssignmentTargetIdentifier(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSAsExpression(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNonNullExpression(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeAssertion(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ComputedMemberExpression(it) => {
+                state.write_u8(48);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StaticMemberExpression(it) => {
+                state.write_u8(49);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateFieldExpression(it) => {
+                state.write_u8(50);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrayAssignmentTarget(it) => {
+                state.write_u8(8);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ObjectAssignmentTarget(it) => {
+                state.write_u8(9);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for AssignmentTargetWithDefault<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.binding, state);
+        ContentHash::content_hash(&self.init, state);
+    }
+}
+
+impl ContentHash for AssignmentTargetProperty<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::AssignmentTargetPropertyIdentifier(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AssignmentTargetPropertyProperty(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for AssignmentTargetPropertyIdentifier<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.binding, state);
+        ContentHash::content_hash(&self.init, state);
+    }
+}
+
+impl ContentHash for AssignmentTargetPropertyProperty<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.name, state);
+        ContentHash::content_hash(&self.binding, state);
+        ContentHash::content_hash(&self.computed, state);
+    }
+}
+
+impl ContentHash for SequenceExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expressions, state);
+    }
+}
+
+impl ContentHash for Super {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for AwaitExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.argument, state);
+    }
+}
+
+impl ContentHash for ChainExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expression, state);
+    }
+}
+
+impl ContentHash for ChainElement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::CallExpression(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNonNullExpression(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ComputedMemberExpression(it) => {
+                state.write_u8(48);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StaticMemberExpression(it) => {
+                state.write_u8(49);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateFieldExpression(it) => {
+                state.write_u8(50);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for ParenthesizedExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expression, state);
+    }
+}
+
+impl ContentHash for Statement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::BlockStatement(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BreakStatement(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ContinueStatement(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::DebuggerStatement(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::DoWhileStatement(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::EmptyStatement(it) => {
+                state.write_u8(5);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ExpressionStatement(it) => {
+                state.write_u8(6);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ForInStatement(it) => {
+                state.write_u8(7);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ForOfStatement(it) => {
+                state.write_u8(8);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ForStatement(it) => {
+                state.write_u8(9);
+                ContentHash::content_hash(it, state);
+            }
+            Self::IfStatement(it) => {
+                state.write_u8(10);
+                ContentHash::content_hash(it, state);
+            }
+            Self::LabeledStatement(it) => {
+                state.write_u8(11);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ReturnStatement(it) => {
+                state.write_u8(12);
+                ContentHash::content_hash(it, state);
+            }
+            Self::SwitchStatement(it) => {
+                state.write_u8(13);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ThrowStatement(it) => {
+                state.write_u8(14);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TryStatement(it) => {
+                state.write_u8(15);
+                ContentHash::content_hash(it, state);
+            }
+            Self::WhileStatement(it) => {
+                state.write_u8(16);
+                ContentHash::content_hash(it, state);
+            }
+            Self::WithStatement(it) => {
+                state.write_u8(17);
+                ContentHash::content_hash(it, state);
+            }
+            Self::VariableDeclaration(it) => {
+                state.write_u8(32);
+                ContentHash::content_hash(it, state);
+            }
+            Self::FunctionDeclaration(it) => {
+                state.write_u8(33);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ClassDeclaration(it) => {
+                state.write_u8(34);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeAliasDeclaration(it) => {
+                state.write_u8(35);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSInterfaceDeclaration(it) => {
+                state.write_u8(36);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSEnumDeclaration(it) => {
+                state.write_u8(37);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSModuleDeclaration(it) => {
+                state.write_u8(38);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSGlobalDeclaration(it) => {
+                state.write_u8(39);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSImportEqualsDeclaration(it) => {
+                state.write_u8(40);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ImportDeclaration(it) => {
+                state.write_u8(64);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ExportAllDeclaration(it) => {
+                state.write_u8(65);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ExportDefaultDeclaration(it) => {
+                state.write_u8(66);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ExportNamedDeclaration(it) => {
+                state.write_u8(67);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSExportAssignment(it) => {
+                state.write_u8(68);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNamespaceExportDeclaration(it) => {
+                state.write_u8(69);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for Directive<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expression, state);
+        ContentHash::content_hash(&self.directive, state);
+    }
+}
+
+impl ContentHash for Hashbang<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.value, state);
+    }
+}
+
+impl ContentHash for BlockStatement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for Declaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::VariableDeclaration(it) => {
+                state.write_u8(32);
+                ContentHash::content_hash(it, state);
+            }
+            Self::FunctionDeclaration(it) => {
+                state.write_u8(33);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ClassDeclaration(it) => {
+                state.write_u8(34);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeAliasDeclaration(it) => {
+                state.write_u8(35);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSInterfaceDeclaration(it) => {
+                state.write_u8(36);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSEnumDeclaration(it) => {
+                state.write_u8(37);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSModuleDeclaration(it) => {
+                state.write_u8(38);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSGlobalDeclaration(it) => {
+                state.write_u8(39);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSImportEqualsDeclaration(it) => {
+                state.write_u8(40);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for VariableDeclaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.kind, state);
+        ContentHash::content_hash(&self.declarations, state);
+        ContentHash::content_hash(&self.declare, state);
+    }
+}
+
+impl ContentHash for VariableDeclarationKind {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Var => state.write_u8(0),
+            Self::Let => state.write_u8(1),
+            Self::Const => state.write_u8(2),
+            Self::Using => state.write_u8(3),
+            Self::AwaitUsing => state.write_u8(4),
+        }
+    }
+}
+
+impl ContentHash for VariableDeclarator<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.kind, state);
+        ContentHash::content_hash(&self.id, state);
+        ContentHash::content_hash(&self.type_annotation, state);
+        ContentHash::content_hash(&self.init, state);
+        ContentHash::content_hash(&self.definite, state);
+    }
+}
+
+impl ContentHash for EmptyStatement {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for ExpressionStatement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expression, state);
+    }
+}
+
+impl ContentHash for IfStatement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.test, state);
+        ContentHash::content_hash(&self.consequent, state);
+        ContentHash::content_hash(&self.alternate, state);
+    }
+}
+
+impl ContentHash for DoWhileStatement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.body, state);
+        ContentHash::content_hash(&self.test, state);
+    }
+}
+
+impl ContentHash for WhileStatement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.test, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for ForStatement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.init, state);
+        ContentHash::content_hash(&self.test, state);
+        ContentHash::content_hash(&self.update, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for ForStatementInit<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::VariableDeclaration(it) => {
+                state.write_u8(64);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BooleanLiteral(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NullLiteral(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NumericLiteral(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BigIntLiteral(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::RegExpLiteral(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StringLiteral(it) => {
+                state.write_u8(5);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TemplateLiteral(it) => {
+                state.write_u8(6);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Identifier(it) => {
+                state.write_u8(7);
+                ContentHash::content_hash(it, state);
+            }
+            Self::MetaProperty(it) => {
+                state.write_u8(8);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Super(it) => {
+                state.write_u8(9);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrayExpression(it) => {
+                state.write_u8(10);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrowFunctionExpression(it) => {
+                state.write_u8(11);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AssignmentExpression(it) => {
+                state.write_u8(12);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AwaitExpression(it) => {
+                state.write_u8(13);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BinaryExpression(it) => {
+                state.write_u8(14);
+                ContentHash::content_hash(it, state);
+            }
+            Self::CallExpression(it) => {
+                state.write_u8(15);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ChainExpression(it) => {
+                state.write_u8(16);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ClassExpression(it) => {
+                state.write_u8(17);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ConditionalExpression(it) => {
+                state.write_u8(18);
+                ContentHash::content_hash(it, state);
+            }
+            Self::FunctionExpression(it) => {
+                state.write_u8(19);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ImportExpression(it) => {
+                state.write_u8(20);
+                ContentHash::content_hash(it, state);
+            }
+            Self::LogicalExpression(it) => {
+                state.write_u8(21);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NewExpression(it) => {
+                state.write_u8(22);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ObjectExpression(it) => {
+                state.write_u8(23);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ParenthesizedExpression(it) => {
+                state.write_u8(24);
+                ContentHash::content_hash(it, state);
+            }
+            Self::SequenceExpression(it) => {
+                state.write_u8(25);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TaggedTemplateExpression(it) => {
+                state.write_u8(26);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ThisExpression(it) => {
+                state.write_u8(27);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UnaryExpression(it) => {
+                state.write_u8(28);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UpdateExpression(it) => {
+                state.write_u8(29);
+                ContentHash::content_hash(it, state);
+            }
+            Self::YieldExpression(it) => {
+                state.write_u8(30);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateInExpression(it) => {
+                state.write_u8(31);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSXElement(it) => {
+                state.write_u8(32);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSXFragment(it) => {
+                state.write_u8(33);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSAsExpression(it) => {
+                state.write_u8(34);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                state.write_u8(35);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeAssertion(it) => {
+                state.write_u8(36);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNonNullExpression(it) => {
+                state.write_u8(37);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSInstantiationExpression(it) => {
+                state.write_u8(38);
+                ContentHash::content_hash(it, state);
+            }
+            Self::V8IntrinsicExpression(it) => {
+                state.write_u8(39);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PipelineExpression(it) => {
+                state.write_u8(40);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TopicReference(it) => {
+                state.write_u8(41);
+                ContentHash::content_hash(it, state);
+            }
+            Self::DoExpression(it) => {
+                state.write_u8(42);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ComputedMemberExpression(it) => {
+                state.write_u8(48);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StaticMemberExpression(it) => {
+                state.write_u8(49);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateFieldExpression(it) => {
+                state.write_u8(50);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for ForInStatement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.left, state);
+        ContentHash::content_hash(&self.right, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for ForStatementLeft<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::VariableDeclaration(it) => {
+                state.write_u8(16);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AssignmentTargetIdentifier(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSAsExpression(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNonNullExpression(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeAssertion(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ComputedMemberExpression(it) => {
+                state.write_u8(48);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StaticMemberExpression(it) => {
+                state.write_u8(49);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateFieldExpression(it) => {
+                state.write_u8(50);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrayAssignmentTarget(it) => {
+                state.write_u8(8);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ObjectAssignmentTarget(it) => {
+                state.write_u8(9);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for ForOfStatement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.r#await, state);
+        ContentHash::content_hash(&self.left, state);
+        ContentHash::content_hash(&self.right, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for ContinueStatement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.label, state);
+    }
+}
+
+impl ContentHash for BreakStatement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.label, state);
+    }
+}
+
+impl ContentHash for ReturnStatement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.argument, state);
+    }
+}
+
+impl ContentHash for WithStatement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.object, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for SwitchStatement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.discriminant, state);
+        ContentHash::content_hash(&self.cases, state);
+    }
+}
+
+impl ContentHash for SwitchCase<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.test, state);
+        ContentHash::content_hash(&self.consequent, state);
+    }
+}
+
+impl ContentHash for LabeledStatement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.label, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for ThrowStatement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.argument, state);
+    }
+}
+
+impl ContentHash for TryStatement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.block, state);
+        ContentHash::content_hash(&self.handler, state);
+        ContentHash::content_hash(&self.finalizer, state);
+    }
+}
+
+impl ContentHash for CatchClause<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.param, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for CatchParameter<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.pattern, state);
+        ContentHash::content_hash(&self.type_annotation, state);
+    }
+}
+
+impl ContentHash for DebuggerStatement {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for BindingPattern<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::BindingIdentifier(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ObjectPattern(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrayPattern(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AssignmentPattern(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for AssignmentPattern<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.left, state);
+        ContentHash::content_hash(&self.right, state);
+    }
+}
+
+impl ContentHash for ObjectPattern<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.properties, state);
+        ContentHash::content_hash(&self.rest, state);
+    }
+}
+
+impl ContentHash for BindingProperty<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.key, state);
+        ContentHash::content_hash(&self.value, state);
+        ContentHash::content_hash(&self.shorthand, state);
+        ContentHash::content_hash(&self.computed, state);
+    }
+}
+
+impl ContentHash for ArrayPattern<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.elements, state);
+        ContentHash::content_hash(&self.rest, state);
+    }
+}
+
+impl ContentHash for BindingRestElement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.argument, state);
+    }
+}
+
+impl ContentHash for Function<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.r#type, state);
+        ContentHash::content_hash(&self.id, state);
+        ContentHash::content_hash(&self.generator, state);
+        ContentHash::content_hash(&self.r#async, state);
+        ContentHash::content_hash(&self.declare, state);
+        ContentHash::content_hash(&self.type_parameters, state);
+        ContentHash::content_hash(&self.this_param, state);
+        ContentHash::content_hash(&self.params, state);
+        ContentHash::content_hash(&self.return_type, state);
+        ContentHash::content_hash(&self.body, state);
+        ContentHash::content_hash(&self.pure, state);
+        ContentHash::content_hash(&self.pife, state);
+    }
+}
+
+impl ContentHash for FunctionType {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::FunctionDeclaration => state.write_u8(0),
+            Self::FunctionExpression => state.write_u8(1),
+            Self::TSDeclareFunction => state.write_u8(2),
+            Self::TSEmptyBodyFunctionExpression => state.write_u8(3),
+        }
+    }
+}
+
+impl ContentHash for FormalParameters<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.kind, state);
+        ContentHash::content_hash(&self.items, state);
+        ContentHash::content_hash(&self.rest, state);
+    }
+}
+
+impl ContentHash for FormalParameter<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.decorators, state);
+        ContentHash::content_hash(&self.pattern, state);
+        ContentHash::content_hash(&self.type_annotation, state);
+        ContentHash::content_hash(&self.initializer, state);
+        ContentHash::content_hash(&self.optional, state);
+        ContentHash::content_hash(&self.accessibility, state);
+        ContentHash::content_hash(&self.readonly, state);
+        ContentHash::content_hash(&self.r#override, state);
+    }
+}
+
+impl ContentHash for FormalParameterKind {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::FormalParameter => state.write_u8(0),
+            Self::UniqueFormalParameters => state.write_u8(1),
+            Self::ArrowFormalParameters => state.write_u8(2),
+            Self::Signature => state.write_u8(3),
+        }
+    }
+}
+
+impl ContentHash for FormalParameterRest<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.rest, state);
+        ContentHash::content_hash(&self.type_annotation, state);
+    }
+}
+
+impl ContentHash for FunctionBody<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.directives, state);
+        ContentHash::content_hash(&self.statements, state);
+    }
+}
+
+impl ContentHash for ArrowFunctionExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expression, state);
+        ContentHash::content_hash(&self.r#async, state);
+        ContentHash::content_hash(&self.type_parameters, state);
+        ContentHash::content_hash(&self.params, state);
+        ContentHash::content_hash(&self.return_type, state);
+        ContentHash::content_hash(&self.body, state);
+        ContentHash::content_hash(&self.pure, state);
+        ContentHash::content_hash(&self.pife, state);
+    }
+}
+
+impl ContentHash for YieldExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.delegate, state);
+        ContentHash::content_hash(&self.argument, state);
+    }
+}
+
+impl ContentHash for Class<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.r#type, state);
+        ContentHash::content_hash(&self.decorators, state);
+        ContentHash::content_hash(&self.id, state);
+        ContentHash::content_hash(&self.type_parameters, state);
+        ContentHash::content_hash(&self.super_class, state);
+        ContentHash::content_hash(&self.super_type_arguments, state);
+        ContentHash::content_hash(&self.implements, state);
+        ContentHash::content_hash(&self.body, state);
+        ContentHash::content_hash(&self.r#abstract, state);
+        ContentHash::content_hash(&self.declare, state);
+    }
+}
+
+impl ContentHash for ClassType {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::ClassDeclaration => state.write_u8(0),
+            Self::ClassExpression => state.write_u8(1),
+        }
+    }
+}
+
+impl ContentHash for ClassBody<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for ClassElement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::StaticBlock(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::MethodDefinition(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PropertyDefinition(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AccessorProperty(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSIndexSignature(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for MethodDefinition<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.r#type, state);
+        ContentHash::content_hash(&self.decorators, state);
+        ContentHash::content_hash(&self.key, state);
+        ContentHash::content_hash(&self.value, state);
+        ContentHash::content_hash(&self.kind, state);
+        ContentHash::content_hash(&self.computed, state);
+        ContentHash::content_hash(&self.r#static, state);
+        ContentHash::content_hash(&self.r#override, state);
+        ContentHash::content_hash(&self.optional, state);
+        ContentHash::content_hash(&self.accessibility, state);
+    }
+}
+
+impl ContentHash for MethodDefinitionType {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::MethodDefinition => state.write_u8(0),
+            Self::TSAbstractMethodDefinition => state.write_u8(1),
+        }
+    }
+}
+
+impl ContentHash for PropertyDefinition<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.r#type, state);
+        ContentHash::content_hash(&self.decorators, state);
+        ContentHash::content_hash(&self.key, state);
+        ContentHash::content_hash(&self.type_annotation, state);
+        ContentHash::content_hash(&self.value, state);
+        ContentHash::content_hash(&self.computed, state);
+        ContentHash::content_hash(&self.r#static, state);
+        ContentHash::content_hash(&self.declare, state);
+        ContentHash::content_hash(&self.r#override, state);
+        ContentHash::content_hash(&self.optional, state);
+        ContentHash::content_hash(&self.definite, state);
+        ContentHash::content_hash(&self.readonly, state);
+        ContentHash::content_hash(&self.accessibility, state);
+    }
+}
+
+impl ContentHash for PropertyDefinitionType {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::PropertyDefinition => state.write_u8(0),
+            Self::TSAbstractPropertyDefinition => state.write_u8(1),
+        }
+    }
+}
+
+impl ContentHash for MethodDefinitionKind {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Constructor => state.write_u8(0),
+            Self::Method => state.write_u8(1),
+            Self::Get => state.write_u8(2),
+            Self::Set => state.write_u8(3),
+        }
+    }
+}
+
+impl ContentHash for PrivateIdentifier<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.name, state);
+    }
+}
+
+impl ContentHash for StaticBlock<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for ModuleDeclaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::ImportDeclaration(it) => {
+                state.write_u8(64);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ExportAllDeclaration(it) => {
+                state.write_u8(65);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ExportDefaultDeclaration(it) => {
+                state.write_u8(66);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ExportNamedDeclaration(it) => {
+                state.write_u8(67);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSExportAssignment(it) => {
+                state.write_u8(68);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNamespaceExportDeclaration(it) => {
+                state.write_u8(69);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for AccessorPropertyType {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::AccessorProperty => state.write_u8(0),
+            Self::TSAbstractAccessorProperty => state.write_u8(1),
+        }
+    }
+}
+
+impl ContentHash for AccessorProperty<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.r#type, state);
+        ContentHash::content_hash(&self.decorators, state);
+        ContentHash::content_hash(&self.key, state);
+        ContentHash::content_hash(&self.type_annotation, state);
+        ContentHash::content_hash(&self.value, state);
+        ContentHash::content_hash(&self.computed, state);
+        ContentHash::content_hash(&self.r#static, state);
+        ContentHash::content_hash(&self.r#override, state);
+        ContentHash::content_hash(&self.definite, state);
+        ContentHash::content_hash(&self.accessibility, state);
+    }
+}
+
+impl ContentHash for ImportExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.source, state);
+        ContentHash::content_hash(&self.options, state);
+        ContentHash::content_hash(&self.phase, state);
+    }
+}
+
+impl ContentHash for ImportDeclaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.specifiers, state);
+        ContentHash::content_hash(&self.source, state);
+        ContentHash::content_hash(&self.phase, state);
+        ContentHash::content_hash(&self.with_clause, state);
+        ContentHash::content_hash(&self.import_kind, state);
+    }
+}
+
+impl ContentHash for ImportPhase {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Source => state.write_u8(0),
+            Self::Defer => state.write_u8(1),
+        }
+    }
+}
+
+impl ContentHash for ImportDeclarationSpecifier<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::ImportSpecifier(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ImportDefaultSpecifier(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ImportNamespaceSpecifier(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for ImportSpecifier<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.imported, state);
+        ContentHash::content_hash(&self.local, state);
+        ContentHash::content_hash(&self.import_kind, state);
+    }
+}
+
+impl ContentHash for ImportDefaultSpecifier<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.local, state);
+    }
+}
+
+impl ContentHash for ImportNamespaceSpecifier<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.local, state);
+    }
+}
+
+impl ContentHash for WithClause<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.keyword, state);
+        ContentHash::content_hash(&self.with_entries, state);
+    }
+}
+
+impl ContentHash for WithClauseKeyword {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::With => state.write_u8(0),
+            Self::Assert => state.write_u8(1),
+        }
+    }
+}
+
+impl ContentHash for ImportAttribute<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.key, state);
+        ContentHash::content_hash(&self.value, state);
+    }
+}
+
+impl ContentHash for ImportAttributeKey<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Identifier(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StringLiteral(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for ExportNamedDeclaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.declaration, state);
+        ContentHash::content_hash(&self.specifiers, state);
+        ContentHash::content_hash(&self.source, state);
+        ContentHash::content_hash(&self.export_kind, state);
+        ContentHash::content_hash(&self.with_clause, state);
+    }
+}
+
+impl ContentHash for ExportDefaultDeclaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.declaration, state);
+    }
+}
+
+impl ContentHash for ExportAllDeclaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.exported, state);
+        ContentHash::content_hash(&self.source, state);
+        ContentHash::content_hash(&self.with_clause, state);
+        ContentHash::content_hash(&self.export_kind, state);
+    }
+}
+
+impl ContentHash for ExportSpecifier<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.local, state);
+        ContentHash::content_hash(&self.exported, state);
+        ContentHash::content_hash(&self.export_kind, state);
+    }
+}
+
+impl ContentHash for ExportDefaultDeclarationKind<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::FunctionDeclaration(it) => {
+                state.write_u8(64);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ClassDeclaration(it) => {
+                state.write_u8(65);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSInterfaceDeclaration(it) => {
+                state.write_u8(66);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BooleanLiteral(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NullLiteral(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NumericLiteral(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BigIntLiteral(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::RegExpLiteral(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StringLiteral(it) => {
+                state.write_u8(5);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TemplateLiteral(it) => {
+                state.write_u8(6);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Identifier(it) => {
+                state.write_u8(7);
+                ContentHash::content_hash(it, state);
+            }
+            Self::MetaProperty(it) => {
+                state.write_u8(8);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Super(it) => {
+                state.write_u8(9);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrayExpression(it) => {
+                state.write_u8(10);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrowFunctionExpression(it) => {
+                state.write_u8(11);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AssignmentExpression(it) => {
+                state.write_u8(12);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AwaitExpression(it) => {
+                state.write_u8(13);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BinaryExpression(it) => {
+                state.write_u8(14);
+                ContentHash::content_hash(it, state);
+            }
+            Self::CallExpression(it) => {
+                state.write_u8(15);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ChainExpression(it) => {
+                state.write_u8(16);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ClassExpression(it) => {
+                state.write_u8(17);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ConditionalExpression(it) => {
+                state.write_u8(18);
+                ContentHash::content_hash(it, state);
+            }
+            Self::FunctionExpression(it) => {
+                state.write_u8(19);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ImportExpression(it) => {
+                state.write_u8(20);
+                ContentHash::content_hash(it, state);
+            }
+            Self::LogicalExpression(it) => {
+                state.write_u8(21);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NewExpression(it) => {
+                state.write_u8(22);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ObjectExpression(it) => {
+                state.write_u8(23);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ParenthesizedExpression(it) => {
+                state.write_u8(24);
+                ContentHash::content_hash(it, state);
+            }
+            Self::SequenceExpression(it) => {
+                state.write_u8(25);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TaggedTemplateExpression(it) => {
+                state.write_u8(26);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ThisExpression(it) => {
+                state.write_u8(27);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UnaryExpression(it) => {
+                state.write_u8(28);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UpdateExpression(it) => {
+                state.write_u8(29);
+                ContentHash::content_hash(it, state);
+            }
+            Self::YieldExpression(it) => {
+                state.write_u8(30);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateInExpression(it) => {
+                state.write_u8(31);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSXElement(it) => {
+                state.write_u8(32);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSXFragment(it) => {
+                state.write_u8(33);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSAsExpression(it) => {
+                state.write_u8(34);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                state.write_u8(35);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeAssertion(it) => {
+                state.write_u8(36);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNonNullExpression(it) => {
+                state.write_u8(37);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSInstantiationExpression(it) => {
+                state.write_u8(38);
+                ContentHash::content_hash(it, state);
+            }
+            Self::V8IntrinsicExpression(it) => {
+                state.write_u8(39);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PipelineExpression(it) => {
+                state.write_u8(40);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TopicReference(it) => {
+                state.write_u8(41);
+                ContentHash::content_hash(it, state);
+            }
+            Self::DoExpression(it) => {
+                state.write_u8(42);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ComputedMemberExpression(it) => {
+                state.write_u8(48);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StaticMemberExpression(it) => {
+                state.write_u8(49);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateFieldExpression(it) => {
+                state.write_u8(50);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for ModuleExportName<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::IdentifierName(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::IdentifierReference(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StringLiteral(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for V8IntrinsicExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.name, state);
+        ContentHash::content_hash(&self.arguments, state);
+    }
+}
+
+impl ContentHash for PipelineExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.head, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for TopicReference {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for DoExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for BooleanLiteral {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.value, state);
+    }
+}
+
+impl ContentHash for NullLiteral {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for NumericLiteral<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.value, state);
+    }
+}
+
+impl ContentHash for StringLiteral<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.value, state);
+        ContentHash::content_hash(&self.lone_surrogates, state);
+    }
+}
+
+impl ContentHash for BigIntLiteral<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.value, state);
+    }
+}
+
+impl ContentHash for RegExpLiteral<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.regex, state);
+    }
+}
+
+impl ContentHash for RegExp<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.pattern, state);
+        ContentHash::content_hash(&self.flags, state);
+    }
+}
+
+impl ContentHash for RegExpPattern<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.text, state);
+    }
+}
+
+impl ContentHash for JSXElement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.opening_element, state);
+        ContentHash::content_hash(&self.children, state);
+        ContentHash::content_hash(&self.closing_element, state);
+    }
+}
+
+impl ContentHash for JSXOpeningElement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.name, state);
+        ContentHash::content_hash(&self.type_arguments, state);
+        ContentHash::content_hash(&self.attributes, state);
+    }
+}
+
+impl ContentHash for JSXClosingElement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.name, state);
+    }
+}
+
+impl ContentHash for JSXFragment<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.opening_fragment, state);
+        ContentHash::content_hash(&self.children, state);
+        ContentHash::content_hash(&self.closing_fragment, state);
+    }
+}
+
+impl ContentHash for JSXOpeningFragment {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for JSXClosingFragment {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for JSXElementName<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Identifier(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::IdentifierReference(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NamespacedName(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::MemberExpression(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ThisExpression(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for JSXNamespacedName<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.namespace, state);
+        ContentHash::content_hash(&self.name, state);
+    }
+}
+
+impl ContentHash for JSXMemberExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.object, state);
+        ContentHash::content_hash(&self.property, state);
+    }
+}
+
+impl ContentHash for JSXMemberExpressionObject<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::IdentifierReference(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::MemberExpression(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ThisExpression(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for JSXExpressionContainer<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expression, state);
+    }
+}
+
+impl ContentHash for JSXExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::EmptyExpression(it) => {
+                state.write_u8(64);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BooleanLiteral(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NullLiteral(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NumericLiteral(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BigIntLiteral(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::RegExpLiteral(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StringLiteral(it) => {
+                state.write_u8(5);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TemplateLiteral(it) => {
+                state.write_u8(6);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Identifier(it) => {
+                state.write_u8(7);
+                ContentHash::content_hash(it, state);
+            }
+            Self::MetaProperty(it) => {
+                state.write_u8(8);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Super(it) => {
+                state.write_u8(9);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrayExpression(it) => {
+                state.write_u8(10);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ArrowFunctionExpression(it) => {
+                state.write_u8(11);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AssignmentExpression(it) => {
+                state.write_u8(12);
+                ContentHash::content_hash(it, state);
+            }
+            Self::AwaitExpression(it) => {
+                state.write_u8(13);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BinaryExpression(it) => {
+                state.write_u8(14);
+                ContentHash::content_hash(it, state);
+            }
+            Self::CallExpression(it) => {
+                state.write_u8(15);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ChainExpression(it) => {
+                state.write_u8(16);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ClassExpression(it) => {
+                state.write_u8(17);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ConditionalExpression(it) => {
+                state.write_u8(18);
+                ContentHash::content_hash(it, state);
+            }
+            Self::FunctionExpression(it) => {
+                state.write_u8(19);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ImportExpression(it) => {
+                state.write_u8(20);
+                ContentHash::content_hash(it, state);
+            }
+            Self::LogicalExpression(it) => {
+                state.write_u8(21);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NewExpression(it) => {
+                state.write_u8(22);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ObjectExpression(it) => {
+                state.write_u8(23);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ParenthesizedExpression(it) => {
+                state.write_u8(24);
+                ContentHash::content_hash(it, state);
+            }
+            Self::SequenceExpression(it) => {
+                state.write_u8(25);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TaggedTemplateExpression(it) => {
+                state.write_u8(26);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ThisExpression(it) => {
+                state.write_u8(27);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UnaryExpression(it) => {
+                state.write_u8(28);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UpdateExpression(it) => {
+                state.write_u8(29);
+                ContentHash::content_hash(it, state);
+            }
+            Self::YieldExpression(it) => {
+                state.write_u8(30);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateInExpression(it) => {
+                state.write_u8(31);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSXElement(it) => {
+                state.write_u8(32);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSXFragment(it) => {
+                state.write_u8(33);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSAsExpression(it) => {
+                state.write_u8(34);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                state.write_u8(35);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeAssertion(it) => {
+                state.write_u8(36);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNonNullExpression(it) => {
+                state.write_u8(37);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSInstantiationExpression(it) => {
+                state.write_u8(38);
+                ContentHash::content_hash(it, state);
+            }
+            Self::V8IntrinsicExpression(it) => {
+                state.write_u8(39);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PipelineExpression(it) => {
+                state.write_u8(40);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TopicReference(it) => {
+                state.write_u8(41);
+                ContentHash::content_hash(it, state);
+            }
+            Self::DoExpression(it) => {
+                state.write_u8(42);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ComputedMemberExpression(it) => {
+                state.write_u8(48);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StaticMemberExpression(it) => {
+                state.write_u8(49);
+                ContentHash::content_hash(it, state);
+            }
+            Self::PrivateFieldExpression(it) => {
+                state.write_u8(50);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for JSXEmptyExpression {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for JSXAttributeItem<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Attribute(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::SpreadAttribute(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for JSXAttribute<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.name, state);
+        ContentHash::content_hash(&self.value, state);
+    }
+}
+
+impl ContentHash for JSXSpreadAttribute<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.argument, state);
+    }
+}
+
+impl ContentHash for JSXAttributeName<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Identifier(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NamespacedName(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for JSXAttributeValue<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::StringLiteral(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ExpressionContainer(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Element(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Fragment(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for JSXIdentifier<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.name, state);
+    }
+}
+
+impl ContentHash for JSXChild<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Text(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Element(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Fragment(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ExpressionContainer(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Spread(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for JSXSpreadChild<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expression, state);
+    }
+}
+
+impl ContentHash for JSXText<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.value, state);
+    }
+}
+
+impl ContentHash for TSThisParameter<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.type_annotation, state);
+    }
+}
+
+impl ContentHash for TSEnumDeclaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.id, state);
+        ContentHash::content_hash(&self.body, state);
+        ContentHash::content_hash(&self.r#const, state);
+        ContentHash::content_hash(&self.declare, state);
+    }
+}
+
+impl ContentHash for TSEnumBody<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.members, state);
+    }
+}
+
+impl ContentHash for TSEnumMember<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.id, state);
+        ContentHash::content_hash(&self.initializer, state);
+    }
+}
+
+impl ContentHash for TSEnumMemberName<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Identifier(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::String(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ComputedString(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ComputedTemplateString(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for TSTypeAnnotation<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.type_annotation, state);
+    }
+}
+
+impl ContentHash for TSLiteralType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.literal, state);
+    }
+}
+
+impl ContentHash for TSLiteral<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::BooleanLiteral(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NumericLiteral(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::BigIntLiteral(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StringLiteral(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TemplateLiteral(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UnaryExpression(it) => {
+                state.write_u8(5);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for TSType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::TSAnyKeyword(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSBigIntKeyword(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSBooleanKeyword(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSIntrinsicKeyword(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNeverKeyword(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNullKeyword(it) => {
+                state.write_u8(5);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNumberKeyword(it) => {
+                state.write_u8(6);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSObjectKeyword(it) => {
+                state.write_u8(7);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSStringKeyword(it) => {
+                state.write_u8(8);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSSymbolKeyword(it) => {
+                state.write_u8(9);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSUndefinedKeyword(it) => {
+                state.write_u8(11);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSUnknownKeyword(it) => {
+                state.write_u8(12);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSVoidKeyword(it) => {
+                state.write_u8(13);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSArrayType(it) => {
+                state.write_u8(14);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSConditionalType(it) => {
+                state.write_u8(15);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSConstructorType(it) => {
+                state.write_u8(16);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSFunctionType(it) => {
+                state.write_u8(17);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSImportType(it) => {
+                state.write_u8(18);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSIndexedAccessType(it) => {
+                state.write_u8(19);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSInferType(it) => {
+                state.write_u8(20);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSIntersectionType(it) => {
+                state.write_u8(21);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSLiteralType(it) => {
+                state.write_u8(22);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSMappedType(it) => {
+                state.write_u8(23);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNamedTupleMember(it) => {
+                state.write_u8(24);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTemplateLiteralType(it) => {
+                state.write_u8(26);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSThisType(it) => {
+                state.write_u8(10);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTupleType(it) => {
+                state.write_u8(27);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeLiteral(it) => {
+                state.write_u8(28);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeOperatorType(it) => {
+                state.write_u8(29);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypePredicate(it) => {
+                state.write_u8(30);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeQuery(it) => {
+                state.write_u8(31);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeReference(it) => {
+                state.write_u8(32);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSUnionType(it) => {
+                state.write_u8(33);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSParenthesizedType(it) => {
+                state.write_u8(34);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSDocNullableType(it) => {
+                state.write_u8(35);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSDocNonNullableType(it) => {
+                state.write_u8(36);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSDocUnknownType(it) => {
+                state.write_u8(37);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for TSConditionalType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.check_type, state);
+        ContentHash::content_hash(&self.extends_type, state);
+        ContentHash::content_hash(&self.true_type, state);
+        ContentHash::content_hash(&self.false_type, state);
+    }
+}
+
+impl ContentHash for TSUnionType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.types, state);
+    }
+}
+
+impl ContentHash for TSIntersectionType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.types, state);
+    }
+}
+
+impl ContentHash for TSParenthesizedType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.type_annotation, state);
+    }
+}
+
+impl ContentHash for TSTypeOperator<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.operator, state);
+        ContentHash::content_hash(&self.type_annotation, state);
+    }
+}
+
+impl ContentHash for TSTypeOperatorOperator {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Keyof => state.write_u8(0),
+            Self::Unique => state.write_u8(1),
+            Self::Readonly => state.write_u8(2),
+        }
+    }
+}
+
+impl ContentHash for TSArrayType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.element_type, state);
+    }
+}
+
+impl ContentHash for TSIndexedAccessType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.object_type, state);
+        ContentHash::content_hash(&self.index_type, state);
+    }
+}
+
+impl ContentHash for TSTupleType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.element_types, state);
+    }
+}
+
+impl ContentHash for TSNamedTupleMember<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.label, state);
+        ContentHash::content_hash(&self.element_type, state);
+        ContentHash::content_hash(&self.optional, state);
+    }
+}
+
+impl ContentHash for TSOptionalType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.type_annotation, state);
+    }
+}
+
+impl ContentHash for TSRestType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.type_annotation, state);
+    }
+}
+
+impl ContentHash for TSTupleElement<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::TSOptionalType(it) => {
+                state.write_u8(64);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSRestType(it) => {
+                state.write_u8(65);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSAnyKeyword(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSBigIntKeyword(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSBooleanKeyword(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSIntrinsicKeyword(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNeverKeyword(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNullKeyword(it) => {
+                state.write_u8(5);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNumberKeyword(it) => {
+                state.write_u8(6);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSObjectKeyword(it) => {
+                state.write_u8(7);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSStringKeyword(it) => {
+                state.write_u8(8);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSSymbolKeyword(it) => {
+                state.write_u8(9);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSUndefinedKeyword(it) => {
+                state.write_u8(11);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSUnknownKeyword(it) => {
+                state.write_u8(12);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSVoidKeyword(it) => {
+                state.write_u8(13);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSArrayType(it) => {
+                state.write_u8(14);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSConditionalType(it) => {
+                state.write_u8(15);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSConstructorType(it) => {
+                state.write_u8(16);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSFunctionType(it) => {
+                state.write_u8(17);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSImportType(it) => {
+                state.write_u8(18);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSIndexedAccessType(it) => {
+                state.write_u8(19);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSInferType(it) => {
+                state.write_u8(20);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSIntersectionType(it) => {
+                state.write_u8(21);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSLiteralType(it) => {
+                state.write_u8(22);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSMappedType(it) => {
+                state.write_u8(23);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSNamedTupleMember(it) => {
+                state.write_u8(24);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTemplateLiteralType(it) => {
+                state.write_u8(26);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSThisType(it) => {
+                state.write_u8(10);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTupleType(it) => {
+                state.write_u8(27);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeLiteral(it) => {
+                state.write_u8(28);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeOperatorType(it) => {
+                state.write_u8(29);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypePredicate(it) => {
+                state.write_u8(30);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeQuery(it) => {
+                state.write_u8(31);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSTypeReference(it) => {
+                state.write_u8(32);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSUnionType(it) => {
+                state.write_u8(33);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSParenthesizedType(it) => {
+                state.write_u8(34);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSDocNullableType(it) => {
+                state.write_u8(35);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSDocNonNullableType(it) => {
+                state.write_u8(36);
+                ContentHash::content_hash(it, state);
+            }
+            Self::JSDocUnknownType(it) => {
+                state.write_u8(37);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for TSAnyKeyword {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for TSStringKeyword {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for TSBooleanKeyword {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for TSNumberKeyword {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for TSNeverKeyword {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for TSIntrinsicKeyword {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for TSUnknownKeyword {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for TSNullKeyword {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for TSUndefinedKeyword {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for TSVoidKeyword {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for TSSymbolKeyword {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for TSThisType {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for TSObjectKeyword {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for TSBigIntKeyword {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for TSTypeReference<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.type_name, state);
+        ContentHash::content_hash(&self.type_arguments, state);
+    }
+}
+
+impl ContentHash for TSTypeName<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::IdentifierReference(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::QualifiedName(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ThisExpression(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for TSQualifiedName<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.left, state);
+        ContentHash::content_hash(&self.right, state);
+    }
+}
+
+impl ContentHash for TSTypeParameterInstantiation<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.params, state);
+    }
+}
+
+impl ContentHash for TSTypeParameter<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.name, state);
+        ContentHash::content_hash(&self.constraint, state);
+        ContentHash::content_hash(&self.default, state);
+        ContentHash::content_hash(&self.r#in, state);
+        ContentHash::content_hash(&self.out, state);
+        ContentHash::content_hash(&self.r#const, state);
+    }
+}
+
+impl ContentHash for TSTypeParameterDeclaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.params, state);
+    }
+}
+
+impl ContentHash for TSTypeAliasDeclaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.id, state);
+        ContentHash::content_hash(&self.type_parameters, state);
+        ContentHash::content_hash(&self.type_annotation, state);
+        ContentHash::content_hash(&self.declare, state);
+    }
+}
+
+impl ContentHash for TSAccessibility {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Private => state.write_u8(0),
+            Self::Protected => state.write_u8(1),
+            Self::Public => state.write_u8(2),
+        }
+    }
+}
+
+impl ContentHash for TSClassImplements<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expression, state);
+        ContentHash::content_hash(&self.type_arguments, state);
+    }
+}
+
+impl ContentHash for TSInterfaceDeclaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.id, state);
+        ContentHash::content_hash(&self.type_parameters, state);
+        ContentHash::content_hash(&self.extends, state);
+        ContentHash::content_hash(&self.body, state);
+        ContentHash::content_hash(&self.declare, state);
+    }
+}
+
+impl ContentHash for TSInterfaceBody<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for TSPropertySignature<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.computed, state);
+        ContentHash::content_hash(&self.optional, state);
+        ContentHash::content_hash(&self.readonly, state);
+        ContentHash::content_hash(&self.key, state);
+        ContentHash::content_hash(&self.type_annotation, state);
+    }
+}
+
+impl ContentHash for TSSignature<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::TSIndexSignature(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSPropertySignature(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSCallSignatureDeclaration(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSConstructSignatureDeclaration(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSMethodSignature(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for TSIndexSignature<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.parameters, state);
+        ContentHash::content_hash(&self.type_annotation, state);
+        ContentHash::content_hash(&self.readonly, state);
+        ContentHash::content_hash(&self.r#static, state);
+    }
+}
+
+impl ContentHash for TSCallSignatureDeclaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.type_parameters, state);
+        ContentHash::content_hash(&self.this_param, state);
+        ContentHash::content_hash(&self.params, state);
+        ContentHash::content_hash(&self.return_type, state);
+    }
+}
+
+impl ContentHash for TSMethodSignatureKind {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Method => state.write_u8(0),
+            Self::Get => state.write_u8(1),
+            Self::Set => state.write_u8(2),
+        }
+    }
+}
+
+impl ContentHash for TSMethodSignature<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.key, state);
+        ContentHash::content_hash(&self.computed, state);
+        ContentHash::content_hash(&self.optional, state);
+        ContentHash::content_hash(&self.kind, state);
+        ContentHash::content_hash(&self.type_parameters, state);
+        ContentHash::content_hash(&self.this_param, state);
+        ContentHash::content_hash(&self.params, state);
+        ContentHash::content_hash(&self.return_type, state);
+    }
+}
+
+impl ContentHash for TSConstructSignatureDeclaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.type_parameters, state);
+        ContentHash::content_hash(&self.params, state);
+        ContentHash::content_hash(&self.return_type, state);
+    }
+}
+
+impl ContentHash for TSIndexSignatureName<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.name, state);
+        ContentHash::content_hash(&self.type_annotation, state);
+    }
+}
+
+impl ContentHash for TSInterfaceHeritage<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expression, state);
+        ContentHash::content_hash(&self.type_arguments, state);
+    }
+}
+
+impl ContentHash for TSTypePredicate<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.parameter_name, state);
+        ContentHash::content_hash(&self.asserts, state);
+        ContentHash::content_hash(&self.type_annotation, state);
+    }
+}
+
+impl ContentHash for TSTypePredicateName<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Identifier(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::This(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for TSModuleDeclaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.id, state);
+        ContentHash::content_hash(&self.body, state);
+        ContentHash::content_hash(&self.kind, state);
+        ContentHash::content_hash(&self.declare, state);
+    }
+}
+
+impl ContentHash for TSModuleDeclarationKind {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Module => state.write_u8(0),
+            Self::Namespace => state.write_u8(1),
+        }
+    }
+}
+
+impl ContentHash for TSModuleDeclarationName<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Identifier(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::StringLiteral(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for TSModuleDeclarationBody<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::TSModuleDeclaration(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::TSModuleBlock(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for TSGlobalDeclaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.body, state);
+        ContentHash::content_hash(&self.declare, state);
+    }
+}
+
+impl ContentHash for TSModuleBlock<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.directives, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for TSTypeLiteral<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.members, state);
+    }
+}
+
+impl ContentHash for TSInferType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.type_parameter, state);
+    }
+}
+
+impl ContentHash for TSTypeQuery<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expr_name, state);
+        ContentHash::content_hash(&self.type_arguments, state);
+    }
+}
+
+impl ContentHash for TSTypeQueryExprName<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::TSImportType(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::IdentifierReference(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::QualifiedName(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ThisExpression(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for TSImportType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.source, state);
+        ContentHash::content_hash(&self.options, state);
+        ContentHash::content_hash(&self.qualifier, state);
+        ContentHash::content_hash(&self.type_arguments, state);
+    }
+}
+
+impl ContentHash for TSImportTypeQualifier<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Identifier(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::QualifiedName(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for TSImportTypeQualifiedName<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.left, state);
+        ContentHash::content_hash(&self.right, state);
+    }
+}
+
+impl ContentHash for TSFunctionType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.type_parameters, state);
+        ContentHash::content_hash(&self.this_param, state);
+        ContentHash::content_hash(&self.params, state);
+        ContentHash::content_hash(&self.return_type, state);
+    }
+}
+
+impl ContentHash for TSConstructorType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.r#abstract, state);
+        ContentHash::content_hash(&self.type_parameters, state);
+        ContentHash::content_hash(&self.params, state);
+        ContentHash::content_hash(&self.return_type, state);
+    }
+}
+
+impl ContentHash for TSMappedType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.type_parameter, state);
+        ContentHash::content_hash(&self.name_type, state);
+        ContentHash::content_hash(&self.type_annotation, state);
+        ContentHash::content_hash(&self.optional, state);
+        ContentHash::content_hash(&self.readonly, state);
+    }
+}
+
+impl ContentHash for TSMappedTypeModifierOperator {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::True => state.write_u8(0),
+            Self::Plus => state.write_u8(1),
+            Self::Minus => state.write_u8(2),
+        }
+    }
+}
+
+impl ContentHash for TSTemplateLiteralType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.quasis, state);
+        ContentHash::content_hash(&self.types, state);
+    }
+}
+
+impl ContentHash for TSAsExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expression, state);
+        ContentHash::content_hash(&self.type_annotation, state);
+    }
+}
+
+impl ContentHash for TSSatisfiesExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expression, state);
+        ContentHash::content_hash(&self.type_annotation, state);
+    }
+}
+
+impl ContentHash for TSTypeAssertion<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.type_annotation, state);
+        ContentHash::content_hash(&self.expression, state);
+    }
+}
+
+impl ContentHash for TSImportEqualsDeclaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.id, state);
+        ContentHash::content_hash(&self.module_reference, state);
+        ContentHash::content_hash(&self.import_kind, state);
+    }
+}
+
+impl ContentHash for TSModuleReference<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::ExternalModuleReference(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::IdentifierReference(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::QualifiedName(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ThisExpression(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for TSExternalModuleReference<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expression, state);
+    }
+}
+
+impl ContentHash for TSNonNullExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expression, state);
+    }
+}
+
+impl ContentHash for Decorator<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expression, state);
+    }
+}
+
+impl ContentHash for TSExportAssignment<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expression, state);
+    }
+}
+
+impl ContentHash for TSNamespaceExportDeclaration<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.id, state);
+    }
+}
+
+impl ContentHash for TSInstantiationExpression<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.expression, state);
+        ContentHash::content_hash(&self.type_arguments, state);
+    }
+}
+
+impl ContentHash for ImportOrExportKind {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Value => state.write_u8(0),
+            Self::Type => state.write_u8(1),
+        }
+    }
+}
+
+impl ContentHash for JSDocNullableType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.type_annotation, state);
+        ContentHash::content_hash(&self.postfix, state);
+    }
+}
+
+impl ContentHash for JSDocNonNullableType<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.type_annotation, state);
+        ContentHash::content_hash(&self.postfix, state);
+    }
+}
+
+impl ContentHash for JSDocUnknownType {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for CommentKind {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Line => state.write_u8(0),
+            Self::SingleLineBlock => state.write_u8(1),
+            Self::MultiLineBlock => state.write_u8(2),
+        }
+    }
+}
+
+impl ContentHash for CommentPosition {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Leading => state.write_u8(0),
+            Self::Trailing => state.write_u8(1),
+        }
+    }
+}
+
+impl ContentHash for CommentContent {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::None => state.write_u8(0),
+            Self::Legal => state.write_u8(1),
+            Self::Jsdoc => state.write_u8(2),
+            Self::JsdocLegal => state.write_u8(3),
+            Self::Pure => state.write_u8(4),
+            Self::NoSideEffects => state.write_u8(5),
+            Self::Webpack => state.write_u8(6),
+            Self::Vite => state.write_u8(7),
+            Self::CoverageIgnore => state.write_u8(8),
+        }
+    }
+}
+
+impl ContentHash for Comment {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.attached_to, state);
+        ContentHash::content_hash(&self.kind, state);
+        ContentHash::content_hash(&self.position, state);
+        ContentHash::content_hash(&self.newlines, state);
+        ContentHash::content_hash(&self.content, state);
+    }
+}
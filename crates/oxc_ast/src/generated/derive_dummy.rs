@@ -1566,6 +1566,37 @@ impl<'a> Dummy<'a> for V8IntrinsicExpression<'a> {
     }
 }
 
+impl<'a> Dummy<'a> for PipelineExpression<'a> {
+    /// Create a dummy [`PipelineExpression`].
+    ///
+    /// Has cost of making 2 allocations (16 bytes).
+    fn dummy(allocator: &'a Allocator) -> Self {
+        Self {
+            span: Dummy::dummy(allocator),
+            head: Dummy::dummy(allocator),
+            body: Dummy::dummy(allocator),
+        }
+    }
+}
+
+impl<'a> Dummy<'a> for TopicReference {
+    /// Create a dummy [`TopicReference`].
+    ///
+    /// Does not allocate any data into arena.
+    fn dummy(allocator: &'a Allocator) -> Self {
+        Self { span: Dummy::dummy(allocator) }
+    }
+}
+
+impl<'a> Dummy<'a> for DoExpression<'a> {
+    /// Create a dummy [`DoExpression`].
+    ///
+    /// Has cost of making 1 allocation (40 bytes).
+    fn dummy(allocator: &'a Allocator) -> Self {
+        Self { span: Dummy::dummy(allocator), body: Dummy::dummy(allocator) }
+    }
+}
+
 impl<'a> Dummy<'a> for BooleanLiteral {
     /// Create a dummy [`BooleanLiteral`].
     ///
@@ -54,6 +54,9 @@ impl GetAddress for Expression<'_> {
             Self::TSNonNullExpression(it) => GetAddress::address(it),
             Self::TSInstantiationExpression(it) => GetAddress::address(it),
             Self::V8IntrinsicExpression(it) => GetAddress::address(it),
+            Self::PipelineExpression(it) => GetAddress::address(it),
+            Self::TopicReference(it) => GetAddress::address(it),
+            Self::DoExpression(it) => GetAddress::address(it),
             Self::ComputedMemberExpression(it) => GetAddress::address(it),
             Self::StaticMemberExpression(it) => GetAddress::address(it),
             Self::PrivateFieldExpression(it) => GetAddress::address(it),
@@ -119,6 +122,9 @@ impl GetAddress for PropertyKey<'_> {
             Self::TSNonNullExpression(it) => GetAddress::address(it),
             Self::TSInstantiationExpression(it) => GetAddress::address(it),
             Self::V8IntrinsicExpression(it) => GetAddress::address(it),
+            Self::PipelineExpression(it) => GetAddress::address(it),
+            Self::TopicReference(it) => GetAddress::address(it),
+            Self::DoExpression(it) => GetAddress::address(it),
             Self::ComputedMemberExpression(it) => GetAddress::address(it),
             Self::StaticMemberExpression(it) => GetAddress::address(it),
             Self::PrivateFieldExpression(it) => GetAddress::address(it),
@@ -184,6 +190,9 @@ impl GetAddress for Argument<'_> {
             Self::TSNonNullExpression(it) => GetAddress::address(it),
             Self::TSInstantiationExpression(it) => GetAddress::address(it),
             Self::V8IntrinsicExpression(it) => GetAddress::address(it),
+            Self::PipelineExpression(it) => GetAddress::address(it),
+            Self::TopicReference(it) => GetAddress::address(it),
+            Self::DoExpression(it) => GetAddress::address(it),
             Self::ComputedMemberExpression(it) => GetAddress::address(it),
             Self::StaticMemberExpression(it) => GetAddress::address(it),
             Self::PrivateFieldExpression(it) => GetAddress::address(it),
@@ -389,6 +398,9 @@ impl GetAddress for ForStatementInit<'_> {
             Self::TSNonNullExpression(it) => GetAddress::address(it),
             Self::TSInstantiationExpression(it) => GetAddress::address(it),
             Self::V8IntrinsicExpression(it) => GetAddress::address(it),
+            Self::PipelineExpression(it) => GetAddress::address(it),
+            Self::TopicReference(it) => GetAddress::address(it),
+            Self::DoExpression(it) => GetAddress::address(it),
             Self::ComputedMemberExpression(it) => GetAddress::address(it),
             Self::StaticMemberExpression(it) => GetAddress::address(it),
             Self::PrivateFieldExpression(it) => GetAddress::address(it),
@@ -518,6 +530,9 @@ impl GetAddress for ExportDefaultDeclarationKind<'_> {
             Self::TSNonNullExpression(it) => GetAddress::address(it),
             Self::TSInstantiationExpression(it) => GetAddress::address(it),
             Self::V8IntrinsicExpression(it) => GetAddress::address(it),
+            Self::PipelineExpression(it) => GetAddress::address(it),
+            Self::TopicReference(it) => GetAddress::address(it),
+            Self::DoExpression(it) => GetAddress::address(it),
             Self::ComputedMemberExpression(it) => GetAddress::address(it),
             Self::StaticMemberExpression(it) => GetAddress::address(it),
             Self::PrivateFieldExpression(it) => GetAddress::address(it),
@@ -60,6 +60,9 @@ impl GetSpan for Expression<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
+            Self::DoExpression(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -154,6 +157,9 @@ impl GetSpan for ArrayExpressionElement<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
+            Self::DoExpression(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -236,6 +242,9 @@ impl GetSpan for PropertyKey<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
+            Self::DoExpression(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -367,6 +376,9 @@ impl GetSpan for Argument<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
+            Self::DoExpression(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -757,6 +769,9 @@ impl GetSpan for ForStatementInit<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
+            Self::DoExpression(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -1191,6 +1206,9 @@ impl GetSpan for ExportDefaultDeclarationKind<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
+            Self::DoExpression(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -1215,6 +1233,27 @@ impl GetSpan for V8IntrinsicExpression<'_> {
     }
 }
 
+impl GetSpan for PipelineExpression<'_> {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl GetSpan for TopicReference {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl GetSpan for DoExpression<'_> {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
 impl GetSpan for BooleanLiteral {
     #[inline]
     fn span(&self) -> Span {
@@ -1386,6 +1425,9 @@ impl GetSpan for JSXExpression<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
+            Self::DoExpression(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -60,6 +60,9 @@ impl GetSpanMut for Expression<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
+            Self::DoExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -154,6 +157,9 @@ impl GetSpanMut for ArrayExpressionElement<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
+            Self::DoExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -236,6 +242,9 @@ impl GetSpanMut for PropertyKey<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
+            Self::DoExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -367,6 +376,9 @@ impl GetSpanMut for Argument<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
+            Self::DoExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -757,6 +769,9 @@ impl GetSpanMut for ForStatementInit<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
+            Self::DoExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -1191,6 +1206,9 @@ impl GetSpanMut for ExportDefaultDeclarationKind<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
+            Self::DoExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -1215,6 +1233,27 @@ impl GetSpanMut for V8IntrinsicExpression<'_> {
     }
 }
 
+impl GetSpanMut for PipelineExpression<'_> {
+    #[inline]
+    fn span_mut(&mut self) -> &mut Span {
+        &mut self.span
+    }
+}
+
+impl GetSpanMut for TopicReference {
+    #[inline]
+    fn span_mut(&mut self) -> &mut Span {
+        &mut self.span
+    }
+}
+
+impl GetSpanMut for DoExpression<'_> {
+    #[inline]
+    fn span_mut(&mut self) -> &mut Span {
+        &mut self.span
+    }
+}
+
 impl GetSpanMut for BooleanLiteral {
     #[inline]
     fn span_mut(&mut self) -> &mut Span {
@@ -1386,6 +1425,9 @@ impl GetSpanMut for JSXExpression<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
+            Self::DoExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
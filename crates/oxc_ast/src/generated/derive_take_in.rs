@@ -246,6 +246,12 @@ impl<'a> TakeIn<'a> for ModuleExportName<'a> {}
 
 impl<'a> TakeIn<'a> for V8IntrinsicExpression<'a> {}
 
+impl<'a> TakeIn<'a> for PipelineExpression<'a> {}
+
+impl<'a> TakeIn<'a> for TopicReference {}
+
+impl<'a> TakeIn<'a> for DoExpression<'a> {}
+
 impl<'a> TakeIn<'a> for BooleanLiteral {}
 
 impl<'a> TakeIn<'a> for NullLiteral {}
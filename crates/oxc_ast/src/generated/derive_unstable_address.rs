@@ -196,6 +196,12 @@ impl UnstableAddress for ExportSpecifier<'_> {}
 
 impl UnstableAddress for V8IntrinsicExpression<'_> {}
 
+impl UnstableAddress for PipelineExpression<'_> {}
+
+impl UnstableAddress for TopicReference {}
+
+impl UnstableAddress for DoExpression<'_> {}
+
 impl UnstableAddress for BooleanLiteral {}
 
 impl UnstableAddress for NullLiteral {}
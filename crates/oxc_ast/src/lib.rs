@@ -23,6 +23,7 @@
 //!
 //! ## Cargo Features
 //! * `"serialize"` enables support for serialization to ESTree JSON
+//! * `"deserialize"` enables support for building an AST from ESTree JSON
 //!
 //! [`BindingIdentifier`]: ast::BindingIdentifier
 //! [`IdentifierReference`]: ast::IdentifierReference
@@ -43,6 +44,8 @@
 
 #![warn(missing_docs)]
 
+#[cfg(feature = "deserialize")]
+mod deserialize;
 #[cfg(feature = "serialize")]
 mod serialize;
 
@@ -51,6 +54,7 @@ mod ast_builder_impl;
 mod ast_impl;
 mod ast_kind_impl;
 pub mod precedence;
+mod triple_slash;
 mod trivia;
 
 mod generated {
@@ -61,6 +65,7 @@ mod generated {
     mod ast_builder;
     mod derive_clone_in;
     mod derive_content_eq;
+    mod derive_content_hash;
     mod derive_dummy;
     #[cfg(feature = "serialize")]
     mod derive_estree;
@@ -79,6 +84,7 @@ pub use crate::{
     ast_builder_impl::{AstBuilder, NONE},
     ast_kind::{AstKind, AstType},
     ast_kind_impl::{MemberExpressionKind, ModuleDeclarationKind},
+    triple_slash::{TripleSlashDirective, TripleSlashDirectiveKind, triple_slash_directives},
     trivia::{
         CommentsRange, comments_range, get_comment_at, has_comments_between, is_inside_comment,
     },
@@ -19,7 +19,7 @@ use basic::{EmptyArray, Null};
 
 /// Main serialization methods for `Program`.
 ///
-/// Note: 8 separate methods for the different serialization options, rather than 1 method
+/// Note: Separate methods for the different serialization options, rather than 1 method
 /// with behavior controlled by flags
 /// (e.g. `fn to_estree_json(&self, with_ts: bool, pretty: bool, fixes: bool)`)
 /// to avoid bloating binary size.
@@ -44,6 +44,8 @@ use basic::{EmptyArray, Null};
 /// so have gone on the generous side.
 const JSON_CAPACITY_RATIO_COMPACT: usize = 16;
 const JSON_CAPACITY_RATIO_PRETTY: usize = 80;
+/// `loc` fields roughly double the size of compact JSON.
+const JSON_CAPACITY_RATIO_COMPACT_LOC: usize = 32;
 
 impl Program<'_> {
     /// Serialize AST to ESTree JSON, including TypeScript fields.
@@ -62,6 +64,30 @@ impl Program<'_> {
         serializer.into_string()
     }
 
+    /// Serialize AST to ESTree JSON, including TypeScript fields, with `loc` fields.
+    ///
+    /// `loc` fields contain the line and column of the start and end of each node,
+    /// with lines starting at 1 and columns starting at 0, in UTF-16 code units.
+    pub fn to_estree_ts_json_with_loc(&self, ranges: bool) -> String {
+        let capacity = self.source_text.len() * JSON_CAPACITY_RATIO_COMPACT_LOC;
+        let mut serializer =
+            CompactTSSerializer::with_capacity(capacity, ranges).with_loc(self.source_text);
+        self.serialize(&mut serializer);
+        serializer.into_string()
+    }
+
+    /// Serialize AST to ESTree JSON, without TypeScript fields, with `loc` fields.
+    ///
+    /// `loc` fields contain the line and column of the start and end of each node,
+    /// with lines starting at 1 and columns starting at 0, in UTF-16 code units.
+    pub fn to_estree_js_json_with_loc(&self, ranges: bool) -> String {
+        let capacity = self.source_text.len() * JSON_CAPACITY_RATIO_COMPACT_LOC;
+        let mut serializer =
+            CompactJSSerializer::with_capacity(capacity, ranges).with_loc(self.source_text);
+        self.serialize(&mut serializer);
+        serializer.into_string()
+    }
+
     /// Serialize AST to pretty-printed ESTree JSON, including TypeScript fields.
     pub fn to_pretty_estree_ts_json(&self, ranges: bool) -> String {
         let capacity = self.source_text.len() * JSON_CAPACITY_RATIO_PRETTY;
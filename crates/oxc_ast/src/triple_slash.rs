@@ -0,0 +1,151 @@
+//! Triple-slash directives
+//!
+//! `/// <reference path="..." />`, `/// <reference types="..." />` and `/// <reference lib="..." />`
+//! comments at the top of a file, which TypeScript uses to include other files, type packages and
+//! built-in libraries.
+//!
+//! <https://www.typescriptlang.org/docs/handbook/triple-slash-directives.html>
+
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::ast::{Comment, CommentKind, Program};
+
+/// A `/// <reference ... />` directive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TripleSlashDirective<'a> {
+    /// Span of the comment.
+    pub span: Span,
+    /// Kind of the directive, which is the attribute it references with.
+    pub kind: TripleSlashDirectiveKind,
+    /// Value of the attribute, e.g. `node` in `/// <reference types="node" />`.
+    pub value: Atom<'a>,
+    /// Span of the value, excluding quotes.
+    pub value_span: Span,
+    /// Value of the `resolution-mode` attribute, i.e. `import` or `require`, if it has one.
+    pub resolution_mode: Option<Atom<'a>>,
+}
+
+/// Kind of a [`TripleSlashDirective`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TripleSlashDirectiveKind {
+    /// `/// <reference path="..." />`: a file.
+    Path,
+    /// `/// <reference types="..." />`: a package of types.
+    Types,
+    /// `/// <reference lib="..." />`: a built-in library, e.g. `es2022`.
+    Lib,
+}
+
+impl TripleSlashDirectiveKind {
+    /// Name of the attribute of the directive.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Path => "path",
+            Self::Types => "types",
+            Self::Lib => "lib",
+        }
+    }
+}
+
+/// Triple-slash directives of `program`, in source order.
+///
+/// As in TypeScript, only the comments before the first statement or directive are directives.
+/// Malformed directives are ignored.
+pub fn triple_slash_directives<'a>(program: &Program<'a>) -> Vec<TripleSlashDirective<'a>> {
+    let end = program
+        .directives
+        .first()
+        .map(GetSpan::span)
+        .or_else(|| program.body.first().map(GetSpan::span))
+        .map_or(program.span.end, |span| span.start);
+    program
+        .comments
+        .iter()
+        .take_while(|comment| comment.span.end <= end)
+        .filter_map(|comment| parse_directive(comment, program.source_text))
+        .collect()
+}
+
+fn parse_directive<'a>(
+    comment: &Comment,
+    source_text: &'a str,
+) -> Option<TripleSlashDirective<'a>> {
+    if comment.kind != CommentKind::Line {
+        return None;
+    }
+    let content_span = comment.content_span();
+    let content = content_span.source_text(source_text).strip_prefix('/')?;
+    let rest = content.trim_start().strip_prefix("<reference")?;
+    if !rest.starts_with(|c: char| c.is_ascii_whitespace()) {
+        return None;
+    }
+    // Offset of `rest` in the source text, after `//` and the third `/`
+    let offset = content_span.start as usize + 1 + content.len() - rest.len();
+
+    let mut directive: Option<(TripleSlashDirectiveKind, Atom<'a>, Span)> = None;
+    let mut resolution_mode = None;
+    let mut attributes = Attributes { text: rest, pos: 0 };
+    loop {
+        attributes.skip_whitespace();
+        if attributes.rest().starts_with("/>") {
+            break;
+        }
+        let (name, value_start, value) = attributes.next_attribute()?;
+        #[expect(clippy::cast_possible_truncation)]
+        let value_span = Span::sized((offset + value_start) as u32, value.len() as u32);
+        let kind = match name {
+            "path" => TripleSlashDirectiveKind::Path,
+            "types" => TripleSlashDirectiveKind::Types,
+            "lib" => TripleSlashDirectiveKind::Lib,
+            "resolution-mode" => {
+                resolution_mode = Some(Atom::from(value));
+                continue;
+            }
+            _ => continue,
+        };
+        // The first of `path`, `types` and `lib` wins, as in TypeScript
+        directive.get_or_insert((kind, Atom::from(value), value_span));
+    }
+
+    let (kind, value, value_span) = directive?;
+    Some(TripleSlashDirective { span: comment.span, kind, value, value_span, resolution_mode })
+}
+
+/// Attributes of a `<reference` tag, e.g. `path="a.d.ts"`.
+struct Attributes<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Attributes<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Next attribute as its name, the offset of its value and its value.
+    fn next_attribute(&mut self) -> Option<(&'a str, usize, &'a str)> {
+        let rest = self.rest();
+        let name_len = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))?;
+        if name_len == 0 {
+            return None;
+        }
+        let name = &rest[..name_len];
+        self.pos += name_len;
+        self.skip_whitespace();
+        self.rest().strip_prefix('=')?;
+        self.pos += 1;
+        self.skip_whitespace();
+        let rest = self.rest();
+        let quote = rest.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+        let value_len = rest[1..].find(quote)?;
+        let value_start = self.pos + 1;
+        let value = &rest[1..=value_len];
+        self.pos += value_len + 2;
+        Some((name, value_start, value))
+    }
+}
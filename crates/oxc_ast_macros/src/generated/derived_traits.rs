@@ -14,6 +14,7 @@ pub fn get_trait_crate_and_generics(trait_name: &str) -> Option<(TokenStream, To
         "GetSpan" => (quote!(::oxc_span::GetSpan), TokenStream::new()),
         "GetSpanMut" => (quote!(::oxc_span::GetSpanMut), TokenStream::new()),
         "ContentEq" => (quote!(::oxc_span::ContentEq), TokenStream::new()),
+        "ContentHash" => (quote!(::oxc_span::ContentHash), TokenStream::new()),
         "ESTree" => (quote!(::oxc_estree::ESTree), TokenStream::new()),
         _ => return None,
     };
@@ -562,6 +562,24 @@ impl<'a> VisitMut<'a> for Utf8ToUtf16Converter<'_> {
         self.convert_offset(&mut it.span.end);
     }
 
+    fn visit_pipeline_expression(&mut self, it: &mut PipelineExpression<'a>) {
+        self.convert_offset(&mut it.span.start);
+        walk_mut::walk_pipeline_expression(self, it);
+        self.convert_offset(&mut it.span.end);
+    }
+
+    fn visit_topic_reference(&mut self, it: &mut TopicReference) {
+        self.convert_offset(&mut it.span.start);
+        walk_mut::walk_topic_reference(self, it);
+        self.convert_offset(&mut it.span.end);
+    }
+
+    fn visit_do_expression(&mut self, it: &mut DoExpression<'a>) {
+        self.convert_offset(&mut it.span.start);
+        walk_mut::walk_do_expression(self, it);
+        self.convert_offset(&mut it.span.end);
+    }
+
     fn visit_boolean_literal(&mut self, it: &mut BooleanLiteral) {
         self.convert_offset(&mut it.span.start);
         walk_mut::walk_boolean_literal(self, it);
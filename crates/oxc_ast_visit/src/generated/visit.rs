@@ -631,6 +631,21 @@ pub trait Visit<'a>: Sized {
         walk_v_8_intrinsic_expression(self, it);
     }
 
+    #[inline]
+    fn visit_pipeline_expression(&mut self, it: &PipelineExpression<'a>) {
+        walk_pipeline_expression(self, it);
+    }
+
+    #[inline]
+    fn visit_topic_reference(&mut self, it: &TopicReference) {
+        walk_topic_reference(self, it);
+    }
+
+    #[inline]
+    fn visit_do_expression(&mut self, it: &DoExpression<'a>) {
+        walk_do_expression(self, it);
+    }
+
     #[inline]
     fn visit_boolean_literal(&mut self, it: &BooleanLiteral) {
         walk_boolean_literal(self, it);
@@ -1425,6 +1440,9 @@ pub mod walk {
                 visitor.visit_ts_instantiation_expression(it)
             }
             Expression::V8IntrinsicExpression(it) => visitor.visit_v_8_intrinsic_expression(it),
+            Expression::PipelineExpression(it) => visitor.visit_pipeline_expression(it),
+            Expression::TopicReference(it) => visitor.visit_topic_reference(it),
+            Expression::DoExpression(it) => visitor.visit_do_expression(it),
             match_member_expression!(Expression) => {
                 visitor.visit_member_expression(it.to_member_expression())
             }
@@ -2908,6 +2926,36 @@ pub mod walk {
         visitor.leave_node(kind);
     }
 
+    #[inline]
+    pub fn walk_pipeline_expression<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &PipelineExpression<'a>,
+    ) {
+        let kind = AstKind::PipelineExpression(visitor.alloc(it));
+        visitor.enter_node(kind);
+        visitor.visit_span(&it.span);
+        visitor.visit_expression(&it.head);
+        visitor.visit_expression(&it.body);
+        visitor.leave_node(kind);
+    }
+
+    #[inline]
+    pub fn walk_topic_reference<'a, V: Visit<'a>>(visitor: &mut V, it: &TopicReference) {
+        let kind = AstKind::TopicReference(visitor.alloc(it));
+        visitor.enter_node(kind);
+        visitor.visit_span(&it.span);
+        visitor.leave_node(kind);
+    }
+
+    #[inline]
+    pub fn walk_do_expression<'a, V: Visit<'a>>(visitor: &mut V, it: &DoExpression<'a>) {
+        let kind = AstKind::DoExpression(visitor.alloc(it));
+        visitor.enter_node(kind);
+        visitor.visit_span(&it.span);
+        visitor.visit_block_statement(&it.body);
+        visitor.leave_node(kind);
+    }
+
     #[inline]
     pub fn walk_boolean_literal<'a, V: Visit<'a>>(visitor: &mut V, it: &BooleanLiteral) {
         let kind = AstKind::BooleanLiteral(visitor.alloc(it));
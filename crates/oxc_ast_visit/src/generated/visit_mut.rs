@@ -623,6 +623,21 @@ pub trait VisitMut<'a>: Sized {
         walk_v_8_intrinsic_expression(self, it);
     }
 
+    #[inline]
+    fn visit_pipeline_expression(&mut self, it: &mut PipelineExpression<'a>) {
+        walk_pipeline_expression(self, it);
+    }
+
+    #[inline]
+    fn visit_topic_reference(&mut self, it: &mut TopicReference) {
+        walk_topic_reference(self, it);
+    }
+
+    #[inline]
+    fn visit_do_expression(&mut self, it: &mut DoExpression<'a>) {
+        walk_do_expression(self, it);
+    }
+
     #[inline]
     fn visit_boolean_literal(&mut self, it: &mut BooleanLiteral) {
         walk_boolean_literal(self, it);
@@ -1420,6 +1435,9 @@ pub mod walk_mut {
                 visitor.visit_ts_instantiation_expression(it)
             }
             Expression::V8IntrinsicExpression(it) => visitor.visit_v_8_intrinsic_expression(it),
+            Expression::PipelineExpression(it) => visitor.visit_pipeline_expression(it),
+            Expression::TopicReference(it) => visitor.visit_topic_reference(it),
+            Expression::DoExpression(it) => visitor.visit_do_expression(it),
             match_member_expression!(Expression) => {
                 visitor.visit_member_expression(it.to_member_expression_mut())
             }
@@ -3024,6 +3042,36 @@ pub mod walk_mut {
         visitor.leave_node(kind);
     }
 
+    #[inline]
+    pub fn walk_pipeline_expression<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut PipelineExpression<'a>,
+    ) {
+        let kind = AstType::PipelineExpression;
+        visitor.enter_node(kind);
+        visitor.visit_span(&mut it.span);
+        visitor.visit_expression(&mut it.head);
+        visitor.visit_expression(&mut it.body);
+        visitor.leave_node(kind);
+    }
+
+    #[inline]
+    pub fn walk_topic_reference<'a, V: VisitMut<'a>>(visitor: &mut V, it: &mut TopicReference) {
+        let kind = AstType::TopicReference;
+        visitor.enter_node(kind);
+        visitor.visit_span(&mut it.span);
+        visitor.leave_node(kind);
+    }
+
+    #[inline]
+    pub fn walk_do_expression<'a, V: VisitMut<'a>>(visitor: &mut V, it: &mut DoExpression<'a>) {
+        let kind = AstType::DoExpression;
+        visitor.enter_node(kind);
+        visitor.visit_span(&mut it.span);
+        visitor.visit_block_statement(&mut it.body);
+        visitor.leave_node(kind);
+    }
+
     #[inline]
     pub fn walk_boolean_literal<'a, V: VisitMut<'a>>(visitor: &mut V, it: &mut BooleanLiteral) {
         let kind = AstType::BooleanLiteral;
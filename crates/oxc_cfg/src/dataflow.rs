@@ -0,0 +1,406 @@
+//! Generic dataflow analysis over a [`ControlFlowGraph`].
+//!
+//! An [`Analysis`] describes the state tracked at each program point (its [`Analysis::Domain`]),
+//! the direction in which that state flows, and how each [`Instruction`] transforms it.
+//! [`Analysis::iterate_to_fixpoint`] then propagates states along the edges of the graph with a
+//! worklist until nothing changes, and returns the state at the start and end of every block.
+//!
+//! Analyses whose state is a set of facts which instructions add ("gen") and remove ("kill"),
+//! such as reaching definitions or liveness, can implement [`GenKillAnalysis`] instead, which
+//! uses a [`BitSet`] as its state.
+//!
+//! States are joined with [`JoinSemiLattice::join`] where control flow merges, so they can only
+//! grow. Analyses which need facts to hold on *every* path, such as definite assignment, should
+//! track the complement instead, e.g. "may be unassigned".
+
+use std::{collections::VecDeque, fmt};
+
+use petgraph::{Direction, visit::EdgeRef};
+
+use crate::{BasicBlock, BlockNodeId, ControlFlowGraph, EdgeType, Instruction};
+
+/// A state which can be merged with another state where control flow joins.
+///
+/// `join` must be commutative, associative and idempotent, and states may only grow a finite
+/// number of times. Otherwise iterating to a fixed point may not terminate.
+pub trait JoinSemiLattice: Clone + Eq {
+    /// Set `self` to the least upper bound of `self` and `other`.
+    /// Returns `true` if `self` changed.
+    fn join(&mut self, other: &Self) -> bool;
+}
+
+impl JoinSemiLattice for bool {
+    fn join(&mut self, other: &Self) -> bool {
+        if *self || !*other {
+            return false;
+        }
+        *self = true;
+        true
+    }
+}
+
+impl JoinSemiLattice for () {
+    fn join(&mut self, _other: &Self) -> bool {
+        false
+    }
+}
+
+/// Direction in which states flow through the graph.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DataflowDirection {
+    /// States flow from the start of each block to its end, and along edges to their targets.
+    /// e.g. reaching definitions.
+    #[default]
+    Forward,
+    /// States flow from the end of each block to its start, and along edges to their sources.
+    /// e.g. liveness.
+    Backward,
+}
+
+/// A dataflow analysis.
+///
+/// Boundary blocks, which have no edges leading into them in the direction of the analysis
+/// other than [`EdgeType::NewFunction`], start with the state set by
+/// [`Analysis::initialize_boundary`]. For a forward analysis these are the entries of the program
+/// and of each function. For a backward analysis they are the blocks control leaves through,
+/// e.g. error harnesses. All other blocks start with [`Analysis::bottom_value`].
+pub trait Analysis {
+    type Domain: JoinSemiLattice;
+
+    const DIRECTION: DataflowDirection = DataflowDirection::Forward;
+
+    /// Initial state of every block. Must be the bottom of the lattice,
+    /// i.e. joining it with any state gives that state.
+    fn bottom_value(&self, cfg: &ControlFlowGraph) -> Self::Domain;
+
+    /// Set the state at the start of a boundary block, in the direction of the analysis.
+    fn initialize_boundary(&self, _block: BlockNodeId, _state: &mut Self::Domain) {}
+
+    /// Update `state` with the effect of `instruction`.
+    fn apply_instruction(&self, instruction: &Instruction, state: &mut Self::Domain);
+
+    /// Update `state` with the effect of all instructions in `block`.
+    ///
+    /// By default, applies each instruction in the direction of the analysis.
+    fn apply_block(&self, _block_id: BlockNodeId, block: &BasicBlock, state: &mut Self::Domain) {
+        match Self::DIRECTION {
+            DataflowDirection::Forward => {
+                for instruction in block.instructions() {
+                    self.apply_instruction(instruction, state);
+                }
+            }
+            DataflowDirection::Backward => {
+                for instruction in block.instructions().iter().rev() {
+                    self.apply_instruction(instruction, state);
+                }
+            }
+        }
+    }
+
+    /// Whether states flow along edges of kind `edge`.
+    ///
+    /// By default, states do not flow into nested functions, or into code which can never run.
+    fn follows_edge(&self, edge: &EdgeType) -> bool {
+        !matches!(edge, EdgeType::NewFunction | EdgeType::Unreachable)
+    }
+
+    /// Propagate states through `cfg` until they reach a fixed point.
+    fn iterate_to_fixpoint(self, cfg: &ControlFlowGraph) -> DataflowResults<Self>
+    where
+        Self: Sized,
+    {
+        DataflowResults::new(self, cfg)
+    }
+}
+
+/// States of an [`Analysis`] at the start and end of each block, at a fixed point.
+pub struct DataflowResults<A: Analysis> {
+    analysis: A,
+    /// State flowing into each block in the direction of the analysis, indexed by [`BlockNodeId`].
+    inputs: Vec<A::Domain>,
+    /// State flowing out of each block in the direction of the analysis.
+    outputs: Vec<A::Domain>,
+}
+
+impl<A: Analysis> DataflowResults<A> {
+    fn new(analysis: A, cfg: &ControlFlowGraph) -> Self {
+        let graph = cfg.graph();
+        let (incoming, outgoing) = match A::DIRECTION {
+            DataflowDirection::Forward => (Direction::Incoming, Direction::Outgoing),
+            DataflowDirection::Backward => (Direction::Outgoing, Direction::Incoming),
+        };
+
+        let bottom = analysis.bottom_value(cfg);
+        let mut inputs = vec![bottom.clone(); graph.node_count()];
+        for block in graph.node_indices() {
+            let is_boundary = graph
+                .edges_directed(block, incoming)
+                .all(|edge| matches!(edge.weight(), EdgeType::NewFunction));
+            if is_boundary {
+                analysis.initialize_boundary(block, &mut inputs[block.index()]);
+            }
+        }
+        let mut outputs = vec![bottom; graph.node_count()];
+
+        // Blocks are mostly created in program order,
+        // so visiting them in that order (or its reverse) converges quickly
+        let mut worklist = graph.node_indices().collect::<VecDeque<_>>();
+        if A::DIRECTION == DataflowDirection::Backward {
+            worklist.make_contiguous().reverse();
+        }
+        let mut queued = vec![true; graph.node_count()];
+
+        while let Some(block) = worklist.pop_front() {
+            queued[block.index()] = false;
+            let mut state = inputs[block.index()].clone();
+            analysis.apply_block(block, cfg.basic_block(block), &mut state);
+
+            for edge in graph.edges_directed(block, outgoing) {
+                if !analysis.follows_edge(edge.weight()) {
+                    continue;
+                }
+                let next = match A::DIRECTION {
+                    DataflowDirection::Forward => edge.target(),
+                    DataflowDirection::Backward => edge.source(),
+                };
+                if inputs[next.index()].join(&state) && !queued[next.index()] {
+                    queued[next.index()] = true;
+                    worklist.push_back(next);
+                }
+            }
+            outputs[block.index()] = state;
+        }
+
+        Self { analysis, inputs, outputs }
+    }
+
+    pub fn analysis(&self) -> &A {
+        &self.analysis
+    }
+
+    /// State before the first instruction of `block`.
+    pub fn state_at_block_start(&self, block: BlockNodeId) -> &A::Domain {
+        match A::DIRECTION {
+            DataflowDirection::Forward => &self.inputs[block.index()],
+            DataflowDirection::Backward => &self.outputs[block.index()],
+        }
+    }
+
+    /// State after the last instruction of `block`.
+    pub fn state_at_block_end(&self, block: BlockNodeId) -> &A::Domain {
+        match A::DIRECTION {
+            DataflowDirection::Forward => &self.outputs[block.index()],
+            DataflowDirection::Backward => &self.inputs[block.index()],
+        }
+    }
+
+    /// Call `visitor` with each instruction of `block`, and the state immediately before it.
+    ///
+    /// Instructions are visited in the direction of the analysis, so for a backward analysis
+    /// the last instruction is visited first.
+    pub fn visit_block_states<F>(&self, cfg: &ControlFlowGraph, block: BlockNodeId, mut visitor: F)
+    where
+        F: FnMut(&Instruction, &A::Domain),
+    {
+        let instructions = cfg.basic_block(block).instructions();
+        match A::DIRECTION {
+            DataflowDirection::Forward => {
+                let mut state = self.state_at_block_start(block).clone();
+                for instruction in instructions {
+                    visitor(instruction, &state);
+                    self.analysis.apply_instruction(instruction, &mut state);
+                }
+            }
+            DataflowDirection::Backward => {
+                let mut state = self.state_at_block_end(block).clone();
+                for instruction in instructions.iter().rev() {
+                    self.analysis.apply_instruction(instruction, &mut state);
+                    visitor(instruction, &state);
+                }
+            }
+        }
+    }
+}
+
+/// A dataflow analysis whose state is a set of facts, numbered `0..domain_size`.
+///
+/// Each instruction adds facts to the set ([`BitSet::insert`]) and removes them
+/// ([`BitSet::remove`]). Sets are unioned where control flow joins.
+pub trait GenKillAnalysis {
+    const DIRECTION: DataflowDirection = DataflowDirection::Forward;
+
+    /// Number of facts tracked.
+    fn domain_size(&self, cfg: &ControlFlowGraph) -> usize;
+
+    /// Set the facts which hold at the start of a boundary block. See [`Analysis`].
+    fn initialize_boundary(&self, _block: BlockNodeId, _state: &mut BitSet) {}
+
+    /// Add the facts `instruction` generates to `state`, and remove the facts it kills.
+    fn gen_kill(&self, instruction: &Instruction, state: &mut BitSet);
+
+    /// Whether facts flow along edges of kind `edge`. See [`Analysis::follows_edge`].
+    fn follows_edge(&self, edge: &EdgeType) -> bool {
+        !matches!(edge, EdgeType::NewFunction | EdgeType::Unreachable)
+    }
+}
+
+impl<A: GenKillAnalysis> Analysis for A {
+    type Domain = BitSet;
+
+    const DIRECTION: DataflowDirection = <A as GenKillAnalysis>::DIRECTION;
+
+    fn bottom_value(&self, cfg: &ControlFlowGraph) -> BitSet {
+        BitSet::new_empty(self.domain_size(cfg))
+    }
+
+    fn initialize_boundary(&self, block: BlockNodeId, state: &mut BitSet) {
+        GenKillAnalysis::initialize_boundary(self, block, state);
+    }
+
+    fn apply_instruction(&self, instruction: &Instruction, state: &mut BitSet) {
+        self.gen_kill(instruction, state);
+    }
+
+    fn follows_edge(&self, edge: &EdgeType) -> bool {
+        GenKillAnalysis::follows_edge(self, edge)
+    }
+}
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A fixed-size set of `usize`s in `0..len`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct BitSet {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitSet {
+    /// Create a set of `len` possible elements, with none of them in it.
+    pub fn new_empty(len: usize) -> Self {
+        Self { words: vec![0; len.div_ceil(WORD_BITS)], len }
+    }
+
+    /// Create a set of `len` possible elements, with all of them in it.
+    pub fn new_filled(len: usize) -> Self {
+        let mut set = Self { words: vec![u64::MAX; len.div_ceil(WORD_BITS)], len };
+        set.clear_excess_bits();
+        set
+    }
+
+    /// Number of possible elements.
+    pub fn domain_size(&self) -> usize {
+        self.len
+    }
+
+    /// Number of elements in the set.
+    pub fn count(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// # Panics
+    /// Panics if `elem` is out of bounds.
+    pub fn contains(&self, elem: usize) -> bool {
+        assert!(elem < self.len, "element {elem} out of bounds of set of size {}", self.len);
+        self.words[elem / WORD_BITS] & (1 << (elem % WORD_BITS)) != 0
+    }
+
+    /// Add `elem` to the set. Returns `true` if it was not in the set before.
+    ///
+    /// # Panics
+    /// Panics if `elem` is out of bounds.
+    pub fn insert(&mut self, elem: usize) -> bool {
+        assert!(elem < self.len, "element {elem} out of bounds of set of size {}", self.len);
+        let word = &mut self.words[elem / WORD_BITS];
+        let old = *word;
+        *word |= 1 << (elem % WORD_BITS);
+        *word != old
+    }
+
+    /// Remove `elem` from the set. Returns `true` if it was in the set before.
+    ///
+    /// # Panics
+    /// Panics if `elem` is out of bounds.
+    pub fn remove(&mut self, elem: usize) -> bool {
+        assert!(elem < self.len, "element {elem} out of bounds of set of size {}", self.len);
+        let word = &mut self.words[elem / WORD_BITS];
+        let old = *word;
+        *word &= !(1 << (elem % WORD_BITS));
+        *word != old
+    }
+
+    /// Add all elements of `other` to the set. Returns `true` if the set changed.
+    ///
+    /// # Panics
+    /// Panics if the sets have different domain sizes.
+    pub fn union(&mut self, other: &Self) -> bool {
+        assert_eq!(self.len, other.len, "sets have different domain sizes");
+        let mut changed = false;
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            let old = *word;
+            *word |= other;
+            changed |= *word != old;
+        }
+        changed
+    }
+
+    /// Remove all elements not in `other` from the set. Returns `true` if the set changed.
+    ///
+    /// # Panics
+    /// Panics if the sets have different domain sizes.
+    pub fn intersect(&mut self, other: &Self) -> bool {
+        assert_eq!(self.len, other.len, "sets have different domain sizes");
+        let mut changed = false;
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            let old = *word;
+            *word &= other;
+            changed |= *word != old;
+        }
+        changed
+    }
+
+    /// Remove all elements from the set.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Iterate over the elements of the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(index, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(index * WORD_BITS + bit)
+            })
+        })
+    }
+
+    fn clear_excess_bits(&mut self) {
+        let excess = self.len % WORD_BITS;
+        if excess != 0
+            && let Some(last) = self.words.last_mut()
+        {
+            *last &= (1 << excess) - 1;
+        }
+    }
+}
+
+impl JoinSemiLattice for BitSet {
+    fn join(&mut self, other: &Self) -> bool {
+        self.union(other)
+    }
+}
+
+impl fmt::Debug for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,204 @@
+use oxc_cfg::{
+    BlockNodeId, ControlFlowGraph, ControlFlowGraphBuilder, EdgeType, ErrorEdgeKind, Instruction,
+    InstructionKind,
+    dataflow::{Analysis, BitSet, DataflowDirection, GenKillAnalysis},
+};
+use oxc_syntax::node::NodeId;
+
+/// Node id `n` stands for variable `n`. Statements assign to their variable,
+/// conditions read from it.
+const VARIABLES: usize = 3;
+
+fn variable(instruction: &Instruction) -> usize {
+    instruction.node_id.unwrap().index()
+}
+
+/// ```js
+/// x0 = 0;          // entry
+/// if (x1) {
+///   x1 = 1;        // consequent
+/// } else {
+///   x2 = 2;        // alternate
+/// }
+/// x2;              // join
+/// ```
+struct Diamond {
+    cfg: ControlFlowGraph,
+    entry: BlockNodeId,
+    consequent: BlockNodeId,
+    alternate: BlockNodeId,
+    join: BlockNodeId,
+}
+
+fn diamond() -> Diamond {
+    let mut cfg = ControlFlowGraphBuilder::default();
+    cfg.attach_error_harness(ErrorEdgeKind::Implicit);
+    let entry = cfg.new_basic_block_normal();
+    cfg.push_statement(NodeId::new(0));
+    cfg.append_condition_to(entry, Some(NodeId::new(1)));
+    let consequent = cfg.new_basic_block_normal();
+    cfg.push_statement(NodeId::new(1));
+    let alternate = cfg.new_basic_block_normal();
+    cfg.push_statement(NodeId::new(2));
+    let join = cfg.new_basic_block_normal();
+    cfg.append_condition_to(join, Some(NodeId::new(2)));
+    cfg.add_edge(entry, consequent, EdgeType::Jump);
+    cfg.add_edge(entry, alternate, EdgeType::Normal);
+    cfg.add_edge(consequent, join, EdgeType::Normal);
+    cfg.add_edge(alternate, join, EdgeType::Normal);
+    Diamond { cfg: cfg.build(), entry, consequent, alternate, join }
+}
+
+/// Variables which may be unassigned. Complement of definitely assigned variables.
+struct MaybeUnassigned;
+
+impl GenKillAnalysis for MaybeUnassigned {
+    fn domain_size(&self, _cfg: &ControlFlowGraph) -> usize {
+        VARIABLES
+    }
+
+    fn initialize_boundary(&self, _block: BlockNodeId, state: &mut BitSet) {
+        *state = BitSet::new_filled(VARIABLES);
+    }
+
+    fn gen_kill(&self, instruction: &Instruction, state: &mut BitSet) {
+        if instruction.kind == InstructionKind::Statement {
+            state.remove(variable(instruction));
+        }
+    }
+}
+
+/// Variables which may be read before they're next assigned.
+struct Liveness;
+
+impl GenKillAnalysis for Liveness {
+    const DIRECTION: DataflowDirection = DataflowDirection::Backward;
+
+    fn domain_size(&self, _cfg: &ControlFlowGraph) -> usize {
+        VARIABLES
+    }
+
+    fn gen_kill(&self, instruction: &Instruction, state: &mut BitSet) {
+        match instruction.kind {
+            InstructionKind::Statement => {
+                state.remove(variable(instruction));
+            }
+            InstructionKind::Condition => {
+                state.insert(variable(instruction));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Whether a block can be reached from the entry of its function.
+struct Reachable;
+
+impl Analysis for Reachable {
+    type Domain = bool;
+
+    fn bottom_value(&self, _cfg: &ControlFlowGraph) -> bool {
+        false
+    }
+
+    fn initialize_boundary(&self, _block: BlockNodeId, state: &mut bool) {
+        *state = true;
+    }
+
+    fn apply_instruction(&self, _instruction: &Instruction, _state: &mut bool) {}
+}
+
+fn elements(set: &BitSet) -> Vec<usize> {
+    set.iter().collect()
+}
+
+#[test]
+fn forward_gen_kill() {
+    let Diamond { cfg, entry, consequent, alternate, join } = diamond();
+    let results = MaybeUnassigned.iterate_to_fixpoint(&cfg);
+
+    assert_eq!(elements(results.state_at_block_start(entry)), [0, 1, 2]);
+    assert_eq!(elements(results.state_at_block_end(entry)), [1, 2]);
+    assert_eq!(elements(results.state_at_block_end(consequent)), [2]);
+    assert_eq!(elements(results.state_at_block_end(alternate)), [1]);
+    // Each variable is assigned on only one of the paths into the join
+    assert_eq!(elements(results.state_at_block_start(join)), [1, 2]);
+
+    let mut states = vec![];
+    results.visit_block_states(&cfg, entry, |instruction, state| {
+        states.push((instruction.kind, elements(state)));
+    });
+    assert_eq!(
+        states,
+        [(InstructionKind::Statement, vec![0, 1, 2]), (InstructionKind::Condition, vec![1, 2])]
+    );
+}
+
+#[test]
+fn backward_gen_kill() {
+    let Diamond { cfg, entry, consequent, alternate, join } = diamond();
+    let results = Liveness.iterate_to_fixpoint(&cfg);
+
+    assert_eq!(elements(results.state_at_block_start(join)), [2]);
+    assert_eq!(elements(results.state_at_block_start(consequent)), [2]);
+    // `x2` is assigned before the join
+    assert_eq!(elements(results.state_at_block_start(alternate)), [] as [usize; 0]);
+    assert_eq!(elements(results.state_at_block_end(entry)), [2]);
+    // `x1` is read by the condition
+    assert_eq!(elements(results.state_at_block_start(entry)), [1, 2]);
+
+    // Visited from the last instruction to the first, with the state before each instruction
+    let mut states = vec![];
+    results.visit_block_states(&cfg, entry, |instruction, state| {
+        states.push((instruction.kind, elements(state)));
+    });
+    assert_eq!(
+        states,
+        [(InstructionKind::Condition, vec![1, 2]), (InstructionKind::Statement, vec![1, 2])]
+    );
+}
+
+#[test]
+fn unreachable_and_new_function_edges() {
+    let mut cfg = ControlFlowGraphBuilder::default();
+    cfg.attach_error_harness(ErrorEdgeKind::Implicit);
+    let entry = cfg.new_basic_block_normal();
+    cfg.push_statement(NodeId::new(0));
+    cfg.append_unreachable();
+    let after_return = cfg.current_node_ix;
+    let function = cfg.new_basic_block_function();
+    cfg.add_edge(entry, function, EdgeType::NewFunction);
+    let cfg = cfg.build();
+
+    let results = Reachable.iterate_to_fixpoint(&cfg);
+    assert!(*results.state_at_block_start(entry));
+    assert!(!*results.state_at_block_start(after_return));
+    // Functions are boundaries, rather than being reached from where they're defined
+    assert!(*results.state_at_block_start(function));
+}
+
+#[test]
+fn bit_set() {
+    let mut set = BitSet::new_empty(130);
+    assert!(set.is_empty());
+    assert!(set.insert(0));
+    assert!(set.insert(64));
+    assert!(set.insert(129));
+    assert!(!set.insert(129));
+    assert_eq!(set.count(), 3);
+    assert!(set.contains(64));
+    assert!(set.remove(64));
+    assert!(!set.contains(64));
+    assert_eq!(elements(&set), [0, 129]);
+
+    let filled = BitSet::new_filled(130);
+    assert_eq!(filled.count(), 130);
+    assert!(set.union(&filled));
+    assert_eq!(set, filled);
+
+    let mut other = BitSet::new_empty(130);
+    other.insert(5);
+    assert!(set.intersect(&other));
+    assert_eq!(elements(&set), [5]);
+    assert_eq!(format!("{set:?}"), "{5}");
+}
@@ -33,6 +33,24 @@ impl<'a> Binaryish<'a> {
         }
     }
 
+    /// Left operand to print, which keeps its parentheses with `preserve_parens`.
+    fn left_to_print(&self, p: &Codegen) -> &'a Expression<'a> {
+        let left = match self {
+            Self::Binary(e) => &e.left,
+            Self::Logical(e) => &e.left,
+        };
+        if p.options.preserve_parens { left } else { left.without_parentheses() }
+    }
+
+    /// Right operand to print, which keeps its parentheses with `preserve_parens`.
+    fn right_to_print(&self, p: &Codegen) -> &'a Expression<'a> {
+        let right = match self {
+            Self::Binary(e) => &e.right,
+            Self::Logical(e) => &e.right,
+        };
+        if p.options.preserve_parens { right } else { right.without_parentheses() }
+    }
+
     pub fn operator(&self) -> BinaryishOperator {
         match self {
             Self::Binary(e) => BinaryishOperator::Binary(e.operator),
@@ -116,7 +134,7 @@ impl<'a> BinaryExpressionVisitor<'a> {
                 break;
             }
 
-            let left = v.e.left();
+            let left = v.e.left_to_print(p);
             let left_binary = match left {
                 Expression::BinaryExpression(e) => Some(Binaryish::Binary(e)),
                 Expression::LogicalExpression(e) => Some(Binaryish::Logical(e)),
@@ -223,7 +241,7 @@ impl<'a> BinaryExpressionVisitor<'a> {
         p.print_soft_space();
         self.operator.r#gen(p);
         p.print_soft_space();
-        self.e.right().gen_expr(p, self.right_precedence, self.ctx);
+        self.e.right_to_print(p).gen_expr(p, self.right_precedence, self.ctx);
         if self.wrap {
             p.print_ascii_byte(b')');
         }
@@ -1251,13 +1251,30 @@ impl GenExpr for Expression<'_> {
             Self::TSInstantiationExpression(e) => e.print_expr(p, precedence, ctx),
             // V8 intrinsics (rare)
             Self::V8IntrinsicExpression(e) => e.print_expr(p, precedence, ctx),
+            // Pipeline operator proposal (rare)
+            Self::PipelineExpression(e) => e.print_expr(p, precedence, ctx),
+            Self::TopicReference(e) => e.print(p, ctx),
+            Self::DoExpression(e) => e.print_expr(p, precedence, ctx),
         }
     }
 }
 
 impl GenExpr for ParenthesizedExpression<'_> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
-        self.expression.print_expr(p, precedence, ctx);
+        // Functions marked as `pife` print their own parentheses
+        let is_pife = match &self.expression {
+            Expression::FunctionExpression(func) => func.pife,
+            Expression::ArrowFunctionExpression(func) => func.pife,
+            _ => false,
+        };
+        if p.options.preserve_parens && !is_pife {
+            p.print_ascii_byte(b'(');
+            let ctx = ctx.and_forbid_in(false).and_forbid_call(false);
+            self.expression.print_expr(p, Precedence::Lowest, ctx);
+            p.print_ascii_byte(b')');
+        } else {
+            self.expression.print_expr(p, precedence, ctx);
+        }
     }
 }
 
@@ -2286,7 +2303,9 @@ impl GenExpr for TSSatisfiesExpression<'_> {
 
 impl GenExpr for TSNonNullExpression<'_> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
-        p.wrap(matches!(self.expression, Expression::ParenthesizedExpression(_)), |p| {
+        let wrap = !p.options.preserve_parens
+            && matches!(self.expression, Expression::ParenthesizedExpression(_));
+        p.wrap(wrap, |p| {
             self.expression.print_expr(p, precedence, ctx);
         });
         p.print_ascii_byte(b'!');
@@ -3878,3 +3897,46 @@ impl GenExpr for V8IntrinsicExpression<'_> {
         });
     }
 }
+
+impl GenExpr for PipelineExpression<'_> {
+    fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
+        // Same precedence as assignment, so `a |> (% ? b : c)` prints without parentheses
+        p.wrap(precedence >= Precedence::Assign, |p| {
+            // `|>` is left associative
+            let head_precedence = if matches!(self.head, Expression::PipelineExpression(_)) {
+                Precedence::Yield
+            } else {
+                Precedence::Conditional
+            };
+            self.head.print_expr(p, head_precedence, ctx);
+            p.print_soft_space();
+            p.print_str("|>");
+            p.print_soft_space();
+            self.body.print_expr(p, Precedence::Assign, ctx);
+        });
+    }
+}
+
+impl GenExpr for DoExpression<'_> {
+    fn gen_expr(&self, p: &mut Codegen, _precedence: Precedence, ctx: Context) {
+        // `do {} while (a)` is a statement
+        let wrap = p.start_of_stmt == p.code_len();
+        p.wrap(wrap, |p| {
+            p.print_space_before_identifier();
+            p.add_source_mapping(self.span);
+            p.print_str("do");
+            p.print_soft_space();
+            p.print_block_statement(&self.body, ctx);
+        });
+    }
+}
+
+impl Gen for TopicReference {
+    fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
+        p.add_source_mapping(self.span);
+        p.print_ascii_byte(b'%');
+        // Keep the space in `% == a`, as `%==` is lexed as `%=`
+        p.prev_op = Some(BinaryOperator::Remainder.into());
+        p.prev_op_end = p.code_len();
+    }
+}
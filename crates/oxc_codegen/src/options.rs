@@ -43,6 +43,17 @@ pub struct CodegenOptions {
     ///
     /// Default is `0`.
     pub initial_indent: u32,
+
+    /// Print the parentheses of [`ParenthesizedExpression`]s, even where they are not needed.
+    ///
+    /// Parse with [`ParseOptions::preserve_parens`] to reprint e.g. `(a || b) || c` as written.
+    /// Otherwise, parentheses are printed only where precedence requires them.
+    ///
+    /// Default is `false`.
+    ///
+    /// [`ParenthesizedExpression`]: oxc_ast::ast::ParenthesizedExpression
+    /// [`ParseOptions::preserve_parens`]: https://docs.rs/oxc_parser/latest/oxc_parser/struct.ParseOptions.html#structfield.preserve_parens
+    pub preserve_parens: bool,
 }
 
 impl Default for CodegenOptions {
@@ -55,6 +66,7 @@ impl Default for CodegenOptions {
             indent_char: IndentChar::default(),
             indent_width: DEFAULT_INDENT_WIDTH,
             initial_indent: 0,
+            preserve_parens: false,
         }
     }
 }
@@ -70,6 +82,7 @@ impl CodegenOptions {
             indent_char: IndentChar::default(),
             indent_width: DEFAULT_INDENT_WIDTH,
             initial_indent: 0,
+            preserve_parens: false,
         }
     }
 
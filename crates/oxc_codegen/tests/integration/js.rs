@@ -642,6 +642,73 @@ fn v8_intrinsics() {
     );
 }
 
+#[test]
+fn pipeline_operator() {
+    let parse_opts = oxc_parser::ParseOptions {
+        allow_pipeline_operator: true,
+        ..oxc_parser::ParseOptions::default()
+    };
+
+    let cases = [
+        ("a |> f(%) |> % + 1", "a |> f(%) |> % + 1;\n"),
+        ("a |> (% |> f(%, %))", "a |> (% |> f(%, %));\n"),
+        ("(a |> f(%)) ?? b", "(a |> f(%)) ?? b;\n"),
+        ("x = a |> % ? b : c", "x = a |> % ? b : c;\n"),
+        ("(a |> %) ? b : c", "(a |> %) ? b : c;\n"),
+        ("(a ? b : c) |> %", "(a ? b : c) |> %;\n"),
+        ("a ? b : c |> %", "a ? b : c |> %;\n"),
+        ("a |> % % 2 === 0", "a |> % % 2 === 0;\n"),
+    ];
+    for (source, expected) in cases {
+        test_with_parse_options(source, expected, parse_opts);
+    }
+}
+
+#[test]
+fn do_expressions() {
+    let parse_opts = oxc_parser::ParseOptions {
+        allow_do_expressions: true,
+        ..oxc_parser::ParseOptions::default()
+    };
+
+    let cases = [
+        (
+            "let a = do { if (b) { 1 } else { 2 } }",
+            "let a = do {\n\tif (b) {\n\t\t1;\n\t} else {\n\t\t2;\n\t}\n};\n",
+        ),
+        ("(do { a })", "(do {\n\ta;\n});\n"),
+        ("(do {}).b", "(do {}).b;\n"),
+        ("f(do {})", "f(do {});\n"),
+    ];
+    for (source, expected) in cases {
+        test_with_parse_options(source, expected, parse_opts);
+    }
+}
+
+#[test]
+fn preserve_parens() {
+    let options = CodegenOptions { preserve_parens: true, ..CodegenOptions::default() };
+    let cases = [
+        ("(a || b) || c", "(a || b) || c;\n"),
+        ("(a || b) && c", "(a || b) && c;\n"),
+        ("x = ((a))", "x = ((a));\n"),
+        ("a = (b, c)", "a = (b, c);\n"),
+        ("(function() {})()", "(function() {})();\n"),
+        ("(() => {})()", "(() => {})();\n"),
+        ("new (a())()", "new (a())();\n"),
+        ("(a?.b)()", "(a?.b)();\n"),
+        ("({}).a", "({}).a;\n"),
+        ("() => ({})", "() => ({});\n"),
+        ("for (let a = (b in c); ;) {}", "for (let a = (b in c);;) {}\n"),
+        ("(a as any)!", "(a as any)!;\n"),
+    ];
+    for (source, expected) in cases {
+        test_options(source, expected, options.clone());
+    }
+
+    test("(a || b) || c", "a || b || c;\n");
+}
+
 #[test]
 fn indentation() {
     // Test default - tabs with width 1
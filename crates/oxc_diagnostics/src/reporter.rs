@@ -53,6 +53,13 @@ pub trait DiagnosticReporter {
     ///
     /// Reporters should use this method to write diagnostics to their output stream.
     fn render_error(&mut self, error: Error) -> Option<String>;
+
+    /// Lifecycle hook that gets called after all diagnostics of a batch sent to the
+    /// [`DiagnosticService`](crate::service::DiagnosticService) have been rendered. Linters send
+    /// one batch per file, so reporters can use this to group diagnostics by file.
+    fn finish_file(&mut self) -> Option<String> {
+        None
+    }
 }
 
 /// DiagnosticResult will be submitted to the Reporter when the [`DiagnosticService`](crate::service::DiagnosticService)
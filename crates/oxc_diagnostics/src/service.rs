@@ -18,6 +18,16 @@ use crate::{
 pub type DiagnosticSender = mpsc::Sender<Vec<Error>>;
 pub type DiagnosticReceiver = mpsc::Receiver<Vec<Error>>;
 
+/// Decides whether a diagnostic should be dropped before it is counted or reported.
+///
+/// Filters get used by [`DiagnosticService`] to suppress known diagnostics, e.g. violations
+/// recorded in a baseline file. Since filters see every diagnostic before it is reported, they can
+/// also be used to observe diagnostics without dropping any.
+pub trait DiagnosticFilter {
+    /// Returns `true` if `error` should be dropped.
+    fn is_suppressed(&mut self, error: &Error) -> bool;
+}
+
 /// Listens for diagnostics sent over a [channel](DiagnosticSender) by some job, and
 /// formats/reports them to the user.
 ///
@@ -60,6 +70,9 @@ pub struct DiagnosticService {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     max_warnings: Option<usize>,
 
+    /// Drop diagnostics before they are counted or reported, applied in order
+    filters: Vec<Box<dyn DiagnosticFilter>>,
+
     receiver: DiagnosticReceiver,
 }
 
@@ -68,7 +81,17 @@ impl DiagnosticService {
     /// provided [`DiagnosticReporter`].
     pub fn new(reporter: Box<dyn DiagnosticReporter>) -> (Self, DiagnosticSender) {
         let (sender, receiver) = mpsc::channel();
-        (Self { reporter, quiet: false, silent: false, max_warnings: None, receiver }, sender)
+        (
+            Self {
+                reporter,
+                quiet: false,
+                silent: false,
+                max_warnings: None,
+                filters: Vec::new(),
+                receiver,
+            },
+            sender,
+        )
     }
 
     /// Set to `true` to only report errors and ignore warnings.
@@ -107,6 +130,16 @@ impl DiagnosticService {
         self
     }
 
+    /// Add a [`DiagnosticFilter`]. Suppressed diagnostics are neither counted nor reported, and
+    /// are not passed to filters added after this one.
+    ///
+    /// Default: no filters
+    #[must_use]
+    pub fn with_filter(mut self, filter: Box<dyn DiagnosticFilter>) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
     /// Check if the max warning threshold, as set by
     /// [`with_max_warnings`](DiagnosticService::with_max_warnings), has been exceeded.
     fn max_warnings_exceeded(&self, warnings_count: usize) -> bool {
@@ -122,6 +155,20 @@ impl DiagnosticService {
         source_text: &str,
         diagnostics: Vec<OxcDiagnostic>,
     ) -> Vec<Error> {
+        let source = Self::named_source(cwd, path, source_text);
+        diagnostics
+            .into_iter()
+            .map(|diagnostic| diagnostic.with_source_code(Arc::clone(&source)))
+            .collect()
+    }
+
+    /// Create the source code that [`wrap_diagnostics`](DiagnosticService::wrap_diagnostics)
+    /// attaches to diagnostics, named after `path` relative to `cwd`.
+    pub fn named_source<C: AsRef<Path>, P: AsRef<Path>>(
+        cwd: C,
+        path: P,
+        source_text: &str,
+    ) -> Arc<NamedSource<String>> {
         // TODO: This causes snapshots to fail when running tests through a JetBrains terminal.
         let is_jetbrains =
             std::env::var("TERMINAL_EMULATOR").is_ok_and(|x| x.eq("JetBrains-JediTerm"));
@@ -135,11 +182,7 @@ impl DiagnosticService {
                 normalized_path.to_string()
             });
 
-        let source = Arc::new(NamedSource::new(path_display, source_text.to_owned()));
-        diagnostics
-            .into_iter()
-            .map(|diagnostic| diagnostic.with_source_code(Arc::clone(&source)))
-            .collect()
+        Arc::new(NamedSource::new(path_display, source_text.to_owned()))
     }
 
     /// # Panics
@@ -158,6 +201,10 @@ impl DiagnosticService {
         while let Ok(diagnostics) = self.receiver.recv() {
             let mut is_minified = false;
             for diagnostic in diagnostics {
+                if self.filters.iter_mut().any(|filter| filter.is_suppressed(&diagnostic)) {
+                    continue;
+                }
+
                 let severity = diagnostic.severity();
                 let is_warning = severity == Some(Severity::Warning);
                 let is_error = severity == Some(Severity::Error) || severity.is_none();
@@ -213,6 +260,13 @@ impl DiagnosticService {
                         .unwrap();
                 }
             }
+
+            if let Some(file_output) = self.reporter.finish_file() {
+                writer
+                    .write_all(file_output.as_bytes())
+                    .or_else(Self::check_for_writer_error)
+                    .unwrap();
+            }
         }
 
         let result = DiagnosticResult::new(
@@ -0,0 +1,69 @@
+use oxc_ast::ast::*;
+
+use super::{MayHaveSideEffects, context::MayHaveSideEffectsContext};
+
+/// Returns true if calling the function may change application state.
+///
+/// Only the call itself is considered. The evaluation of the callee and the arguments is not.
+///
+/// A call is side effect free if
+/// - the function is annotated with `/* #__NO_SIDE_EFFECTS__ */` and annotations are respected, or
+/// - the function is neither async nor a generator, the parameters are identifiers whose defaults
+///   are side effect free, and none of the statements in the body have side effects.
+///
+/// Destructuring parameters are not allowed, since `function foo({}) {} foo(null)` throws a `TypeError`.
+pub trait CallMayHaveSideEffects<'a> {
+    fn call_may_have_side_effects(&self, ctx: &impl MayHaveSideEffectsContext<'a>) -> bool;
+}
+
+impl<'a> CallMayHaveSideEffects<'a> for Function<'a> {
+    fn call_may_have_side_effects(&self, ctx: &impl MayHaveSideEffectsContext<'a>) -> bool {
+        if self.pure && ctx.annotations() {
+            return false;
+        }
+        if self.r#async || self.generator {
+            return true;
+        }
+        let Some(body) = &self.body else { return true };
+        self.params.may_have_side_effects(ctx) || body.may_have_side_effects(ctx)
+    }
+}
+
+impl<'a> CallMayHaveSideEffects<'a> for ArrowFunctionExpression<'a> {
+    fn call_may_have_side_effects(&self, ctx: &impl MayHaveSideEffectsContext<'a>) -> bool {
+        if self.pure && ctx.annotations() {
+            return false;
+        }
+        if self.r#async {
+            return true;
+        }
+        self.params.may_have_side_effects(ctx) || self.body.may_have_side_effects(ctx)
+    }
+}
+
+impl<'a> CallMayHaveSideEffects<'a> for Expression<'a> {
+    /// Returns true unless the expression is a function whose call is side effect free.
+    fn call_may_have_side_effects(&self, ctx: &impl MayHaveSideEffectsContext<'a>) -> bool {
+        match self.without_parentheses() {
+            Expression::FunctionExpression(func) => func.call_may_have_side_effects(ctx),
+            Expression::ArrowFunctionExpression(arrow) => arrow.call_may_have_side_effects(ctx),
+            _ => true,
+        }
+    }
+}
+
+impl<'a> MayHaveSideEffects<'a> for FormalParameters<'a> {
+    /// Whether binding the arguments to the parameters may have side effects.
+    fn may_have_side_effects(&self, ctx: &impl MayHaveSideEffectsContext<'a>) -> bool {
+        self.items.iter().any(|param| {
+            !param.pattern.is_binding_identifier()
+                || param.initializer.as_ref().is_some_and(|init| init.may_have_side_effects(ctx))
+        }) || self.rest.as_ref().is_some_and(|rest| !rest.rest.argument.is_binding_identifier())
+    }
+}
+
+impl<'a> MayHaveSideEffects<'a> for FunctionBody<'a> {
+    fn may_have_side_effects(&self, ctx: &impl MayHaveSideEffectsContext<'a>) -> bool {
+        self.statements.iter().any(|stmt| stmt.may_have_side_effects(ctx))
+    }
+}
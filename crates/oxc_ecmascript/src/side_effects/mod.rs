@@ -1,9 +1,11 @@
 mod context;
 mod expressions;
+mod functions;
 mod pure_function;
 mod statements;
 
 pub use context::{MayHaveSideEffectsContext, PropertyReadSideEffects};
+pub use functions::CallMayHaveSideEffects;
 pub use pure_function::is_pure_function;
 
 /// Returns true if subtree changes application state.
@@ -88,14 +88,15 @@ impl<'a> MayHaveSideEffects<'a> for Declaration<'a> {
     fn may_have_side_effects(&self, ctx: &impl MayHaveSideEffectsContext<'a>) -> bool {
         match self {
             Declaration::VariableDeclaration(var_decl) => var_decl.may_have_side_effects(ctx),
-            Declaration::FunctionDeclaration(_) => false,
+            // Function declarations are hoisted, and type-only declarations are erased
+            Declaration::FunctionDeclaration(_)
+            | Declaration::TSInterfaceDeclaration(_)
+            | Declaration::TSTypeAliasDeclaration(_) => false,
             Declaration::ClassDeclaration(class_decl) => class_decl.may_have_side_effects(ctx),
             Declaration::TSEnumDeclaration(_)
             | Declaration::TSImportEqualsDeclaration(_)
             | Declaration::TSModuleDeclaration(_)
-            | Declaration::TSGlobalDeclaration(_)
-            | Declaration::TSInterfaceDeclaration(_)
-            | Declaration::TSTypeAliasDeclaration(_) => unreachable!(),
+            | Declaration::TSGlobalDeclaration(_) => true,
         }
     }
 }
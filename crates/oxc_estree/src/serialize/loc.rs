@@ -0,0 +1,122 @@
+use super::{ESTree, Serializer, StructSerializer};
+
+/// Table of the lines of source text, to get the line and column of offsets for `loc` fields.
+pub(super) struct LineTable {
+    /// Offset of the start of each line
+    line_starts: Vec<u32>,
+    /// Offset after each non-ASCII character, and the number of UTF-8 bytes up to that offset
+    /// in excess of UTF-16 code units
+    utf16_differences: Vec<(u32, u32)>,
+}
+
+impl LineTable {
+    /// Create a [`LineTable`] for `source_text`.
+    ///
+    /// Line terminators are as in ECMAScript: `\n`, `\r\n`, `\r`, `\u{2028}` and `\u{2029}`.
+    #[expect(clippy::cast_possible_truncation)]
+    pub(super) fn new(source_text: &str) -> Self {
+        let mut line_starts = vec![0];
+        let mut utf16_differences = vec![];
+        let mut difference = 0;
+        let mut chars = source_text.char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            let end = (offset + c.len_utf8()) as u32;
+            match c {
+                '\r' if chars.peek().is_some_and(|&(_, next)| next == '\n') => {}
+                '\n' | '\r' | '\u{2028}' | '\u{2029}' => line_starts.push(end),
+                _ => {}
+            }
+            if !c.is_ascii() {
+                difference += (c.len_utf8() - c.len_utf16()) as u32;
+                utf16_differences.push((end, difference));
+            }
+        }
+        Self { line_starts, utf16_differences }
+    }
+
+    /// Get location of the span from `start` to `end`.
+    pub(super) fn loc(&self, [start, end]: [u32; 2]) -> SourceLocation {
+        SourceLocation { start: self.position(start), end: self.position(end) }
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn position(&self, offset: u32) -> Position {
+        // Number of lines starting at or before `offset`, which is the 1-based line number
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let line_start = self.line_starts[line - 1];
+        let utf16_difference =
+            self.utf16_difference_at(offset) - self.utf16_difference_at(line_start);
+        Position { line: line as u32, column: offset - line_start - utf16_difference }
+    }
+
+    fn utf16_difference_at(&self, offset: u32) -> u32 {
+        let index = self.utf16_differences.partition_point(|&(end, _)| end <= offset);
+        index.checked_sub(1).map_or(0, |index| self.utf16_differences[index].1)
+    }
+}
+
+/// `loc` field of a node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct SourceLocation {
+    start: Position,
+    end: Position,
+}
+
+impl ESTree for SourceLocation {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("start", &self.start);
+        state.serialize_field("end", &self.end);
+        state.end();
+    }
+}
+
+/// Line and column of an offset. Lines start at 1, and columns at 0 counting UTF-16 code units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Position {
+    line: u32,
+    column: u32,
+}
+
+impl ESTree for Position {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("line", &self.line);
+        state.serialize_field("column", &self.column);
+        state.end();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LineTable, Position};
+
+    #[test]
+    fn positions() {
+        let table = LineTable::new("ab\ncd\r\nef\rgh\u{2028}ij");
+        let cases = [
+            (0, 1, 0),
+            (2, 1, 2),
+            (3, 2, 0),
+            (5, 2, 2),
+            (7, 3, 0),
+            (10, 4, 0),
+            (12, 4, 2),
+            (15, 5, 0),
+            (17, 5, 2),
+        ];
+        for (offset, line, column) in cases {
+            assert_eq!(table.position(offset), Position { line, column }, "offset {offset}");
+        }
+    }
+
+    #[test]
+    fn utf16_columns() {
+        // `é` is 2 bytes in UTF-8 and 1 code unit in UTF-16, `😀` is 4 bytes and 2 code units
+        let table = LineTable::new("é😀a\n😀b");
+        let cases = [(0, 1, 0), (2, 1, 1), (6, 1, 3), (7, 1, 4), (8, 2, 0), (12, 2, 2)];
+        for (offset, line, column) in cases {
+            assert_eq!(table.position(offset), Position { line, column }, "offset {offset}");
+        }
+    }
+}
@@ -11,12 +11,14 @@ mod blanket;
 mod concat;
 mod config;
 mod formatter;
+mod loc;
 mod primitives;
 mod sequences;
 mod strings;
 mod structs;
 use config::{Config, ConfigFixesJS, ConfigFixesTS, ConfigJS, ConfigTS};
 use formatter::{CompactFormatter, Formatter, PrettyFormatter};
+use loc::LineTable;
 use sequences::ESTreeSequenceSerializer;
 use structs::ESTreeStructSerializer;
 
@@ -104,6 +106,7 @@ pub struct ESTreeSerializer<C: Config, F: Formatter> {
     trace_path: NonEmptyStack<TracePathPart>,
     fixes_buffer: CodeBuffer,
     config: C,
+    line_table: Option<LineTable>,
 }
 
 impl<C: Config, F: Formatter> ESTreeSerializer<C, F> {
@@ -115,6 +118,7 @@ impl<C: Config, F: Formatter> ESTreeSerializer<C, F> {
             trace_path: NonEmptyStack::new(TracePathPart::Index(0)),
             fixes_buffer: CodeBuffer::new(),
             config: C::new(ranges),
+            line_table: None,
         }
     }
 
@@ -126,9 +130,20 @@ impl<C: Config, F: Formatter> ESTreeSerializer<C, F> {
             trace_path: NonEmptyStack::new(TracePathPart::Index(0)),
             fixes_buffer: CodeBuffer::new(),
             config: C::new(ranges),
+            line_table: None,
         }
     }
 
+    /// Output `loc` fields, with the line and column of the start and end of each node in `source_text`.
+    ///
+    /// Lines start at 1, and columns start at 0 and count UTF-16 code units, as in ESTree.
+    /// Spans must be UTF-8 offsets into `source_text`, as produced by the parser.
+    #[must_use]
+    pub fn with_loc(mut self, source_text: &str) -> Self {
+        self.line_table = Some(LineTable::new(source_text));
+        self
+    }
+
     /// Serialize `node` and output a `JSON` string containing
     /// `{ "node": { ... }, "fixes": [ ... ]}`, where `node` is the serialized AST node,
     /// and `fixes` is a list of paths to any `Literal`s which are `BigInt`s or `RegExp`s.
@@ -141,10 +141,16 @@ impl<C: Config, F: Formatter> StructSerializer for ESTreeStructSerializer<'_, C,
     ///
     /// * If `serializer.ranges() == true`, outputs `start`, `end`, and `range` fields.
     /// * Otherwise, outputs only `start` and `end`.
+    ///
+    /// If the serializer was created [`with_loc`](ESTreeSerializer::with_loc), also outputs `loc`
+    /// before `range`.
     fn serialize_span<S: ESTreeSpan>(&mut self, span: S) {
         let range = span.range();
         self.serialize_field("start", &range[0]);
         self.serialize_field("end", &range[1]);
+        if let Some(loc) = self.serializer.line_table.as_ref().map(|table| table.loc(range)) {
+            self.serialize_field("loc", &loc);
+        }
         if self.serializer.ranges() {
             self.serialize_field("range", &range);
         }
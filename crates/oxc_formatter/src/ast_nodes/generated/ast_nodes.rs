@@ -117,6 +117,9 @@ pub enum AstNodes<'a> {
     ExportAllDeclaration(&'a AstNode<'a, ExportAllDeclaration<'a>>),
     ExportSpecifier(&'a AstNode<'a, ExportSpecifier<'a>>),
     V8IntrinsicExpression(&'a AstNode<'a, V8IntrinsicExpression<'a>>),
+    PipelineExpression(&'a AstNode<'a, PipelineExpression<'a>>),
+    TopicReference(&'a AstNode<'a, TopicReference>),
+    DoExpression(&'a AstNode<'a, DoExpression<'a>>),
     BooleanLiteral(&'a AstNode<'a, BooleanLiteral>),
     NullLiteral(&'a AstNode<'a, NullLiteral>),
     NumericLiteral(&'a AstNode<'a, NumericLiteral<'a>>),
@@ -311,6 +314,9 @@ impl<'a> AstNodes<'a> {
             Self::ExportAllDeclaration(n) => n.span(),
             Self::ExportSpecifier(n) => n.span(),
             Self::V8IntrinsicExpression(n) => n.span(),
+            Self::PipelineExpression(n) => n.span(),
+            Self::TopicReference(n) => n.span(),
+            Self::DoExpression(n) => n.span(),
             Self::BooleanLiteral(n) => n.span(),
             Self::NullLiteral(n) => n.span(),
             Self::NumericLiteral(n) => n.span(),
@@ -505,6 +511,9 @@ impl<'a> AstNodes<'a> {
             Self::ExportAllDeclaration(n) => n.parent,
             Self::ExportSpecifier(n) => n.parent,
             Self::V8IntrinsicExpression(n) => n.parent,
+            Self::PipelineExpression(n) => n.parent,
+            Self::TopicReference(n) => n.parent,
+            Self::DoExpression(n) => n.parent,
             Self::BooleanLiteral(n) => n.parent,
             Self::NullLiteral(n) => n.parent,
             Self::NumericLiteral(n) => n.parent,
@@ -699,6 +708,9 @@ impl<'a> AstNodes<'a> {
             Self::ExportAllDeclaration(_) => "ExportAllDeclaration",
             Self::ExportSpecifier(_) => "ExportSpecifier",
             Self::V8IntrinsicExpression(_) => "V8IntrinsicExpression",
+            Self::PipelineExpression(_) => "PipelineExpression",
+            Self::TopicReference(_) => "TopicReference",
+            Self::DoExpression(_) => "DoExpression",
             Self::BooleanLiteral(_) => "BooleanLiteral",
             Self::NullLiteral(_) => "NullLiteral",
             Self::NumericLiteral(_) => "NumericLiteral",
@@ -1176,6 +1188,28 @@ impl<'a> AstNode<'a, Expression<'a>> {
                     following_span: self.following_span,
                 }))
             }
+            Expression::PipelineExpression(s) => {
+                AstNodes::PipelineExpression(self.allocator.alloc(AstNode {
+                    inner: s.as_ref(),
+                    parent,
+                    allocator: self.allocator,
+                    following_span: self.following_span,
+                }))
+            }
+            Expression::TopicReference(s) => {
+                AstNodes::TopicReference(self.allocator.alloc(AstNode {
+                    inner: s.as_ref(),
+                    parent,
+                    allocator: self.allocator,
+                    following_span: self.following_span,
+                }))
+            }
+            Expression::DoExpression(s) => AstNodes::DoExpression(self.allocator.alloc(AstNode {
+                inner: s.as_ref(),
+                parent,
+                allocator: self.allocator,
+                following_span: self.following_span,
+            })),
             it @ match_member_expression!(Expression) => {
                 return self
                     .allocator
@@ -5612,6 +5646,69 @@ impl<'a> AstNode<'a, V8IntrinsicExpression<'a>> {
     }
 }
 
+impl<'a> AstNode<'a, PipelineExpression<'a>> {
+    #[inline]
+    pub fn head(&self) -> &AstNode<'a, Expression<'a>> {
+        let following_span = Some(self.inner.body.span());
+        self.allocator.alloc(AstNode {
+            inner: &self.inner.head,
+            allocator: self.allocator,
+            parent: self.allocator.alloc(AstNodes::PipelineExpression(transmute_self(self))),
+            following_span,
+        })
+    }
+
+    #[inline]
+    pub fn body(&self) -> &AstNode<'a, Expression<'a>> {
+        let following_span = self.following_span;
+        self.allocator.alloc(AstNode {
+            inner: &self.inner.body,
+            allocator: self.allocator,
+            parent: self.allocator.alloc(AstNodes::PipelineExpression(transmute_self(self))),
+            following_span,
+        })
+    }
+
+    pub fn format_leading_comments(&self, f: &mut Formatter<'_, 'a>) {
+        format_leading_comments(self.span()).fmt(f);
+    }
+
+    pub fn format_trailing_comments(&self, f: &mut Formatter<'_, 'a>) {
+        format_trailing_comments(self.parent.span(), self.inner.span(), self.following_span).fmt(f);
+    }
+}
+
+impl<'a> AstNode<'a, TopicReference> {
+    pub fn format_leading_comments(&self, f: &mut Formatter<'_, 'a>) {
+        format_leading_comments(self.span()).fmt(f);
+    }
+
+    pub fn format_trailing_comments(&self, f: &mut Formatter<'_, 'a>) {
+        format_trailing_comments(self.parent.span(), self.inner.span(), self.following_span).fmt(f);
+    }
+}
+
+impl<'a> AstNode<'a, DoExpression<'a>> {
+    #[inline]
+    pub fn body(&self) -> &AstNode<'a, BlockStatement<'a>> {
+        let following_span = self.following_span;
+        self.allocator.alloc(AstNode {
+            inner: self.inner.body.as_ref(),
+            allocator: self.allocator,
+            parent: self.allocator.alloc(AstNodes::DoExpression(transmute_self(self))),
+            following_span,
+        })
+    }
+
+    pub fn format_leading_comments(&self, f: &mut Formatter<'_, 'a>) {
+        format_leading_comments(self.span()).fmt(f);
+    }
+
+    pub fn format_trailing_comments(&self, f: &mut Formatter<'_, 'a>) {
+        format_trailing_comments(self.parent.span(), self.inner.span(), self.following_span).fmt(f);
+    }
+}
+
 impl<'a> AstNode<'a, BooleanLiteral> {
     #[inline]
     pub fn value(&self) -> bool {
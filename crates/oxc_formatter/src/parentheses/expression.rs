@@ -55,6 +55,9 @@ impl NeedsParentheses<'_> for AstNode<'_, Expression<'_>> {
             AstNodes::TSNonNullExpression(it) => it.needs_parentheses(f),
             AstNodes::TSInstantiationExpression(it) => it.needs_parentheses(f),
             AstNodes::V8IntrinsicExpression(it) => it.needs_parentheses(f),
+            AstNodes::PipelineExpression(it) => it.needs_parentheses(f),
+            AstNodes::TopicReference(it) => it.needs_parentheses(f),
+            AstNodes::DoExpression(it) => it.needs_parentheses(f),
             AstNodes::StaticMemberExpression(it) => it.needs_parentheses(f),
             AstNodes::ComputedMemberExpression(it) => it.needs_parentheses(f),
             AstNodes::PrivateFieldExpression(it) => it.needs_parentheses(f),
@@ -451,10 +454,11 @@ impl NeedsParentheses<'_> for AstNode<'_, ConditionalExpression<'_>> {
         ) {
             return true;
         }
-        if let AstNodes::ConditionalExpression(e) = parent {
-            e.test.span() == self.span()
-        } else {
-            update_or_lower_expression_needs_parens(self.span(), parent)
+        match parent {
+            AstNodes::ConditionalExpression(e) => e.test.span() == self.span(),
+            // The head of `|>` is a short-circuit expression
+            AstNodes::PipelineExpression(e) => e.head.span() == self.span(),
+            _ => update_or_lower_expression_needs_parens(self.span(), parent),
         }
     }
 }
@@ -650,6 +654,7 @@ impl NeedsParentheses<'_> for AstNode<'_, ArrowFunctionExpression<'_>> {
                 | AstNodes::AwaitExpression(_)
                 | AstNodes::LogicalExpression(_)
                 | AstNodes::BinaryExpression(_)
+                | AstNodes::PipelineExpression(_)
         ) {
             return true;
         }
@@ -668,8 +673,12 @@ impl NeedsParentheses<'_> for AstNode<'_, YieldExpression<'_>> {
         }
 
         let parent = self.parent;
-        matches!(parent, AstNodes::AwaitExpression(_) | AstNodes::TSTypeAssertion(_))
-            || await_or_yield_needs_parens(self.span(), parent)
+        matches!(
+            parent,
+            AstNodes::AwaitExpression(_)
+                | AstNodes::TSTypeAssertion(_)
+                | AstNodes::PipelineExpression(_)
+        ) || await_or_yield_needs_parens(self.span(), parent)
     }
 }
 
@@ -690,6 +699,58 @@ impl NeedsParentheses<'_> for AstNode<'_, V8IntrinsicExpression<'_>> {
     }
 }
 
+impl NeedsParentheses<'_> for AstNode<'_, PipelineExpression<'_>> {
+    fn needs_parentheses(&self, f: &Formatter<'_, '_>) -> bool {
+        if f.comments().is_type_cast_node(self) {
+            return false;
+        }
+
+        let parent = self.parent;
+        if matches!(
+            parent,
+            AstNodes::UnaryExpression(_)
+                | AstNodes::AwaitExpression(_)
+                | AstNodes::TSTypeAssertion(_)
+                | AstNodes::TSAsExpression(_)
+                | AstNodes::TSSatisfiesExpression(_)
+                | AstNodes::SpreadElement(_)
+                | AstNodes::JSXSpreadAttribute(_)
+                | AstNodes::LogicalExpression(_)
+                | AstNodes::BinaryExpression(_)
+        ) {
+            return true;
+        }
+        match parent {
+            AstNodes::ConditionalExpression(e) => e.test.span() == self.span(),
+            // `|>` is left-associative
+            AstNodes::PipelineExpression(e) => e.body.span() == self.span(),
+            _ => update_or_lower_expression_needs_parens(self.span(), parent),
+        }
+    }
+}
+
+impl NeedsParentheses<'_> for AstNode<'_, TopicReference> {
+    #[inline]
+    fn needs_parentheses(&self, _f: &Formatter<'_, '_>) -> bool {
+        false
+    }
+}
+
+impl NeedsParentheses<'_> for AstNode<'_, DoExpression<'_>> {
+    fn needs_parentheses(&self, f: &Formatter<'_, '_>) -> bool {
+        if f.comments().is_type_cast_node(self) {
+            return false;
+        }
+
+        // `do {} while (a)` is a statement
+        is_first_in_statement(
+            self.span,
+            self.parent,
+            FirstInStatementMode::ExpressionOrExportDefault,
+        )
+    }
+}
+
 impl NeedsParentheses<'_> for AstNode<'_, JSXMemberExpression<'_>> {
     #[inline]
     fn needs_parentheses(&self, _f: &Formatter<'_, '_>) -> bool {
@@ -989,6 +1050,11 @@ fn is_first_in_statement(
             {
                 return !is_not_first_iteration;
             }
+            AstNodes::PipelineExpression(pipeline) => {
+                if pipeline.head.span() != current_span {
+                    break;
+                }
+            }
             _ if ancestor.is_call_like_callee_span(current_span) => {}
             _ => break,
         }
@@ -9,8 +9,18 @@ pub fn get_parse_options() -> ParseOptions {
         // Enable all syntax features
         allow_return_outside_function: true,
         allow_v8_intrinsics: true,
+        allow_pipeline_operator: true,
+        allow_do_expressions: true,
+        // Flow files would be printed as TypeScript
+        allow_flow_syntax: false,
+        // Every function body is formatted
+        skip_function_bodies: false,
         // `oxc_formatter` expects this to be `false`, otherwise panics
         preserve_parens: false,
+        // Skipped statements would be removed by formatting
+        recover_from_errors: false,
+        // Comments are taken from the AST, tokens are not needed
+        collect_tokens: false,
     }
 }
 
@@ -79,6 +79,7 @@ fn is_non_collapsible(parent: &AstNodes<'_>) -> bool {
         | AstNodes::ForStatement(_)
         | AstNodes::WhileStatement(_)
         | AstNodes::DoWhileStatement(_)
+        | AstNodes::DoExpression(_)
         | AstNodes::TSModuleDeclaration(_)
         | AstNodes::TSGlobalDeclaration(_) => false,
         AstNodes::CatchClause(catch) => {
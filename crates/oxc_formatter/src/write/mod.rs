@@ -1002,6 +1002,24 @@ impl<'a> FormatWrite<'a> for AstNode<'a, V8IntrinsicExpression<'a>> {
     }
 }
 
+impl<'a> FormatWrite<'a> for AstNode<'a, PipelineExpression<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) {
+        write!(f, [self.head(), space(), "|>", space(), self.body()]);
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, TopicReference> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) {
+        write!(f, "%");
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, DoExpression<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) {
+        write!(f, ["do", space(), self.body()]);
+    }
+}
+
 impl<'a> FormatWrite<'a> for AstNode<'a, BooleanLiteral> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
         write!(f, if self.value() { "true" } else { "false" });
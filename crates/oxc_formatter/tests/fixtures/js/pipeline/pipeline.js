@@ -0,0 +1,16 @@
+a |> f(%) |> % + 1;
+a |> (% |> f(%));
+x = a |> % ? b : c;
+(a |> %) ? b : c;
+(a ? b : c) |> %;
+(x = a) |> %;
+(() => a) |> %;
+(a |> %) + 1;
+({ a } |> %);
+(function () {} |> %);
+a |> (x = %);
+a |> (() => %);
+function* g() {
+  (yield a) |> %;
+  a |> (yield %);
+}
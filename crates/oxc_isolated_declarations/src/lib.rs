@@ -10,7 +10,7 @@ use std::{cell::RefCell, mem};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use oxc_allocator::{Allocator, CloneIn, Vec as ArenaVec};
-use oxc_ast::{AstBuilder, NONE, ast::*};
+use oxc_ast::{AstBuilder, NONE, ast::*, triple_slash_directives};
 use oxc_ast_visit::Visit;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{Atom, GetSpan, SPAN, SourceType};
@@ -88,11 +88,24 @@ impl<'a> IsolatedDeclarations<'a> {
         let source_type = SourceType::d_ts();
         let directives = self.ast.vec();
         let stmts = self.transform_program(program);
+        // Triple-slash directives are kept at the top of the file, even if the statement which
+        // they were attached to is removed.
+        let directive_spans = triple_slash_directives(program)
+            .into_iter()
+            .map(|directive| directive.span)
+            .collect::<FxHashSet<_>>();
+        let comments = program.comments.iter().map(|comment| {
+            if directive_spans.contains(&comment.span) {
+                Comment { attached_to: 0, ..*comment }
+            } else {
+                *comment
+            }
+        });
         let program = self.ast.program(
             SPAN,
             source_type,
             program.source_text,
-            self.ast.vec_from_iter(program.comments.iter().copied()),
+            self.ast.vec_from_iter(comments),
             None,
             directives,
             stmts,
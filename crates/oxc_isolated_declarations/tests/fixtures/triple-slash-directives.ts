@@ -0,0 +1,6 @@
+/// <reference types="node" />
+/// <reference lib="es2022" />
+import { B } from "./b";
+const local = 1;
+
+export function f(): void {}
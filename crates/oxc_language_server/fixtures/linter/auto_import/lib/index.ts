@@ -0,0 +1,2 @@
+export * from "./utils";
+export const baz = 1;
@@ -0,0 +1,2 @@
+export function foo() {}
+export function bar() {}
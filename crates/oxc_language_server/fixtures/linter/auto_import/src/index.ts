@@ -0,0 +1,4 @@
+import { bar } from "../lib/utils";
+import { baz } from "../lib";
+
+bar(baz, foo);
@@ -0,0 +1,2 @@
+export { add as sum } from './utils';
+export * from './utils';
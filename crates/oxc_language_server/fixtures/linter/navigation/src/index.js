@@ -0,0 +1,5 @@
+import { sum, add as plus } from '../lib';
+import { add } from '../lib/utils';
+
+const total = sum(1, 2) + plus(3, 4) + add(5, 6);
+export default total;
@@ -0,0 +1,7 @@
+import { b } from './b';
+import { x } from './a';
+import { readFile } from 'node:fs';
+import { y } from './a';
+import { unused } from './c';
+
+readFile(b, x, y);
@@ -0,0 +1,11 @@
+import { foo } from './foo';
+
+const value = foo();
+export { value };
+
+function print() {
+  const other = 1;
+  console.log({ value }, other);
+}
+
+print();
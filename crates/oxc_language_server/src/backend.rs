@@ -1,8 +1,12 @@
-use std::{borrow::Cow, sync::Arc};
+use std::{
+    borrow::Cow,
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
 
 use futures::future::join_all;
 use log::{debug, error, info, warn};
-use rustc_hash::FxBuildHasher;
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHasher};
 use serde_json::Value;
 use tokio::sync::{OnceCell, RwLock, SetError};
 use tower_lsp_server::{
@@ -14,12 +18,19 @@ use tower_lsp_server::{
         DidChangeWorkspaceFoldersParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
         DidSaveTextDocumentParams, DocumentDiagnosticParams, DocumentDiagnosticReport,
         DocumentDiagnosticReportKind, DocumentDiagnosticReportResult, DocumentFormattingParams,
-        ExecuteCommandParams, FullDocumentDiagnosticReport, InitializeParams, InitializeResult,
-        InitializedParams, MessageType, RelatedFullDocumentDiagnosticReport, ServerInfo, TextEdit,
-        Uri,
+        ExecuteCommandParams, FileEvent, FullDocumentDiagnosticReport, GotoDefinitionParams,
+        GotoDefinitionResponse, Hover, HoverParams, InitializeParams, InitializeResult,
+        InitializedParams, Location, MessageType, ReferenceParams, Registration,
+        RelatedFullDocumentDiagnosticReport, RelatedUnchangedDocumentDiagnosticReport,
+        RenameParams, ServerInfo, TextEdit, UnchangedDocumentDiagnosticReport, Unregistration, Uri,
+        WorkspaceDiagnosticParams, WorkspaceDiagnosticReport, WorkspaceDiagnosticReportResult,
+        WorkspaceDocumentDiagnosticReport, WorkspaceEdit, WorkspaceFullDocumentDiagnosticReport,
+        WorkspaceUnchangedDocumentDiagnosticReport,
     },
 };
 
+#[cfg(feature = "linter")]
+use crate::file_watcher::WorkspaceFolderWatcher;
 use crate::{
     ConcurrentHashMap, ToolBuilder,
     capabilities::{Capabilities, server_capabilities},
@@ -70,6 +81,9 @@ pub struct Backend {
     // The client will send the content of in-memory files on `textDocument/didOpen` and `textDocument/didChange`.
     // This is only needed when the client supports `textDocument/formatting` request.
     file_system: Arc<RwLock<LSPFileSystem>>,
+    // File watchers of the workspace folders, when the client can not watch files for the server.
+    #[cfg(feature = "linter")]
+    file_watchers: tokio::sync::Mutex<FxHashMap<Uri, WorkspaceFolderWatcher>>,
 }
 
 impl LanguageServer for Backend {
@@ -254,6 +268,11 @@ impl LanguageServer for Backend {
             for worker in workers {
                 registrations.extend(worker.init_watchers().await);
             }
+        } else {
+            #[cfg(feature = "linter")]
+            for worker in workers {
+                self.watch_workspace_folder(worker.get_root_uri()).await;
+            }
         }
 
         if registrations.is_empty() {
@@ -276,6 +295,8 @@ impl LanguageServer for Backend {
             // some clients (`helix`) do not expect any requests after shutdown is sent.
             let (uris, _) = worker.shutdown().await;
             clearing_diagnostics.extend(uris);
+            #[cfg(feature = "linter")]
+            self.unwatch_workspace_folder(worker.get_root_uri()).await;
         }
 
         // only clear diagnostics when we are using push diagnostics
@@ -406,50 +427,17 @@ impl LanguageServer for Backend {
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#workspace_didChangeWatchedFiles>
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
-        let workers = self.workspace_workers.read().await;
-        // ToDo: what if an empty changes flag is passed?
         debug!("watched file did change");
-
-        let mut new_diagnostics = Vec::new();
-        let mut removing_registrations = vec![];
-        let mut adding_registrations = vec![];
-
-        let mut needs_diagnostics_refresh = false;
         let is_push_diagnostics =
             self.capabilities.get().is_some_and(Capabilities::use_push_diagnostics);
-        let fs_guard = if is_push_diagnostics { Some(self.file_system.read().await) } else { None };
-        let fs_ref = fs_guard.as_deref();
-
-        for file_event in &params.changes {
-            // We do not expect multiple changes from the same workspace folder.
-            // If we should consider it, we need to map the events to the workers first,
-            // to only restart the internal linter / diagnostics for once
-            let Some(worker) =
-                workers.iter().find(|worker| worker.is_responsible_for_uri(&file_event.uri))
-            else {
-                continue;
-            };
-            let (diagnostics, registrations, unregistrations) = worker
-                .did_change_watched_files(file_event, &mut needs_diagnostics_refresh, fs_ref)
-                .await;
-
-            if let Some(diagnostics) = diagnostics {
-                new_diagnostics.extend(diagnostics);
-            }
-            removing_registrations.extend(unregistrations);
-            adding_registrations.extend(registrations);
-        }
-
-        if is_push_diagnostics && !new_diagnostics.is_empty() {
-            self.publish_all_diagnostics(new_diagnostics, ConcurrentHashMap::default()).await;
-        }
-
-        if !is_push_diagnostics && needs_diagnostics_refresh {
-            // In pull diagnostic model, we ask the client to refresh diagnostics
-            if let Err(err) = self.client.workspace_diagnostic_refresh().await {
-                warn!("sending workspace/diagnostic/refresh failed: {err}");
-            }
-        }
+        let (adding_registrations, removing_registrations) = handle_watched_file_events(
+            &self.client,
+            &self.workspace_workers,
+            &self.file_system,
+            is_push_diagnostics,
+            params.changes,
+        )
+        .await;
 
         if self.capabilities.get().is_some_and(|capabilities| capabilities.dynamic_watchers) {
             if !removing_registrations.is_empty()
@@ -477,6 +465,8 @@ impl LanguageServer for Backend {
         let mut cleared_diagnostics = vec![];
         let mut added_registrations = vec![];
         let mut removed_registrations = vec![];
+        #[cfg(feature = "linter")]
+        let added_folders = params.event.added.len();
 
         for folder in params.event.removed {
             let Some((index, worker)) = workers
@@ -489,6 +479,8 @@ impl LanguageServer for Backend {
             let (uris, unregistrations) = worker.shutdown().await;
             cleared_diagnostics.extend(uris);
             removed_registrations.extend(unregistrations);
+            #[cfg(feature = "linter")]
+            self.unwatch_workspace_folder(worker.get_root_uri()).await;
             workers.remove(index);
         }
 
@@ -528,6 +520,14 @@ impl LanguageServer for Backend {
             }
         }
 
+        // watch the files of the added workspace folders on the server
+        #[cfg(feature = "linter")]
+        if !self.capabilities.get().is_some_and(|capabilities| capabilities.dynamic_watchers) {
+            for worker in &workers[workers.len() - added_folders..] {
+                self.watch_workspace_folder(worker.get_root_uri()).await;
+            }
+        }
+
         // tell client to stop / start watching for files
         if self.capabilities.get().is_some_and(|capabilities| capabilities.dynamic_watchers) {
             if !added_registrations.is_empty()
@@ -658,7 +658,8 @@ impl LanguageServer for Backend {
     }
 
     /// It will return code actions or commands for the given range.
-    /// The client can send `context.only` to `source.fixAll.oxc` to fix all diagnostics of the file.
+    /// The client can send `context.only` to `source.fixAll.oxc` to fix all diagnostics of the file,
+    /// or to `source.organizeImports.oxc` to organize the imports of the file.
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_codeAction>
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
@@ -668,8 +669,15 @@ impl LanguageServer for Backend {
             return Ok(None);
         };
 
-        let code_actions =
-            worker.get_code_actions_or_commands(uri, &params.range, params.context.only).await;
+        let content = self.file_system.read().await.get(uri);
+        let code_actions = worker
+            .get_code_actions_or_commands(
+                uri,
+                &params.range,
+                params.context.only,
+                content.as_deref(),
+            )
+            .await;
 
         if code_actions.is_empty() {
             return Ok(None);
@@ -743,11 +751,25 @@ impl LanguageServer for Backend {
         let related_diagnostics =
             diagnostics.into_iter().filter(|(diag_uri, _)| diag_uri != uri).collect::<Vec<_>>();
 
+        let result_id = diagnostics_result_id(&uri_diagnostics);
+        if related_diagnostics.is_empty()
+            && params.previous_result_id.as_ref().is_some_and(|previous| *previous == result_id)
+        {
+            return Ok(DocumentDiagnosticReportResult::Report(
+                DocumentDiagnosticReport::Unchanged(RelatedUnchangedDocumentDiagnosticReport {
+                    unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                        result_id,
+                    },
+                    related_documents: None,
+                }),
+            ));
+        }
+
         Ok(DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(
             RelatedFullDocumentDiagnosticReport {
                 full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                    result_id: Some(result_id),
                     items: uri_diagnostics,
-                    ..Default::default()
                 },
                 related_documents: if related_diagnostics.is_empty() {
                     None
@@ -773,6 +795,145 @@ impl LanguageServer for Backend {
         )))
     }
 
+    /// It will lint all files of every workspace, reusing the already started tools,
+    /// and report the diagnostics of each file.
+    /// Files whose diagnostics did not change since the `previous_result_ids` are reported as unchanged.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#workspace_diagnostic>
+    async fn workspace_diagnostic(
+        &self,
+        params: WorkspaceDiagnosticParams,
+    ) -> Result<WorkspaceDiagnosticReportResult> {
+        let previous_result_ids = params
+            .previous_result_ids
+            .iter()
+            .map(|previous| (&previous.uri, previous.value.as_str()))
+            .collect::<FxHashMap<_, _>>();
+        let file_system = self.file_system.read().await;
+        let mut items = vec![];
+
+        for worker in self.workspace_workers.read().await.iter() {
+            let diagnostics = match worker.run_workspace_diagnostic(&file_system).await {
+                Err(err) => {
+                    error!(
+                        "running workspace diagnostics for {} failed: {err}",
+                        worker.get_root_uri().as_str()
+                    );
+                    return Err(Error {
+                        code: ErrorCode::ServerError(1),
+                        message: Cow::Owned(err),
+                        data: None,
+                    });
+                }
+                Ok(diagnostics) => diagnostics,
+            };
+
+            for (uri, diagnostics) in diagnostics {
+                let result_id = diagnostics_result_id(&diagnostics);
+                let report = if previous_result_ids.get(&uri) == Some(&result_id.as_str()) {
+                    WorkspaceDocumentDiagnosticReport::Unchanged(
+                        WorkspaceUnchangedDocumentDiagnosticReport {
+                            uri,
+                            version: None,
+                            unchanged_document_diagnostic_report:
+                                UnchangedDocumentDiagnosticReport { result_id },
+                        },
+                    )
+                } else {
+                    WorkspaceDocumentDiagnosticReport::Full(WorkspaceFullDocumentDiagnosticReport {
+                        uri,
+                        version: None,
+                        full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                            result_id: Some(result_id),
+                            items: diagnostics,
+                        },
+                    })
+                };
+                items.push(report);
+            }
+        }
+
+        Ok(WorkspaceDiagnosticReportResult::Report(WorkspaceDiagnosticReport { items }))
+    }
+
+    /// It will return the documentation of the rule reported at the given position,
+    /// or of the rule named inside a disable comment.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_hover>
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+            return Ok(None);
+        };
+        let content = self.file_system.read().await.get(uri);
+        Ok(worker
+            .get_hover(uri, params.text_document_position_params.position, content.as_deref())
+            .await)
+    }
+
+    /// It will return the declaration of the symbol at the given position,
+    /// following imports to the modules declaring them.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_definition>
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+            return Ok(None);
+        };
+        let content = self.file_system.read().await.get(uri);
+        Ok(worker
+            .get_definition(uri, params.text_document_position_params.position, content.as_deref())
+            .await)
+    }
+
+    /// It will return the references of the symbol at the given position,
+    /// including the ones in the modules importing it.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_references>
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let uri = &params.text_document_position.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+            return Ok(None);
+        };
+        let content = self.file_system.read().await.get(uri);
+        Ok(worker
+            .get_references(
+                uri,
+                params.text_document_position.position,
+                params.context.include_declaration,
+                content.as_deref(),
+            )
+            .await)
+    }
+
+    /// It will return the edits to rename the symbol at the given position,
+    /// or an error when the new name is invalid or conflicts with another binding.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_rename>
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let uri = &params.text_document_position.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+            return Ok(None);
+        };
+        let content = self.file_system.read().await.get(uri);
+        worker
+            .rename(
+                uri,
+                params.text_document_position.position,
+                &params.new_name,
+                content.as_deref(),
+            )
+            .await
+            .map_err(Error::invalid_params)
+    }
+
     /// It will return text edits to format the document if formatting is enabled for the workspace.
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_formatting>
@@ -799,6 +960,8 @@ impl Backend {
             workspace_workers: Arc::new(RwLock::new(vec![])),
             capabilities: OnceCell::new(),
             file_system: Arc::new(RwLock::new(LSPFileSystem::default())),
+            #[cfg(feature = "linter")]
+            file_watchers: tokio::sync::Mutex::default(),
         }
     }
 
@@ -850,6 +1013,77 @@ impl Backend {
         .await;
     }
 
+    /// Watch the files of the workspace folder of a [WorkspaceWorker] on the server,
+    /// because the client can not register file watchers.
+    /// Changes of files matching the watcher patterns of the tools are handled like `workspace/didChangeWatchedFiles`.
+    /// Watching stops with [Backend::unwatch_workspace_folder].
+    #[cfg(feature = "linter")]
+    async fn watch_workspace_folder(&self, root_uri: &Uri) {
+        let Some(root_path) = root_uri.to_file_path() else {
+            return;
+        };
+        let (file_watcher, mut receiver) = match WorkspaceFolderWatcher::new(&root_path) {
+            Ok(watcher) => watcher,
+            Err(err) => {
+                warn!("watching files of {} failed: {err}", root_uri.as_str());
+                return;
+            }
+        };
+        self.unwatch_workspace_folder(root_uri).await;
+        self.file_watchers.lock().await.insert(root_uri.clone(), file_watcher);
+
+        let client = self.client.clone();
+        let workspace_workers = Arc::clone(&self.workspace_workers);
+        let file_system = Arc::clone(&self.file_system);
+        let is_push_diagnostics =
+            self.capabilities.get().is_some_and(Capabilities::use_push_diagnostics);
+        let root_uri = root_uri.clone();
+        // the receiver is closed when the file watcher is dropped
+        tokio::spawn(async move {
+            while let Some(file_events) = receiver.recv().await {
+                let mut watched_file_events = vec![];
+                {
+                    let workers = workspace_workers.read().await;
+                    let Some(worker) =
+                        workers.iter().find(|worker| worker.get_root_uri() == &root_uri)
+                    else {
+                        // the workspace folder was removed
+                        break;
+                    };
+                    for file_event in file_events {
+                        if worker.is_watched_uri(&file_event.uri).await {
+                            watched_file_events.push(file_event);
+                        }
+                    }
+                }
+                if watched_file_events.is_empty() {
+                    continue;
+                }
+                // the registrations are ignored, because the whole workspace folder is watched
+                handle_watched_file_events(
+                    &client,
+                    &workspace_workers,
+                    &file_system,
+                    is_push_diagnostics,
+                    watched_file_events,
+                )
+                .await;
+            }
+        });
+    }
+
+    /// Stop watching the files of a workspace folder, see [Backend::watch_workspace_folder].
+    #[cfg(feature = "linter")]
+    async fn unwatch_workspace_folder(&self, root_uri: &Uri) {
+        let Some(file_watcher) = self.file_watchers.lock().await.remove(root_uri) else {
+            return;
+        };
+        // dropping the watcher joins its thread
+        if let Err(err) = tokio::task::spawn_blocking(move || drop(file_watcher)).await {
+            warn!("stopping the file watcher of {} failed: {err}", root_uri.as_str());
+        }
+    }
+
     /// Assert that all workspace URIs are valid file paths.
     /// If any URI is not a valid file path, return an error.
     ///
@@ -866,3 +1100,78 @@ impl Backend {
         Ok(())
     }
 }
+
+/// Restart the tools of the [WorkspaceWorker]s for changed watched files and revalidate the diagnostics.
+/// The events are reported by the client with `workspace/didChangeWatchedFiles`,
+/// or by the server's own file watchers when the client can not watch files.
+///
+/// Returns the file watcher registrations to add and to remove.
+async fn handle_watched_file_events(
+    client: &Client,
+    workspace_workers: &RwLock<Vec<WorkspaceWorker>>,
+    file_system: &RwLock<LSPFileSystem>,
+    is_push_diagnostics: bool,
+    changes: Vec<FileEvent>,
+) -> (Vec<Registration>, Vec<Unregistration>) {
+    let workers = workspace_workers.read().await;
+
+    let mut new_diagnostics = Vec::new();
+    let mut removing_registrations = vec![];
+    let mut adding_registrations = vec![];
+
+    let mut needs_diagnostics_refresh = false;
+    let fs_guard = if is_push_diagnostics { Some(file_system.read().await) } else { None };
+    let fs_ref = fs_guard.as_deref();
+
+    // Map the events to the workers first, to only restart the tools of a worker once.
+    let mut worker_events: Vec<Vec<FileEvent>> = vec![vec![]; workers.len()];
+    for file_event in changes {
+        let Some(index) =
+            workers.iter().position(|worker| worker.is_responsible_for_uri(&file_event.uri))
+        else {
+            continue;
+        };
+        worker_events[index].push(file_event);
+    }
+
+    for (worker, file_events) in workers.iter().zip(worker_events) {
+        if file_events.is_empty() {
+            continue;
+        }
+        let (diagnostics, registrations, unregistrations) = worker
+            .did_change_watched_files(&file_events, &mut needs_diagnostics_refresh, fs_ref)
+            .await;
+
+        if let Some(diagnostics) = diagnostics {
+            new_diagnostics.extend(diagnostics);
+        }
+        removing_registrations.extend(unregistrations);
+        adding_registrations.extend(registrations);
+    }
+
+    if is_push_diagnostics && !new_diagnostics.is_empty() {
+        join_all(
+            new_diagnostics
+                .into_iter()
+                .map(|(uri, diagnostics)| client.publish_diagnostics(uri, diagnostics, None)),
+        )
+        .await;
+    }
+
+    if !is_push_diagnostics && needs_diagnostics_refresh {
+        // In pull diagnostic model, we ask the client to refresh diagnostics
+        if let Err(err) = client.workspace_diagnostic_refresh().await {
+            warn!("sending workspace/diagnostic/refresh failed: {err}");
+        }
+    }
+
+    (adding_registrations, removing_registrations)
+}
+
+/// The result ID of a diagnostic report, which changes whenever the diagnostics change.
+/// Clients send it back with the next pull request, so unchanged reports can be skipped.
+fn diagnostics_result_id(diagnostics: &[Diagnostic]) -> String {
+    let mut hasher = FxHasher::default();
+    serde_json::to_string(diagnostics).unwrap_or_default().hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
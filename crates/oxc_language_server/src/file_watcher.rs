@@ -0,0 +1,84 @@
+use std::{
+    ffi::OsStr,
+    path::Path,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+use tower_lsp_server::ls_types::{FileChangeType, FileEvent, Uri};
+
+use oxc_linter::{FileChangeKind, FileWatcher, WatchError};
+
+use crate::linter::ServerLinterBuilder;
+
+/// How long the watcher thread waits for changes before checking whether it should stop.
+const STOP_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Watches the files of a workspace folder on a thread, until it is dropped.
+pub struct WorkspaceFolderWatcher {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl WorkspaceFolderWatcher {
+    /// Watch the files of a workspace folder with the notifications of the OS,
+    /// for clients which do not support `workspace/didChangeWatchedFiles` registrations.
+    ///
+    /// The changes are sent as batches of [FileEvent]s, like the client would send them.
+    /// Files ignored by `.gitignore`, `.eslintignore` or `.oxlintignore` and `.git` directories are not watched.
+    /// The receiver is closed when the watcher is dropped.
+    pub fn new(root_path: &Path) -> Result<(Self, UnboundedReceiver<Vec<FileEvent>>), WatchError> {
+        let ignore_globs = ServerLinterBuilder::create_ignore_glob(root_path);
+        let mut watcher = FileWatcher::new(root_path, move |path, is_dir| {
+            if is_dir && path.file_name() == Some(OsStr::new(".git")) {
+                return true;
+            }
+            ignore_globs.iter().any(|gitignore| {
+                path.starts_with(gitignore.path())
+                    && gitignore.matched_path_or_any_parents(path, is_dir).is_ignore()
+            })
+        })?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let (sender, receiver) = unbounded_channel();
+        let thread = thread::spawn({
+            let stop = Arc::clone(&stop);
+            move || {
+                while !stop.load(Ordering::Relaxed) {
+                    let file_events = watcher
+                        .wait_timeout(STOP_INTERVAL)
+                        .into_iter()
+                        .filter_map(|change| {
+                            let typ = match change.kind {
+                                FileChangeKind::Created => FileChangeType::CREATED,
+                                FileChangeKind::Changed => FileChangeType::CHANGED,
+                                FileChangeKind::Removed => FileChangeType::DELETED,
+                            };
+                            Some(FileEvent { uri: Uri::from_file_path(&change.path)?, typ })
+                        })
+                        .collect::<Vec<_>>();
+                    if !file_events.is_empty() && sender.send(file_events).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok((Self { stop, thread: Some(thread) }, receiver))
+    }
+}
+
+impl Drop for WorkspaceFolderWatcher {
+    /// Stop the thread and the OS watches, which takes at most [STOP_INTERVAL].
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
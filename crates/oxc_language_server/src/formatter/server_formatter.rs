@@ -9,7 +9,9 @@ use oxc_formatter::{
     oxfmtrc::{OxfmtOptions, Oxfmtrc},
 };
 use oxc_parser::Parser;
-use tower_lsp_server::ls_types::{Pattern, Position, Range, ServerCapabilities, TextEdit, Uri};
+use tower_lsp_server::ls_types::{
+    FileEvent, Pattern, Position, Range, ServerCapabilities, TextEdit, Uri,
+};
 
 use crate::{
     capabilities::Capabilities,
@@ -193,7 +195,7 @@ impl Tool for ServerFormatter {
         };
 
         if old_option == new_option {
-            return ToolRestartChanges { tool: None, watch_patterns: None };
+            return ToolRestartChanges { tool: None, watch_patterns: None, scope: None };
         }
 
         let new_formatter = ServerFormatterBuilder::build(root_uri, new_options_json.clone());
@@ -201,6 +203,7 @@ impl Tool for ServerFormatter {
         ToolRestartChanges {
             tool: Some(Box::new(new_formatter)),
             watch_patterns: Some(watch_patterns),
+            scope: None,
         }
     }
 
@@ -222,9 +225,9 @@ impl Tool for ServerFormatter {
         FORMAT_CONFIG_FILES.iter().map(|file| (*file).to_string()).collect()
     }
 
-    fn handle_watched_file_change(
+    fn handle_watched_file_changes(
         &self,
-        _changed_uri: &Uri,
+        _changed_files: &[FileEvent],
         root_uri: &Uri,
         options: serde_json::Value,
     ) -> ToolRestartChanges {
@@ -236,6 +239,7 @@ impl Tool for ServerFormatter {
             tool: Some(Box::new(new_formatter)),
             // TODO: update watch patterns if config_path changed
             watch_patterns: None,
+            scope: None,
         }
     }
 
@@ -4,6 +4,8 @@ use tower_lsp_server::{LspService, Server, ls_types::ServerInfo};
 mod backend;
 mod capabilities;
 mod file_system;
+#[cfg(feature = "linter")]
+mod file_watcher;
 #[cfg(feature = "formatter")]
 mod formatter;
 #[cfg(feature = "linter")]
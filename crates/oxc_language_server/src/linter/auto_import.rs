@@ -0,0 +1,257 @@
+use std::path::{Component, Path};
+
+use oxc_data_structures::rope::Rope;
+use oxc_linter::LintRunner;
+use tower_lsp_server::ls_types::{
+    CodeAction, CodeActionKind, Diagnostic, NumberOrString, Position, Range, TextEdit, Uri,
+    WorkspaceEdit,
+};
+
+use crate::linter::{
+    error_with_position::offset_to_position,
+    navigation::{position_to_offset, with_semantic},
+    virtual_document::VirtualDocument,
+};
+
+const NO_UNDEF_CODE: &str = "eslint(no-undef)";
+
+/// Extensions which are not written in import specifiers, longest first.
+const OMITTED_EXTENSIONS: [&str; 11] =
+    [".d.mts", ".d.cts", ".d.ts", ".mts", ".cts", ".tsx", ".mjs", ".cjs", ".jsx", ".ts", ".js"];
+
+/// Whether the diagnostic reports an undefined identifier, which could be imported.
+pub fn is_no_undef_diagnostic(diagnostic: &Diagnostic) -> bool {
+    matches!(&diagnostic.code, Some(NumberOrString::String(code)) if code == NO_UNDEF_CODE)
+}
+
+/// "Add import from '...'" code actions for the identifiers reported by `no-undef`.
+/// The modules exporting the identifiers are found in the module graph of `runner`, which is
+/// only built when cross-module linting is enabled.
+pub fn auto_import_code_actions(
+    runner: &LintRunner,
+    uri: &Uri,
+    source_text: &str,
+    diagnostics: &[&Diagnostic],
+) -> Vec<CodeAction> {
+    let Some(path) = uri.to_file_path() else {
+        return vec![];
+    };
+    let rope = Rope::from_str(source_text);
+
+    let mut code_actions = vec![];
+    for diagnostic in diagnostics {
+        let Some(name) = undefined_name(diagnostic) else {
+            continue;
+        };
+        let Some(insertion) = import_insertion(&path, source_text, diagnostic.range.start) else {
+            continue;
+        };
+        let position = offset_to_position(&rope, insertion.offset, source_text);
+        for specifier in import_specifiers(runner, &path, name) {
+            let quote = insertion.quote;
+            let import = format!("import {{ {name} }} from {quote}{specifier}{quote};");
+            let new_text =
+                if insertion.after_import { format!("\n{import}") } else { format!("{import}\n") };
+            let text_edit = TextEdit { range: Range::new(position, position), new_text };
+
+            code_actions.push(CodeAction {
+                title: format!("Add import from '{specifier}'"),
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: Some(vec![(*diagnostic).clone()]),
+                edit: Some(WorkspaceEdit {
+                    #[expect(clippy::disallowed_types)]
+                    changes: Some(std::collections::HashMap::from([(
+                        uri.clone(),
+                        vec![text_edit],
+                    )])),
+                    ..WorkspaceEdit::default()
+                }),
+                is_preferred: Some(false),
+                ..CodeAction::default()
+            });
+        }
+    }
+    code_actions
+}
+
+/// Where an import is inserted in a file.
+struct ImportInsertion {
+    offset: u32,
+    /// The quote of the specifiers of the other imports.
+    quote: char,
+    /// Whether the import is inserted after another import, or before the first statement.
+    after_import: bool,
+}
+
+/// Insert the import in the document of the position, which is the whole file or a `<script>`
+/// block: after the last import statement, with the same quotes, or else at its start.
+fn import_insertion(path: &Path, source_text: &str, position: Position) -> Option<ImportInsertion> {
+    let offset = position_to_offset(source_text, position)?;
+    let document = VirtualDocument::at_offset(path, source_text, offset)?;
+    let last_import = with_semantic(&document, |_, module_record| {
+        module_record
+            .requested_modules
+            .values()
+            .flatten()
+            .filter(|module| module.is_import)
+            .max_by_key(|module| module.statement_span.end)
+            .map(|module| (module.statement_span.end, module.span.start))
+    })?;
+
+    let Some((statement_end, specifier_start)) = last_import else {
+        // keep a hashbang first, and the `<script>` tag on its own line
+        let start = if document.start == 0 {
+            hashbang_end(source_text)
+        } else {
+            line_break_end(document.source_text)
+        };
+        #[expect(clippy::cast_possible_truncation)]
+        let offset = document.start + start as u32;
+        return Some(ImportInsertion { offset, quote: '"', after_import: false });
+    };
+    let quote = document.source_text[specifier_start as usize..]
+        .chars()
+        .next()
+        .filter(|quote| *quote == '\'' || *quote == '"')
+        .unwrap_or('"');
+    Some(ImportInsertion { offset: document.start + statement_end, quote, after_import: true })
+}
+
+/// The name of the identifier in a `no-undef` message, e.g. `foo` in `'foo' is not defined.`
+fn undefined_name(diagnostic: &Diagnostic) -> Option<&str> {
+    let (name, _) = diagnostic.message.strip_prefix('\'')?.split_once("' is not defined.")?;
+    Some(name)
+}
+
+/// The specifiers with which `name` can be imported into the module at `path`, from the modules
+/// of the module graph exporting it. Every specifier is checked with the resolver.
+fn import_specifiers(runner: &LintRunner, path: &Path, name: &str) -> Vec<String> {
+    let module_graph = runner.module_graph();
+    let mut specifiers = vec![];
+    for exporter in module_graph.exporters(name) {
+        if exporter == path {
+            continue;
+        }
+        let resolves_to_exporter =
+            |specifier: &str| runner.resolve(path, specifier).is_some_and(|p| p == exporter);
+
+        // packages and path aliases, like the other modules import the exporter
+        let mut exporter_specifiers = module_graph
+            .specifiers(&exporter)
+            .into_iter()
+            .filter(|specifier| !is_relative(specifier) && resolves_to_exporter(specifier))
+            .map(|specifier| specifier.to_string())
+            .collect::<Vec<_>>();
+        if !exporter.components().any(|component| component.as_os_str() == "node_modules")
+            && let Some(specifier) = path
+                .parent()
+                .into_iter()
+                .flat_map(|dir| relative_specifiers(dir, &exporter))
+                .find(|specifier| resolves_to_exporter(specifier))
+        {
+            exporter_specifiers.push(specifier);
+        }
+        for specifier in exporter_specifiers {
+            if !specifiers.contains(&specifier) {
+                specifiers.push(specifier);
+            }
+        }
+    }
+    specifiers
+}
+
+fn is_relative(specifier: &str) -> bool {
+    specifier.starts_with('.') || specifier.starts_with('/')
+}
+
+/// The relative specifiers of the module at `to` from the directory `from`, from the shortest:
+/// the directory of an index file, the path without extension and the path with extension.
+fn relative_specifiers(from: &Path, to: &Path) -> Vec<String> {
+    let from = from.components().collect::<Vec<_>>();
+    let to = to.components().collect::<Vec<_>>();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    if common == 0 || to.len() == common {
+        return vec![];
+    }
+
+    let parents = from.len() - common;
+    let mut segments =
+        if parents == 0 { vec![".".to_string()] } else { vec!["..".to_string(); parents] };
+    for component in &to[common..] {
+        let Component::Normal(segment) = component else {
+            return vec![];
+        };
+        segments.push(segment.to_string_lossy().to_string());
+    }
+    let specifier = segments.join("/");
+
+    let mut specifiers = vec![];
+    if let Some(without_extension) =
+        OMITTED_EXTENSIONS.iter().find_map(|extension| specifier.strip_suffix(extension))
+    {
+        if let Some(directory) = without_extension.strip_suffix("/index") {
+            specifiers.push(directory.to_string());
+        }
+        specifiers.push(without_extension.to_string());
+    }
+    specifiers.push(specifier);
+    specifiers
+}
+
+/// The offset after the `#!` line, where imports can be inserted at the start of a file.
+fn hashbang_end(source_text: &str) -> usize {
+    if !source_text.starts_with("#!") {
+        return 0;
+    }
+    source_text.find('\n').map_or(source_text.len(), |end| end + 1)
+}
+
+/// The offset after the line break at the start of a `<script>` block, if any.
+fn line_break_end(source_text: &str) -> usize {
+    if source_text.starts_with("\r\n") { 2 } else { usize::from(source_text.starts_with('\n')) }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use tower_lsp_server::ls_types::Position;
+
+    use super::{hashbang_end, import_insertion, relative_specifiers};
+
+    #[test]
+    fn test_relative_specifiers() {
+        assert_eq!(
+            relative_specifiers(Path::new("/root/src"), Path::new("/root/src/utils.ts")),
+            vec!["./utils", "./utils.ts"]
+        );
+        assert_eq!(
+            relative_specifiers(Path::new("/root/src/deep"), Path::new("/root/lib/index.d.ts")),
+            vec!["../../lib", "../../lib/index", "../../lib/index.d.ts"]
+        );
+        assert_eq!(
+            relative_specifiers(Path::new("/root/src"), Path::new("/root/src/data.json")),
+            vec!["./data.json"]
+        );
+    }
+
+    #[test]
+    fn test_hashbang_end() {
+        assert_eq!(hashbang_end("foo();"), 0);
+        assert_eq!(hashbang_end("#!/usr/bin/env node\nfoo();"), 20);
+    }
+
+    #[test]
+    fn test_import_insertion() {
+        let insertion = |path: &str, source_text: &str, line: u32| {
+            import_insertion(Path::new(path), source_text, Position::new(line, 0))
+                .map(|insertion| (insertion.offset, insertion.quote, insertion.after_import))
+        };
+        assert_eq!(insertion("a.js", "import a from 'a';\nfoo;", 1), Some((18, '\'', true)));
+        assert_eq!(insertion("a.js", "#!/usr/bin/env node\nfoo;", 1), Some((20, '"', false)));
+
+        let source_text = "<template><div /></template>\n<script>\nfoo;\n</script>\n";
+        assert_eq!(insertion("a.vue", source_text, 2), Some((38, '"', false)));
+        assert_eq!(insertion("a.vue", source_text, 0), None);
+    }
+}
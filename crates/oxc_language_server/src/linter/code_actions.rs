@@ -1,11 +1,24 @@
 use log::debug;
-use tower_lsp_server::ls_types::{CodeAction, CodeActionKind, TextEdit, Uri, WorkspaceEdit};
+use oxc_data_structures::rope::Rope;
+use tower_lsp_server::ls_types::{CodeAction, CodeActionKind, Range, TextEdit, Uri, WorkspaceEdit};
 
-use crate::linter::error_with_position::{FixedContent, LinterCodeAction};
+use crate::linter::error_with_position::{FixedContent, LinterCodeAction, offset_to_position};
 
 pub const CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC: CodeActionKind =
     CodeActionKind::new("source.fixAll.oxc");
 
+pub const CODE_ACTION_KIND_SOURCE_ORGANIZE_IMPORTS_OXC: CodeActionKind =
+    CodeActionKind::new("source.organizeImports.oxc");
+
+/// Whether the client requested to organize the imports,
+/// either with `source.organizeImports.oxc` or the generic `source.organizeImports`.
+pub fn is_source_organize_imports(only_code_action_kinds: &[CodeActionKind]) -> bool {
+    only_code_action_kinds.iter().any(|kind| {
+        *kind == CODE_ACTION_KIND_SOURCE_ORGANIZE_IMPORTS_OXC
+            || *kind == CodeActionKind::SOURCE_ORGANIZE_IMPORTS
+    })
+}
+
 fn fix_content_to_code_action(
     fixed_content: FixedContent,
     uri: Uri,
@@ -33,10 +46,11 @@ fn fix_content_to_code_action(
 pub fn apply_fix_code_actions(action: LinterCodeAction, uri: &Uri) -> Vec<CodeAction> {
     let mut code_actions = vec![];
 
-    // only the first code action is preferred
+    // only the first code action is preferred, unless it is a suggestion
     let mut preferred = true;
     for fixed in action.fixed_content {
-        let action = fix_content_to_code_action(fixed, uri.clone(), preferred);
+        let is_preferred = preferred && !fixed.is_suggestion;
+        let action = fix_content_to_code_action(fixed, uri.clone(), is_preferred);
         preferred = false;
         code_actions.push(action);
     }
@@ -70,6 +84,51 @@ pub fn apply_all_fix_code_action(
     })
 }
 
+/// Replace the changed part of `source_text` with the organized imports in one text edit.
+pub fn organize_imports_code_action(uri: Uri, source_text: &str, organized: &str) -> CodeAction {
+    let prefix = source_text
+        .chars()
+        .zip(organized.chars())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum::<usize>();
+    let suffix = source_text[prefix..]
+        .chars()
+        .rev()
+        .zip(organized[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum::<usize>();
+
+    let rope = Rope::from_str(source_text);
+    #[expect(clippy::cast_possible_truncation)]
+    let range = Range::new(
+        offset_to_position(&rope, prefix as u32, source_text),
+        offset_to_position(&rope, (source_text.len() - suffix) as u32, source_text),
+    );
+
+    CodeAction {
+        title: "Organize imports".to_string(),
+        kind: Some(CODE_ACTION_KIND_SOURCE_ORGANIZE_IMPORTS_OXC),
+        is_preferred: Some(true),
+        edit: Some(WorkspaceEdit {
+            #[expect(clippy::disallowed_types)]
+            changes: Some(std::collections::HashMap::from([(
+                uri,
+                vec![TextEdit {
+                    range,
+                    new_text: organized[prefix..organized.len() - suffix].to_string(),
+                }],
+            )])),
+            ..WorkspaceEdit::default()
+        }),
+        disabled: None,
+        data: None,
+        diagnostics: None,
+        command: None,
+    }
+}
+
 /// Collect all text edits from the provided diagnostic reports, which can be applied at once.
 /// This is useful for implementing a "fix all" code action / command that applies multiple fixes in one go.
 pub fn fix_all_text_edit(actions: impl Iterator<Item = LinterCodeAction>) -> Vec<TextEdit> {
@@ -94,6 +153,10 @@ pub fn fix_all_text_edit(actions: impl Iterator<Item = LinterCodeAction>) -> Vec
         // For multiple fixes, we take the first one as a representative fix.
         // Applying all possible fixes at once is not possible in this context.
         let fixed_content = action.fixed_content.first().unwrap();
+        // Suggestions are never applied automatically.
+        if fixed_content.is_suggestion {
+            continue;
+        }
         // when source.fixAll.oxc we collect all changes at ones
         // and return them as one workspace edit.
         // it is possible that one fix will change the range for the next fix
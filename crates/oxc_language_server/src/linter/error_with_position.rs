@@ -7,7 +7,7 @@ use tower_lsp_server::ls_types::{
 
 use oxc_data_structures::rope::{Rope, get_line_column};
 use oxc_diagnostics::{OxcCode, Severity};
-use oxc_linter::{Fix, Message, PossibleFixes};
+use oxc_linter::{Fix, Message, PossibleFixes, Suggestion, line_ending};
 
 #[derive(Debug, Clone, Default)]
 pub struct DiagnosticReport {
@@ -26,6 +26,8 @@ pub struct FixedContent {
     pub message: String,
     pub code: String,
     pub range: Range,
+    /// Suggestions are only applied when picked by the user, never by "fix all".
+    pub is_suggestion: bool,
 }
 
 // clippy: the source field is checked and assumed to be less than 4GB, and
@@ -130,6 +132,13 @@ pub fn message_to_lsp_diagnostic(
         }
     }
 
+    fixed_content.extend(
+        message
+            .suggestions
+            .iter()
+            .map(|suggestion| suggestion_to_fixed_content(suggestion, rope, source_text)),
+    );
+
     // Add ignore fixes
     let error_offset = message.span.start;
     let section_offset = message.section_offset;
@@ -175,6 +184,23 @@ fn fix_to_fixed_content(fix: &Fix, rope: &Rope, source_text: &str) -> FixedConte
         message: fix.message.as_ref().map(std::string::ToString::to_string).unwrap_or_default(),
         code: fix.content.to_string(),
         range: Range::new(start_position, end_position),
+        is_suggestion: false,
+    }
+}
+
+fn suggestion_to_fixed_content(
+    suggestion: &Suggestion,
+    rope: &Rope,
+    source_text: &str,
+) -> FixedContent {
+    let start_position = offset_to_position(rope, suggestion.fix.span.start, source_text);
+    let end_position = offset_to_position(rope, suggestion.fix.span.end, source_text);
+
+    FixedContent {
+        message: suggestion.message.to_string(),
+        code: suggestion.fix.content.to_string(),
+        range: Range::new(start_position, end_position),
+        is_suggestion: true,
     }
 }
 
@@ -276,8 +302,9 @@ fn disable_for_this_line(
         line_break_offset = section_offset;
     }
 
+    let line_ending = line_ending(source_text);
     let (content_prefix, insert_offset) =
-        get_section_insert_position(section_offset, line_break_offset, bytes);
+        get_section_insert_position(section_offset, line_break_offset, bytes, line_ending);
 
     let whitespace_range = {
         let start = insert_offset as usize;
@@ -295,9 +322,10 @@ fn disable_for_this_line(
     FixedContent {
         message: format!("Disable {rule_name} for this line"),
         code: format!(
-            "{content_prefix}{whitespace_string}// oxlint-disable-next-line {rule_name}\n"
+            "{content_prefix}{whitespace_string}// oxlint-disable-next-line {rule_name}{line_ending}"
         ),
         range: Range::new(position, position),
+        is_suggestion: false,
     }
 }
 
@@ -307,10 +335,15 @@ fn disable_for_this_section(
     rope: &Rope,
     source_text: &str,
 ) -> FixedContent {
-    let comment = format!("// oxlint-disable {rule_name}\n");
+    let line_ending = line_ending(source_text);
+    let comment = format!("// oxlint-disable {rule_name}{line_ending}");
 
-    let (content_prefix, insert_offset) =
-        get_section_insert_position(section_offset, section_offset, source_text.as_bytes());
+    let (content_prefix, insert_offset) = get_section_insert_position(
+        section_offset,
+        section_offset,
+        source_text.as_bytes(),
+        line_ending,
+    );
 
     let content = format!("{content_prefix}{comment}");
     let position = offset_to_position(rope, insert_offset, source_text);
@@ -319,6 +352,7 @@ fn disable_for_this_section(
         message: format!("Disable {rule_name} for this whole file"),
         code: content,
         range: Range::new(position, position),
+        is_suggestion: false,
     }
 }
 
@@ -328,12 +362,13 @@ fn disable_for_this_section(
 /// For regular JS files (section_offset == 0), it returns the offset as-is.
 ///
 /// Returns (content_prefix, insert_offset) where:
-/// - content_prefix: "\n" if we need to add a line break, "" otherwise
+/// - content_prefix: `line_ending` if we need to add a line break, "" otherwise
 /// - insert_offset: the byte offset where the content should be inserted
 fn get_section_insert_position(
     section_offset: u32,
     target_offset: u32,
     bytes: &[u8],
+    line_ending: &'static str,
 ) -> (&'static str, u32) {
     if section_offset == 0 {
         // Regular JS files - insert at target offset
@@ -354,7 +389,7 @@ fn get_section_insert_position(
             }
             _ => {
                 // Not at line start, prepend newline
-                ("\n", section_offset)
+                (line_ending, section_offset)
             }
         }
     } else {
@@ -366,6 +401,9 @@ fn get_section_insert_position(
 #[cfg(test)]
 mod test {
     use oxc_data_structures::rope::Rope;
+    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_linter::{Fix, Message, PossibleFixes, Suggestion};
+    use oxc_span::Span;
 
     use super::offset_to_position;
 
@@ -426,13 +464,24 @@ mod test {
         assert_eq!(fix.range.start.character, 0);
     }
 
+    #[test]
+    fn disable_for_section_mid_line_crlf() {
+        let source = "<div />\r\n<script>debugger;</script>\r\n";
+        let rope = Rope::from_str(source);
+        let fix = super::disable_for_this_section("no-debugger", 17, &rope, source);
+
+        assert_eq!(fix.code, "\r\n// oxlint-disable no-debugger\r\n");
+        assert_eq!(fix.range.start.line, 1);
+        assert_eq!(fix.range.start.character, 8);
+    }
+
     #[test]
     fn disable_for_section_after_crlf() {
         let source = "<script>\r\nconsole.log('hello');";
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_section("no-console", 8, &rope, source);
 
-        assert_eq!(fix.code, "// oxlint-disable no-console\n");
+        assert_eq!(fix.code, "// oxlint-disable no-console\r\n");
         assert_eq!(fix.range.start.line, 1);
         assert_eq!(fix.range.start.character, 0);
     }
@@ -542,7 +591,7 @@ mod test {
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_line("no-console", 28, 0, &rope, source);
 
-        assert_eq!(fix.code, "\t// oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.code, "\t// oxlint-disable-next-line no-console\r\n");
         assert_eq!(fix.range.start.line, 1);
         assert_eq!(fix.range.start.character, 0);
     }
@@ -643,6 +692,33 @@ mod test {
         assert_eq!(fix.range.start.character, 0);
     }
 
+    #[test]
+    fn suggestions_after_fixes() {
+        let source = "var a;";
+        let rope = Rope::from_str(source);
+        let uri = "file:///test.js".parse().unwrap();
+        let message = Message::new(
+            OxcDiagnostic::warn("Unexpected var")
+                .with_error_code("eslint", "no-var")
+                .with_label(Span::new(0, 3)),
+            PossibleFixes::None,
+        )
+        .with_suggestions(vec![Suggestion::new(
+            "Replace with `let`",
+            Fix::new("let", Span::new(0, 3)),
+        )]);
+
+        let report = super::message_to_lsp_diagnostic(message, &uri, source, &rope);
+        let fixed_content = report.code_action.unwrap().fixed_content;
+
+        assert_eq!(fixed_content.len(), 3);
+        assert_eq!(fixed_content[0].message, "Replace with `let`");
+        assert_eq!(fixed_content[0].code, "let");
+        assert!(fixed_content[0].is_suggestion);
+        assert!(!fixed_content[1].is_suggestion);
+        assert!(!fixed_content[2].is_suggestion);
+    }
+
     fn assert_position(source: &str, offset: u32, expected: (u32, u32)) {
         let position = offset_to_position(&Rope::from_str(source), offset, source);
         assert_eq!(position.line, expected.0);
@@ -0,0 +1,153 @@
+use tower_lsp_server::ls_types::Position;
+#[cfg(feature = "ruledocs")]
+use tower_lsp_server::ls_types::{
+    Diagnostic, Hover, HoverContents, MarkupContent, MarkupKind, NumberOrString, Range,
+};
+
+#[cfg(feature = "ruledocs")]
+use oxc_linter::rule_docs::RuleDocs;
+
+#[cfg(feature = "ruledocs")]
+const DISABLE_DIRECTIVES: [&str; 4] =
+    ["eslint-disable", "eslint-enable", "oxlint-disable", "oxlint-enable"];
+
+/// Documentation of the rules reported by the diagnostics at the given position.
+#[cfg(feature = "ruledocs")]
+pub fn diagnostics_hover(diagnostics: &[Diagnostic], position: Position) -> Option<Hover> {
+    let diagnostics = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.range.start <= position && position <= diagnostic.range.end)
+        .collect::<Vec<_>>();
+
+    let mut codes = diagnostics
+        .iter()
+        .filter_map(|diagnostic| match &diagnostic.code {
+            Some(NumberOrString::String(code)) => Some(code.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    codes.dedup();
+
+    let docs =
+        codes.into_iter().filter_map(RuleDocs::from_code).map(|docs| docs.to_summary_markdown());
+    let value = docs.collect::<Vec<_>>().join("\n---\n\n");
+    if value.is_empty() {
+        return None;
+    }
+    Some(markdown_hover(value, diagnostics.first().map(|diagnostic| diagnostic.range)))
+}
+
+/// Documentation of the rule named inside a disable comment at the given position,
+/// e.g. `no-debugger` in `// eslint-disable-next-line no-debugger -- reason`.
+#[cfg(feature = "ruledocs")]
+pub fn disable_comment_hover(source_text: &str, position: Position) -> Option<Hover> {
+    let line = source_text.lines().nth(position.line as usize)?;
+    let (rule, start, end) = disable_comment_rule_at(line, utf16_to_byte_offset(line, position))?;
+    let docs = RuleDocs::find(rule)?;
+    let range = Range::new(
+        Position::new(position.line, byte_to_utf16_offset(line, start)),
+        Position::new(position.line, byte_to_utf16_offset(line, end)),
+    );
+    Some(markdown_hover(docs.to_summary_markdown(), Some(range)))
+}
+
+#[cfg(feature = "ruledocs")]
+fn markdown_hover(value: String, range: Option<Range>) -> Hover {
+    Hover {
+        contents: HoverContents::Markup(MarkupContent { kind: MarkupKind::Markdown, value }),
+        range,
+    }
+}
+
+/// Finds the rule name of a disable comment in `line` at the byte `offset`,
+/// returning the name with its byte range.
+#[cfg(feature = "ruledocs")]
+fn disable_comment_rule_at(line: &str, offset: usize) -> Option<(&str, usize, usize)> {
+    let comment_start = line.find("//").into_iter().chain(line.find("/*")).min()?;
+    let comment = &line[comment_start..];
+    let directive_start = DISABLE_DIRECTIVES
+        .iter()
+        .filter_map(|directive| comment.find(directive))
+        .min()
+        .map(|start| comment_start + start)?;
+
+    // skip the directive itself, e.g. `eslint-disable-next-line`
+    let rules_start = line[directive_start..]
+        .find(char::is_whitespace)
+        .map_or(line.len(), |end| directive_start + end);
+    let rules_end = ["--", "*/"]
+        .iter()
+        .filter_map(|end| line[rules_start..].find(end))
+        .min()
+        .map_or(line.len(), |end| rules_start + end);
+
+    let mut start = rules_start;
+    for rule in line[rules_start..rules_end].split(|c: char| c == ',' || c.is_whitespace()) {
+        let end = start + rule.len();
+        if !rule.is_empty() && start <= offset && offset <= end {
+            return Some((rule, start, end));
+        }
+        start = end + 1;
+    }
+    None
+}
+
+/// LSP positions count UTF-16 code units.
+pub fn utf16_to_byte_offset(line: &str, position: Position) -> usize {
+    let mut utf16_offset = 0;
+    for (byte_offset, c) in line.char_indices() {
+        if utf16_offset >= position.character as usize {
+            return byte_offset;
+        }
+        utf16_offset += c.len_utf16();
+    }
+    line.len()
+}
+
+#[cfg(feature = "ruledocs")]
+#[expect(clippy::cast_possible_truncation)]
+fn byte_to_utf16_offset(line: &str, offset: usize) -> u32 {
+    line[..offset].encode_utf16().count() as u32
+}
+
+#[cfg(all(test, feature = "ruledocs"))]
+mod test {
+    use tower_lsp_server::ls_types::{HoverContents, Position, Range};
+
+    use super::{disable_comment_hover, disable_comment_rule_at};
+
+    #[test]
+    fn test_disable_comment_rule_at() {
+        let line = "debugger; // eslint-disable-line no-debugger, eqeqeq -- reason";
+        let offset = |needle: &str| line.find(needle).unwrap();
+
+        assert_eq!(
+            disable_comment_rule_at(line, offset("no-debugger") + 3),
+            Some(("no-debugger", 33, 44))
+        );
+        assert_eq!(disable_comment_rule_at(line, offset("eqeqeq")), Some(("eqeqeq", 46, 52)));
+        assert_eq!(disable_comment_rule_at(line, offset("disable")), None);
+        assert_eq!(disable_comment_rule_at(line, offset("reason")), None);
+        assert_eq!(disable_comment_rule_at(line, offset("debugger;")), None);
+        assert_eq!(
+            disable_comment_rule_at("/* oxlint-disable typescript/no-explicit-any */", 20),
+            Some(("typescript/no-explicit-any", 18, 44))
+        );
+        assert_eq!(disable_comment_rule_at("const eslint-disable = 1", 22), None);
+    }
+
+    #[test]
+    fn test_disable_comment_hover() {
+        let source_text = "const a = 1;\n// 😀 eslint-disable-next-line no-debugger\ndebugger;\n";
+
+        let hover = disable_comment_hover(source_text, Position::new(1, 34)).unwrap();
+        assert_eq!(hover.range, Some(Range::new(Position::new(1, 31), Position::new(1, 42))));
+        let HoverContents::Markup(contents) = hover.contents else {
+            panic!("expected markdown hover contents");
+        };
+        assert!(contents.value.starts_with("# eslint/no-debugger\n"));
+
+        assert!(disable_comment_hover(source_text, Position::new(0, 3)).is_none());
+        assert!(disable_comment_hover(source_text, Position::new(5, 0)).is_none());
+    }
+}
@@ -6,18 +6,23 @@ use std::{
 use log::{debug, warn};
 use oxc_data_structures::rope::Rope;
 use rustc_hash::{FxHashMap, FxHashSet};
-use tower_lsp_server::ls_types::Uri;
+use tower_lsp_server::ls_types::{CodeAction, Diagnostic, Location, Position, Uri};
 
 use oxc_allocator::Allocator;
 use oxc_linter::{
-    AllowWarnDeny, ConfigStore, DisableDirectives, Fix, FixKind, LINTABLE_EXTENSIONS, LintOptions,
-    LintRunner, LintRunnerBuilder, LintServiceOptions, Linter, Message, PossibleFixes,
-    RuleCommentType, RuntimeFileSystem, read_to_arena_str, read_to_string,
+    AllowWarnDeny, ConfigStore, FixKind, Fixer, LINTABLE_EXTENSIONS, LintOptions, LintRunner,
+    LintRunnerBuilder, LintServiceOptions, Linter, Message, PossibleFixes, RuntimeFileSystem,
+    create_unused_directives_messages, read_to_arena_str, read_to_string,
 };
+use oxc_parser::Parser;
+use oxc_span::Span;
 
+use super::auto_import::auto_import_code_actions;
 use super::error_with_position::{
     DiagnosticReport, generate_inverted_diagnostics, message_to_lsp_diagnostic,
 };
+use super::navigation::{definition, references};
+use super::virtual_document::VirtualDocument;
 
 /// smaller subset of LintServiceOptions, which is used by IsolatedLintHandler
 #[derive(Debug, Clone)]
@@ -29,8 +34,18 @@ pub struct IsolatedLintHandlerOptions {
     pub tsconfig_path: Option<PathBuf>,
 }
 
+/// The rules whose fixes organize the imports of a file.
+const ORGANIZE_IMPORTS_RULES: [&str; 2] =
+    ["eslint-plugin-import(order)", "eslint-plugin-import(no-duplicates)"];
+
+/// Merging an import can change the order of the others, so the fixes are applied in passes
+/// until nothing changes anymore.
+const ORGANIZE_IMPORTS_MAX_PASSES: usize = 10;
+
 pub struct IsolatedLintHandler {
     runner: LintRunner,
+    /// Runner which reports the safe fixes, used to organize the imports.
+    organize_imports_runner: LintRunner,
     unused_directives_severity: Option<AllowWarnDeny>,
 }
 
@@ -69,6 +84,11 @@ impl IsolatedLintHandler {
         options: &IsolatedLintHandlerOptions,
     ) -> Self {
         let config_store_clone = config_store.clone();
+        let organize_imports_linter = Linter::new(
+            LintOptions { fix: FixKind::SafeFix, ..LintOptions::default() },
+            config_store.clone(),
+            None,
+        );
 
         let linter = Linter::new(lint_options, config_store, None);
         let mut lint_service_options = LintServiceOptions::new(options.root_path.clone())
@@ -81,6 +101,13 @@ impl IsolatedLintHandler {
             lint_service_options = lint_service_options.with_tsconfig(tsconfig_path);
         }
 
+        let organize_imports_runner =
+            LintRunnerBuilder::new(lint_service_options.clone(), organize_imports_linter)
+                .with_type_aware(false)
+                .with_fix_kind(FixKind::SafeFix)
+                .build()
+                .expect("Failed to build LintRunner without type-aware linting");
+
         let runner = match LintRunnerBuilder::new(lint_service_options.clone(), linter)
             .with_type_aware(options.type_aware)
             .with_fix_kind(options.fix_kind)
@@ -98,7 +125,11 @@ impl IsolatedLintHandler {
             }
         };
 
-        Self { runner, unused_directives_severity: lint_options.report_unused_directive }
+        Self {
+            runner,
+            organize_imports_runner,
+            unused_directives_severity: lint_options.report_unused_directive,
+        }
     }
 
     pub fn run_single(&self, uri: &Uri, content: Option<&str>) -> Option<Vec<DiagnosticReport>> {
@@ -116,6 +147,73 @@ impl IsolatedLintHandler {
         Some(diagnostics)
     }
 
+    /// Sort and merge the imports of the file, by applying the safe fixes of `import/order` and
+    /// `import/no-duplicates` which only change import declarations, like `oxlint --fix`.
+    /// Only the rules enabled in the configuration are applied.
+    /// Returns [`None`] when the file is not lintable or the imports are already organized.
+    pub fn organize_imports(&self, uri: &Uri, source_text: &str) -> Option<String> {
+        let path = uri.to_file_path()?;
+
+        if !Self::should_lint_path(&path) {
+            return None;
+        }
+
+        let mut fixed_code = source_text.to_string();
+        for _ in 0..ORGANIZE_IMPORTS_MAX_PASSES {
+            let import_spans = import_declaration_spans(&path, &fixed_code);
+            if import_spans.is_empty() {
+                break;
+            }
+
+            let mut fs = IsolatedLintHandlerFileSystem::default();
+            fs.add_file(path.to_path_buf(), Arc::from(fixed_code.as_str()));
+
+            let messages = self
+                .organize_imports_runner
+                .run_source(&[Arc::from(path.as_os_str())], &fs)
+                .into_iter()
+                .filter(|message| is_organize_imports_message(message, &import_spans))
+                .collect::<Vec<_>>();
+
+            let fix_result = Fixer::new(&fixed_code, messages, None).fix();
+            if !fix_result.fixed {
+                break;
+            }
+            fixed_code = fix_result.fixed_code.into_owned();
+        }
+
+        (fixed_code != source_text).then_some(fixed_code)
+    }
+
+    /// Code actions importing the identifiers reported by `no-undef` from the modules exporting
+    /// them. Only available with cross-module linting, which builds the module graph.
+    pub fn auto_import_code_actions(
+        &self,
+        uri: &Uri,
+        source_text: &str,
+        diagnostics: &[&Diagnostic],
+    ) -> Vec<CodeAction> {
+        auto_import_code_actions(&self.runner, uri, source_text, diagnostics)
+    }
+
+    /// The declaration of the symbol at the position. Imports are only followed to other modules
+    /// with cross-module linting, which enables the resolver.
+    pub fn definition(&self, uri: &Uri, source_text: &str, position: Position) -> Option<Location> {
+        definition(&self.runner, uri, source_text, position)
+    }
+
+    /// The references of the symbol at the position. References in other modules are only found
+    /// with cross-module linting, which builds the module graph.
+    pub fn references(
+        &self,
+        uri: &Uri,
+        source_text: &str,
+        position: Position,
+        include_declaration: bool,
+    ) -> Option<Vec<Location>> {
+        references(&self.runner, uri, source_text, position, include_declaration)
+    }
+
     fn lint_path(&self, path: &Path, uri: &Uri, source_text: &str) -> Vec<DiagnosticReport> {
         debug!("lint {}", path.display());
         let rope = &Rope::from_str(source_text);
@@ -135,7 +233,7 @@ impl IsolatedLintHandler {
             && let Some(directives) = self.runner.directives_coordinator().get(path)
         {
             messages.extend(
-                create_unused_directives_messages(&directives, severity, source_text)
+                create_unused_directives_messages(&directives, severity, Some(source_text))
                     .into_iter()
                     .map(|message| message_to_lsp_diagnostic(message, uri, source_text, rope)),
             );
@@ -144,7 +242,7 @@ impl IsolatedLintHandler {
         messages
     }
 
-    fn should_lint_path(path: &Path) -> bool {
+    pub fn should_lint_path(path: &Path) -> bool {
         static WANTED_EXTENSIONS: OnceLock<FxHashSet<&'static str>> = OnceLock::new();
         let wanted_exts =
             WANTED_EXTENSIONS.get_or_init(|| LINTABLE_EXTENSIONS.iter().copied().collect());
@@ -155,76 +253,41 @@ impl IsolatedLintHandler {
     }
 }
 
-/// Almost the same as [oxc_linter::create_unused_directives_diagnostics], but returns `Message`s
-/// with a `PossibleFixes` instead of `OxcDiagnostic`s.
-fn create_unused_directives_messages(
-    directives: &DisableDirectives,
-    severity: AllowWarnDeny,
-    source_text: &str,
-) -> Vec<Message> {
-    use oxc_diagnostics::OxcDiagnostic;
-
-    let mut diagnostics = Vec::new();
-    let fix_message = "remove unused disable directive";
-
-    let severity = if severity == AllowWarnDeny::Deny {
-        oxc_diagnostics::Severity::Error
-    } else {
-        oxc_diagnostics::Severity::Warning
-    };
-
-    // Report unused disable comments
-    let unused_disable = directives.collect_unused_disable_comments();
-    for unused_comment in unused_disable {
-        let span = unused_comment.span;
-        match unused_comment.r#type {
-            RuleCommentType::All => {
-                diagnostics.push(Message::new(
-                    OxcDiagnostic::warn(
-                        "Unused eslint-disable directive (no problems were reported).",
-                    )
-                    .with_label(span)
-                    .with_severity(severity),
-                    PossibleFixes::Single(Fix::delete(span).with_message(fix_message)),
-                ));
-            }
-            RuleCommentType::Single(rules) => {
-                for rule in rules {
-                    let rule_message = format!(
-                        "Unused eslint-disable directive (no problems were reported from {}).",
-                        rule.rule_name
-                    );
-                    diagnostics.push(Message::new(
-                        OxcDiagnostic::warn(rule_message)
-                            .with_label(rule.name_span)
-                            .with_severity(severity),
-                        PossibleFixes::Single(
-                            rule.create_fix(source_text, span).with_message(fix_message),
-                        ),
-                    ));
-                }
-            }
-        }
+/// Whether `message` is reported by one of [`ORGANIZE_IMPORTS_RULES`] on an import declaration,
+/// with fixes which are not dangerous and only change import declarations.
+fn is_organize_imports_message(message: &Message, import_spans: &[Span]) -> bool {
+    if !ORGANIZE_IMPORTS_RULES.contains(&message.error.code.to_string().as_str())
+        || !import_spans.iter().any(|import| import.contains_inclusive(message.span))
+    {
+        return false;
     }
+    let fixes = match &message.fixes {
+        PossibleFixes::None => return false,
+        PossibleFixes::Single(fix) => std::slice::from_ref(fix),
+        PossibleFixes::Multiple(fixes) => fixes.as_slice(),
+    };
+    fixes.iter().all(|fix| {
+        !fix.kind.is_dangerous()
+            && import_spans
+                .iter()
+                .any(|import| fix.span.start <= import.end && import.start <= fix.span.end)
+    })
+}
 
-    // Report unused enable comments
-    let unused_enable = directives.unused_enable_comments();
-    for (rule_name, span) in unused_enable {
-        let message = if let Some(rule_name) = rule_name {
-            format!(
-                "Unused eslint-enable directive (no matching eslint-disable directives were found for {rule_name})."
-            )
-        } else {
-            "Unused eslint-enable directive (no matching eslint-disable directives were found)."
-                .to_string()
-        };
-        diagnostics.push(Message::new(
-            OxcDiagnostic::warn(message).with_label(*span).with_severity(severity),
-            // TODO: fixer
-            // copy the structure of disable directives
-            PossibleFixes::None,
-        ));
+/// The spans in the file of the import declarations of the documents of the file.
+fn import_declaration_spans(path: &Path, source_text: &str) -> Vec<Span> {
+    let mut spans = vec![];
+    for document in VirtualDocument::from_file(path, source_text) {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, document.source_text, document.source_type).parse();
+        spans.extend(
+            ret.module_record
+                .requested_modules
+                .values()
+                .flatten()
+                .filter(|module| module.is_import)
+                .map(|module| document.to_file_span(module.statement_span)),
+        );
     }
-
-    diagnostics
+    spans
 }
@@ -1,12 +1,17 @@
+mod auto_import;
 mod code_actions;
 mod commands;
 mod config_walker;
 mod error_with_position;
+mod hover;
 mod isolated_lint_handler;
+mod navigation;
 mod options;
+mod rename;
 mod server_linter;
 #[cfg(test)]
 mod tester;
+mod virtual_document;
 
 pub use server_linter::ServerLinterBuilder;
 
@@ -0,0 +1,423 @@
+use std::path::{Path, PathBuf};
+
+use oxc_allocator::Allocator;
+use oxc_data_structures::rope::Rope;
+use oxc_linter::LintRunner;
+use oxc_parser::Parser;
+use oxc_semantic::{Semantic, SemanticBuilder, SymbolId};
+use oxc_span::Span;
+use oxc_syntax::module_record::{
+    ExportExportName, ExportImportName, ExportLocalName, ImportImportName, ModuleRecord,
+};
+use rustc_hash::FxHashSet;
+use tower_lsp_server::ls_types::{Location, Position, Range, Uri};
+
+use crate::linter::{
+    error_with_position::offset_to_position, hover::utf16_to_byte_offset,
+    virtual_document::VirtualDocument,
+};
+
+/// How many re-exports are followed to find the declaration of an imported symbol.
+const MAX_REEXPORT_DEPTH: usize = 10;
+
+/// A span in a file, relative to the start of the file.
+struct Target {
+    path: PathBuf,
+    span: Span,
+}
+
+/// Where an exported name is declared in a module.
+enum Export {
+    /// Declared in the module itself, with the span relative to its document.
+    Local(Span),
+    /// Re-exported from other modules, with the imported name, or `None` for a namespace.
+    Reexports(Vec<(String, Option<String>)>),
+}
+
+/// The declaration of the symbol at the given position. Imported symbols are followed to the
+/// module declaring them, which is only possible with cross-module linting.
+pub fn definition(
+    runner: &LintRunner,
+    uri: &Uri,
+    source_text: &str,
+    position: Position,
+) -> Option<Location> {
+    let path = uri.to_file_path()?;
+    let target = declaration_at(runner, &path, source_text, position)?;
+
+    if target.path == path {
+        return location(&target.path, source_text, target.span);
+    }
+    location(&target.path, &std::fs::read_to_string(&target.path).ok()?, target.span)
+}
+
+/// The references of the symbol at the given position, in its file and in the modules importing
+/// it, directly or through re-exports. The importers are found in the module graph, which is only
+/// built with cross-module linting.
+pub fn references(
+    runner: &LintRunner,
+    uri: &Uri,
+    source_text: &str,
+    position: Position,
+    include_declaration: bool,
+) -> Option<Vec<Location>> {
+    let path = uri.to_file_path()?;
+    let target = declaration_at(runner, &path, source_text, position)?;
+    let read_source_text = |file: &Path| {
+        if file == path {
+            Some(source_text.to_string())
+        } else {
+            std::fs::read_to_string(file).ok()
+        }
+    };
+
+    let target_source_text = read_source_text(&target.path)?;
+    let document =
+        VirtualDocument::at_offset(&target.path, &target_source_text, target.span.start)?;
+    let target_span = document.to_document_span(target.span)?;
+    let (mut spans, export_names) = with_semantic(&document, |semantic, module_record| {
+        let scoping = semantic.scoping();
+        let symbol_id = scoping
+            .symbol_ids()
+            .find(|&symbol_id| scoping.symbol_span(symbol_id) == target_span)?;
+        let spans = symbol_reference_spans(semantic, symbol_id)
+            .into_iter()
+            .map(|span| document.to_file_span(span))
+            .collect::<Vec<_>>();
+        let export_names = if scoping.symbol_scope_id(symbol_id) == scoping.root_scope_id() {
+            local_export_names(module_record, scoping.symbol_name(symbol_id))
+        } else {
+            vec![]
+        };
+        Some((spans, export_names))
+    })??;
+    if include_declaration {
+        spans.insert(0, target.span);
+    }
+    let mut locations = spans
+        .into_iter()
+        .filter_map(|span| location(&target.path, &target_source_text, span))
+        .collect::<Vec<_>>();
+    if export_names.is_empty() {
+        return Some(locations);
+    }
+
+    // the modules re-exporting the symbol are searched for importers too
+    let mut modules = vec![(target.path, export_names)];
+    let mut visited = FxHashSet::default();
+    while let Some((module, export_names)) = modules.pop() {
+        if !visited.insert(module.clone()) {
+            continue;
+        }
+        for importer in runner.module_graph().importers(&module) {
+            let Some(importer_source_text) = read_source_text(&importer) else {
+                continue;
+            };
+            let mut spans = vec![];
+            let mut reexported_names = vec![];
+            for document in VirtualDocument::from_file(&importer, &importer_source_text) {
+                let Some((document_spans, document_reexported_names)) =
+                    with_semantic(&document, |semantic, module_record| {
+                        importer_references(
+                            runner,
+                            &importer,
+                            semantic,
+                            module_record,
+                            &module,
+                            &export_names,
+                        )
+                    })
+                else {
+                    continue;
+                };
+                spans.extend(document_spans.into_iter().map(|span| document.to_file_span(span)));
+                reexported_names.extend(document_reexported_names);
+            }
+            locations.extend(
+                spans
+                    .into_iter()
+                    .filter_map(|span| location(&importer, &importer_source_text, span)),
+            );
+            if !reexported_names.is_empty() {
+                modules.push((importer, reexported_names));
+            }
+        }
+    }
+    Some(locations)
+}
+
+/// The references in `importer` of the exports `export_names` of `module`,
+/// and the names with which `importer` re-exports them.
+fn importer_references(
+    runner: &LintRunner,
+    importer: &Path,
+    semantic: &Semantic<'_>,
+    module_record: &ModuleRecord<'_>,
+    module: &Path,
+    export_names: &[String],
+) -> (Vec<Span>, Vec<String>) {
+    let is_module =
+        |specifier: &str| runner.resolve(importer, specifier).as_deref() == Some(module);
+    let is_exported = |name: &str| export_names.iter().any(|export_name| export_name == name);
+
+    let mut spans = vec![];
+    for entry in &module_record.import_entries {
+        let imported_name = match &entry.import_name {
+            ImportImportName::Name(name) => name.name.as_str(),
+            ImportImportName::Default(_) => "default",
+            ImportImportName::NamespaceObject => continue,
+        };
+        if !is_exported(imported_name) || !is_module(&entry.module_request.name) {
+            continue;
+        }
+        spans.push(entry.local_name.span);
+        if let Some(symbol_id) = semantic.scoping().get_root_binding(&entry.local_name.name) {
+            spans.extend(symbol_reference_spans(semantic, symbol_id));
+        }
+    }
+
+    let mut reexported_names = vec![];
+    for entry in &module_record.indirect_export_entries {
+        let (Some(request), ExportImportName::Name(imported_name)) =
+            (&entry.module_request, &entry.import_name)
+        else {
+            continue;
+        };
+        if !is_exported(&imported_name.name) || !is_module(&request.name) {
+            continue;
+        }
+        spans.push(imported_name.span);
+        match &entry.export_name {
+            ExportExportName::Name(name) => reexported_names.push(name.name.to_string()),
+            ExportExportName::Default(_) => reexported_names.push("default".to_string()),
+            ExportExportName::Null => {}
+        }
+    }
+    // `export * from` re-exports the names as they are, except for the default export
+    if module_record
+        .star_export_entries
+        .iter()
+        .filter(|entry| entry.export_name.is_null())
+        .filter_map(|entry| entry.module_request.as_ref())
+        .any(|request| is_module(&request.name))
+    {
+        reexported_names.extend(export_names.iter().filter(|name| *name != "default").cloned());
+    }
+    (spans, reexported_names)
+}
+
+/// The byte offset of an LSP position, which counts UTF-16 code units.
+pub fn position_to_offset(source_text: &str, position: Position) -> Option<u32> {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        line_start += source_text[line_start..].find('\n')? + 1;
+    }
+    let line = source_text[line_start..].lines().next().unwrap_or_default();
+    u32::try_from(line_start + utf16_to_byte_offset(line, position)).ok()
+}
+
+/// The symbol declared or referenced at the byte offset.
+pub fn symbol_at_offset(semantic: &Semantic<'_>, offset: u32) -> Option<SymbolId> {
+    let scoping = semantic.scoping();
+    scoping.symbol_ids().find(|&symbol_id| {
+        let redeclarations =
+            scoping.symbol_redeclarations(symbol_id).iter().map(|redeclaration| redeclaration.span);
+        std::iter::once(scoping.symbol_span(symbol_id))
+            .chain(redeclarations)
+            .chain(symbol_reference_spans(semantic, symbol_id))
+            .any(|span| span.start <= offset && offset <= span.end)
+    })
+}
+
+/// Parse a JavaScript or TypeScript document and run `f` with its semantic model and module
+/// record, whose spans are relative to the document.
+/// Returns `None` when the document could not be parsed.
+pub fn with_semantic<T>(
+    document: &VirtualDocument<'_>,
+    f: impl FnOnce(&Semantic<'_>, &ModuleRecord<'_>) -> T,
+) -> Option<T> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, document.source_text, document.source_type).parse();
+    if ret.panicked {
+        return None;
+    }
+    let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+    Some(f(&semantic, &ret.module_record))
+}
+
+fn symbol_reference_spans(semantic: &Semantic<'_>, symbol_id: SymbolId) -> Vec<Span> {
+    semantic
+        .symbol_references(symbol_id)
+        .map(|reference| semantic.reference_span(reference))
+        .collect()
+}
+
+/// The declaration of the symbol at the position of the file at `path`.
+fn declaration_at(
+    runner: &LintRunner,
+    path: &Path,
+    source_text: &str,
+    position: Position,
+) -> Option<Target> {
+    let offset = position_to_offset(source_text, position)?;
+    let document = VirtualDocument::at_offset(path, source_text, offset)?;
+    let offset = document.to_document_offset(offset)?;
+    with_semantic(&document, |semantic, module_record| {
+        let symbol_id = symbol_at_offset(semantic, offset)?;
+        Some(declaration(runner, path, &document, semantic, module_record, symbol_id))
+    })?
+}
+
+/// The declaration of a symbol of `document`, following imports to the modules exporting them.
+fn declaration(
+    runner: &LintRunner,
+    path: &Path,
+    document: &VirtualDocument<'_>,
+    semantic: &Semantic<'_>,
+    module_record: &ModuleRecord<'_>,
+    symbol_id: SymbolId,
+) -> Target {
+    let span = semantic.scoping().symbol_span(symbol_id);
+    let local = Target { path: path.to_path_buf(), span: document.to_file_span(span) };
+    if !semantic.scoping().symbol_flags(symbol_id).is_import() {
+        return local;
+    }
+    let Some(entry) =
+        module_record.import_entries.iter().find(|entry| entry.local_name.span == span)
+    else {
+        return local;
+    };
+    let Some(module) = runner.resolve(path, &entry.module_request.name) else {
+        return local;
+    };
+    let name = match &entry.import_name {
+        ImportImportName::Name(name) => name.name.as_str(),
+        ImportImportName::Default(_) => "default",
+        ImportImportName::NamespaceObject => return Target { path: module, span: Span::default() },
+    };
+    find_export(runner, module, name, MAX_REEXPORT_DEPTH).unwrap_or(local)
+}
+
+/// The declaration of the export `name` of the module at `path`.
+fn find_export(runner: &LintRunner, path: PathBuf, name: &str, depth: usize) -> Option<Target> {
+    let source_text = std::fs::read_to_string(&path).ok()?;
+    let mut reexports = vec![];
+    for document in VirtualDocument::from_file(&path, &source_text) {
+        match find_document_export(&document, name) {
+            Some(Export::Local(span)) => {
+                return Some(Target { path, span: document.to_file_span(span) });
+            }
+            Some(Export::Reexports(document_reexports)) => reexports.extend(document_reexports),
+            None => {}
+        }
+    }
+
+    if depth == 0 {
+        return None;
+    }
+    reexports.into_iter().find_map(|(specifier, imported_name)| {
+        let module = runner.resolve(&path, &specifier)?;
+        match imported_name {
+            Some(imported_name) => find_export(runner, module, &imported_name, depth - 1),
+            None => Some(Target { path: module, span: Span::default() }),
+        }
+    })
+}
+
+/// Where the export `name` of a document is declared.
+fn find_document_export(document: &VirtualDocument<'_>, name: &str) -> Option<Export> {
+    with_semantic(document, |semantic, module_record| {
+        let is_name = |export_name: &ExportExportName| match export_name {
+            ExportExportName::Name(export_name) => export_name.name == name,
+            ExportExportName::Default(_) => name == "default",
+            ExportExportName::Null => false,
+        };
+
+        if let Some(entry) =
+            module_record.local_export_entries.iter().find(|entry| is_name(&entry.export_name))
+        {
+            let local_name = match &entry.local_name {
+                ExportLocalName::Name(local_name) | ExportLocalName::Default(local_name) => {
+                    Some(local_name.name.as_str())
+                }
+                ExportLocalName::Null => None,
+            };
+            let scoping = semantic.scoping();
+            let span = local_name
+                .and_then(|local_name| scoping.get_root_binding(local_name))
+                .map_or(entry.span, |symbol_id| scoping.symbol_span(symbol_id));
+            return Export::Local(span);
+        }
+
+        if let Some(entry) =
+            module_record.indirect_export_entries.iter().find(|entry| is_name(&entry.export_name))
+        {
+            let imported_name = match &entry.import_name {
+                ExportImportName::Name(imported_name) => Some(imported_name.name.to_string()),
+                _ => None,
+            };
+            return Export::Reexports(
+                entry
+                    .module_request
+                    .iter()
+                    .map(|request| (request.name.to_string(), imported_name.clone()))
+                    .collect(),
+            );
+        }
+
+        // `export *` does not re-export the default export
+        if name == "default" {
+            return Export::Reexports(vec![]);
+        }
+        Export::Reexports(
+            module_record
+                .star_export_entries
+                .iter()
+                .filter_map(|entry| entry.module_request.as_ref())
+                .map(|request| (request.name.to_string(), Some(name.to_string())))
+                .collect(),
+        )
+    })
+}
+
+/// The names with which the local binding `local_name` is exported.
+fn local_export_names(module_record: &ModuleRecord<'_>, local_name: &str) -> Vec<String> {
+    module_record
+        .local_export_entries
+        .iter()
+        .filter(|entry| match &entry.local_name {
+            ExportLocalName::Name(name) | ExportLocalName::Default(name) => name.name == local_name,
+            ExportLocalName::Null => false,
+        })
+        .filter_map(|entry| match &entry.export_name {
+            ExportExportName::Name(name) => Some(name.name.to_string()),
+            ExportExportName::Default(_) => Some("default".to_string()),
+            ExportExportName::Null => None,
+        })
+        .collect()
+}
+
+fn location(path: &Path, source_text: &str, span: Span) -> Option<Location> {
+    let rope = Rope::from_str(source_text);
+    let range = Range::new(
+        offset_to_position(&rope, span.start, source_text),
+        offset_to_position(&rope, span.end, source_text),
+    );
+    Some(Location::new(Uri::from_file_path(path)?, range))
+}
+
+#[cfg(test)]
+mod test {
+    use tower_lsp_server::ls_types::Position;
+
+    use super::position_to_offset;
+
+    #[test]
+    fn test_position_to_offset() {
+        let source_text = "let a;\r\nlet 😀 = b;\nc";
+        assert_eq!(position_to_offset(source_text, Position::new(0, 4)), Some(4));
+        assert_eq!(position_to_offset(source_text, Position::new(1, 9)), Some(19));
+        assert_eq!(position_to_offset(source_text, Position::new(2, 0)), Some(22));
+        assert_eq!(position_to_offset(source_text, Position::new(3, 0)), None);
+    }
+}
@@ -0,0 +1,60 @@
+use oxc_data_structures::rope::Rope;
+use tower_lsp_server::ls_types::{Position, Range, TextEdit, Uri, WorkspaceEdit};
+
+use crate::linter::{
+    error_with_position::offset_to_position,
+    navigation::{position_to_offset, symbol_at_offset, with_semantic},
+    virtual_document::VirtualDocument,
+};
+
+/// Rename the symbol at the given position of a JavaScript or TypeScript file, or of a script
+/// block of a `.vue`, `.mpx`, `.svelte` or `.astro` file.
+/// Returns `Ok(None)` when there is no symbol at the position.
+///
+/// # Errors
+///
+/// When `new_name` is not a valid identifier, or would conflict with another binding.
+pub fn rename(
+    uri: &Uri,
+    source_text: &str,
+    position: Position,
+    new_name: &str,
+) -> Result<Option<WorkspaceEdit>, String> {
+    let (Some(path), Some(offset)) =
+        (uri.to_file_path(), position_to_offset(source_text, position))
+    else {
+        return Ok(None);
+    };
+    let Some(document) = VirtualDocument::at_offset(&path, source_text, offset) else {
+        return Ok(None);
+    };
+    let offset = offset - document.start;
+    let edits = with_semantic(&document, |semantic, _| {
+        symbol_at_offset(semantic, offset).map(|symbol_id| semantic.rename(symbol_id, new_name))
+    });
+    let Some(Some(edits)) = edits else {
+        return Ok(None);
+    };
+    let edits = edits.map_err(|error| error.to_string())?;
+
+    let rope = Rope::from_str(source_text);
+    let edits = edits
+        .into_iter()
+        .map(|edit| {
+            let span = document.to_file_span(edit.span);
+            TextEdit {
+                range: Range::new(
+                    offset_to_position(&rope, span.start, source_text),
+                    offset_to_position(&rope, span.end, source_text),
+                ),
+                new_text: edit.new_text,
+            }
+        })
+        .collect();
+
+    Ok(Some(WorkspaceEdit {
+        #[expect(clippy::disallowed_types)]
+        changes: Some(std::collections::HashMap::from([(uri.clone(), edits)])),
+        ..WorkspaceEdit::default()
+    }))
+}
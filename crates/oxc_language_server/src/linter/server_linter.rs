@@ -8,35 +8,44 @@ use tower_lsp_server::ls_types::{DiagnosticOptions, DiagnosticServerCapabilities
 use tower_lsp_server::{
     jsonrpc::ErrorCode,
     ls_types::{
-        CodeActionKind, CodeActionOptions, CodeActionOrCommand, CodeActionProviderCapability,
-        Diagnostic, ExecuteCommandOptions, Pattern, Range, ServerCapabilities, Uri,
+        CodeAction, CodeActionKind, CodeActionOptions, CodeActionOrCommand,
+        CodeActionProviderCapability, Diagnostic, ExecuteCommandOptions, FileChangeType, FileEvent,
+        GotoDefinitionResponse, Location, OneOf, Pattern, Position, Range, ServerCapabilities, Uri,
         WorkDoneProgressOptions, WorkspaceEdit,
     },
 };
 
 use oxc_linter::{
-    AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, ExternalPluginStore, FixKind,
-    LintIgnoreMatcher, LintOptions, Oxlintrc,
+    AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, ExternalPluginStore, FileChange,
+    FileChangeKind, FixKind, LintIgnoreMatcher, LintOptions, Oxlintrc, WatchedFileKind,
+    coalesce_file_changes,
 };
 
+#[cfg(feature = "ruledocs")]
+use crate::linter::hover::{diagnostics_hover, disable_comment_hover};
 use crate::{
     ConcurrentHashMap,
     capabilities::Capabilities,
     linter::{
         LINT_CONFIG_FILE,
+        auto_import::is_no_undef_diagnostic,
         code_actions::{
-            CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC, apply_all_fix_code_action, apply_fix_code_actions,
-            fix_all_text_edit,
+            CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC, CODE_ACTION_KIND_SOURCE_ORGANIZE_IMPORTS_OXC,
+            apply_all_fix_code_action, apply_fix_code_actions, fix_all_text_edit,
+            is_source_organize_imports, organize_imports_code_action,
         },
         commands::{FIX_ALL_COMMAND_ID, FixAllCommandArgs},
         config_walker::ConfigWalker,
         error_with_position::LinterCodeAction,
         isolated_lint_handler::{IsolatedLintHandler, IsolatedLintHandlerOptions},
         options::{LintOptions as LSPLintOptions, Run, UnusedDisableDirectives},
+        rename::rename,
     },
     tool::{DiagnosticResult, Tool, ToolBuilder, ToolRestartChanges},
     utils::normalize_path,
 };
+#[cfg(feature = "ruledocs")]
+use tower_lsp_server::ls_types::{Hover, HoverProviderCapability};
 
 pub struct ServerLinterBuilder;
 
@@ -86,7 +95,7 @@ impl ServerLinterBuilder {
 
         // TODO(refactor): pull this into a shared function, because in oxlint we have the same functionality.
         let use_nested_config = options.use_nested_configs();
-        let fix_kind = FixKind::from(options.fix_kind);
+        let fix_kind = FixKind::from(options.fix_kind.clone());
 
         let use_cross_module = config_builder.plugins().has_import()
             || (use_nested_config
@@ -137,7 +146,8 @@ impl ServerLinterBuilder {
         );
 
         ServerLinter::new(
-            options.run,
+            &options,
+            use_cross_module,
             root_path.to_path_buf(),
             isolated_linter,
             LintIgnoreMatcher::new(&base_patterns, &root_path, nested_ignore_patterns),
@@ -168,6 +178,9 @@ impl ToolBuilder for ServerLinterBuilder {
         if !code_action_kinds.contains(&CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC) {
             code_action_kinds.push(CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC);
         }
+        if !code_action_kinds.contains(&CODE_ACTION_KIND_SOURCE_ORGANIZE_IMPORTS_OXC) {
+            code_action_kinds.push(CODE_ACTION_KIND_SOURCE_ORGANIZE_IMPORTS_OXC);
+        }
 
         // override code action kinds if the code action provider is already set
         capabilities.code_action_provider =
@@ -210,10 +223,21 @@ impl ToolBuilder for ServerLinterBuilder {
             },
         });
 
+        #[cfg(feature = "ruledocs")]
+        {
+            capabilities.hover_provider = Some(HoverProviderCapability::Simple(true));
+        }
+        capabilities.definition_provider = Some(OneOf::Left(true));
+        capabilities.references_provider = Some(OneOf::Left(true));
+        capabilities.rename_provider = Some(OneOf::Left(true));
+
         capabilities.diagnostic_provider = if backend_capabilities.use_push_diagnostics() {
             None
         } else {
-            Some(DiagnosticServerCapabilities::Options(DiagnosticOptions::default()))
+            Some(DiagnosticServerCapabilities::Options(DiagnosticOptions {
+                workspace_diagnostics: true,
+                ..Default::default()
+            }))
         };
     }
     fn build_boxed(&self, root_uri: &Uri, options: serde_json::Value) -> Box<dyn Tool> {
@@ -273,8 +297,9 @@ impl ServerLinterBuilder {
         (nested_configs, extended_paths)
     }
 
+    /// Collect the `.gitignore`, `.eslintignore` and `.oxlintignore` files of the workspace folder.
     #[expect(clippy::filetype_is_file)]
-    fn create_ignore_glob(root_path: &Path) -> Vec<Gitignore> {
+    pub(crate) fn create_ignore_glob(root_path: &Path) -> Vec<Gitignore> {
         let walk = ignore::WalkBuilder::new(root_path)
             .ignore(true)
             .hidden(false)
@@ -291,7 +316,7 @@ impl ServerLinterBuilder {
             if !ignore_file_path
                 .file_name()
                 .and_then(std::ffi::OsStr::to_str)
-                .is_some_and(|v| [".eslintignore", ".gitignore"].contains(&v))
+                .is_some_and(|v| [".eslintignore", ".oxlintignore", ".gitignore"].contains(&v))
             {
                 continue;
             }
@@ -315,7 +340,10 @@ pub struct ServerLinter {
     ignore_matcher: LintIgnoreMatcher,
     gitignore_glob: Vec<Gitignore>,
     extended_paths: FxHashSet<PathBuf>,
+    use_nested_configs: bool,
+    use_cross_module: bool,
     code_actions: Arc<ConcurrentHashMap<Uri, Option<Vec<LinterCodeAction>>>>,
+    diagnostics: Arc<ConcurrentHashMap<Uri, Vec<Diagnostic>>>,
 }
 
 impl Tool for ServerLinter {
@@ -352,7 +380,7 @@ impl Tool for ServerLinter {
         };
 
         if !Self::needs_restart(&old_option, &new_options) {
-            return ToolRestartChanges { tool: None, watch_patterns: None };
+            return ToolRestartChanges { tool: None, watch_patterns: None, scope: None };
         }
 
         // get the cached files before refreshing the linter, and revalidate them after
@@ -369,7 +397,11 @@ impl Tool for ServerLinter {
             }
         };
 
-        ToolRestartChanges { tool: Some(Box::new(new_linter)), watch_patterns: patterns }
+        ToolRestartChanges {
+            tool: Some(Box::new(new_linter)),
+            watch_patterns: patterns,
+            scope: None,
+        }
     }
 
     fn get_watcher_patterns(&self, options: serde_json::Value) -> Vec<Pattern> {
@@ -399,27 +431,55 @@ impl Tool for ServerLinter {
             watchers.push(normalize_path(pattern).to_string_lossy().to_string());
         }
 
-        if options.type_aware {
+        // tsconfig and package.json files are used to resolve the imported modules
+        if options.type_aware || self.use_cross_module {
             watchers.push("**/tsconfig*.json".to_string());
         }
+        if self.use_cross_module {
+            watchers.push("**/package.json".to_string());
+        }
 
         watchers
     }
 
-    fn handle_watched_file_change(
+    fn handle_watched_file_changes(
         &self,
-        _changed_uri: &Uri,
+        changed_files: &[FileEvent],
         root_uri: &Uri,
         options: serde_json::Value,
     ) -> ToolRestartChanges {
-        // TODO: Check if the changed file is actually a config file (including extended paths)
-        let new_linter = ServerLinterBuilder::build(root_uri, options);
+        // Merge the events like `oxlint --watch` does, e.g. a temporary file of an atomic save
+        // is created and removed in the same batch, and didn't change anything.
+        let changes = coalesce_file_changes(changed_files.iter().filter_map(|file_event| {
+            let kind = match file_event.typ {
+                FileChangeType::CREATED => FileChangeKind::Created,
+                FileChangeType::DELETED => FileChangeKind::Removed,
+                _ => FileChangeKind::Changed,
+            };
+            Some(FileChange::new(file_event.uri.to_file_path()?, kind))
+        }));
+
+        // a file which can be linted is not a config file, so the linter is still up to date
+        if changes.iter().all(|change| {
+            WatchedFileKind::from_path(&change.path) == WatchedFileKind::Source
+                && !self.extended_paths.contains(&change.path)
+        }) {
+            return ToolRestartChanges { tool: None, watch_patterns: None, scope: None };
+        }
 
-        ToolRestartChanges {
-            tool: Some(Box::new(new_linter)),
-            // TODO: update watch patterns if config_path changed, or the extended paths changed
-            watch_patterns: None,
+        let new_linter = ServerLinterBuilder::build(root_uri, options.clone());
+        let scope = self.changed_files_scope(&changes, &new_linter);
+
+        // the cached results outside of the scope are still valid
+        if let Some(scope) = &scope {
+            new_linter.keep_cache_outside_of(self, scope);
         }
+
+        // a config may extend other configs now, or not anymore
+        let watch_patterns = (new_linter.extended_paths != self.extended_paths)
+            .then(|| new_linter.get_watcher_patterns(options));
+
+        ToolRestartChanges { tool: Some(Box::new(new_linter)), watch_patterns, scope }
     }
 
     /// Check if the linter should know about the given command
@@ -476,7 +536,14 @@ impl Tool for ServerLinter {
         uri: &Uri,
         range: &Range,
         only_code_action_kinds: Option<&Vec<CodeActionKind>>,
+        content: Option<&str>,
     ) -> Vec<CodeActionOrCommand> {
+        if only_code_action_kinds.is_some_and(|only| is_source_organize_imports(only)) {
+            return self
+                .organize_imports(uri, content)
+                .map_or(vec![], |action| vec![CodeActionOrCommand::CodeAction(action)]);
+        }
+
         let actions = self.get_code_actions_for_uri(uri);
 
         let Some(actions) = actions else {
@@ -506,9 +573,103 @@ impl Tool for ServerLinter {
             code_actions_vec.extend(fix_actions.into_iter().map(CodeActionOrCommand::CodeAction));
         }
 
+        code_actions_vec.extend(
+            self.auto_import_code_actions(uri, range, content)
+                .into_iter()
+                .map(CodeActionOrCommand::CodeAction),
+        );
+
         code_actions_vec
     }
 
+    /// Show the documentation of the rule reported at the position,
+    /// or of the rule named inside a disable comment.
+    /// - If the file is not lintable or ignored, [`None`] is returned
+    #[cfg(feature = "ruledocs")]
+    fn get_hover(&self, uri: &Uri, position: Position, content: Option<&str>) -> Option<Hover> {
+        if self.is_ignored(uri) {
+            return None;
+        }
+
+        let source_text = match content {
+            Some(content) => content.to_string(),
+            None => std::fs::read_to_string(uri.to_file_path()?).ok()?,
+        };
+        if let Some(hover) = disable_comment_hover(&source_text, position) {
+            return Some(hover);
+        }
+
+        let diagnostics = match self.diagnostics.pin().get(uri) {
+            Some(diagnostics) => diagnostics.clone(),
+            None => self.run_file(uri, Some(&source_text))?,
+        };
+        diagnostics_hover(&diagnostics, position)
+    }
+
+    /// Go to the declaration of the symbol at the position.
+    /// - If the file is not lintable or ignored, [`None`] is returned
+    fn get_definition(
+        &self,
+        uri: &Uri,
+        position: Position,
+        content: Option<&str>,
+    ) -> Option<GotoDefinitionResponse> {
+        if self.is_ignored(uri) {
+            return None;
+        }
+
+        let source_text = match content {
+            Some(content) => content.to_string(),
+            None => std::fs::read_to_string(uri.to_file_path()?).ok()?,
+        };
+        self.isolated_linter
+            .definition(uri, &source_text, position)
+            .map(GotoDefinitionResponse::Scalar)
+    }
+
+    /// Find the references of the symbol at the position, including the modules importing it.
+    /// - If the file is not lintable or ignored, [`None`] is returned
+    fn get_references(
+        &self,
+        uri: &Uri,
+        position: Position,
+        include_declaration: bool,
+        content: Option<&str>,
+    ) -> Option<Vec<Location>> {
+        if self.is_ignored(uri) {
+            return None;
+        }
+
+        let source_text = match content {
+            Some(content) => content.to_string(),
+            None => std::fs::read_to_string(uri.to_file_path()?).ok()?,
+        };
+        self.isolated_linter.references(uri, &source_text, position, include_declaration)
+    }
+
+    /// Rename the symbol at the position, including its references in the same file.
+    /// - If the file is not lintable or ignored, `Ok(None)` is returned
+    fn rename(
+        &self,
+        uri: &Uri,
+        position: Position,
+        new_name: &str,
+        content: Option<&str>,
+    ) -> Result<Option<WorkspaceEdit>, String> {
+        if self.is_ignored(uri) {
+            return Ok(None);
+        }
+
+        let source_text = match content {
+            Some(content) => content.to_string(),
+            None => match uri.to_file_path().map(std::fs::read_to_string) {
+                Some(Ok(source_text)) => source_text,
+                _ => return Ok(None),
+            },
+        };
+        rename(uri, &source_text, position, new_name)
+    }
+
     /// Lint a file with the current linter
     /// - If the file is not lintable or ignored, an empty vector is returned
     fn run_diagnostic(&self, uri: &Uri, content: Option<&str>) -> DiagnosticResult {
@@ -538,8 +699,22 @@ impl Tool for ServerLinter {
         self.run_diagnostic(uri, content)
     }
 
+    /// All lintable files inside the workspace, which are not ignored.
+    fn get_workspace_files(&self) -> Vec<Uri> {
+        ignore::WalkBuilder::new(&self.cwd)
+            .git_global(false)
+            .build()
+            .flatten()
+            .filter(|entry| entry.file_type().is_some_and(|file_type| !file_type.is_dir()))
+            .filter(|entry| IsolatedLintHandler::should_lint_path(entry.path()))
+            .filter_map(|entry| Uri::from_file_path(entry.path()))
+            .filter(|uri| !self.is_ignored(uri))
+            .collect()
+    }
+
     fn remove_uri_cache(&self, uri: &Uri) {
         self.code_actions.pin().remove(uri);
+        self.diagnostics.pin().remove(uri);
     }
 }
 
@@ -547,7 +722,8 @@ impl ServerLinter {
     /// # Panics
     /// Panics if the root URI cannot be converted to a file path.
     pub fn new(
-        run: Run,
+        options: &LSPLintOptions,
+        use_cross_module: bool,
         cwd: PathBuf,
         isolated_linter: IsolatedLintHandler,
         ignore_matcher: LintIgnoreMatcher,
@@ -555,14 +731,110 @@ impl ServerLinter {
         extended_paths: FxHashSet<PathBuf>,
     ) -> Self {
         Self {
-            run,
+            run: options.run,
             cwd,
             isolated_linter,
             ignore_matcher,
             gitignore_glob,
             extended_paths,
+            use_nested_configs: options.use_nested_configs(),
+            use_cross_module,
             code_actions: Arc::new(ConcurrentHashMap::default()),
+            diagnostics: Arc::new(ConcurrentHashMap::default()),
+        }
+    }
+
+    /// The directory of the files affected by a change of a watched file.
+    /// A nested config only affects the files inside its directory, while the root config,
+    /// extended configs and `tsconfig.json` or `package.json` files can affect every file.
+    fn changed_file_scope(&self, path: &Path, new_linter: &Self) -> Option<PathBuf> {
+        if !new_linter.use_nested_configs {
+            return None;
+        }
+        if path.file_name()? != LINT_CONFIG_FILE
+            || self.extended_paths.contains(path)
+            || new_linter.extended_paths.contains(path)
+        {
+            return None;
+        }
+        let directory = path.parent()?;
+        (directory != self.cwd).then(|| directory.to_path_buf())
+    }
+
+    /// The directory containing the scopes of all `changes`, see [`Self::changed_file_scope`].
+    fn changed_files_scope(&self, changes: &[FileChange], new_linter: &Self) -> Option<PathBuf> {
+        let mut scopes =
+            changes.iter().map(|change| self.changed_file_scope(&change.path, new_linter));
+        let mut common = scopes.next()??;
+        for scope in scopes {
+            let scope = scope?;
+            while !scope.starts_with(&common) {
+                common = common.parent()?.to_path_buf();
+            }
         }
+        (common != self.cwd && common.starts_with(&self.cwd)).then_some(common)
+    }
+
+    /// Copy the cached results of `old_linter` for files outside of the `scope` directory.
+    fn keep_cache_outside_of(&self, old_linter: &Self, scope: &Path) {
+        let is_outside =
+            |uri: &Uri| uri.to_file_path().is_some_and(|path| !path.starts_with(scope));
+        let code_actions = self.code_actions.pin();
+        for (uri, actions) in
+            old_linter.code_actions.pin().iter().filter(|(uri, _)| is_outside(uri))
+        {
+            code_actions.insert(uri.clone(), actions.clone());
+        }
+        let diagnostics = self.diagnostics.pin();
+        for (uri, reports) in old_linter.diagnostics.pin().iter().filter(|(uri, _)| is_outside(uri))
+        {
+            diagnostics.insert(uri.clone(), reports.clone());
+        }
+    }
+
+    /// Sort, merge and remove the unused imports of the file in one code action.
+    /// - If the file is not lintable, ignored or the imports are already organized, [`None`] is returned
+    fn organize_imports(&self, uri: &Uri, content: Option<&str>) -> Option<CodeAction> {
+        if self.is_ignored(uri) {
+            return None;
+        }
+
+        let source_text = match content {
+            Some(content) => content.to_string(),
+            None => std::fs::read_to_string(uri.to_file_path()?).ok()?,
+        };
+        let organized = self.isolated_linter.organize_imports(uri, &source_text)?;
+        Some(organize_imports_code_action(uri.clone(), &source_text, &organized))
+    }
+
+    /// Import the identifiers reported by `no-undef` in the range from the modules exporting them.
+    fn auto_import_code_actions(
+        &self,
+        uri: &Uri,
+        range: &Range,
+        content: Option<&str>,
+    ) -> Vec<CodeAction> {
+        let Some(diagnostics) = self.diagnostics.pin().get(uri).cloned() else {
+            return vec![];
+        };
+        let undefined = diagnostics
+            .iter()
+            .filter(|diagnostic| {
+                is_no_undef_diagnostic(diagnostic)
+                    && (diagnostic.range == *range || range_overlaps(*range, diagnostic.range))
+            })
+            .collect::<Vec<_>>();
+        if undefined.is_empty() {
+            return vec![];
+        }
+
+        let Some(source_text) = content
+            .map(ToString::to_string)
+            .or_else(|| std::fs::read_to_string(uri.to_file_path()?).ok())
+        else {
+            return vec![];
+        };
+        self.isolated_linter.auto_import_code_actions(uri, &source_text, &undefined)
     }
 
     fn get_code_actions_for_uri(&self, uri: &Uri) -> Option<Vec<LinterCodeAction>> {
@@ -617,6 +889,7 @@ impl ServerLinter {
         }
 
         self.code_actions.pin().insert(uri.clone(), Some(code_actions));
+        self.diagnostics.pin().insert(uri.clone(), diagnostics.clone());
 
         Some(diagnostics)
     }
@@ -657,7 +930,12 @@ mod tests_builder {
     use crate::{
         ServerLinterBuilder, ToolBuilder,
         capabilities::Capabilities,
-        linter::{code_actions::CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC, commands::FIX_ALL_COMMAND_ID},
+        linter::{
+            code_actions::{
+                CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC, CODE_ACTION_KIND_SOURCE_ORGANIZE_IMPORTS_OXC,
+            },
+            commands::FIX_ALL_COMMAND_ID,
+        },
     };
 
     #[test]
@@ -673,7 +951,8 @@ mod tests_builder {
                 let code_action_kinds = options.code_action_kinds.as_ref().unwrap();
                 assert!(code_action_kinds.contains(&CodeActionKind::QUICKFIX));
                 assert!(code_action_kinds.contains(&CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC));
-                assert_eq!(code_action_kinds.len(), 2);
+                assert!(code_action_kinds.contains(&CODE_ACTION_KIND_SOURCE_ORGANIZE_IMPORTS_OXC));
+                assert_eq!(code_action_kinds.len(), 3);
             }
             _ => panic!("Expected code action provider options"),
         }
@@ -704,7 +983,8 @@ mod tests_builder {
                 assert!(code_action_kinds.contains(&CodeActionKind::REFACTOR));
                 assert!(code_action_kinds.contains(&CodeActionKind::QUICKFIX));
                 assert!(code_action_kinds.contains(&CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC));
-                assert_eq!(code_action_kinds.len(), 3);
+                assert!(code_action_kinds.contains(&CODE_ACTION_KIND_SOURCE_ORGANIZE_IMPORTS_OXC));
+                assert_eq!(code_action_kinds.len(), 4);
                 assert_eq!(options.resolve_provider, Some(true));
             }
             _ => panic!("Expected code action provider options"),
@@ -730,7 +1010,8 @@ mod tests_builder {
                 let code_action_kinds = options.code_action_kinds.as_ref().unwrap();
                 assert!(code_action_kinds.contains(&CodeActionKind::QUICKFIX));
                 assert!(code_action_kinds.contains(&CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC));
-                assert_eq!(code_action_kinds.len(), 2);
+                assert!(code_action_kinds.contains(&CODE_ACTION_KIND_SOURCE_ORGANIZE_IMPORTS_OXC));
+                assert_eq!(code_action_kinds.len(), 3);
             }
             _ => panic!("Expected code action provider options"),
         }
@@ -752,7 +1033,8 @@ mod tests_builder {
                 let code_action_kinds = options.code_action_kinds.as_ref().unwrap();
                 assert!(code_action_kinds.contains(&CodeActionKind::QUICKFIX));
                 assert!(code_action_kinds.contains(&CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC));
-                assert_eq!(code_action_kinds.len(), 2);
+                assert!(code_action_kinds.contains(&CODE_ACTION_KIND_SOURCE_ORGANIZE_IMPORTS_OXC));
+                assert_eq!(code_action_kinds.len(), 3);
             }
             _ => panic!("Expected code action provider options"),
         }
@@ -885,6 +1167,111 @@ mod test_watchers {
             assert_eq!(patterns[0], "**/.oxlintrc.json".to_string());
             assert_eq!(patterns[1], "**/tsconfig*.json".to_string());
         }
+
+        #[test]
+        fn test_linter_with_import_plugin() {
+            let patterns =
+                Tester::new("fixtures/linter/cross_module", json!({})).get_watcher_patterns();
+
+            assert_eq!(
+                patterns,
+                vec![
+                    "**/.oxlintrc.json".to_string(),
+                    "**/tsconfig*.json".to_string(),
+                    "**/package.json".to_string()
+                ]
+            );
+        }
+    }
+
+    mod handle_watched_file_changes {
+        use crate::{
+            ToolRestartChanges,
+            linter::tester::{Tester, get_file_path},
+        };
+        use serde_json::json;
+        use tower_lsp_server::ls_types::FileChangeType;
+
+        #[test]
+        fn test_nested_config_change() {
+            let ToolRestartChanges { tool, scope, .. } =
+                Tester::new("fixtures/linter/init_nested_configs", json!({}))
+                    .handle_watched_file_changes(&["deep1/.oxlintrc.json"]);
+
+            assert!(tool.is_some());
+            assert_eq!(scope, Some(get_file_path("fixtures/linter/init_nested_configs/deep1")));
+        }
+
+        #[test]
+        fn test_root_config_change() {
+            let ToolRestartChanges { tool, scope, .. } =
+                Tester::new("fixtures/linter/init_nested_configs", json!({}))
+                    .handle_watched_file_changes(&[".oxlintrc.json"]);
+
+            assert!(tool.is_some());
+            assert!(scope.is_none());
+        }
+
+        #[test]
+        fn test_nested_config_changes() {
+            let tester = Tester::new("fixtures/linter/init_nested_configs", json!({}));
+            let ToolRestartChanges { scope, .. } = tester.handle_watched_file_changes(&[
+                "deep1/deep2/.oxlintrc.json",
+                "deep1/.oxlintrc.json",
+            ]);
+            assert_eq!(scope, Some(get_file_path("fixtures/linter/init_nested_configs/deep1")));
+
+            let ToolRestartChanges { scope, .. } =
+                tester.handle_watched_file_changes(&["deep1/.oxlintrc.json", ".oxlintrc.json"]);
+            assert!(scope.is_none());
+        }
+
+        #[test]
+        fn test_atomic_save_of_source_file() {
+            let ToolRestartChanges { tool, .. } =
+                Tester::new("fixtures/linter/init_nested_configs", json!({}))
+                    .handle_watched_file_events(&[
+                        ("deep1/.index.ts.swp", FileChangeType::CREATED),
+                        ("deep1/.index.ts.swp", FileChangeType::DELETED),
+                        ("deep1/index.ts", FileChangeType::DELETED),
+                        ("deep1/index.ts", FileChangeType::CREATED),
+                    ]);
+
+            // the temporary file was created and removed, so only a source file changed
+            assert!(tool.is_none());
+        }
+
+        #[test]
+        fn test_source_file_change() {
+            let ToolRestartChanges { tool, watch_patterns, .. } =
+                Tester::new("fixtures/linter/init_nested_configs", json!({}))
+                    .handle_watched_file_changes(&["deep1/index.ts"]);
+
+            assert!(tool.is_none());
+            assert!(watch_patterns.is_none());
+        }
+
+        #[test]
+        fn test_nested_config_change_without_nested_configs() {
+            let ToolRestartChanges { scope, .. } = Tester::new(
+                "fixtures/linter/init_nested_configs",
+                json!({ "disableNestedConfig": true }),
+            )
+            .handle_watched_file_changes(&["deep1/.oxlintrc.json"]);
+
+            assert!(scope.is_none());
+        }
+
+        #[test]
+        fn test_extended_config_change() {
+            let ToolRestartChanges { scope, watch_patterns, .. } =
+                Tester::new("fixtures/linter/watchers/linter_extends", json!({}))
+                    .handle_watched_file_changes(&["lint.json"]);
+
+            assert!(scope.is_none());
+            // the extended configs did not change
+            assert!(watch_patterns.is_none());
+        }
     }
 
     mod handle_configuration_change {
@@ -945,6 +1332,7 @@ mod test {
     use std::path::{Path, PathBuf};
 
     use serde_json::json;
+    use tower_lsp_server::ls_types::{Position, Range};
 
     use crate::linter::{
         options::LintOptions,
@@ -1094,6 +1482,183 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn test_workspace_files() {
+        let tester = Tester::new("fixtures/linter/ignore_patterns", json!({}));
+        assert_eq!(tester.get_workspace_files(), vec!["another_config/not-ignored-file.ts"]);
+
+        let tester = Tester::new("fixtures/linter/frameworks", json!({}));
+        assert_eq!(
+            tester.get_workspace_files(),
+            vec![
+                "astro/debugger.astro",
+                "mpx/debugger.mpx",
+                "nextjs/[[..rest]]/debugger.ts",
+                "svelte/debugger.svelte",
+                "vue/debugger.vue",
+            ]
+        );
+    }
+
+    #[cfg(feature = "ruledocs")]
+    #[test]
+    fn test_hover() {
+        let tester = Tester::new("fixtures/linter/deny_no_console", json!({}));
+        assert_eq!(
+            tester.get_hover_title("hello_world.js", Position::new(0, 3)),
+            Some("# eslint/no-console".to_string())
+        );
+        assert_eq!(tester.get_hover_title("hello_world.js", Position::new(0, 28)), None);
+
+        let tester = Tester::new("fixtures/linter/unused_disabled_directives", json!({}));
+        assert_eq!(
+            tester.get_hover_title("test.js", Position::new(0, 32)),
+            Some("# eslint/no-debugger".to_string())
+        );
+    }
+
+    #[test]
+    fn test_auto_import() {
+        Tester::new("fixtures/linter/auto_import", json!({}))
+            .test_and_snapshot_single_file("src/index.ts");
+    }
+
+    #[test]
+    fn test_rename() {
+        let tester = Tester::new("fixtures/linter/rename", json!({}));
+        assert_eq!(
+            tester.rename("index.js", Position::new(2, 7), "result"),
+            Ok(Some(
+                "import { foo } from './foo';\n\nconst result = foo();\nexport { result as value };\n\nfunction print() {\n  const other = 1;\n  console.log({ value: result }, other);\n}\n\nprint();\n"
+                    .to_string()
+            ))
+        );
+        assert_eq!(
+            tester.rename("index.js", Position::new(0, 10), "bar"),
+            Ok(Some(
+                "import { foo as bar } from './foo';\n\nconst value = bar();\nexport { value };\n\nfunction print() {\n  const other = 1;\n  console.log({ value }, other);\n}\n\nprint();\n"
+                    .to_string()
+            ))
+        );
+        assert!(tester.rename("index.js", Position::new(2, 7), "other").is_err());
+        assert!(tester.rename("index.js", Position::new(2, 7), "1value").is_err());
+        // `console` is a global
+        assert_eq!(tester.rename("index.js", Position::new(7, 4), "logger"), Ok(None));
+        // in the `<script>` block of a Vue file
+        assert_eq!(
+            tester.rename("App.vue", Position::new(5, 7), "total"),
+            Ok(Some(
+                "<template>\n  <div />\n</template>\n\n<script setup lang=\"ts\">\nconst total: number = 1;\nconsole.log({ count: total });\n</script>\n"
+                    .to_string()
+            ))
+        );
+        assert_eq!(tester.rename("App.vue", Position::new(1, 4), "total"), Ok(None));
+    }
+
+    #[test]
+    fn test_definition() {
+        let tester = Tester::new("fixtures/linter/navigation", json!({}));
+        let add = Some((
+            "lib/utils.js".to_string(),
+            Range::new(Position::new(0, 16), Position::new(0, 19)),
+        ));
+        // through `export { add as sum }`
+        assert_eq!(tester.get_definition("src/index.js", Position::new(3, 15)), add);
+        // through `export *`
+        assert_eq!(tester.get_definition("src/index.js", Position::new(3, 27)), add);
+        assert_eq!(tester.get_definition("src/index.js", Position::new(3, 40)), add);
+        assert_eq!(
+            tester.get_definition("src/index.js", Position::new(4, 16)),
+            Some((
+                "src/index.js".to_string(),
+                Range::new(Position::new(3, 6), Position::new(3, 11))
+            ))
+        );
+        assert_eq!(tester.get_definition("src/index.js", Position::new(2, 0)), None);
+        // in the `<script>` block of a Vue file
+        assert_eq!(tester.get_definition("src/App.vue", Position::new(7, 15)), add);
+        assert_eq!(
+            tester.get_definition("src/App.vue", Position::new(7, 7)),
+            Some((
+                "src/App.vue".to_string(),
+                Range::new(Position::new(7, 6), Position::new(7, 11))
+            ))
+        );
+        assert_eq!(tester.get_definition("src/App.vue", Position::new(1, 12)), None);
+    }
+
+    #[test]
+    fn test_references() {
+        let tester = Tester::new("fixtures/linter/navigation", json!({}));
+        let references = |relative_file_path: &str, position: Position, include_declaration| {
+            tester
+                .get_references(
+                    &["src/index.js"],
+                    relative_file_path,
+                    position,
+                    include_declaration,
+                )
+                .iter()
+                .map(|(path, range)| {
+                    format!(
+                        "{path}:{}:{}-{}:{}",
+                        range.start.line,
+                        range.start.character,
+                        range.end.line,
+                        range.end.character
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            references("lib/utils.js", Position::new(0, 17), true),
+            vec![
+                "lib/utils.js:0:16-0:19",
+                // `export { add as sum } from './utils'`
+                "lib/index.js:0:9-0:12",
+                "src/index.js:1:9-1:12",
+                "src/index.js:3:39-3:42",
+                "src/index.js:0:9-0:12",
+                "src/index.js:3:14-3:17",
+                // `add as plus`, through `export * from './utils'`
+                "src/index.js:0:21-0:25",
+                "src/index.js:3:26-3:30",
+            ]
+        );
+        // from an import, without the declaration
+        assert_eq!(references("src/index.js", Position::new(3, 27), false).len(), 7);
+        // without the module graph, only the references in the file are found
+        assert_eq!(tester.get_references(&[], "src/index.js", Position::new(3, 7), true).len(), 2);
+        // in the `<script>` block of a Vue file
+        assert_eq!(
+            tester
+                .get_references(&["src/App.vue"], "lib/utils.js", Position::new(0, 17), false)
+                .into_iter()
+                .filter(|(path, _)| path == "src/App.vue")
+                .map(|(_, range)| range)
+                .collect::<Vec<_>>(),
+            vec![
+                Range::new(Position::new(5, 9), Position::new(5, 12)),
+                Range::new(Position::new(7, 14), Position::new(7, 17)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_organize_imports() {
+        let tester = Tester::new("fixtures/linter/organize_imports", json!({}));
+        // the unused import is kept, removing it is a dangerous fix
+        assert_eq!(
+            tester.organize_imports("index.js").as_deref(),
+            Some(
+                "import { readFile } from 'node:fs';\nimport { b } from './b';\nimport { x, y } from './a';\nimport { unused } from './c';\n\nreadFile(b, x, y);\n"
+            )
+        );
+        // nothing to organize
+        let tester = Tester::new("fixtures/linter/deny_no_console", json!({}));
+        assert_eq!(tester.organize_imports("hello_world.js"), None);
+    }
+
     #[test]
     fn test_ts_alias() {
         Tester::new(
@@ -1,13 +1,16 @@
 use std::{fmt::Write, path::PathBuf};
 
 use tower_lsp_server::ls_types::{
-    CodeAction, CodeActionOrCommand, CodeDescription, Diagnostic, NumberOrString, Position, Range,
-    Uri,
+    CodeAction, CodeActionOrCommand, CodeDescription, Diagnostic, FileChangeType, FileEvent,
+    GotoDefinitionResponse, Location, NumberOrString, Position, Range, TextEdit, Uri,
 };
 
 use crate::{
     ToolRestartChanges,
-    linter::{ServerLinterBuilder, server_linter::ServerLinter},
+    linter::{
+        ServerLinterBuilder, code_actions::CODE_ACTION_KIND_SOURCE_ORGANIZE_IMPORTS_OXC,
+        server_linter::ServerLinter,
+    },
     tool::{DiagnosticResult, Tool},
 };
 
@@ -219,6 +222,7 @@ impl Tester<'_> {
                     &uri,
                     &Range::new(Position::new(0, 0), Position::new(u32::MAX, u32::MAX)),
                     None,
+                    None,
                 ),
             };
 
@@ -242,6 +246,159 @@ impl Tester<'_> {
         });
     }
 
+    /// The files linted on `workspace/diagnostic` requests, relative to the root directory.
+    pub fn get_workspace_files(&self) -> Vec<String> {
+        let root_path =
+            Self::get_root_uri(self.relative_root_dir).to_file_path().unwrap().into_owned();
+        let mut files = self
+            .create_linter()
+            .get_workspace_files()
+            .iter()
+            .filter_map(Uri::to_file_path)
+            .filter_map(|path| {
+                #[expect(clippy::disallowed_methods)]
+                Some(path.strip_prefix(&root_path).ok()?.to_string_lossy().replace('\\', "/"))
+            })
+            .collect::<Vec<_>>();
+        files.sort_unstable();
+        files
+    }
+
+    /// The title of the hover at the given position of a file, relative to the root directory.
+    #[cfg(feature = "ruledocs")]
+    pub fn get_hover_title(&self, relative_file_path: &str, position: Position) -> Option<String> {
+        use tower_lsp_server::ls_types::HoverContents;
+
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        let hover = self.create_linter().get_hover(&uri, position, None)?;
+        let HoverContents::Markup(contents) = hover.contents else {
+            return None;
+        };
+        contents.value.lines().next().map(ToString::to_string)
+    }
+
+    /// Organize the imports of a file, relative to the root directory,
+    /// and return the source text with the edit of the code action applied.
+    pub fn organize_imports(&self, relative_file_path: &str) -> Option<String> {
+        let path = format!("{}/{}", self.relative_root_dir, relative_file_path);
+        let uri = get_file_uri(&path);
+        let source_text = std::fs::read_to_string(get_file_path(&path)).unwrap();
+        let actions = self.create_linter().get_code_actions_or_commands(
+            &uri,
+            &Range::default(),
+            Some(&vec![CODE_ACTION_KIND_SOURCE_ORGANIZE_IMPORTS_OXC]),
+            Some(&source_text),
+        );
+        let [CodeActionOrCommand::CodeAction(action)] = actions.as_slice() else {
+            return None;
+        };
+        let mut changes = action.edit.as_ref()?.changes.clone()?;
+        let edits = changes.remove(&uri)?;
+        if edits.len() != 1 {
+            return None;
+        }
+        Some(apply_text_edits(&source_text, edits))
+    }
+
+    /// Go to the definition of the symbol at the position of a file, relative to the root
+    /// directory, and return the file of the declaration, relative to the root directory, with its range.
+    pub fn get_definition(
+        &self,
+        relative_file_path: &str,
+        position: Position,
+    ) -> Option<(String, Range)> {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        let GotoDefinitionResponse::Scalar(location) =
+            self.create_linter().get_definition(&uri, position, None)?
+        else {
+            return None;
+        };
+        Some(self.relative_location(&location))
+    }
+
+    /// Find the references of the symbol at the position of a file, relative to the root directory,
+    /// after linting the `linted_file_paths` to build the module graph.
+    pub fn get_references(
+        &self,
+        linted_file_paths: &[&str],
+        relative_file_path: &str,
+        position: Position,
+        include_declaration: bool,
+    ) -> Vec<(String, Range)> {
+        let linter = self.create_linter();
+        for linted_file_path in linted_file_paths {
+            let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, linted_file_path));
+            linter.run_diagnostic(&uri, None).unwrap();
+        }
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        linter
+            .get_references(&uri, position, include_declaration, None)
+            .unwrap_or_default()
+            .iter()
+            .map(|location| self.relative_location(location))
+            .collect()
+    }
+
+    fn relative_location(&self, location: &Location) -> (String, Range) {
+        let root_path = get_file_path(self.relative_root_dir);
+        let path = location.uri.to_file_path().unwrap();
+        let relative_path = path
+            .strip_prefix(&root_path)
+            .unwrap()
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        (relative_path, location.range)
+    }
+
+    /// Rename the symbol at the position of a file, relative to the root directory,
+    /// and return the source text with the edits applied.
+    pub fn rename(
+        &self,
+        relative_file_path: &str,
+        position: Position,
+        new_name: &str,
+    ) -> Result<Option<String>, String> {
+        let path = format!("{}/{}", self.relative_root_dir, relative_file_path);
+        let uri = get_file_uri(&path);
+        let source_text = std::fs::read_to_string(get_file_path(&path)).unwrap();
+        let Some(edit) = self.create_linter().rename(&uri, position, new_name, None)? else {
+            return Ok(None);
+        };
+        let edits = edit.changes.and_then(|mut changes| changes.remove(&uri)).unwrap_or_default();
+        Ok(Some(apply_text_edits(&source_text, edits)))
+    }
+
+    /// Handle a batch of changes of watched files, relative to the root directory.
+    pub fn handle_watched_file_changes(&self, relative_file_paths: &[&str]) -> ToolRestartChanges {
+        let events = relative_file_paths
+            .iter()
+            .map(|path| (*path, FileChangeType::CHANGED))
+            .collect::<Vec<_>>();
+        self.handle_watched_file_events(&events)
+    }
+
+    /// Handle a batch of watched file events, relative to the root directory.
+    pub fn handle_watched_file_events(
+        &self,
+        events: &[(&str, FileChangeType)],
+    ) -> ToolRestartChanges {
+        let root_uri = Self::get_root_uri(self.relative_root_dir);
+        let file_events = events
+            .iter()
+            .map(|(path, typ)| FileEvent {
+                uri: get_file_uri(&format!("{}/{}", self.relative_root_dir, path)),
+                typ: *typ,
+            })
+            .collect::<Vec<_>>();
+        self.create_linter().handle_watched_file_changes(
+            &file_events,
+            &root_uri,
+            self.options.clone(),
+        )
+    }
+
     pub fn get_watcher_patterns(&self) -> Vec<String> {
         self.create_linter().get_watcher_patterns(self.options.clone())
     }
@@ -257,3 +414,22 @@ impl Tester<'_> {
         )
     }
 }
+
+/// Apply non-overlapping text edits to the source text.
+fn apply_text_edits(source_text: &str, mut edits: Vec<TextEdit>) -> String {
+    // the fixture files only contain ASCII, so the characters are bytes
+    let offset = |position: Position| {
+        let line_start = source_text
+            .split_inclusive('\n')
+            .take(position.line as usize)
+            .map(str::len)
+            .sum::<usize>();
+        line_start + position.character as usize
+    };
+    edits.sort_unstable_by_key(|edit| edit.range.start);
+    let mut output = source_text.to_string();
+    for edit in edits.iter().rev() {
+        output.replace_range(offset(edit.range.start)..offset(edit.range.end), &edit.new_text);
+    }
+    output
+}
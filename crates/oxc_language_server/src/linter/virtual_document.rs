@@ -0,0 +1,109 @@
+use std::path::Path;
+
+use oxc_linter::loader::PartialLoader;
+use oxc_span::{SourceType, Span};
+
+/// A JavaScript or TypeScript document of a file: the whole file, or one of the `<script>`
+/// blocks of a `.vue`, `.mpx`, `.svelte` or `.astro` file.
+///
+/// Offsets of the document are relative to its start in the file. They are mapped to offsets of
+/// the file with [`VirtualDocument::to_file_span`], and back with
+/// [`VirtualDocument::to_document_span`].
+#[derive(Debug, Clone, Copy)]
+pub struct VirtualDocument<'a> {
+    pub source_text: &'a str,
+    pub source_type: SourceType,
+    /// Offset of the document in the file.
+    pub start: u32,
+}
+
+impl<'a> VirtualDocument<'a> {
+    /// The documents of the file at `path`, in the order of the file.
+    /// Empty when the file is neither a JavaScript or TypeScript file, nor contains script blocks.
+    pub fn from_file(path: &Path, source_text: &'a str) -> Vec<Self> {
+        let extension = path.extension().and_then(std::ffi::OsStr::to_str).unwrap_or_default();
+        if let Some(sources) = PartialLoader::parse(extension, source_text) {
+            return sources
+                .into_iter()
+                .map(|source| Self {
+                    source_text: source.source_text,
+                    source_type: source.source_type,
+                    start: source.start,
+                })
+                .collect();
+        }
+        SourceType::from_path(path)
+            .ok()
+            .map(|source_type| Self { source_text, source_type, start: 0 })
+            .into_iter()
+            .collect()
+    }
+
+    /// The document of the file at `path` containing the offset of the file.
+    pub fn at_offset(path: &Path, source_text: &'a str, offset: u32) -> Option<Self> {
+        Self::from_file(path, source_text)
+            .into_iter()
+            .find(|document| document.to_document_offset(offset).is_some())
+    }
+
+    /// The offset in the document of the offset of the file, or `None` when it is outside.
+    pub fn to_document_offset(self, offset: u32) -> Option<u32> {
+        let offset = offset.checked_sub(self.start)?;
+        (offset as usize <= self.source_text.len()).then_some(offset)
+    }
+
+    /// The span in the document of the span of the file, or `None` when it is outside.
+    pub fn to_document_span(self, span: Span) -> Option<Span> {
+        Some(Span::new(self.to_document_offset(span.start)?, self.to_document_offset(span.end)?))
+    }
+
+    /// The span in the file of the span of the document.
+    pub fn to_file_span(self, span: Span) -> Span {
+        Span::new(span.start + self.start, span.end + self.start)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_span::Span;
+
+    use super::VirtualDocument;
+
+    #[test]
+    fn test_from_file() {
+        let source_text = "let a = 1;";
+        let documents = VirtualDocument::from_file(Path::new("a.ts"), source_text);
+        assert_eq!(documents.len(), 1);
+        assert_eq!(documents[0].start, 0);
+        assert!(documents[0].source_type.is_typescript());
+
+        let source_text = "<template><div /></template>\n<script setup lang=\"ts\">\nlet a = 1;\n</script>\n<script>\nexport default {};\n</script>\n";
+        let documents = VirtualDocument::from_file(Path::new("a.vue"), source_text);
+        assert_eq!(documents.len(), 2);
+        assert_eq!(documents[0].source_text, "\nlet a = 1;\n");
+        assert!(documents[0].source_type.is_typescript());
+        assert_eq!(&source_text[documents[1].start as usize..][..19], "\nexport default {};");
+
+        assert!(VirtualDocument::from_file(Path::new("a.md"), source_text).is_empty());
+    }
+
+    #[test]
+    fn test_offset_mapping() {
+        let source_text = "<template><div /></template>\n<script>\nlet a = 1;\n</script>\n";
+        let a = u32::try_from(source_text.find("a =").unwrap()).unwrap();
+        let document = VirtualDocument::at_offset(Path::new("a.vue"), source_text, a).unwrap();
+
+        let offset = document.to_document_offset(a).unwrap();
+        assert_eq!(&document.source_text[offset as usize..][..1], "a");
+        assert_eq!(document.to_file_span(Span::new(offset, offset + 1)), Span::new(a, a + 1));
+        assert_eq!(
+            document.to_document_span(Span::new(a, a + 1)),
+            Some(Span::new(offset, offset + 1))
+        );
+
+        assert!(document.to_document_offset(3).is_none());
+        assert!(VirtualDocument::at_offset(Path::new("a.vue"), source_text, 3).is_none());
+    }
+}
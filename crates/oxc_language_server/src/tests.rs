@@ -61,15 +61,17 @@ impl Tool for FakeTool {
             return ToolRestartChanges {
                 tool: Some(FakeToolBuilder.build_boxed(root_uri, new_options_json)),
                 watch_patterns: None,
+                scope: None,
             };
         }
         if new_options_json.as_u64() == Some(2) {
             return ToolRestartChanges {
                 tool: None,
                 watch_patterns: Some(vec!["**/new_watcher.config".to_string()]),
+                scope: None,
             };
         }
-        ToolRestartChanges { tool: None, watch_patterns: None }
+        ToolRestartChanges { tool: None, watch_patterns: None, scope: None }
     }
 
     fn get_watcher_patterns(
@@ -82,26 +84,39 @@ impl Tool for FakeTool {
         vec!["**/fake.config".to_string()]
     }
 
-    fn handle_watched_file_change(
+    fn handle_watched_file_changes(
         &self,
-        changed_uri: &Uri,
+        changed_files: &[FileEvent],
         root_uri: &Uri,
         options: serde_json::Value,
     ) -> ToolRestartChanges {
-        if changed_uri.as_str().ends_with("tool.config") {
-            return ToolRestartChanges {
-                tool: Some(FakeToolBuilder.build_boxed(root_uri, options)),
-                watch_patterns: None,
-            };
-        }
-        if changed_uri.as_str().ends_with("watcher.config") {
-            return ToolRestartChanges {
-                tool: None,
-                watch_patterns: Some(vec!["**/new_watcher.config".to_string()]),
-            };
+        for FileEvent { uri: changed_uri, .. } in changed_files {
+            if changed_uri.as_str().ends_with("tool.config") {
+                return ToolRestartChanges {
+                    tool: Some(FakeToolBuilder.build_boxed(root_uri, options)),
+                    watch_patterns: None,
+                    scope: None,
+                };
+            }
+            if changed_uri.as_str().ends_with("watcher.config") {
+                return ToolRestartChanges {
+                    tool: None,
+                    watch_patterns: Some(vec!["**/new_watcher.config".to_string()]),
+                    scope: None,
+                };
+            }
+            if changed_uri.as_str().ends_with("nested.config") {
+                return ToolRestartChanges {
+                    tool: Some(FakeToolBuilder.build_boxed(root_uri, options)),
+                    watch_patterns: None,
+                    scope: changed_uri
+                        .to_file_path()
+                        .and_then(|path| path.parent().map(std::path::Path::to_path_buf)),
+                };
+            }
         }
 
-        ToolRestartChanges { tool: None, watch_patterns: None }
+        ToolRestartChanges { tool: None, watch_patterns: None, scope: None }
     }
 
     fn get_code_actions_or_commands(
@@ -109,6 +124,7 @@ impl Tool for FakeTool {
         uri: &Uri,
         _range: &Range,
         _only_code_action_kinds: Option<&Vec<CodeActionKind>>,
+        _content: Option<&str>,
     ) -> Vec<CodeActionOrCommand> {
         if uri.as_str().ends_with("code_action.config") {
             return vec![CodeActionOrCommand::CodeAction(CodeAction {
@@ -122,6 +138,19 @@ impl Tool for FakeTool {
         vec![]
     }
 
+    fn get_hover(&self, uri: &Uri, position: Position, content: Option<&str>) -> Option<Hover> {
+        if !uri.as_str().ends_with("hover.config") {
+            return None;
+        }
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: content.unwrap_or_default().to_string(),
+            }),
+            range: Some(Range::new(position, position)),
+        })
+    }
+
     fn run_diagnostic(&self, uri: &Uri, content: Option<&str>) -> DiagnosticResult {
         if uri.as_str().ends_with("diagnostics.config") {
             return Ok(vec![(
@@ -143,6 +172,13 @@ impl Tool for FakeTool {
         Ok(Vec::new())
     }
 
+    fn get_workspace_files(&self) -> Vec<Uri> {
+        vec![
+            format!("{WORKSPACE}/diagnostics.config").parse().unwrap(),
+            format!("{WORKSPACE}/unlinted.config").parse().unwrap(),
+        ]
+    }
+
     fn run_diagnostic_on_change(&self, uri: &Uri, content: Option<&str>) -> DiagnosticResult {
         // For this fake tool, we use the same logic as run_diagnostic
         self.run_diagnostic(uri, content)
@@ -520,6 +556,18 @@ fn code_action(id: i64, uri: &str) -> Request {
     Request::build("textDocument/codeAction").id(id).params(json!(params)).finish()
 }
 
+fn hover(id: i64, uri: &str, position: Position) -> Request {
+    let params = HoverParams {
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.parse().unwrap() },
+            position,
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    };
+
+    Request::build("textDocument/hover").id(id).params(json!(params)).finish()
+}
+
 fn test_configuration_request(id: i64) -> Request {
     Request::build("test/configuration").id(id).params(json!(null)).finish()
 }
@@ -536,14 +584,25 @@ fn diagnostic(id: i64, uri: &str) -> Request {
     Request::build("textDocument/diagnostic").id(id).params(json!(params)).finish()
 }
 
+fn workspace_diagnostic(id: i64, previous_result_ids: Vec<PreviousResultId>) -> Request {
+    let params = WorkspaceDiagnosticParams {
+        identifier: None,
+        previous_result_ids,
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+
+    Request::build("workspace/diagnostic").id(id).params(json!(params)).finish()
+}
+
 #[cfg(test)]
 mod test_suite {
     use serde_json::{Value, json};
     use tower_lsp_server::{
         jsonrpc::{Error, ErrorCode, Id, Response},
         ls_types::{
-            ApplyWorkspaceEditResponse, InitializeResult, PublishDiagnosticsParams, ServerInfo,
-            WorkspaceEdit, WorkspaceFolder,
+            ApplyWorkspaceEditResponse, InitializeResult, Position, PreviousResultId,
+            PublishDiagnosticsParams, ServerInfo, WorkspaceEdit, WorkspaceFolder,
         },
     };
 
@@ -554,9 +613,10 @@ mod test_suite {
             WORKSPACE_2, acknowledge_diagnostic_refresh, acknowledge_registrations,
             acknowledge_unregistrations, code_action, diagnostic, did_change,
             did_change_configuration, did_change_watched_files, did_close, did_open, did_save,
-            execute_command_request, initialize_request, initialize_request_workspace_folders,
-            initialized_notification, response_to_configuration, shutdown_request,
-            test_configuration_request, workspace_folders_changed,
+            execute_command_request, hover, initialize_request,
+            initialize_request_workspace_folders, initialized_notification,
+            response_to_configuration, shutdown_request, test_configuration_request,
+            workspace_diagnostic, workspace_folders_changed,
         },
     };
 
@@ -1398,6 +1458,42 @@ mod test_suite {
         server.shutdown(4).await;
     }
 
+    #[tokio::test]
+    async fn test_hover() {
+        let mut server = TestServer::new_initialized(
+            |client| Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder)]),
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/hover.config");
+        server.send_request(did_open(&file, "some text")).await;
+
+        server.send_request(hover(3, &file, Position::new(0, 2))).await;
+        let response = server.recv_response().await;
+        assert!(response.id() == &Id::Number(3));
+        assert_eq!(
+            response.result().unwrap(),
+            &json!({
+                "contents": { "kind": "markdown", "value": "some text" },
+                "range": {
+                    "start": { "line": 0, "character": 2 },
+                    "end": { "line": 0, "character": 2 }
+                }
+            })
+        );
+
+        // No hover expected
+        let file = format!("{WORKSPACE}/file.txt");
+        server.send_request(did_open(&file, "some text")).await;
+        server.send_request(hover(4, &file, Position::new(0, 2))).await;
+        let response = server.recv_response().await;
+        assert!(response.id() == &Id::Number(4));
+        assert!(response.result().is_some_and(|result| *result == Value::Null));
+
+        server.shutdown(5).await;
+    }
+
     #[tokio::test]
     async fn test_diagnostic_on_open() {
         let mut server = TestServer::new_initialized(
@@ -1539,4 +1635,55 @@ mod test_suite {
 
         server.shutdown(4).await;
     }
+
+    #[tokio::test]
+    async fn test_workspace_diagnostics_pull_mode() {
+        let init_options = InitializeRequestOptions { pull_mode: true, ..Default::default() };
+
+        let mut server = TestServer::new_initialized(
+            |client| Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder)]),
+            initialize_request(init_options),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/diagnostics.config");
+        server.send_request(did_open(&file, "open file")).await;
+
+        server.send_request(workspace_diagnostic(3, vec![])).await;
+        let response = server.recv_response().await;
+        assert_eq!(response.id(), &Id::Number(3));
+        let report = response.result().unwrap().clone();
+        let items = report["items"].as_array().unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0]["uri"], file);
+        assert_eq!(items[0]["kind"], "full");
+        assert_eq!(items[0]["items"][0]["message"], "Fake diagnostic for content: open file");
+        assert_eq!(items[1]["uri"], format!("{WORKSPACE}/unlinted.config"));
+        assert_eq!(items[1]["items"], json!([]));
+
+        // Reports are unchanged, until the content of the file changes.
+        let previous_result_ids = items
+            .iter()
+            .map(|item| PreviousResultId {
+                uri: item["uri"].as_str().unwrap().parse().unwrap(),
+                value: item["resultId"].as_str().unwrap().to_string(),
+            })
+            .collect::<Vec<_>>();
+        server.send_request(workspace_diagnostic(4, previous_result_ids.clone())).await;
+        let report = server.recv_response().await.result().unwrap().clone();
+        assert_eq!(report["items"][0]["kind"], "unchanged");
+        assert_eq!(report["items"][1]["kind"], "unchanged");
+
+        server.send_request(did_change(&file, "changed file")).await;
+        server.send_request(workspace_diagnostic(5, previous_result_ids)).await;
+        let report = server.recv_response().await.result().unwrap().clone();
+        assert_eq!(report["items"][0]["kind"], "full");
+        assert_eq!(
+            report["items"][0]["items"][0]["message"],
+            "Fake diagnostic for content: changed file"
+        );
+        assert_eq!(report["items"][1]["kind"], "unchanged");
+
+        server.shutdown(6).await;
+    }
 }
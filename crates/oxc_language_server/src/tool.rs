@@ -1,8 +1,10 @@
+use std::path::PathBuf;
+
 use tower_lsp_server::{
     jsonrpc::ErrorCode,
     ls_types::{
-        CodeActionKind, CodeActionOrCommand, Diagnostic, Pattern, Range, ServerCapabilities,
-        TextEdit, Uri, WorkspaceEdit,
+        CodeActionKind, CodeActionOrCommand, Diagnostic, FileEvent, GotoDefinitionResponse, Hover,
+        Location, Pattern, Position, Range, ServerCapabilities, TextEdit, Uri, WorkspaceEdit,
     },
 };
 
@@ -40,12 +42,14 @@ pub trait Tool: Send + Sync {
     /// These patterns will be used to watch for file changes relevant to the tool.
     fn get_watcher_patterns(&self, options: serde_json::Value) -> Vec<Pattern>;
 
-    /// Handle a watched file change event for the given URI.
+    /// Handle a batch of watched file change events, in the order the client sent them.
+    /// A file may change multiple times in a batch, e.g. when it is saved atomically.
     /// Returns a [ToolRestartChanges] indicating what changes were made for the Tool.
-    /// The Tool should decide whether it needs to restart or take any action based on the URI.
-    fn handle_watched_file_change(
+    /// The Tool should decide whether it needs to restart or take any action based on the events,
+    /// and restart at most once for the whole batch.
+    fn handle_watched_file_changes(
         &self,
-        changed_uri: &Uri,
+        changed_files: &[FileEvent],
         root_uri: &Uri,
         options: serde_json::Value,
     ) -> ToolRestartChanges;
@@ -73,15 +77,69 @@ pub trait Tool: Send + Sync {
 
     /// Get code actions or commands provided by this tool for the given URI and range.
     /// The `only_code_action_kinds` parameter can be used to filter the results based on specific code action kinds.
+    /// If `content` is `None`, the tool should read the content from the file system.
     fn get_code_actions_or_commands(
         &self,
         _uri: &Uri,
         _range: &Range,
         _only_code_action_kinds: Option<&Vec<CodeActionKind>>,
+        _content: Option<&str>,
     ) -> Vec<CodeActionOrCommand> {
         Vec::new()
     }
 
+    /// Get the hover information for the given position of the URI.
+    /// If `content` is `None`, the tool should read the content from the file system.
+    ///
+    /// Not all tools will implement hovers, so the default implementation returns `None`.
+    fn get_hover(&self, _uri: &Uri, _position: Position, _content: Option<&str>) -> Option<Hover> {
+        None
+    }
+
+    /// Get the definition of the symbol at the given position of the URI.
+    /// If `content` is `None`, the tool should read the content from the file system.
+    ///
+    /// Not all tools will implement go-to-definition, so the default implementation returns `None`.
+    fn get_definition(
+        &self,
+        _uri: &Uri,
+        _position: Position,
+        _content: Option<&str>,
+    ) -> Option<GotoDefinitionResponse> {
+        None
+    }
+
+    /// Get the references of the symbol at the given position of the URI.
+    /// If `content` is `None`, the tool should read the content from the file system.
+    ///
+    /// Not all tools will implement find-references, so the default implementation returns `None`.
+    fn get_references(
+        &self,
+        _uri: &Uri,
+        _position: Position,
+        _include_declaration: bool,
+        _content: Option<&str>,
+    ) -> Option<Vec<Location>> {
+        None
+    }
+
+    /// Rename the symbol at the given position of the URI to `new_name`.
+    /// If `content` is `None`, the tool should read the content from the file system.
+    ///
+    /// Not all tools will implement renaming, so the default implementation returns `Ok(None)`.
+    ///
+    /// # Errors
+    /// If the symbol can not be renamed to `new_name`, returns an `Err` with the reason.
+    fn rename(
+        &self,
+        _uri: &Uri,
+        _position: Position,
+        _new_name: &str,
+        _content: Option<&str>,
+    ) -> Result<Option<WorkspaceEdit>, String> {
+        Ok(None)
+    }
+
     /// Format the content of the given URI.
     /// If `content` is `None`, the tool should read the content from the file system.
     /// Returns a vector of `TextEdit` representing the formatting changes.
@@ -123,6 +181,13 @@ pub trait Tool: Send + Sync {
         Ok(Vec::new())
     }
 
+    /// Get the files of the workspace this tool reports diagnostics for,
+    /// which are linted with [`Tool::run_diagnostic`] on `workspace/diagnostic` requests.
+    /// Not all tools will implement diagnostics, so the default implementation returns an empty vector.
+    fn get_workspace_files(&self) -> Vec<Uri> {
+        Vec::new()
+    }
+
     /// Remove internal cache for the given URI, if any.
     fn remove_uri_cache(&self, _uri: &Uri) {
         // Default implementation does nothing.
@@ -141,4 +206,7 @@ pub struct ToolRestartChanges {
     /// The patterns that were added during the tool restart
     /// Old patterns will be automatically unregistered
     pub watch_patterns: Option<Vec<Pattern>>,
+    /// The directory of the files affected by the restarted tool, e.g. the directory of a changed nested config.
+    /// If None, all files of the workspace are affected.
+    pub scope: Option<PathBuf>,
 }
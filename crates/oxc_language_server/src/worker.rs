@@ -6,8 +6,9 @@ use tower_lsp_server::{
     jsonrpc::ErrorCode,
     ls_types::{
         CodeActionKind, CodeActionOrCommand, Diagnostic, DidChangeWatchedFilesRegistrationOptions,
-        FileEvent, FileSystemWatcher, GlobPattern, OneOf, Range, Registration, RelativePattern,
-        TextEdit, Unregistration, Uri, WatchKind, WorkspaceEdit,
+        FileEvent, FileSystemWatcher, GlobPattern, GotoDefinitionResponse, Hover, Location, OneOf,
+        Position, Range, Registration, RelativePattern, TextEdit, Unregistration, Uri, WatchKind,
+        WorkspaceEdit,
     },
 };
 
@@ -100,6 +101,36 @@ impl WorkspaceWorker {
             .collect()
     }
 
+    /// Check if the given URI matches a watcher pattern of a tool.
+    /// The patterns are relative to the root URI of the worker, like the registered file watchers.
+    #[cfg(feature = "linter")]
+    pub async fn is_watched_uri(&self, uri: &Uri) -> bool {
+        let (Some(path), Some(root_path)) = (uri.to_file_path(), self.root_uri.to_file_path())
+        else {
+            return false;
+        };
+        let Ok(relative_path) = path.strip_prefix(&root_path) else {
+            return false;
+        };
+        // glob patterns are separated by `/` on every platform
+        let Some(relative_path) = relative_path
+            .components()
+            .map(|component| component.as_os_str().to_str())
+            .collect::<Option<Vec<_>>>()
+            .map(|components| components.join("/"))
+        else {
+            return false;
+        };
+        // clone the options to avoid locking the mutex
+        let options_json = { self.options.lock().await.clone().unwrap_or_default() };
+
+        self.tools.read().await.iter().any(|tool| {
+            tool.get_watcher_patterns(options_json.clone()).iter().any(|pattern| {
+                fast_glob::glob_match(pattern.trim_start_matches("./"), &relative_path)
+            })
+        })
+    }
+
     /// Check if the worker needs to be initialized with options
     pub async fn needs_init_options(&self) -> bool {
         self.options.lock().await.is_none()
@@ -194,6 +225,101 @@ impl WorkspaceWorker {
         .await
     }
 
+    /// Run different tools to collect diagnostics for all files of the workspace.
+    /// The content of files opened in the client is taken from the `file_system`.
+    /// Files without diagnostics are included with an empty vector, so their previous diagnostics are cleared.
+    pub async fn run_workspace_diagnostic(
+        &self,
+        file_system: &LSPFileSystem,
+    ) -> Result<Vec<(Uri, Vec<Diagnostic>)>, String> {
+        let mut aggregated: FxHashMap<Uri, Vec<Diagnostic>> = FxHashMap::default();
+
+        for tool in self.tools.read().await.iter() {
+            for uri in tool.get_workspace_files() {
+                let content = file_system.get(&uri);
+                aggregated.entry(uri.clone()).or_default();
+                for (entry_uri, mut diags) in tool.run_diagnostic(&uri, content.as_deref())? {
+                    aggregated.entry(entry_uri).or_default().append(&mut diags);
+                }
+            }
+        }
+
+        let mut result = aggregated.into_iter().collect::<Vec<_>>();
+        result.sort_unstable_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+        Ok(result)
+    }
+
+    /// Get the hover information for the given position.
+    /// The first tool returning a hover wins.
+    pub async fn get_hover(
+        &self,
+        uri: &Uri,
+        position: Position,
+        content: Option<&str>,
+    ) -> Option<Hover> {
+        for tool in self.tools.read().await.iter() {
+            if let Some(hover) = tool.get_hover(uri, position, content) {
+                return Some(hover);
+            }
+        }
+        None
+    }
+
+    /// Get the definition of the symbol at the given position.
+    /// The first tool returning a definition wins.
+    pub async fn get_definition(
+        &self,
+        uri: &Uri,
+        position: Position,
+        content: Option<&str>,
+    ) -> Option<GotoDefinitionResponse> {
+        for tool in self.tools.read().await.iter() {
+            if let Some(definition) = tool.get_definition(uri, position, content) {
+                return Some(definition);
+            }
+        }
+        None
+    }
+
+    /// Get the references of the symbol at the given position.
+    /// The first tool returning references wins.
+    pub async fn get_references(
+        &self,
+        uri: &Uri,
+        position: Position,
+        include_declaration: bool,
+        content: Option<&str>,
+    ) -> Option<Vec<Location>> {
+        for tool in self.tools.read().await.iter() {
+            if let Some(references) =
+                tool.get_references(uri, position, include_declaration, content)
+            {
+                return Some(references);
+            }
+        }
+        None
+    }
+
+    /// Rename the symbol at the given position.
+    /// The first tool returning a workspace edit wins.
+    ///
+    /// # Errors
+    /// If a tool can not rename the symbol to `new_name`, returns the reason.
+    pub async fn rename(
+        &self,
+        uri: &Uri,
+        position: Position,
+        new_name: &str,
+        content: Option<&str>,
+    ) -> Result<Option<WorkspaceEdit>, String> {
+        for tool in self.tools.read().await.iter() {
+            if let Some(edit) = tool.rename(uri, position, new_name, content)? {
+                return Ok(Some(edit));
+            }
+        }
+        Ok(None)
+    }
+
     /// Format a file with the current formatter
     /// - If no file is not formattable or ignored, [`None`] is returned
     /// - If the file is formattable, but no changes are made, an empty vector is returned
@@ -236,6 +362,7 @@ impl WorkspaceWorker {
         uri: &Uri,
         range: &Range,
         only_code_action_kinds: Option<Vec<CodeActionKind>>,
+        content: Option<&str>,
     ) -> Vec<CodeActionOrCommand> {
         let mut actions = Vec::new();
         for tool in self.tools.read().await.iter() {
@@ -243,17 +370,18 @@ impl WorkspaceWorker {
                 uri,
                 range,
                 only_code_action_kinds.as_ref(),
+                content,
             ));
         }
         actions
     }
 
-    /// Handle file changes that are watched by the client
+    /// Handle a batch of file changes that are watched by the client, each file at most once
     /// At the moment, this only handles changes to lint configuration files
     /// When a change is detected, the linter is refreshed and all diagnostics are revalidated
     pub async fn did_change_watched_files(
         &self,
-        file_event: &FileEvent,
+        file_events: &[FileEvent],
         needs_diagnostic_refresh: &mut bool,
         file_system: Option<&LSPFileSystem>,
     ) -> (
@@ -271,7 +399,7 @@ impl WorkspaceWorker {
         };
 
         self.handle_tool_changes(file_system, needs_diagnostic_refresh, |tool| {
-            tool.handle_watched_file_change(&file_event.uri, &self.root_uri, options.clone())
+            tool.handle_watched_file_changes(file_events, &self.root_uri, options.clone())
         })
         .await
     }
@@ -361,6 +489,12 @@ impl WorkspaceWorker {
                 };
 
                 for uri in file_system.keys() {
+                    // files outside of the scope are not affected by the restart
+                    if let Some(scope) = &change.scope
+                        && !uri.to_file_path().is_some_and(|path| path.starts_with(scope))
+                    {
+                        continue;
+                    }
                     let Ok(mut reports) =
                         tool.run_diagnostic(&uri, file_system.get(&uri).as_deref())
                     else {
@@ -494,6 +628,21 @@ mod tests {
         assert_eq!(registrations_no_watchers.len(), 0);
     }
 
+    #[cfg(feature = "linter")]
+    #[tokio::test]
+    async fn test_is_watched_uri() {
+        let worker = WorkspaceWorker::new(Uri::from_str("file:///root/").unwrap(), false);
+        let tools: Vec<Box<dyn ToolBuilder>> = vec![Box::new(FakeToolBuilder)];
+        worker.start_worker(serde_json::Value::Null, &tools).await;
+
+        assert!(worker.is_watched_uri(&Uri::from_str("file:///root/fake.config").unwrap()).await);
+        assert!(
+            worker.is_watched_uri(&Uri::from_str("file:///root/folder/fake.config").unwrap()).await
+        );
+        assert!(!worker.is_watched_uri(&Uri::from_str("file:///root/file.js").unwrap()).await);
+        assert!(!worker.is_watched_uri(&Uri::from_str("file:///other/fake.config").unwrap()).await);
+    }
+
     #[tokio::test]
     async fn test_execute_command() {
         let worker = WorkspaceWorker::new(Uri::from_str("file:///root/").unwrap(), false);
@@ -531,10 +680,10 @@ mod tests {
 
         let (diagnostics, registrations, unregistrations) = worker
             .did_change_watched_files(
-                &FileEvent {
+                &[FileEvent {
                     uri: Uri::from_str("file:///root/unknown.file").unwrap(),
                     typ: FileChangeType::CHANGED,
-                },
+                }],
                 &mut needs_diagnostic_refresh,
                 Some(&fs),
             )
@@ -548,10 +697,10 @@ mod tests {
 
         let (diagnostics, registrations, unregistrations) = worker
             .did_change_watched_files(
-                &FileEvent {
+                &[FileEvent {
                     uri: Uri::from_str("file:///root/watcher.config").unwrap(),
                     typ: FileChangeType::CHANGED,
-                },
+                }],
                 &mut needs_diagnostic_refresh,
                 Some(&fs),
             )
@@ -567,10 +716,10 @@ mod tests {
 
         let (diagnostics, registrations, unregistrations) = worker
             .did_change_watched_files(
-                &FileEvent {
+                &[FileEvent {
                     uri: Uri::from_str("file:///root/tool.config").unwrap(),
                     typ: FileChangeType::CHANGED,
-                },
+                }],
                 &mut needs_diagnostic_refresh,
                 Some(&fs),
             )
@@ -586,10 +735,10 @@ mod tests {
         needs_diagnostic_refresh = false;
         let (diagnostics, registrations, unregistrations) = worker
             .did_change_watched_files(
-                &FileEvent {
+                &[FileEvent {
                     uri: Uri::from_str("file:///root/tool.config").unwrap(),
                     typ: FileChangeType::CHANGED,
-                },
+                }],
                 &mut needs_diagnostic_refresh,
                 None,
             )
@@ -600,6 +749,28 @@ mod tests {
         assert_eq!(registrations.len(), 0); // No new registrations expected
         assert_eq!(unregistrations.len(), 0); // No unregistrations expected
         assert!(needs_diagnostic_refresh); // Need to refresh diagnostics
+
+        fs.set(
+            Uri::from_str("file:///root/nested/diagnostics.config").unwrap(),
+            "hello nested world".to_string(),
+        );
+        needs_diagnostic_refresh = false;
+        let (diagnostics, _, _) = worker
+            .did_change_watched_files(
+                &[FileEvent {
+                    uri: Uri::from_str("file:///root/nested/nested.config").unwrap(),
+                    typ: FileChangeType::CHANGED,
+                }],
+                &mut needs_diagnostic_refresh,
+                Some(&fs),
+            )
+            .await;
+
+        // Only the files inside the directory of "nested.config" are revalidated
+        let diagnostics = diagnostics.unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].0.as_str(), "file:///root/nested/diagnostics.config");
+        assert!(needs_diagnostic_refresh); // Need to refresh diagnostics
     }
 
     #[tokio::test]
@@ -673,6 +844,7 @@ mod tests {
                 &Uri::from_str("file:///root/file.js").unwrap(),
                 &Range::default(),
                 None,
+                None,
             )
             .await;
 
@@ -683,6 +855,7 @@ mod tests {
                 &Uri::from_str("file:///root/code_action.config").unwrap(),
                 &Range::default(),
                 None,
+                None,
             )
             .await;
 
@@ -0,0 +1,7 @@
+export { foo } from "./foo";
+export { bar as baz } from "./bar";
+export * from "./qux";
+export { default as Def } from "./def";
+import { local } from "./local";
+export { local };
+export * from "./nested";
@@ -0,0 +1 @@
+export const local = 5;
@@ -0,0 +1,2 @@
+export { foo } from "../foo";
+export const mixed = 7;
@@ -0,0 +1 @@
+export const deep = 6;
@@ -0,0 +1 @@
+export { deep } from "./deep";
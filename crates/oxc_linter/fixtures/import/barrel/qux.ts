@@ -0,0 +1,2 @@
+export const qux = 3;
+export type Qux = number;
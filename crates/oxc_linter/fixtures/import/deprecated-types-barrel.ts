@@ -0,0 +1,5 @@
+export { legacy, current } from 'deprecated-types';
+export * from './deprecated';
+
+/** @deprecated Use `current` from 'deprecated-types' instead. */
+export { fine as oldFn } from './deprecated';
@@ -0,0 +1,207 @@
+use std::{hash::Hasher, path::Path};
+
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use oxc_diagnostics::{Error, OxcDiagnostic};
+use oxc_span::{ContentHash, FingerprintHasher};
+
+use crate::utils::read_to_string;
+
+/// A snapshot of pre-existing lint violations.
+///
+/// Violations are identified by file, rule, and a fingerprint of the message and the source text
+/// the violation covers. The fingerprint doesn't include line numbers, so unrelated edits elsewhere
+/// in a file don't invalidate the baseline. Identical violations are tracked with a count.
+///
+/// File paths are recorded as reported in diagnostics (relative to the working directory), so a
+/// baseline must be generated and used from the same directory.
+#[derive(Debug, Default)]
+pub struct LintBaseline {
+    violations: FxHashMap<BaselineKey, usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+struct BaselineKey {
+    file: String,
+    rule: String,
+    fingerprint: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BaselineFile {
+    violations: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BaselineEntry {
+    #[serde(flatten)]
+    key: BaselineKey,
+    count: usize,
+}
+
+impl LintBaseline {
+    /// # Errors
+    ///
+    /// * The file cannot be read
+    /// * The file is not a valid baseline
+    pub fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
+        let string = read_to_string(path).map_err(|e| {
+            OxcDiagnostic::error(format!(
+                "Failed to read baseline {} with error {e:?}",
+                path.display()
+            ))
+        })?;
+        Self::from_json(&string).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to parse baseline {}.\n{err}", path.display()))
+        })
+    }
+
+    fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let file = serde_json::from_str::<BaselineFile>(json)?;
+        let mut violations = FxHashMap::default();
+        for entry in file.violations {
+            *violations.entry(entry.key).or_default() += entry.count;
+        }
+        Ok(Self { violations })
+    }
+
+    /// Serializes the baseline, with violations sorted by file, rule and fingerprint so the
+    /// output is stable across runs.
+    ///
+    /// # Panics
+    /// Panics if serialization fails.
+    pub fn to_json(&self) -> String {
+        let mut violations = self
+            .violations
+            .iter()
+            .map(|(key, count)| BaselineEntry { key: key.clone(), count: *count })
+            .collect::<Vec<_>>();
+        violations.sort_unstable_by(|a, b| a.key.cmp(&b.key));
+        let mut json = serde_json::to_string_pretty(&BaselineFile { violations }).unwrap();
+        json.push('\n');
+        json
+    }
+
+    /// Total number of violations in the baseline.
+    pub fn len(&self) -> usize {
+        self.violations.values().sum()
+    }
+
+    /// Returns `true` if the baseline contains no violations.
+    pub fn is_empty(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Adds `error` to the baseline.
+    ///
+    /// Returns `false` if `error` is not a rule violation (e.g. a parse error), which is never recorded.
+    pub fn record(&mut self, error: &Error) -> bool {
+        let Some(key) = BaselineKey::new(error) else { return false };
+        *self.violations.entry(key).or_default() += 1;
+        true
+    }
+
+    /// Returns `true` if `error` is a violation recorded in the baseline.
+    ///
+    /// Each recorded violation suppresses at most one diagnostic, so adding another violation
+    /// identical to an existing one is still reported.
+    pub fn suppress(&mut self, error: &Error) -> bool {
+        let Some(key) = BaselineKey::new(error) else { return false };
+        match self.violations.get_mut(&key) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl BaselineKey {
+    fn new(error: &Error) -> Option<Self> {
+        let rule = error.code()?.to_string();
+        let label = error.labels()?.next()?;
+        let contents = error.source_code()?.read_span(label.inner(), 0, 0).ok()?;
+        let file = contents.name()?.to_string();
+
+        // Source text covered by the label, ignoring indentation so re-indenting code keeps the
+        // fingerprint.
+        let text = String::from_utf8_lossy(contents.data());
+        let mut hasher = FingerprintHasher::default();
+        rule.content_hash(&mut hasher);
+        error.to_string().content_hash(&mut hasher);
+        for line in text.lines() {
+            line.trim().content_hash(&mut hasher);
+        }
+
+        Some(Self { file, rule, fingerprint: format!("{:016x}", hasher.finish()) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use oxc_diagnostics::{Error, NamedSource, OxcDiagnostic};
+    use oxc_span::Span;
+
+    use super::LintBaseline;
+
+    fn violation(file: &str, source_text: &str, span: Span) -> Error {
+        let source = Arc::new(NamedSource::new(file, source_text.to_string()));
+        OxcDiagnostic::warn("`debugger` statement is not allowed")
+            .with_error_code("eslint", "no-debugger")
+            .with_label(span)
+            .with_source_code(source)
+    }
+
+    #[test]
+    fn test_suppress() {
+        let mut baseline = LintBaseline::default();
+        assert!(baseline.record(&violation("a.js", "debugger;", Span::new(0, 9))));
+        assert_eq!(baseline.len(), 1);
+
+        // Moving the violation to another line keeps the fingerprint.
+        assert!(baseline.suppress(&violation("a.js", "foo();\n  debugger;", Span::new(9, 18))));
+        // Each recorded violation only suppresses a single diagnostic.
+        assert!(!baseline.suppress(&violation("a.js", "debugger;", Span::new(0, 9))));
+    }
+
+    #[test]
+    fn test_new_violations_are_not_suppressed() {
+        let mut baseline = LintBaseline::default();
+        baseline.record(&violation("a.js", "debugger;", Span::new(0, 9)));
+
+        assert!(!baseline.suppress(&violation("b.js", "debugger;", Span::new(0, 9))));
+        assert!(!baseline.suppress(&violation("a.js", "foo();", Span::new(0, 6))));
+    }
+
+    #[test]
+    fn test_diagnostics_without_rule() {
+        let mut baseline = LintBaseline::default();
+        let error = OxcDiagnostic::error("Unexpected token")
+            .with_label(Span::new(0, 1))
+            .with_source_code(Arc::new(NamedSource::new("a.js", "}".to_string())));
+        assert!(!baseline.record(&error));
+        assert!(baseline.is_empty());
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let mut baseline = LintBaseline::default();
+        baseline.record(&violation("b.js", "debugger;", Span::new(0, 9)));
+        baseline.record(&violation("a.js", "debugger;", Span::new(0, 9)));
+        baseline.record(&violation("a.js", "debugger;", Span::new(0, 9)));
+
+        let json = baseline.to_json();
+        let mut restored = LintBaseline::from_json(&json).unwrap();
+        assert_eq!(restored.len(), 3);
+        assert_eq!(restored.to_json(), json);
+        assert!(json.find("a.js").unwrap() < json.find("b.js").unwrap());
+
+        assert!(restored.suppress(&violation("a.js", "debugger;", Span::new(0, 9))));
+        assert!(restored.suppress(&violation("a.js", "debugger;", Span::new(0, 9))));
+        assert!(!restored.suppress(&violation("a.js", "debugger;", Span::new(0, 9))));
+    }
+}
@@ -0,0 +1,195 @@
+use std::{collections::BTreeMap, ffi::OsStr, fs, hash::Hasher, io, path::Path, sync::Arc};
+
+use cow_utils::CowUtils;
+use rayon::prelude::*;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use oxc_diagnostics::Error;
+use oxc_span::{ContentHash, FingerprintHasher};
+
+use crate::Linter;
+
+/// Results of previous lint runs, used to skip files that haven't changed since they were last
+/// linted without any diagnostics.
+///
+/// Only files without diagnostics are cached, so files that reported diagnostics are always linted
+/// again and a cached run reports the same diagnostics as an uncached one.
+///
+/// Files are identified by their path relative to the working directory and a hash of their
+/// contents. The whole cache is discarded when the cache key changes, see [`LintCache::key`].
+#[derive(Debug, Default)]
+pub struct LintCache {
+    key: String,
+    /// Content hashes of files which had no diagnostics, keyed by relative path.
+    files: FxHashMap<String, String>,
+    /// Content hashes of files being linted in the current run.
+    pending: FxHashMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    key: String,
+    files: BTreeMap<String, String>,
+}
+
+impl LintCache {
+    /// Computes a cache key from the oxlint `version`, and the configuration and options of
+    /// `linter`. Cached results are only reused when the key is unchanged.
+    pub fn key(version: &str, linter: &Linter) -> String {
+        let mut hasher = FingerprintHasher::default();
+        version.content_hash(&mut hasher);
+        format!("{:?}", linter.options()).content_hash(&mut hasher);
+        format!("{:?}", linter.fix_only()).content_hash(&mut hasher);
+        linter.config.hash_into(&mut hasher);
+        format!("{version}-{:016x}", hasher.finish())
+    }
+
+    /// Loads the cache at `path`.
+    ///
+    /// Returns an empty cache if the file doesn't exist, cannot be parsed, or was written with a
+    /// different `key`.
+    pub fn load(path: &Path, key: String) -> Self {
+        let files = fs::read_to_string(path)
+            .ok()
+            .and_then(|json| serde_json::from_str::<CacheFile>(&json).ok())
+            .filter(|file| file.key == key)
+            .map(|file| file.files.into_iter().collect())
+            .unwrap_or_default();
+        Self { key, files, pending: FxHashMap::default() }
+    }
+
+    /// Removes files from `paths` which are unchanged since they were cached.
+    ///
+    /// Files that cannot be read are kept, so that the linter reports the error.
+    pub fn retain_changed(&mut self, cwd: &Path, paths: &mut Vec<Arc<OsStr>>) {
+        let hashes = paths
+            .par_iter()
+            .map(|path| {
+                let path = Path::new(path);
+                fs::read(path).ok().map(|bytes| (relative_path(cwd, path), hash(&bytes)))
+            })
+            .collect::<Vec<_>>();
+
+        let mut hashes = hashes.into_iter();
+        paths.retain(|_| {
+            let Some((file, hash)) = hashes.next().flatten() else { return true };
+            if self.files.get(&file) == Some(&hash) {
+                return false;
+            }
+            self.pending.insert(file, hash);
+            true
+        });
+    }
+
+    /// Marks the file `error` was reported for as having diagnostics, so it isn't cached.
+    pub fn record_diagnostic(&mut self, error: &Error) {
+        // `NamedSource` only exposes its name through the contents of a span.
+        let Some(source) = error.source_code() else { return };
+        let Ok(contents) = source.read_span(&(0, 0).into(), 0, 0) else { return };
+        if let Some(file) = contents.name() {
+            self.pending.remove(file);
+            self.files.remove(file);
+        }
+    }
+
+    /// Writes the cache to `path`, including files linted without diagnostics in this run.
+    ///
+    /// # Errors
+    ///
+    /// * The file cannot be written
+    pub fn save(mut self, path: &Path) -> io::Result<()> {
+        self.files.extend(self.pending);
+        let file = CacheFile { key: self.key, files: self.files.into_iter().collect() };
+        let mut json = serde_json::to_string(&file).map_err(io::Error::other)?;
+        json.push('\n');
+        fs::write(path, json)
+    }
+}
+
+/// Path as displayed in diagnostics, see `DiagnosticService::wrap_diagnostics`.
+fn relative_path(cwd: &Path, path: &Path) -> String {
+    path.strip_prefix(cwd).unwrap_or(path).to_string_lossy().cow_replace('\\', "/").into_owned()
+}
+
+fn hash(bytes: &[u8]) -> String {
+    let mut hasher = FingerprintHasher::default();
+    hasher.write(bytes);
+    format!("{:016x}", hasher.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{ffi::OsStr, fs, sync::Arc};
+
+    use oxc_diagnostics::{Error, NamedSource, OxcDiagnostic};
+    use oxc_span::Span;
+
+    use super::LintCache;
+
+    fn paths(dir: &std::path::Path, files: &[&str]) -> Vec<Arc<OsStr>> {
+        files.iter().map(|file| Arc::from(dir.join(file).as_os_str())).collect()
+    }
+
+    fn diagnostic(file: &str) -> Error {
+        OxcDiagnostic::warn("`debugger` statement is not allowed")
+            .with_label(Span::new(0, 9))
+            .with_source_code(Arc::new(NamedSource::new(file, "debugger;".to_string())))
+    }
+
+    #[test]
+    fn test_skip_unchanged_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join(".oxlintcache");
+        fs::write(dir.path().join("clean.js"), "foo();").unwrap();
+        fs::write(dir.path().join("dirty.js"), "debugger;").unwrap();
+
+        let mut cache = LintCache::load(&cache_path, "key".to_string());
+        let mut files = paths(dir.path(), &["clean.js", "dirty.js"]);
+        cache.retain_changed(dir.path(), &mut files);
+        assert_eq!(files.len(), 2);
+        cache.record_diagnostic(&diagnostic("dirty.js"));
+        cache.save(&cache_path).unwrap();
+
+        // Files with diagnostics are linted again.
+        let mut cache = LintCache::load(&cache_path, "key".to_string());
+        let mut files = paths(dir.path(), &["clean.js", "dirty.js"]);
+        cache.retain_changed(dir.path(), &mut files);
+        assert_eq!(files, paths(dir.path(), &["dirty.js"]));
+
+        // Changed files are linted again.
+        fs::write(dir.path().join("clean.js"), "bar();").unwrap();
+        let mut files = paths(dir.path(), &["clean.js"]);
+        cache.retain_changed(dir.path(), &mut files);
+        assert_eq!(files.len(), 1);
+    }
+
+    #[test]
+    fn test_key_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join(".oxlintcache");
+        fs::write(dir.path().join("clean.js"), "foo();").unwrap();
+
+        let mut cache = LintCache::load(&cache_path, "a".to_string());
+        cache.retain_changed(dir.path(), &mut paths(dir.path(), &["clean.js"]));
+        cache.save(&cache_path).unwrap();
+
+        let mut cache = LintCache::load(&cache_path, "b".to_string());
+        let mut files = paths(dir.path(), &["clean.js"]);
+        cache.retain_changed(dir.path(), &mut files);
+        assert_eq!(files.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_cache_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join(".oxlintcache");
+        fs::write(&cache_path, "not json").unwrap();
+        fs::write(dir.path().join("clean.js"), "foo();").unwrap();
+
+        let mut cache = LintCache::load(&cache_path, "key".to_string());
+        let mut files = paths(dir.path(), &["clean.js"]);
+        cache.retain_changed(dir.path(), &mut files);
+        assert_eq!(files.len(), 1);
+    }
+}
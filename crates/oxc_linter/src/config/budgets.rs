@@ -0,0 +1,155 @@
+use rustc_hash::FxHashMap;
+
+use oxc_diagnostics::{Error, Severity};
+
+use crate::{
+    RuleCategory,
+    context::plugin_name_to_prefix,
+    rules::{RULES, RuleEnum},
+};
+
+use super::rules::{parse_rule_key, transform_rule_and_plugin_name};
+
+/// Counts the warnings of the rules and categories which have a budget, given by the `budgets`
+/// field of the configuration file, e.g. `{ "budgets": { "no-explicit-any": 25, "style": 100 } }`.
+///
+/// A budget is exceeded when its rule, or the rules of its category, report more warnings than
+/// the budget allows. Budgets can be lowered over time to ratchet down existing violations.
+#[derive(Debug, Default)]
+pub struct LintBudgets {
+    budgets: Vec<Budget>,
+}
+
+#[derive(Debug)]
+struct Budget {
+    /// Key of the budget in the configuration file.
+    name: String,
+    target: BudgetTarget,
+    max_warnings: usize,
+    warnings: usize,
+}
+
+#[derive(Debug)]
+enum BudgetTarget {
+    Category(RuleCategory),
+    Rule { plugin_name: String, rule_name: String },
+}
+
+impl LintBudgets {
+    pub fn new(budgets: &FxHashMap<String, usize>) -> Self {
+        let mut budgets = budgets
+            .iter()
+            .map(|(name, &max_warnings)| {
+                let target = if let Ok(category) = RuleCategory::try_from(name.as_str()) {
+                    BudgetTarget::Category(category)
+                } else {
+                    let (plugin_name, rule_name) = parse_rule_key(name);
+                    let (rule_name, plugin_name) =
+                        transform_rule_and_plugin_name(&rule_name, &plugin_name);
+                    BudgetTarget::Rule {
+                        plugin_name: plugin_name.to_string(),
+                        rule_name: rule_name.to_string(),
+                    }
+                };
+                Budget { name: name.clone(), target, max_warnings, warnings: 0 }
+            })
+            .collect::<Vec<_>>();
+        budgets.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        Self { budgets }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.budgets.is_empty()
+    }
+
+    /// Counts `error` towards the budgets of its rule and category, if it is a warning.
+    pub fn record(&mut self, error: &Error) {
+        if self.budgets.is_empty() || error.severity() != Some(Severity::Warning) {
+            return;
+        }
+        let Some(code) = error.code().map(|code| code.to_string()) else { return };
+        let Some((scope, rule_name)) =
+            code.strip_suffix(')').and_then(|code| code.rsplit_once('('))
+        else {
+            return;
+        };
+        // Diagnostics of builtin rules are scoped by the plugin prefix, e.g. `typescript-eslint`.
+        // Diagnostics of JS plugins are scoped by the plugin name.
+        let rule = RULES.iter().find(|rule| {
+            rule.name() == rule_name && plugin_name_to_prefix(rule.plugin_name()) == scope
+        });
+        let plugin_name = rule.map_or(scope, |rule| rule.plugin_name());
+        let category = rule.map(RuleEnum::category);
+
+        for budget in &mut self.budgets {
+            let matches = match &budget.target {
+                BudgetTarget::Category(target) => category == Some(*target),
+                BudgetTarget::Rule { plugin_name: target_plugin, rule_name: target_rule } => {
+                    target_plugin == plugin_name && target_rule == rule_name
+                }
+            };
+            if matches {
+                budget.warnings += 1;
+            }
+        }
+    }
+
+    /// Budgets which were exceeded, by key in the configuration file, with the number of
+    /// warnings and the budget.
+    pub fn exceeded(&self) -> impl Iterator<Item = (&str, usize, usize)> {
+        self.budgets
+            .iter()
+            .filter(|budget| budget.warnings > budget.max_warnings)
+            .map(|budget| (budget.name.as_str(), budget.warnings, budget.max_warnings))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rustc_hash::FxHashMap;
+
+    use oxc_diagnostics::{Error, OxcDiagnostic};
+
+    use super::LintBudgets;
+
+    fn warning(scope: &'static str, rule_name: &'static str) -> Error {
+        Error::new(OxcDiagnostic::warn("problem").with_error_code(scope, rule_name))
+    }
+
+    #[test]
+    fn exceeded_budgets() {
+        let budgets = FxHashMap::from_iter([
+            ("no-debugger".to_string(), 1),
+            ("typescript/no-explicit-any".to_string(), 2),
+            ("style".to_string(), 0),
+        ]);
+        let mut budgets = LintBudgets::new(&budgets);
+
+        budgets.record(&warning("eslint", "no-debugger"));
+        budgets.record(&warning("eslint", "no-debugger"));
+        budgets.record(&warning("typescript-eslint", "no-explicit-any"));
+        budgets.record(&warning("typescript-eslint", "no-explicit-any"));
+        budgets.record(&Error::new(
+            OxcDiagnostic::error("problem").with_error_code("typescript-eslint", "no-explicit-any"),
+        ));
+
+        assert_eq!(budgets.exceeded().collect::<Vec<_>>(), vec![("no-debugger", 2, 1)]);
+
+        budgets.record(&warning("eslint", "func-style"));
+
+        assert_eq!(
+            budgets.exceeded().collect::<Vec<_>>(),
+            vec![("no-debugger", 2, 1), ("style", 1, 0)]
+        );
+    }
+
+    #[test]
+    fn js_plugin_rules() {
+        let budgets = FxHashMap::from_iter([("my-plugin/no-foo".to_string(), 0)]);
+        let mut budgets = LintBudgets::new(&budgets);
+
+        budgets.record(&warning("my-plugin", "no-foo"));
+
+        assert_eq!(budgets.exceeded().collect::<Vec<_>>(), vec![("my-plugin/no-foo", 1, 0)]);
+    }
+}
@@ -1,5 +1,6 @@
 use std::{
     borrow::Cow,
+    hash::{Hash, Hasher},
     ops::{Deref, DerefMut},
 };
 
@@ -33,6 +34,14 @@ impl OxlintCategories {
     }
 }
 
+impl Hash for OxlintCategories {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut categories = self.0.iter().collect::<Vec<_>>();
+        categories.sort_unstable_by_key(|(category, _)| category.as_str());
+        categories.hash(state);
+    }
+}
+
 impl JsonSchema for OxlintCategories {
     fn schema_id() -> Cow<'static, str> {
         Cow::Borrowed("OxlintCategories")
@@ -41,6 +41,10 @@ pub struct ConfigStoreBuilder {
     // Collect all `extends` file paths for the language server.
     // The server will tell the clients to watch for the extends files.
     pub extended_paths: Vec<PathBuf>,
+
+    // The names of the rules turned on by name in the config or the configs it extends, as
+    // opposed to through a category.
+    pub enabled_rule_names: FxHashSet<String>,
 }
 
 impl Default for ConfigStoreBuilder {
@@ -61,8 +65,17 @@ impl ConfigStoreBuilder {
         let categories: OxlintCategories = OxlintCategories::default();
         let overrides = OxlintOverrides::default();
         let extended_paths = Vec::new();
+        let enabled_rule_names = FxHashSet::default();
 
-        Self { rules, external_rules, config, categories, overrides, extended_paths }
+        Self {
+            rules,
+            external_rules,
+            config,
+            categories,
+            overrides,
+            extended_paths,
+            enabled_rule_names,
+        }
     }
 
     /// Warn on all rules in all plugins and categories, including those in `nursery`.
@@ -76,7 +89,16 @@ impl ConfigStoreBuilder {
         let rules = RULES.iter().map(|rule| (rule.clone(), AllowWarnDeny::Warn)).collect();
         let external_rules = FxHashMap::default();
         let extended_paths = Vec::new();
-        Self { rules, external_rules, config, categories, overrides, extended_paths }
+        let enabled_rule_names = FxHashSet::default();
+        Self {
+            rules,
+            external_rules,
+            config,
+            categories,
+            overrides,
+            extended_paths,
+            enabled_rule_names,
+        }
     }
 
     /// Create a [`ConfigStoreBuilder`] from a loaded or manually built [`Oxlintrc`].
@@ -149,6 +171,7 @@ impl ConfigStoreBuilder {
         }
 
         let (oxlintrc, extended_paths) = resolve_oxlintrc_config(oxlintrc)?;
+        let enabled_rule_names = oxlintrc.enabled_rule_names().map(str::to_string).collect();
 
         // Collect external plugins from both base config and overrides
         let mut external_plugins: FxHashSet<&ExternalPluginEntry> = FxHashSet::default();
@@ -218,6 +241,7 @@ impl ConfigStoreBuilder {
             categories,
             overrides: oxlintrc.overrides,
             extended_paths,
+            enabled_rule_names,
         };
 
         for filter in oxlintrc.categories.filters() {
@@ -227,15 +251,12 @@ impl ConfigStoreBuilder {
         {
             let all_rules = builder.get_all_rules();
 
-            oxlintrc
-                .rules
-                .override_rules(
-                    &mut builder.rules,
-                    &mut builder.external_rules,
-                    &all_rules,
-                    external_plugin_store,
-                )
-                .map_err(ConfigBuilderError::ExternalRuleLookupError)?;
+            oxlintrc.rules.override_rules(
+                &mut builder.rules,
+                &mut builder.external_rules,
+                &all_rules,
+                external_plugin_store,
+            )?;
         }
 
         Ok(builder)
@@ -280,9 +301,16 @@ impl ConfigStoreBuilder {
         self.config.plugins
     }
 
-    #[cfg(test)]
-    pub(crate) fn with_rule(mut self, rule: RuleEnum, severity: AllowWarnDeny) -> Self {
-        self.rules.insert(rule, severity);
+    /// Configure `rule` with `severity`, replacing its previous configuration. The rule is
+    /// removed if `severity` is [`AllowWarnDeny::Allow`].
+    ///
+    /// Like with [`with_filters`](ConfigStoreBuilder::with_filters), the plugin of the rule must
+    /// be enabled.
+    pub fn with_rule(mut self, rule: RuleEnum, severity: AllowWarnDeny) -> Self {
+        self.rules.remove(&rule);
+        if severity.is_warn_deny() {
+            self.rules.insert(rule, severity);
+        }
         self
     }
 
@@ -402,9 +430,7 @@ impl ConfigStoreBuilder {
         }
 
         let overrides = std::mem::take(&mut self.overrides);
-        let resolved_overrides = self
-            .resolve_overrides(overrides, external_plugin_store)
-            .map_err(ConfigBuilderError::ExternalRuleLookupError)?;
+        let resolved_overrides = self.resolve_overrides(overrides, external_plugin_store)?;
 
         let mut rules: Vec<_> = self
             .rules
@@ -432,7 +458,7 @@ impl ConfigStoreBuilder {
         &self,
         overrides: OxlintOverrides,
         external_plugin_store: &mut ExternalPluginStore,
-    ) -> Result<ResolvedOxlintOverrides, ExternalRuleLookupError> {
+    ) -> Result<ResolvedOxlintOverrides, ConfigBuilderError> {
         let resolved = overrides
             .into_iter()
             .map(|override_config| {
@@ -637,6 +663,11 @@ pub enum ConfigBuilderError {
         error: String,
     },
     ExternalRuleLookupError(ExternalRuleLookupError),
+    /// The options of a rule are not valid.
+    InvalidRuleConfiguration {
+        rule: String,
+        reason: String,
+    },
     NoExternalLinterConfigured {
         plugin_specifier: String,
     },
@@ -662,6 +693,9 @@ impl Display for ConfigBuilderError {
             ConfigBuilderError::InvalidConfigFile { file, reason } => {
                 write!(f, "invalid config file {file}: {reason}")
             }
+            ConfigBuilderError::InvalidRuleConfiguration { rule, reason } => {
+                write!(f, "invalid configuration of rule {rule}: {reason}")
+            }
             ConfigBuilderError::PluginLoadFailed { plugin_specifier, error } => {
                 write!(f, "Failed to load JS plugin: {plugin_specifier}\n  {error}")?;
                 Ok(())
@@ -1160,6 +1194,27 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_invalid_rule_configuration() {
+        let oxlintrc: Oxlintrc = serde_json::from_str(
+            r#"{
+                "rules": {
+                    "typescript/naming-convention": ["error", { "selector": "variable", "format": null, "filter": "[" }]
+                }
+            }"#,
+        )
+        .unwrap();
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let err =
+            ConfigStoreBuilder::from_oxlintrc(true, oxlintrc, None, &mut external_plugin_store)
+                .unwrap_err();
+        let ConfigBuilderError::InvalidRuleConfiguration { rule, reason } = err else {
+            panic!("expected an invalid rule configuration, got {err:?}");
+        };
+        assert_eq!(rule, "typescript/naming-convention");
+        assert!(reason.starts_with("invalid regex `[`"));
+    }
+
     #[test]
     fn test_extends_plugins() {
         // Test 1: Default plugins when none are specified
@@ -1,4 +1,5 @@
 use std::{
+    hash::{Hash, Hasher},
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -26,7 +27,26 @@ pub struct ResolvedLinterState {
     pub external_rules: Arc<[(ExternalRuleId, ExternalOptionsId, AllowWarnDeny)]>,
 }
 
-#[derive(Debug, Default, Clone)]
+impl Hash for ResolvedLinterState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_rules(&self.rules, state);
+        self.config.hash(state);
+        self.external_rules.hash(state);
+    }
+}
+
+/// Hashes `rules` including their options. [`RuleEnum`]'s own `Hash` only identifies the rule.
+fn hash_rules<H: Hasher>(rules: &[(RuleEnum, AllowWarnDeny)], state: &mut H) {
+    state.write_usize(rules.len());
+    for (rule, severity) in rules {
+        rule.hash(state);
+        // Rule options don't implement `Hash`.
+        format!("{rule:?}").hash(state);
+        severity.hash(state);
+    }
+}
+
+#[derive(Debug, Default, Clone, Hash)]
 pub struct ResolvedOxlintOverrides(Vec<ResolvedOxlintOverride>);
 
 impl ResolvedOxlintOverrides {
@@ -43,7 +63,7 @@ impl ResolvedOxlintOverrides {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Hash)]
 pub struct ResolvedOxlintOverride {
     pub files: GlobSet,
     pub env: Option<OxlintEnv>,
@@ -58,6 +78,13 @@ pub struct ResolvedOxlintOverrideRules {
     pub(crate) external_rules: Vec<(ExternalRuleId, ExternalOptionsId, AllowWarnDeny)>,
 }
 
+impl Hash for ResolvedOxlintOverrideRules {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_rules(&self.builtin_rules, state);
+        self.external_rules.hash(state);
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Config {
     /// The basic linter state for this configuration.
@@ -77,6 +104,15 @@ pub struct Config {
     pub(crate) overrides: ResolvedOxlintOverrides,
 }
 
+impl Hash for Config {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.base.hash(state);
+        hash_rules(&self.base_rules, state);
+        self.categories.hash(state);
+        self.overrides.hash(state);
+    }
+}
+
 impl Config {
     pub fn new(
         rules: Vec<(RuleEnum, AllowWarnDeny)>,
@@ -295,6 +331,15 @@ impl ConfigStore {
         }
     }
 
+    /// Hashes the resolved base and nested configurations, so that results cached with one
+    /// configuration aren't reused with another.
+    pub(crate) fn hash_into<H: Hasher>(&self, hasher: &mut H) {
+        self.base.hash(hasher);
+        let mut nested_configs = self.nested_configs.iter().collect::<Vec<_>>();
+        nested_configs.sort_unstable_by_key(|(path, _)| *path);
+        nested_configs.hash(hasher);
+    }
+
     /// Returns the number of rules, optionally filtering out tsgolint rules if type_aware_enabled is false.
     pub fn number_of_rules(&self, type_aware_enabled: bool) -> Option<usize> {
         if !self.nested_configs.is_empty() {
@@ -312,10 +357,42 @@ impl ConfigStore {
         &self.base.base.rules
     }
 
+    /// The names of the type-aware rules configured by any configuration or override, e.g.
+    /// `typescript/no-floating-promises`. These rules only run with tsgolint.
+    pub fn configured_type_aware_rule_names(&self) -> Vec<String> {
+        let configs = std::iter::once(&self.base).chain(self.nested_configs.values());
+        let base_rules = configs.clone().flat_map(|config| config.base.rules.iter());
+        let override_rules = configs.flat_map(|config| {
+            config.overrides.iter().flat_map(|override_config| &override_config.rules.builtin_rules)
+        });
+        let mut names = base_rules
+            .chain(override_rules)
+            .filter(|(rule, severity)| rule.is_tsgolint_rule() && severity.is_warn_deny())
+            .map(|(rule, _)| format!("{}/{}", rule.plugin_name(), rule.name()))
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+
     pub fn plugins(&self) -> LintPlugins {
         self.base.base.config.plugins
     }
 
+    /// The import aliases of the root configuration, with paths resolved from the directory of
+    /// the configuration file, or from `cwd` without one.
+    pub(crate) fn import_aliases(&self, cwd: &Path) -> Vec<(String, Vec<PathBuf>)> {
+        let config = &self.base.base.config;
+        let dir = config.path.as_deref().and_then(Path::parent).unwrap_or(cwd);
+        config
+            .settings
+            .import
+            .alias
+            .iter()
+            .map(|(name, paths)| (name.clone(), paths.iter().map(|path| dir.join(path)).collect()))
+            .collect()
+    }
+
     pub(crate) fn get_related_config(&self, path: &Path) -> &Config {
         if self.nested_configs.is_empty() {
             &self.base
@@ -1,4 +1,7 @@
-use std::{borrow::Borrow, hash::Hash};
+use std::{
+    borrow::Borrow,
+    hash::{Hash, Hasher},
+};
 
 use rustc_hash::FxHashMap;
 use schemars::JsonSchema;
@@ -50,6 +53,14 @@ impl OxlintEnv {
     }
 }
 
+impl Hash for OxlintEnv {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut envs = self.0.iter().collect::<Vec<_>>();
+        envs.sort_unstable_by_key(|(env, _)| *env);
+        envs.hash(state);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use serde::Deserialize;
@@ -0,0 +1,762 @@
+use std::path::Path;
+
+use javascript_globals::GLOBALS;
+use serde::Deserialize;
+use serde_json::{Map, Number, Value};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    Argument, ArrayExpressionElement, AssignmentTarget, Expression, MemberExpression,
+    ObjectExpression, ObjectPropertyKind, Program, PropertyKind, Statement, UnaryOperator,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+
+use crate::{LintPlugins, rules::RULES, utils::read_to_string};
+
+use super::{
+    oxlintrc::Oxlintrc,
+    rules::{parse_rule_key, transform_rule_and_plugin_name, unalias_plugin_name},
+};
+
+/// An ESLint flat config file (`eslint.config.js`), converted into an [`Oxlintrc`].
+///
+/// The config file is never executed. Instead, it is parsed and the exported config array is
+/// statically analyzed. Entries which are plain literals (`files`, `ignores`, `rules`,
+/// `languageOptions.globals`, `settings`) are mapped onto their oxlint equivalents. Everything
+/// else (shared configs imported from packages, computed values, unknown rules and plugins) is
+/// reported in [`EslintFlatConfig::unmapped`] so users know what was left behind.
+#[derive(Debug)]
+pub struct EslintFlatConfig {
+    /// The equivalent oxlint configuration.
+    pub oxlintrc: Oxlintrc,
+    /// Warnings for config entries which could not be mapped onto oxlint,
+    /// labeled with their location in [`EslintFlatConfig::source_text`].
+    pub unmapped: Vec<OxcDiagnostic>,
+    /// Source text of the config file.
+    pub source_text: String,
+}
+
+impl EslintFlatConfig {
+    /// File names ESLint looks for when searching for a flat config.
+    pub const FILE_NAMES: [&'static str; 6] = [
+        "eslint.config.js",
+        "eslint.config.mjs",
+        "eslint.config.cjs",
+        "eslint.config.ts",
+        "eslint.config.mts",
+        "eslint.config.cts",
+    ];
+
+    /// Returns `true` if `path` points to an ESLint flat config file.
+    pub fn is_flat_config_path(path: &Path) -> bool {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| Self::FILE_NAMES.contains(&name))
+    }
+
+    /// # Errors
+    ///
+    /// * The file cannot be read
+    /// * The file contains syntax errors
+    /// * The file has no default export (or `module.exports` assignment)
+    pub fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
+        let source_text = read_to_string(path).map_err(|e| {
+            OxcDiagnostic::error(format!(
+                "Failed to read ESLint config {} with error {e:?}",
+                path.display()
+            ))
+        })?;
+        Self::from_source(path, source_text)
+    }
+
+    /// # Errors
+    ///
+    /// * The source text contains syntax errors
+    /// * The source text has no default export (or `module.exports` assignment)
+    pub fn from_source(path: &Path, source_text: String) -> Result<Self, OxcDiagnostic> {
+        let source_type = SourceType::from_path(path).unwrap_or_else(|_| SourceType::mjs());
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        if ret.panicked || !ret.errors.is_empty() {
+            return Err(OxcDiagnostic::error(format!(
+                "Failed to parse ESLint config {}",
+                path.display()
+            )));
+        }
+
+        let Some(exported) = find_exported_config(&ret.program) else {
+            return Err(OxcDiagnostic::error(format!(
+                "Failed to find the exported config array in ESLint config {}",
+                path.display()
+            ))
+            .with_help("Export the config array with `export default [...]`"));
+        };
+
+        let mut converter = FlatConfigConverter::new(&ret.program, &source_text);
+        converter.convert_config_array(exported, 0);
+        let (json, plugins, unmapped) = converter.finish();
+
+        let mut oxlintrc = Oxlintrc::deserialize(&json).map_err(|err| {
+            OxcDiagnostic::error(format!(
+                "Failed to convert ESLint config {} with error {err}",
+                path.display()
+            ))
+        })?;
+        oxlintrc.plugins = Some(LintPlugins::default() | plugins);
+        oxlintrc.path = path.to_path_buf();
+
+        Ok(Self { oxlintrc, unmapped, source_text })
+    }
+}
+
+/// Identifiers referring to other top-level declarations are followed at most this many times,
+/// which guards against cycles like `const a = [...b]; const b = [...a];`.
+const MAX_REFERENCE_DEPTH: u8 = 8;
+
+/// Keys of `languageOptions` which oxlint infers from the file itself, and so can be dropped
+/// without losing behavior.
+const INFERRED_LANGUAGE_OPTIONS: [&str; 4] =
+    ["ecmaVersion", "sourceType", "parser", "parserOptions"];
+
+/// Finds the expression exported by `export default <expr>` or `module.exports = <expr>`.
+pub(super) fn find_exported_config<'a, 'b>(program: &'b Program<'a>) -> Option<&'b Expression<'a>> {
+    program.body.iter().find_map(|stmt| match stmt {
+        Statement::ExportDefaultDeclaration(decl) => decl.declaration.as_expression(),
+        Statement::ExpressionStatement(stmt) => {
+            let Expression::AssignmentExpression(assign) = &stmt.expression else {
+                return None;
+            };
+            let AssignmentTarget::StaticMemberExpression(member) = &assign.left else {
+                return None;
+            };
+            (member.object.is_specific_id("module") && member.property.name == "exports")
+                .then_some(&assign.right)
+        }
+        _ => None,
+    })
+}
+
+struct FlatConfigConverter<'a, 'b> {
+    program: &'b Program<'a>,
+    source_text: &'b str,
+    /// Top-level `rules`, `globals`, `env` and `settings`.
+    base: Map<String, Value>,
+    overrides: Vec<Value>,
+    ignore_patterns: Vec<String>,
+    plugins: LintPlugins,
+    unmapped: Vec<OxcDiagnostic>,
+}
+
+impl<'a, 'b> FlatConfigConverter<'a, 'b> {
+    fn new(program: &'b Program<'a>, source_text: &'b str) -> Self {
+        Self {
+            program,
+            source_text,
+            base: Map::default(),
+            overrides: vec![],
+            ignore_patterns: vec![],
+            plugins: LintPlugins::empty(),
+            unmapped: vec![],
+        }
+    }
+
+    fn finish(mut self) -> (Value, LintPlugins, Vec<OxcDiagnostic>) {
+        self.base.insert("overrides".to_string(), Value::Array(self.overrides));
+        self.base.insert(
+            "ignorePatterns".to_string(),
+            Value::Array(self.ignore_patterns.into_iter().map(Value::String).collect()),
+        );
+        (Value::Object(self.base), self.plugins, self.unmapped)
+    }
+
+    fn unmapped(&mut self, span: Span, message: String) {
+        self.unmapped.push(OxcDiagnostic::warn(message).with_label(span));
+    }
+
+    /// Looks up the initializer of a top-level `const`/`let`/`var` declaration named `name`.
+    fn resolve_reference(&self, name: &str) -> Option<&'b Expression<'a>> {
+        self.program.body.iter().find_map(|stmt| {
+            let decl = match stmt {
+                Statement::VariableDeclaration(decl) => decl,
+                Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                    Some(oxc_ast::ast::Declaration::VariableDeclaration(decl)) => decl,
+                    _ => return None,
+                },
+                _ => return None,
+            };
+            decl.declarations
+                .iter()
+                .find(|declarator| declarator.id.get_identifier_name().is_some_and(|n| n == name))
+                .and_then(|declarator| declarator.init.as_ref())
+        })
+    }
+
+    /// Converts an expression which evaluates to a config array, or a single config object.
+    fn convert_config_array(&mut self, expr: &'b Expression<'a>, depth: u8) {
+        match expr.get_inner_expression() {
+            Expression::ArrayExpression(array) => {
+                for element in &array.elements {
+                    match element {
+                        ArrayExpressionElement::SpreadElement(spread) => {
+                            self.convert_config_array(&spread.argument, depth);
+                        }
+                        ArrayExpressionElement::Elision(_) => {}
+                        _ => self.convert_config_array(element.to_expression(), depth),
+                    }
+                }
+            }
+            // `defineConfig([...])`, `tseslint.config(...)`
+            Expression::CallExpression(call)
+                if call.callee.is_specific_id("defineConfig")
+                    || call
+                        .callee
+                        .as_member_expression()
+                        .and_then(MemberExpression::static_property_name)
+                        .is_some_and(|name| name == "config") =>
+            {
+                for argument in &call.arguments {
+                    match argument {
+                        Argument::SpreadElement(spread) => {
+                            self.convert_config_array(&spread.argument, depth);
+                        }
+                        _ => self.convert_config_array(argument.to_expression(), depth),
+                    }
+                }
+            }
+            Expression::Identifier(ident) if depth < MAX_REFERENCE_DEPTH => {
+                if let Some(init) = self.resolve_reference(&ident.name) {
+                    self.convert_config_array(init, depth + 1);
+                } else {
+                    self.unmapped_config(ident.span);
+                }
+            }
+            Expression::ObjectExpression(object) => self.convert_config_object(object),
+            expr => self.unmapped_config(expr.span()),
+        }
+    }
+
+    fn unmapped_config(&mut self, span: Span) {
+        let text = span.source_text(self.source_text);
+        self.unmapped(span, format!("Config `{text}` cannot be statically mapped to oxlint"));
+    }
+
+    fn convert_config_object(&mut self, object: &'b ObjectExpression<'a>) {
+        let mut files = None;
+        let mut ignores = None;
+        let mut has_other_keys = false;
+        let mut entry = Map::default();
+
+        for property in &object.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else {
+                self.unmapped(
+                    property.span(),
+                    "Spread properties in a config object cannot be statically mapped to oxlint"
+                        .to_string(),
+                );
+                continue;
+            };
+            let Some(key) = property.key.static_name() else {
+                self.unmapped(
+                    property.key.span(),
+                    "Computed config keys cannot be statically mapped to oxlint".to_string(),
+                );
+                continue;
+            };
+            let value = property.value.get_inner_expression();
+            match key.as_ref() {
+                "name" => {}
+                "files" => {
+                    files = self.string_array(value, "files");
+                    has_other_keys = true;
+                }
+                "ignores" => {
+                    ignores = self.string_array(value, "ignores").map(|v| (v, value.span()));
+                }
+                "rules" => {
+                    has_other_keys = true;
+                    if let Some(rules) = self.convert_rules(value) {
+                        entry.insert("rules".to_string(), Value::Object(rules));
+                    }
+                }
+                "plugins" => {
+                    has_other_keys = true;
+                    self.convert_plugins(value);
+                }
+                "languageOptions" => {
+                    has_other_keys = true;
+                    self.convert_language_options(value, &mut entry);
+                }
+                "settings" => {
+                    has_other_keys = true;
+                    match to_json(value) {
+                        Some(settings @ Value::Object(_)) => {
+                            entry.insert("settings".to_string(), settings);
+                        }
+                        _ => self.unmapped(
+                            value.span(),
+                            "`settings` must be an object literal to be mapped to oxlint"
+                                .to_string(),
+                        ),
+                    }
+                }
+                _ => {
+                    has_other_keys = true;
+                    self.unmapped(property.span, format!("`{key}` has no oxlint equivalent"));
+                }
+            }
+        }
+
+        if let Some((ignores, span)) = ignores {
+            if has_other_keys {
+                self.unmapped(span, "`ignores` is only supported in config objects containing no other keys (global ignores)".to_string());
+            } else {
+                self.ignore_patterns.extend(ignores);
+                return;
+            }
+        }
+
+        if let Some(files) = files {
+            if entry.contains_key("settings") {
+                entry.remove("settings");
+                self.unmapped(
+                    object.span,
+                    "`settings` is not supported in config objects with `files`".to_string(),
+                );
+            }
+            entry.insert(
+                "files".to_string(),
+                Value::Array(files.into_iter().map(Value::String).collect()),
+            );
+            self.overrides.push(Value::Object(entry));
+        } else if !self.overrides.is_empty() {
+            // Later config objects take precedence over earlier ones, but oxlint always applies
+            // overrides on top of the base config. Preserve ordering by matching all files.
+            if entry.remove("settings").is_some() {
+                self.unmapped(
+                    object.span,
+                    "`settings` is not supported in config objects following config objects with `files`"
+                        .to_string(),
+                );
+            }
+            entry
+                .insert("files".to_string(), Value::Array(vec![Value::String("**/*".to_string())]));
+            self.overrides.push(Value::Object(entry));
+        } else {
+            for (key, value) in entry {
+                match (self.base.get_mut(&key), value) {
+                    (Some(Value::Object(existing)), Value::Object(value)) => existing.extend(value),
+                    (_, value) => {
+                        self.base.insert(key, value);
+                    }
+                }
+            }
+        }
+    }
+
+    fn string_array(&mut self, expr: &'b Expression<'a>, key: &str) -> Option<Vec<String>> {
+        match to_json(expr) {
+            Some(Value::Array(values)) if values.iter().all(Value::is_string) => Some(
+                values
+                    .into_iter()
+                    .filter_map(|value| match value {
+                        Value::String(s) => Some(s),
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+            _ => {
+                self.unmapped(
+                    expr.span(),
+                    format!("`{key}` must be an array of string literals to be mapped to oxlint"),
+                );
+                None
+            }
+        }
+    }
+
+    fn convert_rules(&mut self, expr: &'b Expression<'a>) -> Option<Map<String, Value>> {
+        let Expression::ObjectExpression(object) = expr else {
+            self.unmapped_config(expr.span());
+            return None;
+        };
+
+        let mut rules = Map::default();
+        for property in &object.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else {
+                self.unmapped_config(property.span());
+                continue;
+            };
+            let Some(key) = property.key.static_name() else {
+                self.unmapped_config(property.key.span());
+                continue;
+            };
+            let Some(value) = to_json(&property.value) else {
+                self.unmapped(
+                    property.value.span(),
+                    format!("Configuration of rule `{key}` cannot be statically mapped to oxlint"),
+                );
+                continue;
+            };
+
+            match map_rule(&key, &value) {
+                RuleMapping::Implemented(plugin) => {
+                    if let Some(plugin) = plugin {
+                        self.plugins |= plugin;
+                    }
+                    rules.insert(key.into_owned(), value);
+                }
+                RuleMapping::Dropped => {}
+                RuleMapping::Unimplemented => self
+                    .unmapped(property.span, format!("Rule `{key}` is not implemented in oxlint")),
+            }
+        }
+        Some(rules)
+    }
+
+    fn convert_plugins(&mut self, expr: &'b Expression<'a>) {
+        let Expression::ObjectExpression(object) = expr else {
+            self.unmapped_config(expr.span());
+            return;
+        };
+        for property in &object.properties {
+            let name = match property {
+                ObjectPropertyKind::ObjectProperty(property) => property.key.static_name(),
+                ObjectPropertyKind::SpreadProperty(_) => None,
+            };
+            let Some(name) = name else {
+                self.unmapped_config(property.span());
+                continue;
+            };
+            let (plugin_name, _) = unalias_plugin_name(&name, "");
+            match LintPlugins::try_from(plugin_name.as_str()) {
+                Ok(plugin) => self.plugins |= plugin,
+                Err(()) => self.unmapped(
+                    property.span(),
+                    format!("Plugin `{name}` is not implemented in oxlint"),
+                ),
+            }
+        }
+    }
+
+    fn convert_language_options(
+        &mut self,
+        expr: &'b Expression<'a>,
+        entry: &mut Map<String, Value>,
+    ) {
+        let Expression::ObjectExpression(object) = expr else {
+            self.unmapped_config(expr.span());
+            return;
+        };
+        for property in &object.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else {
+                self.unmapped_config(property.span());
+                continue;
+            };
+            match property.key.static_name().as_deref() {
+                Some("globals") => {
+                    self.convert_globals(property.value.get_inner_expression(), entry);
+                }
+                Some(key) if INFERRED_LANGUAGE_OPTIONS.contains(&key) => {}
+                Some(key) => self.unmapped(
+                    property.span,
+                    format!("`languageOptions.{key}` has no oxlint equivalent"),
+                ),
+                None => self.unmapped_config(property.key.span()),
+            }
+        }
+    }
+
+    /// Maps `globals: { ...globals.browser, foo: "readonly" }` onto oxlint's `env` and `globals`.
+    fn convert_globals(&mut self, expr: &'b Expression<'a>, entry: &mut Map<String, Value>) {
+        let Expression::ObjectExpression(object) = expr else {
+            self.unmapped_config(expr.span());
+            return;
+        };
+        let mut env = Map::default();
+        let mut globals = Map::default();
+        for property in &object.properties {
+            match property {
+                ObjectPropertyKind::SpreadProperty(spread) => {
+                    // `...globals.browser` from the `globals` package
+                    let env_name = spread
+                        .argument
+                        .get_inner_expression()
+                        .as_member_expression()
+                        .and_then(MemberExpression::static_property_name)
+                        .filter(|name| GLOBALS.contains_key(name));
+                    if let Some(env_name) = env_name {
+                        env.insert(env_name.to_string(), Value::Bool(true));
+                    } else {
+                        self.unmapped_config(spread.span);
+                    }
+                }
+                ObjectPropertyKind::ObjectProperty(property) => {
+                    let key = property.key.static_name();
+                    match (key, to_json(&property.value)) {
+                        (Some(key), Some(value @ (Value::String(_) | Value::Bool(_)))) => {
+                            globals.insert(key.into_owned(), value);
+                        }
+                        _ => self.unmapped_config(property.span),
+                    }
+                }
+            }
+        }
+        if !env.is_empty() {
+            entry.insert("env".to_string(), Value::Object(env));
+        }
+        if !globals.is_empty() {
+            entry.insert("globals".to_string(), Value::Object(globals));
+        }
+    }
+}
+
+/// How a rule configured in an ESLint config carries over to oxlint.
+pub(super) enum RuleMapping {
+    /// The rule is implemented in oxlint. Contains the plugin to enable if the rule is turned on.
+    Implemented(Option<LintPlugins>),
+    /// The rule is not implemented in oxlint, but it is turned off, so dropping it loses nothing.
+    Dropped,
+    Unimplemented,
+}
+
+pub(super) fn map_rule(key: &str, value: &Value) -> RuleMapping {
+    let is_off = match value {
+        Value::Array(values) => values.first(),
+        value => Some(value),
+    }
+    .is_some_and(|severity| {
+        matches!(severity.as_str(), Some("off")) || severity.as_u64() == Some(0)
+    });
+
+    let (plugin_name, rule_name) = parse_rule_key(key);
+    let (builtin_rule_name, builtin_plugin_name) =
+        transform_rule_and_plugin_name(&rule_name, &plugin_name);
+    let is_implemented = RULES
+        .iter()
+        .any(|rule| rule.name() == builtin_rule_name && rule.plugin_name() == builtin_plugin_name);
+    if !is_implemented {
+        return if is_off { RuleMapping::Dropped } else { RuleMapping::Unimplemented };
+    }
+    let plugin = if is_off { None } else { LintPlugins::try_from(plugin_name.as_str()).ok() };
+    RuleMapping::Implemented(plugin)
+}
+
+/// Evaluates a literal expression (including arrays and objects made of literals) into JSON.
+///
+/// Returns `None` if any part of the expression is not a literal.
+pub(super) fn to_json(expr: &Expression<'_>) -> Option<Value> {
+    match expr.get_inner_expression() {
+        Expression::StringLiteral(lit) => Some(Value::String(lit.value.to_string())),
+        Expression::TemplateLiteral(lit) => {
+            lit.single_quasi().map(|s| Value::String(s.to_string()))
+        }
+        Expression::BooleanLiteral(lit) => Some(Value::Bool(lit.value)),
+        Expression::NullLiteral(_) => Some(Value::Null),
+        Expression::NumericLiteral(lit) => number_to_json(lit.value),
+        Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::UnaryNegation => {
+            match unary.argument.get_inner_expression() {
+                Expression::NumericLiteral(lit) => number_to_json(-lit.value),
+                _ => None,
+            }
+        }
+        Expression::ArrayExpression(array) => array
+            .elements
+            .iter()
+            .map(|element| element.as_expression().and_then(to_json))
+            .collect::<Option<Vec<_>>>()
+            .map(Value::Array),
+        Expression::ObjectExpression(object) => object
+            .properties
+            .iter()
+            .map(|property| match property {
+                ObjectPropertyKind::ObjectProperty(property)
+                    if property.kind == PropertyKind::Init && !property.method =>
+                {
+                    let key = property.key.static_name()?;
+                    Some((key.into_owned(), to_json(&property.value)?))
+                }
+                _ => None,
+            })
+            .collect::<Option<Map<_, _>>>()
+            .map(Value::Object),
+        _ => None,
+    }
+}
+
+#[expect(clippy::cast_possible_truncation)]
+fn number_to_json(value: f64) -> Option<Value> {
+    if value.fract() == 0.0 && value.abs() < 2f64.powi(53) {
+        Some(Value::Number(Number::from(value as i64)))
+    } else {
+        Number::from_f64(value).map(Value::Number)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use crate::{AllowWarnDeny, LintPlugins};
+
+    use super::EslintFlatConfig;
+
+    fn convert(source_text: &str) -> EslintFlatConfig {
+        EslintFlatConfig::from_source(Path::new("eslint.config.mjs"), source_text.to_string())
+            .unwrap()
+    }
+
+    fn unmapped_messages(config: &EslintFlatConfig) -> Vec<String> {
+        config.unmapped.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_is_flat_config_path() {
+        assert!(EslintFlatConfig::is_flat_config_path(Path::new("eslint.config.js")));
+        assert!(EslintFlatConfig::is_flat_config_path(Path::new("foo/eslint.config.mts")));
+        assert!(!EslintFlatConfig::is_flat_config_path(Path::new(".oxlintrc.json")));
+        assert!(!EslintFlatConfig::is_flat_config_path(Path::new("eslint.config.json")));
+    }
+
+    #[test]
+    fn test_rules() {
+        let config = convert(
+            r#"
+            export default [
+              {
+                rules: {
+                  "no-debugger": "error",
+                  eqeqeq: ["warn", "smart"],
+                  "@typescript-eslint/no-explicit-any": 2,
+                  "react/jsx-key": "error",
+                  "no-undef": "off",
+                },
+              },
+            ];
+            "#,
+        );
+        assert!(config.unmapped.is_empty());
+
+        let rules = &config.oxlintrc.rules.rules;
+        assert_eq!(rules.len(), 5);
+        assert_eq!(rules[0].rule_name, "no-debugger");
+        assert_eq!(rules[0].severity, AllowWarnDeny::Deny);
+        assert_eq!(rules[1].rule_name, "eqeqeq");
+        assert_eq!(rules[1].config.as_slice(), &[serde_json::json!("smart")]);
+        assert_eq!(rules[2].plugin_name, "typescript");
+        assert_eq!(rules[3].plugin_name, "react");
+        assert_eq!(rules[4].severity, AllowWarnDeny::Allow);
+
+        let plugins = config.oxlintrc.plugins.unwrap();
+        assert!(plugins.contains(LintPlugins::REACT | LintPlugins::TYPESCRIPT));
+        assert!(!plugins.contains(LintPlugins::IMPORT));
+    }
+
+    #[test]
+    fn test_files_and_ignores() {
+        let config = convert(
+            r#"
+            import { defineConfig } from "eslint/config";
+
+            const testConfig = { files: ["**/*.test.js"], rules: { "no-console": "off" } };
+
+            export default defineConfig([
+              { ignores: ["dist/**"] },
+              { rules: { "no-console": "error" } },
+              testConfig,
+            ]);
+            "#,
+        );
+        assert!(config.unmapped.is_empty());
+        assert_eq!(config.oxlintrc.ignore_patterns, vec!["dist/**".to_string()]);
+        assert_eq!(config.oxlintrc.rules.rules.len(), 1);
+        assert_eq!(config.oxlintrc.overrides.len(), 1);
+        assert!(config.oxlintrc.overrides[0].files.is_match("src/foo.test.js"));
+    }
+
+    #[test]
+    fn test_globals() {
+        let config = convert(
+            r#"
+            import globals from "globals";
+
+            module.exports = [
+              {
+                languageOptions: {
+                  ecmaVersion: 2022,
+                  globals: { ...globals.browser, myGlobal: "readonly" },
+                },
+              },
+            ];
+            "#,
+        );
+        assert!(config.unmapped.is_empty());
+        assert!(config.oxlintrc.env.contains("browser"));
+        assert!(config.oxlintrc.globals.is_enabled("myGlobal"));
+    }
+
+    #[test]
+    fn test_unmapped() {
+        let config = convert(
+            r#"
+            import js from "@eslint/js";
+            import foo from "eslint-plugin-foo";
+
+            export default [
+              js.configs.recommended,
+              {
+                plugins: { foo },
+                linterOptions: { reportUnusedDisableDirectives: true },
+                rules: {
+                  "foo/bar": "error",
+                  "not-a-real-rule": "off",
+                  "no-console": ["error", { allow: getAllowed() }],
+                },
+              },
+            ];
+            "#,
+        );
+        assert_eq!(
+            unmapped_messages(&config),
+            vec![
+                "Config `js.configs.recommended` cannot be statically mapped to oxlint",
+                "Plugin `foo` is not implemented in oxlint",
+                "`linterOptions` has no oxlint equivalent",
+                "Rule `foo/bar` is not implemented in oxlint",
+                "Configuration of rule `no-console` cannot be statically mapped to oxlint",
+            ]
+        );
+        assert!(config.oxlintrc.rules.is_empty());
+    }
+
+    #[test]
+    fn test_settings_in_overrides() {
+        let config = convert(
+            r#"
+            export default [
+              { settings: { react: { version: "18" } } },
+              { files: ["**/*.test.js"], settings: { jest: { version: 29 } } },
+              { settings: { next: { rootDir: "app" } } },
+            ];
+            "#,
+        );
+        assert_eq!(
+            unmapped_messages(&config),
+            vec![
+                "`settings` is not supported in config objects with `files`",
+                "`settings` is not supported in config objects following config objects with `files`",
+            ]
+        );
+        assert_eq!(config.oxlintrc.overrides.len(), 2);
+    }
+
+    #[test]
+    fn test_no_export() {
+        let result = EslintFlatConfig::from_source(
+            Path::new("eslint.config.js"),
+            "const config = [];".to_string(),
+        );
+        assert!(result.is_err());
+    }
+}
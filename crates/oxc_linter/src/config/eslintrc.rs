@@ -0,0 +1,337 @@
+use std::path::Path;
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use oxc_allocator::Allocator;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+use crate::{LintPlugins, utils::read_to_string};
+
+use super::{
+    eslint_flat_config::{RuleMapping, find_exported_config, map_rule, to_json},
+    oxlintrc::Oxlintrc,
+    rules::unalias_plugin_name,
+};
+
+/// A legacy ESLint config file (`.eslintrc.json`, `.eslintrc.js`, ...), converted into an
+/// [`Oxlintrc`].
+///
+/// JavaScript config files are never executed, so they must assign an object literal to
+/// `module.exports`. Entries without an oxlint equivalent (`extends`, unknown rules and plugins,
+/// ...) are reported in [`EslintrcConfig::unmapped`] so users know what was left behind.
+#[derive(Debug)]
+pub struct EslintrcConfig {
+    /// The equivalent oxlint configuration.
+    pub oxlintrc: Oxlintrc,
+    /// Warnings for config entries which could not be mapped onto oxlint.
+    pub unmapped: Vec<OxcDiagnostic>,
+}
+
+impl EslintrcConfig {
+    /// File names ESLint looks for when searching for a legacy config, in order of precedence.
+    pub const FILE_NAMES: [&'static str; 6] = [
+        ".eslintrc.js",
+        ".eslintrc.cjs",
+        ".eslintrc.yaml",
+        ".eslintrc.yml",
+        ".eslintrc.json",
+        ".eslintrc",
+    ];
+
+    /// Returns `true` if `path` points to a legacy ESLint config file.
+    pub fn is_eslintrc_path(path: &Path) -> bool {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| Self::FILE_NAMES.contains(&name))
+    }
+
+    /// # Errors
+    ///
+    /// * The file cannot be read
+    /// * See [`EslintrcConfig::from_source`]
+    pub fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
+        let source_text = read_to_string(path).map_err(|e| {
+            OxcDiagnostic::error(format!(
+                "Failed to read ESLint config {} with error {e:?}",
+                path.display()
+            ))
+        })?;
+        Self::from_source(path, source_text)
+    }
+
+    /// # Errors
+    ///
+    /// * The config is YAML, which is not supported
+    /// * The source text is not valid JSON, or JavaScript assigning an object literal to
+    ///   `module.exports`
+    pub fn from_source(path: &Path, mut source_text: String) -> Result<Self, OxcDiagnostic> {
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        let config = match extension {
+            Some("yaml" | "yml") => {
+                return Err(OxcDiagnostic::error(format!(
+                    "YAML ESLint config {} is not supported",
+                    path.display()
+                ))
+                .with_help("Convert the config to `.eslintrc.json`"));
+            }
+            Some("js" | "cjs") => {
+                let allocator = Allocator::default();
+                let ret = Parser::new(&allocator, &source_text, SourceType::cjs()).parse();
+                if ret.panicked || !ret.errors.is_empty() {
+                    return Err(OxcDiagnostic::error(format!(
+                        "Failed to parse ESLint config {}",
+                        path.display()
+                    )));
+                }
+                find_exported_config(&ret.program).and_then(to_json)
+            }
+            _ => {
+                json_strip_comments::strip(&mut source_text).map_err(|err| {
+                    OxcDiagnostic::error(format!(
+                        "Failed to parse ESLint config {} with error {err:?}",
+                        path.display()
+                    ))
+                })?;
+                serde_json::from_str(&source_text).ok()
+            }
+        };
+        let Some(Value::Object(config)) = config else {
+            return Err(OxcDiagnostic::error(format!(
+                "Failed to read the config object from ESLint config {}",
+                path.display()
+            ))
+            .with_help("The config must be a JSON object, or an object literal assigned to `module.exports`"));
+        };
+
+        let mut converter = EslintrcConverter { plugins: LintPlugins::empty(), unmapped: vec![] };
+        let json = converter.convert_config(config, false);
+
+        let mut oxlintrc = Oxlintrc::deserialize(&Value::Object(json)).map_err(|err| {
+            OxcDiagnostic::error(format!(
+                "Failed to convert ESLint config {} with error {err}",
+                path.display()
+            ))
+        })?;
+        oxlintrc.plugins = Some(LintPlugins::default() | converter.plugins);
+        oxlintrc.path = path.to_path_buf();
+
+        Ok(Self { oxlintrc, unmapped: converter.unmapped })
+    }
+}
+
+/// Keys which oxlint infers from the file itself, and so can be dropped without losing behavior.
+const INFERRED_KEYS: [&str; 2] = ["parser", "parserOptions"];
+
+struct EslintrcConverter {
+    plugins: LintPlugins,
+    unmapped: Vec<OxcDiagnostic>,
+}
+
+impl EslintrcConverter {
+    fn unmapped(&mut self, message: String) {
+        self.unmapped.push(OxcDiagnostic::warn(message));
+    }
+
+    /// Converts the top-level config, or an entry of `overrides` if `is_override` is `true`.
+    fn convert_config(
+        &mut self,
+        config: Map<String, Value>,
+        is_override: bool,
+    ) -> Map<String, Value> {
+        let mut entry = Map::default();
+        for (key, value) in config {
+            match key.as_str() {
+                "root" if !is_override => {}
+                key if INFERRED_KEYS.contains(&key) => {}
+                "rules" => {
+                    if let Value::Object(rules) = value {
+                        entry.insert(key, Value::Object(self.convert_rules(rules)));
+                    } else {
+                        self.unmapped("`rules` must be an object".to_string());
+                    }
+                }
+                "plugins" => self.convert_plugins(value),
+                "env" | "globals" => {
+                    entry.insert(key, value);
+                }
+                "settings" if !is_override => {
+                    entry.insert(key, value);
+                }
+                "ignorePatterns" if !is_override => {
+                    entry.insert(key, string_or_array(value));
+                }
+                "files" if is_override => {
+                    entry.insert(key, string_or_array(value));
+                }
+                "overrides" if !is_override => {
+                    let overrides = match value {
+                        Value::Array(overrides) => overrides,
+                        _ => vec![],
+                    };
+                    let overrides = overrides
+                        .into_iter()
+                        .filter_map(|value| {
+                            if let Value::Object(config) = value {
+                                Some(Value::Object(self.convert_config(config, true)))
+                            } else {
+                                self.unmapped("Entries of `overrides` must be objects".to_string());
+                                None
+                            }
+                        })
+                        .collect();
+                    entry.insert(key, Value::Array(overrides));
+                }
+                "extends" => {
+                    let Value::Array(extends) = string_or_array(value) else { continue };
+                    for config in extends {
+                        self.unmapped(format!(
+                            "Shared config `{}` from `extends` cannot be mapped to oxlint",
+                            config.as_str().unwrap_or_default()
+                        ));
+                    }
+                }
+                key if is_override => {
+                    self.unmapped(format!("`{key}` in `overrides` has no oxlint equivalent"));
+                }
+                key => self.unmapped(format!("`{key}` has no oxlint equivalent")),
+            }
+        }
+        entry
+    }
+
+    fn convert_rules(&mut self, rules: Map<String, Value>) -> Map<String, Value> {
+        let mut converted = Map::default();
+        for (key, value) in rules {
+            match map_rule(&key, &value) {
+                RuleMapping::Implemented(plugin) => {
+                    if let Some(plugin) = plugin {
+                        self.plugins |= plugin;
+                    }
+                    converted.insert(key, value);
+                }
+                RuleMapping::Dropped => {}
+                RuleMapping::Unimplemented => {
+                    self.unmapped(format!("Rule `{key}` is not implemented in oxlint"));
+                }
+            }
+        }
+        converted
+    }
+
+    /// Legacy plugins are listed by package name, e.g. `["react", "@typescript-eslint"]`.
+    fn convert_plugins(&mut self, value: Value) {
+        let Value::Array(plugins) = value else {
+            self.unmapped("`plugins` must be an array of plugin names".to_string());
+            return;
+        };
+        for name in plugins {
+            let Value::String(name) = name else { continue };
+            let (plugin_name, _) = unalias_plugin_name(&name, "");
+            match LintPlugins::try_from(plugin_name.as_str()) {
+                Ok(plugin) => self.plugins |= plugin,
+                Err(()) => self.unmapped(format!("Plugin `{name}` is not implemented in oxlint")),
+            }
+        }
+    }
+}
+
+/// ESLint accepts a single pattern in place of an array of patterns.
+fn string_or_array(value: Value) -> Value {
+    match value {
+        Value::String(_) => Value::Array(vec![value]),
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use crate::{AllowWarnDeny, LintPlugins};
+
+    use super::EslintrcConfig;
+
+    fn unmapped_messages(config: &EslintrcConfig) -> Vec<String> {
+        config.unmapped.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_is_eslintrc_path() {
+        assert!(EslintrcConfig::is_eslintrc_path(Path::new(".eslintrc.json")));
+        assert!(EslintrcConfig::is_eslintrc_path(Path::new("foo/.eslintrc")));
+        assert!(!EslintrcConfig::is_eslintrc_path(Path::new("eslintrc.json")));
+        assert!(!EslintrcConfig::is_eslintrc_path(Path::new("eslint.config.js")));
+    }
+
+    #[test]
+    fn test_json() {
+        let config = EslintrcConfig::from_source(
+            Path::new(".eslintrc.json"),
+            r#"{
+              // comments are allowed
+              "root": true,
+              "extends": ["eslint:recommended", "plugin:react/recommended"],
+              "parser": "@typescript-eslint/parser",
+              "plugins": ["react", "@typescript-eslint", "unknown"],
+              "env": { "browser": true },
+              "ignorePatterns": "dist/",
+              "rules": {
+                "no-debugger": "error",
+                "@typescript-eslint/no-explicit-any": ["warn"],
+                "not-a-rule": "error",
+                "also-not-a-rule": "off"
+              },
+              "overrides": [
+                { "files": "*.test.js", "rules": { "no-console": "off" }, "excludedFiles": "x.js" }
+              ]
+            }"#
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            unmapped_messages(&config),
+            vec![
+                "Shared config `eslint:recommended` from `extends` cannot be mapped to oxlint",
+                "Shared config `plugin:react/recommended` from `extends` cannot be mapped to oxlint",
+                "Plugin `unknown` is not implemented in oxlint",
+                "Rule `not-a-rule` is not implemented in oxlint",
+                "`excludedFiles` in `overrides` has no oxlint equivalent",
+            ]
+        );
+
+        let oxlintrc = &config.oxlintrc;
+        assert_eq!(oxlintrc.ignore_patterns, vec!["dist/".to_string()]);
+        assert_eq!(oxlintrc.rules.rules.len(), 2);
+        assert_eq!(oxlintrc.rules.rules[0].severity, AllowWarnDeny::Deny);
+        assert_eq!(oxlintrc.rules.rules[1].plugin_name, "typescript");
+        assert_eq!(oxlintrc.overrides.len(), 1);
+        assert!(oxlintrc.overrides[0].files.is_match("src/foo.test.js"));
+        assert!(oxlintrc.plugins.unwrap().contains(LintPlugins::REACT | LintPlugins::TYPESCRIPT));
+    }
+
+    #[test]
+    fn test_js() {
+        let config = EslintrcConfig::from_source(
+            Path::new(".eslintrc.js"),
+            r#"module.exports = { rules: { eqeqeq: ["error", "smart"] } };"#.to_string(),
+        )
+        .unwrap();
+        assert!(config.unmapped.is_empty());
+        assert_eq!(config.oxlintrc.rules.rules[0].rule_name, "eqeqeq");
+
+        let err = EslintrcConfig::from_source(
+            Path::new(".eslintrc.js"),
+            "module.exports = require('./base');".to_string(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("Failed to read the config object"));
+
+        let err = EslintrcConfig::from_source(Path::new(".eslintrc.yml"), "root: true".to_string())
+            .unwrap_err();
+        assert!(err.to_string().starts_with("YAML ESLint config"));
+    }
+}
@@ -128,7 +128,7 @@ impl JsonSchema for ExternalPluginEntry {
                         "Custom name/alias for the plugin.\n\n\
                          Note: The following plugin names are reserved because they are implemented \
                          natively in Rust within oxlint and cannot be used for JS plugins:\n\
-                         - react (includes react-hooks)\n\
+                         - react (includes react-hooks and react-compiler)\n\
                          - unicorn\n\
                          - typescript\n\
                          - oxc\n\
@@ -143,6 +143,10 @@ impl JsonSchema for ExternalPluginEntry {
                          - node\n\
                          - regex\n\
                          - vue\n\
+                         - security\n\
+                         - sonarjs\n\
+                         - regexp\n\
+                         - i18next\n\
                          - eslint\n\n\
                          If you need to use the JavaScript version of any of these plugins, \
                          provide a custom alias to avoid conflicts."
@@ -57,7 +57,15 @@ impl OxlintGlobals {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+impl hash::Hash for OxlintGlobals {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        let mut globals = self.0.iter().collect::<Vec<_>>();
+        globals.sort_unstable_by_key(|(name, _)| *name);
+        globals.hash(state);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum GlobalValue {
     Readonly,
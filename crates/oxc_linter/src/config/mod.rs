@@ -1,9 +1,12 @@
 use std::path::PathBuf;
 
+mod budgets;
 mod categories;
 mod config_builder;
 mod config_store;
 mod env;
+mod eslint_flat_config;
+mod eslintrc;
 mod external_plugins;
 mod globals;
 mod ignore_matcher;
@@ -12,18 +15,24 @@ mod oxlintrc;
 pub mod plugins;
 mod rules;
 mod settings;
+mod validate;
+pub use budgets::LintBudgets;
 pub use config_builder::{ConfigBuilderError, ConfigStoreBuilder};
 pub use config_store::{Config, ConfigStore, ResolvedLinterState};
 pub use env::OxlintEnv;
+pub use eslint_flat_config::EslintFlatConfig;
+pub use eslintrc::EslintrcConfig;
 pub use globals::{GlobalValue, OxlintGlobals};
 pub use ignore_matcher::LintIgnoreMatcher;
 pub use overrides::OxlintOverrides;
 pub use oxlintrc::Oxlintrc;
 pub use plugins::LintPlugins;
+pub use rules::configure_rule;
+pub use rules::parse_rule_key;
 pub use rules::{ESLintRule, OxlintRules};
 pub use settings::{OxlintSettings, ReactVersion, jsdoc::JSDocPluginSettings};
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Hash)]
 pub struct LintConfig {
     pub(crate) plugins: LintPlugins,
     pub(crate) settings: OxlintSettings,
@@ -109,7 +109,7 @@ pub struct OxlintOverride {
 }
 
 /// A set of glob patterns.
-#[derive(Debug, Default, Clone, Serialize, JsonSchema)]
+#[derive(Debug, Default, Clone, Hash, Serialize, JsonSchema)]
 pub struct GlobSet(Vec<String>);
 
 impl<'de> Deserialize<'de> for GlobSet {
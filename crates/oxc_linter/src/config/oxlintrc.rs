@@ -4,12 +4,12 @@ use std::{
 };
 
 use rustc_hash::{FxHashMap, FxHashSet};
-use schemars::{JsonSchema, schema_for};
+use schemars::{JsonSchema, SchemaGenerator, schema::RootSchema, schema_for};
 use serde::{Deserialize, Serialize};
 
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
 
-use crate::{LintPlugins, utils::read_to_string};
+use crate::{LintPlugins, rules::RULES, utils::read_to_string};
 
 use super::{
     categories::OxlintCategories,
@@ -19,6 +19,7 @@ use super::{
     overrides::OxlintOverrides,
     rules::OxlintRules,
     settings::OxlintSettings,
+    validate,
 };
 
 /// Oxlint Configuration File
@@ -124,6 +125,28 @@ pub struct Oxlintrc {
     /// overriding the previous ones.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub extends: Vec<PathBuf>,
+    /// Maximum number of warnings of a rule or category of rules. Linting fails when a rule, or
+    /// the rules of a category, report more warnings than their budget allows. Budgets are only
+    /// read from the root configuration file.
+    ///
+    /// Budgets allow to keep existing violations of a rule, while preventing new ones. Lower the
+    /// budgets as violations are fixed.
+    ///
+    /// Example
+    ///
+    /// ```json
+    /// {
+    ///   "rules": {
+    ///     "typescript/no-explicit-any": "warn"
+    ///   },
+    ///   "budgets": {
+    ///     "typescript/no-explicit-any": 25,
+    ///     "style": 100
+    ///   }
+    /// }
+    /// ```
+    #[serde(skip_serializing_if = "FxHashMap::is_empty")]
+    pub budgets: FxHashMap<String, usize>,
 }
 
 impl Oxlintrc {
@@ -162,7 +185,13 @@ impl Oxlintrc {
         })?;
 
         let mut config = Self::deserialize(&json).map_err(|err| {
-            OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
+            // Point at the offending entry when the validator can tell which one it is.
+            Self::validate(&string)
+                .into_iter()
+                .find(|diagnostic| diagnostic.severity == Severity::Error)
+                .unwrap_or_else(|| {
+                    OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
+                })
         })?;
 
         config.path = path.to_path_buf();
@@ -194,6 +223,27 @@ impl Oxlintrc {
         Ok(config)
     }
 
+    /// Checks the source text of a configuration file for unknown keys, plugins, categories and
+    /// rules, and invalid severities. The diagnostics are labeled with the location of the entry
+    /// in the source text, and suggest the closest valid name.
+    ///
+    /// Unknown keys, plugins and categories, and invalid severities, fail to deserialize and are
+    /// errors. Unknown rules are ignored when linting and are warnings.
+    pub fn validate(source_text: &str) -> Vec<OxcDiagnostic> {
+        validate::validate(source_text)
+    }
+
+    /// The names of the rules turned on by name in `rules` or in any override, e.g.
+    /// `no-floating-promises`. Rules only enabled through a category are not included.
+    pub(crate) fn enabled_rule_names(&self) -> impl Iterator<Item = &str> {
+        self.overrides
+            .iter()
+            .flat_map(|override_config| &override_config.rules.rules)
+            .chain(&self.rules.rules)
+            .filter(|rule| rule.severity.is_warn_deny())
+            .map(|rule| rule.rule_name.as_str())
+    }
+
     /// # Errors
     ///
     /// * Parse Failure
@@ -211,8 +261,58 @@ impl Oxlintrc {
     /// # Panics
     /// Panics if the schema generation fails.
     pub fn generate_schema_json() -> String {
-        let mut schema = schema_for!(Oxlintrc);
+        let mut json = Self::schema_to_json(schema_for!(Oxlintrc));
+
+        // Inject markdown descriptions for better editor support
+        Self::inject_markdown_descriptions(&mut json);
+
+        serde_json::to_string_pretty(&json).unwrap()
+    }
 
+    /// Generates the JSON schema for Oxlintrc configuration files, with the severity and the
+    /// options of each built-in rule in `rules`.
+    ///
+    /// Rules are listed by the name `--print-config` uses, other names of a rule are still
+    /// allowed.
+    ///
+    /// # Panics
+    /// Panics if the schema generation fails.
+    pub fn generate_schema_json_with_rules() -> String {
+        let mut generator = SchemaGenerator::default();
+        let rules = RULES
+            .iter()
+            .map(|rule| {
+                let name = if rule.plugin_name() == "eslint" {
+                    rule.name().to_string()
+                } else {
+                    format!("{}/{}", rule.plugin_name(), rule.name())
+                };
+                let schema = match rule.config_schema(&mut generator) {
+                    Some(config) => serde_json::json!({
+                        "anyOf": [
+                            { "$ref": "#/definitions/AllowWarnDeny" },
+                            {
+                                "type": "array",
+                                "items": [{ "$ref": "#/definitions/AllowWarnDeny" }, config],
+                            },
+                        ],
+                    }),
+                    None => serde_json::json!({ "$ref": "#/definitions/DummyRule" }),
+                };
+                (name, schema)
+            })
+            .collect::<serde_json::Map<_, _>>();
+
+        let mut json = Self::schema_to_json(generator.into_root_schema_for::<Oxlintrc>());
+        json["definitions"]["DummyRuleMap"]["properties"] = serde_json::Value::Object(rules);
+
+        // Inject markdown descriptions for better editor support
+        Self::inject_markdown_descriptions(&mut json);
+
+        serde_json::to_string_pretty(&json).unwrap()
+    }
+
+    fn schema_to_json(mut schema: RootSchema) -> serde_json::Value {
         // Allow comments and trailing commas for vscode-json-languageservice
         // NOTE: This is NOT part of standard JSON Schema specification
         // https://github.com/microsoft/vscode-json-languageservice/blob/fb83547762901f32d8449d57e24666573016b10c/src/jsonLanguageTypes.ts#L151-L159
@@ -222,12 +322,7 @@ impl Oxlintrc {
             .extensions
             .insert("allowTrailingCommas".to_string(), serde_json::Value::Bool(true));
 
-        let mut json = serde_json::to_value(&schema).unwrap();
-
-        // Inject markdown descriptions for better editor support
-        Self::inject_markdown_descriptions(&mut json);
-
-        serde_json::to_string_pretty(&json).unwrap()
+        serde_json::to_value(&schema).unwrap()
     }
 
     /// Recursively inject `markdownDescription` fields into the JSON schema.
@@ -328,6 +423,7 @@ impl Oxlintrc {
             path: self.path.clone(),
             ignore_patterns: self.ignore_patterns.clone(),
             extends: self.extends.clone(),
+            budgets: self.budgets.clone(),
         }
     }
 }
@@ -378,7 +474,7 @@ mod test {
             serde_json::from_str(r#"{ "plugins": ["typescript", "unicorn"] }"#).unwrap();
         assert_eq!(config.plugins, Some(LintPlugins::TYPESCRIPT | LintPlugins::UNICORN));
         let config: Oxlintrc =
-            serde_json::from_str(r#"{ "plugins": ["typescript", "unicorn", "react", "oxc", "import", "jsdoc", "jest", "vitest", "jsx-a11y", "nextjs", "react-perf", "promise", "node", "vue"] }"#).unwrap();
+            serde_json::from_str(r#"{ "plugins": ["typescript", "unicorn", "react", "oxc", "import", "jsdoc", "jest", "vitest", "jsx-a11y", "nextjs", "react-perf", "promise", "node", "vue", "security", "sonarjs", "regexp", "i18next"] }"#).unwrap();
         assert_eq!(config.plugins, Some(LintPlugins::all()));
 
         let config: Oxlintrc =
@@ -508,4 +604,23 @@ mod test {
         let merged = config1.merge(config2);
         assert_eq!(merged.schema, Some("schema2.json".to_string()));
     }
+
+    #[test]
+    fn test_schema_json_with_rules() {
+        let schema: serde_json::Value =
+            serde_json::from_str(&Oxlintrc::generate_schema_json_with_rules()).unwrap();
+        let rules = &schema["definitions"]["DummyRuleMap"]["properties"];
+
+        // Rules with options
+        assert_eq!(
+            rules["no-console"]["anyOf"][1]["items"][1]["$ref"],
+            "#/definitions/NoConsoleConfig"
+        );
+        assert!(schema["definitions"]["NoConsoleConfig"]["properties"]["allow"].is_object());
+        // Rules without options
+        assert_eq!(rules["no-debugger"]["$ref"], "#/definitions/DummyRule");
+        assert!(rules["unicorn/no-null"].is_object());
+        // Other names of rules are still allowed
+        assert!(schema["definitions"]["DummyRuleMap"]["additionalProperties"].is_object());
+    }
 }
@@ -61,12 +61,12 @@ pub fn is_normal_plugin_name(plugin_name: &str) -> bool {
 }
 
 bitflags! {
-    // NOTE: may be increased to a u32 if needed
+    // NOTE: may be increased to a u64 if needed
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-    pub struct LintPlugins: u16 {
+    pub struct LintPlugins: u32 {
         /// Not really a plugin. Included for completeness.
         const ESLINT = 0;
-        /// `eslint-plugin-react`, plus `eslint-plugin-react-hooks`
+        /// `eslint-plugin-react`, plus `eslint-plugin-react-hooks` and `eslint-plugin-react-compiler`
         const REACT = 1 << 0;
         /// `eslint-plugin-unicorn`
         const UNICORN = 1 << 1;
@@ -94,6 +94,14 @@ bitflags! {
         const NODE = 1 << 12;
         /// `eslint-plugin-vue`
         const VUE = 1 << 13;
+        /// `eslint-plugin-security`
+        const SECURITY = 1 << 14;
+        /// `eslint-plugin-sonarjs`
+        const SONARJS = 1 << 15;
+        /// `eslint-plugin-regexp`
+        const REGEXP = 1 << 16;
+        /// `eslint-plugin-i18next`
+        const I18NEXT = 1 << 17;
     }
 }
 
@@ -140,7 +148,7 @@ impl TryFrom<&str> for LintPlugins {
         let value = normalized.as_ref();
 
         match value {
-            "react" | "react-hooks" | "react_hooks" => Ok(LintPlugins::REACT),
+            "react" | "react-hooks" | "react_hooks" | "react-compiler" => Ok(LintPlugins::REACT),
             "unicorn" => Ok(LintPlugins::UNICORN),
             "typescript" | "typescript-eslint" | "typescript_eslint" | "@typescript-eslint" => {
                 Ok(LintPlugins::TYPESCRIPT)
@@ -158,6 +166,10 @@ impl TryFrom<&str> for LintPlugins {
             "promise" => Ok(LintPlugins::PROMISE),
             "node" => Ok(LintPlugins::NODE),
             "vue" => Ok(LintPlugins::VUE),
+            "security" => Ok(LintPlugins::SECURITY),
+            "sonarjs" => Ok(LintPlugins::SONARJS),
+            "regexp" => Ok(LintPlugins::REGEXP),
+            "i18next" => Ok(LintPlugins::I18NEXT),
             // "eslint" is not really a plugin, so it's 'empty'. This has the added benefit of
             // making it the default value.
             "eslint" => Ok(LintPlugins::ESLINT),
@@ -183,6 +195,10 @@ impl From<LintPlugins> for &'static str {
             LintPlugins::PROMISE => "promise",
             LintPlugins::NODE => "node",
             LintPlugins::VUE => "vue",
+            LintPlugins::SECURITY => "security",
+            LintPlugins::SONARJS => "sonarjs",
+            LintPlugins::REGEXP => "regexp",
+            LintPlugins::I18NEXT => "i18next",
             _ => "",
         }
     }
@@ -254,6 +270,10 @@ impl JsonSchema for LintPlugins {
             Promise,
             Node,
             Vue,
+            Security,
+            Sonarjs,
+            Regexp,
+            I18next,
         }
 
         let enum_schema = r#gen.subschema_for::<LintPluginOptionsSchema>();
@@ -295,6 +315,7 @@ mod tests {
         assert_eq!(LintPlugins::try_from("react"), Ok(LintPlugins::REACT));
         assert_eq!(LintPlugins::try_from("typescript-eslint"), Ok(LintPlugins::TYPESCRIPT));
         assert_eq!(LintPlugins::try_from("deepscan"), Ok(LintPlugins::OXC));
+        assert_eq!(LintPlugins::try_from("react-compiler"), Ok(LintPlugins::REACT));
         assert_eq!(LintPlugins::try_from("unknown"), Err(()));
     }
 
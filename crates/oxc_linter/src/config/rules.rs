@@ -14,7 +14,8 @@ use oxc_diagnostics::{Error, OxcDiagnostic};
 
 use crate::{
     AllowWarnDeny, ExternalPluginStore, LintPlugins,
-    external_plugin_store::{ExternalOptionsId, ExternalRuleId, ExternalRuleLookupError},
+    config::ConfigBuilderError,
+    external_plugin_store::{ExternalOptionsId, ExternalRuleId},
     rules::{RULES, RuleEnum},
     utils::{is_eslint_rule_adapted_to_typescript, is_jest_rule_adapted_to_vitest},
 };
@@ -71,7 +72,7 @@ impl OxlintRules {
         >,
         all_rules: &[RuleEnum],
         external_plugin_store: &mut ExternalPluginStore,
-    ) -> Result<(), ExternalRuleLookupError> {
+    ) -> Result<(), ConfigBuilderError> {
         let mut rules_to_replace = vec![];
 
         let lookup = self.rules.iter().into_group_map_by(|r| r.rule_name.as_str());
@@ -104,7 +105,13 @@ impl OxlintRules {
                         } else {
                             serde_json::Value::Array(rule_config.config.to_vec())
                         };
-                        rules_to_replace.push((rule.from_configuration(config), severity));
+                        let rule = rule.try_from_configuration(config).map_err(|reason| {
+                            ConfigBuilderError::InvalidRuleConfiguration {
+                                rule: rule_config.full_name().into_owned(),
+                                reason,
+                            }
+                        })?;
+                        rules_to_replace.push((rule, severity));
                     }
                 } else {
                     // If JS plugins are disabled (language server), assume plugin name refers to a JS plugin,
@@ -115,8 +122,9 @@ impl OxlintRules {
                     // (e.g. typos like `unicon/filename-case`). But we can't avoid this as the name of a JS plugin
                     // can only be known by loading it, which language server can't do at present.
                     if external_plugin_store.is_enabled() {
-                        let external_rule_id =
-                            external_plugin_store.lookup_rule_id(plugin_name, rule_name)?;
+                        let external_rule_id = external_plugin_store
+                            .lookup_rule_id(plugin_name, rule_name)
+                            .map_err(ConfigBuilderError::ExternalRuleLookupError)?;
 
                         // Add options to store and get options ID
                         let options_id = external_plugin_store
@@ -143,7 +151,32 @@ impl OxlintRules {
     }
 }
 
-fn transform_rule_and_plugin_name<'a>(
+/// Configures the builtin rule `name` with `value`, like an entry of `rules` in a config file,
+/// e.g. `react/jsx-key` with `["error", { "checkFragmentShorthand": true }]`.
+///
+/// Returns `Ok(None)` if there is no builtin rule named `name`.
+pub fn configure_rule(
+    name: &str,
+    value: serde_json::Value,
+) -> Result<Option<(RuleEnum, AllowWarnDeny)>, Error> {
+    let (severity, config) = parse_rule_value(value)?;
+    let (plugin_name, rule_name) = parse_rule_key(name);
+    let (rule_name, plugin_name) = transform_rule_and_plugin_name(&rule_name, &plugin_name);
+    let Some(rule) =
+        RULES.iter().find(|rule| rule.name() == rule_name && rule.plugin_name() == plugin_name)
+    else {
+        return Ok(None);
+    };
+    let config = if config.is_empty() {
+        serde_json::Value::Null
+    } else {
+        serde_json::Value::Array(config.into_vec())
+    };
+    let rule = rule.try_from_configuration(config).map_err(OxcDiagnostic::error)?;
+    Ok(Some((rule, severity)))
+}
+
+pub(super) fn transform_rule_and_plugin_name<'a>(
     rule_name: &'a str,
     plugin_name: &'a str,
 ) -> (&'a str, &'a str) {
@@ -155,6 +188,7 @@ fn transform_rule_and_plugin_name<'a>(
     let plugin_name = match plugin_name {
         "vitest" if is_jest_rule_adapted_to_vitest(rule_name) => "jest",
         "unicorn" if rule_name == "no-negated-condition" => "eslint",
+        "node" if rule_name == "prefer-node-protocol" => "unicorn",
         "typescript" if is_eslint_rule_adapted_to_typescript(rule_name) => "eslint",
         _ => plugin_name,
     };
@@ -248,7 +282,7 @@ impl<'de> Deserialize<'de> for OxlintRules {
     }
 }
 
-fn parse_rule_key(name: &str) -> (String, String) {
+pub fn parse_rule_key(name: &str) -> (String, String) {
     // For scoped packages (starting with `@`), split at the last `/` to handle
     // packages like `@eslint-react/naming-convention` with rule `rule-name`.
     // For non-scoped packages, split at the first `/`.
@@ -282,8 +316,9 @@ pub(super) fn unalias_plugin_name(plugin_name: &str, rule_name: &str) -> (String
         "react-perf" => ("react_perf", rule_name),
         // e.g. "@next/google-font-display", "@next/next/google-font-display"
         "@next" | "@next/next" => ("nextjs", rule_name),
-        // For backwards compatibility, react hook rules reside in the react plugin.
-        "react-hooks" => ("react", rule_name),
+        // For backwards compatibility, react hook rules reside in the react plugin, and so
+        // does "react-compiler/react-compiler".
+        "react-hooks" | "react-compiler" => ("react", rule_name),
         // For backwards compatibility, deepscan rules reside in the oxc plugin.
         "deepscan" => ("oxc", rule_name),
         _ => (plugin_name, rule_name),
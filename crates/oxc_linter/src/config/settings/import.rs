@@ -0,0 +1,38 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Configure the import plugin rules.
+#[derive(Debug, Clone, Deserialize, Serialize, Default, JsonSchema, PartialEq, Eq)]
+pub struct ImportPluginSettings {
+    /// Aliases used to resolve imports, in addition to the `paths` of the `tsconfig.json`.
+    ///
+    /// Each alias maps a module name, or a prefix of module names, to one or more paths. The
+    /// paths are relative to the directory of the root configuration file.
+    ///
+    /// Only the aliases of the root configuration are used, not those of nested configurations.
+    ///
+    /// Example:
+    ///
+    /// ```json
+    /// {
+    ///   "settings": {
+    ///     "import": {
+    ///       "alias": {
+    ///         "@": ["./src"],
+    ///         "utils": ["./src/utils", "./vendor/utils"]
+    ///       }
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub alias: BTreeMap<String, Vec<String>>,
+}
+
+impl ImportPluginSettings {
+    pub fn is_empty(&self) -> bool {
+        self.alias.is_empty()
+    }
+}
@@ -1,15 +1,18 @@
+mod import;
 pub mod jsdoc;
 mod jsx_a11y;
 mod next;
 mod react;
 pub mod vitest;
 
+use std::hash::{Hash, Hasher};
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use self::{
-    jsdoc::JSDocPluginSettings, jsx_a11y::JSXA11yPluginSettings, next::NextPluginSettings,
-    react::ReactPluginSettings, vitest::VitestPluginSettings,
+    import::ImportPluginSettings, jsdoc::JSDocPluginSettings, jsx_a11y::JSXA11yPluginSettings,
+    next::NextPluginSettings, react::ReactPluginSettings, vitest::VitestPluginSettings,
 };
 
 pub use self::react::ReactVersion;
@@ -60,6 +63,9 @@ pub struct OxlintSettings {
 
     #[serde(default)]
     pub vitest: VitestPluginSettings,
+
+    #[serde(default, skip_serializing_if = "ImportPluginSettings::is_empty")]
+    pub import: ImportPluginSettings,
 }
 
 #[derive(Deserialize, Default)]
@@ -80,10 +86,20 @@ struct WellKnownOxlintSettings {
 
     #[serde(default)]
     pub vitest: VitestPluginSettings,
+
+    #[serde(default)]
+    pub import: ImportPluginSettings,
 }
 
 pub type OxlintSettingsJson = serde_json::Map<String, serde_json::Value>;
 
+impl Hash for OxlintSettings {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // The well-known settings are all deserialized from `json`.
+        self.json.hash(state);
+    }
+}
+
 impl<'de> Deserialize<'de> for OxlintSettings {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -104,6 +120,7 @@ impl<'de> Deserialize<'de> for OxlintSettings {
             react: well_known_settings.react,
             jsdoc: well_known_settings.jsdoc,
             vitest: well_known_settings.vitest,
+            import: well_known_settings.import,
         })
     }
 }
@@ -128,6 +145,7 @@ impl OxlintSettings {
                         settings_to_override.react = well_known_settings.react;
                         settings_to_override.jsdoc = well_known_settings.jsdoc;
                         settings_to_override.vitest = well_known_settings.vitest;
+                        settings_to_override.import = well_known_settings.import;
                     }
                     Err(e) => {
                         panic!("Failed to parse override settings: {e:?}");
@@ -140,6 +158,7 @@ impl OxlintSettings {
                 settings_to_override.react = self.react.clone();
                 settings_to_override.jsdoc = self.jsdoc.clone();
                 settings_to_override.vitest = self.vitest.clone();
+                settings_to_override.import = self.import.clone();
             }
         }
     }
@@ -0,0 +1,369 @@
+use std::fmt::Display;
+
+use rustc_hash::FxHashSet;
+use schemars::{JsonSchema, schema_for};
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::json::{JsonDialect, JsonKey, JsonParser, JsonProperty, JsonValue};
+
+use crate::{
+    AllowWarnDeny, LintPlugins, RuleCategory,
+    rules::{RULES, RuleEnum},
+    utils::{closest, did_you_mean},
+};
+
+use super::{
+    overrides::OxlintOverride, oxlintrc::Oxlintrc, parse_rule_key,
+    rules::transform_rule_and_plugin_name,
+};
+
+const SEVERITIES: [&str; 5] = ["off", "warn", "error", "allow", "deny"];
+
+/// Checks the keys, plugins, categories, severities and rule names of the source text of a
+/// configuration file. See [`Oxlintrc::validate`].
+///
+/// JSON is parsed with spans, so that the diagnostics point at the exact entries which are wrong.
+/// Source text which isn't a valid object is left to `serde_json` to report.
+pub(super) fn validate(source_text: &str) -> Vec<OxcDiagnostic> {
+    let Ok(config) = JsonParser::new(source_text, JsonDialect::Jsonc).parse() else {
+        return vec![];
+    };
+    let Some(properties) = config.as_object() else { return vec![] };
+    let mut validator = Validator {
+        // Rules of JS plugins can't be checked, their names are only known once loaded.
+        has_js_plugins: config.get("jsPlugins").is_some(),
+        diagnostics: vec![],
+    };
+    validator.validate_config(properties, false);
+    validator.diagnostics
+}
+
+struct Validator {
+    has_js_plugins: bool,
+    diagnostics: Vec<OxcDiagnostic>,
+}
+
+impl Validator {
+    fn report<T: Display>(&mut self, diagnostic: OxcDiagnostic, close_matches: &[T]) {
+        self.diagnostics.push(match did_you_mean(close_matches) {
+            Some(help) => diagnostic.with_help(help),
+            None => diagnostic,
+        });
+    }
+
+    /// Validates the root of the configuration file, or an entry of `overrides`.
+    fn validate_config(&mut self, config: &[JsonProperty], is_override: bool) {
+        let keys =
+            if is_override { schema_keys::<OxlintOverride>() } else { schema_keys::<Oxlintrc>() };
+        for JsonProperty { key, value } in config {
+            match key.value.as_ref() {
+                "plugins" => self.validate_plugins(value),
+                "rules" => self.validate_rules(value),
+                "categories" if !is_override => self.validate_categories(value),
+                "budgets" if !is_override => self.validate_budgets(value),
+                "overrides" if !is_override => {
+                    for config in value.as_array().unwrap_or_default() {
+                        if let Some(config) = config.as_object() {
+                            self.validate_config(config, true);
+                        }
+                    }
+                }
+                name if !keys.contains(name) => {
+                    let message = if is_override {
+                        format!("Unknown key `{name}` in `overrides`")
+                    } else {
+                        format!("Unknown key `{name}`")
+                    };
+                    let close_matches = closest(name, keys.iter().map(String::as_str));
+                    self.report(OxcDiagnostic::error(message).with_label(key.span), &close_matches);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn validate_plugins(&mut self, plugins: &JsonValue) {
+        for plugin in plugins.as_array().unwrap_or_default() {
+            let Some(name) = plugin.as_str() else { continue };
+            if LintPlugins::try_from(name).is_err() {
+                let close_matches = closest(name, plugin_names());
+                self.report(
+                    OxcDiagnostic::error(format!("Unknown plugin `{name}`"))
+                        .with_label(plugin.span),
+                    &close_matches,
+                );
+            }
+        }
+    }
+
+    fn validate_categories(&mut self, categories: &JsonValue) {
+        for JsonProperty { key, value } in categories.as_object().unwrap_or_default() {
+            if RuleCategory::try_from(key.value.as_ref()).is_err() {
+                let names = ALL_CATEGORIES.map(RuleCategory::as_str);
+                let close_matches = closest(&key.value, names.into_iter());
+                self.report(
+                    OxcDiagnostic::error(format!("Unknown category `{}`", key.value))
+                        .with_label(key.span),
+                    &close_matches,
+                );
+            }
+            self.validate_severity(value);
+        }
+    }
+
+    fn validate_rules(&mut self, rules: &JsonValue) {
+        for JsonProperty { key, value } in rules.as_object().unwrap_or_default() {
+            let severity = match value.as_array() {
+                Some(array) => array.first(),
+                None => Some(value),
+            };
+            if let Some(severity) = severity {
+                self.validate_severity(severity);
+            }
+            self.validate_rule_name(key);
+        }
+    }
+
+    /// Budgets are keyed by categories or rule names.
+    fn validate_budgets(&mut self, budgets: &JsonValue) {
+        for JsonProperty { key, .. } in budgets.as_object().unwrap_or_default() {
+            if RuleCategory::try_from(key.value.as_ref()).is_err() {
+                self.validate_rule_name(key);
+            }
+        }
+    }
+
+    fn validate_rule_name(&mut self, key: &JsonKey) {
+        let name = key.value.as_ref();
+        let (plugin, rule_name) = parse_rule_key(name);
+        if LintPlugins::try_from(plugin.as_str()).is_err() {
+            if !self.has_js_plugins {
+                let prefix = &name[..name.len() - rule_name.len()];
+                let plugin_name = prefix.trim_end_matches('/');
+                let close_matches = closest(plugin_name, plugin_names());
+                self.report(
+                    OxcDiagnostic::warn(format!(
+                        "Rule `{name}` is from unknown plugin `{plugin_name}`"
+                    ))
+                    .with_label(key.span),
+                    &close_matches,
+                );
+            }
+            return;
+        }
+        let (resolved_name, resolved_plugin) = transform_rule_and_plugin_name(&rule_name, &plugin);
+        if !RULES
+            .iter()
+            .any(|rule| rule.plugin_name() == resolved_plugin && rule.name() == resolved_name)
+        {
+            // Suggest rules of the same plugin, with the prefix the rule was written with,
+            // e.g. `@typescript-eslint/`.
+            let prefix = &name[..name.len() - rule_name.len()];
+            let rule_names = RULES.iter().filter(|rule| {
+                transform_rule_and_plugin_name(rule.name(), &plugin)
+                    == (rule.name(), rule.plugin_name())
+            });
+            let close_matches = closest(&rule_name, rule_names.map(RuleEnum::name))
+                .into_iter()
+                .map(|rule_name| format!("{prefix}{rule_name}"))
+                .collect::<Vec<_>>();
+            self.report(
+                OxcDiagnostic::warn(format!("Unknown rule `{name}`")).with_label(key.span),
+                &close_matches,
+            );
+        }
+    }
+
+    fn validate_severity(&mut self, severity: &JsonValue) {
+        let Some(value) = severity.as_str() else { return };
+        if AllowWarnDeny::try_from(value).is_err() {
+            let close_matches = closest(value, SEVERITIES.into_iter());
+            self.report(
+                OxcDiagnostic::error(format!(
+                    r#"Invalid severity `{value}`, expected one of "off", "warn", "error", "allow" or "deny""#
+                ))
+                .with_label(severity.span),
+                &close_matches,
+            );
+        }
+    }
+}
+
+const ALL_CATEGORIES: [RuleCategory; 7] = [
+    RuleCategory::Correctness,
+    RuleCategory::Suspicious,
+    RuleCategory::Pedantic,
+    RuleCategory::Perf,
+    RuleCategory::Style,
+    RuleCategory::Restriction,
+    RuleCategory::Nursery,
+];
+
+/// The keys of the configuration object `T`, from its JSON schema.
+fn schema_keys<T: JsonSchema>() -> FxHashSet<String> {
+    schema_for!(T)
+        .schema
+        .object
+        .map(|object| object.properties.into_keys().collect())
+        .unwrap_or_default()
+}
+
+fn plugin_names() -> impl Iterator<Item = &'static str> {
+    LintPlugins::all().iter().map(Into::into).filter(|name: &&str| !name.is_empty())
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{OxcDiagnostic, Severity};
+
+    use super::validate;
+
+    /// The message, labeled source and help of each diagnostic.
+    fn check(source_text: &str) -> Vec<(Severity, String, &str, Option<String>)> {
+        validate(source_text)
+            .into_iter()
+            .map(|diagnostic: OxcDiagnostic| {
+                let span = diagnostic.labels.as_ref().unwrap()[0].inner();
+                let labeled = &source_text[span.offset()..span.offset() + span.len()];
+                (
+                    diagnostic.severity,
+                    diagnostic.message.to_string(),
+                    labeled,
+                    diagnostic.help.as_ref().map(ToString::to_string),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid() {
+        let source_text = r#"{
+            // comment
+            "$schema": "./node_modules/oxlint/configuration_schema.json",
+            "plugins": ["import", "jsx-a11y"],
+            "categories": { "correctness": "error" },
+            "rules": {
+                "no-console": ["warn", { "allow": ["info"] }],
+                "eqeqeq": ["error", "always", { "null": "ignore" }],
+                "@typescript-eslint/no-explicit-any": 2,
+                "jsx-a11y/alt-text": "off",
+                "vitest/no-identical-title": "error",
+                "vitest/no-restricted-vi-methods": "error",
+                "typescript/no-magic-numbers": "error",
+            },
+            "overrides": [{ "files": ["*.ts"], "rules": { "no-debugger": "deny" } }],
+        }"#;
+        assert!(check(source_text).is_empty());
+        // Left to the JSON parser.
+        assert!(check("{ \"rules\": ").is_empty());
+    }
+
+    #[test]
+    fn unknown_keys() {
+        let source_text = r#"{ "rulez": {}, "overrides": [{ "file": [] }] }"#;
+        assert_eq!(
+            check(source_text),
+            vec![
+                (
+                    Severity::Error,
+                    "Unknown key `rulez`".to_string(),
+                    "\"rulez\"",
+                    Some("Did you mean `rules`?".to_string())
+                ),
+                (
+                    Severity::Error,
+                    "Unknown key `file` in `overrides`".to_string(),
+                    "\"file\"",
+                    Some("Did you mean `files`?".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_plugins_and_categories() {
+        let source_text =
+            r#"{ "plugins": ["react", "imprt"], "categories": { "corectness": "warn" } }"#;
+        assert_eq!(
+            check(source_text),
+            vec![
+                (
+                    Severity::Error,
+                    "Unknown plugin `imprt`".to_string(),
+                    "\"imprt\"",
+                    Some("Did you mean `import`?".to_string())
+                ),
+                (
+                    Severity::Error,
+                    "Unknown category `corectness`".to_string(),
+                    "\"corectness\"",
+                    Some("Did you mean `correctness`?".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_severities() {
+        let source_text = r#"{ "rules": { "no-debugger": "eror", "no-console": ["of"] } }"#;
+        let diagnostics = check(source_text);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].2, "\"eror\"");
+        assert_eq!(diagnostics[0].3.as_deref(), Some("Did you mean `error`?"));
+        assert_eq!(diagnostics[1].2, "\"of\"");
+        assert_eq!(diagnostics[1].3.as_deref(), Some("Did you mean `off`?"));
+    }
+
+    #[test]
+    fn unknown_rules() {
+        let source_text = r#"{
+            "rules": {
+                "no-debuger": "error",
+                "@typescript-eslint/no-explict-any": "error",
+                "imprt/no-cycle": "error"
+            }
+        }"#;
+        assert_eq!(
+            check(source_text),
+            vec![
+                (
+                    Severity::Warning,
+                    "Unknown rule `no-debuger`".to_string(),
+                    "\"no-debuger\"",
+                    Some("Did you mean `no-debugger`?".to_string())
+                ),
+                (
+                    Severity::Warning,
+                    "Unknown rule `@typescript-eslint/no-explict-any`".to_string(),
+                    "\"@typescript-eslint/no-explict-any\"",
+                    Some("Did you mean `@typescript-eslint/no-explicit-any`?".to_string())
+                ),
+                (
+                    Severity::Warning,
+                    "Rule `imprt/no-cycle` is from unknown plugin `imprt`".to_string(),
+                    "\"imprt/no-cycle\"",
+                    Some("Did you mean `import`?".to_string())
+                ),
+            ]
+        );
+
+        // Rules of JS plugins are only known once the plugins are loaded.
+        let source_text =
+            r#"{ "jsPlugins": ["./plugin.js"], "rules": { "my-plugin/rule": "error" } }"#;
+        assert!(check(source_text).is_empty());
+    }
+
+    #[test]
+    fn unknown_budget_rules() {
+        let source_text = r#"{ "budgets": { "style": 10, "no-debugger": 1, "no-debuger": 2 } }"#;
+        assert_eq!(
+            check(source_text),
+            vec![(
+                Severity::Warning,
+                "Unknown rule `no-debuger`".to_string(),
+                "\"no-debuger\"",
+                Some("Did you mean `no-debugger`?".to_string())
+            )]
+        );
+    }
+}
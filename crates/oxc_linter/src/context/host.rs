@@ -1,5 +1,4 @@
 use std::{
-    borrow::Cow,
     cell::{Cell, RefCell},
     ffi::OsStr,
     path::Path,
@@ -7,19 +6,24 @@ use std::{
     sync::Arc,
 };
 
-use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_semantic::Semantic;
-use oxc_span::{SourceType, Span};
+use oxc_span::SourceType;
 
 use crate::{
     AllowWarnDeny, FrameworkFlags,
     config::{LintConfig, LintPlugins, OxlintEnv, OxlintGlobals, OxlintSettings},
-    disable_directives::{DisableDirectives, DisableDirectivesBuilder, RuleCommentType},
-    fixer::{Fix, FixKind, Message, PossibleFixes},
+    disable_directives::{
+        DisableDirectives, DisableDirectivesBuilder, create_unused_directives_messages,
+    },
+    fixer::{FixKind, Message, line_ending},
     frameworks::{self, FrameworkOptions},
+    loader::{Template, TemplateSource},
     module_record::ModuleRecord,
-    options::LintOptions,
+    options::{FixOnly, LintOptions},
+    package_json::{PackageJson, PackageJsonCache, Workspace},
     rules::RuleEnum,
+    suppress::Suppressor,
+    tsconfig::{CompilerOptions, TsConfigCache},
 };
 
 use super::{LintContext, plugin_name_to_prefix};
@@ -38,6 +42,8 @@ pub struct ContextSubHost<'a> {
     pub(super) framework_options: FrameworkOptions,
     /// The source text offset of the sub host
     pub(super) source_text_offset: u32,
+    /// The `<template>` block of the file, for Vue and MPX files.
+    pub(super) template: Option<Template<'a>>,
 }
 
 impl<'a> ContextSubHost<'a> {
@@ -78,9 +84,17 @@ impl<'a> ContextSubHost<'a> {
             source_text_offset,
             disable_directives,
             framework_options: frameworks_options,
+            template: None,
         }
     }
 
+    /// Parse the `<template>` block of the file, see [`Template`].
+    #[must_use]
+    pub fn with_template(mut self, template: Option<TemplateSource<'a>>) -> Self {
+        self.template = template.map(Template::parse);
+        self
+    }
+
     /// Shared reference to the [`Semantic`] analysis
     #[inline]
     pub fn semantic(&self) -> &Semantic<'a> {
@@ -102,6 +116,11 @@ impl<'a> ContextSubHost<'a> {
     pub fn framework_options(&self) -> FrameworkOptions {
         self.framework_options
     }
+
+    /// The `<template>` block of the file, for Vue and MPX files.
+    pub fn template(&self) -> Option<&Template<'a>> {
+        self.template.as_ref()
+    }
 }
 
 /// Stores shared information about a file being linted.
@@ -140,6 +159,10 @@ pub struct ContextHost<'a> {
     /// Set via the `--fix`, `--fix-suggestions`, and `--fix-dangerously` CLI
     /// flags.
     pub(super) fix: FixKind,
+    /// Rules whose fixes are applied, if not all of them.
+    ///
+    /// Set via the `--fix-only` CLI flag.
+    fix_only: Option<Arc<FixOnly>>,
     /// Path to the file being linted.
     pub(super) file_path: Box<Path>,
     /// Extension of the file being linted.
@@ -149,6 +172,12 @@ pub struct ContextHost<'a> {
     pub(super) config: Arc<LintConfig>,
     /// Front-end frameworks that might be in use in the target file.
     pub(super) frameworks: FrameworkFlags,
+    /// Test frameworks declared as dependencies by the nearest `package.json`, e.g. Vitest.
+    project_test_frameworks: FrameworkFlags,
+    /// `package.json` files of the lint run, to look up the one of the file being linted.
+    package_jsons: Arc<PackageJsonCache>,
+    /// `tsconfig.json` files of the lint run, to look up the one of the file being linted.
+    tsconfigs: Arc<TsConfigCache>,
 }
 
 impl std::fmt::Debug for ContextHost<'_> {
@@ -181,14 +210,66 @@ impl<'a> ContextHost<'a> {
             current_sub_host_index: Cell::new(0),
             diagnostics: RefCell::new(Vec::with_capacity(DIAGNOSTICS_INITIAL_CAPACITY)),
             fix: options.fix,
+            fix_only: None,
             file_path,
             file_extension,
             config,
             frameworks: options.framework_hints,
+            project_test_frameworks: FrameworkFlags::empty(),
+            package_jsons: Arc::default(),
+            tsconfigs: Arc::default(),
         }
         .sniff_for_frameworks()
     }
 
+    /// Only apply the fixes of the rules in `fix_only`.
+    pub(crate) fn with_fix_only(mut self, fix_only: Option<Arc<FixOnly>>) -> Self {
+        self.fix_only = fix_only;
+        self
+    }
+
+    /// Look up `package.json` files in `package_jsons`, shared with other files of the lint run.
+    /// The test frameworks of the nearest `package.json` gate the Vitest rules.
+    pub(crate) fn with_package_jsons(mut self, package_jsons: Arc<PackageJsonCache>) -> Self {
+        self.package_jsons = package_jsons;
+        if self.plugins().has_test() {
+            self.project_test_frameworks = self
+                .package_json()
+                .map(|package_json| frameworks::test_frameworks_of(&package_json))
+                .unwrap_or_default();
+        }
+        self
+    }
+
+    /// Look up `tsconfig.json` files in `tsconfigs`, shared with other files of the lint run.
+    pub(crate) fn with_tsconfigs(mut self, tsconfigs: Arc<TsConfigCache>) -> Self {
+        self.tsconfigs = tsconfigs;
+        self
+    }
+
+    /// The nearest `package.json` of the file being linted.
+    pub fn package_json(&self) -> Option<Arc<PackageJson>> {
+        self.package_jsons.nearest(&self.file_path)
+    }
+
+    /// The pnpm, yarn or npm workspace of the file being linted.
+    pub fn workspace(&self) -> Option<Arc<Workspace>> {
+        self.package_jsons.workspace(&self.file_path)
+    }
+
+    /// The compiler options of the nearest `tsconfig.json` of the file being linted.
+    pub fn compiler_options(&self) -> Option<Arc<CompilerOptions>> {
+        self.tsconfigs.nearest(&self.file_path)
+    }
+
+    /// The kind of fixes to apply for a rule. No fixes are applied for rules not in `--fix-only`.
+    pub(crate) fn rule_fix_kind(&self, plugin_name: &str, rule_name: &str) -> FixKind {
+        match &self.fix_only {
+            Some(fix_only) if !fix_only.contains(plugin_name, rule_name) => FixKind::None,
+            _ => self.fix,
+        }
+    }
+
     /// The current [`ContextSubHost`]
     pub fn current_sub_host(&self) -> &ContextSubHost<'a> {
         &self.sub_hosts[self.current_sub_host_index.get()]
@@ -269,6 +350,12 @@ impl<'a> ContextHost<'a> {
         &self.config.env
     }
 
+    /// Add a diagnostic message whose spans are relative to the whole file, not to the current
+    /// script block, like the ones about a `<template>` block.
+    pub(crate) fn push_file_diagnostic(&self, diagnostic: Message) {
+        self.diagnostics.borrow_mut().push(diagnostic);
+    }
+
     /// Add a diagnostic message to the end of the list of diagnostics. Can be used
     /// by any rule to report issues.
     #[inline]
@@ -302,77 +389,47 @@ impl<'a> ContextHost<'a> {
     }
 
     /// report unused enable/disable directives, add these as Messages to diagnostics
-    pub fn report_unused_directives(&self, rule_severity: Severity) {
-        // report unused disable
+    pub fn report_unused_directives(&self, severity: AllowWarnDeny) {
         // relate to lint result, check after linter run finish
-        let unused_disable_comments = self.disable_directives().collect_unused_disable_comments();
-        let message_for_disable = "Unused eslint-disable directive (no problems were reported).";
-        let fix_message = "remove unused disable directive";
-        let source_text = self.semantic().source_text();
-
-        for unused_disable_comment in unused_disable_comments {
-            let span = unused_disable_comment.span;
-            match &unused_disable_comment.r#type {
-                RuleCommentType::All => {
-                    // eslint-disable
-                    self.push_diagnostic(Message::new(
-                        OxcDiagnostic::error(message_for_disable)
-                            .with_label(span)
-                            .with_severity(rule_severity),
-                        PossibleFixes::Single(Fix::delete(span).with_message(fix_message)),
-                    ));
-                }
-                RuleCommentType::Single(rules_vec) => {
-                    for rule in rules_vec {
-                        let rule_message = Cow::<str>::Owned(format!(
-                            "Unused eslint-disable directive (no problems were reported from {}).",
-                            rule.rule_name
-                        ));
-
-                        let fix = rule.create_fix(source_text, span).with_message(fix_message);
-
-                        self.push_diagnostic(Message::new(
-                            OxcDiagnostic::error(rule_message)
-                                .with_label(rule.name_span)
-                                .with_severity(rule_severity),
-                            PossibleFixes::Single(fix),
-                        ));
-                    }
-                }
-            }
-        }
+        // unused directives don't belong to a rule, so they are not fixed with `--fix-only`
+        let source_text = self.fix_only.is_none().then(|| self.semantic().source_text());
+        self.append_diagnostics(create_unused_directives_messages(
+            self.disable_directives(),
+            severity,
+            source_text,
+        ));
+    }
+
+    /// Collect what is needed to insert disable comments for the diagnostics of the current
+    /// script block, before JS plugins take its [`Semantic`].
+    pub(crate) fn suppressor(&self) -> Suppressor<'a> {
+        Suppressor::new(
+            self.semantic(),
+            self.current_sub_host().source_text_offset,
+            self.line_ending(),
+        )
+    }
 
-        let unused_enable_comments = self.disable_directives().unused_enable_comments();
-        let mut unused_directive_diagnostics: Vec<(Cow<str>, Span)> =
-            Vec::with_capacity(unused_enable_comments.len());
-        // report unused enable
-        // not relate to lint result, check during comment directives' construction
-        let message_for_enable =
-            "Unused eslint-enable directive (no matching eslint-disable directives were found).";
-        for (rule_name, enable_comment_span) in self.disable_directives().unused_enable_comments() {
-            unused_directive_diagnostics.push((
-                rule_name.as_ref().map_or(Cow::Borrowed(message_for_enable), |name| {
-                    Cow::Owned(format!(
-                        "Unused eslint-enable directive (no matching eslint-disable directives were found for {name})."
-                    ))
-                }),
-                *enable_comment_span,
-            ));
-        }
+    /// Line break of the file. A script block of a framework file can be a single line, so the
+    /// first script block spanning multiple lines is used.
+    fn line_ending(&self) -> &'static str {
+        self.sub_hosts
+            .iter()
+            .map(|sub_host| sub_host.semantic.source_text())
+            .find(|source_text| source_text.contains('\n'))
+            .map_or("\n", line_ending)
+    }
 
-        self.append_diagnostics(
-            unused_directive_diagnostics
-                .into_iter()
-                .map(|(message, span)| {
-                    Message::new(
-                        OxcDiagnostic::error(message).with_label(span).with_severity(rule_severity),
-                        // TODO: fixer
-                        // copy the structure of disable directives
-                        PossibleFixes::None,
-                    )
-                })
-                .collect(),
-        );
+    /// Replace the fixes of the diagnostics from `start` on with disable comments.
+    /// Diagnostics from `external_start` on were reported by JS plugins.
+    pub(crate) fn suppress_diagnostics(
+        &self,
+        suppressor: &Suppressor<'a>,
+        start: usize,
+        external_start: usize,
+    ) {
+        let mut diagnostics = self.diagnostics.borrow_mut();
+        suppressor.suppress(&mut diagnostics[start..], external_start - start);
     }
 
     /// Take ownership of all diagnostics collected during linting.
@@ -403,7 +460,6 @@ impl<'a> ContextHost<'a> {
         cb(self.diagnostics.borrow_mut().as_mut());
     }
 
-    #[cfg(debug_assertions)]
     pub fn diagnostic_count(&self) -> usize {
         self.diagnostics.borrow().len()
     }
@@ -414,6 +470,7 @@ impl<'a> ContextHost<'a> {
         let plugin_name = rule.plugin_name();
 
         LintContext {
+            fix: self.rule_fix_kind(plugin_name, rule_name),
             parent: self,
             current_rule_name: rule_name,
             current_plugin_name: plugin_name,
@@ -421,6 +478,7 @@ impl<'a> ContextHost<'a> {
             #[cfg(debug_assertions)]
             current_rule_fix_capabilities: rule.fix(),
             severity: severity.into(),
+            report_range: None,
         }
     }
 
@@ -428,6 +486,7 @@ impl<'a> ContextHost<'a> {
     #[cfg(test)]
     pub(crate) fn spawn_for_test(self: Rc<Self>) -> LintContext<'a> {
         LintContext {
+            fix: self.fix,
             parent: Rc::clone(&self),
             current_rule_name: "",
             current_plugin_name: "eslint",
@@ -435,6 +494,7 @@ impl<'a> ContextHost<'a> {
             #[cfg(debug_assertions)]
             current_rule_fix_capabilities: crate::rule::RuleFixMeta::None,
             severity: oxc_diagnostics::Severity::Warning,
+            report_range: None,
         }
     }
 
@@ -461,6 +521,16 @@ impl<'a> ContextHost<'a> {
         self
     }
 
+    /// Whether rules specific to Vitest should be skipped for the target file,
+    /// i.e. it doesn't import `vitest`, and the nearest `package.json` depends
+    /// on Jest but not on Vitest.
+    ///
+    /// The framework flags of the file are not changed by its `package.json`,
+    /// so the Jest rules shared with Vitest keep running on test files.
+    pub fn skips_vitest_rules(&self) -> bool {
+        !self.frameworks.is_vitest() && self.project_test_frameworks == FrameworkFlags::Jest
+    }
+
     /// Returns the framework hints for the target file.
     #[inline]
     pub fn frameworks(&self) -> FrameworkFlags {
@@ -471,6 +541,11 @@ impl<'a> ContextHost<'a> {
         self.current_sub_host().framework_options
     }
 
+    /// The `<template>` block of the file, for Vue and MPX files.
+    pub fn template(&self) -> Option<&Template<'a>> {
+        self.current_sub_host().template()
+    }
+
     pub fn other_file_hosts(&self) -> Vec<&ContextSubHost<'a>> {
         self.sub_hosts
             .iter()
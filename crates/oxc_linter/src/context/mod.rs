@@ -1,13 +1,17 @@
 #![expect(rustdoc::private_intra_doc_links)] // useful for intellisense
 
-use std::{ffi::OsStr, ops::Deref, path::Path, rc::Rc};
+use std::{borrow::Cow, ffi::OsStr, ops::Deref, path::Path, rc::Rc, sync::Arc};
 
 use javascript_globals::GLOBALS;
 
-use oxc_ast::ast::IdentifierReference;
+use oxc_ast::ast::{Expression, IdentifierReference};
 use oxc_cfg::ControlFlowGraph;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
-use oxc_semantic::Semantic;
+use oxc_ecmascript::{
+    GlobalContext,
+    side_effects::{MayHaveSideEffectsContext, PropertyReadSideEffects},
+};
+use oxc_semantic::{IsGlobalReference, Semantic};
 use oxc_span::Span;
 
 #[cfg(debug_assertions)]
@@ -16,8 +20,11 @@ use crate::{
     AllowWarnDeny, FrameworkFlags, ModuleRecord, OxlintEnv, OxlintGlobals, OxlintSettings,
     config::GlobalValue,
     disable_directives::DisableDirectives,
-    fixer::{Fix, FixKind, Message, PossibleFixes, RuleFix, RuleFixer},
+    fixer::{Fix, FixKind, Message, PossibleFixes, RuleFix, RuleFixer, Suggestion},
     frameworks::FrameworkOptions,
+    loader::Template,
+    package_json::{PackageJson, Workspace},
+    tsconfig::CompilerOptions,
 };
 
 mod host;
@@ -41,6 +48,9 @@ pub struct LintContext<'a> {
     current_plugin_prefix: &'static str,
     /// Kebab-cased name of the current rule being linted. Example: `no-unused-vars`, `no-undef`.
     current_rule_name: &'static str,
+    /// Kind of fixes to apply for the current rule. Same as the fix kind of the [`ContextHost`],
+    /// unless the rule is left out by `--fix-only`.
+    fix: FixKind,
     /// Capabilities of the current rule to fix issues. Indicates whether:
     /// - Rule cannot be auto-fixed [`RuleFixMeta::None`]
     /// - Rule needs an auto-fix to be written still [`RuleFixMeta::FixPending`]
@@ -58,6 +68,9 @@ pub struct LintContext<'a> {
     /// }
     /// ```
     severity: Severity,
+    /// Range of the file the rule reports diagnostics in, when the rule is configured with
+    /// inline config comments. Diagnostics outside of it are dropped.
+    report_range: Option<Span>,
 }
 
 impl<'a> Deref for LintContext<'a> {
@@ -71,7 +84,8 @@ impl<'a> Deref for LintContext<'a> {
 
 impl<'a> LintContext<'a> {
     /// Base URL for the documentation, used to generate rule documentation URLs when a diagnostic is reported.
-    const WEBSITE_BASE_URL: &'static str = "https://oxc.rs/docs/guide/usage/linter/rules";
+    pub(crate) const WEBSITE_BASE_URL: &'static str =
+        "https://oxc.rs/docs/guide/usage/linter/rules";
 
     /// Set the plugin name for the current rule.
     pub fn with_plugin_name(mut self, plugin: &'static str) -> Self {
@@ -101,6 +115,15 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    /// Restrict the diagnostics of the rule to `range` of the current script block.
+    pub(crate) fn with_report_range(mut self, range: Option<Span>) -> Self {
+        let offset = self.parent.current_sub_host().source_text_offset;
+        self.report_range = range.map(|range| {
+            Span::new(range.start.saturating_add(offset), range.end.saturating_add(offset))
+        });
+        self
+    }
+
     /// Get information such as the control flow graph, bound symbols, AST, etc.
     /// for the file being linted.
     ///
@@ -256,10 +279,20 @@ impl<'a> LintContext<'a> {
 
     /// Add a diagnostic message to the list of diagnostics. Outputs a diagnostic with the current rule
     /// name, severity, and a link to the rule's documentation URL.
-    fn add_diagnostic(&self, mut message: Message) {
+    fn add_diagnostic(&self, message: Message) {
+        if let Some(range) = self.report_range
+            && !(range.start <= message.span.start && message.span.start < range.end)
+        {
+            return;
+        }
         if self.parent.disable_directives().contains(self.current_rule_name, message.span) {
             return;
         }
+        self.parent.push_diagnostic(self.with_rule_info(message));
+    }
+
+    /// Set the error code, docs URL and severity of the current rule on `message`.
+    fn with_rule_info(&self, mut message: Message) -> Message {
         message.error = message
             .error
             .with_error_code(self.current_plugin_prefix, self.current_rule_name)
@@ -272,8 +305,7 @@ impl<'a> LintContext<'a> {
         if message.error.severity != self.severity {
             message.error = message.error.with_severity(self.severity);
         }
-
-        self.parent.push_diagnostic(message);
+        message
     }
 
     /// Report a lint rule violation.
@@ -287,6 +319,15 @@ impl<'a> LintContext<'a> {
         );
     }
 
+    /// Report a lint rule violation in the `<template>` block of a Vue or MPX file.
+    ///
+    /// Spans of the diagnostic are relative to the whole file, as the ones of [`Template`].
+    pub fn template_diagnostic(&self, diagnostic: OxcDiagnostic) {
+        self.parent.push_file_diagnostic(
+            self.with_rule_info(Message::new(diagnostic, PossibleFixes::None)),
+        );
+    }
+
     /// Report a lint rule violation and provide an automatic fix.
     ///
     /// The second argument is a [closure] that takes a [`RuleFixer`] and
@@ -457,7 +498,7 @@ impl<'a> LintContext<'a> {
                     rule_fix.kind()
                 );
 
-                if self.parent.fix.can_apply(rule_fix.kind()) && !rule_fix.is_empty() {
+                if self.fix.can_apply(rule_fix.kind()) && !rule_fix.is_empty() {
                     Some(rule_fix.into_fix(self.source_text()))
                 } else {
                     None
@@ -475,6 +516,53 @@ impl<'a> LintContext<'a> {
         }
     }
 
+    /// Report a lint rule violation with suggestions for fixing it, which editors offer as code
+    /// actions.
+    ///
+    /// Unlike [`LintContext::diagnostic_with_suggestions`], the suggestions are never applied
+    /// automatically, not even with `--fix-suggestions`. Use this when picking the right change
+    /// requires knowing the intent of the code.
+    ///
+    /// The second argument is a [closure] that takes a [`RuleFixer`] and returns the suggestions.
+    /// Each [`RuleFix`] needs a message describing the change, see [`RuleFix::with_message`].
+    ///
+    /// [closure]: <https://doc.rust-lang.org/book/ch13-01-closures.html>
+    pub fn diagnostic_with_manual_suggestions<F, I>(&self, diagnostic: OxcDiagnostic, suggest: F)
+    where
+        F: FnOnce(RuleFixer<'_, 'a>) -> I,
+        I: IntoIterator<Item = RuleFix>,
+    {
+        #[cfg(debug_assertions)]
+        debug_assert!(
+            self.current_rule_fix_capabilities.supports_fix(FixKind::Suggestion),
+            "Rule `{}` does not support suggestions. Did you forget to update fix capabilities in declare_oxc_lint?.\n\tSupported fix kinds: {:?}",
+            self.current_rule_name,
+            FixKind::from(self.current_rule_fix_capabilities),
+        );
+
+        let suggestions = suggest(RuleFixer::new(FixKind::Suggestion, self).for_multifix())
+            .into_iter()
+            .filter(|rule_fix| !rule_fix.is_empty())
+            .map(|rule_fix| {
+                debug_assert!(
+                    rule_fix.message().is_some_and(|message| !message.is_empty()),
+                    "Rule `{}/{}` suggestion should have a message.",
+                    self.current_plugin_name,
+                    self.current_rule_name,
+                );
+                let mut fix = rule_fix.into_fix(self.source_text());
+                let message = fix.message.take().unwrap_or(Cow::Borrowed("Apply suggestion"));
+                Suggestion::new(message, fix)
+            })
+            .collect();
+
+        self.add_diagnostic(
+            Message::new(diagnostic, PossibleFixes::None)
+                .with_suggestions(suggestions)
+                .with_section_offset(self.parent.current_sub_host().source_text_offset),
+        );
+    }
+
     fn create_fix<C, F>(
         &self,
         fix_kind: FixKind,
@@ -501,7 +589,7 @@ impl<'a> LintContext<'a> {
             _ => diagnostic,
         };
 
-        if self.parent.fix.can_apply(rule_fix.kind()) && !rule_fix.is_empty() {
+        if self.fix.can_apply(rule_fix.kind()) && !rule_fix.is_empty() {
             let fix = rule_fix.into_fix(self.source_text());
             #[cfg(debug_assertions)]
             {
@@ -523,6 +611,22 @@ impl<'a> LintContext<'a> {
         }
     }
 
+    /// The nearest `package.json` of the file being linted, in its directory or a parent directory.
+    pub fn package_json(&self) -> Option<Arc<PackageJson>> {
+        self.parent.package_json()
+    }
+
+    /// The pnpm, yarn or npm workspace of the file being linted, if it is in one.
+    pub fn workspace(&self) -> Option<Arc<Workspace>> {
+        self.parent.workspace()
+    }
+
+    /// The compiler options of the nearest `tsconfig.json` of the file being linted, in its
+    /// directory or a parent directory.
+    pub fn compiler_options(&self) -> Option<Arc<CompilerOptions>> {
+        self.parent.compiler_options()
+    }
+
     /// Framework flags, indicating front-end frameworks that might be in use.
     pub fn frameworks(&self) -> FrameworkFlags {
         self.parent.frameworks
@@ -537,6 +641,40 @@ impl<'a> LintContext<'a> {
     pub fn other_file_hosts(&self) -> Vec<&ContextSubHost<'a>> {
         self.parent.other_file_hosts()
     }
+
+    /// The `<template>` block of the file, for Vue and MPX files.
+    pub fn template(&self) -> Option<&Template<'a>> {
+        self.parent.template()
+    }
+}
+
+impl<'a> GlobalContext<'a> for LintContext<'a> {
+    fn is_global_reference(&self, reference: &IdentifierReference<'a>) -> bool {
+        reference.is_global_reference(self.scoping())
+    }
+}
+
+/// Lets rules share the side effect analysis used by the minifier, e.g.
+/// `expr.may_have_side_effects(ctx)`.
+///
+/// Pure annotations are respected, while property reads and unknown globals are assumed to have
+/// side effects, the same as the minifier's defaults.
+impl<'a> MayHaveSideEffectsContext<'a> for LintContext<'a> {
+    fn annotations(&self) -> bool {
+        true
+    }
+
+    fn manual_pure_functions(&self, _callee: &Expression) -> bool {
+        false
+    }
+
+    fn property_read_side_effects(&self) -> PropertyReadSideEffects {
+        PropertyReadSideEffects::All
+    }
+
+    fn unknown_global_side_effects(&self) -> bool {
+        true
+    }
 }
 
 /// Gets the prefixed plugin name, given the short plugin name.
@@ -547,7 +685,7 @@ impl<'a> LintContext<'a> {
 /// assert_eq!(plugin_name_to_prefix("react"), "eslint-plugin-react");
 /// ```
 #[inline]
-fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
+pub fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
     match plugin_name {
         "import" => "eslint-plugin-import",
         "jest" => "eslint-plugin-jest",
@@ -562,6 +700,10 @@ fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
         "vitest" => "eslint-plugin-vitest",
         "node" => "eslint-plugin-node",
         "vue" => "eslint-plugin-vue",
+        "security" => "eslint-plugin-security",
+        "sonarjs" => "eslint-plugin-sonarjs",
+        "regexp" => "eslint-plugin-regexp",
+        "i18next" => "eslint-plugin-i18next",
         _ => plugin_name,
     }
 }
@@ -0,0 +1,118 @@
+//! Dead code detection.
+//!
+//! Finds code which can be removed without changing the behavior of the program:
+//! - statements which are unreachable in the control flow graph,
+//! - private class members which are never used,
+//! - variables which are assigned but never read.
+//!
+//! [`find_dead_code`] analyzes a single file. Exported variables may be read by other modules, so
+//! [`find_dead_exports`] checks them against the [`ModuleGraph`] once all modules are linted.
+//!
+//! The analysis backs the `eslint/no-unreachable`, `eslint/no-unused-private-class-members`
+//! and `oxc/no-write-only-variables` rules, and can be used directly by build tools which want a
+//! report of the dead code in a project.
+
+mod private_members;
+mod unreachable;
+mod write_only;
+
+use std::path::Path;
+
+use oxc_semantic::Semantic;
+use oxc_span::{CompactStr, Span};
+
+use crate::ModuleGraph;
+
+pub use private_members::unused_private_members;
+pub use unreachable::unreachable_statements;
+pub use write_only::write_only_variables;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeadCodeKind {
+    /// A statement which can never run.
+    UnreachableStatement,
+    /// A private class property or method which is never used.
+    UnusedPrivateMember,
+    /// A variable which is assigned but never read.
+    WriteOnlyVariable,
+}
+
+/// A piece of dead code in a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeadCode {
+    pub kind: DeadCodeKind,
+    /// Name of the member or variable. `None` for statements.
+    pub name: Option<CompactStr>,
+    pub span: Span,
+}
+
+/// Dead code in the file of `semantic`, sorted by position.
+///
+/// Exported variables are not included, see [`find_dead_exports`].
+/// Unreachable statements are only found if `semantic` was built with a control flow graph.
+pub fn find_dead_code(semantic: &Semantic<'_>) -> Vec<DeadCode> {
+    let unreachable = unreachable_statements(semantic).into_iter().map(|span| DeadCode {
+        kind: DeadCodeKind::UnreachableStatement,
+        name: None,
+        span,
+    });
+    let private_members =
+        unused_private_members(semantic).into_iter().map(|(name, span)| DeadCode {
+            kind: DeadCodeKind::UnusedPrivateMember,
+            name: Some(CompactStr::from(name)),
+            span,
+        });
+    let write_only = write_only_variables(semantic)
+        .into_iter()
+        .filter(|variable| variable.export_names.is_empty())
+        .map(|variable| DeadCode {
+            kind: DeadCodeKind::WriteOnlyVariable,
+            name: Some(CompactStr::from(variable.name)),
+            span: variable.span,
+        });
+    let mut dead_code = unreachable.chain(private_members).chain(write_only).collect::<Vec<_>>();
+    dead_code.sort_unstable_by_key(|dead_code| (dead_code.span.start, dead_code.span.end));
+    dead_code
+}
+
+/// Exported variables of the module at `path` which are never read in the module, and never
+/// imported by another module of `module_graph`. Must be called once all modules are in the graph.
+///
+/// Modules which are not imported at all are skipped, as their exports may be used outside of
+/// the project, e.g. by the entry point of a library.
+pub fn find_dead_exports(path: &Path, module_graph: &ModuleGraph) -> Vec<DeadCode> {
+    let records = module_graph.module_records(path);
+    let [record] = records.as_slice() else {
+        return vec![];
+    };
+    if record.write_only_exports.is_empty()
+        || module_graph.importers(path).iter().all(|importer| importer == path)
+    {
+        return vec![];
+    }
+    let Some(imported_names) = module_graph.imported_names(path) else {
+        return vec![];
+    };
+
+    let mut dead_code = record
+        .write_only_exports
+        .iter()
+        .filter(|(name, _)| !imported_names.contains(*name))
+        .map(|(name, span)| DeadCode {
+            kind: DeadCodeKind::WriteOnlyVariable,
+            name: Some(name.clone()),
+            span: *span,
+        })
+        .collect::<Vec<_>>();
+    // A variable exported under several names is only dead if none of them is imported.
+    dead_code.retain(|dead| {
+        record
+            .write_only_exports
+            .iter()
+            .filter(|(_, span)| **span == dead.span)
+            .all(|(name, _)| !imported_names.contains(name))
+    });
+    dead_code.sort_unstable_by(|a, b| a.span.start.cmp(&b.span.start).then(a.name.cmp(&b.name)));
+    dead_code.dedup_by_key(|dead| dead.span);
+    dead_code
+}
@@ -0,0 +1,189 @@
+use itertools::Itertools;
+use oxc_ast::{AstKind, ast::AssignmentOperator};
+use oxc_semantic::{AstNode, NodeId, Semantic};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::class::ElementKind;
+
+/// Names and spans of the private properties and methods of classes which are never used.
+///
+/// A property is only used if it is read. Writing to it, including updating it with `++` or a
+/// compound assignment whose result is unused, doesn't count. Private accessors are used if they
+/// are either read or written.
+pub fn unused_private_members<'s>(semantic: &'s Semantic<'_>) -> Vec<(&'s str, Span)> {
+    let classes = semantic.classes();
+    let mut members = vec![];
+    for (class_id, elements) in classes.elements.iter_enumerated() {
+        for (element_id, element) in elements.iter_enumerated() {
+            if !element.kind.intersects(ElementKind::Property | ElementKind::Method) {
+                continue;
+            }
+
+            if element.is_private
+                && !classes.iter_private_identifiers(class_id).any(|ident| {
+                    // If the element is a property, it must be read.
+                    (!element.kind.is_property() || is_read(ident.id, semantic))
+                        && ident.element_ids.contains(&element_id)
+                })
+            {
+                members.push((element.name.as_ref(), element.span));
+            }
+        }
+    }
+    members
+}
+
+fn is_read(current_node_id: NodeId, semantic: &Semantic) -> bool {
+    for (curr, parent) in semantic
+        .nodes()
+        .ancestors(current_node_id)
+        .filter(|parent| {
+            !matches!(
+                parent.kind(),
+                AstKind::ParenthesizedExpression(_)
+                    | AstKind::TSAsExpression(_)
+                    | AstKind::TSSatisfiesExpression(_)
+                    | AstKind::TSInstantiationExpression(_)
+                    | AstKind::TSNonNullExpression(_)
+                    | AstKind::TSTypeAssertion(_)
+            )
+        })
+        .tuple_windows::<(&AstNode<'_>, &AstNode<'_>)>()
+    {
+        match (curr.kind(), parent.kind()) {
+            // Skip member expressions in identifier context
+            (member_expr, AstKind::IdentifierReference(_))
+                if member_expr.is_member_expression_kind() => {}
+            // Skip identifier references in assignment targets
+            (
+                AstKind::IdentifierReference(_),
+                AstKind::ArrayAssignmentTarget(_)
+                | AstKind::ObjectAssignmentTarget(_)
+                | AstKind::IdentifierReference(_),
+            ) => {}
+            // All these are read contexts for private fields
+            (AstKind::PrivateFieldExpression(_), _) if is_value_context(parent, curr, semantic) => {
+                return true;
+            }
+            // AssignmentExpression: right-hand side is a read, compound assignment result in value context is a read
+            (AstKind::PrivateFieldExpression(_), AstKind::AssignmentExpression(assign_expr)) => {
+                // Right-hand side of assignment is a read
+                if assign_expr.right.span() == curr.span() {
+                    return true;
+                }
+                // Compound assignment result used in a value context is a read
+                if assign_expr.operator != AssignmentOperator::Assign
+                    && is_compound_assignment_read(parent.id(), semantic)
+                {
+                    return true;
+                }
+                // Not a read otherwise
+                return false;
+            }
+            // ForIn/ForOf: only right-hand side is a read
+            (AstKind::PrivateFieldExpression(_), AstKind::ForInStatement(for_in)) => {
+                if for_in.right.span() == curr.span() {
+                    return true;
+                }
+            }
+            (AstKind::PrivateFieldExpression(_), AstKind::ForOfStatement(for_of)) => {
+                if for_of.right.span() == curr.span() {
+                    return true;
+                }
+            }
+            // AssignmentTargetPropertyProperty: only computed property name is a read
+            (
+                AstKind::PrivateFieldExpression(_),
+                AstKind::AssignmentTargetPropertyProperty(prop),
+            ) => {
+                if prop.computed && prop.name.span() == curr.span() {
+                    return true;
+                }
+            }
+            (
+                AstKind::PrivateFieldExpression(_),
+                AstKind::ConditionalExpression(conditional_expr),
+            ) => {
+                if conditional_expr.test.span() == curr.span() {
+                    return true;
+                }
+                if is_value_context(parent, curr, semantic) {
+                    return true;
+                }
+            }
+            _ => {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Check if the given AST kind represents a context where a value is being read/used
+/// `parent` is the parent node, `child` is the child node we're checking
+fn is_value_context(parent: &AstNode, child: &AstNode, semantic: &Semantic<'_>) -> bool {
+    match parent.kind() {
+        AstKind::ReturnStatement(_)
+        | AstKind::CallExpression(_)
+        | AstKind::NewExpression(_)
+        | AstKind::BinaryExpression(_)
+        | AstKind::VariableDeclarator(_)
+        | AstKind::PropertyDefinition(_)
+        | AstKind::ArrayExpression(_)
+        | AstKind::ObjectProperty(_)
+        | AstKind::JSXExpressionContainer(_)
+        | AstKind::ChainExpression(_)
+        | AstKind::StaticMemberExpression(_)
+        | AstKind::ComputedMemberExpression(_)
+        | AstKind::TemplateLiteral(_)
+        | AstKind::UnaryExpression(_)
+        | AstKind::IfStatement(_)
+        | AstKind::SpreadElement(_)
+        | AstKind::AssignmentPattern(_)
+        | AstKind::SwitchCase(_)
+        | AstKind::ThrowStatement(_)
+        | AstKind::WhileStatement(_)
+        | AstKind::DoWhileStatement(_)
+        | AstKind::SequenceExpression(_) => true,
+        AstKind::FormalParameter(p) => {
+            p.initializer.as_ref().is_some_and(|init| init.span().contains_inclusive(child.span()))
+        }
+        AstKind::AssignmentExpression(assign_expr) => {
+            // The right-hand side of an assignment is always in a value context (being read for assignment)
+            assign_expr.right.span().contains_inclusive(child.span())
+        }
+        AstKind::ExpressionStatement(_) => {
+            let grandparent = semantic.nodes().parent_node(parent.id());
+            if let AstKind::FunctionBody(_) = grandparent.kind()
+                && let AstKind::ArrowFunctionExpression(arrow) =
+                    semantic.nodes().parent_kind(grandparent.id())
+                && arrow.expression
+            {
+                return true;
+            }
+            false
+        }
+        AstKind::ParenthesizedExpression(_)
+        | AstKind::TSAsExpression(_)
+        | AstKind::TSSatisfiesExpression(_)
+        | AstKind::TSInstantiationExpression(_)
+        | AstKind::TSNonNullExpression(_)
+        | AstKind::TSTypeAssertion(_)
+        | AstKind::UpdateExpression(_)
+        | AstKind::AwaitExpression(_)
+        | AstKind::ConditionalExpression(_)
+        | AstKind::LogicalExpression(_) => {
+            let grandparent = semantic.nodes().parent_node(parent.id());
+            is_value_context(grandparent, parent, semantic)
+        }
+
+        _ => false,
+    }
+}
+
+/// Check if a compound assignment result is being used in a value context
+fn is_compound_assignment_read(parent_id: NodeId, semantic: &Semantic) -> bool {
+    let assignment_expr = semantic.nodes().get_node(parent_id);
+    semantic.nodes().ancestors(parent_id).next().is_some_and(|assignment_parent| {
+        is_value_context(assignment_parent, assignment_expr, semantic)
+    })
+}
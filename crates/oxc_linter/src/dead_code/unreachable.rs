@@ -0,0 +1,141 @@
+use oxc_ast::{AstKind, ast::VariableDeclarationKind};
+use oxc_cfg::{
+    EdgeType, ErrorEdgeKind, Instruction, InstructionKind,
+    graph::{
+        Direction,
+        visit::{Control, DfsEvent, EdgeRef, depth_first_search},
+    },
+};
+use oxc_semantic::{NodeId, Semantic};
+use oxc_span::{GetSpan, Span};
+
+/// Spans of the statements which can never run, e.g. after `return`, `throw`, `break` and
+/// `continue`, or after an infinite loop.
+///
+/// Empty statements, and `var` declarations without an initializer, are not included, as the
+/// declarations are hoisted. Returns nothing if `semantic` was built without a control flow graph.
+pub fn unreachable_statements(semantic: &Semantic<'_>) -> Vec<Span> {
+    let nodes = semantic.nodes();
+    let root = nodes.get_node(NodeId::ROOT);
+    let Some(cfg) = semantic.cfg() else {
+        return vec![];
+    };
+    let graph = cfg.graph();
+
+    // A pre-allocated vector containing the reachability status of all the basic blocks.
+    // We initialize this vector with all nodes set to `unreachable` since if we don't visit a
+    // node in our paths then it should be unreachable by definition.
+    let mut unreachables = vec![true; cfg.basic_blocks.len()];
+
+    // All of the end points of infinite loops we encountered.
+    let mut infinite_loops = Vec::new();
+
+    // Set the root as reachable.
+    let root_cfg_id = semantic.nodes().cfg_id(root.id());
+    unreachables[root_cfg_id.index()] = false;
+
+    // In our first path we first check if each block is definitely unreachable, If it is then
+    // we set it as such, If we encounter an infinite loop we keep its end block since it can
+    // prevent other reachable blocks from ever getting executed.
+    let _: Control<()> = depth_first_search(graph, Some(root_cfg_id), |event| {
+        if let DfsEvent::Finish(node, _) = event {
+            let unreachable = cfg.basic_block(node).is_unreachable();
+            unreachables[node.index()] = unreachable;
+
+            if !unreachable
+                && let Some(it) = cfg.is_infinite_loop_start(node, |instruction| {
+                    use oxc_cfg::EvalConstConditionResult::{Eval, Fail, NotFound};
+                    match instruction {
+                        Instruction { kind: InstructionKind::Condition, node_id: Some(id) } => {
+                            match nodes.kind(*id) {
+                                AstKind::BooleanLiteral(lit) => Eval(lit.value),
+                                _ => Fail,
+                            }
+                        }
+                        _ => NotFound,
+                    }
+                })
+            {
+                infinite_loops.push(it);
+            }
+        }
+        Control::Continue
+    });
+
+    // In the second path we go for each infinite loop end block and follow it marking all
+    // edges as unreachable unless they have a reachable jump (eg. break).
+    for loop_ in infinite_loops {
+        // A loop end block usually is also its condition and start point but what is common
+        // in all cases is that it may have `Jump` or `Backedge` edges so we only want to
+        // follow the `Normal` edges as these are the exiting edges.
+        let starts: Vec<_> = graph
+            .edges_directed(loop_.1, Direction::Outgoing)
+            .filter(|it| matches!(it.weight(), EdgeType::Normal))
+            .map(|it| it.target())
+            .collect();
+
+        // Search with all `Normal` edges as starting point(s).
+        let _: Control<()> = depth_first_search(graph, starts, |event| match event {
+            DfsEvent::Discover(node, _) => {
+                let mut incoming = graph.edges_directed(node, Direction::Incoming);
+                if incoming.any(|e| match e.weight() {
+                    // `NewFunction` is always reachable
+                    | EdgeType::NewFunction
+                    // `Finalize` can be reachable if we encounter an error in the loop.
+                    | EdgeType::Finalize
+                    // Explicit `Error` can also be reachable if we encounter an error in the loop.
+                    | EdgeType::Error(ErrorEdgeKind::Explicit) => true,
+
+                    // If we have an incoming `Jump` and it is from a `Break` instruction,
+                    // We know with high confidence that we are visiting a reachable block.
+                    // NOTE: May cause false negatives but I couldn't think of one.
+                    EdgeType::Jump
+                        if cfg
+                            .basic_block(e.source())
+                            .instructions()
+                            .iter()
+                            .any(|it| matches!(it.kind, InstructionKind::Break(_))) =>
+                    {
+                        true
+                    }
+                    _ => false,
+                }) {
+                    // We prune this branch if it is reachable from this point forward.
+                    Control::Prune
+                } else {
+                    // Otherwise we set it to unreachable and continue.
+                    unreachables[node.index()] = true;
+                    Control::Continue
+                }
+            }
+            _ => Control::Continue,
+        });
+    }
+    let mut statements = vec![];
+    for node in semantic.nodes() {
+        // exit early if we are not visiting a statement.
+        if !node.kind().is_statement() {
+            continue;
+        }
+
+        // exit early if it is an empty statement.
+        if matches!(node.kind(), AstKind::EmptyStatement(_)) {
+            continue;
+        }
+
+        if matches!(
+            node.kind(),
+            AstKind::VariableDeclaration(decl)
+                if matches!(decl.kind, VariableDeclarationKind::Var) && !decl.has_init()
+        ) {
+            // Skip `var` declarations without any initialization,
+            // These work because of the JavaScript hoisting rules.
+            continue;
+        }
+
+        if unreachables[semantic.nodes().cfg_id(node.id()).index()] {
+            statements.push(node.kind().span());
+        }
+    }
+    statements
+}
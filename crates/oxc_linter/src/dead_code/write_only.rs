@@ -0,0 +1,85 @@
+use oxc_ast::AstKind;
+use oxc_semantic::{Semantic, SymbolFlags, SymbolId};
+use oxc_span::Span;
+
+/// A variable which is assigned, but whose value is never read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WriteOnlyVariable<'s> {
+    pub symbol_id: SymbolId,
+    pub name: &'s str,
+    /// Span of the declared identifier.
+    pub span: Span,
+    /// Names the variable is exported as. Other modules may read exported variables, so these
+    /// are only dead if no module imports them.
+    pub export_names: Vec<&'s str>,
+}
+
+/// Variables, including parameters, which are assigned at least once and never read.
+/// Catch parameters are not included.
+///
+/// Updates such as `x++` and `x += 1` read the variable, so the variable is not write-only.
+/// Nothing is returned for a file with a direct `eval`, which may read any variable. In scripts,
+/// variables in the global scope are not included, as other scripts may read them.
+pub fn write_only_variables<'s>(semantic: &'s Semantic<'_>) -> Vec<WriteOnlyVariable<'s>> {
+    let scoping = semantic.scoping();
+    let nodes = semantic.nodes();
+    if scoping
+        .scope_descendants_from_root()
+        .any(|scope_id| scoping.scope_flags(scope_id).contains_direct_eval())
+    {
+        return vec![];
+    }
+    let is_script = !semantic.source_type().is_module();
+
+    let mut variables = vec![];
+    'symbols: for symbol_id in scoping.symbol_ids() {
+        let flags = scoping.symbol_flags(symbol_id);
+        if !flags.intersects(SymbolFlags::Variable)
+            || flags.intersects(SymbolFlags::Ambient | SymbolFlags::CatchVariable)
+        {
+            continue;
+        }
+        if is_script && scoping.symbol_scope_id(symbol_id) == scoping.root_scope_id() {
+            continue;
+        }
+
+        let mut is_written = false;
+        let mut export_names = vec![];
+        for reference in scoping.get_resolved_references(symbol_id) {
+            let reference_flags = reference.flags();
+            if reference_flags.is_write() && !reference_flags.is_read() {
+                is_written = true;
+            } else if let AstKind::ExportSpecifier(specifier) =
+                nodes.parent_kind(reference.node_id())
+            {
+                // `export { x }` doesn't read the value
+                export_names.push(specifier.exported.name().as_str());
+            } else {
+                continue 'symbols;
+            }
+        }
+        if !is_written {
+            continue;
+        }
+
+        let name = scoping.symbol_name(symbol_id);
+        // `export let x`
+        let declaration = scoping.symbol_declaration(symbol_id);
+        if matches!(nodes.kind(declaration), AstKind::VariableDeclarator(_))
+            && matches!(
+                nodes.parent_kind(nodes.parent_id(declaration)),
+                AstKind::ExportNamedDeclaration(_)
+            )
+        {
+            export_names.push(name);
+        }
+
+        variables.push(WriteOnlyVariable {
+            symbol_id,
+            name,
+            span: scoping.symbol_span(symbol_id),
+            export_names,
+        });
+    }
+    variables
+}
@@ -6,7 +6,7 @@ use oxc_span::Span;
 use rust_lapper::{Interval, Lapper};
 use rustc_hash::FxHashMap;
 
-use crate::fixer::Fix;
+use crate::fixer::{Fix, Message, PossibleFixes};
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 enum DisabledRule {
@@ -120,14 +120,16 @@ impl RuleCommentRule {
         let after_source = &source_text[self.name_span.end as usize..comment_span.end as usize];
 
         // check if there is a comma after the rule name
-        // if there is, remove the comma, whitespace and the rule name
+        // if there is, remove the rule name, the comma and the whitespace around it
         let mut comma_after_offset = None;
         for (i, c) in after_source.char_indices() {
             if c.is_whitespace() {
                 continue;
             }
             if c == ',' {
-                comma_after_offset = Some(1 + i as u32);
+                let after_comma = &after_source[i + 1..];
+                let whitespace = after_comma.len() - after_comma.trim_start().len();
+                comma_after_offset = Some((i + 1 + whitespace) as u32);
             }
             break;
         }
@@ -162,12 +164,64 @@ pub struct DisableRuleComment {
     pub r#type: RuleCommentType,
 }
 
+impl DisableRuleComment {
+    /// Create a fix which removes the whole comment, e.g. when none of its rules are used.
+    ///
+    /// A comment alone on its line is removed with the line. A comment after code on the same
+    /// line is removed with the whitespace before it, so no trailing whitespace is left behind.
+    #[expect(clippy::cast_possible_truncation)] // for `as u32`
+    pub fn create_fix(&self, source_text: &str) -> Fix {
+        // `self.span` only covers the content of the comment, extend it to the delimiters.
+        let mut start = self.span.start as usize;
+        let mut end = self.span.end as usize;
+        if source_text[..start].ends_with("//") {
+            start -= 2;
+        } else if source_text[..start].ends_with("/*") && source_text[end..].starts_with("*/") {
+            start -= 2;
+            end += 2;
+        }
+
+        let line_start = source_text[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source_text[end..].find('\n').map_or(source_text.len(), |i| end + i);
+        let before = &source_text[line_start..start];
+        let after = &source_text[end..line_end];
+
+        let span = match (before.trim().is_empty(), after.trim().is_empty()) {
+            // `// eslint-disable-next-line`
+            (true, true) => {
+                Span::new(line_start as u32, (line_end + 1).min(source_text.len()) as u32)
+            }
+            // `/* eslint-disable */ foo();`
+            (true, false) => {
+                Span::new(start as u32, (end + after.len() - after.trim_start().len()) as u32)
+            }
+            // `foo(); // eslint-disable-line`
+            (false, true) => Span::new(
+                (start - (before.len() - before.trim_end().len())) as u32,
+                (end + after.trim_end_matches('\r').len()) as u32,
+            ),
+            // `foo(/* eslint-disable-line */ bar);`
+            (false, false) => {
+                if before.ends_with(char::is_whitespace) || after.starts_with(char::is_whitespace) {
+                    Span::new(start as u32, end as u32)
+                } else {
+                    // Keep the code before and after the comment apart.
+                    return Fix::new(" ", Span::new(start as u32, end as u32));
+                }
+            }
+        };
+        Fix::delete(span)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DisableDirectives {
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule>,
     /// All comments that disable one or more specific rules
     disable_rule_comments: Box<[DisableRuleComment]>,
+    /// All comments that enable all rules or one or more specific rules
+    enable_rule_comments: Box<[DisableRuleComment]>,
     /// Spans of unused enable directives
     unused_enable_comments: Box<[(Option<String>, Span)]>,
     /// Spans of used enable directives, to filter out unused
@@ -249,6 +303,33 @@ impl DisableDirectives {
         &self.unused_enable_comments
     }
 
+    /// Create a fix which removes an unused enable directive: the span of the whole comment for
+    /// `eslint-enable`, or the span of the name of a rule.
+    ///
+    /// The comment is removed completely when none of its rules are used, otherwise only the name
+    /// of the rule is removed.
+    fn create_unused_enable_fix(&self, source_text: &str, span: Span) -> Option<Fix> {
+        let comment = self
+            .enable_rule_comments
+            .iter()
+            .find(|comment| comment.span.contains_inclusive(span))?;
+        match &comment.r#type {
+            RuleCommentType::All => Some(comment.create_fix(source_text)),
+            RuleCommentType::Single(rules) => {
+                let all_unused = rules.iter().all(|rule| {
+                    self.unused_enable_comments
+                        .iter()
+                        .any(|(_, unused_span)| *unused_span == rule.name_span)
+                });
+                if all_unused {
+                    return Some(comment.create_fix(source_text));
+                }
+                let rule = rules.iter().find(|rule| rule.name_span == span)?;
+                Some(rule.create_fix(source_text, comment.span))
+            }
+        }
+    }
+
     pub fn collect_unused_disable_comments(&self) -> Vec<DisableRuleComment> {
         let used = self.used_disable_comments.borrow();
 
@@ -317,6 +398,8 @@ pub struct DisableDirectivesBuilder {
     disable_start_map: FxHashMap<String, (u32, Span, Span)>,
     /// All comments that disable one or more specific rules
     disable_rule_comments: Vec<DisableRuleComment>,
+    /// All comments that enable all rules or one or more specific rules
+    enable_rule_comments: Vec<DisableRuleComment>,
     /// Spans of unused enable directives
     unused_enable_comments: Vec<(Option<String>, Span)>,
 }
@@ -328,6 +411,7 @@ impl DisableDirectivesBuilder {
             disable_all_start: None,
             disable_start_map: FxHashMap::default(),
             disable_rule_comments: vec![],
+            enable_rule_comments: vec![],
             unused_enable_comments: vec![],
         }
     }
@@ -338,6 +422,7 @@ impl DisableDirectivesBuilder {
         DisableDirectives {
             intervals: self.intervals,
             disable_rule_comments: self.disable_rule_comments.into_boxed_slice(),
+            enable_rule_comments: self.enable_rule_comments.into_boxed_slice(),
             unused_enable_comments: self.unused_enable_comments.into_boxed_slice(),
             used_disable_comments: RefCell::new(Vec::new()),
         }
@@ -521,9 +606,15 @@ impl DisableDirectivesBuilder {
                         // collect as unused enable (see more at note comments in beginning of this method)
                         unused_enable_directives.push((None, comment_span));
                     }
+                    self.enable_rule_comments.push(DisableRuleComment {
+                        span: comment_span,
+                        r#type: RuleCommentType::All,
+                    });
                 } else {
                     // `eslint-enable rule-name1, rule-name2`
+                    let mut rules = vec![];
                     Self::get_rule_names(text, rule_name_start, |rule_name, name_span| {
+                        rules.push(RuleCommentRule { rule_name: rule_name.to_string(), name_span });
                         if let Some((start, _, _)) = self.disable_start_map.remove(rule_name) {
                             self.add_interval(
                                 start,
@@ -540,6 +631,10 @@ impl DisableDirectivesBuilder {
                             unused_enable_directives.push((Some(rule_name.to_string()), name_span));
                         }
                     });
+                    self.enable_rule_comments.push(DisableRuleComment {
+                        span: comment_span,
+                        r#type: RuleCommentType::Single(rules),
+                    });
                 }
             }
         }
@@ -1018,9 +1113,28 @@ pub fn create_unused_directives_diagnostics(
     directives: &DisableDirectives,
     severity: crate::AllowWarnDeny,
 ) -> Vec<oxc_diagnostics::OxcDiagnostic> {
+    create_unused_directives_messages(directives, severity, None)
+        .into_iter()
+        .map(|message| message.error)
+        .collect()
+}
+
+/// Same as [`create_unused_directives_diagnostics`], but returns [`Message`]s with fixes removing
+/// the unused directives.
+///
+/// A disable or enable comment is removed completely when none of its rules are used, otherwise
+/// only the names of the unused rules are removed. No fixes are created when `source_text` is
+/// `None`.
+pub fn create_unused_directives_messages(
+    directives: &DisableDirectives,
+    severity: crate::AllowWarnDeny,
+    source_text: Option<&str>,
+) -> Vec<Message> {
     use oxc_diagnostics::OxcDiagnostic;
 
-    let mut diagnostics = Vec::new();
+    let mut messages = Vec::new();
+    let fix_message = "remove unused disable directive";
+    let enable_fix_message = "remove unused enable directive";
 
     let severity = if severity == crate::AllowWarnDeny::Deny {
         oxc_diagnostics::Severity::Error
@@ -1032,15 +1146,21 @@ pub fn create_unused_directives_diagnostics(
     let unused_disable = directives.collect_unused_disable_comments();
     for unused_comment in unused_disable {
         let span = unused_comment.span;
-        match unused_comment.r#type {
+        match &unused_comment.r#type {
             RuleCommentType::All => {
-                diagnostics.push(
+                let fix = source_text.map_or(PossibleFixes::None, |source_text| {
+                    PossibleFixes::Single(
+                        unused_comment.create_fix(source_text).with_message(fix_message),
+                    )
+                });
+                messages.push(Message::new(
                     OxcDiagnostic::warn(
                         "Unused eslint-disable directive (no problems were reported).",
                     )
                     .with_label(span)
                     .with_severity(severity),
-                );
+                    fix,
+                ));
             }
             RuleCommentType::Single(rules) => {
                 for rule in rules {
@@ -1048,11 +1168,17 @@ pub fn create_unused_directives_diagnostics(
                         "Unused eslint-disable directive (no problems were reported from {}).",
                         rule.rule_name
                     );
-                    diagnostics.push(
+                    let fix = source_text.map_or(PossibleFixes::None, |source_text| {
+                        PossibleFixes::Single(
+                            rule.create_fix(source_text, span).with_message(fix_message),
+                        )
+                    });
+                    messages.push(Message::new(
                         OxcDiagnostic::warn(rule_message)
                             .with_label(rule.name_span)
                             .with_severity(severity),
-                    );
+                        fix,
+                    ));
                 }
             }
         }
@@ -1069,10 +1195,18 @@ pub fn create_unused_directives_diagnostics(
             "Unused eslint-enable directive (no matching eslint-disable directives were found)."
                 .to_string()
         };
-        diagnostics.push(OxcDiagnostic::warn(message).with_label(*span).with_severity(severity));
+        let fix = source_text
+            .and_then(|source_text| directives.create_unused_enable_fix(source_text, *span))
+            .map_or(PossibleFixes::None, |fix| {
+                PossibleFixes::Single(fix.with_message(enable_fix_message))
+            });
+        messages.push(Message::new(
+            OxcDiagnostic::warn(message).with_label(*span).with_severity(severity),
+            fix,
+        ));
     }
 
-    diagnostics
+    messages
 }
 
 #[cfg(test)]
@@ -1310,7 +1444,7 @@ mod tests {
                 .create_fix(source_text, comment_span);
 
         assert_eq!(&source_text[28..38], "max-params");
-        assert_eq!(max_params_fix.span, Span::sized(28, 11)); // max-params is 10 + 1 for the comma
+        assert_eq!(max_params_fix.span, Span::sized(28, 12)); // max-params is 10 + 1 for the comma + 1 for the space
 
         let no_console_fix =
             RuleCommentRule { rule_name: "no-console".to_string(), name_span: Span::sized(40, 10) }
@@ -1389,4 +1523,63 @@ function test() {
             "eslint-disable-next-line should NOT suppress diagnostics on lines after the next line"
         );
     }
+
+    #[test]
+    fn remove_unused_disable_comment() {
+        let cases = [
+            ("foo();\n// eslint-disable-next-line\nbar();\n", "foo();\nbar();\n"),
+            ("foo();\n    /* eslint-disable-next-line */\r\nbar();", "foo();\nbar();"),
+            ("foo();\n// eslint-disable-next-line", "foo();\n"),
+            ("foo(); // eslint-disable-line\nbar();\n", "foo();\nbar();\n"),
+            ("foo(); /* eslint-disable-line */  \r\nbar();", "foo();\r\nbar();"),
+            ("/* eslint-disable-next-line */ foo();\nbar();", "foo();\nbar();"),
+            ("foo(/* eslint-disable-line */ bar);", "foo( bar);"),
+            ("a +/* eslint-disable-line */+ b;", "a + + b;"),
+            ("let a/* eslint-disable-line */= 1;", "let a = 1;"),
+        ];
+
+        let allocator = Allocator::default();
+        for (source_text, expected) in cases {
+            let semantic = process_source(&allocator, source_text);
+            let directives =
+                DisableDirectivesBuilder::new().build(semantic.source_text(), semantic.comments());
+            let unused = directives.collect_unused_disable_comments();
+            assert_eq!(unused.len(), 1, "{source_text:?}");
+
+            let fix = unused[0].create_fix(source_text);
+            let mut fixed = source_text.to_string();
+            fixed.replace_range(fix.span.start as usize..fix.span.end as usize, &fix.content);
+            assert_eq!(fixed, expected, "{source_text:?}");
+        }
+    }
+
+    #[test]
+    fn remove_unused_enable_comment() {
+        let cases = [
+            ("foo();\n// eslint-enable\nbar();\n", "foo();\nbar();\n"),
+            ("foo();\n/* eslint-enable no-console */\nbar();", "foo();\nbar();"),
+            ("foo();\n/* eslint-enable no-console, no-debugger */\nbar();", "foo();\nbar();"),
+            (
+                "/* eslint-disable no-console */\nfoo();\n/* eslint-enable no-console, no-debugger */",
+                "/* eslint-disable no-console */\nfoo();\n/* eslint-enable no-console */",
+            ),
+            (
+                "/* eslint-disable no-console */\nfoo();\n/* eslint-enable no-debugger, no-console */",
+                "/* eslint-disable no-console */\nfoo();\n/* eslint-enable no-console */",
+            ),
+        ];
+
+        let allocator = Allocator::default();
+        for (source_text, expected) in cases {
+            let semantic = process_source(&allocator, source_text);
+            let directives =
+                DisableDirectivesBuilder::new().build(semantic.source_text(), semantic.comments());
+            let (_, span) = directives.unused_enable_comments()[0];
+
+            let fix = directives.create_unused_enable_fix(source_text, span).unwrap();
+            let mut fixed = source_text.to_string();
+            fixed.replace_range(fix.span.start as usize..fix.span.end as usize, &fix.content);
+            assert_eq!(fixed, expected, "{source_text:?}");
+        }
+    }
 }
@@ -254,6 +254,7 @@ impl RuleFix {
         };
         let mut fix = self.fix.normalize_fixes(source_text);
         fix.message = message;
+        fix.kind = self.kind;
         fix
     }
 
@@ -294,6 +295,9 @@ pub struct Fix {
     /// editors via code actions.
     pub message: Option<Cow<'static, str>>,
     pub span: Span,
+    /// Whether this is a safe fix, a suggestion, or dangerous, so reporters can label fixes
+    /// which are only applied with `--fix-suggestions` or `--fix-dangerously`.
+    pub kind: FixKind,
 }
 
 impl Default for Fix {
@@ -304,17 +308,17 @@ impl Default for Fix {
 
 impl Fix {
     pub const fn delete(span: Span) -> Self {
-        Self { content: Cow::Borrowed(""), message: None, span }
+        Self { content: Cow::Borrowed(""), message: None, span, kind: FixKind::SafeFix }
     }
 
     pub fn new<T: Into<Cow<'static, str>>>(content: T, span: Span) -> Self {
-        Self { content: content.into(), message: None, span }
+        Self { content: content.into(), message: None, span, kind: FixKind::SafeFix }
     }
 
     /// Creates a [`Fix`] that doesn't change the source code.
     #[inline]
     pub const fn empty() -> Self {
-        Self { content: Cow::Borrowed(""), message: None, span: SPAN }
+        Self { content: Cow::Borrowed(""), message: None, span: SPAN, kind: FixKind::SafeFix }
     }
 
     #[must_use]
@@ -322,6 +326,31 @@ impl Fix {
         self.message = Some(message.into());
         self
     }
+
+    #[must_use]
+    pub fn with_kind(mut self, kind: FixKind) -> Self {
+        self.kind = kind;
+        self
+    }
+}
+
+/// A change suggested to resolve a diagnostic, which is never applied automatically, not even with
+/// [`FixKind::Suggestion`]. Editors offer suggestions as code actions.
+///
+/// Rules report suggestions with [`LintContext::diagnostic_with_manual_suggestions`].
+///
+/// [`LintContext::diagnostic_with_manual_suggestions`]: crate::LintContext::diagnostic_with_manual_suggestions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    /// Describes the change, e.g. `Replace with 'let'`.
+    pub message: Cow<'static, str>,
+    pub fix: Fix,
+}
+
+impl Suggestion {
+    pub fn new<T: Into<Cow<'static, str>>>(message: T, fix: Fix) -> Self {
+        Self { message: message.into(), fix }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -547,12 +576,15 @@ impl CompositeFix {
         let mut last_pos = start;
         let mut output = String::new();
         let mut merged_fix_message = None;
+        // The merged fix is dangerous if any of the fixes is.
+        let mut merged_kind = FixKind::None;
 
         for fix in fixes {
-            let Fix { content, span, message } = fix;
+            let Fix { content, span, message, kind } = fix;
             if let Some(message) = message {
                 merged_fix_message.get_or_insert(message);
             }
+            merged_kind |= kind;
 
             // negative range or overlapping ranges is invalid
             if span.start > span.end {
@@ -578,7 +610,7 @@ impl CompositeFix {
 
         output.push_str(after);
 
-        let mut fix = Fix::new(output, Span::new(start, end));
+        let mut fix = Fix::new(output, Span::new(start, end)).with_kind(merged_kind);
         if let Some(message) = merged_fix_message {
             fix = fix.with_message(message);
         }
@@ -734,6 +766,24 @@ mod test {
         assert_eq!(f, CompositeFix::Multiple(vec![f1, f2, f3.clone(), f3]));
     }
 
+    #[test]
+    fn test_fix_kind_is_kept() {
+        let source_text = "var a = b;";
+        let fix = RuleFix::dangerous(Fix::new("let", Span::new(0, 3)).into()).into_fix(source_text);
+        assert_eq!(fix.kind, FixKind::DangerousFix);
+
+        // A merged fix is dangerous if any of its parts is.
+        let fix = CompositeFix::merge_fixes(
+            vec![
+                Fix::new("let", Span::new(0, 3)),
+                Fix::new("c", Span::new(8, 9)).with_kind(FixKind::DangerousFix),
+            ],
+            source_text,
+        );
+        assert_eq!(fix.content, "let a = c");
+        assert_eq!(fix.kind, FixKind::DangerousFix);
+    }
+
     #[test]
     fn test_emojis() {
         let tests = vec![
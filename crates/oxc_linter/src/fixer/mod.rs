@@ -1,13 +1,22 @@
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    path::Path,
+    sync::Arc,
+};
+
+use miette::{Diagnostic, SourceCode};
 
 use oxc_codegen::{Codegen, CodegenOptions};
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{
+    DiagnosticService, Error, LabeledSpan, NamedSource, OxcDiagnostic, Severity,
+};
 use oxc_span::{GetSpan, SourceType, Span};
 
 use crate::LintContext;
 
 mod fix;
-pub use fix::{CompositeFix, Fix, FixKind, PossibleFixes, RuleFix};
+pub use fix::{CompositeFix, Fix, FixKind, PossibleFixes, RuleFix, Suggestion};
 
 /// Produces [`RuleFix`] instances. Inspired by ESLint's [`RuleFixer`].
 ///
@@ -215,12 +224,16 @@ pub struct FixResult<'a> {
     pub fixed: bool,
     pub fixed_code: Cow<'a, str>,
     pub messages: Vec<Message>,
+    /// Spans of the fixes which were not applied, because they overlap with an applied fix.
+    pub conflicts: Vec<Span>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Message {
     pub error: OxcDiagnostic,
     pub fixes: PossibleFixes,
+    /// Suggestions, which unlike `fixes` are never applied by the [`Fixer`].
+    pub suggestions: Vec<Suggestion>,
     pub span: Span,
     fixed: bool,
     pub section_offset: u32,
@@ -236,7 +249,13 @@ impl Message {
             .map(|span| Span::new(span.offset() as u32, (span.offset() + span.len()) as u32))
             .unwrap_or_default();
 
-        Self { error, span, fixes, fixed: false, section_offset: 0 }
+        Self { error, span, fixes, suggestions: vec![], fixed: false, section_offset: 0 }
+    }
+
+    #[must_use]
+    pub fn with_suggestions(mut self, suggestions: Vec<Suggestion>) -> Self {
+        self.suggestions = suggestions;
+        self
     }
 
     #[must_use]
@@ -269,6 +288,10 @@ impl Message {
             }
         }
 
+        for suggestion in &mut self.suggestions {
+            suggestion.fix.span = suggestion.fix.span.move_right(offset);
+        }
+
         self
     }
 }
@@ -280,6 +303,35 @@ impl From<Message> for OxcDiagnostic {
     }
 }
 
+impl Message {
+    /// Wrap `messages` with the source code and path, converting them into [`Error`]s like
+    /// [`DiagnosticService::wrap_diagnostics`]. Messages with fixes or suggestions are converted
+    /// into [`DiagnosticWithFixes`], so reporters can recover them.
+    pub fn wrap_messages<C: AsRef<Path>, P: AsRef<Path>>(
+        cwd: C,
+        path: P,
+        source_text: &str,
+        messages: Vec<Message>,
+    ) -> Vec<Error> {
+        let source = DiagnosticService::named_source(cwd, path, source_text);
+        messages
+            .into_iter()
+            .map(|message| {
+                if message.fixes.is_empty() && message.suggestions.is_empty() {
+                    message.error.with_source_code(Arc::clone(&source))
+                } else {
+                    Error::new(DiagnosticWithFixes {
+                        diagnostic: message.error,
+                        fixes: message.fixes,
+                        suggestions: message.suggestions,
+                        source: Arc::clone(&source),
+                    })
+                }
+            })
+            .collect()
+    }
+}
+
 impl GetSpan for Message {
     #[inline]
     fn span(&self) -> Span {
@@ -287,6 +339,75 @@ impl GetSpan for Message {
     }
 }
 
+/// A lint diagnostic with the fixes and suggestions available for it, and the source code it was
+/// reported in.
+///
+/// Reporters can recover the fixes of a diagnostic with [`Error::downcast_ref`]. Otherwise, it is
+/// rendered like the wrapped [`OxcDiagnostic`].
+#[derive(Debug)]
+pub struct DiagnosticWithFixes {
+    diagnostic: OxcDiagnostic,
+    fixes: PossibleFixes,
+    suggestions: Vec<Suggestion>,
+    source: Arc<NamedSource<String>>,
+}
+
+impl DiagnosticWithFixes {
+    /// The diagnostic the fixes are for.
+    pub fn diagnostic(&self) -> &OxcDiagnostic {
+        &self.diagnostic
+    }
+
+    /// Fixes for the diagnostic, as spans of the source code and their replacements. Each fix is
+    /// an alternative way of fixing the diagnostic.
+    pub fn fixes(&self) -> &[Fix] {
+        match &self.fixes {
+            PossibleFixes::None => &[],
+            PossibleFixes::Single(fix) => std::slice::from_ref(fix),
+            PossibleFixes::Multiple(fixes) => fixes,
+        }
+    }
+
+    /// Suggestions for the diagnostic, which should only be applied on request.
+    pub fn suggestions(&self) -> &[Suggestion] {
+        &self.suggestions
+    }
+}
+
+impl Display for DiagnosticWithFixes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.diagnostic.fmt(f)
+    }
+}
+
+impl std::error::Error for DiagnosticWithFixes {}
+
+impl Diagnostic for DiagnosticWithFixes {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&*self.source)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+}
+
 /// The fixer of the code.
 /// Note that our parser has handled the BOM, so we don't need to port the BOM test cases from `ESLint`.
 pub struct Fixer<'a> {
@@ -319,6 +440,7 @@ impl<'a> Fixer<'a> {
     }
 
     #[cfg(test)]
+    #[must_use]
     pub fn with_fix_index(mut self, fix_index: u8) -> Self {
         self.fix_index = fix_index;
         self
@@ -332,16 +454,22 @@ impl<'a> Fixer<'a> {
                 fixed: false,
                 fixed_code: Cow::Borrowed(source_text),
                 messages: self.messages,
+                conflicts: vec![],
             };
         }
 
-        self.messages.sort_unstable_by_key(|m| m.fixes.span());
+        // Sort by the rule as well, so the same fix wins every time two fixes start at the same
+        // position, independent of the order the rules were run in.
+        self.messages.sort_by(|a, b| {
+            a.fixes.span().cmp(&b.fixes.span()).then_with(|| a.error.code.cmp(&b.error.code))
+        });
         let mut fixed = false;
         let mut output = String::with_capacity(source_text.len());
         let mut last_pos: u32 = 0;
 
         // only keep messages that were not fixed
         let mut filtered_messages = Vec::with_capacity(self.messages.len());
+        let mut conflicts = vec![];
 
         for mut m in self.messages {
             let fix = match &m.fixes {
@@ -363,6 +491,7 @@ impl<'a> Fixer<'a> {
                 continue;
             }
             if start < last_pos {
+                conflicts.push(*span);
                 filtered_messages.push(m);
                 continue;
             }
@@ -399,7 +528,16 @@ impl<'a> Fixer<'a> {
             );
         }
 
-        FixResult { fixed, fixed_code: Cow::Owned(output), messages: filtered_messages }
+        FixResult { fixed, fixed_code: Cow::Owned(output), messages: filtered_messages, conflicts }
+    }
+}
+
+/// The line break of `source_text`, so that fixes inserting lines don't mix line endings.
+/// Files with a `\r\n` first line break use `\r\n`, all others `\n`.
+pub fn line_ending(source_text: &str) -> &'static str {
+    match memchr::memchr(b'\n', source_text.as_bytes()) {
+        Some(index) if index > 0 && source_text.as_bytes()[index - 1] == b'\r' => "\r\n",
+        _ => "\n",
     }
 }
 
@@ -408,10 +546,13 @@ mod test {
     use std::borrow::Cow;
 
     use cow_utils::CowUtils;
-    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_diagnostics::{Error, OxcDiagnostic};
     use oxc_span::{SourceType, Span};
 
-    use super::{CompositeFix, Fix, FixResult, Fixer, Message, PossibleFixes};
+    use super::{
+        CompositeFix, DiagnosticWithFixes, Fix, FixKind, FixResult, Fixer, Message, PossibleFixes,
+        Suggestion, line_ending,
+    };
 
     fn insert_at_end() -> OxcDiagnostic {
         OxcDiagnostic::warn("End")
@@ -466,23 +607,51 @@ mod test {
     }
 
     const TEST_CODE: &str = "var answer = 6 * 7;";
-    const INSERT_AT_END: Fix =
-        Fix { span: Span::new(19, 19), content: Cow::Borrowed("// end"), message: None };
-    const INSERT_AT_START: Fix =
-        Fix { span: Span::new(0, 0), content: Cow::Borrowed("// start"), message: None };
-    const INSERT_AT_MIDDLE: Fix =
-        Fix { span: Span::new(13, 13), content: Cow::Borrowed("5 *"), message: None };
-    const REPLACE_ID: Fix =
-        Fix { span: Span::new(4, 10), content: Cow::Borrowed("foo"), message: None };
-    const REPLACE_VAR: Fix =
-        Fix { span: Span::new(0, 3), content: Cow::Borrowed("let"), message: None };
-    const REPLACE_NUM: Fix =
-        Fix { span: Span::new(13, 14), content: Cow::Borrowed("5"), message: None };
+    const INSERT_AT_END: Fix = Fix {
+        span: Span::new(19, 19),
+        content: Cow::Borrowed("// end"),
+        message: None,
+        kind: FixKind::SafeFix,
+    };
+    const INSERT_AT_START: Fix = Fix {
+        span: Span::new(0, 0),
+        content: Cow::Borrowed("// start"),
+        message: None,
+        kind: FixKind::SafeFix,
+    };
+    const INSERT_AT_MIDDLE: Fix = Fix {
+        span: Span::new(13, 13),
+        content: Cow::Borrowed("5 *"),
+        message: None,
+        kind: FixKind::SafeFix,
+    };
+    const REPLACE_ID: Fix = Fix {
+        span: Span::new(4, 10),
+        content: Cow::Borrowed("foo"),
+        message: None,
+        kind: FixKind::SafeFix,
+    };
+    const REPLACE_VAR: Fix = Fix {
+        span: Span::new(0, 3),
+        content: Cow::Borrowed("let"),
+        message: None,
+        kind: FixKind::SafeFix,
+    };
+    const REPLACE_NUM: Fix = Fix {
+        span: Span::new(13, 14),
+        content: Cow::Borrowed("5"),
+        message: None,
+        kind: FixKind::SafeFix,
+    };
     const REMOVE_START: Fix = Fix::delete(Span::new(0, 4));
     const REMOVE_MIDDLE: Fix = Fix::delete(Span::new(5, 10));
     const REMOVE_END: Fix = Fix::delete(Span::new(14, 18));
-    const REVERSE_RANGE: Fix =
-        Fix { span: Span::new(3, 0), content: Cow::Borrowed(" "), message: None };
+    const REVERSE_RANGE: Fix = Fix {
+        span: Span::new(3, 0),
+        content: Cow::Borrowed(" "),
+        message: None,
+        kind: FixKind::SafeFix,
+    };
 
     fn get_fix_result(messages: Vec<Message>) -> FixResult<'static> {
         Fixer::new(TEST_CODE, messages, Some(SourceType::default())).fix()
@@ -647,6 +816,7 @@ mod test {
         assert_eq!(result.fixed_code, TEST_CODE.cow_replace("answer", "foo"));
         assert_eq!(result.messages.len(), 1);
         assert_eq!(result.messages[0].error.to_string(), "removemiddle");
+        assert_eq!(result.conflicts, [REMOVE_MIDDLE.span]);
         assert!(result.fixed);
     }
 
@@ -688,6 +858,43 @@ mod test {
         assert_eq!(result1.fixed_code, result2.fixed_code);
     }
 
+    #[test]
+    fn apply_same_fix_when_spans_are_equal_regardless_of_order() {
+        let replace_with_let = || {
+            create_message(
+                replace_var().with_error_code("eslint", "no-var"),
+                PossibleFixes::Single(REPLACE_VAR),
+            )
+        };
+        let replace_with_const = || {
+            create_message(
+                OxcDiagnostic::warn("const").with_error_code("eslint", "prefer-const"),
+                PossibleFixes::Single(Fix {
+                    span: REPLACE_VAR.span,
+                    content: Cow::Borrowed("const"),
+                    message: None,
+                    kind: FixKind::SafeFix,
+                }),
+            )
+        };
+        let result1 = get_fix_result(vec![replace_with_let(), replace_with_const()]);
+        let result2 = get_fix_result(vec![replace_with_const(), replace_with_let()]);
+        assert_eq!(result1.fixed_code, TEST_CODE.cow_replace("var", "let"));
+        assert_eq!(result2.fixed_code, result1.fixed_code);
+        assert_eq!(result1.conflicts, [REPLACE_VAR.span]);
+    }
+
+    #[test]
+    fn should_not_apply_suggestions() {
+        let result = get_fix_result(vec![
+            create_message(replace_var(), PossibleFixes::None)
+                .with_suggestions(vec![Suggestion::new("Replace with 'let'", REPLACE_VAR)]),
+        ]);
+        assert_eq!(result.fixed_code, TEST_CODE);
+        assert_eq!(result.messages.len(), 1);
+        assert!(!result.fixed);
+    }
+
     #[test]
     fn should_not_apply_fix_with_one_no_fix() {
         let result =
@@ -863,4 +1070,41 @@ mod test {
         assert!(result.fixed);
         assert_eq!(result.fixed_code, "let answer = 42;");
     }
+
+    #[test]
+    fn wrap_messages_keeps_fixes() {
+        let messages = vec![
+            create_message(no_fix(Span::new(4, 10)), PossibleFixes::None),
+            create_message(replace_id(), PossibleFixes::Multiple(vec![REPLACE_ID, REPLACE_VAR])),
+            create_message(replace_var(), PossibleFixes::None)
+                .with_suggestions(vec![Suggestion::new("Replace with 'let'", REPLACE_VAR)]),
+        ];
+        let errors = Message::wrap_messages("/cwd", "/cwd/test.js", TEST_CODE, messages);
+
+        let source_name = |error: &Error| {
+            let source = error.source_code().unwrap();
+            source.read_span(&Span::empty(0).into(), 0, 0).unwrap().name().map(ToString::to_string)
+        };
+
+        assert!(errors[0].downcast_ref::<DiagnosticWithFixes>().is_none());
+        assert_eq!(source_name(&errors[0]).as_deref(), Some("test.js"));
+
+        let diagnostic = errors[1].downcast_ref::<DiagnosticWithFixes>().unwrap();
+        assert_eq!(diagnostic.fixes(), [REPLACE_ID, REPLACE_VAR]);
+        assert!(diagnostic.suggestions().is_empty());
+        assert_eq!(errors[1].to_string(), "foo");
+        assert_eq!(source_name(&errors[1]).as_deref(), Some("test.js"));
+
+        let diagnostic = errors[2].downcast_ref::<DiagnosticWithFixes>().unwrap();
+        assert!(diagnostic.fixes().is_empty());
+        assert_eq!(diagnostic.suggestions(), [Suggestion::new("Replace with 'let'", REPLACE_VAR)]);
+    }
+
+    #[test]
+    fn detect_line_ending() {
+        assert_eq!(line_ending("a;\r\nb;\n"), "\r\n");
+        assert_eq!(line_ending("a;\nb;\r\n"), "\n");
+        assert_eq!(line_ending("\na;"), "\n");
+        assert_eq!(line_ending("a;"), "\n");
+    }
 }
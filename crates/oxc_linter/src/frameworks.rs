@@ -2,7 +2,7 @@ use std::{hash, path::Path};
 
 use bitflags::bitflags;
 
-use crate::ModuleRecord;
+use crate::{ModuleRecord, package_json::PackageJson};
 
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -96,6 +96,17 @@ pub fn has_jest_imports(module_record: &ModuleRecord) -> bool {
     module_record.import_entries.iter().any(|entry| entry.module_request.name() == "@jest/globals")
 }
 
+/// The test frameworks a package depends on.
+pub fn test_frameworks_of(package_json: &PackageJson) -> FrameworkFlags {
+    let mut frameworks = FrameworkFlags::empty();
+    frameworks.set(FrameworkFlags::Vitest, package_json.has_dependency("vitest"));
+    frameworks.set(
+        FrameworkFlags::Jest,
+        ["jest", "@jest/globals", "ts-jest"].iter().any(|name| package_json.has_dependency(name)),
+    );
+    frameworks
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 
 pub enum FrameworkOptions {
@@ -0,0 +1,304 @@
+use oxc_ast::Comment;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+use serde_json::Value;
+
+use crate::{AllowWarnDeny, config::configure_rule, rules::RuleEnum};
+
+/// A rule configured with a comment like `/* oxlint eqeqeq: ["error", "smart"] */`.
+#[derive(Debug)]
+struct InlineRuleConfig {
+    rule: RuleEnum,
+    severity: AllowWarnDeny,
+    /// Whether the comment configures options for the rule, and not only its severity.
+    has_options: bool,
+    /// End of the comment. The configuration applies to the rest of the file.
+    start: u32,
+}
+
+/// Rules configured with inline config comments in a script block, like
+/// `/* oxlint eqeqeq: ["error", "smart"], no-console: "off" */`.
+///
+/// Like in ESLint, `eslint` can be used instead of `oxlint`. A description can follow the rules
+/// after `--`. Unlike in ESLint, the configuration only applies to the rest of the file after the
+/// comment, until the rule is configured again.
+#[derive(Debug, Default)]
+pub struct InlineConfig {
+    rules: Vec<InlineRuleConfig>,
+    /// Comments which could not be parsed, or configure unknown rules.
+    diagnostics: Vec<OxcDiagnostic>,
+}
+
+impl InlineConfig {
+    pub fn parse(source_text: &str, comments: &[Comment]) -> Self {
+        let mut config = Self::default();
+        for comment in comments.iter().filter(|comment| comment.is_block()) {
+            let comment_span = comment.content_span();
+            let text = comment_span.source_text(source_text).trim_start();
+            let (text, report_unknown_rules) = if let Some(text) = text.strip_prefix("oxlint") {
+                (text, true)
+            } else if let Some(text) = text.strip_prefix("eslint") {
+                // `eslint` comments can configure rules oxlint doesn't implement.
+                (text, false)
+            } else {
+                continue;
+            };
+            // Not a directive like `oxlint-disable`.
+            if !text.starts_with(char::is_whitespace) {
+                continue;
+            }
+
+            let rules = match parse_rules(strip_description(text)) {
+                Ok(rules) => rules,
+                Err(error) => {
+                    config.diagnostics.push(
+                        OxcDiagnostic::error(format!(
+                            "Failed to parse inline configuration: {error}"
+                        ))
+                        .with_label(comment.span),
+                    );
+                    continue;
+                }
+            };
+            for (name, value) in rules {
+                let has_options = value.as_array().is_some_and(|value| value.len() > 1);
+                match configure_rule(&name, value) {
+                    Ok(Some((rule, severity))) => config.rules.push(InlineRuleConfig {
+                        rule,
+                        severity,
+                        has_options,
+                        start: comment.span.end,
+                    }),
+                    Ok(None) if report_unknown_rules => config.diagnostics.push(
+                        OxcDiagnostic::error(format!("Rule `{name}` was not found"))
+                            .with_label(comment.span),
+                    ),
+                    Ok(None) => {}
+                    Err(error) => config.diagnostics.push(
+                        OxcDiagnostic::error(format!(
+                            "Failed to configure rule `{name}` inline: {error}"
+                        ))
+                        .with_label(comment.span),
+                    ),
+                }
+            }
+        }
+        config
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Takes the diagnostics for comments which could not be parsed, or configure unknown rules.
+    pub fn take_diagnostics(&mut self) -> Vec<OxcDiagnostic> {
+        std::mem::take(&mut self.diagnostics)
+    }
+
+    /// `rules` configured for the file, and the rules configured with inline config comments,
+    /// with the range of the file each rule reports diagnostics in.
+    ///
+    /// A rule configured inline replaces the rule from the start of the comment on. Like in
+    /// ESLint, a comment which only sets the severity of a rule keeps the options it was
+    /// configured with before.
+    pub fn apply<'r>(
+        &'r self,
+        rules: &'r [(RuleEnum, AllowWarnDeny)],
+    ) -> impl Iterator<Item = (&'r RuleEnum, AllowWarnDeny, Option<Span>)> + 'r {
+        // The start of the next comment configuring the same rule as the comment at `index`.
+        let next_start = |rule: &RuleEnum, index: usize| {
+            self.rules[index..]
+                .iter()
+                .find(|config| config.rule.id() == rule.id())
+                .map_or(u32::MAX, |config| config.start)
+        };
+
+        let configured_rules = rules.iter().map(move |(rule, severity)| {
+            let end = next_start(rule, 0);
+            (rule, *severity, (end != u32::MAX).then(|| Span::new(0, end)))
+        });
+        // The rule with the options in effect at the comment at `index`.
+        let configured_rule = move |index: usize| {
+            let config = &self.rules[index];
+            if config.has_options {
+                return &config.rule;
+            }
+            self.rules[..index]
+                .iter()
+                .rev()
+                .find(|previous| previous.has_options && previous.rule.id() == config.rule.id())
+                .map(|previous| &previous.rule)
+                .or_else(|| {
+                    rules
+                        .iter()
+                        .find(|(rule, _)| rule.id() == config.rule.id())
+                        .map(|(rule, _)| rule)
+                })
+                .unwrap_or(&config.rule)
+        };
+        let inline_rules =
+            self.rules.iter().enumerate().filter(|(_, config)| config.severity.is_warn_deny()).map(
+                move |(index, config)| {
+                    let end = next_start(&config.rule, index + 1);
+                    (configured_rule(index), config.severity, Some(Span::new(config.start, end)))
+                },
+            );
+        configured_rules.chain(inline_rules)
+    }
+}
+
+/// Parses and configures rules written like in inline config comments, e.g.
+/// `eqeqeq: [warn, smart], no-console: off`.
+///
+/// # Errors
+///
+/// Returns an error if the rules can't be parsed, a rule doesn't exist or can't be configured.
+pub fn configure_rules(text: &str) -> Result<Vec<(RuleEnum, AllowWarnDeny)>, String> {
+    parse_rules(text)?
+        .into_iter()
+        .map(|(name, value)| match configure_rule(&name, value) {
+            Ok(Some(rule)) => Ok(rule),
+            Ok(None) => Err(format!("Rule `{name}` was not found")),
+            Err(error) => Err(format!("Failed to configure rule `{name}`: {error}")),
+        })
+        .collect()
+}
+
+/// Strips a description after `--`, e.g. `eqeqeq: "off" -- legacy code`.
+fn strip_description(text: &str) -> &str {
+    text.match_indices("--")
+        .find(|(index, _)| text[..*index].ends_with(char::is_whitespace))
+        .map_or(text, |(index, _)| &text[..index])
+}
+
+/// Parses rules and their configuration, like `eqeqeq: [error, smart], no-console: off`.
+///
+/// Configurations are JSON, but strings can be written without quotes if they only contain
+/// letters, digits, `-`, `_`, `.`, `/`, `@` and `$`.
+fn parse_rules(text: &str) -> Result<Vec<(String, Value)>, String> {
+    let text = quote_bare_words(text);
+    let mut rules = vec![];
+    let mut rest = text.trim();
+    while !rest.is_empty() {
+        let Some((name, value)) = rest.split_once(':') else {
+            let name = rest.trim().trim_matches('"');
+            return Err(format!("expected `:` after rule name `{name}`"));
+        };
+        let name = name.trim().trim_matches('"');
+
+        let mut values = serde_json::Deserializer::from_str(value).into_iter::<Value>();
+        let (value, after) = match values.next() {
+            Some(Ok(json)) => (json, &value[values.byte_offset()..]),
+            Some(Err(error)) => {
+                return Err(format!("invalid configuration for rule `{name}`: {error}"));
+            }
+            None => return Err(format!("missing configuration for rule `{name}`")),
+        };
+        rules.push((name.to_string(), value));
+
+        let after = after.trim_start();
+        rest = match after.strip_prefix(',') {
+            Some(after) => after.trim_start(),
+            None if after.is_empty() => after,
+            None => return Err(format!("expected `,` after configuration for rule `{name}`")),
+        };
+    }
+    Ok(rules)
+}
+
+/// Puts quotes around words which aren't JSON strings, numbers or literals, e.g.
+/// `eqeqeq: [warn, smart]` becomes `"eqeqeq": ["warn", "smart"]`.
+fn quote_bare_words(text: &str) -> String {
+    let is_word_char =
+        |c: char| c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '/' | '@' | '$');
+    let mut quoted = String::with_capacity(text.len());
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c == '"' {
+            // Copy strings as they are, including escaped quotes.
+            quoted.push(c);
+            let mut escaped = false;
+            for (_, c) in chars.by_ref() {
+                quoted.push(c);
+                match c {
+                    '\\' if !escaped => escaped = true,
+                    '"' if !escaped => break,
+                    _ => escaped = false,
+                }
+            }
+        } else if is_word_char(c) {
+            let mut end = start + c.len_utf8();
+            while let Some(&(index, c)) = chars.peek() {
+                if !is_word_char(c) {
+                    break;
+                }
+                end = index + c.len_utf8();
+                chars.next();
+            }
+            let word = &text[start..end];
+            if matches!(word, "true" | "false" | "null")
+                || serde_json::from_str::<serde_json::Number>(word).is_ok()
+            {
+                quoted.push_str(word);
+            } else {
+                quoted.push('"');
+                quoted.push_str(word);
+                quoted.push('"');
+            }
+        } else {
+            quoted.push(c);
+        }
+    }
+    quoted
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{configure_rules, parse_rules, strip_description};
+    use crate::AllowWarnDeny;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            parse_rules(r#"eqeqeq: ["error", "smart"], no-console: off, "no-var": 1"#).unwrap(),
+            vec![
+                ("eqeqeq".to_string(), json!(["error", "smart"])),
+                ("no-console".to_string(), json!("off")),
+                ("no-var".to_string(), json!(1)),
+            ]
+        );
+        assert_eq!(
+            parse_rules(r#"@typescript-eslint/ban-types: [warn, {types: {"a\"b": null}}]"#)
+                .unwrap(),
+            vec![(
+                "@typescript-eslint/ban-types".to_string(),
+                json!(["warn", { "types": { "a\"b": null } }])
+            )]
+        );
+        assert_eq!(parse_rules("  ").unwrap(), vec![]);
+        assert!(parse_rules("eqeqeq").is_err());
+        assert!(parse_rules(r#"eqeqeq: ["error" "smart"]"#).is_err());
+        assert!(parse_rules(r#"eqeqeq: "error" no-console: "off""#).is_err());
+    }
+
+    #[test]
+    fn configure() {
+        let rules = configure_rules("no-debugger: error, eqeqeq: [warn, smart]").unwrap();
+        assert_eq!(
+            rules.iter().map(|(rule, severity)| (rule.name(), *severity)).collect::<Vec<_>>(),
+            vec![("no-debugger", AllowWarnDeny::Deny), ("eqeqeq", AllowWarnDeny::Warn)]
+        );
+        assert!(configure_rules("no-such-rule: error").is_err());
+    }
+
+    #[test]
+    fn description() {
+        assert_eq!(strip_description(r#" eqeqeq: "off" -- legacy code"#), r#" eqeqeq: "off" "#);
+        assert_eq!(
+            strip_description(r#" no-console: ["error", "--"]"#),
+            r#" no-console: ["error", "--"]"#
+        );
+    }
+}
@@ -2,11 +2,14 @@
 
 use std::{
     mem,
-    path::Path,
+    path::{Path, PathBuf},
     ptr::{self, NonNull},
     rc::Rc,
+    sync::Arc,
+    time::Instant,
 };
 
+use itertools::Either;
 use oxc_allocator::{Allocator, AllocatorPool, CloneIn};
 use oxc_ast::{ast::Program, ast_kind::AST_TYPE_MAX};
 use oxc_ast_macros::ast;
@@ -17,24 +20,37 @@ use oxc_semantic::AstNode;
 use oxc_span::Span;
 
 mod ast_util;
+mod baseline;
+mod cache;
 mod config;
 mod context;
+mod dead_code;
 mod disable_directives;
 mod external_linter;
 mod external_plugin_store;
 mod fixer;
 mod frameworks;
 mod globals;
+mod inline_config;
+mod module_graph;
 mod module_graph_visitor;
 mod module_record;
 mod options;
+mod package_json;
+mod reporter;
 mod rule;
 mod service;
+mod suppress;
+mod timing;
+mod tsconfig;
 mod tsgolint;
 mod utils;
+mod watcher;
 
 pub mod loader;
+pub mod rule_docs;
 pub mod rules;
+pub mod selector;
 pub mod table;
 
 mod generated {
@@ -51,38 +67,61 @@ mod lint_runner;
 pub use crate::config::plugins::normalize_plugin_name;
 pub use crate::disable_directives::{
     DisableDirectives, DisableRuleComment, RuleCommentRule, RuleCommentType,
-    create_unused_directives_diagnostics,
+    create_unused_directives_diagnostics, create_unused_directives_messages,
 };
+#[cfg(feature = "watch")]
+pub use crate::watcher::{FileWatcher, WatchError};
 pub use crate::{
+    baseline::LintBaseline,
+    cache::LintCache,
     config::{
-        Config, ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ESLintRule, LintIgnoreMatcher,
-        LintPlugins, Oxlintrc, ResolvedLinterState,
+        Config, ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ESLintRule, EslintFlatConfig,
+        EslintrcConfig, LintBudgets, LintIgnoreMatcher, LintPlugins, Oxlintrc, ResolvedLinterState,
     },
     context::{ContextSubHost, LintContext},
+    dead_code::{DeadCode, DeadCodeKind, find_dead_code, find_dead_exports},
     external_linter::{
         ExternalLinter, ExternalLinterLintFileCb, ExternalLinterLoadPluginCb,
         ExternalLinterSetupConfigsCb, JsFix, LintFileResult, LoadPluginResult,
     },
     external_plugin_store::{ExternalOptionsId, ExternalPluginStore, ExternalRuleId},
-    fixer::{Fix, FixKind, Message, PossibleFixes},
+    fixer::{
+        DiagnosticWithFixes, Fix, FixKind, FixResult, Fixer, Message, PossibleFixes, Suggestion,
+        line_ending,
+    },
     frameworks::FrameworkFlags,
+    inline_config::configure_rules,
     lint_runner::{DirectivesStore, LintRunner, LintRunnerBuilder},
     loader::LINTABLE_EXTENSIONS,
+    module_graph::ModuleGraph,
     module_record::ModuleRecord,
     options::LintOptions,
-    options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
+    options::{AllowWarnDeny, FixOnly, InvalidFilterKind, LintFilter, LintFilterKind},
+    package_json::{PackageJson, Workspace, WorkspacePackage, Workspaces},
+    reporter::{LintRunSummary, Reporter, ReporterFactory, ReporterRegistry},
     rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleRunFunctionsImplemented, RuleRunner},
     service::{LintService, LintServiceOptions, OsFileSystem, RuntimeFileSystem},
+    timing::{RuleTiming, RuleTimings},
+    tsconfig::CompilerOptions,
     tsgolint::TsGoLintState,
     utils::{read_to_arena_str, read_to_string},
+    watcher::{
+        FileChange, FileChangeKind, FileStamp, WatchedFileKind, coalesce_file_changes, file_stamp,
+    },
 };
 use crate::{
     config::{LintConfig, OxlintEnv, OxlintGlobals, OxlintSettings},
     context::ContextHost,
     external_linter::GlobalsAndEnvs,
-    fixer::{CompositeFix, Fixer},
+    fixer::CompositeFix,
+    inline_config::InlineConfig,
     loader::LINT_PARTIAL_LOADER_EXTENSIONS,
-    rules::RuleEnum,
+    package_json::PackageJsonCache,
+    rules::{
+        RuleEnum, import::no_unused_modules::NoUnusedModules,
+        oxc::no_write_only_variables::NoWriteOnlyVariables,
+    },
+    tsconfig::TsConfigCache,
     utils::iter_possible_jest_call_node,
 };
 
@@ -101,6 +140,10 @@ pub struct Linter {
     options: LintOptions,
     config: ConfigStore,
     external_linter: Option<ExternalLinter>,
+    timings: Option<Arc<RuleTimings>>,
+    fix_only: Option<Arc<FixOnly>>,
+    package_jsons: Arc<PackageJsonCache>,
+    tsconfigs: Arc<TsConfigCache>,
 }
 
 impl Linter {
@@ -109,7 +152,15 @@ impl Linter {
         config: ConfigStore,
         external_linter: Option<ExternalLinter>,
     ) -> Self {
-        Self { options, config, external_linter }
+        Self {
+            options,
+            config,
+            external_linter,
+            timings: None,
+            fix_only: None,
+            package_jsons: Arc::default(),
+            tsconfigs: Arc::default(),
+        }
     }
 
     /// Set the kind of auto fixes to apply.
@@ -119,6 +170,43 @@ impl Linter {
         self
     }
 
+    /// Set to `true` to report the fixes of the kind set with [`Linter::with_fix`] with their
+    /// diagnostics, instead of applying them. See [`DiagnosticWithFixes`].
+    #[must_use]
+    pub fn with_report_fixes(mut self, yes: bool) -> Self {
+        self.options.report_fixes = yes;
+        self
+    }
+
+    /// Set how often a file is fixed and linted again at most. See [`LintOptions::max_fix_passes`].
+    #[must_use]
+    pub fn with_max_fix_passes(mut self, max_fix_passes: u8) -> Self {
+        self.options.max_fix_passes = max_fix_passes;
+        self
+    }
+
+    /// Set to `true` to insert disable comments for all diagnostics of rules, instead of fixing
+    /// them. See [`LintOptions::suppress_all`].
+    #[must_use]
+    pub fn with_suppress_all(mut self, yes: bool) -> Self {
+        self.options.suppress_all = yes;
+        self
+    }
+
+    /// Only apply the fixes of the rules in `fix_only`.
+    #[must_use]
+    pub fn with_fix_only(mut self, fix_only: FixOnly) -> Self {
+        self.fix_only = Some(Arc::new(fix_only));
+        self
+    }
+
+    /// Record the time spent in each rule into `timings`.
+    #[must_use]
+    pub fn with_timings(mut self, timings: Arc<RuleTimings>) -> Self {
+        self.timings = Some(timings);
+        self
+    }
+
     #[must_use]
     pub fn with_report_unused_directives(mut self, report_config: Option<AllowWarnDeny>) -> Self {
         self.options.report_unused_directive = report_config;
@@ -129,6 +217,14 @@ impl Linter {
         &self.options
     }
 
+    pub(crate) fn fix_only(&self) -> Option<&Arc<FixOnly>> {
+        self.fix_only.as_ref()
+    }
+
+    pub(crate) fn import_aliases(&self, cwd: &Path) -> Vec<(String, Vec<PathBuf>)> {
+        self.config.import_aliases(cwd)
+    }
+
     /// Returns the number of rules that will are being used, unless there
     /// nested configurations in use, in which case it returns `None` since the
     /// number of rules depends on which file is being linted.
@@ -136,6 +232,12 @@ impl Linter {
         self.config.number_of_rules(type_aware)
     }
 
+    /// The names of the type-aware rules configured beyond the default `correctness` warnings,
+    /// which only run with tsgolint.
+    pub fn configured_type_aware_rule_names(&self) -> Vec<String> {
+        self.config.configured_type_aware_rule_names()
+    }
+
     /// Return `true` if `Linter` has an external linter (JS plugins).
     pub fn has_external_linter(&self) -> bool {
         self.external_linter.is_some()
@@ -170,9 +272,13 @@ impl Linter {
     ) -> (Vec<Message>, Option<DisableDirectives>) {
         let ResolvedLinterState { rules, config, external_rules } = self.config.resolve(path);
 
-        let mut ctx_host = Rc::new(ContextHost::new(path, context_sub_hosts, self.options, config));
+        let mut ctx_host = Rc::new(
+            ContextHost::new(path, context_sub_hosts, self.options, config)
+                .with_fix_only(self.fix_only.clone())
+                .with_package_jsons(Arc::clone(&self.package_jsons))
+                .with_tsconfigs(Arc::clone(&self.tsconfigs)),
+        );
 
-        #[cfg(debug_assertions)]
         let mut current_diagnostic_index = 0;
 
         let is_partial_loader_file = ctx_host
@@ -181,13 +287,23 @@ impl Linter {
 
         loop {
             let semantic = ctx_host.semantic();
-            let rules = rules
-                .iter()
-                .filter(|(rule, _)| {
+            let mut inline_config =
+                InlineConfig::parse(semantic.source_text(), semantic.comments());
+            let configured_rules = if inline_config.is_empty() {
+                Either::Left(rules.iter().map(|(rule, severity)| (rule, *severity, None)))
+            } else {
+                Either::Right(inline_config.apply(&rules))
+            };
+            let rules = configured_rules
+                .filter(|(rule, _, _)| {
                     if rule.is_tsgolint_rule() {
                         return false;
                     }
 
+                    if rule.plugin_name() == "vitest" && ctx_host.skips_vitest_rules() {
+                        return false;
+                    }
+
                     // If only the `run` function is implemented, we can skip running the file entirely if the current
                     // file does not contain any of the relevant AST node types.
                     if rule.run_info() == RuleRunFunctionsImplemented::Run
@@ -199,7 +315,12 @@ impl Linter {
 
                     rule.should_run(&ctx_host)
                 })
-                .map(|(rule, severity)| (rule, Rc::clone(&ctx_host).spawn(rule, *severity)))
+                .map(|(rule, severity, report_range)| {
+                    (
+                        rule,
+                        Rc::clone(&ctx_host).spawn(rule, severity).with_report_range(report_range),
+                    )
+                })
                 .collect::<Vec<_>>();
 
             let should_run_on_jest_node =
@@ -224,7 +345,10 @@ impl Linter {
                 // don't thrash the cache too much. Feel free to tweak based on benchmarking.
                 //
                 // See https://github.com/oxc-project/oxc/pull/6600 for more context.
-                if semantic.nodes().len() > 200_000 {
+                //
+                // Rules can only be timed when they are in the outer loop.
+                let timings = self.timings.as_ref().filter(|_| with_runtime_optimization);
+                if semantic.nodes().len() > 200_000 && timings.is_none() {
                     // TODO: It seems like there is probably a more intelligent way to preallocate space here. This will
                     // likely incur quite a few unnecessary reallocs currently. We theoretically could compute this at
                     // compile-time since we know all of the rules and their AST node type information ahead of time.
@@ -279,10 +403,15 @@ impl Linter {
                         }
                     }
                 } else {
+                    let mut rule_timings = Vec::new();
                     for (rule, ctx) in &rules {
+                        let start = timings.is_some().then(Instant::now);
+                        let mut calls = 0;
+
                         let run_info = rule.run_info();
                         if !with_runtime_optimization || run_info.is_run_once_implemented() {
                             rule.run_once(ctx);
+                            calls += 1;
                         }
 
                         if !with_runtime_optimization || run_info.is_run_implemented() {
@@ -293,11 +422,13 @@ impl Linter {
                                 for node in semantic.nodes() {
                                     if ast_types.has(node.kind().ty()) {
                                         rule.run(node, ctx);
+                                        calls += 1;
                                     }
                                 }
                             } else {
                                 for node in semantic.nodes() {
                                     rule.run(node, ctx);
+                                    calls += 1;
                                 }
                             }
                         }
@@ -308,8 +439,21 @@ impl Linter {
                         {
                             for jest_node in iter_possible_jest_call_node(semantic) {
                                 rule.run_on_jest_node(&jest_node, ctx);
+                                calls += 1;
                             }
                         }
+
+                        if let Some(start) = start {
+                            rule_timings.push(RuleTiming {
+                                plugin_name: rule.plugin_name(),
+                                rule_name: rule.name(),
+                                duration: start.elapsed(),
+                                calls,
+                            });
+                        }
+                    }
+                    if let Some(timings) = timings {
+                        timings.record(rule_timings);
                     }
                 }
             };
@@ -357,6 +501,13 @@ impl Linter {
             // can mutably access `ctx_host` via `Rc::get_mut` without panicking due to multiple references.
             drop(rules);
 
+            for diagnostic in inline_config.take_diagnostics() {
+                ctx_host.push_diagnostic(Message::new(diagnostic, PossibleFixes::None));
+            }
+
+            let suppressor = self.options.suppress_all.then(|| ctx_host.suppressor());
+            let external_diagnostic_index = ctx_host.diagnostic_count();
+
             self.run_external_rules(
                 &external_rules,
                 path,
@@ -365,13 +516,21 @@ impl Linter {
                 js_allocator_pool,
             );
 
+            if let Some(suppressor) = &suppressor {
+                ctx_host.suppress_diagnostics(
+                    suppressor,
+                    current_diagnostic_index,
+                    external_diagnostic_index,
+                );
+            }
+
             // Report unused directives is now handled differently with type-aware linting
 
             if let Some(severity) = self.options.report_unused_directive
                 && severity.is_warn_deny()
                 && is_partial_loader_file
             {
-                ctx_host.report_unused_directives(severity.into());
+                ctx_host.report_unused_directives(severity);
             }
 
             // no next `<script>` block found, the complete file is finished linting
@@ -379,10 +538,7 @@ impl Linter {
                 break;
             }
 
-            #[cfg(debug_assertions)]
-            {
-                current_diagnostic_index = ctx_host.diagnostic_count();
-            }
+            current_diagnostic_index = ctx_host.diagnostic_count();
         }
 
         let diagnostics = ctx_host.take_diagnostics();
@@ -395,6 +551,62 @@ impl Linter {
         (diagnostics, disable_directives)
     }
 
+    /// Run rules which need the module graph of all linted files, like `import/no-unused-modules`
+    /// and `oxc/no-write-only-variables`,
+    /// on the module at `path`. Must be called after all files are linted.
+    pub(crate) fn run_on_module_graph(
+        &self,
+        path: &Path,
+        cwd: &Path,
+        module_graph: &ModuleGraph,
+        disable_directives: Option<&DisableDirectives>,
+    ) -> Vec<Message> {
+        // Spans of partial loader files are relative to their `<script>` blocks.
+        if path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| LINT_PARTIAL_LOADER_EXTENSIONS.contains(&ext))
+        {
+            return vec![];
+        }
+        let ResolvedLinterState { rules, .. } = self.config.resolve(path);
+        rules
+            .iter()
+            .flat_map(|(rule, severity)| {
+                let (diagnostics, plugin, rule_name) = match rule {
+                    RuleEnum::ImportNoUnusedModules(rule) => (
+                        rule.run_on_module_graph(path, cwd, module_graph),
+                        NoUnusedModules::PLUGIN,
+                        NoUnusedModules::NAME,
+                    ),
+                    RuleEnum::OxcNoWriteOnlyVariables(_) => (
+                        NoWriteOnlyVariables::run_on_module_graph(path, module_graph),
+                        NoWriteOnlyVariables::PLUGIN,
+                        NoWriteOnlyVariables::NAME,
+                    ),
+                    _ => return vec![],
+                };
+                diagnostics
+                    .into_iter()
+                    .map(|diagnostic| {
+                        let diagnostic = diagnostic
+                            .with_error_code(context::plugin_name_to_prefix(plugin), rule_name)
+                            .with_url(format!(
+                                "{}/{plugin}/{rule_name}.html",
+                                LintContext::WEBSITE_BASE_URL,
+                            ))
+                            .with_severity((*severity).into());
+                        Message::new(diagnostic, PossibleFixes::None)
+                    })
+                    .filter(|message| {
+                        disable_directives
+                            .is_none_or(|directives| !directives.contains(rule_name, message.span))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
     #[cfg(all(target_pointer_width = "64", target_endian = "little"))]
     fn run_external_rules<'a>(
         &self,
@@ -610,7 +822,12 @@ impl Linter {
                     }
 
                     // Convert `JSFix`s fixes to `PossibleFixes`, including converting spans back to UTF-8
-                    let fix = if let Some(fixes) = diagnostic.fixes {
+                    let fixes = diagnostic.fixes.filter(|_| {
+                        self.fix_only
+                            .as_ref()
+                            .is_none_or(|fix_only| fix_only.contains(plugin_name, rule_name))
+                    });
+                    let fix = if let Some(fixes) = fixes {
                         debug_assert!(!fixes.is_empty()); // JS should send `None` instead of `Some([])`
 
                         let is_single = fixes.len() == 1;
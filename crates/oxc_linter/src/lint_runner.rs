@@ -6,12 +6,14 @@ use std::{
 
 use rustc_hash::FxHashMap;
 
-use oxc_diagnostics::{DiagnosticSender, DiagnosticService, OxcDiagnostic};
+use oxc_allocator::Allocator;
+use oxc_diagnostics::{DiagnosticSender, OxcDiagnostic};
 use oxc_span::Span;
 
 use crate::{
     AllowWarnDeny, DisableDirectives, FixKind, LintService, LintServiceOptions, Linter, Message,
-    OsFileSystem, PossibleFixes, TsGoLintState,
+    ModuleGraph, OsFileSystem, PossibleFixes, RuntimeFileSystem, TsGoLintState,
+    create_unused_directives_messages, fixer::Fixer,
 };
 
 /// Unified runner that orchestrates both regular (oxc) and type-aware (tsgolint) linting
@@ -25,6 +27,8 @@ pub struct LintRunner {
     directives_store: DirectivesStore,
     /// Current working directory
     cwd: PathBuf,
+    /// Whether unused disable directives are removed from the files
+    fix_unused_directives: bool,
 }
 
 /// Manages disable directives across all linting engines.
@@ -85,23 +89,39 @@ impl DirectivesStore {
 
     /// Report unused disable directives
     ///
+    /// With `fix`, the unused directives are removed from the files, and only the directives which
+    /// could not be removed are reported.
+    ///
     /// # Panics
-    /// Panics if the mutex is poisoned or if sending to the error channel fails.
-    pub fn report_unused(&self, severity: AllowWarnDeny, cwd: &Path, tx_error: &DiagnosticSender) {
-        use crate::create_unused_directives_diagnostics;
-
+    /// Panics if the mutex is poisoned, if sending to the error channel fails, or if a fixed file
+    /// cannot be written.
+    pub fn report_unused(
+        &self,
+        severity: AllowWarnDeny,
+        fix: bool,
+        cwd: &Path,
+        file_system: &(dyn RuntimeFileSystem + Sync + Send),
+        tx_error: &DiagnosticSender,
+    ) {
         let map = self.map.lock().expect("DirectivesStore mutex poisoned in report_unused");
         for (path, directives) in map.iter() {
-            let diagnostics = create_unused_directives_diagnostics(directives, severity);
-
-            if !diagnostics.is_empty() {
-                let source_text = std::fs::read_to_string(path.as_path()).unwrap_or_default();
-                let wrapped = DiagnosticService::wrap_diagnostics(
-                    cwd,
-                    path.clone(),
-                    &source_text,
-                    diagnostics,
-                );
+            let allocator = Allocator::default();
+            let source_text = file_system.read_to_arena_str(path, &allocator).unwrap_or_default();
+            let mut messages =
+                create_unused_directives_messages(directives, severity, Some(source_text));
+
+            if fix && messages.iter().any(|message| !message.fixes.is_empty()) {
+                let fix_result = Fixer::new(source_text, messages, None).fix();
+                if fix_result.fixed {
+                    file_system
+                        .write_file(path, &fix_result.fixed_code)
+                        .expect("failed to write fixed file");
+                }
+                messages = fix_result.messages;
+            }
+
+            if !messages.is_empty() {
+                let wrapped = Message::wrap_messages(cwd, path, source_text, messages);
                 tx_error.send(wrapped).expect("failed to send unused directive diagnostics");
             }
         }
@@ -173,13 +193,19 @@ impl LintRunnerBuilder {
     pub fn build(self) -> Result<LintRunner, String> {
         let directives_coordinator = DirectivesStore::new();
 
+        let fix_only = self.regular_linter.fix_only().cloned();
         let type_aware_linter = if self.type_aware_enabled {
             match TsGoLintState::try_new(
                 self.lint_service_options.cwd(),
                 self.regular_linter.config.clone(),
                 self.fix_kind,
             ) {
-                Ok(state) => Some(state.with_silent(self.silent).with_type_check(self.type_check)),
+                Ok(state) => Some(
+                    state
+                        .with_silent(self.silent)
+                        .with_type_check(self.type_check)
+                        .with_fix_only(fix_only.clone()),
+                ),
                 Err(e) => return Err(e),
             }
         } else {
@@ -195,6 +221,8 @@ impl LintRunnerBuilder {
             type_aware_linter,
             directives_store: directives_coordinator,
             cwd,
+            // Unused directives don't belong to a rule, so they are not fixed with `--fix-only`.
+            fix_unused_directives: self.fix_kind.is_some() && fix_only.is_none(),
         })
     }
 }
@@ -260,14 +288,23 @@ impl LintRunner {
         messages
     }
 
-    /// Report unused disable directives
+    /// Report unused disable directives, removing them from the files when fixing.
+    ///
+    /// `file_system` must be the file system passed to [`LintRunner::lint_files`].
     pub fn report_unused_directives(
         &self,
         severity: Option<AllowWarnDeny>,
+        file_system: Option<&(dyn RuntimeFileSystem + Sync + Send)>,
         tx_error: &DiagnosticSender,
     ) {
         if let Some(severity) = severity {
-            self.directives_store.report_unused(severity, &self.cwd, tx_error);
+            self.directives_store.report_unused(
+                severity,
+                self.fix_unused_directives,
+                &self.cwd,
+                file_system.unwrap_or(&OsFileSystem),
+                tx_error,
+            );
         }
     }
 
@@ -276,6 +313,18 @@ impl LintRunner {
         &self.directives_store
     }
 
+    /// The module graph of the linted files and their imports, built while linting when
+    /// cross-module linting is enabled.
+    pub fn module_graph(&self) -> &ModuleGraph {
+        self.lint_service.module_graph()
+    }
+
+    /// Resolves `specifier` imported by the module at `importer`, like the imports of the module
+    /// graph. Only possible when cross-module linting is enabled.
+    pub fn resolve(&self, importer: &Path, specifier: &str) -> Option<PathBuf> {
+        self.lint_service.resolve(importer, specifier)
+    }
+
     /// Check if type-aware linting is enabled
     pub fn has_type_aware(&self) -> bool {
         self.type_aware_linter.is_some()
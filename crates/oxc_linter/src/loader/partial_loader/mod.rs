@@ -6,10 +6,14 @@ use crate::loader::JavaScriptSource;
 mod astro;
 mod mpx;
 mod svelte;
+mod template;
 mod vue;
 pub use astro::AstroPartialLoader;
 pub use mpx::MpxPartialLoader;
 pub use svelte::SveltePartialLoader;
+pub use template::{
+    Directive, Template, TemplateAttribute, TemplateElement, TemplateElementId, TemplateSource,
+};
 pub use vue::VuePartialLoader;
 
 const SCRIPT_START: &str = "<script";
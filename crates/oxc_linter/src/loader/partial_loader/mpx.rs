@@ -3,7 +3,7 @@ use memchr::memmem::{Finder, FinderRev};
 use oxc_span::SourceType;
 
 use super::{
-    COMMENT_END, COMMENT_START, JavaScriptSource, SCRIPT_END, SCRIPT_START,
+    COMMENT_END, COMMENT_START, JavaScriptSource, SCRIPT_END, SCRIPT_START, TemplateSource,
     find_script_closing_angle, find_script_start,
 };
 
@@ -17,7 +17,8 @@ impl<'a> MpxPartialLoader<'a> {
     }
 
     pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
-        self.parse_scripts()
+        let template = TemplateSource::find(self.source_text);
+        self.parse_scripts().into_iter().map(|source| source.with_template(template)).collect()
     }
 
     /// MPX files can contain multiple `<script>` blocks.
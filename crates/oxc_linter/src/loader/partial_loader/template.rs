@@ -0,0 +1,360 @@
+use memchr::memmem::Finder;
+
+use oxc_span::Span;
+
+use super::{COMMENT_END, COMMENT_START};
+
+const TEMPLATE_START: &str = "<template";
+
+/// Elements which never have children, and are not closed by an end tag.
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// The content of the top-level `<template>` block of a Vue or MPX file.
+#[derive(Debug, Clone, Copy)]
+pub struct TemplateSource<'a> {
+    pub source_text: &'a str,
+    /// Start offset of the content in the original file.
+    pub start: u32,
+}
+
+impl<'a> TemplateSource<'a> {
+    /// Find the top-level `<template>` block of `source_text`, skipping nested `<template>`
+    /// elements and HTML comments.
+    pub fn find(source_text: &'a str) -> Option<Self> {
+        let bytes = source_text.as_bytes();
+        let mut pointer = 0;
+        let content_start = loop {
+            let offset = Finder::new(TEMPLATE_START).find(&bytes[pointer..])?;
+            let tag_start = pointer + offset;
+            pointer = tag_start + TEMPLATE_START.len();
+            if is_inside_comment(source_text, tag_start) {
+                continue;
+            }
+            if source_text[pointer..].starts_with(|c: char| c.is_ascii_whitespace() || c == '>') {
+                break pointer + source_text[pointer..].find('>')? + 1;
+            }
+        };
+
+        // Find the matching `</template>`, nested `<template>` elements included.
+        let mut depth = 0;
+        pointer = content_start;
+        loop {
+            let offset = source_text[pointer..].find("template")?;
+            let index = pointer + offset;
+            pointer = index + "template".len();
+            if source_text[..index].ends_with("</") {
+                if depth == 0 {
+                    let content_end = index - 2;
+                    // NOTE: loader checked that source_text.len() is less than u32::MAX
+                    #[expect(clippy::cast_possible_truncation)]
+                    return Some(Self {
+                        source_text: &source_text[content_start..content_end],
+                        start: content_start as u32,
+                    });
+                }
+                depth -= 1;
+            } else if source_text[..index].ends_with('<')
+                && source_text[pointer..]
+                    .starts_with(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+            {
+                let self_closing = source_text[pointer..]
+                    .find('>')
+                    .is_some_and(|end| source_text[..pointer + end].ends_with('/'));
+                if !self_closing {
+                    depth += 1;
+                }
+            }
+        }
+    }
+}
+
+fn is_inside_comment(source_text: &str, index: usize) -> bool {
+    source_text[..index]
+        .rfind(COMMENT_START)
+        .is_some_and(|start| !source_text[start..index].contains(COMMENT_END))
+}
+
+/// A lightweight AST of a `<template>` block: its elements, their attributes and how they nest.
+///
+/// Only start tags are kept, which is enough for rules about element names, directives and keys.
+/// Text and interpolations are skipped.
+#[derive(Debug, Default)]
+pub struct Template<'a> {
+    elements: Vec<TemplateElement<'a>>,
+}
+
+/// Index of an element in [`Template::elements`].
+pub type TemplateElementId = usize;
+
+#[derive(Debug)]
+pub struct TemplateElement<'a> {
+    /// Tag name as written, e.g. `div`, `MyComponent` or `my-component`.
+    pub name: &'a str,
+    /// Span of the start tag in the original file.
+    pub span: Span,
+    pub attributes: Vec<TemplateAttribute<'a>>,
+    pub parent: Option<TemplateElementId>,
+}
+
+#[derive(Debug)]
+pub struct TemplateAttribute<'a> {
+    /// Attribute name as written, e.g. `class`, `:key`, `v-bind:key` or `@click.prevent`.
+    pub name: &'a str,
+    /// Value without quotes, `None` for attributes without a value.
+    pub value: Option<&'a str>,
+    /// Span of the whole attribute in the original file.
+    pub span: Span,
+}
+
+/// A Vue directive such as `v-for`, `v-bind:key` or its shorthand `:key`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Directive<'a> {
+    /// Name without `v-`, e.g. `for`, `bind` or `on`.
+    pub name: &'a str,
+    pub argument: Option<&'a str>,
+}
+
+impl<'a> TemplateAttribute<'a> {
+    /// Parse the attribute name as a directive, expanding the `:`, `@` and `#` shorthands.
+    pub fn directive(&self) -> Option<Directive<'a>> {
+        let (name, rest) = if let Some(rest) = self.name.strip_prefix("v-") {
+            match rest.find([':', '.']) {
+                Some(end) if rest.as_bytes()[end] == b':' => (&rest[..end], Some(&rest[end + 1..])),
+                Some(end) => (&rest[..end], None),
+                None => (rest, None),
+            }
+        } else if let Some(rest) = self.name.strip_prefix(':') {
+            ("bind", Some(rest))
+        } else if let Some(rest) = self.name.strip_prefix('.') {
+            ("bind", Some(rest))
+        } else if let Some(rest) = self.name.strip_prefix('@') {
+            ("on", Some(rest))
+        } else if let Some(rest) = self.name.strip_prefix('#') {
+            ("slot", Some(rest))
+        } else {
+            return None;
+        };
+        let argument = rest.map(|rest| rest.find('.').map_or(rest, |end| &rest[..end]));
+        Some(Directive { name, argument })
+    }
+}
+
+impl<'a> TemplateElement<'a> {
+    pub fn attribute(&self, name: &str) -> Option<&TemplateAttribute<'a>> {
+        self.attributes.iter().find(|attribute| attribute.name == name)
+    }
+
+    pub fn directive(&self, name: &str, argument: Option<&str>) -> Option<&TemplateAttribute<'a>> {
+        self.attributes.iter().find(|attribute| {
+            attribute.directive().is_some_and(|directive| {
+                directive.name == name && (argument.is_none() || directive.argument == argument)
+            })
+        })
+    }
+}
+
+impl<'a> Template<'a> {
+    pub fn parse(source: TemplateSource<'a>) -> Self {
+        TemplateParser { source_text: source.source_text, offset: source.start, pointer: 0 }.parse()
+    }
+
+    pub fn elements(&self) -> &[TemplateElement<'a>] {
+        &self.elements
+    }
+
+    pub fn children(
+        &self,
+        parent: TemplateElementId,
+    ) -> impl Iterator<Item = (TemplateElementId, &TemplateElement<'a>)> {
+        self.elements.iter().enumerate().filter(move |(_, element)| element.parent == Some(parent))
+    }
+}
+
+struct TemplateParser<'a> {
+    source_text: &'a str,
+    offset: u32,
+    pointer: usize,
+}
+
+impl<'a> TemplateParser<'a> {
+    fn parse(mut self) -> Template<'a> {
+        let mut elements: Vec<TemplateElement<'a>> = vec![];
+        let mut open: Vec<TemplateElementId> = vec![];
+        let bytes = self.source_text.as_bytes();
+
+        while self.pointer < bytes.len() {
+            let rest = &self.source_text[self.pointer..];
+            if rest.starts_with("{{") {
+                self.skip_past("}}");
+            } else if rest.starts_with(COMMENT_START) {
+                self.skip_past(COMMENT_END);
+            } else if let Some(name) = rest.strip_prefix("</") {
+                let name = &name
+                    [..name.find(|c: char| c == '>' || c.is_whitespace()).unwrap_or(name.len())];
+                if let Some(index) = open.iter().rposition(|&id| elements[id].name == name) {
+                    open.truncate(index);
+                }
+                self.skip_past(">");
+            } else if rest.starts_with('<')
+                && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+            {
+                let start = self.pointer;
+                self.pointer += 1;
+                let name = self.take_while(|c| !c.is_whitespace() && c != '>' && c != '/');
+                let (attributes, self_closing) = self.parse_attributes();
+                let id = elements.len();
+                elements.push(TemplateElement {
+                    name,
+                    span: self.span(start, self.pointer),
+                    attributes,
+                    parent: open.last().copied(),
+                });
+                if !self_closing
+                    && !VOID_ELEMENTS.iter().any(|void| void.eq_ignore_ascii_case(name))
+                {
+                    open.push(id);
+                }
+            } else {
+                self.pointer += rest.chars().next().map_or(1, char::len_utf8);
+            }
+        }
+
+        Template { elements }
+    }
+
+    /// Parse attributes up to and including the end of the start tag, returning them and
+    /// whether the tag is self-closing.
+    fn parse_attributes(&mut self) -> (Vec<TemplateAttribute<'a>>, bool) {
+        let mut attributes = vec![];
+        loop {
+            self.take_while(char::is_whitespace);
+            let rest = &self.source_text[self.pointer..];
+            if rest.is_empty() {
+                return (attributes, false);
+            }
+            if rest.starts_with("/>") {
+                self.pointer += 2;
+                return (attributes, true);
+            }
+            if rest.starts_with('>') {
+                self.pointer += 1;
+                return (attributes, false);
+            }
+
+            let start = self.pointer;
+            let name = self.take_while(|c| !c.is_whitespace() && c != '=' && c != '>' && c != '/');
+            if name.is_empty() {
+                // A stray `/`.
+                self.pointer += 1;
+                continue;
+            }
+            self.take_while(char::is_whitespace);
+            let mut value = None;
+            if self.source_text[self.pointer..].starts_with('=') {
+                self.pointer += 1;
+                self.take_while(char::is_whitespace);
+                let rest = &self.source_text[self.pointer..];
+                if let Some(quote) = rest.chars().next().filter(|c| matches!(c, '"' | '\'')) {
+                    self.pointer += 1;
+                    value = Some(self.take_while(|c| c != quote));
+                    self.pointer = (self.pointer + 1).min(self.source_text.len());
+                } else {
+                    value = Some(self.take_while(|c| !c.is_whitespace() && c != '>'));
+                }
+            }
+            attributes.push(TemplateAttribute {
+                name,
+                value,
+                span: self.span(start, self.pointer),
+            });
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = &self.source_text[self.pointer..];
+        let end = rest.find(|c: char| !predicate(c)).unwrap_or(rest.len());
+        self.pointer += end;
+        &rest[..end]
+    }
+
+    fn skip_past(&mut self, pattern: &str) {
+        self.pointer = self.source_text[self.pointer..]
+            .find(pattern)
+            .map_or(self.source_text.len(), |index| self.pointer + index + pattern.len());
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn span(&self, start: usize, end: usize) -> Span {
+        Span::new(self.offset + start as u32, self.offset + end as u32)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Directive, Template, TemplateAttribute, TemplateSource};
+
+    fn parse(source_text: &str) -> Template<'_> {
+        Template::parse(TemplateSource::find(source_text).unwrap())
+    }
+
+    #[test]
+    fn test_find_template() {
+        let source_text = r#"
+        <!-- <template>commented</template> -->
+        <template>
+          <template v-if="a"><div /></template>
+          <template v-else />
+        </template>
+        <script></script>
+        "#;
+        let source = TemplateSource::find(source_text).unwrap();
+        assert!(source.source_text.trim().starts_with(r#"<template v-if="a">"#));
+        assert!(source.source_text.trim().ends_with("<template v-else />"));
+        assert_eq!(
+            &source_text[source.start as usize..source.start as usize + source.source_text.len()],
+            source.source_text
+        );
+
+        assert!(TemplateSource::find("<script></script>").is_none());
+    }
+
+    #[test]
+    fn test_parse_elements() {
+        let source_text = r#"<template>
+          <ul class="list">
+            <li v-for="item in items" :key="item.id">{{ item.a < item.b }}</li>
+            <br>
+            <MyComponent @click.prevent="go" v-bind:title='t' disabled />
+          </ul>
+        </template>"#;
+        let template = parse(source_text);
+        let names: Vec<_> = template.elements().iter().map(|element| element.name).collect();
+        assert_eq!(names, ["ul", "li", "br", "MyComponent"]);
+
+        let parents: Vec<_> = template.elements().iter().map(|element| element.parent).collect();
+        assert_eq!(parents, [None, Some(0), Some(0), Some(0)]);
+        assert_eq!(template.children(0).count(), 3);
+
+        let li = &template.elements()[1];
+        assert_eq!(
+            li.span.source_text(source_text),
+            r#"<li v-for="item in items" :key="item.id">"#
+        );
+        assert_eq!(li.attribute("v-for").unwrap().value, Some("item in items"));
+        assert!(li.directive("bind", Some("key")).is_some());
+
+        let component = &template.elements()[3];
+        assert_eq!(
+            component.attributes.iter().map(TemplateAttribute::directive).collect::<Vec<_>>(),
+            [
+                Some(Directive { name: "on", argument: Some("click") }),
+                Some(Directive { name: "bind", argument: Some("title") }),
+                None,
+            ]
+        );
+        assert_eq!(component.attribute("disabled").unwrap().value, None);
+    }
+}
@@ -5,7 +5,7 @@ use oxc_span::SourceType;
 use crate::frameworks::FrameworkOptions;
 
 use super::{
-    COMMENT_END, COMMENT_START, JavaScriptSource, SCRIPT_END, SCRIPT_START,
+    COMMENT_END, COMMENT_START, JavaScriptSource, SCRIPT_END, SCRIPT_START, TemplateSource,
     find_script_closing_angle, find_script_start,
 };
 
@@ -19,7 +19,8 @@ impl<'a> VuePartialLoader<'a> {
     }
 
     pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
-        self.parse_scripts()
+        let template = TemplateSource::find(self.source_text);
+        self.parse_scripts().into_iter().map(|source| source.with_template(template)).collect()
     }
 
     /// Each *.vue file can contain at most
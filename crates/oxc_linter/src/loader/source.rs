@@ -2,6 +2,8 @@ use oxc_span::SourceType;
 
 use crate::frameworks::FrameworkOptions;
 
+use super::TemplateSource;
+
 #[derive(Debug, Clone, Copy)]
 #[non_exhaustive]
 pub struct JavaScriptSource<'a> {
@@ -15,6 +17,9 @@ pub struct JavaScriptSource<'a> {
 
     // some partial sources can have special options defined, like Vue's `<script setup>`.
     pub framework_options: FrameworkOptions,
+
+    /// The `<template>` block of the file, for Vue and MPX files.
+    pub template: Option<TemplateSource<'a>>,
 }
 
 impl<'a> JavaScriptSource<'a> {
@@ -25,6 +30,7 @@ impl<'a> JavaScriptSource<'a> {
             start: 0,
             is_partial: false,
             framework_options: FrameworkOptions::Default,
+            template: None,
         }
     }
 
@@ -43,7 +49,20 @@ impl<'a> JavaScriptSource<'a> {
         framework_options: FrameworkOptions,
         start: u32,
     ) -> Self {
-        Self { source_text, source_type, start, is_partial: true, framework_options }
+        Self {
+            source_text,
+            source_type,
+            start,
+            is_partial: true,
+            framework_options,
+            template: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_template(mut self, template: Option<TemplateSource<'a>>) -> Self {
+        self.template = template;
+        self
     }
 
     pub fn as_str(&self) -> &'a str {
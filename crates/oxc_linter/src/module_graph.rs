@@ -0,0 +1,326 @@
+use std::{
+    ffi::OsStr,
+    hash::BuildHasherDefault,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
+use smallvec::SmallVec;
+
+use oxc_span::CompactStr;
+
+use crate::module_record::{ExportImportName, ImportImportName, ModuleRecord};
+
+type ModulesByPath =
+    papaya::HashMap<Arc<OsStr>, SmallVec<[Arc<ModuleRecord>; 1]>, BuildHasherDefault<FxHasher>>;
+
+/// An import of a module, resolved to the path of the imported module.
+pub struct ResolvedModuleRequest {
+    pub specifier: CompactStr,
+    pub resolved_requested_path: Arc<OsStr>,
+}
+
+/// The modules of a lint run, and the modules they import.
+///
+/// The graph is built in parallel by [`LintService`](crate::LintService) when cross-module
+/// linting is enabled (e.g. by the import plugin). It contains the linted files and the files
+/// they import, directly or indirectly. Imports are resolved with `oxc_resolver`.
+///
+/// Rules access the imports of the linted file through [`ModuleRecord::loaded_modules`].
+/// The graph can be queried after linting, e.g. for the modules importing a module.
+pub struct ModuleGraph {
+    /// Module records keyed by module paths. The values are module records of sections (check
+    /// the docs of `ProcessedModule.section_module_records`).
+    ///
+    /// `ModuleRecord` is a cyclic data structure.
+    /// To make sure all `ModuleRecord` gets dropped after `ModuleGraph` is dropped,
+    /// `modules_by_path` must own `ModuleRecord` with `Arc`, all other references must use `Weak<ModuleRecord>`.
+    modules_by_path: ModulesByPath,
+    /// Paths of the modules importing each module.
+    importers: RwLock<FxHashMap<Arc<OsStr>, FxHashSet<Arc<OsStr>>>>,
+}
+
+impl Default for ModuleGraph {
+    fn default() -> Self {
+        Self {
+            modules_by_path: papaya::HashMap::builder()
+                .hasher(BuildHasherDefault::default())
+                .resize_mode(papaya::ResizeMode::Blocking)
+                .build(),
+            importers: RwLock::default(),
+        }
+    }
+}
+
+impl ModuleGraph {
+    /// Number of modules in the graph.
+    pub fn len(&self) -> usize {
+        self.modules_by_path.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules_by_path.is_empty()
+    }
+
+    /// Whether the module at `path` is in the graph.
+    pub fn contains(&self, path: &Path) -> bool {
+        self.modules_by_path.pin().contains_key(path.as_os_str())
+    }
+
+    /// Paths of all modules in the graph, sorted.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        let mut paths = self
+            .modules_by_path
+            .pin()
+            .keys()
+            .map(|path| PathBuf::from(&**path))
+            .collect::<Vec<_>>();
+        paths.sort_unstable();
+        paths
+    }
+
+    /// Module records of the module at `path`, one for each script section of the module.
+    /// Empty if the module is not in the graph, or couldn't be parsed.
+    pub fn module_records(&self, path: &Path) -> Vec<Arc<ModuleRecord>> {
+        self.modules_by_path
+            .pin()
+            .get(path.as_os_str())
+            .map(|records| records.iter().map(Arc::clone).collect())
+            .unwrap_or_default()
+    }
+
+    /// Paths of the modules imported by the module at `path`, sorted.
+    pub fn dependencies(&self, path: &Path) -> Vec<PathBuf> {
+        let mut dependencies = self
+            .module_records(path)
+            .iter()
+            .flat_map(|record| {
+                record
+                    .loaded_modules()
+                    .values()
+                    .filter_map(|module| Some(module.upgrade()?.resolved_absolute_path.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        dependencies.sort_unstable();
+        dependencies.dedup();
+        dependencies
+    }
+
+    /// Paths of the modules importing the module at `path`, sorted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a thread panicked while adding modules to the graph.
+    pub fn importers(&self, path: &Path) -> Vec<PathBuf> {
+        let importers = self.importers.read().expect("module graph importers lock poisoned");
+        let mut importers = importers.get(path.as_os_str()).map_or_else(Vec::new, |importers| {
+            importers.iter().map(|path| PathBuf::from(&**path)).collect()
+        });
+        importers.sort_unstable();
+        importers
+    }
+
+    /// Paths of the modules exporting `name`, sorted.
+    pub fn exporters(&self, name: &str) -> Vec<PathBuf> {
+        let mut exporters = self
+            .modules_by_path
+            .pin()
+            .iter()
+            .filter(|(_, records)| records.iter().any(|record| record.exports_name(name)))
+            .map(|(path, _)| PathBuf::from(&**path))
+            .collect::<Vec<_>>();
+        exporters.sort_unstable();
+        exporters
+    }
+
+    /// The specifiers with which the module at `path` is imported by its importers, sorted.
+    pub fn specifiers(&self, path: &Path) -> Vec<CompactStr> {
+        let mut specifiers = self
+            .importers(path)
+            .iter()
+            .flat_map(|importer| self.module_records(importer))
+            .flat_map(|record| {
+                record
+                    .loaded_modules()
+                    .iter()
+                    .filter(|(_, module)| {
+                        module.upgrade().is_some_and(|module| module.resolved_absolute_path == path)
+                    })
+                    .map(|(specifier, _)| specifier.clone())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        specifiers.sort_unstable();
+        specifiers.dedup();
+        specifiers
+    }
+
+    /// Names of the exports of the module at `path` imported by other modules, or `None` if any
+    /// export may be used, e.g. by a namespace import. The names imported from a module which
+    /// re-exports all exports of `path` with `export *` are imported from `path` too.
+    pub fn imported_names(&self, path: &Path) -> Option<FxHashSet<CompactStr>> {
+        self.collect_imported_names(path, &mut FxHashSet::default())
+    }
+
+    /// `visited` are the modules whose imported names are already looked up, for cyclic re-exports.
+    fn collect_imported_names(
+        &self,
+        path: &Path,
+        visited: &mut FxHashSet<PathBuf>,
+    ) -> Option<FxHashSet<CompactStr>> {
+        let mut names = FxHashSet::default();
+        if !visited.insert(path.to_path_buf()) {
+            return Some(names);
+        }
+        for importer in self.importers(path) {
+            if importer == path {
+                continue;
+            }
+            for record in self.module_records(&importer) {
+                let specifiers = record
+                    .loaded_modules()
+                    .iter()
+                    .filter(|(_, module)| {
+                        module.upgrade().is_some_and(|module| module.resolved_absolute_path == path)
+                    })
+                    .map(|(specifier, _)| specifier.clone())
+                    .collect::<Vec<_>>();
+                for specifier in &specifiers {
+                    for entry in record
+                        .import_entries
+                        .iter()
+                        .filter(|entry| entry.module_request.name == *specifier)
+                    {
+                        match &entry.import_name {
+                            ImportImportName::Name(name) => names.insert(name.name.clone()),
+                            ImportImportName::Default(_) => names.insert("default".into()),
+                            ImportImportName::NamespaceObject => return None,
+                        };
+                    }
+                    for entry in
+                        record.indirect_export_entries.iter().chain(&record.star_export_entries)
+                    {
+                        if entry
+                            .module_request
+                            .as_ref()
+                            .is_none_or(|request| request.name != *specifier)
+                        {
+                            continue;
+                        }
+                        match &entry.import_name {
+                            ExportImportName::Name(name) => {
+                                names.insert(name.name.clone());
+                            }
+                            ExportImportName::AllButDefault => {
+                                names.extend(self.collect_imported_names(&importer, visited)?);
+                            }
+                            ExportImportName::All | ExportImportName::Null => return None,
+                        }
+                    }
+                }
+            }
+        }
+        Some(names)
+    }
+
+    pub(crate) fn reserve(&self, additional: usize) {
+        self.modules_by_path.pin().reserve(additional);
+    }
+
+    /// Adds the module at `path`, with the module records of its sections.
+    pub(crate) fn insert(&self, path: Arc<OsStr>, records: SmallVec<[Arc<ModuleRecord>; 1]>) {
+        self.modules_by_path.pin().insert(path, records);
+    }
+
+    /// Populates the `loaded_modules` of the module records of the module at `path` with the
+    /// modules imported by each section. The imported modules must already be in the graph.
+    pub(crate) fn link(
+        &self,
+        path: &Arc<OsStr>,
+        requested_module_paths: SmallVec<[Vec<ResolvedModuleRequest>; 1]>,
+    ) {
+        if requested_module_paths.is_empty() {
+            return;
+        }
+        let modules_by_path = self.modules_by_path.pin();
+        let records = modules_by_path.get(path).unwrap();
+        assert_eq!(
+            records.len(),
+            requested_module_paths.len(),
+            "This is an internal logic error. Please file an issue at https://github.com/oxc-project/oxc/issues",
+        );
+        let mut dependencies = FxHashSet::default();
+        for (record, requested_module_paths) in records.iter().zip(requested_module_paths) {
+            let mut loaded_modules = record.write_loaded_modules();
+            for request in requested_module_paths {
+                // TODO: revise how to store multiple sections in loaded_modules
+                let Some(dep_module_record) =
+                    modules_by_path.get(&request.resolved_requested_path).unwrap().last()
+                else {
+                    continue;
+                };
+                loaded_modules.insert(request.specifier, Arc::downgrade(dep_module_record));
+                dependencies.insert(request.resolved_requested_path);
+            }
+        }
+
+        let mut importers = self.importers.write().expect("module graph importers lock poisoned");
+        for dependency in dependencies {
+            importers.entry(dependency).or_default().insert(Arc::clone(path));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, ffi::OsStr, path::PathBuf, sync::Arc, sync::mpsc};
+
+    use rustc_hash::FxHashMap;
+
+    use crate::{
+        ConfigStore, ConfigStoreBuilder, ExternalPluginStore, LintOptions, LintService,
+        LintServiceOptions, Linter, OsFileSystem,
+    };
+
+    #[test]
+    fn importers_and_dependencies() {
+        let cwd = env::current_dir().unwrap().join("fixtures/import/cycles/es6");
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let config = ConfigStoreBuilder::empty().build(&mut external_plugin_store).unwrap();
+        let linter = Linter::new(
+            LintOptions::default(),
+            ConfigStore::new(config, FxHashMap::default(), external_plugin_store),
+            None,
+        );
+        let service = LintService::new(
+            linter,
+            LintServiceOptions::new(cwd.as_path()).with_cross_module(true),
+        );
+        let path = cwd.join("depth-three-indirect.js");
+        let (sender, _receiver) = mpsc::channel();
+        service.run_test_source(
+            &OsFileSystem,
+            vec![Arc::<OsStr>::from(path.as_os_str())],
+            false,
+            &sender,
+        );
+
+        let graph = service.module_graph();
+        let depth_one = cwd.join("depth-one.js");
+        let depth_two = cwd.join("depth-two.js");
+        let depth_zero = cwd.parent().unwrap().join("depth-zero.js");
+        assert_eq!(graph.len(), 4);
+        assert!(graph.contains(&depth_zero));
+        assert_eq!(graph.dependencies(&path), vec![depth_two.clone()]);
+        assert_eq!(graph.dependencies(&depth_two), vec![depth_one.clone()]);
+        assert_eq!(graph.importers(&depth_one), vec![depth_two.clone()]);
+        assert_eq!(graph.importers(&path), Vec::<PathBuf>::new());
+        assert_eq!(graph.module_records(&depth_one).len(), 1);
+        assert_eq!(graph.exporters("foo"), vec![depth_one.clone(), depth_two]);
+        assert_eq!(graph.specifiers(&depth_one), vec!["./depth-one"]);
+        assert_eq!(graph.specifiers(&depth_zero), vec!["../depth-zero"]);
+        assert_eq!(graph.exporters("bar"), vec![path]);
+    }
+}
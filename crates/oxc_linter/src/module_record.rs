@@ -6,12 +6,16 @@ use std::{
     sync::{Arc, OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard, Weak},
 };
 
-use rustc_hash::FxHashMap;
+use cow_utils::CowUtils;
+use rustc_hash::{FxHashMap, FxHashSet};
 
-use oxc_semantic::Semantic;
+use oxc_ast::AstKind;
+use oxc_semantic::{JSDoc, Semantic};
 use oxc_span::{CompactStr, Span};
 pub use oxc_syntax::module_record::RequestedModule;
 
+use crate::dead_code::write_only_variables;
+
 /// ESM Module Record
 ///
 /// All data inside this data structure are for ESM, no commonjs data is allowed.
@@ -50,6 +54,11 @@ pub struct ModuleRecord {
     /// Use [ModuleRecord::get_loaded_module] to get a `ModuleRecord`.
     loaded_modules: RwLock<FxHashMap<CompactStr, Weak<ModuleRecord>>>,
 
+    /// The specifiers of `requested_modules` which could not be resolved.
+    ///
+    /// Only filled when the modules are resolved, i.e. when the import plugin is enabled.
+    pub unresolved_modules: FxHashSet<CompactStr>,
+
     /// `[[ImportEntries]]`
     ///
     /// A List of `ImportEntry` records derived from the code of this module
@@ -85,6 +94,18 @@ pub struct ModuleRecord {
     /// `export default name`
     ///         ^^^^^^^ span
     pub export_default: Option<Span>,
+
+    /// The message of a `@deprecated` tag in the module's JSDoc, i.e. the JSDoc with a `@module`
+    /// tag.
+    pub deprecation: Option<CompactStr>,
+
+    /// The messages of the `@deprecated` tags in the JSDoc of exported declarations, or of export
+    /// statements, keyed by export name.
+    pub deprecated_exports: FxHashMap<CompactStr, CompactStr>,
+
+    /// Exported variables which are assigned in this module but never read, keyed by export name.
+    /// They are dead code unless another module imports them.
+    pub write_only_exports: FxHashMap<CompactStr, Span>,
 }
 
 impl fmt::Debug for ModuleRecord {
@@ -104,6 +125,7 @@ impl fmt::Debug for ModuleRecord {
             .field("resolved_absolute_path", &self.resolved_absolute_path)
             .field("requested_modules", &self.requested_modules)
             .field("loaded_modules", &loaded_modules)
+            .field("unresolved_modules", &self.unresolved_modules)
             .field("import_entries", &self.import_entries)
             .field("local_export_entries", &self.local_export_entries)
             .field("indirect_export_entries", &self.indirect_export_entries)
@@ -111,6 +133,9 @@ impl fmt::Debug for ModuleRecord {
             .field("exported_bindings", &self.exported_bindings)
             .field("exported_bindings_from_star_export", &self.exported_bindings_from_star_export)
             .field("export_default", &self.export_default)
+            .field("deprecation", &self.deprecation)
+            .field("deprecated_exports", &self.deprecated_exports)
+            .field("write_only_exports", &self.write_only_exports)
             .finish()
     }
 }
@@ -462,7 +487,7 @@ impl ModuleRecord {
     pub fn new(
         path: &Path,
         other: &oxc_syntax::module_record::ModuleRecord,
-        _semantic: &Semantic,
+        semantic: &Semantic,
     ) -> Self {
         Self {
             has_module_syntax: other.has_module_syntax,
@@ -506,6 +531,38 @@ impl ModuleRecord {
                         .filter_map(|export_entry| export_entry.export_name.default_export_span()),
                 )
                 .next(),
+            deprecation: semantic
+                .jsdoc()
+                .iter_all()
+                .find(|jsdoc| jsdoc.tags().iter().any(|tag| tag.kind.parsed() == "module"))
+                .and_then(deprecation_message),
+            deprecated_exports: other
+                .local_export_entries
+                .iter()
+                .chain(&other.indirect_export_entries)
+                .filter_map(|export_entry| {
+                    let export_name = match &export_entry.export_name {
+                        oxc_syntax::module_record::ExportExportName::Name(name) => name.name,
+                        oxc_syntax::module_record::ExportExportName::Default(_) => "default".into(),
+                        oxc_syntax::module_record::ExportExportName::Null => return None,
+                    };
+                    let message = export_deprecation(export_entry, semantic)?;
+                    Some((CompactStr::from(export_name.as_str()), message))
+                })
+                .collect(),
+            write_only_exports: if other.local_export_entries.is_empty() {
+                FxHashMap::default()
+            } else {
+                write_only_variables(semantic)
+                    .into_iter()
+                    .flat_map(|variable| {
+                        variable
+                            .export_names
+                            .into_iter()
+                            .map(move |name| (CompactStr::from(name), variable.span))
+                    })
+                    .collect()
+            },
             ..ModuleRecord::default()
         }
     }
@@ -538,6 +595,20 @@ impl ModuleRecord {
         loaded_modules.get(key).map(|weak| Weak::upgrade(weak).unwrap())
     }
 
+    /// Whether this module exports `name`, declared in this module or re-exported from another.
+    pub fn exports_name(&self, name: &str) -> bool {
+        self.exported_bindings.contains_key(name)
+            || self.indirect_export_entries.iter().any(|entry| match &entry.export_name {
+                ExportExportName::Name(export_name) => export_name.name() == name,
+                ExportExportName::Default(_) => name == "default",
+                ExportExportName::Null => false,
+            })
+            || self
+                .exported_bindings_from_star_export()
+                .values()
+                .any(|names| names.iter().any(|n| n == name))
+    }
+
     pub(crate) fn exported_bindings_from_star_export(
         &self,
     ) -> &FxHashMap<PathBuf, Vec<CompactStr>> {
@@ -571,3 +642,37 @@ impl ModuleRecord {
         })
     }
 }
+
+/// The deprecation of an export: the `@deprecated` tag in the nearest JSDoc of the exported
+/// declaration, or of the export statement.
+fn export_deprecation(
+    export_entry: &oxc_syntax::module_record::ExportEntry,
+    semantic: &Semantic,
+) -> Option<CompactStr> {
+    let jsdoc = semantic.jsdoc();
+    let declaration = export_entry
+        .local_name
+        .name()
+        .and_then(|name| semantic.scoping().get_root_binding(name.as_str()))
+        .map(|symbol_id| semantic.scoping().symbol_declaration(symbol_id));
+    declaration
+        .into_iter()
+        .flat_map(|node_id| {
+            std::iter::once(semantic.nodes().get_node(node_id))
+                .chain(semantic.nodes().ancestors(node_id))
+        })
+        .take_while(|node| !matches!(node.kind(), AstKind::Program(_)))
+        .find_map(|node| jsdoc.get_one_by_node(semantic.nodes(), node))
+        .or_else(|| jsdoc.get_all_by_span(export_entry.statement_span)?.pop())
+        .as_ref()
+        .and_then(deprecation_message)
+}
+
+/// The message of the `@deprecated` tag of a JSDoc, which is empty if the tag has no message.
+fn deprecation_message(jsdoc: &JSDoc) -> Option<CompactStr> {
+    jsdoc
+        .tags()
+        .iter()
+        .find(|tag| tag.kind.parsed() == "deprecated")
+        .map(|tag| CompactStr::from(tag.comment().parsed().cow_replace('\n', " ").as_ref()))
+}
@@ -0,0 +1,38 @@
+use rustc_hash::FxHashSet;
+
+/// Rules whose fixes are applied, when fixes of all other rules should be left out.
+///
+/// Rules are given by name, e.g. `no-debugger`, or by plugin and name, e.g. `eslint/no-debugger`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FixOnly {
+    rules: FxHashSet<String>,
+}
+
+impl FixOnly {
+    pub fn new<I: IntoIterator<Item = S>, S: Into<String>>(rules: I) -> Self {
+        Self { rules: rules.into_iter().map(Into::into).collect() }
+    }
+
+    /// Returns `true` if the fixes of rule `rule_name` of plugin `plugin_name` are applied.
+    pub fn contains(&self, plugin_name: &str, rule_name: &str) -> bool {
+        self.rules.iter().any(|rule| match rule.split_once('/') {
+            Some((plugin, rule)) => plugin == plugin_name && rule == rule_name,
+            None => rule == rule_name,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FixOnly;
+
+    #[test]
+    fn contains() {
+        let fix_only = FixOnly::new(["no-debugger", "unicorn/no-useless-spread"]);
+
+        assert!(fix_only.contains("eslint", "no-debugger"));
+        assert!(fix_only.contains("unicorn", "no-useless-spread"));
+        assert!(!fix_only.contains("eslint", "no-useless-spread"));
+        assert!(!fix_only.contains("eslint", "no-console"));
+    }
+}
@@ -2,15 +2,43 @@ use crate::{FrameworkFlags, fixer::FixKind};
 
 mod allow_warn_deny;
 mod filter;
+mod fix_only;
 
 pub use allow_warn_deny::AllowWarnDeny;
 pub use filter::{InvalidFilterKind, LintFilter, LintFilterKind};
+pub use fix_only::FixOnly;
 
 /// Subset of options used directly by the linter.
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 #[cfg_attr(test, derive(PartialEq, Eq))]
 pub struct LintOptions {
     pub fix: FixKind,
+    /// Only report fixes of the kind set by `fix` with their diagnostics, without applying them.
+    pub report_fixes: bool,
+    /// How often a file is fixed and linted again, so fixes which conflict with each other or
+    /// only become possible after other fixes are applied as well. At least one pass is made.
+    pub max_fix_passes: u8,
     pub framework_hints: FrameworkFlags,
     pub report_unused_directive: Option<AllowWarnDeny>,
+    /// Insert `oxlint-disable-next-line` comments for all diagnostics of rules instead of fixing
+    /// them, so existing violations are no longer reported.
+    pub suppress_all: bool,
+}
+
+impl LintOptions {
+    /// The default for [`LintOptions::max_fix_passes`], same as ESLint.
+    pub const DEFAULT_MAX_FIX_PASSES: u8 = 10;
+}
+
+impl Default for LintOptions {
+    fn default() -> Self {
+        Self {
+            fix: FixKind::default(),
+            report_fixes: false,
+            max_fix_passes: Self::DEFAULT_MAX_FIX_PASSES,
+            framework_hints: FrameworkFlags::default(),
+            report_unused_directive: None,
+            suppress_all: false,
+        }
+    }
 }
@@ -0,0 +1,230 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use cow_utils::CowUtils;
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+/// The dependencies declared in a `package.json`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PackageJson {
+    pub name: Option<String>,
+    /// The `exports` map, e.g. `{ ".": "./index.js", "./utils/*": "./src/utils/*.js" }`.
+    pub exports: Option<serde_json::Value>,
+    /// The package patterns of an npm or yarn workspace.
+    pub workspaces: Option<Workspaces>,
+    pub dependencies: FxHashMap<String, serde_json::Value>,
+    pub dev_dependencies: FxHashMap<String, serde_json::Value>,
+    pub optional_dependencies: FxHashMap<String, serde_json::Value>,
+    pub peer_dependencies: FxHashMap<String, serde_json::Value>,
+    /// The versions of runtimes the package supports, e.g. `{ "node": ">=18" }`.
+    pub engines: FxHashMap<String, serde_json::Value>,
+    #[serde(alias = "bundleDependencies")]
+    pub bundled_dependencies: Vec<String>,
+}
+
+impl PackageJson {
+    /// Whether `name` is a dependency of any kind, e.g. a dev or peer dependency.
+    pub fn has_dependency(&self, name: &str) -> bool {
+        self.dependencies.contains_key(name)
+            || self.dev_dependencies.contains_key(name)
+            || self.optional_dependencies.contains_key(name)
+            || self.peer_dependencies.contains_key(name)
+    }
+}
+
+/// The `workspaces` of a `package.json`, either a list of patterns or yarn's
+/// `{ "packages": [...] }`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Workspaces {
+    Patterns(Vec<String>),
+    Config {
+        #[serde(default)]
+        packages: Vec<String>,
+    },
+}
+
+impl Workspaces {
+    fn patterns(&self) -> &[String] {
+        match self {
+            Self::Patterns(patterns) | Self::Config { packages: patterns } => patterns,
+        }
+    }
+}
+
+/// The packages of a pnpm, yarn or npm workspace.
+#[derive(Debug)]
+pub struct Workspace {
+    pub packages: Vec<WorkspacePackage>,
+}
+
+#[derive(Debug)]
+pub struct WorkspacePackage {
+    pub dir: PathBuf,
+    pub package_json: Arc<PackageJson>,
+}
+
+impl WorkspacePackage {
+    pub fn name(&self) -> Option<&str> {
+        self.package_json.name.as_deref()
+    }
+}
+
+impl Workspace {
+    /// The package named `name`.
+    pub fn package_named(&self, name: &str) -> Option<&WorkspacePackage> {
+        self.packages.iter().find(|package| package.name() == Some(name))
+    }
+
+    /// The innermost package whose directory contains `path`.
+    pub fn package_containing(&self, path: &Path) -> Option<&WorkspacePackage> {
+        self.packages
+            .iter()
+            .filter(|package| path.starts_with(&package.dir))
+            .max_by_key(|package| package.dir.components().count())
+    }
+}
+
+/// How deep package directories are searched for patterns with `**`.
+const MAX_WORKSPACE_DEPTH: usize = 6;
+
+/// Finds the nearest `package.json` of linted files. Each `package.json` is read once per lint
+/// run, and shared by the files of its package.
+#[derive(Debug, Default)]
+pub struct PackageJsonCache {
+    /// The `package.json` in each directory looked up, if it exists and can be parsed.
+    by_dir: Mutex<FxHashMap<PathBuf, Option<Arc<PackageJson>>>>,
+    /// The workspace rooted in each directory looked up, if it is a workspace root.
+    workspaces: Mutex<FxHashMap<PathBuf, Option<Arc<Workspace>>>>,
+}
+
+impl PackageJsonCache {
+    /// The nearest `package.json` of the file at `path`, in its directory or a parent directory.
+    /// A `package.json` which can't be read or parsed is skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a thread panicked while looking up a `package.json`.
+    pub fn nearest(&self, path: &Path) -> Option<Arc<PackageJson>> {
+        path.ancestors().skip(1).find_map(|dir| self.in_dir(dir))
+    }
+
+    fn in_dir(&self, dir: &Path) -> Option<Arc<PackageJson>> {
+        if let Some(package_json) =
+            self.by_dir.lock().expect("package.json cache poisoned").get(dir)
+        {
+            return package_json.clone();
+        }
+        let package_json = std::fs::read_to_string(dir.join("package.json"))
+            .ok()
+            .and_then(|text| serde_json::from_str::<PackageJson>(&text).ok())
+            .map(Arc::new);
+        self.by_dir
+            .lock()
+            .expect("package.json cache poisoned")
+            .insert(dir.to_path_buf(), package_json.clone());
+        package_json
+    }
+
+    /// The workspace containing the file at `path`, rooted in the nearest directory with a
+    /// `pnpm-workspace.yaml`, or a `package.json` with `workspaces`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a thread panicked while looking up a workspace.
+    pub fn workspace(&self, path: &Path) -> Option<Arc<Workspace>> {
+        path.ancestors().skip(1).find_map(|dir| self.workspace_at(dir))
+    }
+
+    fn workspace_at(&self, dir: &Path) -> Option<Arc<Workspace>> {
+        if let Some(workspace) = self.workspaces.lock().expect("workspace cache poisoned").get(dir)
+        {
+            return workspace.clone();
+        }
+        let patterns = std::fs::read_to_string(dir.join("pnpm-workspace.yaml"))
+            .ok()
+            .map(|text| pnpm_workspace_patterns(&text))
+            .or_else(|| {
+                let package_json = self.in_dir(dir)?;
+                Some(package_json.workspaces.as_ref()?.patterns().to_vec())
+            });
+        let workspace = patterns.map(|patterns| Arc::new(self.collect_workspace(dir, &patterns)));
+        self.workspaces
+            .lock()
+            .expect("workspace cache poisoned")
+            .insert(dir.to_path_buf(), workspace.clone());
+        workspace
+    }
+
+    fn collect_workspace(&self, root: &Path, patterns: &[String]) -> Workspace {
+        let normalize =
+            |pattern: &str| pattern.trim_start_matches("./").trim_end_matches('/').to_string();
+        let (excluded, included): (Vec<_>, Vec<_>) =
+            patterns.iter().partition(|pattern| pattern.starts_with('!'));
+        let included = included.iter().map(|pattern| normalize(pattern)).collect::<Vec<_>>();
+        let excluded = excluded.iter().map(|pattern| normalize(&pattern[1..])).collect::<Vec<_>>();
+        let max_depth = included
+            .iter()
+            .map(|pattern| {
+                if pattern.contains("**") {
+                    MAX_WORKSPACE_DEPTH
+                } else {
+                    pattern.split('/').count()
+                }
+            })
+            .max()
+            .unwrap_or(0);
+
+        let mut packages = vec![];
+        let mut dirs = vec![(root.to_path_buf(), 0)];
+        while let Some((dir, depth)) = dirs.pop() {
+            if depth >= max_depth {
+                continue;
+            }
+            let Ok(entries) = std::fs::read_dir(&dir) else { continue };
+            for entry in entries.filter_map(Result::ok) {
+                let path = entry.path();
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                if !path.is_dir() || name.starts_with('.') || name == "node_modules" {
+                    continue;
+                }
+                let Ok(relative) = path.strip_prefix(root) else { continue };
+                let relative = relative.to_string_lossy().cow_replace('\\', "/").into_owned();
+                if included.iter().any(|pattern| fast_glob::glob_match(pattern, &relative))
+                    && !excluded.iter().any(|pattern| fast_glob::glob_match(pattern, &relative))
+                    && let Some(package_json) = self.in_dir(&path)
+                {
+                    packages.push(WorkspacePackage { dir: path.clone(), package_json });
+                }
+                dirs.push((path, depth + 1));
+            }
+        }
+        packages.sort_unstable_by(|a, b| a.dir.cmp(&b.dir));
+        Workspace { packages }
+    }
+}
+
+/// The `packages` patterns of a `pnpm-workspace.yaml`.
+fn pnpm_workspace_patterns(text: &str) -> Vec<String> {
+    let mut patterns = vec![];
+    let mut in_packages = false;
+    for line in text.lines() {
+        let trimmed = line.split(" #").next().unwrap_or_default().trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if !line.starts_with([' ', '\t', '-']) {
+            in_packages = trimmed == "packages:";
+            continue;
+        }
+        if in_packages && let Some(pattern) = trimmed.strip_prefix('-') {
+            patterns.push(pattern.trim().trim_matches(['"', '\'']).to_string());
+        }
+    }
+    patterns
+}
@@ -0,0 +1,113 @@
+use std::{fmt, time::Duration};
+
+use rustc_hash::FxHashMap;
+
+use oxc_diagnostics::Error;
+use oxc_span::CompactStr;
+
+/// Formats the results of a lint run.
+///
+/// Unlike a [`DiagnosticReporter`](oxc_diagnostics::reporter::DiagnosticReporter), which renders
+/// one diagnostic at a time, a reporter sees the whole run: it is notified when the run begins,
+/// receives the diagnostics of each file, and gets a summary when the run ends. Each method
+/// returns the output to write, if any.
+///
+/// Reporters are made available to the CLI by adding them to a [`ReporterRegistry`].
+///
+/// ## Example
+/// ```
+/// use oxc_diagnostics::Error;
+/// use oxc_linter::{LintRunSummary, Reporter};
+///
+/// #[derive(Default)]
+/// struct CountReporter {
+///     files: usize,
+/// }
+///
+/// impl Reporter for CountReporter {
+///     fn report_file(&mut self, _diagnostics: Vec<Error>) -> Option<String> {
+///         self.files += 1;
+///         None
+///     }
+///
+///     fn end_run(&mut self, summary: &LintRunSummary) -> Option<String> {
+///         Some(format!("{} of {} files have problems\n", self.files, summary.number_of_files))
+///     }
+/// }
+/// ```
+pub trait Reporter {
+    /// Called once before any diagnostics are reported.
+    fn begin_run(&mut self) -> Option<String> {
+        None
+    }
+
+    /// Called with the diagnostics of a linted file. Files without diagnostics are not reported.
+    ///
+    /// This may be called more than once for the same file, e.g. when type-aware diagnostics are
+    /// reported separately.
+    fn report_file(&mut self, diagnostics: Vec<Error>) -> Option<String>;
+
+    /// Called once after all diagnostics have been reported.
+    fn end_run(&mut self, _summary: &LintRunSummary) -> Option<String> {
+        None
+    }
+}
+
+/// Summary of a lint run, passed to [`Reporter::end_run`].
+#[derive(Debug, Default, Clone)]
+pub struct LintRunSummary {
+    /// The number of files that were linted.
+    pub number_of_files: usize,
+    /// The number of lint rules that were run, if it can be clearly computed.
+    pub number_of_rules: Option<usize>,
+    /// The number of threads used for linting.
+    pub threads_count: usize,
+    /// How long the run took.
+    pub duration: Duration,
+    /// The number of warnings found.
+    pub warnings_count: usize,
+    /// The number of errors found.
+    pub errors_count: usize,
+}
+
+/// Creates a new [`Reporter`] for a lint run.
+pub type ReporterFactory = fn() -> Box<dyn Reporter>;
+
+/// Named [`Reporter`]s which can be selected as output format, e.g. with `--format=<name>`.
+#[derive(Default, Clone)]
+pub struct ReporterRegistry {
+    factories: FxHashMap<CompactStr, ReporterFactory>,
+}
+
+impl ReporterRegistry {
+    /// Registers `factory` under `name`. See [`ReporterRegistry::register`].
+    #[must_use]
+    pub fn with_reporter(mut self, name: &str, factory: ReporterFactory) -> Self {
+        self.register(name, factory);
+        self
+    }
+
+    /// Registers `factory` under `name`, replacing any reporter previously registered with the
+    /// same name.
+    pub fn register(&mut self, name: &str, factory: ReporterFactory) {
+        self.factories.insert(CompactStr::new(name), factory);
+    }
+
+    /// Creates the reporter registered under `name`.
+    pub fn create(&self, name: &str) -> Option<Box<dyn Reporter>> {
+        self.factories.get(name).map(|factory| factory())
+    }
+
+    /// Names of all registered reporters, sorted alphabetically.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names = self.factories.keys().map(CompactStr::as_str).collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+}
+
+impl fmt::Debug for ReporterRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReporterRegistry").field("names", &self.names()).finish()
+    }
+}
@@ -19,6 +19,16 @@ pub trait Rule: Sized + Default + fmt::Debug {
         Self::default()
     }
 
+    /// Initialize from eslint json configuration, failing on invalid options which
+    /// [`Rule::from_configuration`] ignores. Used when loading configuration files.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the invalid option.
+    fn try_from_configuration(value: serde_json::Value) -> Result<Self, String> {
+        Ok(Self::from_configuration(value))
+    }
+
     /// Serialize rule configuration to JSON. Only used for sending rule configurations
     /// to another linter. This allows oxlint to handle the parsing and error handling.
     /// Type-aware rules implemented in tsgolint will need to override this method.
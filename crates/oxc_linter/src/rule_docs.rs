@@ -0,0 +1,374 @@
+//! Documentation of rules, rendered from the rule metadata and the rule tests so that the
+//! website and `oxlint --explain` never drift from the implementation.
+//!
+//! The pass and fail cases of a rule only exist in its tests. They are recorded into a directory
+//! when the tests run with [`RULE_EXAMPLES_DIR_ENV`] set, and read back with
+//! [`RuleExamples::read`]:
+//!
+//! ```sh
+//! OXC_RULE_EXAMPLES_DIR=target/rule-examples cargo test -p oxc_linter
+//! ```
+
+#[cfg(feature = "ruledocs")]
+use std::borrow::Cow;
+use std::{
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Mutex, PoisonError},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[cfg(feature = "ruledocs")]
+use crate::{
+    config::parse_rule_key, context::plugin_name_to_prefix, rules::RULES, rules::RuleEnum,
+};
+
+/// Directory where the rule tests record their cases, see [`RuleExamples::record`].
+pub const RULE_EXAMPLES_DIR_ENV: &str = "OXC_RULE_EXAMPLES_DIR";
+
+/// A test case of a rule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleExample {
+    pub code: String,
+    /// Options the rule was configured with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<Value>,
+}
+
+/// The test cases of a rule.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RuleExamples {
+    /// Code which the rule does not report.
+    pub pass: Vec<RuleExample>,
+    /// Code which the rule reports.
+    pub fail: Vec<RuleExample>,
+}
+
+impl RuleExamples {
+    fn path(dir: &Path, plugin: &str, rule: &str) -> PathBuf {
+        dir.join(plugin).join(format!("{rule}.json"))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pass.is_empty() && self.fail.is_empty()
+    }
+
+    /// Reads the cases recorded for a rule, or `None` if its tests did not record any.
+    ///
+    /// # Errors
+    ///
+    /// * The recorded file cannot be read or is invalid
+    pub fn read(dir: &Path, plugin: &str, rule: &str) -> io::Result<Option<Self>> {
+        match fs::read_to_string(Self::path(dir, plugin, rule)) {
+            Ok(json) => serde_json::from_str(&json).map(Some).map_err(io::Error::other),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Adds these cases to the ones recorded for a rule. A rule can be tested by several test
+    /// functions running in parallel, so the cases of each are merged and duplicates skipped.
+    ///
+    /// # Errors
+    ///
+    /// * The recorded file cannot be read or written
+    pub fn record(self, dir: &Path, plugin: &str, rule: &str) -> io::Result<()> {
+        static LOCK: Mutex<()> = Mutex::new(());
+        let _guard = LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let mut recorded = Self::read(dir, plugin, rule)?.unwrap_or_default();
+        for (cases, recorded) in [(self.pass, &mut recorded.pass), (self.fail, &mut recorded.fail)]
+        {
+            for case in cases {
+                if !recorded.contains(&case) {
+                    recorded.push(case);
+                }
+            }
+        }
+
+        let path = Self::path(dir, plugin, rule);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(&recorded).map_err(io::Error::other)?)
+    }
+
+    /// Renders the cases as markdown code blocks.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        for (title, cases) in [("correct", &self.pass), ("incorrect", &self.fail)] {
+            if cases.is_empty() {
+                continue;
+            }
+            let _ = writeln!(markdown, "\nExamples of **{title}** code for this rule:");
+            for case in cases {
+                if let Some(options) = &case.options {
+                    let _ = writeln!(markdown, "\nWith the options `{options}`:");
+                }
+                let fence = code_fence(&case.code);
+                let _ = writeln!(markdown, "\n{fence}ts\n{}\n{fence}", case.code.trim_end());
+            }
+        }
+        markdown
+    }
+}
+
+/// A fence longer than any run of backticks in `code`.
+fn code_fence(code: &str) -> String {
+    let longest = code.split(|c| c != '`').map(str::len).max().unwrap_or_default();
+    "`".repeat(longest.max(2) + 1)
+}
+
+/// Documentation of a rule.
+#[cfg(feature = "ruledocs")]
+#[derive(Debug, Serialize)]
+pub struct RuleDocs {
+    pub plugin: &'static str,
+    pub name: &'static str,
+    pub category: &'static str,
+    /// Auto-fix capabilities of the rule.
+    pub fix: Cow<'static, str>,
+    pub documentation: Option<&'static str>,
+    /// JSON schema of the options of the rule, with the definitions it refers to.
+    pub options: Option<Value>,
+    #[serde(skip_serializing_if = "RuleExamples::is_empty")]
+    pub examples: RuleExamples,
+}
+
+#[cfg(feature = "ruledocs")]
+impl RuleDocs {
+    pub fn new(rule: &RuleEnum) -> Self {
+        Self {
+            plugin: rule.plugin_name(),
+            name: rule.name(),
+            category: rule.category().as_str(),
+            fix: rule.fix().description(),
+            documentation: rule.documentation(),
+            options: options_schema(rule),
+            examples: RuleExamples::default(),
+        }
+    }
+
+    /// Finds a rule by the name it is configured with, e.g. `no-debugger`,
+    /// `typescript/no-explicit-any` or `@typescript-eslint/no-explicit-any`.
+    pub fn find(name: &str) -> Option<Self> {
+        let (plugin, name) = parse_rule_key(name);
+        RULES.iter().find(|rule| rule.plugin_name() == plugin && rule.name() == name).map(Self::new)
+    }
+
+    /// Finds a rule by the code of its diagnostics, e.g. `eslint(no-debugger)` or
+    /// `typescript-eslint(no-explicit-any)`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let (prefix, name) = code.strip_suffix(')')?.split_once('(')?;
+        RULES
+            .iter()
+            .find(|rule| plugin_name_to_prefix(rule.plugin_name()) == prefix && rule.name() == name)
+            .map(Self::new)
+    }
+
+    #[must_use]
+    pub fn with_examples(mut self, examples: RuleExamples) -> Self {
+        self.examples = examples;
+        self
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!("# {}/{}\n\n", self.plugin, self.name);
+        let _ = writeln!(markdown, "Category: {}\n{}", self.category, self.fix);
+        if let Some(documentation) = self.documentation {
+            let _ = writeln!(markdown, "\n{}", documentation.trim());
+        }
+        if let Some(options) = &self.options {
+            let options = serde_json::to_string_pretty(options).unwrap_or_default();
+            let _ = writeln!(markdown, "\n## Options\n\n```json\n{options}\n```");
+        }
+        if !self.examples.is_empty() {
+            let _ = write!(markdown, "\n## Tested examples\n{}", self.examples.to_markdown());
+        }
+        markdown
+    }
+
+    /// Renders a shorter version of [`RuleDocs::to_markdown`] for editors, summarizing each option
+    /// in a single line instead of printing its schema.
+    pub fn to_summary_markdown(&self) -> String {
+        let mut markdown = format!("# {}/{}\n\n", self.plugin, self.name);
+        let _ = writeln!(markdown, "Category: {}\n{}", self.category, self.fix);
+        if let Some(documentation) = self.documentation {
+            let _ = writeln!(markdown, "\n{}", documentation.trim());
+        }
+        let options = self.options.as_ref().map(options_summary).unwrap_or_default();
+        if !options.is_empty() {
+            let _ = writeln!(markdown, "\n## Options\n\n{options}");
+        }
+        markdown
+    }
+}
+
+#[cfg(feature = "ruledocs")]
+fn options_schema(rule: &RuleEnum) -> Option<Value> {
+    let mut generator = schemars::SchemaGenerator::default();
+    let schema = rule.schema(&mut generator)?;
+    let schema = generator.dereference(&schema).unwrap_or(&schema);
+    let mut json = serde_json::to_value(schema).ok()?;
+    let definitions = generator.take_definitions();
+    if let Value::Object(json) = &mut json
+        && !definitions.is_empty()
+    {
+        json.insert("definitions".to_string(), serde_json::to_value(definitions).ok()?);
+    }
+    Some(json)
+}
+
+/// One markdown list item for each property of an options schema, with its type, default value
+/// and the first paragraph of its description.
+#[cfg(feature = "ruledocs")]
+fn options_summary(schema: &Value) -> String {
+    let Some(Value::Object(properties)) =
+        resolve_reference(schema, schema).get("properties").cloned()
+    else {
+        return String::new();
+    };
+
+    let mut summary = String::new();
+    for (name, property) in &properties {
+        let property = resolve_reference(schema, property);
+        let _ = write!(summary, "- `{name}`");
+        let details = [
+            schema_type(schema, &property).map(|ty| format!("`{ty}`")),
+            property.get("default").map(|default| format!("default `{default}`")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        if !details.is_empty() {
+            let _ = write!(summary, " ({})", details.join(", "));
+        }
+        if let Some(description) = property.get("description").and_then(Value::as_str) {
+            let paragraph = description.split("\n\n").next().unwrap_or_default();
+            let _ =
+                write!(summary, ": {}", paragraph.split_whitespace().collect::<Vec<_>>().join(" "));
+        }
+        summary.push('\n');
+    }
+    summary
+}
+
+/// Replaces a `$ref` to the definitions of `root` with the definition it refers to.
+/// Keywords next to the reference, e.g. `description`, take precedence.
+#[cfg(feature = "ruledocs")]
+fn resolve_reference(root: &Value, schema: &Value) -> Value {
+    let Some(name) = schema.get("$ref").and_then(Value::as_str).and_then(|reference| {
+        reference.strip_prefix("#/definitions/").or_else(|| reference.strip_prefix("#/$defs/"))
+    }) else {
+        return schema.clone();
+    };
+    let mut resolved =
+        root.get("definitions").and_then(|definitions| definitions.get(name)).cloned();
+    if let (Some(Value::Object(resolved)), Value::Object(schema)) = (&mut resolved, schema) {
+        resolved.extend(
+            schema
+                .iter()
+                .filter(|(key, _)| *key != "$ref")
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+    }
+    resolved.unwrap_or_else(|| schema.clone())
+}
+
+/// A TypeScript-like type of a schema, e.g. `string[]` or `"always" | "never"`.
+#[cfg(feature = "ruledocs")]
+fn schema_type(root: &Value, schema: &Value) -> Option<String> {
+    let schema = resolve_reference(root, schema);
+    if let Some(Value::Array(values)) = schema.get("enum") {
+        return Some(values.iter().map(Value::to_string).collect::<Vec<_>>().join(" | "));
+    }
+    match schema.get("type")? {
+        Value::String(ty) if ty == "array" => {
+            let items = schema.get("items").and_then(|items| schema_type(root, items));
+            Some(format!("{}[]", items.as_deref().unwrap_or("unknown")))
+        }
+        Value::String(ty) => Some(ty.clone()),
+        Value::Array(types) => {
+            Some(types.iter().filter_map(Value::as_str).collect::<Vec<_>>().join(" | "))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{RuleExample, RuleExamples, code_fence};
+
+    #[test]
+    fn record() {
+        let dir = tempfile::tempdir().unwrap();
+        let case = |code: &str| RuleExample { code: code.to_string(), options: None };
+
+        RuleExamples { pass: vec![case("a")], fail: vec![case("b")] }
+            .record(dir.path(), "eslint", "no-foo")
+            .unwrap();
+        RuleExamples { pass: vec![case("a"), case("c")], fail: vec![] }
+            .record(dir.path(), "eslint", "no-foo")
+            .unwrap();
+
+        let recorded = RuleExamples::read(dir.path(), "eslint", "no-foo").unwrap().unwrap();
+        assert_eq!(recorded.pass, vec![case("a"), case("c")]);
+        assert_eq!(recorded.fail, vec![case("b")]);
+        assert!(RuleExamples::read(dir.path(), "eslint", "no-bar").unwrap().is_none());
+    }
+
+    #[test]
+    fn markdown() {
+        assert_eq!(code_fence("a"), "```");
+        assert_eq!(code_fence("`a` ```"), "````");
+
+        let examples = RuleExamples {
+            pass: vec![],
+            fail: vec![RuleExample {
+                code: "debugger;\n".to_string(),
+                options: Some(serde_json::json!(["always"])),
+            }],
+        };
+        assert_eq!(
+            examples.to_markdown(),
+            "\nExamples of **incorrect** code for this rule:\n\nWith the options `[\"always\"]`:\n\n```ts\ndebugger;\n```\n"
+        );
+    }
+
+    #[cfg(feature = "ruledocs")]
+    #[test]
+    fn options_summary() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "allow": {
+                    "description": "Methods which are allowed.\n\nMore details.",
+                    "default": [],
+                    "type": "array",
+                    "items": { "type": "string" }
+                },
+                "mode": { "$ref": "#/definitions/Mode" }
+            },
+            "definitions": { "Mode": { "type": "string", "enum": ["always", "never"] } }
+        });
+        assert_eq!(
+            super::options_summary(&schema),
+            "- `allow` (`string[]`, default `[]`): Methods which are allowed.\n- `mode` (`\"always\" | \"never\"`)\n"
+        );
+    }
+
+    #[cfg(feature = "ruledocs")]
+    #[test]
+    fn from_code() {
+        let docs = super::RuleDocs::from_code("typescript-eslint(no-explicit-any)").unwrap();
+        assert_eq!((docs.plugin, docs.name), ("typescript", "no-explicit-any"));
+        assert!(
+            docs.to_summary_markdown().contains("- `ignoreRestArgs` (`boolean`, default `false`)")
+        );
+        assert!(super::RuleDocs::from_code("eslint(no-foo)").is_none());
+        assert!(super::RuleDocs::from_code("no-debugger").is_none());
+    }
+}
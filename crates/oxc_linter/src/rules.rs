@@ -20,12 +20,15 @@ pub(crate) mod import {
     pub mod no_absolute_path;
     pub mod no_amd;
     pub mod no_anonymous_default_export;
+    pub mod no_barrel_files;
     pub mod no_commonjs;
     pub mod no_cycle;
     pub mod no_default_export;
+    pub mod no_deprecated;
     pub mod no_duplicates;
     pub mod no_dynamic_require;
     pub mod no_empty_named_blocks;
+    pub mod no_extraneous_dependencies;
     pub mod no_mutable_exports;
     pub mod no_named_as_default;
     pub mod no_named_as_default_member;
@@ -34,7 +37,11 @@ pub(crate) mod import {
     pub mod no_namespace;
     pub mod no_self_import;
     pub mod no_unassigned_import;
+    pub mod no_unresolved;
+    pub mod no_unused_modules;
     pub mod no_webpack_loader_syntax;
+    pub mod no_workspace_internals;
+    pub mod order;
     pub mod prefer_default_export;
     pub mod unambiguous;
 }
@@ -46,6 +53,7 @@ pub(crate) mod eslint {
     pub mod block_scoped_var;
     pub mod capitalized_comments;
     pub mod class_methods_use_this;
+    pub mod complexity;
     pub mod constructor_super;
     pub mod curly;
     pub mod default_case;
@@ -144,6 +152,7 @@ pub(crate) mod eslint {
     pub mod no_regex_spaces;
     pub mod no_restricted_globals;
     pub mod no_restricted_imports;
+    pub mod no_restricted_syntax;
     pub mod no_return_assign;
     pub mod no_script_url;
     pub mod no_self_assign;
@@ -220,6 +229,8 @@ pub(crate) mod typescript {
     pub mod consistent_type_imports;
     pub mod explicit_function_return_type;
     pub mod explicit_module_boundary_types;
+    pub mod member_ordering;
+    pub mod naming_convention;
     pub mod no_array_delete;
     pub mod no_base_to_string;
     pub mod no_confusing_non_null_assertion;
@@ -252,6 +263,7 @@ pub(crate) mod typescript {
     pub mod no_restricted_types;
     pub mod no_this_alias;
     pub mod no_unnecessary_boolean_literal_compare;
+    pub mod no_unnecessary_condition;
     pub mod no_unnecessary_parameter_property_assignment;
     pub mod no_unnecessary_template_expression;
     pub mod no_unnecessary_type_arguments;
@@ -280,6 +292,7 @@ pub(crate) mod typescript {
     pub mod prefer_literal_enum_member;
     pub mod prefer_namespace_keyword;
     pub mod prefer_nullish_coalescing;
+    pub mod prefer_optional_chain;
     pub mod prefer_promise_reject_errors;
     pub mod prefer_reduce_type_parameter;
     pub mod prefer_return_this_type;
@@ -370,6 +383,7 @@ pub(crate) mod react {
     pub mod jsx_max_depth;
     pub mod jsx_no_comment_textnodes;
     pub mod jsx_no_duplicate_props;
+    pub mod jsx_no_leaked_render;
     pub mod jsx_no_script_url;
     pub mod jsx_no_target_blank;
     pub mod jsx_no_undef;
@@ -393,8 +407,10 @@ pub(crate) mod react {
     pub mod no_unescaped_entities;
     pub mod no_unknown_property;
     pub mod no_unsafe;
+    pub mod no_unstable_nested_components;
     pub mod only_export_components;
     pub mod prefer_es6_class;
+    pub mod react_compiler;
     pub mod react_in_jsx_scope;
     pub mod require_render_return;
     pub mod rules_of_hooks;
@@ -415,6 +431,7 @@ pub(crate) mod unicorn {
     pub mod catch_error_name;
     pub mod consistent_assert;
     pub mod consistent_date_clone;
+    pub mod consistent_destructuring;
     pub mod consistent_empty_array_spread;
     pub mod consistent_existence_index_check;
     pub mod consistent_function_scoping;
@@ -462,6 +479,7 @@ pub(crate) mod unicorn {
     pub mod no_unnecessary_array_flat_depth;
     pub mod no_unnecessary_array_splice_count;
     pub mod no_unnecessary_await;
+    pub mod no_unnecessary_polyfills;
     pub mod no_unnecessary_slice_end;
     pub mod no_unreadable_array_destructuring;
     pub mod no_unreadable_iife;
@@ -547,10 +565,12 @@ pub(crate) mod jsx_a11y {
     pub mod aria_unsupported_elements;
     pub mod autocomplete_valid;
     pub mod click_events_have_key_events;
+    pub mod control_has_associated_label;
     pub mod heading_has_content;
     pub mod html_has_lang;
     pub mod iframe_has_title;
     pub mod img_redundant_alt;
+    pub mod interactive_supports_focus;
     pub mod label_has_associated_control;
     pub mod lang;
     pub mod media_has_caption;
@@ -559,8 +579,12 @@ pub(crate) mod jsx_a11y {
     pub mod no_aria_hidden_on_focusable;
     pub mod no_autofocus;
     pub mod no_distracting_elements;
+    pub mod no_interactive_element_to_noninteractive_role;
+    pub mod no_noninteractive_element_interactions;
+    pub mod no_noninteractive_element_to_interactive_role;
     pub mod no_noninteractive_tabindex;
     pub mod no_redundant_roles;
+    pub mod no_static_element_interactions;
     pub mod prefer_tag_over_role;
     pub mod role_has_required_aria_props;
     pub mod role_supports_aria_props;
@@ -592,6 +616,7 @@ pub(crate) mod oxc {
     pub mod no_optional_chaining;
     pub mod no_rest_spread_properties;
     pub mod no_this_in_exported_function;
+    pub mod no_write_only_variables;
     pub mod number_arg_out_of_range;
     pub mod only_used_in_recursion;
     pub mod uninvoked_array_callback;
@@ -624,8 +649,10 @@ pub(crate) mod nextjs {
 /// <https://github.com/gajus/eslint-plugin-jsdoc>
 pub(crate) mod jsdoc {
     pub mod check_access;
+    pub mod check_param_names;
     pub mod check_property_names;
     pub mod check_tag_names;
+    pub mod check_types;
     pub mod empty_tags;
     pub mod implements_on_classes;
     pub mod no_defaults;
@@ -638,6 +665,7 @@ pub(crate) mod jsdoc {
     pub mod require_property_name;
     pub mod require_property_type;
     pub mod require_returns;
+    pub mod require_returns_check;
     pub mod require_returns_description;
     pub mod require_returns_type;
     pub mod require_yields;
@@ -664,6 +692,7 @@ pub(crate) mod promise {
 
 pub(crate) mod vitest {
     pub mod consistent_test_filename;
+    pub mod hoisted_apis_on_top;
     pub mod no_conditional_tests;
     pub mod no_import_node_test;
     pub mod prefer_called_times;
@@ -675,9 +704,12 @@ pub(crate) mod vitest {
 
 pub(crate) mod node {
     pub mod global_require;
+    pub mod no_deprecated_api;
     pub mod no_exports_assign;
+    pub mod no_missing_import;
     pub mod no_new_require;
     pub mod no_process_env;
+    pub mod no_unsupported_features;
 }
 
 pub(crate) mod vue {
@@ -690,13 +722,40 @@ pub(crate) mod vue {
     pub mod no_import_compiler_macros;
     pub mod no_multiple_slot_args;
     pub mod no_required_prop_with_default;
+    pub mod no_unused_components;
     pub mod prefer_import_from_vue;
     pub mod require_default_export;
     pub mod require_typed_ref;
+    pub mod require_v_for_key;
     pub mod valid_define_emits;
     pub mod valid_define_props;
 }
 
+pub(crate) mod security {
+    pub mod detect_child_process;
+    pub mod detect_eval_with_expression;
+    pub mod detect_non_literal_fs_filename;
+    pub mod detect_unsafe_regex;
+    pub mod no_secrets;
+}
+
+pub(crate) mod sonarjs {
+    pub mod cognitive_complexity;
+    pub mod no_all_duplicated_branches;
+    pub mod no_identical_functions;
+}
+
+pub(crate) mod regexp {
+    pub mod no_empty_alternative;
+    pub mod no_super_linear_backtracking;
+    pub mod no_useless_escape;
+    pub mod optimal_quantifier_concatenation;
+}
+
+pub(crate) mod i18next {
+    pub mod no_literal_string;
+}
+
 oxc_macros::declare_all_lint_rules! {
     eslint::accessor_pairs,
     eslint::array_callback_return,
@@ -704,6 +763,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::block_scoped_var,
     eslint::capitalized_comments,
     eslint::class_methods_use_this,
+    eslint::complexity,
     eslint::constructor_super,
     eslint::curly,
     eslint::default_case,
@@ -750,6 +810,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_object_constructor,
     eslint::no_param_reassign,
     eslint::no_restricted_imports,
+    eslint::no_restricted_syntax,
     eslint::no_unneeded_ternary,
     eslint::no_useless_backreference,
     eslint::no_useless_call,
@@ -882,15 +943,22 @@ oxc_macros::declare_all_lint_rules! {
     import::named,
     import::namespace,
     import::no_amd,
+    import::no_barrel_files,
     import::no_commonjs,
     import::no_cycle,
     import::no_default_export,
+    import::no_deprecated,
     import::no_duplicates,
     import::no_dynamic_require,
+    import::no_extraneous_dependencies,
     import::no_named_as_default,
     import::no_named_as_default_member,
     import::no_self_import,
+    import::no_unresolved,
+    import::no_unused_modules,
     import::no_webpack_loader_syntax,
+    import::no_workspace_internals,
+    import::order,
     import::prefer_default_export,
     import::unambiguous,
     jest::consistent_test_it,
@@ -946,8 +1014,10 @@ oxc_macros::declare_all_lint_rules! {
     jest::valid_expect,
     jest::valid_title,
     jsdoc::check_access,
+    jsdoc::check_param_names,
     jsdoc::check_property_names,
     jsdoc::check_tag_names,
+    jsdoc::check_types,
     jsdoc::empty_tags,
     jsdoc::implements_on_classes,
     jsdoc::no_defaults,
@@ -960,6 +1030,7 @@ oxc_macros::declare_all_lint_rules! {
     jsdoc::require_property_name,
     jsdoc::require_property_type,
     jsdoc::require_returns,
+    jsdoc::require_returns_check,
     jsdoc::require_returns_description,
     jsdoc::require_returns_type,
     jsdoc::require_yields,
@@ -973,10 +1044,12 @@ oxc_macros::declare_all_lint_rules! {
     jsx_a11y::aria_unsupported_elements,
     jsx_a11y::autocomplete_valid,
     jsx_a11y::click_events_have_key_events,
+    jsx_a11y::control_has_associated_label,
     jsx_a11y::heading_has_content,
     jsx_a11y::html_has_lang,
     jsx_a11y::iframe_has_title,
     jsx_a11y::img_redundant_alt,
+    jsx_a11y::interactive_supports_focus,
     jsx_a11y::label_has_associated_control,
     jsx_a11y::lang,
     jsx_a11y::media_has_caption,
@@ -986,7 +1059,11 @@ oxc_macros::declare_all_lint_rules! {
     jsx_a11y::no_aria_hidden_on_focusable,
     jsx_a11y::no_autofocus,
     jsx_a11y::no_distracting_elements,
+    jsx_a11y::no_interactive_element_to_noninteractive_role,
+    jsx_a11y::no_noninteractive_element_interactions,
+    jsx_a11y::no_noninteractive_element_to_interactive_role,
     jsx_a11y::no_redundant_roles,
+    jsx_a11y::no_static_element_interactions,
     jsx_a11y::prefer_tag_over_role,
     jsx_a11y::role_has_required_aria_props,
     jsx_a11y::role_supports_aria_props,
@@ -1015,8 +1092,11 @@ oxc_macros::declare_all_lint_rules! {
     nextjs::no_unwanted_polyfillio,
     nextjs::no_html_link_for_pages,
     node::global_require,
+    node::no_deprecated_api,
     node::no_process_env,
+    node::no_unsupported_features,
     node::no_exports_assign,
+    node::no_missing_import,
     node::no_new_require,
     oxc::approx_constant,
     oxc::bad_array_method_on_arguments,
@@ -1041,6 +1121,7 @@ oxc_macros::declare_all_lint_rules! {
     oxc::no_optional_chaining,
     oxc::no_rest_spread_properties,
     oxc::no_this_in_exported_function,
+    oxc::no_write_only_variables,
     oxc::number_arg_out_of_range,
     oxc::only_used_in_recursion,
     oxc::uninvoked_array_callback,
@@ -1077,6 +1158,7 @@ oxc_macros::declare_all_lint_rules! {
     react::jsx_max_depth,
     react::jsx_no_comment_textnodes,
     react::jsx_no_duplicate_props,
+    react::jsx_no_leaked_render,
     react::jsx_no_script_url,
     react::jsx_no_target_blank,
     react::jsx_no_undef,
@@ -1099,8 +1181,10 @@ oxc_macros::declare_all_lint_rules! {
     react::no_unescaped_entities,
     react::no_unknown_property,
     react::no_unsafe,
+    react::no_unstable_nested_components,
     react::only_export_components,
     react::prefer_es6_class,
+    react::react_compiler,
     react::react_in_jsx_scope,
     react::require_render_return,
     react::rules_of_hooks,
@@ -1123,6 +1207,8 @@ oxc_macros::declare_all_lint_rules! {
     typescript::consistent_type_definitions,
     typescript::consistent_type_imports,
     typescript::explicit_module_boundary_types,
+    typescript::member_ordering,
+    typescript::naming_convention,
     typescript::explicit_function_return_type,
     typescript::no_misused_promises,
     typescript::no_floating_promises,
@@ -1156,6 +1242,7 @@ oxc_macros::declare_all_lint_rules! {
     typescript::no_restricted_types,
     typescript::no_this_alias,
     typescript::no_unnecessary_boolean_literal_compare,
+    typescript::no_unnecessary_condition,
     typescript::no_unnecessary_parameter_property_assignment,
     typescript::no_unnecessary_template_expression,
     typescript::no_unnecessary_type_arguments,
@@ -1184,6 +1271,7 @@ oxc_macros::declare_all_lint_rules! {
     typescript::prefer_literal_enum_member,
     typescript::prefer_namespace_keyword,
     typescript::prefer_nullish_coalescing,
+    typescript::prefer_optional_chain,
     typescript::prefer_promise_reject_errors,
     typescript::prefer_reduce_type_parameter,
     typescript::prefer_return_this_type,
@@ -1203,6 +1291,7 @@ oxc_macros::declare_all_lint_rules! {
     unicorn::catch_error_name,
     unicorn::consistent_assert,
     unicorn::consistent_date_clone,
+    unicorn::consistent_destructuring,
     unicorn::consistent_empty_array_spread,
     unicorn::consistent_existence_index_check,
     unicorn::consistent_function_scoping,
@@ -1253,6 +1342,7 @@ oxc_macros::declare_all_lint_rules! {
     unicorn::no_this_assignment,
     unicorn::no_typeof_undefined,
     unicorn::no_unnecessary_await,
+    unicorn::no_unnecessary_polyfills,
     unicorn::no_unreadable_array_destructuring,
     unicorn::no_unreadable_iife,
     unicorn::no_useless_fallback_in_spread,
@@ -1322,6 +1412,7 @@ oxc_macros::declare_all_lint_rules! {
     unicorn::text_encoding_identifier_case,
     unicorn::throw_new_error,
     vitest::consistent_test_filename,
+    vitest::hoisted_apis_on_top,
     vitest::no_conditional_tests,
     vitest::no_import_node_test,
     vitest::prefer_called_times,
@@ -1338,9 +1429,24 @@ oxc_macros::declare_all_lint_rules! {
     vue::no_import_compiler_macros,
     vue::no_multiple_slot_args,
     vue::no_required_prop_with_default,
+    vue::no_unused_components,
     vue::prefer_import_from_vue,
     vue::require_default_export,
     vue::require_typed_ref,
+    vue::require_v_for_key,
     vue::valid_define_emits,
     vue::valid_define_props,
+    security::detect_child_process,
+    security::detect_eval_with_expression,
+    security::detect_non_literal_fs_filename,
+    security::detect_unsafe_regex,
+    security::no_secrets,
+    sonarjs::cognitive_complexity,
+    sonarjs::no_all_duplicated_branches,
+    sonarjs::no_identical_functions,
+    regexp::no_empty_alternative,
+    regexp::no_super_linear_backtracking,
+    regexp::no_useless_escape,
+    regexp::optimal_quantifier_concatenation,
+    i18next::no_literal_string,
 }
@@ -0,0 +1,476 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrowFunctionExpression, AssignmentPattern, AssignmentTargetPropertyIdentifier,
+        AssignmentTargetWithDefault, CallExpression, CatchClause, ComputedMemberExpression,
+        ForStatement, FormalParameter, Function, PrivateFieldExpression, StaticMemberExpression,
+        SwitchStatement,
+    },
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_cfg::{
+    EdgeType, InstructionKind,
+    graph::{Direction, visit::EdgeRef},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::Span;
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    ast_util::get_function_name_with_kind,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::get_function_label_span,
+};
+
+fn complexity_diagnostic(name: &str, complexity: usize, max: usize, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "{name} has a complexity of {complexity}. Maximum allowed is {max}."
+    ))
+    .with_help("Split the function into smaller functions, or simplify its branches.")
+    .with_label(span)
+}
+
+const DEFAULT_MAX_COMPLEXITY: usize = 20;
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct Complexity(Box<ComplexityConfig>);
+
+impl std::ops::Deref for Complexity {
+    type Target = ComplexityConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ComplexityConfig {
+    /// The maximum cyclomatic complexity allowed in a function.
+    #[serde(alias = "maximum")]
+    max: usize,
+    /// How `switch` statements are counted. `"classic"` counts each `case` with a test, and
+    /// `"modified"` counts the whole `switch` statement once.
+    variant: ComplexityVariant,
+}
+
+impl Default for ComplexityConfig {
+    fn default() -> Self {
+        Self { max: DEFAULT_MAX_COMPLEXITY, variant: ComplexityVariant::Classic }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, JsonSchema, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ComplexityVariant {
+    #[default]
+    Classic,
+    Modified,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces a maximum cyclomatic complexity of functions, the number of independent paths
+    /// through the control flow of a function. The complexity of a function is 1, plus 1 for
+    /// each `if`, loop, `case` with a test, `catch`, conditional expression, logical operator
+    /// (including `&&=`, `||=` and `??=`), default value and optional chain.
+    ///
+    /// Nested functions are measured on their own, and don't add to the complexity of the
+    /// function they are nested in.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A function with many paths through it is difficult to read, and each path has to be
+    /// tested.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "max": 2 }`:
+    /// ```js
+    /// function a(x) {
+    ///     if (true) {
+    ///         return x; // 1st path
+    ///     } else if (false) {
+    ///         return x + 1; // 2nd path
+    ///     } else {
+    ///         return 4; // 3rd path
+    ///     }
+    /// }
+    ///
+    /// function b() {
+    ///     foo ||= 1;
+    ///     bar &&= 1;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "max": 2 }`:
+    /// ```js
+    /// function a(x) {
+    ///     if (true) {
+    ///         return x;
+    ///     } else {
+    ///         return 4;
+    ///     }
+    /// }
+    ///
+    /// function b() {
+    ///     foo ||= 1;
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// The maximum can be given as a number, or as an object:
+    /// ```json
+    /// { "eslint/complexity": ["error", { "max": 10, "variant": "modified" }] }
+    /// ```
+    Complexity,
+    eslint,
+    pedantic,
+    config = ComplexityConfig,
+);
+
+impl Rule for Complexity {
+    fn from_configuration(value: Value) -> Self {
+        if let Some(max) =
+            value.get(0).and_then(Value::as_u64).and_then(|max| usize::try_from(max).ok())
+        {
+            Self(Box::new(ComplexityConfig { max, ..ComplexityConfig::default() }))
+        } else {
+            serde_json::from_value::<DefaultRuleConfig<Complexity>>(value)
+                .unwrap_or_default()
+                .into_inner()
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let mut finder = DecisionFinder { variant: self.variant, decisions: 0 };
+        match node.kind() {
+            AstKind::Function(func) => {
+                let Some(body) = &func.body else {
+                    return;
+                };
+                finder.visit_formal_parameters(&func.params);
+                finder.visit_function_body(body);
+            }
+            AstKind::ArrowFunctionExpression(arrow) => {
+                finder.visit_formal_parameters(&arrow.params);
+                finder.visit_function_body(&arrow.body);
+            }
+            _ => return,
+        }
+
+        let complexity = 1 + self.count_cfg_decisions(node, ctx) + finder.decisions;
+        if complexity > self.max {
+            let parent = ctx.nodes().parent_node(node.id());
+            let name = get_function_name_with_kind(node, parent);
+            let mut chars = name.chars();
+            let name = chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default();
+            ctx.diagnostic(complexity_diagnostic(
+                &name,
+                complexity,
+                self.max,
+                get_function_label_span(node, parent),
+            ));
+        }
+    }
+}
+
+impl Complexity {
+    /// Counts the conditions and iterations in the basic blocks of the function, which are the
+    /// blocks reachable from its entry without entering a nested function.
+    fn count_cfg_decisions(&self, node: &AstNode, ctx: &LintContext) -> usize {
+        let cfg = ctx.cfg();
+        let graph = cfg.graph();
+        let mut decisions = 0;
+        let mut visited = FxHashSet::default();
+        let mut stack = vec![ctx.nodes().cfg_id(node.id())];
+        while let Some(block_id) = stack.pop() {
+            if !visited.insert(block_id) {
+                continue;
+            }
+            for instruction in cfg.basic_block(block_id).instructions() {
+                match instruction.kind {
+                    InstructionKind::Condition => {
+                        // In the modified variant, the `switch` statement is counted instead.
+                        let is_switch_case_test = instruction.node_id.is_some_and(|node_id| {
+                            matches!(ctx.nodes().parent_kind(node_id), AstKind::SwitchCase(_))
+                        });
+                        if !(is_switch_case_test && self.variant == ComplexityVariant::Modified) {
+                            decisions += 1;
+                        }
+                    }
+                    InstructionKind::Iteration(_) => decisions += 1,
+                    _ => {}
+                }
+            }
+            stack.extend(
+                graph
+                    .edges_directed(block_id, Direction::Outgoing)
+                    .filter(|edge| !matches!(edge.weight(), EdgeType::NewFunction))
+                    .map(|edge| edge.target()),
+            );
+        }
+        decisions
+    }
+}
+
+/// Counts the decisions of a function which are not branches of the control flow graph.
+struct DecisionFinder {
+    variant: ComplexityVariant,
+    decisions: usize,
+}
+
+impl<'a> Visit<'a> for DecisionFinder {
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_catch_clause(&mut self, clause: &CatchClause<'a>) {
+        self.decisions += 1;
+        walk::walk_catch_clause(self, clause);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &ForStatement<'a>) {
+        // A `for` statement with a test is a condition of the control flow graph.
+        if stmt.test.is_none() {
+            self.decisions += 1;
+        }
+        walk::walk_for_statement(self, stmt);
+    }
+
+    fn visit_switch_statement(&mut self, stmt: &SwitchStatement<'a>) {
+        if self.variant == ComplexityVariant::Modified {
+            self.decisions += 1;
+        }
+        walk::walk_switch_statement(self, stmt);
+    }
+
+    fn visit_formal_parameter(&mut self, param: &FormalParameter<'a>) {
+        if param.initializer.is_some() {
+            self.decisions += 1;
+        }
+        walk::walk_formal_parameter(self, param);
+    }
+
+    fn visit_assignment_pattern(&mut self, pattern: &AssignmentPattern<'a>) {
+        self.decisions += 1;
+        walk::walk_assignment_pattern(self, pattern);
+    }
+
+    fn visit_assignment_target_with_default(&mut self, target: &AssignmentTargetWithDefault<'a>) {
+        self.decisions += 1;
+        walk::walk_assignment_target_with_default(self, target);
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        property: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        if property.init.is_some() {
+            self.decisions += 1;
+        }
+        walk::walk_assignment_target_property_identifier(self, property);
+    }
+
+    fn visit_static_member_expression(&mut self, expr: &StaticMemberExpression<'a>) {
+        if expr.optional {
+            self.decisions += 1;
+        }
+        walk::walk_static_member_expression(self, expr);
+    }
+
+    fn visit_computed_member_expression(&mut self, expr: &ComputedMemberExpression<'a>) {
+        if expr.optional {
+            self.decisions += 1;
+        }
+        walk::walk_computed_member_expression(self, expr);
+    }
+
+    fn visit_private_field_expression(&mut self, expr: &PrivateFieldExpression<'a>) {
+        if expr.optional {
+            self.decisions += 1;
+        }
+        walk::walk_private_field_expression(self, expr);
+    }
+
+    fn visit_call_expression(&mut self, expr: &CallExpression<'a>) {
+        if expr.optional {
+            self.decisions += 1;
+        }
+        walk::walk_call_expression(self, expr);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function a(x) {}", None),
+        ("function b(x) {}", Some(serde_json::json!([1]))),
+        ("function a(x) {if (true) {return x;}}", Some(serde_json::json!([2]))),
+        ("function a(x) {if (true) {return x;} else {return x+1;}}", Some(serde_json::json!([2]))),
+        (
+            "function a(x) {if (true) {return x;} else if (false) {return x+1;} else {return 4;}}",
+            Some(serde_json::json!([3])),
+        ),
+        (
+            "function a(x) {for(var i = 0; i < 5; i ++) {x ++;} return x;}",
+            Some(serde_json::json!([2])),
+        ),
+        ("function a(obj) {for(var i in obj) {obj[i] = 3;}}", Some(serde_json::json!([2]))),
+        (
+            "function a(x) {for(var i = 0; i < 5; i ++) {if(i % 2 === 0) {x ++;}} return x;}",
+            Some(serde_json::json!([3])),
+        ),
+        (
+            "function a(obj) {if(obj){ for(var x in obj) {try {x.getThis();} catch (e) {x.getThat();}}} else {return false;}}",
+            Some(serde_json::json!([4])),
+        ),
+        (
+            "function a(x) {try {x.getThis();} catch (e) {x.getThat();}}",
+            Some(serde_json::json!([2])),
+        ),
+        ("function a(x) {return x === 4 ? 3 : 5;}", Some(serde_json::json!([2]))),
+        ("function a(x) {return x === 4 ? 3 : (x === 3 ? 2 : 1);}", Some(serde_json::json!([3]))),
+        ("function a(x) {return x || 4;}", Some(serde_json::json!([2]))),
+        ("function a(x) {x && 4;}", Some(serde_json::json!([2]))),
+        ("function a(x) {x ?? 4;}", Some(serde_json::json!([2]))),
+        ("function a(x) {x ||= 4;}", Some(serde_json::json!([2]))),
+        ("function a(x) {x &&= 4;}", Some(serde_json::json!([2]))),
+        ("function a(x) {x ??= 4;}", Some(serde_json::json!([2]))),
+        ("function a(x) {x = 4;}", Some(serde_json::json!([1]))),
+        ("function a(x) {x |= 4;}", Some(serde_json::json!([1]))),
+        ("function a(x) {x &= 4;}", Some(serde_json::json!([1]))),
+        ("function a(x) {x += 4;}", Some(serde_json::json!([1]))),
+        ("function a(x) {x >>= 4;}", Some(serde_json::json!([1]))),
+        ("function a(x) {x >>>= 4;}", Some(serde_json::json!([1]))),
+        ("function a(x) {x == 4;}", Some(serde_json::json!([1]))),
+        ("function a(x) {x === 4;}", Some(serde_json::json!([1]))),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: 3;}}",
+            Some(serde_json::json!([3])),
+        ),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: if(x == 'foo') {5;};}}",
+            Some(serde_json::json!([4])),
+        ),
+        ("function a(x) {while(true) {'foo';}}", Some(serde_json::json!([2]))),
+        ("function a(x) {do {'foo';} while (true)}", Some(serde_json::json!([2]))),
+        ("if (foo) { bar(); }", Some(serde_json::json!([3]))),
+        ("var a = (x) => {do {'foo';} while (true)}", Some(serde_json::json!([2]))),
+        ("function foo(a = x) {}", Some(serde_json::json!([2]))),
+        ("function foo({ a = x }) {}", Some(serde_json::json!([2]))),
+        ("function foo([a = x]) {}", Some(serde_json::json!([2]))),
+        ("function foo() { ({ a = x } = obj); }", Some(serde_json::json!([2]))),
+        ("function foo() { [a = x] = obj; }", Some(serde_json::json!([2]))),
+        ("function foo(a) { a?.b; }", Some(serde_json::json!([2]))),
+        ("function foo(a) { a?.b?.c; }", Some(serde_json::json!([3]))),
+        ("function foo(a) { a?.(); }", Some(serde_json::json!([2]))),
+        ("function foo(a) { a.b.c; }", Some(serde_json::json!([1]))),
+        ("function foo() { for (;;) {} }", Some(serde_json::json!([2]))),
+        ("function foo(a) { for (const b of a) {} }", Some(serde_json::json!([2]))),
+        ("function foo() { function bar(x) { if (x) {} } }", Some(serde_json::json!([2]))),
+        ("function foo() { return (x) => x ? 1 : 2; }", Some(serde_json::json!([2]))),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: 3;}}",
+            Some(serde_json::json!([{ "max": 2, "variant": "modified" }])),
+        ),
+        ("function a(x) {if (x) {} if (x) {}}", Some(serde_json::json!([{ "max": 3 }]))),
+        ("function a(x) {if (x) {} if (x) {}}", Some(serde_json::json!([{ "maximum": 3 }]))),
+        ("function a(x) {if (x) {} if (x) {}}", Some(serde_json::json!([{}]))),
+    ];
+
+    let fail = vec![
+        ("function a(x) {}", Some(serde_json::json!([0]))),
+        ("var func = function () {}", Some(serde_json::json!([0]))),
+        ("var obj = { a(x) {} }", Some(serde_json::json!([0]))),
+        ("class Test { a(x) {} }", Some(serde_json::json!([0]))),
+        ("var a = (x) => {if (true) {return x;}}", Some(serde_json::json!([1]))),
+        ("function a(x) {if (true) {return x;}}", Some(serde_json::json!([1]))),
+        ("function a(x) {if (true) {return x;} else {return x+1;}}", Some(serde_json::json!([1]))),
+        (
+            "function a(x) {if (true) {return x;} else if (false) {return x+1;} else {return 4;}}",
+            Some(serde_json::json!([2])),
+        ),
+        (
+            "function a(x) {for(var i = 0; i < 5; i ++) {x ++;} return x;}",
+            Some(serde_json::json!([1])),
+        ),
+        ("function a(obj) {for(var i in obj) {obj[i] = 3;}}", Some(serde_json::json!([1]))),
+        ("function a(obj) {for(var i of obj) {obj[i] = 3;}}", Some(serde_json::json!([1]))),
+        (
+            "function a(x) {for(var i = 0; i < 5; i ++) {if(i % 2 === 0) {x ++;}} return x;}",
+            Some(serde_json::json!([2])),
+        ),
+        (
+            "function a(obj) {if(obj){ for(var x in obj) {try {x.getThis();} catch (e) {x.getThat();}}} else {return false;}}",
+            Some(serde_json::json!([3])),
+        ),
+        (
+            "function a(x) {try {x.getThis();} catch (e) {x.getThat();}}",
+            Some(serde_json::json!([1])),
+        ),
+        ("function a(x) {return x === 4 ? 3 : 5;}", Some(serde_json::json!([1]))),
+        ("function a(x) {return x === 4 ? 3 : (x === 3 ? 2 : 1);}", Some(serde_json::json!([2]))),
+        ("function a(x) {return x || 4;}", Some(serde_json::json!([1]))),
+        ("function a(x) {x && 4;}", Some(serde_json::json!([1]))),
+        ("function a(x) {x ?? 4;}", Some(serde_json::json!([1]))),
+        ("function a(x) {x ||= 4;}", Some(serde_json::json!([1]))),
+        ("function a(x) {x &&= 4;}", Some(serde_json::json!([1]))),
+        ("function a(x) {x ??= 4;}", Some(serde_json::json!([1]))),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: 3;}}",
+            Some(serde_json::json!([2])),
+        ),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: if(x == 'foo') {5;};}}",
+            Some(serde_json::json!([3])),
+        ),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: 3;}}",
+            Some(serde_json::json!([{ "max": 1, "variant": "modified" }])),
+        ),
+        ("function a(x) {while(true) {'foo';}}", Some(serde_json::json!([1]))),
+        ("function a(x) {do {'foo';} while (true)}", Some(serde_json::json!([1]))),
+        (
+            "function a(x) {(function() {while(true){'foo';}})(); (function() {while(true){'bar';}})();}",
+            Some(serde_json::json!([1])),
+        ),
+        (
+            "function a(x) {(function() {while(true){'foo';}})(); (function() {'bar';})();}",
+            Some(serde_json::json!([1])),
+        ),
+        ("function foo() { function bar(x) { if (x) {} } }", Some(serde_json::json!([1]))),
+        ("function foo() { return (x) => x ? 1 : 2; }", Some(serde_json::json!([1]))),
+        ("var obj = { a(x) { return x ? 0 : 1; } };", Some(serde_json::json!([1]))),
+        ("var obj = { a: function b(x) { return x ? 0 : 1; } };", Some(serde_json::json!([1]))),
+        ("function foo(a = x) {}", Some(serde_json::json!([1]))),
+        ("function foo({ a = x }) {}", Some(serde_json::json!([1]))),
+        ("function foo() { ({ a = x } = obj); }", Some(serde_json::json!([1]))),
+        ("function foo(a) { a?.b; }", Some(serde_json::json!([1]))),
+        ("function foo(a) { a?.(); }", Some(serde_json::json!([1]))),
+        ("function foo() { for (;;) {} }", Some(serde_json::json!([1]))),
+        ("function foo(a) { if (a) { a?.b?.c ?? d; } }", Some(serde_json::json!([4]))),
+        ("function foo(a) { if (a && b || c) {} }", Some(serde_json::json!([{ "max": 3 }]))),
+        ("function foo(a) { if (a && b || c) {} }", Some(serde_json::json!([{ "maximum": 3 }]))),
+        (
+            "function foo() { if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} }",
+            None,
+        ),
+    ];
+
+    Tester::new(Complexity::NAME, Complexity::PLUGIN, pass, fail).test_and_snapshot();
+}
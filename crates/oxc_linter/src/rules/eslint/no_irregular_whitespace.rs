@@ -236,7 +236,7 @@ fn test() {
         (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
         (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
         (r"<div>　</div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
-        // (r"﻿console.log('hello BOM');", None),
+        (r"﻿console.log('hello BOM');", None),
     ];
 
     let fail = vec![
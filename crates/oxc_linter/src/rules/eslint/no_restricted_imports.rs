@@ -406,6 +406,9 @@ declare_oxc_lint!(
     /// This configuration restricts imports not just from `import-foo` using path,
     /// but also `import-foo/bar` and `import-foo/baz` using `patterns`.
     ///
+    /// As in a `.gitignore`, a pattern like `import-foo` also restricts the modules in the `import-foo` directory,
+    /// like `import-foo/bar`, and a negated pattern like `!import-foo/bar` can't allow a module in a restricted directory again.
+    ///
     /// You can also use regular expressions to restrict modules (see the `regex` option).
     ///
     /// Examples of **incorrect** code for `patterns` option:
@@ -589,10 +592,14 @@ fn add_configuration_patterns_from_object(
         return;
     };
 
+    // Like in ESLint, the strings are the lines of one `.gitignore`, so a negated pattern can
+    // allow a module which an earlier one restricts.
+    let mut group = vec![];
+
     for path_value in paths_array {
         match path_value {
             Value::String(module_name) => {
-                add_configuration_patterns_from_string(patterns, module_name);
+                group.push(CompactStr::new(module_name));
             }
             Value::Object(_) => {
                 if let Ok(pattern) = serde_json::from_value::<RestrictedPattern>(path_value.clone())
@@ -625,11 +632,18 @@ fn add_configuration_patterns_from_object(
             _ => (),
         }
     }
+
+    if !group.is_empty() {
+        add_configuration_patterns_from_group(patterns, group);
+    }
 }
 
-fn add_configuration_patterns_from_string(paths: &mut Vec<RestrictedPattern>, module_name: &str) {
-    paths.push(RestrictedPattern {
-        group: Some(vec![CompactStr::new(module_name)]),
+fn add_configuration_patterns_from_group(
+    patterns: &mut Vec<RestrictedPattern>,
+    group: Vec<CompactStr>,
+) {
+    patterns.push(RestrictedPattern {
+        group: Some(group),
         regex: None,
         import_names: None,
         import_name_pattern: None,
@@ -860,29 +874,72 @@ impl RestrictedPattern {
         };
 
         let case_insensitive = !self.case_sensitive.unwrap_or(false);
+        let name =
+            if case_insensitive { name.cow_to_ascii_lowercase() } else { Cow::Borrowed(name) };
+
+        // Like in a `.gitignore`, a pattern also matches the modules in a directory it matches,
+        // and a negated pattern can't allow a module again when its directory is restricted.
+        for (end, _) in name.match_indices('/') {
+            let directory = &name[..end];
+            if !directory.is_empty()
+                && matches!(
+                    Self::match_group(groups, directory, true, case_insensitive),
+                    GlobResult::Found
+                )
+            {
+                return GlobResult::Found;
+            }
+        }
+
+        Self::match_group(groups, &name, false, case_insensitive)
+    }
 
+    fn match_group(
+        groups: &[CompactStr],
+        path: &str,
+        is_directory: bool,
+        case_insensitive: bool,
+    ) -> GlobResult {
         let mut decision = GlobResult::None;
 
         for raw_pat in groups {
-            let (negated, pat) = match raw_pat.strip_prefix('!') {
+            // roughly based on https://github.com/BurntSushi/ripgrep/blob/6dfaec03/crates/ignore/src/gitignore.rs#L436-L516
+            let pat = raw_pat.trim_end();
+            if pat.is_empty() || pat.starts_with('#') {
+                continue;
+            }
+
+            let (negated, pat) = match pat.strip_prefix('!') {
                 Some(rest) => (true, rest),
-                None => (false, raw_pat.as_str()),
+                None => (false, pat),
+            };
+            // `\#` and `\!` match a leading `#` or `!`, which would start a comment or a negation.
+            let pat =
+                pat.strip_prefix('\\').filter(|rest| rest.starts_with(['#', '!'])).unwrap_or(pat);
+
+            // `foo/` only matches a directory.
+            let pat = match pat.strip_suffix('/') {
+                Some(_) if !is_directory => continue,
+                Some(rest) => rest,
+                None => pat,
             };
 
-            // roughly based on https://github.com/BurntSushi/ripgrep/blob/6dfaec03/crates/ignore/src/gitignore.rs#L436-L516
-            let pat = if pat.contains('/') {
+            // `/foo` and `foo/bar` are relative to the root, `foo` matches at any level.
+            let pat = if let Some(rest) = pat.strip_prefix('/') {
+                Cow::Borrowed(rest)
+            } else if pat.contains('/') {
                 Cow::Borrowed(pat)
             } else {
                 Cow::Owned(format!("**/{pat}"))
             };
 
-            let (pat, name) = if case_insensitive {
-                (pat.cow_to_ascii_lowercase(), name.cow_to_ascii_lowercase())
+            let pat = if case_insensitive {
+                Cow::Owned(pat.cow_to_ascii_lowercase().into_owned())
             } else {
-                (pat, name.into())
+                pat
             };
 
-            if fast_glob::glob_match(pat.as_ref(), name.as_ref()) {
+            if fast_glob::glob_match(pat.as_ref(), path) {
                 decision = if negated { GlobResult::Whitelist } else { GlobResult::Found };
             }
         }
@@ -1446,6 +1503,14 @@ fn test() {
             r#"import withGitignores from "foo/bar";"#,
             Some(serde_json::json!([{ "patterns": ["foo/*", "!foo/bar"] }])),
         ),
+        (
+            r#"import withGitignores from "foo";"#,
+            Some(serde_json::json!([{ "patterns": ["foo/"] }])),
+        ),
+        (
+            r#"import withGitignores from "foo";"#,
+            Some(serde_json::json!([{ "patterns": ["# foo"] }])),
+        ),
         (
             r#"import withPatterns from "foo/bar";"#,
             Some(
@@ -2122,10 +2187,18 @@ fn test() {
             r#"import withPaths from "foo/bar";"#,
             Some(serde_json::json!([{ "paths": ["foo/bar"] }])),
         ),
-        // (
-        //     r#"import withPatterns from "foo/bar";"#,
-        //     Some(serde_json::json!([{ "patterns": ["foo"] }])),
-        // ),
+        (
+            r#"import withPatterns from "foo/bar";"#,
+            Some(serde_json::json!([{ "patterns": ["foo"] }])),
+        ),
+        (
+            r#"import withGitignores from "foo/bar";"#,
+            Some(serde_json::json!([{ "patterns": ["foo/"] }])),
+        ),
+        (
+            r#"import withGitignores from "foo/bar/baz";"#,
+            Some(serde_json::json!([{ "patterns": ["foo", "!foo/bar/baz"] }])),
+        ),
         (
             r#"import withPatterns from "foo/bar";"#,
             Some(serde_json::json!([{ "patterns": ["bar"] }])),
@@ -2635,10 +2708,10 @@ fn test() {
             "import absoluteWithPatterns from '/foo';",
             Some(serde_json::json!([{ "patterns": ["foo"] }])),
         ),
-        // (
-        //     "import absoluteWithPatterns from '#foo/bar';",
-        //     Some(serde_json::json!([{ "patterns": ["\\#foo"] }])),
-        // ),
+        (
+            "import absoluteWithPatterns from '#foo/bar';",
+            Some(serde_json::json!([{ "patterns": ["\\#foo"] }])),
+        ),
         (
             "import { Foo } from '../../my/relative-module';",
             Some(serde_json::json!([{
@@ -0,0 +1,252 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use schemars::JsonSchema;
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    selector::{EstreeJson, EstreeTree, Selector},
+};
+
+fn no_restricted_syntax_diagnostic(span: Span, message: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(message.to_string()).with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntax(Box<NoRestrictedSyntaxConfig>);
+
+/// A list of selectors matching the syntax to restrict.
+///
+/// Example configuration:
+/// ```json
+/// {
+///   "no-restricted-syntax": [
+///     "error",
+///     "ForInStatement",
+///     {
+///       "selector": "CallExpression[callee.name='setTimeout'][arguments.length!=2]",
+///       "message": "setTimeout must always be invoked with two arguments."
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Default, Clone, JsonSchema)]
+pub struct NoRestrictedSyntaxConfig(
+    #[schemars(with = "Vec<RestrictedSyntaxOption>")] Vec<RestrictedSyntax>,
+);
+
+#[derive(Debug, Clone)]
+struct RestrictedSyntax {
+    selector: Selector,
+    message: Option<CompactStr>,
+}
+
+#[derive(JsonSchema)]
+#[serde(untagged)]
+#[expect(dead_code)]
+enum RestrictedSyntaxOption {
+    /// A selector, e.g. `"WithStatement"`.
+    Selector(String),
+    Object {
+        /// Selector matching the syntax to restrict.
+        selector: String,
+        /// Message reported instead of the default one.
+        message: Option<String>,
+    },
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows syntax matching the configured selectors.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JavaScript has many language features, and not all of them are appropriate for every
+    /// codebase. For example, a project may want to disallow `with` statements, or `for...in`
+    /// loops in favor of `Object.keys`. This rule allows restricting any syntax that can be
+    /// described by a selector, without writing a custom rule.
+    ///
+    /// Selectors use the syntax of [esquery](https://github.com/estools/esquery) and are matched
+    /// against the [ESTree](https://github.com/estree/estree) AST, so selectors written for
+    /// ESLint's `no-restricted-syntax` work unchanged.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// /* no-restricted-syntax: ["error", "FunctionExpression", "WithStatement", "BinaryExpression[operator='in']"] */
+    ///
+    /// with (me) {
+    ///     dontMess();
+    /// }
+    ///
+    /// var doSomething = function () {};
+    ///
+    /// foo in bar;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// /* no-restricted-syntax: ["error", "FunctionExpression", "WithStatement", "BinaryExpression[operator='in']"] */
+    ///
+    /// me.dontMess();
+    ///
+    /// function doSomething() {}
+    ///
+    /// foo instanceof bar;
+    /// ```
+    NoRestrictedSyntax,
+    eslint,
+    restriction,
+    config = NoRestrictedSyntaxConfig,
+);
+
+impl RestrictedSyntax {
+    /// Parses the restrictions of `value`, failing on selectors which can't be parsed.
+    fn parse_all(value: &Value) -> impl Iterator<Item = Result<Self, String>> {
+        value.as_array().into_iter().flatten().filter_map(|restriction| {
+            let (selector, message) = match restriction {
+                // "no-restricted-syntax": ["error", "WithStatement"]
+                Value::String(selector) => (selector.as_str(), None),
+                // "no-restricted-syntax": ["error", { "selector": "WithStatement", "message": "..." }]
+                Value::Object(object) => (
+                    object.get("selector").and_then(Value::as_str)?,
+                    object.get("message").and_then(Value::as_str).map(CompactStr::from),
+                ),
+                _ => return None,
+            };
+            Some(
+                Selector::parse(selector)
+                    .map(|selector| Self { selector, message })
+                    .map_err(|error| error.message.to_string()),
+            )
+        })
+    }
+}
+
+impl Rule for NoRestrictedSyntax {
+    fn from_configuration(value: Value) -> Self {
+        let restrictions = RestrictedSyntax::parse_all(&value).filter_map(Result::ok).collect();
+        Self(Box::new(NoRestrictedSyntaxConfig(restrictions)))
+    }
+
+    fn try_from_configuration(value: Value) -> Result<Self, String> {
+        let restrictions = RestrictedSyntax::parse_all(&value).collect::<Result<_, _>>()?;
+        Ok(Self(Box::new(NoRestrictedSyntaxConfig(restrictions))))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        if self.0.0.is_empty() {
+            return;
+        }
+
+        let json = EstreeJson::new(ctx.nodes().program());
+        let node_types = json.node_types();
+        let restrictions = self
+            .0
+            .0
+            .iter()
+            .filter(|restriction| restriction.selector.may_match(&node_types))
+            .collect::<Vec<_>>();
+        if restrictions.is_empty() {
+            return;
+        }
+        // The rule is skipped for ASTs nested too deeply to be matched without a stack overflow.
+        let Some(value) = json.parse() else { return };
+        let tree = EstreeTree::new(&value);
+        for restriction in restrictions {
+            let selector = &restriction.selector;
+            for node in selector.query(&tree) {
+                let message = match &restriction.message {
+                    Some(message) => message.to_string(),
+                    None => format!("Using '{}' is not allowed.", selector.source()),
+                };
+                ctx.diagnostic(no_restricted_syntax_diagnostic(node.span(), &message));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("doSomething();", None, None),
+        ("var foo = 42;", Some(json!(["ConditionalExpression"])), None),
+        ("foo += 42;", Some(json!(["VariableDeclaration", "FunctionExpression"])), None),
+        ("foo;", Some(json!(["Identifier[name=\"bar\"]"])), None),
+        ("() => 5", Some(json!(["ArrowFunctionExpression > BlockStatement"])), None),
+        ("A: for (;;) break;", Some(json!(["BreakStatement[label]"])), None),
+        ("function foo(bar, baz) {}", Some(json!(["FunctionDeclaration[params.length>2]"])), None),
+        ("var foo = 42;", Some(json!([{ "selector": "ConditionalExpression" }])), None),
+        (
+            "for (const a of b) {}",
+            Some(json!([{ "selector": "ForInStatement", "message": "msg" }])),
+            None,
+        ),
+        ("a()", Some(json!(["CallExpression:has(CallExpression)"])), None),
+        ("let x: string;", Some(json!(["TSAnyKeyword"])), None),
+    ];
+
+    let fail = vec![
+        ("var foo = 41;", Some(json!(["VariableDeclaration"])), None),
+        (";function lol(a) { return 42; }", Some(json!(["EmptyStatement"])), None),
+        (
+            "try { voices(); } catch (e) { oranges(); } finally { yodel(); }",
+            Some(json!(["Literal", "TryStatement"])),
+            None,
+        ),
+        ("bar;", Some(json!(["Identifier[name=\"bar\"]"])), None),
+        ("bar;", Some(json!(["Identifier", "Identifier[name=\"bar\"]"])), None),
+        ("() => {}", Some(json!(["ArrowFunctionExpression > BlockStatement"])), None),
+        ("({ 'foo': 1, 'bar': 2 })", Some(json!(["Property > Literal"])), None),
+        ("A: for (;;) break A;", Some(json!(["BreakStatement[label]"])), None),
+        (
+            "function foo(bar, baz, qux) {}",
+            Some(json!(["FunctionDeclaration[params.length>2]"])),
+            None,
+        ),
+        ("var foo = 41;", Some(json!([{ "selector": "VariableDeclaration" }])), None),
+        (
+            "function foo(bar, baz, qux) {}",
+            Some(
+                json!([{ "selector": "FunctionDeclaration[params.length>2]", "message": "custom message" }]),
+            ),
+            None,
+        ),
+        ("a(b())", Some(json!(["CallExpression:has(CallExpression)"])), None),
+        (
+            "for (a in b) {}",
+            Some(json!([{ "selector": "ForInStatement", "message": "msg" }])),
+            None,
+        ),
+        (
+            "setTimeout(f)",
+            Some(json!(["CallExpression[callee.name=/^set(Timeout|Interval)$/]"])),
+            None,
+        ),
+        ("let x: any;", Some(json!(["TSAnyKeyword"])), None),
+        ("<div>{'ü'}</div>", Some(json!(["JSXExpressionContainer > Literal"])), None),
+    ];
+
+    Tester::new(NoRestrictedSyntax::NAME, NoRestrictedSyntax::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_invalid_selector() {
+    use serde_json::json;
+
+    let error = NoRestrictedSyntax::try_from_configuration(json!(["Identifier[", "Identifier"]))
+        .unwrap_err();
+    assert!(error.starts_with("Invalid selector `Identifier[`"), "{error}");
+
+    // Invalid selectors are skipped when the configuration isn't validated.
+    let rule = NoRestrictedSyntax::from_configuration(json!(["Identifier[", "Identifier"]));
+    assert_eq!(rule.0.0.len(), 1);
+}
@@ -1,8 +1,9 @@
 use oxc_ast::{
     AstKind,
-    ast::{ChainElement, Expression, UnaryOperator},
+    ast::{CallExpression, ChainElement, Expression, UnaryOperator},
 };
 use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::side_effects::{CallMayHaveSideEffects, MayHaveSideEffects};
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use schemars::JsonSchema;
@@ -49,11 +50,16 @@ declare_oxc_lint!(
     ///
     /// ### Examples
     ///
+    /// Calls without side effects are unused expressions too, e.g. calls annotated with
+    /// `/* @__PURE__ */` and immediately invoked functions whose body has no side effects.
+    ///
     /// Examples of **incorrect** code for this rule:
     /// ```ts
     /// Set<number>;
     /// 1 as number;
     /// window!;
+    /// /* @__PURE__ */ createStore();
+    /// (() => {})();
     /// ```
     ///
     /// Examples of **correct** code for this rule:
@@ -72,7 +78,7 @@ impl Rule for NoUnusedExpressions {
             return;
         };
 
-        if self.is_disallowed(&expression_stmt.expression)
+        if self.is_disallowed(&expression_stmt.expression, ctx)
             && !is_parent_arrow_function_expression(node, ctx)
         {
             ctx.diagnostic(no_unused_expressions_diagnostic(expression_stmt.span));
@@ -101,7 +107,7 @@ fn is_parent_arrow_function_expression<'a>(node: &AstNode<'a>, ctx: &LintContext
 }
 
 impl NoUnusedExpressions {
-    fn is_disallowed(&self, expr: &Expression) -> bool {
+    fn is_disallowed<'a>(&self, expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
         match expr {
             Expression::BooleanLiteral(_)
             | Expression::NullLiteral(_)
@@ -123,11 +129,12 @@ impl NoUnusedExpressions {
             | Expression::BinaryExpression(_)
             | Expression::PrivateInExpression(_)
             | Expression::ThisExpression(_)
+            | Expression::TopicReference(_)
             | Expression::Identifier(_) => true,
             Expression::ChainExpression(chain_expression) => match &chain_expression.expression {
                 ChainElement::CallExpression(_) => false,
                 ChainElement::TSNonNullExpression(ts_non_null_expression) => {
-                    self.is_disallowed(&ts_non_null_expression.expression)
+                    self.is_disallowed(&ts_non_null_expression.expression, ctx)
                 }
                 ChainElement::ComputedMemberExpression(_)
                 | ChainElement::StaticMemberExpression(_)
@@ -138,26 +145,32 @@ impl NoUnusedExpressions {
             | Expression::NewExpression(_)
             | Expression::ImportExpression(_)
             | Expression::Super(_)
-            | Expression::CallExpression(_)
             | Expression::V8IntrinsicExpression(_)
+            | Expression::DoExpression(_)
             | Expression::UpdateExpression(_)
             | Expression::TSSatisfiesExpression(_)
             | Expression::YieldExpression(_) => false,
+            Expression::CallExpression(call_expression) => {
+                !call_has_side_effects(call_expression, ctx)
+            }
             Expression::ConditionalExpression(conditional_expression) => {
                 if self.0.allow_ternary {
-                    return self.is_disallowed(&conditional_expression.alternate)
-                        || self.is_disallowed(&conditional_expression.consequent);
+                    return self.is_disallowed(&conditional_expression.alternate, ctx)
+                        || self.is_disallowed(&conditional_expression.consequent, ctx);
                 }
                 true
             }
             Expression::LogicalExpression(logical_expression) => {
                 if self.0.allow_short_circuit {
-                    return self.is_disallowed(&logical_expression.right);
+                    return self.is_disallowed(&logical_expression.right, ctx);
                 }
                 true
             }
+            Expression::PipelineExpression(pipeline_expression) => {
+                self.is_disallowed(&pipeline_expression.body, ctx)
+            }
             Expression::ParenthesizedExpression(parenthesized_expression) => {
-                self.is_disallowed(&parenthesized_expression.expression)
+                self.is_disallowed(&parenthesized_expression.expression, ctx)
             }
             Expression::TaggedTemplateExpression(_) => !self.0.allow_tagged_templates,
             Expression::UnaryExpression(unary_expression) => {
@@ -165,21 +178,31 @@ impl NoUnusedExpressions {
             }
             Expression::JSXElement(_) | Expression::JSXFragment(_) => self.0.enforce_for_jsx,
             Expression::TSAsExpression(ts_as_expression) => {
-                self.is_disallowed(&ts_as_expression.expression)
+                self.is_disallowed(&ts_as_expression.expression, ctx)
             }
             Expression::TSTypeAssertion(ts_type_assertion) => {
-                self.is_disallowed(&ts_type_assertion.expression)
+                self.is_disallowed(&ts_type_assertion.expression, ctx)
             }
             Expression::TSNonNullExpression(ts_non_null_expression) => {
-                self.is_disallowed(&ts_non_null_expression.expression)
+                self.is_disallowed(&ts_non_null_expression.expression, ctx)
             }
             Expression::TSInstantiationExpression(ts_instantiation_expression) => {
-                self.is_disallowed(&ts_instantiation_expression.expression)
+                self.is_disallowed(&ts_instantiation_expression.expression, ctx)
             }
         }
     }
 }
 
+/// Whether the call changes application state, using the side effect analysis of the minifier.
+fn call_has_side_effects<'a>(call: &CallExpression<'a>, ctx: &LintContext<'a>) -> bool {
+    if !call.may_have_side_effects(ctx) {
+        return false;
+    }
+    // e.g. `(function () {})()`
+    call.callee.call_may_have_side_effects(ctx)
+        || call.arguments.iter().any(|argument| argument.may_have_side_effects(ctx))
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -345,6 +368,13 @@ fn test() {
             None,
         ),
         ("value satisfies number;", None),
+        ("/* @__PURE__ */ foo(bar())", None),
+        ("(() => { sideEffect(); })()", None),
+        ("(async () => {})()", None),
+        ("(function* () {})()", None),
+        ("(function ({ a }) {})(null)", None),
+        ("(() => {})(sideEffect())", None),
+        ("Math.max(a(), 1)", None),
     ];
 
     let fail = vec![
@@ -544,6 +574,11 @@ fn test() {
             None,
         ),
         ("const _func = (value: number) => { value + 1; }", None),
+        ("/* @__PURE__ */ foo()", None),
+        ("/* @__PURE__ */ foo(1, 'a')", None),
+        ("(() => {})()", None),
+        ("(function () { const a = 1; })()", None),
+        ("Math.max(1, 2)", None),
     ];
 
     Tester::new(NoUnusedExpressions::NAME, NoUnusedExpressions::PLUGIN, pass, fail)
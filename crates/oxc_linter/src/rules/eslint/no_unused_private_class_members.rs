@@ -1,12 +1,8 @@
-use itertools::Itertools;
-use oxc_ast::{AstKind, ast::AssignmentOperator};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_semantic::{AstNode, NodeId, Semantic};
-use oxc_span::{GetSpan, Span};
-use oxc_syntax::class::ElementKind;
+use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, dead_code::unused_private_members, rule::Rule};
 
 fn no_unused_private_class_members_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("'{name}' is defined but never used.")).with_label(span)
@@ -90,26 +86,9 @@ declare_oxc_lint!(
 
 impl Rule for NoUnusedPrivateClassMembers {
     fn run_once(&self, ctx: &LintContext) {
-        ctx.classes().iter_enumerated().for_each(|(class_id, _)| {
-            for (element_id, element) in ctx.classes().elements[class_id].iter_enumerated() {
-                if !element.kind.intersects(ElementKind::Property | ElementKind::Method) {
-                    continue;
-                }
-
-                if element.is_private
-                    && !ctx.classes().iter_private_identifiers(class_id).any(|ident| {
-                        // If the element is a property, it must be read.
-                        (!element.kind.is_property() || is_read(ident.id, ctx.semantic()))
-                            && ident.element_ids.contains(&element_id)
-                    })
-                {
-                    ctx.diagnostic(no_unused_private_class_members_diagnostic(
-                        &element.name,
-                        element.span,
-                    ));
-                }
-            }
-        });
+        for (name, span) in unused_private_members(ctx.semantic()) {
+            ctx.diagnostic(no_unused_private_class_members_diagnostic(name, span));
+        }
     }
 
     fn should_run(&self, ctx: &crate::context::ContextHost) -> bool {
@@ -117,162 +96,6 @@ impl Rule for NoUnusedPrivateClassMembers {
     }
 }
 
-fn is_read(current_node_id: NodeId, semantic: &Semantic) -> bool {
-    for (curr, parent) in semantic
-        .nodes()
-        .ancestors(current_node_id)
-        .filter(|parent| {
-            !matches!(
-                parent.kind(),
-                AstKind::ParenthesizedExpression(_)
-                    | AstKind::TSAsExpression(_)
-                    | AstKind::TSSatisfiesExpression(_)
-                    | AstKind::TSInstantiationExpression(_)
-                    | AstKind::TSNonNullExpression(_)
-                    | AstKind::TSTypeAssertion(_)
-            )
-        })
-        .tuple_windows::<(&AstNode<'_>, &AstNode<'_>)>()
-    {
-        match (curr.kind(), parent.kind()) {
-            // Skip member expressions in identifier context
-            (member_expr, AstKind::IdentifierReference(_))
-                if member_expr.is_member_expression_kind() => {}
-            // Skip identifier references in assignment targets
-            (
-                AstKind::IdentifierReference(_),
-                AstKind::ArrayAssignmentTarget(_)
-                | AstKind::ObjectAssignmentTarget(_)
-                | AstKind::IdentifierReference(_),
-            ) => {}
-            // All these are read contexts for private fields
-            (AstKind::PrivateFieldExpression(_), _) if is_value_context(parent, curr, semantic) => {
-                return true;
-            }
-            // AssignmentExpression: right-hand side is a read, compound assignment result in value context is a read
-            (AstKind::PrivateFieldExpression(_), AstKind::AssignmentExpression(assign_expr)) => {
-                // Right-hand side of assignment is a read
-                if assign_expr.right.span() == curr.span() {
-                    return true;
-                }
-                // Compound assignment result used in a value context is a read
-                if assign_expr.operator != AssignmentOperator::Assign
-                    && is_compound_assignment_read(parent.id(), semantic)
-                {
-                    return true;
-                }
-                // Not a read otherwise
-                return false;
-            }
-            // ForIn/ForOf: only right-hand side is a read
-            (AstKind::PrivateFieldExpression(_), AstKind::ForInStatement(for_in)) => {
-                if for_in.right.span() == curr.span() {
-                    return true;
-                }
-            }
-            (AstKind::PrivateFieldExpression(_), AstKind::ForOfStatement(for_of)) => {
-                if for_of.right.span() == curr.span() {
-                    return true;
-                }
-            }
-            // AssignmentTargetPropertyProperty: only computed property name is a read
-            (
-                AstKind::PrivateFieldExpression(_),
-                AstKind::AssignmentTargetPropertyProperty(prop),
-            ) => {
-                if prop.computed && prop.name.span() == curr.span() {
-                    return true;
-                }
-            }
-            (
-                AstKind::PrivateFieldExpression(_),
-                AstKind::ConditionalExpression(conditional_expr),
-            ) => {
-                if conditional_expr.test.span() == curr.span() {
-                    return true;
-                }
-                if is_value_context(parent, curr, semantic) {
-                    return true;
-                }
-            }
-            _ => {
-                return false;
-            }
-        }
-    }
-    true
-}
-
-/// Check if the given AST kind represents a context where a value is being read/used
-/// `parent` is the parent node, `child` is the child node we're checking
-fn is_value_context(parent: &AstNode, child: &AstNode, semantic: &Semantic<'_>) -> bool {
-    match parent.kind() {
-        AstKind::ReturnStatement(_)
-        | AstKind::CallExpression(_)
-        | AstKind::NewExpression(_)
-        | AstKind::BinaryExpression(_)
-        | AstKind::VariableDeclarator(_)
-        | AstKind::PropertyDefinition(_)
-        | AstKind::ArrayExpression(_)
-        | AstKind::ObjectProperty(_)
-        | AstKind::JSXExpressionContainer(_)
-        | AstKind::ChainExpression(_)
-        | AstKind::StaticMemberExpression(_)
-        | AstKind::ComputedMemberExpression(_)
-        | AstKind::TemplateLiteral(_)
-        | AstKind::UnaryExpression(_)
-        | AstKind::IfStatement(_)
-        | AstKind::SpreadElement(_)
-        | AstKind::AssignmentPattern(_)
-        | AstKind::SwitchCase(_)
-        | AstKind::ThrowStatement(_)
-        | AstKind::WhileStatement(_)
-        | AstKind::DoWhileStatement(_)
-        | AstKind::SequenceExpression(_) => true,
-        AstKind::FormalParameter(p) => {
-            p.initializer.as_ref().is_some_and(|init| init.span().contains_inclusive(child.span()))
-        }
-        AstKind::AssignmentExpression(assign_expr) => {
-            // The right-hand side of an assignment is always in a value context (being read for assignment)
-            assign_expr.right.span().contains_inclusive(child.span())
-        }
-        AstKind::ExpressionStatement(_) => {
-            let grandparent = semantic.nodes().parent_node(parent.id());
-            if let AstKind::FunctionBody(_) = grandparent.kind()
-                && let AstKind::ArrowFunctionExpression(arrow) =
-                    semantic.nodes().parent_kind(grandparent.id())
-                && arrow.expression
-            {
-                return true;
-            }
-            false
-        }
-        AstKind::ParenthesizedExpression(_)
-        | AstKind::TSAsExpression(_)
-        | AstKind::TSSatisfiesExpression(_)
-        | AstKind::TSInstantiationExpression(_)
-        | AstKind::TSNonNullExpression(_)
-        | AstKind::TSTypeAssertion(_)
-        | AstKind::UpdateExpression(_)
-        | AstKind::AwaitExpression(_)
-        | AstKind::ConditionalExpression(_)
-        | AstKind::LogicalExpression(_) => {
-            let grandparent = semantic.nodes().parent_node(parent.id());
-            is_value_context(grandparent, parent, semantic)
-        }
-
-        _ => false,
-    }
-}
-
-/// Check if a compound assignment result is being used in a value context
-fn is_compound_assignment_read(parent_id: NodeId, semantic: &Semantic) -> bool {
-    let assignment_expr = semantic.nodes().get_node(parent_id);
-    semantic.nodes().ancestors(parent_id).next().is_some_and(|assignment_parent| {
-        is_value_context(assignment_parent, assignment_expr, semantic)
-    })
-}
-
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -69,8 +69,7 @@ impl Rule for UnicodeBom {
     }
 
     fn run_once(&self, ctx: &LintContext) {
-        let source = ctx.source_text();
-        let has_bomb = source.starts_with('﻿');
+        let has_bomb = ctx.nodes().program().has_bom();
 
         if has_bomb && matches!(self.0, BomOptionType::Never) {
             ctx.diagnostic_with_fix(unexpected_unicode_bom_diagnostic(SPAN), |fixer| {
@@ -0,0 +1,566 @@
+use std::{borrow::Cow, iter, ops::Deref};
+
+use lazy_regex::Regex;
+use oxc_ast::{
+    AstKind,
+    ast::{BinaryOperator, Expression, JSXAttribute},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, de::Error};
+use serde_json::{Value, json};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    frameworks::is_jestlike_file,
+    rule::Rule,
+    utils::{get_jsx_attribute_name, get_node_name},
+};
+
+fn no_literal_string_diagnostic(span: Span, text: &str, message: Option<&str>) -> OxcDiagnostic {
+    let message = message
+        .map_or_else(|| format!("Literal string `{text}` should be translated."), str::to_string);
+    OxcDiagnostic::warn(message)
+        .with_help("Move the string to a translation file, and look it up with a translation function like `t`.")
+        .with_label(span)
+}
+
+const JSX_COMPONENTS_EXCLUDE: [&str; 1] = ["Trans"];
+
+const JSX_ATTRIBUTES_EXCLUDE: [&str; 8] =
+    ["className", "styleName", "style", "type", "key", "id", "width", "height"];
+
+const CALLEES_EXCLUDE: [&str; 14] = [
+    "i18n(ext)?",
+    "t",
+    "require",
+    "addEventListener",
+    "removeEventListener",
+    "postMessage",
+    "getElementById",
+    "dispatch",
+    "commit",
+    "includes",
+    "indexOf",
+    "endsWith",
+    "startsWith",
+    r"console\.\w+",
+];
+
+/// Strings without letters, and constants like `FOO_BAR`.
+const WORDS_EXCLUDE: [&str; 2] = ["[0-9!-/:-@\\[-`{-~\\s]+", "[A-Z_-]+"];
+
+const OBJECT_PROPERTIES_EXCLUDE: [&str; 1] = ["[A-Z_-]+"];
+
+const CLASS_PROPERTIES_EXCLUDE: [&str; 1] = ["displayName"];
+
+#[derive(Debug, Default, Clone)]
+pub struct NoLiteralString(Box<NoLiteralStringConfig>);
+
+impl Deref for NoLiteralString {
+    type Target = NoLiteralStringConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows hard-coded strings which are shown to users, so that they can be translated.
+    ///
+    /// By default, only text in JSX is checked. The `mode` option also checks JSX attributes
+    /// (`"jsx-only"`), or every string (`"all"`). Strings which are never shown to users, like
+    /// module names, object keys, comparisons, and arguments of functions like `console.log`,
+    /// are never reported, and neither are strings without letters, or in `UPPER_CASE`.
+    ///
+    /// Test files are ignored by default.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A hard-coded string can't be translated, so an application which should support more
+    /// than one language must look up all of its text in translation files instead.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// const Greeting = () => <div>Hello, world!</div>;
+    ///
+    /// const Link = () => <a href="/">{"Home"}</a>;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// const Greeting = () => <div>{t("greeting")}</div>;
+    ///
+    /// const Link = () => <a href="/" className="link">{t("home")}</a>;
+    ///
+    /// const Message = () => <Trans>Hello, <b>world</b>!</Trans>;
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "mode": "all" }`:
+    /// ```js
+    /// alert("Something went wrong");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "mode": "all" }`:
+    /// ```js
+    /// import { t } from "i18next";
+    ///
+    /// alert(t("error.unknown"));
+    /// console.error("Something went wrong");
+    /// if (status === "loading") {}
+    /// ```
+    NoLiteralString,
+    i18next,
+    pedantic,
+    config = NoLiteralStringConfig,
+);
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct NoLiteralStringConfig {
+    /// Which strings are checked:
+    ///
+    /// - `"jsx-text-only"`: text in JSX, like `<div>text</div>` and `<div>{"text"}</div>`.
+    /// - `"jsx-only"`: text in JSX and values of JSX attributes.
+    /// - `"all"`: all strings.
+    ///
+    /// Values of attributes and arguments of functions matching an `include` pattern are
+    /// checked in every mode.
+    mode: Mode,
+    /// Names of JSX components whose text is checked (`include`), or ignored (`exclude`).
+    /// Defaults to excluding `Trans`.
+    jsx_components: Filter,
+    /// Names of JSX attributes whose values are checked (`include`), or ignored (`exclude`).
+    /// Defaults to excluding attributes like `className`, `style` and `key`.
+    jsx_attributes: Filter,
+    /// Names of functions whose arguments are checked (`include`), or ignored (`exclude`),
+    /// matched against the full name, like `console.log`, and the name of the method, like
+    /// `log`. Defaults to excluding functions like `t`, `require` and `console.*`.
+    callees: Filter,
+    /// Strings which are ignored. Defaults to excluding strings without letters, and strings
+    /// in `UPPER_CASE`.
+    words: ExcludeFilter,
+    /// Names of object properties whose values are ignored. Defaults to excluding names in
+    /// `UPPER_CASE`.
+    object_properties: ExcludeFilter,
+    /// Names of class properties whose values are ignored. Defaults to excluding
+    /// `displayName`.
+    class_properties: ExcludeFilter,
+    /// Message reported instead of the default one.
+    message: Option<String>,
+    /// Whether to also check template literals.
+    should_validate_template: bool,
+    /// Whether to skip test files, like `foo.test.js` and `__tests__/foo.js`.
+    ignore_test_files: bool,
+}
+
+impl Default for NoLiteralStringConfig {
+    fn default() -> Self {
+        Self {
+            mode: Mode::default(),
+            jsx_components: Filter {
+                include: Patterns::default(),
+                exclude: Patterns::new(&JSX_COMPONENTS_EXCLUDE),
+            },
+            jsx_attributes: Filter {
+                include: Patterns::default(),
+                exclude: Patterns::new(&JSX_ATTRIBUTES_EXCLUDE),
+            },
+            callees: Filter {
+                include: Patterns::default(),
+                exclude: Patterns::new(&CALLEES_EXCLUDE),
+            },
+            words: ExcludeFilter { exclude: Patterns::new(&WORDS_EXCLUDE) },
+            object_properties: ExcludeFilter { exclude: Patterns::new(&OBJECT_PROPERTIES_EXCLUDE) },
+            class_properties: ExcludeFilter { exclude: Patterns::new(&CLASS_PROPERTIES_EXCLUDE) },
+            message: None,
+            should_validate_template: false,
+            ignore_test_files: true,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+enum Mode {
+    #[default]
+    JsxTextOnly,
+    JsxOnly,
+    All,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default)]
+struct Filter {
+    /// Regular expressions of names which are checked.
+    include: Patterns,
+    /// Regular expressions of names which are ignored.
+    exclude: Patterns,
+}
+
+impl Filter {
+    /// Whether a string belonging to one of `names` is checked, where `default` is whether it
+    /// is checked when there are no `include` patterns.
+    fn is_checked(&self, names: &[&str], default: bool) -> bool {
+        if names.iter().any(|name| self.exclude.is_match(name)) {
+            return false;
+        }
+        if self.include.0.is_empty() {
+            default
+        } else {
+            names.iter().any(|name| self.include.is_match(name))
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default)]
+struct ExcludeFilter {
+    /// Regular expressions of names which are ignored.
+    exclude: Patterns,
+}
+
+/// Regular expressions which must match a whole name.
+#[derive(Debug, Default, Clone, JsonSchema)]
+struct Patterns(#[schemars(with = "Vec<String>")] Vec<Regex>);
+
+impl Patterns {
+    fn new(patterns: &[&str]) -> Self {
+        Self(patterns.iter().map(|pattern| Self::compile(pattern).unwrap()).collect())
+    }
+
+    fn compile(pattern: &str) -> Result<Regex, lazy_regex::regex::Error> {
+        Regex::new(&format!("^(?:{pattern})$"))
+    }
+
+    fn is_match(&self, text: &str) -> bool {
+        self.0.iter().any(|regex| regex.is_match(text))
+    }
+}
+
+impl<'de> Deserialize<'de> for Patterns {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<Cow<str>>::deserialize(d)?
+            .iter()
+            .map(|pattern| Self::compile(pattern).map_err(D::Error::custom))
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// The default options, into which the configured ones are merged, so that e.g. configuring
+/// `callees.include` keeps the default `callees.exclude`.
+fn default_options() -> Value {
+    json!({
+        "jsx-components": { "exclude": JSX_COMPONENTS_EXCLUDE },
+        "jsx-attributes": { "exclude": JSX_ATTRIBUTES_EXCLUDE },
+        "callees": { "exclude": CALLEES_EXCLUDE },
+        "words": { "exclude": WORDS_EXCLUDE },
+        "object-properties": { "exclude": OBJECT_PROPERTIES_EXCLUDE },
+        "class-properties": { "exclude": CLASS_PROPERTIES_EXCLUDE },
+    })
+}
+
+fn merge_options(options: &mut Value, value: Value) {
+    match (options, value) {
+        (Value::Object(options), Value::Object(value)) => {
+            for (key, value) in value {
+                merge_options(options.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (options, value) => *options = value,
+    }
+}
+
+impl Rule for NoLiteralString {
+    fn from_configuration(value: Value) -> Self {
+        let Some(value) = value.get(0) else {
+            return Self::default();
+        };
+        let mut options = default_options();
+        merge_options(&mut options, value.clone());
+        serde_json::from_value(options).map(|config| Self(Box::new(config))).unwrap_or_default()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (span, text) = match node.kind() {
+            AstKind::JSXText(jsx_text) => {
+                let value = jsx_text.value.as_str();
+                let text = value.trim();
+                let start = value.len() - value.trim_start().len();
+                #[expect(clippy::cast_possible_truncation)]
+                let span = Span::sized(jsx_text.span.start + start as u32, text.len() as u32);
+                (span, Cow::Borrowed(text))
+            }
+            AstKind::StringLiteral(lit) => (lit.span, Cow::Borrowed(lit.value.as_str())),
+            AstKind::TemplateLiteral(template) if self.should_validate_template => {
+                if matches!(
+                    ctx.nodes().parent_kind(node.id()),
+                    AstKind::TaggedTemplateExpression(_)
+                ) {
+                    return;
+                }
+                let text = template.quasis.iter().map(|quasi| quasi.value.raw.as_str()).collect();
+                (template.span, Cow::Owned(text))
+            }
+            _ => return,
+        };
+
+        let text = text.trim();
+        if text.is_empty() || self.words.exclude.is_match(text) || !self.is_checked(node, ctx) {
+            return;
+        }
+        let text = match node.kind() {
+            AstKind::TemplateLiteral(_) => span.shrink(1).source_text(ctx.source_text()),
+            _ => text,
+        };
+        ctx.diagnostic(no_literal_string_diagnostic(span, text, self.message.as_deref()));
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        !(self.ignore_test_files && is_jestlike_file(ctx.file_path()))
+    }
+}
+
+impl NoLiteralString {
+    /// Finds where the value of a string ends up, through e.g. `cond ? "foo" : "bar"` and
+    /// `"foo" + bar`, and whether strings there are checked.
+    fn is_checked<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+        let nodes = ctx.nodes();
+        let mut current = node;
+        loop {
+            let parent = nodes.parent_node(current.id());
+            match parent.kind() {
+                AstKind::ParenthesizedExpression(_)
+                | AstKind::LogicalExpression(_)
+                | AstKind::TemplateLiteral(_)
+                | AstKind::TSAsExpression(_)
+                | AstKind::TSSatisfiesExpression(_)
+                | AstKind::TSNonNullExpression(_) => {}
+                AstKind::ConditionalExpression(cond) if cond.test.span() != current.span() => {}
+                AstKind::BinaryExpression(binary)
+                    if binary.operator == BinaryOperator::Addition => {}
+                AstKind::JSXElement(_) | AstKind::JSXFragment(_) => {
+                    return self.is_jsx_child_checked(parent, ctx);
+                }
+                AstKind::JSXExpressionContainer(_) => {
+                    let grandparent = nodes.parent_node(parent.id());
+                    return match grandparent.kind() {
+                        AstKind::JSXAttribute(attr) => self.is_jsx_attribute_checked(attr),
+                        AstKind::JSXElement(_) | AstKind::JSXFragment(_) => {
+                            self.is_jsx_child_checked(grandparent, ctx)
+                        }
+                        _ => false,
+                    };
+                }
+                AstKind::JSXAttribute(attr) => return self.is_jsx_attribute_checked(attr),
+                AstKind::CallExpression(call) if call.callee.span() != current.span() => {
+                    return self.is_argument_checked(&call.callee);
+                }
+                AstKind::NewExpression(new_expr) if new_expr.callee.span() != current.span() => {
+                    return self.is_argument_checked(&new_expr.callee);
+                }
+                _ => return self.mode == Mode::All && !self.is_ignored(parent, current),
+            }
+            current = parent;
+        }
+    }
+
+    /// Checks the names of all of the enclosing components, so that e.g. the text of `<b>` in
+    /// `<Trans>Hello, <b>world</b></Trans>` is also ignored.
+    fn is_jsx_child_checked(&self, parent: &AstNode, ctx: &LintContext) -> bool {
+        let names = iter::once(parent.kind())
+            .chain(ctx.nodes().ancestor_kinds(parent.id()))
+            .filter_map(|kind| match kind {
+                AstKind::JSXElement(element) => Some(element.opening_element.name.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let names = names.iter().map(String::as_str).collect::<Vec<_>>();
+        self.jsx_components.is_checked(&names, true)
+    }
+
+    fn is_jsx_attribute_checked(&self, attr: &JSXAttribute) -> bool {
+        let name = get_jsx_attribute_name(&attr.name);
+        self.jsx_attributes.is_checked(&[&name], self.mode != Mode::JsxTextOnly)
+    }
+
+    fn is_argument_checked(&self, callee: &Expression) -> bool {
+        let name = get_node_name(callee);
+        let method_name = name.rsplit('.').next().unwrap_or_default();
+        self.callees.is_checked(&[&name, method_name], self.mode == Mode::All)
+    }
+
+    /// Whether a string in `parent` is never shown to users, like a module name or a key.
+    fn is_ignored(&self, parent: &AstNode, current: &AstNode) -> bool {
+        match parent.kind() {
+            AstKind::ImportDeclaration(_)
+            | AstKind::ImportExpression(_)
+            | AstKind::ImportSpecifier(_)
+            | AstKind::ImportAttribute(_)
+            | AstKind::ExportAllDeclaration(_)
+            | AstKind::ExportNamedDeclaration(_)
+            | AstKind::ExportSpecifier(_)
+            | AstKind::Directive(_)
+            | AstKind::TaggedTemplateExpression(_)
+            | AstKind::ComputedMemberExpression(_)
+            | AstKind::BinaryExpression(_)
+            | AstKind::SwitchCase(_)
+            | AstKind::MethodDefinition(_)
+            | AstKind::BindingProperty(_)
+            | AstKind::TSLiteralType(_)
+            | AstKind::TSEnumMember(_)
+            | AstKind::TSPropertySignature(_)
+            | AstKind::TSModuleDeclaration(_)
+            | AstKind::TSExternalModuleReference(_) => true,
+            AstKind::ObjectProperty(prop) => {
+                prop.key.span() == current.span()
+                    || prop
+                        .key
+                        .static_name()
+                        .is_some_and(|name| self.object_properties.exclude.is_match(&name))
+            }
+            AstKind::PropertyDefinition(prop) => {
+                prop.key.span() == current.span()
+                    || prop
+                        .key
+                        .static_name()
+                        .is_some_and(|name| self.class_properties.exclude.is_match(&name))
+            }
+            _ => false,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("const Greeting = () => <div>{t('greeting')}</div>;", None),
+        ("const Empty = () => <div>   </div>;", None),
+        ("const Numbers = () => <div>123 - 456</div>;", None),
+        ("const Constant = () => <div>FOO_BAR</div>;", None),
+        ("const Message = () => <Trans>Hello, <b>world</b>!</Trans>;", None),
+        ("const Link = () => <a className='link' href='/'>{t('home')}</a>;", None),
+        ("const Link = () => <a title='Home'>{t('home')}</a>;", None),
+        (
+            "const Button = () => <button type='submit' />;",
+            Some(serde_json::json!([{ "mode": "jsx-only" }])),
+        ),
+        ("alert('Something went wrong');", None),
+        ("const name = 'foo';", None),
+        (
+            "import foo from 'foo'; export * from 'bar';",
+            Some(serde_json::json!([{ "mode": "all" }])),
+        ),
+        ("'use strict';", Some(serde_json::json!([{ "mode": "all" }]))),
+        (
+            "console.log('Hello, world'); console.error('Something went wrong');",
+            Some(serde_json::json!([{ "mode": "all" }])),
+        ),
+        (
+            "i18n.t('Hello, world'); t('Hello') + t(`world`);",
+            Some(serde_json::json!([{ "mode": "all" }])),
+        ),
+        (
+            "const foo = require('foo'); document.getElementById('root');",
+            Some(serde_json::json!([{ "mode": "all" }])),
+        ),
+        (
+            "if (status === 'loading') {} switch (status) { case 'done': break; }",
+            Some(serde_json::json!([{ "mode": "all" }])),
+        ),
+        ("if ('key' in obj) {} obj['key'] = 1;", Some(serde_json::json!([{ "mode": "all" }]))),
+        ("const obj = { 'key': 1, FOO: 'bar' };", Some(serde_json::json!([{ "mode": "all" }]))),
+        ("class Foo { static displayName = 'Foo'; }", Some(serde_json::json!([{ "mode": "all" }]))),
+        (
+            "type Status = 'loading' | 'done'; enum Foo { Bar = 'bar' }",
+            Some(serde_json::json!([{ "mode": "all" }])),
+        ),
+        ("const Greeting = () => <div>{`Hello, ${name}`}</div>;", None),
+        (
+            "const style = css`color: red;`;",
+            Some(serde_json::json!([{ "mode": "all", "should-validate-template": true }])),
+        ),
+        (
+            "const Greeting = () => <div>Hello</div>;",
+            Some(serde_json::json!([{ "jsx-components": { "include": ["Text"] } }])),
+        ),
+        (
+            "log('Hello, world');",
+            Some(serde_json::json!([{ "mode": "all", "callees": { "exclude": ["log"] } }])),
+        ),
+        (
+            "const obj = { label: 'Hello' };",
+            Some(
+                serde_json::json!([{ "mode": "all", "object-properties": { "exclude": ["label"] } }]),
+            ),
+        ),
+        (
+            "const Greeting = () => <div>Hello</div>;",
+            Some(serde_json::json!([{ "words": { "exclude": ["Hello"] } }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("const Greeting = () => <div>Hello, world!</div>;", None),
+        ("const Link = () => <a href='/'>{'Home'}</a>;", None),
+        ("const Greeting = () => <>Hello</>;", None),
+        ("const Status = () => <div>{loading ? 'Loading' : t('done')}</div>;", None),
+        (
+            "const Link = () => <a title='Home' />;",
+            Some(serde_json::json!([{ "mode": "jsx-only" }])),
+        ),
+        (
+            "const Link = () => <a title={'Home'} />;",
+            Some(serde_json::json!([{ "jsx-attributes": { "include": ["title"] } }])),
+        ),
+        ("alert('Something went wrong');", Some(serde_json::json!([{ "mode": "all" }]))),
+        ("const message = 'Hello, ' + name;", Some(serde_json::json!([{ "mode": "all" }]))),
+        ("const obj = { label: 'Hello' };", Some(serde_json::json!([{ "mode": "all" }]))),
+        ("class Foo { title = 'Hello'; }", Some(serde_json::json!([{ "mode": "all" }]))),
+        (
+            "toast.show('Saved');",
+            Some(serde_json::json!([{ "callees": { "include": ["toast\\.show"] } }])),
+        ),
+        (
+            "log('Saved'); t('Saved');",
+            Some(serde_json::json!([{ "callees": { "include": ["log", "t"] } }])),
+        ),
+        (
+            "const Greeting = () => <div>{`Hello, ${name}`}</div>;",
+            Some(serde_json::json!([{ "should-validate-template": true }])),
+        ),
+        (
+            "const Greeting = () => <div>Hello</div>;",
+            Some(serde_json::json!([{ "message": "Translate this." }])),
+        ),
+        (
+            "const Greeting = () => <Text><b>Hello</b></Text>;",
+            Some(serde_json::json!([{ "jsx-components": { "include": ["Text"] } }])),
+        ),
+    ];
+
+    Tester::new(NoLiteralString::NAME, NoLiteralString::PLUGIN, pass, fail).test_and_snapshot();
+
+    let pass = vec![("const Greeting = () => <div>Hello, world!</div>;", None)];
+    let fail = vec![(
+        "const Greeting = () => <div>Hello, world!</div>;",
+        Some(serde_json::json!([{ "ignore-test-files": false }])),
+    )];
+
+    Tester::new(NoLiteralString::NAME, NoLiteralString::PLUGIN, pass, fail)
+        .change_rule_path("greeting.test.tsx")
+        .test();
+}
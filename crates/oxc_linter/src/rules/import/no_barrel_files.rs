@@ -0,0 +1,421 @@
+use std::{
+    path::{Component, Path},
+    sync::Arc,
+};
+
+use oxc_ast::ast::{ImportDeclaration, ImportDeclarationSpecifier, Statement};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use super::no_extraneous_dependencies::is_in_node_modules;
+use crate::{
+    ModuleRecord,
+    context::LintContext,
+    module_record::{ExportExportName, ExportImportName, ExportLocalName, ImportImportName},
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn barrel_file_diagnostic() -> OxcDiagnostic {
+    OxcDiagnostic::warn("Barrel files are not allowed")
+        .with_help("This file only re-exports other modules. Import from these modules instead.")
+        .with_label(Span::empty(0))
+}
+
+fn barrel_import_diagnostic(span: Span, specifier: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{specifier}` is a barrel file"))
+        .with_help("Import from the modules which declare the imported names instead.")
+        .with_label(span)
+}
+
+/// Barrel files re-exporting from other barrel files are followed up to this depth.
+const MAX_BARREL_DEPTH: usize = 8;
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoBarrelFiles(Box<NoBarrelFilesConfig>);
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoBarrelFilesConfig {
+    /// Report `index` files which only re-export other modules.
+    barrel_files: bool,
+    /// Report imports through a barrel file of the project, when the imported names can be
+    /// imported from the modules which declare them.
+    barrel_imports: bool,
+}
+
+impl Default for NoBarrelFilesConfig {
+    fn default() -> Self {
+        Self { barrel_files: true, barrel_imports: true }
+    }
+}
+
+impl std::ops::Deref for NoBarrelFiles {
+    type Target = NoBarrelFilesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports barrel files, i.e. `index` files which only re-export other modules, and imports
+    /// which go through a barrel file of the project.
+    ///
+    /// The fix rewrites an import through a barrel file to import from the modules which declare
+    /// the imported names, using the module graph. Imports of packages in `node_modules` are not
+    /// checked, and neither are namespace imports and side-effect imports.
+    ///
+    /// See also `oxc/no-barrel-file`, which only reports `export *` of large module trees.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Importing a single name from a barrel file loads all the modules it re-exports. This slows
+    /// down tests and development servers, can prevent bundlers from removing unused code, and
+    /// easily creates import cycles.
+    ///
+    /// ### Examples
+    ///
+    /// Given `utils/index.js`:
+    /// ```javascript
+    /// export { add } from "./add";
+    /// export * from "./format";
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// import { add, formatDate } from "./utils";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// import { add } from "./utils/add";
+    /// import { formatDate } from "./utils/format";
+    /// ```
+    NoBarrelFiles,
+    import,
+    restriction,
+    conditional_fix,
+    config = NoBarrelFilesConfig,
+);
+
+impl Rule for NoBarrelFiles {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoBarrelFiles>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let body = &ctx.nodes().program().body;
+        if self.barrel_files && is_index_file(ctx.file_path()) && is_barrel_program(body) {
+            ctx.diagnostic(barrel_file_diagnostic());
+        }
+        if self.barrel_imports {
+            for statement in body {
+                if let Statement::ImportDeclaration(decl) = statement {
+                    check_import(decl, ctx);
+                }
+            }
+        }
+    }
+}
+
+/// An import specifier rewritten to import from the module which declares the name.
+struct DirectImport {
+    module: Arc<ModuleRecord>,
+    /// The name exported by `module`, `default` for the default export.
+    imported: CompactStr,
+    local: CompactStr,
+    is_type: bool,
+}
+
+fn check_import(decl: &ImportDeclaration, ctx: &LintContext) {
+    let Some(specifiers) = &decl.specifiers else {
+        return;
+    };
+    let specifier = decl.source.value.as_str();
+    let Some(barrel) = ctx.module_record().get_loaded_module(specifier) else {
+        return;
+    };
+    if is_in_node_modules(&barrel.resolved_absolute_path) || !is_barrel_record(&barrel) {
+        return;
+    }
+
+    let mut direct_imports = vec![];
+    for import in specifiers {
+        let (imported, local, is_type) = match import {
+            ImportDeclarationSpecifier::ImportSpecifier(import) => (
+                CompactStr::from(import.imported.name().as_str()),
+                &import.local.name,
+                import.import_kind.is_type(),
+            ),
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(import) => {
+                (CompactStr::from("default"), &import.local.name, false)
+            }
+            ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => return,
+        };
+        let direct_import = declaring_module(&barrel, &imported, 0).map(|(module, imported)| {
+            DirectImport { module, imported, local: CompactStr::from(local.as_str()), is_type }
+        });
+        direct_imports.push(direct_import);
+    }
+    if direct_imports.iter().all(Option::is_none) {
+        return;
+    }
+
+    let diagnostic = barrel_import_diagnostic(decl.source.span, specifier);
+    let direct_imports = direct_imports.into_iter().collect::<Option<Vec<_>>>();
+    let Some(text) = direct_imports
+        .filter(|_| decl.with_clause.is_none())
+        .and_then(|direct_imports| rewrite_import(decl, &direct_imports, ctx))
+    else {
+        ctx.diagnostic(diagnostic);
+        return;
+    };
+    ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(decl.span, text));
+}
+
+/// The module which declares the export `name` of `barrel`, and the name it exports it with,
+/// following re-exports through other barrel files.
+fn declaring_module(
+    barrel: &ModuleRecord,
+    name: &str,
+    depth: usize,
+) -> Option<(Arc<ModuleRecord>, CompactStr)> {
+    if depth > MAX_BARREL_DEPTH {
+        return None;
+    }
+    let (module, imported) = barrel
+        .indirect_export_entries
+        .iter()
+        .find(|entry| export_name(&entry.export_name) == Some(name))
+        .and_then(|entry| {
+            let ExportImportName::Name(imported) = &entry.import_name else {
+                return None;
+            };
+            let module = barrel.get_loaded_module(entry.module_request.as_ref()?.name())?;
+            Some((module, imported.name.clone()))
+        })
+        .or_else(|| {
+            // `export *` doesn't re-export the default export.
+            if name == "default" {
+                return None;
+            }
+            barrel.star_export_entries.iter().find_map(|entry| {
+                let module = barrel.get_loaded_module(entry.module_request.as_ref()?.name())?;
+                module.exports_name(name).then(|| (module, CompactStr::from(name)))
+            })
+        })?;
+    if is_barrel_record(&module) {
+        return declaring_module(&module, &imported, depth + 1);
+    }
+    Some((module, imported))
+}
+
+fn export_name(name: &ExportExportName) -> Option<&str> {
+    match name {
+        ExportExportName::Name(name) => Some(name.name()),
+        ExportExportName::Default(_) => Some("default"),
+        ExportExportName::Null => None,
+    }
+}
+
+/// The import declarations importing `direct_imports` from the modules which declare them, in
+/// place of `decl`.
+fn rewrite_import(
+    decl: &ImportDeclaration,
+    direct_imports: &[DirectImport],
+    ctx: &LintContext,
+) -> Option<String> {
+    let dir = ctx.file_path().parent()?;
+    let source = ctx.source_range(decl.source.span);
+    let quote = &source[..1];
+    let with_extension = Path::new(decl.source.value.as_str()).extension().is_some();
+    let semicolon = if ctx.source_range(decl.span).ends_with(';') { ";" } else { "" };
+    let import_kind = if decl.import_kind.is_type() { "import type" } else { "import" };
+
+    let mut modules: Vec<&Arc<ModuleRecord>> = vec![];
+    for direct_import in direct_imports {
+        if !modules.iter().any(|module| Arc::ptr_eq(module, &direct_import.module)) {
+            modules.push(&direct_import.module);
+        }
+    }
+    let mut declarations = vec![];
+    for module in modules {
+        let imports = direct_imports
+            .iter()
+            .filter(|direct_import| Arc::ptr_eq(&direct_import.module, module));
+        let (defaults, named): (Vec<_>, Vec<_>) = imports.partition(|direct_import| {
+            direct_import.imported == "default" && !direct_import.is_type
+        });
+        if defaults.len() > 1 {
+            return None;
+        }
+        let named = named
+            .iter()
+            .map(|direct_import| {
+                let kind = if direct_import.is_type { "type " } else { "" };
+                if direct_import.imported == direct_import.local {
+                    format!("{kind}{}", direct_import.local)
+                } else {
+                    format!("{kind}{} as {}", direct_import.imported, direct_import.local)
+                }
+            })
+            .collect::<Vec<_>>();
+        let clause = match (defaults.first(), named.is_empty()) {
+            (Some(default), true) => default.local.to_string(),
+            (Some(default), false) => format!("{}, {{ {} }}", default.local, named.join(", ")),
+            (None, _) => format!("{{ {} }}", named.join(", ")),
+        };
+        let specifier = relative_specifier(dir, &module.resolved_absolute_path, with_extension)?;
+        declarations
+            .push(format!("{import_kind} {clause} from {quote}{specifier}{quote}{semicolon}"));
+    }
+    Some(declarations.join("\n"))
+}
+
+/// The relative specifier of the module at `path` from the directory `dir`.
+fn relative_specifier(dir: &Path, path: &Path, with_extension: bool) -> Option<String> {
+    let path = if with_extension { path.to_path_buf() } else { path.with_extension("") };
+    let dir = dir.components().collect::<Vec<_>>();
+    let path = path.components().collect::<Vec<_>>();
+    let common = dir.iter().zip(&path).take_while(|(a, b)| a == b).count();
+    let mut segments = vec![];
+    if common == dir.len() {
+        segments.push(".".to_string());
+    }
+    segments.extend(dir[common..].iter().map(|_| "..".to_string()));
+    for component in &path[common..] {
+        let Component::Normal(segment) = component else {
+            return None;
+        };
+        segments.push(segment.to_str()?.to_string());
+    }
+    Some(segments.join("/"))
+}
+
+fn is_index_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.split('.').next() == Some("index") && !name.ends_with(".d.ts"))
+}
+
+/// Whether the program only contains imports and re-exports.
+fn is_barrel_program(body: &[Statement]) -> bool {
+    body.iter().any(|statement| {
+        matches!(
+            statement,
+            Statement::ExportNamedDeclaration(_) | Statement::ExportAllDeclaration(_)
+        )
+    }) && body.iter().all(|statement| match statement {
+        Statement::ImportDeclaration(_) | Statement::ExportAllDeclaration(_) => true,
+        Statement::ExportNamedDeclaration(decl) => decl.declaration.is_none(),
+        _ => false,
+    })
+}
+
+/// Whether the module is an `index` file which only re-exports other modules. A namespace import
+/// exported as is stays a local export. Statements which aren't exported are not known from the
+/// module record, so they aren't taken into account.
+fn is_barrel_record(module: &ModuleRecord) -> bool {
+    is_index_file(&module.resolved_absolute_path)
+        && (!module.indirect_export_entries.is_empty() || !module.star_export_entries.is_empty())
+        && module.local_export_entries.iter().all(|entry| match &entry.local_name {
+            ExportLocalName::Name(name) | ExportLocalName::Default(name) => {
+                module.import_entries.iter().any(|import| {
+                    import.local_name.name == name.name
+                        && matches!(import.import_name, ImportImportName::NamespaceObject)
+                })
+            }
+            ExportLocalName::Null => false,
+        })
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"import { foo } from "./barrel/foo""#, None),
+        (r#"import { mixed, foo } from "./barrel/mixed""#, None),
+        (r#"import * as barrel from "./barrel""#, None),
+        (r#"import "./barrel""#, None),
+        (r#"import React from "react""#, None),
+        (r#"import { foo } from "./barrel""#, Some(json!([{ "barrelImports": false }]))),
+        (r#"export { foo } from "./barrel/foo""#, None),
+    ];
+
+    let fail = vec![
+        (r#"import { foo } from "./barrel""#, None),
+        (r#"import { baz, qux } from "./barrel/index""#, None),
+        (r#"import { deep } from "./barrel""#, None),
+        (r#"import { deep } from "./barrel/nested""#, None),
+        (r#"import { foo, notExported } from "./barrel""#, None),
+        (r#"import * as ns from "./barrel/foo"; import { foo } from "./barrel";"#, None),
+    ];
+
+    let fix = vec![
+        (r#"import { foo } from "./barrel";"#, r#"import { foo } from "./barrel/foo";"#, None),
+        (
+            "import { foo as f, baz, qux } from './barrel'",
+            "import { foo as f } from './barrel/foo'\nimport { bar as baz } from './barrel/bar'\nimport { qux } from './barrel/qux'",
+            None,
+        ),
+        (
+            r#"import { Def, local } from "./barrel";"#,
+            "import Def from \"./barrel/def\";\nimport { local } from \"./barrel/local\";",
+            None,
+        ),
+        (
+            r#"import { deep } from "./barrel/index.ts";"#,
+            r#"import { deep } from "./barrel/nested/deep.ts";"#,
+            None,
+        ),
+        (
+            r#"import type { Qux } from "./barrel";"#,
+            r#"import type { Qux } from "./barrel/qux";"#,
+            None,
+        ),
+        (
+            r#"import { type Qux, qux } from "./barrel";"#,
+            r#"import { type Qux, qux } from "./barrel/qux";"#,
+            None,
+        ),
+        (
+            r#"import { foo, notExported } from "./barrel";"#,
+            r#"import { foo, notExported } from "./barrel";"#,
+            None,
+        ),
+    ];
+
+    Tester::new(NoBarrelFiles::NAME, NoBarrelFiles::PLUGIN, pass, fail)
+        .change_rule_path("barrel-consumer.ts")
+        .with_import_plugin(true)
+        .expect_fix(fix)
+        .test_and_snapshot();
+
+    let pass = vec![
+        (r#"export { foo } from "../foo"; export const mixed = 7;"#, None),
+        (r#"export * from "./foo"; console.log("side effect");"#, None),
+        (r#"export * from "./foo";"#, Some(json!([{ "barrelFiles": false }]))),
+    ];
+
+    let fail = vec![
+        (r#"export * from "./foo";"#, None),
+        (r#"import { foo } from "./foo"; export { foo }; export type { Qux } from "./qux";"#, None),
+    ];
+
+    Tester::new(NoBarrelFiles::NAME, NoBarrelFiles::PLUGIN, pass, fail)
+        .change_rule_path("barrel/index.ts")
+        .with_import_plugin(true)
+        .with_snapshot_suffix("barrel-files")
+        .intentionally_allow_no_fix_tests()
+        .test_and_snapshot();
+}
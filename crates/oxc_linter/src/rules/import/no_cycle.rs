@@ -1,17 +1,21 @@
-use std::{ffi::OsStr, path::Component, sync::Arc};
+use std::{
+    ffi::OsStr,
+    path::{Component, Path},
+    sync::Arc,
+};
 
 use cow_utils::CowUtils;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactStr, Span};
 use schemars::JsonSchema;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer, de::Error};
 
 use crate::{
     ModuleRecord,
     context::LintContext,
     module_graph_visitor::{ModuleGraphVisitorBuilder, ModuleGraphVisitorEvent, VisitFoldWhile},
-    rule::{DefaultRuleConfig, Rule},
+    rule::Rule,
 };
 
 fn no_cycle_diagnostic(span: Span, paths: &str) -> OxcDiagnostic {
@@ -24,7 +28,9 @@ fn no_cycle_diagnostic(span: Span, paths: &str) -> OxcDiagnostic {
 #[derive(Debug, Clone, JsonSchema, Deserialize)]
 #[serde(rename_all = "camelCase", default)]
 pub struct NoCycle {
-    /// Maximum dependency depth to traverse
+    /// Maximum dependency depth to traverse. `"∞"` or `null` for no maximum depth.
+    #[serde(deserialize_with = "deserialize_max_depth")]
+    #[schemars(with = "Option<MaxDepth>")]
     max_depth: u32,
     /// Ignore type-only imports
     ignore_types: bool,
@@ -34,6 +40,24 @@ pub struct NoCycle {
     allow_unsafe_dynamic_cyclic_dependency: bool,
 }
 
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum MaxDepth {
+    Depth(u32),
+    /// `"∞"`
+    Infinity(String),
+}
+
+fn deserialize_max_depth<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    match Option::<MaxDepth>::deserialize(deserializer)? {
+        Some(MaxDepth::Depth(depth)) => Ok(depth),
+        Some(MaxDepth::Infinity(infinity)) if infinity != "∞" => Err(D::Error::custom(format!(
+            "invalid maxDepth `{infinity}`, expected a number or `\"∞\"`"
+        ))),
+        Some(MaxDepth::Infinity(_)) | None => Ok(u32::MAX),
+    }
+}
+
 impl Default for NoCycle {
     fn default() -> Self {
         Self {
@@ -95,7 +119,13 @@ declare_oxc_lint!(
 
 impl Rule for NoCycle {
     fn from_configuration(value: serde_json::Value) -> Self {
-        serde_json::from_value::<DefaultRuleConfig<NoCycle>>(value).unwrap_or_default().into_inner()
+        Self::try_from_configuration(value).unwrap_or_default()
+    }
+
+    fn try_from_configuration(value: serde_json::Value) -> Result<Self, String> {
+        // `DefaultRuleConfig` falls back to the defaults when the options are invalid.
+        let Some(options) = value.get(0) else { return Ok(Self::default()) };
+        Self::deserialize(options).map_err(|error| error.to_string())
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
@@ -182,19 +212,21 @@ impl Rule for NoCycle {
 
         if visitor_result.result {
             let span = module_record.requested_modules[&stack[0].0][0].span;
-            let help = stack
-                .iter()
-                .map(|(specifier, path)| {
-                    format!(
-                        "-> {specifier} - {}",
-                        path.strip_prefix(&cwd)
-                            .unwrap_or(path)
-                            .to_string_lossy()
-                            .cow_replace('\\', "/")
-                    )
-                })
-                .collect::<Vec<_>>()
-                .join("\n");
+            let relative_path = |path: &Path| {
+                path.strip_prefix(&cwd)
+                    .unwrap_or(path)
+                    .to_string_lossy()
+                    .cow_replace('\\', "/")
+                    .into_owned()
+            };
+            // Start at the linted module, so the path ends where it starts.
+            let help =
+                std::iter::once(relative_path(needle))
+                    .chain(stack.iter().map(|(specifier, path)| {
+                        format!("-> {specifier} - {}", relative_path(path))
+                    }))
+                    .collect::<Vec<_>>()
+                    .join("\n");
             ctx.diagnostic(no_cycle_diagnostic(span, &help));
         }
     }
@@ -379,6 +411,14 @@ fn test() {
             r#"import { foo } from "./typescript/ts-types-re-exporting-type";"#,
             Some(json!([{"ignoreTypes":false}])),
         ),
+        (
+            r#"import { foo } from "./typescript/ts-types-re-exporting-type";"#,
+            Some(json!([{"ignoreTypes":false,"maxDepth":"∞"}])),
+        ),
+        (
+            r#"import { foo } from "./typescript/ts-types-re-exporting-type";"#,
+            Some(json!([{"ignoreTypes":false,"maxDepth":null}])),
+        ),
         (r"export function Foo() {}; export * from './depth-zero'", None),
     ];
 
@@ -387,3 +427,13 @@ fn test() {
         .with_import_plugin(true)
         .test_and_snapshot();
 }
+
+#[test]
+fn test_invalid_max_depth() {
+    use serde_json::json;
+
+    let error = NoCycle::try_from_configuration(json!([{ "maxDepth": "infinity" }])).unwrap_err();
+    assert!(error.starts_with("invalid maxDepth `infinity`"), "{error}");
+    assert!(NoCycle::try_from_configuration(json!([{ "maxDepth": "∞" }])).is_ok());
+    assert!(NoCycle::try_from_configuration(serde_json::Value::Null).is_ok());
+}
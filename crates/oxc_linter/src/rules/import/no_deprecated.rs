@@ -0,0 +1,322 @@
+use std::sync::Arc;
+
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use rustc_hash::FxHashSet;
+
+use crate::{
+    context::LintContext,
+    module_record::{ExportExportName, ExportImportName, ImportImportName, ModuleRecord},
+    rule::Rule,
+};
+
+fn deprecated_module_diagnostic(span: Span, module_name: &str, message: &str) -> OxcDiagnostic {
+    let warning = if message.is_empty() {
+        format!("Module {module_name:?} is deprecated.")
+    } else {
+        format!("Module {module_name:?} is deprecated: {message}")
+    };
+    OxcDiagnostic::warn(warning).with_label(span)
+}
+
+fn deprecated_diagnostic(span: Span, name: &str, message: &str) -> OxcDiagnostic {
+    let warning = if message.is_empty() {
+        format!("`{name}` is deprecated.")
+    } else {
+        format!("`{name}` is deprecated: {message}")
+    };
+    OxcDiagnostic::warn(warning).with_label(span)
+}
+
+// <https://github.com/import-js/eslint-plugin-import/blob/v2.29.1/docs/rules/no-deprecated.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoDeprecated;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports the use of imported names marked as deprecated by a `@deprecated` tag in the
+    /// JSDoc of their declaration, and the import of modules marked as deprecated by a
+    /// `@deprecated` tag in the JSDoc with their `@module` tag.
+    ///
+    /// Re-exports are followed through the module graph, so that the deprecations of names
+    /// re-exported by a barrel file or a package are reported. In TypeScript files, imports of
+    /// JavaScript modules with a declaration file next to them, e.g. `index.d.ts` for
+    /// `index.js`, are resolved to the declaration file, as TypeScript does.
+    ///
+    /// Unlike `typescript/no-deprecated`, this rule doesn't need type information, but only
+    /// checks imported names and the members of imported namespaces.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deprecated code may be removed in a later version, or may have better alternatives.
+    /// The deprecation message usually tells what to use instead.
+    ///
+    /// ### Examples
+    ///
+    /// Given
+    /// ```javascript
+    /// // ./answer.js
+    /// /**
+    ///  * @deprecated Use `answer` instead.
+    ///  */
+    /// export const ANSWER = 42;
+    /// export const answer = 42;
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// import { ANSWER } from './answer';
+    ///
+    /// import * as answers from './answer';
+    /// console.log(answers.ANSWER);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// import { answer } from './answer';
+    ///
+    /// import * as answers from './answer';
+    /// console.log(answers.answer);
+    /// ```
+    NoDeprecated,
+    import,
+    pedantic
+);
+
+impl Rule for NoDeprecated {
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+        let mut deprecated_modules = FxHashSet::default();
+
+        for entry in &module_record.import_entries {
+            let module_name = entry.module_request.name();
+            let Some(remote_module_record) = module_record.get_loaded_module(module_name) else {
+                continue;
+            };
+
+            if let Some(message) = &remote_module_record.deprecation
+                && deprecated_modules.insert(entry.statement_span)
+            {
+                ctx.diagnostic(deprecated_module_diagnostic(
+                    entry.module_request.span,
+                    module_name,
+                    message,
+                ));
+            }
+
+            let export = match &entry.import_name {
+                ImportImportName::Name(name) => {
+                    resolve_export(&remote_module_record, name.name(), 0)
+                }
+                ImportImportName::Default(_) => resolve_export(&remote_module_record, "default", 0),
+                ImportImportName::NamespaceObject => {
+                    Some(Export::Namespace(Arc::clone(&remote_module_record)))
+                }
+            };
+            let Some(export) = export else {
+                continue;
+            };
+
+            let local_name = entry.local_name.name();
+            if let Export::Deprecated(message) = &export {
+                ctx.diagnostic(deprecated_diagnostic(entry.local_name.span, local_name, message));
+            }
+            let Some(symbol_id) = ctx.scoping().get_root_binding(local_name) else {
+                continue;
+            };
+            for reference in ctx.scoping().get_resolved_references(symbol_id) {
+                match &export {
+                    Export::Deprecated(message) => {
+                        let span = ctx.nodes().get_node(reference.node_id()).span();
+                        ctx.diagnostic(deprecated_diagnostic(span, local_name, message));
+                    }
+                    Export::Namespace(namespace) => {
+                        check_namespace_member(reference.node_id(), namespace, ctx);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// What an imported name resolves to, if it matters to this rule.
+enum Export {
+    /// A deprecated export, with its deprecation message.
+    Deprecated(CompactStr),
+    /// A namespace object of a module.
+    Namespace(Arc<ModuleRecord>),
+}
+
+/// How many re-exports are followed, which also stops cycles.
+const MAX_REEXPORT_DEPTH: usize = 16;
+
+/// Follows the export `name` of `module_record` through re-exports, to its deprecation or to the
+/// namespace object it is.
+fn resolve_export(module_record: &ModuleRecord, name: &str, depth: usize) -> Option<Export> {
+    if depth > MAX_REEXPORT_DEPTH {
+        return None;
+    }
+    if let Some(message) = module_record.deprecated_exports.get(name) {
+        return Some(Export::Deprecated(message.clone()));
+    }
+
+    let is_export_name = |export_name: &ExportExportName| match export_name {
+        ExportExportName::Name(export_name) => export_name.name() == name,
+        ExportExportName::Default(_) => name == "default",
+        ExportExportName::Null => false,
+    };
+
+    // `import { a } from './a'; export { a };`
+    if let Some(local_name) = module_record
+        .local_export_entries
+        .iter()
+        .find(|entry| is_export_name(&entry.export_name))
+        .and_then(|entry| entry.local_name.name())
+    {
+        let import_entry = module_record
+            .import_entries
+            .iter()
+            .find(|entry| entry.local_name.name() == local_name)?;
+        let remote_module_record =
+            module_record.get_loaded_module(import_entry.module_request.name())?;
+        return match &import_entry.import_name {
+            ImportImportName::Name(name) => {
+                resolve_export(&remote_module_record, name.name(), depth + 1)
+            }
+            ImportImportName::Default(_) => {
+                resolve_export(&remote_module_record, "default", depth + 1)
+            }
+            ImportImportName::NamespaceObject => Some(Export::Namespace(remote_module_record)),
+        };
+    }
+
+    // `export { a } from './a';`, `export * as a from './a';`
+    if let Some(entry) = module_record
+        .indirect_export_entries
+        .iter()
+        .find(|entry| is_export_name(&entry.export_name))
+    {
+        let remote_module_record =
+            module_record.get_loaded_module(entry.module_request.as_ref()?.name())?;
+        return match &entry.import_name {
+            ExportImportName::Name(import_name) => {
+                let import_name = import_name.name();
+                // `import * as a from './a'; export { a };`
+                if module_record.import_entries.iter().any(|entry| {
+                    entry.local_name.name() == import_name
+                        && entry.import_name.is_namespace_object()
+                }) {
+                    return Some(Export::Namespace(remote_module_record));
+                }
+                resolve_export(&remote_module_record, import_name, depth + 1)
+            }
+            ExportImportName::All => Some(Export::Namespace(remote_module_record)),
+            ExportImportName::AllButDefault | ExportImportName::Null => None,
+        };
+    }
+
+    // `export * from './a';`
+    if name == "default" {
+        return None;
+    }
+    module_record.star_export_entries.iter().find_map(|entry| {
+        let remote_module_record =
+            module_record.get_loaded_module(entry.module_request.as_ref()?.name())?;
+        resolve_export(&remote_module_record, name, depth + 1)
+    })
+}
+
+/// Checks the member accessed on a reference to a namespace object, e.g. `b` in `a.b` or
+/// `a.b.c`, where `a` is the namespace object of a module.
+fn check_namespace_member(
+    node_id: oxc_semantic::NodeId,
+    namespace: &ModuleRecord,
+    ctx: &LintContext<'_>,
+) {
+    let parent = ctx.nodes().parent_node(node_id);
+    let (span, name) = match parent.kind() {
+        AstKind::StaticMemberExpression(member) => member.static_property_info(),
+        AstKind::ComputedMemberExpression(member) => {
+            let Some(info) = member.static_property_info() else {
+                return;
+            };
+            info
+        }
+        AstKind::TSQualifiedName(name) => (name.right.span, name.right.name.as_str()),
+        AstKind::JSXMemberExpression(member) => {
+            (member.property.span, member.property.name.as_str())
+        }
+        _ => return,
+    };
+    match resolve_export(namespace, name, 0) {
+        Some(Export::Deprecated(message)) => {
+            ctx.diagnostic(deprecated_diagnostic(span, name, &message));
+        }
+        Some(Export::Namespace(namespace)) => {
+            check_namespace_member(parent.id(), &namespace, ctx);
+        }
+        None => {}
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "import { x } from './fake'",
+        "import bar from './bar'",
+        "import { fine } from './deprecated'",
+        "import { _undocumented } from './deprecated'",
+        "import * as depd from './deprecated'",
+        "import * as depd from './deprecated'; console.log(depd.fine())",
+        "import { deepDep } from './deep-deprecated'",
+        "import { deepDep } from './deep-deprecated'; console.log(deepDep.fine())",
+        "import * as depd from './deprecated'; function f(depd) { console.log(depd.MY_TERRIBLE_ACTION) }",
+        "export { fn } from './deprecated'",
+    ];
+
+    let fail = vec![
+        "import { fn } from './deprecated'",
+        "import TerribleClass from './deprecated'",
+        "import { MY_TERRIBLE_ACTION } from './deprecated'",
+        "import { CHAIN_A, CHAIN_B, CHAIN_C } from './deprecated'",
+        "import { fn as oldFn } from './deprecated'; oldFn(); oldFn();",
+        "import { MY_TERRIBLE_ACTION, fine } from './deprecated'; console.log(MY_TERRIBLE_ACTION, fine)",
+        "import * as depd from './deprecated'; console.log(depd.MY_TERRIBLE_ACTION)",
+        "import * as depd from './deprecated'; console.log(depd['fn']())",
+        "import { deepDep } from './deep-deprecated'; console.log(deepDep.MY_TERRIBLE_ACTION)",
+        "import * as deep from './deep-deprecated'; console.log(deep.deepDep.fn())",
+        "import Thing from './deprecated-file'",
+        "import Thing, { Thing as Other } from './deprecated-file'",
+    ];
+
+    Tester::new(NoDeprecated::NAME, NoDeprecated::PLUGIN, pass, fail)
+        .change_rule_path("index.js")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+
+    let pass = vec![
+        "import { current } from 'deprecated-types'",
+        "import { current } from './deprecated-types-barrel'",
+        "import type { Options } from 'deprecated-types'",
+    ];
+
+    let fail = vec![
+        "import { legacy } from 'deprecated-types'; legacy();",
+        "import { legacy } from './deprecated-types-barrel'; legacy();",
+        "import { oldFn } from './deprecated-types-barrel'",
+        "import { fn } from './deprecated-types-barrel'",
+        "import type { LegacyOptions } from 'deprecated-types'; let options: LegacyOptions;",
+        "import * as types from 'deprecated-types'; let options: types.LegacyOptions;",
+    ];
+
+    Tester::new(NoDeprecated::NAME, NoDeprecated::PLUGIN, pass, fail)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .with_snapshot_suffix("typescript")
+        .test_and_snapshot();
+}
@@ -1,15 +1,17 @@
 use std::borrow::Cow;
 
 use itertools::Itertools;
+use oxc_ast::ast::{ImportDeclaration, ImportDeclarationSpecifier, Statement};
 use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
-use rustc_hash::FxHashMap;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::{
     context::LintContext,
+    fixer::{RuleFix, RuleFixer},
     module_record::{ImportImportName, RequestedModule},
     rule::{DefaultRuleConfig, Rule},
 };
@@ -64,6 +66,9 @@ declare_oxc_lint!(
     /// unnecessary complexity. It also affects maintainability, as it might
     /// confuse developers and result in inconsistent usage of imports across the code.
     ///
+    /// The fix merges the imports into the first one, when they only import names and
+    /// no comments are inside of them.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -88,6 +93,7 @@ declare_oxc_lint!(
     NoDuplicates,
     import,
     style,
+    conditional_fix,
     config = NoDuplicates,
 );
 
@@ -100,6 +106,17 @@ impl Rule for NoDuplicates {
 
     fn run_once(&self, ctx: &LintContext<'_>) {
         let module_record = ctx.module_record();
+        // the top-level import declarations by the span of their source, which can be merged
+        let declarations = ctx
+            .nodes()
+            .program()
+            .body
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::ImportDeclaration(decl) => Some((decl.source.span, &**decl)),
+                _ => None,
+            })
+            .collect::<FxHashMap<_, _>>();
 
         let groups = module_record
             .requested_modules
@@ -155,21 +172,122 @@ impl Rule for NoDuplicates {
             }
 
             for i in 0..4 {
-                check_duplicates(ctx, import_entries_maps.get(&i));
+                check_duplicates(ctx, import_entries_maps.get(&i), &declarations);
             }
         }
     }
 }
 
-fn check_duplicates(ctx: &LintContext, requested_modules: Option<&Vec<&RequestedModule>>) {
+fn check_duplicates<'a>(
+    ctx: &LintContext<'a>,
+    requested_modules: Option<&Vec<&RequestedModule>>,
+    declarations: &FxHashMap<Span, &ImportDeclaration<'a>>,
+) {
     if let Some(requested_modules) = requested_modules
         && requested_modules.len() > 1
     {
         let mut labels = requested_modules.iter().map(|m| m.span);
         let first = labels.next().unwrap(); // we know there is at least one
         let module_name = ctx.source_range(first).trim_matches('\'').trim_matches('"');
-        ctx.diagnostic(no_duplicates_diagnostic(module_name, first, labels));
+        let diagnostic = no_duplicates_diagnostic(module_name, first, labels);
+
+        let mut duplicates = requested_modules
+            .iter()
+            .map(|module| declarations.get(&module.span).copied())
+            .collect::<Option<Vec<_>>>();
+        if let Some(duplicates) = &mut duplicates {
+            duplicates.sort_unstable_by_key(|decl| decl.span.start);
+            duplicates.dedup_by_key(|decl| decl.span);
+        }
+        match duplicates.filter(|duplicates| can_merge(duplicates, ctx)) {
+            Some(duplicates) => {
+                ctx.diagnostic_with_fix(diagnostic, |fixer| merge_fix(fixer, &duplicates, ctx));
+            }
+            None => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+/// Whether the declarations only import names, with at most one default import, and have
+/// no comments inside of them which would be lost.
+fn can_merge(duplicates: &[&ImportDeclaration], ctx: &LintContext) -> bool {
+    let is_type = duplicates.iter().all(|decl| decl.import_kind.is_type());
+    let mut defaults = 0;
+    for decl in duplicates {
+        if decl.phase.is_some()
+            || decl.with_clause.is_some()
+            || ctx.semantic().has_comments_between(decl.span)
+        {
+            return false;
+        }
+        for specifier in decl.specifiers.iter().flatten() {
+            match specifier {
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => return false,
+                // a default type import can't be inlined into a value import
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(_)
+                    if decl.import_kind.is_type() && !is_type =>
+                {
+                    return false;
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => defaults += 1,
+                ImportDeclarationSpecifier::ImportSpecifier(_) => {}
+            }
+        }
+    }
+    defaults <= 1
+}
+
+/// Replaces the first declaration with one importing the names of all declarations,
+/// and removes the others.
+fn merge_fix<'a>(
+    fixer: RuleFixer<'_, 'a>,
+    duplicates: &[&ImportDeclaration<'a>],
+    ctx: &LintContext<'a>,
+) -> RuleFix {
+    let first = duplicates[0];
+    let is_type = duplicates.iter().all(|decl| decl.import_kind.is_type());
+
+    let mut default = None;
+    let mut names = vec![];
+    let mut locals = FxHashSet::default();
+    for decl in duplicates {
+        for specifier in decl.specifiers.iter().flatten() {
+            if !locals.insert(specifier.local().name.as_str()) {
+                continue;
+            }
+            let text = ctx.source_range(specifier.span());
+            match specifier {
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => default = Some(text),
+                ImportDeclarationSpecifier::ImportSpecifier(_)
+                    if decl.import_kind.is_type() && !is_type =>
+                {
+                    names.push(format!("type {text}"));
+                }
+                _ => names.push(text.to_string()),
+            }
+        }
+    }
+
+    let fixer = fixer.for_multifix();
+    let mut fix = fixer.new_fix_with_capacity(duplicates.len());
+    if default.is_some() || !names.is_empty() {
+        let names =
+            if names.is_empty() { None } else { Some(format!("{{ {} }}", names.join(", "))) };
+        let clause = default.map(ToString::to_string).into_iter().chain(names).join(", ");
+        let kind = if is_type { "type " } else { "" };
+        let source = ctx.source_range(first.source.span);
+        let semicolon = if ctx.source_range(first.span).ends_with(';') { ";" } else { "" };
+        fix.push(
+            fixer.replace(first.span, format!("import {kind}{clause} from {source}{semicolon}")),
+        );
     }
+    for decl in &duplicates[1..] {
+        // remove the whitespace before the declaration as well, e.g. its line break
+        let start = ctx.source_text()[..decl.span.start as usize].trim_end().len();
+        #[expect(clippy::cast_possible_truncation)]
+        fix.push(fixer.delete_range(Span::new(start as u32, decl.span.end)));
+    }
+    fix.with_message("Merge these imports into a single import statement")
 }
 
 #[test]
@@ -424,8 +542,48 @@ fn test() {
         ),
     ];
 
+    let fix = vec![
+        (
+            r"import { x } from './foo'; import { y } from './foo'",
+            r"import { x, y } from './foo';",
+            None,
+        ),
+        (
+            "import x from './foo';\nimport { y } from './foo';\nimport { z, y as w } from './foo';\nfoo();",
+            "import x, { y, z, y as w } from './foo';\nfoo();",
+            None,
+        ),
+        (
+            "import { x } from './foo'\nimport { y } from './foo'\n",
+            "import { x, y } from './foo'\n",
+            None,
+        ),
+        (
+            r"import type {x} from './foo'; import type {y} from './foo'",
+            r"import type { x, y } from './foo';",
+            None,
+        ),
+        (
+            r"import {AValue} from './foo'; import type {AType} from './foo'",
+            r"import { AValue, type AType } from './foo';",
+            Some(json!([{ "prefer-inline": true }])),
+        ),
+        // not fixed
+        (
+            r"import x from './foo'; import y from './foo'",
+            r"import x from './foo'; import y from './foo'",
+            None,
+        ),
+        (
+            r"import { x } from './foo'; /* y */ import { /* y */ y } from './foo'",
+            r"import { x } from './foo'; /* y */ import { /* y */ y } from './foo'",
+            None,
+        ),
+    ];
+
     Tester::new(NoDuplicates::NAME, NoDuplicates::PLUGIN, pass, fail)
         .change_rule_path("index.ts")
         .with_import_plugin(true)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
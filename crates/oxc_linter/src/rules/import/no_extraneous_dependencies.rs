@@ -0,0 +1,320 @@
+use std::{
+    ffi::OsStr,
+    path::{Component, Path},
+};
+
+use cow_utils::CowUtils;
+use fast_glob::glob_match;
+use nodejs_built_in_modules::is_nodejs_builtin_module;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    package_json::PackageJson,
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn missing_dependency_diagnostic(span: Span, package_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{package_name}` should be listed in the project's dependencies"))
+        .with_help(format!("Run `npm i -S {package_name}` to add it."))
+        .with_label(span)
+}
+
+fn wrong_dependency_diagnostic(
+    span: Span,
+    package_name: &str,
+    dependency_kind: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{package_name}` should be listed in the project's dependencies, not {dependency_kind}"
+    ))
+    .with_help(format!("Move `{package_name}` to the dependencies of the `package.json`."))
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoExtraneousDependencies(Box<NoExtraneousDependenciesConfig>);
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoExtraneousDependenciesConfig {
+    /// Whether `devDependencies` can be imported. Either a boolean, or glob patterns, relative to
+    /// the working directory, of the files which can import them, e.g. `["**/*.test.js"]`.
+    dev_dependencies: AllowedIn,
+    /// Whether `optionalDependencies` can be imported. Either a boolean, or glob patterns,
+    /// relative to the working directory, of the files which can import them.
+    optional_dependencies: AllowedIn,
+    /// Whether `peerDependencies` can be imported.
+    peer_dependencies: bool,
+    /// Whether `bundledDependencies` can be imported.
+    bundled_dependencies: bool,
+    /// Check imports of packages which resolve to a path outside of `node_modules`, like the
+    /// packages of a workspace linked by the package manager.
+    include_internal: bool,
+    /// Check type-only imports, like `import type { Foo } from "foo"`.
+    include_types: bool,
+}
+
+impl Default for NoExtraneousDependenciesConfig {
+    fn default() -> Self {
+        Self {
+            dev_dependencies: AllowedIn::Always(true),
+            optional_dependencies: AllowedIn::Always(true),
+            peer_dependencies: true,
+            bundled_dependencies: true,
+            include_internal: false,
+            include_types: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(untagged)]
+enum AllowedIn {
+    Always(bool),
+    Files(Vec<CompactStr>),
+}
+
+impl AllowedIn {
+    fn allows(&self, relative_path: &str) -> bool {
+        match self {
+            Self::Always(allowed) => *allowed,
+            Self::Files(globs) => globs.iter().any(|glob| glob_match(glob.as_str(), relative_path)),
+        }
+    }
+}
+
+impl std::ops::Deref for NoExtraneousDependencies {
+    type Target = NoExtraneousDependenciesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbids importing packages which are not declared in the `dependencies`,
+    /// `devDependencies`, `optionalDependencies`, `peerDependencies` or `bundledDependencies`
+    /// of the nearest `package.json`. Imports of `devDependencies` and `optionalDependencies`
+    /// can be restricted to some files, e.g. tests.
+    ///
+    /// Node.js builtin modules and relative imports are ignored. Packages which resolve to a path
+    /// outside of `node_modules`, like the packages of a workspace, are ignored unless
+    /// `includeInternal` is set. Only `import` and `export ... from` declarations are checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Packages which are not declared in the `package.json` may be missing when the project is
+    /// installed elsewhere, e.g. when it is published, or when another package which depended on
+    /// them is removed.
+    ///
+    /// ### Examples
+    ///
+    /// Given a `package.json` declaring `react` in `dependencies` and `vitest` in
+    /// `devDependencies`, with `{ "devDependencies": ["**/*.test.js"] }`:
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// // src/index.js
+    /// import lodash from "lodash"; // not declared
+    /// import { test } from "vitest"; // devDependency outside of tests
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// // src/index.js
+    /// import React from "react";
+    /// import fs from "node:fs";
+    /// ```
+    /// ```javascript
+    /// // src/index.test.js
+    /// import { test } from "vitest";
+    /// ```
+    NoExtraneousDependencies,
+    import,
+    suspicious,
+    config = NoExtraneousDependenciesConfig,
+);
+
+impl Rule for NoExtraneousDependencies {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoExtraneousDependencies>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+        if module_record.requested_modules.is_empty() {
+            return;
+        }
+        let Some(package_json) = ctx.package_json() else {
+            return;
+        };
+        let cwd = std::env::current_dir().unwrap_or_default();
+        let relative_path = ctx.file_path().strip_prefix(&cwd).unwrap_or(ctx.file_path());
+        let relative_path = relative_path.to_string_lossy();
+        let relative_path = relative_path.cow_replace('\\', "/");
+
+        for (specifier, requests) in &module_record.requested_modules {
+            let Some(package_name) = package_name(specifier) else {
+                continue;
+            };
+            if !self.include_types && requests.iter().all(|request| request.is_type) {
+                continue;
+            }
+            if !self.include_internal
+                && module_record
+                    .get_loaded_module(specifier)
+                    .is_some_and(|module| !is_in_node_modules(&module.resolved_absolute_path))
+            {
+                continue;
+            }
+            let Some(request) = requests.first() else {
+                continue;
+            };
+            if let Some(diagnostic) =
+                self.check_package(&package_json, package_name, &relative_path, request.span)
+            {
+                ctx.diagnostic(diagnostic);
+            }
+        }
+    }
+}
+
+impl NoExtraneousDependencies {
+    fn check_package(
+        &self,
+        package_json: &PackageJson,
+        package_name: &str,
+        relative_path: &str,
+        span: Span,
+    ) -> Option<OxcDiagnostic> {
+        // A package can import itself by its name.
+        if package_json.name.as_deref() == Some(package_name)
+            || package_json.dependencies.contains_key(package_name)
+        {
+            return None;
+        }
+        // A package can be declared in several kinds of dependencies, e.g. both in
+        // `devDependencies` and `peerDependencies`.
+        let declared_in = [
+            (
+                "devDependencies",
+                package_json.dev_dependencies.contains_key(package_name),
+                self.dev_dependencies.allows(relative_path),
+            ),
+            (
+                "optionalDependencies",
+                package_json.optional_dependencies.contains_key(package_name),
+                self.optional_dependencies.allows(relative_path),
+            ),
+            (
+                "peerDependencies",
+                package_json.peer_dependencies.contains_key(package_name),
+                self.peer_dependencies,
+            ),
+            (
+                "bundledDependencies",
+                package_json.bundled_dependencies.iter().any(|name| name == package_name),
+                self.bundled_dependencies,
+            ),
+        ];
+        if declared_in.iter().any(|(_, declared, allowed)| *declared && *allowed) {
+            return None;
+        }
+        if let Some((dependency_kind, _, _)) = declared_in.iter().find(|(_, declared, _)| *declared)
+        {
+            return Some(wrong_dependency_diagnostic(span, package_name, dependency_kind));
+        }
+        Some(missing_dependency_diagnostic(span, package_name))
+    }
+}
+
+/// The name of the package imported by `specifier`, e.g. `@scope/name` for `@scope/name/sub`.
+/// `None` for relative and absolute imports, URLs and Node.js builtin modules.
+pub fn package_name(specifier: &str) -> Option<&str> {
+    if specifier.starts_with(['.', '/', '#'])
+        || specifier.contains(':')
+        || is_nodejs_builtin_module(specifier)
+    {
+        return None;
+    }
+    let mut segments = specifier.splitn(3, '/');
+    let first = segments.next()?;
+    if first.starts_with('@') {
+        let name = segments.next()?;
+        return Some(&specifier[..first.len() + 1 + name.len()]);
+    }
+    if is_nodejs_builtin_module(first) {
+        return None;
+    }
+    Some(first)
+}
+
+/// Whether `path` is inside a `node_modules` directory.
+pub fn is_in_node_modules(path: &Path) -> bool {
+    path.components().any(|c| matches!(c, Component::Normal(p) if p == OsStr::new("node_modules")))
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"import "lodash.cond""#, None),
+        (r#"import { foo } from "@org/package""#, None),
+        (r#"import { foo } from "@org/package/sub""#, None),
+        (r#"export { foo } from "rxjs/operators""#, None),
+        (r#"import "eslint""#, None),
+        (r#"import "lodash.isarray""#, None),
+        (r#"import "@generated/foo""#, None),
+        (r#"import fs from "fs""#, None),
+        (r#"import { readFile } from "fs/promises""#, None),
+        (r#"import test from "node:test""#, None),
+        (r#"import "./foo""#, None),
+        (r#"import "../foo""#, None),
+        (r##"import "#internal""##, None),
+        (r#"import type { Foo } from "not-a-dependency""#, None),
+        (r#"import "eslint""#, Some(json!([{ "devDependencies": ["**/index.ts"] }]))),
+        (
+            r#"import "eslint""#,
+            Some(json!([{ "devDependencies": false, "peerDependencies": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        (r#"import "not-a-dependency""#, None),
+        (r#"import { foo } from "@org/not-a-package/sub""#, None),
+        (r#"export * from "exceljs""#, None),
+        (r#"import type { Foo } from "not-a-dependency""#, Some(json!([{ "includeTypes": true }]))),
+        (r#"import "glob""#, Some(json!([{ "devDependencies": false }]))),
+        (r#"import "glob""#, Some(json!([{ "devDependencies": ["**/*.test.ts"] }]))),
+        (r#"import "lodash.isarray""#, Some(json!([{ "optionalDependencies": false }]))),
+        (r#"import "@generated/foo""#, Some(json!([{ "bundledDependencies": false }]))),
+    ];
+
+    Tester::new(NoExtraneousDependencies::NAME, NoExtraneousDependencies::PLUGIN, pass, fail)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+
+    // The nearest `package.json` declares the dependencies.
+    let pass = vec![(r#"import React from "react""#, None)];
+    let fail = vec![(r#"import "left-pad""#, None)];
+
+    Tester::new(NoExtraneousDependencies::NAME, NoExtraneousDependencies::PLUGIN, pass, fail)
+        .change_rule_path("monorepo/packages/nested-package/index.ts")
+        .with_import_plugin(true)
+        .with_snapshot_suffix("nested-package")
+        .test_and_snapshot();
+}
@@ -0,0 +1,258 @@
+use std::path::Path;
+
+use lazy_regex::{Regex, RegexBuilder};
+use nodejs_built_in_modules::is_nodejs_builtin_module;
+use schemars::JsonSchema;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use super::no_extraneous_dependencies::package_name;
+use crate::{
+    context::LintContext,
+    package_json::PackageJson,
+    rule::Rule,
+    utils::{closest, did_you_mean},
+};
+
+fn no_unresolved_diagnostic(
+    span: Span,
+    specifier: &str,
+    close_matches: &[String],
+) -> OxcDiagnostic {
+    let diagnostic = OxcDiagnostic::warn(format!("Unable to resolve path to module `{specifier}`"))
+        .with_label(span);
+    match did_you_mean(close_matches) {
+        Some(help) => diagnostic.with_help(help),
+        None => diagnostic,
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolved(Box<NoUnresolvedConfig>);
+
+#[derive(Debug, Default, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoUnresolvedConfig {
+    /// Regular expressions of module specifiers which are not reported, e.g. `["^virtual:"]`.
+    ignore: Vec<Regex>,
+}
+
+impl std::ops::Deref for NoUnresolved {
+    type Target = NoUnresolvedConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports imports of modules which can't be resolved to a file.
+    ///
+    /// Modules are resolved like Node.js and bundlers do, with the `paths` and `baseUrl` of the
+    /// `tsconfig.json`, and the aliases of the `import.alias` settings:
+    ///
+    /// ```json
+    /// {
+    ///   "settings": {
+    ///     "import": {
+    ///       "alias": { "@": ["./src"] }
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Node.js builtin modules, specifiers with a protocol like `https:` or `virtual:`, and
+    /// type-only imports are not checked. Only `import` and `export ... from` declarations are
+    /// checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An import which can't be resolved fails at runtime or when the project is bundled, e.g.
+    /// because of a typo, or because a file was moved or a package isn't installed.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// import foo from "./not-a-file";
+    /// import { bar } from "not-a-package";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// import foo from "./foo";
+    /// import fs from "node:fs";
+    /// ```
+    NoUnresolved,
+    import,
+    suspicious,
+    config = NoUnresolvedConfig,
+);
+
+impl Rule for NoUnresolved {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self::try_from_configuration(value).unwrap_or_default()
+    }
+
+    fn try_from_configuration(value: serde_json::Value) -> Result<Self, String> {
+        let ignore = value
+            .get(0)
+            .and_then(|config| config.get("ignore"))
+            .and_then(serde_json::Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(serde_json::Value::as_str)
+            .map(|pattern| {
+                RegexBuilder::new(pattern)
+                    .build()
+                    .map_err(|error| format!("invalid regex `{pattern}`: {error}"))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self(Box::new(NoUnresolvedConfig { ignore })))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+        if module_record.unresolved_modules.is_empty() {
+            return;
+        }
+        let mut unresolved = module_record
+            .requested_modules
+            .iter()
+            .filter(|(specifier, _)| module_record.unresolved_modules.contains(*specifier))
+            .filter(|(specifier, requests)| {
+                !is_nodejs_builtin_module(specifier)
+                    && !specifier.contains(':')
+                    && !requests.iter().all(|request| request.is_type)
+                    && !self.ignore.iter().any(|pattern| pattern.is_match(specifier))
+            })
+            .filter_map(|(specifier, requests)| Some((specifier, requests.first()?.span)))
+            .collect::<Vec<_>>();
+        unresolved.sort_unstable_by_key(|(_, span)| *span);
+
+        for (specifier, span) in unresolved {
+            let close_matches = close_matches(specifier, ctx);
+            ctx.diagnostic(no_unresolved_diagnostic(span, specifier, &close_matches));
+        }
+    }
+}
+
+/// The specifiers close to `specifier` which may resolve: the files and directories next to the
+/// requested path for relative imports, and the dependencies of the `package.json` for packages.
+fn close_matches(specifier: &str, ctx: &LintContext) -> Vec<String> {
+    if specifier.starts_with('.') {
+        let Some((prefix, name)) = specifier.rsplit_once('/') else {
+            return vec![];
+        };
+        let Some(dir) = ctx.file_path().parent() else {
+            return vec![];
+        };
+        let names = sibling_names(&dir.join(prefix), name.contains('.'));
+        return closest(name, names.iter().map(String::as_str))
+            .into_iter()
+            .map(|name| format!("{prefix}/{name}"))
+            .collect();
+    }
+    let Some(package) = package_name(specifier) else {
+        return vec![];
+    };
+    let Some(package_json) = ctx.package_json() else {
+        return vec![];
+    };
+    let subpath = &specifier[package.len()..];
+    closest(package, dependency_names(&package_json))
+        .into_iter()
+        .map(|name| format!("{name}{subpath}"))
+        .collect()
+}
+
+/// The names of the files and directories in `dir`. File extensions are removed unless
+/// `with_extension`.
+fn sibling_names(dir: &Path, with_extension: bool) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return vec![];
+    };
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            let name =
+                if with_extension || path.is_dir() { path.file_name()? } else { path.file_stem()? };
+            Some(name.to_string_lossy().into_owned())
+        })
+        .collect()
+}
+
+fn dependency_names(package_json: &PackageJson) -> impl Iterator<Item = &str> {
+    package_json
+        .dependencies
+        .keys()
+        .chain(package_json.dev_dependencies.keys())
+        .chain(package_json.optional_dependencies.keys())
+        .chain(package_json.peer_dependencies.keys())
+        .map(String::as_str)
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"import foo from "./foo""#, None, None),
+        (r#"import bar from "./bar""#, None, None),
+        (r#"import { a } from "./deep/a.js""#, None, None),
+        (r#"export { foo } from "./foo""#, None, None),
+        (r#"import "./data.json""#, None, None),
+        (r#"import React from "react""#, None, None),
+        (r#"import fs from "fs""#, None, None),
+        (r#"import test from "node:test""#, None, None),
+        (r#"import "virtual:module""#, None, None),
+        (r#"import type { Foo } from "./not-a-file""#, None, None),
+        (r#"import foo from "./not-a-file""#, Some(json!([{ "ignore": ["^\\./not-a"] }])), None),
+        (
+            r#"import foo from "@fixtures/foo""#,
+            None,
+            Some(json!({ "settings": { "import": { "alias": { "@fixtures": ["./"] } } } })),
+        ),
+        (
+            r#"import { a } from "deep-alias/a""#,
+            None,
+            Some(json!({ "settings": { "import": { "alias": { "deep-alias": ["./deep"] } } } })),
+        ),
+    ];
+
+    let fail = vec![
+        (r#"import foo from "./not-a-file""#, None, None),
+        (r#"import foo from "./fooo""#, None, None),
+        (r#"import { a } from "./deep/aa.js""#, None, None),
+        (r#"export * from "./Foo""#, None, None),
+        (r#"import "not-a-package""#, None, None),
+        (r#"import "lodahs.cond""#, None, None),
+        (r#"import foo from "./not-a-file""#, Some(json!([{ "ignore": ["^virtual:"] }])), None),
+        (
+            r#"import foo from "@fixtures/fooo""#,
+            None,
+            Some(json!({ "settings": { "import": { "alias": { "@fixtures": ["./"] } } } })),
+        ),
+    ];
+
+    Tester::new(NoUnresolved::NAME, NoUnresolved::PLUGIN, pass, fail)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_invalid_ignore_pattern() {
+    use serde_json::json;
+
+    let error =
+        NoUnresolved::try_from_configuration(json!([{ "ignore": ["^@app/", "["] }])).unwrap_err();
+    assert!(error.starts_with("invalid regex `[`"), "{error}");
+}
@@ -0,0 +1,193 @@
+use std::path::Path;
+
+use cow_utils::CowUtils;
+use fast_glob::glob_match;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    ModuleGraph,
+    context::ContextHost,
+    module_record::ExportExportName,
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn unused_module_diagnostic() -> OxcDiagnostic {
+    OxcDiagnostic::warn("Module is never imported")
+        .with_help("Import the module, remove it, or add it to the `entryPoints` of this rule.")
+        .with_label(Span::empty(0))
+}
+
+fn unused_export_diagnostic(spans: Vec<Span>, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Export `{name}` is never imported"))
+        .with_help("Remove the export, or add the module to the `publicApi` of this rule.")
+        .with_labels(spans)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUnusedModules(Box<NoUnusedModulesConfig>);
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoUnusedModulesConfig {
+    /// Report exports which are never imported by another module.
+    unused_exports: bool,
+    /// Report modules which are never imported by another module.
+    unused_modules: bool,
+    /// Glob patterns, relative to the working directory, of modules which are loaded without
+    /// being imported, e.g. `["src/main.ts", "**/*.test.ts"]`. They are not reported when they
+    /// are never imported.
+    entry_points: Vec<CompactStr>,
+    /// Glob patterns, relative to the working directory, of modules whose exports are used
+    /// outside of the project, e.g. `["src/index.ts"]` for a library. Their exports are not
+    /// reported, and neither are the modules.
+    public_api: Vec<CompactStr>,
+}
+
+impl Default for NoUnusedModulesConfig {
+    fn default() -> Self {
+        Self {
+            unused_exports: true,
+            unused_modules: true,
+            entry_points: vec![],
+            public_api: vec![],
+        }
+    }
+}
+
+impl std::ops::Deref for NoUnusedModules {
+    type Target = NoUnusedModulesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports exports which are never imported by another module of the project, and modules
+    /// which are never imported at all.
+    ///
+    /// The rule checks the module graph of all linted files, after they are linted. Modules are
+    /// only known to be imported by the linted files, so files which are ignored or not passed to
+    /// oxlint are not taken into account. Modules loaded with `require` or dynamic `import()`
+    /// are not known to be imported, and CommonJS modules are not checked.
+    ///
+    /// TypeScript types and interfaces are checked like other exports. Merged declarations, e.g.
+    /// an interface declared twice, are reported once. Exports re-exported with `export *` are
+    /// used when they are imported from the re-exporting module.
+    ///
+    /// This rule is only reported by the CLI, as it needs all files of the project to be linted.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unused exports and modules are dead code. They make the code harder to maintain, and
+    /// are often left behind after a refactoring.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// // utils.js
+    /// export function used() {}
+    /// export function unused() {} // never imported
+    /// ```
+    /// ```javascript
+    /// // main.js
+    /// import { used } from './utils.js';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// // utils.js
+    /// export function used() {}
+    /// ```
+    /// ```javascript
+    /// // main.js
+    /// import { used } from './utils.js';
+    /// ```
+    ///
+    /// With `{ "entryPoints": ["main.js"] }`, `main.js` is not reported for being never imported.
+    NoUnusedModules,
+    import,
+    restriction,
+    config = NoUnusedModulesConfig,
+);
+
+impl Rule for NoUnusedModules {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoUnusedModules>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    // The rule runs on the module graph once all files are linted, see `run_on_module_graph`.
+    fn should_run(&self, _ctx: &ContextHost) -> bool {
+        false
+    }
+}
+
+impl NoUnusedModules {
+    /// Checks the module at `path` once all modules of the lint run are in `module_graph`.
+    pub(crate) fn run_on_module_graph(
+        &self,
+        path: &Path,
+        cwd: &Path,
+        module_graph: &ModuleGraph,
+    ) -> Vec<OxcDiagnostic> {
+        let records = module_graph.module_records(path);
+        let [record] = records.as_slice() else {
+            return vec![];
+        };
+        if !record.has_module_syntax {
+            return vec![];
+        }
+
+        let relative_path = path.strip_prefix(cwd).unwrap_or(path).to_string_lossy();
+        let relative_path = relative_path.cow_replace('\\', "/");
+        let matches_any = |globs: &[CompactStr]| {
+            globs.iter().any(|glob| glob_match(glob.as_str(), relative_path.as_ref()))
+        };
+        if matches_any(&self.public_api) {
+            return vec![];
+        }
+
+        let importers = module_graph.importers(path);
+        if importers.iter().all(|importer| importer == path) && !matches_any(&self.entry_points) {
+            return if self.unused_modules { vec![unused_module_diagnostic()] } else { vec![] };
+        }
+        if !self.unused_exports {
+            return vec![];
+        }
+        let Some(imported_names) = module_graph.imported_names(path) else {
+            return vec![];
+        };
+
+        // Merged TypeScript declarations, e.g. an interface declared twice, are a single export.
+        let mut unused_exports: Vec<(&str, Vec<Span>)> = vec![];
+        for (name, span) in record
+            .local_export_entries
+            .iter()
+            .chain(&record.indirect_export_entries)
+            .filter_map(|entry| match &entry.export_name {
+                ExportExportName::Name(name) => Some((name.name.as_str(), name.span)),
+                ExportExportName::Default(span) => Some(("default", *span)),
+                ExportExportName::Null => None,
+            })
+            .filter(|(name, _)| !imported_names.contains(*name))
+        {
+            match unused_exports.iter_mut().find(|(unused, _)| *unused == name) {
+                Some((_, spans)) => spans.push(span),
+                None => unused_exports.push((name, vec![span])),
+            }
+        }
+        unused_exports
+            .into_iter()
+            .map(|(name, spans)| unused_export_diagnostic(spans, name))
+            .collect()
+    }
+}
@@ -0,0 +1,305 @@
+use std::path::{Component, Path, PathBuf};
+
+use cow_utils::CowUtils;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use super::no_extraneous_dependencies::package_name;
+use crate::{
+    context::LintContext,
+    package_json::{Workspace, WorkspacePackage},
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn not_exported_diagnostic(span: Span, specifier: &str, package: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{specifier}` is not exported by the workspace package `{package}`"
+    ))
+    .with_help(format!(
+        "Import from an entry point in the `exports` of `{package}`, or add this path to them."
+    ))
+    .with_label(span)
+}
+
+fn relative_import_diagnostic(span: Span, specifier: &str, package: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{specifier}` reaches into the files of the workspace package `{package}`"
+    ))
+    .with_help(format!("Import from the package `{package}` instead."))
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoWorkspaceInternals(Box<NoWorkspaceInternalsConfig>);
+
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoWorkspaceInternalsConfig {
+    /// Globs of internal paths which may be imported, matched against the package name followed
+    /// by the path in the package, e.g. `["@acme/ui/src/testing/**"]`.
+    allow: Vec<String>,
+}
+
+impl std::ops::Deref for NoWorkspaceInternals {
+    type Target = NoWorkspaceInternalsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports imports of the internal modules of another package of a pnpm, yarn or npm
+    /// workspace: paths of the package which are not exposed by the `exports` of its
+    /// `package.json`, and relative imports reaching into the directory of the package.
+    ///
+    /// The workspace packages are found with the `packages` of the nearest
+    /// `pnpm-workspace.yaml`, or the `workspaces` of the nearest `package.json`. A package
+    /// without `exports` only exposes its root.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The internal modules of a package are not part of its API, and may be moved or changed
+    /// without notice. Importing them couples the packages of a monorepo, and fails once the
+    /// package is built or published.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule, with `@acme/ui` exporting `.` and `./button`:
+    /// ```javascript
+    /// import { Button } from "@acme/ui/src/button";
+    /// import { Button } from "../../ui/src/button";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// import { Button } from "@acme/ui";
+    /// import { Button } from "@acme/ui/button";
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### allow
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Globs of internal paths which may be imported, matched against the package name followed
+    /// by the path in the package:
+    ///
+    /// ```json
+    /// {
+    ///   "import/no-workspace-internals": ["error", { "allow": ["@acme/ui/src/testing/**"] }]
+    /// }
+    /// ```
+    NoWorkspaceInternals,
+    import,
+    restriction,
+    config = NoWorkspaceInternalsConfig,
+);
+
+impl Rule for NoWorkspaceInternals {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoWorkspaceInternals>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+        if module_record.requested_modules.is_empty() {
+            return;
+        }
+        let Some(workspace) = ctx.workspace() else {
+            return;
+        };
+        let own_package = workspace.package_containing(ctx.file_path());
+        let Some(dir) = ctx.file_path().parent() else {
+            return;
+        };
+
+        let mut requests = module_record
+            .requested_modules
+            .iter()
+            .filter_map(|(specifier, requests)| Some((specifier, requests.first()?.span)))
+            .collect::<Vec<_>>();
+        requests.sort_unstable_by_key(|(_, span)| *span);
+
+        for (specifier, span) in requests {
+            if specifier.starts_with('.') {
+                self.check_relative(specifier, span, dir, &workspace, own_package, ctx);
+            } else {
+                self.check_package(specifier, span, &workspace, own_package, ctx);
+            }
+        }
+    }
+}
+
+impl NoWorkspaceInternals {
+    fn check_package(
+        &self,
+        specifier: &str,
+        span: Span,
+        workspace: &Workspace,
+        own_package: Option<&WorkspacePackage>,
+        ctx: &LintContext,
+    ) {
+        let Some(name) = package_name(specifier) else {
+            return;
+        };
+        let Some(package) = workspace.package_named(name) else {
+            return;
+        };
+        if own_package.is_some_and(|own_package| own_package.dir == package.dir) {
+            return;
+        }
+        let subpath = format!(".{}", &specifier[name.len()..]);
+        if is_exported(package.package_json.exports.as_ref(), &subpath)
+            || self.is_allowed(specifier)
+        {
+            return;
+        }
+        ctx.diagnostic(not_exported_diagnostic(span, specifier, name));
+    }
+
+    fn check_relative(
+        &self,
+        specifier: &str,
+        span: Span,
+        dir: &Path,
+        workspace: &Workspace,
+        own_package: Option<&WorkspacePackage>,
+        ctx: &LintContext,
+    ) {
+        let target = normalize(&dir.join(specifier));
+        let Some(package) = workspace.package_containing(&target) else {
+            return;
+        };
+        if own_package.is_some_and(|own_package| own_package.dir == package.dir) {
+            return;
+        }
+        let Some(name) = package.name() else {
+            return;
+        };
+        let Ok(path) = target.strip_prefix(&package.dir) else {
+            return;
+        };
+        let path = path.to_string_lossy().cow_replace('\\', "/").into_owned();
+        let internal = if path.is_empty() { name.to_string() } else { format!("{name}/{path}") };
+        if self.is_allowed(&internal) {
+            return;
+        }
+        ctx.diagnostic(relative_import_diagnostic(span, specifier, name));
+    }
+
+    fn is_allowed(&self, path: &str) -> bool {
+        self.allow.iter().any(|glob| fast_glob::glob_match(glob, path))
+    }
+}
+
+/// Whether `subpath` of a package, e.g. `.` or `./utils`, is exposed by its `exports`.
+fn is_exported(exports: Option<&serde_json::Value>, subpath: &str) -> bool {
+    let Some(serde_json::Value::Object(map)) = exports else {
+        // A package without `exports`, or with a single entry point, only exposes its root.
+        return subpath == ".";
+    };
+    if !map.keys().any(|key| key.starts_with('.')) {
+        // Conditions like `{ "import": ..., "require": ... }` for the root.
+        return subpath == ".";
+    }
+    if let Some(target) = map.get(subpath) {
+        return !target.is_null();
+    }
+    // The most specific pattern matching `subpath`, like Node.js resolves `exports`.
+    map.iter()
+        .filter(|(key, _)| match key.split_once('*') {
+            Some((prefix, suffix)) => {
+                subpath.len() >= prefix.len() + suffix.len()
+                    && subpath.starts_with(prefix)
+                    && subpath.ends_with(suffix)
+            }
+            None => key.ends_with('/') && subpath.starts_with(key.as_str()),
+        })
+        .max_by_key(|(key, _)| key.len())
+        .is_some_and(|(_, target)| !target.is_null())
+}
+
+/// `path` without `.` and `..` components.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"import { Button } from "@acme/ui""#, None),
+        (r#"import { Button } from "@acme/ui/button""#, None),
+        (r#"import { Star } from "@acme/ui/icons/star""#, None),
+        (r#"import pkg from "@acme/ui/package.json""#, None),
+        (r#"import { legacy } from "@acme/legacy""#, None),
+        (r#"import { helper } from "./helper""#, None),
+        (r#"import { app } from "@acme/app/src/helper""#, None),
+        (r#"import { tools } from "@acme/internal-tools/src/tools""#, None),
+        (r#"import { tools } from "../../internal-tools/src/tools""#, None),
+        (r#"import React from "react""#, None),
+        (r#"import fs from "node:fs""#, None),
+        (
+            r#"import { internal } from "@acme/ui/src/internal""#,
+            Some(json!([{ "allow": ["@acme/ui/src/**"] }])),
+        ),
+        (
+            r#"import { internal } from "../../ui/src/internal""#,
+            Some(json!([{ "allow": ["@acme/ui/src/**"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        (r#"import { internal } from "@acme/ui/src/internal""#, None),
+        (r#"import { Secret } from "@acme/ui/icons/internal/secret""#, None),
+        (r#"export { Button } from "@acme/ui/src/button.js""#, None),
+        (r#"import { legacy } from "@acme/legacy/lib/index.js""#, None),
+        (r#"import { Button } from "../../ui/src/button""#, None),
+        (r#"import { Button } from "../../ui""#, None),
+        (
+            r#"import { internal } from "@acme/ui/src/internal""#,
+            Some(json!([{ "allow": ["@acme/legacy/**"] }])),
+        ),
+    ];
+
+    Tester::new(NoWorkspaceInternals::NAME, NoWorkspaceInternals::PLUGIN, pass, fail)
+        .change_rule_path("workspace/packages/app/src/index.ts")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+
+    let pass = vec![(r#"import { a } from "a""#, None), (r#"import { b } from "./index""#, None)];
+
+    let fail = vec![
+        (r#"import { util } from "a/src/util.js""#, None),
+        (r#"import { util } from "../a/src/util.js""#, None),
+    ];
+
+    Tester::new(NoWorkspaceInternals::NAME, NoWorkspaceInternals::PLUGIN, pass, fail)
+        .change_rule_path("pnpm-workspace/libs/b/index.ts")
+        .with_import_plugin(true)
+        .with_snapshot_suffix("pnpm")
+        .test_and_snapshot();
+}
@@ -0,0 +1,671 @@
+use std::cmp::Ordering;
+
+use cow_utils::CowUtils;
+use nodejs_built_in_modules::is_nodejs_builtin_module;
+use oxc_ast::ast::{ImportDeclaration, Statement};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use super::no_extraneous_dependencies::is_in_node_modules;
+use crate::{
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn order_diagnostic(span: Span, current: &str, previous: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{current}` import should occur before import of `{previous}`"))
+        .with_label(span)
+}
+
+fn missing_empty_line_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be at least one empty line between import groups")
+        .with_label(span)
+}
+
+fn empty_line_within_group_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be no empty line within import group").with_label(span)
+}
+
+fn empty_line_between_groups_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be no empty line between import groups").with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct Order(Box<OrderConfig>);
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OrderConfig {
+    /// The order of the groups of imports. A group can be a single kind of import, or an array
+    /// of kinds which are mixed together, e.g. `["builtin", "external", ["parent", "sibling"]]`.
+    /// The kinds which are omitted are grouped together at the end.
+    ///
+    /// The kinds are `builtin`, `external`, `internal`, `parent`, `sibling`, `index`, `unknown`
+    /// and `type`. `type` only applies to `import type` declarations when it is listed.
+    groups: Vec<GroupOrGroups>,
+    /// Whether the groups must be separated by empty lines.
+    #[serde(rename = "newlines-between")]
+    newlines_between: NewlinesBetween,
+    /// Sort the imports of each group by their module name.
+    alphabetize: Alphabetize,
+}
+
+impl Default for OrderConfig {
+    fn default() -> Self {
+        Self {
+            groups: vec![
+                GroupOrGroups::Group(ImportGroup::Builtin),
+                GroupOrGroups::Group(ImportGroup::External),
+                GroupOrGroups::Group(ImportGroup::Parent),
+                GroupOrGroups::Group(ImportGroup::Sibling),
+                GroupOrGroups::Group(ImportGroup::Index),
+            ],
+            newlines_between: NewlinesBetween::default(),
+            alphabetize: Alphabetize::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, JsonSchema, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ImportGroup {
+    /// Node.js builtin modules, e.g. `fs` or `node:path`.
+    Builtin,
+    /// Packages which resolve to `node_modules`, or which can't be resolved.
+    External,
+    /// Packages which resolve outside of `node_modules`, e.g. with a path alias.
+    Internal,
+    /// Modules in a parent directory, e.g. `../foo`.
+    Parent,
+    /// Modules in the same directory, e.g. `./foo`.
+    Sibling,
+    /// The index of the current directory, e.g. `./` or `./index.js`.
+    Index,
+    /// Other modules, e.g. absolute paths and URLs.
+    Unknown,
+    /// `import type` declarations.
+    Type,
+}
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(untagged)]
+enum GroupOrGroups {
+    Group(ImportGroup),
+    Groups(Vec<ImportGroup>),
+}
+
+impl GroupOrGroups {
+    fn contains(&self, group: ImportGroup) -> bool {
+        match self {
+            Self::Group(g) => *g == group,
+            Self::Groups(groups) => groups.contains(&group),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, JsonSchema, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum NewlinesBetween {
+    /// Empty lines between imports are not checked.
+    #[default]
+    Ignore,
+    /// Groups must be separated by an empty line, and imports of a group must not be.
+    Always,
+    /// Groups must be separated by an empty line, and imports of a group may be.
+    AlwaysAndInsideGroups,
+    /// Imports must not be separated by empty lines.
+    Never,
+}
+
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct Alphabetize {
+    /// The order of the module names in a group.
+    order: AlphabetizeOrder,
+    /// Ignore the case of the module names.
+    case_insensitive: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, JsonSchema, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum AlphabetizeOrder {
+    #[default]
+    Ignore,
+    Asc,
+    Desc,
+}
+
+impl std::ops::Deref for Order {
+    type Target = OrderConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces the order of the import declarations of a module, by the kind of module they
+    /// import: Node.js builtin modules, external packages, internal packages, parent modules,
+    /// sibling modules and the index of the directory. Groups can optionally be separated by
+    /// empty lines, and the imports of each group sorted by module name.
+    ///
+    /// The fix moves whole import declarations, along with the comments on the lines above them
+    /// and on the same line. Imports are only moved when no other statement is between them.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Imports in a consistent order are easier to read, and it's easier to see which
+    /// dependencies a module has.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// import foo from "./foo";
+    /// import path from "node:path";
+    /// import _ from "lodash";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// import path from "node:path";
+    /// import _ from "lodash";
+    /// import foo from "./foo";
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with
+    /// `{ "newlines-between": "always", "alphabetize": { "order": "asc" } }`:
+    /// ```javascript
+    /// import fs from "node:fs";
+    /// import _ from "lodash";
+    /// import bar from "./bar";
+    ///
+    /// import baz from "./baz";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with
+    /// `{ "newlines-between": "always", "alphabetize": { "order": "asc" } }`:
+    /// ```javascript
+    /// import fs from "node:fs";
+    ///
+    /// import _ from "lodash";
+    ///
+    /// import bar from "./bar";
+    /// import baz from "./baz";
+    /// ```
+    Order,
+    import,
+    style,
+    conditional_fix,
+    config = OrderConfig,
+);
+
+/// A top-level import declaration, with the rank of its group.
+struct Import<'a, 'b> {
+    decl: &'b ImportDeclaration<'a>,
+    /// The index of the declaration in the program body.
+    index: usize,
+    rank: usize,
+}
+
+impl Rule for Order {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<Order>>(value).unwrap_or_default().into_inner()
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let imports = ctx
+            .nodes()
+            .program()
+            .body
+            .iter()
+            .enumerate()
+            .filter_map(|(index, statement)| match statement {
+                Statement::ImportDeclaration(decl) => {
+                    Some(Import { decl, index, rank: self.rank(decl, ctx) })
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if imports.len() < 2 {
+            return;
+        }
+
+        let fix = self.reorder_fix(&imports, ctx);
+        let mut is_ordered = true;
+        for (i, current) in imports.iter().enumerate() {
+            let Some(previous) = imports[..i]
+                .iter()
+                .find(|previous| self.compare(previous, current) == Ordering::Greater)
+            else {
+                continue;
+            };
+            is_ordered = false;
+            let diagnostic = order_diagnostic(
+                current.decl.span,
+                current.decl.source.value.as_str(),
+                previous.decl.source.value.as_str(),
+            );
+            match &fix {
+                Some((span, text)) => {
+                    ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(*span, text.clone()));
+                }
+                None => ctx.diagnostic(diagnostic),
+            }
+        }
+
+        // Empty lines are fixed along with the order, so they are only checked once it's right.
+        if is_ordered && self.newlines_between != NewlinesBetween::Ignore {
+            self.check_newlines_between(&imports, ctx);
+        }
+    }
+}
+
+impl Order {
+    /// The position of the group of `decl` in the configured groups. Imports whose group is not
+    /// configured are ranked after all groups.
+    fn rank(&self, decl: &ImportDeclaration, ctx: &LintContext) -> usize {
+        let position = |group| self.groups.iter().position(|groups| groups.contains(group));
+        if decl.import_kind.is_type()
+            && let Some(rank) = position(ImportGroup::Type)
+        {
+            return rank;
+        }
+        position(import_group(decl.source.value.as_str(), ctx)).unwrap_or(self.groups.len())
+    }
+
+    fn compare(&self, a: &Import, b: &Import) -> Ordering {
+        a.rank.cmp(&b.rank).then_with(|| {
+            let (a, b) = (a.decl.source.value.as_str(), b.decl.source.value.as_str());
+            let (a, b) = if self.alphabetize.case_insensitive {
+                (a.cow_to_lowercase(), b.cow_to_lowercase())
+            } else {
+                (a.into(), b.into())
+            };
+            match self.alphabetize.order {
+                AlphabetizeOrder::Ignore => Ordering::Equal,
+                AlphabetizeOrder::Asc => a.cmp(&b),
+                AlphabetizeOrder::Desc => b.cmp(&a),
+            }
+        })
+    }
+
+    /// The span of all imports and their text once sorted, or `None` if they can't be moved.
+    fn reorder_fix(&self, imports: &[Import], ctx: &LintContext) -> Option<(Span, String)> {
+        if imports.windows(2).any(|pair| pair[1].index != pair[0].index + 1) {
+            return None;
+        }
+        let source_text = ctx.source_text();
+        let line_ends = imports
+            .iter()
+            .enumerate()
+            .map(|(i, import)| line_end(source_text, import.decl, imports.get(i + 1)))
+            .collect::<Vec<_>>();
+        // A comment over several lines can't be split between two imports.
+        if ctx.comments().iter().any(|comment| {
+            line_ends.iter().any(|&end| comment.span.start < end && end < comment.span.end)
+        }) {
+            return None;
+        }
+
+        // Each import is moved with the comments above it and on its line. The whitespace before
+        // it is kept in place, so empty lines stay between the same positions.
+        let mut start = imports[0].decl.span.start;
+        let chunks = line_ends
+            .iter()
+            .map(|&end| {
+                let text = &source_text[start as usize..end as usize];
+                let trimmed = text.trim_start();
+                start = end;
+                (&text[..text.len() - trimmed.len()], trimmed)
+            })
+            .collect::<Vec<_>>();
+
+        let mut sorted = (0..imports.len()).collect::<Vec<_>>();
+        sorted.sort_by(|&a, &b| self.compare(&imports[a], &imports[b]));
+        let mut text = String::new();
+        for (position, &i) in sorted.iter().enumerate() {
+            if position > 0 {
+                let same_group = imports[sorted[position - 1]].rank == imports[i].rank;
+                text.push_str(match self.newlines_between {
+                    NewlinesBetween::Ignore => chunks[position].0,
+                    NewlinesBetween::AlwaysAndInsideGroups if same_group => chunks[position].0,
+                    NewlinesBetween::Always if same_group => "\n",
+                    NewlinesBetween::Never => "\n",
+                    NewlinesBetween::Always | NewlinesBetween::AlwaysAndInsideGroups => "\n\n",
+                });
+            }
+            text.push_str(chunks[i].1);
+        }
+        Some((Span::new(imports[0].decl.span.start, line_ends[line_ends.len() - 1]), text))
+    }
+
+    fn check_newlines_between(&self, imports: &[Import], ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        for pair in imports.windows(2) {
+            let [previous, current] = pair else {
+                continue;
+            };
+            let between =
+                ctx.source_range(Span::new(previous.decl.span.end, current.decl.span.start));
+            let lines = between.split('\n').collect::<Vec<_>>();
+            let empty_lines = if lines.len() > 2 {
+                lines[1..lines.len() - 1].iter().filter(|line| line.trim().is_empty()).count()
+            } else {
+                0
+            };
+            let same_group = previous.rank == current.rank;
+            let end = line_end(source_text, previous.decl, Some(current));
+
+            if empty_lines == 0 && !same_group && self.newlines_between != NewlinesBetween::Never {
+                let newlines =
+                    if source_text[end as usize..].starts_with('\n') { "\n" } else { "\n\n" };
+                ctx.diagnostic_with_fix(
+                    missing_empty_line_diagnostic(previous.decl.span),
+                    |fixer| fixer.insert_text_after_range(Span::empty(end), newlines),
+                );
+                continue;
+            }
+            if empty_lines == 0 {
+                continue;
+            }
+            let diagnostic = match self.newlines_between {
+                NewlinesBetween::Always if same_group => {
+                    empty_line_within_group_diagnostic(previous.decl.span)
+                }
+                NewlinesBetween::Never => empty_line_between_groups_diagnostic(previous.decl.span),
+                _ => continue,
+            };
+            // Remove the empty lines, but keep the comments between the imports.
+            let span = Span::new(end, current.decl.span.start);
+            let lines = ctx.source_range(span).split('\n').collect::<Vec<_>>();
+            let kept = lines
+                .iter()
+                .enumerate()
+                .filter(|(i, line)| *i == 0 || *i == lines.len() - 1 || !line.trim().is_empty())
+                .map(|(_, line)| *line)
+                .collect::<Vec<_>>()
+                .join("\n");
+            ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(span, kept));
+        }
+    }
+}
+
+/// The group of the module imported by `specifier`.
+fn import_group(specifier: &str, ctx: &LintContext) -> ImportGroup {
+    if is_nodejs_builtin_module(specifier) {
+        ImportGroup::Builtin
+    } else if matches!(specifier, "." | "./")
+        || specifier
+            .strip_prefix("./")
+            .is_some_and(|path| path == "index" || path.starts_with("index."))
+    {
+        ImportGroup::Index
+    } else if specifier == ".." || specifier.starts_with("../") {
+        ImportGroup::Parent
+    } else if specifier.starts_with("./") {
+        ImportGroup::Sibling
+    } else if specifier.starts_with('/') || specifier.contains(':') {
+        ImportGroup::Unknown
+    } else if ctx
+        .module_record()
+        .get_loaded_module(specifier)
+        .is_some_and(|module| !is_in_node_modules(&module.resolved_absolute_path))
+    {
+        ImportGroup::Internal
+    } else {
+        ImportGroup::External
+    }
+}
+
+/// The end of the line of `decl`, after a comment on the same line. If `next` starts on the same
+/// line, the end of `decl`.
+fn line_end(source_text: &str, decl: &ImportDeclaration, next: Option<&Import>) -> u32 {
+    let end = decl.span.end;
+    #[expect(clippy::cast_possible_truncation)]
+    let newline = source_text[end as usize..]
+        .find('\n')
+        .map_or(source_text.len() as u32, |offset| end + offset as u32);
+    if next.is_some_and(|next| next.decl.span.start < newline) { end } else { newline }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            r#"
+                import fs from "fs";
+                import path from "node:path";
+                import _ from "lodash";
+                import foo from "../foo";
+                import bar from "./bar";
+                import index from "./";
+            "#,
+            None,
+        ),
+        (
+            r#"
+                import fs from "fs";
+                const path = require("path");
+                import _ from "lodash";
+            "#,
+            None,
+        ),
+        (
+            r#"import _ from "lodash"; import fs from "fs";"#,
+            Some(json!([{ "groups": ["external", "builtin"] }])),
+        ),
+        (
+            r#"
+                import foo from "../foo";
+                import bar from "./bar";
+                import fs from "fs";
+            "#,
+            Some(json!([{ "groups": [["parent", "sibling"], "builtin"] }])),
+        ),
+        (
+            r#"
+                import bar from "./bar";
+                import foo from "../foo";
+            "#,
+            Some(json!([{ "groups": [["parent", "sibling"]] }])),
+        ),
+        (
+            r#"
+                import a from "/a";
+                import b from "https://example.com/b";
+                import fs from "fs";
+            "#,
+            Some(json!([{ "groups": ["unknown", "builtin"] }])),
+        ),
+        (
+            r#"
+                import type { Foo } from "./foo";
+                import fs from "fs";
+            "#,
+            Some(json!([{ "groups": ["type", "builtin"] }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+
+                import _ from "lodash";
+                import react from "react";
+
+                import bar from "./bar";
+            "#,
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+
+                import _ from "lodash";
+
+                import react from "react";
+            "#,
+            Some(json!([{ "newlines-between": "always-and-inside-groups" }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+                import _ from "lodash";
+                // A comment between imports.
+                import bar from "./bar";
+            "#,
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            r#"
+                import a from "a";
+                import B from "B";
+                import c from "c";
+            "#,
+            Some(json!([{ "alphabetize": { "order": "asc", "caseInsensitive": true } }])),
+        ),
+        (
+            r#"
+                import c from "c";
+                import a from "a";
+                import fs from "fs";
+            "#,
+            Some(
+                json!([{ "groups": ["external", "builtin"], "alphabetize": { "order": "desc" } }]),
+            ),
+        ),
+    ];
+
+    let fail = vec![
+        (
+            r#"
+                import _ from "lodash";
+                import fs from "fs";
+            "#,
+            None,
+        ),
+        (
+            r#"
+                import bar from "./bar";
+                import foo from "../foo";
+                import index from "./index.js";
+                import fs from "node:fs";
+            "#,
+            None,
+        ),
+        (
+            r#"
+                import _ from "lodash";
+                const x = 1;
+                import fs from "fs";
+            "#,
+            None,
+        ),
+        (
+            r#"
+                import fs from "fs";
+                import bar from "./bar";
+            "#,
+            Some(json!([{ "groups": ["sibling", "builtin"] }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+                import _ from "lodash";
+            "#,
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r#"
+                import _ from "lodash";
+
+                import react from "react";
+            "#,
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r#"
+                import fs from "fs";
+
+                import _ from "lodash";
+            "#,
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            r#"
+                import c from "c";
+                import a from "a";
+            "#,
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r#"
+                import a from "a";
+                import B from "B";
+            "#,
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "import _ from \"lodash\";\nimport fs from \"fs\";",
+            "import fs from \"fs\";\nimport _ from \"lodash\";",
+            None,
+        ),
+        (
+            "// File header.\nimport bar from \"./bar\"; // bar\n// foo\nimport foo from \"../foo\";\n\nimport fs from \"fs\";\nfoo();",
+            "// File header.\nimport fs from \"fs\";\n// foo\nimport foo from \"../foo\";\n\nimport bar from \"./bar\"; // bar\nfoo();",
+            None,
+        ),
+        (
+            "import bar from \"./bar\"; import fs from \"fs\";",
+            "import fs from \"fs\"; import bar from \"./bar\";",
+            None,
+        ),
+        (
+            "import _ from \"lodash\";\nconst x = 1;\nimport fs from \"fs\";",
+            "import _ from \"lodash\";\nconst x = 1;\nimport fs from \"fs\";",
+            None,
+        ),
+        (
+            "import bar from \"./bar\";\nimport react from \"react\";\nimport _ from \"lodash\";\nimport fs from \"fs\";",
+            "import fs from \"fs\";\n\nimport _ from \"lodash\";\nimport react from \"react\";\n\nimport bar from \"./bar\";",
+            Some(json!([{ "newlines-between": "always", "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            "import _ from \"lodash\";\n\nimport fs from \"fs\";",
+            "import fs from \"fs\";\nimport _ from \"lodash\";",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            "import fs from \"fs\"; // fs\nimport _ from \"lodash\";",
+            "import fs from \"fs\"; // fs\n\nimport _ from \"lodash\";",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import _ from \"lodash\";\n\n// react\n\nimport react from \"react\";",
+            "import _ from \"lodash\";\n// react\nimport react from \"react\";",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from \"fs\";\n\n\nimport _ from \"lodash\";",
+            "import fs from \"fs\";\nimport _ from \"lodash\";",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+    ];
+
+    Tester::new(Order::NAME, Order::PLUGIN, pass, fail)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,432 @@
+use std::ops::Deref;
+
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::JSDoc;
+use oxc_span::Span;
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{
+        ParamKind, collect_params, get_function_nearest_jsdoc_node, should_ignore_as_internal,
+        should_ignore_as_private,
+    },
+};
+
+fn duplicate_param_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Duplicate `@param` `{name}`."))
+        .with_help("Remove the redundant `@param` tag.")
+        .with_label(span)
+}
+
+fn unknown_param_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`@param` `{name}` does not match an existing function parameter."))
+        .with_help("Remove the `@param` tag, or rename it to the parameter it documents.")
+        .with_label(span)
+}
+
+fn wrong_name_diagnostic(span: Span, expected: &str, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Expected `@param` name to be `{expected}`, but got `{name}`."))
+        .with_help("Rename the `@param` tag to the name of the parameter.")
+        .with_label(span)
+}
+
+fn wrong_order_diagnostic(span: Span, expected: &str, names: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Expected `@param` names to be `{expected}`, but got `{names}`."))
+        .with_help("Reorder the `@param` tags to match the order of the parameters.")
+        .with_label(span)
+}
+
+fn unknown_property_diagnostic(span: Span, name: &str, root: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`@param` `{name}` does not exist on `{root}`."))
+        .with_help(
+            "Remove the `@param` tag, or rename it to a property of the destructured parameter.",
+        )
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct CheckParamNames(Box<CheckParamNamesConfig>);
+
+impl Deref for CheckParamNames {
+    type Target = CheckParamNamesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures that the names of `@param` tags match the parameters of the function, in the
+    /// same order, and are not duplicated. Names of properties of destructured parameters,
+    /// like `root.foo`, must also exist.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A `@param` tag whose name doesn't match a parameter documents something that doesn't
+    /// exist, which usually happens when a parameter is renamed, removed or reordered without
+    /// updating its documentation.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// /**
+    ///  * @param {string} fo
+    ///  */
+    /// function quux (foo) {}
+    ///
+    /// /**
+    ///  * @param {string} bar
+    ///  * @param {string} foo
+    ///  */
+    /// function quux (foo, bar) {}
+    ///
+    /// /**
+    ///  * @param {object} root
+    ///  * @param {string} root.baz
+    ///  */
+    /// function quux ({ foo, bar }) {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// /**
+    ///  * @param {string} foo
+    ///  * @param {string} bar
+    ///  */
+    /// function quux (foo, bar) {}
+    ///
+    /// /**
+    ///  * @param {object} root
+    ///  * @param {string} root.foo
+    ///  */
+    /// function quux ({ foo, bar }) {}
+    /// ```
+    CheckParamNames,
+    jsdoc,
+    correctness,
+    config = CheckParamNamesConfig,
+);
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CheckParamNamesConfig {
+    /// Whether to allow `@param` tags after the last parameter, e.g. for parameters which are
+    /// read from `arguments`.
+    allow_extra_trailing_param_docs: bool,
+    /// Whether to check that the properties of destructured parameters which are documented,
+    /// like `root.foo`, exist.
+    check_destructured: bool,
+}
+
+impl Default for CheckParamNamesConfig {
+    fn default() -> Self {
+        Self { allow_extra_trailing_param_docs: false, check_destructured: true }
+    }
+}
+
+impl Rule for CheckParamNames {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<CheckParamNames>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let params = match node.kind() {
+            AstKind::Function(func) if !func.is_typescript_syntax() => collect_params(&func.params),
+            AstKind::ArrowFunctionExpression(arrow_func) => collect_params(&arrow_func.params),
+            _ => return,
+        };
+
+        let Some(func_def_node) = get_function_nearest_jsdoc_node(node, ctx) else {
+            return;
+        };
+        let Some(jsdocs) = ctx.jsdoc().get_all_by_node(ctx.nodes(), func_def_node) else {
+            return;
+        };
+
+        let settings = &ctx.settings().jsdoc;
+        let resolved_param_tag_name = settings.resolve_tag_name("param");
+        for jsdoc in jsdocs
+            .iter()
+            .filter(|jsdoc| !should_ignore_as_internal(jsdoc, settings))
+            .filter(|jsdoc| !should_ignore_as_private(jsdoc, settings))
+        {
+            self.check_jsdoc(jsdoc, resolved_param_tag_name, &params, ctx);
+        }
+    }
+}
+
+impl CheckParamNames {
+    fn check_jsdoc(
+        &self,
+        jsdoc: &JSDoc,
+        resolved_param_tag_name: &str,
+        params: &[ParamKind],
+        ctx: &LintContext,
+    ) {
+        let tags = jsdoc
+            .tags()
+            .iter()
+            .filter(|tag| tag.kind.parsed() == resolved_param_tag_name)
+            .filter_map(|tag| tag.type_name_comment().1)
+            // thisParam is special, not collected as `FormalParameter`
+            .filter(|name_part| name_part.parsed() != "this")
+            .collect::<Vec<_>>();
+
+        let mut seen = FxHashSet::default();
+        // The documented names of the parameters, and the parameters they document.
+        let mut roots: Vec<(&str, Option<&ParamKind>)> = vec![];
+        for name_part in &tags {
+            let name = name_part.parsed();
+            if !seen.insert(name) {
+                ctx.diagnostic(duplicate_param_diagnostic(name_part.span, name));
+                continue;
+            }
+
+            if let Some((root, path)) = name.split_once('.') {
+                if !self.check_destructured {
+                    continue;
+                }
+                let Some(ParamKind::Nested(properties)) =
+                    roots.iter().find(|(name, _)| *name == root).and_then(|(_, param)| *param)
+                else {
+                    continue;
+                };
+                // Any property may be documented when the rest is collected.
+                if properties.iter().any(|property| property.is_rest) {
+                    continue;
+                }
+                if !properties.iter().any(|property| is_name_equal(&property.name, path)) {
+                    ctx.diagnostic(unknown_property_diagnostic(name_part.span, name, root));
+                }
+                continue;
+            }
+
+            let param = params.get(roots.len());
+            roots.push((name, param));
+            match param {
+                None if !self.allow_extra_trailing_param_docs => {
+                    ctx.diagnostic(unknown_param_diagnostic(name_part.span, name));
+                }
+                Some(ParamKind::Single(param)) if param.name != name => {
+                    let is_other_param = params.iter().any(
+                        |param| matches!(param, ParamKind::Single(param) if param.name == name),
+                    );
+                    if is_other_param {
+                        let documented = tags
+                            .iter()
+                            .filter_map(|name_part| {
+                                let name = name_part.parsed();
+                                (!name.contains('.')).then_some(name)
+                            })
+                            .collect::<Vec<_>>();
+                        let expected = params
+                            .iter()
+                            .enumerate()
+                            .map(|(i, param)| match param {
+                                ParamKind::Single(param) => param.name.as_str(),
+                                ParamKind::Nested(_) => {
+                                    documented.get(i).copied().unwrap_or("root")
+                                }
+                            })
+                            .collect::<Vec<_>>();
+                        ctx.diagnostic(wrong_order_diagnostic(
+                            name_part.span,
+                            &expected.join(", "),
+                            &documented.join(", "),
+                        ));
+                        // The other names are only misplaced, so don't report them too.
+                        return;
+                    }
+                    ctx.diagnostic(wrong_name_diagnostic(name_part.span, &param.name, name));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Compares names of properties of destructured parameters without quotes, e.g. `"0"` of an
+/// array pattern.
+fn is_name_equal(a: &str, b: &str) -> bool {
+    a.chars().filter(|&c| c != '"').eq(b.chars().filter(|&c| c != '"'))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "
+            /**
+             * @param {string} foo
+             * @param {string} bar
+             */
+            function quux (foo, bar) {}
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+            /**
+             * @param {string} foo
+             */
+            function quux (foo, bar) {}
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+            /**
+             * @param {object} root
+             * @param {string} root.foo
+             * @param {number} root.bar.baz
+             */
+            function quux ({ foo, bar: { baz } }) {}
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+            /**
+             * @param {object} options
+             * @param {string} options.anything
+             */
+            function quux ({ foo, ...rest }) {}
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+            /**
+             * @param {string} foo
+             * @param {string} extra
+             */
+            const quux = (foo) => {};
+            ",
+            Some(serde_json::json!([{ "allowExtraTrailingParamDocs": true }])),
+            None,
+        ),
+        (
+            "
+            /**
+             * @param {object} root
+             * @param {string} root.baz
+             */
+            function quux ({ foo }) {}
+            ",
+            Some(serde_json::json!([{ "checkDestructured": false }])),
+            None,
+        ),
+        (
+            "
+            /**
+             * @param this
+             * @param {string} foo
+             */
+            function quux (this: Foo, foo) {}
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+            /**
+             * @arg {string} foo
+             */
+            function quux (foo) {}
+            ",
+            None,
+            Some(
+                serde_json::json!({ "settings": { "jsdoc": { "tagNamePreference": { "param": "arg" } } } }),
+            ),
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "
+            /**
+             * @param {string} fo
+             */
+            function quux (foo) {}
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+            /**
+             * @param {string} bar
+             * @param {string} foo
+             */
+            function quux (foo, bar) {}
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+            /**
+             * @param {string} foo
+             * @param {string} foo
+             */
+            function quux (foo) {}
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+            /**
+             * @param {string} foo
+             * @param {string} extra
+             */
+            const quux = (foo) => {};
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+            /**
+             * @param {object} root
+             * @param {string} root.baz
+             */
+            function quux ({ foo, bar }) {}
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+            class Foo {
+                /**
+                 * @param {string} bar
+                 */
+                method(foo) {}
+            }
+            ",
+            None,
+            None,
+        ),
+    ];
+
+    Tester::new(CheckParamNames::NAME, CheckParamNames::PLUGIN, pass, fail).test_and_snapshot();
+}
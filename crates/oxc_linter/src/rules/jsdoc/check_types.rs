@@ -0,0 +1,234 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{should_ignore_as_internal, should_ignore_as_private},
+};
+
+fn check_types_diagnostic(
+    span: Span,
+    tag_name: &str,
+    name: &str,
+    preferred: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Invalid JSDoc `@{tag_name}` type `{name}`."))
+        .with_help(format!("Use `{preferred}` instead."))
+        .with_label(span)
+}
+
+/// Tags whose type is checked. Other tags, like `@example`, may contain braces which are not a
+/// type.
+const TYPE_TAG_NAMES: [&str; 17] = [
+    "arg",
+    "argument",
+    "augments",
+    "callback",
+    "enum",
+    "exception",
+    "extends",
+    "implements",
+    "param",
+    "prop",
+    "property",
+    "return",
+    "returns",
+    "this",
+    "throws",
+    "type",
+    "typedef",
+];
+
+#[derive(Debug, Default, Clone)]
+pub struct CheckTypes;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports the wrapper object types of primitives in JSDoc types, e.g. `String` instead of
+    /// `string`, and `Object` instead of `object`. `Object` with type arguments, like
+    /// `Object<string, number>`, is allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A value of the primitive type, like `"foo"`, is not an instance of its wrapper object
+    /// type, like `new String("foo")`. Using the wrapper type is almost always a mistake, and
+    /// TypeScript does not treat them the same when it checks JSDoc types.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// /**
+    ///  * @param {String} foo
+    ///  * @param {Array<Number>} bar
+    ///  * @returns {Boolean}
+    ///  */
+    /// function quux (foo, bar) {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// /**
+    ///  * @param {string} foo
+    ///  * @param {Array<number>} bar
+    ///  * @returns {boolean}
+    ///  */
+    /// function quux (foo, bar) {}
+    /// ```
+    CheckTypes,
+    jsdoc,
+    pedantic,
+    fix
+);
+
+impl Rule for CheckTypes {
+    fn run_once(&self, ctx: &LintContext) {
+        let settings = &ctx.settings().jsdoc;
+        let source_text = ctx.source_text();
+        let type_tag_names =
+            TYPE_TAG_NAMES.iter().map(|name| settings.resolve_tag_name(name)).collect::<Vec<_>>();
+
+        for jsdoc in ctx
+            .jsdoc()
+            .iter_all()
+            .filter(|jsdoc| !should_ignore_as_internal(jsdoc, settings))
+            .filter(|jsdoc| !should_ignore_as_private(jsdoc, settings))
+        {
+            for tag in jsdoc.tags() {
+                let tag_name = tag.kind.parsed();
+                if !type_tag_names.contains(&tag_name) {
+                    continue;
+                }
+                let Some(type_part) = tag.r#type() else {
+                    continue;
+                };
+                for (name, span) in type_part.type_names() {
+                    let Some(preferred) = get_preferred_type(name) else {
+                        continue;
+                    };
+                    // `Object<K, V>` and `Object.<K, V>` describe the values of a record.
+                    let rest = source_text[span.end as usize..].trim_start();
+                    if name == "Object" && (rest.starts_with('<') || rest.starts_with(".<")) {
+                        continue;
+                    }
+                    ctx.diagnostic_with_fix(
+                        check_types_diagnostic(span, tag_name, name, preferred),
+                        |fixer| fixer.replace(span, preferred),
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn get_preferred_type(name: &str) -> Option<&'static str> {
+    match name {
+        "Boolean" => Some("boolean"),
+        "Number" => Some("number"),
+        "String" => Some("string"),
+        "Symbol" => Some("symbol"),
+        "BigInt" => Some("bigint"),
+        "Object" => Some("object"),
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "
+            /**
+             * @param {string} foo
+             * @param {Array<number>} bar
+             * @returns {boolean}
+             */
+            function quux (foo, bar) {}
+            ",
+            None,
+            None,
+        ),
+        ("/** @type {Object<string, number>} */ let foo;", None, None),
+        ("/** @type {Object.<string, number>} */ let foo;", None, None),
+        ("/** @type {{ String: number }} */ let foo;", None, None),
+        ("/** @type {'String' | \"Number\"} */ let foo;", None, None),
+        ("/** @type {Foo.String} */ let foo;", None, None),
+        ("/** @type {StringLike} */ let foo;", None, None),
+        (
+            "
+            /**
+             * @example {String}
+             * @see {@link String}
+             */
+            function quux () {}
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+            /**
+             * @private
+             * @param {String} foo
+             */
+            function quux (foo) {}
+            ",
+            None,
+            Some(serde_json::json!({ "settings": { "jsdoc": { "ignorePrivate": true } } })),
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "
+            /**
+             * @param {String} foo
+             * @param {Array<Number>} bar
+             * @returns {Boolean}
+             */
+            function quux (foo, bar) {}
+            ",
+            None,
+            None,
+        ),
+        ("/** @type {Object} */ let foo;", None, None),
+        ("/** @type {Symbol | BigInt} */ let foo;", None, None),
+        ("/** @typedef {{ name: String }} Person */", None, None),
+        ("/** @type {(value: Number) => void} */ let foo;", None, None),
+        (
+            "
+            /**
+             * @arg {String} foo
+             */
+            function quux (foo) {}
+            ",
+            None,
+            Some(
+                serde_json::json!({ "settings": { "jsdoc": { "tagNamePreference": { "param": "arg" } } } }),
+            ),
+        ),
+    ];
+
+    let fix = vec![
+        ("/** @type {Object} */ let foo;", "/** @type {object} */ let foo;", None),
+        (
+            "/** @type {Symbol | BigInt} */ let foo;",
+            "/** @type {symbol | bigint} */ let foo;",
+            None,
+        ),
+        (
+            "/** @param {Array<Number>} bar */ function quux (bar) {}",
+            "/** @param {Array<number>} bar */ function quux (bar) {}",
+            None,
+        ),
+    ];
+
+    Tester::new(CheckTypes::NAME, CheckTypes::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
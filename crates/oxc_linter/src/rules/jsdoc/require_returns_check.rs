@@ -0,0 +1,340 @@
+use oxc_ast::{
+    AstKind,
+    ast::{ArrowFunctionExpression, Function, ReturnStatement},
+};
+use oxc_ast_visit::Visit;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{
+        get_function_nearest_jsdoc_node, should_ignore_as_custom_skip, should_ignore_as_internal,
+        should_ignore_as_private,
+    },
+};
+
+fn require_returns_check_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "JSDoc `@returns` declaration is present, but the function does not return a value.",
+    )
+    .with_help("Return a value from the function, or remove the `@returns` tag.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct RequireReturnsCheck(Box<RequireReturnsCheckConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Requires that a function with a `@returns` tag returns a value, with a `return`
+    /// statement with an argument, or an arrow function with an expression body.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A `@returns` tag of a function which doesn't return a value is wrong, which usually
+    /// happens when the function is changed without updating its documentation.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// /**
+    ///  * @returns {string}
+    ///  */
+    /// function quux (foo) {
+    ///   console.log(foo);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// /**
+    ///  * @returns {string}
+    ///  */
+    /// function quux (foo) {
+    ///   return foo;
+    /// }
+    ///
+    /// /**
+    ///  * @returns {void}
+    ///  */
+    /// function quux (foo) {
+    ///   console.log(foo);
+    /// }
+    /// ```
+    RequireReturnsCheck,
+    jsdoc,
+    correctness,
+    config = RequireReturnsCheckConfig,
+);
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RequireReturnsCheckConfig {
+    /// Whether to skip async functions, which always return a `Promise`.
+    exempt_async: bool,
+    /// Whether to skip generator functions, whose `@returns` documents the generator.
+    exempt_generators: bool,
+    /// Whether to report functions without a return value whose `@returns` type is `void`,
+    /// `undefined` or `never`.
+    report_missing_return_for_undefined_types: bool,
+}
+
+impl Default for RequireReturnsCheckConfig {
+    fn default() -> Self {
+        Self {
+            exempt_async: true,
+            exempt_generators: true,
+            report_missing_return_for_undefined_types: false,
+        }
+    }
+}
+
+impl Rule for RequireReturnsCheck {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<RequireReturnsCheck>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let config = &self.0;
+        match node.kind() {
+            AstKind::Function(func) => {
+                let Some(body) = &func.body else {
+                    return;
+                };
+                if (config.exempt_async && func.r#async)
+                    || (config.exempt_generators && func.generator)
+                {
+                    return;
+                }
+                let mut finder = ReturnValueFinder::default();
+                finder.visit_function_body(body);
+                if finder.has_return_value {
+                    return;
+                }
+            }
+            AstKind::ArrowFunctionExpression(arrow_func) => {
+                if arrow_func.expression || (config.exempt_async && arrow_func.r#async) {
+                    return;
+                }
+                let mut finder = ReturnValueFinder::default();
+                finder.visit_function_body(&arrow_func.body);
+                if finder.has_return_value {
+                    return;
+                }
+            }
+            _ => return,
+        }
+
+        let Some(func_def_node) = get_function_nearest_jsdoc_node(node, ctx) else {
+            return;
+        };
+        let Some(jsdocs) = ctx.jsdoc().get_all_by_node(ctx.nodes(), func_def_node) else {
+            return;
+        };
+
+        let settings = &ctx.settings().jsdoc;
+        let resolved_returns_tag_name = settings.resolve_tag_name("returns");
+        for jsdoc in jsdocs
+            .iter()
+            .filter(|jsdoc| !should_ignore_as_custom_skip(jsdoc))
+            .filter(|jsdoc| !should_ignore_as_internal(jsdoc, settings))
+            .filter(|jsdoc| !should_ignore_as_private(jsdoc, settings))
+        {
+            for tag in jsdoc.tags() {
+                if tag.kind.parsed() != resolved_returns_tag_name {
+                    continue;
+                }
+                if !config.report_missing_return_for_undefined_types
+                    && tag.r#type().is_some_and(|type_part| {
+                        matches!(type_part.parsed(), "void" | "undefined" | "never")
+                    })
+                {
+                    continue;
+                }
+                ctx.diagnostic(require_returns_check_diagnostic(tag.kind.span));
+            }
+        }
+    }
+}
+
+/// Finds a `return` with a value in a function body, but not in the functions it contains.
+#[derive(Default)]
+struct ReturnValueFinder {
+    has_return_value: bool,
+}
+
+impl<'a> Visit<'a> for ReturnValueFinder {
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement<'a>) {
+        if stmt.argument.is_some() {
+            self.has_return_value = true;
+        }
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "
+            /**
+             * @returns {string}
+             */
+            function quux (foo) {
+              return foo;
+            }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+            /**
+             * @returns {string}
+             */
+            function quux (foo) {
+              if (foo) {
+                return foo;
+              }
+              throw new Error();
+            }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+            /**
+             * @returns {void}
+             */
+            function quux (foo) {
+              console.log(foo);
+            }
+            ",
+            None,
+            None,
+        ),
+        ("/** @returns {undefined} */ function quux () {}", None, None),
+        ("/** @returns {string} */ const quux = () => 'foo';", None, None),
+        ("/** @returns {Promise<string>} */ async function quux () {}", None, None),
+        ("/** @returns {Generator} */ function * quux () {}", None, None),
+        (
+            "
+            /**
+             * @abstract
+             * @returns {string}
+             */
+            function quux () {}
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+            abstract class Foo {
+              /**
+               * @returns {string}
+               */
+              abstract bar(): string;
+            }
+            ",
+            None,
+            None,
+        ),
+        ("/** Foo. */ function quux () {}", None, None),
+    ];
+
+    let fail = vec![
+        (
+            "
+            /**
+             * @returns {string}
+             */
+            function quux (foo) {
+              console.log(foo);
+            }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+            /**
+             * @returns {string}
+             */
+            function quux (foo) {
+              return;
+            }
+            ",
+            None,
+            None,
+        ),
+        (
+            "
+            /**
+             * @returns {string}
+             */
+            function quux (foo) {
+              function bar () { return foo; }
+              bar();
+            }
+            ",
+            None,
+            None,
+        ),
+        ("/** @returns {string} */ function quux () {}", None, None),
+        (
+            "
+            /**
+             * @returns {string}
+             */
+            const quux = (foo) => {
+              [foo].map((x) => { return x; });
+            };
+            ",
+            None,
+            None,
+        ),
+        (
+            "/** @returns {Promise<string>} */ async function quux () {}",
+            Some(serde_json::json!([{ "exemptAsync": false }])),
+            None,
+        ),
+        (
+            "/** @returns {undefined} */ function quux () {}",
+            Some(serde_json::json!([{ "reportMissingReturnForUndefinedTypes": true }])),
+            None,
+        ),
+        (
+            "
+            class Foo {
+              /**
+               * @returns {string}
+               */
+              bar() {}
+            }
+            ",
+            None,
+            None,
+        ),
+    ];
+
+    Tester::new(RequireReturnsCheck::NAME, RequireReturnsCheck::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,300 @@
+use oxc_ast::{
+    AstKind,
+    ast::{JSXAttributeItem, JSXAttributeValue, JSXChild, JSXElement, JSXExpression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{
+        get_element_type, get_explicit_role, get_jsx_attribute_name, is_hidden_from_screen_reader,
+        is_interactive_html_element, is_interactive_role, is_react_component_name,
+    },
+};
+
+fn control_has_associated_label_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("A control must be associated with a text label.")
+        .with_help("Add text content to the control, or label it with an attribute such as `aria-label` or `aria-labelledby`. You can mark more attributes as labels by configuring the `labelAttributes` option.")
+        .with_label(span)
+}
+
+/// Attributes which always label an element.
+const LABEL_ATTRIBUTES: [&str; 3] = ["alt", "aria-label", "aria-labelledby"];
+
+/// How deep in the children of a control its label is searched for, at most.
+const MAX_DEPTH: u8 = 25;
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct ControlHasAssociatedLabel(Box<ControlHasAssociatedLabelConfig>);
+
+impl std::ops::Deref for ControlHasAssociatedLabel {
+    type Target = ControlHasAssociatedLabelConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ControlHasAssociatedLabelConfig {
+    /// Attributes to check for a label, in addition to `alt`, `aria-label` and
+    /// `aria-labelledby`.
+    label_attributes: Vec<CompactStr>,
+    /// Custom JSX components to be treated as controls. Globs are supported.
+    control_components: Vec<CompactStr>,
+    /// Elements which are not checked, e.g. `input`, which is usually labelled by a separate
+    /// `<label>` element.
+    ignore_elements: Vec<CompactStr>,
+    /// Roles of elements which are not checked.
+    ignore_roles: Vec<CompactStr>,
+    /// How deep in the children of a control its label is searched for. At most 25.
+    depth: u8,
+}
+
+impl Default for ControlHasAssociatedLabelConfig {
+    fn default() -> Self {
+        Self {
+            label_attributes: vec![],
+            control_components: vec![],
+            ignore_elements: vec![],
+            ignore_roles: vec![],
+            depth: 2,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that controls, i.e. interactive elements and elements with interactive roles,
+    /// have a text label.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Assistive technologies announce a control by its label. A control without one, e.g. a
+    /// button with only an icon, is announced as just "button", and its purpose is unknown to
+    /// users who can't see it.
+    ///
+    /// The label may be the text content of the control, or given by an attribute such as
+    /// `aria-label` or `aria-labelledby`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <button />
+    /// <button><span /></button>
+    /// <div role="checkbox" />
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <button>Save</button>
+    /// <button aria-label="Save" />
+    /// <div role="checkbox" aria-labelledby="checkbox-label" />
+    /// <button><Icon /></button>
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "jsx-a11y/control-has-associated-label": [
+    ///     "error",
+    ///     {
+    ///       "labelAttributes": ["label"],
+    ///       "controlComponents": ["CustomComponent"],
+    ///       "ignoreElements": ["audio", "canvas", "embed", "input", "textarea", "tr", "video"],
+    ///       "ignoreRoles": ["grid", "listbox", "menu", "menubar", "radiogroup", "row", "tablist", "toolbar", "tree", "treegrid"],
+    ///       "depth": 3
+    ///     }
+    ///   ]
+    /// }
+    /// ```
+    ControlHasAssociatedLabel,
+    jsx_a11y,
+    pedantic,
+    config = ControlHasAssociatedLabelConfig,
+);
+
+impl Rule for ControlHasAssociatedLabel {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<ControlHasAssociatedLabel>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXElement(element) = node.kind() else {
+            return;
+        };
+        let opening_element = &element.opening_element;
+
+        let element_type = get_element_type(ctx, opening_element);
+        if self.ignore_elements.iter().any(|ignored| ignored == element_type.as_ref()) {
+            return;
+        }
+
+        let role = get_explicit_role(opening_element);
+        if role.is_some_and(|role| self.ignore_roles.iter().any(|ignored| ignored == role)) {
+            return;
+        }
+
+        if is_hidden_from_screen_reader(ctx, opening_element) {
+            return;
+        }
+
+        let is_control = if HTML_TAG.contains(element_type.as_ref()) {
+            is_interactive_html_element(&element_type, opening_element)
+                || role.is_some_and(is_interactive_role)
+        } else {
+            self.is_control_component(&element_type)
+        };
+        if !is_control || self.may_have_accessible_label(element, 0, ctx) {
+            return;
+        }
+
+        ctx.diagnostic(control_has_associated_label_diagnostic(opening_element.span));
+    }
+}
+
+impl ControlHasAssociatedLabel {
+    fn is_control_component(&self, name: &str) -> bool {
+        self.control_components
+            .iter()
+            .any(|component| fast_glob::glob_match(component.as_str(), name))
+    }
+
+    fn is_label_attribute(&self, name: &str) -> bool {
+        LABEL_ATTRIBUTES.contains(&name)
+            || self.label_attributes.iter().any(|attribute| attribute == name)
+    }
+
+    fn may_have_accessible_label<'a>(
+        &self,
+        element: &JSXElement<'a>,
+        depth: u8,
+        ctx: &LintContext<'a>,
+    ) -> bool {
+        let has_label_attribute =
+            element.opening_element.attributes.iter().any(|attribute| match attribute {
+                JSXAttributeItem::Attribute(attr) => {
+                    self.is_label_attribute(&get_jsx_attribute_name(&attr.name))
+                        && attr.value.as_ref().is_some_and(|value| match value {
+                            JSXAttributeValue::StringLiteral(lit) => !lit.value.trim().is_empty(),
+                            JSXAttributeValue::ExpressionContainer(container) => !matches!(
+                                container.expression,
+                                JSXExpression::EmptyExpression(_) | JSXExpression::NullLiteral(_)
+                            ),
+                            _ => true,
+                        })
+                }
+                JSXAttributeItem::SpreadAttribute(_) => true,
+            });
+        if has_label_attribute {
+            return true;
+        }
+
+        // A component without children may render a label of its own.
+        if depth > 0 && element.children.is_empty() {
+            let name = get_element_type(ctx, &element.opening_element);
+            if is_react_component_name(&name) && !self.is_control_component(&name) {
+                return true;
+            }
+        }
+
+        element
+            .children
+            .iter()
+            .any(|child| self.child_may_have_accessible_label(child, depth + 1, ctx))
+    }
+
+    fn child_may_have_accessible_label<'a>(
+        &self,
+        child: &JSXChild<'a>,
+        depth: u8,
+        ctx: &LintContext<'a>,
+    ) -> bool {
+        if depth > self.depth.min(MAX_DEPTH) {
+            return false;
+        }
+        match child {
+            JSXChild::Text(text) => !text.value.trim().is_empty(),
+            JSXChild::ExpressionContainer(container) => !matches!(
+                container.expression,
+                JSXExpression::EmptyExpression(_) | JSXExpression::NullLiteral(_)
+            ),
+            JSXChild::Element(element) => self.may_have_accessible_label(element, depth, ctx),
+            JSXChild::Fragment(fragment) => fragment
+                .children
+                .iter()
+                .any(|child| self.child_may_have_accessible_label(child, depth + 1, ctx)),
+            JSXChild::Spread(_) => true,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r"<button>Save</button>", None),
+        (r#"<button aria-label="Save" />"#, None),
+        (r#"<button aria-labelledby="js_1" />"#, None),
+        (r"<button>{label}</button>", None),
+        (r"<button><span>Save</span></button>", None),
+        (r#"<button><img alt="Save" /></button>"#, None),
+        (r"<button><Icon /></button>", None),
+        (r"<button {...props} />", None),
+        (r#"<button><span aria-label="Save" /></button>"#, None),
+        (r"<button aria-hidden />", None),
+        (r"<button><>Save</></button>", None),
+        (r##"<a href="#">Save</a>"##, None),
+        (r"<a />", None),
+        (r"<div />", None),
+        (r"<div><span /></div>", None),
+        (r#"<div role="checkbox" aria-labelledby="js_1" />"#, None),
+        (r#"<div role="article" />"#, None),
+        (r#"<div role="button">Save</div>"#, None),
+        (r"<CustomControl />", None),
+        (r#"<button label="Save" />"#, Some(serde_json::json!([{ "labelAttributes": ["label"] }]))),
+        (r#"<input type="text" />"#, Some(serde_json::json!([{ "ignoreElements": ["input"] }]))),
+        (r#"<div role="toolbar" />"#, Some(serde_json::json!([{ "ignoreRoles": ["toolbar"] }]))),
+        (
+            r"<button><div><div><span>Save</span></div></div></button>",
+            Some(serde_json::json!([{ "depth": 4 }])),
+        ),
+    ];
+
+    let fail = vec![
+        (r"<button />", None),
+        (r"<button></button>", None),
+        (r"<button>  </button>", None),
+        (r"<button><span /></button>", None),
+        (r#"<button aria-label="" />"#, None),
+        (r"<button>{/* label */}</button>", None),
+        (r##"<a href="#" />"##, None),
+        (r#"<input type="text" />"#, None),
+        (r"<select />", None),
+        (r#"<div role="checkbox" />"#, None),
+        (r#"<span role="button"><span /></span>"#, None),
+        (r"<button><div><div><span>Save</span></div></div></button>", None),
+        (r"<CustomControl />", Some(serde_json::json!([{ "controlComponents": ["Custom*"] }]))),
+        (
+            r"<button><Icon /></button>",
+            Some(serde_json::json!([{ "controlComponents": ["Icon"] }])),
+        ),
+    ];
+
+    Tester::new(ControlHasAssociatedLabel::NAME, ControlHasAssociatedLabel::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
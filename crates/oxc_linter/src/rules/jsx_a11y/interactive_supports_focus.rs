@@ -0,0 +1,247 @@
+use oxc_ast::{
+    AstKind,
+    ast::{JSXAttributeValue, JSXExpression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{
+        KEYBOARD_EVENT_HANDLERS, MOUSE_EVENT_HANDLERS, get_element_type, get_explicit_role,
+        get_prop_value, has_event_handler, has_jsx_prop_ignore_case, is_disabled_element,
+        is_hidden_from_screen_reader, is_interactive_html_element, is_interactive_role,
+        is_non_interactive_html_element, is_presentation_role, parse_jsx_value,
+    },
+};
+
+fn must_be_tabbable_diagnostic(span: Span, role: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Elements with the `{role}` interactive role must be tabbable."))
+        .with_help("Add `tabIndex={0}` to put the element in the tab order.")
+        .with_label(span)
+}
+
+fn must_be_focusable_diagnostic(span: Span, role: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Elements with the `{role}` interactive role must be focusable."))
+        .with_help(
+            "Add `tabIndex={0}` to put the element in the tab order, or `tabIndex={-1}` to make it focusable by script only.",
+        )
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct InteractiveSupportsFocus(Box<InteractiveSupportsFocusConfig>);
+
+impl std::ops::Deref for InteractiveSupportsFocus {
+    type Target = InteractiveSupportsFocusConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct InteractiveSupportsFocusConfig {
+    /// Interactive roles which must be in the tab order, i.e. have a `tabIndex` of `0` or more.
+    /// Elements with other interactive roles only need to be focusable.
+    tabbable: Vec<CompactStr>,
+}
+
+impl Default for InteractiveSupportsFocusConfig {
+    fn default() -> Self {
+        Self {
+            tabbable: [
+                "button",
+                "checkbox",
+                "link",
+                "searchbox",
+                "spinbutton",
+                "switch",
+                "textbox",
+            ]
+            .into_iter()
+            .map(CompactStr::new)
+            .collect(),
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that elements with an interactive role and mouse or keyboard event handlers are
+    /// focusable, with a `tabIndex`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An element with an interactive role, e.g. `<div role="button">`, looks like a control to
+    /// assistive technologies, but unlike a native control it is not focusable. Keyboard users
+    /// can't reach it, and so can't use it.
+    ///
+    /// Native interactive elements, e.g. `<button>` or `<a href>`, are focusable already and
+    /// should be preferred.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <span onClick={submitForm} role="button">Submit</span>
+    /// <a onClick={showNextPage} role="button">Next page</a>
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <div aria-hidden onClick={() => void 0} />
+    /// <span onClick={doSomething} tabIndex="0" role="button">Click me!</span>
+    /// <span onClick={doSomething} tabIndex="-1" role="menuitem">Click me too!</span>
+    /// <a href="javascript:void(0);" onClick={doSomething}>Click ALL the things!</a>
+    /// <button onClick={doSomething}>Click the button :)</button>
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// By default, the `button`, `checkbox`, `link`, `searchbox`, `spinbutton`, `switch` and
+    /// `textbox` roles must be tabbable, and the other interactive roles focusable.
+    /// ```json
+    /// {
+    ///   "jsx-a11y/interactive-supports-focus": ["error", { "tabbable": ["button", "link"] }]
+    /// }
+    /// ```
+    InteractiveSupportsFocus,
+    jsx_a11y,
+    correctness,
+    config = InteractiveSupportsFocusConfig,
+);
+
+impl Rule for InteractiveSupportsFocus {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<InteractiveSupportsFocus>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
+            return;
+        };
+
+        let element_type = get_element_type(ctx, jsx_el);
+        if !HTML_TAG.contains(element_type.as_ref()) {
+            return;
+        }
+
+        if !MOUSE_EVENT_HANDLERS
+            .iter()
+            .chain(KEYBOARD_EVENT_HANDLERS.iter())
+            .any(|handler| has_event_handler(jsx_el, handler))
+        {
+            return;
+        }
+
+        if is_disabled_element(jsx_el)
+            || is_hidden_from_screen_reader(ctx, jsx_el)
+            || is_presentation_role(jsx_el)
+        {
+            return;
+        }
+
+        let Some(role) = get_explicit_role(jsx_el) else {
+            return;
+        };
+        if !is_interactive_role(role)
+            || is_interactive_html_element(&element_type, jsx_el)
+            || is_non_interactive_html_element(&element_type, jsx_el)
+        {
+            return;
+        }
+
+        if has_jsx_prop_ignore_case(jsx_el, "tabIndex")
+            .and_then(get_prop_value)
+            .is_some_and(has_tab_index_value)
+        {
+            return;
+        }
+
+        if self.tabbable.iter().any(|tabbable| tabbable == role) {
+            ctx.diagnostic(must_be_tabbable_diagnostic(jsx_el.span, role));
+        } else {
+            ctx.diagnostic(must_be_focusable_diagnostic(jsx_el.span, role));
+        }
+    }
+}
+
+/// Whether the value of `tabIndex` is a number, or an expression which may be one.
+fn has_tab_index_value(value: &JSXAttributeValue) -> bool {
+    match value {
+        JSXAttributeValue::ExpressionContainer(container) => match &container.expression {
+            JSXExpression::NumericLiteral(_) => true,
+            JSXExpression::StringLiteral(_) | JSXExpression::TemplateLiteral(_) => {
+                parse_jsx_value(value).is_ok()
+            }
+            JSXExpression::NullLiteral(_) | JSXExpression::EmptyExpression(_) => false,
+            expr => !expr.is_undefined(),
+        },
+        _ => parse_jsx_value(value).is_ok(),
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r"<div />", None),
+        (r"<div aria-hidden onClick={() => void 0} />", None),
+        (r"<div aria-hidden={true == true} onClick={() => void 0} />", None),
+        (r#"<div aria-hidden="true" onClick={() => void 0} />"#, None),
+        (r#"<div onClick={() => void 0} role="button" tabIndex="0" />"#, None),
+        (r#"<div onClick={() => void 0} role="button" tabIndex={0} />"#, None),
+        (r#"<div onClick={() => void 0} role="menuitem" tabIndex="-1" />"#, None),
+        (r#"<div onClick={() => void 0} role="button" tabIndex={tabIndex} />"#, None),
+        (r#"<div onClick={() => void 0} role="presentation" />"#, None),
+        (r#"<div onClick={() => void 0} role="none" />"#, None),
+        (r#"<div onClick={() => void 0} role="article" />"#, None),
+        (r"<div onClick={() => void 0} />", None),
+        (r#"<div role="button" />"#, None),
+        (r#"<div role="button" onClick={null} />"#, None),
+        (r#"<div role="button" onClick={() => void 0} disabled />"#, None),
+        (r#"<div role="button" onClick={() => void 0} aria-disabled="true" />"#, None),
+        (r#"<a onClick={() => void 0} href="http://x.y.z" />"#, None),
+        (r#"<a onClick={() => void 0} href="http://x.y.z" role="button" />"#, None),
+        (r#"<button onClick={() => void 0} role="menuitem" />"#, None),
+        (r#"<input type="text" onClick={() => void 0} role="combobox" />"#, None),
+        (r#"<li onClick={() => void 0} role="menuitem" />"#, None),
+        (r#"<MyButton onClick={() => void 0} role="button" />"#, None),
+        (r"<Foo.Bar onClick={() => void 0} aria-hidden={false} />", None),
+    ];
+
+    let fail = vec![
+        (r#"<span onClick={submitForm} role="button">Submit</span>"#, None),
+        (r#"<a onClick={showNextPage} role="button">Next page</a>"#, None),
+        (r#"<div onKeyDown={() => void 0} role="link" />"#, None),
+        (r#"<div onMouseDown={() => void 0} role="checkbox" />"#, None),
+        (r#"<div onClick={() => void 0} role="menuitem" />"#, None),
+        (r#"<div onClick={() => void 0} role="tab" />"#, None),
+        (r#"<span onClick={() => void 0} role="slider" />"#, None),
+        (r#"<div onClick={() => void 0} role="button" aria-disabled="false" />"#, None),
+        (r#"<div onClick={() => void 0} role="button" tabIndex="foo" />"#, None),
+        (
+            r#"<div onClick={() => void 0} role="button" />"#,
+            Some(serde_json::json!([{ "tabbable": [] }])),
+        ),
+        (
+            r#"<div onClick={() => void 0} role="tab" />"#,
+            Some(serde_json::json!([{ "tabbable": ["tab"] }])),
+        ),
+    ];
+
+    Tester::new(InteractiveSupportsFocus::NAME, InteractiveSupportsFocus::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
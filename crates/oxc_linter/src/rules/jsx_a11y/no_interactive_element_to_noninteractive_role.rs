@@ -0,0 +1,204 @@
+use oxc_ast::{AstKind, ast::JSXAttributeItem};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{
+        get_element_type, get_explicit_role, has_jsx_prop, is_interactive_html_element,
+        is_non_interactive_role,
+    },
+};
+
+fn no_interactive_element_to_noninteractive_role_diagnostic(
+    span: Span,
+    element: &str,
+    role: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Interactive elements should not be assigned non-interactive roles.")
+        .with_help(format!(
+            "`<{element}>` is interactive, but the `{role}` role hides it from assistive technologies. Remove the role, or wrap the element in an element with the role."
+        ))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoInteractiveElementToNoninteractiveRole(
+    Box<NoInteractiveElementToNoninteractiveRoleConfig>,
+);
+
+impl std::ops::Deref for NoInteractiveElementToNoninteractiveRole {
+    type Target = NoInteractiveElementToNoninteractiveRoleConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// The non-interactive roles allowed on specific interactive elements, by element name.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(transparent)]
+pub struct NoInteractiveElementToNoninteractiveRoleConfig(FxHashMap<CompactStr, Vec<CompactStr>>);
+
+impl Default for NoInteractiveElementToNoninteractiveRoleConfig {
+    fn default() -> Self {
+        Self(
+            [("tr", vec!["none", "presentation"]), ("canvas", vec!["img"])]
+                .into_iter()
+                .map(|(element, roles)| {
+                    (CompactStr::new(element), roles.into_iter().map(CompactStr::new).collect())
+                })
+                .collect(),
+        )
+    }
+}
+
+impl NoInteractiveElementToNoninteractiveRoleConfig {
+    fn allows(&self, element: &str, role: &str) -> bool {
+        self.0.get(element).is_some_and(|roles| roles.iter().any(|allowed| allowed == role))
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that interactive HTML elements, e.g. `<button>` or `<a href>`, are not given
+    /// non-interactive or presentational roles.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Interactive elements are announced as controls by assistive technologies. A
+    /// non-interactive role, e.g. `role="article"`, or `role="presentation"` makes them
+    /// announce the element as content instead, so that users don't know they can interact with
+    /// it.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <a href="http://x.y.z" role="img" />
+    /// <button role="presentation" />
+    /// <input role="listitem" />
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <div role="button" />
+    /// <button role="menuitem" />
+    /// <tr role="presentation" />
+    /// <canvas role="img" />
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// An object of element names, with the non-interactive roles allowed on them. By default:
+    /// ```json
+    /// {
+    ///   "jsx-a11y/no-interactive-element-to-noninteractive-role": [
+    ///     "error",
+    ///     { "tr": ["none", "presentation"], "canvas": ["img"] }
+    ///   ]
+    /// }
+    /// ```
+    NoInteractiveElementToNoninteractiveRole,
+    jsx_a11y,
+    correctness,
+    config = NoInteractiveElementToNoninteractiveRoleConfig,
+);
+
+impl Rule for NoInteractiveElementToNoninteractiveRole {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoInteractiveElementToNoninteractiveRole>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
+            return;
+        };
+        let Some(role_attr @ JSXAttributeItem::Attribute(_)) = has_jsx_prop(jsx_el, "role") else {
+            return;
+        };
+        let Some(role) = get_explicit_role(jsx_el) else {
+            return;
+        };
+
+        let element_type = get_element_type(ctx, jsx_el);
+        if !HTML_TAG.contains(element_type.as_ref()) {
+            return;
+        }
+
+        if self.allows(&element_type, role) {
+            return;
+        }
+
+        if is_interactive_html_element(&element_type, jsx_el)
+            && (is_non_interactive_role(role) || matches!(role, "none" | "presentation"))
+        {
+            ctx.diagnostic(no_interactive_element_to_noninteractive_role_diagnostic(
+                role_attr.span(),
+                &element_type,
+                role,
+            ));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"<div role="button" />"#, None),
+        (r#"<div role="article" />"#, None),
+        (r#"<span role="presentation" />"#, None),
+        (r"<button />", None),
+        (r#"<button role="menuitem" />"#, None),
+        (r#"<button role="tab" />"#, None),
+        (r#"<a role="img" />"#, None),
+        (r#"<a href="http://x.y.z" role="button" />"#, None),
+        (r#"<input type="checkbox" role="switch" />"#, None),
+        (r#"<input type="hidden" role="img" />"#, None),
+        (r#"<li role="listitem" />"#, None),
+        (r#"<tr role="presentation" />"#, None),
+        (r#"<tr role="none" />"#, None),
+        (r#"<canvas role="img" />"#, None),
+        (r"<button role={ROLE} />", None),
+        (r#"<button role="foo" />"#, None),
+        (r#"<Button role="img" />"#, None),
+        (r#"<tr role="presentation" />"#, Some(serde_json::json!([{ "tr": ["presentation"] }]))),
+    ];
+
+    let fail = vec![
+        (r#"<a href="http://x.y.z" role="img" />"#, None),
+        (r#"<a href="http://x.y.z" role="listitem" />"#, None),
+        (r#"<button role="presentation" />"#, None),
+        (r#"<button role="none" />"#, None),
+        (r#"<button role="article" />"#, None),
+        (r#"<input role="listitem" />"#, None),
+        (r#"<input type="checkbox" role="img" />"#, None),
+        (r#"<select role="heading" />"#, None),
+        (r#"<textarea role="region" />"#, None),
+        (r#"<tr role="listitem" />"#, None),
+        (r#"<canvas role="article" />"#, None),
+        (r#"<button role="img presentation" />"#, None),
+        (r#"<tr role="none" />"#, Some(serde_json::json!([{ "tr": ["presentation"] }]))),
+        (r#"<canvas role="img" />"#, Some(serde_json::json!([{}]))),
+    ];
+
+    Tester::new(
+        NoInteractiveElementToNoninteractiveRole::NAME,
+        NoInteractiveElementToNoninteractiveRole::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
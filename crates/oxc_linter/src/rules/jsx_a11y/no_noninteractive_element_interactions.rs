@@ -0,0 +1,254 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{
+        get_element_type, get_explicit_role, has_event_handler, is_abstract_role,
+        is_content_editable, is_hidden_from_screen_reader, is_interactive_html_element,
+        is_interactive_role, is_non_interactive_html_element, is_non_interactive_role,
+        is_presentation_role,
+    },
+};
+
+fn no_noninteractive_element_interactions_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Non-interactive elements should not be assigned mouse or keyboard event listeners.",
+    )
+    .with_help("Use an interactive element, e.g. `<button>`, instead, or move the event listener to an interactive element inside this one.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoNoninteractiveElementInteractions(Box<NoNoninteractiveElementInteractionsConfig>);
+
+impl std::ops::Deref for NoNoninteractiveElementInteractions {
+    type Target = NoNoninteractiveElementInteractionsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoNoninteractiveElementInteractionsConfig {
+    /// The event handler props which make an element interactive.
+    handlers: Vec<CompactStr>,
+    /// The event handler props allowed on specific elements or roles, by element name or role,
+    /// e.g. `"img": ["onLoad", "onError"]`.
+    #[serde(flatten)]
+    allowed_handlers: FxHashMap<CompactStr, Vec<CompactStr>>,
+}
+
+impl Default for NoNoninteractiveElementInteractionsConfig {
+    fn default() -> Self {
+        let handlers = |handlers: &[&str]| handlers.iter().copied().map(CompactStr::new).collect();
+        Self {
+            handlers: handlers(&[
+                "onClick",
+                "onError",
+                "onLoad",
+                "onMouseDown",
+                "onMouseUp",
+                "onKeyPress",
+                "onKeyDown",
+                "onKeyUp",
+            ]),
+            allowed_handlers: [
+                ("alert", handlers(&["onKeyUp", "onKeyDown", "onKeyPress"])),
+                ("body", handlers(&["onError", "onLoad"])),
+                ("dialog", handlers(&["onKeyDown", "onKeyUp", "onKeyPress"])),
+                ("iframe", handlers(&["onError", "onLoad"])),
+                ("img", handlers(&["onError", "onLoad"])),
+            ]
+            .into_iter()
+            .map(|(name, handlers)| (CompactStr::new(name), handlers))
+            .collect(),
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that non-interactive elements, i.e. elements with non-interactive semantics
+    /// such as `<li>` or `<h1>`, or with a non-interactive `role`, don't have mouse or keyboard
+    /// event handlers.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Non-interactive elements present content and containers to assistive technologies,
+    /// which don't announce them as something to interact with. Event handlers on them are
+    /// invisible to users of assistive technologies, and usually out of reach of keyboard
+    /// users.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <li onClick={() => void 0} />
+    /// <div onClick={() => void 0} role="listitem" />
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <div onClick={() => void 0} role="button" />
+    /// <div onClick={() => void 0} role="presentation" />
+    /// <input type="text" onClick={() => void 0} />
+    /// <button onClick={() => void 0} className="foo" />
+    /// <img onLoad={() => void 0} src="foo.png" alt="" />
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `handlers` are the event handler props to check. Other keys are element names or roles,
+    /// with the handlers allowed on them. By default:
+    /// ```json
+    /// {
+    ///   "jsx-a11y/no-noninteractive-element-interactions": [
+    ///     "error",
+    ///     {
+    ///       "handlers": ["onClick", "onError", "onLoad", "onMouseDown", "onMouseUp", "onKeyPress", "onKeyDown", "onKeyUp"],
+    ///       "alert": ["onKeyUp", "onKeyDown", "onKeyPress"],
+    ///       "body": ["onError", "onLoad"],
+    ///       "dialog": ["onKeyDown", "onKeyUp", "onKeyPress"],
+    ///       "iframe": ["onError", "onLoad"],
+    ///       "img": ["onError", "onLoad"]
+    ///     }
+    ///   ]
+    /// }
+    /// ```
+    NoNoninteractiveElementInteractions,
+    jsx_a11y,
+    correctness,
+    config = NoNoninteractiveElementInteractionsConfig,
+);
+
+impl Rule for NoNoninteractiveElementInteractions {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoNoninteractiveElementInteractions>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
+            return;
+        };
+
+        let element_type = get_element_type(ctx, jsx_el);
+        if !HTML_TAG.contains(element_type.as_ref()) {
+            return;
+        }
+
+        let role = get_explicit_role(jsx_el);
+        let is_allowed = |handler: &CompactStr| {
+            [Some(element_type.as_ref()), role].into_iter().flatten().any(|name| {
+                self.allowed_handlers.get(name).is_some_and(|allowed| allowed.contains(handler))
+            })
+        };
+        if !self
+            .handlers
+            .iter()
+            .any(|handler| !is_allowed(handler) && has_event_handler(jsx_el, handler))
+        {
+            return;
+        }
+
+        if is_content_editable(jsx_el)
+            || is_hidden_from_screen_reader(ctx, jsx_el)
+            || is_presentation_role(jsx_el)
+        {
+            return;
+        }
+
+        if is_interactive_html_element(&element_type, jsx_el)
+            || role.is_some_and(|role| is_interactive_role(role) || is_abstract_role(role))
+        {
+            return;
+        }
+
+        let is_non_interactive = match role {
+            Some(role) => is_non_interactive_role(role),
+            None => is_non_interactive_html_element(&element_type, jsx_el),
+        };
+        if !is_non_interactive {
+            return;
+        }
+
+        ctx.diagnostic(no_noninteractive_element_interactions_diagnostic(jsx_el.span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"<div className="foo" />"#, None),
+        (r"<div onClick={() => void 0} />", None),
+        (r"<span onClick={() => void 0} />", None),
+        (r"<a onClick={() => void 0} />", None),
+        (r#"<a onClick={() => void 0} href="http://x.y.z" />"#, None),
+        (r"<button onClick={() => void 0} />", None),
+        (r#"<input type="text" onClick={() => void 0} />"#, None),
+        (r"<select onChange={() => void 0} />", None),
+        (r"<textarea onKeyDown={() => void 0} />", None),
+        (r"<audio onClick={() => void 0} />", None),
+        (r"<li onMouseEnter={() => void 0} />", None),
+        (r"<li onClick={null} />", None),
+        (r"<li onClick={() => void 0} aria-hidden />", None),
+        (r#"<li onClick={() => void 0} role="presentation" />"#, None),
+        (r#"<li onClick={() => void 0} role="menuitem" />"#, None),
+        (r#"<li onClick={() => void 0} contentEditable="true" />"#, None),
+        (r#"<div onClick={() => void 0} role="button" />"#, None),
+        (r#"<div onClick={() => void 0} role="widget" />"#, None),
+        (r#"<img onLoad={() => void 0} src="foo.png" alt="" />"#, None),
+        (r#"<img onError={() => void 0} src="foo.png" alt="foo" />"#, None),
+        (r"<iframe onLoad={() => void 0} />", None),
+        (r"<body onLoad={() => void 0} />", None),
+        (r"<dialog onKeyDown={() => void 0} />", None),
+        (r#"<div role="alert" onKeyDown={() => void 0} />"#, None),
+        (r"<TestComponent onClick={doFoo} />", None),
+        (
+            r"<li onClick={() => void 0} />",
+            Some(serde_json::json!([{ "handlers": ["onKeyDown"] }])),
+        ),
+        (r"<li onClick={() => void 0} />", Some(serde_json::json!([{ "li": ["onClick"] }]))),
+    ];
+
+    let fail = vec![
+        (r"<li onClick={() => void 0} />", None),
+        (r"<li onKeyDown={() => void 0} />", None),
+        (r"<article onMouseDown={() => void 0} />", None),
+        (r"<h1 onClick={() => void 0} />", None),
+        (r"<label onClick={() => void 0} />", None),
+        (r"<main onKeyUp={() => void 0} />", None),
+        (r"<section onClick={() => void 0} />", None),
+        (r#"<img onClick={() => void 0} src="foo.png" alt="" role="img" />"#, None),
+        (r"<img onClick={() => void 0} />", None),
+        (r"<dialog onClick={() => void 0} />", None),
+        (r#"<div onClick={() => void 0} role="listitem" />"#, None),
+        (r#"<div onClick={() => void 0} role="article" />"#, None),
+        (r#"<div onMouseUp={() => void 0} role="heading" />"#, None),
+        (r#"<div role="alert" onClick={() => void 0} />"#, None),
+        (r"<img onLoad={() => void 0} />", Some(serde_json::json!([{ "handlers": ["onLoad"] }]))),
+    ];
+
+    Tester::new(
+        NoNoninteractiveElementInteractions::NAME,
+        NoNoninteractiveElementInteractions::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
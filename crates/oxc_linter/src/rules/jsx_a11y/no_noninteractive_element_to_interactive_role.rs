@@ -0,0 +1,226 @@
+use oxc_ast::{AstKind, ast::JSXAttributeItem};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{
+        get_element_type, get_explicit_role, has_jsx_prop, is_interactive_role,
+        is_non_interactive_html_element,
+    },
+};
+
+fn no_noninteractive_element_to_interactive_role_diagnostic(
+    span: Span,
+    element: &str,
+    role: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Non-interactive elements should not be assigned interactive roles.")
+        .with_help(format!(
+            "`<{element}>` has non-interactive semantics. Use a native element for the `{role}` role, or wrap the content of `<{element}>` in an element with the role."
+        ))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoNoninteractiveElementToInteractiveRole(
+    Box<NoNoninteractiveElementToInteractiveRoleConfig>,
+);
+
+impl std::ops::Deref for NoNoninteractiveElementToInteractiveRole {
+    type Target = NoNoninteractiveElementToInteractiveRoleConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// The interactive roles allowed on specific non-interactive elements, by element name.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(transparent)]
+pub struct NoNoninteractiveElementToInteractiveRoleConfig(FxHashMap<CompactStr, Vec<CompactStr>>);
+
+impl Default for NoNoninteractiveElementToInteractiveRoleConfig {
+    fn default() -> Self {
+        let list_roles =
+            || vec!["listbox", "menu", "menubar", "radiogroup", "tablist", "tree", "treegrid"];
+        Self(
+            [
+                ("ul", list_roles()),
+                ("ol", list_roles()),
+                (
+                    "li",
+                    vec![
+                        "menuitem",
+                        "menuitemradio",
+                        "menuitemcheckbox",
+                        "option",
+                        "row",
+                        "tab",
+                        "treeitem",
+                    ],
+                ),
+                ("table", vec!["grid"]),
+                ("td", vec!["gridcell"]),
+                ("fieldset", vec!["radiogroup", "presentation"]),
+            ]
+            .into_iter()
+            .map(|(element, roles)| {
+                (CompactStr::new(element), roles.into_iter().map(CompactStr::new).collect())
+            })
+            .collect(),
+        )
+    }
+}
+
+impl NoNoninteractiveElementToInteractiveRoleConfig {
+    fn allows(&self, element: &str, role: &str) -> bool {
+        self.0.get(element).is_some_and(|roles| roles.iter().any(|allowed| allowed == role))
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that HTML elements with non-interactive semantics, e.g. `<h1>` or `<li>`, are
+    /// not given interactive roles.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An interactive role makes assistive technologies announce the element as a control, but
+    /// doesn't make it behave like one: it is not focusable, and doesn't respond to the keyboard.
+    /// Its own semantics, e.g. a heading, are lost as well. Native interactive elements should
+    /// be used instead, or the content of the element wrapped in an element with the role.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <h1 role="button">Save</h1>
+    /// <li role="link" />
+    /// <article role="tab" />
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <h1><button>Save</button></h1>
+    /// <div role="button" />
+    /// <ul role="menu"><li role="menuitem" /></ul>
+    /// <table role="grid" />
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// An object of element names, with the interactive roles allowed on them. By default:
+    /// ```json
+    /// {
+    ///   "jsx-a11y/no-noninteractive-element-to-interactive-role": [
+    ///     "error",
+    ///     {
+    ///       "ul": ["listbox", "menu", "menubar", "radiogroup", "tablist", "tree", "treegrid"],
+    ///       "ol": ["listbox", "menu", "menubar", "radiogroup", "tablist", "tree", "treegrid"],
+    ///       "li": ["menuitem", "menuitemradio", "menuitemcheckbox", "option", "row", "tab", "treeitem"],
+    ///       "table": ["grid"],
+    ///       "td": ["gridcell"],
+    ///       "fieldset": ["radiogroup", "presentation"]
+    ///     }
+    ///   ]
+    /// }
+    /// ```
+    NoNoninteractiveElementToInteractiveRole,
+    jsx_a11y,
+    correctness,
+    config = NoNoninteractiveElementToInteractiveRoleConfig,
+);
+
+impl Rule for NoNoninteractiveElementToInteractiveRole {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoNoninteractiveElementToInteractiveRole>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
+            return;
+        };
+        let Some(role_attr @ JSXAttributeItem::Attribute(_)) = has_jsx_prop(jsx_el, "role") else {
+            return;
+        };
+        let Some(role) = get_explicit_role(jsx_el) else {
+            return;
+        };
+
+        let element_type = get_element_type(ctx, jsx_el);
+        if !HTML_TAG.contains(element_type.as_ref()) {
+            return;
+        }
+
+        if self.allows(&element_type, role) {
+            return;
+        }
+
+        if is_non_interactive_html_element(&element_type, jsx_el) && is_interactive_role(role) {
+            ctx.diagnostic(no_noninteractive_element_to_interactive_role_diagnostic(
+                role_attr.span(),
+                &element_type,
+                role,
+            ));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"<div role="button" />"#, None),
+        (r#"<span role="link" />"#, None),
+        (r#"<a role="button" />"#, None),
+        (r#"<button role="menuitem" />"#, None),
+        (r#"<h1 role="presentation" />"#, None),
+        (r#"<h1 role="heading" />"#, None),
+        (r#"<li role="article" />"#, None),
+        (r#"<ul role="menu" />"#, None),
+        (r#"<ol role="tablist" />"#, None),
+        (r#"<li role="menuitem" />"#, None),
+        (r#"<li role="tab" />"#, None),
+        (r#"<table role="grid" />"#, None),
+        (r#"<td role="gridcell" />"#, None),
+        (r#"<fieldset role="radiogroup" />"#, None),
+        (r"<h1 role={ROLE} />", None),
+        (r#"<h1 role="foo" />"#, None),
+        (r#"<Heading role="button" />"#, None),
+        (r#"<article role="tab" />"#, Some(serde_json::json!([{ "article": ["tab"] }]))),
+    ];
+
+    let fail = vec![
+        (r#"<h1 role="button" />"#, None),
+        (r#"<li role="link" />"#, None),
+        (r#"<article role="tab" />"#, None),
+        (r#"<main role="listbox" />"#, None),
+        (r#"<p role="checkbox" />"#, None),
+        (r#"<img role="button" />"#, None),
+        (r#"<label role="textbox" />"#, None),
+        (r#"<section role="toolbar" />"#, None),
+        (r#"<ul role="button" />"#, None),
+        (r#"<h2 role="foo button" />"#, None),
+        (r#"<li role="menuitem" />"#, Some(serde_json::json!([{ "ul": ["menu"] }]))),
+    ];
+
+    Tester::new(
+        NoNoninteractiveElementToInteractiveRole::NAME,
+        NoNoninteractiveElementToInteractiveRole::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
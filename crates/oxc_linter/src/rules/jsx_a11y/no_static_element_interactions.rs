@@ -0,0 +1,240 @@
+use oxc_ast::{
+    AstKind,
+    ast::{JSXAttributeItem, JSXAttributeValue, JSXExpression, JSXOpeningElement},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{
+        get_element_type, get_explicit_role, has_event_handler, has_jsx_prop, is_abstract_role,
+        is_content_editable, is_hidden_from_screen_reader, is_interactive_html_element,
+        is_interactive_role, is_non_interactive_html_element, is_non_interactive_role,
+        is_presentation_role,
+    },
+};
+
+fn no_static_element_interactions_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Avoid non-native interactive elements.")
+        .with_help("Use a native interactive element, e.g. `<button>`, or add an appropriate `role` and support for tabbing, mouse, key press and touch inputs to the element with this event handler.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoStaticElementInteractions(Box<NoStaticElementInteractionsConfig>);
+
+impl std::ops::Deref for NoStaticElementInteractions {
+    type Target = NoStaticElementInteractionsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoStaticElementInteractionsConfig {
+    /// The event handler props which make an element interactive.
+    handlers: Vec<CompactStr>,
+    /// Whether to allow a `role` given by an expression, e.g. `role={ROLE}` or
+    /// `role={isButton ? "button" : "link"}`, assuming it gives the element an appropriate role.
+    allow_expression_values: bool,
+}
+
+impl Default for NoStaticElementInteractionsConfig {
+    fn default() -> Self {
+        Self {
+            handlers: ["onClick", "onMouseDown", "onMouseUp", "onKeyPress", "onKeyDown", "onKeyUp"]
+                .into_iter()
+                .map(CompactStr::new)
+                .collect(),
+            allow_expression_values: true,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that static HTML elements, i.e. elements without semantics such as `<div>` or
+    /// `<span>`, with event handlers have a `role`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A static element with a click or key handler behaves like a control, but assistive
+    /// technologies can't tell what it is, or that it can be interacted with at all. Either use
+    /// a native interactive element, such as `<button>`, or give the element the role of the
+    /// widget it implements.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <div onClick={() => {}} />
+    /// <span onKeyDown={handleKeyDown} />
+    /// <a onClick={() => {}} />
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <button onClick={() => {}} className="foo" />
+    /// <div className="foo" onClick={() => {}} role="button" />
+    /// <input type="text" onClick={() => {}} />
+    /// <div onClick={() => {}} role="presentation" />
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// By default, `onClick`, `onMouseDown`, `onMouseUp`, `onKeyPress`, `onKeyDown` and
+    /// `onKeyUp` are checked, and roles given by expressions are allowed.
+    /// ```json
+    /// {
+    ///   "jsx-a11y/no-static-element-interactions": [
+    ///     "error",
+    ///     { "handlers": ["onClick", "onKeyDown"], "allowExpressionValues": false }
+    ///   ]
+    /// }
+    /// ```
+    NoStaticElementInteractions,
+    jsx_a11y,
+    correctness,
+    config = NoStaticElementInteractionsConfig,
+);
+
+impl Rule for NoStaticElementInteractions {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoStaticElementInteractions>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
+            return;
+        };
+
+        let element_type = get_element_type(ctx, jsx_el);
+        if !HTML_TAG.contains(element_type.as_ref()) {
+            return;
+        }
+
+        if !self.handlers.iter().any(|handler| has_event_handler(jsx_el, handler)) {
+            return;
+        }
+
+        if is_content_editable(jsx_el)
+            || is_hidden_from_screen_reader(ctx, jsx_el)
+            || is_presentation_role(jsx_el)
+        {
+            return;
+        }
+
+        if is_interactive_html_element(&element_type, jsx_el)
+            || is_non_interactive_html_element(&element_type, jsx_el)
+        {
+            return;
+        }
+
+        if let Some(role) = get_explicit_role(jsx_el)
+            && (is_interactive_role(role)
+                || is_non_interactive_role(role)
+                || is_abstract_role(role))
+        {
+            return;
+        }
+
+        if self.allow_expression_values && has_expression_role(jsx_el) {
+            return;
+        }
+
+        ctx.diagnostic(no_static_element_interactions_diagnostic(jsx_el.span));
+    }
+}
+
+/// Whether the `role` of the element is given by an expression other than a literal.
+fn has_expression_role(jsx_el: &JSXOpeningElement) -> bool {
+    let Some(JSXAttributeItem::Attribute(attr)) = has_jsx_prop(jsx_el, "role") else {
+        return false;
+    };
+    let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value else {
+        return false;
+    };
+    !matches!(
+        &container.expression,
+        JSXExpression::EmptyExpression(_)
+            | JSXExpression::StringLiteral(_)
+            | JSXExpression::NullLiteral(_)
+    ) && !container.expression.is_undefined()
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"<div className="foo" />"#, None),
+        (r#"<div className="foo" {...props} />"#, None),
+        (r"<div onClick={() => void 0} aria-hidden />", None),
+        (r"<div onClick={() => void 0} aria-hidden={true} />", None),
+        (r"<div onClick={null} />", None),
+        (r"<div onKeyUp={undefined} />", None),
+        (r"<div onMouseEnter={() => void 0} />", None),
+        (r"<div onFocus={() => void 0} />", None),
+        (r#"<div onClick={() => void 0} role="presentation" />"#, None),
+        (r#"<div onClick={() => void 0} role="none" />"#, None),
+        (r#"<div onClick={() => void 0} role="button" />"#, None),
+        (r#"<div onClick={() => void 0} role="article" />"#, None),
+        (r#"<div onClick={() => void 0} role="widget" />"#, None),
+        (r#"<div onClick={() => void 0} role="button link" />"#, None),
+        (r"<div onClick={() => void 0} contentEditable />", None),
+        (r#"<div onClick={() => void 0} contentEditable="true" />"#, None),
+        (r"<div onClick={() => void 0} role={ROLE} />", None),
+        (r#"<div onClick={() => void 0} role={isButton ? "button" : "link"} />"#, None),
+        (r#"<a onClick={() => void 0} href="http://x.y.z" />"#, None),
+        (r"<button onClick={() => void 0} />", None),
+        (r#"<input type="text" onClick={() => void 0} />"#, None),
+        (r"<select onClick={() => void 0} />", None),
+        (r"<textarea onClick={() => void 0} />", None),
+        (r"<audio onClick={() => void 0} />", None),
+        (r"<li onClick={() => void 0} />", None),
+        (r"<article onClick={() => void 0} />", None),
+        (r"<label onClick={() => void 0} />", None),
+        (r"<TestComponent onClick={doFoo} />", None),
+        (r"<Button onClick={doFoo} />", None),
+        (r"<div onChange={() => void 0} />", None),
+        (
+            r"<div onMouseEnter={() => void 0} />",
+            Some(serde_json::json!([{ "handlers": ["onClick"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        (r"<div onClick={() => void 0} />", None),
+        (r"<div onClick={() => void 0} onKeyDown={foo} />", None),
+        (r"<div onKeyUp={() => void 0} />", None),
+        (r"<div onMouseDown={() => void 0} />", None),
+        (r"<span onClick={() => void 0} />", None),
+        (r"<a onClick={() => void 0} />", None),
+        (r"<span onClick={() => void 0} role={undefined} />", None),
+        (r#"<div onClick={() => void 0} role="foo" />"#, None),
+        (r#"<div onClick={() => void 0} contentEditable="false" />"#, None),
+        (
+            r"<div onClick={() => void 0} role={ROLE} />",
+            Some(serde_json::json!([{ "allowExpressionValues": false }])),
+        ),
+        (
+            r"<div onMouseEnter={() => void 0} />",
+            Some(serde_json::json!([{ "handlers": ["onMouseEnter"] }])),
+        ),
+    ];
+
+    Tester::new(NoStaticElementInteractions::NAME, NoStaticElementInteractions::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,363 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, BindingPattern, ImportDeclarationSpecifier},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    ast_util::is_global_require_call,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{NodeVersion, get_node_module_path, strip_node_protocol, supported_node_version_range},
+};
+
+fn no_deprecated_api_diagnostic(span: Span, path: &str, api: &DeprecatedApi) -> OxcDiagnostic {
+    let name = match api.kind {
+        DeprecatedKind::Module => format!("The `{path}` module"),
+        DeprecatedKind::Member => format!("`{path}`"),
+        DeprecatedKind::Call => format!("Calling `{path}()`"),
+    };
+    let help = match api.replacement {
+        Some(replacement) => format!("Use {replacement} instead."),
+        None => "Avoid using it, as it may be removed in a future version of Node.js.".to_string(),
+    };
+    OxcDiagnostic::warn(format!("{name} is deprecated since Node.js {}.", api.since))
+        .with_help(help)
+        .with_label(span)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DeprecatedKind {
+    /// A whole module, e.g. `domain`.
+    Module,
+    /// A member of a module or a global, e.g. `fs.exists`.
+    Member,
+    /// A function which is deprecated when called, but not otherwise, e.g. `Buffer()`.
+    Call,
+}
+
+#[derive(Debug)]
+struct DeprecatedApi {
+    kind: DeprecatedKind,
+    /// The version of Node.js which deprecated the API.
+    since: &'static str,
+    replacement: Option<&'static str>,
+}
+
+const fn deprecated(
+    kind: DeprecatedKind,
+    since: &'static str,
+    replacement: Option<&'static str>,
+) -> DeprecatedApi {
+    DeprecatedApi { kind, since, replacement }
+}
+
+use DeprecatedKind::{Call, Member, Module};
+
+/// Deprecated modules, module members and globals, by path, e.g. `fs.exists`.
+static DEPRECATED_APIS: phf::Map<&'static str, DeprecatedApi> = phf::phf_map! {
+    "_linklist" => deprecated(Module, "5.0.0", None),
+    "_stream_wrap" => deprecated(Module, "12.0.0", None),
+    "constants" => deprecated(Module, "6.3.0", Some("the `constants` property of each module")),
+    "domain" => deprecated(Module, "4.0.0", None),
+    "punycode" => deprecated(Module, "7.0.0", Some("the `punycode` package from npm")),
+    "sys" => deprecated(Module, "1.0.0", Some("the `util` module")),
+
+    "Buffer" => deprecated(Call, "6.0.0", Some("`Buffer.alloc()` or `Buffer.from()`")),
+    "buffer.Buffer" => deprecated(Call, "6.0.0", Some("`Buffer.alloc()` or `Buffer.from()`")),
+    "buffer.SlowBuffer" => deprecated(Member, "6.0.0", Some("`Buffer.allocUnsafeSlow()`")),
+    "crypto._toBuf" => deprecated(Member, "11.0.0", None),
+    "crypto.Credentials" => deprecated(Member, "0.12.0", Some("`tls.SecureContext`")),
+    "crypto.DEFAULT_ENCODING" => deprecated(Member, "4.0.0", None),
+    "crypto.createCipher" => deprecated(Member, "10.0.0", Some("`crypto.createCipheriv()`")),
+    "crypto.createCredentials" => deprecated(Member, "0.12.0", Some("`tls.createSecureContext()`")),
+    "crypto.createDecipher" => deprecated(Member, "10.0.0", Some("`crypto.createDecipheriv()`")),
+    "crypto.fips" => deprecated(Member, "10.0.0", Some("`crypto.getFips()` and `crypto.setFips()`")),
+    "crypto.prng" => deprecated(Member, "11.0.0", Some("`crypto.randomBytes()`")),
+    "crypto.pseudoRandomBytes" => deprecated(Member, "11.0.0", Some("`crypto.randomBytes()`")),
+    "crypto.rng" => deprecated(Member, "11.0.0", Some("`crypto.randomBytes()`")),
+    "events.EventEmitter.listenerCount" => deprecated(Member, "3.2.0", Some("`emitter.listenerCount()`")),
+    "events.listenerCount" => deprecated(Member, "3.2.0", Some("`emitter.listenerCount()`")),
+    "fs.SyncWriteStream" => deprecated(Member, "4.0.0", None),
+    "fs.exists" => deprecated(Member, "4.0.0", Some("`fs.stat()` or `fs.access()`")),
+    "module.createRequireFromPath" => deprecated(Member, "12.2.0", Some("`module.createRequire()`")),
+    "os.getNetworkInterfaces" => deprecated(Member, "0.6.0", Some("`os.networkInterfaces()`")),
+    "os.tmpDir" => deprecated(Member, "7.0.0", Some("`os.tmpdir()`")),
+    "path._makeLong" => deprecated(Member, "9.0.0", Some("`path.toNamespacedPath()`")),
+    "process.EventEmitter" => deprecated(Member, "0.6.0", Some("the `events` module")),
+    "process.assert" => deprecated(Member, "10.0.0", Some("the `assert` module")),
+    "process.binding" => deprecated(Member, "10.12.0", None),
+    "process.env.NODE_REPL_HISTORY_FILE" => deprecated(Member, "4.0.0", Some("`NODE_REPL_HISTORY`")),
+    "repl.REPL_MODE_MAGIC" => deprecated(Member, "8.0.0", None),
+    "tls.CleartextStream" => deprecated(Member, "0.10.0", None),
+    "tls.CryptoStream" => deprecated(Member, "0.12.0", Some("`tls.TLSSocket`")),
+    "tls.SecurePair" => deprecated(Member, "6.0.0", Some("`tls.TLSSocket`")),
+    "tls.createSecurePair" => deprecated(Member, "6.0.0", Some("`tls.TLSSocket`")),
+    "tls.parseCertString" => deprecated(Member, "8.6.0", Some("`querystring.parse()`")),
+    "tty.setRawMode" => deprecated(Member, "0.10.0", Some("`tty.ReadStream#setRawMode()`")),
+    "url.parse" => deprecated(Member, "11.0.0", Some("`new URL()`")),
+    "url.resolve" => deprecated(Member, "11.0.0", Some("`new URL()`")),
+    "util._extend" => deprecated(Member, "6.0.0", Some("`Object.assign()`")),
+    "util.debug" => deprecated(Member, "0.12.0", Some("`console.error()`")),
+    "util.error" => deprecated(Member, "0.12.0", Some("`console.error()`")),
+    "util.isArray" => deprecated(Member, "4.0.0", Some("`Array.isArray()`")),
+    "util.isBoolean" => deprecated(Member, "4.0.0", None),
+    "util.isBuffer" => deprecated(Member, "4.0.0", Some("`Buffer.isBuffer()`")),
+    "util.isDate" => deprecated(Member, "4.0.0", None),
+    "util.isError" => deprecated(Member, "4.0.0", None),
+    "util.isFunction" => deprecated(Member, "4.0.0", None),
+    "util.isNull" => deprecated(Member, "4.0.0", None),
+    "util.isNullOrUndefined" => deprecated(Member, "4.0.0", None),
+    "util.isNumber" => deprecated(Member, "4.0.0", None),
+    "util.isObject" => deprecated(Member, "4.0.0", None),
+    "util.isPrimitive" => deprecated(Member, "4.0.0", None),
+    "util.isRegExp" => deprecated(Member, "4.0.0", None),
+    "util.isString" => deprecated(Member, "4.0.0", None),
+    "util.isSymbol" => deprecated(Member, "4.0.0", None),
+    "util.isUndefined" => deprecated(Member, "4.0.0", None),
+    "util.log" => deprecated(Member, "6.0.0", None),
+    "util.print" => deprecated(Member, "0.12.0", Some("`console.log()`")),
+    "util.pump" => deprecated(Member, "0.10.0", Some("`stream.pipeline()`")),
+    "util.puts" => deprecated(Member, "0.12.0", Some("`console.log()`")),
+    "vm.runInDebugContext" => deprecated(Member, "8.0.0", None),
+    "GLOBAL" => deprecated(Member, "6.0.0", Some("`globalThis`")),
+    "root" => deprecated(Member, "6.0.0", Some("`globalThis`")),
+    "require.extensions" => deprecated(Member, "0.12.0", None),
+};
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoDeprecatedApi(Box<NoDeprecatedApiConfig>);
+
+impl std::ops::Deref for NoDeprecatedApi {
+    type Target = NoDeprecatedApiConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoDeprecatedApiConfig {
+    /// The range of Node.js versions to support, e.g. `">=18.0.0"`. By default, the `engines`
+    /// field of the nearest `package.json`, or `">=16.0.0"` without one.
+    version: Option<String>,
+    /// Module members not to report, e.g. `fs.exists`, or whole modules, e.g. `domain`.
+    ignore_module_items: Vec<CompactStr>,
+    /// Globals not to report, e.g. `process.binding` or `new Buffer()`.
+    ignore_global_items: Vec<CompactStr>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows deprecated Node.js APIs, e.g. `fs.exists` or `new Buffer()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deprecated APIs are kept for compatibility, but may be removed in later versions of
+    /// Node.js. Most have replacements which are safer or better supported.
+    ///
+    /// Only APIs deprecated in the Node.js versions the package supports are reported, as given
+    /// by the `engines` field of its `package.json`, so that their replacements are available.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const fs = require("fs");
+    /// fs.exists("./foo", (exists) => {});
+    ///
+    /// import { isArray } from "node:util";
+    /// const buffer = new Buffer(10);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const fs = require("fs");
+    /// fs.access("./foo", (error) => {});
+    ///
+    /// Array.isArray(value);
+    /// const buffer = Buffer.alloc(10);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "node/no-deprecated-api": [
+    ///     "error",
+    ///     {
+    ///       "version": ">=18.0.0",
+    ///       "ignoreModuleItems": ["url.parse"],
+    ///       "ignoreGlobalItems": ["process.binding"]
+    ///     }
+    ///   ]
+    /// }
+    /// ```
+    NoDeprecatedApi,
+    node,
+    suspicious,
+    config = NoDeprecatedApiConfig,
+);
+
+impl Rule for NoDeprecatedApi {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoDeprecatedApi>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ImportDeclaration(import) => {
+                let module = strip_node_protocol(&import.source.value);
+                self.check(module, import.source.span, false, ctx);
+                for specifier in import.specifiers.iter().flatten() {
+                    if let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier {
+                        let path = format!("{module}.{}", specifier.imported.name());
+                        self.check(&path, specifier.span, false, ctx);
+                    }
+                }
+            }
+            AstKind::VariableDeclarator(declarator) => {
+                let (BindingPattern::ObjectPattern(pattern), Some(init)) =
+                    (&declarator.id, &declarator.init)
+                else {
+                    return;
+                };
+                let Some(object) = get_node_module_path(init, ctx) else {
+                    return;
+                };
+                for property in &pattern.properties {
+                    if let Some(name) = property.key.static_name() {
+                        self.check(&format!("{object}.{name}"), property.span, false, ctx);
+                    }
+                }
+            }
+            AstKind::CallExpression(call) => {
+                if is_global_require_call(call, ctx.semantic())
+                    && let Argument::StringLiteral(module) = &call.arguments[0]
+                {
+                    self.check(strip_node_protocol(&module.value), call.span, false, ctx);
+                }
+                if let Some(callee) = get_node_module_path(&call.callee, ctx) {
+                    self.check(&callee, call.span, true, ctx);
+                }
+            }
+            AstKind::NewExpression(new_expr) => {
+                if let Some(callee) = get_node_module_path(&new_expr.callee, ctx) {
+                    self.check(&callee, new_expr.span, true, ctx);
+                }
+            }
+            AstKind::StaticMemberExpression(member) => {
+                if let Some(path) = get_node_module_path(&member.object, ctx) {
+                    let path = format!("{path}.{}", member.property.name);
+                    self.check(&path, member.span(), false, ctx);
+                }
+            }
+            AstKind::IdentifierReference(ident) => {
+                if matches!(ident.name.as_str(), "GLOBAL" | "root")
+                    && ctx.is_reference_to_global_variable(ident)
+                {
+                    self.check(&ident.name, ident.span, false, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoDeprecatedApi {
+    /// Reports the API at `path` if it is deprecated, e.g. `fs.exists`. `is_call` is whether
+    /// the API is called, rather than accessed.
+    fn check(&self, path: &str, span: Span, is_call: bool, ctx: &LintContext) {
+        let Some(api) = DEPRECATED_APIS.get(path) else {
+            return;
+        };
+        if matches!(api.kind, DeprecatedKind::Call) != is_call {
+            return;
+        }
+        if self
+            .ignore_module_items
+            .iter()
+            .chain(&self.ignore_global_items)
+            .any(|ignored| ignored == path)
+        {
+            return;
+        }
+        let range = supported_node_version_range(ctx, self.version.as_deref());
+        let min_version = NodeVersion::min_of_range(&range).unwrap_or_default();
+        if !min_version.supports(&[api.since]) {
+            return;
+        }
+        ctx.diagnostic(no_deprecated_api_diagnostic(span, path, api));
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"const fs = require("fs"); fs.access("./foo", () => {})"#, None),
+        (r#"import { access } from "fs""#, None),
+        (r"Array.isArray(value)", None),
+        (r"Buffer.alloc(10)", None),
+        (r#"Buffer.from("foo")"#, None),
+        (r#"const { Buffer } = require("buffer"); Buffer.alloc(10)"#, None),
+        (r"let fs = foo; fs.exists()", None),
+        (r"const fs = foo(); fs.exists()", None),
+        (r"function f(util) { util.isArray(value) }", None),
+        (r"const Buffer = MyBuffer; new Buffer()", None),
+        (r"function f(GLOBAL) { return GLOBAL }", None),
+        (r"exists()", None),
+        (r#"import { URL } from "url"; new URL("https://x.y.z")"#, None),
+        (
+            r#"require("url").parse("https://x.y.z")"#,
+            Some(json!([{ "ignoreModuleItems": ["url.parse"] }])),
+        ),
+        (r"process.binding('util')", Some(json!([{ "ignoreGlobalItems": ["process.binding"] }]))),
+        (r#"require("url").parse("https://x.y.z")"#, Some(json!([{ "version": ">=10.0.0" }]))),
+    ];
+
+    let fail = vec![
+        (r#"const fs = require("fs"); fs.exists("./foo", () => {})"#, None),
+        (r#"require("fs").exists("./foo", () => {})"#, None),
+        (r#"require("node:fs").exists("./foo", () => {})"#, None),
+        (r#"import fs from "fs"; fs.exists("./foo", () => {})"#, None),
+        (r#"import * as fs from "node:fs"; fs.exists("./foo", () => {})"#, None),
+        (r#"import { exists } from "fs""#, None),
+        (r#"import { isArray as isArr } from "util""#, None),
+        (r#"const { exists } = require("fs")"#, None),
+        (r#"const { isArray: isArr } = require("node:util")"#, None),
+        (r#"const util = require("util"); util.isArray(value)"#, None),
+        (r#"const { EventEmitter } = require("events"); EventEmitter.listenerCount(e, "x")"#, None),
+        (r#"require("domain")"#, None),
+        (r#"import domain from "node:domain""#, None),
+        (r#"import "punycode""#, None),
+        (r"new Buffer(10)", None),
+        (r"Buffer(10)", None),
+        (r#"const { Buffer } = require("buffer"); new Buffer(10)"#, None),
+        (r"process.binding('util')", None),
+        (r"process.env.NODE_REPL_HISTORY_FILE", None),
+        (r"GLOBAL.foo = 1", None),
+        (r"root.foo = 1", None),
+        (r#"require.extensions[".txt"] = () => {}"#, None),
+        (r#"require("url").parse("https://x.y.z")"#, None),
+        (r#"require("crypto").createCipher("aes192", key)"#, None),
+        (
+            r#"require("url").parse("https://x.y.z")"#,
+            Some(json!([{ "ignoreModuleItems": ["url.resolve"] }])),
+        ),
+    ];
+
+    Tester::new(NoDeprecatedApi::NAME, NoDeprecatedApi::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,234 @@
+use std::path::{Path, PathBuf};
+
+use nodejs_built_in_modules::is_nodejs_builtin_module;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    rules::import::no_extraneous_dependencies::package_name,
+};
+
+fn no_missing_import_diagnostic(span: Span, specifier: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{specifier}` is not found."))
+        .with_help("Check the path of the imported file, or install the imported package.")
+        .with_label(span)
+}
+
+fn unknown_builtin_module_diagnostic(span: Span, specifier: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{specifier}` is not a built-in module of Node.js."))
+        .with_label(span)
+}
+
+/// The extensions of the TypeScript files which are imported with the extensions of the
+/// JavaScript files they are compiled to, e.g. `./foo.ts` as `./foo.js`.
+const TYPESCRIPT_EXTENSIONS: [(&str, &[&str]); 4] =
+    [("js", &["ts", "tsx"]), ("jsx", &["tsx"]), ("mjs", &["mts"]), ("cjs", &["cts"])];
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoMissingImport(Box<NoMissingImportConfig>);
+
+impl std::ops::Deref for NoMissingImport {
+    type Target = NoMissingImportConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoMissingImportConfig {
+    /// Packages which are allowed to be imported without being installed, e.g. `electron`.
+    allow_modules: Vec<CompactStr>,
+    /// The extensions tried to find a file imported without one, in order.
+    try_extensions: Vec<CompactStr>,
+}
+
+impl Default for NoMissingImportConfig {
+    fn default() -> Self {
+        Self {
+            allow_modules: vec![],
+            try_extensions: [
+                ".js", ".json", ".node", ".mjs", ".cjs", ".ts", ".tsx", ".mts", ".cts",
+            ]
+            .into_iter()
+            .map(CompactStr::new)
+            .collect(),
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows `import` declarations of files and packages which don't exist, and of `node:`
+    /// modules which are not built into Node.js.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Importing a module which can't be found throws when the module is loaded. The cause is
+    /// usually a typo in the path, a moved file, or a package which is not installed.
+    ///
+    /// Path aliases of the `paths` of the nearest `tsconfig.json` are not checked.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import foo from "./not-a-file";
+    /// import pkg from "not-installed-package";
+    /// import fs from "node:not-a-module";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import foo from "./foo";
+    /// import fs from "node:fs";
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "node/no-missing-import": [
+    ///     "error",
+    ///     { "allowModules": ["electron"], "tryExtensions": [".js", ".json"] }
+    ///   ]
+    /// }
+    /// ```
+    NoMissingImport,
+    node,
+    suspicious,
+    config = NoMissingImportConfig,
+);
+
+impl Rule for NoMissingImport {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoMissingImport>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let Some(dir) = ctx.file_path().parent() else {
+            return;
+        };
+        let compiler_options = ctx.compiler_options();
+        let mut missing = ctx
+            .module_record()
+            .requested_modules
+            .iter()
+            .filter(|(_, requests)| !requests.iter().all(|request| request.is_type))
+            .filter_map(|(specifier, requests)| Some((specifier, requests.first()?.span)))
+            .collect::<Vec<_>>();
+        missing.sort_unstable_by_key(|(_, span)| *span);
+
+        for (specifier, span) in missing {
+            if specifier.starts_with("node:") {
+                if !is_nodejs_builtin_module(specifier) {
+                    ctx.diagnostic(unknown_builtin_module_diagnostic(span, specifier));
+                }
+                continue;
+            }
+            if compiler_options.as_ref().is_some_and(|options| options.has_path_alias(specifier)) {
+                continue;
+            }
+            let exists =
+                if specifier.starts_with('.') || Path::new(specifier.as_str()).is_absolute() {
+                    self.file_exists(&dir.join(specifier.as_str()))
+                } else if let Some(package) = package_name(specifier) {
+                    self.allow_modules.iter().any(|allowed| allowed == package)
+                        || is_package_installed(dir, package)
+                } else {
+                    // Built-in modules, subpath imports, and URLs, e.g. `virtual:module`.
+                    true
+                };
+            if !exists {
+                ctx.diagnostic(no_missing_import_diagnostic(span, specifier));
+            }
+        }
+    }
+}
+
+impl NoMissingImport {
+    /// Whether `path` is a file, with or without one of `try_extensions`, or the TypeScript
+    /// source of one, or a directory of a package or with an index file.
+    fn file_exists(&self, path: &Path) -> bool {
+        if path.is_file() {
+            return true;
+        }
+        let with_extension = |extension: &str| {
+            let mut path = path.as_os_str().to_os_string();
+            path.push(extension);
+            PathBuf::from(path)
+        };
+        if self.try_extensions.iter().any(|extension| with_extension(extension).is_file()) {
+            return true;
+        }
+        if let Some(extension) = path.extension().and_then(|extension| extension.to_str())
+            && let Some((_, typescript_extensions)) =
+                TYPESCRIPT_EXTENSIONS.iter().find(|(js, _)| *js == extension)
+            && typescript_extensions
+                .iter()
+                .any(|typescript| path.with_extension(typescript).is_file())
+        {
+            return true;
+        }
+        path.is_dir()
+            && (path.join("package.json").is_file()
+                || self
+                    .try_extensions
+                    .iter()
+                    .any(|extension| path.join(format!("index{extension}")).is_file()))
+    }
+}
+
+/// Whether `package` is installed in a `node_modules` directory of `dir` or its ancestors.
+fn is_package_installed(dir: &Path, package: &str) -> bool {
+    dir.ancestors().any(|dir| dir.join("node_modules").join(package).is_dir())
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"import foo from "./foo""#, None),
+        (r#"import bar from "./bar.js""#, None),
+        (r#"import { a } from "./deep/a.js""#, None),
+        (r#"export { foo } from "./foo""#, None),
+        (r#"import "./data.json""#, None),
+        (r#"import bar from "./bar""#, None),
+        (r#"import fs from "fs""#, None),
+        (r#"import fs from "node:fs""#, None),
+        (r#"import { readFile } from "node:fs/promises""#, None),
+        (r#"import test from "node:test""#, None),
+        (r#"import chai from "chai""#, None),
+        (r#"import { expect } from "chai/index""#, None),
+        (r#"import "virtual:module""#, None),
+        (r##"import internal from "#internal""##, None),
+        (r#"import electron from "electron""#, Some(json!([{ "allowModules": ["electron"] }]))),
+    ];
+
+    let fail = vec![
+        (r#"import foo from "./not-a-file""#, None),
+        (r#"import foo from "./foo.ts""#, None),
+        (r#"export * from "../not-a-dir/foo""#, None),
+        (r#"import pkg from "not-installed-package""#, None),
+        (r#"import pkg from "@not-installed/package/subpath""#, None),
+        (r#"import fs from "node:not-a-module""#, None),
+        (r#"import data from "./data""#, Some(json!([{ "tryExtensions": [".js"] }]))),
+    ];
+
+    Tester::new(NoMissingImport::NAME, NoMissingImport::PLUGIN, pass, fail)
+        .change_rule_path("index.js")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,369 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, BindingPattern, ImportDeclarationSpecifier},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    ast_util::is_global_require_call,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{NodeVersion, get_node_module_path, strip_node_protocol, supported_node_version_range},
+};
+
+fn no_unsupported_features_diagnostic(
+    span: Span,
+    feature: &str,
+    since: &[&str],
+    range: &str,
+) -> OxcDiagnostic {
+    let (added, backports) =
+        since.split_last().map_or(("", &[][..]), |(added, backports)| (*added, backports));
+    let backports = if backports.is_empty() {
+        String::new()
+    } else {
+        format!(" (backported to {})", backports.join(", "))
+    };
+    OxcDiagnostic::warn(format!(
+        "{feature} is not supported until Node.js {added}{backports}, but the supported versions are `{range}`."
+    ))
+    .with_help("Raise the minimum Node.js version in the `engines` field of `package.json`, or avoid using it.")
+    .with_label(span)
+}
+
+/// The versions of Node.js which added modules and module members, by path, e.g. `fs.cp`. The
+/// last version added the feature; earlier ones are release lines it was backported to.
+static NODE_BUILTINS: phf::Map<&'static str, &'static [&'static str]> = phf::phf_map! {
+    "assert/strict" => &["15.0.0"],
+    "async_hooks" => &["8.1.0"],
+    "diagnostics_channel" => &["14.17.0", "15.1.0"],
+    "dns/promises" => &["15.0.0"],
+    "fs/promises" => &["14.0.0"],
+    "http2" => &["8.4.0"],
+    "inspector" => &["8.0.0"],
+    "path/posix" => &["15.3.0"],
+    "path/win32" => &["15.3.0"],
+    "perf_hooks" => &["8.5.0"],
+    "readline/promises" => &["17.0.0"],
+    "sqlite" => &["22.5.0"],
+    "stream/consumers" => &["16.7.0"],
+    "stream/promises" => &["15.0.0"],
+    "stream/web" => &["16.5.0"],
+    "test" => &["16.17.0", "18.0.0"],
+    "timers/promises" => &["15.0.0"],
+    "trace_events" => &["10.0.0"],
+    "util/types" => &["15.3.0"],
+    "worker_threads" => &["11.7.0"],
+
+    "buffer.Blob" => &["15.7.0"],
+    "buffer.File" => &["20.0.0"],
+    "crypto.getRandomValues" => &["17.4.0"],
+    "crypto.hash" => &["20.12.0", "21.7.0"],
+    "crypto.randomUUID" => &["14.17.0", "15.6.0"],
+    "crypto.webcrypto" => &["15.0.0"],
+    "events.on" => &["12.16.0", "13.6.0"],
+    "events.once" => &["10.16.0", "11.13.0"],
+    "fs.cp" => &["16.7.0"],
+    "fs.cpSync" => &["16.7.0"],
+    "fs.glob" => &["22.0.0"],
+    "fs.globSync" => &["22.0.0"],
+    "fs.openAsBlob" => &["19.8.0"],
+    "fs.opendir" => &["12.12.0"],
+    "fs.promises" => &["10.1.0"],
+    "fs.readv" => &["12.17.0", "13.13.0"],
+    "fs.rm" => &["14.14.0"],
+    "fs.rmSync" => &["14.14.0"],
+    "fs.statfs" => &["18.15.0", "19.6.0"],
+    "module.createRequire" => &["12.2.0"],
+    "module.register" => &["18.19.0", "20.6.0"],
+    "os.availableParallelism" => &["18.14.0", "19.4.0"],
+    "os.machine" => &["16.18.0", "18.9.0"],
+    "process.getActiveResourcesInfo" => &["17.3.0"],
+    "stream.Readable.from" => &["10.17.0", "12.3.0"],
+    "stream.addAbortSignal" => &["15.4.0"],
+    "stream.compose" => &["16.9.0"],
+    "url.fileURLToPath" => &["10.12.0"],
+    "url.pathToFileURL" => &["10.12.0"],
+    "util.aborted" => &["18.16.0", "19.7.0"],
+    "util.parseArgs" => &["16.17.0", "18.3.0"],
+    "util.stripVTControlCharacters" => &["16.11.0"],
+    "util.styleText" => &["20.12.0", "21.7.0"],
+    "util.types" => &["10.0.0"],
+};
+
+/// The versions of Node.js which added globals, like [`NODE_BUILTINS`]. These are kept apart
+/// from modules of the same name, e.g. the `crypto` global and the `crypto` module.
+static NODE_GLOBALS: phf::Map<&'static str, &'static [&'static str]> = phf::phf_map! {
+    "AbortController" => &["14.17.0", "15.0.0"],
+    "AbortSignal" => &["14.17.0", "15.0.0"],
+    "Blob" => &["18.0.0"],
+    "BroadcastChannel" => &["18.0.0"],
+    "CustomEvent" => &["19.0.0"],
+    "DOMException" => &["17.0.0"],
+    "Event" => &["14.5.0", "15.0.0"],
+    "EventTarget" => &["14.5.0", "15.0.0"],
+    "File" => &["20.0.0"],
+    "FormData" => &["18.0.0"],
+    "Headers" => &["18.0.0"],
+    "ReadableStream" => &["18.0.0"],
+    "Request" => &["18.0.0"],
+    "Response" => &["18.0.0"],
+    "TextDecoder" => &["11.0.0"],
+    "TextEncoder" => &["11.0.0"],
+    "URL" => &["10.0.0"],
+    "URLSearchParams" => &["10.0.0"],
+    "WebSocket" => &["22.0.0"],
+    "WritableStream" => &["18.0.0"],
+    "crypto" => &["19.0.0"],
+    "fetch" => &["18.0.0"],
+    "globalThis" => &["12.0.0"],
+    "navigator" => &["21.0.0"],
+    "performance" => &["16.0.0"],
+    "queueMicrotask" => &["11.0.0"],
+    "structuredClone" => &["17.0.0"],
+};
+
+/// The versions of Node.js which support the `node:` prefix in `import` declarations.
+const NODE_PROTOCOL_IMPORT: &[&str] = &["12.20.0", "14.13.1"];
+/// The versions of Node.js which support the `node:` prefix in `require()` calls.
+const NODE_PROTOCOL_REQUIRE: &[&str] = &["14.18.0", "16.0.0"];
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUnsupportedFeatures(Box<NoUnsupportedFeaturesConfig>);
+
+impl std::ops::Deref for NoUnsupportedFeatures {
+    type Target = NoUnsupportedFeaturesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoUnsupportedFeaturesConfig {
+    /// The range of Node.js versions to support, e.g. `">=18.0.0"`. By default, the `engines`
+    /// field of the nearest `package.json`, or `">=16.0.0"` without one.
+    version: Option<String>,
+    /// Features not to report, e.g. `fetch`, `fs.cp`, or `node:` for the `node:` prefix of
+    /// module names.
+    ignores: Vec<CompactStr>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows Node.js built-in modules, module members and globals which are not supported
+    /// by all the Node.js versions the package supports, as given by the `engines` field of its
+    /// `package.json`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Code which uses a feature added in a later version of Node.js than the package claims
+    /// to support throws, or silently misbehaves, on the earlier versions.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule, with `"engines": { "node": ">=14.0.0" }`:
+    /// ```js
+    /// import { cp } from "node:fs/promises";
+    /// const response = await fetch("https://example.com");
+    /// const copy = structuredClone(value);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule, with `"engines": { "node": ">=14.0.0" }`:
+    /// ```js
+    /// import { readFile } from "fs/promises";
+    /// const copy = JSON.parse(JSON.stringify(value));
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "node/no-unsupported-features": [
+    ///     "error",
+    ///     { "version": ">=16.0.0", "ignores": ["fetch"] }
+    ///   ]
+    /// }
+    /// ```
+    NoUnsupportedFeatures,
+    node,
+    restriction,
+    config = NoUnsupportedFeaturesConfig,
+);
+
+impl Rule for NoUnsupportedFeatures {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoUnsupportedFeatures>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ImportDeclaration(import) => {
+                self.check_module(&import.source.value, import.source.span, false, ctx);
+                let module = strip_node_protocol(&import.source.value);
+                for specifier in import.specifiers.iter().flatten() {
+                    if let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier {
+                        let path = format!("{module}.{}", specifier.imported.name());
+                        self.check(&path, specifier.span, ctx);
+                    }
+                }
+            }
+            AstKind::CallExpression(call) => {
+                if is_global_require_call(call, ctx.semantic())
+                    && let Argument::StringLiteral(module) = &call.arguments[0]
+                {
+                    self.check_module(&module.value, module.span, true, ctx);
+                }
+            }
+            AstKind::VariableDeclarator(declarator) => {
+                let (BindingPattern::ObjectPattern(pattern), Some(init)) =
+                    (&declarator.id, &declarator.init)
+                else {
+                    return;
+                };
+                let Some(object) = get_node_module_path(init, ctx) else {
+                    return;
+                };
+                for property in &pattern.properties {
+                    if let Some(name) = property.key.static_name() {
+                        self.check(&format!("{object}.{name}"), property.span, ctx);
+                    }
+                }
+            }
+            AstKind::StaticMemberExpression(member) => {
+                if let Some(path) = get_node_module_path(&member.object, ctx) {
+                    let path = format!("{path}.{}", member.property.name);
+                    self.check(&path, member.span(), ctx);
+                }
+            }
+            AstKind::IdentifierReference(ident) => {
+                if let Some(since) = NODE_GLOBALS.get(ident.name.as_str())
+                    && ctx.is_reference_to_global_variable(ident)
+                    && !self.is_ignored(&ident.name)
+                {
+                    self.report_unsupported(&format!("`{}`", ident.name), since, ident.span, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoUnsupportedFeatures {
+    /// Checks a module imported or required by its name, e.g. `node:fs/promises`.
+    fn check_module(&self, specifier: &str, span: Span, is_require: bool, ctx: &LintContext) {
+        let module = strip_node_protocol(specifier);
+        if module.len() < specifier.len() && !self.is_ignored("node:") {
+            let since = if is_require { NODE_PROTOCOL_REQUIRE } else { NODE_PROTOCOL_IMPORT };
+            let feature = if is_require {
+                "The `node:` prefix in `require()`"
+            } else {
+                "The `node:` prefix in `import`"
+            };
+            self.report_unsupported(feature, since, span, ctx);
+        }
+        self.check(module, span, ctx);
+    }
+
+    /// Checks a module or module member by its path, e.g. `fs.cp`.
+    fn check(&self, path: &str, span: Span, ctx: &LintContext) {
+        let Some(since) = NODE_BUILTINS.get(path) else {
+            return;
+        };
+        if self.is_ignored(path) {
+            return;
+        }
+        self.report_unsupported(&format!("`{path}`"), since, span, ctx);
+    }
+
+    fn report_unsupported(&self, feature: &str, since: &[&str], span: Span, ctx: &LintContext) {
+        let range = supported_node_version_range(ctx, self.version.as_deref());
+        let Some(min_version) = NodeVersion::min_of_range(&range) else {
+            return;
+        };
+        if !min_version.supports(since) {
+            ctx.diagnostic(no_unsupported_features_diagnostic(span, feature, since, &range));
+        }
+    }
+
+    fn is_ignored(&self, path: &str) -> bool {
+        self.ignores.iter().any(|ignored| ignored == path)
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let v14 = || Some(json!([{ "version": ">=14.0.0" }]));
+    let v18 = || Some(json!([{ "version": ">=18.0.0" }]));
+
+    let pass = vec![
+        (r#"import { readFile } from "fs/promises""#, v14()),
+        (r#"import crypto from "crypto""#, v14()),
+        (
+            r#"import { readFile } from "node:fs/promises""#,
+            Some(json!([{ "version": ">=14.18.0" }])),
+        ),
+        (r#"const { readFile } = require("fs").promises"#, v14()),
+        (
+            r#"const fs = require("fs"); fs.rm("./foo", () => {})"#,
+            Some(json!([{ "version": "^14.14.0 || >=16" }])),
+        ),
+        (
+            r#"const { randomUUID } = require("crypto")"#,
+            Some(json!([{ "version": "^14.17.0 || >=15.6.0" }])),
+        ),
+        (r#"fetch("https://example.com")"#, v18()),
+        (r"structuredClone(value)", v18()),
+        (r#"import { parseArgs } from "node:util""#, Some(json!([{ "version": ">=18.3.0" }]))),
+        (r#"require("node:test")"#, v18()),
+        (r"const fetch = myFetch; fetch()", v14()),
+        (r"function f(structuredClone) { structuredClone() }", v14()),
+        (
+            r#"fetch("https://example.com")"#,
+            Some(json!([{ "version": ">=14.0.0", "ignores": ["fetch"] }])),
+        ),
+        (r#"require("node:fs")"#, Some(json!([{ "version": ">=14.0.0", "ignores": ["node:"] }]))),
+        (r#"import { cp } from "fs/promises""#, Some(json!([{ "version": "16.x || 18.x" }]))),
+        (r"JSON.parse(JSON.stringify(value))", v14()),
+    ];
+
+    let fail = vec![
+        (r#"import { cp } from "fs""#, v14()),
+        (r#"const fs = require("fs"); fs.cp("./a", "./b", () => {})"#, v14()),
+        (
+            r#"import fs from "fs"; fs.rm("./foo", () => {})"#,
+            Some(json!([{ "version": ">=12.0.0" }])),
+        ),
+        (r#"const { randomUUID } = require("crypto")"#, Some(json!([{ "version": ">=14.0.0" }]))),
+        (r#"require("crypto").randomUUID()"#, Some(json!([{ "version": "^14.16.0 || >=16" }]))),
+        (r#"fetch("https://example.com")"#, v14()),
+        (r"structuredClone(value)", Some(json!([{ "version": ">= 16" }]))),
+        (r"new AbortController()", Some(json!([{ "version": "^12.22.0 || ^14.17.0 || >=16" }]))),
+        (r#"import { parseArgs } from "node:util""#, Some(json!([{ "version": ">=16.0.0" }]))),
+        (r#"import { setTimeout } from "timers/promises""#, v14()),
+        (r#"require("node:fs")"#, v14()),
+        (r#"import fs from "node:fs""#, Some(json!([{ "version": ">=12.0.0" }]))),
+        (r#"import test from "node:test""#, Some(json!([{ "version": ">=16.0.0" }]))),
+        (
+            r"const { Readable } = require('stream'); Readable.from([])",
+            Some(json!([{ "version": ">=10.0.0" }])),
+        ),
+        (r"process.getActiveResourcesInfo()", Some(json!([{ "version": ">=16.0.0" }]))),
+    ];
+
+    Tester::new(NoUnsupportedFeatures::NAME, NoUnsupportedFeatures::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,149 @@
+use std::path::Path;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    ModuleGraph,
+    context::{ContextHost, LintContext},
+    dead_code::{find_dead_exports, write_only_variables},
+    rule::Rule,
+};
+
+fn no_write_only_variables_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is assigned a value but never read."))
+        .with_help("Remove the variable and its assignments, or use its value.")
+        .with_label(span)
+}
+
+fn unused_write_only_export_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "'{name}' is exported and assigned a value, but never read or imported."
+    ))
+    .with_help("Remove the variable and its assignments, or use its value.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoWriteOnlyVariables;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow variables which are assigned, but whose value is never read.
+    ///
+    /// Updates such as `x++` or `x += 1` read the variable. Parameters are checked like other
+    /// variables. Catch parameters, files with a direct `eval`, and variables in the global scope
+    /// of scripts are not checked.
+    ///
+    /// Exported variables are checked once all files are linted, when the import plugin is
+    /// enabled. They are reported if no other linted module imports them. Modules which are never
+    /// imported are not checked, since their exports may be used outside of the project.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Assignments to a variable which is never read are dead code. They are often left behind
+    /// after a refactoring, or are a bug where another variable was meant to be read.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// let count = 0;
+    /// for (const item of items) {
+    ///     count = count + 1;
+    /// }
+    ///
+    /// function f(options) {
+    ///     options = {};
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// let count = 0;
+    /// for (const item of items) {
+    ///     count = count + 1;
+    /// }
+    /// console.log(count);
+    /// ```
+    NoWriteOnlyVariables,
+    oxc,
+    nursery
+);
+
+impl Rule for NoWriteOnlyVariables {
+    fn run_once(&self, ctx: &LintContext) {
+        for variable in write_only_variables(ctx.semantic()) {
+            if variable.export_names.is_empty() {
+                ctx.diagnostic(no_write_only_variables_diagnostic(variable.name, variable.span));
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        // Variables of vue/svelte/astro scripts may be read by the template
+        !ctx.source_type().is_typescript_definition()
+            && !ctx.file_extension().is_some_and(|ext| {
+                ext == "vue" || ext == "svelte" || ext == "astro" || ext == "mpx"
+            })
+    }
+}
+
+impl NoWriteOnlyVariables {
+    /// Checks the exported variables of the module at `path` once all modules of the lint run
+    /// are in `module_graph`.
+    pub(crate) fn run_on_module_graph(
+        path: &Path,
+        module_graph: &ModuleGraph,
+    ) -> Vec<OxcDiagnostic> {
+        find_dead_exports(path, module_graph)
+            .into_iter()
+            .map(|dead_code| {
+                let name = dead_code.name.as_deref().unwrap_or_default();
+                unused_write_only_export_diagnostic(name, dead_code.span)
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "let a = 1; console.log(a);",
+        "let a; a = 1; console.log(a);",
+        "let a = 0; a++;",
+        "let a = 0; a += 1;",
+        "let a; for (a of b) {} use(a);",
+        "let a;",
+        "const a = 1;",
+        "function f(a) { return a; }",
+        "function f(a) { a = a || {}; return a; }",
+        "try {} catch (e) { e = 1; }",
+        "let a = 1; eval('a'); a = 2;",
+        "export let a = 1; a = 2;",
+        "let a = 1; a = 2; export { a };",
+        "let a = 1; a = 2; export { a as b };",
+        "let a = 1; a = 2; export default a;",
+        "let a; a = 1; type T = typeof a;",
+        "declare let a: number;",
+    ];
+
+    let fail = vec![
+        "let a = 1; a = 2;",
+        "let a; a = 1;",
+        "var a; function f() { a = 1; }",
+        "function f(a) { a = 1; }",
+        "let a; [a] = b;",
+        "let a = 0; f(a = 1);",
+        "let a; ({ a } = b);",
+        "let a; for (a of b) {}",
+        "let a; function f() { let b; b = 1; }",
+    ];
+
+    Tester::new(NoWriteOnlyVariables::NAME, NoWriteOnlyVariables::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
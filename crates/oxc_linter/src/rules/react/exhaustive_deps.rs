@@ -543,9 +543,12 @@ impl Rule for ExhaustiveDeps {
         let declared_dependencies = {
             let mut declared_dependencies = FxHashSet::default();
             for item in declared_dependencies_iter {
-                let span = item.span;
-                if !declared_dependencies.insert(item) {
-                    ctx.diagnostic(duplicate_dependency_diagnostic(span));
+                if declared_dependencies.contains(&item) {
+                    ctx.diagnostic_with_fix(duplicate_dependency_diagnostic(item.span), |fixer| {
+                        fix::remove_dependency(fixer, &item, dependencies_node)
+                    });
+                } else {
+                    declared_dependencies.insert(item);
                 }
             }
 
@@ -619,19 +622,21 @@ impl Rule for ExhaustiveDeps {
             // lastly, we need co compare for any unnecessary deps
             // for example if `props.foo`, AND `props.foo.bar.baz` was declared in the deps array
             // `props.foo.bar.baz` is unnecessary (already covered by `props.foo`)
-            declared_dependencies.iter().tuple_combinations().for_each(|(a, b)| {
-                if a.contains(b) {
-                    ctx.diagnostic(unnecessary_dependency_diagnostic(
+            let report_unnecessary = |dep: &Dependency| {
+                ctx.diagnostic_with_dangerous_suggestion(
+                    unnecessary_dependency_diagnostic(
                         hook_name,
-                        &a.to_string(),
+                        &dep.to_string(),
                         dependencies_node.span,
-                    ));
+                    ),
+                    |fixer| fix::remove_dependency(fixer, dep, dependencies_node),
+                );
+            };
+            declared_dependencies.iter().tuple_combinations().for_each(|(a, b)| {
+                if a.contains(b) {
+                    report_unnecessary(a);
                 } else if b.contains(a) {
-                    ctx.diagnostic(unnecessary_dependency_diagnostic(
-                        hook_name,
-                        &b.to_string(),
-                        dependencies_node.span,
-                    ));
+                    report_unnecessary(b);
                 }
             });
 
@@ -640,11 +645,7 @@ impl Rule for ExhaustiveDeps {
                     continue;
                 }
 
-                ctx.diagnostic(unnecessary_dependency_diagnostic(
-                    hook_name,
-                    &dep.to_string(),
-                    dependencies_node.span,
-                ));
+                report_unnecessary(dep);
             }
         }
 
@@ -1520,63 +1521,103 @@ fn is_inside_effect_cleanup(stack: &[AstType]) -> bool {
 
 mod fix {
     use super::Name;
-    use oxc_allocator::{Allocator, CloneIn};
-    use oxc_ast::{
-        AstBuilder,
-        ast::{ArrayExpression, Expression},
-    };
-    use oxc_span::{Atom, GetSpan, SPAN};
+    use oxc_ast::ast::{ArrayExpression, ArrayExpressionElement};
+    use oxc_span::{GetSpan, Span};
 
     use crate::{
         fixer::{RuleFix, RuleFixer},
         rules::react::exhaustive_deps::Dependency,
     };
 
+    /// Inserts `names` after the last element of the dependency array, so comments and line
+    /// breaks in it are kept.
     pub fn append_dependencies<'c, 'a: 'c>(
         fixer: RuleFixer<'c, 'a>,
         names: &[Name<'a>],
         deps: &ArrayExpression<'a>,
     ) -> RuleFix {
-        let mut codegen = fixer.codegen();
-
-        let alloc = Allocator::default();
-        let ast_builder = AstBuilder::new(&alloc);
-
-        let mut vec = deps.elements.clone_in(&alloc);
-
-        for name in names {
-            vec.push(
-                ast_builder
-                    .expression_identifier(SPAN, Atom::from_cow_in(&name.name, &alloc))
-                    .into(),
-            );
+        let names = names.iter().map(|name| &*name.name).collect::<Vec<_>>().join(", ");
+        match element_spans(deps).last() {
+            Some(last) => fixer.insert_text_after_range(last, format!(", {names}")),
+            None => fixer.insert_text_after_range(Span::empty(deps.span.start + 1), names),
         }
-
-        codegen.print_expression(&ast_builder.expression_array(SPAN, vec));
-        fixer.replace(deps.span, codegen.into_source_text())
     }
 
+    /// Deletes `dependency` from the dependency array with its comma, keeping comments and
+    /// line breaks around other elements.
+    #[expect(clippy::cast_possible_truncation)]
     pub fn remove_dependency<'c, 'a: 'c>(
         fixer: RuleFixer<'c, 'a>,
         dependency: &Dependency,
         deps: &ArrayExpression<'a>,
     ) -> RuleFix {
-        let mut codegen = fixer.codegen();
+        let elements = element_spans(deps).collect::<Vec<_>>();
+        let Some(index) = elements.iter().position(|span| span.contains_inclusive(dependency.span))
+        else {
+            return fixer.noop();
+        };
+        let span = elements[index];
+        let source_text = fixer.source_text();
+        let next_start = elements.get(index + 1).map_or(deps.span.end - 1, |next| next.start);
+        let after = &source_text[span.end as usize..next_start as usize];
+        let comma_after = after.find(',');
+
+        // `[a, b]` -> `[b]`
+        if index + 1 < elements.len()
+            && let Some(comma) = comma_after
+        {
+            let rest = &after[comma + 1..];
+            // Whitespace up to the next element, or up to the comment before it.
+            let whitespace = if rest.trim().is_empty() {
+                rest.len()
+            } else {
+                rest.len() - rest.trim_start_matches([' ', '\t']).len()
+            };
+            let end = span.end + (comma + 1 + whitespace) as u32;
+            return fixer.delete_range(Span::new(span.start, end));
+        }
 
-        let alloc = Allocator::default();
-        let ast_builder = AstBuilder::new(&alloc);
+        // Deleted together with the whitespace before it, which separates it from the previous
+        // element or the opening bracket.
+        let start = span.start - whitespace_before(source_text, span.start, deps.span.start + 1);
+        // `[a, b,]` -> `[a,]`
+        if let Some(comma) = comma_after {
+            return fixer.delete_range(Span::new(start, span.end + comma as u32 + 1));
+        }
+        let Some(&prev) = index.checked_sub(1).and_then(|index| elements.get(index)) else {
+            // `[a]` -> `[]`
+            return fixer.delete_range(Span::new(start, span.end));
+        };
+        let before = &source_text[prev.end as usize..span.start as usize];
+        let Some(comma) = before.find(',') else {
+            return fixer.noop();
+        };
+        // `[a, b]` -> `[a]`
+        if before.trim() == "," {
+            return fixer.delete_range(Span::new(prev.end, span.end));
+        }
+        // `[a, /* b */ b]` -> `[a /* b */]`
+        let comma = prev.end + comma as u32;
+        let fixer = fixer.for_multifix();
+        let mut fix = fixer.new_fix_with_capacity(2);
+        fix.push(fixer.delete_range(Span::new(comma, comma + 1)));
+        fix.push(fixer.delete_range(Span::new(start, span.end)));
+        fix.with_message("Remove the dependency")
+    }
 
-        let new_deps = deps
-            .elements
+    /// The spans of the elements of the dependency array, without holes.
+    fn element_spans<'c>(deps: &'c ArrayExpression<'_>) -> impl Iterator<Item = Span> + 'c {
+        deps.elements
             .iter()
-            .filter(|el| (*el).span() != dependency.span)
-            .map(|el| el.clone_in(&alloc));
-
-        codegen.print_expression(&Expression::ArrayExpression(ast_builder.alloc_array_expression(
-            deps.span,
-            oxc_allocator::Vec::from_iter_in(new_deps, &alloc),
-        )));
-        fixer.replace(deps.span, codegen.into_source_text())
+            .filter(|el| !matches!(el, ArrayExpressionElement::Elision(_)))
+            .map(GetSpan::span)
+    }
+
+    /// The length of the whitespace before `end`, not going back further than `min`.
+    #[expect(clippy::cast_possible_truncation)]
+    fn whitespace_before(source_text: &str, end: u32, min: u32) -> u32 {
+        let text = &source_text[min as usize..end as usize];
+        (text.len() - text.trim_end().len()) as u32
     }
 }
 
@@ -4230,7 +4271,7 @@ fn test() {
               const [x] = useState(0);
               const [y] = useState(0);
               const [z] = useState(0);
-              const foo = useCallback(() => x + y + z, [\n\tx,\n\ty,\n\tz\n]);
+              const foo = useCallback(() => x + y + z, [x, y, z]);
             }",
             // None,
             // FixKind::DangerousSuggestion,
@@ -4246,7 +4287,7 @@ fn test() {
               const [x] = useState(0);
               const [y] = useState(0);
               const [z] = useState(0);
-              const foo = useCallback(() => x + y + z, [\n\tx,\n\ty,\n\tz\n]);
+              const foo = useCallback(() => x + y + z, [x, y, z]);
             }",
             // None,
             // FixKind::DangerousSuggestion,
@@ -4277,6 +4318,14 @@ fn test() {
             "function MyComponent(props) { useEffect(() => { console.log(props.foo, props.bar); }, [props.foo, props.bar]); }",
         ),
         // Test adding to existing dependencies
+        (
+            "function MyComponent(props) { const local = someFunc(); useEffect(() => { console.log(props.foo, local); }, [\n  props.foo, // foo\n]); }",
+            "function MyComponent(props) { const local = someFunc(); useEffect(() => { console.log(props.foo, local); }, [\n  props.foo, local, // foo\n]); }",
+        ),
+        (
+            "function MyComponent(props) { const local = someFunc(); useEffect(() => { console.log(props.foo, local); }, [\n  // foo\n  props.foo\n]); }",
+            "function MyComponent(props) { const local = someFunc(); useEffect(() => { console.log(props.foo, local); }, [\n  // foo\n  props.foo, local\n]); }",
+        ),
         (
             "function MyComponent(props) { const local = someFunc(); useEffect(() => { console.log(props.foo, local); }, [props.foo]); }",
             "function MyComponent(props) { const local = someFunc(); useEffect(() => { console.log(props.foo, local); }, [props.foo, local]); }",
@@ -4291,11 +4340,39 @@ fn test() {
             "function MyComponent() { const value = useMemo(() => { return 2*2; }, []); }",
         ),
         // Test unnecessary dependency removal for non-effect hooks
+        (
+            "function MyComponent(props) { const value = useMemo(() => props.foo.bar, [props.foo, props.foo.bar]); }",
+            "function MyComponent(props) { const value = useMemo(() => props.foo.bar, [props.foo]); }",
+        ),
+        (
+            "function MyComponent(props) { const value = useMemo(() => props.foo, [props.foo, props.bar]); }",
+            "function MyComponent(props) { const value = useMemo(() => props.foo, [props.foo]); }",
+        ),
+        (
+            "function MyComponent(props) { const value = useMemo(() => props.foo, [props.bar, props.foo]); }",
+            "function MyComponent(props) { const value = useMemo(() => props.foo, [props.foo]); }",
+        ),
+        (
+            "function MyComponent(props) { const value = useMemo(() => props.foo, [\n  props.foo, // foo\n  props.bar,\n]); }",
+            "function MyComponent(props) { const value = useMemo(() => props.foo, [\n  props.foo, // foo\n]); }",
+        ),
+        (
+            "function MyComponent(props) { const value = useMemo(() => props.foo, [\n  props.bar, // bar\n  props.foo,\n]); }",
+            "function MyComponent(props) { const value = useMemo(() => props.foo, [\n  // bar\n  props.foo,\n]); }",
+        ),
+        (
+            "function MyComponent(props) { const value = useMemo(() => props.foo, [props.foo, /* bar */ props.bar]); }",
+            "function MyComponent(props) { const value = useMemo(() => props.foo, [props.foo /* bar */]); }",
+        ),
         (
             "function MyComponent() { const local1 = {}; useCallback(() => {}, [local1]); }",
             "function MyComponent() { const local1 = {}; useCallback(() => {}, []); }",
         ),
         // Test duplicate dependency removal
+        (
+            "function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo, props.foo]); }",
+            "function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo]); }",
+        ),
         (
             "function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, [local, local]); }",
             "function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, [local]); }",
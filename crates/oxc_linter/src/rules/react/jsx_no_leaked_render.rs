@@ -0,0 +1,473 @@
+use oxc_ast::{
+    AstKind,
+    ast::{ConditionalExpression, Expression, LogicalExpression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{LogicalOperator, UnaryOperator};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    fixer::{RuleFix, RuleFixer},
+    rule::{DefaultRuleConfig, Rule},
+    utils::is_function_component,
+};
+
+fn jsx_no_leaked_render_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Potential leaked value that might cause unintentionally rendered values or rendering crashes.",
+    )
+    .with_help("Use a ternary, or coerce the condition to a boolean.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct JsxNoLeakedRender(Box<JsxNoLeakedRenderConfig>);
+
+impl std::ops::Deref for JsxNoLeakedRender {
+    type Target = JsxNoLeakedRenderConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, JsonSchema, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Strategy {
+    /// `cond ? <Foo /> : null`
+    Ternary,
+    /// `!!cond && <Foo />`
+    Coerce,
+}
+
+#[derive(Debug, Clone, JsonSchema, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct JsxNoLeakedRenderConfig {
+    /// The ways of conditional rendering which are allowed. The first one is used to fix the
+    /// reported expressions.
+    valid_strategies: Vec<Strategy>,
+    /// Whether to ignore conditions in the values of JSX attributes.
+    ignore_attributes: bool,
+}
+
+impl Default for JsxNoLeakedRenderConfig {
+    fn default() -> Self {
+        Self {
+            valid_strategies: vec![Strategy::Ternary, Strategy::Coerce],
+            ignore_attributes: false,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Prevents values which React renders, such as `0` or `NaN`, from leaking into the output
+    /// through `&&` conditions, in JSX expression containers and in what function components
+    /// return.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `cond && <Foo />` renders `cond` when it is falsy. When `cond` is a number, `0` or `NaN`
+    /// is rendered instead of nothing, and in React Native, rendering a string outside of a
+    /// `<Text>` crashes.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// const Component = ({ count }) => <div>{count && <Count count={count} />}</div>;
+    ///
+    /// const Component = ({ items }) => items.length && <List items={items} />;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// const Component = ({ count }) => <div>{count ? <Count count={count} /> : null}</div>;
+    ///
+    /// const Component = ({ items }) => items.length > 0 && <List items={items} />;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// With `{ "validStrategies": ["coerce"] }`, conditional expressions with a `null`,
+    /// `undefined` or `false` alternate are reported too, and fixed to `!!cond && <Foo />`.
+    JsxNoLeakedRender,
+    react,
+    suspicious,
+    dangerous_fix,
+    config = JsxNoLeakedRenderConfig,
+);
+
+impl Rule for JsxNoLeakedRender {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mut rule = serde_json::from_value::<DefaultRuleConfig<JsxNoLeakedRender>>(value)
+            .unwrap_or_default()
+            .into_inner();
+        if rule.0.valid_strategies.is_empty() {
+            rule.0.valid_strategies = JsxNoLeakedRenderConfig::default().valid_strategies;
+        }
+        rule
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::LogicalExpression(logical_expr)
+                if logical_expr.operator == LogicalOperator::And =>
+            {
+                if !self.is_rendered(node, ctx)
+                    || self.is_valid_logical_expression(logical_expr, ctx)
+                {
+                    return;
+                }
+                ctx.diagnostic_with_dangerous_fix(
+                    jsx_no_leaked_render_diagnostic(logical_expr.span),
+                    |fixer| match self.fix_strategy() {
+                        Strategy::Ternary => fix_with_ternary(fixer, logical_expr),
+                        Strategy::Coerce => fix_with_coerce(fixer, logical_expr, ctx),
+                    },
+                );
+            }
+            AstKind::ConditionalExpression(cond_expr) => {
+                if self.valid_strategies.contains(&Strategy::Ternary)
+                    || !is_empty_alternate(&cond_expr.alternate)
+                    || !self.is_rendered(node, ctx)
+                {
+                    return;
+                }
+                ctx.diagnostic_with_dangerous_fix(
+                    jsx_no_leaked_render_diagnostic(cond_expr.span),
+                    |fixer| fix_conditional_with_coerce(fixer, cond_expr, ctx),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+impl JsxNoLeakedRender {
+    fn fix_strategy(&self) -> Strategy {
+        self.valid_strategies.first().copied().unwrap_or(Strategy::Ternary)
+    }
+
+    /// Whether the value of `node` is rendered: it is in a JSX expression container, or it is
+    /// returned by a function component.
+    fn is_rendered<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+        let parent = ctx.nodes().parent_node(node.id());
+        match parent.kind() {
+            AstKind::JSXExpressionContainer(_) => {
+                !(self.ignore_attributes
+                    && matches!(ctx.nodes().parent_kind(parent.id()), AstKind::JSXAttribute(_)))
+            }
+            AstKind::ReturnStatement(_) => ctx
+                .nodes()
+                .ancestors(parent.id())
+                .find(|ancestor| {
+                    matches!(
+                        ancestor.kind(),
+                        AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                    )
+                })
+                .is_some_and(|function| is_function_component(function, ctx)),
+            // The expression body of an arrow function.
+            AstKind::ExpressionStatement(_) => {
+                let function = ctx.nodes().parent_node(ctx.nodes().parent_id(parent.id()));
+                matches!(
+                    function.kind(),
+                    AstKind::ArrowFunctionExpression(arrow_func) if arrow_func.expression
+                ) && is_function_component(function, ctx)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_valid_logical_expression(
+        &self,
+        logical_expr: &LogicalExpression,
+        ctx: &LintContext,
+    ) -> bool {
+        if self.valid_strategies.contains(&Strategy::Coerce)
+            && is_coerced_condition(&logical_expr.left, ctx)
+        {
+            return true;
+        }
+        // React 18 renders nothing for empty strings.
+        matches!(logical_expr.left.get_inner_expression(), Expression::StringLiteral(lit) if lit.value.is_empty())
+            && ctx.settings().react.version.is_none_or(|version| version.major() >= 18)
+    }
+}
+
+/// Whether the condition can only be a boolean, or a value which React doesn't render: a unary,
+/// binary or call expression, a variable initialized to a boolean, or `&&` and `||` of these.
+fn is_coerced_condition(expr: &Expression, ctx: &LintContext) -> bool {
+    match expr.get_inner_expression() {
+        Expression::UnaryExpression(_)
+        | Expression::BinaryExpression(_)
+        | Expression::CallExpression(_) => true,
+        Expression::LogicalExpression(logical_expr) => {
+            is_coerced_condition(&logical_expr.left, ctx)
+                && is_coerced_condition(&logical_expr.right, ctx)
+        }
+        Expression::Identifier(ident) => ctx
+            .scoping()
+            .get_reference(ident.reference_id())
+            .symbol_id()
+            .map(|symbol_id| ctx.nodes().kind(ctx.scoping().symbol_declaration(symbol_id)))
+            .is_some_and(|decl| {
+                matches!(
+                    decl,
+                    AstKind::VariableDeclarator(decl)
+                        if matches!(decl.init, Some(Expression::BooleanLiteral(_)))
+                )
+            }),
+        _ => false,
+    }
+}
+
+fn is_empty_alternate(expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::NullLiteral(_) => true,
+        Expression::BooleanLiteral(lit) => !lit.value,
+        Expression::Identifier(ident) => ident.name == "undefined",
+        _ => false,
+    }
+}
+
+/// `cond && <Foo />` to `cond ? <Foo /> : null`
+fn fix_with_ternary<'a>(fixer: RuleFixer<'_, 'a>, logical_expr: &LogicalExpression<'a>) -> RuleFix {
+    let test = parenthesized_if(
+        fixer.source_range(logical_expr.left.span()),
+        matches!(
+            &logical_expr.left,
+            Expression::ConditionalExpression(_)
+                | Expression::AssignmentExpression(_)
+                | Expression::SequenceExpression(_)
+                | Expression::ArrowFunctionExpression(_)
+                | Expression::YieldExpression(_)
+        ),
+    );
+    let consequent = fixer.source_range(logical_expr.right.span());
+    fixer.replace(logical_expr.span, format!("{test} ? {consequent} : null"))
+}
+
+/// `a && b && <Foo />` to `!!a && !!b && <Foo />`
+fn fix_with_coerce<'a>(
+    fixer: RuleFixer<'_, 'a>,
+    logical_expr: &LogicalExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> RuleFix {
+    let mut conditions = vec![];
+    let mut left = &logical_expr.left;
+    while let Expression::LogicalExpression(left_expr) = left
+        && left_expr.operator == LogicalOperator::And
+    {
+        conditions.push(&left_expr.right);
+        left = &left_expr.left;
+    }
+    conditions.push(left);
+    let conditions = conditions.iter().rev().map(|condition| coerce(fixer, condition, ctx));
+    let consequent = fixer.source_range(logical_expr.right.span());
+    let text =
+        conditions.chain(std::iter::once(consequent.into())).collect::<Vec<_>>().join(" && ");
+    fixer.replace(logical_expr.span, text)
+}
+
+/// `cond ? <Foo /> : null` to `!!cond && <Foo />`
+fn fix_conditional_with_coerce<'a>(
+    fixer: RuleFixer<'_, 'a>,
+    cond_expr: &ConditionalExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> RuleFix {
+    let test = coerce(fixer, &cond_expr.test, ctx);
+    let consequent = parenthesized_if(
+        fixer.source_range(cond_expr.consequent.span()),
+        matches!(
+            cond_expr.consequent.get_inner_expression(),
+            Expression::ConditionalExpression(_)
+                | Expression::AssignmentExpression(_)
+                | Expression::SequenceExpression(_)
+                | Expression::ArrowFunctionExpression(_)
+                | Expression::YieldExpression(_)
+                | Expression::LogicalExpression(_)
+        ),
+    );
+    fixer.replace(cond_expr.span, format!("{test} && {consequent}"))
+}
+
+fn coerce(fixer: RuleFixer<'_, '_>, condition: &Expression, ctx: &LintContext) -> String {
+    let text = fixer.source_range(condition.span());
+    if is_coerced_condition(condition, ctx) {
+        return text.to_string();
+    }
+    let is_operand = match condition.get_inner_expression() {
+        Expression::Identifier(_)
+        | Expression::ThisExpression(_)
+        | Expression::CallExpression(_)
+        | Expression::StaticMemberExpression(_)
+        | Expression::ComputedMemberExpression(_)
+        | Expression::PrivateFieldExpression(_)
+        | Expression::NumericLiteral(_)
+        | Expression::StringLiteral(_)
+        | Expression::TemplateLiteral(_) => true,
+        Expression::UnaryExpression(unary_expr) => unary_expr.operator != UnaryOperator::Delete,
+        _ => condition.get_inner_expression().span() != condition.span(),
+    };
+    format!("!!{}", parenthesized_if(text, !is_operand))
+}
+
+fn parenthesized_if(text: &str, parenthesize: bool) -> String {
+    if parenthesize { format!("({text})") } else { text.to_string() }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "const Component = () => <div>{elements.length > 0 && <List elements={elements} />}</div>",
+            None,
+            None,
+        ),
+        ("const Component = ({ count }) => <div>{count ? <Count /> : null}</div>", None, None),
+        ("const Component = ({ count }) => <div>{!!count && <Count />}</div>", None, None),
+        ("const Component = ({ count }) => <div>{Boolean(count) && <Count />}</div>", None, None),
+        ("const Component = ({ a, b }) => <div>{!!a && b > 0 && <Count />}</div>", None, None),
+        ("const Component = ({ count }) => <div>{count || <Empty />}</div>", None, None),
+        (
+            "const isOpen = true; const Component = () => <div>{isOpen && <Modal />}</div>",
+            None,
+            None,
+        ),
+        ("const Component = ({ count }) => <div>{'' && <Count />}</div>", None, None),
+        (
+            "const Component = ({ count }) => <div>{count ? <Count /> : null}</div>",
+            Some(serde_json::json!([{ "validStrategies": ["ternary"] }])),
+            None,
+        ),
+        (
+            "const Component = ({ count }) => <div>{!!count && <Count />}</div>",
+            Some(serde_json::json!([{ "validStrategies": ["coerce"] }])),
+            None,
+        ),
+        (
+            "const Component = ({ count }) => <div>{count ? <Count /> : <Empty />}</div>",
+            Some(serde_json::json!([{ "validStrategies": ["coerce"] }])),
+            None,
+        ),
+        (
+            "const Component = ({ count }) => <Foo title={count && 'title'} />",
+            Some(serde_json::json!([{ "ignoreAttributes": true }])),
+            None,
+        ),
+        // Not rendered.
+        ("const value = count && <Count />", None, None),
+        ("function useCount(count) { return count && count + 1; }", None, None),
+        ("function render(count) { return count && <Count />; }", None, None),
+        (
+            "const Component = ({ a, b }) => { const c = a && b; return <div>{c ? 1 : 2}</div>; }",
+            None,
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        ("const Component = ({ count }) => <div>{count && <Count />}</div>", None, None),
+        (
+            "const Component = ({ items }) => <div>{items.length && <List items={items} />}</div>",
+            None,
+            None,
+        ),
+        ("const Component = ({ a, b }) => <div>{a && b && <Count />}</div>", None, None),
+        ("const Component = ({ count }) => <Foo title={count && 'title'} />", None, None),
+        ("function Component({ count }) { return count && <Count />; }", None, None),
+        ("const Component = ({ items }) => items.length && <List items={items} />", None, None),
+        ("const Component = memo(({ count }) => count && <Count />)", None, None),
+        (
+            "class Component extends React.Component { render() { return <div>{this.props.count && <Count />}</div>; } }",
+            None,
+            None,
+        ),
+        (
+            "const Component = ({ count }) => <div>{'' && <Count />}</div>",
+            None,
+            Some(serde_json::json!({ "settings": { "react": { "version": "17.0.2" } } })),
+        ),
+        (
+            "const Component = ({ count }) => <div>{!!count && <Count />}</div>",
+            Some(serde_json::json!([{ "validStrategies": ["ternary"] }])),
+            None,
+        ),
+        (
+            "const Component = ({ count }) => <div>{count && <Count />}</div>",
+            Some(serde_json::json!([{ "validStrategies": ["coerce"] }])),
+            None,
+        ),
+        (
+            "const Component = ({ count }) => <div>{count ? <Count /> : null}</div>",
+            Some(serde_json::json!([{ "validStrategies": ["coerce"] }])),
+            None,
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "const Component = ({ count }) => <div>{count && <Count />}</div>",
+            "const Component = ({ count }) => <div>{count ? <Count /> : null}</div>",
+            None,
+        ),
+        (
+            "const Component = ({ a, b }) => <div>{a && b && <Count />}</div>",
+            "const Component = ({ a, b }) => <div>{a && b ? <Count /> : null}</div>",
+            None,
+        ),
+        (
+            "const Component = ({ a, b }) => <div>{(a ? b : c) && <Count />}</div>",
+            "const Component = ({ a, b }) => <div>{(a ? b : c) ? <Count /> : null}</div>",
+            None,
+        ),
+        (
+            "function Component({ count }) { return count && <Count />; }",
+            "function Component({ count }) { return count ? <Count /> : null; }",
+            None,
+        ),
+        (
+            "const Component = ({ a, b }) => <div>{a && b.length && <Count />}</div>",
+            "const Component = ({ a, b }) => <div>{!!a && !!b.length && <Count />}</div>",
+            Some(serde_json::json!([{ "validStrategies": ["coerce", "ternary"] }])),
+        ),
+        (
+            "const Component = ({ a, b }) => <div>{(a || b) && <Count />}</div>",
+            "const Component = ({ a, b }) => <div>{!!(a || b) && <Count />}</div>",
+            Some(serde_json::json!([{ "validStrategies": ["coerce"] }])),
+        ),
+        (
+            "const Component = ({ a, b }) => <div>{a > 0 && b && <Count />}</div>",
+            "const Component = ({ a, b }) => <div>{a > 0 && !!b && <Count />}</div>",
+            Some(serde_json::json!([{ "validStrategies": ["coerce"] }])),
+        ),
+        (
+            "const Component = ({ count }) => <div>{count ? <Count /> : null}</div>",
+            "const Component = ({ count }) => <div>{!!count && <Count />}</div>",
+            Some(serde_json::json!([{ "validStrategies": ["coerce"] }])),
+        ),
+        (
+            "const Component = ({ count }) => <div>{count > 0 ? <Count /> : undefined}</div>",
+            "const Component = ({ count }) => <div>{count > 0 && <Count />}</div>",
+            Some(serde_json::json!([{ "validStrategies": ["coerce"] }])),
+        ),
+    ];
+
+    Tester::new(JsxNoLeakedRender::NAME, JsxNoLeakedRender::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
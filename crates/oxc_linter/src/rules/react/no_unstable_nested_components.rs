@@ -0,0 +1,368 @@
+use oxc_ast::{
+    AstKind,
+    ast::{JSXAttributeName, PropertyKey},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::{DefaultRuleConfig, Rule},
+    utils::{
+        function_returns_jsx, get_component_name, get_rendering_component, is_es6_component,
+        is_function_component,
+    },
+};
+
+fn no_unstable_nested_components_diagnostic(span: Span, parent_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Do not define components during render.")
+        .with_help(format!(
+            "React sees a new component type on every render of `{parent_name}`, and remounts its whole subtree, losing its state. Move this component out of `{parent_name}` and pass data to it as props."
+        ))
+        .with_label(span)
+}
+
+fn no_unstable_component_as_prop_diagnostic(
+    span: Span,
+    parent_name: &str,
+    prop_name: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Do not define components during render, as the `{prop_name}` prop."))
+        .with_help(format!(
+            "React sees a new component type on every render of `{parent_name}`, and remounts its whole subtree, losing its state. Move this component out of `{parent_name}`, or set `allowAsProps` to `true` to allow components in props."
+        ))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUnstableNestedComponents(Box<NoUnstableNestedComponentsConfig>);
+
+impl std::ops::Deref for NoUnstableNestedComponents {
+    type Target = NoUnstableNestedComponentsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, JsonSchema, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoUnstableNestedComponentsConfig {
+    /// Whether to allow components defined in the props of JSX elements, e.g.
+    /// `<Table footer={() => <Footer />} />`, as the component receiving them may call them
+    /// instead of rendering them.
+    allow_as_props: bool,
+    /// A glob of the names of props which take render functions rather than components, and
+    /// in which functions returning JSX are always allowed.
+    prop_name_pattern: CompactStr,
+}
+
+impl Default for NoUnstableNestedComponentsConfig {
+    fn default() -> Self {
+        Self { allow_as_props: false, prop_name_pattern: CompactStr::new("render*") }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows defining components in the render of other components: in function
+    /// components, in class components, and in the props of the JSX they render.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A component defined during render is a new component type on every render. React
+    /// unmounts the previous one and mounts the new one in its place, which recreates its DOM
+    /// nodes, loses the state of its whole subtree, and is slow.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// function Component() {
+    ///   function UnstableNestedComponent() {
+    ///     return <div />;
+    ///   }
+    ///   return <UnstableNestedComponent />;
+    /// }
+    ///
+    /// function Component() {
+    ///   return <Table footer={() => <div />} />;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// function StableComponent() {
+    ///   return <div />;
+    /// }
+    ///
+    /// function Component() {
+    ///   return <StableComponent />;
+    /// }
+    ///
+    /// function Component() {
+    ///   return <List renderItem={(item) => <div>{item}</div>} />;
+    /// }
+    /// ```
+    NoUnstableNestedComponents,
+    react,
+    suspicious,
+    config = NoUnstableNestedComponentsConfig,
+);
+
+impl Rule for NoUnstableNestedComponents {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoUnstableNestedComponents>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let span = match node.kind() {
+            AstKind::Function(func) => func.span,
+            AstKind::ArrowFunctionExpression(arrow_func) => arrow_func.span,
+            AstKind::Class(class) => class.span,
+            _ => return,
+        };
+
+        if let Some(prop_name) = get_prop_name(node, ctx) {
+            if self.allow_as_props
+                || !function_returns_jsx(node)
+                || fast_glob::glob_match(self.prop_name_pattern.as_str(), prop_name)
+            {
+                return;
+            }
+            let Some(component) = get_rendering_component(node, ctx) else {
+                return;
+            };
+            ctx.diagnostic(no_unstable_component_as_prop_diagnostic(
+                span,
+                get_component_name(component, ctx).unwrap_or("component"),
+                prop_name,
+            ));
+            return;
+        }
+
+        if !is_function_component(node, ctx) && !is_es6_component(node) {
+            return;
+        }
+        let Some(component) = get_rendering_component(node, ctx) else {
+            return;
+        };
+        ctx.diagnostic(no_unstable_nested_components_diagnostic(
+            span,
+            get_component_name(component, ctx).unwrap_or("component"),
+        ));
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+/// The name of the JSX prop the function is passed as, directly as in `<Foo footer={() => {}} />`
+/// or as a property of an object as in `<Foo components={{ Footer: () => {} }} />`.
+fn get_prop_name<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<&'a str> {
+    if !matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)) {
+        return None;
+    }
+    let parent = ctx.nodes().parent_node(node.id());
+    match parent.kind() {
+        AstKind::JSXExpressionContainer(_) => {
+            let AstKind::JSXAttribute(attr) = ctx.nodes().parent_kind(parent.id()) else {
+                return None;
+            };
+            match &attr.name {
+                JSXAttributeName::Identifier(ident) => Some(ident.name.as_str()),
+                JSXAttributeName::NamespacedName(_) => None,
+            }
+        }
+        AstKind::ObjectProperty(prop) if prop.value.span() == node.span() => {
+            let object = ctx.nodes().parent_node(parent.id());
+            if !matches!(ctx.nodes().parent_kind(object.id()), AstKind::JSXExpressionContainer(_)) {
+                return None;
+            }
+            match &prop.key {
+                PropertyKey::StaticIdentifier(ident) => Some(ident.name.as_str()),
+                PropertyKey::StringLiteral(lit) => Some(lit.value.as_str()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "function ParentComponent() {
+              return <div><OutsideDefinedFunctionComponent /></div>;
+            }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+              const renderItem = () => <div />;
+              return <div>{renderItem()}</div>;
+            }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+              return <ul>{items.map((item) => <li key={item.id}>{item.name}</li>)}</ul>;
+            }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+              return <List renderItem={(item) => <div>{item}</div>} />;
+            }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+              return <List renderFooter={function () { return <div />; }} />;
+            }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+              return <Button onClick={() => setOpen(true)} />;
+            }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+              return <Table footer={() => <div />} />;
+            }",
+            Some(serde_json::json!([{ "allowAsProps": true }])),
+        ),
+        (
+            "function ParentComponent() {
+              return <Table footer={() => <div />} />;
+            }",
+            Some(serde_json::json!([{ "propNamePattern": "*" }])),
+        ),
+        (
+            "function ParentComponent() {
+              const Memoized = useMemo(() => <div />, []);
+              return Memoized;
+            }",
+            None,
+        ),
+        (
+            "function withHOC(WrappedComponent) {
+              return function Wrapper(props) {
+                return <WrappedComponent {...props} />;
+              };
+            }",
+            None,
+        ),
+        (
+            "function useCustomHook() {
+              const Header = () => <div />;
+              return Header;
+            }",
+            None,
+        ),
+        ("const Table = () => <Cell footer={() => 'text'} />;", None),
+        (
+            "function notAComponent() {
+              function Inner() { return <div />; }
+              return Inner;
+            }",
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "function ParentComponent() {
+              function UnstableNestedFunctionComponent() {
+                return <div />;
+              }
+              return <div><UnstableNestedFunctionComponent /></div>;
+            }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+              const UnstableNestedVariableComponent = () => <div />;
+              return <div><UnstableNestedVariableComponent /></div>;
+            }",
+            None,
+        ),
+        (
+            "const ParentComponent = () => {
+              const UnstableNestedMemo = memo(() => <div />);
+              return <UnstableNestedMemo />;
+            };",
+            None,
+        ),
+        (
+            "export default function () {
+              const Unstable = () => <div />;
+              return <Unstable />;
+            }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+              class UnstableNestedClassComponent extends React.Component {
+                render() { return <div />; }
+              }
+              return <UnstableNestedClassComponent />;
+            }",
+            None,
+        ),
+        (
+            "class ParentComponent extends React.Component {
+              render() {
+                function UnstableNestedFunctionComponent() {
+                  return <div />;
+                }
+                return <UnstableNestedFunctionComponent />;
+              }
+            }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+              return <Table footer={() => <div />} />;
+            }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+              return <Table components={{ Cell: () => <td /> }} />;
+            }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+              return <ul>{items.map((item) => {
+                const Item = () => <li>{item}</li>;
+                return <Item key={item} />;
+              })}</ul>;
+            }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+              return <Table renderFooter={() => <div />} />;
+            }",
+            Some(serde_json::json!([{ "propNamePattern": "*Renderer" }])),
+        ),
+    ];
+
+    Tester::new(NoUnstableNestedComponents::NAME, NoUnstableNestedComponents::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
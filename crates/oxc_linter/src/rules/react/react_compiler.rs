@@ -0,0 +1,377 @@
+use oxc_ast::{
+    AstKind,
+    ast::{BinaryExpression, Expression, FormalParameters, FunctionBody, Statement, UnaryOperator},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolId;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{get_component_name, is_function_component, is_react_hook, is_react_hook_name},
+};
+
+const SCOPE: &str = "eslint-plugin-react-compiler";
+
+fn mutate_props_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Mutating component props or hook arguments is not allowed.")
+        .with_help("Copy the value and change the copy instead, e.g. with the spread syntax.")
+        .with_label(span)
+        .with_error_code_scope(SCOPE)
+}
+
+fn mutate_hook_value_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Mutating a value returned from a Hook is not allowed.")
+        .with_help(
+            "Copy the value and change the copy instead, and update state with its setter function.",
+        )
+        .with_label(span)
+        .with_error_code_scope(SCOPE)
+}
+
+fn ref_during_render_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Refs cannot be accessed during render.")
+        .with_help("Access `ref.current` in effects or event handlers instead, or use state for values which are rendered.")
+        .with_label(span)
+        .with_error_code_scope(SCOPE)
+}
+
+fn hook_as_value_diagnostic(span: Span, hook_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "React Hook {hook_name:?} is used as a value. Hooks must be called directly, so that \
+        the same Hooks are called on every render."
+    ))
+    .with_help(
+        "Call the Hook at the top level of the component or Hook, and use its result instead.",
+    )
+    .with_label(span)
+    .with_error_code_scope(SCOPE)
+}
+
+/// Methods which change the array they are called on.
+const MUTATING_METHODS: [&str; 9] =
+    ["copyWithin", "fill", "pop", "push", "reverse", "shift", "sort", "splice", "unshift"];
+
+#[derive(Debug, Default, Clone)]
+pub struct ReactCompiler;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports code in components and Hooks which breaks the rules
+    /// [React Compiler](https://react.dev/learn/react-compiler) relies on to memoize them.
+    /// The rule name is `react-compiler/react-compiler`.
+    ///
+    /// It reports:
+    ///
+    /// - Mutations of props, Hook arguments, and values returned from Hooks, like state, e.g.
+    ///   `props.items.push(item)` or `state.count = 1`.
+    /// - Reads and writes of `ref.current` during render, except to lazily initialize the ref
+    ///   in `if (ref.current === null)`.
+    /// - Hooks which are used as values instead of being called, e.g. `const hook = useState`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// React Compiler assumes that components and Hooks are pure: props and state are never
+    /// changed, and rendering doesn't depend on refs. It skips code which breaks these rules,
+    /// or worse, memoizes it as if it didn't, so that changes are not rendered.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// function List({ items }) {
+    ///   items.sort();
+    ///   return <ul>{items.map((item) => <li key={item}>{item}</li>)}</ul>;
+    /// }
+    ///
+    /// function Counter() {
+    ///   const count = useRef(0);
+    ///   count.current += 1;
+    ///   return <div>{count.current}</div>;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// function List({ items }) {
+    ///   const sorted = items.toSorted();
+    ///   return <ul>{sorted.map((item) => <li key={item}>{item}</li>)}</ul>;
+    /// }
+    ///
+    /// function Counter() {
+    ///   const count = useRef(0);
+    ///   useEffect(() => {
+    ///     count.current += 1;
+    ///   });
+    ///   return <div />;
+    /// }
+    /// ```
+    ReactCompiler,
+    react,
+    suspicious
+);
+
+impl Rule for ReactCompiler {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::Function(func) => {
+                if let Some(body) = &func.body
+                    && is_component_or_hook(node, ctx)
+                {
+                    check_component_or_hook(node, &func.params, body, ctx);
+                }
+            }
+            AstKind::ArrowFunctionExpression(arrow) => {
+                if is_component_or_hook(node, ctx) {
+                    check_component_or_hook(node, &arrow.params, &arrow.body, ctx);
+                }
+            }
+            AstKind::IdentifierReference(ident) => {
+                if is_react_hook_name(&ident.name) {
+                    check_hook_as_value(node, ident.span, &ident.name, ctx);
+                }
+            }
+            // e.g. `React.useState`
+            AstKind::StaticMemberExpression(member) => {
+                if is_react_hook_name(&member.property.name)
+                    && member.object.get_identifier_reference().is_some_and(|ident| {
+                        ident.name.chars().next().is_some_and(char::is_uppercase)
+                    })
+                {
+                    check_hook_as_value(node, member.span, &member.property.name, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        // `useX` functions are common in other frameworks too
+        !ctx.file_extension().is_some_and(|ext| ext == "vue" || ext == "svelte" || ext == "mpx")
+    }
+}
+
+fn is_component_or_hook<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    is_function_component(node, ctx)
+        || get_component_name(node, ctx).is_some_and(is_react_hook_name)
+}
+
+fn check_component_or_hook<'a>(
+    node: &AstNode<'a>,
+    params: &FormalParameters<'a>,
+    body: &FunctionBody<'a>,
+    ctx: &LintContext<'a>,
+) {
+    for param in &params.items {
+        for ident in param.pattern.get_binding_identifiers() {
+            for span in find_mutations(ident.symbol_id(), ctx) {
+                ctx.diagnostic(mutate_props_diagnostic(span));
+            }
+        }
+    }
+
+    for stmt in &body.statements {
+        let Statement::VariableDeclaration(decl) = stmt else {
+            continue;
+        };
+        for declarator in &decl.declarations {
+            let Some(Expression::CallExpression(call)) =
+                declarator.init.as_ref().map(Expression::get_inner_expression)
+            else {
+                continue;
+            };
+            if !is_react_hook(&call.callee) {
+                continue;
+            }
+            if call.callee_name() == Some("useRef") {
+                if let Some(ident) = declarator.id.get_binding_identifier() {
+                    check_ref_accesses(node, ident.symbol_id(), &ident.name, ctx);
+                }
+                continue;
+            }
+            for ident in declarator.id.get_binding_identifiers() {
+                for span in find_mutations(ident.symbol_id(), ctx) {
+                    ctx.diagnostic(mutate_hook_value_diagnostic(span));
+                }
+            }
+        }
+    }
+}
+
+/// The spans of the expressions which change the value of `symbol_id`, like `foo.bar = 1` and
+/// `foo.push(1)`, but not `foo = 1`, which only changes the variable.
+fn find_mutations(symbol_id: SymbolId, ctx: &LintContext) -> Vec<Span> {
+    let nodes = ctx.nodes();
+    ctx.symbol_references(symbol_id)
+        .filter_map(|reference| {
+            let mut current = nodes.get_node(reference.node_id());
+            let mut is_member = false;
+            loop {
+                let parent = nodes.parent_node(current.id());
+                match parent.kind() {
+                    AstKind::StaticMemberExpression(member)
+                        if member.object.span() == current.span() =>
+                    {
+                        let is_mutating_call = MUTATING_METHODS
+                            .contains(&member.property.name.as_str())
+                            && matches!(
+                                nodes.parent_kind(parent.id()),
+                                AstKind::CallExpression(call) if call.callee.span() == member.span
+                            );
+                        if is_mutating_call {
+                            return Some(nodes.parent_kind(parent.id()).span());
+                        }
+                    }
+                    AstKind::ComputedMemberExpression(member)
+                        if member.object.span() == current.span() => {}
+                    AstKind::AssignmentExpression(assign)
+                        if is_member && assign.left.span() == current.span() =>
+                    {
+                        return Some(assign.span);
+                    }
+                    AstKind::UpdateExpression(update) if is_member => return Some(update.span),
+                    AstKind::UnaryExpression(unary)
+                        if is_member && unary.operator == UnaryOperator::Delete =>
+                    {
+                        return Some(unary.span);
+                    }
+                    _ => return None,
+                }
+                is_member = true;
+                current = parent;
+            }
+        })
+        .collect()
+}
+
+/// Reports `ref.current` in the render of `component`, i.e. not in functions it contains,
+/// unless it is in `if (ref.current === null)`, which lazily initializes the ref.
+fn check_ref_accesses<'a>(
+    component: &AstNode<'a>,
+    symbol_id: SymbolId,
+    ref_name: &str,
+    ctx: &LintContext<'a>,
+) {
+    let nodes = ctx.nodes();
+    for reference in ctx.symbol_references(symbol_id) {
+        let AstKind::StaticMemberExpression(member) = nodes.parent_kind(reference.node_id()) else {
+            continue;
+        };
+        if member.property.name != "current" {
+            continue;
+        }
+        let mut is_lazy_init = false;
+        let mut is_in_render = false;
+        for ancestor in nodes.ancestors(reference.node_id()) {
+            match ancestor.kind() {
+                AstKind::IfStatement(if_stmt) if is_ref_null_check(&if_stmt.test, ref_name) => {
+                    is_lazy_init = true;
+                }
+                AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+                    is_in_render = ancestor.id() == component.id();
+                    break;
+                }
+                _ => {}
+            }
+        }
+        if is_in_render && !is_lazy_init {
+            ctx.diagnostic(ref_during_render_diagnostic(member.span));
+        }
+    }
+}
+
+/// Whether `expr` is `ref.current === null`, `ref.current == undefined`, `!ref.current` or
+/// the like.
+fn is_ref_null_check(expr: &Expression, ref_name: &str) -> bool {
+    let is_ref_current = |expr: &Expression| {
+        expr.get_inner_expression().as_member_expression().is_some_and(|member| {
+            member.static_property_name() == Some("current")
+                && member.object().is_specific_id(ref_name)
+        })
+    };
+    match expr.get_inner_expression() {
+        Expression::UnaryExpression(unary) => {
+            unary.operator == UnaryOperator::LogicalNot && is_ref_current(&unary.argument)
+        }
+        Expression::BinaryExpression(binary) => {
+            let BinaryExpression { left, right, operator, .. } = &**binary;
+            operator.is_equality()
+                && ((is_ref_current(left) && is_nullish(right))
+                    || (is_ref_current(right) && is_nullish(left)))
+        }
+        _ => false,
+    }
+}
+
+fn is_nullish(expr: &Expression) -> bool {
+    expr.is_null() || expr.is_undefined()
+}
+
+/// Reports a Hook which is used as a value, e.g. `const hook = useState`, in a component or
+/// Hook.
+fn check_hook_as_value<'a>(node: &AstNode<'a>, span: Span, hook_name: &str, ctx: &LintContext<'a>) {
+    let nodes = ctx.nodes();
+    match nodes.parent_kind(node.id()) {
+        AstKind::CallExpression(call) if call.callee.span() == span => return,
+        AstKind::StaticMemberExpression(member) if member.object.span() == span => return,
+        AstKind::TSTypeQuery(_) | AstKind::ExportSpecifier(_) => return,
+        _ => {}
+    }
+    let is_in_component_or_hook = nodes.ancestors(node.id()).any(|ancestor| {
+        matches!(ancestor.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+            && is_component_or_hook(ancestor, ctx)
+    });
+    if is_in_component_or_hook {
+        ctx.diagnostic(hook_as_value_diagnostic(span, hook_name));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function List({ items }) { const sorted = items.toSorted(); return <ul>{sorted}</ul>; }",
+        "function List(props) { const items = [...props.items]; items.push(1); return <ul>{items}</ul>; }",
+        "function Counter() { const [count, setCount] = useState(0); return <button onClick={() => setCount(count + 1)}>{count}</button>; }",
+        "function Foo({ value }) { value = value ?? 1; return <div>{value}</div>; }",
+        "function Foo(props) { props.onChange(1); return <div>{props.items.map((item) => item)}</div>; }",
+        "function Foo() { const ref = useRef(null); useEffect(() => { ref.current.focus(); }); return <input ref={ref} />; }",
+        "function Foo() { const ref = useRef(null); const onClick = () => { ref.current = 1; }; return <button onClick={onClick} />; }",
+        "function Foo() { const ref = useRef(null); if (ref.current === null) { ref.current = new Store(); } return <div />; }",
+        "function Foo() { const ref = useRef(null); if (!ref.current) ref.current = new Store(); return <div />; }",
+        "function useFoo() { const [state] = useState(0); return useMemo(() => state + 1, [state]); }",
+        "function notAComponent(props) { props.value = 1; }",
+        "function foo() { const ref = useRef(null); return ref.current; }",
+        "const hooks = [useState, useEffect];",
+        "function Foo() { const value = useContext(Context); const copy = { ...value, a: 1 }; return <div>{copy}</div>; }",
+        "function Foo() { const [state] = useState(() => []); const items = state.slice(); items.push(1); return <div>{items}</div>; }",
+        "function Foo() { const { data } = useQuery(); return <div>{data.length}</div>; }",
+    ];
+
+    let fail = vec![
+        "function List({ items }) { items.sort(); return <ul>{items}</ul>; }",
+        "function List(props) { props.items.push(1); return <ul>{props.items}</ul>; }",
+        "function Foo(props) { props.value = 1; return <div />; }",
+        "const Foo = ({ style }) => { style.color = 'red'; return <div style={style} />; }",
+        "function Foo(props) { const onClick = () => { props.count++; }; return <button onClick={onClick} />; }",
+        "function Foo(props) { delete props.value; return <div />; }",
+        "function useFoo(options) { options.enabled = true; }",
+        "function Counter() { const [state, setState] = useState({ count: 0 }); state.count = 1; return <div>{state.count}</div>; }",
+        "function Foo() { const [items] = useState([]); const onClick = () => items.push(1); return <button onClick={onClick} />; }",
+        "function Foo() { const value = useContext(Context); value.count += 1; return <div />; }",
+        "function Counter() { const count = useRef(0); count.current += 1; return <div />; }",
+        "function Foo() { const ref = useRef(null); return <div>{ref.current}</div>; }",
+        "function Foo() { const ref = React.useRef(null); const width = ref.current.offsetWidth; return <div>{width}</div>; }",
+        "function useFoo() { const ref = useRef(0); return ref.current; }",
+        "function Foo() { const hook = useState; const [state] = hook(0); return <div>{state}</div>; }",
+        "function Foo() { const value = useMemo(useCallback, []); return <div>{value}</div>; }",
+        "function Foo() { const hook = React.useState; return <div />; }",
+    ];
+
+    Tester::new(ReactCompiler::NAME, ReactCompiler::PLUGIN, pass, fail).test_and_snapshot();
+}
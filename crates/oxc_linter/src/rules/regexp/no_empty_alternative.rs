@@ -0,0 +1,121 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::Disjunction,
+    visit::{RegExpAstKind, Visit},
+};
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::run_on_regex_node};
+
+fn no_empty_alternative_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected empty alternative in a regular expression.")
+        .with_help("Use a `?` quantifier to make the other alternatives optional, e.g. `(?:a|b)?` instead of `(?:a|b|)`.")
+        .with_label(span.label("This alternative matches the empty string"))
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoEmptyAlternative;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows empty alternatives in regular expressions, e.g. the last one of `(a|b|)`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An empty alternative matches the empty string, which makes the other alternatives
+    /// optional. This is easily overlooked, or is a mistake, like a leftover `|`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const size = /^(?:small|large|)$/;
+    /// const sign = new RegExp("(|-)\\d+");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const size = /^(?:small|large)?$/;
+    /// const sign = new RegExp("-?\\d+");
+    /// ```
+    NoEmptyAlternative,
+    regexp,
+    suspicious
+);
+
+impl Rule for NoEmptyAlternative {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node(node, ctx, |pattern, _span| {
+            let mut finder = EmptyAlternativeFinder::default();
+            finder.visit_pattern(pattern);
+            for span in finder.empty_alternatives {
+                ctx.diagnostic(no_empty_alternative_diagnostic(span));
+            }
+        });
+    }
+}
+
+#[derive(Default)]
+struct EmptyAlternativeFinder {
+    empty_alternatives: Vec<Span>,
+}
+
+impl<'a> Visit<'a> for EmptyAlternativeFinder {
+    fn enter_node(&mut self, kind: RegExpAstKind<'a>) {
+        if let RegExpAstKind::Disjunction(disjunction) = kind {
+            self.check_disjunction(disjunction);
+        }
+    }
+}
+
+impl EmptyAlternativeFinder {
+    /// A single empty alternative is an empty group or pattern, e.g. `(?:)`, which is not an
+    /// alternation.
+    fn check_disjunction(&mut self, disjunction: &Disjunction) {
+        if disjunction.body.len() < 2 {
+            return;
+        }
+        for alternative in &disjunction.body {
+            if alternative.body.is_empty() {
+                // Label the `|` next to the empty alternative.
+                let span = if alternative.span.start == disjunction.span.start {
+                    Span::sized(alternative.span.start, 1)
+                } else {
+                    Span::sized(alternative.span.start - 1, 1)
+                };
+                self.empty_alternatives.push(span);
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r"/a|b/",
+        r"/(?:small|large)?/",
+        r"/()/",
+        r"/(?:)/",
+        r"/[|]/",
+        r"/a\|/",
+        r#"new RegExp("a|b")"#,
+        r"new RegExp(pattern)",
+    ];
+
+    let fail = vec![
+        r"/a|/",
+        r"/|a/",
+        r"/(?:small|large|)$/",
+        r"/(a||b)/",
+        r"/(|-)\d+/",
+        r#"new RegExp("(|-)\\d+")"#,
+        r#"RegExp("a|", "u")"#,
+    ];
+
+    Tester::new(NoEmptyAlternative::NAME, NoEmptyAlternative::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,261 @@
+use oxc_ast::ast::RegExpFlags;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{Alternative, Quantifier, Term},
+    visit::{RegExpAstKind, Visit},
+};
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        get_quantifier_alternatives, get_regex_flags, get_term_char_set, get_term_chars,
+        get_term_first_chars, get_term_repeatable_chars, is_term_nullable, run_on_regex_node,
+    },
+};
+
+fn exponential_backtracking_diagnostic(span: Span, reason: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "This regular expression can take exponential time to match some inputs.",
+    )
+    .with_help(format!(
+        "{reason}, so a long input of it can be matched in exponentially many ways. Rewrite the pattern so that each character can only be matched in one way."
+    ))
+    .with_label(span.label("This can exchange characters with the repetition around it"))
+}
+
+fn polynomial_backtracking_diagnostic(
+    span: Span,
+    first: &str,
+    second: &str,
+    example: char,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn("This regular expression can take polynomial time to match some inputs.")
+        .with_help(format!(
+            "`{first}` and `{second}` can both match {example:?}, so a long input of it can be split between them in many ways. Rewrite the pattern so that the quantifiers can't match the same characters."
+        ))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoSuperLinearBacktracking;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows regular expressions which can take more than linear time to match, because
+    /// their backtracking tries many ways to match the same characters.
+    ///
+    /// Two cases are reported:
+    /// - A repeated part which can match the characters of its next repetition, e.g. `(a+)+` or
+    ///   `(\w|\d)*`, which takes exponential time.
+    /// - Two unbounded quantifiers in a row which can match the same characters, e.g. `\d+\w*`,
+    ///   which takes polynomial time.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An input which almost matches such a regular expression, e.g. many spaces followed by
+    /// a `!` for `/^\s*(.*?)\s*$/`, can block the event loop for seconds or minutes. When the
+    /// input comes from a user, this is a denial of service known as ReDoS.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const email = /^(\w+\.?)+@example\.com$/;
+    /// const any = /(.|\s)*/;
+    /// const number = /\d+\.?\d*x/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const email = /^\w+(?:\.\w+)*@example\.com$/;
+    /// const any = /[\s\S]*/;
+    /// const number = /\d+(?:\.\d*)?x/;
+    /// ```
+    NoSuperLinearBacktracking,
+    regexp,
+    suspicious
+);
+
+impl Rule for NoSuperLinearBacktracking {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node(node, ctx, |pattern, _span| {
+            let mut finder = BacktrackingFinder {
+                flags: get_regex_flags(node, ctx),
+                source_text: ctx.source_text(),
+                diagnostics: vec![],
+            };
+            finder.visit_pattern(pattern);
+            for diagnostic in finder.diagnostics {
+                ctx.diagnostic(diagnostic);
+            }
+        });
+    }
+}
+
+struct BacktrackingFinder<'s> {
+    flags: RegExpFlags,
+    source_text: &'s str,
+    diagnostics: Vec<OxcDiagnostic>,
+}
+
+impl<'a> Visit<'a> for BacktrackingFinder<'_> {
+    fn enter_node(&mut self, kind: RegExpAstKind<'a>) {
+        match kind {
+            RegExpAstKind::Quantifier(quantifier) if quantifier.max.is_none() => {
+                self.check_repetition(quantifier);
+            }
+            RegExpAstKind::Alternative(alternative) => self.check_sequence(alternative),
+            _ => {}
+        }
+    }
+}
+
+impl BacktrackingFinder<'_> {
+    /// Checks whether the next repetition of an unbounded quantifier can start with characters
+    /// that the current repetition can still consume.
+    fn check_repetition(&mut self, quantifier: &Quantifier) {
+        let Some(first_chars) = get_term_first_chars(&quantifier.body, self.flags) else {
+            return;
+        };
+        let outer = quantifier.span.source_text(self.source_text);
+        let alternatives = get_quantifier_alternatives(quantifier);
+
+        // e.g. `(a+)+` or `(\w+\d+)*`, where the last unbounded part of a repetition overlaps
+        // with the start of the next one.
+        for terms in &alternatives {
+            for (i, term) in terms.iter().enumerate() {
+                let Some(inner) = get_unbounded_quantifier(term) else {
+                    continue;
+                };
+                if !terms[i + 1..].iter().all(is_term_nullable) {
+                    continue;
+                }
+                let Some(chars) = get_term_chars(&inner.body, self.flags) else {
+                    continue;
+                };
+                if let Some(example) = chars.intersect(&first_chars).first() {
+                    let inner_text = inner.span.source_text(self.source_text);
+                    self.diagnostics.push(exponential_backtracking_diagnostic(
+                        inner.span,
+                        &format!("`{inner_text}` and the repetition of `{outer}` can both match {example:?}"),
+                    ));
+                    return;
+                }
+            }
+        }
+
+        // e.g. `(.|\s)*`, where two alternatives of a single character overlap.
+        let single_chars = alternatives
+            .iter()
+            .filter_map(|terms| match terms {
+                [term] => Some((term, get_term_char_set(term, self.flags)?)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for (i, (term, chars)) in single_chars.iter().enumerate() {
+            for (other_term, other_chars) in &single_chars[i + 1..] {
+                if let Some(example) = chars.intersect(other_chars).first() {
+                    let span = Span::new(term.span().start, other_term.span().end);
+                    let reason = format!(
+                        "The alternatives `{}` and `{}` of `{outer}` can both match {example:?}",
+                        term.span().source_text(self.source_text),
+                        other_term.span().source_text(self.source_text)
+                    );
+                    self.diagnostics.push(exponential_backtracking_diagnostic(span, &reason));
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Checks whether two unbounded quantifiers of a sequence, with only optional terms between
+    /// them, can match the same characters, e.g. `\d+\.?\d*`.
+    fn check_sequence(&mut self, alternative: &Alternative) {
+        let terms = &alternative.body;
+        for (i, first) in terms.iter().enumerate() {
+            let Some(first) = get_unbounded_quantifier(first) else { continue };
+            let Some(first_chars) = get_term_repeatable_chars(&first.body, self.flags) else {
+                continue;
+            };
+            for second in &terms[i + 1..] {
+                if let Some(second) = get_unbounded_quantifier(second)
+                    && let Some(second_chars) = get_term_repeatable_chars(&second.body, self.flags)
+                    && let Some(example) = first_chars.intersect(&second_chars).first()
+                {
+                    self.diagnostics.push(polynomial_backtracking_diagnostic(
+                        Span::new(first.span.start, second.span.end),
+                        first.span.source_text(self.source_text),
+                        second.span.source_text(self.source_text),
+                        example,
+                    ));
+                    break;
+                }
+                if !is_term_nullable(second) {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// The unbounded quantifier of a term, which is either the term itself, or is the only term of
+/// an alternative of a group, e.g. `a+` in `a+`, `(a+)` or `(?:a+|b)`.
+fn get_unbounded_quantifier<'b, 'a>(term: &'b Term<'a>) -> Option<&'b Quantifier<'a>> {
+    let disjunction = match term {
+        Term::Quantifier(quantifier) if quantifier.max.is_none() => return Some(quantifier),
+        Term::CapturingGroup(group) => &group.body,
+        Term::IgnoreGroup(group) => &group.body,
+        _ => return None,
+    };
+    disjunction.body.iter().find_map(|alternative| match alternative.body.as_slice() {
+        [term] => get_unbounded_quantifier(term),
+        _ => None,
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r"/^\w+(?:\.\w+)*@example\.com$/",
+        r"/\d+(?:\.\d*)?x/",
+        r"/[\s\S]*/",
+        r"/(a+b)+/",
+        r"/(\d+)-(\d+)/",
+        r"/a.*b.*c/",
+        r"/\s*\S+\s*/",
+        r"/(a|b)*/",
+        r"/(ab){0,10}/",
+        r"/a*b*/",
+        r"/(\w+\s?){0,10}$/",
+        r"/\p{L}+\p{L}+/u",
+        r"/(a)+\1+/",
+        r#"new RegExp("^\\d+$")"#,
+        r"new RegExp(pattern)",
+    ];
+
+    let fail = vec![
+        r"/(a+)+/",
+        r"/^(\w+\.?)+@example\.com$/",
+        r"/(\w+\d+)+x/",
+        r"/(?:a*)*$/",
+        r"/(.|\s)*/",
+        r"/(\w|\d)+$/",
+        r"/(a|A)+/i",
+        r"/^\s*(.*?)\s*$/",
+        r"/\d+\.?\d*x/",
+        r"/\w+\d+/",
+        r"/a*(?:b|)a*c/",
+        r#"new RegExp("(\\w+\\s?)*$")"#,
+        r#"RegExp("\\s+\\s+$", "u")"#,
+    ];
+
+    Tester::new(NoSuperLinearBacktracking::NAME, NoSuperLinearBacktracking::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
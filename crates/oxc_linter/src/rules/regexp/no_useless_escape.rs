@@ -0,0 +1,184 @@
+use oxc_ast::{AstKind, ast::RegExpFlags};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{Character, CharacterClass, CharacterKind},
+    visit::{RegExpAstKind, Visit},
+};
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{get_regex_flags, run_on_regex_node},
+};
+
+fn no_useless_escape_diagnostic(escaped: char, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unnecessary escape of {escaped:?} in a regular expression."))
+        .with_help("Remove the `\\`, the character has no special meaning here.")
+        .with_label(span)
+}
+
+/// The characters which have a special meaning outside of a character class, or end a regular
+/// expression literal.
+const SYNTAX_CHARACTERS: &str = "^$\\.*+?()[]{}|/";
+
+/// The characters which have a special meaning in a character class. `^` only has one at the
+/// start of the class, which is checked separately.
+const CLASS_SYNTAX_CHARACTERS: &str = "\\]-/";
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessEscape;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows escaping characters which have no special meaning in a regular expression,
+    /// e.g. `\#` or `[\.]`. Unlike `eslint/no-useless-escape`, this also checks the patterns
+    /// of `RegExp` calls, and analyzes the parsed pattern, so escapes of a string are not
+    /// mistaken for escapes of the regular expression.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An escape which has no effect makes the pattern harder to read, and suggests that the
+    /// character has a special meaning where it doesn't.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const tag = /\#\w+/;
+    /// const number = /[\d\.]+/;
+    /// const mention = new RegExp("\\@\\w+");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const tag = /#\w+/;
+    /// const number = /[\d.]+/;
+    /// const mention = new RegExp("@\\w+");
+    /// const path = /\/\.\*/;
+    /// ```
+    NoUselessEscape,
+    regexp,
+    style,
+    fix
+);
+
+impl Rule for NoUselessEscape {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node(node, ctx, |pattern, _span| {
+            let mut finder = UselessEscapeFinder {
+                unicode_sets: get_regex_flags(node, ctx).contains(RegExpFlags::V),
+                character_classes: vec![],
+                useless_escapes: vec![],
+            };
+            finder.visit_pattern(pattern);
+
+            // The `\` of the regular expression is written as `\\` in a string.
+            let is_literal = matches!(node.kind(), AstKind::RegExpLiteral(_));
+            for (escaped, span) in finder.useless_escapes {
+                let text = span.source_text(ctx.source_text());
+                let unescaped =
+                    if is_literal { text.strip_prefix('\\') } else { text.strip_prefix("\\\\") };
+                let diagnostic = no_useless_escape_diagnostic(escaped, span);
+                match unescaped {
+                    Some(unescaped) => ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                        fixer.replace(span, unescaped.to_string())
+                    }),
+                    None => ctx.diagnostic(diagnostic),
+                }
+            }
+        });
+    }
+}
+
+struct UselessEscapeFinder<'a> {
+    unicode_sets: bool,
+    character_classes: Vec<&'a CharacterClass<'a>>,
+    useless_escapes: Vec<(char, Span)>,
+}
+
+impl<'a> Visit<'a> for UselessEscapeFinder<'a> {
+    fn enter_node(&mut self, kind: RegExpAstKind<'a>) {
+        if let RegExpAstKind::CharacterClass(class) = kind {
+            self.character_classes.push(class);
+        }
+    }
+
+    fn leave_node(&mut self, kind: RegExpAstKind<'a>) {
+        if let RegExpAstKind::CharacterClass(_) = kind {
+            self.character_classes.pop();
+        }
+    }
+
+    fn visit_character(&mut self, character: &Character) {
+        // Other kinds of escapes, like `\n` or `\x41`, don't match the escaped character.
+        if character.kind != CharacterKind::Identifier {
+            return;
+        }
+        let Some(escaped) = char::from_u32(character.value) else {
+            return;
+        };
+        let is_useless = match self.character_classes.last() {
+            // With the `v` flag, only characters which may need an escape can be escaped.
+            Some(_) if self.unicode_sets => false,
+            Some(class) => {
+                !(CLASS_SYNTAX_CHARACTERS.contains(escaped)
+                    || escaped == '^' && is_at_class_start(class, character))
+            }
+            None => !SYNTAX_CHARACTERS.contains(escaped),
+        };
+        if is_useless {
+            self.useless_escapes.push((escaped, character.span));
+        }
+    }
+}
+
+fn is_at_class_start(class: &CharacterClass, character: &Character) -> bool {
+    class.body.first().is_some_and(|first| first.span() == character.span)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r"/#\w+/",
+        r"/[\d.]+/",
+        r"/\/\.\*\+\?\^\$\{\}\(\)\|\[\]\\/",
+        r"/[\]\\\-\/]/",
+        r"/[\^a]/",
+        r"/\n\t\x41\u0041\0\cJ/",
+        r"/\d\w\s\b\B/",
+        r"/[\&\&]/v",
+        r#"new RegExp("@\\w+")"#,
+        r#"new RegExp("\\.")"#,
+        r#"new RegExp("\#")"#,
+        r"new RegExp(pattern)",
+    ];
+
+    let fail = vec![
+        r"/\#\w+/",
+        r"/[\d\.]+/",
+        r"/[a\^]/",
+        r"/[\(\)]/",
+        r"/\-/",
+        r"/\a\e/",
+        r#"new RegExp("\\@\\w+")"#,
+        r#"RegExp("[\\.]", "u")"#,
+    ];
+
+    let fix = vec![
+        (r"/\#\w+/", r"/#\w+/"),
+        (r"/[\d\.]+/", r"/[\d.]+/"),
+        (r"/[\(\)]/", r"/[()]/"),
+        (r#"new RegExp("\\@\\w+")"#, r#"new RegExp("@\\w+")"#),
+        (r#"RegExp("[\\.]", "u")"#, r#"RegExp("[.]", "u")"#),
+    ];
+
+    Tester::new(NoUselessEscape::NAME, NoUselessEscape::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
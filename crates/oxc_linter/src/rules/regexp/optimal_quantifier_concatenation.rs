@@ -0,0 +1,257 @@
+use oxc_ast::ast::RegExpFlags;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{Alternative, Term},
+    visit::{RegExpAstKind, Visit},
+};
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{CharSet, get_regex_flags, get_term_char_set, run_on_regex_node},
+};
+
+fn optimal_quantifier_concatenation_diagnostic(
+    span: Span,
+    original: &str,
+    replacement: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{original}` can be simplified to `{replacement}`."))
+        .with_help("One quantifier can match every character of the other one, so they can be combined without changing what the regular expression matches.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct OptimalQuantifierConcatenation;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports two quantified characters in a row which can be written as one quantifier, or
+    /// where one of the quantifiers does more than it has to, e.g. `a+a*` is `a+`, and `\w+\d*`
+    /// is `\w+`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The longer pattern is harder to read, and gives the regular expression engine more ways
+    /// to match the same characters, which can make it slow, see
+    /// `regexp/no-super-linear-backtracking`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const a = /a+a*/;
+    /// const b = /\w+\d*/;
+    /// const c = /\d\d+/;
+    /// const d = /[a-z]*\w+/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const a = /a+/;
+    /// const b = /\w+/;
+    /// const c = /\d{2,}/;
+    /// const d = /\w+/;
+    /// ```
+    OptimalQuantifierConcatenation,
+    regexp,
+    style,
+    fix
+);
+
+impl Rule for OptimalQuantifierConcatenation {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node(node, ctx, |pattern, _span| {
+            let mut finder = ConcatenationFinder {
+                flags: get_regex_flags(node, ctx),
+                source_text: ctx.source_text(),
+                replacements: vec![],
+            };
+            finder.visit_pattern(pattern);
+            for (span, replacement) in finder.replacements {
+                let original = span.source_text(ctx.source_text());
+                ctx.diagnostic_with_fix(
+                    optimal_quantifier_concatenation_diagnostic(span, original, &replacement),
+                    |fixer| fixer.replace(span, replacement.clone()),
+                );
+            }
+        });
+    }
+}
+
+/// A term which matches a single character, possibly quantified, e.g. `a`, `\d+` or `[a-z]*`.
+struct Repetition<'s> {
+    span: Span,
+    /// The source text of the single character term.
+    text: &'s str,
+    chars: CharSet,
+    min: u64,
+    max: Option<u64>,
+    /// `None` for a term without a quantifier.
+    greedy: Option<bool>,
+}
+
+impl Repetition<'_> {
+    fn with_count(&self, min: u64, max: Option<u64>, greedy: Option<bool>) -> String {
+        let quantifier = match (min, max) {
+            (1, Some(1)) => String::new(),
+            (0, None) => "*".to_string(),
+            (1, None) => "+".to_string(),
+            (0, Some(1)) => "?".to_string(),
+            (min, None) => format!("{{{min},}}"),
+            (min, Some(max)) if min == max => format!("{{{min}}}"),
+            (min, Some(max)) => format!("{{{min},{max}}}"),
+        };
+        let lazy = if greedy == Some(false) && !quantifier.is_empty() { "?" } else { "" };
+        format!("{}{quantifier}{lazy}", self.text)
+    }
+
+    /// The repetition written with the quantifier it would have on its own.
+    fn span_text(&self) -> String {
+        self.with_count(self.min, self.max, self.greedy)
+    }
+
+    /// The repetition with only its minimum count, when it follows or precedes an unbounded
+    /// repetition of a superset of its characters.
+    fn min_only(&self) -> String {
+        match self.min {
+            0 => String::new(),
+            min => self.with_count(min, Some(min), self.greedy),
+        }
+    }
+}
+
+struct ConcatenationFinder<'s> {
+    flags: RegExpFlags,
+    source_text: &'s str,
+    replacements: Vec<(Span, String)>,
+}
+
+impl<'a> Visit<'a> for ConcatenationFinder<'_> {
+    fn enter_node(&mut self, kind: RegExpAstKind<'a>) {
+        if let RegExpAstKind::Alternative(alternative) = kind {
+            self.check_alternative(alternative);
+        }
+    }
+}
+
+impl<'s> ConcatenationFinder<'s> {
+    fn check_alternative(&mut self, alternative: &Alternative) {
+        let repetitions =
+            alternative.body.iter().map(|term| self.get_repetition(term)).collect::<Vec<_>>();
+        let mut i = 0;
+        while i + 1 < repetitions.len() {
+            if let (Some(first), Some(second)) = (&repetitions[i], &repetitions[i + 1])
+                && let Some(replacement) = get_replacement(first, second)
+            {
+                self.replacements.push((Span::new(first.span.start, second.span.end), replacement));
+                // Don't report overlapping pairs, whose fixes would conflict.
+                i += 2;
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    fn get_repetition(&self, term: &Term) -> Option<Repetition<'s>> {
+        let (body, min, max, greedy) = match term {
+            Term::Quantifier(quantifier) => {
+                (&quantifier.body, quantifier.min, quantifier.max, Some(quantifier.greedy))
+            }
+            term => (term, 1, Some(1), None),
+        };
+        Some(Repetition {
+            span: term.span(),
+            text: body.span().source_text(self.source_text),
+            chars: get_term_char_set(body, self.flags)?,
+            min,
+            max,
+            greedy,
+        })
+    }
+}
+
+fn get_replacement(first: &Repetition, second: &Repetition) -> Option<String> {
+    let greedy = match (first.greedy, second.greedy) {
+        (Some(first), Some(second)) if first != second => return None,
+        (None, None) => return None,
+        (first, second) => first.or(second),
+    };
+
+    // e.g. `a+a*` to `a+`, or `\d\d+` to `\d{2,}`
+    if first.chars == second.chars {
+        if first.max.is_some() && second.max.is_some() {
+            return None;
+        }
+        return Some(first.with_count(first.min + second.min, None, greedy));
+    }
+    // e.g. `\w+\d*` to `\w+`, or `\w*\d{2,}` to `\w*\d{2}`
+    if first.max.is_none() && second.chars.is_subset(&first.chars) && second.max != Some(second.min)
+    {
+        return Some(format!("{}{}", first.span_text(), second.min_only()));
+    }
+    // e.g. `\d*\w+` to `\w+`
+    if second.max.is_none() && first.chars.is_subset(&second.chars) && first.max != Some(first.min)
+    {
+        return Some(format!("{}{}", first.min_only(), second.span_text()));
+    }
+    None
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r"/a+/",
+        r"/\w+/",
+        r"/a+b*/",
+        r"/\d+\w/",
+        r"/a?a?/",
+        r"/a{2}a{3}/",
+        r"/a+?a*/",
+        r"/\d+\.\d*/",
+        r"/\w*\d/",
+        r"/(a+)a*/",
+        r"/\p{L}+\p{L}*/u",
+        r#"new RegExp("\\d+")"#,
+        r"new RegExp(pattern)",
+    ];
+
+    let fail = vec![
+        r"/a+a*/",
+        r"/\d*\d+/",
+        r"/\d\d+/",
+        r"/a*?a+?/",
+        r"/\w+\d*/",
+        r"/\w+\d{2,}/",
+        r"/[a-z]*\w+/",
+        r"/A+a*/i",
+        r"/.*.+/",
+        r#"new RegExp("\\s+\\s*")"#,
+    ];
+
+    let fix = vec![
+        (r"/a+a*/", r"/a+/"),
+        (r"/\d*\d+/", r"/\d+/"),
+        (r"/\d\d+/", r"/\d{2,}/"),
+        (r"/a*?a+?/", r"/a+?/"),
+        (r"/\w+\d*/", r"/\w+/"),
+        (r"/\w+\d{2,}/", r"/\w+\d{2}/"),
+        (r"/[a-z]*\w+/", r"/\w+/"),
+        (r#"new RegExp("\\s+\\s*")"#, r#"new RegExp("\\s+")"#),
+    ];
+
+    Tester::new(
+        OptimalQuantifierConcatenation::NAME,
+        OptimalQuantifierConcatenation::PLUGIN,
+        pass,
+        fail,
+    )
+    .expect_fix(fix)
+    .test_and_snapshot();
+}
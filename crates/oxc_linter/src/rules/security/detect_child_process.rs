@@ -0,0 +1,89 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{InjectionSinkKind, find_injected_argument},
+};
+
+fn detect_child_process_diagnostic(span: Span, sink: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{sink}` is called with a non-literal command, which may run commands injected into it."
+    ))
+    .with_help("Use `execFile` or `spawn`, which don't run the command in a shell, and pass its arguments as an array.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectChildProcess;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows running shell commands which are not string literals with `exec` and
+    /// `execSync` of the `child_process` module of Node.js.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `exec` runs its command in a shell, so a value which comes from a user and is part of the
+    /// command can run other commands, e.g. `file.txt; rm -rf /`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import { exec } from "node:child_process";
+    ///
+    /// exec(`cat ${request.query.file}`, callback);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import { exec, execFile } from "node:child_process";
+    ///
+    /// exec("git status", callback);
+    /// execFile("cat", [request.query.file], callback);
+    /// ```
+    DetectChildProcess,
+    security,
+    suspicious
+);
+
+impl Rule for DetectChildProcess {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if let Some((sink, span)) = find_injected_argument(node, InjectionSinkKind::Command, ctx) {
+            ctx.diagnostic(detect_child_process_diagnostic(span, &sink));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"import { exec } from "child_process"; exec("git status", callback)"#,
+        r#"import { execSync } from "node:child_process"; execSync(`git log -n ${"1"}`)"#,
+        r#"import { execFile } from "child_process"; execFile("cat", [file], callback)"#,
+        r#"import { spawn } from "child_process"; spawn(command, args)"#,
+        r#"const child_process = require("child_process"); child_process.exec("ls")"#,
+        r"child_process.exec(command)",
+        r"const regex = /a/; regex.exec(input)",
+        r#"import { exec } from "./utils"; exec(command)"#,
+    ];
+
+    let fail = vec![
+        r#"import { exec } from "child_process"; exec(command, callback)"#,
+        r#"import { execSync } from "node:child_process"; execSync(`cat ${file}`)"#,
+        r#"import cp from "child_process"; cp.exec("cat " + file)"#,
+        r#"import * as cp from "child_process"; cp.execSync(command)"#,
+        r#"const { exec } = require("child_process"); exec(request.query.command)"#,
+        r#"require("child_process").exec(command)"#,
+    ];
+
+    Tester::new(DetectChildProcess::NAME, DetectChildProcess::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
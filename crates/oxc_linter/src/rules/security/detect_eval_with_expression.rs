@@ -0,0 +1,92 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{InjectionSinkKind, find_injected_argument},
+};
+
+fn detect_eval_with_expression_diagnostic(span: Span, sink: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{sink}` is called with an expression, which may evaluate code from an untrusted source."
+    ))
+    .with_help("Avoid evaluating code built at runtime, e.g. parse data with `JSON.parse`, or pass a function instead.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectEvalWithExpression;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows evaluating code which is not a string literal, with `eval`, the `Function`
+    /// constructor, or the `vm` module of Node.js.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Code built from a value which comes from a user, e.g. a request parameter, lets the user
+    /// run arbitrary code with the privileges of the application.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// eval(request.query.code);
+    /// const fn = new Function("a", `return a.${property}`);
+    /// vm.runInThisContext(source);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// eval("1 + 1");
+    /// const data = JSON.parse(request.body);
+    /// const value = object[property];
+    /// ```
+    DetectEvalWithExpression,
+    security,
+    suspicious
+);
+
+impl Rule for DetectEvalWithExpression {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if let Some((sink, span)) = find_injected_argument(node, InjectionSinkKind::Code, ctx) {
+            ctx.diagnostic(detect_eval_with_expression_diagnostic(span, &sink));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"eval("1 + 1")"#,
+        r"eval(`1 + ${'1'}`)",
+        r#"new Function("a", "b", "return a + b")"#,
+        r"function eval(code) {} eval(code)",
+        r"const obj = { eval() {} }; obj.eval(code)",
+        r"import vm from './vm'; vm.runInThisContext(code)",
+        r"vm.runInThisContext(code)",
+        r"JSON.parse(input)",
+    ];
+
+    let fail = vec![
+        r"eval(code)",
+        r"eval(`var x = ${value}`)",
+        r#"eval("var x = " + value)"#,
+        r"const e = eval; e(code)",
+        r"new Function(body)",
+        r#"Function("a", body)"#,
+        r"Function(...args)",
+        r#"import vm from "node:vm"; vm.runInThisContext(code)"#,
+        r#"const { runInNewContext } = require("vm"); runInNewContext(code, {})"#,
+        r#"import { Script } from "vm"; new Script(source)"#,
+    ];
+
+    Tester::new(DetectEvalWithExpression::NAME, DetectEvalWithExpression::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
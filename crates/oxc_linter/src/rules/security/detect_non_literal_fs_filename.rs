@@ -0,0 +1,97 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{InjectionSinkKind, find_injected_argument},
+};
+
+fn detect_non_literal_fs_filename_diagnostic(span: Span, sink: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{sink}` is called with a non-literal path, which may access files outside of the intended directory."
+    ))
+    .with_help("Check that the path stays inside of the intended directory, e.g. after resolving it against that directory.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectNonLiteralFsFilename;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows calling functions of the `fs` module of Node.js with paths which are not string
+    /// literals, or built from string literals, `__dirname` and `__filename`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A path which comes from a user, e.g. `../../etc/passwd`, can read or write files outside
+    /// of the directory the application means to access.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import fs from "node:fs";
+    ///
+    /// fs.readFile(request.query.file, callback);
+    /// fs.writeFileSync(`./uploads/${name}`, data);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import fs from "node:fs";
+    /// import path from "node:path";
+    ///
+    /// fs.readFile("./config.json", callback);
+    /// fs.writeFileSync(path.join(__dirname, "output.txt"), data);
+    /// ```
+    DetectNonLiteralFsFilename,
+    security,
+    suspicious
+);
+
+impl Rule for DetectNonLiteralFsFilename {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if let Some((sink, span)) = find_injected_argument(node, InjectionSinkKind::FileSystem, ctx)
+        {
+            ctx.diagnostic(detect_non_literal_fs_filename_diagnostic(span, &sink));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"import fs from "fs"; fs.readFile("./config.json", callback)"#,
+        r#"import fs from "fs"; fs.readFileSync(__dirname + "/config.json")"#,
+        r#"import fs from "fs"; fs.readFileSync(`${__dirname}/config.json`)"#,
+        r#"import fs from "fs"; import path from "path"; fs.readFileSync(path.join(__dirname, "a", "b.json"))"#,
+        r#"import { writeFile } from "node:fs/promises"; await writeFile(import.meta.dirname + "/out.txt", data)"#,
+        r#"const fs = require("fs"); fs.writeFile("out.txt", data, callback)"#,
+        r#"import fs from "fs"; fs.copyFile("a.txt", "b.txt", callback)"#,
+        r"fs.readFile(file, callback)",
+        r#"import fs from "./fs"; fs.readFile(file, callback)"#,
+        r#"import fs from "fs"; fs.writeFile("out.txt", data, callback)"#,
+    ];
+
+    let fail = vec![
+        r#"import fs from "fs"; fs.readFile(file, callback)"#,
+        r#"import fs from "node:fs"; fs.readFileSync(request.query.file)"#,
+        r#"import { readFile } from "fs/promises"; await readFile(file)"#,
+        r#"import fs from "fs"; await fs.promises.readFile(file)"#,
+        r#"const fs = require("fs"); fs.writeFileSync(`./uploads/${name}`, data)"#,
+        r#"const { createReadStream } = require("fs"); createReadStream(file)"#,
+        r#"import fs from "fs"; fs.copyFile("a.txt", target, callback)"#,
+        r#"import fs from "fs"; import path from "path"; fs.readFileSync(path.join(__dirname, file))"#,
+        r#"require("fs").unlinkSync(file)"#,
+    ];
+
+    Tester::new(DetectNonLiteralFsFilename::NAME, DetectNonLiteralFsFilename::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
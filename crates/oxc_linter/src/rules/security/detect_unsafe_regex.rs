@@ -0,0 +1,128 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::Pattern,
+    visit::{RegExpAstKind, Visit},
+};
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::run_on_regex_node};
+
+fn detect_unsafe_regex_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Unsafe regular expression, which may take exponential time to match some inputs.",
+    )
+    .with_help("Rewrite the pattern to not repeat a part which itself repeats, e.g. `(a+)+`.")
+    .with_label(span.label("This repeats a part which itself repeats"))
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectUnsafeRegex;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows regular expressions which may take exponential time to match, because they
+    /// repeat a part which itself repeats without a limit, e.g. `(a+)+`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The backtracking of such a regular expression tries exponentially many ways to match an
+    /// input which almost matches it, e.g. `aaaaaaaaaaaaaaaaaaaaaaaaaaaaaa!`. An input which
+    /// comes from a user can block the event loop for minutes, a denial of service known as
+    /// ReDoS.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const email = /^([a-z0-9]+)+@example\.com$/;
+    /// const words = new RegExp("(\\w+\\s?)*$");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const email = /^[a-z0-9]+@example\.com$/;
+    /// const words = new RegExp("(\\w+\\s?){0,10}$");
+    /// ```
+    DetectUnsafeRegex,
+    security,
+    suspicious
+);
+
+impl Rule for DetectUnsafeRegex {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node(node, ctx, |pattern, _span| {
+            if let Some(quantifier) = find_nested_quantifier(pattern) {
+                ctx.diagnostic(detect_unsafe_regex_diagnostic(quantifier));
+            }
+        });
+    }
+}
+
+/// The span of the first unbounded quantifier of `pattern` which has another unbounded
+/// quantifier in its body, i.e. a star height of more than 1.
+fn find_nested_quantifier(pattern: &Pattern) -> Option<Span> {
+    let mut finder = NestedQuantifierFinder::default();
+    finder.visit_pattern(pattern);
+    finder.nested
+}
+
+#[derive(Default)]
+struct NestedQuantifierFinder {
+    /// The spans of the unbounded quantifiers the visitor is in, outermost first.
+    quantifiers: Vec<Span>,
+    nested: Option<Span>,
+}
+
+impl<'a> Visit<'a> for NestedQuantifierFinder {
+    fn enter_node(&mut self, kind: RegExpAstKind<'a>) {
+        if let RegExpAstKind::Quantifier(quantifier) = kind
+            && quantifier.max.is_none()
+        {
+            if self.nested.is_none()
+                && let Some(outer) = self.quantifiers.first()
+            {
+                self.nested = Some(*outer);
+            }
+            self.quantifiers.push(quantifier.span);
+        }
+    }
+
+    fn leave_node(&mut self, kind: RegExpAstKind<'a>) {
+        if let RegExpAstKind::Quantifier(quantifier) = kind
+            && quantifier.max.is_none()
+        {
+            self.quantifiers.pop();
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r"/^[a-z0-9]+@example\.com$/",
+        r"/(a+)b(c+)/",
+        r"/(ab){0,10}/",
+        r"/(a{1,3}){1,3}/",
+        r"/(\w+\s?){0,10}$/",
+        r#"new RegExp("^\\d+$")"#,
+        r"new RegExp(pattern)",
+        r"/[a+]+/",
+    ];
+
+    let fail = vec![
+        r"/(a+)+/",
+        r"/^([a-z0-9]+)+@example\.com$/",
+        r"/(x+x+)+y/",
+        r"/(a|aa)*(b+)*/",
+        r"/((ab)*)*$/",
+        r"/(?:a*){2,}/",
+        r#"new RegExp("(\\w+\\s?)*$")"#,
+        r#"RegExp("(a+)+", "u")"#,
+    ];
+
+    Tester::new(DetectUnsafeRegex::NAME, DetectUnsafeRegex::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,432 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrowFunctionExpression, BreakStatement, CatchClause, ConditionalExpression,
+        ContinueStatement, DoWhileStatement, Expression, ForInStatement, ForOfStatement,
+        ForStatement, Function, IfStatement, LogicalExpression, LogicalOperator, Statement,
+        SwitchStatement, WhileStatement,
+    },
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    ast_util::get_function_name_with_kind,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::get_function_label_span,
+};
+
+fn cognitive_complexity_diagnostic(
+    span: Span,
+    name: &str,
+    complexity: u32,
+    threshold: u32,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "The {name} has a Cognitive Complexity of {complexity}, more than the {threshold} allowed."
+    ))
+    .with_help("Refactor it to reduce its complexity, e.g. by extracting parts of it into functions, or by returning early instead of nesting conditions.")
+    .with_label(span)
+}
+
+const DEFAULT_THRESHOLD: u32 = 15;
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CognitiveComplexity {
+    /// The highest Cognitive Complexity allowed for a function.
+    threshold: u32,
+}
+
+impl Default for CognitiveComplexity {
+    fn default() -> Self {
+        Self { threshold: DEFAULT_THRESHOLD }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces a maximum Cognitive Complexity of functions, a measure of how difficult the
+    /// control flow of a function is to understand.
+    ///
+    /// The complexity is increased by:
+    /// - `if`, `switch`, loops, `catch` and the conditional operator, by 1 plus how deeply they
+    ///   are nested in each other and in nested functions,
+    /// - `else`, `else if`, and `break` or `continue` to a label, by 1,
+    /// - each sequence of the same logical operator, e.g. `a && b && c || d` by 2.
+    ///
+    /// The complexity of a function includes the complexity of the functions nested in it.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Functions with a high Cognitive Complexity are difficult to read, test and change
+    /// without introducing bugs.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "threshold": 4 }`:
+    /// ```js
+    /// function sumOfPrimes(max) {
+    ///   let total = 0;
+    ///   outer: for (let i = 1; i <= max; ++i) { // +1
+    ///     for (let j = 2; j < i; ++j) { // +2 (nesting = 1)
+    ///       if (i % j === 0) { // +3 (nesting = 2)
+    ///         continue outer; // +1
+    ///       }
+    ///     }
+    ///     total += i;
+    ///   }
+    ///   return total;
+    /// } // Cognitive Complexity of 7
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "threshold": 4 }`:
+    /// ```js
+    /// function isPrime(n) {
+    ///   for (let j = 2; j < n; ++j) { // +1
+    ///     if (n % j === 0) { // +2 (nesting = 1)
+    ///       return false;
+    ///     }
+    ///   }
+    ///   return true;
+    /// } // Cognitive Complexity of 3
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// The threshold can be given as a number, or as an object:
+    /// ```json
+    /// { "sonarjs/cognitive-complexity": ["error", 15] }
+    /// ```
+    CognitiveComplexity,
+    sonarjs,
+    pedantic,
+    config = CognitiveComplexity,
+);
+
+impl Rule for CognitiveComplexity {
+    fn from_configuration(value: Value) -> Self {
+        if let Some(threshold) =
+            value.get(0).and_then(Value::as_u64).and_then(|threshold| u32::try_from(threshold).ok())
+        {
+            Self { threshold }
+        } else {
+            serde_json::from_value::<DefaultRuleConfig<CognitiveComplexity>>(value)
+                .unwrap_or_default()
+                .into_inner()
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let body = match node.kind() {
+            AstKind::Function(func) => func.body.as_deref(),
+            AstKind::ArrowFunctionExpression(arrow) => Some(&*arrow.body),
+            _ => return,
+        };
+        let Some(body) = body else {
+            return;
+        };
+        // Nested functions are part of the complexity of the function they are nested in.
+        if ctx.nodes().ancestors(node.id()).any(|ancestor| {
+            matches!(ancestor.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+        }) {
+            return;
+        }
+
+        let mut visitor = CognitiveComplexityVisitor::default();
+        visitor.visit_function_body(body);
+        if visitor.complexity <= self.threshold {
+            return;
+        }
+
+        let parent = ctx.nodes().parent_node(node.id());
+        let name = get_function_name_with_kind(node, parent);
+        ctx.diagnostic(cognitive_complexity_diagnostic(
+            get_function_label_span(node, parent),
+            &name,
+            visitor.complexity,
+            self.threshold,
+        ));
+    }
+}
+
+#[derive(Default)]
+struct CognitiveComplexityVisitor {
+    complexity: u32,
+    nesting: u32,
+}
+
+impl CognitiveComplexityVisitor {
+    /// Increases the complexity for a structure which increases the nesting of the code in it.
+    fn add_structural(&mut self) {
+        self.complexity += 1 + self.nesting;
+    }
+
+    fn nested(&mut self, visit: impl FnOnce(&mut Self)) {
+        self.nesting += 1;
+        visit(self);
+        self.nesting -= 1;
+    }
+
+    /// Visits an `if` statement, and the `else if` statements chained to it.
+    fn visit_if_chain(&mut self, it: &IfStatement<'_>) {
+        self.visit_expression(&it.test);
+        self.nested(|visitor| visitor.visit_statement(&it.consequent));
+        match &it.alternate {
+            Some(Statement::IfStatement(else_if)) => {
+                self.complexity += 1;
+                self.visit_if_chain(else_if);
+            }
+            Some(alternate) => {
+                self.complexity += 1;
+                self.nested(|visitor| visitor.visit_statement(alternate));
+            }
+            None => {}
+        }
+    }
+}
+
+impl<'a> Visit<'a> for CognitiveComplexityVisitor {
+    fn visit_if_statement(&mut self, it: &IfStatement<'a>) {
+        self.add_structural();
+        self.visit_if_chain(it);
+    }
+
+    fn visit_switch_statement(&mut self, it: &SwitchStatement<'a>) {
+        self.add_structural();
+        self.visit_expression(&it.discriminant);
+        self.nested(|visitor| {
+            for case in &it.cases {
+                visitor.visit_switch_case(case);
+            }
+        });
+    }
+
+    fn visit_for_statement(&mut self, it: &ForStatement<'a>) {
+        self.add_structural();
+        if let Some(init) = &it.init {
+            self.visit_for_statement_init(init);
+        }
+        if let Some(test) = &it.test {
+            self.visit_expression(test);
+        }
+        if let Some(update) = &it.update {
+            self.visit_expression(update);
+        }
+        self.nested(|visitor| visitor.visit_statement(&it.body));
+    }
+
+    fn visit_for_in_statement(&mut self, it: &ForInStatement<'a>) {
+        self.add_structural();
+        self.visit_for_statement_left(&it.left);
+        self.visit_expression(&it.right);
+        self.nested(|visitor| visitor.visit_statement(&it.body));
+    }
+
+    fn visit_for_of_statement(&mut self, it: &ForOfStatement<'a>) {
+        self.add_structural();
+        self.visit_for_statement_left(&it.left);
+        self.visit_expression(&it.right);
+        self.nested(|visitor| visitor.visit_statement(&it.body));
+    }
+
+    fn visit_while_statement(&mut self, it: &WhileStatement<'a>) {
+        self.add_structural();
+        self.visit_expression(&it.test);
+        self.nested(|visitor| visitor.visit_statement(&it.body));
+    }
+
+    fn visit_do_while_statement(&mut self, it: &DoWhileStatement<'a>) {
+        self.add_structural();
+        self.nested(|visitor| visitor.visit_statement(&it.body));
+        self.visit_expression(&it.test);
+    }
+
+    fn visit_catch_clause(&mut self, it: &CatchClause<'a>) {
+        self.add_structural();
+        self.nested(|visitor| walk::walk_catch_clause(visitor, it));
+    }
+
+    fn visit_conditional_expression(&mut self, it: &ConditionalExpression<'a>) {
+        self.add_structural();
+        self.visit_expression(&it.test);
+        self.nested(|visitor| {
+            visitor.visit_expression(&it.consequent);
+            visitor.visit_expression(&it.alternate);
+        });
+    }
+
+    fn visit_break_statement(&mut self, it: &BreakStatement<'a>) {
+        if it.label.is_some() {
+            self.complexity += 1;
+        }
+    }
+
+    fn visit_continue_statement(&mut self, it: &ContinueStatement<'a>) {
+        if it.label.is_some() {
+            self.complexity += 1;
+        }
+    }
+
+    fn visit_logical_expression(&mut self, it: &LogicalExpression<'a>) {
+        let mut operators = vec![];
+        let mut operands = vec![];
+        flatten_logical_expression(&it.left, &mut operators, &mut operands);
+        operators.push(it.operator);
+        flatten_logical_expression(&it.right, &mut operators, &mut operands);
+
+        // Each sequence of the same operator counts once, e.g. `a && b && c || d` counts twice.
+        let mut previous = None;
+        for operator in operators {
+            if previous != Some(operator) {
+                self.complexity += 1;
+            }
+            previous = Some(operator);
+        }
+        for operand in operands {
+            self.visit_expression(operand);
+        }
+    }
+
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        self.nested(|visitor| walk::walk_function(visitor, it, flags));
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        self.nested(|visitor| walk::walk_arrow_function_expression(visitor, it));
+    }
+}
+
+/// Collects the operators and operands of nested logical expressions in source order, e.g.
+/// `&&`, `||` and `a`, `b`, `c` for `a && (b || c)`.
+fn flatten_logical_expression<'b, 'a>(
+    expr: &'b Expression<'a>,
+    operators: &mut Vec<LogicalOperator>,
+    operands: &mut Vec<&'b Expression<'a>>,
+) {
+    if let Expression::LogicalExpression(logical) = expr.without_parentheses() {
+        flatten_logical_expression(&logical.left, operators, operands);
+        operators.push(logical.operator);
+        flatten_logical_expression(&logical.right, operators, operands);
+    } else {
+        operands.push(expr);
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo() { return 1 }", None),
+        (
+            "function isPrime(n) {
+                for (let j = 2; j < n; ++j) {
+                    if (n % j === 0) {
+                        return false;
+                    }
+                }
+                return true;
+            }",
+            Some(json!([3])),
+        ),
+        (
+            "function foo(a, b) {
+                if (a) {} else if (b) {} else {}
+            }",
+            Some(json!([3])),
+        ),
+        ("function foo(a, b, c) { return a && b && c }", Some(json!([1]))),
+        (
+            "function foo(a) { switch (a) { case 1: return 1; default: return 0 } }",
+            Some(json!([1])),
+        ),
+        (
+            "function foo(a) {
+                try { a() } catch (e) { if (e) {} }
+            }",
+            Some(json!([{ "threshold": 3 }])),
+        ),
+        ("const foo = (a) => a ? 1 : 2", Some(json!([1]))),
+        ("function foo() { if (a) {} } function bar() { if (b) {} }", Some(json!([1]))),
+        ("class A { foo() { if (a) {} } bar() { if (b) {} } }", Some(json!([1]))),
+    ];
+
+    let fail = vec![
+        (
+            "function sumOfPrimes(max) {
+                let total = 0;
+                outer: for (let i = 1; i <= max; ++i) {
+                    for (let j = 2; j < i; ++j) {
+                        if (i % j === 0) {
+                            continue outer;
+                        }
+                    }
+                    total += i;
+                }
+                return total;
+            }",
+            Some(json!([4])),
+        ),
+        (
+            "function foo(a, b) {
+                if (a) {} else if (b) {} else {}
+            }",
+            Some(json!([2])),
+        ),
+        ("function foo(a, b, c, d) { return a && b && c || d }", Some(json!([1]))),
+        ("function foo(a, b, c) { return a && (b || c) }", Some(json!([1]))),
+        (
+            "function foo(a) {
+                if (a) {
+                    if (a.b) {
+                        if (a.b.c) {}
+                    }
+                }
+            }",
+            Some(json!([5])),
+        ),
+        (
+            "function foo(items) {
+                return items.map((item) => {
+                    if (item) { return 1 }
+                    return 0;
+                });
+            }",
+            Some(json!([1])),
+        ),
+        (
+            "function foo(a) {
+                while (a) { a = a.next ? a.next : null }
+            }",
+            Some(json!([2])),
+        ),
+        ("class A { foo(a) { do { if (a) {} } while (a) } }", Some(json!([{ "threshold": 2 }]))),
+        ("const foo = (a) => { for (const b of a) { if (b) {} } }", Some(json!([2]))),
+        (
+            "const obj = { foo(a) { try {} catch { if (a) {} } } }",
+            Some(json!([{ "threshold": 2 }])),
+        ),
+        (
+            "function foo() {
+                if (a) {} if (b) {} if (c) {} if (d) {} if (e) {} if (f) {} if (g) {} if (h) {}
+                if (i) {} if (j) {} if (k) {} if (l) {} if (m) {} if (n) {} if (o) {} if (p) {}
+            }",
+            None,
+        ),
+    ];
+
+    Tester::new(CognitiveComplexity::NAME, CognitiveComplexity::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,196 @@
+use oxc_ast::{
+    AstKind,
+    ast::{IfStatement, Statement, SwitchStatement},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{ContentEq, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn all_branches_duplicated_diagnostic(span: Span, structure: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("All branches of this `{structure}` have the same code."))
+        .with_help(format!(
+            "Remove the `{structure}`, or change the code of the branches so that they are not all the same."
+        ))
+        .with_label(span)
+}
+
+fn same_value_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "This conditional expression has the same value whether its condition is true or false.",
+    )
+    .with_help("Remove the condition, or change one of its values.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoAllDuplicatedBranches;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows `if` chains with an `else`, `switch` statements with a `default`, and
+    /// conditional expressions, whose branches all have the same code.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When every branch does the same, the condition has no effect. This is usually a
+    /// copy-paste mistake, where one of the branches was meant to be changed.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// if (b === 0) {
+    ///   doOneMoreThing();
+    /// } else {
+    ///   doOneMoreThing();
+    /// }
+    ///
+    /// const a = b === 0 ? getValue() : getValue();
+    ///
+    /// switch (i) {
+    ///   case 1:
+    ///     doSomething();
+    ///     break;
+    ///   default:
+    ///     doSomething();
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// if (b === 0) {
+    ///   doOneMoreThing();
+    /// } else {
+    ///   doSomethingElse();
+    /// }
+    ///
+    /// // Without an `else`, the branches don't cover all cases.
+    /// if (b === 0) {
+    ///   doOneMoreThing();
+    /// } else if (b === 1) {
+    ///   doOneMoreThing();
+    /// }
+    /// ```
+    NoAllDuplicatedBranches,
+    sonarjs,
+    suspicious
+);
+
+impl Rule for NoAllDuplicatedBranches {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::IfStatement(if_statement) => {
+                // Only check the first `if` of a chain of `else if`s.
+                if let AstKind::IfStatement(parent) = ctx.nodes().parent_kind(node.id())
+                    && parent.alternate.as_ref().is_some_and(|alternate| {
+                        matches!(alternate, Statement::IfStatement(it) if it.span == if_statement.span)
+                    })
+                {
+                    return;
+                }
+                if are_all_branches_duplicated(if_statement) {
+                    let span = Span::sized(if_statement.span.start, 2);
+                    ctx.diagnostic(all_branches_duplicated_diagnostic(span, "if"));
+                }
+            }
+            AstKind::SwitchStatement(switch) => {
+                if are_all_cases_duplicated(switch) {
+                    let span = Span::sized(switch.span.start, 6);
+                    ctx.diagnostic(all_branches_duplicated_diagnostic(span, "switch"));
+                }
+            }
+            AstKind::ConditionalExpression(conditional) => {
+                if conditional.consequent.content_eq(&conditional.alternate) {
+                    ctx.diagnostic(same_value_diagnostic(conditional.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Whether an `if` chain ends with an `else`, and all of its branches are the same.
+fn are_all_branches_duplicated(if_statement: &IfStatement) -> bool {
+    let first = statements_of(&if_statement.consequent);
+    let mut current = if_statement;
+    loop {
+        match &current.alternate {
+            Some(Statement::IfStatement(else_if)) => {
+                if !statements_eq(statements_of(&else_if.consequent), first) {
+                    return false;
+                }
+                current = else_if;
+            }
+            Some(alternate) => return statements_eq(statements_of(alternate), first),
+            None => return false,
+        }
+    }
+}
+
+/// Whether a `switch` has a `default` case, and all of its cases run the same code. Cases which
+/// fall through to the next one are not compared, and neither is a final `break`.
+fn are_all_cases_duplicated(switch: &SwitchStatement) -> bool {
+    if switch.cases.len() < 2 || !switch.cases.iter().any(|case| case.test.is_none()) {
+        return false;
+    }
+    let mut consequents =
+        switch.cases.iter().filter(|case| !case.consequent.is_empty()).map(|case| {
+            match case.consequent.split_last() {
+                Some((Statement::BreakStatement(statement), rest)) if statement.label.is_none() => {
+                    rest
+                }
+                _ => &case.consequent,
+            }
+        });
+    let Some(first) = consequents.next() else {
+        return false;
+    };
+    consequents.all(|consequent| statements_eq(consequent, first))
+}
+
+/// The statements of a branch, which is a block, or a single statement.
+fn statements_of<'b, 'a>(statement: &'b Statement<'a>) -> &'b [Statement<'a>] {
+    match statement {
+        Statement::BlockStatement(block) => &block.body,
+        statement => std::slice::from_ref(statement),
+    }
+}
+
+fn statements_eq(a: &[Statement], b: &[Statement]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.content_eq(b))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "if (a) { foo() } else { bar() }",
+        "if (a) { foo() }",
+        "if (a) { foo() } else if (b) { foo() }",
+        "if (a) { foo() } else if (b) { foo() } else { bar() }",
+        "const x = a ? foo() : bar()",
+        "switch (a) { case 1: foo(); break; case 2: foo(); break; }",
+        "switch (a) { case 1: foo(); break; default: bar() }",
+        "switch (a) { default: foo() }",
+        "switch (a) { case 1: foo(); break; case 2: bar(); default: foo() }",
+        "if (a) { const x = 1; foo(x) } else { const x = 2; foo(x) }",
+    ];
+
+    let fail = vec![
+        "if (a) { foo() } else { foo() }",
+        "if (a) foo(); else { foo() }",
+        "if (a) { foo(); bar() } else if (b) { foo(); bar() } else { foo(); bar() }",
+        "if (a) { if (b) { foo() } else { foo() } } else { bar() }",
+        "const x = a ? foo() : foo()",
+        "const x = a ? { b: 1 } : { b: 1 }",
+        "switch (a) { case 1: foo(); break; default: foo() }",
+        "switch (a) { case 1: case 2: foo(); break; case 3: foo(); break; default: foo(); break; }",
+    ];
+
+    Tester::new(NoAllDuplicatedBranches::NAME, NoAllDuplicatedBranches::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
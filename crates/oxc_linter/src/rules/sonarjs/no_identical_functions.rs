@@ -0,0 +1,246 @@
+use std::hash::Hasher;
+
+use oxc_ast::{
+    AstKind,
+    ast::{FormalParameters, FunctionBody},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{ContentEq, ContentHash, Span};
+use rustc_hash::{FxHashMap, FxHasher};
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::get_function_label_span};
+
+fn no_identical_functions_diagnostic(span: Span, original: Span, line: usize) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "This function has the same implementation as the function on line {line}."
+    ))
+    .with_help("Call the other function instead of duplicating it, or extract their code into a shared function.")
+    .with_labels([
+        span.primary_label("This function is identical"),
+        original.label("to this function"),
+    ])
+}
+
+/// Functions with bodies of fewer lines are not reported, as duplicating them is often clearer
+/// than sharing them, e.g. `() => {}`.
+const MIN_LINES: usize = 3;
+
+#[derive(Debug, Default, Clone)]
+pub struct NoIdenticalFunctions;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows functions with the same parameters and body as another function of the file.
+    /// Functions with bodies of less than 3 lines are ignored.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Duplicated functions have to be changed together, which is easily forgotten for one of
+    /// them. When the duplication is a copy-paste mistake, one of them was meant to be changed.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function calculateCode() {
+    ///   doTheThing();
+    ///   doOtherThing();
+    ///   return code;
+    /// }
+    ///
+    /// function getName() {
+    ///   doTheThing();
+    ///   doOtherThing();
+    ///   return code;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// function calculateCode() {
+    ///   doTheThing();
+    ///   doOtherThing();
+    ///   return code;
+    /// }
+    ///
+    /// function getName() {
+    ///   return calculateCode();
+    /// }
+    /// ```
+    NoIdenticalFunctions,
+    sonarjs,
+    suspicious
+);
+
+impl Rule for NoIdenticalFunctions {
+    fn run_once(&self, ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        let mut buckets: FxHashMap<u64, Vec<FunctionInfo>> = FxHashMap::default();
+
+        for node in ctx.nodes() {
+            let Some(function) = FunctionInfo::new(node) else {
+                continue;
+            };
+            let body_text = function.body.span.source_text(source_text);
+            if body_text.lines().count() < MIN_LINES {
+                continue;
+            }
+
+            // Functions which are equal by `ContentEq` have the same `ContentHash`, so only the
+            // functions of a bucket need to be compared.
+            let mut hasher = FxHasher::default();
+            function.params.content_hash(&mut hasher);
+            function.body.content_hash(&mut hasher);
+            let bucket = buckets.entry(hasher.finish()).or_default();
+
+            if let Some(original) = bucket.iter().find(|original| original.is_identical(&function))
+            {
+                let parent = ctx.nodes().parent_node(node.id());
+                let original_parent = ctx.nodes().parent_node(original.node.id());
+                let original_span = get_function_label_span(original.node, original_parent);
+                let line = source_text[..original_span.start as usize].matches('\n').count() + 1;
+                ctx.diagnostic(no_identical_functions_diagnostic(
+                    get_function_label_span(node, parent),
+                    original_span,
+                    line,
+                ));
+            } else {
+                bucket.push(function);
+            }
+        }
+    }
+}
+
+struct FunctionInfo<'n, 'a> {
+    node: &'n AstNode<'a>,
+    params: &'n FormalParameters<'a>,
+    body: &'n FunctionBody<'a>,
+    is_arrow: bool,
+    is_async: bool,
+    is_generator: bool,
+}
+
+impl<'n, 'a> FunctionInfo<'n, 'a> {
+    fn new(node: &'n AstNode<'a>) -> Option<Self> {
+        match node.kind() {
+            AstKind::Function(func) => Some(Self {
+                node,
+                params: &func.params,
+                body: func.body.as_deref()?,
+                is_arrow: false,
+                is_async: func.r#async,
+                is_generator: func.generator,
+            }),
+            AstKind::ArrowFunctionExpression(arrow) if !arrow.expression => Some(Self {
+                node,
+                params: &arrow.params,
+                body: &arrow.body,
+                is_arrow: true,
+                is_async: arrow.r#async,
+                is_generator: false,
+            }),
+            _ => None,
+        }
+    }
+
+    fn is_identical(&self, other: &Self) -> bool {
+        self.is_arrow == other.is_arrow
+            && self.is_async == other.is_async
+            && self.is_generator == other.is_generator
+            && self.params.content_eq(other.params)
+            && self.body.content_eq(other.body)
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function foo() { a(); b(); return c; }
+         function bar() { a(); b(); return c; }",
+        "function foo() {
+            a();
+            return 1;
+         }
+         function bar() {
+            a();
+            return 2;
+         }",
+        "function foo(x) {
+            a();
+            return x;
+         }
+         function bar(y) {
+            a();
+            return y;
+         }",
+        "function foo() {
+            a();
+            return 1;
+         }
+         async function bar() {
+            a();
+            return 1;
+         }",
+        "const foo = () => {
+            a();
+            return 1;
+         };
+         function bar() {
+            a();
+            return 1;
+         }",
+        "const foo = (x) => x + 1; const bar = (x) => x + 1;",
+    ];
+
+    let fail = vec![
+        "function calculateCode() {
+            doTheThing();
+            doOtherThing();
+            return code;
+         }
+         function getName() {
+            doTheThing();
+            doOtherThing();
+            return code;
+         }",
+        "const foo = (a, b) => {
+            if (a) { return b; }
+            return a;
+         };
+         const bar = (a, b) => {
+            if (a) { return b; }
+            return a;
+         };",
+        "class A {
+            foo() {
+                this.a();
+                return this.b;
+            }
+            bar() {
+                this.a();
+                return this.b;
+            }
+         }",
+        "const obj = {
+            foo: function () {
+                a();
+                return 1;
+            },
+            bar: function () {
+                a();
+                return 1;
+            },
+            baz() {
+                a();
+                return 1;
+            },
+         };",
+    ];
+
+    Tester::new(NoIdenticalFunctions::NAME, NoIdenticalFunctions::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -4,7 +4,7 @@ use itertools::Itertools;
 use oxc_ast::{
     AstKind,
     ast::{
-        ImportDeclaration, ImportDeclarationSpecifier, ImportDefaultSpecifier,
+        BindingIdentifier, ImportDeclaration, ImportDeclarationSpecifier, ImportDefaultSpecifier,
         ImportNamespaceSpecifier, ImportSpecifier, Statement,
     },
 };
@@ -16,7 +16,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    AstNode,
+    AstNode, CompilerOptions,
     context::{ContextHost, LintContext},
     fixer::{RuleFix, RuleFixer},
     rule::{DefaultRuleConfig, Rule},
@@ -100,6 +100,20 @@ declare_oxc_lint!(
     ///
     /// Inconsistent usage of type imports can make the code harder to read and understand.
     ///
+    /// ### Compiler options
+    ///
+    /// The rule follows the compiler options of the nearest `tsconfig.json`, including those of
+    /// the configs it `extends`:
+    /// - With `verbatimModuleSyntax`, imports of types only are always fixed to `import type`,
+    ///   even with `"fixStyle": "inline-type-imports"`, since `import { type A } from 'foo'` is
+    ///   kept as the side effect import `import {} from 'foo'`.
+    /// - With `"prefer": "no-type-imports"`, type imports are not fixed when the compiler needs
+    ///   them: all of them with `verbatimModuleSyntax`, and re-exported ones with `isolatedModules`.
+    /// - With `emitDecoratorMetadata` and `experimentalDecorators`, files with decorators are
+    ///   skipped, since the types of decorated declarations are emitted as values.
+    /// - `React` is only reported when `jsx` is `react-jsx`, `react-jsxdev` or `preserve`, since
+    ///   the classic runtime needs it in scope.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -188,36 +202,45 @@ impl Rule for ConsistentTypeImports {
             }
         }
 
+        let compiler_options = ctx.compiler_options().unwrap_or_default();
+
         if matches!(self.prefer, Prefer::NoTypeImports) {
             match node.kind() {
                 // `import type { Foo } from 'foo'`
                 AstKind::ImportDeclaration(import_decl) => {
                     if import_decl.import_kind.is_type() {
-                        ctx.diagnostic_with_fix(
-                            avoid_import_type_diagnostic(import_decl.span),
-                            |fixer| {
-                                fix_remove_type_specifier_from_import_declaration(
-                                    fixer,
-                                    import_decl.span,
-                                    ctx,
-                                )
-                            },
-                        );
+                        let diagnostic = avoid_import_type_diagnostic(import_decl.span);
+                        let needs_type = import_decl.specifiers.iter().flatten().any(|specifier| {
+                            needs_type_import(specifier.local(), &compiler_options, ctx)
+                        });
+                        if needs_type {
+                            ctx.diagnostic(diagnostic);
+                            return;
+                        }
+                        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                            fix_remove_type_specifier_from_import_declaration(
+                                fixer,
+                                import_decl.span,
+                                ctx,
+                            )
+                        });
                     }
                 }
                 // import { type Foo } from 'foo'
                 AstKind::ImportSpecifier(import_specifier) => {
                     if import_specifier.import_kind.is_type() {
-                        ctx.diagnostic_with_fix(
-                            avoid_import_type_diagnostic(import_specifier.span),
-                            |fixer| {
-                                fix_remove_type_specifier_from_import_specifier(
-                                    fixer,
-                                    import_specifier.span,
-                                    ctx,
-                                )
-                            },
-                        );
+                        let diagnostic = avoid_import_type_diagnostic(import_specifier.span);
+                        if needs_type_import(&import_specifier.local, &compiler_options, ctx) {
+                            ctx.diagnostic(diagnostic);
+                            return;
+                        }
+                        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                            fix_remove_type_specifier_from_import_specifier(
+                                fixer,
+                                import_specifier.span,
+                                ctx,
+                            )
+                        });
                     }
                 }
                 _ => {}
@@ -229,6 +252,12 @@ impl Rule for ConsistentTypeImports {
             return;
         };
 
+        // With `emitDecoratorMetadata`, the types of decorated declarations are emitted as
+        // values, so their imports may be needed at runtime.
+        if compiler_options.emits_decorator_metadata() && has_decorators(ctx) {
+            return;
+        }
+
         // Store references that only used as type and without type qualifier.
         // For example:
         // ```typescript
@@ -249,15 +278,15 @@ impl Rule for ConsistentTypeImports {
                     ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
                         specifier.import_kind.is_value()
                     }
-                    // TODO: consume tsconfig and see if React is needed based on "jsx" field
+                    // `React` is needed by JSX, unless it is compiled with the automatic runtime.
                     ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
-                        if specifier.local.name == "React" {
+                        if specifier.local.name == "React" && compiler_options.jsx_needs_react() {
                             continue;
                         }
                         true
                     }
                     ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
-                        if specifier.local.name == "React" {
+                        if specifier.local.name == "React" && compiler_options.jsx_needs_react() {
                             continue;
                         }
                         true
@@ -285,13 +314,16 @@ impl Rule for ConsistentTypeImports {
             let type_names = type_names.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>();
 
             let fixer_fn = |fixer: RuleFixer<'_, 'a>| {
-                let fix_options = FixOptions {
-                    fixer,
-                    import_decl,
-                    type_names: &type_names,
-                    fix_style: self.fix_style,
-                    ctx,
-                };
+                // With `verbatimModuleSyntax`, `import { type A } from 'foo'` is kept as the
+                // side effect import `import {} from 'foo'`, unlike `import type { A } from 'foo'`.
+                let fix_style =
+                    if is_only_type_references && compiler_options.verbatim_module_syntax() {
+                        FixStyle::SeparateTypeImports
+                    } else {
+                        self.fix_style
+                    };
+                let fix_options =
+                    FixOptions { fixer, import_decl, type_names: &type_names, fix_style, ctx };
 
                 match fix_to_type_import_declaration(&fix_options) {
                     Ok(fixes) => fixes,
@@ -346,6 +378,33 @@ fn format_word_list<'a>(words: &[Cow<'a, str>]) -> Cow<'a, str> {
     }
 }
 
+// Returns `true` if removing `type` from the import of `local` would be a compiler error:
+// with `verbatimModuleSyntax`, imports of types must use `type`, and with `isolatedModules`,
+// re-exported types must be imported or exported with `type`.
+fn needs_type_import(
+    local: &BindingIdentifier,
+    compiler_options: &CompilerOptions,
+    ctx: &LintContext,
+) -> bool {
+    compiler_options.verbatim_module_syntax()
+        || (compiler_options.isolated_modules()
+            && ctx.semantic().symbol_references(local.symbol_id()).any(|reference| {
+                let parent_id = ctx.nodes().parent_id(reference.node_id());
+                let AstKind::ExportSpecifier(specifier) = ctx.nodes().kind(parent_id) else {
+                    return false;
+                };
+                !specifier.export_kind.is_type()
+                    && !matches!(
+                        ctx.nodes().parent_kind(parent_id),
+                        AstKind::ExportNamedDeclaration(decl) if decl.export_kind.is_type()
+                    )
+            }))
+}
+
+fn has_decorators(ctx: &LintContext) -> bool {
+    ctx.nodes().iter().any(|node| matches!(node.kind(), AstKind::Decorator(_)))
+}
+
 // Returns `true` if the symbol is only used as a type reference, and `false` otherwise.
 // Specifically, return `false` if the symbol does not have any references.
 fn is_only_has_type_references(symbol_id: SymbolId, ctx: &LintContext) -> bool {
@@ -3422,3 +3481,94 @@ export class Foo extends Bar {}
         .expect_fix(fix)
         .test_and_snapshot();
 }
+
+#[test]
+fn test_compiler_options() {
+    use crate::tester::Tester;
+
+    let inline = || Some(serde_json::json!([{ "fixStyle": "inline-type-imports" }]));
+    let no_type_imports = || Some(serde_json::json!([{ "prefer": "no-type-imports" }]));
+
+    let pass = vec![(
+        "import type { A } from 'foo'; import { B } from 'foo'; type T = A; const b = B;",
+        inline(),
+    )];
+    let fail = vec![
+        ("import { A, B } from 'foo'; type T = A; type U = B;", inline()),
+        ("import { A, B } from 'foo'; type T = A; const b = B;", inline()),
+        ("import type { A } from 'foo'; type T = A;", no_type_imports()),
+        ("import { type A, B } from 'foo'; type T = A; const b = B;", no_type_imports()),
+    ];
+    let fix = vec![
+        (
+            "import { A, B } from 'foo'; type T = A; type U = B;",
+            "import type { A, B } from 'foo'; type T = A; type U = B;",
+            inline(),
+        ),
+        (
+            "import { A, B } from 'foo'; type T = A; const b = B;",
+            "import { type A, B } from 'foo'; type T = A; const b = B;",
+            inline(),
+        ),
+    ];
+    Tester::new(ConsistentTypeImports::NAME, ConsistentTypeImports::PLUGIN, pass, fail)
+        .change_rule_path("consistent-type-imports/verbatim/index.ts")
+        .expect_fix(fix)
+        .with_snapshot_suffix("verbatim_module_syntax")
+        .test_and_snapshot();
+
+    let pass = vec![
+        (
+            "import { Service } from './service';
+            class Foo { constructor(@Inject() service: Service) {} }",
+            None,
+        ),
+        ("import { Service } from './service'; @Component() class Foo { service: Service }", None),
+    ];
+    let fail = vec![("import { Service } from './service'; class Foo { service: Service }", None)];
+    let fix = vec![(
+        "import { Service } from './service'; class Foo { service: Service }",
+        "import type { Service } from './service'; class Foo { service: Service }",
+        None,
+    )];
+    Tester::new(ConsistentTypeImports::NAME, ConsistentTypeImports::PLUGIN, pass, fail)
+        .change_rule_path("consistent-type-imports/decorator-metadata/index.ts")
+        .expect_fix(fix)
+        .with_snapshot_suffix("emit_decorator_metadata")
+        .test_and_snapshot();
+
+    let pass = vec![
+        ("import { A } from 'foo'; export { A };", no_type_imports()),
+        ("import { A } from 'foo'; export type { A };", no_type_imports()),
+        ("import type { A } from 'foo'; type T = A;", None),
+    ];
+    let fail = vec![
+        ("import React from 'react'; type T = React.ReactNode;", None),
+        ("import type { A } from 'foo'; export { A };", no_type_imports()),
+        ("import { type A as B } from 'foo'; export { B as C };", no_type_imports()),
+        ("import type { A } from 'foo'; export type { A };", no_type_imports()),
+        ("import type { A } from 'foo'; type T = A;", no_type_imports()),
+    ];
+    let fix = vec![
+        (
+            "import React from 'react'; type T = React.ReactNode;",
+            "import type React from 'react'; type T = React.ReactNode;",
+            None,
+        ),
+        (
+            "import type { A } from 'foo'; type T = A;",
+            "import { A } from 'foo'; type T = A;",
+            no_type_imports(),
+        ),
+        (
+            "import type { A } from 'foo'; export type { A };",
+            "import { A } from 'foo'; export type { A };",
+            no_type_imports(),
+        ),
+    ];
+    Tester::new(ConsistentTypeImports::NAME, ConsistentTypeImports::PLUGIN, pass, fail)
+        .change_rule_path("consistent-type-imports/react-jsx/index.tsx")
+        .expect_fix(fix)
+        .with_snapshot_suffix("jsx_isolated_modules")
+        .test_and_snapshot();
+}
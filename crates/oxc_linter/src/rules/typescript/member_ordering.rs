@@ -0,0 +1,878 @@
+use std::{borrow::Cow, cmp::Ordering, iter::Peekable, str::Chars};
+
+use cow_utils::CowUtils;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        AccessorPropertyType, ClassElement, ClassType, Expression, MethodDefinitionKind,
+        MethodDefinitionType, PropertyDefinitionType, PropertyKey, TSAccessibility,
+        TSMethodSignatureKind, TSSignature,
+    },
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn incorrect_group_order_diagnostic(span: Span, name: &str, group: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Member `{name}` should be declared before all {group} definitions."
+    ))
+    .with_label(span)
+}
+
+fn incorrect_order_diagnostic(span: Span, name: &str, before: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Member `{name}` should be declared before member `{before}`."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct MemberOrdering(Box<MemberOrderingConfig>);
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MemberOrderingConfig {
+    /// The order of the members of classes, interfaces and type literals, unless configured by
+    /// one of the more specific options.
+    default: OrderConfig,
+    /// The order of the members of class declarations.
+    classes: Option<OrderConfig>,
+    /// The order of the members of class expressions.
+    class_expressions: Option<OrderConfig>,
+    /// The order of the members of interfaces.
+    interfaces: Option<OrderConfig>,
+    /// The order of the members of type literals.
+    type_literals: Option<OrderConfig>,
+}
+
+impl Default for MemberOrderingConfig {
+    fn default() -> Self {
+        Self {
+            default: OrderConfig::MemberTypes(default_order()),
+            classes: None,
+            class_expressions: None,
+            interfaces: None,
+            type_literals: None,
+        }
+    }
+}
+
+/// Either `"never"` to not check the order, a list of member types, or an object with
+/// `memberTypes` and the `order` of the members of each type.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum OrderConfig {
+    Never(Never),
+    MemberTypes(Vec<MemberTypeGroup>),
+    Object(OrderObject),
+}
+
+impl OrderConfig {
+    /// The member types, or `None` if members of all types may be mixed.
+    fn member_types(&self) -> Option<&[MemberTypeGroup]> {
+        match self {
+            Self::Never(_)
+            | Self::Object(OrderObject { member_types: MemberTypes::Never(_), .. }) => None,
+            Self::MemberTypes(member_types)
+            | Self::Object(OrderObject {
+                member_types: MemberTypes::Types(member_types), ..
+            }) => Some(member_types),
+        }
+    }
+
+    fn order(&self) -> Order {
+        match self {
+            Self::Object(object) => object.order,
+            _ => Order::AsWritten,
+        }
+    }
+
+    fn is_never(&self) -> bool {
+        matches!(self, Self::Never(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+enum Never {
+    Never,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct OrderObject {
+    /// The order of the member types, or `"never"` to allow mixing members of all types.
+    #[serde(default = "default_member_types")]
+    member_types: MemberTypes,
+    /// The order of the members of each member type.
+    #[serde(default)]
+    order: Order,
+}
+
+fn default_member_types() -> MemberTypes {
+    MemberTypes::Types(default_order())
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum MemberTypes {
+    Never(Never),
+    Types(Vec<MemberTypeGroup>),
+}
+
+/// A member type like `"public-static-field"` or `"method"`, or a list of member types which
+/// may be mixed.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum MemberTypeGroup {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl MemberTypeGroup {
+    fn contains(&self, member_type: &str) -> bool {
+        match self {
+            Self::One(one) => one == member_type,
+            Self::Many(many) => many.iter().any(|one| one == member_type),
+        }
+    }
+
+    fn description(&self) -> String {
+        let description = |member_type: &String| member_type.cow_replace('-', " ").into_owned();
+        match self {
+            Self::One(one) => description(one),
+            Self::Many(many) => many.iter().map(description).collect::<Vec<_>>().join(", "),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+enum Order {
+    /// Members of the same type may be in any order.
+    #[default]
+    AsWritten,
+    /// Members of the same type are sorted by their names.
+    Alphabetically,
+    /// Members of the same type are sorted by their names, ignoring case.
+    AlphabeticallyCaseInsensitive,
+    /// Members of the same type are sorted by their names, comparing numbers by their values.
+    Natural,
+    /// Members of the same type are sorted by their names, comparing numbers by their values
+    /// and ignoring case.
+    NaturalCaseInsensitive,
+}
+
+impl Order {
+    fn compare(self, a: &str, b: &str) -> Ordering {
+        match self {
+            Self::AsWritten => Ordering::Equal,
+            Self::Alphabetically => a.cmp(b),
+            Self::AlphabeticallyCaseInsensitive => a.cow_to_lowercase().cmp(&b.cow_to_lowercase()),
+            Self::Natural => natural_compare(a, b),
+            Self::NaturalCaseInsensitive => {
+                natural_compare(&a.cow_to_lowercase(), &b.cow_to_lowercase())
+            }
+        }
+    }
+}
+
+/// Compares `a` and `b` like strings, except for runs of digits which are compared by their
+/// values, so that `a2` comes before `a10`.
+fn natural_compare(a: &str, b: &str) -> Ordering {
+    fn number(chars: &mut Peekable<Chars>) -> String {
+        let mut number = String::new();
+        while let Some(char) = chars.next_if(char::is_ascii_digit) {
+            number.push(char);
+        }
+        number.trim_start_matches('0').to_string()
+    }
+
+    let (mut a, mut b) = (a.chars().peekable(), b.chars().peekable());
+    loop {
+        match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let (x, y) = (number(&mut a), number(&mut b));
+                let ordering = x.len().cmp(&y.len()).then_with(|| x.cmp(&y));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(&y);
+                }
+                a.next();
+                b.next();
+            }
+        }
+    }
+}
+
+/// The default order of typescript-eslint: fields, static blocks, constructors, accessors,
+/// getters, setters and methods, each ordered by their modifiers.
+fn default_order() -> Vec<MemberTypeGroup> {
+    fn push_kind(order: &mut Vec<String>, kind: &str) {
+        for modifiers in [
+            "public-static",
+            "protected-static",
+            "private-static",
+            "#private-static",
+            "public-decorated",
+            "protected-decorated",
+            "private-decorated",
+            "public-instance",
+            "protected-instance",
+            "private-instance",
+            "#private-instance",
+            "public-abstract",
+            "protected-abstract",
+            "public",
+            "protected",
+            "private",
+            "#private",
+            "static",
+            "instance",
+            "abstract",
+            "decorated",
+        ] {
+            order.push(format!("{modifiers}-{kind}"));
+        }
+        order.push(kind.to_string());
+    }
+
+    let mut order = vec!["signature".to_string(), "call-signature".to_string()];
+    push_kind(&mut order, "field");
+    order.push("static-initialization".to_string());
+    for accessibility in ["public", "protected", "private"] {
+        order.push(format!("{accessibility}-constructor"));
+    }
+    order.push("constructor".to_string());
+    for kind in ["accessor", "get", "set", "method"] {
+        push_kind(&mut order, kind);
+    }
+    order.into_iter().map(MemberTypeGroup::One).collect()
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces a consistent order of the members of classes, interfaces and type literals,
+    /// with the options of typescript-eslint's `member-ordering`.
+    ///
+    /// Members are ordered by their type, e.g. `"public-static-field"`, `"constructor"` or
+    /// `"method"`, and optionally sorted by their names within each type. By default, fields
+    /// come first, then static blocks, constructors, accessors, getters, setters and methods,
+    /// each ordered from static to instance members, and from public to private members.
+    ///
+    /// The fix moves the members into order, together with their comments and decorators. It
+    /// is dangerous, since it may change the order in which fields and static blocks are
+    /// initialized.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A consistent order of members makes classes and interfaces easier to read and navigate.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// class Foo {
+    ///   method() {}
+    ///   constructor() {}
+    ///   private field = 1;
+    ///   static staticField = 2;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// class Foo {
+    ///   static staticField = 2;
+    ///   private field = 1;
+    ///   constructor() {}
+    ///   method() {}
+    /// }
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with
+    /// `{ "default": { "memberTypes": ["field", "method"], "order": "alphabetically" } }`:
+    /// ```ts
+    /// interface Foo {
+    ///   b: string;
+    ///   a: string;
+    ///   c(): void;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the same options:
+    /// ```ts
+    /// interface Foo {
+    ///   a: string;
+    ///   b: string;
+    ///   c(): void;
+    /// }
+    /// ```
+    MemberOrdering,
+    typescript,
+    style,
+    dangerous_fix,
+    config = MemberOrderingConfig,
+);
+
+impl Rule for MemberOrdering {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<MemberOrdering>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let config = &self.0;
+        match node.kind() {
+            AstKind::ClassBody(body) => {
+                let is_expression = matches!(
+                    ctx.nodes().parent_kind(node.id()),
+                    AstKind::Class(class) if class.r#type == ClassType::ClassExpression
+                );
+                let order = if is_expression { &config.class_expressions } else { &config.classes };
+                let members = body.body.iter().map(|element| class_member(element, ctx));
+                check_members(
+                    order.as_ref().unwrap_or(&config.default),
+                    body.span,
+                    &members.collect::<Vec<_>>(),
+                    true,
+                    ctx,
+                );
+            }
+            AstKind::TSInterfaceBody(body) => {
+                let members = body.body.iter().map(|signature| type_member(signature, ctx));
+                check_members(
+                    config.interfaces.as_ref().unwrap_or(&config.default),
+                    body.span,
+                    &members.collect::<Vec<_>>(),
+                    false,
+                    ctx,
+                );
+            }
+            AstKind::TSTypeLiteral(literal) => {
+                let members = literal.members.iter().map(|signature| type_member(signature, ctx));
+                check_members(
+                    config.type_literals.as_ref().unwrap_or(&config.default),
+                    literal.span,
+                    &members.collect::<Vec<_>>(),
+                    false,
+                    ctx,
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A member of a class, interface or type literal.
+struct Member<'a> {
+    /// The span of the whole member, including its decorators.
+    span: Span,
+    /// The span which is reported, usually the key of the member.
+    label: Span,
+    name: Cow<'a, str>,
+    kind: &'static str,
+    accessibility: &'static str,
+    /// `"static"`, `"abstract"` or `"instance"`.
+    scope: &'static str,
+    decorated: bool,
+}
+
+impl Member<'_> {
+    /// The member types of this member, from the most to the least specific.
+    fn member_types(&self, supports_modifiers: bool) -> Vec<String> {
+        let Self { kind, accessibility, scope, .. } = self;
+        let mut member_types = vec![];
+        if supports_modifiers {
+            if self.decorated
+                && matches!(
+                    *kind,
+                    "readonly-field" | "field" | "method" | "accessor" | "get" | "set"
+                )
+            {
+                member_types.push(format!("{accessibility}-decorated-{kind}"));
+                member_types.push(format!("decorated-{kind}"));
+                if *kind == "readonly-field" {
+                    member_types.push(format!("{accessibility}-decorated-field"));
+                    member_types.push("decorated-field".to_string());
+                }
+            }
+            if !matches!(*kind, "readonly-signature" | "signature" | "static-initialization") {
+                if *kind != "constructor" {
+                    member_types.push(format!("{accessibility}-{scope}-{kind}"));
+                    member_types.push(format!("{scope}-{kind}"));
+                    if *kind == "readonly-field" {
+                        member_types.push(format!("{accessibility}-{scope}-field"));
+                        member_types.push(format!("{scope}-field"));
+                    }
+                }
+                member_types.push(format!("{accessibility}-{kind}"));
+                if *kind == "readonly-field" {
+                    member_types.push(format!("{accessibility}-field"));
+                }
+            }
+        }
+        member_types.push((*kind).to_string());
+        match *kind {
+            "readonly-signature" => member_types.push("signature".to_string()),
+            "readonly-field" => member_types.push("field".to_string()),
+            _ => {}
+        }
+        member_types
+    }
+
+    /// The index of the first configured member type of this member.
+    fn rank(&self, order: &[MemberTypeGroup], supports_modifiers: bool) -> Option<usize> {
+        self.member_types(supports_modifiers)
+            .iter()
+            .find_map(|member_type| order.iter().position(|group| group.contains(member_type)))
+    }
+}
+
+fn class_member<'a>(element: &ClassElement<'a>, ctx: &LintContext<'a>) -> Member<'a> {
+    let member = |key: &PropertyKey<'a>, kind, accessibility: Option<TSAccessibility>, scope| {
+        let (name, accessibility) = match key {
+            PropertyKey::PrivateIdentifier(id) => (Cow::Owned(format!("#{}", id.name)), "#private"),
+            key => (
+                key.static_name().unwrap_or_else(|| Cow::Borrowed(ctx.source_range(key.span()))),
+                match accessibility {
+                    Some(TSAccessibility::Private) => "private",
+                    Some(TSAccessibility::Protected) => "protected",
+                    Some(TSAccessibility::Public) | None => "public",
+                },
+            ),
+        };
+        Member {
+            span: element.span(),
+            label: key.span(),
+            name,
+            kind,
+            accessibility,
+            scope,
+            decorated: false,
+        }
+    };
+    let scope = |is_static: bool, is_abstract: bool| {
+        if is_static {
+            "static"
+        } else if is_abstract {
+            "abstract"
+        } else {
+            "instance"
+        }
+    };
+
+    match element {
+        ClassElement::StaticBlock(block) => Member {
+            span: block.span,
+            label: Span::sized(block.span.start, 6),
+            name: Cow::Borrowed("static block"),
+            kind: "static-initialization",
+            accessibility: "public",
+            scope: "static",
+            decorated: false,
+        },
+        ClassElement::MethodDefinition(method) => {
+            let kind = match method.kind {
+                MethodDefinitionKind::Constructor => "constructor",
+                MethodDefinitionKind::Method => "method",
+                MethodDefinitionKind::Get => "get",
+                MethodDefinitionKind::Set => "set",
+            };
+            let is_abstract = method.r#type == MethodDefinitionType::TSAbstractMethodDefinition;
+            Member {
+                decorated: !method.decorators.is_empty(),
+                ..member(
+                    &method.key,
+                    kind,
+                    method.accessibility,
+                    scope(method.r#static, is_abstract),
+                )
+            }
+        }
+        ClassElement::PropertyDefinition(prop) => {
+            let kind = if prop.value.as_ref().is_some_and(|value| {
+                matches!(
+                    value.get_inner_expression(),
+                    Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_)
+                )
+            }) {
+                "method"
+            } else if prop.readonly {
+                "readonly-field"
+            } else {
+                "field"
+            };
+            let is_abstract = prop.r#type == PropertyDefinitionType::TSAbstractPropertyDefinition;
+            Member {
+                decorated: !prop.decorators.is_empty(),
+                ..member(&prop.key, kind, prop.accessibility, scope(prop.r#static, is_abstract))
+            }
+        }
+        ClassElement::AccessorProperty(prop) => {
+            let is_abstract = prop.r#type == AccessorPropertyType::TSAbstractAccessorProperty;
+            Member {
+                decorated: !prop.decorators.is_empty(),
+                ..member(
+                    &prop.key,
+                    "accessor",
+                    prop.accessibility,
+                    scope(prop.r#static, is_abstract),
+                )
+            }
+        }
+        ClassElement::TSIndexSignature(signature) => Member {
+            span: signature.span,
+            label: signature.span,
+            name: Cow::Borrowed(index_signature_name(signature.span, ctx)),
+            kind: if signature.readonly { "readonly-signature" } else { "signature" },
+            accessibility: "public",
+            scope: scope(signature.r#static, false),
+            decorated: false,
+        },
+    }
+}
+
+fn type_member<'a>(signature: &TSSignature<'a>, ctx: &LintContext<'a>) -> Member<'a> {
+    let member = |span: Span, label: Span, name: Cow<'a, str>, kind| Member {
+        span,
+        label,
+        name,
+        kind,
+        accessibility: "public",
+        scope: "instance",
+        decorated: false,
+    };
+    let key_name = |key: &PropertyKey<'a>| {
+        key.static_name().unwrap_or_else(|| Cow::Borrowed(ctx.source_range(key.span())))
+    };
+
+    match signature {
+        TSSignature::TSIndexSignature(signature) => member(
+            signature.span,
+            signature.span,
+            Cow::Borrowed(index_signature_name(signature.span, ctx)),
+            if signature.readonly { "readonly-signature" } else { "signature" },
+        ),
+        TSSignature::TSPropertySignature(signature) => member(
+            signature.span,
+            signature.key.span(),
+            key_name(&signature.key),
+            if signature.readonly { "readonly-field" } else { "field" },
+        ),
+        TSSignature::TSMethodSignature(signature) => member(
+            signature.span,
+            signature.key.span(),
+            key_name(&signature.key),
+            match signature.kind {
+                TSMethodSignatureKind::Method => "method",
+                TSMethodSignatureKind::Get => "get",
+                TSMethodSignatureKind::Set => "set",
+            },
+        ),
+        TSSignature::TSCallSignatureDeclaration(signature) => {
+            member(signature.span, signature.span, Cow::Borrowed("call"), "call-signature")
+        }
+        TSSignature::TSConstructSignatureDeclaration(signature) => {
+            member(signature.span, signature.span, Cow::Borrowed("new"), "constructor")
+        }
+    }
+}
+
+/// The source text of an index signature up to its closing bracket, e.g. `[key: string]`.
+fn index_signature_name<'a>(span: Span, ctx: &LintContext<'a>) -> &'a str {
+    let text = ctx.source_range(span);
+    text.find(']').map_or(text, |end| &text[..=end])
+}
+
+fn check_members(
+    config: &OrderConfig,
+    body_span: Span,
+    members: &[Member],
+    supports_modifiers: bool,
+    ctx: &LintContext,
+) {
+    if config.is_never() || members.len() < 2 {
+        return;
+    }
+    let order = config.order();
+    let ranks = members
+        .iter()
+        .map(|member| match config.member_types() {
+            Some(member_types) => member.rank(member_types, supports_modifiers),
+            None => Some(0),
+        })
+        .collect::<Vec<_>>();
+
+    let mut diagnostics = vec![];
+
+    // The ranks of the member types seen so far, in increasing order.
+    let mut previous_ranks: Vec<usize> = vec![];
+    for (member, rank) in members.iter().zip(&ranks) {
+        let Some(rank) = *rank else {
+            continue;
+        };
+        match previous_ranks.last() {
+            Some(&last) if rank < last => {
+                let lowest =
+                    previous_ranks.iter().copied().filter(|&r| r > rank).min().unwrap_or(last);
+                let group = config
+                    .member_types()
+                    .map_or_else(String::new, |types| types[lowest].description());
+                diagnostics.push((
+                    member.label.start,
+                    incorrect_group_order_diagnostic(member.label, &member.name, &group),
+                ));
+            }
+            Some(&last) if rank == last => {}
+            _ => previous_ranks.push(rank),
+        }
+    }
+
+    if !matches!(order, Order::AsWritten) {
+        // Members of each member type, in the order they are written.
+        let mut groups: Vec<(usize, Vec<&Member>)> = vec![];
+        for (member, rank) in members.iter().zip(&ranks) {
+            let Some(rank) = *rank else {
+                continue;
+            };
+            match groups.iter_mut().find(|(group_rank, _)| *group_rank == rank) {
+                Some((_, group)) => group.push(member),
+                None => groups.push((rank, vec![member])),
+            }
+        }
+        for (_, group) in groups {
+            for pair in group.windows(2) {
+                let (previous, member) = (pair[0], pair[1]);
+                if order.compare(&member.name, &previous.name) == Ordering::Less {
+                    diagnostics.push((
+                        member.label.start,
+                        incorrect_order_diagnostic(member.label, &member.name, &previous.name),
+                    ));
+                }
+            }
+        }
+    }
+
+    if diagnostics.is_empty() {
+        return;
+    }
+    diagnostics.sort_by_key(|(start, _)| *start);
+
+    let fix =
+        ranks.iter().copied().collect::<Option<Vec<_>>>().map(|ranks| {
+            reorder_members(body_span, members, &ranks, order, !supports_modifiers, ctx)
+        });
+    let mut diagnostics = diagnostics.into_iter().map(|(_, diagnostic)| diagnostic);
+    if let (Some(diagnostic), Some((span, text))) = (diagnostics.next(), fix) {
+        ctx.diagnostic_with_dangerous_fix(diagnostic, |fixer| fixer.replace(span, text));
+    }
+    for diagnostic in diagnostics {
+        ctx.diagnostic(diagnostic);
+    }
+}
+
+/// The span of all `members` and the text replacing it, with the members in order. Each member
+/// moves with the comments before it, and with its separator and a comment after it on the
+/// same line.
+fn reorder_members(
+    body_span: Span,
+    members: &[Member],
+    ranks: &[usize],
+    order: Order,
+    needs_separators: bool,
+    ctx: &LintContext,
+) -> (Span, String) {
+    let source = ctx.source_text();
+    let mut start = body_span.start + 1;
+    let segments = members
+        .iter()
+        .map(|member| {
+            let (end, has_separator) = member_end(source, member.span.end);
+            // The spans of signatures include their separators.
+            let has_separator =
+                has_separator || source[..member.span.end as usize].ends_with([';', ',']);
+            let segment = (start, member.span.end, end, has_separator);
+            start = end;
+            segment
+        })
+        .collect::<Vec<_>>();
+
+    let mut indices = (0..members.len()).collect::<Vec<_>>();
+    indices.sort_by(|&a, &b| {
+        ranks[a].cmp(&ranks[b]).then_with(|| order.compare(&members[a].name, &members[b].name))
+    });
+
+    let mut text = String::new();
+    for (position, &index) in indices.iter().enumerate() {
+        let (start, member_end, end, has_separator) = segments[index];
+        text.push_str(&source[start as usize..member_end as usize]);
+        if needs_separators && !has_separator && position + 1 < indices.len() {
+            text.push(';');
+        }
+        text.push_str(&source[member_end as usize..end as usize]);
+    }
+    (Span::new(segments[0].0, start), text)
+}
+
+/// The end of the separator and the comment after the member ending at `end`, which are on the
+/// same line as the member, and whether the member is followed by a separator.
+fn member_end(source: &str, end: u32) -> (u32, bool) {
+    let rest = &source[end as usize..];
+    let skip_spaces = |offset: usize| {
+        offset + rest[offset..].len() - rest[offset..].trim_start_matches([' ', '\t']).len()
+    };
+    let mut offset = skip_spaces(0);
+    let has_separator = rest[offset..].starts_with([';', ',']);
+    if has_separator {
+        offset = skip_spaces(offset + 1);
+    }
+    let rest_of_line = &rest[offset..];
+    if rest_of_line.starts_with("//") {
+        offset += rest_of_line.find(['\n', '\r']).unwrap_or(rest_of_line.len());
+    } else if rest_of_line.starts_with("/*")
+        && let Some(comment_end) = rest_of_line.find("*/")
+        && !rest_of_line[..comment_end].contains('\n')
+    {
+        offset += comment_end + 2;
+    } else if !has_separator {
+        offset = 0;
+    }
+    #[expect(clippy::cast_possible_truncation)]
+    (end + offset as u32, has_separator)
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "class Foo { static a = 1; b = 2; private c = 3; constructor() {} get d() { return 1; } set d(v) {} method() {} }",
+            None,
+        ),
+        ("class Foo { @Input() a = 1; b = 2; }", None),
+        ("class Foo { static {} constructor() {} }", None),
+        (
+            "interface Foo { [key: string]: any; (): void; a: string; new (): Foo; b(): void; }",
+            None,
+        ),
+        ("type Foo = { a: string; b(): void; }", None),
+        ("class Foo { method() {} field = 1; }", Some(json!([{ "default": "never" }]))),
+        ("class Foo { method() {} field = 1; }", Some(json!([{ "default": ["method", "field"] }]))),
+        (
+            "class Foo { b = 1; a() {} }",
+            Some(json!([{ "default": { "memberTypes": "never", "order": "as-written" } }])),
+        ),
+        (
+            "interface Foo { a: string; B: string; c(): void; }",
+            Some(
+                json!([{ "default": { "memberTypes": ["field", "method"], "order": "alphabetically-case-insensitive" } }]),
+            ),
+        ),
+        (
+            "interface Foo { a2: string; a10: string; }",
+            Some(json!([{ "default": { "memberTypes": "never", "order": "natural" } }])),
+        ),
+        (
+            "class Foo { a() {} b = 1; } interface Bar { b: string; a(): void; }",
+            Some(json!([{ "classes": ["method", "field"], "interfaces": ["field", "method"] }])),
+        ),
+        (
+            "const Foo = class { a() {} b = 1; }",
+            Some(json!([{ "classExpressions": ["method", "field"] }])),
+        ),
+        (
+            "class Foo { a = 1; private b() {} public c() {} }",
+            Some(json!([{ "default": ["field", ["public-method", "private-method"]] }])),
+        ),
+        ("class Foo { a() {} b = 1; }", Some(json!([{ "default": ["method"] }]))),
+    ];
+
+    let fail = vec![
+        ("class Foo { method() {} constructor() {} field = 1; }", None),
+        ("class Foo { private a = 1; public b = 2; }", None),
+        ("class Foo { b = 1; static a = 2; }", None),
+        ("class Foo { b() {} #a = 1; }", None),
+        ("class Foo { constructor() {} static {} }", None),
+        ("abstract class Foo { abstract a(): void; static b(): void {} }", None),
+        ("interface Foo { a(): void; b: string; }", None),
+        ("type Foo = { a(): void; [key: string]: any; }", None),
+        ("const Foo = class { method() {} field = 1; }", None),
+        ("class Foo { field = 1; method() {} }", Some(json!([{ "default": ["method", "field"] }]))),
+        (
+            "interface Foo { b: string; a: string; c(): void; }",
+            Some(
+                json!([{ "default": { "memberTypes": ["field", "method"], "order": "alphabetically" } }]),
+            ),
+        ),
+        (
+            "interface Foo { a10: string; a2: string; }",
+            Some(json!([{ "default": { "memberTypes": "never", "order": "natural" } }])),
+        ),
+        (
+            "class Foo { private a() {} b = 1; public c() {} }",
+            Some(json!([{ "default": ["field", ["public-method", "private-method"]] }])),
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "class Foo {
+  method() {}
+  // The field.
+  field = 1; // Trailing.
+  constructor() {}
+}",
+            "class Foo {
+  // The field.
+  field = 1; // Trailing.
+  constructor() {}
+  method() {}
+}",
+            None,
+        ),
+        (
+            "class Foo {
+  @Output() b = new EventEmitter();
+  static a = 1;
+}",
+            "class Foo {
+  static a = 1;
+  @Output() b = new EventEmitter();
+}",
+            None,
+        ),
+        ("interface Foo { a(): void; b: string }", "interface Foo { b: string; a(): void; }", None),
+        (
+            "type Foo = { c: string, b: string, a(): void }",
+            "type Foo = { b: string, c: string, a(): void }",
+            Some(
+                json!([{ "default": { "memberTypes": ["field", "method"], "order": "alphabetically" } }]),
+            ),
+        ),
+    ];
+
+    Tester::new(MemberOrdering::NAME, MemberOrdering::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,1533 @@
+use std::cmp::Reverse;
+
+use bitflags::bitflags;
+use lazy_regex::Regex;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        AccessorPropertyType, BindingIdentifier, BindingPattern, Expression, FormalParameter,
+        MethodDefinitionKind, MethodDefinitionType, PropertyDefinitionType, PropertyKey,
+        PropertyKind, TSAccessibility, TSEnumMemberName, TSLiteral, TSMethodSignatureKind, TSType,
+        TSTypeName, TSTypeOperatorOperator, UnaryOperator, VariableDeclarationKind,
+    },
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::identifier::is_identifier_name;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn format_diagnostic(
+    span: Span,
+    selector: Selector,
+    name: &str,
+    trimmed: Option<&str>,
+    formats: &[Format],
+) -> OxcDiagnostic {
+    let selector = selector.description();
+    let formats = formats.iter().map(|format| format.as_str()).collect::<Vec<_>>().join(", ");
+    let message = if let Some(trimmed) = trimmed {
+        format!(
+            "{selector} name `{name}` trimmed as `{trimmed}` must match one of the following formats: {formats}"
+        )
+    } else {
+        format!("{selector} name `{name}` must match one of the following formats: {formats}")
+    };
+    OxcDiagnostic::warn(message).with_label(span)
+}
+
+fn missing_underscore_diagnostic(
+    span: Span,
+    selector: Selector,
+    name: &str,
+    count: &str,
+    position: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "{} name `{name}` must have {count} {position} underscore(s)",
+        selector.description()
+    ))
+    .with_label(span)
+}
+
+fn unexpected_underscore_diagnostic(
+    span: Span,
+    selector: Selector,
+    name: &str,
+    position: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "{} name `{name}` must not have a {position} underscore",
+        selector.description()
+    ))
+    .with_label(span)
+}
+
+fn missing_affix_diagnostic(
+    span: Span,
+    selector: Selector,
+    name: &str,
+    position: &str,
+    affixes: &[String],
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "{} name `{name}` must have one of the following {position}es: {}",
+        selector.description(),
+        affixes.join(", ")
+    ))
+    .with_label(span)
+}
+
+fn custom_diagnostic(
+    span: Span,
+    selector: Selector,
+    name: &str,
+    custom: &MatchRegex,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "{} name `{name}` must {} the RegExp: /{}/u",
+        selector.description(),
+        if custom.matches { "match" } else { "not match" },
+        custom.regex.as_str()
+    ))
+    .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NamingConvention(Box<NamingConventionConfig>);
+
+/// A list of options, each enforcing a naming convention on the names matched by its selector.
+/// When a name is matched by several options, the most specific one applies.
+///
+/// Example configuration:
+/// ```json
+/// {
+///   "typescript/naming-convention": [
+///     "error",
+///     { "selector": "variable", "format": ["camelCase", "UPPER_CASE"] },
+///     { "selector": "interface", "format": ["PascalCase"], "custom": { "regex": "^I[A-Z]", "match": false } },
+///     { "selector": "variable", "types": ["boolean"], "format": ["PascalCase"], "prefix": ["is", "has"] }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Clone, JsonSchema)]
+pub struct NamingConventionConfig(
+    #[schemars(with = "Vec<NamingConventionOption>")] Vec<SelectorConfig>,
+);
+
+impl Default for NamingConvention {
+    fn default() -> Self {
+        Self(Box::new(NamingConventionConfig(SelectorConfig::defaults())))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct NamingConventionOption {
+    /// The kinds of names this option applies to, e.g. `"variable"` or `["class", "enum"]`.
+    selector: OneOrMany<Selector>,
+    /// Modifiers which a name must all have for this option to apply, e.g. `["const", "exported"]`.
+    #[serde(default)]
+    modifiers: Vec<Modifier>,
+    /// Types of which a name must have one for this option to apply. The type is taken from the
+    /// type annotation or the initializer of the name, and options with `types` don't apply to
+    /// names whose type is unknown.
+    #[serde(default)]
+    types: Vec<TypeModifier>,
+    /// The formats of which the name must match one, after trimming its underscores and affixes.
+    /// `null` skips all checks of the matched names.
+    format: Option<Vec<Format>>,
+    /// A regular expression which the trimmed name must match or not match.
+    #[serde(default)]
+    custom: Option<MatchRegexOption>,
+    /// A regular expression which the name must match or not match for this option to apply.
+    #[serde(default)]
+    filter: Option<FilterOption>,
+    /// How a leading underscore is handled.
+    #[serde(default)]
+    leading_underscore: Option<Underscore>,
+    /// How a trailing underscore is handled.
+    #[serde(default)]
+    trailing_underscore: Option<Underscore>,
+    /// Prefixes of which the name must start with one. The prefix is trimmed before checking
+    /// the format.
+    #[serde(default)]
+    prefix: Vec<String>,
+    /// Suffixes of which the name must end with one. The suffix is trimmed before checking
+    /// the format.
+    #[serde(default)]
+    suffix: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+struct MatchRegexOption {
+    regex: String,
+    #[serde(rename = "match")]
+    matches: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum FilterOption {
+    /// A regular expression which the name must match.
+    Regex(String),
+    Object(MatchRegexOption),
+}
+
+#[derive(Debug, Clone)]
+struct MatchRegex {
+    regex: Regex,
+    matches: bool,
+}
+
+impl MatchRegex {
+    fn from_option(option: &MatchRegexOption) -> Result<Self, String> {
+        let regex = Regex::new(&option.regex)
+            .map_err(|error| format!("invalid regex `{}`: {error}", option.regex))?;
+        Ok(Self { regex, matches: option.matches })
+    }
+
+    fn test(&self, name: &str) -> bool {
+        self.regex.is_match(name) == self.matches
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SelectorConfig {
+    selector: Selector,
+    modifiers: Modifiers,
+    types: Vec<TypeModifier>,
+    format: Option<Vec<Format>>,
+    custom: Option<MatchRegex>,
+    filter: Option<MatchRegex>,
+    leading_underscore: Option<Underscore>,
+    trailing_underscore: Option<Underscore>,
+    prefix: Vec<String>,
+    suffix: Vec<String>,
+}
+
+impl SelectorConfig {
+    /// The default options of typescript-eslint.
+    fn defaults() -> Vec<Self> {
+        let config = |selector, format: &[Format], underscore| Self {
+            selector,
+            modifiers: Modifiers::empty(),
+            types: vec![],
+            format: Some(format.to_vec()),
+            custom: None,
+            filter: None,
+            leading_underscore: underscore,
+            trailing_underscore: underscore,
+            prefix: vec![],
+            suffix: vec![],
+        };
+        let mut configs = vec![
+            config(Selector::Default, &[Format::CamelCase], Some(Underscore::Allow)),
+            config(Selector::Import, &[Format::CamelCase, Format::PascalCase], None),
+            config(
+                Selector::Variable,
+                &[Format::CamelCase, Format::UpperCase],
+                Some(Underscore::Allow),
+            ),
+            config(Selector::TypeLike, &[Format::PascalCase], None),
+        ];
+        Self::sort(&mut configs);
+        configs
+    }
+
+    fn from_option(option: NamingConventionOption) -> Result<Vec<Self>, String> {
+        let selectors = match option.selector {
+            OneOrMany::One(selector) => vec![selector],
+            OneOrMany::Many(selectors) => selectors,
+        };
+        let modifiers = option
+            .modifiers
+            .iter()
+            .fold(Modifiers::empty(), |modifiers, modifier| modifiers | modifier.flag());
+        let custom = option.custom.as_ref().map(MatchRegex::from_option).transpose()?;
+        let filter = option
+            .filter
+            .map(|filter| match filter {
+                FilterOption::Regex(regex) => {
+                    MatchRegex::from_option(&MatchRegexOption { regex, matches: true })
+                }
+                FilterOption::Object(option) => MatchRegex::from_option(&option),
+            })
+            .transpose()?;
+        Ok(selectors
+            .into_iter()
+            .map(|selector| Self {
+                selector,
+                modifiers,
+                types: option.types.clone(),
+                format: option.format.clone(),
+                custom: custom.clone(),
+                filter: filter.clone(),
+                leading_underscore: option.leading_underscore,
+                trailing_underscore: option.trailing_underscore,
+                prefix: option.prefix.clone(),
+                suffix: option.suffix.clone(),
+            })
+            .collect())
+    }
+
+    /// Sorts the options from the most to the least specific, keeping the configured order
+    /// between options which are equally specific.
+    fn sort(configs: &mut [Self]) {
+        configs.sort_by_key(|config| {
+            (
+                config.selector.precedence(),
+                Reverse(!config.types.is_empty()),
+                Reverse(config.modifiers.bits().count_ones()),
+            )
+        });
+    }
+
+    fn applies_to(&self, name: &Name) -> bool {
+        self.selector.includes(name.selector)
+            && name.modifiers.contains(self.modifiers)
+            && (self.types.is_empty()
+                || name.type_modifier.is_some_and(|ty| self.types.contains(&ty)))
+            && self.filter.as_ref().is_none_or(|filter| filter.test(name.text))
+    }
+
+    fn check(&self, name: &Name) -> Result<(), OxcDiagnostic> {
+        let Some(formats) = &self.format else {
+            return Ok(());
+        };
+        let original = name.original_name();
+        let trimmed = self.trim_underscore(name, name.text, &original, true)?;
+        let trimmed = self.trim_underscore(name, trimmed, &original, false)?;
+        let trimmed = self.trim_affix(name, trimmed, &original, true)?;
+        let trimmed = self.trim_affix(name, trimmed, &original, false)?;
+        if let Some(custom) = &self.custom
+            && !custom.test(trimmed)
+        {
+            return Err(custom_diagnostic(name.span, name.selector, &original, custom));
+        }
+        if formats.is_empty()
+            || (!name.modifiers.contains(Modifiers::REQUIRES_QUOTES)
+                && formats.iter().any(|format| format.matches(trimmed)))
+        {
+            return Ok(());
+        }
+        let trimmed = (trimmed != name.text).then_some(trimmed);
+        Err(format_diagnostic(name.span, name.selector, &original, trimmed, formats))
+    }
+
+    /// Returns `name` without its leading or trailing underscores, or reports it with `Err`.
+    fn trim_underscore<'n>(
+        &self,
+        name: &Name,
+        trimmed: &'n str,
+        original: &str,
+        leading: bool,
+    ) -> Result<&'n str, OxcDiagnostic> {
+        let (option, position, underscore) = if leading {
+            (self.leading_underscore, "leading", trimmed.strip_prefix('_'))
+        } else {
+            (self.trailing_underscore, "trailing", trimmed.strip_suffix('_'))
+        };
+        let double_underscore =
+            if leading { trimmed.strip_prefix("__") } else { trimmed.strip_suffix("__") };
+        let Some(option) = option else {
+            return Ok(trimmed);
+        };
+        match option {
+            Underscore::Allow => Ok(underscore.unwrap_or(trimmed)),
+            Underscore::AllowDouble => Ok(double_underscore.unwrap_or(trimmed)),
+            Underscore::AllowSingleOrDouble => {
+                Ok(double_underscore.or(underscore).unwrap_or(trimmed))
+            }
+            Underscore::Forbid => match underscore {
+                Some(_) => Err(unexpected_underscore_diagnostic(
+                    name.span,
+                    name.selector,
+                    original,
+                    position,
+                )),
+                None => Ok(trimmed),
+            },
+            Underscore::Require => underscore.ok_or_else(|| {
+                missing_underscore_diagnostic(name.span, name.selector, original, "one", position)
+            }),
+            Underscore::RequireDouble => double_underscore.ok_or_else(|| {
+                missing_underscore_diagnostic(name.span, name.selector, original, "two", position)
+            }),
+        }
+    }
+
+    /// Returns `name` without the first of the configured prefixes or suffixes which it has,
+    /// or reports it with `Err`.
+    fn trim_affix<'n>(
+        &self,
+        name: &Name,
+        trimmed: &'n str,
+        original: &str,
+        prefix: bool,
+    ) -> Result<&'n str, OxcDiagnostic> {
+        let (affixes, position) =
+            if prefix { (&self.prefix, "prefix") } else { (&self.suffix, "suffix") };
+        if affixes.is_empty() {
+            return Ok(trimmed);
+        }
+        affixes
+            .iter()
+            .find_map(|affix| {
+                if prefix { trimmed.strip_prefix(affix) } else { trimmed.strip_suffix(affix) }
+            })
+            .ok_or_else(|| {
+                missing_affix_diagnostic(name.span, name.selector, original, position, affixes)
+            })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+enum Selector {
+    /// All names.
+    Default,
+    /// `variable`, `function` and `parameter`.
+    VariableLike,
+    /// All members: `property`, `parameterProperty`, `method`, `accessor` and `enumMember`.
+    MemberLike,
+    /// `class`, `interface`, `typeAlias`, `enum` and `typeParameter`.
+    TypeLike,
+    /// `classProperty`, `objectLiteralProperty` and `typeProperty`.
+    Property,
+    /// `classMethod`, `objectLiteralMethod` and `typeMethod`.
+    Method,
+    /// `classicAccessor` and `autoAccessor`.
+    Accessor,
+    Variable,
+    Function,
+    Parameter,
+    ParameterProperty,
+    ClassProperty,
+    ObjectLiteralProperty,
+    TypeProperty,
+    ClassMethod,
+    ObjectLiteralMethod,
+    TypeMethod,
+    ClassicAccessor,
+    AutoAccessor,
+    EnumMember,
+    Class,
+    Interface,
+    TypeAlias,
+    Enum,
+    TypeParameter,
+    /// Default and namespace imports.
+    Import,
+}
+
+impl Selector {
+    /// Whether this selector matches the names of the individual `selector`.
+    fn includes(self, selector: Self) -> bool {
+        self == selector
+            || match self {
+                Self::Default => true,
+                Self::VariableLike => {
+                    matches!(selector, Self::Variable | Self::Function | Self::Parameter)
+                }
+                Self::MemberLike => matches!(
+                    selector,
+                    Self::ParameterProperty
+                        | Self::ClassProperty
+                        | Self::ObjectLiteralProperty
+                        | Self::TypeProperty
+                        | Self::ClassMethod
+                        | Self::ObjectLiteralMethod
+                        | Self::TypeMethod
+                        | Self::ClassicAccessor
+                        | Self::AutoAccessor
+                        | Self::EnumMember
+                ),
+                Self::TypeLike => matches!(
+                    selector,
+                    Self::Class
+                        | Self::Interface
+                        | Self::TypeAlias
+                        | Self::Enum
+                        | Self::TypeParameter
+                ),
+                Self::Property => matches!(
+                    selector,
+                    Self::ClassProperty | Self::ObjectLiteralProperty | Self::TypeProperty
+                ),
+                Self::Method => matches!(
+                    selector,
+                    Self::ClassMethod | Self::ObjectLiteralMethod | Self::TypeMethod
+                ),
+                Self::Accessor => matches!(selector, Self::ClassicAccessor | Self::AutoAccessor),
+                _ => false,
+            }
+    }
+
+    /// Individual selectors take precedence over `property`, `method` and `accessor`, which
+    /// take precedence over the other groups, which take precedence over `default`.
+    fn precedence(self) -> u8 {
+        match self {
+            Self::Default => 3,
+            Self::VariableLike | Self::MemberLike | Self::TypeLike => 2,
+            Self::Property | Self::Method | Self::Accessor => 1,
+            _ => 0,
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::VariableLike => "Variable Like",
+            Self::MemberLike => "Member Like",
+            Self::TypeLike => "Type Like",
+            Self::Property => "Property",
+            Self::Method => "Method",
+            Self::Accessor => "Accessor",
+            Self::Variable => "Variable",
+            Self::Function => "Function",
+            Self::Parameter => "Parameter",
+            Self::ParameterProperty => "Parameter Property",
+            Self::ClassProperty => "Class Property",
+            Self::ObjectLiteralProperty => "Object Literal Property",
+            Self::TypeProperty => "Type Property",
+            Self::ClassMethod => "Class Method",
+            Self::ObjectLiteralMethod => "Object Literal Method",
+            Self::TypeMethod => "Type Method",
+            Self::ClassicAccessor => "Classic Accessor",
+            Self::AutoAccessor => "Auto Accessor",
+            Self::EnumMember => "Enum Member",
+            Self::Class => "Class",
+            Self::Interface => "Interface",
+            Self::TypeAlias => "Type Alias",
+            Self::Enum => "Enum",
+            Self::TypeParameter => "Type Parameter",
+            Self::Import => "Import",
+        }
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    struct Modifiers: u32 {
+        const CONST = 1 << 0;
+        const DESTRUCTURED = 1 << 1;
+        const EXPORTED = 1 << 2;
+        const GLOBAL = 1 << 3;
+        const UNUSED = 1 << 4;
+        const READONLY = 1 << 5;
+        const STATIC = 1 << 6;
+        const PUBLIC = 1 << 7;
+        const PROTECTED = 1 << 8;
+        const PRIVATE = 1 << 9;
+        const HASH_PRIVATE = 1 << 10;
+        const ABSTRACT = 1 << 11;
+        const ASYNC = 1 << 12;
+        const OVERRIDE = 1 << 13;
+        const REQUIRES_QUOTES = 1 << 14;
+        const DEFAULT = 1 << 15;
+        const NAMESPACE = 1 << 16;
+    }
+}
+
+impl Modifiers {
+    fn accessibility(accessibility: Option<TSAccessibility>) -> Self {
+        match accessibility {
+            Some(TSAccessibility::Private) => Self::PRIVATE,
+            Some(TSAccessibility::Protected) => Self::PROTECTED,
+            Some(TSAccessibility::Public) | None => Self::PUBLIC,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+enum Modifier {
+    /// Variables declared with `const`.
+    Const,
+    /// Variables and parameters declared by shorthand object destructuring, e.g. `const { x } = obj`.
+    Destructured,
+    /// Names exported from the module.
+    Exported,
+    /// Variables and functions declared in the top-level scope.
+    Global,
+    /// Names which are never read.
+    Unused,
+    Readonly,
+    Static,
+    Public,
+    Protected,
+    Private,
+    /// Members named with a private identifier, e.g. `#value`.
+    #[serde(rename = "#private")]
+    HashPrivate,
+    Abstract,
+    Async,
+    Override,
+    /// Members whose name must be quoted, e.g. `"Content-Type"`.
+    RequiresQuotes,
+    /// Default imports.
+    Default,
+    /// Namespace imports.
+    Namespace,
+}
+
+impl Modifier {
+    fn flag(self) -> Modifiers {
+        match self {
+            Self::Const => Modifiers::CONST,
+            Self::Destructured => Modifiers::DESTRUCTURED,
+            Self::Exported => Modifiers::EXPORTED,
+            Self::Global => Modifiers::GLOBAL,
+            Self::Unused => Modifiers::UNUSED,
+            Self::Readonly => Modifiers::READONLY,
+            Self::Static => Modifiers::STATIC,
+            Self::Public => Modifiers::PUBLIC,
+            Self::Protected => Modifiers::PROTECTED,
+            Self::Private => Modifiers::PRIVATE,
+            Self::HashPrivate => Modifiers::HASH_PRIVATE,
+            Self::Abstract => Modifiers::ABSTRACT,
+            Self::Async => Modifiers::ASYNC,
+            Self::Override => Modifiers::OVERRIDE,
+            Self::RequiresQuotes => Modifiers::REQUIRES_QUOTES,
+            Self::Default => Modifiers::DEFAULT,
+            Self::Namespace => Modifiers::NAMESPACE,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+enum TypeModifier {
+    Boolean,
+    String,
+    Number,
+    Function,
+    Array,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[expect(clippy::enum_variant_names)]
+enum Format {
+    #[serde(rename = "camelCase")]
+    CamelCase,
+    #[serde(rename = "strictCamelCase")]
+    StrictCamelCase,
+    #[serde(rename = "PascalCase")]
+    PascalCase,
+    #[serde(rename = "StrictPascalCase")]
+    StrictPascalCase,
+    #[serde(rename = "snake_case")]
+    SnakeCase,
+    #[serde(rename = "UPPER_CASE")]
+    UpperCase,
+}
+
+impl Format {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::CamelCase => "camelCase",
+            Self::StrictCamelCase => "strictCamelCase",
+            Self::PascalCase => "PascalCase",
+            Self::StrictPascalCase => "StrictPascalCase",
+            Self::SnakeCase => "snake_case",
+            Self::UpperCase => "UPPER_CASE",
+        }
+    }
+
+    fn matches(self, name: &str) -> bool {
+        let Some(first) = name.chars().next() else {
+            return true;
+        };
+        match self {
+            Self::CamelCase => !first.is_uppercase() && !name.contains('_'),
+            Self::StrictCamelCase => !first.is_uppercase() && has_strict_camel_humps(name, false),
+            Self::PascalCase => !first.is_lowercase() && !name.contains('_'),
+            Self::StrictPascalCase => !first.is_lowercase() && has_strict_camel_humps(name, true),
+            Self::SnakeCase => !name.chars().any(char::is_uppercase) && has_valid_underscores(name),
+            Self::UpperCase => !name.chars().any(char::is_lowercase) && has_valid_underscores(name),
+        }
+    }
+}
+
+/// Whether `name` has no underscores, nor two upper case characters in a row.
+fn has_strict_camel_humps(name: &str, is_upper: bool) -> bool {
+    if name.starts_with('_') {
+        return false;
+    }
+    let mut is_upper = is_upper;
+    for char in name.chars().skip(1) {
+        if char == '_' {
+            return false;
+        }
+        if is_upper == char.is_uppercase() {
+            if is_upper {
+                return false;
+            }
+        } else {
+            is_upper = !is_upper;
+        }
+    }
+    true
+}
+
+/// Whether `name` has no leading, trailing or consecutive underscores.
+fn has_valid_underscores(name: &str) -> bool {
+    !name.starts_with('_') && !name.ends_with('_') && !name.contains("__")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+enum Underscore {
+    /// Disallow the underscore.
+    Forbid,
+    /// Require a single underscore.
+    Require,
+    /// Require two underscores.
+    RequireDouble,
+    /// Allow a single underscore.
+    Allow,
+    /// Allow two underscores.
+    AllowDouble,
+    /// Allow one or two underscores.
+    AllowSingleOrDouble,
+}
+
+/// A name to check, with what the rule knows about it.
+struct Name<'n> {
+    selector: Selector,
+    text: &'n str,
+    span: Span,
+    modifiers: Modifiers,
+    type_modifier: Option<TypeModifier>,
+}
+
+impl<'n> Name<'n> {
+    fn new(selector: Selector, name: &'n str, span: Span, modifiers: Modifiers) -> Self {
+        Self { selector, text: name, span, modifiers, type_modifier: None }
+    }
+
+    fn original_name(&self) -> String {
+        if self.modifiers.contains(Modifiers::HASH_PRIVATE) {
+            format!("#{}", self.text)
+        } else {
+            self.text.to_string()
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces naming conventions for everything from variables and functions to class
+    /// members, type parameters and enum members, with the options of typescript-eslint's
+    /// `naming-convention`.
+    ///
+    /// Each option selects names by their kind (`selector`), `modifiers` and `types`, and
+    /// requires them to match one of its `format`s after trimming their underscores, `prefix`
+    /// and `suffix`. A name is checked by the most specific option which selects it: options
+    /// with individual selectors come before those with group selectors like `memberLike`, and
+    /// options with `types` or more `modifiers` come first among options with the same selector.
+    /// Names which no option selects are not checked.
+    ///
+    /// Without options, names must be in camelCase, variables may also be in UPPER_CASE,
+    /// imports may also be in PascalCase, and type-like names must be in PascalCase.
+    ///
+    /// Types are taken from type annotations and initializers, since the rule is not type-aware;
+    /// options with `types` don't apply to names whose type can't be inferred this way.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Consistent names make code easier to read and search, and can convey information about
+    /// a name at a glance, e.g. that it's a constant or a private member.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// const my_value = 1;
+    /// function DoSomething() {}
+    /// class myClass {}
+    /// interface props {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// const myValue = 1;
+    /// const MAX_SIZE = 10;
+    /// function doSomething() {}
+    /// class MyClass {}
+    /// interface Props {}
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with
+    /// `[{ "selector": "variable", "types": ["boolean"], "format": ["PascalCase"], "prefix": ["is", "has"] }]`:
+    /// ```ts
+    /// const visible = true;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the same options:
+    /// ```ts
+    /// const isVisible = true;
+    /// ```
+    NamingConvention,
+    typescript,
+    style,
+    config = NamingConventionConfig,
+);
+
+impl Rule for NamingConvention {
+    fn from_configuration(value: Value) -> Self {
+        Self::try_from_configuration(value).unwrap_or_default()
+    }
+
+    fn try_from_configuration(value: Value) -> Result<Self, String> {
+        let mut configs = vec![];
+        for option in value.as_array().into_iter().flatten() {
+            let option = serde_json::from_value::<NamingConventionOption>(option.clone())
+                .map_err(|error| format!("invalid option `{option}`: {error}"))?;
+            configs.extend(SelectorConfig::from_option(option)?);
+        }
+        if configs.is_empty() {
+            return Ok(Self::default());
+        }
+        SelectorConfig::sort(&mut configs);
+        Ok(Self(Box::new(NamingConventionConfig(configs))))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::VariableDeclarator(decl) => {
+                let mut modifiers = Modifiers::empty();
+                if decl.kind == VariableDeclarationKind::Const {
+                    modifiers |= Modifiers::CONST;
+                }
+                if decl.init.as_ref().is_some_and(is_async_function) {
+                    modifiers |= Modifiers::ASYNC;
+                }
+                let type_modifier = match &decl.id {
+                    BindingPattern::BindingIdentifier(_) => decl
+                        .type_annotation
+                        .as_ref()
+                        .and_then(|annotation| type_of_annotation(&annotation.type_annotation))
+                        .or_else(|| decl.init.as_ref().and_then(type_of_expression)),
+                    _ => None,
+                };
+                self.check_binding_pattern(
+                    &decl.id,
+                    Selector::Variable,
+                    modifiers,
+                    type_modifier,
+                    false,
+                    ctx,
+                );
+            }
+            AstKind::Function(func) => {
+                let Some(id) = &func.id else {
+                    return;
+                };
+                let mut modifiers = self.symbol_modifiers(id, true, ctx);
+                if func.r#async {
+                    modifiers |= Modifiers::ASYNC;
+                }
+                self.check(
+                    &Name::new(Selector::Function, id.name.as_str(), id.span, modifiers),
+                    ctx,
+                );
+            }
+            AstKind::FormalParameter(param) => {
+                if !matches!(
+                    ctx.nodes().parent_kind(ctx.nodes().parent_id(node.id())),
+                    AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                ) {
+                    return;
+                }
+                self.check_parameter(param, ctx);
+            }
+            AstKind::PropertyDefinition(prop) => {
+                if prop.computed {
+                    return;
+                }
+                let mut modifiers = Modifiers::empty();
+                if prop.r#static {
+                    modifiers |= Modifiers::STATIC;
+                }
+                if prop.readonly {
+                    modifiers |= Modifiers::READONLY;
+                }
+                if prop.r#override {
+                    modifiers |= Modifiers::OVERRIDE;
+                }
+                if prop.r#type == PropertyDefinitionType::TSAbstractPropertyDefinition {
+                    modifiers |= Modifiers::ABSTRACT;
+                }
+                let selector = if prop.value.as_ref().is_some_and(is_function) {
+                    if prop.value.as_ref().is_some_and(is_async_function) {
+                        modifiers |= Modifiers::ASYNC;
+                    }
+                    Selector::ClassMethod
+                } else {
+                    Selector::ClassProperty
+                };
+                let type_modifier = prop
+                    .type_annotation
+                    .as_ref()
+                    .and_then(|annotation| type_of_annotation(&annotation.type_annotation))
+                    .or_else(|| prop.value.as_ref().and_then(type_of_expression));
+                self.check_member(
+                    &prop.key,
+                    selector,
+                    modifiers,
+                    prop.accessibility,
+                    type_modifier,
+                    ctx,
+                );
+            }
+            AstKind::AccessorProperty(prop) => {
+                if prop.computed {
+                    return;
+                }
+                let mut modifiers = Modifiers::empty();
+                if prop.r#static {
+                    modifiers |= Modifiers::STATIC;
+                }
+                if prop.r#override {
+                    modifiers |= Modifiers::OVERRIDE;
+                }
+                if prop.r#type == AccessorPropertyType::TSAbstractAccessorProperty {
+                    modifiers |= Modifiers::ABSTRACT;
+                }
+                self.check_member(
+                    &prop.key,
+                    Selector::AutoAccessor,
+                    modifiers,
+                    prop.accessibility,
+                    None,
+                    ctx,
+                );
+            }
+            AstKind::MethodDefinition(method) => {
+                if method.computed {
+                    return;
+                }
+                let selector = match method.kind {
+                    MethodDefinitionKind::Constructor => return,
+                    MethodDefinitionKind::Method => Selector::ClassMethod,
+                    MethodDefinitionKind::Get | MethodDefinitionKind::Set => {
+                        Selector::ClassicAccessor
+                    }
+                };
+                let mut modifiers = Modifiers::empty();
+                if method.r#static {
+                    modifiers |= Modifiers::STATIC;
+                }
+                if method.r#override {
+                    modifiers |= Modifiers::OVERRIDE;
+                }
+                if method.r#type == MethodDefinitionType::TSAbstractMethodDefinition {
+                    modifiers |= Modifiers::ABSTRACT;
+                }
+                if method.value.r#async {
+                    modifiers |= Modifiers::ASYNC;
+                }
+                self.check_member(
+                    &method.key,
+                    selector,
+                    modifiers,
+                    method.accessibility,
+                    None,
+                    ctx,
+                );
+            }
+            AstKind::ObjectProperty(prop) => {
+                if prop.computed || prop.shorthand {
+                    return;
+                }
+                let mut modifiers = Modifiers::empty();
+                let selector = match prop.kind {
+                    PropertyKind::Get | PropertyKind::Set => Selector::ClassicAccessor,
+                    PropertyKind::Init if prop.method || is_function(&prop.value) => {
+                        if is_async_function(&prop.value) {
+                            modifiers |= Modifiers::ASYNC;
+                        }
+                        Selector::ObjectLiteralMethod
+                    }
+                    PropertyKind::Init => Selector::ObjectLiteralProperty,
+                };
+                self.check_member(
+                    &prop.key,
+                    selector,
+                    modifiers,
+                    None,
+                    type_of_expression(&prop.value),
+                    ctx,
+                );
+            }
+            AstKind::TSPropertySignature(sig) => {
+                if sig.computed {
+                    return;
+                }
+                let type_annotation =
+                    sig.type_annotation.as_ref().map(|annotation| &annotation.type_annotation);
+                let selector = if matches!(type_annotation, Some(TSType::TSFunctionType(_))) {
+                    Selector::TypeMethod
+                } else {
+                    Selector::TypeProperty
+                };
+                let modifiers = if sig.readonly { Modifiers::READONLY } else { Modifiers::empty() };
+                self.check_member(
+                    &sig.key,
+                    selector,
+                    modifiers,
+                    None,
+                    type_annotation.and_then(type_of_annotation),
+                    ctx,
+                );
+            }
+            AstKind::TSMethodSignature(sig) => {
+                if sig.computed {
+                    return;
+                }
+                let selector = match sig.kind {
+                    TSMethodSignatureKind::Method => Selector::TypeMethod,
+                    TSMethodSignatureKind::Get | TSMethodSignatureKind::Set => {
+                        Selector::ClassicAccessor
+                    }
+                };
+                self.check_member(&sig.key, selector, Modifiers::empty(), None, None, ctx);
+            }
+            AstKind::TSEnumMember(member) => {
+                let (name, span, modifiers) = match &member.id {
+                    TSEnumMemberName::Identifier(id) => {
+                        (id.name.as_str(), id.span, Modifiers::empty())
+                    }
+                    TSEnumMemberName::String(string) => {
+                        let modifiers = if is_identifier_name(&string.value) {
+                            Modifiers::empty()
+                        } else {
+                            Modifiers::REQUIRES_QUOTES
+                        };
+                        (string.value.as_str(), string.span, modifiers)
+                    }
+                    _ => return,
+                };
+                self.check(&Name::new(Selector::EnumMember, name, span, modifiers), ctx);
+            }
+            AstKind::Class(class) => {
+                let Some(id) = &class.id else {
+                    return;
+                };
+                let mut modifiers = self.symbol_modifiers(id, false, ctx);
+                if class.r#abstract {
+                    modifiers |= Modifiers::ABSTRACT;
+                }
+                self.check(&Name::new(Selector::Class, id.name.as_str(), id.span, modifiers), ctx);
+            }
+            AstKind::TSInterfaceDeclaration(decl) => {
+                self.check_declaration(&decl.id, Selector::Interface, ctx);
+            }
+            AstKind::TSTypeAliasDeclaration(decl) => {
+                self.check_declaration(&decl.id, Selector::TypeAlias, ctx);
+            }
+            AstKind::TSEnumDeclaration(decl) => {
+                self.check_declaration(&decl.id, Selector::Enum, ctx);
+            }
+            AstKind::TSTypeParameter(param) => {
+                self.check_declaration(&param.name, Selector::TypeParameter, ctx);
+            }
+            AstKind::ImportDefaultSpecifier(specifier) => {
+                let modifiers =
+                    Modifiers::DEFAULT | self.symbol_modifiers(&specifier.local, false, ctx);
+                let id = &specifier.local;
+                self.check(&Name::new(Selector::Import, id.name.as_str(), id.span, modifiers), ctx);
+            }
+            AstKind::ImportNamespaceSpecifier(specifier) => {
+                let modifiers =
+                    Modifiers::NAMESPACE | self.symbol_modifiers(&specifier.local, false, ctx);
+                let id = &specifier.local;
+                self.check(&Name::new(Selector::Import, id.name.as_str(), id.span, modifiers), ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NamingConvention {
+    fn check(&self, name: &Name, ctx: &LintContext) {
+        let Some(config) = self.0.0.iter().find(|config| config.applies_to(name)) else {
+            return;
+        };
+        if let Err(diagnostic) = config.check(name) {
+            ctx.diagnostic(diagnostic);
+        }
+    }
+
+    fn check_declaration(&self, id: &BindingIdentifier, selector: Selector, ctx: &LintContext) {
+        let modifiers = self.symbol_modifiers(id, false, ctx);
+        self.check(&Name::new(selector, id.name.as_str(), id.span, modifiers), ctx);
+    }
+
+    fn check_parameter(&self, param: &FormalParameter, ctx: &LintContext) {
+        let type_modifier = param
+            .type_annotation
+            .as_ref()
+            .and_then(|annotation| type_of_annotation(&annotation.type_annotation));
+        if param.accessibility.is_none() && !param.readonly && !param.r#override {
+            self.check_binding_pattern(
+                &param.pattern,
+                Selector::Parameter,
+                Modifiers::empty(),
+                type_modifier,
+                false,
+                ctx,
+            );
+            return;
+        }
+        let id = match &param.pattern {
+            BindingPattern::BindingIdentifier(id) => id,
+            BindingPattern::AssignmentPattern(pattern) => match &pattern.left {
+                BindingPattern::BindingIdentifier(id) => id,
+                _ => return,
+            },
+            _ => return,
+        };
+        let mut modifiers = Modifiers::accessibility(param.accessibility);
+        if param.readonly {
+            modifiers |= Modifiers::READONLY;
+        }
+        if param.r#override {
+            modifiers |= Modifiers::OVERRIDE;
+        }
+        self.check(
+            &Name {
+                type_modifier,
+                ..Name::new(Selector::ParameterProperty, id.name.as_str(), id.span, modifiers)
+            },
+            ctx,
+        );
+    }
+
+    fn check_binding_pattern(
+        &self,
+        pattern: &BindingPattern,
+        selector: Selector,
+        modifiers: Modifiers,
+        type_modifier: Option<TypeModifier>,
+        destructured: bool,
+        ctx: &LintContext,
+    ) {
+        match pattern {
+            BindingPattern::BindingIdentifier(id) => {
+                let mut modifiers =
+                    modifiers | self.symbol_modifiers(id, selector == Selector::Variable, ctx);
+                if destructured {
+                    modifiers |= Modifiers::DESTRUCTURED;
+                }
+                self.check(
+                    &Name {
+                        type_modifier,
+                        ..Name::new(selector, id.name.as_str(), id.span, modifiers)
+                    },
+                    ctx,
+                );
+            }
+            BindingPattern::AssignmentPattern(pattern) => {
+                self.check_binding_pattern(
+                    &pattern.left,
+                    selector,
+                    modifiers,
+                    type_modifier,
+                    destructured,
+                    ctx,
+                );
+            }
+            BindingPattern::ObjectPattern(pattern) => {
+                for property in &pattern.properties {
+                    self.check_binding_pattern(
+                        &property.value,
+                        selector,
+                        modifiers,
+                        None,
+                        property.shorthand,
+                        ctx,
+                    );
+                }
+                if let Some(rest) = &pattern.rest {
+                    self.check_binding_pattern(
+                        &rest.argument,
+                        selector,
+                        modifiers,
+                        None,
+                        false,
+                        ctx,
+                    );
+                }
+            }
+            BindingPattern::ArrayPattern(pattern) => {
+                for element in pattern.elements.iter().flatten() {
+                    self.check_binding_pattern(element, selector, modifiers, None, false, ctx);
+                }
+                if let Some(rest) = &pattern.rest {
+                    self.check_binding_pattern(
+                        &rest.argument,
+                        selector,
+                        modifiers,
+                        None,
+                        false,
+                        ctx,
+                    );
+                }
+            }
+        }
+    }
+
+    fn check_member(
+        &self,
+        key: &PropertyKey,
+        selector: Selector,
+        modifiers: Modifiers,
+        accessibility: Option<TSAccessibility>,
+        type_modifier: Option<TypeModifier>,
+        ctx: &LintContext,
+    ) {
+        let (name, span, modifiers) = match key {
+            PropertyKey::StaticIdentifier(id) => {
+                (id.name.as_str(), id.span, modifiers | Modifiers::accessibility(accessibility))
+            }
+            PropertyKey::PrivateIdentifier(id) => {
+                (id.name.as_str(), id.span, modifiers | Modifiers::HASH_PRIVATE)
+            }
+            PropertyKey::StringLiteral(string) => {
+                let mut modifiers = modifiers | Modifiers::accessibility(accessibility);
+                if !is_identifier_name(&string.value) {
+                    modifiers |= Modifiers::REQUIRES_QUOTES;
+                }
+                (string.value.as_str(), string.span, modifiers)
+            }
+            _ => return,
+        };
+        self.check(&Name { type_modifier, ..Name::new(selector, name, span, modifiers) }, ctx);
+    }
+
+    /// The modifiers of a declared name which depend on its symbol: `exported`, `unused`, and
+    /// for variables and functions, `global`.
+    #[expect(clippy::unused_self)]
+    fn symbol_modifiers(
+        &self,
+        id: &BindingIdentifier,
+        global: bool,
+        ctx: &LintContext,
+    ) -> Modifiers {
+        let mut modifiers = Modifiers::empty();
+        let Some(symbol_id) = id.symbol_id.get() else {
+            return modifiers;
+        };
+        let scoping = ctx.scoping();
+        let is_global = scoping.symbol_scope_id(symbol_id) == scoping.root_scope_id();
+        if global && is_global {
+            modifiers |= Modifiers::GLOBAL;
+        }
+        let is_exported = is_global
+            && ctx
+                .module_record()
+                .local_export_entries
+                .iter()
+                .any(|entry| entry.local_name.name() == Some(id.name.as_str()));
+        if is_exported {
+            modifiers |= Modifiers::EXPORTED;
+        } else if !ctx
+            .semantic()
+            .symbol_references(symbol_id)
+            .any(|reference| reference.is_read() || reference.is_type())
+        {
+            modifiers |= Modifiers::UNUSED;
+        }
+        modifiers
+    }
+}
+
+fn is_function(expr: &Expression) -> bool {
+    matches!(
+        expr.get_inner_expression(),
+        Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_)
+    )
+}
+
+fn is_async_function(expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::FunctionExpression(func) => func.r#async,
+        Expression::ArrowFunctionExpression(func) => func.r#async,
+        _ => false,
+    }
+}
+
+/// The type of a value initialized with `expr`, if it's obvious from the syntax.
+fn type_of_expression(expr: &Expression) -> Option<TypeModifier> {
+    match expr {
+        Expression::BooleanLiteral(_) => Some(TypeModifier::Boolean),
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => Some(TypeModifier::String),
+        Expression::NumericLiteral(_) => Some(TypeModifier::Number),
+        Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_) => {
+            Some(TypeModifier::Function)
+        }
+        Expression::ArrayExpression(_) => Some(TypeModifier::Array),
+        Expression::UnaryExpression(unary) => match unary.operator {
+            UnaryOperator::LogicalNot => Some(TypeModifier::Boolean),
+            UnaryOperator::UnaryNegation | UnaryOperator::UnaryPlus
+                if matches!(unary.argument, Expression::NumericLiteral(_)) =>
+            {
+                Some(TypeModifier::Number)
+            }
+            _ => None,
+        },
+        Expression::ParenthesizedExpression(expr) => type_of_expression(&expr.expression),
+        Expression::TSAsExpression(expr) => type_of_annotation(&expr.type_annotation),
+        Expression::TSSatisfiesExpression(expr) => type_of_expression(&expr.expression),
+        _ => None,
+    }
+}
+
+/// The type of a value annotated with `ty`, ignoring `null` and `undefined` in unions.
+fn type_of_annotation(ty: &TSType) -> Option<TypeModifier> {
+    match ty {
+        TSType::TSBooleanKeyword(_) => Some(TypeModifier::Boolean),
+        TSType::TSStringKeyword(_) | TSType::TSTemplateLiteralType(_) => Some(TypeModifier::String),
+        TSType::TSNumberKeyword(_) => Some(TypeModifier::Number),
+        TSType::TSLiteralType(literal) => match &literal.literal {
+            TSLiteral::BooleanLiteral(_) => Some(TypeModifier::Boolean),
+            TSLiteral::NumericLiteral(_) | TSLiteral::UnaryExpression(_) => {
+                Some(TypeModifier::Number)
+            }
+            TSLiteral::StringLiteral(_) | TSLiteral::TemplateLiteral(_) => {
+                Some(TypeModifier::String)
+            }
+            TSLiteral::BigIntLiteral(_) => None,
+        },
+        TSType::TSFunctionType(_) | TSType::TSConstructorType(_) => Some(TypeModifier::Function),
+        TSType::TSArrayType(_) | TSType::TSTupleType(_) => Some(TypeModifier::Array),
+        TSType::TSTypeReference(reference) => match &reference.type_name {
+            TSTypeName::IdentifierReference(id)
+                if matches!(id.name.as_str(), "Array" | "ReadonlyArray") =>
+            {
+                Some(TypeModifier::Array)
+            }
+            _ => None,
+        },
+        TSType::TSTypeOperatorType(operator)
+            if operator.operator == TSTypeOperatorOperator::Readonly =>
+        {
+            type_of_annotation(&operator.type_annotation)
+        }
+        TSType::TSParenthesizedType(ty) => type_of_annotation(&ty.type_annotation),
+        TSType::TSUnionType(union) => {
+            let mut types = union.types.iter().filter(|ty| {
+                !matches!(ty, TSType::TSNullKeyword(_) | TSType::TSUndefinedKeyword(_))
+            });
+            let first = type_of_annotation(types.next()?)?;
+            types.all(|ty| type_of_annotation(ty) == Some(first)).then_some(first)
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("const myValue = 1;", None),
+        ("const MAX_SIZE = 10;", None),
+        ("let _private = 1; let trailing_ = 2;", None),
+        ("function doSomething(firstArg, _unused) {}", None),
+        (
+            "class MyClass { myField = 1; #secret = 2; myMethod() {} get value() { return 1; } }",
+            None,
+        ),
+        ("class Foo { constructor(private readonly myService) {} }", None),
+        ("interface Props { onClick(): void; readonly userName: string }", None),
+        ("type MyType<TValue> = TValue[]; enum Color { red }", None),
+        (
+            "import React from 'react'; import * as path from 'path'; import { read_file } from 'fs';",
+            None,
+        ),
+        ("const obj = { fooBar: 1, [`computed_key`]: 2, 'valid': 3 };", None),
+        (
+            "const { foo_bar } = obj;",
+            Some(
+                json!([{ "selector": "variable", "modifiers": ["destructured"], "format": null }]),
+            ),
+        ),
+        ("const foo_bar = 1;", Some(json!([{ "selector": "variable", "format": ["snake_case"] }]))),
+        (
+            "const isVisible = true; const visible = 1;",
+            Some(
+                json!([{ "selector": "variable", "types": ["boolean"], "format": ["PascalCase"], "prefix": ["is", "has"] }]),
+            ),
+        ),
+        (
+            "interface Props {}",
+            Some(
+                json!([{ "selector": "interface", "format": ["PascalCase"], "custom": { "regex": "^I[A-Z]", "match": false } }]),
+            ),
+        ),
+        (
+            "const headers = { 'Content-Type': 'json' };",
+            Some(
+                json!([{ "selector": "objectLiteralProperty", "modifiers": ["requiresQuotes"], "format": null }]),
+            ),
+        ),
+        (
+            "class Foo { private _value = 1; value = 2; }",
+            Some(json!([
+                { "selector": "memberLike", "format": ["camelCase"] },
+                { "selector": "memberLike", "modifiers": ["private"], "format": ["camelCase"], "leadingUnderscore": "require" },
+            ])),
+        ),
+        (
+            "const __dirname_ = 1;",
+            Some(
+                json!([{ "selector": "variable", "format": ["camelCase"], "leadingUnderscore": "allowDouble", "trailingUnderscore": "allow", "filter": { "regex": "dirname", "match": false } }]),
+            ),
+        ),
+        (
+            "export const API_URL = ''; const localValue = '';",
+            Some(json!([
+                { "selector": "variable", "format": ["camelCase"] },
+                { "selector": "variable", "modifiers": ["exported", "const"], "format": ["UPPER_CASE"] },
+            ])),
+        ),
+        (
+            "const fooBar = 1;",
+            Some(json!([{ "selector": "variable", "format": ["strictCamelCase"] }])),
+        ),
+        ("class FooBar {}", Some(json!([{ "selector": "class", "format": ["StrictPascalCase"] }]))),
+        (
+            "type T = string;",
+            Some(
+                json!([{ "selector": "typeAlias", "format": ["PascalCase"], "filter": "^[A-Z]{2,}" }]),
+            ),
+        ),
+    ];
+
+    let fail = vec![
+        ("const my_value = 1;", None),
+        ("function DoSomething() {}", None),
+        ("function foo(First_Arg) {}", None),
+        ("class myClass {}", None),
+        ("interface props {}", None),
+        ("type my_type = string;", None),
+        ("enum Color { red_value }", None),
+        ("class Foo { my_field = 1; #my_secret = 2; My_Method() {} }", None),
+        ("const obj = { foo_bar: 1, 'Content-Type': 2 };", None),
+        ("import * as my_path from 'path';", None),
+        ("function foo<t_value>() {}", None),
+        (
+            "const foo = 1;",
+            Some(
+                json!([{ "selector": "variable", "format": ["snake_case"], "leadingUnderscore": "require" }]),
+            ),
+        ),
+        (
+            "const __foo = 1;",
+            Some(
+                json!([{ "selector": "variable", "format": ["camelCase"], "leadingUnderscore": "forbid" }]),
+            ),
+        ),
+        (
+            "const _foo = 1;",
+            Some(
+                json!([{ "selector": "variable", "format": ["camelCase"], "leadingUnderscore": "requireDouble" }]),
+            ),
+        ),
+        (
+            "const foo_ = 1;",
+            Some(
+                json!([{ "selector": "variable", "format": ["camelCase"], "trailingUnderscore": "forbid" }]),
+            ),
+        ),
+        (
+            "const visible = true;",
+            Some(
+                json!([{ "selector": "variable", "types": ["boolean"], "format": ["PascalCase"], "prefix": ["is", "has"] }]),
+            ),
+        ),
+        (
+            "const isvisible = true;",
+            Some(
+                json!([{ "selector": "variable", "types": ["boolean"], "format": ["PascalCase"], "prefix": ["is", "has"] }]),
+            ),
+        ),
+        (
+            "interface IProps {}",
+            Some(
+                json!([{ "selector": "interface", "format": ["PascalCase"], "custom": { "regex": "^I[A-Z]", "match": false } }]),
+            ),
+        ),
+        (
+            "class Foo { private value = 1; }",
+            Some(
+                json!([{ "selector": "memberLike", "modifiers": ["private"], "format": ["camelCase"], "leadingUnderscore": "require" }]),
+            ),
+        ),
+        (
+            "const fooBAR = 1;",
+            Some(json!([{ "selector": "variable", "format": ["strictCamelCase"] }])),
+        ),
+        ("class FooBAR {}", Some(json!([{ "selector": "class", "format": ["StrictPascalCase"] }]))),
+        (
+            "type Props = {}; ",
+            Some(
+                json!([{ "selector": "typeAlias", "format": ["PascalCase"], "suffix": ["Type"] }]),
+            ),
+        ),
+        (
+            "export const apiUrl = '';",
+            Some(
+                json!([{ "selector": "variable", "modifiers": ["exported", "const"], "format": ["UPPER_CASE"] }]),
+            ),
+        ),
+        (
+            "const callback = () => {};",
+            Some(
+                json!([{ "selector": "variable", "types": ["function"], "format": ["PascalCase"] }]),
+            ),
+        ),
+        (
+            "class Foo { static my_value = 1; other_value = 2 }",
+            Some(
+                json!([{ "selector": ["classProperty", "classMethod"], "modifiers": ["static"], "format": ["UPPER_CASE"] }]),
+            ),
+        ),
+    ];
+
+    Tester::new(NamingConvention::NAME, NamingConvention::PLUGIN, pass, fail)
+        .change_rule_path_extension("ts")
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_invalid_option() {
+    use serde_json::json;
+
+    let error = NamingConvention::try_from_configuration(json!([
+        { "selector": "variable", "format": ["camelCase"] },
+        { "selector": "unknown", "format": ["camelCase"] }
+    ]))
+    .unwrap_err();
+    assert!(error.starts_with("invalid option `"), "{error}");
+    assert!(error.contains(r#""selector":"unknown""#), "{error}");
+}
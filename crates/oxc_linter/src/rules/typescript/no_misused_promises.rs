@@ -140,3 +140,61 @@ impl Rule for NoMisusedPromises {
         Some(serde_json::to_value(&*self.0))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_default_config() {
+        let rule = NoMisusedPromises::default();
+        let config = rule.to_configuration().unwrap().unwrap();
+
+        assert_eq!(config["checksConditionals"], json!(true));
+        assert_eq!(config["checksSpreads"], json!(true));
+        assert_eq!(config["checksVoidReturn"], json!(true));
+    }
+
+    #[test]
+    fn test_checks_void_return_boolean() {
+        let rule = NoMisusedPromises::from_configuration(json!([{
+            "checksConditionals": false,
+            "checksVoidReturn": false
+        }]));
+
+        assert!(!rule.0.checks_conditionals);
+        assert!(rule.0.checks_spreads);
+        assert_eq!(rule.0.checks_void_return, ChecksVoidReturn::Boolean(false));
+    }
+
+    #[test]
+    fn test_checks_void_return_options() {
+        let original_config = json!([{
+            "checksVoidReturn": { "arguments": false, "attributes": false }
+        }]);
+
+        let rule = NoMisusedPromises::from_configuration(original_config);
+        let ChecksVoidReturn::Options(options) = &rule.0.checks_void_return else {
+            panic!("expected `checksVoidReturn` options");
+        };
+        assert!(!options.arguments);
+        assert!(!options.attributes);
+        // Options which aren't given keep their defaults.
+        assert!(options.inherited_methods);
+        assert!(options.returns);
+
+        let serialized = rule.to_configuration().unwrap().unwrap();
+        assert_eq!(
+            serialized["checksVoidReturn"],
+            json!({
+                "arguments": false,
+                "attributes": false,
+                "inheritedMethods": true,
+                "properties": true,
+                "returns": true,
+                "variables": true
+            })
+        );
+    }
+}
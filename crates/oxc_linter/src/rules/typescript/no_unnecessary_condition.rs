@@ -0,0 +1,152 @@
+use oxc_macros::declare_oxc_lint;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::rule::{DefaultRuleConfig, Rule};
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUnnecessaryCondition(Box<NoUnnecessaryConditionConfig>);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoUnnecessaryConditionConfig {
+    /// Whether to allow constant conditions in loops, like `while (true)`.
+    pub allow_constant_loop_conditions: AllowConstantLoopConditions,
+    /// Whether to report type predicates whose argument is always or never of the checked type,
+    /// e.g. `Array.isArray(value)` where `value` is known to be an array.
+    pub check_type_predicates: bool,
+    /// Whether to run the rule without `strictNullChecks`, where it reports many false positives.
+    #[serde(rename = "allowRuleToRunWithoutStrictNullChecksIKnowWhatIAmDoing")]
+    pub allow_rule_to_run_without_strict_null_checks: bool,
+}
+
+/// Which constant loop conditions are allowed:
+/// - `true` or `"always"` - all of them
+/// - `false` or `"never"` - none of them
+/// - `"only-allowed-literals"` - only `true`, `false`, `0` and `1`, e.g. `while (true)`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum AllowConstantLoopConditions {
+    Boolean(bool),
+    Mode(ConstantLoopConditionsMode),
+}
+
+impl Default for AllowConstantLoopConditions {
+    fn default() -> Self {
+        Self::Mode(ConstantLoopConditionsMode::Never)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConstantLoopConditionsMode {
+    Always,
+    Never,
+    OnlyAllowedLiterals,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// This rule disallows conditions which are always truthy or always falsy according to
+    /// their types, e.g. checking a value which can't be nullish for `null`, or an optional
+    /// chain on a value which is never nullish.
+    ///
+    /// Accesses of index signatures and array elements are not reported as always defined, since
+    /// their types don't include `undefined` unless `noUncheckedIndexedAccess` is enabled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A condition which can't change is either dead code or a sign that the types are wrong,
+    /// e.g. a value which is typed as non-nullable but may be `null` at runtime.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// function head(items: string[]) {
+    ///   if (items) {
+    ///     return items[0];
+    ///   }
+    /// }
+    ///
+    /// function name(user: { name: string }) {
+    ///   return user.name ?? 'anonymous';
+    /// }
+    ///
+    /// function length(text: string) {
+    ///   return text?.length;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// function head(items: string[]) {
+    ///   if (items.length > 0) {
+    ///     return items[0];
+    ///   }
+    /// }
+    ///
+    /// function name(user: { name?: string }) {
+    ///   return user.name ?? 'anonymous';
+    /// }
+    ///
+    /// function first(record: Record<string, string[]>, key: string) {
+    ///   return record[key]?.[0];
+    /// }
+    /// ```
+    NoUnnecessaryCondition(tsgolint),
+    typescript,
+    nursery,
+    pending,
+    config = NoUnnecessaryConditionConfig,
+);
+
+impl Rule for NoUnnecessaryCondition {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoUnnecessaryCondition>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn to_configuration(&self) -> Option<Result<serde_json::Value, serde_json::Error>> {
+        Some(serde_json::to_value(&*self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_default_config() {
+        let rule = NoUnnecessaryCondition::default();
+        let config = rule.to_configuration().unwrap().unwrap();
+
+        assert_eq!(config["allowConstantLoopConditions"], json!("never"));
+        assert_eq!(config["checkTypePredicates"], json!(false));
+        assert_eq!(config["allowRuleToRunWithoutStrictNullChecksIKnowWhatIAmDoing"], json!(false));
+    }
+
+    #[test]
+    fn test_allow_constant_loop_conditions() {
+        let rule = NoUnnecessaryCondition::from_configuration(
+            json!([{ "allowConstantLoopConditions": true }]),
+        );
+        assert_eq!(
+            rule.0.allow_constant_loop_conditions,
+            AllowConstantLoopConditions::Boolean(true)
+        );
+
+        let rule = NoUnnecessaryCondition::from_configuration(json!([{
+            "allowConstantLoopConditions": "only-allowed-literals",
+            "checkTypePredicates": true
+        }]));
+        assert_eq!(
+            rule.0.allow_constant_loop_conditions,
+            AllowConstantLoopConditions::Mode(ConstantLoopConditionsMode::OnlyAllowedLiterals)
+        );
+        assert!(rule.0.check_type_predicates);
+    }
+}
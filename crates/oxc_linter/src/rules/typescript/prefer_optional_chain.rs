@@ -0,0 +1,132 @@
+use oxc_macros::declare_oxc_lint;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::rule::{DefaultRuleConfig, Rule};
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct PreferOptionalChain(Box<PreferOptionalChainConfig>);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+#[expect(clippy::struct_excessive_bools)]
+pub struct PreferOptionalChainConfig {
+    /// Whether to fix chains even when the fix changes the type of the expression, e.g.
+    /// `foo && foo.bar` of type `false | string` becoming `foo?.bar` of type
+    /// `string | undefined`. Otherwise, such chains only get suggestions.
+    ///
+    /// It is _not_ recommended to enable this config option.
+    pub allow_potentially_unsafe_fixes_that_modify_the_return_type_i_know_what_im_doing: bool,
+    /// Whether to check operands of type `any`.
+    pub check_any: bool,
+    /// Whether to check operands of type `bigint`, whose `0n` is falsy.
+    pub check_big_int: bool,
+    /// Whether to check operands of type `boolean`, whose `false` is falsy.
+    pub check_boolean: bool,
+    /// Whether to check operands of type `number`, whose `0` and `NaN` are falsy.
+    pub check_number: bool,
+    /// Whether to check operands of type `string`, whose `''` is falsy.
+    pub check_string: bool,
+    /// Whether to check operands of type `unknown`.
+    pub check_unknown: bool,
+    /// Whether to only check operands whose type includes `null` or `undefined`.
+    pub require_nullish: bool,
+}
+
+impl Default for PreferOptionalChainConfig {
+    fn default() -> Self {
+        Self {
+            allow_potentially_unsafe_fixes_that_modify_the_return_type_i_know_what_im_doing: false,
+            check_any: true,
+            check_big_int: true,
+            check_boolean: true,
+            check_number: true,
+            check_string: true,
+            check_unknown: true,
+            require_nullish: false,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using optional chains (`?.`) instead of chained logical ANDs (`&&`), negated
+    /// logical ORs (`||`) and empty objects, e.g. `foo && foo.bar` or `(foo || {}).bar`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Optional chains are shorter, and express the intent of checking for `null` and
+    /// `undefined` directly, instead of through the truthiness of each operand.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// declare const foo: { bar?: { baz: () => void } } | null;
+    ///
+    /// foo && foo.bar && foo.bar.baz();
+    /// !foo || !foo.bar;
+    /// (foo || {}).bar;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// declare const foo: { bar?: { baz: () => void } } | null;
+    ///
+    /// foo?.bar?.baz();
+    /// !foo?.bar;
+    /// foo?.bar;
+    /// ```
+    PreferOptionalChain(tsgolint),
+    typescript,
+    nursery,
+    pending,
+    config = PreferOptionalChainConfig,
+);
+
+impl Rule for PreferOptionalChain {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<PreferOptionalChain>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn to_configuration(&self) -> Option<Result<serde_json::Value, serde_json::Error>> {
+        Some(serde_json::to_value(&*self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_default_config() {
+        let rule = PreferOptionalChain::default();
+        let config = rule.to_configuration().unwrap().unwrap();
+
+        assert_eq!(
+            config["allowPotentiallyUnsafeFixesThatModifyTheReturnTypeIKnowWhatImDoing"],
+            json!(false)
+        );
+        assert_eq!(config["checkBigInt"], json!(true));
+        assert_eq!(config["checkString"], json!(true));
+        assert_eq!(config["requireNullish"], json!(false));
+    }
+
+    #[test]
+    fn test_from_configuration() {
+        let rule = PreferOptionalChain::from_configuration(json!([{
+            "checkNumber": false,
+            "checkString": false,
+            "requireNullish": true
+        }]));
+
+        assert!(!rule.0.check_number);
+        assert!(!rule.0.check_string);
+        assert!(rule.0.check_boolean);
+        assert!(rule.0.require_nullish);
+    }
+}
@@ -1,7 +1,11 @@
-use oxc_ast::ast::{Statement, TSModuleReference};
+use oxc_ast::{
+    TripleSlashDirectiveKind,
+    ast::{Statement, TSModuleReference},
+    triple_slash_directives,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{GetSpan, Span};
+use oxc_span::Span;
 use rustc_hash::FxHashMap;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -109,25 +113,23 @@ impl Rule for TripleSlashReference {
 
     fn run_once(&self, ctx: &LintContext) {
         let program = ctx.nodes().program();
-
-        // We don't need to iterate over all comments since Triple-slash directives are only valid at the top of their containing file.
-        // We are trying to get the first statement start potioin, falling back to the program end if statement does not exist
-        let comments_range_end = program.body.first().map_or(program.span.end, |v| v.span().start);
         let mut refs_for_import = FxHashMap::default();
 
-        for comment in ctx.comments_range(0..comments_range_end) {
-            let raw = ctx.source_range(comment.content_span());
-            if let Some((group1, group2)) = get_attr_key_and_value(raw) {
-                if (group1 == "types" && self.types == TypesOption::Never)
-                    || (group1 == "path" && self.path == PathOption::Never)
-                    || (group1 == "lib" && self.lib == LibOption::Never)
-                {
-                    ctx.diagnostic(triple_slash_reference_diagnostic(&group2, comment.span));
-                }
+        for directive in triple_slash_directives(program) {
+            let value = directive.value.as_str();
+            let disallowed = match directive.kind {
+                TripleSlashDirectiveKind::Types => self.types == TypesOption::Never,
+                TripleSlashDirectiveKind::Path => self.path == PathOption::Never,
+                TripleSlashDirectiveKind::Lib => self.lib == LibOption::Never,
+            };
+            if disallowed {
+                ctx.diagnostic(triple_slash_reference_diagnostic(value, directive.span));
+            }
 
-                if group1 == "types" && self.types == TypesOption::PreferImport {
-                    refs_for_import.insert(group2, comment.span);
-                }
+            if directive.kind == TripleSlashDirectiveKind::Types
+                && self.types == TypesOption::PreferImport
+            {
+                refs_for_import.insert(value, directive.span);
             }
         }
 
@@ -167,46 +169,6 @@ impl Rule for TripleSlashReference {
     }
 }
 
-fn get_attr_key_and_value(raw: &str) -> Option<(String, String)> {
-    if !raw.starts_with('/') {
-        return None;
-    }
-
-    let reference_start = "<reference ";
-    let reference_end = "/>";
-
-    if let Some(start_idx) = raw.find(reference_start) {
-        // Check if the string contains '/>' after the start index
-        if let Some(end_idx) = raw[start_idx..].find(reference_end) {
-            let reference_str = &raw[start_idx + reference_start.len()..start_idx + end_idx];
-
-            // Split the string by whitespaces
-            let parts = reference_str.split_whitespace();
-
-            // Filter parts that start with attribute key pattern
-            let filtered_parts: Vec<&str> = parts
-                .into_iter()
-                .filter(|part| {
-                    part.starts_with("types=")
-                        || part.starts_with("path=")
-                        || part.starts_with("lib=")
-                })
-                .collect();
-
-            if let Some(attr) = filtered_parts.first() {
-                // Split the attribute by '=' to get key and value
-                let attr_parts: Vec<&str> = attr.split('=').collect();
-                if attr_parts.len() == 2 {
-                    let key = attr_parts[0].trim().trim_matches('"').to_string();
-                    let value = attr_parts[1].trim_matches('"').trim_end_matches('/').to_string();
-                    return Some((key, value));
-                }
-            }
-        }
-    }
-    None
-}
-
 #[test]
 fn test() {
     use crate::tester::Tester;
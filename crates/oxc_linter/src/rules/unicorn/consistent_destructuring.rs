@@ -0,0 +1,252 @@
+use oxc_ast::{
+    AstKind,
+    ast::{BindingPattern, BindingProperty, Expression, StaticMemberExpression, UnaryOperator},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{NodeId, Reference, SymbolId};
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn consistent_destructuring_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Use destructured variables over properties.")
+        .with_help("Add the property to the destructuring above, and use its variable.")
+        .with_label(span)
+}
+
+fn use_destructured_variable_diagnostic(span: Span, expression: &str, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Use destructured variables over properties.")
+        .with_help(format!("Replace `{expression}` with the destructured variable `{name}`."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ConsistentDestructuring;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Use destructured variables over properties of an object which was destructured before.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Mixing destructured variables and property accesses on the same object makes it harder
+    /// to see which properties are used, and to rename or move them.
+    ///
+    /// Method calls like `foo.bar()` are ignored, as destructuring methods loses their `this`,
+    /// and so are assignments to properties. Objects destructured with a rest element, like
+    /// `const { a, ...rest } = foo`, are ignored too.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const { a } = foo;
+    /// console.log(a, foo.b);
+    ///
+    /// const { a } = foo;
+    /// console.log(foo.a);
+    ///
+    /// const { c } = foo.bar;
+    /// console.log(foo.bar.d);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const { a, b } = foo;
+    /// console.log(a, b);
+    ///
+    /// const { a } = foo;
+    /// foo.b = 1;
+    /// foo.run();
+    /// ```
+    ConsistentDestructuring,
+    unicorn,
+    style,
+    suggestion,
+);
+
+impl Rule for ConsistentDestructuring {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::StaticMemberExpression(member) = node.kind() else {
+            return;
+        };
+        if member.optional || is_call_or_write(node, member, ctx) {
+            return;
+        }
+        let Some(references) = root_references(&member.object, ctx) else {
+            return;
+        };
+        let object_text = ctx.source_range(member.object.without_parentheses().span());
+        let member_scope = node.scope_id();
+
+        // The last destructuring of the same object before the member, in a scope it can see.
+        let Some(pattern) = references
+            .into_iter()
+            .filter_map(|reference| {
+                ctx.nodes().ancestors(reference).find_map(|ancestor| match ancestor.kind() {
+                    AstKind::VariableDeclarator(declarator) => Some((ancestor, declarator)),
+                    _ => None,
+                })
+            })
+            .filter(|(declarator_node, declarator)| {
+                declarator.span.end <= member.span.start
+                    && declarator.init.as_ref().is_some_and(|init| {
+                        ctx.source_range(init.without_parentheses().span()) == object_text
+                    })
+                    && ctx
+                        .scoping()
+                        .scope_ancestors(member_scope)
+                        .any(|scope| scope == declarator_node.scope_id())
+            })
+            .filter_map(|(_, declarator)| match &declarator.id {
+                BindingPattern::ObjectPattern(pattern) => Some(pattern),
+                _ => None,
+            })
+            .max_by_key(|pattern| pattern.span.start)
+        else {
+            return;
+        };
+        if pattern.rest.is_some() {
+            return;
+        }
+
+        let property = member.property.name.as_str();
+        let Some(destructured) = pattern
+            .properties
+            .iter()
+            .find(|p| !p.computed && p.key.is_specific_static_name(property))
+        else {
+            ctx.diagnostic(consistent_destructuring_diagnostic(member.span));
+            return;
+        };
+        let Some(symbol_id) = destructured_variable(destructured) else {
+            return;
+        };
+        let name = ctx.scoping().symbol_name(symbol_id);
+        // The variable can be shadowed where the property is used.
+        if ctx.scoping().find_binding(member_scope, name) != Some(symbol_id) {
+            return;
+        }
+        let expression = ctx.source_range(member.span);
+        ctx.diagnostic_with_suggestion(
+            use_destructured_variable_diagnostic(member.span, expression, name),
+            |fixer| fixer.replace(member.span, name.to_string()),
+        );
+    }
+}
+
+/// The nodes referencing the same variable as the identifier at the root of a chain like
+/// `foo.bar.baz`, global or not.
+fn root_references(expr: &Expression, ctx: &LintContext) -> Option<Vec<NodeId>> {
+    match expr.without_parentheses() {
+        Expression::Identifier(ident) => {
+            let scoping = ctx.scoping();
+            let references = match scoping.get_reference(ident.reference_id()).symbol_id() {
+                Some(symbol_id) => {
+                    ctx.symbol_references(symbol_id).map(Reference::node_id).collect()
+                }
+                None => scoping
+                    .root_unresolved_references()
+                    .get(ident.name.as_str())?
+                    .iter()
+                    .map(|&reference_id| scoping.get_reference(reference_id).node_id())
+                    .collect(),
+            };
+            Some(references)
+        }
+        Expression::StaticMemberExpression(member) if !member.optional => {
+            root_references(&member.object, ctx)
+        }
+        _ => None,
+    }
+}
+
+/// The variable `property` is destructured into by `pattern`, unless it's destructured into a
+/// nested pattern, e.g. `const { a: { b } } = foo`.
+fn destructured_variable(property: &BindingProperty) -> Option<SymbolId> {
+    match &property.value {
+        BindingPattern::BindingIdentifier(ident) => Some(ident.symbol_id()),
+        BindingPattern::AssignmentPattern(assignment) => match &assignment.left {
+            BindingPattern::BindingIdentifier(ident) => Some(ident.symbol_id()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether the member is called as a method, or written to.
+fn is_call_or_write(node: &AstNode, member: &StaticMemberExpression, ctx: &LintContext) -> bool {
+    match ctx.nodes().parent_kind(node.id()) {
+        AstKind::CallExpression(call) => call.callee.without_parentheses().span() == member.span,
+        AstKind::TaggedTemplateExpression(tagged) => tagged.tag.span() == member.span,
+        AstKind::AssignmentExpression(assignment) => assignment.left.span() == member.span,
+        AstKind::UpdateExpression(_)
+        | AstKind::ArrayAssignmentTarget(_)
+        | AstKind::ObjectAssignmentTarget(_)
+        | AstKind::AssignmentTargetWithDefault(_)
+        | AstKind::AssignmentTargetPropertyProperty(_)
+        | AstKind::AssignmentTargetRest(_)
+        | AstKind::ForInStatement(_)
+        | AstKind::ForOfStatement(_) => true,
+        AstKind::UnaryExpression(unary) => unary.operator == UnaryOperator::Delete,
+        AstKind::ParenthesizedExpression(_) => {
+            // e.g. `(foo.bar)()`
+            let parent = ctx.nodes().parent_node(node.id());
+            matches!(ctx.nodes().parent_kind(parent.id()), AstKind::CallExpression(call) if call.callee.span() == parent.span())
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "console.log(foo.a, foo.b);",
+        "const { a } = foo; console.log(a);",
+        "const { a, b } = foo; console.log(a, b);",
+        "console.log(foo.a); const { a } = foo;",
+        "const { a } = foo; foo.b = 1;",
+        "const { a } = foo; foo.b++;",
+        "const { a } = foo; delete foo.b;",
+        "const { a } = foo; foo.run();",
+        "const { a } = foo; foo.tag`x`;",
+        "const { a } = foo; [foo.b] = [1];",
+        "const { a } = foo; for (foo.b of list);",
+        "const { a, ...rest } = foo; console.log(foo.b);",
+        "const { a } = foo; console.log(foo?.b);",
+        "const { a } = foo; console.log(bar.b);",
+        "const { a } = foo; function f(foo) { return foo.b; }",
+        "function f() { const { a } = foo; } console.log(foo.b);",
+        "const { a: { b } } = foo; console.log(foo.a);",
+        "const { a } = foo; const f = (a) => foo.a;",
+        "const { a } = foo.bar; console.log(foo.baz);",
+        "const [a] = foo; console.log(foo.length);",
+    ];
+
+    let fail = vec![
+        "const { a } = foo; console.log(a, foo.b);",
+        "const { a } = foo; console.log(foo.a);",
+        "const { a: renamed } = foo; console.log(foo.a);",
+        "const { a = 1 } = foo; console.log(foo.a);",
+        "const { c } = foo.bar; console.log(foo.bar.d);",
+        "const { a } = foo; if (x) { console.log(foo.b); }",
+        "const { a } = foo; function f() { return foo.b.c; }",
+        "const { a } = (foo); console.log((foo).b);",
+    ];
+
+    let fix = vec![
+        ("const { a } = foo; console.log(foo.a);", "const { a } = foo; console.log(a);"),
+        (
+            "const { a: renamed } = foo; console.log(foo.a);",
+            "const { a: renamed } = foo; console.log(renamed);",
+        ),
+    ];
+
+    Tester::new(ConsistentDestructuring::NAME, ConsistentDestructuring::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,308 @@
+use cow_utils::CowUtils;
+use oxc_ast::{AstKind, ast::Argument};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    ast_util::is_global_require_call,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::NodeVersion,
+};
+
+fn no_unnecessary_polyfills_diagnostic(
+    span: Span,
+    module: &str,
+    feature: &str,
+    range: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Use the built-in `{feature}` instead of the `{module}` polyfill, it is supported by all targets ({range})."
+    ))
+    .with_help("Remove the polyfill.")
+    .with_label(span)
+}
+
+/// Polyfilled features: their `core-js` module path, npm packages polyfilling them, and the
+/// Node.js version which first supports them natively.
+const POLYFILLS: &[(&str, &[&str], &str)] = &[
+    (
+        "promise",
+        &["es6-promise", "es6-promise/auto", "promise-polyfill", "native-promise-only"],
+        "4.0.0",
+    ),
+    ("promise/all-settled", &["promise.allsettled"], "12.9.0"),
+    ("promise/any", &["promise.any"], "15.0.0"),
+    ("promise/finally", &["promise.prototype.finally"], "10.0.0"),
+    ("symbol", &["es6-symbol", "es6-symbol/implement"], "4.0.0"),
+    ("map", &["es6-map", "es6-map/implement"], "4.0.0"),
+    ("set", &["es6-set", "es6-set/implement"], "4.0.0"),
+    ("weak-map", &["es6-weak-map", "es6-weak-map/implement"], "4.0.0"),
+    ("object/assign", &["object-assign", "object.assign", "es6-object-assign"], "4.0.0"),
+    ("object/entries", &["object.entries"], "7.0.0"),
+    ("object/values", &["object.values"], "7.0.0"),
+    ("object/from-entries", &["object.fromentries"], "12.0.0"),
+    ("object/has-own", &["object.hasown"], "16.9.0"),
+    ("array/from", &["array.from", "array-from"], "4.0.0"),
+    ("array/includes", &["array-includes", "array.prototype.includes"], "6.0.0"),
+    ("array/find", &["array.prototype.find", "array-find"], "4.0.0"),
+    ("array/find-index", &["array.prototype.findindex"], "4.0.0"),
+    ("array/find-last", &["array.prototype.findlast"], "18.0.0"),
+    ("array/flat", &["array.prototype.flat", "array-flat-polyfill"], "11.0.0"),
+    ("array/flat-map", &["array.prototype.flatmap"], "11.0.0"),
+    ("array/at", &["array.prototype.at"], "16.6.0"),
+    ("string/includes", &["string.prototype.includes"], "4.0.0"),
+    ("string/starts-with", &["string.prototype.startswith"], "4.0.0"),
+    ("string/ends-with", &["string.prototype.endswith"], "4.0.0"),
+    ("string/pad-start", &["string.prototype.padstart"], "8.0.0"),
+    ("string/pad-end", &["string.prototype.padend"], "8.0.0"),
+    ("string/trim-start", &["string.prototype.trimstart"], "10.0.0"),
+    ("string/trim-end", &["string.prototype.trimend"], "10.0.0"),
+    ("string/match-all", &["string.prototype.matchall"], "12.0.0"),
+    ("string/replace-all", &["string.prototype.replaceall"], "15.0.0"),
+    ("string/at", &["string.prototype.at"], "16.6.0"),
+    ("global-this", &["globalthis", "globalthis/auto"], "12.0.0"),
+    ("structured-clone", &["@ungap/structured-clone"], "17.0.0"),
+];
+
+/// Namespaces of `core-js` entry points, e.g. `core-js/stable/array/flat`.
+const CORE_JS_NAMESPACES: [&str; 5] = ["es", "stable", "actual", "full", "features"];
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUnnecessaryPolyfills(Box<NoUnnecessaryPolyfillsConfig>);
+
+impl std::ops::Deref for NoUnnecessaryPolyfills {
+    type Target = NoUnnecessaryPolyfillsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoUnnecessaryPolyfillsConfig {
+    /// The targets to support: a range of Node.js versions, e.g. `"node >=18"` or
+    /// `{ "node": ">=18" }`. By default, the `engines.node` field of the nearest `package.json`.
+    ///
+    /// Browser targets are not supported yet: without Node.js targets, nothing is reported.
+    targets: Option<Targets>,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum Targets {
+    Query(String),
+    Engines { node: Option<String> },
+}
+
+impl Targets {
+    /// The range of Node.js versions, if only Node.js is targeted.
+    fn node_range(&self) -> Option<String> {
+        match self {
+            Self::Engines { node } => node.clone(),
+            Self::Query(query) => {
+                let ranges = query
+                    .split([',', '\n'])
+                    .map(|query| query.trim().strip_prefix("node ").map(str::trim))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(ranges.join(" || "))
+            }
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows importing polyfills of features which all the targets already support.
+    ///
+    /// The targets are the Node.js versions given by the `targets` option, or by the
+    /// `engines.node` field of the nearest `package.json`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unnecessary polyfills add code to load and run, and can be slower or less correct than
+    /// the built-in features they replace.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule, with `{ "targets": "node >=18" }`:
+    /// ```js
+    /// import "core-js/stable/array/flat";
+    /// import assign from "object-assign";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule, with `{ "targets": "node >=18" }`:
+    /// ```js
+    /// import "core-js/actual/array/from-async";
+    /// ```
+    NoUnnecessaryPolyfills,
+    unicorn,
+    pedantic,
+    config = NoUnnecessaryPolyfillsConfig,
+);
+
+impl Rule for NoUnnecessaryPolyfills {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<NoUnnecessaryPolyfills>>(value)
+            .unwrap_or_default()
+            .into_inner()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (module, span) = match node.kind() {
+            AstKind::ImportDeclaration(import) => (&import.source.value, import.source.span),
+            AstKind::CallExpression(call) if is_global_require_call(call, ctx.semantic()) => {
+                let Argument::StringLiteral(module) = &call.arguments[0] else {
+                    return;
+                };
+                (&module.value, module.span)
+            }
+            _ => return,
+        };
+        // `core-js` itself is left alone, as it includes proposals which are never built in.
+        let polyfill = match module.strip_prefix("core-js/") {
+            Some(path) => core_js_feature(path),
+            None => POLYFILLS
+                .iter()
+                .find(|(_, packages, _)| packages.contains(&module.as_str()))
+                .map(|(feature, _, since)| (*feature, *since)),
+        };
+        let Some((feature, since)) = polyfill else {
+            return;
+        };
+        let Some(range) = self.node_range(ctx) else {
+            return;
+        };
+        if NodeVersion::min_of_range(&range).is_some_and(|version| version.supports(&[since])) {
+            ctx.diagnostic(no_unnecessary_polyfills_diagnostic(
+                span,
+                module,
+                &feature_name(feature),
+                &range,
+            ));
+        }
+    }
+}
+
+impl NoUnnecessaryPolyfills {
+    fn node_range(&self, ctx: &LintContext) -> Option<String> {
+        match &self.targets {
+            Some(targets) => targets.node_range(),
+            None => ctx.package_json().and_then(|package_json| {
+                package_json
+                    .engines
+                    .get("node")
+                    .and_then(serde_json::Value::as_str)
+                    .map(String::from)
+            }),
+        }
+    }
+}
+
+/// The polyfilled feature of a `core-js` module path, e.g. `stable/array/flat` or
+/// `modules/es.array.flat`, and the Node.js version which first supports it.
+fn core_js_feature(path: &str) -> Option<(&'static str, &'static str)> {
+    let feature = if let Some(module) = path.strip_prefix("modules/") {
+        // e.g. `es.array.flat-map`, but not proposals like `esnext.array.group`.
+        let module = module.strip_suffix(".js").unwrap_or(module).strip_prefix("es.")?;
+        module.cow_replacen('.', "/", 1)
+    } else {
+        let (namespace, feature) = path.split_once('/')?;
+        if !CORE_JS_NAMESPACES.contains(&namespace) {
+            return None;
+        }
+        let feature = feature.strip_suffix(".js").unwrap_or(feature);
+        let feature = feature.strip_suffix("/index").unwrap_or(feature);
+        // `array/virtual/flat` polyfills `Array.prototype.flat` as well.
+        feature.cow_replacen("/virtual/", "/", 1)
+    };
+    POLYFILLS
+        .iter()
+        .find(|(name, _, _)| *name == feature.as_ref())
+        .map(|(name, _, since)| (*name, *since))
+}
+
+/// The JavaScript name of a polyfilled feature, e.g. `Array.prototype.flatMap` for `array/flat-map`.
+fn feature_name(feature: &str) -> String {
+    let to_camel_case = |name: &str, capitalize: bool| {
+        let mut camel = String::with_capacity(name.len());
+        let mut upper = capitalize;
+        for c in name.chars() {
+            if c == '-' {
+                upper = true;
+            } else if upper {
+                camel.push(c.to_ascii_uppercase());
+                upper = false;
+            } else {
+                camel.push(c);
+            }
+        }
+        camel
+    };
+    match feature.split_once('/') {
+        Some((object, method)) => {
+            let object = to_camel_case(object, true);
+            let method = to_camel_case(method, false);
+            if matches!(object.as_str(), "Array" | "String")
+                || (object == "Promise" && method == "finally")
+            {
+                format!("{object}.prototype.{method}")
+            } else {
+                format!("{object}.{method}")
+            }
+        }
+        None if feature == "global-this" || feature == "structured-clone" => {
+            to_camel_case(feature, false)
+        }
+        None => to_camel_case(feature, true),
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let node18 = || Some(json!([{ "targets": "node >=18" }]));
+    let node8 = || Some(json!([{ "targets": { "node": ">=8.0.0" } }]));
+
+    let pass = vec![
+        (r#"import "core-js/actual/array/from-async""#, node18()),
+        (r#"import "core-js/stable/array/flat""#, node8()),
+        (r#"import assign from "object-assign""#, None),
+        (r#"import "core-js/stable/array/flat""#, Some(json!([{ "targets": "> 1%, not dead" }]))),
+        (r#"import "core-js/proposals/array-grouping""#, node18()),
+        (r#"import "core-js/modules/esnext.array.group""#, node18()),
+        (r#"import "core-js""#, node18()),
+        (
+            r#"const padStart = require("string.prototype.padstart")"#,
+            Some(json!([{ "targets": "node >=7" }])),
+        ),
+        (r#"const flat = require("array.prototype.flat")"#, node8()),
+        (r#"import "object.hasown""#, Some(json!([{ "targets": "node >=16.0.0" }]))),
+        (r#"import lodash from "lodash""#, node18()),
+    ];
+
+    let fail = vec![
+        (r#"import "core-js/stable/array/flat""#, node18()),
+        (r#"import "core-js/es/promise""#, node8()),
+        (r#"import "core-js/features/array/virtual/flat-map""#, node18()),
+        (r#"import "core-js/modules/es.string.pad-start""#, node8()),
+        (r#"import "core-js/actual/global-this/index.js""#, node18()),
+        (r#"import assign from "object-assign""#, node8()),
+        (r#"const entries = require("object.entries")"#, node8()),
+        (r#"import "es6-promise/auto""#, node18()),
+        (r#"import "object.hasown""#, Some(json!([{ "targets": "node >=16.9.0, node >=18" }]))),
+        (r#"import structuredClone from "@ungap/structured-clone""#, node18()),
+    ];
+
+    Tester::new(NoUnnecessaryPolyfills::NAME, NoUnnecessaryPolyfills::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
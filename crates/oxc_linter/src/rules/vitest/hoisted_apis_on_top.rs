@@ -0,0 +1,294 @@
+use oxc_ast::{
+    AstKind,
+    ast::{CallExpression, Expression, Program, Statement},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, module_record::ImportImportName, rule::Rule};
+
+fn hoisted_api_not_on_top_diagnostic(span: Span, api: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`vi.{api}` is hoisted to the top of the file, but is called after other code."
+    ))
+    .with_help(format!(
+        "Move `vi.{api}` above the other code of the file, right after the imports, where it runs."
+    ))
+    .with_label(span)
+}
+
+fn hoisted_api_in_runtime_location_diagnostic(span: Span, api: &str) -> OxcDiagnostic {
+    let help = match api {
+        "mock" => {
+            "Move `vi.mock` to the top level of the file, or use `vi.doMock` to mock the module from here."
+        }
+        "unmock" => {
+            "Move `vi.unmock` to the top level of the file, or use `vi.doUnmock` to unmock the module from here."
+        }
+        _ => "Move `vi.hoisted` to the top level of the file.",
+    };
+    OxcDiagnostic::warn(format!(
+        "`vi.{api}` is hoisted to the top of the file, regardless of where it is called."
+    ))
+    .with_help(help)
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct HoistedApisOnTop;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that `vi.mock`, `vi.unmock` and `vi.hoisted` are called at the top level of the
+    /// file, before any code other than imports.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Vitest hoists these calls to the top of the file, so that they run before the imports.
+    /// Code which calls them inside a function or a condition, or after other statements,
+    /// suggests an order of execution that is not the one in which it actually runs, e.g.
+    /// a module which is mocked in only one test is mocked for every test of the file.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// import { vi } from 'vitest'
+    ///
+    /// if (process.env.CI) {
+    ///   vi.mock('./api')
+    /// }
+    ///
+    /// const value = 1
+    /// vi.mock('./path', () => ({ value }))
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// import { vi } from 'vitest'
+    ///
+    /// vi.mock('./api')
+    /// const { value } = vi.hoisted(() => ({ value: 1 }))
+    /// vi.mock('./path', () => ({ value }))
+    ///
+    /// test('foo', () => {
+    ///   vi.doMock('./other')
+    /// })
+    /// ```
+    HoistedApisOnTop,
+    vitest,
+    style,
+    fix_suggestion
+);
+
+impl Rule for HoistedApisOnTop {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some((api, property_span)) = get_hoisted_api(call, ctx) else {
+            return;
+        };
+
+        let mut statement_span = None;
+        for ancestor in ctx.nodes().ancestors(node.id()) {
+            match ancestor.kind() {
+                AstKind::Program(program) => {
+                    if let Some(statement_span) = statement_span {
+                        check_top_level_call(program, statement_span, call, api, ctx);
+                    }
+                    return;
+                }
+                AstKind::ExpressionStatement(statement) => statement_span = Some(statement.span),
+                AstKind::VariableDeclaration(declaration) => {
+                    statement_span = Some(declaration.span);
+                }
+                AstKind::AwaitExpression(_)
+                | AstKind::ParenthesizedExpression(_)
+                | AstKind::VariableDeclarator(_) => {}
+                _ => break,
+            }
+        }
+
+        let diagnostic = hoisted_api_in_runtime_location_diagnostic(call.span, api);
+        let replacement = match api {
+            "mock" => "doMock",
+            "unmock" => "doUnmock",
+            _ => {
+                ctx.diagnostic(diagnostic);
+                return;
+            }
+        };
+        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+            fixer
+                .replace(property_span, replacement)
+                .with_message(format!("Replace `vi.{api}` with `vi.{replacement}`"))
+        });
+    }
+}
+
+/// Reports a hoisted API called at the top level of the file, in the statement at
+/// `statement_span`, if it comes after code other than imports and other hoisted API calls.
+fn check_top_level_call<'a>(
+    program: &Program<'a>,
+    statement_span: Span,
+    call: &CallExpression<'a>,
+    api: &str,
+    ctx: &LintContext<'a>,
+) {
+    let Some(first_code) = program
+        .body
+        .iter()
+        .take_while(|statement| statement.span() != statement_span)
+        .find(|statement| !is_import(statement) && !is_hoisted_api_statement(statement, ctx))
+    else {
+        return;
+    };
+
+    ctx.diagnostic_with_fix(hoisted_api_not_on_top_diagnostic(call.span, api), |fixer| {
+        let fixer = fixer.for_multifix();
+        let source_text = ctx.source_text();
+        let statement_text = statement_span.source_text(source_text);
+        // Also delete the line break after the statement, to not leave an empty line behind.
+        let end = statement_span.end as usize;
+        let end = if source_text[end..].starts_with("\r\n") {
+            end + 2
+        } else if source_text[end..].starts_with('\n') {
+            end + 1
+        } else {
+            end
+        };
+        #[expect(clippy::cast_possible_truncation)]
+        let delete_span = Span::new(statement_span.start, end as u32);
+
+        let mut fix = fixer.new_fix_with_capacity(2);
+        fix.push(fixer.insert_text_before_range(first_code.span(), format!("{statement_text}\n")));
+        fix.push(fixer.delete_range(delete_span));
+        fix.with_message(format!("Move `vi.{api}` above the other code of the file"))
+    });
+}
+
+fn is_import(statement: &Statement) -> bool {
+    matches!(statement, Statement::ImportDeclaration(_) | Statement::TSImportEqualsDeclaration(_))
+}
+
+/// Whether `statement` is a call of a hoisted API, e.g. `vi.mock('./foo')`, or
+/// `const { foo } = vi.hoisted(() => ({ foo: 1 }))`.
+fn is_hoisted_api_statement<'a>(statement: &Statement<'a>, ctx: &LintContext<'a>) -> bool {
+    let expression = match statement {
+        Statement::ExpressionStatement(statement) => &statement.expression,
+        Statement::VariableDeclaration(declaration) => match declaration.declarations.as_slice() {
+            [declarator] => match &declarator.init {
+                Some(init) => init,
+                None => return false,
+            },
+            _ => return false,
+        },
+        _ => return false,
+    };
+    let expression = match expression.without_parentheses() {
+        Expression::AwaitExpression(await_expression) => {
+            await_expression.argument.without_parentheses()
+        }
+        expression => expression,
+    };
+    matches!(expression, Expression::CallExpression(call) if get_hoisted_api(call, ctx).is_some())
+}
+
+/// The name of the hoisted API called by `call`, e.g. `mock` for `vi.mock('./foo')`, and the
+/// span of the name.
+fn get_hoisted_api<'a>(
+    call: &CallExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<(&'a str, Span)> {
+    let Expression::StaticMemberExpression(member) = call.callee.without_parentheses() else {
+        return None;
+    };
+    let Expression::Identifier(object) = &member.object else {
+        return None;
+    };
+    let is_vitest_object = if ctx.is_reference_to_global_variable(object) {
+        matches!(object.name.as_str(), "vi" | "vitest")
+    } else {
+        ctx.module_record().import_entries.iter().any(|entry| {
+            entry.module_request.name() == "vitest"
+                && entry.local_name.name() == object.name
+                && matches!(
+                    &entry.import_name,
+                    ImportImportName::Name(name) if matches!(name.name(), "vi" | "vitest")
+                )
+        })
+    };
+    if !is_vitest_object {
+        return None;
+    }
+    let api = member.property.name.as_str();
+    matches!(api, "mock" | "unmock" | "hoisted").then_some((api, member.property.span))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r"vi.mock('./foo')", None),
+        (
+            r"
+            import { vi } from 'vitest'
+            import { foo } from './foo'
+            vi.mock('./foo')
+            vi.unmock('./bar')
+            const { value } = vi.hoisted(() => ({ value: 1 }))
+            vi.mock('./path', () => ({ value }))
+            test('foo', () => {})
+            ",
+            None,
+        ),
+        (r"const mocks = await vi.hoisted(async () => ({}))", None),
+        (r"test('foo', () => { vi.doMock('./foo') })", None),
+        (r"test('foo', () => { vi.fn() })", None),
+        (r"function setup(vi) { vi.mock('./foo') }", None),
+        (r"import { vi } from './utils'; const a = 1; vi.mock('./foo')", None),
+        (r"const a = 1; jest.mock('./foo')", None),
+    ];
+
+    let fail = vec![
+        (r"test('foo', () => { vi.mock('./foo') })", None),
+        (r"if (process.env.CI) { vi.mock('./foo') }", None),
+        (r"beforeEach(() => { vi.unmock('./foo') })", None),
+        (r"function setup() { const value = vi.hoisted(() => 1) }", None),
+        (r"import { vi as v } from 'vitest'; describe('foo', () => { v.mock('./foo') })", None),
+        (r"const value = 1; vi.mock('./foo', () => ({ value }))", None),
+        (
+            r"
+            import { foo } from './foo'
+            foo()
+            const { value } = vi.hoisted(() => ({ value: 1 }))
+            ",
+            None,
+        ),
+    ];
+
+    let fix = vec![
+        (r"test('foo', () => { vi.mock('./foo') })", r"test('foo', () => { vi.doMock('./foo') })"),
+        (
+            r"beforeEach(() => { vi.unmock('./foo') })",
+            r"beforeEach(() => { vi.doUnmock('./foo') })",
+        ),
+        (
+            "import { foo } from './foo'\nconst value = 1\nvi.mock('./bar')\n",
+            "import { foo } from './foo'\nvi.mock('./bar')\nconst value = 1\n",
+        ),
+        (
+            "vi.mock('./foo')\nfoo()\nvi.unmock('./bar')\n",
+            "vi.mock('./foo')\nvi.unmock('./bar')\nfoo()\n",
+        ),
+    ];
+
+    Tester::new(HoistedApisOnTop::NAME, HoistedApisOnTop::PLUGIN, pass, fail)
+        .with_vitest_plugin(true)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
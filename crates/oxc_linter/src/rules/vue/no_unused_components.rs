@@ -0,0 +1,334 @@
+use oxc_ast::{
+    AstKind,
+    ast::{ExportDefaultDeclarationKind, Expression, ObjectExpression, ObjectPropertyKind},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    frameworks::FrameworkOptions,
+    loader::Template,
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn no_unused_components_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("The \"{name}\" component has been registered but not used."))
+        .with_help("Remove the component from `components`, or use it in the template.")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoUnusedComponents {
+    /// Don't report any component when the template renders a dynamic component, like
+    /// `<component :is="name" />`, as it may render any of the registered components.
+    ignore_when_binding_present: bool,
+}
+
+impl Default for NoUnusedComponents {
+    fn default() -> Self {
+        Self { ignore_when_binding_present: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow registering components in the `components` option that are not used in the
+    /// `<template>`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unused components are dead code: they add imports and bundle size, and make readers
+    /// look for where they are used.
+    ///
+    /// Components are matched by name in PascalCase or kebab-case, and through the `is`
+    /// attribute, e.g. `<component is="TheButton" />`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```vue
+    /// <template>
+    ///   <h2>Lorem ipsum</h2>
+    /// </template>
+    ///
+    /// <script>
+    /// import TheButton from 'components/TheButton.vue'
+    ///
+    /// export default {
+    ///   components: {
+    ///     TheButton,
+    ///   },
+    /// }
+    /// </script>
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```vue
+    /// <template>
+    ///   <the-button>Lorem ipsum</the-button>
+    /// </template>
+    ///
+    /// <script>
+    /// import TheButton from 'components/TheButton.vue'
+    ///
+    /// export default {
+    ///   components: {
+    ///     TheButton,
+    ///   },
+    /// }
+    /// </script>
+    /// ```
+    NoUnusedComponents,
+    vue,
+    suspicious,
+    config = NoUnusedComponents,
+);
+
+impl Rule for NoUnusedComponents {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).unwrap_or_default().into_inner()
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(components) = ctx.nodes().iter().find_map(|node| match node.kind() {
+            AstKind::ExportDefaultDeclaration(export) => components_option(&export.declaration),
+            _ => None,
+        }) else {
+            return;
+        };
+        let Some(template) = ctx.template() else {
+            return;
+        };
+        let Some(used) = used_component_names(template, self.ignore_when_binding_present) else {
+            return;
+        };
+
+        for property in &components.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else {
+                continue;
+            };
+            let Some(name) = property.key.static_name() else {
+                continue;
+            };
+            if !used.contains(&kebab_case(&name)) {
+                ctx.diagnostic(no_unused_components_diagnostic(property.key.span(), &name));
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        // The `components` option is only in a `<script>` block, not in `<script setup>`.
+        ctx.file_extension().is_some_and(|ext| ext == "vue")
+            && ctx.frameworks_options() != FrameworkOptions::VueSetup
+            && ctx.template().is_some()
+    }
+}
+
+/// The `components` option of `export default { ... }` or `export default defineComponent({ ... })`.
+fn components_option<'a>(
+    declaration: &'a ExportDefaultDeclarationKind<'a>,
+) -> Option<&'a ObjectExpression<'a>> {
+    let options = match declaration {
+        ExportDefaultDeclarationKind::ObjectExpression(options) => options,
+        ExportDefaultDeclarationKind::CallExpression(call)
+            if call
+                .callee
+                .get_identifier_reference()
+                .is_some_and(|callee| callee.name == "defineComponent") =>
+        {
+            let Some(Expression::ObjectExpression(options)) =
+                call.arguments.first().and_then(|argument| argument.as_expression())
+            else {
+                return None;
+            };
+            options
+        }
+        _ => return None,
+    };
+    options.properties.iter().find_map(|property| match property {
+        ObjectPropertyKind::ObjectProperty(property)
+            if property.key.is_specific_static_name("components") =>
+        {
+            match property.value.get_inner_expression() {
+                Expression::ObjectExpression(components) => Some(&**components),
+                _ => None,
+            }
+        }
+        _ => None,
+    })
+}
+
+/// Names of the elements and `is` components of the template, in kebab-case.
+///
+/// Returns `None` when a dynamic `:is` binding is present and should disable the rule.
+fn used_component_names(
+    template: &Template,
+    ignore_when_binding_present: bool,
+) -> Option<FxHashSet<String>> {
+    let mut used = FxHashSet::default();
+    for element in template.elements() {
+        used.insert(kebab_case(element.name));
+        if let Some(is) = element.attribute("is").and_then(|attribute| attribute.value) {
+            used.insert(kebab_case(is.strip_prefix("vue:").unwrap_or(is)));
+        }
+        if let Some(is) = element.directive("bind", Some("is")) {
+            let value = is.value.unwrap_or_default().trim();
+            match value.strip_prefix(['\'', '"', '`']).and_then(|value| {
+                value.strip_suffix(['\'', '"', '`']).filter(|value| !value.contains("${"))
+            }) {
+                Some(name) => {
+                    used.insert(kebab_case(name));
+                }
+                None if ignore_when_binding_present => return None,
+                None => {}
+            }
+        }
+    }
+    Some(used)
+}
+
+fn kebab_case(name: &str) -> String {
+    let mut kebab = String::with_capacity(name.len() + 2);
+    for (i, c) in name.char_indices() {
+        if c.is_ascii_uppercase() {
+            if i > 0 && !kebab.ends_with('-') {
+                kebab.push('-');
+            }
+            kebab.push(c.to_ascii_lowercase());
+        } else if c == '_' {
+            kebab.push('-');
+        } else {
+            kebab.push(c);
+        }
+    }
+    kebab
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use std::path::PathBuf;
+
+    let pass = vec![
+        (
+            r"<template><TheButton /></template>
+            <script>
+            import TheButton from './TheButton.vue'
+            export default { components: { TheButton } }
+            </script>",
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r"<template><div><the-button>Ok</the-button></div></template>
+            <script>
+            import TheButton from './TheButton.vue'
+            export default { components: { TheButton } }
+            </script>",
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r"<template><my-button /></template>
+            <script>
+            import { defineComponent } from 'vue'
+            import TheButton from './TheButton.vue'
+            export default defineComponent({ components: { 'my-button': TheButton } })
+            </script>",
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r#"<template><component is="TheButton" /><div is="vue:TheInput" /></template>
+            <script>
+            export default { components: { TheButton, TheInput } }
+            </script>"#,
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r#"<template><component :is="'TheButton'" /></template>
+            <script>
+            export default { components: { TheButton } }
+            </script>"#,
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r#"<template><component :is="current" /></template>
+            <script>
+            export default { components: { TheButton, TheInput } }
+            </script>"#,
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r"<template><h2>Lorem ipsum</h2></template>
+            <script setup>
+            import TheButton from './TheButton.vue'
+            </script>",
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r"<script>
+            export default { components: { TheButton } }
+            </script>",
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+    ];
+
+    let fail = vec![
+        (
+            r"<template><h2>Lorem ipsum</h2></template>
+            <script>
+            import TheButton from './TheButton.vue'
+            export default { components: { TheButton } }
+            </script>",
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r"<template>
+              <!-- <the-input /> -->
+              <the-button />
+            </template>
+            <script>
+            import { defineComponent } from 'vue'
+            export default defineComponent({ components: { TheButton, TheInput, 'the-select': TheSelect } })
+            </script>",
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r#"<template><component :is="current" /></template>
+            <script>
+            export default { components: { TheButton } }
+            </script>"#,
+            Some(serde_json::json!([{ "ignoreWhenBindingPresent": false }])),
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+    ];
+
+    Tester::new(NoUnusedComponents::NAME, NoUnusedComponents::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,209 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    loader::{Template, TemplateElement, TemplateElementId},
+    rule::Rule,
+};
+
+fn require_v_for_key_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Elements in iteration expect to have 'v-bind:key' directives.")
+        .with_help("Add a `:key` which is unique for each item, e.g. `:key=\"item.id\"`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RequireVForKey;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require `v-bind:key` on elements rendered with `v-for`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Without a key, Vue reuses elements in place when the list changes, which breaks the
+    /// state of the elements, like the value of an `<input>` or a running transition, as it
+    /// stays at the same position instead of moving with its item.
+    ///
+    /// Elements in a `<template v-for>` need a key themselves, unless the `<template>` has one.
+    /// Custom components are left to `vue/valid-v-for`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```vue
+    /// <template>
+    ///   <div v-for="todo in todos">{{ todo.text }}</div>
+    /// </template>
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```vue
+    /// <template>
+    ///   <div v-for="todo in todos" :key="todo.id">{{ todo.text }}</div>
+    /// </template>
+    /// ```
+    RequireVForKey,
+    vue,
+    correctness,
+);
+
+impl Rule for RequireVForKey {
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(template) = ctx.template() else {
+            return;
+        };
+        for (id, element) in template.elements().iter().enumerate() {
+            if element.directive("for", None).is_some() {
+                check_key(template, id, ctx);
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        // The template is shared by all `<script>` blocks, check it once.
+        ctx.is_first_sub_host() && ctx.template().is_some()
+    }
+}
+
+fn check_key(template: &Template, id: TemplateElementId, ctx: &LintContext) {
+    let element = &template.elements()[id];
+    if element.directive("bind", Some("key")).is_some() {
+        return;
+    }
+    if matches!(element.name, "template" | "slot") {
+        for (child_id, child) in template.children(id) {
+            // Children with their own `v-for` are checked on their own.
+            if child.directive("for", None).is_none() {
+                check_key(template, child_id, ctx);
+            }
+        }
+    } else if !is_custom_component(element) {
+        ctx.template_diagnostic(require_v_for_key_diagnostic(element.span));
+    }
+}
+
+fn is_custom_component(element: &TemplateElement) -> bool {
+    element.name.contains('-')
+        || element.name.starts_with(|c: char| c.is_ascii_uppercase())
+        || element.attribute("is").is_some()
+        || element.directive("bind", Some("is")).is_some()
+        || element.directive("is", None).is_some()
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use std::path::PathBuf;
+
+    let pass = vec![
+        (
+            r#"<template><div v-for="item in items" :key="item.id">{{ item }}</div></template>
+            <script>export default {}</script>"#,
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r#"<template><div v-for="item in items" v-bind:key="item.id"></div></template>
+            <script>export default {}</script>"#,
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r#"<template>
+              <template v-for="item in items">
+                <div :key="item.id"></div>
+              </template>
+            </template>
+            <script>export default {}</script>"#,
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r#"<template>
+              <template v-for="item in items" :key="item.id">
+                <div></div>
+              </template>
+            </template>
+            <script setup>const items = []</script>"#,
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r#"<template><custom-component v-for="item in items" /></template>
+            <script>export default {}</script>"#,
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r#"<template><TodoItem v-for="item in items" /></template>
+            <script>export default {}</script>"#,
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r#"<template>
+              <!-- <div v-for="item in items"></div> -->
+              <div>{{ "v-for" }}</div>
+            </template>
+            <script>export default {}</script>"#,
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+    ];
+
+    let fail = vec![
+        (
+            r#"<template><div v-for="item in items">{{ item }}</div></template>
+            <script>export default {}</script>"#,
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r#"<template>
+              <template v-for="item in items">
+                <div></div>
+                <span :key="item.id"></span>
+              </template>
+            </template>
+            <script>export default {}</script>"#,
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r#"<script setup>const items = []</script>
+            <template>
+              <ul>
+                <li v-for="item in items" :class="item.class" @click="select(item)">
+                  <input v-for="field in item.fields">
+                </li>
+              </ul>
+            </template>"#,
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+        (
+            r#"<script>export default {}</script>
+            <script setup>const items = []</script>
+            <template><p v-for="item in items" /></template>"#,
+            None,
+            None,
+            Some(PathBuf::from("test.vue")),
+        ),
+    ];
+
+    Tester::new(RequireVForKey::NAME, RequireVForKey::PLUGIN, pass, fail).test_and_snapshot();
+}
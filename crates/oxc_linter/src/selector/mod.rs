@@ -0,0 +1,666 @@
+//! Selectors for matching AST nodes, using the syntax of [esquery].
+//!
+//! Selectors are matched against the [ESTree] representation of the AST, which is the AST seen by
+//! ESLint rules and JS plugins. Selectors written for ESLint's `no-restricted-syntax` rule or for
+//! JS plugin visitors can therefore be used unchanged.
+//!
+//! Supported syntax:
+//!
+//! * Node types and the wildcard: `Identifier`, `*`
+//! * Attributes: `[attr]`, `[attr.nested="value"]`, `[attr!=value]`, `[attr=/regex/i]`,
+//!   `[attr.length>2]`, `[attr.0<=2]`
+//! * Combinators: `A B` (descendant), `A > B` (child), `A ~ B` (sibling), `A + B` (adjacent)
+//! * Alternatives: `A, B`
+//! * Pseudo-classes: `:has(...)`, `:not(...)`, `:matches(...)` (or `:is(...)`), `:first-child`,
+//!   `:last-child`, `:nth-child(n)`, `:nth-last-child(n)`
+//! * Node classes: `:statement`, `:expression`, `:declaration`, `:function`, `:pattern`
+//!
+//! ```
+//! use oxc_linter::selector::{EstreeTree, Selector};
+//!
+//! let program = serde_json::json!({
+//!     "type": "Program", "start": 0, "end": 7,
+//!     "body": [{
+//!         "type": "ExpressionStatement", "start": 0, "end": 7,
+//!         "expression": {
+//!             "type": "CallExpression", "start": 0, "end": 6,
+//!             "callee": { "type": "Identifier", "start": 0, "end": 4, "name": "eval" },
+//!             "arguments": [],
+//!         },
+//!     }],
+//! });
+//! let tree = EstreeTree::new(&program);
+//! let selector = Selector::parse("CallExpression[callee.name='eval']").unwrap();
+//! let spans = selector.query(&tree).map(|node| node.span()).collect::<Vec<_>>();
+//! assert_eq!(spans, [oxc_span::Span::new(0, 6)]);
+//! ```
+//!
+//! [esquery]: https://github.com/estools/esquery
+//! [ESTree]: https://github.com/estree/estree
+
+mod parser;
+
+use std::{borrow::Cow, cmp::Ordering};
+
+use lazy_regex::Regex;
+use rustc_hash::FxHashSet;
+use serde_json::{Map, Value};
+
+use oxc_ast::ast::Program;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{CompactStr, Span};
+
+/// ESTree representation of a program serialized to JSON, from which an [`EstreeTree`] is built.
+///
+/// TypeScript syntax is included if the program is TypeScript. Spans are UTF-8 byte offsets, like
+/// [`Span`].
+#[derive(Debug)]
+pub struct EstreeJson {
+    json: String,
+}
+
+impl EstreeJson {
+    /// Serializes `program` to ESTree JSON.
+    pub fn new(program: &Program) -> Self {
+        let json = if program.source_type.is_typescript() {
+            program.to_estree_ts_json(false)
+        } else {
+            program.to_estree_js_json(false)
+        };
+        Self { json }
+    }
+
+    /// Types of all nodes in the AST, found without parsing the JSON.
+    pub fn node_types(&self) -> FxHashSet<&str> {
+        const TYPE_KEY: &str = "\"type\":\"";
+        self.json
+            .match_indices(TYPE_KEY)
+            .filter_map(|(start, _)| {
+                let rest = &self.json[start + TYPE_KEY.len()..];
+                rest.find('"').map(|end| &rest[..end])
+            })
+            .collect()
+    }
+
+    /// Parses the JSON into a value for [`EstreeTree::new`].
+    ///
+    /// Returns `None` if the AST is nested deeper than the recursion limit of `serde_json`, which
+    /// protects against stack overflows in the code handling the value, including its `Drop`.
+    pub fn parse(&self) -> Option<Value> {
+        serde_json::from_str(&self.json).ok()
+    }
+}
+
+/// A node of an [`EstreeTree`], i.e. a JSON object with a `type`.
+#[derive(Debug, Clone, Copy)]
+pub struct EstreeNode<'v> {
+    value: &'v Map<String, Value>,
+}
+
+impl<'v> EstreeNode<'v> {
+    /// Node type, e.g. `"CallExpression"`.
+    pub fn node_type(self) -> &'v str {
+        self.value["type"].as_str().unwrap_or_default()
+    }
+
+    /// Source range of the node, from its `start` and `end` properties.
+    pub fn span(self) -> Span {
+        let offset =
+            |key| self.value.get(key).and_then(Value::as_u64).and_then(|n| u32::try_from(n).ok());
+        Span::new(offset("start").unwrap_or_default(), offset("end").unwrap_or_default())
+    }
+
+    /// Properties of the node.
+    pub fn value(self) -> &'v Map<String, Value> {
+        self.value
+    }
+}
+
+/// ESTree AST prepared for matching selectors.
+///
+/// Nodes are stored in pre-order, so the descendants of a node directly follow it.
+#[derive(Debug)]
+pub struct EstreeTree<'v> {
+    nodes: Vec<TreeNode<'v>>,
+    /// Arrays of nodes, e.g. the `body` of a `BlockStatement`. `None` for elements which are not
+    /// nodes, such as holes in array patterns.
+    lists: Vec<Vec<Option<usize>>>,
+}
+
+#[derive(Debug)]
+struct TreeNode<'v> {
+    node: EstreeNode<'v>,
+    parent: Option<usize>,
+    /// Index after the last descendant of this node.
+    subtree_end: usize,
+    /// Index of the array containing this node in `lists`, and its position in that array.
+    list: Option<(usize, usize)>,
+}
+
+impl<'v> EstreeTree<'v> {
+    /// Builds the tree of all nodes in `root`, which is usually a `Program`.
+    pub fn new(root: &'v Value) -> Self {
+        let mut tree = Self { nodes: vec![], lists: vec![] };
+        tree.add_value(root, None, None);
+        tree
+    }
+
+    /// All nodes of the tree, in pre-order.
+    pub fn nodes(&self) -> impl Iterator<Item = EstreeNode<'v>> + '_ {
+        self.nodes.iter().map(|node| node.node)
+    }
+
+    fn add_value(
+        &mut self,
+        value: &'v Value,
+        parent: Option<usize>,
+        list: Option<(usize, usize)>,
+    ) -> Option<usize> {
+        match value {
+            Value::Object(object) if object.get("type").is_some_and(Value::is_string) => {
+                let id = self.nodes.len();
+                self.nodes.push(TreeNode {
+                    node: EstreeNode { value: object },
+                    parent,
+                    subtree_end: id + 1,
+                    list,
+                });
+                self.add_children(object, Some(id));
+                self.nodes[id].subtree_end = self.nodes.len();
+                Some(id)
+            }
+            Value::Object(object) => {
+                self.add_children(object, parent);
+                None
+            }
+            Value::Array(array) => {
+                let list_id = self.lists.len();
+                self.lists.push(vec![]);
+                let elements = array
+                    .iter()
+                    .enumerate()
+                    .map(|(i, element)| self.add_value(element, parent, Some((list_id, i))))
+                    .collect();
+                self.lists[list_id] = elements;
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn add_children(&mut self, object: &'v Map<String, Value>, parent: Option<usize>) {
+        for value in object.values() {
+            self.add_value(value, parent, None);
+        }
+    }
+
+    /// Parent of `id`, unless `id` is `scope`.
+    fn parent(&self, id: usize, scope: Option<usize>) -> Option<usize> {
+        if Some(id) == scope { None } else { self.nodes[id].parent }
+    }
+
+    /// Ancestors of `id`, up to and including `scope`.
+    fn ancestors(&self, id: usize, scope: Option<usize>) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(self.parent(id, scope), move |&id| self.parent(id, scope))
+    }
+
+    /// Siblings before `id` in the array containing it, nearest first.
+    fn previous_siblings(
+        &self,
+        id: usize,
+        scope: Option<usize>,
+    ) -> impl Iterator<Item = usize> + '_ {
+        let siblings = self.nodes[id]
+            .list
+            .filter(|_| Some(id) != scope)
+            .map(|(list, position)| &self.lists[list][..position])
+            .unwrap_or_default();
+        siblings.iter().rev().flatten().copied()
+    }
+}
+
+/// A parsed selector, which can be matched against nodes of an [`EstreeTree`].
+#[derive(Debug, Clone)]
+pub struct Selector {
+    source: CompactStr,
+    alternatives: Vec<Complex>,
+}
+
+impl Selector {
+    /// Parses `source` into a selector.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `source` is not a valid selector.
+    pub fn parse(source: &str) -> Result<Self, OxcDiagnostic> {
+        let alternatives = parser::parse(source).map_err(|reason| {
+            OxcDiagnostic::error(format!("Invalid selector `{source}`: {reason}"))
+        })?;
+        Ok(Self { source: CompactStr::new(source), alternatives })
+    }
+
+    /// The selector as written.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Whether this selector can match a node in an AST with nodes of `node_types`, e.g. from
+    /// [`EstreeJson::node_types`]. Only the type of the matched node is considered, so this may
+    /// return `true` even if no node matches.
+    pub fn may_match(&self, node_types: &FxHashSet<&str>) -> bool {
+        self.alternatives.iter().any(|selector| {
+            selector.subject().types().is_none_or(|types| {
+                types.iter().any(|expected| {
+                    node_types.iter().any(|node_type| node_type.eq_ignore_ascii_case(expected))
+                })
+            })
+        })
+    }
+
+    /// Nodes of `tree` matching this selector, in pre-order.
+    pub fn query<'t, 'v>(
+        &'t self,
+        tree: &'t EstreeTree<'v>,
+    ) -> impl Iterator<Item = EstreeNode<'v>> + 't {
+        (0..tree.nodes.len())
+            .filter(|&id| matches_any(&self.alternatives, tree, id, None))
+            .map(|id| tree.nodes[id].node)
+    }
+}
+
+/// Selector without top-level commas, e.g. `A > B C`.
+#[derive(Debug, Clone)]
+struct Complex {
+    /// Compound selectors and the combinators preceding them. The first combinator is only
+    /// present in relative selectors, e.g. `> B` in `A:has(> B)`.
+    parts: Vec<(Option<Combinator>, Compound)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Combinator {
+    Descendant,
+    Child,
+    Sibling,
+    Adjacent,
+}
+
+/// Simple selectors which all have to match the same node, e.g. `Identifier[name="foo"]`.
+#[derive(Debug, Clone)]
+struct Compound {
+    parts: Vec<Simple>,
+}
+
+#[derive(Debug, Clone)]
+enum Simple {
+    Wildcard,
+    Type(CompactStr),
+    Attribute {
+        path: Vec<CompactStr>,
+        test: Option<(AttributeOperator, AttributeValue)>,
+    },
+    Has(Vec<Complex>),
+    Not(Vec<Complex>),
+    Matches(Vec<Complex>),
+    /// 1-based position from the start of the array containing the node.
+    NthChild(usize),
+    /// 1-based position from the end of the array containing the node.
+    NthLastChild(usize),
+    Class(NodeClass),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum AttributeOperator {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+#[derive(Debug, Clone)]
+enum AttributeValue {
+    String(String),
+    Number(f64),
+    Regex(Regex),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum NodeClass {
+    Statement,
+    Expression,
+    Declaration,
+    Function,
+    Pattern,
+}
+
+/// Whether any of `selectors` matches node `id`.
+///
+/// `scope` is the node whose subtree is searched by `:has`. Nodes outside of it are ignored, and
+/// relative selectors are relative to it.
+fn matches_any(selectors: &[Complex], tree: &EstreeTree, id: usize, scope: Option<usize>) -> bool {
+    selectors.iter().any(|selector| selector.matches(tree, id, selector.parts.len() - 1, scope))
+}
+
+impl Complex {
+    /// The compound selector which has to match the selected node, e.g. `C` in `A > B C`.
+    fn subject(&self) -> &Compound {
+        &self.parts[self.parts.len() - 1].1
+    }
+
+    /// Whether `parts[..=index]` matches node `id`.
+    fn matches(&self, tree: &EstreeTree, id: usize, index: usize, scope: Option<usize>) -> bool {
+        let (combinator, compound) = &self.parts[index];
+        if !compound.matches(tree, id, scope) {
+            return false;
+        }
+        let Some(combinator) = combinator else { return true };
+        let matches_previous = |previous: usize| {
+            if index == 0 {
+                Some(previous) == scope
+            } else {
+                self.matches(tree, previous, index - 1, scope)
+            }
+        };
+        match combinator {
+            Combinator::Descendant => tree.ancestors(id, scope).any(matches_previous),
+            Combinator::Child => tree.parent(id, scope).is_some_and(matches_previous),
+            Combinator::Sibling => tree.previous_siblings(id, scope).any(matches_previous),
+            Combinator::Adjacent => {
+                tree.previous_siblings(id, scope).next().is_some_and(matches_previous)
+            }
+        }
+    }
+}
+
+impl Compound {
+    /// Types one of which a matching node must have, or `None` if it can have any type.
+    fn types(&self) -> Option<Vec<&str>> {
+        self.parts.iter().find_map(|simple| match simple {
+            Simple::Type(node_type) => Some(vec![node_type.as_str()]),
+            Simple::Matches(selectors) => selectors
+                .iter()
+                .map(|selector| selector.subject().types())
+                .collect::<Option<Vec<_>>>()
+                .map(|types| types.concat()),
+            _ => None,
+        })
+    }
+
+    fn matches(&self, tree: &EstreeTree, id: usize, scope: Option<usize>) -> bool {
+        self.parts.iter().all(|simple| simple.matches(tree, id, scope))
+    }
+}
+
+impl Simple {
+    fn matches(&self, tree: &EstreeTree, id: usize, scope: Option<usize>) -> bool {
+        let node = tree.nodes[id].node;
+        match self {
+            Self::Wildcard => true,
+            Self::Type(node_type) => node.node_type().eq_ignore_ascii_case(node_type),
+            Self::Attribute { path, test } => {
+                let value = get_path(node.value, path);
+                let value = value.as_deref();
+                match test {
+                    None => value.is_some_and(|value| !value.is_null()),
+                    Some((operator, expected)) => test_attribute(value, *operator, expected),
+                }
+            }
+            Self::Has(selectors) => (id + 1..tree.nodes[id].subtree_end)
+                .any(|descendant| matches_any(selectors, tree, descendant, Some(id))),
+            Self::Not(selectors) => !matches_any(selectors, tree, id, scope),
+            Self::Matches(selectors) => matches_any(selectors, tree, id, scope),
+            Self::NthChild(n) => {
+                tree.nodes[id].list.is_some_and(|(_, position)| position + 1 == *n)
+            }
+            Self::NthLastChild(n) => tree.nodes[id]
+                .list
+                .is_some_and(|(list, position)| tree.lists[list].len() - position == *n),
+            Self::Class(class) => {
+                let node_type = node.node_type();
+                let is_expression = || {
+                    node_type.ends_with("Expression")
+                        || node_type.ends_with("Literal")
+                        || node_type == "MetaProperty"
+                        || (node_type == "Identifier"
+                            && tree.nodes[id].parent.is_none_or(|parent| {
+                                tree.nodes[parent].node.node_type() != "MetaProperty"
+                            }))
+                };
+                match class {
+                    NodeClass::Statement => {
+                        node_type.ends_with("Statement") || node_type.ends_with("Declaration")
+                    }
+                    NodeClass::Declaration => node_type.ends_with("Declaration"),
+                    NodeClass::Function => matches!(
+                        node_type,
+                        "FunctionDeclaration" | "FunctionExpression" | "ArrowFunctionExpression"
+                    ),
+                    NodeClass::Pattern => node_type.ends_with("Pattern") || is_expression(),
+                    NodeClass::Expression => is_expression(),
+                }
+            }
+        }
+    }
+}
+
+/// Value at `path` in `object`. Like in JavaScript, arrays have a `length`.
+fn get_path<'v>(object: &'v Map<String, Value>, path: &[CompactStr]) -> Option<Cow<'v, Value>> {
+    let (first, rest) = path.split_first()?;
+    rest.iter().try_fold(Cow::Borrowed(object.get(first.as_str())?), |value, key| {
+        let Cow::Borrowed(value) = value else { return None };
+        match value {
+            Value::Object(object) => object.get(key.as_str()).map(Cow::Borrowed),
+            Value::Array(array) if key == "length" => Some(Cow::Owned(array.len().into())),
+            Value::Array(array) => {
+                key.parse::<usize>().ok().and_then(|i| array.get(i)).map(Cow::Borrowed)
+            }
+            _ => None,
+        }
+    })
+}
+
+/// Compares an attribute with the semantics of esquery, which compares values by their string
+/// representation in JavaScript.
+fn test_attribute(
+    value: Option<&Value>,
+    operator: AttributeOperator,
+    expected: &AttributeValue,
+) -> bool {
+    match (operator, expected) {
+        (AttributeOperator::Eq, AttributeValue::Regex(regex)) => {
+            value.and_then(Value::as_str).is_some_and(|value| regex.is_match(value))
+        }
+        (AttributeOperator::NotEq, AttributeValue::Regex(regex)) => {
+            !value.and_then(Value::as_str).is_some_and(|value| regex.is_match(value))
+        }
+        (AttributeOperator::Eq, expected) => js_string(value) == expected_string(expected),
+        (AttributeOperator::NotEq, expected) => js_string(value) != expected_string(expected),
+        (operator, expected) => {
+            let ordering = match (value, expected) {
+                (Some(Value::String(value)), AttributeValue::String(expected)) => {
+                    Some(value.as_str().cmp(expected.as_str()))
+                }
+                (Some(value), expected) => {
+                    let expected = match expected {
+                        AttributeValue::Number(number) => Some(*number),
+                        AttributeValue::String(string) => string.parse().ok(),
+                        AttributeValue::Regex(_) => None,
+                    };
+                    let value = match value {
+                        Value::Number(number) => number.as_f64(),
+                        Value::String(string) => string.parse().ok(),
+                        _ => None,
+                    };
+                    value.zip(expected).and_then(|(value, expected)| value.partial_cmp(&expected))
+                }
+                (None, _) => None,
+            };
+            ordering.is_some_and(|ordering| match operator {
+                AttributeOperator::Lt => ordering == Ordering::Less,
+                AttributeOperator::LtEq => ordering != Ordering::Greater,
+                AttributeOperator::Gt => ordering == Ordering::Greater,
+                AttributeOperator::GtEq => ordering != Ordering::Less,
+                AttributeOperator::Eq | AttributeOperator::NotEq => unreachable!(),
+            })
+        }
+    }
+}
+
+fn js_string(value: Option<&Value>) -> String {
+    match value {
+        None => "undefined".to_string(),
+        Some(Value::String(string)) => string.clone(),
+        Some(Value::Number(number)) => {
+            number.as_f64().map_or_else(|| number.to_string(), |n| n.to_string())
+        }
+        Some(Value::Object(_)) => "[object Object]".to_string(),
+        Some(value) => value.to_string(),
+    }
+}
+
+fn expected_string(expected: &AttributeValue) -> String {
+    match expected {
+        AttributeValue::String(string) => string.clone(),
+        AttributeValue::Number(number) => number.to_string(),
+        AttributeValue::Regex(regex) => regex.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::{EstreeJson, EstreeTree, Selector};
+
+    fn estree_json(source_text: &str) -> EstreeJson {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source_text, SourceType::tsx()).parse().program;
+        EstreeJson::new(&program)
+    }
+
+    /// Source text of the nodes in `source_text` matching `selector`.
+    fn query(source_text: &str, selector: &str) -> Vec<String> {
+        let json = estree_json(source_text).parse().unwrap();
+        let tree = EstreeTree::new(&json);
+        let selector = Selector::parse(selector).unwrap();
+        selector.query(&tree).map(|node| node.span().source_text(source_text).to_string()).collect()
+    }
+
+    #[test]
+    fn test_types_and_attributes() {
+        assert_eq!(query("foo(bar)", "Identifier"), ["foo", "bar"]);
+        assert_eq!(query("foo(bar)", "identifier"), ["foo", "bar"]);
+        assert_eq!(query("a; b", "*"), ["a; b", "a;", "a", "b", "b"]);
+        assert_eq!(query("foo(bar)", "Identifier[name='bar']"), ["bar"]);
+        assert_eq!(query("foo(bar)", "Identifier[name!=\"bar\"]"), ["foo"]);
+        assert_eq!(query("foo(bar)", "Identifier[name=/^b/]"), ["bar"]);
+        assert_eq!(query("foo(bar)", "Identifier[name!=/^B/i]"), ["foo"]);
+        assert_eq!(query("foo(bar)", "CallExpression[callee.name=foo]"), ["foo(bar)"]);
+        assert_eq!(query("foo(bar); baz()", "CallExpression[arguments.0]"), ["foo(bar)"]);
+        assert_eq!(query("foo(bar); baz()", "CallExpression[arguments.length=0]"), ["baz()"]);
+        assert_eq!(query("a = 1; b = 2.5", "Literal[value=2.5]"), ["2.5"]);
+        assert_eq!(query("a = 1; b = 2; c = 3", "Literal[value>=2]"), ["2", "3"]);
+        assert_eq!(query("a = 1; b = 2; c = 3", "Literal[value<2]"), ["1"]);
+        assert_eq!(query("a = true; b = null", "Literal[value=true]"), ["true"]);
+        assert_eq!(query("const [, a] = b", "ArrayPattern[elements.0]"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_combinators() {
+        let source_text = "function f() { a; if (b) { c; } } d;";
+        assert_eq!(query(source_text, "FunctionDeclaration Identifier"), ["f", "a", "b", "c"]);
+        assert_eq!(query(source_text, "BlockStatement > ExpressionStatement"), ["a;", "c;"]);
+        assert_eq!(
+            query(source_text, "FunctionDeclaration > BlockStatement > * > Identifier"),
+            ["a", "b"]
+        );
+        assert_eq!(query(source_text, "ExpressionStatement + IfStatement > Identifier"), ["b"]);
+        assert_eq!(query(source_text, "FunctionDeclaration ~ ExpressionStatement"), ["d;"]);
+        assert_eq!(query(source_text, "FunctionDeclaration ~ IfStatement"), Vec::<String>::new());
+        assert_eq!(
+            query(source_text, "IfStatement, Program > ExpressionStatement"),
+            ["if (b) { c; }", "d;"]
+        );
+    }
+
+    #[test]
+    fn test_pseudo_classes() {
+        let source_text = "a(); b(c()); d(e, f, g);";
+        assert_eq!(query(source_text, "CallExpression:has(CallExpression)"), ["b(c())"]);
+        assert_eq!(
+            query(source_text, "CallExpression:has(> Identifier[name='e'])"),
+            ["d(e, f, g)"]
+        );
+        assert_eq!(
+            query(source_text, "ExpressionStatement:has(> Identifier)"),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            query(source_text, "CallExpression:not([arguments.0], :has(CallExpression))"),
+            ["a()", "c()"]
+        );
+        assert_eq!(
+            query(source_text, ":matches(CallExpression, Program) > Identifier"),
+            ["a", "b", "c", "d", "e", "f", "g"]
+        );
+        assert_eq!(query(source_text, "ExpressionStatement:first-child"), ["a();"]);
+        assert_eq!(query(source_text, "ExpressionStatement:last-child"), ["d(e, f, g);"]);
+        assert_eq!(query(source_text, "Identifier:nth-child(2)"), ["f"]);
+        assert_eq!(query(source_text, "Identifier:nth-last-child(1)"), ["g"]);
+        assert_eq!(
+            query("function f() {} (() => {}); class A {}", ":function"),
+            ["function f() {}", "() => {}"]
+        );
+        assert_eq!(query("a; let b;", ":statement"), ["a;", "let b;"]);
+        assert_eq!(query("a; let b;", ":declaration"), ["let b;"]);
+        assert_eq!(query("new.target", ":expression"), ["new.target"]);
+        assert_eq!(query("const [a] = b", ":pattern"), ["[a]", "a", "b"]);
+    }
+
+    #[test]
+    fn test_may_match() {
+        let json = estree_json("foo(`${bar}`)");
+        let node_types = json.node_types();
+        let may_match = |selector| Selector::parse(selector).unwrap().may_match(&node_types);
+        assert!(may_match("CallExpression"));
+        assert!(may_match("templateliteral"));
+        assert!(may_match("WithStatement, Identifier[name='baz']"));
+        assert!(may_match("WithStatement > :matches(Identifier, Literal)"));
+        assert!(may_match(":function"));
+        assert!(!may_match("WithStatement"));
+        assert!(!may_match("CallExpression > Literal"));
+        assert!(!may_match(":matches(WithStatement, Literal)[value=1]"));
+    }
+
+    #[test]
+    fn test_deeply_nested() {
+        let source_text = "a".to_string() + &" + a".repeat(1000);
+        let json = estree_json(&source_text);
+        assert!(json.node_types().contains("BinaryExpression"));
+        assert!(json.parse().is_none());
+    }
+
+    #[test]
+    fn test_invalid_selectors() {
+        for selector in [
+            "",
+            "Identifier[",
+            "Identifier[name=]",
+            "Identifier[name='foo]",
+            "Identifier[name=/[/]",
+            "Identifier[name=/a/x]",
+            "Identifier[name>/a/]",
+            ":unknown",
+            ":has(",
+            ":nth-child(0)",
+            "> Identifier",
+            "Identifier >",
+            "Identifier,",
+        ] {
+            assert!(Selector::parse(selector).is_err(), "{selector:?} should be invalid");
+        }
+    }
+}
@@ -0,0 +1,317 @@
+use cow_utils::CowUtils;
+use lazy_regex::{Regex, RegexBuilder};
+
+use super::{AttributeOperator, AttributeValue, Combinator, Complex, Compound, NodeClass, Simple};
+
+/// Parses the comma-separated selector list `source`.
+pub(super) fn parse(source: &str) -> Result<Vec<Complex>, String> {
+    let mut parser = Parser { source, pos: 0 };
+    let selectors = parser.parse_list(false)?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        None => Ok(selectors),
+        Some(c) => Err(format!("Unexpected `{c}` at position {}", parser.pos)),
+    }
+}
+
+struct Parser<'s> {
+    source: &'s str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if self.eat(c) {
+            return Ok(());
+        }
+        match self.peek() {
+            Some(found) => {
+                Err(format!("Expected `{c}` but found `{found}` at position {}", self.pos))
+            }
+            None => Err(format!("Expected `{c}` but found end of selector")),
+        }
+    }
+
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+        self.pos != start
+    }
+
+    /// `complex ("," complex)*`
+    ///
+    /// `relative` allows selectors to start with a combinator, as in `:has(> Identifier)`.
+    fn parse_list(&mut self, relative: bool) -> Result<Vec<Complex>, String> {
+        let mut selectors = vec![self.parse_complex(relative)?];
+        while self.eat(',') {
+            selectors.push(self.parse_complex(relative)?);
+        }
+        Ok(selectors)
+    }
+
+    /// `combinator? compound (combinator compound)*`
+    fn parse_complex(&mut self, relative: bool) -> Result<Complex, String> {
+        self.skip_whitespace();
+        let mut combinator = if relative { self.parse_explicit_combinator() } else { None };
+        let mut parts = vec![];
+        loop {
+            self.skip_whitespace();
+            parts.push((combinator, self.parse_compound()?));
+            let whitespace = self.skip_whitespace();
+            combinator = match self.parse_explicit_combinator() {
+                Some(combinator) => Some(combinator),
+                None if whitespace && !matches!(self.peek(), None | Some(',' | ')')) => {
+                    Some(Combinator::Descendant)
+                }
+                None => break,
+            };
+        }
+        Ok(Complex { parts })
+    }
+
+    fn parse_explicit_combinator(&mut self) -> Option<Combinator> {
+        let combinator = match self.peek()? {
+            '>' => Combinator::Child,
+            '~' => Combinator::Sibling,
+            '+' => Combinator::Adjacent,
+            _ => return None,
+        };
+        self.pos += 1;
+        Some(combinator)
+    }
+
+    /// `simple+`
+    fn parse_compound(&mut self) -> Result<Compound, String> {
+        let mut parts = vec![];
+        loop {
+            let simple = match self.peek() {
+                Some('*') => {
+                    self.pos += 1;
+                    Simple::Wildcard
+                }
+                Some('[') => {
+                    self.pos += 1;
+                    self.parse_attribute()?
+                }
+                Some(':') => {
+                    self.pos += 1;
+                    self.parse_pseudo()?
+                }
+                Some(c) if is_identifier_char(c) => Simple::Type(self.parse_identifier()?.into()),
+                _ => break,
+            };
+            parts.push(simple);
+        }
+        if parts.is_empty() {
+            return Err(match self.peek() {
+                Some(c) => format!("Unexpected `{c}` at position {}", self.pos),
+                None => "Unexpected end of selector".to_string(),
+            });
+        }
+        Ok(Compound { parts })
+    }
+
+    fn parse_identifier(&mut self) -> Result<&str, String> {
+        let start = self.pos;
+        while self.peek().is_some_and(is_identifier_char) {
+            self.bump();
+        }
+        if start == self.pos {
+            return Err(format!("Expected a name at position {start}"));
+        }
+        Ok(&self.source[start..self.pos])
+    }
+
+    /// `"[" path (operator value)? "]"`, after the `[`.
+    fn parse_attribute(&mut self) -> Result<Simple, String> {
+        self.skip_whitespace();
+        let mut path = vec![self.parse_identifier()?.into()];
+        while self.eat('.') {
+            path.push(self.parse_identifier()?.into());
+        }
+        self.skip_whitespace();
+        let operator = match self.peek() {
+            Some(']') => {
+                self.pos += 1;
+                return Ok(Simple::Attribute { path, test: None });
+            }
+            Some('=') => AttributeOperator::Eq,
+            Some('!') => {
+                self.pos += 1;
+                AttributeOperator::NotEq
+            }
+            Some('<') => {
+                self.pos += 1;
+                if self.peek() == Some('=') {
+                    AttributeOperator::LtEq
+                } else {
+                    AttributeOperator::Lt
+                }
+            }
+            Some('>') => {
+                self.pos += 1;
+                if self.peek() == Some('=') {
+                    AttributeOperator::GtEq
+                } else {
+                    AttributeOperator::Gt
+                }
+            }
+            _ => return Err(format!("Expected an attribute operator at position {}", self.pos)),
+        };
+        if matches!(operator, AttributeOperator::Eq | AttributeOperator::NotEq) {
+            self.expect('=')?;
+        } else {
+            self.eat('=');
+        }
+        self.skip_whitespace();
+        let value = self.parse_attribute_value()?;
+        if matches!(value, AttributeValue::Regex(_))
+            && !matches!(operator, AttributeOperator::Eq | AttributeOperator::NotEq)
+        {
+            return Err("Regular expressions can only be used with `=` and `!=`".to_string());
+        }
+        self.skip_whitespace();
+        self.expect(']')?;
+        Ok(Simple::Attribute { path, test: Some((operator, value)) })
+    }
+
+    fn parse_attribute_value(&mut self) -> Result<AttributeValue, String> {
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.pos += 1;
+                let mut value = String::new();
+                loop {
+                    match self.bump() {
+                        Some(c) if c == quote => break,
+                        Some('\\') => {
+                            value.push(self.bump().ok_or("Unterminated string")?);
+                        }
+                        Some(c) => value.push(c),
+                        None => return Err("Unterminated string".to_string()),
+                    }
+                }
+                Ok(AttributeValue::String(value))
+            }
+            Some('/') => {
+                self.pos += 1;
+                Ok(AttributeValue::Regex(self.parse_regex()?))
+            }
+            _ => {
+                let start = self.pos;
+                while self.peek().is_some_and(|c| is_identifier_char(c) || c == '.') {
+                    self.bump();
+                }
+                let value = &self.source[start..self.pos];
+                if value.is_empty() {
+                    return Err(format!("Expected an attribute value at position {start}"));
+                }
+                Ok(match value.parse::<f64>() {
+                    Ok(number) => AttributeValue::Number(number),
+                    Err(_) => AttributeValue::String(value.to_string()),
+                })
+            }
+        }
+    }
+
+    /// `"/" pattern "/" flags`, after the first `/`.
+    fn parse_regex(&mut self) -> Result<Regex, String> {
+        let start = self.pos;
+        let mut in_class = false;
+        loop {
+            match self.bump() {
+                Some('/') if !in_class => break,
+                Some('\\') => {
+                    self.bump();
+                }
+                Some('[') => in_class = true,
+                Some(']') => in_class = false,
+                Some(_) => {}
+                None => return Err("Unterminated regular expression".to_string()),
+            }
+        }
+        let pattern = &self.source[start..self.pos - 1];
+        let mut builder = RegexBuilder::new(pattern);
+        while let Some(flag) = self.peek().filter(char::is_ascii_alphabetic) {
+            match flag {
+                'i' => builder.case_insensitive(true),
+                'm' => builder.multi_line(true),
+                's' => builder.dot_matches_new_line(true),
+                'u' => builder.unicode(true),
+                _ => return Err(format!("Unsupported regular expression flag `{flag}`")),
+            };
+            self.pos += 1;
+        }
+        builder.build().map_err(|err| format!("Invalid regular expression `/{pattern}/`: {err}"))
+    }
+
+    /// `":" name ("(" arguments ")")?`, after the `:`.
+    fn parse_pseudo(&mut self) -> Result<Simple, String> {
+        let start = self.pos;
+        let name = self.parse_identifier()?.cow_to_ascii_lowercase().into_owned();
+        let simple = match name.as_str() {
+            "first-child" => Simple::NthChild(1),
+            "last-child" => Simple::NthLastChild(1),
+            "statement" => Simple::Class(NodeClass::Statement),
+            "expression" => Simple::Class(NodeClass::Expression),
+            "declaration" => Simple::Class(NodeClass::Declaration),
+            "function" => Simple::Class(NodeClass::Function),
+            "pattern" => Simple::Class(NodeClass::Pattern),
+            "has" | "not" | "matches" | "is" => {
+                self.expect('(')?;
+                let selectors = self.parse_list(name == "has")?;
+                self.skip_whitespace();
+                self.expect(')')?;
+                match name.as_str() {
+                    "has" => Simple::Has(selectors),
+                    "not" => Simple::Not(selectors),
+                    _ => Simple::Matches(selectors),
+                }
+            }
+            "nth-child" | "nth-last-child" => {
+                self.expect('(')?;
+                self.skip_whitespace();
+                let index = self.parse_identifier()?;
+                let index =
+                    index.parse::<usize>().ok().filter(|&index| index > 0).ok_or_else(|| {
+                        format!("Expected a positive integer but found `{index}`")
+                    })?;
+                self.skip_whitespace();
+                self.expect(')')?;
+                if name == "nth-child" {
+                    Simple::NthChild(index)
+                } else {
+                    Simple::NthLastChild(index)
+                }
+            }
+            _ => {
+                return Err(format!("Unknown pseudo-class `:{}`", &self.source[start..self.pos]));
+            }
+        };
+        Ok(simple)
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '$' | '-')
+}
@@ -1,14 +1,14 @@
 use std::{
     ffi::OsStr,
     path::{Path, PathBuf},
-    sync::{Arc, Mutex},
+    sync::{Arc, Mutex, atomic::AtomicUsize},
 };
 
 use rustc_hash::FxHashMap;
 
 use oxc_diagnostics::DiagnosticSender;
 
-use crate::Linter;
+use crate::{Linter, ModuleGraph};
 
 mod runtime;
 use runtime::Runtime;
@@ -21,15 +21,31 @@ pub struct LintServiceOptions {
     tsconfig: Option<PathBuf>,
 
     cross_module: bool,
+
+    /// Number of threads reading files ahead of parsing them. `0` reads files on the linting threads.
+    io_threads: usize,
+
+    /// Incremented once a file in the linted paths is linted and its diagnostics are sent.
+    linted_files: Option<Arc<AtomicUsize>>,
 }
 
 impl LintServiceOptions {
+    /// Enough to keep the linting threads busy when waiting for IO is slow, e.g. on network
+    /// file systems, while few enough not to contend with them for CPU.
+    pub const DEFAULT_IO_THREADS: usize = 2;
+
     #[must_use]
     pub fn new<T>(cwd: T) -> Self
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), tsconfig: None, cross_module: false }
+        Self {
+            cwd: cwd.into(),
+            tsconfig: None,
+            cross_module: false,
+            io_threads: Self::DEFAULT_IO_THREADS,
+            linted_files: None,
+        }
     }
 
     #[inline]
@@ -54,6 +70,25 @@ impl LintServiceOptions {
         self
     }
 
+    /// Files are always read on the linting threads with cross-module linting, which reads the
+    /// imported files as they are discovered, and with a single linting thread, JS plugins, or a
+    /// [`RuntimeFileSystem`] which doesn't support reading ahead.
+    #[inline]
+    #[must_use]
+    pub fn with_io_threads(mut self, io_threads: usize) -> Self {
+        self.io_threads = io_threads;
+        self
+    }
+
+    /// Counts the linted files in `linted_files`, e.g. to report progress. Files which can't be
+    /// linted, such as unsupported or unreadable files, are counted too.
+    #[inline]
+    #[must_use]
+    pub fn with_linted_files(mut self, linted_files: Arc<AtomicUsize>) -> Self {
+        self.linted_files = Some(linted_files);
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
@@ -80,6 +115,18 @@ impl LintService {
         self.runtime.run(file_system, paths, tx_error);
     }
 
+    /// The module graph of the linted files and their imports. Only populated when cross-module
+    /// linting is enabled with [`LintServiceOptions::with_cross_module`].
+    pub fn module_graph(&self) -> &ModuleGraph {
+        self.runtime.module_graph()
+    }
+
+    /// Resolves `specifier` imported by the module at `importer`. Only possible when cross-module
+    /// linting is enabled.
+    pub fn resolve(&self, importer: &Path, specifier: &str) -> Option<PathBuf> {
+        self.runtime.resolve(importer, specifier)
+    }
+
     pub fn set_disable_directives_map(
         &mut self,
         map: Arc<Mutex<FxHashMap<PathBuf, crate::disable_directives::DisableDirectives>>>,
@@ -107,3 +154,57 @@ impl LintService {
         self.runtime.run_test_source(file_system, paths, check_syntax_errors, tx_error)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{ffi::OsStr, fs, path::Path, sync::Arc, sync::mpsc};
+
+    use rustc_hash::FxHashMap;
+
+    use crate::{
+        ConfigStore, ConfigStoreBuilder, ExternalPluginStore, LintOptions, LintService,
+        LintServiceOptions, Linter, OsFileSystem,
+    };
+
+    /// Messages and errors of linting `files` in `cwd`, sorted.
+    fn lint(cwd: &Path, files: &[&str], io_threads: usize) -> (Vec<String>, Vec<String>) {
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let config = ConfigStoreBuilder::empty().build(&mut external_plugin_store).unwrap();
+        let linter = Linter::new(
+            LintOptions::default(),
+            ConfigStore::new(config, FxHashMap::default(), external_plugin_store),
+            None,
+        );
+        let service =
+            LintService::new(linter, LintServiceOptions::new(cwd).with_io_threads(io_threads));
+        let paths =
+            files.iter().map(|file| Arc::<OsStr>::from(cwd.join(file).as_os_str())).collect();
+        let (sender, receiver) = mpsc::channel();
+        let mut messages = service
+            .run_test_source(&OsFileSystem, paths, true, &sender)
+            .into_iter()
+            .map(|message| message.error.to_string())
+            .collect::<Vec<_>>();
+        drop(sender);
+        let mut errors =
+            receiver.into_iter().flatten().map(|error| error.to_string()).collect::<Vec<_>>();
+        messages.sort();
+        errors.sort();
+        (messages, errors)
+    }
+
+    #[test]
+    fn read_ahead() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cwd = temp_dir.path();
+        fs::write(cwd.join("valid.js"), "let a = 1;").unwrap();
+        fs::write(cwd.join("syntax_error.js"), "let = ;").unwrap();
+        fs::write(cwd.join("invalid_utf8.js"), [0xff, 0xfe]).unwrap();
+        let files = ["valid.js", "syntax_error.js", "invalid_utf8.js", "missing.js"];
+
+        let (messages, errors) = lint(cwd, &files, 2);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(errors.len(), 2);
+        assert_eq!((messages, errors), lint(cwd, &files, 0));
+    }
+}
@@ -1,21 +1,26 @@
 use std::{
     borrow::Cow,
     ffi::OsStr,
-    fs,
-    hash::BuildHasherDefault,
+    fs, io,
     mem::take,
     path::{Path, PathBuf},
-    sync::{Arc, Mutex, mpsc},
+    ptr::NonNull,
+    sync::{
+        Arc, Mutex, Weak,
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
 };
 
 use indexmap::IndexSet;
-use rayon::iter::ParallelDrainRange;
+use rayon::iter::{ParallelBridge, ParallelDrainRange};
 use rayon::{
     Scope,
     iter::IntoParallelRefIterator,
     prelude::{ParallelIterator, ParallelSliceMut},
 };
-use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet, FxHasher};
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 use self_cell::self_cell;
 use smallvec::SmallVec;
 
@@ -24,27 +29,31 @@ use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic}
 use oxc_parser::{ParseOptions, Parser};
 use oxc_resolver::Resolver;
 use oxc_semantic::{Semantic, SemanticBuilder};
-use oxc_span::{CompactStr, SourceType, VALID_EXTENSIONS};
+use oxc_span::{SourceType, VALID_EXTENSIONS};
 
 use crate::{
     Fixer, Linter, Message, PossibleFixes,
     context::ContextSubHost,
     disable_directives::DisableDirectives,
     loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader},
+    module_graph::{ModuleGraph, ResolvedModuleRequest},
     module_record::ModuleRecord,
     utils::read_to_arena_str,
 };
 
 use super::LintServiceOptions;
 
-type ModulesByPath =
-    papaya::HashMap<Arc<OsStr>, SmallVec<[Arc<ModuleRecord>; 1]>, BuildHasherDefault<FxHasher>>;
-
 pub struct Runtime {
     cwd: Box<Path>,
     pub(super) linter: Linter,
     resolver: Option<Resolver>,
 
+    /// Number of threads reading files ahead of parsing them. See [`read_ahead`].
+    io_threads: usize,
+
+    /// See [`LintServiceOptions::with_linted_files`].
+    linted_files: Option<Arc<AtomicUsize>>,
+
     /// Pool of allocators for parsing and linting.
     allocator_pool: AllocatorPool,
 
@@ -54,14 +63,9 @@ pub struct Runtime {
     #[cfg(all(target_pointer_width = "64", target_endian = "little"))]
     js_allocator_pool: Option<AllocatorPool>,
 
-    /// The module graph keyed by module paths. It is looked up when populating `loaded_modules`.
-    /// The values are module records of sections (check the docs of `ProcessedModule.section_module_records`)
+    /// The module graph. It is looked up when populating `loaded_modules`.
     /// Its entries are kept across groups because modules discovered in former groups could be referenced by modules in latter groups.
-    ///
-    /// `ModuleRecord` is a cyclic data structure.
-    /// To make sure all `ModuleRecord` gets dropped after `Runtime` is dropped,
-    /// `modules_by_path` must own `ModuleRecord` with `Arc`, all other references must use `Weak<ModuleRecord>`.
-    modules_by_path: ModulesByPath,
+    module_graph: ModuleGraph,
     /// Collected disable directives from linted files
     disable_directives_map: Arc<Mutex<FxHashMap<PathBuf, DisableDirectives>>>,
 }
@@ -94,11 +98,6 @@ struct ProcessedModule<'alloc_pool> {
     content: Option<ModuleContent<'alloc_pool>>,
 }
 
-struct ResolvedModuleRequest {
-    specifier: CompactStr,
-    resolved_requested_path: Arc<OsStr>,
-}
-
 /// ModuleRecord with all specifiers in import statements resolved to real paths.
 struct ResolvedModuleRecord {
     module_record: Arc<ModuleRecord>,
@@ -155,6 +154,16 @@ impl<'alloc_pool> ModuleToLint<'alloc_pool> {
     }
 }
 
+/// Result of [`Runtime::fix_in_passes`].
+struct FixPassesResult {
+    /// The fixed source text, if any fix was applied.
+    fixed_code: Option<String>,
+    /// Messages which were not fixed.
+    messages: Vec<Message>,
+    /// The source text the spans of `messages` refer to, if not the original source text.
+    messages_source_text: Option<String>,
+}
+
 /// A simple trait for the `Runtime` to load and save file from a filesystem
 /// The `Runtime` uses OsFileSystem as a default
 /// The Tester and `oxc_language_server` would like to provide the content from memory
@@ -174,6 +183,13 @@ pub trait RuntimeFileSystem {
     /// # Errors
     /// When the program does not have write permission for the file system
     fn write_file(&self, path: &Path, content: &str) -> Result<(), std::io::Error>;
+
+    /// Whether files can be read with [`RuntimeFileSystem::read_to_arena_str`] on dedicated IO
+    /// threads, ahead of being parsed on a linting thread. The source text must then be
+    /// allocated in the given allocator.
+    fn supports_read_ahead(&self) -> bool {
+        false
+    }
 }
 
 pub struct OsFileSystem;
@@ -190,6 +206,62 @@ impl RuntimeFileSystem for OsFileSystem {
     fn write_file(&self, path: &Path, content: &str) -> Result<(), std::io::Error> {
         fs::write(path, content)
     }
+
+    fn supports_read_ahead(&self) -> bool {
+        true
+    }
+}
+
+/// A file read by [`read_ahead`] into the arena of an allocator from the pool, which is then
+/// used to parse and lint it.
+struct ReadAheadSource<'alloc_pool> {
+    allocator_guard: AllocatorGuard<'alloc_pool>,
+    /// Points into the arena of `allocator_guard`.
+    source_text: io::Result<NonNull<str>>,
+}
+
+// SAFETY: `NonNull<str>` is only `!Send` because it is a raw pointer. `source_text` points into
+// the arena of `allocator_guard`, whose chunks are on the heap, so moving the guard to another
+// thread does not move the text. The arena is only reset when the guard is dropped, and the guard
+// is sent together with the pointer, which is only dereferenced while the guard is alive (in
+// `process_path_to_module`). The text is never written to after being read.
+unsafe impl Send for ReadAheadSource<'_> {}
+
+/// Reads `paths` on `io_threads` dedicated threads, so waiting for IO overlaps with parsing and
+/// linting on the rayon thread pool, which matters on network file systems. `consume` receives
+/// each path with its source text.
+fn read_ahead<'p, 'alloc_pool>(
+    file_system: &(dyn RuntimeFileSystem + Sync + Send),
+    allocator_pool: &'alloc_pool AllocatorPool,
+    paths: &'p IndexSet<Arc<OsStr>, FxBuildHasher>,
+    io_threads: usize,
+    consume: impl FnOnce(mpsc::IntoIter<(&'p Arc<OsStr>, ReadAheadSource<'alloc_pool>)>),
+) {
+    // Bounded to the number of linting threads, so no more source texts are held in memory than
+    // they can keep up with.
+    let (tx_source, rx_source) = mpsc::sync_channel(rayon::current_num_threads());
+    let next_index = AtomicUsize::new(0);
+    thread::scope(|scope| {
+        for _ in 0..io_threads.min(paths.len()) {
+            let tx_source = tx_source.clone();
+            let next_index = &next_index;
+            scope.spawn(move || {
+                while let Some(path) = paths.get_index(next_index.fetch_add(1, Ordering::Relaxed)) {
+                    let allocator_guard = allocator_pool.get();
+                    let source_text = file_system
+                        .read_to_arena_str(Path::new(path), &allocator_guard)
+                        .map(NonNull::from);
+                    let source = ReadAheadSource { allocator_guard, source_text };
+                    // The receiver is gone if linting panicked.
+                    if tx_source.send((path, source)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(tx_source);
+        consume(rx_source.into_iter());
+    });
 }
 
 impl Runtime {
@@ -244,8 +316,17 @@ impl Runtime {
         #[cfg(not(all(target_pointer_width = "64", target_endian = "little")))]
         let allocator_pool = AllocatorPool::new(thread_count);
 
+        // Files read ahead are linted in whatever order they are read, so the order of paths is
+        // kept with a single linting thread. JS plugins use a fixed-size allocator pool, which has
+        // no allocators to spare for files read ahead.
+        let io_threads =
+            if thread_count == 1 || linter.has_external_linter() { 0 } else { options.io_threads };
+
         let resolver = options.cross_module.then(|| {
-            Self::get_resolver(options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))))
+            Self::get_resolver(
+                options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))),
+                linter.import_aliases(&options.cwd),
+            )
         });
 
         Self {
@@ -255,14 +336,19 @@ impl Runtime {
             cwd: options.cwd,
             linter,
             resolver,
-            modules_by_path: papaya::HashMap::builder()
-                .hasher(BuildHasherDefault::default())
-                .resize_mode(papaya::ResizeMode::Blocking)
-                .build(),
+            io_threads,
+            linted_files: options.linted_files,
+            module_graph: ModuleGraph::default(),
             disable_directives_map: Arc::new(Mutex::new(FxHashMap::default())),
         }
     }
 
+    fn file_linted(&self) {
+        if let Some(linted_files) = &self.linted_files {
+            linted_files.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
     /// Get [`AllocatorPool`] for copying ASTs to fixed-size allocators, if one is required.
     fn js_allocator_pool(&self) -> Option<&AllocatorPool> {
         #[cfg(all(target_pointer_width = "64", target_endian = "little"))]
@@ -274,6 +360,19 @@ impl Runtime {
         pool
     }
 
+    pub fn module_graph(&self) -> &ModuleGraph {
+        &self.module_graph
+    }
+
+    /// Resolves `specifier` imported by the module at `importer`, like the imports of the module
+    /// graph. [`None`] if cross-module linting is disabled or the specifier can't be resolved.
+    pub fn resolve(&self, importer: &Path, specifier: &str) -> Option<PathBuf> {
+        let resolution = self.resolver.as_ref()?.resolve(importer.parent()?, specifier).ok()?;
+        let is_typescript = SourceType::from_path(importer).is_ok_and(SourceType::is_typescript);
+        let declaration = is_typescript.then(|| declaration_file(resolution.path())).flatten();
+        Some(declaration.unwrap_or_else(|| resolution.into_path_buf()))
+    }
+
     pub fn set_disable_directives_map(
         &mut self,
         map: Arc<Mutex<FxHashMap<PathBuf, DisableDirectives>>>,
@@ -281,9 +380,12 @@ impl Runtime {
         self.disable_directives_map = map;
     }
 
-    fn get_resolver(tsconfig_path: Option<PathBuf>) -> Resolver {
+    fn get_resolver(
+        tsconfig_path: Option<PathBuf>,
+        aliases: Vec<(String, Vec<PathBuf>)>,
+    ) -> Resolver {
         use oxc_resolver::{
-            ResolveOptions, TsconfigDiscovery, TsconfigOptions, TsconfigReferences,
+            AliasValue, ResolveOptions, TsconfigDiscovery, TsconfigOptions, TsconfigReferences,
         };
         let tsconfig = tsconfig_path.and_then(|path| {
             path.is_file().then_some(TsconfigDiscovery::Manual(TsconfigOptions {
@@ -303,15 +405,27 @@ impl Runtime {
             main_fields: vec!["module".into(), "main".into()],
             condition_names: vec!["module".into(), "import".into()],
             extension_alias,
+            alias: aliases
+                .into_iter()
+                .map(|(name, paths)| {
+                    let paths = paths
+                        .into_iter()
+                        .map(|path| AliasValue::Path(path.to_string_lossy().into_owned()))
+                        .collect();
+                    (name, paths)
+                })
+                .collect(),
             tsconfig,
             ..ResolveOptions::default()
         })
     }
 
+    /// `source` is the source text read by [`read_ahead`], if any.
     fn get_source_type_and_text<'a>(
         file_system: &'a (dyn RuntimeFileSystem + Sync + Send),
         path: &Path,
         ext: &str,
+        source: Option<io::Result<&'a str>>,
         allocator: &'a Allocator,
     ) -> Option<Result<(SourceType, &'a str), Error>> {
         let source_type = SourceType::from_path(path);
@@ -327,7 +441,11 @@ impl Runtime {
             source_type = source_type.with_jsx(true);
         }
 
-        let file_result = file_system.read_to_arena_str(path, allocator).map_err(|e| {
+        let file_result = match source {
+            Some(source) => source,
+            None => file_system.read_to_arena_str(path, allocator),
+        };
+        let file_result = file_result.map_err(|e| {
             Error::new(OxcDiagnostic::error(format!(
                 "Failed to open file {} with error \"{e}\"",
                 path.display()
@@ -353,16 +471,29 @@ impl Runtime {
         on_module_to_lint: impl Fn(&'a Self, ModuleToLint) + Send + Sync + Clone + 'a,
     ) {
         if self.resolver.is_none() {
-            paths.par_iter().for_each(|path| {
-                let output =
-                    self.process_path(file_system, paths, path, check_syntax_errors, tx_error);
-                let Some(entry) =
+            let lint_path = |path: &Arc<OsStr>, source: Option<ReadAheadSource<'a>>| {
+                let output = self.process_path(
+                    file_system,
+                    paths,
+                    path,
+                    source,
+                    check_syntax_errors,
+                    tx_error,
+                );
+                if let Some(entry) =
                     ModuleToLint::from_processed_module(output.path, output.processed_module)
-                else {
-                    return;
-                };
-                on_module_to_lint(self, entry);
-            });
+                {
+                    on_module_to_lint(self, entry);
+                }
+                self.file_linted();
+            };
+            if self.io_threads == 0 || paths.len() < 2 || !file_system.supports_read_ahead() {
+                paths.par_iter().for_each(|path| lint_path(path, None));
+            } else {
+                read_ahead(file_system, &self.allocator_pool, paths, self.io_threads, |sources| {
+                    sources.par_bridge().for_each(|(path, source)| lint_path(path, Some(source)));
+                });
+            }
             return;
         }
         // The goal of code below is to construct the module graph bootstrapped by the entry modules (`paths`),
@@ -411,7 +542,7 @@ impl Runtime {
         let me: &Self = self;
 
         // `encountered_paths` prevents duplicated processing.
-        // It is a superset of keys of `modules_by_path` as it also contains paths that are queued to process.
+        // It is a superset of modules in `module_graph` as it also contains paths that are queued to process.
         let mut encountered_paths =
             FxHashSet::<Arc<OsStr>>::with_capacity_and_hasher(sorted_paths.len(), FxBuildHasher);
 
@@ -452,6 +583,7 @@ impl Runtime {
                                 file_system,
                                 paths,
                                 &path,
+                                None,
                                 check_syntax_errors,
                                 tx_error,
                             ))
@@ -492,6 +624,7 @@ impl Runtime {
                                             file_system,
                                             paths,
                                             &dep_path,
+                                            None,
                                             check_syntax_errors,
                                             tx_error,
                                         ))
@@ -503,8 +636,8 @@ impl Runtime {
                     }
                 }
 
-                // Populate this module to `modules_by_path`
-                self.modules_by_path.pin().insert(
+                // Populate this module to `module_graph`
+                self.module_graph.insert(
                     Arc::clone(&path),
                     processed_module
                         .section_module_records
@@ -532,45 +665,29 @@ impl Runtime {
 
                 // This module has `content` which means it's one of `self.paths`.
                 // Store it to `modules_to_lint`
+                let is_entry = paths.contains(&path);
                 if let Some(entry_module) =
                     ModuleToLint::from_processed_module(path, processed_module)
                 {
                     modules_to_lint.push(entry_module);
+                } else if is_entry {
+                    self.file_linted();
                 }
             } // while pending_module_count > 0
 
             // Now all dependencies in this group are processed.
             // Writing to `loaded_modules` based on `module_paths_and_resolved_requests`
-            module_paths_and_resolved_requests.par_drain(..).for_each(|(path, requested_module_paths)| {
-                if requested_module_paths.is_empty() {
-                    return;
-                }
-                let modules_by_path = self.modules_by_path.pin();
-                let records = modules_by_path.get(&path).unwrap();
-                assert_eq!(
-                    records.len(), requested_module_paths.len(),
-                    "This is an internal logic error. Please file an issue at https://github.com/oxc-project/oxc/issues",
-                );
-                for (record, requested_module_paths) in
-                    records.iter().zip(requested_module_paths.into_iter())
-                {
-                    let mut loaded_modules = record.write_loaded_modules();
-                    for request in requested_module_paths {
-                        // TODO: revise how to store multiple sections in loaded_modules
-                        let Some(dep_module_record) =
-                            modules_by_path.get(&request.resolved_requested_path).unwrap().last()
-                        else {
-                            continue;
-                        };
-                        loaded_modules.insert(request.specifier, Arc::downgrade(dep_module_record));
-                    }
-                }
-            });
+            module_paths_and_resolved_requests.par_drain(..).for_each(
+                |(path, requested_module_paths)| {
+                    self.module_graph.link(&path, requested_module_paths);
+                },
+            );
             #[expect(clippy::iter_with_drain)]
             for entry in modules_to_lint.drain(..) {
                 let on_entry = on_module_to_lint.clone();
                 scope.spawn(move |_| {
                     on_entry(me, entry);
+                    me.file_linted();
                 });
             }
         }
@@ -582,7 +699,7 @@ impl Runtime {
         paths: Vec<Arc<OsStr>>,
         tx_error: &DiagnosticSender,
     ) {
-        self.modules_by_path.pin().reserve(paths.len());
+        self.module_graph.reserve(paths.len());
         let paths_set: IndexSet<Arc<OsStr>, FxBuildHasher> = paths.into_iter().collect();
 
         rayon::scope(|scope| {
@@ -606,19 +723,34 @@ impl Runtime {
                             dep.section_contents.len()
                         );
 
+                        let options = me.linter.options();
+                        let apply_fixes = (options.fix.is_some() || options.suppress_all)
+                            && !options.report_fixes;
+                        // Kept to resolve imports when linting the fixed source text again.
+                        let module_records = if apply_fixes {
+                            module_to_lint
+                                .section_module_records
+                                .iter()
+                                .filter_map(|record| record.as_ref().ok().map(Arc::clone))
+                                .collect()
+                        } else {
+                            vec![]
+                        };
+
                         let context_sub_hosts: Vec<ContextSubHost<'_>> = module_to_lint
                             .section_module_records
                             .into_iter()
                             .zip(dep.section_contents.drain(..))
                             .filter_map(|(record_result, section)| match record_result {
-                                Ok(module_record) => {
-                                    Some(ContextSubHost::new_with_framework_options(
+                                Ok(module_record) => Some(
+                                    ContextSubHost::new_with_framework_options(
                                         section.semantic.unwrap(),
                                         Arc::clone(&module_record),
                                         section.source.start,
                                         section.source.framework_options,
-                                    ))
-                                }
+                                    )
+                                    .with_template(section.source.template),
+                                ),
                                 Err(messages) => {
                                     if !messages.is_empty() {
                                         let diagnostics = DiagnosticService::wrap_diagnostics(
@@ -654,33 +786,26 @@ impl Runtime {
                                 .insert(path.to_path_buf(), disable_directives);
                         }
 
-                        if me.linter.options().fix.is_some() {
-                            let fix_result = Fixer::new(
-                                dep.source_text,
-                                messages,
-                                SourceType::from_path(path).ok().map(|st| {
-                                    if st.is_javascript() { st.with_jsx(true) } else { st }
-                                }),
-                            )
-                            .fix();
-                            if fix_result.fixed {
-                                // write to file, replacing only the changed part
-                                let start = 0;
-                                let end = start + dep.source_text.len();
-                                new_source_text
-                                    .to_mut()
-                                    .replace_range(start..end, &fix_result.fixed_code);
+                        // The source text the spans of `messages` refer to.
+                        let mut messages_source_text = Cow::Borrowed(dep.source_text);
+                        if apply_fixes {
+                            let fix_result =
+                                me.fix_in_passes(path, dep.source_text, messages, &module_records);
+                            if let Some(fixed_code) = fix_result.fixed_code {
+                                new_source_text = Cow::Owned(fixed_code);
+                            }
+                            if let Some(source_text) = fix_result.messages_source_text {
+                                messages_source_text = Cow::Owned(source_text);
                             }
                             messages = fix_result.messages;
                         }
 
                         if !messages.is_empty() {
-                            let errors = messages.into_iter().map(Into::into).collect();
-                            let diagnostics = DiagnosticService::wrap_diagnostics(
+                            let diagnostics = Message::wrap_messages(
                                 &me.cwd,
                                 path,
-                                dep.source_text,
-                                errors,
+                                &messages_source_text,
+                                messages,
                             );
                             tx_error.send(diagnostics).unwrap();
                         }
@@ -694,6 +819,193 @@ impl Runtime {
                 },
             );
         });
+
+        if self.resolver.is_some() {
+            self.run_on_module_graph(file_system, &paths_set, tx_error);
+        }
+    }
+
+    /// Run rules which need the module graph of all linted files, once all files are linted.
+    fn run_on_module_graph(
+        &self,
+        file_system: &(dyn RuntimeFileSystem + Sync + Send),
+        paths: &IndexSet<Arc<OsStr>, FxBuildHasher>,
+        tx_error: &DiagnosticSender,
+    ) {
+        let disable_directives_map =
+            self.disable_directives_map.lock().expect("disable_directives_map mutex poisoned");
+        for path in paths {
+            let path = Path::new(path);
+            if !self.module_graph.contains(path) {
+                continue;
+            }
+            let messages = self.linter.run_on_module_graph(
+                path,
+                &self.cwd,
+                &self.module_graph,
+                disable_directives_map.get(path),
+            );
+            if messages.is_empty() {
+                continue;
+            }
+            let allocator = Allocator::default();
+            let Ok(source_text) = file_system.read_to_arena_str(path, &allocator) else {
+                continue;
+            };
+            tx_error.send(Message::wrap_messages(&self.cwd, path, source_text, messages)).unwrap();
+        }
+    }
+
+    /// Apply the fixes of `messages` to `source_text`, then lint the fixed source text again and
+    /// apply the new fixes, until no fixes are left or [`LintOptions::max_fix_passes`] is reached.
+    ///
+    /// Fixes which overlap with another fix are skipped in a pass, and applied in the next pass if
+    /// the rule still reports them. Fixes still conflicting after the last pass are reported with
+    /// an extra diagnostic.
+    ///
+    /// [`LintOptions::max_fix_passes`]: crate::LintOptions::max_fix_passes
+    fn fix_in_passes(
+        &self,
+        path: &Path,
+        source_text: &str,
+        mut messages: Vec<Message>,
+        module_records: &[Arc<ModuleRecord>],
+    ) -> FixPassesResult {
+        let source_type = SourceType::from_path(path)
+            .ok()
+            .map(|st| if st.is_javascript() { st.with_jsx(true) } else { st });
+        // Disable comments are inserted for all diagnostics at once. Linting again would only
+        // report diagnostics caused by the comments themselves.
+        let options = self.linter.options();
+        let max_fix_passes = if options.suppress_all { 1 } else { options.max_fix_passes.max(1) };
+        // With JS plugins, parsing and linting may need fixed-size allocators,
+        // which are only available when the source text is read from a file.
+        let can_lint_again =
+            !self.linter.has_external_linter() || self.js_allocator_pool().is_some();
+
+        let mut fixed_code = None;
+        let mut messages_source_text: Option<String> = None;
+        let mut conflicts = vec![];
+        for pass in 1..=max_fix_passes {
+            let text = messages_source_text.as_deref().unwrap_or(source_text);
+            let fix_result = Fixer::new(text, messages, source_type).fix();
+            messages = fix_result.messages;
+            conflicts = fix_result.conflicts;
+            if !fix_result.fixed {
+                break;
+            }
+            let code = fix_result.fixed_code.into_owned();
+            if pass == max_fix_passes || !can_lint_again {
+                fixed_code = Some(code);
+                break;
+            }
+            // Stop if the fixed source text can't be parsed, keeping the messages of this pass.
+            let Some(new_messages) = self.lint_fixed_source(path, &code, module_records) else {
+                fixed_code = Some(code);
+                break;
+            };
+            messages = new_messages;
+            conflicts.clear();
+            fixed_code = Some(code.clone());
+            messages_source_text = Some(code);
+        }
+
+        // The disable directives were collected from the source text before the last fix, so they
+        // can't be used to report unused directives in the fixed file.
+        if fixed_code.is_some() && fixed_code != messages_source_text {
+            self.disable_directives_map
+                .lock()
+                .expect("disable_directives_map mutex poisoned")
+                .remove(path);
+        }
+
+        if !conflicts.is_empty() {
+            messages.push(Message::new(
+                OxcDiagnostic::warn(format!(
+                    "{} fix{} could not be applied because {} with other fixes",
+                    conflicts.len(),
+                    if conflicts.len() == 1 { "" } else { "es" },
+                    if conflicts.len() == 1 { "it conflicts" } else { "they conflict" },
+                ))
+                .with_labels(conflicts)
+                .with_help("Run the linter again to apply the remaining fixes."),
+                PossibleFixes::None,
+            ));
+        }
+
+        FixPassesResult { fixed_code, messages, messages_source_text }
+    }
+
+    /// Lint `source_text`, the fixed source text of the file at `path`.
+    ///
+    /// Imports are resolved with the `loaded_modules` of `module_records`, the module records of
+    /// the sections of the file before it was fixed.
+    ///
+    /// Returns `None` if `source_text` can't be parsed.
+    fn lint_fixed_source(
+        &self,
+        path: &Path,
+        source_text: &str,
+        module_records: &[Arc<ModuleRecord>],
+    ) -> Option<Vec<Message>> {
+        let ext = path.extension().and_then(OsStr::to_str)?;
+        let mut source_type = SourceType::from_path(path).unwrap_or_default();
+        if source_type.is_javascript() {
+            source_type = source_type.with_jsx(true);
+        }
+
+        let allocator = Allocator::default();
+        let source_text = allocator.alloc_str(source_text);
+        let mut section_contents = SmallVec::new();
+        let records = self.process_source(
+            path,
+            ext,
+            true,
+            source_type,
+            source_text,
+            &allocator,
+            Some(&mut section_contents),
+        );
+
+        let mut context_sub_hosts = Vec::with_capacity(records.len());
+        for (i, (record, section)) in records.into_iter().zip(section_contents).enumerate() {
+            let module_record = record.ok()?.module_record;
+            if let Some(previous) = module_records.get(i) {
+                module_record.write_loaded_modules().extend(
+                    previous
+                        .loaded_modules()
+                        .iter()
+                        .map(|(specifier, module)| (specifier.clone(), Weak::clone(module))),
+                );
+            }
+            context_sub_hosts.push(
+                ContextSubHost::new_with_framework_options(
+                    section.semantic.unwrap(),
+                    module_record,
+                    section.source.start,
+                    section.source.framework_options,
+                )
+                .with_template(section.source.template),
+            );
+        }
+
+        let (messages, disable_directives) = self.linter.run_with_disable_directives(
+            path,
+            context_sub_hosts,
+            &allocator,
+            self.js_allocator_pool(),
+        );
+
+        // Replace the disable directives of the source text before it was fixed.
+        let mut disable_directives_map =
+            self.disable_directives_map.lock().expect("disable_directives_map mutex poisoned");
+        if let Some(disable_directives) = disable_directives {
+            disable_directives_map.insert(path.to_path_buf(), disable_directives);
+        } else {
+            disable_directives_map.remove(path);
+        }
+
+        Some(messages)
     }
 
     // language_server: the language server needs line and character position
@@ -706,7 +1018,7 @@ impl Runtime {
     ) -> Vec<Message> {
         use std::sync::Mutex;
 
-        self.modules_by_path.pin().reserve(paths.len());
+        self.module_graph.reserve(paths.len());
         let paths_set: IndexSet<Arc<OsStr>, FxBuildHasher> = paths.into_iter().collect();
 
         let messages = Mutex::new(Vec::<Message>::new());
@@ -736,7 +1048,7 @@ impl Runtime {
                                         Arc::clone(&module_record),
                                         section.source.start,
                                         section.source.framework_options,
-                                    ))
+                                    ).with_template(section.source.template))
                                 }
                                 Err(diagnostics) => {
                                     if !diagnostics.is_empty() {
@@ -790,7 +1102,7 @@ impl Runtime {
     ) -> Vec<Message> {
         use std::sync::Mutex;
 
-        self.modules_by_path.pin().reserve(paths.len());
+        self.module_graph.reserve(paths.len());
         let paths_set: IndexSet<Arc<OsStr>, FxBuildHasher> = paths.into_iter().collect();
 
         let messages = Mutex::new(Vec::<Message>::new());
@@ -810,7 +1122,7 @@ impl Runtime {
                                     Arc::clone(&module_record),
                                     section.source.start,
                                     section.source.framework_options
-                                )),
+                                ).with_template(section.source.template)),
                                 Err(errors) => {
                                     if !errors.is_empty() {
                                         messages
@@ -850,11 +1162,12 @@ impl Runtime {
         file_system: &'a (dyn RuntimeFileSystem + Sync + Send),
         paths: &IndexSet<Arc<OsStr>, FxBuildHasher>,
         path: &Arc<OsStr>,
+        source: Option<ReadAheadSource<'a>>,
         check_syntax_errors: bool,
         tx_error: Option<&DiagnosticSender>,
     ) -> ModuleProcessOutput<'a> {
         let processed_module = self
-            .process_path_to_module(file_system, paths, path, check_syntax_errors, tx_error)
+            .process_path_to_module(file_system, paths, path, source, check_syntax_errors, tx_error)
             .unwrap_or_default();
         ModuleProcessOutput { path: Arc::clone(path), processed_module }
     }
@@ -864,6 +1177,7 @@ impl Runtime {
         file_system: &'a (dyn RuntimeFileSystem + Sync + Send),
         paths: &IndexSet<Arc<OsStr>, FxBuildHasher>,
         path: &Arc<OsStr>,
+        source: Option<ReadAheadSource<'a>>,
         check_syntax_errors: bool,
         tx_error: Option<&DiagnosticSender>,
     ) -> Option<ProcessedModule<'a>> {
@@ -876,7 +1190,10 @@ impl Runtime {
             return None;
         }
 
-        let allocator_guard = self.allocator_pool.get();
+        let (allocator_guard, read_ahead_text) = match source {
+            Some(source) => (source.allocator_guard, Some(source.source_text)),
+            None => (self.allocator_pool.get(), None),
+        };
 
         if paths.contains(path) {
             let mut records =
@@ -884,10 +1201,17 @@ impl Runtime {
 
             let module_content = ModuleContent::try_new(allocator_guard, |allocator_guard| {
                 let allocator = &**allocator_guard;
+                // SAFETY: Text read ahead points into the arena of `allocator_guard`, which owns
+                // the module content borrowing it.
+                let source = read_ahead_text.map(|text| text.map(|text| unsafe { text.as_ref() }));
 
-                let Some(stt) =
-                    Self::get_source_type_and_text(file_system, Path::new(path), ext, allocator)
-                else {
+                let Some(stt) = Self::get_source_type_and_text(
+                    file_system,
+                    Path::new(path),
+                    ext,
+                    source,
+                    allocator,
+                ) else {
                     return Err(());
                 };
 
@@ -919,8 +1243,17 @@ impl Runtime {
             Some(ProcessedModule { section_module_records: records, content: Some(module_content) })
         } else {
             let allocator = &*allocator_guard;
+            // SAFETY: Text read ahead points into the arena of `allocator_guard`, which outlives
+            // this function.
+            let source = read_ahead_text.map(|text| text.map(|text| unsafe { text.as_ref() }));
 
-            let stt = Self::get_source_type_and_text(file_system, Path::new(path), ext, allocator)?;
+            let stt = Self::get_source_type_and_text(
+                file_system,
+                Path::new(path),
+                ext,
+                source,
+                allocator,
+            )?;
 
             let (source_type, source_text) = match stt {
                 Ok(v) => v,
@@ -1038,7 +1371,7 @@ impl Runtime {
         let mut semantic = semantic_ret.semantic;
         semantic.set_irregular_whitespaces(ret.irregular_whitespaces);
 
-        let module_record = Arc::new(ModuleRecord::new(path, &ret.module_record, &semantic));
+        let mut module_record = ModuleRecord::new(path, &ret.module_record, &semantic);
 
         let mut resolved_module_requests: Vec<ResolvedModuleRequest> = vec![];
 
@@ -1046,18 +1379,43 @@ impl Runtime {
         if let Some(resolver) = &self.resolver {
             // Retrieve all dependent modules from this module.
             let dir = path.parent().unwrap();
-            resolved_module_requests = module_record
-                .requested_modules
-                .keys()
-                .filter_map(|specifier| {
-                    let resolution = resolver.resolve(dir, specifier).ok()?;
-                    Some(ResolvedModuleRequest {
-                        specifier: specifier.clone(),
-                        resolved_requested_path: Arc::<OsStr>::from(resolution.path().as_os_str()),
-                    })
-                })
-                .collect();
+            let mut unresolved_modules = FxHashSet::default();
+            for specifier in module_record.requested_modules.keys() {
+                match resolver.resolve(dir, specifier) {
+                    Ok(resolution) => {
+                        // Like TypeScript, resolve JavaScript modules imported by TypeScript to
+                        // their declaration files, which may carry types and JSDoc the JavaScript
+                        // doesn't.
+                        let declaration = source_type
+                            .is_typescript()
+                            .then(|| declaration_file(resolution.path()))
+                            .flatten();
+                        let path = declaration.as_deref().unwrap_or(resolution.path());
+                        resolved_module_requests.push(ResolvedModuleRequest {
+                            specifier: specifier.clone(),
+                            resolved_requested_path: Arc::<OsStr>::from(path.as_os_str()),
+                        });
+                    }
+                    Err(_) => {
+                        unresolved_modules.insert(specifier.clone());
+                    }
+                }
+            }
+            module_record.unresolved_modules = unresolved_modules;
         }
+        let module_record = Arc::new(module_record);
         Ok((ResolvedModuleRecord { module_record, resolved_module_requests }, semantic))
     }
 }
+
+/// The declaration file next to a JavaScript file, e.g. `index.d.ts` for `index.js`.
+fn declaration_file(path: &Path) -> Option<PathBuf> {
+    let extension = match path.extension()?.to_str()? {
+        "js" => "d.ts",
+        "mjs" => "d.mts",
+        "cjs" => "d.cts",
+        _ => return None,
+    };
+    let declaration = path.with_extension(extension);
+    declaration.is_file().then_some(declaration)
+}
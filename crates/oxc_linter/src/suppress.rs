@@ -0,0 +1,269 @@
+use oxc_ast::AstKind;
+use oxc_semantic::Semantic;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::fixer::{Fix, Message, PossibleFixes};
+
+/// Inserts `oxlint-disable-next-line` comments for the diagnostics of a script block, instead of
+/// fixing them. Used with [`LintOptions::suppress_all`].
+///
+/// The parts of the AST needed to insert the comments are collected before JS plugins run, because
+/// JS plugins take the [`Semantic`] of the script block.
+///
+/// [`LintOptions::suppress_all`]: crate::LintOptions::suppress_all
+pub struct Suppressor<'a> {
+    source_text: &'a str,
+    /// Line break used for the inserted comments, see [`crate::line_ending`].
+    line_ending: &'static str,
+    /// Offset of the script block in the file. Spans of diagnostics are relative to the file.
+    section_offset: u32,
+    /// Children of JSX elements and fragments, where comments are written as `{/* */}`.
+    jsx_children: Vec<Span>,
+    /// JSX tags, expression containers and spread children, where comments are written as usual.
+    jsx_expressions: Vec<Span>,
+    /// Strings, template literal text and comments spanning multiple lines, which can't contain a
+    /// comment. Diagnostics on lines starting inside of them are not suppressed.
+    multiline_text: Vec<Span>,
+}
+
+impl<'a> Suppressor<'a> {
+    pub fn new(semantic: &Semantic<'a>, section_offset: u32, line_ending: &'static str) -> Self {
+        let source_text = semantic.source_text();
+        let is_multiline = |span: Span| span.source_text(source_text).contains(['\n', '\r']);
+
+        let mut jsx_children = vec![];
+        let mut jsx_expressions = vec![];
+        let mut multiline_text = vec![];
+        for node in semantic.nodes().iter() {
+            match node.kind() {
+                AstKind::JSXElement(element) => {
+                    if let Some(closing_element) = &element.closing_element {
+                        jsx_children.push(Span::new(
+                            element.opening_element.span.end,
+                            closing_element.span.start,
+                        ));
+                    }
+                }
+                AstKind::JSXFragment(fragment) => {
+                    jsx_children.push(Span::new(
+                        fragment.opening_fragment.span.end,
+                        fragment.closing_fragment.span.start,
+                    ));
+                }
+                AstKind::JSXOpeningElement(element) => jsx_expressions.push(element.span),
+                AstKind::JSXExpressionContainer(container) => jsx_expressions.push(container.span),
+                AstKind::JSXSpreadChild(child) => jsx_expressions.push(child.span),
+                AstKind::StringLiteral(string) if is_multiline(string.span) => {
+                    multiline_text.push(string.span);
+                }
+                AstKind::TemplateLiteral(template) => {
+                    multiline_text.extend(
+                        template
+                            .quasis
+                            .iter()
+                            .map(|quasi| quasi.span)
+                            .filter(|&span| is_multiline(span)),
+                    );
+                }
+                _ => {}
+            }
+        }
+        multiline_text.extend(
+            semantic
+                .comments()
+                .iter()
+                .filter(|comment| comment.is_multiline_block())
+                .map(|comment| comment.span),
+        );
+
+        Self {
+            source_text,
+            line_ending,
+            section_offset,
+            jsx_children,
+            jsx_expressions,
+            multiline_text,
+        }
+    }
+
+    /// Replaces the fixes of `messages` with the insertion of an `oxlint-disable-next-line`
+    /// comment above each line with diagnostics, which lists the rules reported on that line.
+    ///
+    /// Messages from `external_start` on were reported by JS plugins, and their rules are
+    /// prefixed with the plugin name. Messages which don't belong to a rule are not suppressed.
+    pub fn suppress(&self, messages: &mut [Message], external_start: usize) {
+        let mut lines: FxHashMap<u32, Vec<String>> = FxHashMap::default();
+        let mut line_starts = Vec::with_capacity(messages.len());
+        for (index, message) in messages.iter().enumerate() {
+            let line_start = message.error.code.number.as_deref().and_then(|rule_name| {
+                let line_start = self.line_start(message.span.start)?;
+                let rule_name = match &message.error.code.scope {
+                    Some(plugin_name) if index >= external_start => {
+                        format!("{plugin_name}/{rule_name}")
+                    }
+                    _ => rule_name.to_string(),
+                };
+                let rules = lines.entry(line_start).or_default();
+                if !rules.contains(&rule_name) {
+                    rules.push(rule_name);
+                }
+                Some(line_start)
+            });
+            line_starts.push(line_start);
+        }
+
+        for (message, line_start) in messages.iter_mut().zip(line_starts) {
+            // Every message of a line gets a fix, so all of them are removed once the comment is
+            // inserted. Only the fix of the first message inserts the comment.
+            message.fixes = match line_start {
+                Some(line_start) => PossibleFixes::Single(match lines.remove(&line_start) {
+                    Some(rules) => self.disable_comment(line_start, &rules),
+                    None => Fix::new("", Span::empty(line_start + self.section_offset)),
+                }),
+                None => PossibleFixes::None,
+            };
+        }
+    }
+
+    /// Start of the line containing `offset`, relative to the script block.
+    ///
+    /// Returns `None` if no comment can be inserted above the line.
+    fn line_start(&self, offset: u32) -> Option<u32> {
+        let offset = offset.checked_sub(self.section_offset)?;
+        let before = self.source_text.get(..offset as usize)?;
+        #[expect(clippy::cast_possible_truncation)]
+        let line_start = before.rfind(['\n', '\r']).map_or(0, |index| index as u32 + 1);
+        if self.multiline_text.iter().any(|span| span.start < line_start && line_start <= span.end)
+        {
+            return None;
+        }
+        Some(line_start)
+    }
+
+    fn disable_comment(&self, line_start: u32, rules: &[String]) -> Fix {
+        let rest = &self.source_text[line_start as usize..];
+        let indent = &rest[..rest.len() - rest.trim_start_matches([' ', '\t']).len()];
+        // A script block of a framework file can start in the middle of a line.
+        let line_ending = self.line_ending;
+        let line_break = if line_start == 0 && self.section_offset > 0 { line_ending } else { "" };
+        let directive = format!("oxlint-disable-next-line {} -- TODO", rules.join(", "));
+        #[expect(clippy::cast_possible_truncation)]
+        let comment = if self.is_jsx_child(line_start + indent.len() as u32) {
+            format!("{line_break}{indent}{{/* {directive} */}}{line_ending}")
+        } else {
+            format!("{line_break}{indent}// {directive}{line_ending}")
+        };
+        Fix::new(comment, Span::empty(line_start + self.section_offset))
+    }
+
+    /// Whether `offset` is a child of a JSX element or fragment, and not part of a JSX tag or
+    /// expression container within the children.
+    fn is_jsx_child(&self, offset: u32) -> bool {
+        // Spans are nested, so the innermost span containing `offset` starts last.
+        // A closing tag at `offset` ends the children, but a comment before it is still a child.
+        let children = self
+            .jsx_children
+            .iter()
+            .filter(|span| span.start < offset && offset <= span.end)
+            .map(|span| span.start)
+            .max();
+        let expression = self
+            .jsx_expressions
+            .iter()
+            .filter(|span| span.start < offset && offset < span.end)
+            .map(|span| span.start)
+            .max();
+        match (children, expression) {
+            (Some(children), Some(expression)) => children > expression,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{SourceType, Span};
+
+    use super::Suppressor;
+    use crate::fixer::{Fixer, Message, PossibleFixes, line_ending};
+
+    fn suppress(source_text: &str, rules: &[(&'static str, u32)]) -> String {
+        let allocator = Allocator::default();
+        let source_type = SourceType::jsx();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let mut messages = rules
+            .iter()
+            .map(|&(rule_name, offset)| {
+                Message::new(
+                    OxcDiagnostic::warn("problem")
+                        .with_error_code("eslint", rule_name)
+                        .with_label(Span::new(offset, offset + 1)),
+                    PossibleFixes::None,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let external_start = messages.len();
+        Suppressor::new(&semantic, 0, line_ending(source_text))
+            .suppress(&mut messages, external_start);
+
+        let result = Fixer::new(source_text, messages, Some(source_type)).fix();
+        assert!(result.messages.is_empty(), "all messages should be suppressed");
+        result.fixed_code.into_owned()
+    }
+
+    #[test]
+    fn suppress_lines() {
+        assert_eq!(
+            suppress(
+                "debugger;\nif (a) {\n  var b = 1; debugger;\n}\n",
+                &[("no-debugger", 0), ("no-var", 21), ("no-debugger", 32), ("no-var", 21)],
+            ),
+            "// oxlint-disable-next-line no-debugger -- TODO\ndebugger;\nif (a) {\n  // oxlint-disable-next-line no-var, no-debugger -- TODO\n  var b = 1; debugger;\n}\n"
+        );
+    }
+
+    #[test]
+    fn suppress_crlf_lines() {
+        assert_eq!(
+            suppress("a();\r\n  debugger;\r\n", &[("no-debugger", 8)]),
+            "a();\r\n  // oxlint-disable-next-line no-debugger -- TODO\r\n  debugger;\r\n"
+        );
+    }
+
+    #[test]
+    fn suppress_jsx_children() {
+        assert_eq!(
+            suppress(
+                "const a = (\n  <div\n    id={b}\n  >\n    <span>{c}</span>\n    {d}\n  </div>\n);\n",
+                &[("jsx-key", 14), ("no-undef", 25), ("jsx-key", 39), ("no-undef", 60)],
+            ),
+            "const a = (\n  // oxlint-disable-next-line jsx-key -- TODO\n  <div\n    // oxlint-disable-next-line no-undef -- TODO\n    id={b}\n  >\n    {/* oxlint-disable-next-line jsx-key -- TODO */}\n    <span>{c}</span>\n    {/* oxlint-disable-next-line no-undef -- TODO */}\n    {d}\n  </div>\n);\n"
+        );
+    }
+
+    #[test]
+    fn skip_lines_inside_template_literals() {
+        let allocator = Allocator::default();
+        let source_text = "const a = `\n${b}`;\n";
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let mut messages = vec![Message::new(
+            OxcDiagnostic::warn("problem")
+                .with_error_code("eslint", "no-undef")
+                .with_label(Span::new(14, 15)),
+            PossibleFixes::None,
+        )];
+
+        Suppressor::new(&semantic, 0, "\n").suppress(&mut messages, 1);
+
+        assert!(messages[0].fixes.is_empty());
+    }
+}
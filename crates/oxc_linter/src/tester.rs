@@ -22,6 +22,7 @@ use crate::{
     external_plugin_store::ExternalPluginStore,
     fixer::{FixKind, Fixer},
     options::LintOptions,
+    rule_docs::{RULE_EXAMPLES_DIR_ENV, RuleExample, RuleExamples},
     rules::RULES,
     service::RuntimeFileSystem,
     utils::read_to_arena_str,
@@ -423,6 +424,26 @@ impl Tester {
             self.plugin_name,
             self.rule_name
         );
+
+        if let Some(dir) = env::var_os(RULE_EXAMPLES_DIR_ENV) {
+            self.record_examples(Path::new(&dir));
+        }
+    }
+
+    /// Records the pass and fail cases for the rule documentation, see [`crate::rule_docs`].
+    fn record_examples(&self, dir: &Path) {
+        let examples = |cases: &[TestCase]| {
+            cases
+                .iter()
+                .map(|case| RuleExample {
+                    code: case.source.clone(),
+                    options: case.rule_config.clone(),
+                })
+                .collect()
+        };
+        RuleExamples { pass: examples(&self.expect_pass), fail: examples(&self.expect_fail) }
+            .record(dir, self.find_rule().plugin_name(), self.rule_name)
+            .unwrap();
     }
 
     pub fn test_and_snapshot(&mut self) {
@@ -0,0 +1,88 @@
+use std::{sync::Mutex, time::Duration};
+
+use rustc_hash::FxHashMap;
+
+/// Time spent in each lint rule, collected across all files linted by a [`Linter`] with
+/// [`Linter::with_timings`].
+///
+/// Only built-in rules are timed. Rules are timed per file, so collecting timings changes how
+/// rules are run on very large files, which makes the run itself slightly slower.
+///
+/// [`Linter`]: crate::Linter
+/// [`Linter::with_timings`]: crate::Linter::with_timings
+#[derive(Debug, Default)]
+pub struct RuleTimings {
+    rules: Mutex<FxHashMap<(&'static str, &'static str), RuleTiming>>,
+}
+
+/// Time spent in a lint rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleTiming {
+    pub plugin_name: &'static str,
+    pub rule_name: &'static str,
+    /// Wall time spent running the rule, summed over all threads.
+    pub duration: Duration,
+    /// How often the rule's `run`, `run_once` and `run_on_jest_node` functions were called.
+    pub calls: usize,
+}
+
+impl RuleTimings {
+    /// Add the timings of rules run on a file.
+    pub(crate) fn record(&self, timings: Vec<RuleTiming>) {
+        let mut rules = self.rules.lock().expect("rule timings mutex poisoned");
+        for timing in timings {
+            rules
+                .entry((timing.plugin_name, timing.rule_name))
+                .and_modify(|total| {
+                    total.duration += timing.duration;
+                    total.calls += timing.calls;
+                })
+                .or_insert(timing);
+        }
+    }
+
+    /// Timings of all rules which were run, slowest first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a thread panicked while recording timings.
+    pub fn sorted(&self) -> Vec<RuleTiming> {
+        let rules = self.rules.lock().expect("rule timings mutex poisoned");
+        let mut timings = rules.values().copied().collect::<Vec<_>>();
+        timings.sort_unstable_by(|a, b| {
+            b.duration
+                .cmp(&a.duration)
+                .then_with(|| a.plugin_name.cmp(b.plugin_name))
+                .then_with(|| a.rule_name.cmp(b.rule_name))
+        });
+        timings
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{RuleTiming, RuleTimings};
+
+    fn timing(rule_name: &'static str, millis: u64, calls: usize) -> RuleTiming {
+        RuleTiming {
+            plugin_name: "eslint",
+            rule_name,
+            duration: Duration::from_millis(millis),
+            calls,
+        }
+    }
+
+    #[test]
+    fn sums_and_sorts_timings() {
+        let timings = RuleTimings::default();
+        timings.record(vec![timing("no-debugger", 1, 2), timing("no-unused-vars", 3, 1)]);
+        timings.record(vec![timing("no-debugger", 4, 3), timing("eqeqeq", 3, 5)]);
+
+        assert_eq!(
+            timings.sorted(),
+            [timing("no-debugger", 5, 5), timing("eqeqeq", 3, 5), timing("no-unused-vars", 3, 1)]
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+/// The compiler options of a `tsconfig.json` which change what rules report or fix, merged
+/// with the options of the configs it `extends`.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CompilerOptions {
+    pub verbatim_module_syntax: Option<bool>,
+    pub isolated_modules: Option<bool>,
+    pub emit_decorator_metadata: Option<bool>,
+    pub experimental_decorators: Option<bool>,
+    /// e.g. `react`, `react-jsx` or `preserve`.
+    pub jsx: Option<String>,
+    /// Module path aliases, e.g. `{ "@/*": ["./src/*"] }`.
+    pub paths: Option<FxHashMap<String, Vec<String>>>,
+}
+
+impl CompilerOptions {
+    pub fn verbatim_module_syntax(&self) -> bool {
+        self.verbatim_module_syntax.unwrap_or(false)
+    }
+
+    /// Whether each file is compiled on its own, which requires re-exported types to be
+    /// imported or exported with `type`. Implied by `verbatimModuleSyntax`.
+    pub fn isolated_modules(&self) -> bool {
+        self.isolated_modules.unwrap_or(false) || self.verbatim_module_syntax()
+    }
+
+    /// Whether the types of decorated declarations are emitted as values, in which case the
+    /// imports of these types are needed at runtime.
+    pub fn emits_decorator_metadata(&self) -> bool {
+        self.emit_decorator_metadata.unwrap_or(false)
+            && self.experimental_decorators.unwrap_or(false)
+    }
+
+    /// Whether JSX is compiled to calls of `React.createElement`, which need `React` in scope.
+    pub fn jsx_needs_react(&self) -> bool {
+        !matches!(self.jsx.as_deref(), Some("react-jsx" | "react-jsxdev" | "preserve"))
+    }
+
+    /// Whether `specifier` matches a pattern of `paths`, e.g. `@/utils` for `@/*`.
+    pub fn has_path_alias(&self, specifier: &str) -> bool {
+        self.paths.iter().flat_map(FxHashMap::keys).any(|pattern| match pattern.split_once('*') {
+            Some((prefix, suffix)) => {
+                specifier.len() >= prefix.len() + suffix.len()
+                    && specifier.starts_with(prefix)
+                    && specifier.ends_with(suffix)
+            }
+            None => pattern == specifier,
+        })
+    }
+
+    /// `self`, with the options it doesn't set taken from `base`.
+    fn extend(self, base: Self) -> Self {
+        Self {
+            verbatim_module_syntax: self.verbatim_module_syntax.or(base.verbatim_module_syntax),
+            isolated_modules: self.isolated_modules.or(base.isolated_modules),
+            emit_decorator_metadata: self.emit_decorator_metadata.or(base.emit_decorator_metadata),
+            experimental_decorators: self.experimental_decorators.or(base.experimental_decorators),
+            jsx: self.jsx.or(base.jsx),
+            paths: self.paths.or(base.paths),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct TsConfigJson {
+    extends: Option<Extends>,
+    compiler_options: CompilerOptions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Extends {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// How many configs a `tsconfig.json` may extend transitively, which also stops cycles.
+const MAX_EXTENDS_DEPTH: usize = 8;
+
+/// Finds the nearest `tsconfig.json` of linted files. Each `tsconfig.json` is read once per lint
+/// run, and shared by the files of its project.
+#[derive(Debug, Default)]
+pub struct TsConfigCache {
+    /// The compiler options of the `tsconfig.json` in each directory looked up, if it exists and
+    /// can be parsed.
+    by_dir: Mutex<FxHashMap<PathBuf, Option<Arc<CompilerOptions>>>>,
+}
+
+impl TsConfigCache {
+    /// The compiler options of the nearest `tsconfig.json` of the file at `path`, in its
+    /// directory or a parent directory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a thread panicked while looking up a `tsconfig.json`.
+    pub fn nearest(&self, path: &Path) -> Option<Arc<CompilerOptions>> {
+        path.ancestors().skip(1).find_map(|dir| self.in_dir(dir))
+    }
+
+    fn in_dir(&self, dir: &Path) -> Option<Arc<CompilerOptions>> {
+        if let Some(options) = self.by_dir.lock().expect("tsconfig.json cache poisoned").get(dir) {
+            return options.clone();
+        }
+        let options = read_compiler_options(&dir.join("tsconfig.json"), 0).map(Arc::new);
+        self.by_dir
+            .lock()
+            .expect("tsconfig.json cache poisoned")
+            .insert(dir.to_path_buf(), options.clone());
+        options
+    }
+}
+
+fn read_compiler_options(path: &Path, depth: usize) -> Option<CompilerOptions> {
+    let mut text = std::fs::read_to_string(path).ok()?;
+    json_strip_comments::strip(&mut text).ok()?;
+    let config = serde_json::from_str::<TsConfigJson>(&text).ok()?;
+    let dir = path.parent()?;
+    let extends = match config.extends {
+        None => vec![],
+        Some(Extends::One(extends)) => vec![extends],
+        Some(Extends::Many(extends)) => extends,
+    };
+    let base = if depth < MAX_EXTENDS_DEPTH {
+        // Later configs override earlier ones.
+        extends
+            .iter()
+            .filter_map(|extends| resolve_extends(dir, extends))
+            .filter_map(|path| read_compiler_options(&path, depth + 1))
+            .fold(CompilerOptions::default(), |base, options| options.extend(base))
+    } else {
+        CompilerOptions::default()
+    };
+    Some(config.compiler_options.extend(base))
+}
+
+/// The path of a config extended by a config in `dir`: a relative path, or a path in a package
+/// in `node_modules`.
+fn resolve_extends(dir: &Path, extends: &str) -> Option<PathBuf> {
+    let candidates = |path: PathBuf| {
+        let mut with_extension = path.clone().into_os_string();
+        with_extension.push(".json");
+        [path.clone(), PathBuf::from(with_extension), path.join("tsconfig.json")]
+    };
+    if extends.starts_with('.') || Path::new(extends).is_absolute() {
+        return candidates(dir.join(extends)).into_iter().find(|path| path.is_file());
+    }
+    dir.ancestors()
+        .map(|dir| dir.join("node_modules").join(extends))
+        .flat_map(candidates)
+        .find(|path| path.is_file())
+}
@@ -15,7 +15,7 @@ use oxc_span::{SourceType, Span};
 
 use super::{AllowWarnDeny, ConfigStore, DisableDirectives, ResolvedLinterState, read_to_string};
 
-use crate::{CompositeFix, FixKind, Fixer, Message, PossibleFixes};
+use crate::{CompositeFix, FixKind, FixOnly, Fixer, Message, PossibleFixes};
 
 /// State required to initialize the `tsgolint` linter.
 #[derive(Debug, Clone)]
@@ -35,6 +35,8 @@ pub struct TsGoLintState {
     fix_suggestions: bool,
     /// If `true`, include TypeScript compiler syntactic and semantic diagnostics.
     type_check: bool,
+    /// Rules whose fixes are applied, if not all of them.
+    fix_only: Option<Arc<FixOnly>>,
 }
 
 impl TsGoLintState {
@@ -50,6 +52,7 @@ impl TsGoLintState {
             fix: fix_kind.contains(FixKind::Fix),
             fix_suggestions: fix_kind.contains(FixKind::Suggestion),
             type_check: false,
+            fix_only: None,
         }
     }
 
@@ -72,6 +75,7 @@ impl TsGoLintState {
             fix: fix_kind.contains(FixKind::Fix),
             fix_suggestions: fix_kind.contains(FixKind::Suggestion),
             type_check: false,
+            fix_only: None,
         })
     }
 
@@ -94,6 +98,13 @@ impl TsGoLintState {
         self
     }
 
+    /// Only apply the fixes of the rules in `fix_only`.
+    #[must_use]
+    pub fn with_fix_only(mut self, fix_only: Option<Arc<FixOnly>>) -> Self {
+        self.fix_only = fix_only;
+        self
+    }
+
     /// # Panics
     /// - when `stdin` of subprocess cannot be opened
     /// - when `stdout` of subprocess cannot be opened
@@ -140,7 +151,8 @@ impl TsGoLintState {
                         self.silent,
                         should_fix,
                         error_sender,
-                    );
+                    )
+                    .with_fix_only(self.fix_only.clone());
 
                     let msg_iter = TsGoLintMessageStream::new(stdout);
 
@@ -729,7 +741,7 @@ impl From<TsGoLintInternalDiagnostic> for OxcDiagnostic {
 impl Message {
     /// Converts a `TsGoLintDiagnostic` into a `Message` with possible fixes.
     fn from_tsgo_lint_diagnostic(mut val: TsGoLintRuleDiagnostic, source_text: &str) -> Self {
-        use std::{borrow::Cow, mem};
+        use std::mem;
 
         let mut fixes =
             Vec::with_capacity(usize::from(!val.fixes.is_empty()) + val.suggestions.len());
@@ -738,10 +750,8 @@ impl Message {
             let fix_vec = mem::take(&mut val.fixes);
             let fix_vec = fix_vec
                 .into_iter()
-                .map(|fix| crate::fixer::Fix {
-                    content: Cow::Owned(fix.text),
-                    span: Span::new(fix.range.pos, fix.range.end),
-                    message: None,
+                .map(|fix| {
+                    crate::fixer::Fix::new(fix.text, Span::new(fix.range.pos, fix.range.end))
                 })
                 .collect();
 
@@ -763,11 +773,9 @@ impl Message {
                         mem::take(&mut suggestion.message.description)
                     };
 
-                    crate::fixer::Fix {
-                        content: Cow::Owned(fix.text),
-                        span: Span::new(fix.range.pos, fix.range.end),
-                        message: Some(Cow::Owned(message)),
-                    }
+                    crate::fixer::Fix::new(fix.text, Span::new(fix.range.pos, fix.range.end))
+                        .with_message(message)
+                        .with_kind(FixKind::Suggestion)
                 })
                 .collect();
 
@@ -935,6 +943,8 @@ struct DiagnosticHandler {
     cwd: PathBuf,
     silent: bool,
     should_fix: bool,
+    /// Rules whose fixes are applied, if not all of them.
+    fix_only: Option<Arc<FixOnly>>,
     source_text_cache: SourceTextCache,
     error_sender: DiagnosticSender,
     /// Messages requiring fixes, grouped by file path: messages.
@@ -947,12 +957,18 @@ impl DiagnosticHandler {
             cwd,
             silent,
             should_fix,
+            fix_only: None,
             source_text_cache: SourceTextCache::default(),
             error_sender,
             messages_requiring_fixes: FxHashMap::default(),
         }
     }
 
+    fn with_fix_only(mut self, fix_only: Option<Arc<FixOnly>>) -> Self {
+        self.fix_only = fix_only;
+        self
+    }
+
     fn get_source_text(&mut self, path: &Path) -> &str {
         if self.silent && !self.should_fix {
             // The source text is not needed in silent mode, the diagnostic isn't printed.
@@ -968,8 +984,12 @@ impl DiagnosticHandler {
         severity: AllowWarnDeny,
     ) {
         let path = diagnostic.file_path.clone();
-        let has_fixes =
-            self.should_fix && (!diagnostic.fixes.is_empty() || !diagnostic.suggestions.is_empty());
+        let has_fixes = self.should_fix
+            && (!diagnostic.fixes.is_empty() || !diagnostic.suggestions.is_empty())
+            && self
+                .fix_only
+                .as_ref()
+                .is_none_or(|fix_only| fix_only.contains("typescript", &diagnostic.rule));
 
         if has_fixes {
             // Collect for later fix application
@@ -1215,6 +1235,7 @@ mod test {
     use oxc_span::Span;
 
     use crate::{
+        FixKind,
         fixer::{Message, PossibleFixes},
         tsgolint::{Fix, Range, RuleMessage, Suggestion, TsGoLintRuleDiagnostic},
     };
@@ -1277,6 +1298,7 @@ mod test {
                 content: "fixedhello".into(),
                 span: Span::new(0, 10),
                 message: None,
+                kind: FixKind::SafeFix,
             })
         );
     }
@@ -1325,11 +1347,13 @@ mod test {
                     content: "hello".into(),
                     span: Span::new(0, 5),
                     message: Some("Suggestion 1".into()),
+                    kind: FixKind::Suggestion,
                 },
                 crate::fixer::Fix {
                     content: "helloworld".into(),
                     span: Span::new(0, 10),
                     message: Some("Suggestion 2".into()),
+                    kind: FixKind::Suggestion,
                 },
             ])
         );
@@ -1363,11 +1387,17 @@ mod test {
         assert_eq!(
             message.fixes,
             PossibleFixes::Multiple(vec![
-                crate::fixer::Fix { content: "fixed".into(), span: Span::new(0, 5), message: None },
+                crate::fixer::Fix {
+                    content: "fixed".into(),
+                    span: Span::new(0, 5),
+                    message: None,
+                    kind: FixKind::SafeFix,
+                },
                 crate::fixer::Fix {
                     content: "Suggestion 1".into(),
                     span: Span::new(0, 5),
                     message: Some("Suggestion 1".into()),
+                    kind: FixKind::Suggestion,
                 },
             ])
         );
@@ -0,0 +1,329 @@
+//! ARIA role taxonomy and the implicit semantics of HTML elements, used by the `jsx-a11y` rules
+//! which tell interactive elements from non-interactive and static ones.
+//!
+//! The tables follow WAI-ARIA 1.2 and HTML-AAM, as published in
+//! [aria-query](https://github.com/A11yance/aria-query/tree/v5.3.2/src) and
+//! [axobject-query](https://github.com/A11yance/axobject-query/tree/v4.1.0/src), which
+//! eslint-plugin-jsx-a11y is built on.
+
+use cow_utils::CowUtils;
+use oxc_ast::ast::{JSXAttributeItem, JSXAttributeValue, JSXExpression, JSXOpeningElement};
+
+use crate::{
+    globals::VALID_ARIA_ROLES,
+    utils::{
+        get_prop_value, get_string_literal_prop_value, has_jsx_prop, has_jsx_prop_ignore_case,
+    },
+};
+
+/// Roles used by the ARIA spec to organize the taxonomy of roles, which must not be used in
+/// content.
+///
+/// Reference: <https://www.w3.org/TR/wai-aria-1.2/#abstract_roles>
+pub const ABSTRACT_ARIA_ROLES: phf::Set<&'static str> = phf::phf_set![
+    "command",
+    "composite",
+    "input",
+    "landmark",
+    "range",
+    "roletype",
+    "section",
+    "sectionhead",
+    "select",
+    "structure",
+    "widget",
+    "window",
+];
+
+/// Roles of widgets, i.e. the roles which descend from the abstract `widget` role, and `toolbar`,
+/// which groups widgets.
+///
+/// Reference: <https://www.w3.org/TR/wai-aria-1.2/#widget_roles>
+pub const INTERACTIVE_ARIA_ROLES: phf::Set<&'static str> = phf::phf_set![
+    "button",
+    "checkbox",
+    "columnheader",
+    "combobox",
+    "doc-backlink",
+    "doc-biblioref",
+    "doc-glossref",
+    "doc-noteref",
+    "grid",
+    "gridcell",
+    "link",
+    "listbox",
+    "menu",
+    "menubar",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "option",
+    "radio",
+    "radiogroup",
+    "row",
+    "rowheader",
+    "scrollbar",
+    "searchbox",
+    "separator",
+    "slider",
+    "spinbutton",
+    "switch",
+    "tab",
+    "tablist",
+    "textbox",
+    "toolbar",
+    "tree",
+    "treegrid",
+    "treeitem",
+];
+
+/// Implicit ARIA roles of HTML elements which don't depend on their attributes.
+///
+/// Reference: <https://www.w3.org/TR/html-aam-1.0/#html-element-role-mappings>
+const IMPLICIT_ARIA_ROLES: phf::Map<&'static str, &'static str> = phf::phf_map! {
+    "article" => "article",
+    "aside" => "complementary",
+    "blockquote" => "blockquote",
+    "body" => "document",
+    "button" => "button",
+    "caption" => "caption",
+    "code" => "code",
+    "datalist" => "listbox",
+    "dd" => "definition",
+    "del" => "deletion",
+    "details" => "group",
+    "dfn" => "term",
+    "dialog" => "dialog",
+    "dt" => "term",
+    "em" => "emphasis",
+    "fieldset" => "group",
+    "figure" => "figure",
+    "footer" => "contentinfo",
+    "form" => "form",
+    "h1" => "heading",
+    "h2" => "heading",
+    "h3" => "heading",
+    "h4" => "heading",
+    "h5" => "heading",
+    "h6" => "heading",
+    "header" => "banner",
+    "hr" => "separator",
+    "html" => "document",
+    "ins" => "insertion",
+    "li" => "listitem",
+    "main" => "main",
+    "mark" => "mark",
+    "marquee" => "marquee",
+    "math" => "math",
+    "menu" => "list",
+    "menuitem" => "menuitem",
+    "meter" => "meter",
+    "nav" => "navigation",
+    "ol" => "list",
+    "optgroup" => "group",
+    "option" => "option",
+    "output" => "status",
+    "p" => "paragraph",
+    "progress" => "progressbar",
+    "search" => "search",
+    "section" => "region",
+    "strong" => "strong",
+    "sub" => "subscript",
+    "sup" => "superscript",
+    "table" => "table",
+    "tbody" => "rowgroup",
+    "td" => "cell",
+    "textarea" => "textbox",
+    "tfoot" => "rowgroup",
+    "th" => "columnheader",
+    "thead" => "rowgroup",
+    "time" => "time",
+    "tr" => "row",
+    "ul" => "list",
+};
+
+/// Elements without an implicit role which user agents still make interactive, with their own
+/// controls or as a canvas for scripted widgets.
+const INTERACTIVE_ELEMENTS_WITHOUT_ROLE: phf::Set<&'static str> =
+    phf::phf_set!["audio", "canvas", "embed", "summary", "video"];
+
+/// Elements without an implicit role which have non-interactive semantics for assistive
+/// technologies.
+const NON_INTERACTIVE_ELEMENTS_WITHOUT_ROLE: phf::Set<&'static str> = phf::phf_set![
+    "abbr",
+    "address",
+    "br",
+    "dir",
+    "dl",
+    "figcaption",
+    "iframe",
+    "label",
+    "legend",
+    "picture",
+    "pre",
+    "ruby",
+];
+
+/// Event handler props for keyboard events.
+pub const KEYBOARD_EVENT_HANDLERS: [&str; 3] = ["onKeyDown", "onKeyPress", "onKeyUp"];
+
+/// Event handler props for mouse events.
+pub const MOUSE_EVENT_HANDLERS: [&str; 19] = [
+    "onClick",
+    "onContextMenu",
+    "onDblClick",
+    "onDoubleClick",
+    "onDrag",
+    "onDragEnd",
+    "onDragEnter",
+    "onDragExit",
+    "onDragLeave",
+    "onDragOver",
+    "onDragStart",
+    "onDrop",
+    "onMouseDown",
+    "onMouseEnter",
+    "onMouseLeave",
+    "onMouseMove",
+    "onMouseOut",
+    "onMouseOver",
+    "onMouseUp",
+];
+
+pub fn is_abstract_role(role: &str) -> bool {
+    ABSTRACT_ARIA_ROLES.contains(role)
+}
+
+pub fn is_interactive_role(role: &str) -> bool {
+    INTERACTIVE_ARIA_ROLES.contains(role)
+}
+
+/// Whether `role` is a concrete role with semantics other than those of a widget. `generic`,
+/// `presentation` and `none` are neither interactive nor non-interactive.
+pub fn is_non_interactive_role(role: &str) -> bool {
+    VALID_ARIA_ROLES.contains(role)
+        && !INTERACTIVE_ARIA_ROLES.contains(role)
+        && !matches!(role, "generic" | "none" | "presentation")
+}
+
+/// The role given to the element by its `role` attribute, i.e. the first role of the list which
+/// is a valid or abstract role, as user agents fall back to the later roles.
+pub fn get_explicit_role<'a>(jsx_opening_el: &'a JSXOpeningElement<'_>) -> Option<&'a str> {
+    let role = has_jsx_prop(jsx_opening_el, "role").and_then(get_string_literal_prop_value)?;
+    role.split_ascii_whitespace()
+        .find(|role| VALID_ARIA_ROLES.contains(role) || ABSTRACT_ARIA_ROLES.contains(role))
+}
+
+/// The role an HTML element has without a `role` attribute.
+pub fn get_implicit_role(
+    element_type: &str,
+    jsx_opening_el: &JSXOpeningElement,
+) -> Option<&'static str> {
+    let has_prop = |name| has_jsx_prop_ignore_case(jsx_opening_el, name).is_some();
+    let prop_value = |name| {
+        has_jsx_prop_ignore_case(jsx_opening_el, name).and_then(get_string_literal_prop_value)
+    };
+
+    match element_type {
+        "a" | "area" => has_prop("href").then_some("link"),
+        "img" => match prop_value("alt") {
+            Some("") => Some("presentation"),
+            _ => Some("img"),
+        },
+        "input" => {
+            let input_type = prop_value("type").unwrap_or("text");
+            match input_type.cow_to_ascii_lowercase().as_ref() {
+                "button" | "image" | "reset" | "submit" => Some("button"),
+                "checkbox" => Some("checkbox"),
+                "radio" => Some("radio"),
+                "range" => Some("slider"),
+                "number" => Some("spinbutton"),
+                "email" | "search" | "tel" | "text" | "url" if has_prop("list") => Some("combobox"),
+                "search" => Some("searchbox"),
+                "hidden" => None,
+                // `text`, `email`, `tel` and `url`, and other types, e.g. `date`, with their own controls.
+                _ => Some("textbox"),
+            }
+        }
+        "select" => {
+            let is_list = has_prop("multiple")
+                || prop_value("size")
+                    .and_then(|size| size.parse::<u32>().ok())
+                    .is_some_and(|size| size > 1);
+            Some(if is_list { "listbox" } else { "combobox" })
+        }
+        _ => IMPLICIT_ARIA_ROLES.get(element_type).copied(),
+    }
+}
+
+/// Whether the HTML element is interactive by its own semantics, regardless of its `role`
+/// attribute.
+///
+/// ref: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.10.2/src/util/isInteractiveElement.js>
+pub fn is_interactive_html_element(element_type: &str, jsx_opening_el: &JSXOpeningElement) -> bool {
+    if INTERACTIVE_ELEMENTS_WITHOUT_ROLE.contains(element_type) {
+        return true;
+    }
+    get_implicit_role(element_type, jsx_opening_el).is_some_and(is_interactive_role)
+}
+
+/// Whether the HTML element has non-interactive semantics, regardless of its `role` attribute.
+///
+/// ref: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.10.2/src/util/isNonInteractiveElement.js>
+pub fn is_non_interactive_html_element(
+    element_type: &str,
+    jsx_opening_el: &JSXOpeningElement,
+) -> bool {
+    if NON_INTERACTIVE_ELEMENTS_WITHOUT_ROLE.contains(element_type) {
+        return true;
+    }
+    get_implicit_role(element_type, jsx_opening_el).is_some_and(is_non_interactive_role)
+}
+
+/// Whether the element is disabled, by its `disabled` attribute or by `aria-disabled="true"`.
+pub fn is_disabled_element(jsx_opening_el: &JSXOpeningElement) -> bool {
+    if let Some(disabled) = has_jsx_prop_ignore_case(jsx_opening_el, "disabled") {
+        return !is_false_prop(disabled);
+    }
+    has_jsx_prop_ignore_case(jsx_opening_el, "aria-disabled").and_then(get_prop_value).is_some_and(
+        |value| match value {
+            JSXAttributeValue::StringLiteral(lit) => lit.value == "true",
+            JSXAttributeValue::ExpressionContainer(container) => {
+                matches!(&container.expression, JSXExpression::BooleanLiteral(lit) if lit.value)
+            }
+            _ => false,
+        },
+    )
+}
+
+/// Whether the element is editable by its `contentEditable` attribute.
+pub fn is_content_editable(jsx_opening_el: &JSXOpeningElement) -> bool {
+    has_jsx_prop(jsx_opening_el, "contentEditable").is_some_and(|prop| {
+        get_string_literal_prop_value(prop).is_none_or(|value| value != "false")
+    })
+}
+
+/// Whether the element has an event handler prop named `name`, with a value other than `null` or
+/// `undefined`.
+pub fn has_event_handler(jsx_opening_el: &JSXOpeningElement, name: &str) -> bool {
+    let Some(JSXAttributeItem::Attribute(attr)) = has_jsx_prop(jsx_opening_el, name) else {
+        return false;
+    };
+    match &attr.value {
+        Some(JSXAttributeValue::ExpressionContainer(container)) => {
+            !matches!(&container.expression, JSXExpression::NullLiteral(_))
+                && !container.expression.is_undefined()
+        }
+        _ => true,
+    }
+}
+
+fn is_false_prop(item: &JSXAttributeItem) -> bool {
+    match get_prop_value(item) {
+        Some(JSXAttributeValue::ExpressionContainer(container)) => {
+            matches!(&container.expression, JSXExpression::BooleanLiteral(lit) if !lit.value)
+        }
+        Some(JSXAttributeValue::StringLiteral(lit)) => lit.value == "false",
+        _ => false,
+    }
+}
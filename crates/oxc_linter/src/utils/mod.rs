@@ -9,16 +9,22 @@ use std::{
 
 use oxc_allocator::Allocator;
 
+mod aria;
 mod comment;
 mod config;
 mod express;
 mod jest;
 mod jsdoc;
 mod nextjs;
+mod node;
 mod promise;
 mod react;
 mod react_perf;
 mod regex;
+mod regexp;
+mod security;
+mod sonarjs;
+mod suggest;
 mod typescript;
 mod unicorn;
 mod url;
@@ -26,8 +32,9 @@ mod vitest;
 mod vue;
 
 pub use self::{
-    comment::*, config::*, express::*, jest::*, jsdoc::*, nextjs::*, promise::*, react::*,
-    react_perf::*, regex::*, typescript::*, unicorn::*, url::*, vitest::*, vue::*,
+    aria::*, comment::*, config::*, express::*, jest::*, jsdoc::*, nextjs::*, node::*, promise::*,
+    react::*, react_perf::*, regex::*, regexp::*, security::*, sonarjs::*, suggest::*,
+    typescript::*, unicorn::*, url::*, vitest::*, vue::*,
 };
 
 /// List of Jest rules that have Vitest equivalents.
@@ -0,0 +1,189 @@
+use std::{borrow::Cow, fmt};
+
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, BindingPattern, Expression, ImportDeclarationSpecifier},
+};
+use oxc_semantic::SymbolId;
+
+use crate::{LintContext, ast_util::is_global_require_call};
+
+/// The Node.js versions assumed to be supported by a package without an `engines` field.
+pub const DEFAULT_NODE_VERSION_RANGE: &str = ">=16.0.0";
+
+/// A Node.js version, e.g. `16.7.0`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NodeVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl NodeVersion {
+    /// Parses a version like `16.7.0`, `v16.7` or `16.x`. Missing and wildcard parts are `0`.
+    pub fn parse(version: &str) -> Option<Self> {
+        let version = version.trim().trim_start_matches(['v', '=']).trim();
+        // Ignore pre-release and build metadata, e.g. `-rc.1`.
+        let version = version.split(['-', '+']).next()?;
+        let mut parts = version.split('.').map(|part| match part {
+            "x" | "X" | "*" => Some(0),
+            part => part.parse::<u32>().ok(),
+        });
+        let major = parts.next()??;
+        let minor = parts.next().unwrap_or(Some(0))?;
+        let patch = parts.next().unwrap_or(Some(0))?;
+        Some(Self { major, minor, patch })
+    }
+
+    /// The lowest version in a semver range like `>=14.17.0`, `^16 || ^18`, or `16.x`, if it
+    /// can be determined. Upper bounds are ignored.
+    pub fn min_of_range(range: &str) -> Option<Self> {
+        range
+            .split("||")
+            .map(|alternative| {
+                // A hyphen range, e.g. `14.0.0 - 16.0.0`, starts at its first version.
+                let alternative = alternative.split(" - ").next().unwrap_or(alternative);
+                let mut lower_bounds = vec![];
+                let mut tokens = alternative.split_whitespace();
+                while let Some(token) = tokens.next() {
+                    // An operator may be separated from its version, e.g. `>= 14`.
+                    let token = if token.chars().all(|c| matches!(c, '<' | '>' | '=' | '^' | '~')) {
+                        Cow::Owned(format!("{token}{}", tokens.next().unwrap_or_default()))
+                    } else {
+                        Cow::Borrowed(token)
+                    };
+                    if token.starts_with('<') {
+                        continue;
+                    }
+                    if token == "*" || token.eq_ignore_ascii_case("x") {
+                        lower_bounds.push(Self::default());
+                        continue;
+                    }
+                    lower_bounds.push(Self::parse(token.trim_start_matches(['>', '^', '~']))?);
+                }
+                Some(lower_bounds.into_iter().max().unwrap_or_default())
+            })
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .min()
+    }
+
+    /// Whether this version has a feature added in the versions `since`, i.e. the version
+    /// which first supported it, preceded by the earlier release lines it was backported to.
+    pub fn supports(self, since: &[&str]) -> bool {
+        let mut since = since.iter().filter_map(|version| Self::parse(version)).peekable();
+        while let Some(version) = since.next() {
+            if since.peek().is_none() {
+                return self >= version;
+            }
+            if self.major == version.major && self >= version {
+                return true;
+            }
+        }
+        true
+    }
+}
+
+impl fmt::Display for NodeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The range of Node.js versions supported by the linted file: `configured` if set, or the
+/// `engines.node` field of its nearest `package.json`.
+pub fn supported_node_version_range(ctx: &LintContext, configured: Option<&str>) -> String {
+    if let Some(configured) = configured {
+        return configured.to_string();
+    }
+    ctx.package_json()
+        .and_then(|package_json| {
+            package_json.engines.get("node").and_then(serde_json::Value::as_str).map(String::from)
+        })
+        .unwrap_or_else(|| DEFAULT_NODE_VERSION_RANGE.to_string())
+}
+
+/// The name of a Node.js module without the `node:` prefix, e.g. `fs` for `node:fs`.
+pub fn strip_node_protocol(specifier: &str) -> &str {
+    specifier.strip_prefix("node:").unwrap_or(specifier)
+}
+
+/// The path of the Node.js module member an expression refers to, e.g. `fs.promises.readFile`
+/// for `fs.promises.readFile` after `import fs from "node:fs"`, or `util.isArray` for
+/// `require("util").isArray`. References to globals, e.g. `Buffer`, are paths of their own.
+pub fn get_node_module_path<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> Option<String> {
+    match expr.without_parentheses() {
+        Expression::StaticMemberExpression(member) => {
+            let object = get_node_module_path(&member.object, ctx)?;
+            Some(format!("{object}.{}", member.property.name))
+        }
+        Expression::CallExpression(call) if is_global_require_call(call, ctx.semantic()) => {
+            let Argument::StringLiteral(specifier) = &call.arguments[0] else {
+                return None;
+            };
+            Some(strip_node_protocol(&specifier.value).to_string())
+        }
+        Expression::Identifier(ident) => {
+            let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id()
+            else {
+                return Some(ident.name.to_string());
+            };
+            get_node_module_path_of_symbol(symbol_id, ctx)
+        }
+        _ => None,
+    }
+}
+
+/// The path of the Node.js module member a variable is bound to, e.g. `fs.exists` for
+/// `exists` in `import { exists } from "fs"`, or `const { exists } = require("fs")`.
+pub fn get_node_module_path_of_symbol(symbol_id: SymbolId, ctx: &LintContext) -> Option<String> {
+    let declaration = ctx.scoping().symbol_declaration(symbol_id);
+    if let AstKind::ImportDeclaration(import) = ctx.nodes().parent_kind(declaration) {
+        let module = strip_node_protocol(&import.source.value);
+        let specifier = import
+            .specifiers
+            .as_ref()?
+            .iter()
+            .find(|specifier| specifier.local().symbol_id.get() == Some(symbol_id))?;
+        return match specifier {
+            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                Some(format!("{module}.{}", specifier.imported.name()))
+            }
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(_)
+            | ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => Some(module.to_string()),
+        };
+    }
+    let AstKind::VariableDeclarator(declarator) = ctx.nodes().kind(declaration) else {
+        return None;
+    };
+    if !declarator.kind.is_const() {
+        return None;
+    }
+    let init = get_node_module_path(declarator.init.as_ref()?, ctx)?;
+    get_bound_path(&declarator.id, symbol_id, init)
+}
+
+/// The path of the variable bound by `pattern` to a value at `path`, e.g. `fs` for `a` in
+/// `const a = fs`, or `fs.exists` for `a` in `const { exists: a } = fs`.
+fn get_bound_path(pattern: &BindingPattern, symbol_id: SymbolId, path: String) -> Option<String> {
+    match pattern {
+        BindingPattern::BindingIdentifier(ident) => {
+            (ident.symbol_id.get() == Some(symbol_id)).then_some(path)
+        }
+        BindingPattern::ObjectPattern(object) => object.properties.iter().find_map(|property| {
+            let binding = match &property.value {
+                BindingPattern::AssignmentPattern(assignment) => &assignment.left,
+                binding => binding,
+            };
+            match binding {
+                BindingPattern::BindingIdentifier(ident)
+                    if ident.symbol_id.get() == Some(symbol_id) =>
+                {
+                    Some(format!("{path}.{}", property.key.static_name()?))
+                }
+                _ => None,
+            }
+        }),
+        _ => None,
+    }
+}
@@ -3,13 +3,16 @@ use std::borrow::Cow;
 use oxc_ast::{
     AstKind,
     ast::{
-        CallExpression, Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue,
-        JSXChild, JSXElement, JSXElementName, JSXExpression, JSXMemberExpression,
-        JSXMemberExpressionObject, JSXOpeningElement, StaticMemberExpression,
+        ArrowFunctionExpression, CallExpression, Class, Expression, Function, JSXAttributeItem,
+        JSXAttributeName, JSXAttributeValue, JSXChild, JSXElement, JSXElementName, JSXExpression,
+        JSXMemberExpression, JSXMemberExpressionObject, JSXOpeningElement, PropertyKey,
+        ReturnStatement, StaticMemberExpression,
     },
 };
+use oxc_ast_visit::Visit;
 use oxc_ecmascript::{ToBoolean, WithoutGlobalReferenceInformation};
 use oxc_semantic::AstNode;
+use oxc_syntax::scope::ScopeFlags;
 
 use crate::{LintContext, OxlintSettings};
 
@@ -196,6 +199,127 @@ pub fn get_parent_component<'a, 'b>(
     ctx.nodes().ancestors(node.id()).find(|node| is_es5_component(node) || is_es6_component(node))
 }
 
+/// Whether `expr` renders JSX: a JSX element or fragment, a `createElement` call, or a
+/// conditional or logical expression with such a branch.
+pub fn is_jsx_expression(expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::JSXElement(_) | Expression::JSXFragment(_) => true,
+        Expression::CallExpression(call_expr) => is_create_element_call(call_expr),
+        Expression::ConditionalExpression(cond_expr) => {
+            is_jsx_expression(&cond_expr.consequent) || is_jsx_expression(&cond_expr.alternate)
+        }
+        Expression::LogicalExpression(logical_expr) => {
+            is_jsx_expression(&logical_expr.left) || is_jsx_expression(&logical_expr.right)
+        }
+        _ => false,
+    }
+}
+
+/// Whether the function or arrow function `node` returns JSX, from its expression body or from
+/// one of its own `return` statements.
+pub fn function_returns_jsx(node: &AstNode) -> bool {
+    let body = match node.kind() {
+        AstKind::Function(func) => func.body.as_deref(),
+        AstKind::ArrowFunctionExpression(arrow_func) => {
+            if let Some(expr) = arrow_func.get_expression() {
+                return is_jsx_expression(expr);
+            }
+            Some(&*arrow_func.body)
+        }
+        _ => None,
+    };
+    body.is_some_and(|body| {
+        let mut finder = JsxReturnFinder { found: false };
+        finder.visit_function_body(body);
+        finder.found
+    })
+}
+
+struct JsxReturnFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for JsxReturnFinder {
+    fn visit_return_statement(&mut self, it: &ReturnStatement<'a>) {
+        self.found |= it.argument.as_ref().is_some_and(is_jsx_expression);
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _it: &Class<'a>) {}
+}
+
+/// The name of the function or class `node`, or of the variable, property or export it is
+/// assigned to, looking through HOCs such as `memo(() => {})` and `forwardRef(() => {})`.
+///
+/// Anonymous default exports are named `default`.
+pub fn get_component_name<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<&'a str> {
+    match node.kind() {
+        AstKind::Function(Function { id: Some(id), .. })
+        | AstKind::Class(Class { id: Some(id), .. }) => return Some(id.name.as_str()),
+        _ => {}
+    }
+    for ancestor in ctx.nodes().ancestors(node.id()) {
+        match ancestor.kind() {
+            AstKind::CallExpression(call_expr) if is_hoc_call(call_expr) => {}
+            AstKind::ParenthesizedExpression(_)
+            | AstKind::TSAsExpression(_)
+            | AstKind::TSSatisfiesExpression(_) => {}
+            AstKind::VariableDeclarator(decl) => {
+                return decl.id.get_binding_identifier().map(|id| id.name.as_str());
+            }
+            AstKind::AssignmentExpression(assign_expr) => {
+                return assign_expr.left.get_identifier_name();
+            }
+            AstKind::ObjectProperty(prop) => return property_key_name(&prop.key),
+            AstKind::PropertyDefinition(prop) => return property_key_name(&prop.key),
+            AstKind::ExportDefaultDeclaration(_) => return Some("default"),
+            _ => return None,
+        }
+    }
+    None
+}
+
+fn property_key_name<'a>(key: &PropertyKey<'a>) -> Option<&'a str> {
+    match key {
+        PropertyKey::StaticIdentifier(id) => Some(id.name.as_str()),
+        PropertyKey::StringLiteral(lit) => Some(lit.value.as_str()),
+        _ => None,
+    }
+}
+
+/// Whether the call wraps a component, as `memo(...)`, `forwardRef(...)` and their
+/// `React.`-prefixed forms do.
+fn is_hoc_call(call_expr: &CallExpression) -> bool {
+    call_expr.callee_name().is_some_and(|name| matches!(name, "memo" | "forwardRef"))
+}
+
+/// Whether `node` is a function component: a function or arrow function which is named like a
+/// component, or is the default export, and which returns JSX.
+pub fn is_function_component<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+        && get_component_name(node, ctx)
+            .is_some_and(|name| name == "default" || is_react_component_name(name))
+        && function_returns_jsx(node)
+}
+
+/// Whether `node` is a component: a function component, a class extending `Component` or
+/// `PureComponent`, or a `createReactClass` call.
+pub fn is_component<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    is_function_component(node, ctx) || is_es6_component(node) || is_es5_component(node)
+}
+
+/// The nearest component `node` is in, i.e. which runs the code of `node` when rendering, or
+/// defines it on each of its instances.
+pub fn get_rendering_component<'a, 'b>(
+    node: &'b AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    ctx.nodes().ancestors(node.id()).find(|ancestor| is_component(ancestor, ctx))
+}
+
 fn get_jsx_mem_expr_name<'a>(jsx_mem_expr: &JSXMemberExpression) -> Cow<'a, str> {
     let prefix = match &jsx_mem_expr.object {
         JSXMemberExpressionObject::IdentifierReference(id) => Cow::Borrowed(id.name.as_str()),
@@ -1,7 +1,7 @@
 use oxc_allocator::Allocator;
 use oxc_ast::{
     AstKind,
-    ast::{Argument, Expression},
+    ast::{Argument, Expression, RegExpFlags},
 };
 use oxc_regular_expression::{ConstructorParser, Options, ast::Pattern};
 use oxc_semantic::IsGlobalReference;
@@ -31,6 +31,26 @@ where
     }
 }
 
+/// The flags of a regular expression literal, or of a `RegExp` call whose flags are a string.
+/// Flags which are not known are ignored, and are empty when they can't be determined.
+pub fn get_regex_flags(node: &AstNode, ctx: &LintContext) -> RegExpFlags {
+    let arguments = match node.kind() {
+        AstKind::RegExpLiteral(reg) => return reg.regex.flags,
+        AstKind::NewExpression(expr) if is_regexp_callee(&expr.callee, ctx) => &expr.arguments,
+        AstKind::CallExpression(expr) if is_regexp_callee(&expr.callee, ctx) => &expr.arguments,
+        _ => return RegExpFlags::empty(),
+    };
+    let flags = match arguments.get(1).and_then(Argument::as_expression) {
+        Some(Expression::StringLiteral(flags)) => flags.value.as_str(),
+        Some(Expression::TemplateLiteral(flags)) => match flags.single_quasi() {
+            Some(flags) => flags.as_str(),
+            None => return RegExpFlags::empty(),
+        },
+        _ => return RegExpFlags::empty(),
+    };
+    flags.chars().filter_map(|flag| RegExpFlags::try_from(flag).ok()).collect()
+}
+
 fn run_on_arguments<M>(arg1: Option<&Argument>, arg2: Option<&Argument>, ctx: &LintContext, cb: M)
 where
     M: FnOnce(&Pattern<'_>, Span),
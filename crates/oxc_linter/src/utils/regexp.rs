@@ -0,0 +1,320 @@
+use oxc_ast::ast::RegExpFlags;
+use oxc_regular_expression::ast::{
+    Alternative, CharacterClass, CharacterClassContents, CharacterClassContentsKind,
+    CharacterClassEscape, CharacterClassEscapeKind, Disjunction, Quantifier, Term,
+};
+
+const MAX_CODE_POINT: u32 = 0x0010_FFFF;
+
+/// A set of code points, stored as sorted, disjoint and non-adjacent inclusive ranges.
+///
+/// Used to find out whether two parts of a regular expression can match the same characters.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CharSet {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CharSet {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    pub fn all() -> Self {
+        Self { ranges: vec![(0, MAX_CODE_POINT)] }
+    }
+
+    pub fn from_ranges(mut ranges: Vec<(u32, u32)>) -> Self {
+        ranges.retain(|(min, max)| min <= max);
+        ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (min, max) in ranges {
+            match merged.last_mut() {
+                Some(last) if min <= last.1.saturating_add(1) => last.1 = last.1.max(max),
+                _ => merged.push((min, max)),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    /// The smallest code point of the set, which is used as an example of its characters.
+    pub fn first(&self) -> Option<char> {
+        self.ranges.iter().flat_map(|&(min, max)| min..=max).find_map(char::from_u32)
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_ranges(self.ranges.iter().chain(&other.ranges).copied().collect())
+    }
+
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        for &(min, max) in &self.ranges {
+            for &(other_min, other_max) in &other.ranges {
+                if min.max(other_min) <= max.min(other_max) {
+                    ranges.push((min.max(other_min), max.min(other_max)));
+                }
+            }
+        }
+        Self::from_ranges(ranges)
+    }
+
+    #[must_use]
+    pub fn negate(&self) -> Self {
+        let mut ranges = vec![];
+        let mut next = 0;
+        for &(min, max) in &self.ranges {
+            if min > next {
+                ranges.push((next, min - 1));
+            }
+            next = max + 1;
+        }
+        if next <= MAX_CODE_POINT {
+            ranges.push((next, MAX_CODE_POINT));
+        }
+        Self { ranges }
+    }
+
+    #[must_use]
+    pub fn subtract(&self, other: &Self) -> Self {
+        self.intersect(&other.negate())
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.intersect(other) == *self
+    }
+
+    /// Adds the other case of the ASCII letters of the set, for the `i` flag.
+    #[must_use]
+    fn ignore_case(&self) -> Self {
+        let lower = self.intersect(&Self::from_ranges(vec![(u32::from(b'a'), u32::from(b'z'))]));
+        let upper = self.intersect(&Self::from_ranges(vec![(u32::from(b'A'), u32::from(b'Z'))]));
+        let mut ranges = self.ranges.clone();
+        ranges.extend(lower.ranges.iter().map(|&(min, max)| (min - 32, max - 32)));
+        ranges.extend(upper.ranges.iter().map(|&(min, max)| (min + 32, max + 32)));
+        Self::from_ranges(ranges)
+    }
+}
+
+const DIGIT: &[(u32, u32)] = &[(0x30, 0x39)];
+const WORD: &[(u32, u32)] = &[(0x30, 0x39), (0x41, 0x5A), (0x5F, 0x5F), (0x61, 0x7A)];
+const WHITESPACE: &[(u32, u32)] = &[
+    (0x09, 0x0D),
+    (0x20, 0x20),
+    (0xA0, 0xA0),
+    (0x1680, 0x1680),
+    (0x2000, 0x200A),
+    (0x2028, 0x2029),
+    (0x202F, 0x202F),
+    (0x205F, 0x205F),
+    (0x3000, 0x3000),
+    (0xFEFF, 0xFEFF),
+];
+const LINE_TERMINATOR: &[(u32, u32)] = &[(0x0A, 0x0A), (0x0D, 0x0D), (0x2028, 0x2029)];
+
+fn class_escape_char_set(escape: &CharacterClassEscape) -> CharSet {
+    let (ranges, negative) = match escape.kind {
+        CharacterClassEscapeKind::D => (DIGIT, false),
+        CharacterClassEscapeKind::NegativeD => (DIGIT, true),
+        CharacterClassEscapeKind::S => (WHITESPACE, false),
+        CharacterClassEscapeKind::NegativeS => (WHITESPACE, true),
+        CharacterClassEscapeKind::W => (WORD, false),
+        CharacterClassEscapeKind::NegativeW => (WORD, true),
+    };
+    let set = CharSet::from_ranges(ranges.to_vec());
+    if negative { set.negate() } else { set }
+}
+
+fn class_contents_char_set(contents: &CharacterClassContents) -> Option<CharSet> {
+    match contents {
+        CharacterClassContents::CharacterClassRange(range) => {
+            Some(CharSet::from_ranges(vec![(range.min.value, range.max.value)]))
+        }
+        CharacterClassContents::CharacterClassEscape(escape) => Some(class_escape_char_set(escape)),
+        CharacterClassContents::Character(character) => {
+            Some(CharSet::from_ranges(vec![(character.value, character.value)]))
+        }
+        CharacterClassContents::NestedCharacterClass(class) => class_char_set(class),
+        CharacterClassContents::ClassStringDisjunction(disjunction) => disjunction
+            .body
+            .iter()
+            .try_fold(CharSet::empty(), |set, string| match string.body.as_slice() {
+                [character] => {
+                    Some(set.union(&CharSet::from_ranges(vec![(character.value, character.value)])))
+                }
+                _ => None,
+            }),
+        CharacterClassContents::UnicodePropertyEscape(_) => None,
+    }
+}
+
+fn class_char_set(class: &CharacterClass) -> Option<CharSet> {
+    let mut contents = class.body.iter().map(class_contents_char_set);
+    let set = match class.kind {
+        CharacterClassContentsKind::Union => {
+            contents.try_fold(CharSet::empty(), |set, other| Some(set.union(&other?)))?
+        }
+        CharacterClassContentsKind::Intersection => {
+            let first = contents.next()??;
+            contents.try_fold(first, |set, other| Some(set.intersect(&other?)))?
+        }
+        CharacterClassContentsKind::Subtraction => {
+            let first = contents.next()??;
+            contents.try_fold(first, |set, other| Some(set.subtract(&other?)))?
+        }
+    };
+    Some(if class.negative { set.negate() } else { set })
+}
+
+/// The characters a term which always matches a single character can match, e.g. `a`, `.`,
+/// `\d` or `[a-z]`. `None` for other terms, and for sets which are not known, like `\p{L}`.
+pub fn get_term_char_set(term: &Term, flags: RegExpFlags) -> Option<CharSet> {
+    let set = match term {
+        Term::Character(character) => {
+            CharSet::from_ranges(vec![(character.value, character.value)])
+        }
+        Term::Dot(_) if flags.contains(RegExpFlags::S) => CharSet::all(),
+        Term::Dot(_) => CharSet::from_ranges(LINE_TERMINATOR.to_vec()).negate(),
+        Term::CharacterClassEscape(escape) => class_escape_char_set(escape),
+        Term::CharacterClass(class) if !class.strings => class_char_set(class)?,
+        _ => return None,
+    };
+    Some(if flags.contains(RegExpFlags::I) { set.ignore_case() } else { set })
+}
+
+/// All characters which a term can consume, in any position.
+pub fn get_term_chars(term: &Term, flags: RegExpFlags) -> Option<CharSet> {
+    match term {
+        Term::BoundaryAssertion(_) | Term::LookAroundAssertion(_) => Some(CharSet::empty()),
+        Term::Quantifier(quantifier) => get_term_chars(&quantifier.body, flags),
+        Term::CapturingGroup(group) => get_disjunction_chars(&group.body, flags),
+        Term::IgnoreGroup(group) => get_disjunction_chars(&group.body, flags),
+        Term::IndexedReference(_) | Term::NamedReference(_) => None,
+        _ => get_term_char_set(term, flags),
+    }
+}
+
+fn get_disjunction_chars(disjunction: &Disjunction, flags: RegExpFlags) -> Option<CharSet> {
+    disjunction
+        .body
+        .iter()
+        .flat_map(|alternative| &alternative.body)
+        .try_fold(CharSet::empty(), |set, term| Some(set.union(&get_term_chars(term, flags)?)))
+}
+
+/// The characters `c` for which a term can match a non-empty string which only consists of `c`,
+/// e.g. `a` for `(?:a+b?)`, but none for `(?:ab)+`.
+pub fn get_term_repeatable_chars(term: &Term, flags: RegExpFlags) -> Option<CharSet> {
+    let disjunction = match term {
+        Term::BoundaryAssertion(_)
+        | Term::LookAroundAssertion(_)
+        | Term::IndexedReference(_)
+        | Term::NamedReference(_) => return Some(CharSet::empty()),
+        Term::Quantifier(quantifier) if quantifier.max == Some(0) => {
+            return Some(CharSet::empty());
+        }
+        Term::Quantifier(quantifier) => {
+            return get_term_repeatable_chars(&quantifier.body, flags);
+        }
+        Term::CapturingGroup(group) => &group.body,
+        Term::IgnoreGroup(group) => &group.body,
+        _ => return get_term_char_set(term, flags),
+    };
+    disjunction.body.iter().try_fold(CharSet::empty(), |set, alternative| {
+        // Terms which can't match the empty string all have to match the character, and when
+        // there are none, any one of the terms can.
+        let mut required = alternative.body.iter().filter(|term| !is_term_nullable(term));
+        let chars = match required.next() {
+            Some(first) => {
+                let first = get_term_repeatable_chars(first, flags)?;
+                required.try_fold(first, |chars, term| {
+                    Some(chars.intersect(&get_term_repeatable_chars(term, flags)?))
+                })?
+            }
+            None => alternative.body.iter().try_fold(CharSet::empty(), |chars, term| {
+                Some(chars.union(&get_term_repeatable_chars(term, flags)?))
+            })?,
+        };
+        Some(set.union(&chars))
+    })
+}
+
+/// The characters which a term can consume first.
+pub fn get_term_first_chars(term: &Term, flags: RegExpFlags) -> Option<CharSet> {
+    match term {
+        Term::BoundaryAssertion(_) | Term::LookAroundAssertion(_) => Some(CharSet::empty()),
+        Term::Quantifier(quantifier) if quantifier.max == Some(0) => Some(CharSet::empty()),
+        Term::Quantifier(quantifier) => get_term_first_chars(&quantifier.body, flags),
+        Term::CapturingGroup(group) => get_disjunction_first_chars(&group.body, flags),
+        Term::IgnoreGroup(group) => get_disjunction_first_chars(&group.body, flags),
+        Term::IndexedReference(_) | Term::NamedReference(_) => None,
+        _ => get_term_char_set(term, flags),
+    }
+}
+
+fn get_disjunction_first_chars(disjunction: &Disjunction, flags: RegExpFlags) -> Option<CharSet> {
+    disjunction.body.iter().try_fold(CharSet::empty(), |set, alternative| {
+        Some(set.union(&get_terms_first_chars(&alternative.body, flags)?))
+    })
+}
+
+/// The characters which a sequence of terms can consume first.
+pub fn get_terms_first_chars(terms: &[Term], flags: RegExpFlags) -> Option<CharSet> {
+    let mut set = CharSet::empty();
+    for term in terms {
+        set = set.union(&get_term_first_chars(term, flags)?);
+        if !is_term_nullable(term) {
+            break;
+        }
+    }
+    Some(set)
+}
+
+/// Whether a term can match the empty string, e.g. `a*`, `(a|)` or `\b`.
+pub fn is_term_nullable(term: &Term) -> bool {
+    match term {
+        Term::BoundaryAssertion(_)
+        | Term::LookAroundAssertion(_)
+        | Term::IndexedReference(_)
+        | Term::NamedReference(_) => true,
+        Term::Quantifier(quantifier) => quantifier.min == 0 || is_term_nullable(&quantifier.body),
+        Term::CapturingGroup(group) => group.body.body.iter().any(is_alternative_nullable),
+        Term::IgnoreGroup(group) => group.body.body.iter().any(is_alternative_nullable),
+        Term::CharacterClass(class) => class.strings,
+        _ => false,
+    }
+}
+
+fn is_alternative_nullable(alternative: &Alternative) -> bool {
+    alternative.body.iter().all(is_term_nullable)
+}
+
+/// The alternatives of the body of a quantifier, which are those of a group, or just the body.
+pub fn get_quantifier_alternatives<'b, 'a>(quantifier: &'b Quantifier<'a>) -> Vec<&'b [Term<'a>]> {
+    match &quantifier.body {
+        Term::CapturingGroup(group) => {
+            group.body.body.iter().map(|it| it.body.as_slice()).collect()
+        }
+        Term::IgnoreGroup(group) => group.body.body.iter().map(|it| it.body.as_slice()).collect(),
+        body => vec![std::slice::from_ref(body)],
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CharSet;
+
+    #[test]
+    fn test_char_set() {
+        let a_to_f = CharSet::from_ranges(vec![(0x61, 0x66)]);
+        let d_to_z = CharSet::from_ranges(vec![(0x64, 0x7A)]);
+        assert_eq!(a_to_f.intersect(&d_to_z), CharSet::from_ranges(vec![(0x64, 0x66)]));
+        assert_eq!(a_to_f.union(&d_to_z), CharSet::from_ranges(vec![(0x61, 0x7A)]));
+        assert_eq!(a_to_f.subtract(&d_to_z), CharSet::from_ranges(vec![(0x61, 0x63)]));
+        assert_eq!(a_to_f.negate().negate(), a_to_f);
+        assert!(CharSet::from_ranges(vec![(0x62, 0x63)]).is_subset(&a_to_f));
+        assert!(!d_to_z.is_subset(&a_to_f));
+        assert_eq!(d_to_z.first(), Some('d'));
+        assert_eq!(CharSet::all().negate(), CharSet::empty());
+    }
+}
@@ -0,0 +1,213 @@
+use std::borrow::Cow;
+
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, BinaryOperator, Expression},
+};
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, LintContext, utils::get_node_module_path};
+
+/// What the arguments of an [`InjectionSink`] are interpreted as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InjectionSinkKind {
+    /// JavaScript code, e.g. the argument of `eval`.
+    Code,
+    /// A shell command, e.g. the argument of `child_process.exec`.
+    Command,
+    /// A path on the file system, e.g. the first argument of `fs.readFile`.
+    FileSystem,
+}
+
+/// A function which is unsafe to call with arguments from an untrusted source.
+#[derive(Debug)]
+pub struct InjectionSink {
+    pub kind: InjectionSinkKind,
+    /// The indices of the arguments which are interpreted as the [`InjectionSinkKind`], or
+    /// `None` for all of them.
+    pub arguments: Option<&'static [usize]>,
+}
+
+impl InjectionSink {
+    const fn new(kind: InjectionSinkKind, arguments: &'static [usize]) -> Self {
+        Self { kind, arguments: Some(arguments) }
+    }
+
+    /// The arguments of a call of this sink which are interpreted as the [`InjectionSinkKind`].
+    pub fn injected_arguments<'b, 'a>(
+        &self,
+        arguments: &'b [Argument<'a>],
+    ) -> impl Iterator<Item = &'b Argument<'a>> {
+        arguments.iter().enumerate().filter_map(|(index, argument)| {
+            self.arguments.is_none_or(|indices| indices.contains(&index)).then_some(argument)
+        })
+    }
+}
+
+const CODE: InjectionSink = InjectionSink::new(InjectionSinkKind::Code, &[0]);
+const COMMAND: InjectionSink = InjectionSink::new(InjectionSinkKind::Command, &[0]);
+const PATH: InjectionSink = InjectionSink::new(InjectionSinkKind::FileSystem, &[0]);
+const PATHS: InjectionSink = InjectionSink::new(InjectionSinkKind::FileSystem, &[0, 1]);
+
+/// Injection sinks by the path of the global or Node.js module member they are, as given by
+/// [`get_node_module_path`]. Members of `fs/promises` and `fs.promises` are listed as members of
+/// `fs`.
+static INJECTION_SINKS: phf::Map<&'static str, InjectionSink> = phf::phf_map! {
+    "eval" => CODE,
+    "Function" => InjectionSink { kind: InjectionSinkKind::Code, arguments: None },
+    "vm.Script" => CODE,
+    "vm.compileFunction" => CODE,
+    "vm.runInContext" => CODE,
+    "vm.runInNewContext" => CODE,
+    "vm.runInThisContext" => CODE,
+
+    "child_process.exec" => COMMAND,
+    "child_process.execSync" => COMMAND,
+
+    "fs.access" => PATH,
+    "fs.accessSync" => PATH,
+    "fs.appendFile" => PATH,
+    "fs.appendFileSync" => PATH,
+    "fs.chmod" => PATH,
+    "fs.chmodSync" => PATH,
+    "fs.chown" => PATH,
+    "fs.chownSync" => PATH,
+    "fs.copyFile" => PATHS,
+    "fs.copyFileSync" => PATHS,
+    "fs.cp" => PATHS,
+    "fs.cpSync" => PATHS,
+    "fs.createReadStream" => PATH,
+    "fs.createWriteStream" => PATH,
+    "fs.exists" => PATH,
+    "fs.existsSync" => PATH,
+    "fs.lchown" => PATH,
+    "fs.link" => PATHS,
+    "fs.linkSync" => PATHS,
+    "fs.lstat" => PATH,
+    "fs.lstatSync" => PATH,
+    "fs.mkdir" => PATH,
+    "fs.mkdirSync" => PATH,
+    "fs.mkdtemp" => PATH,
+    "fs.mkdtempSync" => PATH,
+    "fs.open" => PATH,
+    "fs.openSync" => PATH,
+    "fs.opendir" => PATH,
+    "fs.opendirSync" => PATH,
+    "fs.readFile" => PATH,
+    "fs.readFileSync" => PATH,
+    "fs.readdir" => PATH,
+    "fs.readdirSync" => PATH,
+    "fs.readlink" => PATH,
+    "fs.readlinkSync" => PATH,
+    "fs.realpath" => PATH,
+    "fs.realpathSync" => PATH,
+    "fs.rename" => PATHS,
+    "fs.renameSync" => PATHS,
+    "fs.rm" => PATH,
+    "fs.rmSync" => PATH,
+    "fs.rmdir" => PATH,
+    "fs.rmdirSync" => PATH,
+    "fs.stat" => PATH,
+    "fs.statSync" => PATH,
+    "fs.symlink" => PATHS,
+    "fs.symlinkSync" => PATHS,
+    "fs.truncate" => PATH,
+    "fs.truncateSync" => PATH,
+    "fs.unlink" => PATH,
+    "fs.unlinkSync" => PATH,
+    "fs.utimes" => PATH,
+    "fs.utimesSync" => PATH,
+    "fs.watch" => PATH,
+    "fs.watchFile" => PATH,
+    "fs.writeFile" => PATH,
+    "fs.writeFileSync" => PATH,
+};
+
+/// The injection sink called by `callee`, e.g. `eval`, or `readFile` after
+/// `import { readFile } from "node:fs/promises"`, and its path, e.g. `fs/promises.readFile`.
+///
+/// Module members are only sinks when they are imported or required, not when they are members
+/// of a global of the same name as the module.
+pub fn get_injection_sink<'a>(
+    callee: &Expression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<(String, &'static InjectionSink)> {
+    let path = get_node_module_path(callee, ctx)?;
+    let member_path =
+        match path.strip_prefix("fs/promises.").or_else(|| path.strip_prefix("fs.promises.")) {
+            Some(member) => Cow::Owned(format!("fs.{member}")),
+            None => Cow::Borrowed(path.as_str()),
+        };
+    let sink = INJECTION_SINKS.get(member_path.as_ref())?;
+    if member_path.contains('.') && has_global_root(callee, ctx) {
+        return None;
+    }
+    Some((path, sink))
+}
+
+/// The path of the injection sink of `kind` called, or constructed, by `node`, and the span of
+/// the first of its injected arguments which is not a [static string](is_static_string).
+pub fn find_injected_argument<'a>(
+    node: &AstNode<'a>,
+    kind: InjectionSinkKind,
+    ctx: &LintContext<'a>,
+) -> Option<(String, Span)> {
+    let (callee, arguments) = match node.kind() {
+        AstKind::CallExpression(call) => (&call.callee, &call.arguments),
+        AstKind::NewExpression(new_expr) => (&new_expr.callee, &new_expr.arguments),
+        _ => return None,
+    };
+    let (path, sink) = get_injection_sink(callee, ctx)?;
+    if sink.kind != kind {
+        return None;
+    }
+    let argument = sink.injected_arguments(arguments).find(|argument| {
+        argument.as_expression().is_none_or(|expr| !is_static_string(expr, ctx))
+    })?;
+    Some((path, argument.span()))
+}
+
+/// Whether `expr` is a member of a global, e.g. `fs.readFile` without an import of `fs`.
+fn has_global_root<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    match expr.without_parentheses() {
+        Expression::StaticMemberExpression(member) => has_global_root(&member.object, ctx),
+        Expression::Identifier(ident) => {
+            ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_none()
+        }
+        _ => false,
+    }
+}
+
+/// Whether `expr` is a string which can't come from an untrusted source: a string literal, a
+/// template literal or concatenation of such strings, the directory or file name of the module,
+/// or a `path.join` or `path.resolve` of such strings.
+pub fn is_static_string<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    match expr.get_inner_expression() {
+        Expression::StringLiteral(_) => true,
+        Expression::TemplateLiteral(template) => {
+            template.expressions.iter().all(|expr| is_static_string(expr, ctx))
+        }
+        Expression::BinaryExpression(binary) => {
+            binary.operator == BinaryOperator::Addition
+                && is_static_string(&binary.left, ctx)
+                && is_static_string(&binary.right, ctx)
+        }
+        Expression::Identifier(ident) => {
+            matches!(ident.name.as_str(), "__dirname" | "__filename")
+                && ctx.is_reference_to_global_variable(ident)
+        }
+        Expression::StaticMemberExpression(member) => {
+            matches!(member.object, Expression::MetaProperty(_))
+                && matches!(member.property.name.as_str(), "dirname" | "filename")
+        }
+        Expression::CallExpression(call) => {
+            matches!(
+                get_node_module_path(&call.callee, ctx).as_deref(),
+                Some("path.join" | "path.resolve")
+            ) && call.arguments.iter().all(|argument| {
+                argument.as_expression().is_some_and(|expr| is_static_string(expr, ctx))
+            })
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,26 @@
+use oxc_ast::AstKind;
+use oxc_span::{GetSpan, Span};
+
+use crate::AstNode;
+
+/// The span to report a function at: its name, the key or variable it is assigned to, or, for
+/// an anonymous function, its head, e.g. `(a, b) =>`.
+pub fn get_function_label_span(node: &AstNode, parent: &AstNode) -> Span {
+    let body_span = match node.kind() {
+        AstKind::Function(func) => {
+            if let Some(id) = &func.id {
+                return id.span;
+            }
+            func.body.as_ref().map_or(func.span, |body| body.span)
+        }
+        AstKind::ArrowFunctionExpression(arrow) => arrow.body.span,
+        _ => return node.span(),
+    };
+    match parent.kind() {
+        AstKind::MethodDefinition(method) => method.key.span(),
+        AstKind::ObjectProperty(property) => property.key.span(),
+        AstKind::PropertyDefinition(property) => property.key.span(),
+        AstKind::VariableDeclarator(declarator) => declarator.id.span(),
+        _ => Span::new(node.span().start, body_span.start),
+    }
+}
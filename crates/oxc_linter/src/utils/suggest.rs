@@ -0,0 +1,47 @@
+use std::fmt::Display;
+
+/// Up to 3 `candidates` closest to `name`, which differ by at most a third of its characters.
+pub fn closest<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    let mut matches = candidates
+        .filter_map(|candidate| {
+            let distance = edit_distance(name, candidate);
+            (distance > 0 && distance <= max_distance).then_some((distance, candidate))
+        })
+        .collect::<Vec<_>>();
+    matches.sort_unstable();
+    matches.dedup();
+    matches.into_iter().take(3).map(|(_, candidate)| candidate).collect()
+}
+
+/// A help message suggesting `close_matches`, or `None` if there are none.
+pub fn did_you_mean<T: Display>(close_matches: &[T]) -> Option<String> {
+    match close_matches {
+        [] => None,
+        [close_match] => Some(format!("Did you mean `{close_match}`?")),
+        _ => Some(format!(
+            "Did you mean one of {}?",
+            close_matches
+                .iter()
+                .map(|close_match| format!("`{close_match}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// The Levenshtein distance between `a` and `b`.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
@@ -0,0 +1,408 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+#[cfg(feature = "watch")]
+use std::{sync::mpsc, time::Duration};
+
+#[cfg(feature = "watch")]
+use notify::Watcher;
+use rustc_hash::FxHashMap;
+
+use crate::{EslintFlatConfig, EslintrcConfig, LINTABLE_EXTENSIONS};
+
+/// Modification time and length of a file, which change when the file is written.
+pub type FileStamp = (Option<SystemTime>, u64);
+
+/// The [`FileStamp`] of the file at `path`, or `None` if it doesn't exist.
+pub fn file_stamp(path: &Path) -> Option<FileStamp> {
+    fs::metadata(path).ok().map(|metadata| (metadata.modified().ok(), metadata.len()))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileChangeKind {
+    Created,
+    Changed,
+    Removed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileChange {
+    pub path: PathBuf,
+    pub kind: FileChangeKind,
+}
+
+impl FileChange {
+    pub fn new(path: impl Into<PathBuf>, kind: FileChangeKind) -> Self {
+        Self { path: path.into(), kind }
+    }
+}
+
+/// Merges a burst of changes into at most one change per path, in the order the paths first
+/// changed.
+///
+/// Editors often save a file atomically, by writing a temporary file and renaming it over the
+/// file. The file is reported as changed once, and the temporary file, created and removed in the
+/// same burst, is dropped.
+pub fn coalesce_file_changes(changes: impl IntoIterator<Item = FileChange>) -> Vec<FileChange> {
+    let mut coalesced: Vec<Option<FileChange>> = vec![];
+    let mut indices = FxHashMap::<PathBuf, usize>::default();
+    for change in changes {
+        let Some(&index) = indices.get(&change.path) else {
+            indices.insert(change.path.clone(), coalesced.len());
+            coalesced.push(Some(change));
+            continue;
+        };
+        let Some(previous) = coalesced[index].as_mut() else { continue };
+        previous.kind = match (previous.kind, change.kind) {
+            (FileChangeKind::Created, FileChangeKind::Removed) => {
+                indices.remove(&change.path);
+                coalesced[index] = None;
+                continue;
+            }
+            (FileChangeKind::Created, _) => FileChangeKind::Created,
+            (_, FileChangeKind::Removed) => FileChangeKind::Removed,
+            _ => FileChangeKind::Changed,
+        };
+    }
+    coalesced.into_iter().flatten().collect()
+}
+
+/// What a change of a watched file affects, so that `--watch`, the daemon and the language
+/// server react to it the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchedFileKind {
+    /// An oxlint or ESLint configuration file. The configuration is loaded again.
+    LintConfig,
+    /// A `tsconfig.json` or `package.json` file, used to resolve the imported modules.
+    ResolutionConfig,
+    /// An ignore file, which changes the files to lint.
+    IgnoreFile,
+    /// A file which can be linted.
+    Source,
+    Other,
+}
+
+impl WatchedFileKind {
+    pub fn from_path(path: &Path) -> Self {
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+            return Self::Other;
+        };
+        if file_name == ".oxlintrc.json"
+            || EslintrcConfig::is_eslintrc_path(path)
+            || EslintFlatConfig::is_flat_config_path(path)
+        {
+            Self::LintConfig
+        } else if file_name == "package.json"
+            || (file_name.starts_with("tsconfig")
+                && path.extension().is_some_and(|extension| extension == "json"))
+        {
+            Self::ResolutionConfig
+        } else if file_name == ".gitignore"
+            || file_name == ".eslintignore"
+            || file_name == ".oxlintignore"
+        {
+            Self::IgnoreFile
+        } else if path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| LINTABLE_EXTENSIONS.contains(&extension))
+        {
+            Self::Source
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// An error of the OS notifications a [`FileWatcher`] is built on.
+#[cfg(feature = "watch")]
+#[derive(Debug)]
+pub struct WatchError(notify::Error);
+
+#[cfg(feature = "watch")]
+impl From<notify::Error> for WatchError {
+    fn from(error: notify::Error) -> Self {
+        Self(error)
+    }
+}
+
+#[cfg(feature = "watch")]
+impl std::fmt::Display for WatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "watch")]
+impl std::error::Error for WatchError {}
+
+/// Whether the changes of a path, given whether it is a directory, are ignored by a [`FileWatcher`].
+#[cfg(feature = "watch")]
+type IgnoreFilter = Box<dyn FnMut(&Path, bool) -> bool + Send>;
+
+/// Watches the files in a directory with the notifications of the OS.
+///
+/// Directories are watched one by one, so that ignored directories, e.g. `node_modules`, are not
+/// watched at all. Directories created later are watched as soon as they are reported, and the
+/// files already in them are reported as created.
+#[cfg(feature = "watch")]
+pub struct FileWatcher {
+    watcher: notify::RecommendedWatcher,
+    events: mpsc::Receiver<notify::Result<notify::Event>>,
+    is_ignored: IgnoreFilter,
+    debounce: Duration,
+}
+
+#[cfg(feature = "watch")]
+impl FileWatcher {
+    pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(100);
+
+    /// Watches the files in `root`, except for those for which `is_ignored`, called with the path
+    /// and whether it is a directory, returns `true`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the OS can't watch `root`.
+    pub fn new(
+        root: &Path,
+        is_ignored: impl FnMut(&Path, bool) -> bool + Send + 'static,
+    ) -> Result<Self, WatchError> {
+        let (sender, events) = mpsc::channel();
+        let mut watcher = Self {
+            watcher: notify::recommended_watcher(sender)?,
+            events,
+            is_ignored: Box::new(is_ignored),
+            debounce: Self::DEFAULT_DEBOUNCE,
+        };
+        watcher.watcher.watch(root, notify::RecursiveMode::NonRecursive)?;
+        watcher.watch_entries(root, &mut vec![]);
+        Ok(watcher)
+    }
+
+    /// How long the files must not change after a change, before the changes are reported.
+    #[must_use]
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// The changes reported so far, without waiting.
+    pub fn poll(&mut self) -> Vec<FileChange> {
+        let mut changes = vec![];
+        while let Ok(event) = self.events.try_recv() {
+            self.add_changes(event, &mut changes);
+        }
+        coalesce_file_changes(changes)
+    }
+
+    /// Blocks until files change, and returns the changes once the files stopped changing for
+    /// the debounce duration. Returns no changes if the OS stopped notifying the watcher.
+    pub fn wait(&mut self) -> Vec<FileChange> {
+        // The sender is owned by `self.watcher`, so receiving only fails if it stopped.
+        while let Ok(event) = self.events.recv() {
+            let changes = self.debounce(event);
+            if !changes.is_empty() {
+                return changes;
+            }
+        }
+        vec![]
+    }
+
+    /// Like [`FileWatcher::wait`], but returns no changes if no file changed within `timeout`,
+    /// so that the caller can stop watching in between.
+    pub fn wait_timeout(&mut self, timeout: Duration) -> Vec<FileChange> {
+        match self.events.recv_timeout(timeout) {
+            Ok(event) => self.debounce(event),
+            Err(_) => vec![],
+        }
+    }
+
+    /// The changes of `event` and of the events following it within the debounce duration.
+    fn debounce(&mut self, event: notify::Result<notify::Event>) -> Vec<FileChange> {
+        let mut changes = vec![];
+        self.add_changes(event, &mut changes);
+        while let Ok(event) = self.events.recv_timeout(self.debounce) {
+            self.add_changes(event, &mut changes);
+        }
+        coalesce_file_changes(changes)
+    }
+
+    /// Watches the directories in `dir` which are not ignored, and adds the files in them to
+    /// `created`.
+    fn watch_entries(&mut self, dir: &Path, created: &mut Vec<FileChange>) {
+        let Ok(entries) = fs::read_dir(dir) else { return };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let is_dir = entry.file_type().is_ok_and(|file_type| file_type.is_dir());
+            if (self.is_ignored)(&path, is_dir) {
+                continue;
+            }
+            if !is_dir {
+                created.push(FileChange::new(path, FileChangeKind::Created));
+            } else if self.watcher.watch(&path, notify::RecursiveMode::NonRecursive).is_ok() {
+                self.watch_entries(&path, created);
+            }
+        }
+    }
+
+    fn add_changes(&mut self, event: notify::Result<notify::Event>, changes: &mut Vec<FileChange>) {
+        use notify::event::{EventKind, ModifyKind, RenameMode};
+
+        // Errors of the OS, e.g. an overflow of its event queue, can't be attributed to files.
+        let Ok(event) = event else { return };
+        let kinds = match event.kind {
+            EventKind::Create(_) | EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
+                [FileChangeKind::Created, FileChangeKind::Created]
+            }
+            EventKind::Remove(_) | EventKind::Modify(ModifyKind::Name(RenameMode::From)) => {
+                [FileChangeKind::Removed, FileChangeKind::Removed]
+            }
+            // The paths are the old and the new path.
+            EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+                [FileChangeKind::Removed, FileChangeKind::Created]
+            }
+            EventKind::Modify(ModifyKind::Name(_)) => {
+                for path in event.paths {
+                    let kind = if path.exists() {
+                        FileChangeKind::Created
+                    } else {
+                        FileChangeKind::Removed
+                    };
+                    self.add_change(FileChange::new(path, kind), changes);
+                }
+                return;
+            }
+            EventKind::Modify(ModifyKind::Metadata(_)) | EventKind::Access(_) => return,
+            EventKind::Modify(_) | EventKind::Any | EventKind::Other => {
+                [FileChangeKind::Changed, FileChangeKind::Changed]
+            }
+        };
+        for (path, kind) in event.paths.into_iter().zip(kinds) {
+            self.add_change(FileChange::new(path, kind), changes);
+        }
+    }
+
+    fn add_change(&mut self, change: FileChange, changes: &mut Vec<FileChange>) {
+        let is_dir = change.kind != FileChangeKind::Removed && change.path.is_dir();
+        if (self.is_ignored)(&change.path, is_dir) {
+            return;
+        }
+        if !is_dir {
+            changes.push(change);
+        } else if change.kind == FileChangeKind::Created
+            && self.watcher.watch(&change.path, notify::RecursiveMode::NonRecursive).is_ok()
+        {
+            self.watch_entries(&change.path, changes);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "watch")]
+    use std::time::Duration;
+    use std::{fs, path::Path};
+
+    #[cfg(feature = "watch")]
+    use super::FileWatcher;
+    use super::{FileChange, FileChangeKind, WatchedFileKind, coalesce_file_changes};
+
+    #[test]
+    fn test_coalesce_file_changes() {
+        let change = FileChange::new;
+        assert_eq!(
+            coalesce_file_changes([
+                // atomic save, renaming a temporary file over `a.js`
+                change("a.js.tmp", FileChangeKind::Created),
+                change("a.js.tmp", FileChangeKind::Removed),
+                change("a.js", FileChangeKind::Removed),
+                change("a.js", FileChangeKind::Created),
+                // new file
+                change("b.js", FileChangeKind::Created),
+                change("b.js", FileChangeKind::Changed),
+                // removed file
+                change("c.js", FileChangeKind::Changed),
+                change("c.js", FileChangeKind::Removed),
+            ]),
+            vec![
+                change("a.js", FileChangeKind::Changed),
+                change("b.js", FileChangeKind::Created),
+                change("c.js", FileChangeKind::Removed),
+            ]
+        );
+        assert_eq!(
+            coalesce_file_changes([
+                change("a.js", FileChangeKind::Removed),
+                change("a.js", FileChangeKind::Created),
+                change("b.js", FileChangeKind::Changed),
+                change("b.js", FileChangeKind::Changed),
+            ]),
+            vec![change("a.js", FileChangeKind::Changed), change("b.js", FileChangeKind::Changed)]
+        );
+    }
+
+    #[test]
+    fn test_watched_file_kind() {
+        let kind = |path: &str| WatchedFileKind::from_path(Path::new(path));
+        assert_eq!(kind("a/.oxlintrc.json"), WatchedFileKind::LintConfig);
+        assert_eq!(kind("eslint.config.js"), WatchedFileKind::LintConfig);
+        assert_eq!(kind("a/tsconfig.base.json"), WatchedFileKind::ResolutionConfig);
+        assert_eq!(kind("package.json"), WatchedFileKind::ResolutionConfig);
+        assert_eq!(kind(".gitignore"), WatchedFileKind::IgnoreFile);
+        assert_eq!(kind("a/.oxlintignore"), WatchedFileKind::IgnoreFile);
+        assert_eq!(kind("src/index.vue"), WatchedFileKind::Source);
+        assert_eq!(kind("README.md"), WatchedFileKind::Other);
+    }
+
+    #[cfg(feature = "watch")]
+    #[test]
+    fn test_file_watcher() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        fs::write(root.join("a.js"), "a").unwrap();
+        fs::create_dir(root.join("dist")).unwrap();
+
+        let mut watcher =
+            FileWatcher::new(&root, |path, _| path.file_name().is_some_and(|name| name == "dist"))
+                .unwrap()
+                .with_debounce(Duration::from_millis(50));
+        assert!(watcher.poll().is_empty());
+
+        fs::write(root.join("a.js"), "a = 1").unwrap();
+        fs::write(root.join("b.js"), "b").unwrap();
+        fs::write(root.join("dist/b.js"), "b").unwrap();
+        let mut changes = watcher.wait();
+        changes.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+        assert_eq!(
+            changes,
+            vec![
+                FileChange::new(root.join("a.js"), FileChangeKind::Changed),
+                FileChange::new(root.join("b.js"), FileChangeKind::Created),
+            ]
+        );
+
+        assert!(watcher.wait_timeout(Duration::from_millis(50)).is_empty());
+        fs::remove_file(root.join("b.js")).unwrap();
+        assert_eq!(
+            watcher.wait_timeout(Duration::from_secs(10)),
+            vec![FileChange::new(root.join("b.js"), FileChangeKind::Removed)]
+        );
+
+        // the files of a new directory are watched
+        fs::create_dir_all(root.join("src/lib")).unwrap();
+        fs::write(root.join("src/lib/c.js"), "c").unwrap();
+        let mut changes = watcher.wait();
+        changes.retain(|change| change.path == root.join("src/lib/c.js"));
+        assert_eq!(
+            changes,
+            vec![FileChange::new(root.join("src/lib/c.js"), FileChangeKind::Created)]
+        );
+        fs::write(root.join("src/lib/c.js"), "c = 1").unwrap();
+        assert_eq!(
+            watcher.wait(),
+            vec![FileChange::new(root.join("src/lib/c.js"), FileChangeKind::Changed)]
+        );
+    }
+}
@@ -130,6 +130,13 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            /// The schema of the options of this [`Rule`], if it declares them with `config = ...`.
+            pub fn config_schema(&self, generator: &mut schemars::SchemaGenerator) -> Option<schemars::schema::Schema> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::config_schema(generator)),*
+                }
+            }
+
             pub fn plugin_name(&self) -> &'static str {
                 match self {
                     #(Self::#struct_names(_) => #plugin_names),*
@@ -144,6 +151,12 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub fn try_from_configuration(&self, value: serde_json::Value) -> Result<Self, String> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::try_from_configuration(value).map(Self::#struct_names)),*
+                }
+            }
+
             pub fn to_configuration(&self) -> Option<Result<serde_json::Value, serde_json::Error>> {
                 match self {
                     #(Self::#struct_names(rule) => rule.to_configuration()),*
@@ -3,7 +3,7 @@ use oxc_ast::ast::*;
 use oxc_ast_visit::Visit;
 use oxc_ecmascript::{
     constant_evaluation::{ConstantEvaluation, ConstantValue},
-    side_effects::MayHaveSideEffects,
+    side_effects::{CallMayHaveSideEffects, MayHaveSideEffects},
 };
 use oxc_span::GetSpan;
 use oxc_traverse::Ancestor;
@@ -355,42 +355,25 @@ impl<'a> PeepholeOptimizations {
     pub fn keep_track_of_pure_functions(stmt: &mut Statement<'a>, ctx: &mut Ctx<'a, '_>) {
         match stmt {
             Statement::FunctionDeclaration(f) => {
-                if let Some(body) = &f.body {
-                    Self::try_save_pure_function(
-                        f.id.as_ref(),
-                        &f.params,
-                        body,
-                        f.r#async,
-                        f.generator,
-                        ctx,
-                    );
+                if !f.call_may_have_side_effects(ctx) {
+                    Self::save_pure_function(f.id.as_ref(), Self::returns_undefined(f), ctx);
                 }
             }
             Statement::VariableDeclaration(decl) => {
                 for d in &decl.declarations {
                     if let BindingPattern::BindingIdentifier(id) = &d.id {
                         match &d.init {
-                            Some(Expression::ArrowFunctionExpression(a)) => {
-                                Self::try_save_pure_function(
-                                    Some(id),
-                                    &a.params,
-                                    &a.body,
-                                    a.r#async,
-                                    false,
-                                    ctx,
-                                );
+                            Some(Expression::ArrowFunctionExpression(a))
+                                if !a.call_may_have_side_effects(ctx) =>
+                            {
+                                // Async functions return a promise
+                                let returns_undefined = !a.r#async && a.body.is_empty();
+                                Self::save_pure_function(Some(id), returns_undefined, ctx);
                             }
-                            Some(Expression::FunctionExpression(f)) => {
-                                if let Some(body) = &f.body {
-                                    Self::try_save_pure_function(
-                                        Some(id),
-                                        &f.params,
-                                        body,
-                                        f.r#async,
-                                        f.generator,
-                                        ctx,
-                                    );
-                                }
+                            Some(Expression::FunctionExpression(f))
+                                if !f.call_may_have_side_effects(ctx) =>
+                            {
+                                Self::save_pure_function(Some(id), Self::returns_undefined(f), ctx);
                             }
                             _ => {}
                         }
@@ -401,30 +384,22 @@ impl<'a> PeepholeOptimizations {
         }
     }
 
-    fn try_save_pure_function(
+    /// Whether calls to `f` always return `undefined`, i.e. it has an empty body.
+    /// Async functions and generators return an object instead.
+    fn returns_undefined(f: &Function<'a>) -> bool {
+        !f.r#async && !f.generator && f.body.as_ref().is_some_and(|body| body.is_empty())
+    }
+
+    fn save_pure_function(
         id: Option<&BindingIdentifier<'a>>,
-        params: &FormalParameters<'a>,
-        body: &FunctionBody<'a>,
-        r#async: bool,
-        generator: bool,
+        returns_undefined: bool,
         ctx: &mut Ctx<'a, '_>,
     ) {
-        if r#async || generator {
-            return;
-        }
-        // `function foo({}) {} foo(null)` is runtime type error.
-        if !params.items.iter().all(|pat| pat.pattern.is_binding_identifier()) {
-            return;
-        }
-        if body.statements.iter().any(|stmt| stmt.may_have_side_effects(ctx)) {
-            return;
-        }
         let Some(symbol_id) = id.and_then(|id| id.symbol_id.get()) else { return };
         if ctx.scoping().get_resolved_references(symbol_id).all(|r| r.flags().is_read_only()) {
-            ctx.state.pure_functions.insert(
-                symbol_id,
-                if body.is_empty() { Some(ConstantValue::Undefined) } else { None },
-            );
+            ctx.state
+                .pure_functions
+                .insert(symbol_id, returns_undefined.then_some(ConstantValue::Undefined));
         }
     }
 
@@ -766,5 +741,8 @@ mod test {
 
         test_same_options("function* foo({}) {} foo()", &options);
         test_options("var foo = function*({}) {}; foo()", "(function*({}) {})()", &options);
+
+        test_same_options("function foo(a = bar()) {} foo()", &options);
+        test_options("function foo(a = 1) {} foo()", "", &options);
     }
 }
@@ -7,7 +7,8 @@ use oxc_ast::ast::{Expression, IdentifierReference, Statement};
 use oxc_ecmascript::{
     GlobalContext,
     side_effects::{
-        MayHaveSideEffects, MayHaveSideEffectsContext, PropertyReadSideEffects, is_pure_function,
+        CallMayHaveSideEffects, MayHaveSideEffects, MayHaveSideEffectsContext,
+        PropertyReadSideEffects, is_pure_function,
     },
 };
 use oxc_parser::Parser;
@@ -113,6 +114,20 @@ fn test_in_function(source_text: &str, expected: bool) {
     assert_eq!(stmt.expression.may_have_side_effects(&ctx), expected, "{source_text}");
 }
 
+#[track_caller]
+fn test_call(source_text: &str, expected: bool) {
+    let ctx = Ctx::default();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+    assert!(!ret.panicked, "{source_text}");
+    assert!(ret.errors.is_empty(), "{source_text}");
+
+    let Some(Statement::ExpressionStatement(stmt)) = &ret.program.body.first() else {
+        panic!("should have a expression statement body: {source_text}");
+    };
+    assert_eq!(stmt.expression.call_may_have_side_effects(&ctx), expected, "{source_text}");
+}
+
 #[track_caller]
 fn test_assign_target(source_text: &str, expected: bool) {
     test_assign_target_with_global_variables(source_text, &[], expected);
@@ -1125,3 +1140,30 @@ fn test_assignment_targets() {
     test_assign_target_with_global_variables("a.#b = 1", &["a"], true); // `a` might not be declared and cause ReferenceError in strict mode
     test_assign_target("(foo(), a).#b = 1", true); // `foo()` may have sideeffect
 }
+
+#[test]
+fn test_function_calls() {
+    test_call("(function() {})", false);
+    test_call("(() => {})", false);
+    test_call("(() => 1)", false);
+    test_call("(function(a, b = 1, ...c) { if (a) { return 1 } return b })", false);
+    test_call("(function() { let a = 1; type T = number; return a })", false);
+    test_call("(function() { foo() })", true);
+    test_call("(() => foo())", true);
+    test_call("(function() { throw 0 })", true);
+    // `foo(null)` throws when destructuring
+    test_call("(function({}) {})", true);
+    test_call("(function(...[a]) {})", true);
+    test_call("(function(a = foo()) {})", true);
+    // Async functions may reject, and generators are not run until iterated
+    test_call("(async function() {})", true);
+    test_call("(async () => {})", true);
+    test_call("(function*() {})", true);
+    test_call("(function() { enum E { A } })", true);
+    // Annotations
+    test_call("(/* #__NO_SIDE_EFFECTS__ */ function() { foo() })", false);
+    test_call("(/* @__NO_SIDE_EFFECTS__ */ () => foo())", false);
+    test_call("(/* #__NO_SIDE_EFFECTS__ */ async () => foo())", false);
+    // Not a function
+    test_call("foo", true);
+}
@@ -0,0 +1,284 @@
+//! Lossless concrete syntax: the tokens of a program and the trivia between them.
+//!
+//! Parse with [`ParseOptions::collect_tokens`](crate::ParseOptions::collect_tokens) enabled to get
+//! the tokens in [`ParserReturn::tokens`](crate::ParserReturn::tokens).
+//!
+//! ```
+//! use oxc_allocator::Allocator;
+//! use oxc_parser::{ParseOptions, Parser, cst::ConcreteSyntax};
+//! use oxc_span::SourceType;
+//!
+//! let source_text = "let a = 1; // one\nlet b = 2;\n";
+//! let allocator = Allocator::default();
+//! let options = ParseOptions { collect_tokens: true, ..ParseOptions::default() };
+//! let ret = Parser::new(&allocator, source_text, SourceType::mjs()).with_options(options).parse();
+//!
+//! let cst = ConcreteSyntax::new(&ret.program, &ret.tokens);
+//! assert_eq!(cst.print(), source_text);
+//! ```
+
+use std::{iter, ops::Range};
+
+use oxc_ast::{Comment, ast::Program};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::line_terminator::is_line_terminator;
+
+/// A piece of trivia: the source text between two tokens which is not part of the AST.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trivia {
+    /// Whitespace and line breaks.
+    Whitespace(Span),
+    /// A line or block comment.
+    Comment(Comment),
+}
+
+impl GetSpan for Trivia {
+    fn span(&self) -> Span {
+        match self {
+            Self::Whitespace(span) => *span,
+            Self::Comment(comment) => comment.span,
+        }
+    }
+}
+
+/// The tokens of a program with the trivia attached to them.
+///
+/// Every byte of the source text belongs to a token or to the trivia of a token:
+/// * The trailing trivia of a token is the trivia after it on the same line.
+/// * The leading trivia of a token is the remaining trivia before it, starting with a line break.
+/// * The trivia after the trailing trivia of the last token is [`ConcreteSyntax::eof_trivia`].
+///
+/// Nodes get the leading trivia of their first token and the trailing trivia of their last token.
+#[derive(Debug, Clone, Copy)]
+pub struct ConcreteSyntax<'a> {
+    source_text: &'a str,
+    tokens: &'a [Span],
+    comments: &'a [Comment],
+}
+
+impl<'a> ConcreteSyntax<'a> {
+    /// Create the concrete syntax of `program` from its
+    /// [`ParserReturn::tokens`](crate::ParserReturn::tokens).
+    pub fn new(program: &'a Program<'_>, tokens: &'a [Span]) -> Self {
+        Self { source_text: program.source_text, tokens, comments: &program.comments }
+    }
+
+    /// Spans of all tokens, sorted.
+    pub fn tokens(&self) -> &'a [Span] {
+        self.tokens
+    }
+
+    /// Spans of the tokens of a node, including its parentheses when they are part of its span.
+    pub fn tokens_of(&self, span: Span) -> &'a [Span] {
+        &self.tokens[self.token_range(span)]
+    }
+
+    /// The trivia before the token at `index`.
+    pub fn leading_trivia(&self, index: usize) -> Span {
+        let start = index.checked_sub(1).map_or(0, |index| self.trailing_trivia(index).end);
+        Span::new(start, self.tokens[index].start)
+    }
+
+    /// The trivia after the token at `index`, up to the end of its line.
+    pub fn trailing_trivia(&self, index: usize) -> Span {
+        let start = self.tokens[index].end;
+        let end = self.tokens.get(index + 1).map_or(self.source_end(), |token| token.start);
+        let gap = Span::new(start, end);
+        Span::new(start, self.line_break(gap).unwrap_or(end))
+    }
+
+    /// The trivia at the end of the source text, after the trailing trivia of the last token.
+    pub fn eof_trivia(&self) -> Span {
+        let start =
+            self.tokens.len().checked_sub(1).map_or(0, |index| self.trailing_trivia(index).end);
+        Span::new(start, self.source_end())
+    }
+
+    /// The leading trivia of the node at `span`, or `None` when it has no tokens.
+    pub fn node_leading_trivia(&self, span: Span) -> Option<Span> {
+        let range = self.token_range(span);
+        (!range.is_empty()).then(|| self.leading_trivia(range.start))
+    }
+
+    /// The trailing trivia of the node at `span`, or `None` when it has no tokens.
+    pub fn node_trailing_trivia(&self, span: Span) -> Option<Span> {
+        let range = self.token_range(span);
+        (!range.is_empty()).then(|| self.trailing_trivia(range.end - 1))
+    }
+
+    /// The whitespace and comments of a trivia span returned by this type.
+    pub fn trivia(&self, span: Span) -> impl Iterator<Item = Trivia> + 'a {
+        let first = self.comments.partition_point(|comment| comment.span.start < span.start);
+        let mut comments = self.comments[first..]
+            .iter()
+            .take_while(move |comment| comment.span.end <= span.end)
+            .copied()
+            .peekable();
+        let mut position = span.start;
+        iter::from_fn(move || {
+            if position >= span.end {
+                return None;
+            }
+            if let Some(comment) = comments.next_if(|comment| comment.span.start == position) {
+                position = comment.span.end;
+                return Some(Trivia::Comment(comment));
+            }
+            let end = comments.peek().map_or(span.end, |comment| comment.span.start);
+            let whitespace = Span::new(position, end);
+            position = end;
+            Some(Trivia::Whitespace(whitespace))
+        })
+    }
+
+    /// Print the tokens with their trivia, which reproduces the source text byte-for-byte.
+    pub fn print(&self) -> String {
+        let mut text = String::with_capacity(self.source_text.len());
+        for (index, token) in self.tokens.iter().enumerate() {
+            text.push_str(self.leading_trivia(index).source_text(self.source_text));
+            text.push_str(token.source_text(self.source_text));
+            text.push_str(self.trailing_trivia(index).source_text(self.source_text));
+        }
+        text.push_str(self.eof_trivia().source_text(self.source_text));
+        text
+    }
+
+    fn token_range(&self, span: Span) -> Range<usize> {
+        let start = self.tokens.partition_point(|token| token.start < span.start);
+        let end = self.tokens.partition_point(|token| token.end <= span.end);
+        start..end.max(start)
+    }
+
+    /// Position of the first line break of the trivia at `span` which is not inside a comment.
+    #[expect(clippy::cast_possible_truncation)]
+    fn line_break(&self, span: Span) -> Option<u32> {
+        self.trivia(span).find_map(|trivia| match trivia {
+            Trivia::Whitespace(span) => span
+                .source_text(self.source_text)
+                .char_indices()
+                .find(|(_, c)| is_line_terminator(*c))
+                .map(|(offset, _)| span.start + offset as u32),
+            Trivia::Comment(_) => None,
+        })
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn source_end(&self) -> u32 {
+        self.source_text.len() as u32
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_span::{GetSpan, SourceType, Span};
+
+    use super::{ConcreteSyntax, Trivia};
+    use crate::{ParseOptions, Parser};
+
+    fn assert_lossless(source_text: &str, source_type: SourceType) {
+        let allocator = Allocator::default();
+        let options = ParseOptions { collect_tokens: true, ..ParseOptions::default() };
+        let ret = Parser::new(&allocator, source_text, source_type).with_options(options).parse();
+        assert!(ret.errors.is_empty(), "{source_text}: {:?}", ret.errors);
+        let cst = ConcreteSyntax::new(&ret.program, &ret.tokens);
+
+        assert!(
+            cst.tokens().windows(2).all(|tokens| tokens[0].end <= tokens[1].start),
+            "{source_text}: {:?}",
+            cst.tokens()
+        );
+        assert!(cst.tokens().iter().all(|token| !token.is_empty()), "{source_text}");
+        for index in 0..cst.tokens().len() {
+            for span in [cst.leading_trivia(index), cst.trailing_trivia(index)] {
+                for trivia in cst.trivia(span) {
+                    if let Trivia::Whitespace(span) = trivia {
+                        assert!(span.source_text(source_text).trim().is_empty(), "{source_text}");
+                    }
+                }
+            }
+        }
+        assert_eq!(cst.print(), source_text);
+    }
+
+    #[test]
+    fn lossless() {
+        let cases = [
+            ("", SourceType::mjs()),
+            ("  // only a comment\n", SourceType::mjs()),
+            ("#!/usr/bin/env node\nlet a = 1;", SourceType::mjs()),
+            ("let a = (1 + 2) * /* c */ 3;\r\nlet b = `x${a}y${`z`}`;  \n", SourceType::mjs()),
+            ("const re = /ab+c/gi, d = a / b / c;", SourceType::mjs()),
+            ("const f = async (a, b) => { await a; };\nf((1), [2]);", SourceType::mjs()),
+            ("let a: Array<Array<number>> = [];\nfoo<string>(a >> 1);", SourceType::ts()),
+            ("const x = <div a=\"1\" {...b}>text {/* c */} <b-c /></div>;", SourceType::jsx()),
+            (
+                "class A { #x = 1; static { this.#x; } }\n/** doc */\nexport default A;",
+                SourceType::mjs(),
+            ),
+        ];
+        for (source_text, source_type) in cases {
+            assert_lossless(source_text, source_type);
+        }
+    }
+
+    #[test]
+    fn trivia_attachment() {
+        let source_text = "let a = 1; // one\n\n/* two */ b(a) /* three */;\n// four\n";
+        let allocator = Allocator::default();
+        let options = ParseOptions { collect_tokens: true, ..ParseOptions::default() };
+        let ret =
+            Parser::new(&allocator, source_text, SourceType::mjs()).with_options(options).parse();
+        let cst = ConcreteSyntax::new(&ret.program, &ret.tokens);
+        let text = |span: Span| span.source_text(source_text);
+
+        let Statement::ExpressionStatement(statement) = &ret.program.body[1] else {
+            unreachable!()
+        };
+        let tokens = cst.tokens_of(statement.span);
+        assert_eq!(
+            tokens.iter().map(|span| text(*span)).collect::<Vec<_>>(),
+            ["b", "(", "a", ")", ";"]
+        );
+        assert_eq!(text(cst.node_leading_trivia(statement.span).unwrap()), "\n\n/* two */ ");
+        assert_eq!(text(cst.node_trailing_trivia(statement.span).unwrap()), "");
+        assert_eq!(text(cst.node_trailing_trivia(ret.program.body[0].span()).unwrap()), " // one");
+        assert_eq!(
+            text(cst.node_trailing_trivia(statement.expression.span()).unwrap()),
+            " /* three */"
+        );
+        assert_eq!(text(cst.eof_trivia()), "\n// four\n");
+
+        let trivia =
+            cst.trivia(cst.node_leading_trivia(statement.span).unwrap()).collect::<Vec<_>>();
+        assert_eq!(
+            trivia.iter().map(|trivia| text(trivia.span())).collect::<Vec<_>>(),
+            ["\n\n", "/* two */", " "]
+        );
+        assert!(matches!(trivia[1], Trivia::Comment(_)));
+        assert_eq!(cst.node_leading_trivia(Span::empty(3)), None);
+    }
+
+    #[test]
+    fn parentheses_without_preserve_parens() {
+        let source_text = "let a = ((b));";
+        let allocator = Allocator::default();
+        let options = ParseOptions {
+            collect_tokens: true,
+            preserve_parens: false,
+            ..ParseOptions::default()
+        };
+        let ret =
+            Parser::new(&allocator, source_text, SourceType::mjs()).with_options(options).parse();
+        let cst = ConcreteSyntax::new(&ret.program, &ret.tokens);
+        assert_eq!(cst.tokens().len(), 9);
+        assert_eq!(cst.print(), source_text);
+    }
+
+    #[test]
+    fn no_tokens_by_default() {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, "let a = 1;", SourceType::mjs()).parse();
+        assert!(ret.tokens.is_empty());
+    }
+}
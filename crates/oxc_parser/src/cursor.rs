@@ -9,6 +9,7 @@ use crate::{
     Context, ParserImpl, diagnostics,
     error_handler::FatalError,
     lexer::{Kind, LexerCheckpoint, LexerContext, Token},
+    module_record::ModuleRecordCheckpoint,
 };
 
 #[derive(Clone)]
@@ -16,8 +17,10 @@ pub struct ParserCheckpoint<'a> {
     lexer: LexerCheckpoint<'a>,
     cur_token: Token,
     prev_span_end: u32,
+    tokens_len: usize,
     errors_pos: usize,
     fatal_error: Option<FatalError>,
+    module_record: ModuleRecordCheckpoint,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -101,13 +104,22 @@ impl<'a> ParserImpl<'a> {
         if self.token.escaped() && kind.is_any_keyword() {
             self.report_escaped_keyword(self.token.span());
         }
+        self.collect_token();
         self.prev_token_end = self.token.end();
         self.token = self.lexer.next_token();
     }
 
+    #[inline]
+    fn collect_token(&mut self) {
+        if self.options.collect_tokens {
+            self.tokens.push(self.token.span());
+        }
+    }
+
     /// Move to the next `JSXChild`
     /// Checks if the current token is escaped if it is a keyword
     pub(crate) fn advance_for_jsx_child(&mut self) {
+        self.collect_token();
         self.prev_token_end = self.token.end();
         self.token = self.lexer.next_jsx_child();
     }
@@ -249,7 +261,9 @@ impl<'a> ParserImpl<'a> {
 
     /// Tell lexer to continue reading jsx identifier if the lexer character position is at `-` for `<component-name>`
     pub(crate) fn continue_lex_jsx_identifier(&mut self) {
-        if let Some(token) = self.lexer.continue_lex_jsx_identifier() {
+        if let Some(mut token) = self.lexer.continue_lex_jsx_identifier() {
+            // The lexer continues from the end of the current token
+            token.set_start(self.token.start());
             self.token = token;
         }
     }
@@ -305,8 +319,10 @@ impl<'a> ParserImpl<'a> {
             lexer: self.lexer.checkpoint(),
             cur_token: self.token,
             prev_span_end: self.prev_token_end,
+            tokens_len: self.tokens.len(),
             errors_pos: self.errors.len(),
             fatal_error: self.fatal_error.take(),
+            module_record: self.module_record_builder.checkpoint(self.token.start()),
         }
     }
 
@@ -315,20 +331,42 @@ impl<'a> ParserImpl<'a> {
             lexer: self.lexer.checkpoint_with_error_recovery(),
             cur_token: self.token,
             prev_span_end: self.prev_token_end,
+            tokens_len: self.tokens.len(),
             errors_pos: self.errors.len(),
             fatal_error: self.fatal_error.take(),
+            module_record: self.module_record_builder.checkpoint(self.token.start()),
         }
     }
 
     pub(crate) fn rewind(&mut self, checkpoint: ParserCheckpoint<'a>) {
-        let ParserCheckpoint { lexer, cur_token, prev_span_end, errors_pos, fatal_error } =
-            checkpoint;
+        let ParserCheckpoint {
+            lexer,
+            cur_token,
+            prev_span_end,
+            tokens_len,
+            errors_pos,
+            fatal_error,
+            module_record,
+        } = checkpoint;
 
         self.lexer.rewind(lexer);
         self.token = cur_token;
         self.prev_token_end = prev_span_end;
+        self.tokens.truncate(tokens_len);
         self.errors.truncate(errors_pos);
         self.fatal_error = fatal_error;
+        self.module_record_builder.rewind(module_record);
+    }
+
+    /// Rewind the tokens and the module record to `checkpoint`, keeping the parser errors recorded since.
+    pub(crate) fn rewind_tokens(&mut self, checkpoint: ParserCheckpoint<'a>) {
+        let ParserCheckpoint { lexer, cur_token, prev_span_end, tokens_len, module_record, .. } =
+            checkpoint;
+        self.lexer.rewind(lexer);
+        self.token = cur_token;
+        self.prev_token_end = prev_span_end;
+        self.tokens.truncate(tokens_len);
+        self.module_record_builder.rewind(module_record);
     }
 
     pub(crate) fn try_parse<T>(
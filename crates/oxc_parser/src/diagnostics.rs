@@ -621,6 +621,24 @@ pub fn v8_intrinsic_spread_elem(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+#[cold]
+pub fn pipeline_operator_not_allowed(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("The pipeline operator `|>` is experimental")
+        .with_label(span)
+        .with_help("Enable the `allow_pipeline_operator` parser option to parse it")
+}
+
+#[cold]
+pub fn pipeline_body_without_topic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Pipeline body must contain a topic reference `%`").with_label(span)
+}
+
+#[cold]
+pub fn topic_reference_outside_pipeline_body(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Topic reference `%` is only allowed in the body of a pipeline expression")
+        .with_label(span)
+}
+
 #[cold]
 pub fn a_set_accessor_cannot_have_a_return_type_annotation(span: Span) -> OxcDiagnostic {
     ts_error("1095", "A 'set' accessor cannot have a return type annotation.").with_label(span)
@@ -1185,3 +1203,15 @@ pub fn only_default_import_allowed_in_source_phase(span: Span) -> OxcDiagnostic
     OxcDiagnostic::error("Only a single default import is allowed in a source phase import.")
         .with_label(span)
 }
+
+#[cold]
+pub fn json_comment(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Comments are not allowed in JSON")
+        .with_label(span)
+        .with_help("Remove the comment, or parse the file as JSONC or JSON5")
+}
+
+#[cold]
+pub fn json_nesting_too_deep(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("JSON is nested too deeply").with_label(span)
+}
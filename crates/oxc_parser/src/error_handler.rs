@@ -4,7 +4,7 @@ use oxc_allocator::Dummy;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
 
-use crate::{ParserImpl, diagnostics, lexer::Kind};
+use crate::{ParserImpl, cursor::ParserCheckpoint, diagnostics, lexer::Kind};
 
 /// Fatal parsing error.
 #[derive(Debug, Clone)]
@@ -13,6 +13,8 @@ pub struct FatalError {
     pub error: OxcDiagnostic,
     /// Length of `errors` at time fatal error is recorded
     pub errors_len: usize,
+    /// Start of the token at which the fatal error is recorded
+    pub position: u32,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -66,8 +68,9 @@ impl<'a> ParserImpl<'a> {
     #[cold]
     pub(crate) fn set_fatal_error(&mut self, error: OxcDiagnostic) {
         if self.fatal_error.is_none() {
+            let position = self.cur_token().start();
             self.lexer.advance_to_end();
-            self.fatal_error = Some(FatalError { error, errors_len: self.errors.len() });
+            self.fatal_error = Some(FatalError { error, errors_len: self.errors.len(), position });
         }
     }
 
@@ -80,6 +83,69 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn has_fatal_error(&self) -> bool {
         matches!(self.cur_kind(), Kind::Eof | Kind::Undetermined) || self.fatal_error.is_some()
     }
+
+    /// Recover from the fatal error of a statement parsed since `checkpoint`, when
+    /// [`ParseOptions::recover_from_errors`](crate::ParseOptions::recover_from_errors) is enabled.
+    ///
+    /// The errors of the statement are kept, and its tokens are skipped along with the imports and
+    /// exports added to the module record.
+    /// Returns `false` if there is no fatal error to recover from.
+    pub(crate) fn recover_from_fatal_error(&mut self, checkpoint: ParserCheckpoint<'a>) -> bool {
+        let Some(FatalError { error, errors_len, position }) = self.fatal_error.take() else {
+            return false;
+        };
+        // Errors recorded after the fatal error come from parsing dummy nodes.
+        self.errors.truncate(errors_len);
+        self.error(error);
+
+        self.rewind_tokens(checkpoint);
+        let start = self.start_span();
+        self.skip_statement(start, position);
+        self.skipped_spans.push(Span::new(start, self.prev_token_end.max(start)));
+        true
+    }
+
+    /// Skip the tokens of the statement at `start`, which failed to parse at `position`.
+    ///
+    /// Stops after the error, outside of the brackets opened after it, at the first token which
+    /// starts a new line, after a `;`, or at a `}` found where the error is, which closes the
+    /// enclosing block. Brackets opened before the error may never be closed, so they are not
+    /// tracked. At least one token is skipped, unless the end of the file is reached.
+    ///
+    /// The lexer errors of the skipped tokens are dropped, as the fatal error already reports the
+    /// statement.
+    fn skip_statement(&mut self, start: u32, position: u32) {
+        let lexer_errors_start = self.lexer.errors.len();
+        let mut lexer_errors_end = lexer_errors_start;
+        let mut depth = 0u32;
+        loop {
+            let token = self.cur_token();
+            if token.kind().is_eof() {
+                break;
+            }
+            if token.start() >= position && token.start() != start {
+                if depth == 0
+                    && (token.is_on_new_line()
+                        || (token.kind() == Kind::RCurly && token.start() == position))
+                {
+                    break;
+                }
+                match token.kind() {
+                    Kind::LCurly | Kind::LParen | Kind::LBrack => depth += 1,
+                    Kind::RCurly | Kind::RParen | Kind::RBrack => depth = depth.saturating_sub(1),
+                    Kind::Semicolon if depth == 0 => {
+                        lexer_errors_end = self.lexer.errors.len();
+                        self.bump_any();
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+            lexer_errors_end = self.lexer.errors.len();
+            self.bump_any();
+        }
+        self.lexer.errors.drain(lexer_errors_start..lexer_errors_end);
+    }
 }
 
 // ==================== Merge Conflict Marker Detection ====================
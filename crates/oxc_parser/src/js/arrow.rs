@@ -141,7 +141,9 @@ impl<'a> ParserImpl<'a> {
 
                         match third {
                             // If we have something like "(a:", then we must have a
-                            // type-annotated parameter in an arrow function expression.
+                            // type-annotated parameter in an arrow function expression,
+                            // unless it is a Flow type cast "(a: T)".
+                            Kind::Colon if self.options.allow_flow_syntax => Tristate::Maybe,
                             Kind::Colon => Tristate::True,
                             // If we have "(a?:" or "(a?," or "(a?=" or "(a?)" then it is definitely a lambda.
                             Kind::Question => {
@@ -1,3 +1,5 @@
+use std::mem;
+
 use cow_utils::CowUtils;
 use oxc_allocator::{Box, TakeIn, Vec};
 use oxc_ast::ast::*;
@@ -194,9 +196,15 @@ impl<'a> ParserImpl<'a> {
             Kind::NoSubstitutionTemplate | Kind::TemplateHead => {
                 self.parse_template_literal_expression(false)
             }
-            Kind::Percent if self.options.allow_v8_intrinsics => {
+            Kind::Percent
+                if self.options.allow_v8_intrinsics
+                    && (!self.options.allow_pipeline_operator
+                        || self.lexer.peek_token().kind().is_identifier_name()) =>
+            {
                 self.parse_v8_intrinsic_expression()
             }
+            Kind::Percent if self.options.allow_pipeline_operator => self.parse_topic_reference(),
+            Kind::Do if self.options.allow_do_expressions => self.parse_do_expression(),
             Kind::New => self.parse_new_expression(),
             Kind::Super => self.parse_super(),
             Kind::Import => self.parse_import_meta_or_call(),
@@ -222,7 +230,7 @@ impl<'a> ParserImpl<'a> {
                 Kind::RParen,
                 Kind::Comma,
                 opening_span,
-                Self::parse_assignment_expression_or_higher,
+                Self::parse_flow_type_cast_or_assignment_expression,
             )
         });
 
@@ -644,6 +652,61 @@ impl<'a> ParserImpl<'a> {
         }
     }
 
+    /// Section [Hack Pipe](https://tc39.es/proposal-pipeline-operator/#sec-pipe-operator)
+    /// `head |> body |> body`
+    ///
+    /// `|>` has the same precedence as assignment, so a body such as `% ? a : b` is a conditional
+    /// expression, while the head is a short-circuit expression.
+    fn parse_pipeline_expression_rest(
+        &mut self,
+        span: u32,
+        mut head: Expression<'a>,
+        allow_return_type_in_arrow_function: bool,
+    ) -> Expression<'a> {
+        while self.at(Kind::Pipeline) {
+            if !self.options.allow_pipeline_operator {
+                let error = diagnostics::pipeline_operator_not_allowed(self.cur_token().span());
+                self.error(error);
+            }
+            self.bump_any();
+            let outer_topic = self.state.pipeline_topic.replace(false);
+            let body_span = self.start_span();
+            let body = self.parse_binary_expression_or_higher(Precedence::Comma);
+            let body = self.parse_conditional_expression_rest(
+                body_span,
+                body,
+                allow_return_type_in_arrow_function,
+            );
+            let has_topic = mem::replace(&mut self.state.pipeline_topic, outer_topic);
+            if has_topic == Some(false) && self.options.allow_pipeline_operator {
+                self.error(diagnostics::pipeline_body_without_topic(body.span()));
+            }
+            head = self.ast.expression_pipeline(self.end_span(span), head, body);
+        }
+        head
+    }
+
+    /// `%` in the body of a pipeline expression.
+    fn parse_topic_reference(&mut self) -> Expression<'a> {
+        let span = self.start_span();
+        self.bump_any();
+        let span = self.end_span(span);
+        match &mut self.state.pipeline_topic {
+            Some(has_topic) => *has_topic = true,
+            None => self.error(diagnostics::topic_reference_outside_pipeline_body(span)),
+        }
+        self.ast.expression_topic_reference(span)
+    }
+
+    /// [Do Expression](https://github.com/tc39/proposal-do-expressions)
+    /// `do { ... }`
+    fn parse_do_expression(&mut self) -> Expression<'a> {
+        let span = self.start_span();
+        self.bump_any(); // advance `do`
+        let body = self.context(Context::In, Context::Decorator, Self::parse_block);
+        self.ast.expression_do(self.end_span(span), body)
+    }
+
     /// V8 Runtime calls.
     /// See: [runtime.h](https://github.com/v8/v8/blob/5fe0aa3bc79c0a9d3ad546b79211f07105f09585/src/runtime/runtime.h#L43)
     pub(crate) fn parse_v8_intrinsic_expression(&mut self) -> Expression<'a> {
@@ -1318,8 +1381,13 @@ impl<'a> ParserImpl<'a> {
 
         let span = self.start_span();
         let lhs_parenthesized = self.at(Kind::LParen);
-        let lhs = self.parse_binary_expression_or_higher(Precedence::Comma);
-        let lhs_parenthesized_span = lhs_parenthesized.then(|| self.end_span(span));
+        let mut lhs = self.parse_binary_expression_or_higher(Precedence::Comma);
+        let mut lhs_parenthesized_span = lhs_parenthesized.then(|| self.end_span(span));
+        if self.at(Kind::Pipeline) {
+            lhs =
+                self.parse_pipeline_expression_rest(span, lhs, allow_return_type_in_arrow_function);
+            lhs_parenthesized_span = None;
+        }
         let kind = self.cur_kind();
 
         // `x => {}`
@@ -5,7 +5,7 @@ use oxc_span::{GetSpan, Span};
 use super::FunctionKind;
 use crate::{
     Context, ParserImpl, StatementContext, diagnostics,
-    lexer::Kind,
+    lexer::{Kind, ReLex, ReLexHeuristic},
     modifiers::{ModifierFlags, ModifierKind, Modifiers},
 };
 
@@ -29,6 +29,11 @@ impl<'a> ParserImpl<'a> {
     }
 
     pub(crate) fn parse_function_body(&mut self) -> Box<'a, FunctionBody<'a>> {
+        if self.options.skip_function_bodies
+            && let Some(body) = self.try_skip_function_body()
+        {
+            return body;
+        }
         let span = self.start_span();
         let opening_span = self.cur_token().span();
         self.expect(Kind::LCurly);
@@ -41,6 +46,56 @@ impl<'a> ParserImpl<'a> {
         self.ast.alloc_function_body(self.end_span(span), directives, statements)
     }
 
+    /// Skip a function body by matching its braces, and return it without statements.
+    ///
+    /// Returns `None` when the body cannot be skipped, e.g. when a regular expression is mistaken
+    /// for a division, and the string inside it is unterminated, or when it contains `import(` or
+    /// `import.`, which must be parsed to be recorded in the module record.
+    fn try_skip_function_body(&mut self) -> Option<Box<'a, FunctionBody<'a>>> {
+        if !self.at(Kind::LCurly) {
+            return None;
+        }
+        let checkpoint = self.checkpoint();
+        let lexer_errors = self.lexer.errors.len();
+        let span = self.start_span();
+        self.bump_any(); // bump `{`
+
+        let mut heuristic = ReLexHeuristic::default();
+        heuristic.push(Kind::LCurly);
+        let mut prev_kind = Kind::LCurly;
+        loop {
+            match heuristic.re_lex(self.cur_kind()) {
+                Some(ReLex::TemplateSubstitutionTail) => self.re_lex_template_substitution_tail(),
+                Some(ReLex::RegExp) => {
+                    self.read_regex();
+                }
+                None => {}
+            }
+            let kind = self.cur_kind();
+            heuristic.push(kind);
+            if kind == Kind::Eof || (kind == Kind::RCurly && heuristic.depth() == 0) {
+                break;
+            }
+            // `import(...)`, `import.meta`, `import.source(...)` and `import.defer(...)`
+            if prev_kind == Kind::Import && matches!(kind, Kind::LParen | Kind::Dot) {
+                self.rewind(checkpoint);
+                return None;
+            }
+            prev_kind = kind;
+            self.bump_any();
+            if self.lexer.errors.len() > lexer_errors {
+                break;
+            }
+        }
+
+        if !self.at(Kind::RCurly) || self.lexer.errors.len() > lexer_errors {
+            self.rewind(checkpoint);
+            return None;
+        }
+        self.bump_any(); // bump `}`
+        Some(self.ast.alloc_function_body(self.end_span(span), self.ast.vec(), self.ast.vec()))
+    }
+
     pub(crate) fn parse_formal_parameters(
         &mut self,
         func_kind: FunctionKind,
@@ -66,6 +66,8 @@ impl<'a> ParserImpl<'a> {
         span: u32,
         should_record_module_record: bool,
     ) -> Statement<'a> {
+        // Flow `import typeof something from ...` is a type import
+        let is_flow_typeof_import = self.options.allow_flow_syntax && self.eat(Kind::Typeof);
         let token_after_import = self.cur_token();
         let mut identifier_after_import: Option<BindingIdentifier<'_>> =
             if self.cur_kind().is_binding_identifier() {
@@ -198,6 +200,9 @@ impl<'a> ParserImpl<'a> {
             }
             // else: `import source from 'source'` - source is the binding name, no phase
         }
+        if is_flow_typeof_import {
+            import_kind = ImportOrExportKind::Type;
+        }
 
         let specifiers = if self.at(Kind::Str) {
             if has_default_specifier && !should_parse_specifiers {
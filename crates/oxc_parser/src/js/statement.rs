@@ -47,7 +47,14 @@ impl<'a> ParserImpl<'a> {
             if !is_top_level && self.at(Kind::RCurly) {
                 break;
             }
+            let checkpoint = self.options.recover_from_errors.then(|| self.checkpoint());
             let stmt = self.parse_statement_list_item(stmt_ctx);
+            if let Some(checkpoint) = checkpoint
+                && self.recover_from_fatal_error(checkpoint)
+            {
+                expecting_directives = false;
+                continue;
+            }
 
             // Section 11.2.1 Directive Prologue
             // The only way to get a correct directive is to parse the statement first and check if it is a string literal.
@@ -0,0 +1,673 @@
+//! Parser for JSON and its dialects JSONC and JSON5, which keeps the span of every value.
+//!
+//! For tools which report on JSON files, e.g. configuration files and `package.json`, where
+//! `serde_json` would discard the positions.
+//!
+//! ```
+//! use oxc_parser::json::{JsonDialect, JsonParser};
+//!
+//! let source_text = r#"{ "rules": { "no-debugger": "warn", }, // comment
+//! }"#;
+//! let value = JsonParser::new(source_text, JsonDialect::Jsonc).parse().unwrap();
+//!
+//! let severity = value.get("rules").and_then(|rules| rules.get("no-debugger")).unwrap();
+//! assert_eq!(severity.as_str(), Some("warn"));
+//! assert_eq!(severity.span.source_text(source_text), r#""warn""#);
+//! ```
+
+use std::borrow::Cow;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{
+    identifier::{is_identifier_part, is_identifier_start, is_white_space},
+    line_terminator::is_line_terminator,
+};
+
+use crate::{MAX_LEN, diagnostics};
+
+/// Maximum depth of nested arrays and objects, as in `serde_json`.
+const MAX_DEPTH: u32 = 128;
+
+/// Dialect of JSON to parse.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum JsonDialect {
+    /// [JSON](https://www.rfc-editor.org/rfc/rfc8259).
+    #[default]
+    Json,
+    /// JSON with comments and trailing commas, as in `tsconfig.json` and VS Code settings.
+    Jsonc,
+    /// [JSON5](https://spec.json5.org), which adds unquoted keys, single-quoted strings,
+    /// hexadecimal numbers, `Infinity` and `NaN`, and more to JSONC.
+    Json5,
+}
+
+impl JsonDialect {
+    /// Dialect of a file with extension `extension`: `jsonc` and `json5` files are in those
+    /// dialects, other files in JSON.
+    pub fn from_extension(extension: &str) -> Self {
+        match extension {
+            "jsonc" => Self::Jsonc,
+            "json5" => Self::Json5,
+            _ => Self::Json,
+        }
+    }
+
+    fn allows_comments_and_trailing_commas(self) -> bool {
+        matches!(self, Self::Jsonc | Self::Json5)
+    }
+}
+
+/// A JSON value and its span.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonValue<'a> {
+    /// Span of the value in the source text.
+    pub span: Span,
+    /// The value.
+    pub kind: JsonValueKind<'a>,
+}
+
+/// Kind of a [`JsonValue`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValueKind<'a> {
+    /// `null`.
+    Null,
+    /// `true` or `false`.
+    Boolean(bool),
+    /// A number.
+    Number(f64),
+    /// A string, borrowed from the source text unless it contains escape sequences.
+    String(Cow<'a, str>),
+    /// An array.
+    Array(Vec<JsonValue<'a>>),
+    /// An object, with its properties in source order, including duplicates.
+    Object(Vec<JsonProperty<'a>>),
+}
+
+/// A property of a JSON object.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonProperty<'a> {
+    /// Key of the property.
+    pub key: JsonKey<'a>,
+    /// Value of the property.
+    pub value: JsonValue<'a>,
+}
+
+/// Key of a [`JsonProperty`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonKey<'a> {
+    /// Span of the key in the source text, including quotes.
+    pub span: Span,
+    /// The key.
+    pub value: Cow<'a, str>,
+}
+
+impl<'a> JsonValue<'a> {
+    /// Value of the property `key`, if this is an object which has it.
+    ///
+    /// If the object has duplicate keys, the value of the last one is returned, as in JavaScript.
+    pub fn get(&self, key: &str) -> Option<&JsonValue<'a>> {
+        self.as_object()?
+            .iter()
+            .rev()
+            .find(|property| property.key.value == key)
+            .map(|property| &property.value)
+    }
+
+    /// Properties of this value, if it is an object.
+    pub fn as_object(&self) -> Option<&[JsonProperty<'a>]> {
+        match &self.kind {
+            JsonValueKind::Object(properties) => Some(properties),
+            _ => None,
+        }
+    }
+
+    /// Elements of this value, if it is an array.
+    pub fn as_array(&self) -> Option<&[JsonValue<'a>]> {
+        match &self.kind {
+            JsonValueKind::Array(elements) => Some(elements),
+            _ => None,
+        }
+    }
+
+    /// This value, if it is a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match &self.kind {
+            JsonValueKind::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// This value, if it is a number.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self.kind {
+            JsonValueKind::Number(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// This value, if it is a boolean.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.kind {
+            JsonValueKind::Boolean(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Whether this value is `null`.
+    pub fn is_null(&self) -> bool {
+        self.kind == JsonValueKind::Null
+    }
+}
+
+impl GetSpan for JsonValue<'_> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// Parses JSON source text. See the [module-level documentation](self).
+pub struct JsonParser<'a> {
+    source_text: &'a str,
+    dialect: JsonDialect,
+    /// Byte offset of the next character
+    pos: usize,
+}
+
+type Result<T> = std::result::Result<T, OxcDiagnostic>;
+
+impl<'a> JsonParser<'a> {
+    /// Create a new [`JsonParser`].
+    pub fn new(source_text: &'a str, dialect: JsonDialect) -> Self {
+        Self { source_text, dialect, pos: 0 }
+    }
+
+    /// Parse the source text, which must contain a single value.
+    ///
+    /// # Errors
+    ///
+    /// If the source text is not valid in the dialect. Parsing stops at the first error.
+    pub fn parse(mut self) -> Result<JsonValue<'a>> {
+        if self.source_text.len() > MAX_LEN {
+            return Err(diagnostics::overlong_source());
+        }
+        if self.source_text.starts_with('\u{feff}') {
+            self.pos = '\u{feff}'.len_utf8();
+        }
+        let value = self.parse_value(0)?;
+        self.skip_trivia()?;
+        match self.peek() {
+            None => Ok(value),
+            Some(_) => Err(self.unexpected()),
+        }
+    }
+
+    fn is_json5(&self) -> bool {
+        self.dialect == JsonDialect::Json5
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source_text[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_while(&mut self, f: impl Fn(char) -> bool) {
+        while let Some(c) = self.peek().filter(|&c| f(c)) {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    /// Span from `start` to the current position.
+    #[expect(clippy::cast_possible_truncation)]
+    fn span_from(&self, start: usize) -> Span {
+        // Source text is no longer than `MAX_LEN`, so offsets fit in `u32`
+        Span::new(start as u32, self.pos as u32)
+    }
+
+    /// Span of the next character, or an empty span at the end of the source text.
+    #[expect(clippy::cast_possible_truncation)]
+    fn next_char_span(&self) -> Span {
+        let len = self.peek().map_or(0, char::len_utf8);
+        Span::sized(self.pos as u32, len as u32)
+    }
+
+    fn unexpected(&self) -> OxcDiagnostic {
+        match self.peek() {
+            Some(_) => diagnostics::unexpected_token(self.next_char_span()),
+            None => diagnostics::unexpected_end(self.next_char_span()),
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        if self.eat(c) {
+            return Ok(());
+        }
+        let span = self.next_char_span();
+        let found = span.source_text(self.source_text);
+        let found = if found.is_empty() { "EOF" } else { found };
+        Err(diagnostics::expect_token(c.encode_utf8(&mut [0; 4]), found, span))
+    }
+
+    /// Skip whitespace and comments.
+    fn skip_trivia(&mut self) -> Result<()> {
+        while let Some(c) = self.peek() {
+            match c {
+                ' ' | '\t' | '\n' | '\r' => self.pos += 1,
+                '/' if matches!(
+                    self.source_text.as_bytes().get(self.pos + 1),
+                    Some(b'/' | b'*')
+                ) =>
+                {
+                    self.skip_comment()?;
+                }
+                c if self.is_json5() && (is_white_space(c) || is_line_terminator(c)) => {
+                    self.pos += c.len_utf8();
+                }
+                _ => break,
+            }
+        }
+        Ok(())
+    }
+
+    fn skip_comment(&mut self) -> Result<()> {
+        let start = self.pos;
+        let body = &self.source_text[start + 2..];
+        let len = if body.is_empty() || self.source_text.as_bytes()[start + 1] == b'/' {
+            body.find(is_line_terminator).unwrap_or(body.len())
+        } else {
+            let Some(end) = body.find("*/") else {
+                self.pos = self.source_text.len();
+                return Err(diagnostics::unterminated_multi_line_comment(self.span_from(start)));
+            };
+            end + 2
+        };
+        self.pos = start + 2 + len;
+        if self.dialect.allows_comments_and_trailing_commas() {
+            Ok(())
+        } else {
+            Err(diagnostics::json_comment(self.span_from(start)))
+        }
+    }
+
+    fn parse_value(&mut self, depth: u32) -> Result<JsonValue<'a>> {
+        self.skip_trivia()?;
+        let start = self.pos;
+        let kind = match self.peek() {
+            Some('{' | '[') if depth >= MAX_DEPTH => {
+                return Err(diagnostics::json_nesting_too_deep(self.next_char_span()));
+            }
+            Some('{') => JsonValueKind::Object(self.parse_list('}', |parser| {
+                let key = parser.parse_key()?;
+                parser.skip_trivia()?;
+                parser.expect(':')?;
+                let value = parser.parse_value(depth + 1)?;
+                Ok(JsonProperty { key, value })
+            })?),
+            Some('[') => {
+                JsonValueKind::Array(self.parse_list(']', |parser| parser.parse_value(depth + 1))?)
+            }
+            Some('"') => JsonValueKind::String(self.parse_string('"')?),
+            Some('\'') if self.is_json5() => JsonValueKind::String(self.parse_string('\'')?),
+            Some('-' | '0'..='9') => JsonValueKind::Number(self.parse_number()?),
+            Some('+' | '.') if self.is_json5() => JsonValueKind::Number(self.parse_number()?),
+            Some(c) if is_identifier_start(c) => match self.read_identifier() {
+                "null" => JsonValueKind::Null,
+                "true" => JsonValueKind::Boolean(true),
+                "false" => JsonValueKind::Boolean(false),
+                "Infinity" if self.is_json5() => JsonValueKind::Number(f64::INFINITY),
+                "NaN" if self.is_json5() => JsonValueKind::Number(f64::NAN),
+                _ => return Err(diagnostics::unexpected_token(self.span_from(start))),
+            },
+            _ => return Err(self.unexpected()),
+        };
+        Ok(JsonValue { span: self.span_from(start), kind })
+    }
+
+    /// Parse the elements of an array or the properties of an object, from the opening bracket
+    /// to `close`.
+    fn parse_list<T>(
+        &mut self,
+        close: char,
+        mut parse_element: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        // Opening bracket
+        self.pos += 1;
+        let mut elements = vec![];
+        loop {
+            self.skip_trivia()?;
+            if self.eat(close) {
+                return Ok(elements);
+            }
+            elements.push(parse_element(self)?);
+            self.skip_trivia()?;
+            if self.eat(close) {
+                return Ok(elements);
+            }
+            let comma_span = self.next_char_span();
+            self.expect(',')?;
+            self.skip_trivia()?;
+            if self.peek() == Some(close) && !self.dialect.allows_comments_and_trailing_commas() {
+                let name = if close == ']' { "JSON arrays" } else { "JSON objects" };
+                return Err(diagnostics::unexpected_trailing_comma(name, comma_span));
+            }
+        }
+    }
+
+    fn parse_key(&mut self) -> Result<JsonKey<'a>> {
+        let start = self.pos;
+        let value = match self.peek() {
+            Some('"') => self.parse_string('"')?,
+            Some('\'') if self.is_json5() => self.parse_string('\'')?,
+            Some(c) if self.is_json5() && is_identifier_start(c) => {
+                Cow::Borrowed(self.read_identifier())
+            }
+            _ => return Err(self.unexpected()),
+        };
+        Ok(JsonKey { span: self.span_from(start), value })
+    }
+
+    /// Read an identifier, which starts at the current position.
+    fn read_identifier(&mut self) -> &'a str {
+        let start = self.pos;
+        self.skip_while(is_identifier_part);
+        &self.source_text[start..self.pos]
+    }
+
+    fn parse_string(&mut self, quote: char) -> Result<Cow<'a, str>> {
+        let start = self.pos;
+        self.pos += 1;
+        // Unescaped value, created on the first escape sequence
+        let mut owned: Option<String> = None;
+        let mut chunk_start = self.pos;
+        loop {
+            let Some(c) = self.peek() else {
+                return Err(diagnostics::unterminated_string(self.span_from(start)));
+            };
+            match c {
+                c if c == quote => {
+                    let chunk = &self.source_text[chunk_start..self.pos];
+                    self.pos += 1;
+                    return Ok(match owned {
+                        Some(mut owned) => {
+                            owned.push_str(chunk);
+                            Cow::Owned(owned)
+                        }
+                        None => Cow::Borrowed(chunk),
+                    });
+                }
+                '\\' => {
+                    let owned = owned.get_or_insert_with(String::new);
+                    owned.push_str(&self.source_text[chunk_start..self.pos]);
+                    if let Some(c) = self.parse_escape()? {
+                        owned.push(c);
+                    }
+                    chunk_start = self.pos;
+                }
+                // JSON5 strings can contain U+2028 and U+2029, but not other line terminators
+                '\n' | '\r' if self.is_json5() => {
+                    return Err(diagnostics::unterminated_string(self.span_from(start)));
+                }
+                '\0'..='\u{1f}' if !self.is_json5() => {
+                    return Err(diagnostics::invalid_character(c, self.next_char_span()));
+                }
+                c => self.pos += c.len_utf8(),
+            }
+        }
+    }
+
+    /// Parse an escape sequence starting at the current `\`.
+    ///
+    /// Returns `None` for a JSON5 line continuation, which is not part of the value.
+    fn parse_escape(&mut self) -> Result<Option<char>> {
+        let start = self.pos;
+        self.pos += 1;
+        let Some(c) = self.peek() else {
+            return Err(diagnostics::invalid_escape_sequence(self.span_from(start)));
+        };
+        self.pos += c.len_utf8();
+        let json5 = self.is_json5();
+        let value = match c {
+            '"' | '\\' | '/' => c,
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => return self.parse_unicode_escape(start).map(Some),
+            'v' if json5 => '\u{b}',
+            '0' if json5 && !self.peek().is_some_and(|c| c.is_ascii_digit()) => '\0',
+            'x' if json5 => self
+                .parse_hex_digits(2)
+                .and_then(char::from_u32)
+                .ok_or_else(|| diagnostics::invalid_escape_sequence(self.span_from(start)))?,
+            '\r' if json5 => {
+                self.eat('\n');
+                return Ok(None);
+            }
+            c if json5 && is_line_terminator(c) => return Ok(None),
+            // Other characters escape themselves in JSON5, e.g. `\'`
+            c if json5 && !c.is_ascii_digit() => c,
+            _ => return Err(diagnostics::invalid_escape_sequence(self.span_from(start))),
+        };
+        Ok(Some(value))
+    }
+
+    /// Parse the rest of a `\uXXXX` escape sequence starting at `start`, combining surrogate
+    /// pairs.
+    fn parse_unicode_escape(&mut self, start: usize) -> Result<char> {
+        let high = self.parse_hex_digits(4);
+        let code_point = match high {
+            Some(high @ 0xD800..=0xDBFF) if self.source_text[self.pos..].starts_with("\\u") => {
+                self.pos += 2;
+                match self.parse_hex_digits(4) {
+                    Some(low @ 0xDC00..=0xDFFF) => {
+                        Some(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+                    }
+                    _ => None,
+                }
+            }
+            high => high,
+        };
+        // Lone surrogates are not valid `char`s
+        code_point
+            .and_then(char::from_u32)
+            .ok_or_else(|| diagnostics::unicode_escape_sequence(self.span_from(start)))
+    }
+
+    fn parse_hex_digits(&mut self, count: usize) -> Option<u32> {
+        let digits = self.source_text.get(self.pos..self.pos + count)?;
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        self.pos += count;
+        u32::from_str_radix(digits, 16).ok()
+    }
+
+    fn parse_number(&mut self) -> Result<f64> {
+        let start = self.pos;
+        let json5 = self.is_json5();
+        let negative = self.eat('-');
+        if json5 && !negative {
+            self.eat('+');
+        }
+
+        if json5 {
+            if self.peek().is_some_and(is_identifier_start) {
+                let value = match self.read_identifier() {
+                    "Infinity" => f64::INFINITY,
+                    "NaN" => f64::NAN,
+                    _ => return Err(diagnostics::unexpected_token(self.span_from(start))),
+                };
+                return Ok(if negative { -value } else { value });
+            }
+            let rest = &self.source_text[self.pos..];
+            if rest.starts_with("0x") || rest.starts_with("0X") {
+                self.pos += 2;
+                let digits_start = self.pos;
+                self.skip_while(|c| c.is_ascii_hexdigit());
+                let digits = &self.source_text[digits_start..self.pos];
+                if digits.is_empty() {
+                    let span = self.span_from(start);
+                    return Err(diagnostics::invalid_number(
+                        span.source_text(self.source_text),
+                        span,
+                    ));
+                }
+                let value = digits
+                    .chars()
+                    .filter_map(|c| c.to_digit(16))
+                    .fold(0.0, |value: f64, digit| value.mul_add(16.0, f64::from(digit)));
+                return Ok(if negative { -value } else { value });
+            }
+        }
+
+        let integer_start = self.pos;
+        self.skip_while(|c| c.is_ascii_digit());
+        let integer = &self.source_text[integer_start..self.pos];
+        // No leading zeros, except in `0` itself
+        let mut valid = integer.len() <= 1 || !integer.starts_with('0');
+        if self.eat('.') {
+            let fraction_start = self.pos;
+            self.skip_while(|c| c.is_ascii_digit());
+            let has_fraction = self.pos > fraction_start;
+            // JSON5 allows `.5` and `5.`
+            valid &= if json5 {
+                !integer.is_empty() || has_fraction
+            } else {
+                !integer.is_empty() && has_fraction
+            };
+        } else {
+            valid &= !integer.is_empty();
+        }
+        if self.eat('e') || self.eat('E') {
+            if !self.eat('+') {
+                self.eat('-');
+            }
+            let exponent_start = self.pos;
+            self.skip_while(|c| c.is_ascii_digit());
+            valid &= self.pos > exponent_start;
+        }
+
+        let span = self.span_from(start);
+        let text = span.source_text(self.source_text);
+        match text.parse::<f64>() {
+            Ok(value) if valid => Ok(value),
+            _ => Err(diagnostics::invalid_number(text, span)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{JsonDialect, JsonParser, JsonValue, JsonValueKind};
+
+    fn parse(source_text: &str, dialect: JsonDialect) -> Result<JsonValue<'_>, String> {
+        JsonParser::new(source_text, dialect).parse().map_err(|error| error.message.to_string())
+    }
+
+    #[test]
+    fn values() {
+        let source_text =
+            r#" {"a": [1, -2.5e3, true, false, null], "b": "x\"\u00e9\ud83d\ude00", "": {}} "#;
+        let value = parse(source_text, JsonDialect::Json).unwrap();
+        assert_eq!(value.span.source_text(source_text), source_text.trim());
+
+        let a = value.get("a").unwrap().as_array().unwrap();
+        assert_eq!(a[0].as_f64(), Some(1.0));
+        assert_eq!(a[1].as_f64(), Some(-2500.0));
+        assert_eq!(a[1].span.source_text(source_text), "-2.5e3");
+        assert_eq!(a[2].as_bool(), Some(true));
+        assert_eq!(a[3].as_bool(), Some(false));
+        assert!(a[4].is_null());
+        assert_eq!(value.get("b").unwrap().as_str(), Some("x\"é😀"));
+        assert_eq!(value.get("").unwrap().as_object(), Some(&[][..]));
+
+        let keys = value.as_object().unwrap().iter().map(|property| property.key.span);
+        let keys = keys.map(|span| span.source_text(source_text)).collect::<Vec<_>>();
+        assert_eq!(keys, [r#""a""#, r#""b""#, r#""""#]);
+    }
+
+    #[test]
+    fn duplicate_keys() {
+        let value = parse(r#"{"a": 1, "a": 2}"#, JsonDialect::Json).unwrap();
+        assert_eq!(value.as_object().unwrap().len(), 2);
+        assert_eq!(value.get("a").unwrap().as_f64(), Some(2.0));
+    }
+
+    #[test]
+    fn jsonc() {
+        let source_text = "// comment\n{ /* a */ \"a\": [1, 2,], }";
+        let value = parse(source_text, JsonDialect::Jsonc).unwrap();
+        assert_eq!(value.get("a").unwrap().as_array().unwrap().len(), 2);
+        assert_eq!(
+            parse(source_text, JsonDialect::Json).unwrap_err(),
+            "Comments are not allowed in JSON"
+        );
+        assert_eq!(
+            parse("[1,]", JsonDialect::Json).unwrap_err(),
+            "JSON arrays may not have a trailing comma."
+        );
+    }
+
+    #[test]
+    fn json5() {
+        let source_text =
+            "{ a: 'b\\'\\x41\\\nc', $c_1: +0x1F, d: .5, e: 5., f: -Infinity, g: NaN }";
+        let value = parse(source_text, JsonDialect::Json5).unwrap();
+        assert_eq!(value.get("a").unwrap().as_str(), Some("b'Ac"));
+        assert_eq!(value.get("$c_1").unwrap().as_f64(), Some(31.0));
+        assert_eq!(value.get("d").unwrap().as_f64(), Some(0.5));
+        assert_eq!(value.get("e").unwrap().as_f64(), Some(5.0));
+        assert_eq!(value.get("f").unwrap().as_f64(), Some(f64::NEG_INFINITY));
+        assert!(value.get("g").unwrap().as_f64().unwrap().is_nan());
+        assert!(parse(source_text, JsonDialect::Jsonc).is_err());
+    }
+
+    #[test]
+    fn errors() {
+        let cases = [
+            ("", "Unexpected end of file"),
+            ("[1] 2", "Unexpected token"),
+            ("[1 2]", "Expected `,` but found `2`"),
+            ("{\"a\" 1}", "Expected `:` but found `1`"),
+            ("{a: 1}", "Unexpected token"),
+            ("\"abc", "Unterminated string"),
+            ("\"a\tb\"", "Invalid Character `\t`"),
+            ("\"\\x41\"", "Invalid escape sequence"),
+            ("\"\\ud800\"", "Invalid Unicode escape sequence"),
+            ("01", "Invalid Number 01"),
+            ("1.", "Invalid Number 1."),
+            ("tru", "Unexpected token"),
+            ("/* a", "Unterminated multiline comment"),
+        ];
+        for (source_text, message) in cases {
+            assert_eq!(
+                parse(source_text, JsonDialect::Json).unwrap_err(),
+                message,
+                "{source_text}"
+            );
+        }
+
+        let deep = "[".repeat(200);
+        assert_eq!(parse(&deep, JsonDialect::Json).unwrap_err(), "JSON is nested too deeply");
+    }
+
+    #[test]
+    fn borrowed_strings() {
+        let value = parse(r#"["a", "\n"]"#, JsonDialect::Json).unwrap();
+        let JsonValueKind::Array(elements) = &value.kind else { unreachable!() };
+        assert!(matches!(
+            &elements[0].kind,
+            JsonValueKind::String(std::borrow::Cow::Borrowed("a"))
+        ));
+        assert!(matches!(&elements[1].kind, JsonValueKind::String(std::borrow::Cow::Owned(_))));
+    }
+}
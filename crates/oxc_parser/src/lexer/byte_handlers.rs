@@ -462,6 +462,11 @@ ascii_byte_handler!(PIP(lexer) {
             lexer.consume_char();
             Kind::PipeEq
         }
+        // Hack-style pipeline operator, `|` followed by `>` is never valid otherwise
+        Some(b'>') => {
+            lexer.consume_char();
+            Kind::Pipeline
+        }
         _ => Kind::Pipe
     }
 });
@@ -7,7 +7,7 @@ use crate::diagnostics;
 
 use super::{
     Kind, Lexer, cold_branch,
-    search::{SafeByteMatchTable, byte_search, safe_byte_match_table},
+    search::{SafeByteMatchTable, byte_search, safe_byte_match_table, swar_search},
     source::SourcePosition,
 };
 
@@ -25,9 +25,16 @@ static MULTILINE_COMMENT_START_TABLE: SafeByteMatchTable =
 impl<'a> Lexer<'a> {
     /// Section 12.4 Single Line Comment
     pub(super) fn skip_single_line_comment(&mut self) -> Kind {
+        // Skip to first line break (or 0xE2), 8 bytes at a time.
+        // SAFETY: `\r` and `\n` are ASCII, and `0xE2` is the first byte of a 3-byte character.
+        let (Ok(start) | Err(start)) = unsafe {
+            swar_search(&self.source, self.source.position(), [b'\r', b'\n', LS_OR_PS_FIRST])
+        };
+
         byte_search! {
             lexer: self,
             table: LINE_BREAK_TABLE,
+            start: start,
             continue_if: (next_byte, pos) {
                 // Match found. Decide whether to continue searching.
                 // If this is end of comment, create trivia, and advance `pos` to after line break.
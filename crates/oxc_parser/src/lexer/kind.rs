@@ -143,6 +143,7 @@ pub enum Kind {
     Pipe2,
     Pipe2Eq,
     PipeEq,
+    Pipeline, // |>
     Plus,
     Plus2,
     PlusEq,
@@ -629,6 +630,7 @@ impl Kind {
             Pipe2 => "||",
             Pipe2Eq => "||=",
             PipeEq => "|=",
+            Pipeline => "|>",
             Plus => "+",
             Plus2 => "++",
             PlusEq => "+=",
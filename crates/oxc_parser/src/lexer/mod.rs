@@ -11,6 +11,7 @@ use oxc_allocator::Allocator;
 use oxc_ast::ast::RegExpFlags;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{SourceType, Span};
+use oxc_syntax::identifier::ZWNBSP;
 
 use crate::{UniquePromise, diagnostics};
 
@@ -22,6 +23,7 @@ mod kind;
 mod number;
 mod numeric;
 mod punctuation;
+mod re_lex;
 mod regex;
 mod search;
 mod source;
@@ -35,6 +37,7 @@ mod whitespace;
 
 pub use kind::Kind;
 pub use number::{parse_big_int, parse_float, parse_int};
+pub use re_lex::{ReLex, ReLexHeuristic};
 pub use token::Token;
 
 use source::{Source, SourcePosition};
@@ -196,6 +199,13 @@ impl<'a> Lexer<'a> {
 
     /// Read first token in file.
     pub fn first_token(&mut self) -> Token {
+        // A byte order mark is not part of the source text, so is not irregular whitespace,
+        // and may be followed by a hashbang
+        if self.peek_char() == Some(ZWNBSP) {
+            self.consume_char();
+            self.token.set_start(self.offset());
+        }
+
         // HashbangComment ::
         //     `#!` SingleLineCommentChars?
         let kind = if let Some([b'#', b'!']) = self.peek_2_bytes() {
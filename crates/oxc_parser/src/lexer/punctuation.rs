@@ -58,8 +58,10 @@ impl Lexer<'_> {
         }
     }
 
+    /// Re-tokenize the current `>` with the following `>` and `=`, e.g. to `>>=`
     pub(crate) fn re_lex_right_angle(&mut self) -> Token {
-        self.token.set_start(self.offset());
+        // The `>` has been consumed already
+        self.token.set_start(self.offset() - 1);
         let kind = self.read_right_angle();
         self.finish_next(kind)
     }
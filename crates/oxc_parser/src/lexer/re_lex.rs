@@ -0,0 +1,108 @@
+//! Re-lexing decisions for reading tokens without a parser.
+
+use super::Kind;
+
+/// How to re-lex a token whose meaning depends on the surrounding syntax.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReLex {
+    /// `/` or `/=` starts a regular expression.
+    RegExp,
+    /// `}` closes a template literal substitution.
+    TemplateSubstitutionTail,
+}
+
+/// Decides how to re-lex tokens from the tokens before them, where the parser would use the syntax.
+///
+/// * `/` starts a regular expression, unless it follows an operand, e.g. `a / b`.
+/// * `}` continues a template literal when it closes a substitution, e.g. `` `${a}` ``.
+pub struct ReLexHeuristic {
+    /// Depth of the enclosing `{`
+    depth: u32,
+    /// Brace depth of the substitutions of the enclosing template literals
+    templates: Vec<u32>,
+    /// Whether each enclosing `(` follows `if`, `for`, `while` or `with`
+    parens: Vec<bool>,
+    prev_kind: Kind,
+    /// Whether `prev_kind` is the `)` of e.g. `if (a)`
+    after_statement_head: bool,
+}
+
+impl Default for ReLexHeuristic {
+    fn default() -> Self {
+        // Start as if after `;`, where `/` starts a regular expression
+        Self {
+            depth: 0,
+            templates: vec![],
+            parens: vec![],
+            prev_kind: Kind::Semicolon,
+            after_statement_head: false,
+        }
+    }
+}
+
+impl ReLexHeuristic {
+    /// Depth of the enclosing `{`, including the last token.
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    /// How the next token of `kind` should be re-lexed, if at all.
+    pub fn re_lex(&self, kind: Kind) -> Option<ReLex> {
+        match kind {
+            Kind::RCurly if self.templates.last() == Some(&self.depth) => {
+                Some(ReLex::TemplateSubstitutionTail)
+            }
+            Kind::Slash | Kind::SlashEq if self.slash_starts_regex() => Some(ReLex::RegExp),
+            _ => None,
+        }
+    }
+
+    /// Record the next token, after re-lexing it.
+    pub fn push(&mut self, kind: Kind) {
+        self.after_statement_head = false;
+        match kind {
+            Kind::LCurly => self.depth += 1,
+            Kind::RCurly => self.depth = self.depth.saturating_sub(1),
+            Kind::TemplateHead => self.templates.push(self.depth),
+            Kind::TemplateTail => {
+                self.templates.pop();
+            }
+            Kind::LParen => self
+                .parens
+                .push(matches!(self.prev_kind, Kind::If | Kind::For | Kind::While | Kind::With)),
+            Kind::RParen => self.after_statement_head = self.parens.pop().unwrap_or(false),
+            _ => {}
+        }
+        self.prev_kind = kind;
+    }
+
+    /// Whether a `/` after the previous token starts a regular expression rather than a division.
+    fn slash_starts_regex(&self) -> bool {
+        match self.prev_kind {
+            Kind::RParen => self.after_statement_head,
+            Kind::RBrack
+            | Kind::This
+            | Kind::Super
+            | Kind::PrivateIdentifier
+            | Kind::NoSubstitutionTemplate
+            | Kind::TemplateTail
+            | Kind::Plus2
+            | Kind::Minus2 => false,
+            Kind::Return
+            | Kind::Typeof
+            | Kind::Instanceof
+            | Kind::In
+            | Kind::Of
+            | Kind::New
+            | Kind::Delete
+            | Kind::Void
+            | Kind::Throw
+            | Kind::Case
+            | Kind::Do
+            | Kind::Else
+            | Kind::Yield
+            | Kind::Await => true,
+            kind => !(kind.is_literal() || kind.is_identifier_name()),
+        }
+    }
+}
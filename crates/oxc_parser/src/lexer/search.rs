@@ -3,10 +3,17 @@
 //! * `ByteMatchTable` and `SafeByteMatchTable` are lookup table types for byte values.
 //! * `byte_match_table!` and `safe_byte_match_table!` macros create those tables at compile time.
 //! * `byte_search!` macro searches source text for first byte matching a byte table.
+//! * `swar_search` skips through source text 8 bytes at a time, to find a position from which
+//!   to start `byte_search!`.
+
+use super::source::{Source, SourcePosition, is_utf8_cont_byte};
 
 /// Batch size for searching
 pub const SEARCH_BATCH_SIZE: usize = 32;
 
+/// Number of bytes processed at once by SWAR ("SIMD within a register") searches
+pub const SWAR_WORD_SIZE: usize = size_of::<u64>();
+
 /// Byte matcher lookup table.
 ///
 /// Create table at compile time as a `static` or `const` with `byte_match_table!` macro.
@@ -521,3 +528,63 @@ macro_rules! byte_search {
     }};
 }
 pub(crate) use byte_search;
+
+/// `u64` with every byte set to `0x01`.
+const LOW_BITS: u64 = u64::from_ne_bytes([0x01; SWAR_WORD_SIZE]);
+/// `u64` with every byte set to `0x80`.
+const HIGH_BITS: u64 = u64::from_ne_bytes([0x80; SWAR_WORD_SIZE]);
+
+/// Search for first of `bytes` in source, starting at `pos`, processing 8 bytes at a time.
+///
+/// Returns `Ok` with position of first byte which is one of `bytes`, or `Err` with position at which
+/// fewer than `SWAR_WORD_SIZE` bytes remain in source, if no match before that.
+/// In the `Err` case, the rest of the search is left to `byte_search!` or a byte-by-byte loop,
+/// which also handles EOF.
+///
+/// Compared to `byte_search!`'s table lookups, this is much faster at skipping the long runs of
+/// bytes which do not match, which are common in strings and comments in large or minified files.
+///
+/// # SAFETY
+/// All of `bytes` must be ASCII, or the first byte of a multi-byte UTF-8 character,
+/// so that returned position is on a UTF-8 character boundary.
+#[inline]
+pub unsafe fn swar_search<'a, const N: usize>(
+    source: &Source<'a>,
+    mut pos: SourcePosition<'a>,
+    bytes: [u8; N],
+) -> Result<SourcePosition<'a>, SourcePosition<'a>> {
+    while pos.can_read_word_from(source) {
+        // SAFETY: Have checked there are at least `SWAR_WORD_SIZE` bytes remaining
+        let word = unsafe { pos.read_word() };
+
+        // Set high bit of each byte in `word` which equals one of `bytes`.
+        // `x.wrapping_sub(LOW_BITS) & !x & HIGH_BITS` sets high bit of each byte of `x` which is 0.
+        // A borrow can also set high bit of bytes after a zero byte, but never before it,
+        // so the lowest set bit always marks the first match.
+        let mut matches = 0;
+        for byte in bytes {
+            let x = word ^ (LOW_BITS * u64::from(byte));
+            matches |= x.wrapping_sub(LOW_BITS) & !x & HIGH_BITS;
+        }
+
+        if matches != 0 {
+            // Word is little-endian, so lowest set bit is in first matching byte
+            let index = matches.trailing_zeros() as usize / 8;
+            // SAFETY: `index < SWAR_WORD_SIZE`, so within bounds
+            return Ok(unsafe { pos.add(index) });
+        }
+
+        // SAFETY: Have checked there are at least `SWAR_WORD_SIZE` bytes remaining
+        pos = unsafe { pos.add(SWAR_WORD_SIZE) };
+    }
+
+    // Skipping whole words may have left `pos` in the middle of a multi-byte character.
+    // Rewind to start of it. Cannot go back before `pos` passed in, as that was a character boundary.
+    // SAFETY: `pos` is not at end of source when reading, and only rewinds over continuation bytes.
+    unsafe {
+        while pos.is_not_end_of(source) && is_utf8_cont_byte(pos.read()) {
+            pos = pos.sub(1);
+        }
+    }
+    Err(pos)
+}
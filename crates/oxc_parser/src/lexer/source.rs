@@ -4,7 +4,7 @@ use oxc_data_structures::assert_unchecked;
 
 use crate::{MAX_LEN, UniquePromise};
 
-use super::search::SEARCH_BATCH_SIZE;
+use super::search::{SEARCH_BATCH_SIZE, SWAR_WORD_SIZE};
 
 /// `Source` holds the source text for the lexer, and provides APIs to read it.
 ///
@@ -722,6 +722,14 @@ impl<'a> SourcePosition<'a> {
         self.ptr as usize <= source.end_for_batch_search_addr
     }
 
+    /// Check if this [`SourcePosition`] is valid for reading `Lexer::search::SWAR_WORD_SIZE` bytes
+    /// from `source`.
+    /// i.e. is position at least `Lexer::search::SWAR_WORD_SIZE` bytes from the end of `source`?
+    #[inline]
+    pub(super) fn can_read_word_from(&self, source: &Source<'a>) -> bool {
+        source.end as usize - self.ptr as usize >= SWAR_WORD_SIZE
+    }
+
     /// Read byte from this `SourcePosition`.
     ///
     /// # SAFETY
@@ -772,6 +780,25 @@ impl<'a> SourcePosition<'a> {
         }
     }
 
+    /// Read `Lexer::search::SWAR_WORD_SIZE` bytes from this `SourcePosition` as a little-endian `u64`.
+    ///
+    /// Little-endian on all platforms, so 1st byte is always in the lowest bits.
+    ///
+    /// # SAFETY
+    /// Caller must ensure `SourcePosition` is no later than `Lexer::search::SWAR_WORD_SIZE` bytes
+    /// before end of source text. i.e. `can_read_word_from` returns `true`.
+    #[inline]
+    pub(super) unsafe fn read_word(self) -> u64 {
+        debug_assert!(!self.ptr.is_null());
+
+        // SAFETY:
+        // Caller guarantees there are at least `SWAR_WORD_SIZE` bytes after this position.
+        // `Source` is created from a valid `&str`, so points to allocated, initialized memory.
+        // `read_unaligned` has no alignment requirements.
+        let word = unsafe { self.ptr.cast::<u64>().read_unaligned() };
+        u64::from_le(word)
+    }
+
     /// Get slice of bytes starting from this [`SourcePosition`], with length `len`.
     ///
     /// # SAFETY
@@ -828,7 +855,7 @@ impl PartialOrd for SourcePosition<'_> {
 
 /// Return if byte is a UTF-8 continuation byte.
 #[inline]
-const fn is_utf8_cont_byte(byte: u8) -> bool {
+pub(super) const fn is_utf8_cont_byte(byte: u8) -> bool {
     // 0x80 - 0xBF are continuation bytes i.e. not 1st byte of a UTF-8 character sequence
     byte >= 0x80 && byte < 0xC0
 }
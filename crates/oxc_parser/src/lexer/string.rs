@@ -6,7 +6,7 @@ use crate::diagnostics;
 
 use super::{
     Kind, Lexer, LexerContext, Span, Token, cold_branch,
-    search::{SafeByteMatchTable, byte_search, safe_byte_match_table},
+    search::{SafeByteMatchTable, byte_search, safe_byte_match_table, swar_search},
 };
 
 /// Convert `char` to UTF-8 bytes array.
@@ -61,14 +61,27 @@ macro_rules! handle_string_literal {
         // SAFETY: Caller guarantees next byte is ASCII, so safe to advance past it.
         let after_opening_quote = $lexer.source.position().add(1);
 
-        // Consume bytes which are part of string
-        let next_byte = byte_search! {
-            lexer: $lexer,
-            table: $table,
-            start: after_opening_quote,
-            handle_eof: {
-                $lexer.error(diagnostics::unterminated_string($lexer.unterminated_range()));
-                return Kind::Undetermined;
+        // Consume bytes which are part of string, 8 bytes at a time.
+        // Finish with `byte_search!` if close to end of source.
+        // SAFETY: These bytes are all ASCII.
+        let next_byte = match swar_search(
+            &$lexer.source,
+            after_opening_quote,
+            [$delimiter, b'\\', b'\r', b'\n'],
+        ) {
+            Ok(pos) => {
+                $lexer.source.set_position(pos);
+                // SAFETY: `pos` is on one of the bytes searched for, so not at end of source
+                pos.read()
+            }
+            Err(search_start) => byte_search! {
+                lexer: $lexer,
+                table: $table,
+                start: search_start,
+                handle_eof: {
+                    $lexer.error(diagnostics::unterminated_string($lexer.unterminated_range()));
+                    return Kind::Undetermined;
+                },
             },
         };
 
@@ -129,6 +142,16 @@ macro_rules! handle_string_literal_escape {
 
             // Consume bytes until reach end of string, line break, or another escape
             let mut chunk_start = $lexer.source.position();
+
+            // Skip to first byte which `$table` may match, 8 bytes at a time.
+            // SAFETY: These bytes are all ASCII, or the first byte of a multi-byte character.
+            let (Ok(search_start) | Err(search_start)) = swar_search(
+                &$lexer.source,
+                chunk_start,
+                [$delimiter, b'\\', b'\r', b'\n', LOSSY_REPLACEMENT_CHAR_FIRST_BYTE],
+            );
+            $lexer.source.set_position(search_start);
+
             while let Some(b) = $lexer.peek_byte() {
                 match b {
                     b if !$table.matches(b) => {
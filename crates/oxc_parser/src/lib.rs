@@ -79,6 +79,10 @@ mod ts;
 
 mod diagnostics;
 
+pub mod cst;
+pub mod json;
+pub mod tokenizer;
+
 // Expose lexer only in benchmarks
 #[cfg(not(feature = "benchmarking"))]
 mod lexer;
@@ -88,8 +92,9 @@ pub mod lexer;
 
 use oxc_allocator::{Allocator, Box as ArenaBox, Dummy};
 use oxc_ast::{
-    AstBuilder,
+    AstBuilder, TripleSlashDirective,
     ast::{Expression, Program},
+    triple_slash_directives,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{ModuleKind, SourceType, Span};
@@ -101,6 +106,7 @@ use crate::{
     lexer::{Lexer, Token},
     module_record::ModuleRecordBuilder,
     state::ParserState,
+    tokenizer::{Tokenizer, TokenizerReturn},
 };
 
 /// Maximum length of source which can be parsed (in bytes).
@@ -168,6 +174,33 @@ pub struct ParserReturn<'a> {
     /// Irregular whitespaces for `Oxlint`
     pub irregular_whitespaces: Box<[Span]>,
 
+    /// Spans of the statements skipped to recover from syntax errors, sorted.
+    ///
+    /// Always empty unless [`ParseOptions::recover_from_errors`] is enabled.
+    pub skipped_spans: Box<[Span]>,
+
+    /// Spans of the tokens of the source text, sorted.
+    ///
+    /// Always empty unless [`ParseOptions::collect_tokens`] is enabled, and when the parser panicked.
+    /// See [`cst::ConcreteSyntax`] for the trivia between them.
+    pub tokens: Box<[Span]>,
+
+    /// Triple-slash directives at the top of the file, e.g. `/// <reference types="node" />`.
+    ///
+    /// See [`triple_slash_directives`](oxc_ast::triple_slash_directives).
+    pub triple_slash_directives: Box<[TripleSlashDirective<'a>]>,
+
+    /// Whether the source text begins with a byte order mark (U+FEFF).
+    ///
+    /// The byte order mark is skipped, and may be followed by a
+    /// [hashbang](oxc_ast::ast::Program::hashbang).
+    pub has_bom: bool,
+
+    /// Offset of the start of the code, after the byte order mark and the hashbang line.
+    ///
+    /// See [`Program::code_start`].
+    pub code_start: u32,
+
     /// Whether the parser panicked and terminated early.
     ///
     /// This will be `false` if parsing was successful, or if parsing was able to recover from a
@@ -189,7 +222,13 @@ pub struct ParserReturn<'a> {
 pub struct ParseOptions {
     /// Whether to parse regular expressions or not.
     ///
+    /// If `true`, the pattern of each regular expression literal is parsed and attached to it
+    /// as [`RegExpPattern::pattern`], so that it doesn't need to be parsed again by each
+    /// consumer. Invalid patterns are reported as errors.
+    ///
     /// Default: `false`
+    ///
+    /// [`RegExpPattern::pattern`]: oxc_ast::ast::RegExpPattern::pattern
     #[cfg(feature = "regular_expression")]
     pub parse_regular_expression: bool,
 
@@ -207,6 +246,7 @@ pub struct ParseOptions {
     /// If this option is `true`, parenthesized expressions are represented by
     /// (non-standard) [`ParenthesizedExpression`] and [`TSParenthesizedType`] nodes
     /// that have a single `expression` property containing the expression inside parentheses.
+    /// `oxc_codegen` prints them as written with its `preserve_parens` option.
     ///
     /// Default: `true`
     ///
@@ -221,6 +261,80 @@ pub struct ParseOptions {
     ///
     /// [`V8IntrinsicExpression`]: oxc_ast::ast::V8IntrinsicExpression
     pub allow_v8_intrinsics: bool,
+
+    /// Parse the experimental [Hack pipeline operator](https://github.com/tc39/proposal-pipeline-operator)
+    /// `a |> f(%)` into [`PipelineExpression`] and [`TopicReference`] nodes.
+    ///
+    /// When [`ParseOptions::allow_v8_intrinsics`] is also enabled, `%` followed by an identifier
+    /// is parsed as a V8 runtime call.
+    ///
+    /// Default: `false`
+    ///
+    /// [`PipelineExpression`]: oxc_ast::ast::PipelineExpression
+    /// [`TopicReference`]: oxc_ast::ast::TopicReference
+    pub allow_pipeline_operator: bool,
+
+    /// Parse the experimental [`do` expressions](https://github.com/tc39/proposal-do-expressions)
+    /// `let a = do { if (b) { 1 } else { 2 } }` into [`DoExpression`] nodes.
+    ///
+    /// Default: `false`
+    ///
+    /// [`DoExpression`]: oxc_ast::ast::DoExpression
+    pub allow_do_expressions: bool,
+
+    /// Parse [Flow](https://flow.org) type annotations in JavaScript files.
+    ///
+    /// Flow syntax is parsed into TypeScript nodes, and the source type of the [`Program`] is
+    /// changed to TypeScript (keeping JSX), so that the TypeScript transform strips the types.
+    /// Besides the syntax shared with TypeScript, common Flow syntax is supported:
+    /// * maybe types `?T`, parsed as `T | null | undefined`
+    /// * exact object types `{| a: T |}`, parsed as object types
+    /// * type parameter bounds `<T: U>`, parsed as `<T extends U>`
+    /// * type casts `(a: T)`, parsed as `a as T`
+    /// * predicates `%checks`, which are skipped
+    /// * `import typeof`, parsed as `import type`
+    ///
+    /// Default: `false`
+    pub allow_flow_syntax: bool,
+
+    /// Skip the bodies of functions, methods and arrow functions instead of parsing them.
+    ///
+    /// A skipped body is a [`FunctionBody`] without statements, which keeps the span of the body.
+    /// This is meant for tools which only need the shape of a module, e.g. its imports, exports
+    /// and declarations, and is much faster on large files.
+    ///
+    /// Syntax errors inside the skipped bodies are not reported, and the return types of
+    /// functions can no longer be inferred from their bodies. Bodies which contain `import(` or
+    /// `import.` are parsed, so dynamic imports and `import.meta` are still recorded in the
+    /// module record.
+    ///
+    /// Default: `false`
+    ///
+    /// [`FunctionBody`]: oxc_ast::ast::FunctionBody
+    pub skip_function_bodies: bool,
+
+    /// Recover from syntax errors which would otherwise abort parsing.
+    ///
+    /// When a statement of the program or of a function body cannot be parsed, its error is
+    /// reported, and the parser skips to the next statement instead of aborting. The skipped
+    /// statements are left out of the AST, and their spans are reported in
+    /// [`ParserReturn::skipped_spans`].
+    ///
+    /// This is meant for tools analyzing files which are being edited, e.g. a language server.
+    /// The AST of the valid statements is kept, but it does not contain the whole program.
+    ///
+    /// Default: `false`
+    pub recover_from_errors: bool,
+
+    /// Collect the spans of all tokens into [`ParserReturn::tokens`].
+    ///
+    /// Together with the comments of the [`Program`], the tokens describe every byte of the source
+    /// text, so [`cst::ConcreteSyntax`] can attach whitespace and comments to tokens and nodes, and
+    /// reprint unchanged code byte-for-byte. Parentheses are tokens too; keep
+    /// [`ParseOptions::preserve_parens`] enabled to also have them as nodes.
+    ///
+    /// Default: `false`
+    pub collect_tokens: bool,
 }
 
 impl Default for ParseOptions {
@@ -231,6 +345,12 @@ impl Default for ParseOptions {
             allow_return_outside_function: false,
             preserve_parens: true,
             allow_v8_intrinsics: false,
+            allow_pipeline_operator: false,
+            allow_do_expressions: false,
+            allow_flow_syntax: false,
+            skip_function_bodies: false,
+            recover_from_errors: false,
+            collect_tokens: false,
         }
     }
 }
@@ -275,7 +395,8 @@ mod parser_parse {
     ///
     /// `ParserImpl::new`, `Lexer::new` and `lexer::Source::new` all require a `UniquePromise`
     /// to be provided to them. `UniquePromise::new` is not visible outside this module, so only
-    /// `Parser::parse` can create one, and it only calls `ParserImpl::new` once.
+    /// `Parser::parse` and `Tokenizer::tokenize` can create one, and they only call
+    /// `ParserImpl::new` or `Lexer::new` once.
     /// This enforces the invariant throughout the entire parser.
     ///
     /// `UniquePromise` is a zero-sized type and has no runtime cost. It's purely for the type-checker.
@@ -349,6 +470,15 @@ mod parser_parse {
             parser.parse_expression()
         }
     }
+
+    impl Tokenizer<'_> {
+        /// Read all tokens of the source text.
+        pub fn tokenize(self) -> TokenizerReturn {
+            let unique = UniquePromise::new();
+            let lexer = Lexer::new(self.allocator, self.source_text, self.source_type, unique);
+            tokenizer::tokenize(lexer, self.source_text)
+        }
+    }
 }
 use parser_parse::UniquePromise;
 
@@ -371,6 +501,12 @@ struct ParserImpl<'a> {
 
     fatal_error: Option<FatalError>,
 
+    /// Spans of the statements skipped by error recovery
+    skipped_spans: Vec<Span>,
+
+    /// Spans of the consumed tokens, if `collect_tokens` is enabled
+    tokens: Vec<Span>,
+
     /// The current parsing token
     token: Token,
 
@@ -406,6 +542,11 @@ impl<'a> ParserImpl<'a> {
         options: ParseOptions,
         unique: UniquePromise,
     ) -> Self {
+        let source_type = if options.allow_flow_syntax && source_type.is_javascript() {
+            source_type.with_typescript(true)
+        } else {
+            source_type
+        };
         Self {
             options,
             lexer: Lexer::new(allocator, source_text, source_type, unique),
@@ -413,6 +554,8 @@ impl<'a> ParserImpl<'a> {
             source_text,
             errors: vec![],
             fatal_error: None,
+            skipped_spans: vec![],
+            tokens: vec![],
             token: Token::default(),
             prev_token_end: 0,
             state: ParserState::new(),
@@ -434,6 +577,7 @@ impl<'a> ParserImpl<'a> {
 
         if let Some(fatal_error) = self.fatal_error.take() {
             panicked = true;
+            self.tokens.clear();
             self.errors.truncate(fatal_error.errors_len);
             if !self.lexer.errors.is_empty() && self.cur_kind().is_eof() {
                 // Noop
@@ -486,11 +630,20 @@ impl<'a> ParserImpl<'a> {
             };
         }
 
+        let triple_slash_directives = triple_slash_directives(&program).into_boxed_slice();
+        let has_bom = program.has_bom();
+        let code_start = program.code_start();
+
         ParserReturn {
             program,
             module_record,
             errors,
             irregular_whitespaces,
+            skipped_spans: self.skipped_spans.into_boxed_slice(),
+            tokens: self.tokens.into_boxed_slice(),
+            triple_slash_directives,
+            has_bom,
+            code_start,
             panicked,
             is_flow_language,
         }
@@ -590,8 +743,11 @@ impl<'a> ParserImpl<'a> {
 mod test {
     use std::path::Path;
 
-    use oxc_ast::ast::{CommentKind, Expression, Statement};
-    use oxc_span::GetSpan;
+    use oxc_ast::{
+        TripleSlashDirectiveKind,
+        ast::{CommentKind, Declaration, Expression, Program, RegExpLiteral, Statement, TSType},
+    };
+    use oxc_span::{Atom, GetSpan};
 
     use super::*;
 
@@ -711,6 +867,331 @@ mod test {
         }
     }
 
+    #[test]
+    fn pipeline_operator() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let opts = ParseOptions { allow_pipeline_operator: true, ..ParseOptions::default() };
+        {
+            let source = "a |> f(%) |> % + 1 ? b : c";
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty());
+
+            let Some(Statement::ExpressionStatement(expr_stmt)) = ret.program.body.first() else {
+                panic!("Expected ExpressionStatement");
+            };
+            // `|>` is looser than `?:`
+            let Expression::PipelineExpression(outer) = &expr_stmt.expression else {
+                panic!("Expected PipelineExpression");
+            };
+            let Expression::ConditionalExpression(body) = &outer.body else {
+                panic!("Expected ConditionalExpression");
+            };
+            assert_eq!(body.span().source_text(source), "% + 1 ? b : c");
+            let Expression::PipelineExpression(inner) = &outer.head else {
+                panic!("Expected PipelineExpression");
+            };
+            assert_eq!(inner.span().source_text(source), "a |> f(%)");
+        }
+        {
+            let source = "x = a |> f(% % 2)";
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty());
+        }
+        for (source, error) in [
+            ("a |> f(b)", "Pipeline body must contain a topic reference `%`"),
+            ("f(%)", "Topic reference `%` is only allowed in the body of a pipeline expression"),
+        ] {
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert_eq!(ret.errors.len(), 1, "{source}");
+            assert_eq!(ret.errors[0].to_string(), error);
+        }
+        {
+            let source = "a |> f(%)";
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert_eq!(ret.errors[0].to_string(), "The pipeline operator `|>` is experimental");
+        }
+        {
+            // `%` followed by an identifier is a V8 runtime call when both options are enabled.
+            let opts = ParseOptions { allow_v8_intrinsics: true, ..opts };
+            let source = "a |> %DebugPrint(%)";
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty());
+        }
+    }
+
+    #[test]
+    fn do_expressions() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let opts = ParseOptions { allow_do_expressions: true, ..ParseOptions::default() };
+        {
+            let source = "let a = do { if (b) { 1 } else { 2 } };";
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty());
+
+            let Some(Statement::VariableDeclaration(decl)) = ret.program.body.first() else {
+                panic!("Expected VariableDeclaration");
+            };
+            let Some(Expression::DoExpression(expr)) = &decl.declarations[0].init else {
+                panic!("Expected DoExpression");
+            };
+            assert_eq!(expr.span().source_text(source), "do { if (b) { 1 } else { 2 } }");
+            assert_eq!(expr.body.body.len(), 1);
+        }
+        {
+            // `do` at the start of a statement is still a `do`-`while` statement.
+            let source = "do { a } while (b)";
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty());
+            assert!(matches!(ret.program.body[0], Statement::DoWhileStatement(_)));
+        }
+        {
+            let source = "let a = do { 1 };";
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert_eq!(ret.errors.len(), 1);
+        }
+    }
+
+    #[test]
+    fn flow_syntax() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::jsx();
+        let opts = ParseOptions { allow_flow_syntax: true, ..ParseOptions::default() };
+        let source = r"// @flow
+import typeof React from 'react';
+import type { Node } from 'react';
+type Props = {| name: ?string, items: Array<number> | null |};
+type Empty = {||};
+function isString(x: mixed): boolean %checks {
+  return typeof x === 'string';
+}
+function first<T: Object>(items: ?T[]): ?T {
+  return (items: any)[0];
+}
+export default function App(props: Props): Node {
+  return <div>{props.name}</div>;
+}
+";
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        assert!(ret.program.source_type.is_typescript());
+        assert!(ret.program.source_type.is_jsx());
+
+        let Statement::ImportDeclaration(import) = &ret.program.body[0] else {
+            panic!("Expected ImportDeclaration");
+        };
+        assert!(import.import_kind.is_type());
+        let Statement::TSTypeAliasDeclaration(alias) = &ret.program.body[2] else {
+            panic!("Expected TSTypeAliasDeclaration");
+        };
+        let TSType::TSTypeLiteral(literal) = &alias.type_annotation else {
+            panic!("Expected TSTypeLiteral");
+        };
+        assert_eq!(literal.members.len(), 2);
+
+        // Without the option, the `@flow` pragma is reported.
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors[0].to_string(), "Flow is not supported");
+    }
+
+    #[test]
+    fn skip_function_bodies() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::jsx();
+        let opts = ParseOptions { skip_function_bodies: true, ..ParseOptions::default() };
+        let sources = [
+            "function f() { if (a) /}/.test(b); return a / 2 / c; }",
+            "function f() { return `${ { a: '}' }.a }${`}`}`; }",
+            "const f = async () => { await x; }, g = (a) => a;",
+            "class A { m() { return (a) / {}.b; } get g() {} }",
+            // The apostrophe is lexed as an unterminated string, so the body is parsed instead.
+            "function f() { return <p>Don't</p>; }",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty(), "{source}: {:?}", ret.errors);
+        }
+
+        let source = "import a from 'a';\nexport function f() { return a; }\nf();";
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        assert_eq!(ret.program.body.len(), 3);
+        let Statement::ExportNamedDeclaration(decl) = &ret.program.body[1] else {
+            panic!("Expected ExportNamedDeclaration");
+        };
+        let Some(Declaration::FunctionDeclaration(func)) = &decl.declaration else {
+            panic!("Expected FunctionDeclaration");
+        };
+        let body = func.body.as_ref().unwrap();
+        assert!(body.statements.is_empty());
+        assert_eq!(body.span.source_text(source), "{ return a; }");
+
+        // Bodies with dynamic imports are parsed, so the imports are recorded.
+        let source = "function f() { return import('./x') }\nconst g = () => { h(); };";
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let dynamic_imports = &ret.module_record.dynamic_imports;
+        assert_eq!(dynamic_imports.len(), 1);
+        assert_eq!(dynamic_imports[0].module_request.source_text(source), "'./x'");
+        let Statement::FunctionDeclaration(func) = &ret.program.body[0] else {
+            panic!("Expected FunctionDeclaration");
+        };
+        assert_eq!(func.body.as_ref().unwrap().statements.len(), 1);
+
+        let source = "function f() { return import.meta.url; }";
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        assert_eq!(ret.module_record.import_metas.len(), 1);
+    }
+
+    #[cfg(feature = "regular_expression")]
+    #[test]
+    fn regular_expression_patterns() {
+        fn regex<'b, 'a>(ret: &'b ParserReturn<'a>) -> &'b RegExpLiteral<'a> {
+            let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else {
+                panic!("Expected ExpressionStatement");
+            };
+            let Expression::RegExpLiteral(regex) = &stmt.expression else {
+                panic!("Expected RegExpLiteral");
+            };
+            regex
+        }
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+
+        let opts = ParseOptions { parse_regular_expression: true, ..ParseOptions::default() };
+        let ret = Parser::new(&allocator, "/a|b/u", source_type).with_options(opts).parse();
+        assert!(ret.errors.is_empty());
+        let literal = regex(&ret);
+        let pattern = literal.regex.pattern.pattern.as_ref().unwrap();
+        assert_eq!(pattern.body.body.len(), 2);
+        assert!(std::ptr::eq(literal.pattern(&allocator).unwrap(), pattern.as_ref()));
+
+        let ret = Parser::new(&allocator, "/(/u", source_type).with_options(opts).parse();
+        assert_eq!(ret.errors.len(), 1);
+        assert!(regex(&ret).regex.pattern.pattern.is_none());
+
+        // Without the option, the pattern is parsed on demand.
+        let ret = Parser::new(&allocator, "/a|b/u", source_type).parse();
+        let literal = regex(&ret);
+        assert!(literal.regex.pattern.pattern.is_none());
+        assert_eq!(literal.pattern(&allocator).unwrap().body.body.len(), 2);
+        let ret = Parser::new(&allocator, "/(/u", source_type).parse();
+        assert!(ret.errors.is_empty());
+        assert!(regex(&ret).pattern(&allocator).is_err());
+    }
+
+    #[test]
+    fn tokenize() {
+        use crate::tokenizer::TokenKind;
+
+        let allocator = Allocator::default();
+        let source =
+            "#!/usr/bin/env node\nif (a) /}/.test(`${{ b: 1 }.b}`);\nx = a / 2 /* c */ ?? null;";
+        let ret = Tokenizer::new(&allocator, source, SourceType::mjs()).tokenize();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        assert_eq!(ret.comments.len(), 1);
+        let tokens = ret
+            .tokens
+            .iter()
+            .map(|token| (token.kind, token.span.source_text(source)))
+            .collect::<std::vec::Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                (TokenKind::Hashbang, "#!/usr/bin/env node"),
+                (TokenKind::Keyword, "if"),
+                (TokenKind::Punctuator, "("),
+                (TokenKind::Identifier, "a"),
+                (TokenKind::Punctuator, ")"),
+                (TokenKind::RegExp, "/}/"),
+                (TokenKind::Punctuator, "."),
+                (TokenKind::Identifier, "test"),
+                (TokenKind::Punctuator, "("),
+                (TokenKind::Template, "`${"),
+                (TokenKind::Punctuator, "{"),
+                (TokenKind::Identifier, "b"),
+                (TokenKind::Punctuator, ":"),
+                (TokenKind::Numeric, "1"),
+                (TokenKind::Punctuator, "}"),
+                (TokenKind::Punctuator, "."),
+                (TokenKind::Identifier, "b"),
+                (TokenKind::Template, "}`"),
+                (TokenKind::Punctuator, ")"),
+                (TokenKind::Punctuator, ";"),
+                (TokenKind::Identifier, "x"),
+                (TokenKind::Punctuator, "="),
+                (TokenKind::Identifier, "a"),
+                (TokenKind::Punctuator, "/"),
+                (TokenKind::Numeric, "2"),
+                (TokenKind::Punctuator, "??"),
+                (TokenKind::Null, "null"),
+                (TokenKind::Punctuator, ";"),
+            ]
+        );
+        assert!(ret.tokens[1].is_on_new_line);
+        assert!(!ret.tokens[2].is_on_new_line);
+
+        let ret = Tokenizer::new(&allocator, "'a", SourceType::mjs()).tokenize();
+        assert_eq!(ret.errors.len(), 1);
+    }
+
+    #[test]
+    fn estree_loc() {
+        let allocator = Allocator::default();
+        let source = "let é =\n  1;";
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+        let json = ret.program.to_estree_js_json_with_loc(true);
+        let literal = r#""raw":"1","start":11,"end":12,"loc":{"start":{"line":2,"column":2},"end":{"line":2,"column":3}},"range":[11,12]"#;
+        assert!(json.contains(literal), "{json}");
+        let identifier = r#""loc":{"start":{"line":1,"column":4},"end":{"line":1,"column":5}}"#;
+        assert!(json.contains(identifier), "{json}");
+    }
+
+    #[test]
+    fn estree_round_trip() {
+        let allocator = Allocator::default();
+        let source = r#"#!/usr/bin/env node
+"use strict";
+import a, { b as c, "d" as e } from "f" with { type: "json" };
+import * as g from "h";
+export { a as default, c };
+export * as i from "j";
+export const [k, , ...l] = [1, 0x2, 3n, 0b1010n, 1.5e3];
+let { m, n: { o = 1 } = {}, ...p } = { m, ...q, [r]: s, get t() { return 1; } };
+for (const u of v) for (w in x) for (let y = 0; y < 10; y++) continue;
+label: while (true) break label;
+try { throw new Error(`a${b}c`); } catch ({ message }) {} finally {}
+switch (a) { case 1: debugger; default: }
+async function* f(a, b = 1, ...c) { yield* await a?.b?.(c)[d]; }
+class C extends D { static #a = 1; accessor b; static { this.#a; } constructor() { super(); } get c() { return #a in this; } }
+[a, { b: c = 1 }] = d;
+a ||= b ?? c, a **= -!~b;
+x = (a, b) => ({ a }), y = async () => { "use strict"; }, z = tag`a${1}`;
+import.meta.url, import("a", { with: {} }), /a+b/giu.test(typeof void delete a.b);
+"#;
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+        assert!(ret.errors.is_empty());
+        let json = ret.program.to_estree_js_json(true);
+        let program = Program::from_estree_json(&allocator, source, &json).unwrap();
+        assert_eq!(program.to_estree_js_json(true), json);
+    }
+
+    #[test]
+    fn content_fingerprint() {
+        let allocator = Allocator::default();
+        let fingerprints = |source| {
+            let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+            ret.program.body.iter().map(oxc_span::content_fingerprint).collect::<Vec<_>>()
+        };
+        let before = fingerprints("foo(1);\nlet a = b;");
+        // Moved to other lines and reformatted
+        let after = fingerprints("\n\nlet   a =\n  b;\nfoo(1.0);\nfoo(2);");
+        assert_eq!(before, [after[1], after[0]]);
+        assert_ne!(after[1], after[2]);
+    }
+
     #[test]
     fn comments() {
         let allocator = Allocator::default();
@@ -731,6 +1212,84 @@ mod test {
         }
     }
 
+    #[test]
+    fn long_strings_and_comments() {
+        // Lengths either side of the 8-byte words and 32-byte batches the lexer searches in
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        for filler in ["a", "é", "…", "\u{FFFD}"] {
+            for len in 0..40 {
+                let text = filler.repeat(len);
+
+                let string_sources = [
+                    (format!("'{text}'"), text.clone()),
+                    (format!("\"{text}\""), text.clone()),
+                    (format!("'{text}\\'{text}\\n{text}'"), format!("{text}'{text}\n{text}")),
+                ];
+                for (source, value) in string_sources {
+                    let ret = Parser::new(&allocator, &source, source_type).parse_expression();
+                    let Ok(Expression::StringLiteral(lit)) = ret else {
+                        panic!("{source}: {ret:?}");
+                    };
+                    assert_eq!(lit.value, value, "{source}");
+                }
+
+                let comment_sources = [
+                    format!("// {text}\nx"),
+                    format!("// {text}\r\nx"),
+                    format!("// {text}\u{2028}x"),
+                    format!("x // {text}"),
+                ];
+                for source in comment_sources {
+                    let ret = Parser::new(&allocator, &source, source_type).parse();
+                    assert!(ret.errors.is_empty(), "{source}");
+                    let comments = &ret.program.comments;
+                    assert_eq!(comments.len(), 1, "{source}");
+                    let comment = comments[0].span.source_text(&source);
+                    assert_eq!(comment.strip_prefix("// "), Some(text.as_str()), "{source}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn triple_slash_directives() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::ts();
+        let source = r#"#!/usr/bin/env node
+/// <reference path="./a.d.ts" />
+///<reference types='node' resolution-mode="import"/>
+/// <reference lib="es2022" />
+// <reference types="not-a-directive" />
+/// <reference no-default-lib="true" />
+/// <reference types="unterminated />
+/// <referencetypes="b" />
+"use strict";
+/// <reference types="after-directive" />
+"#;
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let directives = ret
+            .triple_slash_directives
+            .iter()
+            .map(|directive| {
+                assert_eq!(directive.value_span.source_text(source), directive.value);
+                (directive.kind, directive.value.as_str(), directive.resolution_mode)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            directives,
+            [
+                (TripleSlashDirectiveKind::Path, "./a.d.ts", None),
+                (TripleSlashDirectiveKind::Types, "node", Some(Atom::from("import"))),
+                (TripleSlashDirectiveKind::Lib, "es2022", None),
+            ]
+        );
+        assert_eq!(
+            ret.triple_slash_directives[0].span.source_text(source),
+            r#"/// <reference path="./a.d.ts" />"#
+        );
+    }
+
     #[test]
     fn hashbang() {
         let allocator = Allocator::default();
@@ -738,6 +1297,108 @@ mod test {
         let source = "#!/usr/bin/node\n;";
         let ret = Parser::new(&allocator, source, source_type).parse();
         assert_eq!(ret.program.hashbang.unwrap().value.as_str(), "/usr/bin/node");
+        assert!(!ret.has_bom);
+        assert_eq!(ret.code_start, 16);
+    }
+
+    #[test]
+    fn bom() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+
+        let source = "\u{feff}#!/usr/bin/node\r\nfoo;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+        assert!(ret.has_bom);
+        let hashbang = ret.program.hashbang.unwrap();
+        assert_eq!(hashbang.value.as_str(), "/usr/bin/node");
+        assert_eq!(hashbang.span.source_text(source), "#!/usr/bin/node");
+        assert_eq!(&source[ret.code_start as usize..], "foo;");
+        assert!(ret.irregular_whitespaces.is_empty());
+
+        let source = "\u{feff}foo;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.has_bom);
+        assert!(ret.program.hashbang.is_none());
+        assert_eq!(&source[ret.code_start as usize..], "foo;");
+        assert!(ret.irregular_whitespaces.is_empty());
+
+        // Only a byte order mark at the start of the file is skipped.
+        let ret = Parser::new(&allocator, "foo;\u{feff}", source_type).parse();
+        assert!(!ret.has_bom);
+        assert_eq!(ret.code_start, 0);
+        assert_eq!(ret.irregular_whitespaces.len(), 1);
+    }
+
+    #[test]
+    fn recover_from_errors() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let options = ParseOptions { recover_from_errors: true, ..ParseOptions::default() };
+        let parse =
+            |source| Parser::new(&allocator, source, source_type).with_options(options).parse();
+        let skipped = |source: &str, ret: &ParserReturn| {
+            ret.skipped_spans
+                .iter()
+                .map(|span| span.source_text(source).to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let source = "const a = 1;\nconst b = ;\nconst c = 3;\n";
+        let ret = parse(source);
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.program.body.len(), 2);
+        assert_eq!(skipped(source, &ret), ["const b = ;"]);
+        // without recovery
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.panicked);
+        assert!(ret.skipped_spans.is_empty());
+
+        // in a function body, up to the closing `}`
+        let source = "function f() {\n  let x = (1 +;\n  return 1;\n}\nf(\n";
+        let ret = parse(source);
+        assert_eq!(ret.errors.len(), 2);
+        assert_eq!(ret.program.body.len(), 1);
+        let Statement::FunctionDeclaration(function) = &ret.program.body[0] else {
+            panic!("expected a function declaration");
+        };
+        assert_eq!(function.body.as_ref().unwrap().statements.len(), 1);
+        assert_eq!(skipped(source, &ret), ["let x = (1 +;", "f("]);
+
+        // lexer errors, and a `}` without a block
+        let source = "a();\nconst s = 'abc\nb();\n}\nc();";
+        let ret = parse(source);
+        assert_eq!(ret.errors.len(), 2);
+        assert_eq!(ret.program.body.len(), 2);
+        assert_eq!(skipped(source, &ret), ["const s = 'abc\nb();", "}"]);
+
+        // the imports and exports of a broken statement are not in the module record
+        let source = "import a from 'a';\nexport const b = 1;\nexport { c as b, a } from 'c' +;\nexport const d = ;\nexport { a };\n";
+        let ret = parse(source);
+        assert_eq!(ret.errors.len(), 2);
+        assert_eq!(
+            skipped(source, &ret),
+            ["export { c as b, a } from 'c' +;", "export const d = ;"]
+        );
+        let module_record = &ret.module_record;
+        assert_eq!(
+            module_record.requested_modules.keys().map(Atom::as_str).collect::<Vec<_>>(),
+            ["a"]
+        );
+        assert_eq!(module_record.local_export_entries.len(), 1);
+        assert_eq!(module_record.indirect_export_entries.len(), 1);
+        let mut exported =
+            module_record.exported_bindings.keys().map(Atom::as_str).collect::<Vec<_>>();
+        exported.sort_unstable();
+        assert_eq!(exported, ["a", "b"]);
+        assert_eq!(module_record.exported_bindings["b"].start, 32);
+
+        // a block closed on the line of the error
+        let source = "function f() { let x = ( }\ng();";
+        let ret = parse(source);
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.program.body.len(), 2);
     }
 
     #[test]
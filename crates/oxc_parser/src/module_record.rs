@@ -12,6 +12,23 @@ pub struct ModuleRecordBuilder<'a> {
     module_record: ModuleRecord<'a>,
     export_entries: Vec<'a, ExportEntry<'a>>,
     exported_bindings_duplicated: Vec<'a, NameSpan<'a>>,
+    /// Number of [`RequestedModule`]s in `module_record.requested_modules`.
+    requested_modules_len: usize,
+}
+
+/// State of a [`ModuleRecordBuilder`], to discard the entries added after it when the parser rewinds.
+#[derive(Debug, Clone, Copy)]
+pub struct ModuleRecordCheckpoint {
+    /// Start of the source text parsed after the checkpoint.
+    position: u32,
+    has_module_syntax: bool,
+    requested_modules_len: usize,
+    import_entries_len: usize,
+    export_entries_len: usize,
+    exported_bindings_len: usize,
+    exported_bindings_duplicated_len: usize,
+    dynamic_imports_len: usize,
+    import_metas_len: usize,
 }
 
 impl<'a> ModuleRecordBuilder<'a> {
@@ -21,6 +38,67 @@ impl<'a> ModuleRecordBuilder<'a> {
             module_record: ModuleRecord::new(allocator),
             export_entries: Vec::new_in(allocator),
             exported_bindings_duplicated: Vec::new_in(allocator),
+            requested_modules_len: 0,
+        }
+    }
+
+    pub fn checkpoint(&self, position: u32) -> ModuleRecordCheckpoint {
+        let module_record = &self.module_record;
+        ModuleRecordCheckpoint {
+            position,
+            has_module_syntax: module_record.has_module_syntax,
+            requested_modules_len: self.requested_modules_len,
+            import_entries_len: module_record.import_entries.len(),
+            export_entries_len: self.export_entries.len(),
+            exported_bindings_len: module_record.exported_bindings.len(),
+            exported_bindings_duplicated_len: self.exported_bindings_duplicated.len(),
+            dynamic_imports_len: module_record.dynamic_imports.len(),
+            import_metas_len: module_record.import_metas.len(),
+        }
+    }
+
+    /// Discard the entries added since `checkpoint`.
+    ///
+    /// Entries are only added for source text parsed after the checkpoint, so the entries of the
+    /// maps are found by their position.
+    pub fn rewind(&mut self, checkpoint: ModuleRecordCheckpoint) {
+        let ModuleRecordCheckpoint {
+            position,
+            has_module_syntax,
+            requested_modules_len,
+            import_entries_len,
+            export_entries_len,
+            exported_bindings_len,
+            exported_bindings_duplicated_len,
+            dynamic_imports_len,
+            import_metas_len,
+        } = checkpoint;
+        let module_record = &mut self.module_record;
+
+        module_record.has_module_syntax = has_module_syntax;
+        module_record.import_entries.truncate(import_entries_len);
+        self.export_entries.truncate(export_entries_len);
+        module_record.dynamic_imports.truncate(dynamic_imports_len);
+        module_record.import_metas.truncate(import_metas_len);
+
+        if self.requested_modules_len != requested_modules_len {
+            module_record.requested_modules.retain(|_, requested_modules| {
+                requested_modules.retain(|module| module.statement_span.start < position);
+                !requested_modules.is_empty()
+            });
+            self.requested_modules_len = requested_modules_len;
+        }
+
+        if module_record.exported_bindings.len() != exported_bindings_len
+            || self.exported_bindings_duplicated.len() != exported_bindings_duplicated_len
+        {
+            // Restore the bindings replaced by a duplicate, then remove the new ones.
+            for name_span in
+                self.exported_bindings_duplicated.drain(exported_bindings_duplicated_len..).rev()
+            {
+                module_record.exported_bindings.insert(name_span.name, name_span.span);
+            }
+            module_record.exported_bindings.retain(|_, span| span.start < position);
         }
     }
 
@@ -70,6 +148,7 @@ impl<'a> ModuleRecordBuilder<'a> {
             .entry(name)
             .or_insert_with(|| oxc_allocator::Vec::new_in(self.allocator))
             .push(requested_module);
+        self.requested_modules_len += 1;
     }
 
     fn add_import_entry(&mut self, entry: ImportEntry<'a>) {
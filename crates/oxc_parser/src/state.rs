@@ -15,6 +15,10 @@ pub struct ParserState<'a> {
     /// Keyed by start span of `ArrayExpression` / `ObjectExpression`.
     /// Valued by position of the trailing_comma.
     pub trailing_commas: FxHashMap<u32, Span>,
+
+    /// Whether the body of the pipeline expression being parsed contains a topic reference `%`.
+    /// `None` outside of pipeline bodies.
+    pub pipeline_topic: Option<bool>,
 }
 
 impl ParserState<'_> {
@@ -23,6 +27,7 @@ impl ParserState<'_> {
             not_parenthesized_arrow: FxHashSet::default(),
             cover_initialized_name: FxHashMap::default(),
             trailing_commas: FxHashMap::default(),
+            pipeline_topic: None,
         }
     }
 }
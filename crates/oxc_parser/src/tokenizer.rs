@@ -0,0 +1,184 @@
+//! Tokens of source text, read by the lexer without building an AST.
+//!
+//! For tools which only need the tokens, e.g. syntax highlighters and structural search.
+//!
+//! ```
+//! use oxc_allocator::Allocator;
+//! use oxc_parser::tokenizer::{TokenKind, Tokenizer};
+//! use oxc_span::SourceType;
+//!
+//! let source_text = "const re = /ab+c/g; // match";
+//! let allocator = Allocator::default();
+//! let ret = Tokenizer::new(&allocator, source_text, SourceType::mjs()).tokenize();
+//!
+//! let kinds = ret.tokens.iter().map(|token| token.kind).collect::<Vec<_>>();
+//! assert_eq!(
+//!     kinds,
+//!     [
+//!         TokenKind::Keyword,
+//!         TokenKind::Identifier,
+//!         TokenKind::Punctuator,
+//!         TokenKind::RegExp,
+//!         TokenKind::Punctuator,
+//!     ]
+//! );
+//! assert_eq!(ret.comments.len(), 1);
+//! ```
+//!
+//! Without a parser, some tokens are ambiguous:
+//! * Whether `/` starts a regular expression, and whether `}` continues a template literal, is
+//!   decided from the previous tokens. Both are right for all but contrived code.
+//! * Contextual keywords such as `async`, `of` and `type` are identifiers.
+//! * `>>` and other operators starting with `>` are split into `>` tokens, as in type arguments.
+//! * JSX text is not recognized, and is read as JavaScript tokens.
+//!
+//! Use [`ParseOptions::collect_tokens`](crate::ParseOptions::collect_tokens) to get the exact
+//! token spans from the parser.
+
+use oxc_allocator::Allocator;
+use oxc_ast::Comment;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{SourceType, Span};
+
+use crate::{
+    MAX_LEN, diagnostics,
+    lexer::{self, Kind, Lexer, ReLex, ReLexHeuristic},
+};
+
+/// Kind of a [`Token`], named after the token types of [Esprima](https://esprima.org).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TokenKind {
+    /// `#!/usr/bin/env node` at the start of the file.
+    Hashbang,
+    /// An identifier, including contextual keywords such as `async` and `of`.
+    Identifier,
+    /// `#name` in classes.
+    PrivateIdentifier,
+    /// A reserved word, such as `if` and `this`, or a strict mode reserved word, such as `let`.
+    Keyword,
+    /// `true` or `false`.
+    Boolean,
+    /// `null`.
+    Null,
+    /// A number or BigInt literal.
+    Numeric,
+    /// A string literal.
+    String,
+    /// A template literal, or the part of one before, between or after its substitutions.
+    Template,
+    /// A regular expression literal.
+    RegExp,
+    /// An operator or other punctuation, such as `+`, `=>` and `{`.
+    Punctuator,
+    /// A character which does not start any token.
+    Invalid,
+}
+
+impl TokenKind {
+    fn from_lexer_kind(kind: Kind) -> Self {
+        match kind {
+            Kind::HashbangComment => Self::Hashbang,
+            Kind::PrivateIdentifier => Self::PrivateIdentifier,
+            Kind::True | Kind::False => Self::Boolean,
+            Kind::Null => Self::Null,
+            Kind::Str => Self::String,
+            Kind::NoSubstitutionTemplate
+            | Kind::TemplateHead
+            | Kind::TemplateMiddle
+            | Kind::TemplateTail => Self::Template,
+            Kind::RegExp => Self::RegExp,
+            Kind::Undetermined => Self::Invalid,
+            kind if kind.is_number() => Self::Numeric,
+            kind if kind.is_reserved_keyword() || kind.is_strict_mode_contextual_keyword() => {
+                Self::Keyword
+            }
+            kind if kind.is_identifier_name() => Self::Identifier,
+            _ => Self::Punctuator,
+        }
+    }
+}
+
+/// A token read by [`Tokenizer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    /// Kind of token.
+    pub kind: TokenKind,
+    /// Span of the token in the source text.
+    pub span: Span,
+    /// Whether a line break is between this token and the previous one.
+    pub is_on_new_line: bool,
+    /// Whether the token contains an escape sequence, e.g. `\u0061` or `\n`.
+    pub escaped: bool,
+}
+
+impl Token {
+    fn from_lexer_token(token: lexer::Token) -> Self {
+        Self {
+            kind: TokenKind::from_lexer_kind(token.kind()),
+            span: token.span(),
+            is_on_new_line: token.is_on_new_line(),
+            escaped: token.escaped(),
+        }
+    }
+}
+
+/// Return value of [`Tokenizer::tokenize`].
+#[non_exhaustive]
+pub struct TokenizerReturn {
+    /// Tokens of the source text, in order.
+    pub tokens: Vec<Token>,
+
+    /// Comments of the source text, in order.
+    pub comments: Vec<Comment>,
+
+    /// Syntax errors of the tokens, e.g. an unterminated string.
+    ///
+    /// Tokenizing continues after an error.
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+/// Reads the tokens of source text. See the [module-level documentation](self).
+pub struct Tokenizer<'a> {
+    pub(crate) allocator: &'a Allocator,
+    pub(crate) source_text: &'a str,
+    pub(crate) source_type: SourceType,
+}
+
+impl<'a> Tokenizer<'a> {
+    /// Create a new [`Tokenizer`].
+    ///
+    /// `allocator` holds the unescaped strings the lexer produces along the way.
+    pub fn new(allocator: &'a Allocator, source_text: &'a str, source_type: SourceType) -> Self {
+        Self { allocator, source_text, source_type }
+    }
+}
+
+/// Read all tokens with `lexer`, which has not read any yet.
+pub(crate) fn tokenize(mut lexer: Lexer<'_>, source_text: &str) -> TokenizerReturn {
+    if source_text.len() > MAX_LEN {
+        return TokenizerReturn {
+            tokens: vec![],
+            comments: vec![],
+            errors: vec![diagnostics::overlong_source()],
+        };
+    }
+
+    let mut tokens = vec![];
+    let mut heuristic = ReLexHeuristic::default();
+    let mut token = lexer.first_token();
+    while token.kind() != Kind::Eof {
+        let is_on_new_line = token.is_on_new_line();
+        token = match heuristic.re_lex(token.kind()) {
+            Some(ReLex::TemplateSubstitutionTail) => lexer.next_template_substitution_tail(),
+            Some(ReLex::RegExp) => lexer.next_regex(token.kind()).0,
+            None => token,
+        };
+        token.set_is_on_new_line(is_on_new_line);
+        heuristic.push(token.kind());
+        tokens.push(Token::from_lexer_token(token));
+        token = lexer.next_token();
+    }
+
+    TokenizerReturn { tokens, comments: lexer.trivia_builder.comments, errors: lexer.errors }
+}
@@ -0,0 +1,79 @@
+//! [Flow](https://flow.org) syntax, parsed into TypeScript nodes.
+//!
+//! Only enabled with [`ParseOptions::allow_flow_syntax`](crate::ParseOptions::allow_flow_syntax).
+
+use oxc_ast::ast::*;
+
+use crate::{ParserImpl, lexer::Kind};
+
+impl<'a> ParserImpl<'a> {
+    /// `|` in `{| a: T |}`, which ends an exact object type instead of continuing a union type.
+    pub(crate) fn at_flow_exact_object_type_end(&mut self) -> bool {
+        self.options.allow_flow_syntax
+            && self.at(Kind::Pipe)
+            && self.lexer.peek_token().kind() == Kind::RCurly
+    }
+
+    /// `{| a: T |}` and `{||}`, whose exactness is dropped.
+    pub(crate) fn try_parse_flow_exact_object_type(&mut self) -> Option<TSType<'a>> {
+        if !self.options.allow_flow_syntax
+            || !matches!(self.lexer.peek_token().kind(), Kind::Pipe | Kind::Pipe2)
+        {
+            return None;
+        }
+        let span = self.start_span();
+        self.bump_any(); // bump `{`
+        let mut members = self.ast.vec();
+        if !self.eat(Kind::Pipe2) {
+            self.expect(Kind::Pipe);
+            while !self.at(Kind::Pipe) && !self.at(Kind::Eof) && !self.has_fatal_error() {
+                members.push(self.parse_ts_type_signature());
+            }
+            self.expect(Kind::Pipe);
+        }
+        self.expect(Kind::RCurly);
+        Some(self.ast.ts_type_type_literal(self.end_span(span), members))
+    }
+
+    /// `?T`, which is parsed as `T | null | undefined`.
+    pub(crate) fn parse_flow_maybe_type(&mut self) -> TSType<'a> {
+        let span = self.start_span();
+        self.bump_any(); // bump `?`
+        let question_span = self.end_span(span);
+        let ty = self.parse_postfix_type_or_higher();
+        let types = self.ast.vec_from_array([
+            ty,
+            self.ast.ts_type_null_keyword(question_span),
+            self.ast.ts_type_undefined_keyword(question_span),
+        ]);
+        self.ast.ts_type_union_type(self.end_span(span), types)
+    }
+
+    /// `%checks` in `function f(x): boolean %checks {}`, which is skipped.
+    pub(crate) fn eat_flow_predicate(&mut self) -> bool {
+        if !self.options.allow_flow_syntax
+            || !self.at(Kind::Percent)
+            || self.lexer.peek_token().kind() != Kind::Ident
+        {
+            return false;
+        }
+        self.bump_any(); // bump `%`
+        if self.cur_src() != "checks" {
+            return self.unexpected();
+        }
+        self.bump_any(); // bump `checks`
+        true
+    }
+
+    /// `(expression: T)`, which is parsed as `expression as T`.
+    pub(crate) fn parse_flow_type_cast_or_assignment_expression(&mut self) -> Expression<'a> {
+        let span = self.start_span();
+        let expression = self.parse_assignment_expression_or_higher();
+        if !self.options.allow_flow_syntax || !self.at(Kind::Colon) {
+            return expression;
+        }
+        self.bump_any(); // bump `:`
+        let ty = self.parse_ts_type();
+        self.ast.expression_ts_as(self.end_span(span), expression, ty)
+    }
+}
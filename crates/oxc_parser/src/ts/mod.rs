@@ -1,2 +1,3 @@
+mod flow;
 mod statement;
 mod types;
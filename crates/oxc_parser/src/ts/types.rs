@@ -232,9 +232,9 @@ impl<'a> ParserImpl<'a> {
         let has_leading_operator = self.eat(kind);
         /* hasLeadingOperator && parseFunctionOrConstructorTypeToError(isUnionType) ||*/
         let mut ty = parse_constituent_type(self);
-        if self.at(kind) || has_leading_operator {
+        if (self.at(kind) && !self.at_flow_exact_object_type_end()) || has_leading_operator {
             let mut types = self.ast.vec1(ty);
-            while self.eat(kind) {
+            while !self.at_flow_exact_object_type_end() && self.eat(kind) {
                 types.push(
                     /*parseFunctionOrConstructorTypeToError(isUnionType) || */
                     parse_constituent_type(self),
@@ -293,7 +293,7 @@ impl<'a> ParserImpl<'a> {
         self.ast.alloc_ts_type_parameter(span, name, constraint, None, false, false, false)
     }
 
-    fn parse_postfix_type_or_higher(&mut self) -> TSType<'a> {
+    pub(super) fn parse_postfix_type_or_higher(&mut self) -> TSType<'a> {
         let span = self.start_span();
         let mut ty = self.parse_non_array_type();
 
@@ -378,6 +378,7 @@ impl<'a> ParserImpl<'a> {
             // // falls through
             // case SyntaxKind.FunctionKeyword:
             // return parseJSDocFunctionType();
+            Kind::Question if self.options.allow_flow_syntax => self.parse_flow_maybe_type(),
             Kind::Question => self.parse_js_doc_unknown_or_nullable_type(),
             Kind::Bang => self.parse_js_doc_non_nullable_type(),
             Kind::Str | Kind::True | Kind::False => self.parse_literal_type(),
@@ -653,6 +654,9 @@ impl<'a> ParserImpl<'a> {
     }
 
     fn parse_type_literal(&mut self) -> TSType<'a> {
+        if let Some(ty) = self.try_parse_flow_exact_object_type() {
+            return ty;
+        }
         let span = self.start_span();
         let member_list =
             self.parse_normal_list(Kind::LCurly, Kind::RCurly, Self::parse_ts_type_signature);
@@ -697,7 +701,8 @@ impl<'a> ParserImpl<'a> {
     }
 
     fn parse_ts_type_constraint(&mut self) -> Option<TSType<'a>> {
-        if !self.at(Kind::Extends) {
+        // Flow `<T: U>`
+        if !(self.at(Kind::Extends) || self.options.allow_flow_syntax && self.at(Kind::Colon)) {
             return None;
         }
         self.bump_any();
@@ -1077,8 +1082,16 @@ impl<'a> ParserImpl<'a> {
             return None;
         }
         let span = self.start_span();
+        // Flow `function f(x): %checks {}`
+        if self.options.allow_flow_syntax && self.lexer.peek_token().kind() == Kind::Percent {
+            self.bump_any();
+            self.eat_flow_predicate();
+            return None;
+        }
         let return_type = self.parse_return_type();
-        Some(self.ast.alloc_ts_type_annotation(self.end_span(span), return_type))
+        let return_type = self.ast.alloc_ts_type_annotation(self.end_span(span), return_type);
+        self.eat_flow_predicate();
+        Some(return_type)
     }
 
     fn parse_return_type(&mut self) -> TSType<'a> {
@@ -2,12 +2,12 @@ use bitflags::bitflags;
 
 use oxc_allocator::{Box, CloneIn, GetAddress, Vec};
 use oxc_ast_macros::ast;
-use oxc_span::{Atom, ContentEq, Span};
+use oxc_span::{Atom, ContentEq, ContentHash, Span};
 
 /// The root of the `PatternParser` result.
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct Pattern<'a> {
     pub span: Span,
     pub body: Disjunction<'a>,
@@ -16,7 +16,7 @@ pub struct Pattern<'a> {
 /// Pile of [`Alternative`]s separated by `|`.
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct Disjunction<'a> {
     pub span: Span,
     pub body: Vec<'a, Alternative<'a>>,
@@ -25,7 +25,7 @@ pub struct Disjunction<'a> {
 /// Single unit of `|` separated alternatives.
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct Alternative<'a> {
     pub span: Span,
     pub body: Vec<'a, Term<'a>>,
@@ -34,7 +34,7 @@ pub struct Alternative<'a> {
 /// Single unit of [`Alternative`], containing various kinds.
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub enum Term<'a> {
     // Assertion, QuantifiableAssertion
     BoundaryAssertion(Box<'a, BoundaryAssertion>) = 0,
@@ -57,7 +57,7 @@ pub enum Term<'a> {
 /// e.g. `^`, `$`, `\b`, `\B`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct BoundaryAssertion {
     pub span: Span,
     pub kind: BoundaryAssertionKind,
@@ -65,7 +65,7 @@ pub struct BoundaryAssertion {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub enum BoundaryAssertionKind {
     Start = 0,
     End = 1,
@@ -77,7 +77,7 @@ pub enum BoundaryAssertionKind {
 /// e.g. `(?=...)`, `(?!...)`, `(?<=...)`, `(?<!...)`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct LookAroundAssertion<'a> {
     pub span: Span,
     pub kind: LookAroundAssertionKind,
@@ -86,7 +86,7 @@ pub struct LookAroundAssertion<'a> {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub enum LookAroundAssertionKind {
     Lookahead = 0,
     NegativeLookahead = 1,
@@ -98,7 +98,7 @@ pub enum LookAroundAssertionKind {
 /// e.g. `a*`, `b+`, `c?`, `d{3}`, `e{4,}`, `f{5,6}`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct Quantifier<'a> {
     pub span: Span,
     pub min: u64,
@@ -111,7 +111,7 @@ pub struct Quantifier<'a> {
 /// Single character.
 #[ast]
 #[derive(Debug, Clone, Copy)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct Character {
     /// This will be invalid position when `UnicodeMode` is disabled and `value` is a surrogate pair.
     pub span: Span,
@@ -122,7 +122,7 @@ pub struct Character {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub enum CharacterKind {
     ControlLetter = 0,
     HexadecimalEscape = 1,
@@ -141,7 +141,7 @@ pub enum CharacterKind {
 /// e.g. `\d`, `\D`, `\s`, `\S`, `\w`, `\W`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct CharacterClassEscape {
     pub span: Span,
     pub kind: CharacterClassEscapeKind,
@@ -149,7 +149,7 @@ pub struct CharacterClassEscape {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub enum CharacterClassEscapeKind {
     D = 0,
     NegativeD = 1,
@@ -163,7 +163,7 @@ pub enum CharacterClassEscapeKind {
 /// e.g. `\p{ASCII}`, `\P{ASCII}`, `\p{sc=Hiragana}`, `\P{sc=Hiragana}`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct UnicodePropertyEscape<'a> {
     pub span: Span,
     pub negative: bool,
@@ -176,7 +176,7 @@ pub struct UnicodePropertyEscape<'a> {
 /// The `.`.
 #[ast]
 #[derive(Debug, Clone, Copy)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct Dot {
     pub span: Span,
 }
@@ -185,7 +185,7 @@ pub struct Dot {
 /// e.g. `[a-z]`, `[^A-Z]`, `[abc]`, `[a&&b&&c]`, `[[a-z]--x--y]`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct CharacterClass<'a> {
     pub span: Span,
     pub negative: bool,
@@ -199,7 +199,7 @@ pub struct CharacterClass<'a> {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub enum CharacterClassContentsKind {
     Union = 0,
     /// `UnicodeSetsMode` only.
@@ -210,7 +210,7 @@ pub enum CharacterClassContentsKind {
 
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, GetAddress)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, GetAddress)]
 pub enum CharacterClassContents<'a> {
     CharacterClassRange(Box<'a, CharacterClassRange>) = 0,
     CharacterClassEscape(Box<'a, CharacterClassEscape>) = 1,
@@ -226,7 +226,7 @@ pub enum CharacterClassContents<'a> {
 /// e.g. `a-z`, `A-Z`, `0-9`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct CharacterClassRange {
     pub span: Span,
     pub min: Character,
@@ -236,7 +236,7 @@ pub struct CharacterClassRange {
 /// `|` separated string of characters wrapped by `\q{}`.
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct ClassStringDisjunction<'a> {
     pub span: Span,
     /// `true` if body is empty or contains [`ClassString`] which `strings` is `true`.
@@ -247,7 +247,7 @@ pub struct ClassStringDisjunction<'a> {
 /// Single unit of [`ClassStringDisjunction`].
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct ClassString<'a> {
     pub span: Span,
     /// `true` if body is empty or contain 2 more characters.
@@ -259,7 +259,7 @@ pub struct ClassString<'a> {
 /// e.g. `(...)`, `(?<name>...)`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct CapturingGroup<'a> {
     pub span: Span,
     /// Group name to be referenced by [`NamedReference`].
@@ -271,7 +271,7 @@ pub struct CapturingGroup<'a> {
 /// e.g. `(?:...)`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct IgnoreGroup<'a> {
     pub span: Span,
     pub modifiers: Option<Modifiers>,
@@ -282,7 +282,7 @@ pub struct IgnoreGroup<'a> {
 /// e.g. `i` in `(?i:...)`, `-s` in `(?-s:...)`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct Modifiers {
     pub span: Span,
     pub enabling: Modifier,
@@ -310,7 +310,7 @@ struct ModifierAlias(u8);
 /// e.g. `\1`, `\2`, `\3`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct IndexedReference {
     pub span: Span,
     pub index: u32,
@@ -320,7 +320,7 @@ pub struct IndexedReference {
 /// e.g. `\k<name>`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash)]
 pub struct NamedReference<'a> {
     pub span: Span,
     pub name: Atom<'a>,
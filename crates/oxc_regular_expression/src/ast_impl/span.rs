@@ -1,7 +1,9 @@
 // NOTE: For now, this file is implemented by hand for convenience.
 // But like `oxc_ast`, this should be generated by `tasks/ast_tools` in the future.
 
-use oxc_span::{ContentEq, GetSpan, Span};
+use std::hash::Hasher;
+
+use oxc_span::{ContentEq, ContentHash, GetSpan, Span};
 
 use crate::ast::{CharacterClassContents, Modifier, Term};
 
@@ -46,3 +48,9 @@ impl ContentEq for Modifier {
         self == other
     }
 }
+
+impl ContentHash for Modifier {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u8(self.bits());
+    }
+}
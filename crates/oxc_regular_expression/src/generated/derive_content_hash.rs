@@ -0,0 +1,285 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/derives/content_hash.rs`.
+
+use std::hash::Hasher;
+
+use oxc_span::ContentHash;
+
+use crate::ast::*;
+
+impl ContentHash for Pattern<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for Disjunction<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for Alternative<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for Term<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::BoundaryAssertion(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::LookAroundAssertion(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Quantifier(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Character(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Dot(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::CharacterClassEscape(it) => {
+                state.write_u8(5);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UnicodePropertyEscape(it) => {
+                state.write_u8(6);
+                ContentHash::content_hash(it, state);
+            }
+            Self::CharacterClass(it) => {
+                state.write_u8(7);
+                ContentHash::content_hash(it, state);
+            }
+            Self::CapturingGroup(it) => {
+                state.write_u8(8);
+                ContentHash::content_hash(it, state);
+            }
+            Self::IgnoreGroup(it) => {
+                state.write_u8(9);
+                ContentHash::content_hash(it, state);
+            }
+            Self::IndexedReference(it) => {
+                state.write_u8(10);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NamedReference(it) => {
+                state.write_u8(11);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for BoundaryAssertion {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.kind, state);
+    }
+}
+
+impl ContentHash for BoundaryAssertionKind {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Start => state.write_u8(0),
+            Self::End => state.write_u8(1),
+            Self::Boundary => state.write_u8(2),
+            Self::NegativeBoundary => state.write_u8(3),
+        }
+    }
+}
+
+impl ContentHash for LookAroundAssertion<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.kind, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for LookAroundAssertionKind {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Lookahead => state.write_u8(0),
+            Self::NegativeLookahead => state.write_u8(1),
+            Self::Lookbehind => state.write_u8(2),
+            Self::NegativeLookbehind => state.write_u8(3),
+        }
+    }
+}
+
+impl ContentHash for Quantifier<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.min, state);
+        ContentHash::content_hash(&self.max, state);
+        ContentHash::content_hash(&self.greedy, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for Character {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.kind, state);
+        ContentHash::content_hash(&self.value, state);
+    }
+}
+
+impl ContentHash for CharacterKind {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::ControlLetter => state.write_u8(0),
+            Self::HexadecimalEscape => state.write_u8(1),
+            Self::Identifier => state.write_u8(2),
+            Self::Null => state.write_u8(3),
+            Self::Octal1 => state.write_u8(4),
+            Self::Octal2 => state.write_u8(5),
+            Self::Octal3 => state.write_u8(6),
+            Self::SingleEscape => state.write_u8(7),
+            Self::Symbol => state.write_u8(8),
+            Self::UnicodeEscape => state.write_u8(9),
+        }
+    }
+}
+
+impl ContentHash for CharacterClassEscape {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.kind, state);
+    }
+}
+
+impl ContentHash for CharacterClassEscapeKind {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::D => state.write_u8(0),
+            Self::NegativeD => state.write_u8(1),
+            Self::S => state.write_u8(2),
+            Self::NegativeS => state.write_u8(3),
+            Self::W => state.write_u8(4),
+            Self::NegativeW => state.write_u8(5),
+        }
+    }
+}
+
+impl ContentHash for UnicodePropertyEscape<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.negative, state);
+        ContentHash::content_hash(&self.strings, state);
+        ContentHash::content_hash(&self.name, state);
+        ContentHash::content_hash(&self.value, state);
+    }
+}
+
+impl ContentHash for Dot {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl ContentHash for CharacterClass<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.negative, state);
+        ContentHash::content_hash(&self.strings, state);
+        ContentHash::content_hash(&self.kind, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for CharacterClassContentsKind {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Union => state.write_u8(0),
+            Self::Intersection => state.write_u8(1),
+            Self::Subtraction => state.write_u8(2),
+        }
+    }
+}
+
+impl ContentHash for CharacterClassContents<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::CharacterClassRange(it) => {
+                state.write_u8(0);
+                ContentHash::content_hash(it, state);
+            }
+            Self::CharacterClassEscape(it) => {
+                state.write_u8(1);
+                ContentHash::content_hash(it, state);
+            }
+            Self::UnicodePropertyEscape(it) => {
+                state.write_u8(2);
+                ContentHash::content_hash(it, state);
+            }
+            Self::Character(it) => {
+                state.write_u8(3);
+                ContentHash::content_hash(it, state);
+            }
+            Self::NestedCharacterClass(it) => {
+                state.write_u8(4);
+                ContentHash::content_hash(it, state);
+            }
+            Self::ClassStringDisjunction(it) => {
+                state.write_u8(5);
+                ContentHash::content_hash(it, state);
+            }
+        }
+    }
+}
+
+impl ContentHash for CharacterClassRange {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.min, state);
+        ContentHash::content_hash(&self.max, state);
+    }
+}
+
+impl ContentHash for ClassStringDisjunction<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.strings, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for ClassString<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.strings, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for CapturingGroup<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.name, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for IgnoreGroup<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.modifiers, state);
+        ContentHash::content_hash(&self.body, state);
+    }
+}
+
+impl ContentHash for Modifiers {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.enabling, state);
+        ContentHash::content_hash(&self.disabling, state);
+    }
+}
+
+impl ContentHash for IndexedReference {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.index, state);
+    }
+}
+
+impl ContentHash for NamedReference<'_> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&self.name, state);
+    }
+}
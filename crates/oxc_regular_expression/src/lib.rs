@@ -11,6 +11,7 @@ mod generated {
     mod assert_layouts;
     mod derive_clone_in;
     mod derive_content_eq;
+    mod derive_content_hash;
 }
 
 pub mod ast;
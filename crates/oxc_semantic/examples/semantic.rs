@@ -8,12 +8,14 @@
 //!
 //! Create a `test.js` file and run:
 //! ```bash
-//! cargo run -p oxc_semantic --example semantic [filename] [--symbols]
+//! cargo run -p oxc_semantic --example semantic [filename] [--symbols] [--snapshot] [--json]
 //! ```
 //!
 //! ## Options
 //!
 //! - `--symbols`: Display symbol table and reference information
+//! - `--snapshot`: Display the scope tree with its symbols and references
+//! - `--json`: Display the scope tree, symbols and references as JSON
 
 use std::{env, path::Path, sync::Arc};
 
@@ -33,6 +35,8 @@ use oxc_span::SourceType;
 fn main() -> std::io::Result<()> {
     let name = env::args().nth(1).unwrap_or_else(|| "test.js".to_string());
     let show_symbols = env::args().skip(1).any(|arg| arg == "--symbols");
+    let show_snapshot = env::args().skip(1).any(|arg| arg == "--snapshot");
+    let show_json = env::args().skip(1).any(|arg| arg == "--json");
     let path = Path::new(&name);
     let source_text = Arc::new(std::fs::read_to_string(path)?);
     let source_type = SourceType::from_path(path).unwrap();
@@ -83,5 +87,13 @@ fn main() -> std::io::Result<()> {
         }
     }
 
+    if show_snapshot {
+        print!("{}", semantic.semantic.snapshot());
+    }
+
+    if show_json {
+        println!("{}", semantic.semantic.snapshot().to_json());
+    }
+
     Ok(())
 }
@@ -3,6 +3,7 @@
 use std::{
     cell::{Cell, RefCell},
     mem,
+    sync::OnceLock,
 };
 
 use rustc_hash::FxHashMap;
@@ -293,6 +294,7 @@ impl<'a> SemanticBuilder<'a> {
             source_type: self.source_type,
             comments: &program.comments,
             irregular_whitespaces: [].into(),
+            comment_attachments: OnceLock::new(),
             nodes: self.nodes,
             scoping: self.scoping,
             classes: self.class_table_builder.build(),
@@ -0,0 +1,215 @@
+//! Attachment of comments to AST nodes.
+
+use oxc_ast::Comment;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::node::NodeId;
+
+use crate::{AstNodes, Semantic};
+
+/// How a comment is attached to its node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CommentPlacement {
+    /// Before the node, e.g. `/* c */ a`.
+    Leading,
+    /// Inside the node, with no child node before or after it, e.g. `function f(/* c */) {}`.
+    Dangling,
+    /// After the node, e.g. `a; // c`.
+    Trailing,
+}
+
+/// The node a comment is attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommentAttachment {
+    pub node_id: NodeId,
+    pub placement: CommentPlacement,
+}
+
+/// The [`CommentAttachment`]s of all comments of a program.
+///
+/// Comments are attached with the heuristics of Babel and Prettier. The enclosing node of a
+/// comment is the deepest node containing it, and the preceding and following nodes are the
+/// children of the enclosing node around the comment.
+/// * A comment on its own line is a leading comment of the following node, or else a trailing
+///   comment of the preceding node.
+/// * A comment at the end of a line is a trailing comment of the preceding node, or else a
+///   leading comment of the following node.
+/// * Any other comment is a leading comment of the following node, unless there is source text
+///   between them, e.g. the `,` in `f(a /* c */, b)`. It is then a trailing comment of the
+///   preceding node.
+/// * A comment without preceding and following nodes is a dangling comment of the enclosing node.
+///
+/// A comment is attached to the outermost node starting or ending next to it, e.g. to the
+/// statement rather than to the expression of the statement.
+#[derive(Debug, Default)]
+pub struct CommentAttachments {
+    /// Attachment of each comment, in the order of [`Semantic::comments`].
+    attachments: Vec<CommentAttachment>,
+    /// Indexes of `attachments`, sorted by node and placement.
+    by_node: Vec<u32>,
+}
+
+impl CommentAttachments {
+    pub(crate) fn new(nodes: &AstNodes, source_text: &str, comments: &[Comment]) -> Self {
+        if comments.is_empty() || nodes.is_empty() {
+            return Self::default();
+        }
+        let children = Children::new(nodes);
+        let attachments = comments
+            .iter()
+            .map(|comment| attach(nodes, &children, source_text, comment))
+            .collect::<Vec<_>>();
+        let mut by_node = (0..attachments.len())
+            .map(|index| u32::try_from(index).unwrap_or(u32::MAX))
+            .collect::<Vec<_>>();
+        by_node.sort_by_key(|&index| {
+            let attachment = attachments[index as usize];
+            (attachment.node_id, attachment.placement)
+        });
+        Self { attachments, by_node }
+    }
+
+    /// The attachment of the comment at `index` of [`Semantic::comments`].
+    pub fn get(&self, index: usize) -> Option<CommentAttachment> {
+        self.attachments.get(index).copied()
+    }
+
+    /// The attachments of all comments, in the order of [`Semantic::comments`].
+    pub fn iter(&self) -> impl Iterator<Item = CommentAttachment> + '_ {
+        self.attachments.iter().copied()
+    }
+
+    /// Indexes of the comments attached to `node_id` with `placement`, in source order.
+    pub fn comment_indexes(
+        &self,
+        node_id: NodeId,
+        placement: CommentPlacement,
+    ) -> impl Iterator<Item = usize> + '_ {
+        let key = (node_id, placement);
+        let attachment = |index: u32| {
+            let attachment = self.attachments[index as usize];
+            (attachment.node_id, attachment.placement)
+        };
+        let start = self.by_node.partition_point(|&index| attachment(index) < key);
+        let end = self.by_node.partition_point(|&index| attachment(index) <= key);
+        self.by_node[start..end].iter().map(|&index| index as usize)
+    }
+}
+
+impl<'a> Semantic<'a> {
+    /// The nodes the comments are attached to, see [`CommentAttachments`].
+    ///
+    /// Computed on first use.
+    pub fn comment_attachments(&self) -> &CommentAttachments {
+        self.comment_attachments
+            .get_or_init(|| CommentAttachments::new(&self.nodes, self.source_text, self.comments))
+    }
+
+    /// The comments before the node, e.g. its JSDoc comment.
+    pub fn leading_comments(&self, node_id: NodeId) -> impl Iterator<Item = &'a Comment> + '_ {
+        self.attached_comments(node_id, CommentPlacement::Leading)
+    }
+
+    /// The comments after the node.
+    pub fn trailing_comments(&self, node_id: NodeId) -> impl Iterator<Item = &'a Comment> + '_ {
+        self.attached_comments(node_id, CommentPlacement::Trailing)
+    }
+
+    /// The comments inside the node which are not around any of its children.
+    pub fn dangling_comments(&self, node_id: NodeId) -> impl Iterator<Item = &'a Comment> + '_ {
+        self.attached_comments(node_id, CommentPlacement::Dangling)
+    }
+
+    fn attached_comments(
+        &self,
+        node_id: NodeId,
+        placement: CommentPlacement,
+    ) -> impl Iterator<Item = &'a Comment> + '_ {
+        let comments = self.comments;
+        self.comment_attachments().comment_indexes(node_id, placement).map(|index| &comments[index])
+    }
+}
+
+/// The child nodes of each node, sorted by position.
+struct Children {
+    /// `node` -> range of `ids` with its children
+    offsets: Vec<usize>,
+    ids: Vec<NodeId>,
+}
+
+impl Children {
+    fn new(nodes: &AstNodes) -> Self {
+        let mut offsets = vec![0; nodes.len() + 1];
+        for node in nodes.iter().skip(1) {
+            offsets[nodes.parent_id(node.id()).index() + 1] += 1;
+        }
+        for index in 1..offsets.len() {
+            offsets[index] += offsets[index - 1];
+        }
+        let mut next = offsets.clone();
+        let mut ids = vec![NodeId::ROOT; nodes.len() - 1];
+        for node in nodes.iter().skip(1) {
+            let next = &mut next[nodes.parent_id(node.id()).index()];
+            ids[*next] = node.id();
+            *next += 1;
+        }
+        // Nodes are visited in source order, except for a few, e.g. decorators.
+        for range in offsets.windows(2) {
+            ids[range[0]..range[1]].sort_by_key(|&id| nodes.kind(id).span().start);
+        }
+        Self { offsets, ids }
+    }
+
+    fn of(&self, node_id: NodeId) -> &[NodeId] {
+        &self.ids[self.offsets[node_id.index()]..self.offsets[node_id.index() + 1]]
+    }
+}
+
+fn attach(
+    nodes: &AstNodes,
+    children: &Children,
+    source_text: &str,
+    comment: &Comment,
+) -> CommentAttachment {
+    let span = |node_id: NodeId| nodes.kind(node_id).span();
+    let mut enclosing = NodeId::ROOT;
+    let (preceding, following) = loop {
+        let children = children.of(enclosing);
+        let index = children.partition_point(|&id| span(id).end <= comment.span.start);
+        match children.get(index) {
+            Some(&child) if contains(span(child), comment.span) => enclosing = child,
+            _ => {
+                let following = children[index..]
+                    .iter()
+                    .copied()
+                    .find(|&id| span(id).start >= comment.span.end);
+                break (index.checked_sub(1).map(|index| children[index]), following);
+            }
+        }
+    };
+
+    let leading = |node_id| (node_id, CommentPlacement::Leading);
+    let trailing = |node_id| (node_id, CommentPlacement::Trailing);
+    let attachment = if comment.preceded_by_newline() {
+        following.map(leading).or_else(|| preceding.map(trailing))
+    } else if comment.followed_by_newline() {
+        preceding.map(trailing).or_else(|| following.map(leading))
+    } else {
+        match (preceding, following) {
+            (Some(preceding), Some(following)) => {
+                let between = Span::new(comment.span.end, span(following).start);
+                if between.source_text(source_text).trim().is_empty() {
+                    Some(leading(following))
+                } else {
+                    Some(trailing(preceding))
+                }
+            }
+            (preceding, following) => following.map(leading).or_else(|| preceding.map(trailing)),
+        }
+    };
+    let (node_id, placement) = attachment.unwrap_or((enclosing, CommentPlacement::Dangling));
+    CommentAttachment { node_id, placement }
+}
+
+fn contains(span: Span, comment: Span) -> bool {
+    span.start <= comment.start && comment.end <= span.end
+}
@@ -163,6 +163,58 @@ impl<'a> JSDocTagTypePart<'a> {
         // +1 for `{`, -1 for `}`
         self.raw[1..self.raw.len() - 1].trim()
     }
+
+    /// Returns the names which the type expression refers to, with their spans.
+    ///
+    /// For `{Array<string> | Foo.Bar}`, these are `Array`, `string` and `Foo.Bar`.
+    /// Keys of record types and parameter names, like `key` in `{{ key: string }}`,
+    /// and the contents of string literals are not names of types.
+    pub fn type_names(&self) -> Vec<(&'a str, Span)> {
+        fn is_name_start(b: u8) -> bool {
+            b.is_ascii_alphabetic() || b == b'_' || b == b'$' || !b.is_ascii()
+        }
+        fn is_name_part(b: u8) -> bool {
+            is_name_start(b) || b.is_ascii_digit()
+        }
+
+        let bytes = self.raw.as_bytes();
+        let mut names = vec![];
+        let mut i = 0;
+        while i < bytes.len() {
+            let b = bytes[i];
+            if matches!(b, b'"' | b'\'' | b'`') {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+                i += 1;
+            } else if is_name_start(b) {
+                let start = i;
+                while i < bytes.len()
+                    && (is_name_part(bytes[i])
+                        || bytes[i] == b'.' && bytes.get(i + 1).is_some_and(|&b| is_name_start(b)))
+                {
+                    i += 1;
+                }
+                let rest = self.raw[i..].trim_start();
+                if !rest.starts_with(':') && !rest.starts_with("?:") {
+                    let span = Span::new(
+                        self.span.start + u32::try_from(start).unwrap_or_default(),
+                        self.span.start + u32::try_from(i).unwrap_or_default(),
+                    );
+                    names.push((&self.raw[start..i], span));
+                }
+            } else if b.is_ascii_digit() {
+                // Skip number literals, e.g. `1e3`, as a whole.
+                while i < bytes.len() && is_name_part(bytes[i]) {
+                    i += 1;
+                }
+            } else {
+                i += 1;
+            }
+        }
+        names
+    }
 }
 
 /// Represents a single component of a type name in a JSDoc tag
@@ -357,6 +409,27 @@ mod test {
         }
     }
 
+    #[test]
+    fn type_part_type_names() {
+        for (actual, expect) in [
+            ("{}", vec![]),
+            ("{string}", vec!["string"]),
+            ("{Array<String> | Foo.Bar}", vec!["Array", "String", "Foo.Bar"]),
+            ("{{ key: Number, opt?: string }}", vec!["Number", "string"]),
+            ("{(a: Boolean) => void}", vec!["Boolean", "void"]),
+            (r#"{"String" | 'Number' | 1e3}"#, vec![]),
+            ("{Object.<string, 変数>}", vec!["Object", "string", "変数"]),
+        ] {
+            let type_part =
+                JSDocTagTypePart::new(actual, Span::new(0, u32::try_from(actual.len()).unwrap()));
+            let names = type_part.type_names();
+            assert_eq!(names.iter().map(|(name, _)| *name).collect::<Vec<_>>(), expect);
+            for (name, span) in names {
+                assert_eq!(span.source_text(actual), name);
+            }
+        }
+    }
+
     #[test]
     fn type_name_part_parsed() {
         for (actual, expect) in [
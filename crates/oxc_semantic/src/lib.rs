@@ -5,14 +5,16 @@
 #![doc = include_str!("../examples/semantic.rs")]
 //! ```
 
-use std::ops::RangeBounds;
+use std::{ops::RangeBounds, sync::OnceLock};
 
 use oxc_ast::{
     AstKind, Comment, CommentsRange, ast::IdentifierReference, comments_range, get_comment_at,
     has_comments_between, is_inside_comment,
 };
 #[cfg(feature = "cfg")]
-use oxc_cfg::ControlFlowGraph;
+pub use oxc_cfg::{
+    BasicBlock, BlockNodeId, ControlFlowGraph, EdgeType, Instruction, InstructionKind, dataflow,
+};
 use oxc_span::{GetSpan, SourceType, Span};
 // Re-export flags and ID types
 pub use oxc_syntax::{
@@ -31,25 +33,33 @@ mod binder;
 mod builder;
 mod checker;
 mod class;
+mod comment_attachment;
 mod diagnostics;
 mod is_global_reference;
 #[cfg(feature = "linter")]
 mod jsdoc;
 mod label;
 mod node;
+mod rename;
 mod scoping;
+mod snapshot;
 mod stats;
+mod type_inference;
 mod unresolved_stack;
 
 #[cfg(feature = "linter")]
 pub use ast_types_bitset::AstTypesBitset;
 pub use builder::{SemanticBuilder, SemanticBuilderReturn};
+pub use comment_attachment::{CommentAttachment, CommentAttachments, CommentPlacement};
 pub use is_global_reference::IsGlobalReference;
 #[cfg(feature = "linter")]
 pub use jsdoc::{JSDoc, JSDocFinder, JSDocTag};
 pub use node::{AstNode, AstNodes};
+pub use rename::TextEdit;
 pub use scoping::Scoping;
+pub use snapshot::{ReferenceSnapshot, ScopeSnapshot, SemanticSnapshot, SymbolSnapshot};
 pub use stats::Stats;
+pub use type_inference::InferredType;
 
 use class::ClassTable;
 
@@ -82,6 +92,9 @@ pub struct Semantic<'a> {
     comments: &'a [Comment],
     irregular_whitespaces: Box<[Span]>,
 
+    /// Nodes the comments are attached to. Computed on first use.
+    comment_attachments: OnceLock<CommentAttachments>,
+
     /// Parsed JSDoc comments.
     #[cfg(feature = "linter")]
     jsdoc: JSDocFinder<'a>,
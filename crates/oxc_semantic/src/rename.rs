@@ -0,0 +1,204 @@
+//! Renaming of symbols.
+
+use oxc_ast::{AstKind, ast::ModuleExportName};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{
+    identifier::is_identifier_name, keyword::is_reserved_keyword, scope::ScopeId, symbol::SymbolId,
+};
+
+use crate::Semantic;
+
+/// A replacement of the source text in `span` with `new_text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub new_text: String,
+}
+
+impl TextEdit {
+    fn new(span: Span, new_text: impl Into<String>) -> Self {
+        Self { span, new_text: new_text.into() }
+    }
+}
+
+fn invalid_name(name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("`{name}` is not a valid identifier"))
+}
+
+fn exported_declaration(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Renaming `{name}` would change the exports of the module"))
+        .with_label(span.label(format!("`{name}` is exported by its declaration")))
+}
+
+fn name_conflict(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Renaming to `{name}` would conflict with another binding"))
+        .with_label(span.label(format!("`{name}` is used here")))
+}
+
+impl Semantic<'_> {
+    /// Rename the symbol `symbol_id` to `new_name`, returning the edits to apply to the source
+    /// text, sorted by position.
+    ///
+    /// * Shorthand properties keep their key, e.g. `({ a })` becomes `({ a: b })`.
+    /// * Import and export specifiers keep the imported and exported names, e.g.
+    ///   `export { a }` becomes `export { b as a }`.
+    /// * String-keyed import and export names are never changed.
+    ///
+    /// # Errors
+    ///
+    /// * `new_name` is not a valid identifier.
+    /// * The symbol is exported by its declaration, e.g. `export const a = 1`.
+    /// * The symbol would shadow, or be shadowed by, another binding named `new_name` for any
+    ///   of the references involved.
+    pub fn rename(
+        &self,
+        symbol_id: SymbolId,
+        new_name: &str,
+    ) -> Result<Vec<TextEdit>, OxcDiagnostic> {
+        if !is_identifier_name(new_name) || is_reserved_keyword(new_name) {
+            return Err(invalid_name(new_name));
+        }
+        let old_name = self.scoping.symbol_name(symbol_id);
+        if old_name == new_name {
+            return Ok(vec![]);
+        }
+        self.check_rename_conflicts(symbol_id, new_name)?;
+
+        let is_root_symbol =
+            self.scoping.symbol_scope_id(symbol_id) == self.scoping.root_scope_id();
+        let mut edits = vec![];
+        for node in self.nodes.iter() {
+            let AstKind::BindingIdentifier(ident) = node.kind() else {
+                continue;
+            };
+            if ident.symbol_id.get() != Some(symbol_id) {
+                continue;
+            }
+            if is_root_symbol
+                && self
+                    .nodes
+                    .ancestor_kinds(node.id())
+                    .any(|kind| matches!(kind, AstKind::ExportNamedDeclaration(_)))
+            {
+                return Err(exported_declaration(old_name, ident.span));
+            }
+            let mut parent = self.nodes.parent_node(node.id());
+            if matches!(parent.kind(), AstKind::AssignmentPattern(_)) {
+                parent = self.nodes.parent_node(parent.id());
+            }
+            let edit = match parent.kind() {
+                AstKind::BindingProperty(property)
+                    if property.shorthand && property.key.span() == ident.span =>
+                {
+                    TextEdit::new(ident.span, format!("{old_name}: {new_name}"))
+                }
+                AstKind::ImportSpecifier(specifier) if specifier.imported.span() == ident.span => {
+                    TextEdit::new(ident.span, format!("{old_name} as {new_name}"))
+                }
+                AstKind::ImportSpecifier(specifier)
+                    if module_export_identifier(&specifier.imported) == Some(new_name) =>
+                {
+                    TextEdit::new(
+                        Span::new(specifier.imported.span().start, ident.span.end),
+                        new_name,
+                    )
+                }
+                _ => TextEdit::new(ident.span, new_name),
+            };
+            edits.push(edit);
+        }
+
+        for reference in self.scoping.get_resolved_references(symbol_id) {
+            let node_id = reference.node_id();
+            let AstKind::IdentifierReference(ident) = self.nodes.kind(node_id) else {
+                continue;
+            };
+            let edit = match self.nodes.parent_kind(node_id) {
+                AstKind::ObjectProperty(property) if property.shorthand => {
+                    TextEdit::new(ident.span, format!("{old_name}: {new_name}"))
+                }
+                AstKind::AssignmentTargetPropertyIdentifier(_) => {
+                    TextEdit::new(ident.span, format!("{old_name}: {new_name}"))
+                }
+                AstKind::ExportSpecifier(specifier) if specifier.exported.span() == ident.span => {
+                    TextEdit::new(ident.span, format!("{new_name} as {old_name}"))
+                }
+                AstKind::ExportSpecifier(specifier)
+                    if module_export_identifier(&specifier.exported) == Some(new_name) =>
+                {
+                    TextEdit::new(
+                        Span::new(ident.span.start, specifier.exported.span().end),
+                        new_name,
+                    )
+                }
+                _ => TextEdit::new(ident.span, new_name),
+            };
+            edits.push(edit);
+        }
+
+        edits.sort_unstable_by_key(|edit| edit.span);
+        edits.dedup();
+        Ok(edits)
+    }
+
+    fn check_rename_conflicts(
+        &self,
+        symbol_id: SymbolId,
+        new_name: &str,
+    ) -> Result<(), OxcDiagnostic> {
+        let scoping = &self.scoping;
+        let symbol_scope = scoping.symbol_scope_id(symbol_id);
+        let is_inside_symbol_scope =
+            |scope_id: ScopeId| scoping.scope_ancestors(scope_id).any(|id| id == symbol_scope);
+
+        // another binding in the same scope
+        if let Some(other) = scoping.get_binding(symbol_scope, new_name)
+            && other != symbol_id
+        {
+            return Err(name_conflict(new_name, scoping.symbol_span(other)));
+        }
+
+        // a reference of the symbol would resolve to a binding declared in between
+        for reference in scoping.get_resolved_references(symbol_id) {
+            let scope_id = self.nodes.get_node(reference.node_id()).scope_id();
+            if let Some(other) = scoping.find_binding(scope_id, new_name)
+                && other != symbol_id
+                && is_inside_symbol_scope(scoping.symbol_scope_id(other))
+            {
+                return Err(name_conflict(new_name, scoping.symbol_span(other)));
+            }
+        }
+
+        // a reference to an outer binding or a global would resolve to the symbol
+        let outer_references = scoping
+            .symbol_ids()
+            .filter(|&other| {
+                scoping.symbol_name(other) == new_name
+                    && !is_inside_symbol_scope(scoping.symbol_scope_id(other))
+            })
+            .flat_map(|other| scoping.get_resolved_references(other));
+        let global_references = scoping
+            .root_unresolved_references()
+            .get(new_name)
+            .into_iter()
+            .flatten()
+            .map(|&reference_id| scoping.get_reference(reference_id));
+        for reference in outer_references.chain(global_references) {
+            let node = self.nodes.get_node(reference.node_id());
+            if is_inside_symbol_scope(node.scope_id()) {
+                return Err(name_conflict(new_name, node.kind().span()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn module_export_identifier<'a>(name: &ModuleExportName<'a>) -> Option<&'a str> {
+    match name {
+        ModuleExportName::IdentifierName(ident) => Some(ident.name.as_str()),
+        ModuleExportName::IdentifierReference(ident) => Some(ident.name.as_str()),
+        ModuleExportName::StringLiteral(_) => None,
+    }
+}
@@ -0,0 +1,428 @@
+use std::fmt::{self, Display, Write};
+
+use itertools::Itertools;
+
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{
+    node::NodeId,
+    reference::{ReferenceFlags, ReferenceId},
+    scope::{ScopeFlags, ScopeId},
+    symbol::{SymbolFlags, SymbolId},
+};
+
+use crate::Semantic;
+
+/// Owned snapshot of the scope tree, symbol table, and references of a [`Semantic`].
+///
+/// Intended for inspecting what semantic analysis produced, e.g. when writing a lint rule or
+/// reporting a bug. Scopes, symbols and references are ordered by ID, and flags by their
+/// declaration order, so the output is stable for the same input.
+///
+/// * [`SemanticSnapshot::to_json`] serializes the snapshot as pretty-printed JSON.
+/// * The [`Display`] implementation prints the scope tree with symbols and references nested
+///   inside the scope they are declared in.
+///
+/// # Example
+/// ```
+/// use oxc_allocator::Allocator;
+/// use oxc_parser::Parser;
+/// use oxc_semantic::SemanticBuilder;
+/// use oxc_span::SourceType;
+///
+/// let allocator = Allocator::default();
+/// let ret = Parser::new(&allocator, "let a = 1; a++;", SourceType::mjs()).parse();
+/// let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+/// let snapshot = semantic.snapshot();
+/// assert_eq!(snapshot.symbols[0].name, "a");
+/// println!("{snapshot}");
+/// println!("{}", snapshot.to_json());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SemanticSnapshot {
+    pub scopes: Vec<ScopeSnapshot>,
+    pub symbols: Vec<SymbolSnapshot>,
+    pub references: Vec<ReferenceSnapshot>,
+}
+
+/// A scope in a [`SemanticSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopeSnapshot {
+    pub id: ScopeId,
+    pub parent: Option<ScopeId>,
+    pub flags: ScopeFlags,
+    /// Debug name of the AST node which creates the scope.
+    pub node: String,
+    pub span: Span,
+    /// Symbols declared in the scope.
+    pub symbols: Vec<SymbolId>,
+    pub children: Vec<ScopeId>,
+}
+
+/// A symbol in a [`SemanticSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolSnapshot {
+    pub id: SymbolId,
+    pub name: String,
+    pub flags: SymbolFlags,
+    pub scope: ScopeId,
+    /// Debug name of the declaration AST node.
+    pub node: String,
+    /// Span of the declared identifier.
+    pub span: Span,
+    pub redeclarations: Vec<Span>,
+    /// Resolved references to the symbol.
+    pub references: Vec<ReferenceId>,
+}
+
+/// A reference in a [`SemanticSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceSnapshot {
+    pub id: ReferenceId,
+    pub name: String,
+    pub flags: ReferenceFlags,
+    /// Scope which contains the reference.
+    pub scope: ScopeId,
+    pub node_id: NodeId,
+    pub span: Span,
+    /// `None` if the reference is unresolved, e.g. a global.
+    pub symbol: Option<SymbolId>,
+}
+
+impl Semantic<'_> {
+    /// Take a [`SemanticSnapshot`] of the scope tree, symbol table, and references.
+    pub fn snapshot(&self) -> SemanticSnapshot {
+        let scoping = &self.scoping;
+        let nodes = &self.nodes;
+
+        let mut scopes = scoping
+            .scope_descendants_from_root()
+            .map(|scope_id| {
+                let node = nodes.get_node(scoping.get_node_id(scope_id));
+                let mut symbols = scoping.iter_bindings_in(scope_id).collect::<Vec<_>>();
+                symbols.sort_unstable();
+                ScopeSnapshot {
+                    id: scope_id,
+                    parent: scoping.scope_parent_id(scope_id),
+                    flags: scoping.scope_flags(scope_id),
+                    node: node.kind().debug_name().into_owned(),
+                    span: node.span(),
+                    symbols,
+                    children: vec![],
+                }
+            })
+            .collect::<Vec<_>>();
+        for scope_id in scoping.scope_descendants_from_root() {
+            if let Some(parent_id) = scoping.scope_parent_id(scope_id) {
+                scopes[parent_id.index()].children.push(scope_id);
+            }
+        }
+
+        let symbols = scoping
+            .symbol_ids()
+            .map(|symbol_id| SymbolSnapshot {
+                id: symbol_id,
+                name: scoping.symbol_name(symbol_id).to_string(),
+                flags: scoping.symbol_flags(symbol_id),
+                scope: scoping.symbol_scope_id(symbol_id),
+                node: nodes.kind(scoping.symbol_declaration(symbol_id)).debug_name().into_owned(),
+                span: scoping.symbol_span(symbol_id),
+                redeclarations: scoping
+                    .symbol_redeclarations(symbol_id)
+                    .iter()
+                    .map(|redeclaration| redeclaration.span)
+                    .collect(),
+                references: scoping.get_resolved_reference_ids(symbol_id).to_vec(),
+            })
+            .collect();
+
+        let references = scoping
+            .references
+            .iter_enumerated()
+            .map(|(reference_id, reference)| ReferenceSnapshot {
+                id: reference_id,
+                name: self.reference_name(reference).to_string(),
+                flags: reference.flags(),
+                scope: nodes.get_node(reference.node_id()).scope_id(),
+                node_id: reference.node_id(),
+                span: self.reference_span(reference),
+                symbol: reference.symbol_id(),
+            })
+            .collect();
+
+        SemanticSnapshot { scopes, symbols, references }
+    }
+}
+
+impl SemanticSnapshot {
+    /// Serialize the snapshot as pretty-printed JSON.
+    ///
+    /// IDs are numbers, flags are arrays of flag names, and spans are `{ "start", "end" }`
+    /// objects. Unresolved references have a `null` symbol.
+    pub fn to_json(&self) -> String {
+        let mut json = JsonWriter::default();
+        json.open('{');
+        json.key("scopes");
+        json.array(&self.scopes, |json, scope| {
+            json.open('{');
+            json.key("id");
+            json.number(scope.id.index());
+            json.key("parent");
+            match scope.parent {
+                Some(parent) => json.number(parent.index()),
+                None => json.raw("null"),
+            }
+            json.key("flags");
+            json.flags(scope.flags.iter_names().map(|(name, _)| name));
+            json.key("node");
+            json.string(&scope.node);
+            json.key("span");
+            json.span(scope.span);
+            json.key("symbols");
+            json.array(&scope.symbols, |json, symbol| json.number(symbol.index()));
+            json.key("children");
+            json.array(&scope.children, |json, child| json.number(child.index()));
+            json.close('}');
+        });
+        json.key("symbols");
+        json.array(&self.symbols, |json, symbol| {
+            json.open('{');
+            json.key("id");
+            json.number(symbol.id.index());
+            json.key("name");
+            json.string(&symbol.name);
+            json.key("flags");
+            json.flags(symbol.flags.iter_names().map(|(name, _)| name));
+            json.key("scope");
+            json.number(symbol.scope.index());
+            json.key("node");
+            json.string(&symbol.node);
+            json.key("span");
+            json.span(symbol.span);
+            json.key("redeclarations");
+            json.array(&symbol.redeclarations, |json, span| json.span(*span));
+            json.key("references");
+            json.array(&symbol.references, |json, reference| json.number(reference.index()));
+            json.close('}');
+        });
+        json.key("references");
+        json.array(&self.references, |json, reference| {
+            json.open('{');
+            json.key("id");
+            json.number(reference.id.index());
+            json.key("name");
+            json.string(&reference.name);
+            json.key("flags");
+            json.flags(reference.flags.iter_names().map(|(name, _)| name));
+            json.key("scope");
+            json.number(reference.scope.index());
+            json.key("nodeId");
+            json.number(reference.node_id.index());
+            json.key("span");
+            json.span(reference.span);
+            json.key("symbol");
+            match reference.symbol {
+                Some(symbol) => json.number(symbol.index()),
+                None => json.raw("null"),
+            }
+            json.close('}');
+        });
+        json.close('}');
+        json.output
+    }
+
+    fn fmt_scope(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        scope: &ScopeSnapshot,
+        depth: usize,
+    ) -> fmt::Result {
+        let indent = "  ".repeat(depth);
+        writeln!(
+            f,
+            "{indent}scope {} {} [{}] {}",
+            scope.id.index(),
+            scope.node,
+            scope.flags.iter_names().map(|(name, _)| name).join(" | "),
+            SpanRange(scope.span),
+        )?;
+        for symbol in scope.symbols.iter().filter_map(|id| self.symbols.get(id.index())) {
+            writeln!(
+                f,
+                "{indent}  symbol {} `{}` [{}] {}",
+                symbol.id.index(),
+                symbol.name,
+                symbol.flags.iter_names().map(|(name, _)| name).join(" | "),
+                SpanRange(symbol.span),
+            )?;
+            for span in &symbol.redeclarations {
+                writeln!(f, "{indent}    redeclaration {}", SpanRange(*span))?;
+            }
+            for reference in
+                symbol.references.iter().filter_map(|id| self.references.get(id.index()))
+            {
+                Self::fmt_reference(f, reference, depth + 2)?;
+            }
+        }
+        for child in scope.children.iter().filter_map(|id| self.scopes.get(id.index())) {
+            self.fmt_scope(f, child, depth + 1)?;
+        }
+        Ok(())
+    }
+
+    fn fmt_reference(
+        f: &mut fmt::Formatter<'_>,
+        reference: &ReferenceSnapshot,
+        depth: usize,
+    ) -> fmt::Result {
+        writeln!(
+            f,
+            "{}reference {} `{}` [{}] {}",
+            "  ".repeat(depth),
+            reference.id.index(),
+            reference.name,
+            reference.flags.iter_names().map(|(name, _)| name).join(" | "),
+            SpanRange(reference.span),
+        )
+    }
+}
+
+impl Display for SemanticSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for scope in self.scopes.iter().filter(|scope| scope.parent.is_none()) {
+            self.fmt_scope(f, scope, 0)?;
+        }
+        let mut unresolved =
+            self.references.iter().filter(|reference| reference.symbol.is_none()).peekable();
+        if unresolved.peek().is_some() {
+            writeln!(f, "unresolved")?;
+            for reference in unresolved {
+                Self::fmt_reference(f, reference, 1)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+struct SpanRange(Span);
+
+impl Display for SpanRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.0.start, self.0.end)
+    }
+}
+
+/// Minimal pretty-printing JSON writer, which indents nested objects and arrays by 2 spaces.
+#[derive(Default)]
+struct JsonWriter {
+    output: String,
+    depth: usize,
+    /// Whether the current object or array has no entries yet.
+    is_empty: bool,
+    /// Whether a key was just written, so the next value continues the same line.
+    after_key: bool,
+}
+
+impl JsonWriter {
+    fn open(&mut self, bracket: char) {
+        self.begin_value();
+        self.output.push(bracket);
+        self.depth += 1;
+        self.is_empty = true;
+    }
+
+    fn close(&mut self, bracket: char) {
+        self.depth -= 1;
+        if !self.is_empty {
+            self.newline();
+        }
+        self.output.push(bracket);
+        self.is_empty = false;
+    }
+
+    fn key(&mut self, key: &str) {
+        self.begin_value();
+        self.write_string(key);
+        self.output.push_str(": ");
+        self.after_key = true;
+    }
+
+    fn raw(&mut self, value: &str) {
+        self.begin_value();
+        self.output.push_str(value);
+    }
+
+    fn number(&mut self, value: usize) {
+        self.begin_value();
+        write!(self.output, "{value}").unwrap();
+    }
+
+    fn string(&mut self, value: &str) {
+        self.begin_value();
+        self.write_string(value);
+    }
+
+    fn span(&mut self, span: Span) {
+        self.begin_value();
+        write!(self.output, "{{ \"start\": {}, \"end\": {} }}", span.start, span.end).unwrap();
+    }
+
+    fn flags<'a>(&mut self, names: impl Iterator<Item = &'a str>) {
+        self.begin_value();
+        self.output.push('[');
+        for (index, name) in names.enumerate() {
+            if index != 0 {
+                self.output.push_str(", ");
+            }
+            self.write_string(name);
+        }
+        self.output.push(']');
+    }
+
+    fn array<T>(&mut self, items: &[T], mut write_item: impl FnMut(&mut Self, &T)) {
+        self.open('[');
+        for item in items {
+            write_item(self, item);
+        }
+        self.close(']');
+    }
+
+    /// Write the separator before a value: nothing after a key, otherwise a comma if needed,
+    /// and a newline.
+    fn begin_value(&mut self) {
+        if self.after_key {
+            self.after_key = false;
+            return;
+        }
+        if self.depth == 0 {
+            return;
+        }
+        if !self.is_empty {
+            self.output.push(',');
+        }
+        self.is_empty = false;
+        self.newline();
+    }
+
+    fn newline(&mut self) {
+        self.output.push('\n');
+        for _ in 0..self.depth {
+            self.output.push_str("  ");
+        }
+    }
+
+    fn write_string(&mut self, value: &str) {
+        self.output.push('"');
+        for c in value.chars() {
+            match c {
+                '"' => self.output.push_str("\\\""),
+                '\\' => self.output.push_str("\\\\"),
+                '\n' => self.output.push_str("\\n"),
+                '\r' => self.output.push_str("\\r"),
+                '\t' => self.output.push_str("\\t"),
+                c if c.is_control() => write!(self.output, "\\u{:04x}", c as u32).unwrap(),
+                c => self.output.push(c),
+            }
+        }
+        self.output.push('"');
+    }
+}
@@ -0,0 +1,966 @@
+//! Lightweight, local type inference.
+//!
+//! Infers the possible runtime types of an expression from its syntax alone: literals,
+//! operators, calls to well-known builtins, declarations whose value can be traced, and
+//! `typeof`, equality and truthiness guards on the branches enclosing a reference.
+//! This is not a type checker. Anything it can't work out is [`InferredType::UNKNOWN`], and type
+//! annotations are trusted, so results are sound for code which type checks, but often imprecise.
+
+use std::fmt;
+
+use bitflags::bitflags;
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        AssignmentExpression, AssignmentOperator, AssignmentTarget, BinaryExpression,
+        BindingPattern, CallExpression, ChainElement, Expression, IdentifierReference,
+        LogicalExpression, LogicalOperator, NewExpression, SimpleAssignmentTarget,
+        StaticMemberExpression, TSLiteral, TSType, TSTypeName, UnaryExpression, UnaryOperator,
+        UpdateExpression, VariableDeclarationKind,
+    },
+};
+use oxc_span::GetSpan;
+use oxc_syntax::{node::NodeId, operator::BinaryOperator, symbol::SymbolId};
+
+use crate::Semantic;
+
+/// Traced declarations referring to each other deeper than this are [`InferredType::UNKNOWN`].
+const MAX_DEPTH: usize = 16;
+
+bitflags! {
+    /// Set of runtime types a value may have.
+    ///
+    /// Objects are split into a few kinds which rules commonly care about.
+    /// [`InferredType::empty`] means the value can't exist, e.g. inside a contradictory guard.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct InferredType: u16 {
+        const Undefined = 1 << 0;
+        const Null      = 1 << 1;
+        const Boolean   = 1 << 2;
+        const Number    = 1 << 3;
+        const BigInt    = 1 << 4;
+        const String    = 1 << 5;
+        const Symbol    = 1 << 6;
+        /// Functions and classes.
+        const Function  = 1 << 7;
+        const Array     = 1 << 8;
+        const RegExp    = 1 << 9;
+        const Promise   = 1 << 10;
+        /// Any other object.
+        const Object    = 1 << 11;
+
+        const Nullish = Self::Undefined.bits() | Self::Null.bits();
+        const Numeric = Self::Number.bits() | Self::BigInt.bits();
+        const Primitive = Self::Nullish.bits() | Self::Boolean.bits() | Self::Numeric.bits()
+            | Self::String.bits() | Self::Symbol.bits();
+        /// Objects, including functions.
+        const AnyObject = Self::Function.bits() | Self::Array.bits() | Self::RegExp.bits()
+            | Self::Promise.bits() | Self::Object.bits();
+        /// Values which are never falsy.
+        const AlwaysTruthy = Self::Symbol.bits() | Self::AnyObject.bits();
+        const UNKNOWN = Self::Primitive.bits() | Self::AnyObject.bits();
+    }
+}
+
+impl InferredType {
+    /// Whether nothing is known about the value.
+    #[inline]
+    pub fn is_unknown(self) -> bool {
+        self == Self::UNKNOWN
+    }
+
+    /// Whether the value is definitely of one of the types in `other`.
+    #[inline]
+    pub fn is_only(self, other: Self) -> bool {
+        !self.is_empty() && other.contains(self)
+    }
+
+    /// Types which `typeof` evaluates to `tag` for, or `None` if `tag` is not a valid result of `typeof`.
+    pub fn from_typeof_tag(tag: &str) -> Option<Self> {
+        let ty = match tag {
+            "undefined" => Self::Undefined,
+            "boolean" => Self::Boolean,
+            "number" => Self::Number,
+            "bigint" => Self::BigInt,
+            "string" => Self::String,
+            "symbol" => Self::Symbol,
+            "function" => Self::Function,
+            "object" => Self::Null | Self::Array | Self::RegExp | Self::Promise | Self::Object,
+            _ => return None,
+        };
+        Some(ty)
+    }
+}
+
+impl fmt::Display for InferredType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_unknown() {
+            return f.write_str("unknown");
+        }
+        if self.is_empty() {
+            return f.write_str("never");
+        }
+        let names = [
+            (Self::Undefined, "undefined"),
+            (Self::Null, "null"),
+            (Self::Boolean, "boolean"),
+            (Self::Number, "number"),
+            (Self::BigInt, "bigint"),
+            (Self::String, "string"),
+            (Self::Symbol, "symbol"),
+            (Self::Function, "function"),
+            (Self::Array, "array"),
+            (Self::RegExp, "regexp"),
+            (Self::Promise, "promise"),
+            (Self::Object, "object"),
+        ];
+        let mut first = true;
+        for (ty, name) in names {
+            if self.contains(ty) {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_str(name)?;
+                first = false;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Semantic<'_> {
+    /// Infer the possible runtime types of the value of the node `node_id`.
+    ///
+    /// Supports expressions, and the declarations of variables (`BindingIdentifier`,
+    /// `VariableDeclarator`, `Function`, `Class`). Returns [`InferredType::UNKNOWN`] for any other
+    /// node, and wherever the type can't be determined locally.
+    ///
+    /// References are narrowed by guards such as `typeof x === "string"`, `x !== null`,
+    /// `Array.isArray(x)` or `x &&` on enclosing `if` statements, loops, conditional and logical
+    /// expressions, as long as the variable is never reassigned.
+    pub fn type_of(&self, node_id: NodeId) -> InferredType {
+        TypeInference { semantic: self, visiting: vec![] }.node(node_id)
+    }
+}
+
+struct TypeInference<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+    /// Symbols whose declared type is being inferred, to break cycles.
+    visiting: Vec<SymbolId>,
+}
+
+impl<'a> TypeInference<'_, 'a> {
+    fn node(&mut self, node_id: NodeId) -> InferredType {
+        match self.semantic.nodes().kind(node_id) {
+            AstKind::BooleanLiteral(_) | AstKind::PrivateInExpression(_) => InferredType::Boolean,
+            AstKind::NullLiteral(_) => InferredType::Null,
+            AstKind::NumericLiteral(_) => InferredType::Number,
+            AstKind::BigIntLiteral(_) => InferredType::BigInt,
+            AstKind::StringLiteral(_) | AstKind::TemplateLiteral(_) => InferredType::String,
+            AstKind::RegExpLiteral(_) => InferredType::RegExp,
+            AstKind::ArrayExpression(_) => InferredType::Array,
+            AstKind::ObjectExpression(_) => InferredType::Object,
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) | AstKind::Class(_) => {
+                InferredType::Function
+            }
+            AstKind::IdentifierReference(ident) => self.identifier_reference(ident),
+            AstKind::BindingIdentifier(ident) => ident
+                .symbol_id
+                .get()
+                .map_or(InferredType::UNKNOWN, |symbol_id| self.symbol(symbol_id)),
+            AstKind::VariableDeclarator(declarator) => declarator
+                .id
+                .get_binding_identifier()
+                .and_then(|ident| ident.symbol_id.get())
+                .map_or(InferredType::UNKNOWN, |symbol_id| self.symbol(symbol_id)),
+            AstKind::UnaryExpression(expr) => self.unary(expr),
+            AstKind::BinaryExpression(expr) => self.binary(expr),
+            AstKind::LogicalExpression(expr) => self.logical(expr),
+            AstKind::AssignmentExpression(expr) => self.assignment(expr),
+            AstKind::UpdateExpression(expr) => self.update(expr),
+            AstKind::ConditionalExpression(expr) => {
+                self.expression(&expr.consequent) | self.expression(&expr.alternate)
+            }
+            AstKind::SequenceExpression(expr) => {
+                expr.expressions.last().map_or(InferredType::UNKNOWN, |expr| self.expression(expr))
+            }
+            AstKind::ParenthesizedExpression(expr) => self.expression(&expr.expression),
+            AstKind::CallExpression(expr) => self.call(expr),
+            AstKind::NewExpression(expr) => self.new_expression(expr),
+            AstKind::StaticMemberExpression(expr) => self.static_member(expr),
+            AstKind::ChainExpression(expr) => self.chain_element(&expr.expression),
+            AstKind::TSAsExpression(expr) => {
+                self.assertion(&expr.type_annotation, &expr.expression)
+            }
+            AstKind::TSSatisfiesExpression(expr) => self.expression(&expr.expression),
+            AstKind::TSTypeAssertion(expr) => {
+                self.assertion(&expr.type_annotation, &expr.expression)
+            }
+            AstKind::TSNonNullExpression(expr) => {
+                self.expression(&expr.expression) - InferredType::Nullish
+            }
+            _ => InferredType::UNKNOWN,
+        }
+    }
+
+    fn expression(&mut self, expr: &Expression<'a>) -> InferredType {
+        match expr {
+            Expression::BooleanLiteral(_) | Expression::PrivateInExpression(_) => {
+                InferredType::Boolean
+            }
+            Expression::NullLiteral(_) => InferredType::Null,
+            Expression::NumericLiteral(_) => InferredType::Number,
+            Expression::BigIntLiteral(_) => InferredType::BigInt,
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => InferredType::String,
+            Expression::RegExpLiteral(_) => InferredType::RegExp,
+            Expression::ArrayExpression(_) => InferredType::Array,
+            Expression::ObjectExpression(_) => InferredType::Object,
+            Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::ClassExpression(_) => InferredType::Function,
+            Expression::Identifier(ident) => self.identifier_reference(ident),
+            Expression::UnaryExpression(expr) => self.unary(expr),
+            Expression::BinaryExpression(expr) => self.binary(expr),
+            Expression::LogicalExpression(expr) => self.logical(expr),
+            Expression::AssignmentExpression(expr) => self.assignment(expr),
+            Expression::UpdateExpression(expr) => self.update(expr),
+            Expression::ConditionalExpression(expr) => {
+                self.expression(&expr.consequent) | self.expression(&expr.alternate)
+            }
+            Expression::SequenceExpression(expr) => {
+                expr.expressions.last().map_or(InferredType::UNKNOWN, |expr| self.expression(expr))
+            }
+            Expression::ParenthesizedExpression(expr) => self.expression(&expr.expression),
+            Expression::CallExpression(expr) => self.call(expr),
+            Expression::NewExpression(expr) => self.new_expression(expr),
+            Expression::StaticMemberExpression(expr) => self.static_member(expr),
+            Expression::ChainExpression(expr) => self.chain_element(&expr.expression),
+            Expression::TSAsExpression(expr) => {
+                self.assertion(&expr.type_annotation, &expr.expression)
+            }
+            Expression::TSSatisfiesExpression(expr) => self.expression(&expr.expression),
+            Expression::TSTypeAssertion(expr) => {
+                self.assertion(&expr.type_annotation, &expr.expression)
+            }
+            Expression::TSNonNullExpression(expr) => {
+                self.expression(&expr.expression) - InferredType::Nullish
+            }
+            _ => InferredType::UNKNOWN,
+        }
+    }
+
+    fn chain_element(&mut self, element: &ChainElement<'a>) -> InferredType {
+        // Optional chains evaluate to `undefined` when short-circuited
+        let ty = match element {
+            ChainElement::CallExpression(expr) => self.call(expr),
+            ChainElement::StaticMemberExpression(expr) => self.static_member(expr),
+            ChainElement::TSNonNullExpression(expr) => {
+                self.expression(&expr.expression) - InferredType::Nullish
+            }
+            _ => InferredType::UNKNOWN,
+        };
+        ty | InferredType::Undefined
+    }
+
+    /// `expr as T`. Trusts `T` if it's understood.
+    fn assertion(&mut self, ty: &TSType<'a>, expr: &Expression<'a>) -> InferredType {
+        let asserted = ts_type(ty);
+        if asserted.is_unknown() { self.expression(expr) } else { asserted }
+    }
+
+    fn identifier_reference(&mut self, ident: &IdentifierReference<'a>) -> InferredType {
+        let Some(reference_id) = ident.reference_id.get() else {
+            return InferredType::UNKNOWN;
+        };
+        let reference = self.semantic.scoping().get_reference(reference_id);
+        let Some(symbol_id) = reference.symbol_id() else {
+            return match ident.name.as_str() {
+                "undefined" => InferredType::Undefined,
+                "NaN" | "Infinity" => InferredType::Number,
+                _ => InferredType::UNKNOWN,
+            };
+        };
+        let mut ty = self.symbol(symbol_id);
+        if self.may_read_before_initialization(reference.node_id(), symbol_id) {
+            ty |= InferredType::Undefined;
+        }
+        if self.semantic.scoping().symbol_is_mutated(symbol_id) {
+            return ty;
+        }
+        self.narrow_by_guards(ty, reference.node_id(), symbol_id)
+    }
+
+    /// Whether the read at `node_id` may run before the `var` declaration of `symbol_id` assigned
+    /// its initial value, while the variable is still `undefined`: reads before the declaration,
+    /// reads in functions which may be called before it, and declarations which may be skipped.
+    ///
+    /// Reads of `let`, `const` and classes before their declaration throw instead.
+    fn may_read_before_initialization(&self, node_id: NodeId, symbol_id: SymbolId) -> bool {
+        let nodes = self.semantic.nodes();
+        let declaration = self.semantic.symbol_declaration(symbol_id);
+        let AstKind::VariableDeclarator(declarator) = declaration.kind() else {
+            return false;
+        };
+        if declarator.kind != VariableDeclarationKind::Var || declarator.init.is_none() {
+            return false;
+        }
+        let read = nodes.get_node(node_id);
+        if read.span().start < declarator.span.end {
+            return true;
+        }
+
+        // The declaration runs before any later read of the same function only if it isn't
+        // nested in a conditional, loop or block.
+        let statement = nodes.parent_node(declaration.id());
+        let mut parent = nodes.parent_node(statement.id());
+        loop {
+            match parent.kind() {
+                AstKind::ForStatement(_) | AstKind::ExportNamedDeclaration(_) => {
+                    parent = nodes.parent_node(parent.id());
+                }
+                AstKind::Program(_) | AstKind::FunctionBody(_) => break,
+                _ => return true,
+            }
+        }
+
+        let scoping = self.semantic.scoping();
+        let var_scope_id = scoping
+            .scope_ancestors(read.scope_id())
+            .find(|scope_id| scoping.scope_flags(*scope_id).is_var());
+        var_scope_id != Some(scoping.symbol_scope_id(symbol_id))
+    }
+
+    /// Type of every value the symbol `symbol_id` may hold.
+    fn symbol(&mut self, symbol_id: SymbolId) -> InferredType {
+        if self.visiting.contains(&symbol_id) || self.visiting.len() >= MAX_DEPTH {
+            return InferredType::UNKNOWN;
+        }
+        if !self.semantic.scoping().symbol_redeclarations(symbol_id).is_empty() {
+            return InferredType::UNKNOWN;
+        }
+        self.visiting.push(symbol_id);
+        let ty = self.declared_symbol(symbol_id);
+        self.visiting.pop();
+        ty
+    }
+
+    fn declared_symbol(&mut self, symbol_id: SymbolId) -> InferredType {
+        let declaration = self.semantic.symbol_declaration(symbol_id);
+        let declared = match declaration.kind() {
+            AstKind::VariableDeclarator(declarator) => {
+                // Destructured bindings are not traced
+                let BindingPattern::BindingIdentifier(_) = &declarator.id else {
+                    return InferredType::UNKNOWN;
+                };
+                if let Some(annotation) = &declarator.type_annotation {
+                    ts_type(&annotation.type_annotation)
+                } else {
+                    match &declarator.init {
+                        Some(init) => self.expression(init),
+                        None => InferredType::Undefined,
+                    }
+                }
+            }
+            AstKind::FormalParameter(param) => {
+                let BindingPattern::BindingIdentifier(_) = &param.pattern else {
+                    return InferredType::UNKNOWN;
+                };
+                let Some(annotation) = &param.type_annotation else {
+                    return InferredType::UNKNOWN;
+                };
+                let ty = ts_type(&annotation.type_annotation);
+                if param.optional && param.initializer.is_none() {
+                    ty | InferredType::Undefined
+                } else {
+                    ty
+                }
+            }
+            AstKind::FormalParameterRest(_) => return InferredType::Array,
+            AstKind::Function(_) | AstKind::Class(_) => InferredType::Function,
+            _ => return InferredType::UNKNOWN,
+        };
+
+        // Add the values of all assignments and updates.
+        // Values are not tracked through other kinds of writes, e.g. destructuring.
+        let mut ty = declared;
+        let mut compound_assignments = vec![];
+        let mut has_update = false;
+        for reference in self.semantic.scoping().get_resolved_references(symbol_id) {
+            if !reference.is_write() {
+                continue;
+            }
+            match self.semantic.nodes().parent_kind(reference.node_id()) {
+                AstKind::AssignmentExpression(assignment) => {
+                    let right = self.expression(&assignment.right);
+                    match assignment.operator.to_binary_operator() {
+                        Some(operator) => compound_assignments.push((operator, right)),
+                        // `=`, or a logical assignment which assigns either the old value or `right`
+                        None => ty |= right,
+                    }
+                }
+                AstKind::UpdateExpression(_) => has_update = true,
+                _ => return InferredType::UNKNOWN,
+            }
+        }
+
+        // Compound assignments and updates depend on the previous value.
+        // Apply them until the type stops growing, which happens within a few iterations.
+        loop {
+            let mut next = ty;
+            for &(operator, right) in &compound_assignments {
+                next |= binary_operation(operator, ty, right);
+            }
+            if has_update {
+                next |= to_numeric(ty);
+            }
+            if next == ty {
+                return ty;
+            }
+            ty = next;
+        }
+    }
+
+    fn unary(&mut self, expr: &UnaryExpression<'a>) -> InferredType {
+        match expr.operator {
+            UnaryOperator::Void => InferredType::Undefined,
+            UnaryOperator::LogicalNot | UnaryOperator::Delete => InferredType::Boolean,
+            UnaryOperator::Typeof => InferredType::String,
+            UnaryOperator::UnaryPlus => InferredType::Number,
+            UnaryOperator::UnaryNegation | UnaryOperator::BitwiseNot => {
+                to_numeric(self.expression(&expr.argument))
+            }
+        }
+    }
+
+    fn update(&mut self, expr: &UpdateExpression<'a>) -> InferredType {
+        match &expr.argument {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                to_numeric(self.assignment_target(ident))
+            }
+            _ => InferredType::Numeric,
+        }
+    }
+
+    fn binary(&mut self, expr: &BinaryExpression<'a>) -> InferredType {
+        if expr.operator.is_equality()
+            || expr.operator.is_compare()
+            || expr.operator.is_relational()
+        {
+            return InferredType::Boolean;
+        }
+        let left = self.expression(&expr.left);
+        let right = self.expression(&expr.right);
+        binary_operation(expr.operator, left, right)
+    }
+
+    fn logical(&mut self, expr: &LogicalExpression<'a>) -> InferredType {
+        let left = self.expression(&expr.left);
+        match expr.operator {
+            // Falsy values of `left` are returned as is
+            LogicalOperator::And if left.is_only(InferredType::AlwaysTruthy) => {
+                self.expression(&expr.right)
+            }
+            LogicalOperator::And => {
+                (left - InferredType::AlwaysTruthy) | self.expression(&expr.right)
+            }
+            // Truthy values of `left` are returned as is.
+            // Falsy values may be of any primitive type other than symbol.
+            LogicalOperator::Or if left.is_only(InferredType::AlwaysTruthy) => left,
+            LogicalOperator::Or => left | self.expression(&expr.right),
+            LogicalOperator::Coalesce if !left.intersects(InferredType::Nullish) => left,
+            LogicalOperator::Coalesce => {
+                (left - InferredType::Nullish) | self.expression(&expr.right)
+            }
+        }
+    }
+
+    fn assignment(&mut self, expr: &AssignmentExpression<'a>) -> InferredType {
+        let right = self.expression(&expr.right);
+        let Some(operator) = expr.operator.to_binary_operator() else {
+            return match expr.operator {
+                AssignmentOperator::Assign => right,
+                // Logical assignments
+                _ => InferredType::UNKNOWN,
+            };
+        };
+        let left = match &expr.left {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => self.assignment_target(ident),
+            _ => InferredType::UNKNOWN,
+        };
+        binary_operation(operator, left, right)
+    }
+
+    /// Type of the variable `ident` refers to, without narrowing.
+    fn assignment_target(&mut self, ident: &IdentifierReference<'a>) -> InferredType {
+        ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| {
+                self.semantic.scoping().get_reference(reference_id).symbol_id()
+            })
+            .map_or(InferredType::UNKNOWN, |symbol_id| self.symbol(symbol_id))
+    }
+
+    fn static_member(&mut self, expr: &StaticMemberExpression<'a>) -> InferredType {
+        if expr.property.name == "length"
+            && self.expression(&expr.object).is_only(InferredType::String | InferredType::Array)
+        {
+            return InferredType::Number;
+        }
+        if let Some(object) = self.global_name(&expr.object) {
+            return match (object, expr.property.name.as_str()) {
+                (
+                    "Number",
+                    "MAX_VALUE" | "MIN_VALUE" | "MAX_SAFE_INTEGER" | "MIN_SAFE_INTEGER" | "EPSILON"
+                    | "POSITIVE_INFINITY" | "NEGATIVE_INFINITY" | "NaN",
+                )
+                | ("Math", _) => InferredType::Number,
+                _ => InferredType::UNKNOWN,
+            };
+        }
+        InferredType::UNKNOWN
+    }
+
+    fn new_expression(&self, expr: &NewExpression<'a>) -> InferredType {
+        // Constructors always return an object
+        match self.global_name(&expr.callee) {
+            Some("Array") => InferredType::Array,
+            Some("RegExp") => InferredType::RegExp,
+            Some("Promise") => InferredType::Promise,
+            Some("Function") => InferredType::Function,
+            Some(
+                "Object" | "Date" | "Map" | "Set" | "WeakMap" | "WeakSet" | "WeakRef" | "Error"
+                | "TypeError" | "RangeError" | "SyntaxError" | "ReferenceError" | "URL"
+                | "URLSearchParams" | "ArrayBuffer" | "DataView",
+            ) => InferredType::Object,
+            _ => InferredType::AnyObject,
+        }
+    }
+
+    /// Return types of well-known builtin functions and methods.
+    fn call(&mut self, expr: &CallExpression<'a>) -> InferredType {
+        if let Some(name) = self.global_name(&expr.callee) {
+            return match name {
+                "String" | "encodeURI" | "encodeURIComponent" | "decodeURI"
+                | "decodeURIComponent" | "escape" | "unescape" => InferredType::String,
+                "Number" | "parseInt" | "parseFloat" => InferredType::Number,
+                "Boolean" | "isNaN" | "isFinite" => InferredType::Boolean,
+                "BigInt" => InferredType::BigInt,
+                "Symbol" => InferredType::Symbol,
+                "Array" => InferredType::Array,
+                "RegExp" => InferredType::RegExp,
+                "Function" => InferredType::Function,
+                "Object" => InferredType::AnyObject,
+                _ => InferredType::UNKNOWN,
+            };
+        }
+
+        let Some(member) = expr.callee.get_member_expr() else {
+            return InferredType::UNKNOWN;
+        };
+        let Some(method) = member.static_property_name() else {
+            return InferredType::UNKNOWN;
+        };
+        if let Some(object) = self.global_name(member.object()) {
+            return static_method(object, method);
+        }
+
+        let receiver = self.expression(member.object());
+        if receiver.is_only(InferredType::String) {
+            string_method(method)
+        } else if receiver.is_only(InferredType::Array) {
+            array_method(method)
+        } else if receiver.is_only(InferredType::Numeric) {
+            match method {
+                "toString" | "toFixed" | "toExponential" | "toPrecision" | "toLocaleString" => {
+                    InferredType::String
+                }
+                _ => InferredType::UNKNOWN,
+            }
+        } else if receiver.is_only(InferredType::Promise) {
+            match method {
+                "then" | "catch" | "finally" => InferredType::Promise,
+                _ => InferredType::UNKNOWN,
+            }
+        } else if receiver.is_only(InferredType::RegExp) {
+            match method {
+                "test" => InferredType::Boolean,
+                "exec" => InferredType::Array | InferredType::Null,
+                "toString" => InferredType::String,
+                _ => InferredType::UNKNOWN,
+            }
+        } else if receiver.is_only(InferredType::Function) && method == "bind" {
+            InferredType::Function
+        } else {
+            InferredType::UNKNOWN
+        }
+    }
+
+    /// Name of `expr`, if it's a reference to a global variable.
+    fn global_name<'e>(&self, expr: &'e Expression<'a>) -> Option<&'e str> {
+        let ident = expr.get_identifier_reference()?;
+        let reference_id = ident.reference_id.get()?;
+        self.semantic
+            .scoping()
+            .get_reference(reference_id)
+            .symbol_id()
+            .is_none()
+            .then_some(ident.name.as_str())
+    }
+
+    /// Narrow `ty`, the type of the reference to `symbol_id` at `node_id`, by the guards on the
+    /// branches it's inside of.
+    fn narrow_by_guards(
+        &mut self,
+        mut ty: InferredType,
+        node_id: NodeId,
+        symbol_id: SymbolId,
+    ) -> InferredType {
+        let nodes = self.semantic.nodes();
+        let mut child_span = nodes.get_node(node_id).span();
+        for ancestor in nodes.ancestors(node_id) {
+            let guard = match ancestor.kind() {
+                AstKind::IfStatement(stmt) => {
+                    if stmt.consequent.span() == child_span {
+                        Some((&stmt.test, true))
+                    } else if stmt.alternate.as_ref().is_some_and(|alt| alt.span() == child_span) {
+                        Some((&stmt.test, false))
+                    } else {
+                        None
+                    }
+                }
+                AstKind::ConditionalExpression(expr) => {
+                    if expr.consequent.span() == child_span {
+                        Some((&expr.test, true))
+                    } else if expr.alternate.span() == child_span {
+                        Some((&expr.test, false))
+                    } else {
+                        None
+                    }
+                }
+                AstKind::LogicalExpression(expr) if expr.right.span() == child_span => {
+                    match expr.operator {
+                        LogicalOperator::And => Some((&expr.left, true)),
+                        LogicalOperator::Or => Some((&expr.left, false)),
+                        LogicalOperator::Coalesce => None,
+                    }
+                }
+                AstKind::WhileStatement(stmt) if stmt.body.span() == child_span => {
+                    Some((&stmt.test, true))
+                }
+                AstKind::ForStatement(stmt) if stmt.body.span() == child_span => {
+                    stmt.test.as_ref().map(|test| (test, true))
+                }
+                _ => None,
+            };
+            if let Some((test, assume_true)) = guard {
+                ty = self.narrow(ty, test, symbol_id, assume_true);
+            }
+            child_span = ancestor.span();
+        }
+        ty
+    }
+
+    /// Narrow `ty`, the type of `symbol_id`, assuming `test` evaluates to `assume_true`.
+    fn narrow(
+        &mut self,
+        ty: InferredType,
+        test: &Expression<'a>,
+        symbol_id: SymbolId,
+        assume_true: bool,
+    ) -> InferredType {
+        match test.without_parentheses() {
+            Expression::UnaryExpression(expr) if expr.operator == UnaryOperator::LogicalNot => {
+                self.narrow(ty, &expr.argument, symbol_id, !assume_true)
+            }
+            Expression::LogicalExpression(expr) => match (expr.operator, assume_true) {
+                // Both are true
+                (LogicalOperator::And, true) => {
+                    let ty = self.narrow(ty, &expr.left, symbol_id, true);
+                    self.narrow(ty, &expr.right, symbol_id, true)
+                }
+                // Left is false, or left is true and right is false
+                (LogicalOperator::And, false) => {
+                    let left_false = self.narrow(ty, &expr.left, symbol_id, false);
+                    let left_true = self.narrow(ty, &expr.left, symbol_id, true);
+                    left_false | self.narrow(left_true, &expr.right, symbol_id, false)
+                }
+                // Left is true, or left is false and right is true
+                (LogicalOperator::Or, true) => {
+                    let left_true = self.narrow(ty, &expr.left, symbol_id, true);
+                    let left_false = self.narrow(ty, &expr.left, symbol_id, false);
+                    left_true | self.narrow(left_false, &expr.right, symbol_id, true)
+                }
+                // Both are false
+                (LogicalOperator::Or, false) => {
+                    let ty = self.narrow(ty, &expr.left, symbol_id, false);
+                    self.narrow(ty, &expr.right, symbol_id, false)
+                }
+                (LogicalOperator::Coalesce, _) => ty,
+            },
+            expr if self.refers_to(expr, symbol_id) => {
+                if assume_true {
+                    ty - InferredType::Nullish
+                } else {
+                    ty - InferredType::AlwaysTruthy
+                }
+            }
+            Expression::BinaryExpression(expr) if expr.operator.is_equality() => {
+                let equal = matches!(
+                    expr.operator,
+                    BinaryOperator::StrictEquality | BinaryOperator::Equality
+                ) == assume_true;
+                let strict = matches!(
+                    expr.operator,
+                    BinaryOperator::StrictEquality | BinaryOperator::StrictInequality
+                );
+                self.narrow_equality(ty, &expr.left, &expr.right, symbol_id, equal, strict)
+                    .or_else(|| {
+                        self.narrow_equality(ty, &expr.right, &expr.left, symbol_id, equal, strict)
+                    })
+                    .unwrap_or(ty)
+            }
+            // `Array.isArray(x)`
+            Expression::CallExpression(expr)
+                if expr.arguments.len() == 1
+                    && expr.arguments[0]
+                        .as_expression()
+                        .is_some_and(|arg| self.refers_to(arg, symbol_id))
+                    && expr.callee.get_member_expr().is_some_and(|member| {
+                        member.static_property_name() == Some("isArray")
+                            && self.global_name(member.object()) == Some("Array")
+                    }) =>
+            {
+                if assume_true {
+                    ty & InferredType::Array
+                } else {
+                    ty - InferredType::Array
+                }
+            }
+            // `x instanceof Foo`
+            Expression::BinaryExpression(expr)
+                if expr.operator == BinaryOperator::Instanceof
+                    && assume_true
+                    && self.refers_to(&expr.left, symbol_id) =>
+            {
+                ty & InferredType::AnyObject
+            }
+            _ => ty,
+        }
+    }
+
+    /// Narrow `ty` by `left == right` (or `!=` if `equal` is false), where `left` refers to
+    /// `symbol_id`. Returns `None` if `left` does not refer to `symbol_id`.
+    fn narrow_equality(
+        &mut self,
+        ty: InferredType,
+        left: &Expression<'a>,
+        right: &Expression<'a>,
+        symbol_id: SymbolId,
+        equal: bool,
+        strict: bool,
+    ) -> Option<InferredType> {
+        match left.without_parentheses() {
+            // `typeof x === "string"`
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::Typeof => {
+                if !self.refers_to(&unary.argument, symbol_id) {
+                    return None;
+                }
+                let Expression::StringLiteral(tag) = right.without_parentheses() else {
+                    return Some(ty);
+                };
+                let tagged = InferredType::from_typeof_tag(tag.value.as_str())
+                    .unwrap_or(InferredType::empty());
+                Some(if equal { ty & tagged } else { ty - tagged })
+            }
+            // `x === null`, `x == undefined`, `x === 1`
+            left if self.refers_to(left, symbol_id) => {
+                let right = self.expression(right);
+                let narrowed = if equal && strict {
+                    // Strictly equal values have the same type
+                    ty & right
+                } else if right == InferredType::Null || right == InferredType::Undefined {
+                    // `null` and `undefined` are only loosely equal to each other
+                    let nullish = if strict { right } else { InferredType::Nullish };
+                    if equal { ty & nullish } else { ty - nullish }
+                } else {
+                    ty
+                };
+                Some(narrowed)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether `expr` is a reference to `symbol_id`.
+    fn refers_to(&self, expr: &Expression<'a>, symbol_id: SymbolId) -> bool {
+        let Expression::Identifier(ident) = expr.without_parentheses() else { return false };
+        ident.reference_id.get().is_some_and(|reference_id| {
+            self.semantic.scoping().get_reference(reference_id).symbol_id() == Some(symbol_id)
+        })
+    }
+}
+
+/// Type of the result of `ToNumeric(value)`, for a `value` of type `ty`.
+fn to_numeric(ty: InferredType) -> InferredType {
+    if ty.is_only(InferredType::BigInt) {
+        InferredType::BigInt
+    } else if ty.intersects(InferredType::BigInt | InferredType::AnyObject) {
+        // Objects may convert to a BigInt with `valueOf`
+        InferredType::Numeric
+    } else {
+        InferredType::Number
+    }
+}
+
+fn binary_operation(
+    operator: BinaryOperator,
+    left: InferredType,
+    right: InferredType,
+) -> InferredType {
+    match operator {
+        BinaryOperator::Addition => {
+            if left.is_only(InferredType::String) || right.is_only(InferredType::String) {
+                return InferredType::String;
+            }
+            // Objects may convert to strings
+            let may_be_string =
+                (left | right).intersects(InferredType::String | InferredType::AnyObject);
+            let numeric = to_numeric(left) | to_numeric(right);
+            // Mixing BigInts and numbers throws
+            let numeric = if numeric.is_only(InferredType::BigInt) {
+                InferredType::BigInt
+            } else if left.intersects(InferredType::BigInt | InferredType::AnyObject)
+                && right.intersects(InferredType::BigInt | InferredType::AnyObject)
+            {
+                InferredType::Numeric
+            } else {
+                InferredType::Number
+            };
+            if may_be_string { numeric | InferredType::String } else { numeric }
+        }
+        BinaryOperator::ShiftRightZeroFill => InferredType::Number,
+        BinaryOperator::Subtraction
+        | BinaryOperator::Multiplication
+        | BinaryOperator::Division
+        | BinaryOperator::Remainder
+        | BinaryOperator::Exponential
+        | BinaryOperator::ShiftLeft
+        | BinaryOperator::ShiftRight
+        | BinaryOperator::BitwiseOR
+        | BinaryOperator::BitwiseXOR
+        | BinaryOperator::BitwiseAnd => {
+            let (left, right) = (to_numeric(left), to_numeric(right));
+            // Unless both are BigInts, either the result is a number or the operation throws
+            if left.contains(InferredType::BigInt) && right.contains(InferredType::BigInt) {
+                left & right
+            } else {
+                InferredType::Number
+            }
+        }
+        _ => InferredType::Boolean,
+    }
+}
+
+/// Return types of static methods of the global `object`.
+fn static_method(object: &str, method: &str) -> InferredType {
+    match (object, method) {
+        ("Math", _) | ("Date", "now" | "parse" | "UTC") | ("Number", "parseFloat" | "parseInt") => {
+            InferredType::Number
+        }
+        ("Number", "isInteger" | "isSafeInteger" | "isFinite" | "isNaN")
+        | ("Array", "isArray")
+        | ("Object", "is" | "isFrozen" | "isSealed" | "isExtensible" | "hasOwn") => {
+            InferredType::Boolean
+        }
+        ("Array", "from" | "of")
+        | ("Object", "keys" | "values" | "entries" | "getOwnPropertyNames") => InferredType::Array,
+        ("String", "fromCharCode" | "fromCodePoint" | "raw") => InferredType::String,
+        ("JSON", "stringify") => InferredType::String | InferredType::Undefined,
+        ("Promise", "resolve" | "reject" | "all" | "allSettled" | "any" | "race") => {
+            InferredType::Promise
+        }
+        ("Object", "create" | "fromEntries" | "getOwnPropertyDescriptors")
+        | ("Promise", "withResolvers") => InferredType::Object,
+        _ => InferredType::UNKNOWN,
+    }
+}
+
+/// Return types of methods of `String.prototype`.
+fn string_method(method: &str) -> InferredType {
+    match method {
+        "charAt" | "concat" | "normalize" | "padEnd" | "padStart" | "repeat" | "replace"
+        | "replaceAll" | "slice" | "substring" | "substr" | "toLowerCase" | "toUpperCase"
+        | "toLocaleLowerCase" | "toLocaleUpperCase" | "toString" | "toWellFormed" | "trim"
+        | "trimEnd" | "trimStart" | "valueOf" => InferredType::String,
+        "at" => InferredType::String | InferredType::Undefined,
+        "charCodeAt" | "indexOf" | "lastIndexOf" | "localeCompare" | "search" => {
+            InferredType::Number
+        }
+        "codePointAt" => InferredType::Number | InferredType::Undefined,
+        "endsWith" | "includes" | "isWellFormed" | "startsWith" => InferredType::Boolean,
+        "split" => InferredType::Array,
+        "match" => InferredType::Array | InferredType::Null,
+        _ => InferredType::UNKNOWN,
+    }
+}
+
+/// Return types of methods of `Array.prototype`.
+fn array_method(method: &str) -> InferredType {
+    match method {
+        "concat" | "copyWithin" | "fill" | "filter" | "flat" | "flatMap" | "map" | "reverse"
+        | "slice" | "sort" | "splice" | "toReversed" | "toSorted" | "toSpliced" | "with" => {
+            InferredType::Array
+        }
+        "findIndex" | "findLastIndex" | "indexOf" | "lastIndexOf" | "push" | "unshift" => {
+            InferredType::Number
+        }
+        "every" | "includes" | "some" => InferredType::Boolean,
+        "join" | "toLocaleString" | "toString" => InferredType::String,
+        _ => InferredType::UNKNOWN,
+    }
+}
+
+/// Type of values of the TypeScript type `ty`. Types which are not understood are
+/// [`InferredType::UNKNOWN`].
+fn ts_type(ty: &TSType<'_>) -> InferredType {
+    match ty {
+        TSType::TSStringKeyword(_) | TSType::TSTemplateLiteralType(_) => InferredType::String,
+        TSType::TSNumberKeyword(_) => InferredType::Number,
+        TSType::TSBigIntKeyword(_) => InferredType::BigInt,
+        TSType::TSBooleanKeyword(_) => InferredType::Boolean,
+        TSType::TSSymbolKeyword(_) => InferredType::Symbol,
+        TSType::TSUndefinedKeyword(_) | TSType::TSVoidKeyword(_) => InferredType::Undefined,
+        TSType::TSNullKeyword(_) => InferredType::Null,
+        TSType::TSNeverKeyword(_) => InferredType::empty(),
+        TSType::TSObjectKeyword(_) => InferredType::AnyObject,
+        TSType::TSArrayType(_) | TSType::TSTupleType(_) => InferredType::Array,
+        TSType::TSFunctionType(_) | TSType::TSConstructorType(_) => InferredType::Function,
+        TSType::TSLiteralType(literal) => match &literal.literal {
+            TSLiteral::BooleanLiteral(_) => InferredType::Boolean,
+            TSLiteral::NumericLiteral(_) => InferredType::Number,
+            TSLiteral::BigIntLiteral(_) => InferredType::BigInt,
+            TSLiteral::StringLiteral(_) | TSLiteral::TemplateLiteral(_) => InferredType::String,
+            TSLiteral::UnaryExpression(expr) => match &expr.argument {
+                Expression::BigIntLiteral(_) => InferredType::BigInt,
+                _ => InferredType::Number,
+            },
+        },
+        TSType::TSUnionType(union) => {
+            union.types.iter().fold(InferredType::empty(), |ty, member| ty | ts_type(member))
+        }
+        TSType::TSParenthesizedType(ty) => ts_type(&ty.type_annotation),
+        TSType::TSTypeReference(reference) => match &reference.type_name {
+            TSTypeName::IdentifierReference(ident) => match ident.name.as_str() {
+                "Array" | "ReadonlyArray" => InferredType::Array,
+                "Promise" => InferredType::Promise,
+                "RegExp" => InferredType::RegExp,
+                "Function" => InferredType::Function,
+                _ => InferredType::UNKNOWN,
+            },
+            _ => InferredType::UNKNOWN,
+        },
+        _ => InferredType::UNKNOWN,
+    }
+}
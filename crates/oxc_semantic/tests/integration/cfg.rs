@@ -19,3 +19,61 @@ fn test_cfg_files() {
         });
     });
 }
+
+#[test]
+fn test_cfg_dataflow() {
+    use oxc_ast::{AstKind, ast::Expression};
+    use oxc_semantic::{
+        BlockNodeId, ControlFlowGraph, Instruction,
+        dataflow::{Analysis, DataflowResults},
+    };
+
+    /// Whether a block can be reached from the entry of its function.
+    struct Reachable;
+
+    impl Analysis for Reachable {
+        type Domain = bool;
+
+        fn bottom_value(&self, _cfg: &ControlFlowGraph) -> bool {
+            false
+        }
+
+        fn initialize_boundary(&self, _block: BlockNodeId, state: &mut bool) {
+            *state = true;
+        }
+
+        fn apply_instruction(&self, _instruction: &Instruction, _state: &mut bool) {}
+    }
+
+    let tester = SemanticTester::js(
+        "
+        function f() {
+            a();
+            if (x) { return; b(); } else { c(); }
+            d();
+            while (true) {}
+            e();
+        }
+        ",
+    )
+    .with_cfg(true);
+    let semantic = tester.build();
+    let cfg = semantic.cfg().unwrap();
+    let results: DataflowResults<Reachable> = Reachable.iterate_to_fixpoint(cfg);
+
+    let mut reachable = semantic
+        .nodes()
+        .iter()
+        .filter_map(|node| {
+            let AstKind::ExpressionStatement(stmt) = node.kind() else { return None };
+            let Expression::CallExpression(call) = &stmt.expression else { return None };
+            let name = call.callee.get_identifier_reference()?.name.as_str();
+            let block = semantic.nodes().cfg_id(node.id());
+            Some((name, *results.state_at_block_start(block)))
+        })
+        .collect::<Vec<_>>();
+    reachable.sort_unstable();
+    // Loop conditions are not evaluated when building the CFG, so `e()` is reachable through the
+    // exit of `while (true)`. See `ControlFlowGraph::is_infinite_loop_start`.
+    assert_eq!(reachable, [("a", true), ("b", false), ("c", true), ("d", true), ("e", true)]);
+}
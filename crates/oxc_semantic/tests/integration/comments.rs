@@ -0,0 +1,67 @@
+use oxc_semantic::CommentPlacement;
+use oxc_span::GetSpan;
+
+use crate::util::SemanticTester;
+
+/// The comments of `source_text` with the source text of the node they are attached to.
+fn attachments(source_text: &'static str) -> Vec<(&'static str, &'static str, CommentPlacement)> {
+    let tester = SemanticTester::js(source_text);
+    let semantic = tester.build();
+    semantic
+        .comments()
+        .iter()
+        .zip(semantic.comment_attachments().iter())
+        .map(|(comment, attachment)| {
+            let node = semantic.nodes().kind(attachment.node_id).span();
+            (
+                comment.span.source_text(source_text),
+                node.source_text(source_text),
+                attachment.placement,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_leading_and_trailing_comments() {
+    use CommentPlacement::{Leading, Trailing};
+    assert_eq!(
+        attachments("/** doc */\nfunction f() {}"),
+        [("/** doc */", "function f() {}", Leading)]
+    );
+    assert_eq!(attachments("let a = 1; // one"), [("// one", "let a = 1;", Trailing)]);
+    assert_eq!(attachments("/* c */ a + b;"), [("/* c */", "a + b;", Leading)]);
+    assert_eq!(attachments("f(/* c */ b);"), [("/* c */", "b", Leading)]);
+    assert_eq!(attachments("f(a /* c */, b);"), [("/* c */", "a", Trailing)]);
+    assert_eq!(attachments("a;\n// own line\nb;\n"), [("// own line", "b;", Leading)]);
+    assert_eq!(attachments("{\n  a;\n  // last\n}"), [("// last", "a;", Trailing)]);
+}
+
+#[test]
+fn test_dangling_comments() {
+    use CommentPlacement::Dangling;
+    assert_eq!(attachments("function f(/* c */) {}"), [("/* c */", "(/* c */)", Dangling)]);
+    assert_eq!(
+        attachments("if (a) {\n  // empty\n}"),
+        [("// empty", "{\n  // empty\n}", Dangling)]
+    );
+    assert_eq!(attachments("// only"), [("// only", "// only", Dangling)]);
+}
+
+#[test]
+fn test_attached_comments_of_node() {
+    let source_text = "/** doc */\n// line\nfunction f() {\n  // empty\n} // after\n";
+    let tester = SemanticTester::js(source_text);
+    let semantic = tester.build();
+    let function =
+        semantic.nodes().iter().find(|node| node.kind().as_function().is_some()).unwrap().id();
+    let text = |comments: Vec<&oxc_ast::Comment>| {
+        comments.iter().map(|comment| comment.span.source_text(source_text)).collect::<Vec<_>>()
+    };
+
+    assert_eq!(text(semantic.leading_comments(function).collect()), ["/** doc */", "// line"]);
+    assert_eq!(text(semantic.trailing_comments(function).collect()), ["// after"]);
+    assert!(semantic.dangling_comments(function).next().is_none());
+    let body = semantic.nodes().iter().find(|node| node.kind().as_function_body().is_some());
+    assert_eq!(text(semantic.dangling_comments(body.unwrap().id()).collect()), ["// empty"]);
+}
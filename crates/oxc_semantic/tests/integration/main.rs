@@ -2,7 +2,11 @@
 
 pub mod cfg;
 pub mod classes;
+pub mod comments;
 pub mod modules;
+pub mod rename;
 pub mod scopes;
+pub mod snapshot;
 pub mod symbols;
+pub mod type_inference;
 pub mod util;
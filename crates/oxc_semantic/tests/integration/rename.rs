@@ -0,0 +1,115 @@
+use crate::util::SemanticTester;
+
+/// Rename the first symbol named `name` in `source_text` to `new_name`, and apply the edits.
+fn rename(source_text: &'static str, name: &str, new_name: &str) -> Result<String, String> {
+    let tester = SemanticTester::js(source_text);
+    let semantic = tester.build();
+    let symbol_id = semantic
+        .scoping()
+        .symbol_ids()
+        .find(|&symbol_id| semantic.scoping().symbol_name(symbol_id) == name)
+        .unwrap_or_else(|| panic!("Could not find symbol {name}"));
+    let edits = semantic.rename(symbol_id, new_name).map_err(|error| error.to_string())?;
+
+    let mut output = source_text.to_string();
+    for edit in edits.iter().rev() {
+        output.replace_range(edit.span.start as usize..edit.span.end as usize, &edit.new_text);
+    }
+    Ok(output)
+}
+
+#[test]
+fn test_rename_references() {
+    assert_eq!(
+        rename("let a = 1; function f(b) { return a + b; } a++;", "a", "c").unwrap(),
+        "let c = 1; function f(b) { return c + b; } c++;"
+    );
+    assert_eq!(rename("var a; var a; a = 1;", "a", "b").unwrap(), "var b; var b; b = 1;");
+    assert_eq!(rename("let a = 1;", "a", "a").unwrap(), "let a = 1;");
+}
+
+#[test]
+fn test_rename_shorthand_properties() {
+    assert_eq!(
+        rename("let a = 1; let o = { a };", "a", "b").unwrap(),
+        "let b = 1; let o = { a: b };"
+    );
+    assert_eq!(rename("let { a, c: d } = o; a;", "a", "b").unwrap(), "let { a: b, c: d } = o; b;");
+    assert_eq!(rename("let { a = 1 } = o; a;", "a", "b").unwrap(), "let { a: b = 1 } = o; b;");
+    assert_eq!(
+        rename("let a; ({ a } = o); ({ a = 1 } = o);", "a", "b").unwrap(),
+        "let b; ({ a: b } = o); ({ a: b = 1 } = o);"
+    );
+}
+
+#[test]
+fn test_rename_imports_and_exports() {
+    assert_eq!(
+        rename("import { a } from 'x'; a();", "a", "b").unwrap(),
+        "import { a as b } from 'x'; b();"
+    );
+    assert_eq!(
+        rename("import { b as a } from 'x'; a();", "a", "b").unwrap(),
+        "import { b } from 'x'; b();"
+    );
+    assert_eq!(
+        rename("import { 'a-b' as a } from 'x'; a();", "a", "b").unwrap(),
+        "import { 'a-b' as b } from 'x'; b();"
+    );
+    assert_eq!(rename("let a; export { a };", "a", "b").unwrap(), "let b; export { b as a };");
+    assert_eq!(rename("let a; export { a as b };", "a", "b").unwrap(), "let b; export { b };");
+    assert_eq!(
+        rename("let a; export { a as 'a-b' };", "a", "b").unwrap(),
+        "let b; export { b as 'a-b' };"
+    );
+    assert_eq!(
+        rename("import { a } from 'x'; export { a };", "a", "b").unwrap(),
+        "import { a as b } from 'x'; export { b as a };"
+    );
+}
+
+#[test]
+fn test_rename_exported_declarations() {
+    assert!(rename("export const a = 1; a;", "a", "b").is_err());
+    assert!(rename("export let { a } = o;", "a", "b").is_err());
+    assert!(rename("export function a() {}", "a", "b").is_err());
+    assert!(rename("export class a {}", "a", "b").is_err());
+    // the default export keeps its name
+    assert_eq!(
+        rename("export default function a() {} a();", "a", "b").unwrap(),
+        "export default function b() {} b();"
+    );
+    // bindings inside an exported declaration aren't exported
+    assert_eq!(
+        rename("export function f(a) { return a; }", "a", "b").unwrap(),
+        "export function f(b) { return b; }"
+    );
+}
+
+#[test]
+fn test_rename_conflicts() {
+    // same scope
+    assert!(rename("let a; let b;", "a", "b").is_err());
+    // a reference would resolve to an inner binding
+    assert!(rename("let a; function f() { let b; a; }", "a", "b").is_err());
+    // a reference to an outer binding would resolve to the symbol
+    assert!(rename("let b; function f() { let a; b; }", "a", "b").is_err());
+    // a global would be shadowed
+    assert!(rename("function f() { let a; console.log(a); }", "a", "console").is_err());
+    // shadowing without references in between is fine
+    assert_eq!(
+        rename("let b; function f() { let a; a; }", "a", "b").unwrap(),
+        "let b; function f() { let b; b; }"
+    );
+    assert_eq!(
+        rename("let a; a; function f() { let b; b; }", "a", "b").unwrap(),
+        "let b; b; function f() { let b; b; }"
+    );
+}
+
+#[test]
+fn test_rename_invalid_names() {
+    assert!(rename("let a;", "a", "1a").is_err());
+    assert!(rename("let a;", "a", "class").is_err());
+    assert!(rename("let a;", "a", "").is_err());
+}
@@ -0,0 +1,37 @@
+use oxc_semantic::{ReferenceFlags, ScopeFlags, SymbolFlags};
+
+use crate::util::SemanticTester;
+
+#[test]
+fn test_snapshot() {
+    let tester = SemanticTester::js("let a = 1;\nfunction f(b) {\n  a = b + c;\n}\nf(a);");
+    let snapshot = tester.build().snapshot();
+
+    assert_eq!(snapshot.scopes.len(), 2);
+    assert!(snapshot.scopes[0].flags.contains(ScopeFlags::Top));
+    assert_eq!(snapshot.scopes[0].children, vec![snapshot.scopes[1].id]);
+    assert_eq!(snapshot.scopes[1].parent, Some(snapshot.scopes[0].id));
+
+    let names = snapshot.symbols.iter().map(|symbol| symbol.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, ["a", "f", "b"]);
+    let a = &snapshot.symbols[0];
+    assert!(a.flags.contains(SymbolFlags::BlockScopedVariable));
+    assert_eq!(a.references.len(), 2);
+
+    let c = snapshot.references.iter().find(|reference| reference.name == "c").unwrap();
+    assert_eq!(c.symbol, None);
+    assert_eq!(c.flags, ReferenceFlags::Read);
+    assert_eq!(c.scope, snapshot.scopes[1].id);
+
+    insta::assert_snapshot!("snapshot_display", snapshot.to_string());
+    insta::assert_snapshot!("snapshot_json", snapshot.to_json());
+}
+
+#[test]
+fn test_snapshot_json_is_valid() {
+    let tester = SemanticTester::ts("type T = string;\nvar a: T = '';\nvar a;\nexport { a };");
+    let json = tester.build().snapshot().to_json();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["symbols"][1]["name"], "a");
+    assert_eq!(value["symbols"][1]["redeclarations"].as_array().unwrap().len(), 2);
+}
@@ -0,0 +1,113 @@
+use oxc_ast::AstKind;
+
+use crate::util::SemanticTester;
+
+/// Type of the last reference to `x` in `source_text`.
+fn type_of_x(tester: &SemanticTester) -> String {
+    let semantic = tester.build();
+    let node = semantic
+        .nodes()
+        .iter()
+        .filter(
+            |node| matches!(node.kind(), AstKind::IdentifierReference(ident) if ident.name == "x"),
+        )
+        .last()
+        .expect("Could not find a reference to `x`");
+    semantic.type_of(node.id()).to_string()
+}
+
+fn js(source_text: &'static str) -> String {
+    type_of_x(&SemanticTester::js(source_text))
+}
+
+fn ts(source_text: &'static str) -> String {
+    type_of_x(&SemanticTester::ts(source_text))
+}
+
+#[test]
+fn test_literals_and_operators() {
+    assert_eq!(js("let x = 1; x;"), "number");
+    assert_eq!(js("let x = `a${b}`; x;"), "string");
+    assert_eq!(js("let x = 1n * 2n; x;"), "bigint");
+    assert_eq!(js("let x = a + 'b'; x;"), "string");
+    assert_eq!(js("let x = a - b; x;"), "number | bigint");
+    assert_eq!(js("let x = !a; x;"), "boolean");
+    assert_eq!(js("let x = typeof a; x;"), "string");
+    assert_eq!(js("let x = void a; x;"), "undefined");
+    assert_eq!(js("let x = a ? 1 : 'a'; x;"), "number | string");
+    assert_eq!(js("let x = (a ? 'a' : undefined) ?? 1; x;"), "number | string");
+    assert_eq!(js("let x = () => {}; x;"), "function");
+    assert_eq!(js("let x; x;"), "undefined");
+    assert_eq!(js("x;"), "unknown");
+}
+
+#[test]
+fn test_builtins() {
+    assert_eq!(js("let x = new Map(); x;"), "object");
+    assert_eq!(js("let x = Array.from(a); x;"), "array");
+    assert_eq!(js("let x = Promise.resolve(); x;"), "promise");
+    assert_eq!(js("let x = 'a'.at(0); x;"), "undefined | string");
+    assert_eq!(js("let x = [].join(); x;"), "string");
+    assert_eq!(js("let x = String(a); x;"), "string");
+    assert_eq!(js("let x = undefined; x;"), "undefined");
+    // Shadowed builtins are not the builtin
+    assert_eq!(js("let Array = a; let x = Array.from(a); x;"), "unknown");
+}
+
+#[test]
+fn test_typescript_annotations() {
+    assert_eq!(ts("function f(x: string | null) { x; }"), "null | string");
+    assert_eq!(ts("function f(x?: number) { x; }"), "undefined | number");
+    assert_eq!(ts("function f(x: Foo) { x; }"), "unknown");
+    assert_eq!(ts("let x: boolean = a; x;"), "boolean");
+    // Later writes are added to the annotation
+    assert_eq!(ts("let x: number = 1; x = a as string; x;"), "number | string");
+    assert_eq!(ts("function f(x: number) { x = a as string; x; }"), "number | string");
+}
+
+#[test]
+fn test_narrowing() {
+    let source = "function f(x: string | number | null) { if (typeof x === 'string') { x; } }";
+    assert_eq!(ts(source), "string");
+    let source = "function f(x: string | number | null) { if (typeof x !== 'string') { x; } }";
+    assert_eq!(ts(source), "null | number");
+    assert_eq!(ts("function f(x: string | null) { if (x !== null) { x; } }"), "string");
+    assert_eq!(ts("function f(x?: string | null) { if (x != null) { x; } }"), "string");
+    assert_eq!(ts("function f(x?: string) { x ? x : 1; }"), "string");
+    assert_eq!(ts("function f(x?: number[]) { x && x; }"), "array");
+    assert_eq!(ts("function f(x: string | number[]) { if (Array.isArray(x)) { x; } }"), "array");
+    // Outside of the guarded branch
+    assert_eq!(ts("function f(x: string | null) { if (x !== null) {} x; }"), "null | string");
+    // Not narrowed if reassigned
+    assert_eq!(
+        ts("function f(x: string | null) { if (x !== null) { x = null; x; } }"),
+        "null | string"
+    );
+}
+
+#[test]
+fn test_reassignment() {
+    assert_eq!(js("let x = 1; x = 'a'; x;"), "number | string");
+    assert_eq!(js("let x = 1; x += 1; x;"), "number");
+    assert_eq!(js("let x = 1; x += 'a'; x;"), "number | string");
+    assert_eq!(js("let x = 1; x++; x;"), "number");
+    assert_eq!(js("let x = 1n; x++; x;"), "bigint");
+    assert_eq!(js("let x = null; x ??= 'a'; x;"), "null | string");
+    assert_eq!(js("let x = 1; [x] = a; x;"), "unknown");
+    assert_eq!(js("let x = y; let y = x; x;"), "unknown");
+}
+
+#[test]
+fn test_read_before_initialization() {
+    assert_eq!(js("var x = 1; x;"), "number");
+    assert_eq!(js("for (var x = 0; a; x++) { x; }"), "number");
+    assert_eq!(js("function f() { var x = 1; x; }"), "number");
+    // Hoisted `var`
+    assert_eq!(js("x; var x = 1;"), "undefined | number");
+    // Function called before the declaration
+    assert_eq!(js("f(); var x = 1; function f() { x; }"), "undefined | number");
+    // Declaration which may be skipped
+    assert_eq!(js("if (a) { var x = 1; } x;"), "undefined | number");
+    // Reads of `let` and `const` before their declaration throw
+    assert_eq!(js("f(); let x = 1; function f() { x; }"), "number");
+}
@@ -10,7 +10,7 @@ use oxc_estree::{ESTree, Serializer as ESTreeSerializer};
 #[cfg(feature = "serialize")]
 use serde::{Serialize, Serializer as SerdeSerializer};
 
-use crate::{CompactStr, ContentEq};
+use crate::{CompactStr, ContentEq, ContentHash};
 
 /// An inlinable string for oxc_allocator.
 ///
@@ -266,6 +266,13 @@ impl ContentEq for Atom<'_> {
     }
 }
 
+impl ContentHash for Atom<'_> {
+    #[inline]
+    fn content_hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_str().content_hash(state);
+    }
+}
+
 impl hash::Hash for Atom<'_> {
     #[inline]
     fn hash<H: hash::Hasher>(&self, hasher: &mut H) {
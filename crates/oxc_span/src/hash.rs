@@ -0,0 +1,185 @@
+//! Specialized hashing traits
+
+use std::hash::Hasher;
+
+/// This trait works similarly to [std::hash::Hash] but it only hashes the content of a value,
+/// consistent with [crate::ContentEq].
+///
+/// Parts of the content which [crate::ContentEq] ignores are not hashed either. As an example,
+/// in AST types we ignore fields such as [crate::Span], so the same code gets the same hash
+/// wherever it is in a file.
+///
+/// Unlike [std::hash::Hash], implementations write the same bytes on all platforms, so hashes
+/// can be persisted with [FingerprintHasher]. See [content_fingerprint].
+pub trait ContentHash {
+    /// Feeds the content of `self` into `state`.
+    fn content_hash<H: Hasher>(&self, state: &mut H);
+}
+
+/// Fingerprint of the content of `value`, for matching the same code across parses.
+///
+/// The fingerprint of an AST node doesn't depend on its position, so it stays the same when
+/// unrelated edits move the node to another line. It is the same across runs and platforms, so
+/// it can be persisted, e.g. in caches and baselines.
+///
+/// ```
+/// use oxc_span::content_fingerprint;
+///
+/// assert_eq!(content_fingerprint("foo"), content_fingerprint("foo"));
+/// assert_ne!(content_fingerprint("foo"), content_fingerprint("bar"));
+/// ```
+pub fn content_fingerprint<T: ContentHash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = FingerprintHasher::default();
+    value.content_hash(&mut hasher);
+    hasher.finish()
+}
+
+/// 64-bit FNV-1a, for hashes which are persisted, e.g. several values fed with [ContentHash].
+///
+/// Unlike the `std` hasher, it isn't seeded per process, and unlike `rustc_hash`, it gives the
+/// same hash on 32-bit and 64-bit platforms.
+pub struct FingerprintHasher(u64);
+
+impl Default for FingerprintHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FingerprintHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl ContentHash for () {
+    #[inline]
+    fn content_hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// Hash `f64` as bits, consistent with [crate::ContentEq] for `f64`.
+impl ContentHash for f64 {
+    #[inline]
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bits().content_hash(state);
+    }
+}
+
+impl ContentHash for bool {
+    #[inline]
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u8(u8::from(*self));
+    }
+}
+
+impl ContentHash for char {
+    #[inline]
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        u32::from(*self).content_hash(state);
+    }
+}
+
+impl ContentHash for str {
+    #[inline]
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        state.write(self.as_bytes());
+        // Terminate, so `("ab", "c")` and `("a", "bc")` hash differently, as `std` does
+        state.write_u8(0xff);
+    }
+}
+
+impl<T: ContentHash + ?Sized> ContentHash for &T {
+    #[inline]
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        (**self).content_hash(state);
+    }
+}
+
+/// Blanket implementation for [Option] types
+impl<T: ContentHash> ContentHash for Option<T> {
+    #[inline]
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Some(value) => {
+                state.write_u8(1);
+                value.content_hash(state);
+            }
+            None => state.write_u8(0),
+        }
+    }
+}
+
+/// Blanket implementation for [oxc_allocator::Box] types
+impl<T: ContentHash> ContentHash for oxc_allocator::Box<'_, T> {
+    #[inline]
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().content_hash(state);
+    }
+}
+
+/// Blanket implementation for [oxc_allocator::Vec] types
+impl<T: ContentHash> ContentHash for oxc_allocator::Vec<'_, T> {
+    #[inline]
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.len().content_hash(state);
+        for item in self {
+            item.content_hash(state);
+        }
+    }
+}
+
+mod content_hash_auto_impls {
+    use std::hash::Hasher;
+
+    use super::ContentHash;
+
+    macro_rules! content_hash_impl {
+        ($($t:ty)*) => ($(
+            impl ContentHash for $t {
+                #[inline]
+                fn content_hash<H: Hasher>(&self, state: &mut H) {
+                    state.write(&self.to_le_bytes());
+                }
+            }
+        )*)
+    }
+
+    content_hash_impl! {
+        u8 u16 u32 u64 u128
+        i8 i16 i32 i64 i128
+    }
+
+    // Hash `usize` and `isize` as 64 bits, so hashes are the same on 32-bit platforms
+    impl ContentHash for usize {
+        #[inline]
+        fn content_hash<H: Hasher>(&self, state: &mut H) {
+            (*self as u64).content_hash(state);
+        }
+    }
+
+    impl ContentHash for isize {
+        #[inline]
+        fn content_hash<H: Hasher>(&self, state: &mut H) {
+            (*self as i64).content_hash(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::content_fingerprint;
+
+    #[test]
+    fn fingerprint_is_stable() {
+        // Fingerprints are persisted, changing them invalidates existing caches and baselines
+        assert_eq!(content_fingerprint(""), 0xaf64_724c_8602_eb6e);
+        assert_eq!(content_fingerprint("foo"), 0xdd12_8179_0c25_d618);
+    }
+}
@@ -2,6 +2,7 @@ use std::{
     borrow::Cow,
     error::Error,
     fmt::{self, Display},
+    hash::Hasher,
     ops::Deref,
     path::Path,
     str::FromStr,
@@ -11,7 +12,7 @@ use oxc_allocator::{Allocator, CloneIn, Dummy};
 use oxc_ast_macros::ast;
 use oxc_estree::ESTree;
 
-use crate::ContentEq;
+use crate::{ContentEq, ContentHash};
 
 /// Source Type for JavaScript vs TypeScript / Script vs Module / JSX
 #[ast]
@@ -101,6 +102,15 @@ impl ContentEq for SourceType {
     }
 }
 
+impl ContentHash for SourceType {
+    #[inline]
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u8(self.language as u8);
+        state.write_u8(self.module_kind as u8);
+        state.write_u8(self.variant as u8);
+    }
+}
+
 /// Valid file extensions.
 pub const VALID_EXTENSIONS: &[&str] = &["js", "mjs", "cjs", "jsx", "ts", "mts", "cts", "tsx"];
 
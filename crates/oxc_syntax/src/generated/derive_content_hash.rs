@@ -0,0 +1,117 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/derives/content_hash.rs`.
+
+use std::hash::Hasher;
+
+use oxc_span::ContentHash;
+
+use crate::number::*;
+use crate::operator::*;
+
+impl ContentHash for NumberBase {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Float => state.write_u8(0),
+            Self::Decimal => state.write_u8(1),
+            Self::Binary => state.write_u8(2),
+            Self::Octal => state.write_u8(3),
+            Self::Hex => state.write_u8(4),
+        }
+    }
+}
+
+impl ContentHash for BigintBase {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Decimal => state.write_u8(0),
+            Self::Binary => state.write_u8(1),
+            Self::Octal => state.write_u8(2),
+            Self::Hex => state.write_u8(3),
+        }
+    }
+}
+
+impl ContentHash for AssignmentOperator {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Assign => state.write_u8(0),
+            Self::Addition => state.write_u8(1),
+            Self::Subtraction => state.write_u8(2),
+            Self::Multiplication => state.write_u8(3),
+            Self::Division => state.write_u8(4),
+            Self::Remainder => state.write_u8(5),
+            Self::Exponential => state.write_u8(6),
+            Self::ShiftLeft => state.write_u8(7),
+            Self::ShiftRight => state.write_u8(8),
+            Self::ShiftRightZeroFill => state.write_u8(9),
+            Self::BitwiseOR => state.write_u8(10),
+            Self::BitwiseXOR => state.write_u8(11),
+            Self::BitwiseAnd => state.write_u8(12),
+            Self::LogicalOr => state.write_u8(13),
+            Self::LogicalAnd => state.write_u8(14),
+            Self::LogicalNullish => state.write_u8(15),
+        }
+    }
+}
+
+impl ContentHash for BinaryOperator {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Equality => state.write_u8(0),
+            Self::Inequality => state.write_u8(1),
+            Self::StrictEquality => state.write_u8(2),
+            Self::StrictInequality => state.write_u8(3),
+            Self::LessThan => state.write_u8(4),
+            Self::LessEqualThan => state.write_u8(5),
+            Self::GreaterThan => state.write_u8(6),
+            Self::GreaterEqualThan => state.write_u8(7),
+            Self::Addition => state.write_u8(8),
+            Self::Subtraction => state.write_u8(9),
+            Self::Multiplication => state.write_u8(10),
+            Self::Division => state.write_u8(11),
+            Self::Remainder => state.write_u8(12),
+            Self::Exponential => state.write_u8(13),
+            Self::ShiftLeft => state.write_u8(14),
+            Self::ShiftRight => state.write_u8(15),
+            Self::ShiftRightZeroFill => state.write_u8(16),
+            Self::BitwiseOR => state.write_u8(17),
+            Self::BitwiseXOR => state.write_u8(18),
+            Self::BitwiseAnd => state.write_u8(19),
+            Self::In => state.write_u8(20),
+            Self::Instanceof => state.write_u8(21),
+        }
+    }
+}
+
+impl ContentHash for LogicalOperator {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Or => state.write_u8(0),
+            Self::And => state.write_u8(1),
+            Self::Coalesce => state.write_u8(2),
+        }
+    }
+}
+
+impl ContentHash for UnaryOperator {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::UnaryPlus => state.write_u8(0),
+            Self::UnaryNegation => state.write_u8(1),
+            Self::LogicalNot => state.write_u8(2),
+            Self::BitwiseNot => state.write_u8(3),
+            Self::Typeof => state.write_u8(4),
+            Self::Void => state.write_u8(5),
+            Self::Delete => state.write_u8(6),
+        }
+    }
+}
+
+impl ContentHash for UpdateOperator {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Increment => state.write_u8(0),
+            Self::Decrement => state.write_u8(1),
+        }
+    }
+}
@@ -27,6 +27,7 @@ mod generated {
     mod assert_layouts;
     mod derive_clone_in;
     mod derive_content_eq;
+    mod derive_content_hash;
     mod derive_dummy;
     #[cfg(feature = "serialize")]
     mod derive_estree;
@@ -2,11 +2,11 @@
 
 use oxc_allocator::{CloneIn, Dummy};
 use oxc_ast_macros::ast;
-use oxc_span::ContentEq;
+use oxc_span::{ContentEq, ContentHash};
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[generate_derive(CloneIn, Dummy, ContentEq)]
+#[generate_derive(CloneIn, Dummy, ContentEq, ContentHash)]
 pub enum NumberBase {
     Float = 0,
     Decimal = 1,
@@ -23,7 +23,7 @@ impl NumberBase {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[generate_derive(CloneIn, Dummy, ContentEq)]
+#[generate_derive(CloneIn, Dummy, ContentEq, ContentHash)]
 pub enum BigintBase {
     Decimal = 0,
     Binary = 1,
@@ -141,19 +141,11 @@ impl<'a> RegExp<'a, '_> {
                 return;
             }
 
-            let owned_pattern;
-            let pattern = if let Some(pattern) = &regexp.regex.pattern.pattern {
-                pattern
-            } else {
-                match regexp.parse_pattern(ctx.ast.allocator) {
-                    Ok(pattern) => {
-                        owned_pattern = Some(pattern);
-                        owned_pattern.as_ref().unwrap()
-                    }
-                    Err(error) => {
-                        self.ctx.error(error);
-                        return;
-                    }
+            let pattern = match regexp.pattern(ctx.ast.allocator) {
+                Ok(pattern) => pattern,
+                Err(error) => {
+                    self.ctx.error(error);
+                    return;
                 }
             };
 
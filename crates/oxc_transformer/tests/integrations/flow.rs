@@ -0,0 +1,52 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{TransformOptions, Transformer};
+
+fn strip_flow(source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let options = ParseOptions { allow_flow_syntax: true, ..ParseOptions::default() };
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).with_options(options).parse();
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, Path::new(""), &TransformOptions::default())
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+#[test]
+fn strip_flow_types() {
+    let source_text = "
+// @flow
+import typeof TReact from 'react';
+import type { Node } from 'react';
+import { useState } from 'react';
+type Props = {| name: ?string, items: Array<number> | null |};
+function isString(x: mixed): boolean %checks {
+  return typeof x === 'string';
+}
+export function first<T: Object>(items: ?T[], props: Props): ?T {
+  const [state] = useState<?T>(null);
+  return (items: any)[0] ?? state;
+}
+";
+    let expected = "import { useState } from 'react';
+function isString(x) {
+\treturn typeof x === 'string';
+}
+export function first(items, props) {
+\tconst [state] = useState(null);
+\treturn items[0] ?? state;
+}
+";
+    assert_eq!(strip_flow(source_text), expected);
+}
@@ -194,133 +194,136 @@ pub(crate) enum AncestorType {
     ExportSpecifierExported = 170,
     V8IntrinsicExpressionName = 171,
     V8IntrinsicExpressionArguments = 172,
-    JSXElementOpeningElement = 173,
-    JSXElementChildren = 174,
-    JSXElementClosingElement = 175,
-    JSXOpeningElementName = 176,
-    JSXOpeningElementTypeArguments = 177,
-    JSXOpeningElementAttributes = 178,
-    JSXClosingElementName = 179,
-    JSXFragmentOpeningFragment = 180,
-    JSXFragmentChildren = 181,
-    JSXFragmentClosingFragment = 182,
-    JSXNamespacedNameNamespace = 183,
-    JSXNamespacedNameName = 184,
-    JSXMemberExpressionObject = 185,
-    JSXMemberExpressionProperty = 186,
-    JSXExpressionContainerExpression = 187,
-    JSXAttributeName = 188,
-    JSXAttributeValue = 189,
-    JSXSpreadAttributeArgument = 190,
-    JSXSpreadChildExpression = 191,
-    TSThisParameterTypeAnnotation = 192,
-    TSEnumDeclarationId = 193,
-    TSEnumDeclarationBody = 194,
-    TSEnumBodyMembers = 195,
-    TSEnumMemberId = 196,
-    TSEnumMemberInitializer = 197,
-    TSTypeAnnotationTypeAnnotation = 198,
-    TSLiteralTypeLiteral = 199,
-    TSConditionalTypeCheckType = 200,
-    TSConditionalTypeExtendsType = 201,
-    TSConditionalTypeTrueType = 202,
-    TSConditionalTypeFalseType = 203,
-    TSUnionTypeTypes = 204,
-    TSIntersectionTypeTypes = 205,
-    TSParenthesizedTypeTypeAnnotation = 206,
-    TSTypeOperatorTypeAnnotation = 207,
-    TSArrayTypeElementType = 208,
-    TSIndexedAccessTypeObjectType = 209,
-    TSIndexedAccessTypeIndexType = 210,
-    TSTupleTypeElementTypes = 211,
-    TSNamedTupleMemberLabel = 212,
-    TSNamedTupleMemberElementType = 213,
-    TSOptionalTypeTypeAnnotation = 214,
-    TSRestTypeTypeAnnotation = 215,
-    TSTypeReferenceTypeName = 216,
-    TSTypeReferenceTypeArguments = 217,
-    TSQualifiedNameLeft = 218,
-    TSQualifiedNameRight = 219,
-    TSTypeParameterInstantiationParams = 220,
-    TSTypeParameterName = 221,
-    TSTypeParameterConstraint = 222,
-    TSTypeParameterDefault = 223,
-    TSTypeParameterDeclarationParams = 224,
-    TSTypeAliasDeclarationId = 225,
-    TSTypeAliasDeclarationTypeParameters = 226,
-    TSTypeAliasDeclarationTypeAnnotation = 227,
-    TSClassImplementsExpression = 228,
-    TSClassImplementsTypeArguments = 229,
-    TSInterfaceDeclarationId = 230,
-    TSInterfaceDeclarationTypeParameters = 231,
-    TSInterfaceDeclarationExtends = 232,
-    TSInterfaceDeclarationBody = 233,
-    TSInterfaceBodyBody = 234,
-    TSPropertySignatureKey = 235,
-    TSPropertySignatureTypeAnnotation = 236,
-    TSIndexSignatureParameters = 237,
-    TSIndexSignatureTypeAnnotation = 238,
-    TSCallSignatureDeclarationTypeParameters = 239,
-    TSCallSignatureDeclarationThisParam = 240,
-    TSCallSignatureDeclarationParams = 241,
-    TSCallSignatureDeclarationReturnType = 242,
-    TSMethodSignatureKey = 243,
-    TSMethodSignatureTypeParameters = 244,
-    TSMethodSignatureThisParam = 245,
-    TSMethodSignatureParams = 246,
-    TSMethodSignatureReturnType = 247,
-    TSConstructSignatureDeclarationTypeParameters = 248,
-    TSConstructSignatureDeclarationParams = 249,
-    TSConstructSignatureDeclarationReturnType = 250,
-    TSIndexSignatureNameTypeAnnotation = 251,
-    TSInterfaceHeritageExpression = 252,
-    TSInterfaceHeritageTypeArguments = 253,
-    TSTypePredicateParameterName = 254,
-    TSTypePredicateTypeAnnotation = 255,
-    TSModuleDeclarationId = 256,
-    TSModuleDeclarationBody = 257,
-    TSGlobalDeclarationBody = 258,
-    TSModuleBlockDirectives = 259,
-    TSModuleBlockBody = 260,
-    TSTypeLiteralMembers = 261,
-    TSInferTypeTypeParameter = 262,
-    TSTypeQueryExprName = 263,
-    TSTypeQueryTypeArguments = 264,
-    TSImportTypeSource = 265,
-    TSImportTypeOptions = 266,
-    TSImportTypeQualifier = 267,
-    TSImportTypeTypeArguments = 268,
-    TSImportTypeQualifiedNameLeft = 269,
-    TSImportTypeQualifiedNameRight = 270,
-    TSFunctionTypeTypeParameters = 271,
-    TSFunctionTypeThisParam = 272,
-    TSFunctionTypeParams = 273,
-    TSFunctionTypeReturnType = 274,
-    TSConstructorTypeTypeParameters = 275,
-    TSConstructorTypeParams = 276,
-    TSConstructorTypeReturnType = 277,
-    TSMappedTypeTypeParameter = 278,
-    TSMappedTypeNameType = 279,
-    TSMappedTypeTypeAnnotation = 280,
-    TSTemplateLiteralTypeQuasis = 281,
-    TSTemplateLiteralTypeTypes = 282,
-    TSAsExpressionExpression = 283,
-    TSAsExpressionTypeAnnotation = 284,
-    TSSatisfiesExpressionExpression = 285,
-    TSSatisfiesExpressionTypeAnnotation = 286,
-    TSTypeAssertionTypeAnnotation = 287,
-    TSTypeAssertionExpression = 288,
-    TSImportEqualsDeclarationId = 289,
-    TSImportEqualsDeclarationModuleReference = 290,
-    TSExternalModuleReferenceExpression = 291,
-    TSNonNullExpressionExpression = 292,
-    DecoratorExpression = 293,
-    TSExportAssignmentExpression = 294,
-    TSNamespaceExportDeclarationId = 295,
-    TSInstantiationExpressionExpression = 296,
-    TSInstantiationExpressionTypeArguments = 297,
-    JSDocNullableTypeTypeAnnotation = 298,
-    JSDocNonNullableTypeTypeAnnotation = 299,
+    PipelineExpressionHead = 173,
+    PipelineExpressionBody = 174,
+    DoExpressionBody = 175,
+    JSXElementOpeningElement = 176,
+    JSXElementChildren = 177,
+    JSXElementClosingElement = 178,
+    JSXOpeningElementName = 179,
+    JSXOpeningElementTypeArguments = 180,
+    JSXOpeningElementAttributes = 181,
+    JSXClosingElementName = 182,
+    JSXFragmentOpeningFragment = 183,
+    JSXFragmentChildren = 184,
+    JSXFragmentClosingFragment = 185,
+    JSXNamespacedNameNamespace = 186,
+    JSXNamespacedNameName = 187,
+    JSXMemberExpressionObject = 188,
+    JSXMemberExpressionProperty = 189,
+    JSXExpressionContainerExpression = 190,
+    JSXAttributeName = 191,
+    JSXAttributeValue = 192,
+    JSXSpreadAttributeArgument = 193,
+    JSXSpreadChildExpression = 194,
+    TSThisParameterTypeAnnotation = 195,
+    TSEnumDeclarationId = 196,
+    TSEnumDeclarationBody = 197,
+    TSEnumBodyMembers = 198,
+    TSEnumMemberId = 199,
+    TSEnumMemberInitializer = 200,
+    TSTypeAnnotationTypeAnnotation = 201,
+    TSLiteralTypeLiteral = 202,
+    TSConditionalTypeCheckType = 203,
+    TSConditionalTypeExtendsType = 204,
+    TSConditionalTypeTrueType = 205,
+    TSConditionalTypeFalseType = 206,
+    TSUnionTypeTypes = 207,
+    TSIntersectionTypeTypes = 208,
+    TSParenthesizedTypeTypeAnnotation = 209,
+    TSTypeOperatorTypeAnnotation = 210,
+    TSArrayTypeElementType = 211,
+    TSIndexedAccessTypeObjectType = 212,
+    TSIndexedAccessTypeIndexType = 213,
+    TSTupleTypeElementTypes = 214,
+    TSNamedTupleMemberLabel = 215,
+    TSNamedTupleMemberElementType = 216,
+    TSOptionalTypeTypeAnnotation = 217,
+    TSRestTypeTypeAnnotation = 218,
+    TSTypeReferenceTypeName = 219,
+    TSTypeReferenceTypeArguments = 220,
+    TSQualifiedNameLeft = 221,
+    TSQualifiedNameRight = 222,
+    TSTypeParameterInstantiationParams = 223,
+    TSTypeParameterName = 224,
+    TSTypeParameterConstraint = 225,
+    TSTypeParameterDefault = 226,
+    TSTypeParameterDeclarationParams = 227,
+    TSTypeAliasDeclarationId = 228,
+    TSTypeAliasDeclarationTypeParameters = 229,
+    TSTypeAliasDeclarationTypeAnnotation = 230,
+    TSClassImplementsExpression = 231,
+    TSClassImplementsTypeArguments = 232,
+    TSInterfaceDeclarationId = 233,
+    TSInterfaceDeclarationTypeParameters = 234,
+    TSInterfaceDeclarationExtends = 235,
+    TSInterfaceDeclarationBody = 236,
+    TSInterfaceBodyBody = 237,
+    TSPropertySignatureKey = 238,
+    TSPropertySignatureTypeAnnotation = 239,
+    TSIndexSignatureParameters = 240,
+    TSIndexSignatureTypeAnnotation = 241,
+    TSCallSignatureDeclarationTypeParameters = 242,
+    TSCallSignatureDeclarationThisParam = 243,
+    TSCallSignatureDeclarationParams = 244,
+    TSCallSignatureDeclarationReturnType = 245,
+    TSMethodSignatureKey = 246,
+    TSMethodSignatureTypeParameters = 247,
+    TSMethodSignatureThisParam = 248,
+    TSMethodSignatureParams = 249,
+    TSMethodSignatureReturnType = 250,
+    TSConstructSignatureDeclarationTypeParameters = 251,
+    TSConstructSignatureDeclarationParams = 252,
+    TSConstructSignatureDeclarationReturnType = 253,
+    TSIndexSignatureNameTypeAnnotation = 254,
+    TSInterfaceHeritageExpression = 255,
+    TSInterfaceHeritageTypeArguments = 256,
+    TSTypePredicateParameterName = 257,
+    TSTypePredicateTypeAnnotation = 258,
+    TSModuleDeclarationId = 259,
+    TSModuleDeclarationBody = 260,
+    TSGlobalDeclarationBody = 261,
+    TSModuleBlockDirectives = 262,
+    TSModuleBlockBody = 263,
+    TSTypeLiteralMembers = 264,
+    TSInferTypeTypeParameter = 265,
+    TSTypeQueryExprName = 266,
+    TSTypeQueryTypeArguments = 267,
+    TSImportTypeSource = 268,
+    TSImportTypeOptions = 269,
+    TSImportTypeQualifier = 270,
+    TSImportTypeTypeArguments = 271,
+    TSImportTypeQualifiedNameLeft = 272,
+    TSImportTypeQualifiedNameRight = 273,
+    TSFunctionTypeTypeParameters = 274,
+    TSFunctionTypeThisParam = 275,
+    TSFunctionTypeParams = 276,
+    TSFunctionTypeReturnType = 277,
+    TSConstructorTypeTypeParameters = 278,
+    TSConstructorTypeParams = 279,
+    TSConstructorTypeReturnType = 280,
+    TSMappedTypeTypeParameter = 281,
+    TSMappedTypeNameType = 282,
+    TSMappedTypeTypeAnnotation = 283,
+    TSTemplateLiteralTypeQuasis = 284,
+    TSTemplateLiteralTypeTypes = 285,
+    TSAsExpressionExpression = 286,
+    TSAsExpressionTypeAnnotation = 287,
+    TSSatisfiesExpressionExpression = 288,
+    TSSatisfiesExpressionTypeAnnotation = 289,
+    TSTypeAssertionTypeAnnotation = 290,
+    TSTypeAssertionExpression = 291,
+    TSImportEqualsDeclarationId = 292,
+    TSImportEqualsDeclarationModuleReference = 293,
+    TSExternalModuleReferenceExpression = 294,
+    TSNonNullExpressionExpression = 295,
+    DecoratorExpression = 296,
+    TSExportAssignmentExpression = 297,
+    TSNamespaceExportDeclarationId = 298,
+    TSInstantiationExpressionExpression = 299,
+    TSInstantiationExpressionTypeArguments = 300,
+    JSDocNullableTypeTypeAnnotation = 301,
+    JSDocNonNullableTypeTypeAnnotation = 302,
 }
 
 /// Ancestor type used in AST traversal.
@@ -652,6 +655,11 @@ pub enum Ancestor<'a, 't> {
         AncestorType::V8IntrinsicExpressionName as u16,
     V8IntrinsicExpressionArguments(V8IntrinsicExpressionWithoutArguments<'a, 't>) =
         AncestorType::V8IntrinsicExpressionArguments as u16,
+    PipelineExpressionHead(PipelineExpressionWithoutHead<'a, 't>) =
+        AncestorType::PipelineExpressionHead as u16,
+    PipelineExpressionBody(PipelineExpressionWithoutBody<'a, 't>) =
+        AncestorType::PipelineExpressionBody as u16,
+    DoExpressionBody(DoExpressionWithoutBody<'a, 't>) = AncestorType::DoExpressionBody as u16,
     JSXElementOpeningElement(JSXElementWithoutOpeningElement<'a, 't>) =
         AncestorType::JSXElementOpeningElement as u16,
     JSXElementChildren(JSXElementWithoutChildren<'a, 't>) = AncestorType::JSXElementChildren as u16,
@@ -1451,6 +1459,16 @@ impl<'a, 't> Ancestor<'a, 't> {
         matches!(self, Self::V8IntrinsicExpressionName(_) | Self::V8IntrinsicExpressionArguments(_))
     }
 
+    #[inline]
+    pub fn is_pipeline_expression(self) -> bool {
+        matches!(self, Self::PipelineExpressionHead(_) | Self::PipelineExpressionBody(_))
+    }
+
+    #[inline]
+    pub fn is_do_expression(self) -> bool {
+        matches!(self, Self::DoExpressionBody(_))
+    }
+
     #[inline]
     pub fn is_jsx_element(self) -> bool {
         matches!(
@@ -1995,6 +2013,8 @@ impl<'a, 't> Ancestor<'a, 't> {
                 | Self::AccessorPropertyValue(_)
                 | Self::ImportExpressionSource(_)
                 | Self::ImportExpressionOptions(_)
+                | Self::PipelineExpressionHead(_)
+                | Self::PipelineExpressionBody(_)
                 | Self::JSXSpreadAttributeArgument(_)
                 | Self::JSXSpreadChildExpression(_)
                 | Self::TSEnumMemberInitializer(_)
@@ -2421,6 +2441,9 @@ impl<'a, 't> GetAddress for Ancestor<'a, 't> {
             Self::ExportSpecifierExported(a) => a.address(),
             Self::V8IntrinsicExpressionName(a) => a.address(),
             Self::V8IntrinsicExpressionArguments(a) => a.address(),
+            Self::PipelineExpressionHead(a) => a.address(),
+            Self::PipelineExpressionBody(a) => a.address(),
+            Self::DoExpressionBody(a) => a.address(),
             Self::JSXElementOpeningElement(a) => a.address(),
             Self::JSXElementChildren(a) => a.address(),
             Self::JSXElementClosingElement(a) => a.address(),
@@ -10784,6 +10807,90 @@ impl<'a, 't> GetAddress for V8IntrinsicExpressionWithoutArguments<'a, 't> {
     }
 }
 
+pub(crate) const OFFSET_PIPELINE_EXPRESSION_SPAN: usize = offset_of!(PipelineExpression, span);
+pub(crate) const OFFSET_PIPELINE_EXPRESSION_HEAD: usize = offset_of!(PipelineExpression, head);
+pub(crate) const OFFSET_PIPELINE_EXPRESSION_BODY: usize = offset_of!(PipelineExpression, body);
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct PipelineExpressionWithoutHead<'a, 't>(
+    pub(crate) *const PipelineExpression<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> PipelineExpressionWithoutHead<'a, 't> {
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe { &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_SPAN) as *const Span) }
+    }
+
+    #[inline]
+    pub fn body(self) -> &'t Expression<'a> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_BODY) as *const Expression<'a>)
+        }
+    }
+}
+
+impl<'a, 't> GetAddress for PipelineExpressionWithoutHead<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        unsafe { Address::from_ptr(self.0) }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct PipelineExpressionWithoutBody<'a, 't>(
+    pub(crate) *const PipelineExpression<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> PipelineExpressionWithoutBody<'a, 't> {
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe { &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_SPAN) as *const Span) }
+    }
+
+    #[inline]
+    pub fn head(self) -> &'t Expression<'a> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_HEAD) as *const Expression<'a>)
+        }
+    }
+}
+
+impl<'a, 't> GetAddress for PipelineExpressionWithoutBody<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        unsafe { Address::from_ptr(self.0) }
+    }
+}
+
+pub(crate) const OFFSET_DO_EXPRESSION_SPAN: usize = offset_of!(DoExpression, span);
+pub(crate) const OFFSET_DO_EXPRESSION_BODY: usize = offset_of!(DoExpression, body);
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct DoExpressionWithoutBody<'a, 't>(
+    pub(crate) *const DoExpression<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> DoExpressionWithoutBody<'a, 't> {
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe { &*((self.0 as *const u8).add(OFFSET_DO_EXPRESSION_SPAN) as *const Span) }
+    }
+}
+
+impl<'a, 't> GetAddress for DoExpressionWithoutBody<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        unsafe { Address::from_ptr(self.0) }
+    }
+}
+
 pub(crate) const OFFSET_JSX_ELEMENT_SPAN: usize = offset_of!(JSXElement, span);
 pub(crate) const OFFSET_JSX_ELEMENT_OPENING_ELEMENT: usize =
     offset_of!(JSXElement, opening_element);
@@ -74,6 +74,8 @@ impl<'a> Visit<'a> for ChildScopeCollector {
             Expression::TSNonNullExpression(it) => self.visit_ts_non_null_expression(it),
             Expression::TSInstantiationExpression(it) => self.visit_ts_instantiation_expression(it),
             Expression::V8IntrinsicExpression(it) => self.visit_v_8_intrinsic_expression(it),
+            Expression::PipelineExpression(it) => self.visit_pipeline_expression(it),
+            Expression::DoExpression(it) => self.visit_do_expression(it),
             Expression::ComputedMemberExpression(it) => self.visit_computed_member_expression(it),
             Expression::StaticMemberExpression(it) => self.visit_static_member_expression(it),
             Expression::PrivateFieldExpression(it) => self.visit_private_field_expression(it),
@@ -89,6 +91,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -177,6 +180,8 @@ impl<'a> Visit<'a> for ChildScopeCollector {
             ArrayExpressionElement::V8IntrinsicExpression(it) => {
                 self.visit_v_8_intrinsic_expression(it)
             }
+            ArrayExpressionElement::PipelineExpression(it) => self.visit_pipeline_expression(it),
+            ArrayExpressionElement::DoExpression(it) => self.visit_do_expression(it),
             ArrayExpressionElement::ComputedMemberExpression(it) => {
                 self.visit_computed_member_expression(it)
             }
@@ -199,6 +204,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -256,6 +262,8 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 self.visit_ts_instantiation_expression(it)
             }
             PropertyKey::V8IntrinsicExpression(it) => self.visit_v_8_intrinsic_expression(it),
+            PropertyKey::PipelineExpression(it) => self.visit_pipeline_expression(it),
+            PropertyKey::DoExpression(it) => self.visit_do_expression(it),
             PropertyKey::ComputedMemberExpression(it) => self.visit_computed_member_expression(it),
             PropertyKey::StaticMemberExpression(it) => self.visit_static_member_expression(it),
             PropertyKey::PrivateFieldExpression(it) => self.visit_private_field_expression(it),
@@ -273,6 +281,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -376,6 +385,8 @@ impl<'a> Visit<'a> for ChildScopeCollector {
             Argument::TSNonNullExpression(it) => self.visit_ts_non_null_expression(it),
             Argument::TSInstantiationExpression(it) => self.visit_ts_instantiation_expression(it),
             Argument::V8IntrinsicExpression(it) => self.visit_v_8_intrinsic_expression(it),
+            Argument::PipelineExpression(it) => self.visit_pipeline_expression(it),
+            Argument::DoExpression(it) => self.visit_do_expression(it),
             Argument::ComputedMemberExpression(it) => self.visit_computed_member_expression(it),
             Argument::StaticMemberExpression(it) => self.visit_static_member_expression(it),
             Argument::PrivateFieldExpression(it) => self.visit_private_field_expression(it),
@@ -391,6 +402,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -764,6 +776,8 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 self.visit_ts_instantiation_expression(it)
             }
             ForStatementInit::V8IntrinsicExpression(it) => self.visit_v_8_intrinsic_expression(it),
+            ForStatementInit::PipelineExpression(it) => self.visit_pipeline_expression(it),
+            ForStatementInit::DoExpression(it) => self.visit_do_expression(it),
             ForStatementInit::ComputedMemberExpression(it) => {
                 self.visit_computed_member_expression(it)
             }
@@ -781,6 +795,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -1206,6 +1221,10 @@ impl<'a> Visit<'a> for ChildScopeCollector {
             ExportDefaultDeclarationKind::V8IntrinsicExpression(it) => {
                 self.visit_v_8_intrinsic_expression(it)
             }
+            ExportDefaultDeclarationKind::PipelineExpression(it) => {
+                self.visit_pipeline_expression(it)
+            }
+            ExportDefaultDeclarationKind::DoExpression(it) => self.visit_do_expression(it),
             ExportDefaultDeclarationKind::ComputedMemberExpression(it) => {
                 self.visit_computed_member_expression(it)
             }
@@ -1227,6 +1246,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -1241,6 +1261,22 @@ impl<'a> Visit<'a> for ChildScopeCollector {
         self.visit_arguments(&it.arguments);
     }
 
+    #[inline]
+    fn visit_pipeline_expression(&mut self, it: &PipelineExpression<'a>) {
+        self.visit_expression(&it.head);
+        self.visit_expression(&it.body);
+    }
+
+    #[inline(always)]
+    fn visit_topic_reference(&mut self, it: &TopicReference) {
+        // Struct does not contain a scope. Halt traversal.
+    }
+
+    #[inline]
+    fn visit_do_expression(&mut self, it: &DoExpression<'a>) {
+        self.visit_block_statement(&it.body);
+    }
+
     #[inline(always)]
     fn visit_boolean_literal(&mut self, it: &BooleanLiteral) {
         // Struct does not contain a scope. Halt traversal.
@@ -1369,6 +1405,8 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 self.visit_ts_instantiation_expression(it)
             }
             JSXExpression::V8IntrinsicExpression(it) => self.visit_v_8_intrinsic_expression(it),
+            JSXExpression::PipelineExpression(it) => self.visit_pipeline_expression(it),
+            JSXExpression::DoExpression(it) => self.visit_do_expression(it),
             JSXExpression::ComputedMemberExpression(it) => {
                 self.visit_computed_member_expression(it)
             }
@@ -1387,6 +1425,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -197,6 +197,15 @@ unsafe fn walk_expression<'a, State, Tr: Traverse<'a, State>>(
         Expression::V8IntrinsicExpression(node) => {
             walk_v8_intrinsic_expression(traverser, (&mut **node) as *mut _, ctx)
         }
+        Expression::PipelineExpression(node) => {
+            walk_pipeline_expression(traverser, (&mut **node) as *mut _, ctx)
+        }
+        Expression::TopicReference(node) => {
+            walk_topic_reference(traverser, (&mut **node) as *mut _, ctx)
+        }
+        Expression::DoExpression(node) => {
+            walk_do_expression(traverser, (&mut **node) as *mut _, ctx)
+        }
         Expression::ComputedMemberExpression(_)
         | Expression::StaticMemberExpression(_)
         | Expression::PrivateFieldExpression(_) => {
@@ -320,6 +329,9 @@ unsafe fn walk_array_expression_element<'a, State, Tr: Traverse<'a, State>>(
         | ArrayExpressionElement::TSNonNullExpression(_)
         | ArrayExpressionElement::TSInstantiationExpression(_)
         | ArrayExpressionElement::V8IntrinsicExpression(_)
+        | ArrayExpressionElement::PipelineExpression(_)
+        | ArrayExpressionElement::TopicReference(_)
+        | ArrayExpressionElement::DoExpression(_)
         | ArrayExpressionElement::ComputedMemberExpression(_)
         | ArrayExpressionElement::StaticMemberExpression(_)
         | ArrayExpressionElement::PrivateFieldExpression(_) => {
@@ -450,6 +462,9 @@ unsafe fn walk_property_key<'a, State, Tr: Traverse<'a, State>>(
         | PropertyKey::TSNonNullExpression(_)
         | PropertyKey::TSInstantiationExpression(_)
         | PropertyKey::V8IntrinsicExpression(_)
+        | PropertyKey::PipelineExpression(_)
+        | PropertyKey::TopicReference(_)
+        | PropertyKey::DoExpression(_)
         | PropertyKey::ComputedMemberExpression(_)
         | PropertyKey::StaticMemberExpression(_)
         | PropertyKey::PrivateFieldExpression(_) => walk_expression(traverser, node as *mut _, ctx),
@@ -773,6 +788,9 @@ unsafe fn walk_argument<'a, State, Tr: Traverse<'a, State>>(
         | Argument::TSNonNullExpression(_)
         | Argument::TSInstantiationExpression(_)
         | Argument::V8IntrinsicExpression(_)
+        | Argument::PipelineExpression(_)
+        | Argument::TopicReference(_)
+        | Argument::DoExpression(_)
         | Argument::ComputedMemberExpression(_)
         | Argument::StaticMemberExpression(_)
         | Argument::PrivateFieldExpression(_) => walk_expression(traverser, node as *mut _, ctx),
@@ -1733,6 +1751,9 @@ unsafe fn walk_for_statement_init<'a, State, Tr: Traverse<'a, State>>(
         | ForStatementInit::TSNonNullExpression(_)
         | ForStatementInit::TSInstantiationExpression(_)
         | ForStatementInit::V8IntrinsicExpression(_)
+        | ForStatementInit::PipelineExpression(_)
+        | ForStatementInit::TopicReference(_)
+        | ForStatementInit::DoExpression(_)
         | ForStatementInit::ComputedMemberExpression(_)
         | ForStatementInit::StaticMemberExpression(_)
         | ForStatementInit::PrivateFieldExpression(_) => {
@@ -3151,6 +3172,9 @@ unsafe fn walk_export_default_declaration_kind<'a, State, Tr: Traverse<'a, State
         | ExportDefaultDeclarationKind::TSNonNullExpression(_)
         | ExportDefaultDeclarationKind::TSInstantiationExpression(_)
         | ExportDefaultDeclarationKind::V8IntrinsicExpression(_)
+        | ExportDefaultDeclarationKind::PipelineExpression(_)
+        | ExportDefaultDeclarationKind::TopicReference(_)
+        | ExportDefaultDeclarationKind::DoExpression(_)
         | ExportDefaultDeclarationKind::ComputedMemberExpression(_)
         | ExportDefaultDeclarationKind::StaticMemberExpression(_)
         | ExportDefaultDeclarationKind::PrivateFieldExpression(_) => {
@@ -3204,6 +3228,59 @@ unsafe fn walk_v8_intrinsic_expression<'a, State, Tr: Traverse<'a, State>>(
     traverser.exit_v8_intrinsic_expression(&mut *node, ctx);
 }
 
+unsafe fn walk_pipeline_expression<'a, State, Tr: Traverse<'a, State>>(
+    traverser: &mut Tr,
+    node: *mut PipelineExpression<'a>,
+    ctx: &mut TraverseCtx<'a, State>,
+) {
+    traverser.enter_pipeline_expression(&mut *node, ctx);
+    let pop_token = ctx.push_stack(Ancestor::PipelineExpressionHead(
+        ancestor::PipelineExpressionWithoutHead(node, PhantomData),
+    ));
+    walk_expression(
+        traverser,
+        (node as *mut u8).add(ancestor::OFFSET_PIPELINE_EXPRESSION_HEAD) as *mut Expression,
+        ctx,
+    );
+    ctx.retag_stack(AncestorType::PipelineExpressionBody);
+    walk_expression(
+        traverser,
+        (node as *mut u8).add(ancestor::OFFSET_PIPELINE_EXPRESSION_BODY) as *mut Expression,
+        ctx,
+    );
+    ctx.pop_stack(pop_token);
+    traverser.exit_pipeline_expression(&mut *node, ctx);
+}
+
+unsafe fn walk_topic_reference<'a, State, Tr: Traverse<'a, State>>(
+    traverser: &mut Tr,
+    node: *mut TopicReference,
+    ctx: &mut TraverseCtx<'a, State>,
+) {
+    traverser.enter_topic_reference(&mut *node, ctx);
+    traverser.exit_topic_reference(&mut *node, ctx);
+}
+
+unsafe fn walk_do_expression<'a, State, Tr: Traverse<'a, State>>(
+    traverser: &mut Tr,
+    node: *mut DoExpression<'a>,
+    ctx: &mut TraverseCtx<'a, State>,
+) {
+    traverser.enter_do_expression(&mut *node, ctx);
+    let pop_token = ctx.push_stack(Ancestor::DoExpressionBody(ancestor::DoExpressionWithoutBody(
+        node,
+        PhantomData,
+    )));
+    walk_block_statement(
+        traverser,
+        (&mut **((node as *mut u8).add(ancestor::OFFSET_DO_EXPRESSION_BODY)
+            as *mut Box<BlockStatement>)) as *mut _,
+        ctx,
+    );
+    ctx.pop_stack(pop_token);
+    traverser.exit_do_expression(&mut *node, ctx);
+}
+
 unsafe fn walk_jsx_element<'a, State, Tr: Traverse<'a, State>>(
     traverser: &mut Tr,
     node: *mut JSXElement<'a>,
@@ -3499,6 +3576,9 @@ unsafe fn walk_jsx_expression<'a, State, Tr: Traverse<'a, State>>(
         | JSXExpression::TSNonNullExpression(_)
         | JSXExpression::TSInstantiationExpression(_)
         | JSXExpression::V8IntrinsicExpression(_)
+        | JSXExpression::PipelineExpression(_)
+        | JSXExpression::TopicReference(_)
+        | JSXExpression::DoExpression(_)
         | JSXExpression::ComputedMemberExpression(_)
         | JSXExpression::StaticMemberExpression(_)
         | JSXExpression::PrivateFieldExpression(_) => {
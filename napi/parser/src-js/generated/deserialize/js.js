@@ -126,6 +126,12 @@ function deserializeExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -282,6 +288,12 @@ function deserializeArrayExpressionElement(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -424,6 +436,12 @@ function deserializePropertyKey(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -693,6 +711,12 @@ function deserializeArgument(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -1425,6 +1449,12 @@ function deserializeForStatementInit(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2293,6 +2323,12 @@ function deserializeExportDefaultDeclarationKind(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2336,6 +2372,38 @@ function deserializeV8IntrinsicExpression(pos) {
   return node;
 }
 
+function deserializePipelineExpression(pos) {
+  let node = {
+    type: "PipelineExpression",
+    head: null,
+    body: null,
+    start: deserializeU32(pos),
+    end: deserializeU32(pos + 4),
+  };
+  node.head = deserializeExpression(pos + 8);
+  node.body = deserializeExpression(pos + 24);
+  return node;
+}
+
+function deserializeTopicReference(pos) {
+  return {
+    type: "TopicReference",
+    start: deserializeU32(pos),
+    end: deserializeU32(pos + 4),
+  };
+}
+
+function deserializeDoExpression(pos) {
+  let node = {
+    type: "DoExpression",
+    body: null,
+    start: deserializeU32(pos),
+    end: deserializeU32(pos + 4),
+  };
+  node.body = deserializeBoxBlockStatement(pos + 8);
+  return node;
+}
+
 function deserializeBooleanLiteral(pos) {
   let value = deserializeBool(pos + 8),
     start = deserializeU32(pos),
@@ -2707,6 +2775,12 @@ function deserializeJSXExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -4862,6 +4936,18 @@ function deserializeBoxV8IntrinsicExpression(pos) {
   return deserializeV8IntrinsicExpression(uint32[pos >> 2]);
 }
 
+function deserializeBoxPipelineExpression(pos) {
+  return deserializePipelineExpression(uint32[pos >> 2]);
+}
+
+function deserializeBoxTopicReference(pos) {
+  return deserializeTopicReference(uint32[pos >> 2]);
+}
+
+function deserializeBoxDoExpression(pos) {
+  return deserializeDoExpression(uint32[pos >> 2]);
+}
+
 function deserializeVecArrayExpressionElement(pos) {
   let arr = [],
     pos32 = pos >> 2;
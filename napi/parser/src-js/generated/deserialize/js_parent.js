@@ -134,6 +134,12 @@ function deserializeExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -312,6 +318,12 @@ function deserializeArrayExpressionElement(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -462,6 +474,12 @@ function deserializePropertyKey(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -769,6 +787,12 @@ function deserializeArgument(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -1620,6 +1644,12 @@ function deserializeForStatementInit(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2662,6 +2692,12 @@ function deserializeExportDefaultDeclarationKind(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2710,6 +2746,49 @@ function deserializeV8IntrinsicExpression(pos) {
   return node;
 }
 
+function deserializePipelineExpression(pos) {
+  let start = deserializeU32(pos),
+    end = deserializeU32(pos + 4),
+    previousParent = parent,
+    node = (parent = {
+      type: "PipelineExpression",
+      head: null,
+      body: null,
+      start,
+      end,
+      parent,
+    });
+  node.head = deserializeExpression(pos + 8);
+  node.body = deserializeExpression(pos + 24);
+  parent = previousParent;
+  return node;
+}
+
+function deserializeTopicReference(pos) {
+  return {
+    type: "TopicReference",
+    start: deserializeU32(pos),
+    end: deserializeU32(pos + 4),
+    parent,
+  };
+}
+
+function deserializeDoExpression(pos) {
+  let start = deserializeU32(pos),
+    end = deserializeU32(pos + 4),
+    previousParent = parent,
+    node = (parent = {
+      type: "DoExpression",
+      body: null,
+      start,
+      end,
+      parent,
+    });
+  node.body = deserializeBoxBlockStatement(pos + 8);
+  parent = previousParent;
+  return node;
+}
+
 function deserializeBooleanLiteral(pos) {
   let value = deserializeBool(pos + 8),
     start = deserializeU32(pos),
@@ -3142,6 +3221,12 @@ function deserializeJSXExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -5596,6 +5681,18 @@ function deserializeBoxV8IntrinsicExpression(pos) {
   return deserializeV8IntrinsicExpression(uint32[pos >> 2]);
 }
 
+function deserializeBoxPipelineExpression(pos) {
+  return deserializePipelineExpression(uint32[pos >> 2]);
+}
+
+function deserializeBoxTopicReference(pos) {
+  return deserializeTopicReference(uint32[pos >> 2]);
+}
+
+function deserializeBoxDoExpression(pos) {
+  return deserializeDoExpression(uint32[pos >> 2]);
+}
+
 function deserializeVecArrayExpressionElement(pos) {
   let arr = [],
     pos32 = pos >> 2;
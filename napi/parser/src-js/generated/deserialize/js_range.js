@@ -127,6 +127,12 @@ function deserializeExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -293,6 +299,12 @@ function deserializeArrayExpressionElement(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -439,6 +451,12 @@ function deserializePropertyKey(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -728,6 +746,12 @@ function deserializeArgument(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -1530,6 +1554,12 @@ function deserializeForStatementInit(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2496,6 +2526,12 @@ function deserializeExportDefaultDeclarationKind(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2542,6 +2578,47 @@ function deserializeV8IntrinsicExpression(pos) {
   return node;
 }
 
+function deserializePipelineExpression(pos) {
+  let start = deserializeU32(pos),
+    end = deserializeU32(pos + 4),
+    node = {
+      type: "PipelineExpression",
+      head: null,
+      body: null,
+      start,
+      end,
+      range: [start, end],
+    };
+  node.head = deserializeExpression(pos + 8);
+  node.body = deserializeExpression(pos + 24);
+  return node;
+}
+
+function deserializeTopicReference(pos) {
+  let start = deserializeU32(pos),
+    end = deserializeU32(pos + 4);
+  return {
+    type: "TopicReference",
+    start,
+    end,
+    range: [start, end],
+  };
+}
+
+function deserializeDoExpression(pos) {
+  let start = deserializeU32(pos),
+    end = deserializeU32(pos + 4),
+    node = {
+      type: "DoExpression",
+      body: null,
+      start,
+      end,
+      range: [start, end],
+    };
+  node.body = deserializeBoxBlockStatement(pos + 8);
+  return node;
+}
+
 function deserializeBooleanLiteral(pos) {
   let value = deserializeBool(pos + 8),
     start = deserializeU32(pos),
@@ -2950,6 +3027,12 @@ function deserializeJSXExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -5328,6 +5411,18 @@ function deserializeBoxV8IntrinsicExpression(pos) {
   return deserializeV8IntrinsicExpression(uint32[pos >> 2]);
 }
 
+function deserializeBoxPipelineExpression(pos) {
+  return deserializePipelineExpression(uint32[pos >> 2]);
+}
+
+function deserializeBoxTopicReference(pos) {
+  return deserializeTopicReference(uint32[pos >> 2]);
+}
+
+function deserializeBoxDoExpression(pos) {
+  return deserializeDoExpression(uint32[pos >> 2]);
+}
+
 function deserializeVecArrayExpressionElement(pos) {
   let arr = [],
     pos32 = pos >> 2;
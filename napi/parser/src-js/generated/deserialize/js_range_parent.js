@@ -135,6 +135,12 @@ function deserializeExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -321,6 +327,12 @@ function deserializeArrayExpressionElement(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -473,6 +485,12 @@ function deserializePropertyKey(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -790,6 +808,12 @@ function deserializeArgument(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -1680,6 +1704,12 @@ function deserializeForStatementInit(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2768,6 +2798,12 @@ function deserializeExportDefaultDeclarationKind(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2817,6 +2853,54 @@ function deserializeV8IntrinsicExpression(pos) {
   return node;
 }
 
+function deserializePipelineExpression(pos) {
+  let start = deserializeU32(pos),
+    end = deserializeU32(pos + 4),
+    previousParent = parent,
+    node = (parent = {
+      type: "PipelineExpression",
+      head: null,
+      body: null,
+      start,
+      end,
+      range: [start, end],
+      parent,
+    });
+  node.head = deserializeExpression(pos + 8);
+  node.body = deserializeExpression(pos + 24);
+  parent = previousParent;
+  return node;
+}
+
+function deserializeTopicReference(pos) {
+  let start = deserializeU32(pos),
+    end = deserializeU32(pos + 4);
+  return {
+    type: "TopicReference",
+    start,
+    end,
+    range: [start, end],
+    parent,
+  };
+}
+
+function deserializeDoExpression(pos) {
+  let start = deserializeU32(pos),
+    end = deserializeU32(pos + 4),
+    previousParent = parent,
+    node = (parent = {
+      type: "DoExpression",
+      body: null,
+      start,
+      end,
+      range: [start, end],
+      parent,
+    });
+  node.body = deserializeBoxBlockStatement(pos + 8);
+  parent = previousParent;
+  return node;
+}
+
 function deserializeBooleanLiteral(pos) {
   let value = deserializeBool(pos + 8),
     start = deserializeU32(pos),
@@ -3270,6 +3354,12 @@ function deserializeJSXExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 41:
+      return deserializeBoxTopicReference(pos + 8);
+    case 42:
+      return deserializeBoxDoExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -5865,6 +5955,18 @@ function deserializeBoxV8IntrinsicExpression(pos) {
   return deserializeV8IntrinsicExpression(uint32[pos >> 2]);
 }
 
+function deserializeBoxPipelineExpression(pos) {
+  return deserializePipelineExpression(uint32[pos >> 2]);
+}
+
+function deserializeBoxTopicReference(pos) {
+  return deserializeTopicReference(uint32[pos >> 2]);
+}
+
+function deserializeBoxDoExpression(pos) {
+  return deserializeDoExpression(uint32[pos >> 2]);
+}
+
 function deserializeVecArrayExpressionElement(pos) {
   let arr = [],
     pos32 = pos >> 2;
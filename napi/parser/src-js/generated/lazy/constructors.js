@@ -151,6 +151,12 @@ function constructExpression(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 41:
+      return constructBoxTopicReference(pos + 8, ast);
+    case 42:
+      return constructBoxDoExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -527,6 +533,12 @@ function constructArrayExpressionElement(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 41:
+      return constructBoxTopicReference(pos + 8, ast);
+    case 42:
+      return constructBoxDoExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -799,6 +811,12 @@ function constructPropertyKey(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 41:
+      return constructBoxTopicReference(pos + 8, ast);
+    case 42:
+      return constructBoxDoExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -1528,6 +1546,12 @@ function constructArgument(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 41:
+      return constructBoxTopicReference(pos + 8, ast);
+    case 42:
+      return constructBoxDoExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -3371,6 +3395,12 @@ function constructForStatementInit(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 41:
+      return constructBoxTopicReference(pos + 8, ast);
+    case 42:
+      return constructBoxDoExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -6181,6 +6211,12 @@ function constructExportDefaultDeclarationKind(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 41:
+      return constructBoxTopicReference(pos + 8, ast);
+    case 42:
+      return constructBoxDoExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -6267,6 +6303,144 @@ export class V8IntrinsicExpression {
 
 const DebugV8IntrinsicExpression = class V8IntrinsicExpression {};
 
+export class PipelineExpression {
+  type = "PipelineExpression";
+  #internal;
+
+  constructor(pos, ast) {
+    if (ast?.token !== TOKEN) constructorError();
+
+    const { nodes } = ast;
+    const cached = nodes.get(pos);
+    if (cached !== void 0) return cached;
+
+    this.#internal = { pos, ast };
+    nodes.set(pos, this);
+  }
+
+  get start() {
+    const internal = this.#internal;
+    return constructU32(internal.pos, internal.ast);
+  }
+
+  get end() {
+    const internal = this.#internal;
+    return constructU32(internal.pos + 4, internal.ast);
+  }
+
+  get head() {
+    const internal = this.#internal;
+    return constructExpression(internal.pos + 8, internal.ast);
+  }
+
+  get body() {
+    const internal = this.#internal;
+    return constructExpression(internal.pos + 24, internal.ast);
+  }
+
+  toJSON() {
+    return {
+      type: "PipelineExpression",
+      start: this.start,
+      end: this.end,
+      head: this.head,
+      body: this.body,
+    };
+  }
+
+  [inspectSymbol]() {
+    return Object.setPrototypeOf(this.toJSON(), DebugPipelineExpression.prototype);
+  }
+}
+
+const DebugPipelineExpression = class PipelineExpression {};
+
+export class TopicReference {
+  type = "TopicReference";
+  #internal;
+
+  constructor(pos, ast) {
+    if (ast?.token !== TOKEN) constructorError();
+
+    const { nodes } = ast;
+    const cached = nodes.get(pos);
+    if (cached !== void 0) return cached;
+
+    this.#internal = { pos, ast };
+    nodes.set(pos, this);
+  }
+
+  get start() {
+    const internal = this.#internal;
+    return constructU32(internal.pos, internal.ast);
+  }
+
+  get end() {
+    const internal = this.#internal;
+    return constructU32(internal.pos + 4, internal.ast);
+  }
+
+  toJSON() {
+    return {
+      type: "TopicReference",
+      start: this.start,
+      end: this.end,
+    };
+  }
+
+  [inspectSymbol]() {
+    return Object.setPrototypeOf(this.toJSON(), DebugTopicReference.prototype);
+  }
+}
+
+const DebugTopicReference = class TopicReference {};
+
+export class DoExpression {
+  type = "DoExpression";
+  #internal;
+
+  constructor(pos, ast) {
+    if (ast?.token !== TOKEN) constructorError();
+
+    const { nodes } = ast;
+    const cached = nodes.get(pos);
+    if (cached !== void 0) return cached;
+
+    this.#internal = { pos, ast };
+    nodes.set(pos, this);
+  }
+
+  get start() {
+    const internal = this.#internal;
+    return constructU32(internal.pos, internal.ast);
+  }
+
+  get end() {
+    const internal = this.#internal;
+    return constructU32(internal.pos + 4, internal.ast);
+  }
+
+  get body() {
+    const internal = this.#internal;
+    return constructBoxBlockStatement(internal.pos + 8, internal.ast);
+  }
+
+  toJSON() {
+    return {
+      type: "DoExpression",
+      start: this.start,
+      end: this.end,
+      body: this.body,
+    };
+  }
+
+  [inspectSymbol]() {
+    return Object.setPrototypeOf(this.toJSON(), DebugDoExpression.prototype);
+  }
+}
+
+const DebugDoExpression = class DoExpression {};
+
 export class BooleanLiteral {
   type = "BooleanLiteral";
   #internal;
@@ -7239,6 +7413,12 @@ function constructJSXExpression(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 41:
+      return constructBoxTopicReference(pos + 8, ast);
+    case 42:
+      return constructBoxDoExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -12825,6 +13005,18 @@ function constructBoxV8IntrinsicExpression(pos, ast) {
   return new V8IntrinsicExpression(ast.buffer.uint32[pos >> 2], ast);
 }
 
+function constructBoxPipelineExpression(pos, ast) {
+  return new PipelineExpression(ast.buffer.uint32[pos >> 2], ast);
+}
+
+function constructBoxTopicReference(pos, ast) {
+  return new TopicReference(ast.buffer.uint32[pos >> 2], ast);
+}
+
+function constructBoxDoExpression(pos, ast) {
+  return new DoExpression(ast.buffer.uint32[pos >> 2], ast);
+}
+
 function constructVecArrayExpressionElement(pos, ast) {
   const { uint32 } = ast.buffer,
     pos32 = pos >> 2;
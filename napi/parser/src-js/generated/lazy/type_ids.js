@@ -16,176 +16,179 @@ export const NODE_TYPE_IDS_MAP = new Map([
   ["EmptyStatement", 9],
   ["DebuggerStatement", 10],
   ["PrivateIdentifier", 11],
-  ["BooleanLiteral", 12],
-  ["NullLiteral", 13],
-  ["NumericLiteral", 14],
-  ["StringLiteral", 15],
-  ["BigIntLiteral", 16],
-  ["RegExpLiteral", 17],
-  ["JSXOpeningFragment", 18],
-  ["JSXClosingFragment", 19],
-  ["JSXEmptyExpression", 20],
-  ["JSXIdentifier", 21],
-  ["JSXText", 22],
-  ["TSAnyKeyword", 23],
-  ["TSStringKeyword", 24],
-  ["TSBooleanKeyword", 25],
-  ["TSNumberKeyword", 26],
-  ["TSNeverKeyword", 27],
-  ["TSIntrinsicKeyword", 28],
-  ["TSUnknownKeyword", 29],
-  ["TSNullKeyword", 30],
-  ["TSUndefinedKeyword", 31],
-  ["TSVoidKeyword", 32],
-  ["TSSymbolKeyword", 33],
-  ["TSThisType", 34],
-  ["TSObjectKeyword", 35],
-  ["TSBigIntKeyword", 36],
-  ["JSDocUnknownType", 37],
+  ["TopicReference", 12],
+  ["BooleanLiteral", 13],
+  ["NullLiteral", 14],
+  ["NumericLiteral", 15],
+  ["StringLiteral", 16],
+  ["BigIntLiteral", 17],
+  ["RegExpLiteral", 18],
+  ["JSXOpeningFragment", 19],
+  ["JSXClosingFragment", 20],
+  ["JSXEmptyExpression", 21],
+  ["JSXIdentifier", 22],
+  ["JSXText", 23],
+  ["TSAnyKeyword", 24],
+  ["TSStringKeyword", 25],
+  ["TSBooleanKeyword", 26],
+  ["TSNumberKeyword", 27],
+  ["TSNeverKeyword", 28],
+  ["TSIntrinsicKeyword", 29],
+  ["TSUnknownKeyword", 30],
+  ["TSNullKeyword", 31],
+  ["TSUndefinedKeyword", 32],
+  ["TSVoidKeyword", 33],
+  ["TSSymbolKeyword", 34],
+  ["TSThisType", 35],
+  ["TSObjectKeyword", 36],
+  ["TSBigIntKeyword", 37],
+  ["JSDocUnknownType", 38],
   // Non-leaf nodes
-  ["Program", 38],
-  ["ArrayExpression", 39],
-  ["ObjectExpression", 40],
-  ["ObjectProperty", 41],
-  ["TemplateLiteral", 42],
-  ["TaggedTemplateExpression", 43],
-  ["ComputedMemberExpression", 44],
-  ["StaticMemberExpression", 45],
-  ["PrivateFieldExpression", 46],
-  ["CallExpression", 47],
-  ["NewExpression", 48],
-  ["MetaProperty", 49],
-  ["SpreadElement", 50],
-  ["UpdateExpression", 51],
-  ["UnaryExpression", 52],
-  ["BinaryExpression", 53],
-  ["PrivateInExpression", 54],
-  ["LogicalExpression", 55],
-  ["ConditionalExpression", 56],
-  ["AssignmentExpression", 57],
-  ["ArrayAssignmentTarget", 58],
-  ["ObjectAssignmentTarget", 59],
-  ["AssignmentTargetWithDefault", 60],
-  ["AssignmentTargetPropertyIdentifier", 61],
-  ["AssignmentTargetPropertyProperty", 62],
-  ["SequenceExpression", 63],
-  ["AwaitExpression", 64],
-  ["ChainExpression", 65],
-  ["ParenthesizedExpression", 66],
-  ["BlockStatement", 67],
-  ["VariableDeclaration", 68],
-  ["VariableDeclarator", 69],
-  ["ExpressionStatement", 70],
-  ["IfStatement", 71],
-  ["DoWhileStatement", 72],
-  ["WhileStatement", 73],
-  ["ForStatement", 74],
-  ["ForInStatement", 75],
-  ["ForOfStatement", 76],
-  ["ContinueStatement", 77],
-  ["BreakStatement", 78],
-  ["ReturnStatement", 79],
-  ["WithStatement", 80],
-  ["SwitchStatement", 81],
-  ["SwitchCase", 82],
-  ["LabeledStatement", 83],
-  ["ThrowStatement", 84],
-  ["TryStatement", 85],
-  ["CatchClause", 86],
-  ["AssignmentPattern", 87],
-  ["ObjectPattern", 88],
-  ["BindingProperty", 89],
-  ["ArrayPattern", 90],
-  ["Function", 91],
-  ["FormalParameters", 92],
-  ["FunctionBody", 93],
-  ["ArrowFunctionExpression", 94],
-  ["YieldExpression", 95],
-  ["Class", 96],
-  ["ClassBody", 97],
-  ["MethodDefinition", 98],
-  ["PropertyDefinition", 99],
-  ["StaticBlock", 100],
-  ["AccessorProperty", 101],
-  ["ImportExpression", 102],
-  ["ImportDeclaration", 103],
-  ["ImportSpecifier", 104],
-  ["ImportDefaultSpecifier", 105],
-  ["ImportNamespaceSpecifier", 106],
-  ["ImportAttribute", 107],
-  ["ExportNamedDeclaration", 108],
-  ["ExportDefaultDeclaration", 109],
-  ["ExportAllDeclaration", 110],
-  ["ExportSpecifier", 111],
-  ["V8IntrinsicExpression", 112],
-  ["JSXElement", 113],
-  ["JSXOpeningElement", 114],
-  ["JSXClosingElement", 115],
-  ["JSXFragment", 116],
-  ["JSXNamespacedName", 117],
-  ["JSXMemberExpression", 118],
-  ["JSXExpressionContainer", 119],
-  ["JSXAttribute", 120],
-  ["JSXSpreadAttribute", 121],
-  ["JSXSpreadChild", 122],
-  ["TSEnumDeclaration", 123],
-  ["TSEnumBody", 124],
-  ["TSEnumMember", 125],
-  ["TSTypeAnnotation", 126],
-  ["TSLiteralType", 127],
-  ["TSConditionalType", 128],
-  ["TSUnionType", 129],
-  ["TSIntersectionType", 130],
-  ["TSParenthesizedType", 131],
-  ["TSTypeOperator", 132],
-  ["TSArrayType", 133],
-  ["TSIndexedAccessType", 134],
-  ["TSTupleType", 135],
-  ["TSNamedTupleMember", 136],
-  ["TSOptionalType", 137],
-  ["TSRestType", 138],
-  ["TSTypeReference", 139],
-  ["TSQualifiedName", 140],
-  ["TSTypeParameterInstantiation", 141],
-  ["TSTypeParameter", 142],
-  ["TSTypeParameterDeclaration", 143],
-  ["TSTypeAliasDeclaration", 144],
-  ["TSClassImplements", 145],
-  ["TSInterfaceDeclaration", 146],
-  ["TSInterfaceBody", 147],
-  ["TSPropertySignature", 148],
-  ["TSIndexSignature", 149],
-  ["TSCallSignatureDeclaration", 150],
-  ["TSMethodSignature", 151],
-  ["TSConstructSignatureDeclaration", 152],
-  ["TSIndexSignatureName", 153],
-  ["TSInterfaceHeritage", 154],
-  ["TSTypePredicate", 155],
-  ["TSModuleDeclaration", 156],
-  ["TSGlobalDeclaration", 157],
-  ["TSModuleBlock", 158],
-  ["TSTypeLiteral", 159],
-  ["TSInferType", 160],
-  ["TSTypeQuery", 161],
-  ["TSImportType", 162],
-  ["TSImportTypeQualifiedName", 163],
-  ["TSFunctionType", 164],
-  ["TSConstructorType", 165],
-  ["TSMappedType", 166],
-  ["TSTemplateLiteralType", 167],
-  ["TSAsExpression", 168],
-  ["TSSatisfiesExpression", 169],
-  ["TSTypeAssertion", 170],
-  ["TSImportEqualsDeclaration", 171],
-  ["TSExternalModuleReference", 172],
-  ["TSNonNullExpression", 173],
-  ["Decorator", 174],
-  ["TSExportAssignment", 175],
-  ["TSNamespaceExportDeclaration", 176],
-  ["TSInstantiationExpression", 177],
-  ["JSDocNullableType", 178],
-  ["JSDocNonNullableType", 179],
+  ["Program", 39],
+  ["ArrayExpression", 40],
+  ["ObjectExpression", 41],
+  ["ObjectProperty", 42],
+  ["TemplateLiteral", 43],
+  ["TaggedTemplateExpression", 44],
+  ["ComputedMemberExpression", 45],
+  ["StaticMemberExpression", 46],
+  ["PrivateFieldExpression", 47],
+  ["CallExpression", 48],
+  ["NewExpression", 49],
+  ["MetaProperty", 50],
+  ["SpreadElement", 51],
+  ["UpdateExpression", 52],
+  ["UnaryExpression", 53],
+  ["BinaryExpression", 54],
+  ["PrivateInExpression", 55],
+  ["LogicalExpression", 56],
+  ["ConditionalExpression", 57],
+  ["AssignmentExpression", 58],
+  ["ArrayAssignmentTarget", 59],
+  ["ObjectAssignmentTarget", 60],
+  ["AssignmentTargetWithDefault", 61],
+  ["AssignmentTargetPropertyIdentifier", 62],
+  ["AssignmentTargetPropertyProperty", 63],
+  ["SequenceExpression", 64],
+  ["AwaitExpression", 65],
+  ["ChainExpression", 66],
+  ["ParenthesizedExpression", 67],
+  ["BlockStatement", 68],
+  ["VariableDeclaration", 69],
+  ["VariableDeclarator", 70],
+  ["ExpressionStatement", 71],
+  ["IfStatement", 72],
+  ["DoWhileStatement", 73],
+  ["WhileStatement", 74],
+  ["ForStatement", 75],
+  ["ForInStatement", 76],
+  ["ForOfStatement", 77],
+  ["ContinueStatement", 78],
+  ["BreakStatement", 79],
+  ["ReturnStatement", 80],
+  ["WithStatement", 81],
+  ["SwitchStatement", 82],
+  ["SwitchCase", 83],
+  ["LabeledStatement", 84],
+  ["ThrowStatement", 85],
+  ["TryStatement", 86],
+  ["CatchClause", 87],
+  ["AssignmentPattern", 88],
+  ["ObjectPattern", 89],
+  ["BindingProperty", 90],
+  ["ArrayPattern", 91],
+  ["Function", 92],
+  ["FormalParameters", 93],
+  ["FunctionBody", 94],
+  ["ArrowFunctionExpression", 95],
+  ["YieldExpression", 96],
+  ["Class", 97],
+  ["ClassBody", 98],
+  ["MethodDefinition", 99],
+  ["PropertyDefinition", 100],
+  ["StaticBlock", 101],
+  ["AccessorProperty", 102],
+  ["ImportExpression", 103],
+  ["ImportDeclaration", 104],
+  ["ImportSpecifier", 105],
+  ["ImportDefaultSpecifier", 106],
+  ["ImportNamespaceSpecifier", 107],
+  ["ImportAttribute", 108],
+  ["ExportNamedDeclaration", 109],
+  ["ExportDefaultDeclaration", 110],
+  ["ExportAllDeclaration", 111],
+  ["ExportSpecifier", 112],
+  ["V8IntrinsicExpression", 113],
+  ["PipelineExpression", 114],
+  ["DoExpression", 115],
+  ["JSXElement", 116],
+  ["JSXOpeningElement", 117],
+  ["JSXClosingElement", 118],
+  ["JSXFragment", 119],
+  ["JSXNamespacedName", 120],
+  ["JSXMemberExpression", 121],
+  ["JSXExpressionContainer", 122],
+  ["JSXAttribute", 123],
+  ["JSXSpreadAttribute", 124],
+  ["JSXSpreadChild", 125],
+  ["TSEnumDeclaration", 126],
+  ["TSEnumBody", 127],
+  ["TSEnumMember", 128],
+  ["TSTypeAnnotation", 129],
+  ["TSLiteralType", 130],
+  ["TSConditionalType", 131],
+  ["TSUnionType", 132],
+  ["TSIntersectionType", 133],
+  ["TSParenthesizedType", 134],
+  ["TSTypeOperator", 135],
+  ["TSArrayType", 136],
+  ["TSIndexedAccessType", 137],
+  ["TSTupleType", 138],
+  ["TSNamedTupleMember", 139],
+  ["TSOptionalType", 140],
+  ["TSRestType", 141],
+  ["TSTypeReference", 142],
+  ["TSQualifiedName", 143],
+  ["TSTypeParameterInstantiation", 144],
+  ["TSTypeParameter", 145],
+  ["TSTypeParameterDeclaration", 146],
+  ["TSTypeAliasDeclaration", 147],
+  ["TSClassImplements", 148],
+  ["TSInterfaceDeclaration", 149],
+  ["TSInterfaceBody", 150],
+  ["TSPropertySignature", 151],
+  ["TSIndexSignature", 152],
+  ["TSCallSignatureDeclaration", 153],
+  ["TSMethodSignature", 154],
+  ["TSConstructSignatureDeclaration", 155],
+  ["TSIndexSignatureName", 156],
+  ["TSInterfaceHeritage", 157],
+  ["TSTypePredicate", 158],
+  ["TSModuleDeclaration", 159],
+  ["TSGlobalDeclaration", 160],
+  ["TSModuleBlock", 161],
+  ["TSTypeLiteral", 162],
+  ["TSInferType", 163],
+  ["TSTypeQuery", 164],
+  ["TSImportType", 165],
+  ["TSImportTypeQualifiedName", 166],
+  ["TSFunctionType", 167],
+  ["TSConstructorType", 168],
+  ["TSMappedType", 169],
+  ["TSTemplateLiteralType", 170],
+  ["TSAsExpression", 171],
+  ["TSSatisfiesExpression", 172],
+  ["TSTypeAssertion", 173],
+  ["TSImportEqualsDeclaration", 174],
+  ["TSExternalModuleReference", 175],
+  ["TSNonNullExpression", 176],
+  ["Decorator", 177],
+  ["TSExportAssignment", 178],
+  ["TSNamespaceExportDeclaration", 179],
+  ["TSInstantiationExpression", 180],
+  ["JSDocNullableType", 181],
+  ["JSDocNonNullableType", 182],
 ]);
 
-export const NODE_TYPES_COUNT = 180;
-export const LEAF_NODE_TYPES_COUNT = 38;
+export const NODE_TYPES_COUNT = 183;
+export const LEAF_NODE_TYPES_COUNT = 39;
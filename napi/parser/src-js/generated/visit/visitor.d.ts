@@ -34,6 +34,8 @@ export interface VisitorObject {
   "TemplateElement:exit"?: (node: ESTree.TemplateElement) => void;
   ThisExpression?: (node: ESTree.ThisExpression) => void;
   "ThisExpression:exit"?: (node: ESTree.ThisExpression) => void;
+  TopicReference?: (node: ESTree.TopicReference) => void;
+  "TopicReference:exit"?: (node: ESTree.TopicReference) => void;
   JSXClosingFragment?: (node: ESTree.JSXClosingFragment) => void;
   "JSXClosingFragment:exit"?: (node: ESTree.JSXClosingFragment) => void;
   JSXEmptyExpression?: (node: ESTree.JSXEmptyExpression) => void;
@@ -112,6 +114,8 @@ export interface VisitorObject {
   "ContinueStatement:exit"?: (node: ESTree.ContinueStatement) => void;
   Decorator?: (node: ESTree.Decorator) => void;
   "Decorator:exit"?: (node: ESTree.Decorator) => void;
+  DoExpression?: (node: ESTree.DoExpression) => void;
+  "DoExpression:exit"?: (node: ESTree.DoExpression) => void;
   DoWhileStatement?: (node: ESTree.DoWhileStatement) => void;
   "DoWhileStatement:exit"?: (node: ESTree.DoWhileStatement) => void;
   ExportAllDeclaration?: (node: ESTree.ExportAllDeclaration) => void;
@@ -184,6 +188,8 @@ export interface VisitorObject {
   "ObjectPattern:exit"?: (node: ESTree.ObjectPattern) => void;
   ParenthesizedExpression?: (node: ESTree.ParenthesizedExpression) => void;
   "ParenthesizedExpression:exit"?: (node: ESTree.ParenthesizedExpression) => void;
+  PipelineExpression?: (node: ESTree.PipelineExpression) => void;
+  "PipelineExpression:exit"?: (node: ESTree.PipelineExpression) => void;
   Program?: (node: ESTree.Program) => void;
   "Program:exit"?: (node: ESTree.Program) => void;
   Property?: (
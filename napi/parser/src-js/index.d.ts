@@ -179,6 +179,13 @@ export interface ParserOptions {
    * @default true
    */
   preserveParens?: boolean
+  /**
+   * Parse V8 intrinsics, e.g. `%DebugPrint(x)`, as `V8IntrinsicExpression` nodes, as in
+   * V8 and Node.js internal test files run with `--allow-natives-syntax`.
+   *
+   * @default false
+   */
+  allowV8Intrinsics?: boolean
   /**
    * Produce semantic errors with an additional AST pass.
    * Semantic errors depend on symbols and scopes, where the parser does not construct.
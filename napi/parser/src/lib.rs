@@ -77,6 +77,7 @@ fn parse_impl<'a>(
     Parser::new(allocator, source_text, source_type)
         .with_options(ParseOptions {
             preserve_parens: options.preserve_parens.unwrap_or(true),
+            allow_v8_intrinsics: options.allow_v8_intrinsics.unwrap_or(false),
             ..ParseOptions::default()
         })
         .parse()
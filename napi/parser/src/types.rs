@@ -40,6 +40,12 @@ pub struct ParserOptions {
     /// @default true
     pub preserve_parens: Option<bool>,
 
+    /// Parse V8 intrinsics, e.g. `%DebugPrint(x)`, as `V8IntrinsicExpression` nodes, as in
+    /// V8 and Node.js internal test files run with `--allow-natives-syntax`.
+    ///
+    /// @default false
+    pub allow_v8_intrinsics: Option<bool>,
+
     /// Produce semantic errors with an additional AST pass.
     /// Semantic errors depend on symbols and scopes, where the parser does not construct.
     /// This adds a small performance overhead.
@@ -700,6 +700,20 @@ describe("parse", () => {
     });
   });
 
+  describe("allowV8Intrinsics", () => {
+    it("should parse V8 intrinsics when true", () => {
+      const ret = parseSync("test.js", "%DebugPrint(x)", { allowV8Intrinsics: true });
+      expect(ret.errors.length).toBe(0);
+      const { expression } = ret.program.body[0] as ExpressionStatement;
+      expect(expression.type).toBe("V8IntrinsicExpression");
+    });
+
+    it("should report V8 intrinsics by default", () => {
+      const ret = parseSync("test.js", "%DebugPrint(x)");
+      expect(ret.errors.length).toBe(1);
+    });
+  });
+
   describe("ranges", () => {
     it("should include range when true", () => {
       const ret = parseSync("test.js", "(x)", { range: true });
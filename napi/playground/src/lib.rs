@@ -221,6 +221,12 @@ impl Oxc {
             allow_return_outside_function: parser_options.allow_return_outside_function,
             preserve_parens: parser_options.preserve_parens,
             allow_v8_intrinsics: parser_options.allow_v8_intrinsics,
+            allow_pipeline_operator: false,
+            allow_do_expressions: false,
+            allow_flow_syntax: false,
+            skip_function_bodies: false,
+            recover_from_errors: false,
+            collect_tokens: false,
         };
         let ParserReturn { program, errors, module_record, .. } =
             Parser::new(allocator, source_text, source_type).with_options(parser_options).parse();
@@ -50,6 +50,9 @@ export type Expression =
   | TSNonNullExpression
   | TSInstantiationExpression
   | V8IntrinsicExpression
+  | PipelineExpression
+  | TopicReference
+  | DoExpression
   | MemberExpression;
 
 export interface IdentifierName extends Span {
@@ -918,6 +921,24 @@ export interface V8IntrinsicExpression extends Span {
   parent?: Node;
 }
 
+export interface PipelineExpression extends Span {
+  type: "PipelineExpression";
+  head: Expression;
+  body: Expression;
+  parent?: Node;
+}
+
+export interface TopicReference extends Span {
+  type: "TopicReference";
+  parent?: Node;
+}
+
+export interface DoExpression extends Span {
+  type: "DoExpression";
+  body: BlockStatement;
+  parent?: Node;
+}
+
 export interface BooleanLiteral extends Span {
   type: "Literal";
   value: boolean;
@@ -1815,6 +1836,9 @@ export type Node =
   | ExportAllDeclaration
   | ExportSpecifier
   | V8IntrinsicExpression
+  | PipelineExpression
+  | TopicReference
+  | DoExpression
   | BooleanLiteral
   | NullLiteral
   | NumericLiteral
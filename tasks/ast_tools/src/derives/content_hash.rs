@@ -0,0 +1,115 @@
+//! Derive for `ContentHash` trait.
+//!
+//! Hashes the same content which `ContentEq` compares, so uses `#[content_eq(skip)]` attrs.
+
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use crate::{
+    schema::{Def, EnumDef, Schema, StructDef, TypeDef},
+    utils::create_safe_ident,
+};
+
+use super::{Derive, StructOrEnum, define_derive};
+
+/// Derive for `ContentHash` trait.
+pub struct DeriveContentHash;
+
+define_derive!(DeriveContentHash);
+
+impl Derive for DeriveContentHash {
+    fn trait_name(&self) -> &'static str {
+        "ContentHash"
+    }
+
+    fn crate_name(&self) -> &'static str {
+        "oxc_span"
+    }
+
+    fn prelude(&self) -> TokenStream {
+        quote! {
+            ///@@line_break
+            use std::hash::Hasher;
+
+            ///@@line_break
+            use oxc_span::ContentHash;
+        }
+    }
+
+    fn derive(&self, type_def: StructOrEnum, schema: &Schema) -> TokenStream {
+        match type_def {
+            StructOrEnum::Struct(struct_def) => derive_struct(struct_def, schema),
+            StructOrEnum::Enum(enum_def) => derive_enum(enum_def, schema),
+        }
+    }
+}
+
+fn derive_struct(struct_def: &StructDef, schema: &Schema) -> TokenStream {
+    let body = if struct_def.content_eq.skip {
+        // Struct has `#[content_eq(skip)]` attr. So `content_hash` hashes nothing.
+        TokenStream::new()
+    } else {
+        let fields = struct_def
+            .fields
+            .iter()
+            .filter(|field| !field.content_eq.skip)
+            .filter(|field| {
+                let innermost_type = field.type_def(schema).innermost_type(schema);
+                match innermost_type {
+                    TypeDef::Struct(struct_def) => !struct_def.content_eq.skip,
+                    TypeDef::Enum(enum_def) => !enum_def.content_eq.skip,
+                    _ => true,
+                }
+            })
+            .map(|field| {
+                let ident = field.ident();
+                quote!( ContentHash::content_hash(&self.#ident, state); )
+            });
+
+        quote!( #(#fields)* )
+    };
+
+    generate_impl(&struct_def.ty_anon(schema), &body)
+}
+
+fn derive_enum(enum_def: &EnumDef, schema: &Schema) -> TokenStream {
+    let body = if enum_def.content_eq.skip {
+        // Enum has `#[content_eq(skip)]` attr. So `content_hash` hashes nothing.
+        TokenStream::new()
+    } else {
+        // Hash discriminant, then the content of the variant
+        let matches = enum_def.all_variants(schema).map(|variant| {
+            let ident = variant.ident();
+            let discriminant = Literal::u8_unsuffixed(variant.discriminant);
+            if variant.is_fieldless() {
+                quote!( Self::#ident => state.write_u8(#discriminant) )
+            } else {
+                quote! {
+                    Self::#ident(it) => {
+                        state.write_u8(#discriminant);
+                        ContentHash::content_hash(it, state);
+                    }
+                }
+            }
+        });
+
+        quote! {
+            match self {
+                #(#matches,)*
+            }
+        }
+    };
+
+    generate_impl(&enum_def.ty_anon(schema), &body)
+}
+
+fn generate_impl(ty: &TokenStream, body: &TokenStream) -> TokenStream {
+    let state_ident = create_safe_ident(if body.is_empty() { "_" } else { "state" });
+    quote! {
+        impl ContentHash for #ty {
+            fn content_hash<H: Hasher>(&self, #state_ident: &mut H) {
+                #body
+            }
+        }
+    }
+}
@@ -16,6 +16,7 @@ use crate::{
 
 mod clone_in;
 mod content_eq;
+mod content_hash;
 mod dummy;
 pub mod estree;
 mod get_address;
@@ -25,6 +26,7 @@ mod unstable_address;
 
 pub use clone_in::DeriveCloneIn;
 pub use content_eq::DeriveContentEq;
+pub use content_hash::DeriveContentHash;
 pub use dummy::DeriveDummy;
 pub use estree::DeriveESTree;
 pub use get_address::DeriveGetAddress;
@@ -158,6 +158,9 @@ fn generate(codegen: &Codegen) -> Codes {
     let extra: &[(&str, &[&str])] = &[
         ("ParenthesizedExpression", &["expression"]),
         ("V8IntrinsicExpression", &["name", "arguments"]),
+        ("PipelineExpression", &["head", "body"]),
+        ("TopicReference", &[]),
+        ("DoExpression", &["body"]),
         ("TSParenthesizedType", &["typeAnnotation"]),
         ("TSJSDocNonNullableType", &["typeAnnotation"]),
         ("TSJSDocNullableType", &["typeAnnotation"]),
@@ -275,6 +275,7 @@ const DERIVES: &[&(dyn Derive + Sync)] = &[
     &derives::DeriveGetSpan,
     &derives::DeriveGetSpanMut,
     &derives::DeriveContentEq,
+    &derives::DeriveContentHash,
     &derives::DeriveESTree,
 ];
 
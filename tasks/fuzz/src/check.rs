@@ -0,0 +1,372 @@
+//! Differential checks run on each input.
+
+use std::{
+    cell::RefCell,
+    fmt, fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+use oxc_allocator::{Allocator, CloneIn, TakeIn};
+use oxc_ast::{
+    AstBuilder,
+    ast::{
+        AssignmentTargetMaybeDefault, AssignmentTargetProperty, BindingProperty, Expression,
+        IfStatement, LogicalOperator, ObjectProperty, Program, PropertyKey, SequenceExpression,
+        Statement, TSLiteral,
+    },
+};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{ContentEq, SPAN, SourceType};
+
+/// A divergence found by [`Checker::check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// Parser, semantic or codegen panicked.
+    Panic(String),
+    /// Printed code does not parse.
+    Reparse { minify: bool, printed: String, error: String },
+    /// Printed code parses to a different AST.
+    AstMismatch { minify: bool, printed: String },
+    /// Printing the re-parsed code gives different code.
+    NotIdempotent { minify: bool, printed: String, reprinted: String },
+    /// Reference parser disagrees on whether the input is valid.
+    Reference { oxc_accepts: bool },
+}
+
+impl Failure {
+    /// Name of this kind of failure. Reduced inputs must keep failing with the same kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Panic(_) => "panic",
+            Self::Reparse { minify: false, .. } => "reparse",
+            Self::Reparse { minify: true, .. } => "reparse-minify",
+            Self::AstMismatch { minify: false, .. } => "ast-mismatch",
+            Self::AstMismatch { minify: true, .. } => "ast-mismatch-minify",
+            Self::NotIdempotent { minify: false, .. } => "not-idempotent",
+            Self::NotIdempotent { minify: true, .. } => "not-idempotent-minify",
+            Self::Reference { oxc_accepts: true } => "reference-rejects",
+            Self::Reference { oxc_accepts: false } => "reference-accepts",
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Panic(message) => write!(f, "Panicked: {message}"),
+            Self::Reparse { printed, error, .. } => {
+                write!(f, "Printed code does not parse: {error}\n{printed}")
+            }
+            Self::AstMismatch { printed, .. } => {
+                write!(f, "Printed code parses to a different AST:\n{printed}")
+            }
+            Self::NotIdempotent { printed, reprinted, .. } => {
+                write!(f, "Printing is not idempotent:\n{printed}\n---\n{reprinted}")
+            }
+            Self::Reference { oxc_accepts: true } => {
+                write!(f, "Oxc accepts the input, the reference parser rejects it")
+            }
+            Self::Reference { oxc_accepts: false } => {
+                write!(f, "Oxc rejects the input, the reference parser accepts it")
+            }
+        }
+    }
+}
+
+thread_local! {
+    /// Message of the last panic, with its location.
+    static PANIC_MESSAGE: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Replace the panic hook with one that records the panic message instead of printing it,
+/// so panics can be reported as failures.
+pub fn capture_panics() {
+    panic::set_hook(Box::new(|info| {
+        PANIC_MESSAGE.with(|message| *message.borrow_mut() = info.to_string());
+    }));
+}
+
+/// Command which is run with the path of a file, and exits successfully if the file is valid.
+/// e.g. `node --check`.
+pub struct Reference {
+    program: String,
+    args: Vec<String>,
+    dir: PathBuf,
+}
+
+impl Reference {
+    /// Parse `command`, split on whitespace. Inputs are written into `dir`.
+    pub fn new(command: &str, dir: PathBuf) -> Option<Self> {
+        let mut words = command.split_whitespace().map(ToString::to_string);
+        let program = words.next()?;
+        Some(Self { program, args: words.collect(), dir })
+    }
+
+    /// Whether the reference parser accepts `source_text`.
+    ///
+    /// # Errors
+    /// Returns an error if the input can't be written, or the command can't be run.
+    pub fn accepts(&self, source_text: &str, source_type: SourceType) -> io::Result<bool> {
+        let extension = if source_type.is_module() { "mjs" } else { "cjs" };
+        let path = self.dir.join(format!("reference-input.{extension}"));
+        fs::write(&path, source_text)?;
+        let status = Command::new(&self.program)
+            .args(&self.args)
+            .arg(&path)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()?;
+        Ok(status.success())
+    }
+}
+
+pub struct Checker {
+    pub reference: Option<Reference>,
+}
+
+impl Checker {
+    /// Run `source_text` through parse → codegen → parse, and compare with the reference parser.
+    ///
+    /// Inputs which Oxc rejects are only compared with the reference parser.
+    ///
+    /// # Panics
+    /// Panics if the reference parser cannot be run.
+    pub fn check(&self, source_text: &str, source_type: SourceType) -> Option<Failure> {
+        let (oxc_accepts, failure) =
+            panic::catch_unwind(AssertUnwindSafe(|| round_trip(source_text, source_type)))
+                .unwrap_or_else(|_| {
+                    let message = PANIC_MESSAGE.with(|message| message.borrow().clone());
+                    (false, Some(Failure::Panic(message)))
+                });
+        if failure.is_some() {
+            return failure;
+        }
+
+        // Reference parsers only understand JavaScript
+        let reference = self.reference.as_ref()?;
+        if source_type.is_typescript() || source_type.is_jsx() {
+            return None;
+        }
+        let reference_accepts = reference
+            .accepts(source_text, source_type)
+            .unwrap_or_else(|err| panic!("Failed to run reference parser: {err}"));
+        (reference_accepts != oxc_accepts).then_some(Failure::Reference { oxc_accepts })
+    }
+}
+
+/// Round-trip `source_text` through parse → codegen → parse, if Oxc accepts it.
+/// Returns whether Oxc accepts it, and the first divergence found.
+fn round_trip(source_text: &str, source_type: SourceType) -> (bool, Option<Failure>) {
+    let allocator = Allocator::default();
+    let Ok(mut program) = parse(&allocator, source_text, source_type) else {
+        return (false, None);
+    };
+    if !SemanticBuilder::new().with_check_syntax_error(true).build(&program).errors.is_empty() {
+        return (false, None);
+    }
+
+    for minify in [false, true] {
+        let printed = print(&program, minify);
+        let reparse_allocator = Allocator::default();
+        let mut reparsed = match parse(&reparse_allocator, &printed, source_type) {
+            Ok(reparsed) => reparsed,
+            Err(error) => return (true, Some(Failure::Reparse { minify, printed, error })),
+        };
+        let reprinted = print(&reparsed, minify);
+        Normalize { ast: AstBuilder::new(&allocator), templates: minify }
+            .visit_program(&mut program);
+        Normalize { ast: AstBuilder::new(&reparse_allocator), templates: minify }
+            .visit_program(&mut reparsed);
+        if !program.content_eq(&reparsed) {
+            return (true, Some(Failure::AstMismatch { minify, printed }));
+        }
+        if printed != reprinted {
+            return (true, Some(Failure::NotIdempotent { minify, printed, reprinted }));
+        }
+    }
+
+    (true, None)
+}
+
+/// Parse without preserving parentheses, so that ASTs of the input and printed code can be compared.
+fn parse<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_type: SourceType,
+) -> Result<Program<'a>, String> {
+    let options = ParseOptions { preserve_parens: false, ..ParseOptions::default() };
+    let ret = Parser::new(allocator, source_text, source_type).with_options(options).parse();
+    match ret.errors.first() {
+        Some(error) => Err(error.to_string()),
+        None if ret.panicked => Err("Parser panicked".to_string()),
+        None => Ok(ret.program),
+    }
+}
+
+/// Erase differences between ASTs which codegen does not preserve, and which do not change
+/// the meaning of the program:
+///
+/// * Nested sequence expressions are flattened. `a, (b, c)` is printed as `a, b, c`.
+/// * Chains of the same logical operator are made left-associative.
+///   `a && (b && c)` is printed as `a && b && c`.
+/// * Blocks added around `if` consequents to avoid an ambiguous `else` are removed.
+///   `if (a) if (b) c;` is printed as `if (a) { if (b) c; }`.
+/// * Shorthand flags of properties are cleared. `{a: a}` is printed as `{a}`.
+/// * `{a: a} = x` is converted to `{a} = x`, which is how it's minified.
+/// * If `templates` is `true`, no-substitution templates are converted to string literals.
+///   Minified output prints string literals as templates where that's shorter, e.g. `'"\''` as `` `"'` ``.
+struct Normalize<'a> {
+    ast: AstBuilder<'a>,
+    templates: bool,
+}
+
+impl<'a> VisitMut<'a> for Normalize<'a> {
+    fn visit_expression(&mut self, it: &mut Expression<'a>) {
+        if self.templates
+            && let Expression::TemplateLiteral(template) = it
+            && let Some(value) = template.single_quasi()
+        {
+            let lone_surrogates = template.quasis[0].lone_surrogates;
+            *it = self.ast.expression_string_literal_with_lone_surrogates(
+                template.span,
+                value,
+                None,
+                lone_surrogates,
+            );
+        }
+        walk_mut::walk_expression(self, it);
+
+        if let Expression::LogicalExpression(logical) = it
+            && matches!(&logical.right, Expression::LogicalExpression(right) if right.operator == logical.operator)
+        {
+            let operator = logical.operator;
+            let mut operands = vec![];
+            flatten_logical(it.take_in(self.ast), operator, &mut operands);
+            *it = operands
+                .into_iter()
+                .reduce(|left, right| self.ast.expression_logical(SPAN, left, operator, right))
+                .unwrap();
+        }
+    }
+
+    fn visit_ts_literal(&mut self, it: &mut TSLiteral<'a>) {
+        if self.templates
+            && let TSLiteral::TemplateLiteral(template) = it
+            && let Some(value) = template.single_quasi()
+        {
+            let lone_surrogates = template.quasis[0].lone_surrogates;
+            *it = self.ast.ts_literal_string_literal_with_lone_surrogates(
+                template.span,
+                value,
+                None,
+                lone_surrogates,
+            );
+        }
+        walk_mut::walk_ts_literal(self, it);
+    }
+
+    fn visit_sequence_expression(&mut self, it: &mut SequenceExpression<'a>) {
+        walk_mut::walk_sequence_expression(self, it);
+        if it.expressions.iter().any(|expr| matches!(expr, Expression::SequenceExpression(_))) {
+            let mut expressions = self.ast.vec();
+            for expr in it.expressions.drain(..) {
+                match expr {
+                    Expression::SequenceExpression(sequence) => {
+                        expressions.extend(sequence.unbox().expressions);
+                    }
+                    expr => expressions.push(expr),
+                }
+            }
+            it.expressions = expressions;
+        }
+    }
+
+    fn visit_object_property(&mut self, it: &mut ObjectProperty<'a>) {
+        it.shorthand = false;
+        walk_mut::walk_object_property(self, it);
+    }
+
+    fn visit_binding_property(&mut self, it: &mut BindingProperty<'a>) {
+        it.shorthand = false;
+        walk_mut::walk_binding_property(self, it);
+    }
+
+    fn visit_if_statement(&mut self, it: &mut IfStatement<'a>) {
+        walk_mut::walk_if_statement(self, it);
+        if let Statement::BlockStatement(block) = &mut it.consequent
+            && block.body.len() == 1
+            && ends_with_if_without_else(&block.body[0])
+        {
+            it.consequent = block.body.pop().unwrap();
+        }
+    }
+
+    fn visit_assignment_target_property(&mut self, it: &mut AssignmentTargetProperty<'a>) {
+        walk_mut::walk_assignment_target_property(self, it);
+        if let AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) = it
+            && let PropertyKey::StaticIdentifier(key) = &property.name
+            && let Some(binding) = property.binding.identifier()
+            && key.name == binding.name
+        {
+            let init = match &property.binding {
+                AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                    Some(target.init.clone_in(self.ast.allocator))
+                }
+                _ => None,
+            };
+            *it = self.ast.assignment_target_property_assignment_target_property_identifier(
+                property.span,
+                binding.clone_in(self.ast.allocator),
+                init,
+            );
+        }
+    }
+}
+
+/// Collect operands of a chain of `operator`, in order.
+fn flatten_logical<'a>(
+    expr: Expression<'a>,
+    operator: LogicalOperator,
+    operands: &mut Vec<Expression<'a>>,
+) {
+    match expr {
+        Expression::LogicalExpression(logical) if logical.operator == operator => {
+            let logical = logical.unbox();
+            flatten_logical(logical.left, operator, operands);
+            flatten_logical(logical.right, operator, operands);
+        }
+        expr => operands.push(expr),
+    }
+}
+
+/// Whether an `else` following `stmt` would be ambiguous.
+/// Same as the check codegen uses to decide whether to wrap `stmt` in a block.
+fn ends_with_if_without_else(stmt: &Statement<'_>) -> bool {
+    let mut current = stmt;
+    loop {
+        current = match current {
+            Statement::IfStatement(if_stmt) => match &if_stmt.alternate {
+                Some(alternate) => alternate,
+                None => return true,
+            },
+            Statement::ForStatement(for_stmt) => &for_stmt.body,
+            Statement::ForOfStatement(for_of_stmt) => &for_of_stmt.body,
+            Statement::ForInStatement(for_in_stmt) => &for_in_stmt.body,
+            Statement::WhileStatement(while_stmt) => &while_stmt.body,
+            Statement::WithStatement(with_stmt) => &with_stmt.body,
+            Statement::LabeledStatement(labeled_stmt) => &labeled_stmt.body,
+            _ => return false,
+        }
+    }
+}
+
+fn print(program: &Program<'_>, minify: bool) -> String {
+    let options = if minify { CodegenOptions::minify() } else { CodegenOptions::default() };
+    Codegen::new().with_options(options).build(program).code
+}
@@ -0,0 +1,683 @@
+//! Generator of random, syntactically valid ES module source text.
+//!
+//! Every compound sub-expression is wrapped in parentheses, so the generated source is valid
+//! whatever the precedence of the operators involved. The parser drops those parentheses,
+//! which leaves it to codegen to decide which ones are needed to print the AST back out.
+
+use std::fmt::Write;
+
+use crate::rng::Rng;
+
+const IDENTIFIERS: &[&str] = &["a", "b", "c", "x", "y", "foo", "bar", "$", "_", "ø"];
+
+const NUMBERS: &[&str] = &[
+    "0",
+    "1",
+    "1.5",
+    ".5",
+    "5.",
+    "1e21",
+    "1e-7",
+    "0x1f",
+    "0o17",
+    "0b101",
+    "1_000",
+    "5e-324",
+    "123456789012345680000",
+    "0.000001",
+    "1n",
+    "0xffn",
+];
+
+const STRINGS: &[&str] = &[
+    "''",
+    "\"a\"",
+    "'b'",
+    "\"\\n\"",
+    "'\\''",
+    "\"\\\"\"",
+    "'\\u{1F600}'",
+    "'</script>'",
+    "'\\0'",
+    "'\\x41'",
+    "'é'",
+    "'\\uD800'",
+    "'\\u2028'",
+    "'use strict'",
+];
+
+const REGEXES: &[&str] = &[
+    "/a+/g",
+    "/[/]/",
+    "/\\d{2,}/u",
+    "/(?<n>x)\\k<n>/",
+    "/[\\p{L}--\\p{Ll}]/v",
+    "/=/",
+    "/a/dgimsy",
+];
+
+const TEMPLATE_CHUNKS: &[&str] = &["", "a", "\\n", "\\`", "$", "{", "\\${", "\n"];
+
+const BINARY_OPERATORS: &[&str] = &[
+    "+",
+    "-",
+    "*",
+    "/",
+    "%",
+    "**",
+    "<<",
+    ">>",
+    ">>>",
+    "<",
+    ">",
+    "<=",
+    ">=",
+    "==",
+    "!=",
+    "===",
+    "!==",
+    "&",
+    "|",
+    "^",
+    "&&",
+    "||",
+    "??",
+    "in",
+    "instanceof",
+];
+
+const UNARY_OPERATORS: &[&str] = &["-", "+", "!", "~", "typeof ", "void "];
+
+const ASSIGNMENT_OPERATORS: &[&str] = &[
+    "=", "+=", "-=", "*=", "/=", "%=", "**=", "<<=", ">>=", ">>>=", "&=", "|=", "^=", "&&=", "||=",
+    "??=",
+];
+
+const MODULE_SPECIFIERS: &[&str] = &["'m'", "\"./a.js\"", "'node:fs'"];
+
+/// Where generated code is placed, which determines which syntax is allowed.
+#[derive(Debug, Clone, Copy, Default)]
+#[expect(clippy::struct_excessive_bools)]
+struct Scope {
+    /// Inside a function body, so `return` is allowed.
+    function: bool,
+    /// `await` is allowed.
+    r#await: bool,
+    /// `yield` is allowed.
+    r#yield: bool,
+    /// `new.target` is allowed.
+    new_target: bool,
+    /// `super.x` is allowed.
+    super_property: bool,
+    /// `#x` private names are allowed.
+    private_names: bool,
+    /// `break` is allowed.
+    r#break: bool,
+    /// `continue` is allowed.
+    r#continue: bool,
+}
+
+impl Scope {
+    /// Scope of a function body.
+    fn function(self, is_async: bool, is_generator: bool) -> Self {
+        Self {
+            function: true,
+            r#await: is_async,
+            r#yield: is_generator,
+            new_target: true,
+            super_property: false,
+            private_names: self.private_names,
+            r#break: false,
+            r#continue: false,
+        }
+    }
+
+    /// Scope of an arrow function body. Arrow functions inherit `new.target` and `super`.
+    fn arrow(self, is_async: bool) -> Self {
+        Self {
+            function: true,
+            r#await: is_async,
+            r#yield: false,
+            r#break: false,
+            r#continue: false,
+            ..self
+        }
+    }
+
+    /// Scope of parameter defaults, class field initializers and the like,
+    /// where `await` and `yield` are not allowed.
+    fn without_await_and_yield(self) -> Self {
+        Self { r#await: false, r#yield: false, ..self }
+    }
+}
+
+pub struct Generator<'r> {
+    rng: &'r mut Rng,
+    /// Counter for unique binding names, so declarations never conflict.
+    next_binding: usize,
+    has_default_export: bool,
+}
+
+impl<'r> Generator<'r> {
+    pub fn new(rng: &'r mut Rng) -> Self {
+        Self { rng, next_binding: 0, has_default_export: false }
+    }
+
+    /// Generate a module, with one top-level statement per line.
+    pub fn program(&mut self) -> String {
+        // Top-level `await` is allowed in modules
+        let scope = Scope { r#await: true, ..Scope::default() };
+        let mut out = String::new();
+        for _ in 0..=self.rng.below(8) {
+            if self.rng.one_in(6) {
+                out.push_str(&self.module_declaration());
+            } else {
+                out.push_str(&self.statement(scope, 3));
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    fn binding(&mut self) -> String {
+        let name = format!("v{}", self.next_binding);
+        self.next_binding += 1;
+        name
+    }
+
+    fn module_declaration(&mut self) -> String {
+        let source = *self.rng.choose(MODULE_SPECIFIERS);
+        match self.rng.below(9) {
+            0 => format!("import {} from {source};", self.binding()),
+            1 => format!("import * as {} from {source};", self.binding()),
+            2 => format!("import {{ a as {}, {} }} from {source};", self.binding(), self.binding()),
+            3 => format!("import {source} with {{ type: 'json' }};"),
+            4 => format!("export * from {source};"),
+            5 => format!("export * as {} from {source};", self.binding()),
+            6 => format!(
+                "export {{ a as {}, b as '{} x' }} from {source};",
+                self.binding(),
+                self.binding()
+            ),
+            7 if !self.has_default_export => {
+                self.has_default_export = true;
+                let scope = Scope { r#await: true, ..Scope::default() };
+                match self.rng.below(3) {
+                    0 => format!("export default {};", self.parenthesized(scope, 2)),
+                    1 => format!("export default {}", self.function(scope, 2, true)),
+                    _ => format!("export default {}", self.class(scope, 2, true)),
+                }
+            }
+            _ => {
+                let scope = Scope { r#await: true, ..Scope::default() };
+                format!("export {}", self.declaration(scope, 2))
+            }
+        }
+    }
+
+    fn declaration(&mut self, scope: Scope, depth: usize) -> String {
+        match self.rng.below(3) {
+            0 => self.function(scope, depth, false),
+            1 => self.class(scope, depth, false),
+            _ => self.variable_declaration(scope, depth),
+        }
+    }
+
+    fn variable_declaration(&mut self, scope: Scope, depth: usize) -> String {
+        let kind = *self.rng.choose(&["let", "const", "var"]);
+        let binding = match self.rng.below(4) {
+            0 => format!("[{}, , ...{}]", self.binding(), self.binding()),
+            1 => format!(
+                "{{ a: {}, b = {}, ...{} }}",
+                self.binding(),
+                self.parenthesized(scope, 0),
+                self.binding()
+            ),
+            _ => self.binding(),
+        };
+        format!("{kind} {binding} = {};", self.parenthesized(scope, depth))
+    }
+
+    fn block(&mut self, scope: Scope, depth: usize) -> String {
+        let mut out = String::from("{ ");
+        if depth > 0 {
+            for _ in 0..self.rng.below(4) {
+                out.push_str(&self.statement(scope, depth - 1));
+                out.push(' ');
+            }
+        }
+        out.push('}');
+        out
+    }
+
+    /// Body of `if`, loops and labeled statements, which cannot be a declaration.
+    fn substatement(&mut self, scope: Scope, depth: usize) -> String {
+        if self.rng.one_in(2) {
+            self.block(scope, depth)
+        } else {
+            self.statement_or_declaration(scope, depth, false)
+        }
+    }
+
+    fn statement(&mut self, scope: Scope, depth: usize) -> String {
+        self.statement_or_declaration(scope, depth, true)
+    }
+
+    fn statement_or_declaration(
+        &mut self,
+        scope: Scope,
+        depth: usize,
+        declaration_allowed: bool,
+    ) -> String {
+        if depth == 0 {
+            return format!("{};", self.parenthesized(scope, 1));
+        }
+        let d = depth - 1;
+        let loop_scope = Scope { r#break: true, r#continue: true, ..scope };
+        match self.rng.below(20) {
+            0 | 1 if declaration_allowed => self.declaration(scope, d),
+            2 => format!(
+                "if ({}) {} else {}",
+                self.expression(scope, d),
+                self.block(scope, d),
+                self.substatement(scope, d)
+            ),
+            3 => format!("if ({}) {}", self.expression(scope, d), self.substatement(scope, d)),
+            4 => self.block(scope, depth),
+            5 => format!(
+                "for (let {} = {}; {}; {}) {}",
+                self.binding(),
+                self.parenthesized(scope, d),
+                self.expression(scope, d),
+                self.expression(scope, d),
+                self.substatement(loop_scope, d)
+            ),
+            6 => {
+                let head = if scope.r#await && self.rng.one_in(2) { "for await" } else { "for" };
+                format!(
+                    "{head} (const {} of {}) {}",
+                    self.binding(),
+                    self.parenthesized(scope, d),
+                    self.substatement(loop_scope, d)
+                )
+            }
+            7 => format!(
+                "for (let {} in {}) {}",
+                self.binding(),
+                self.parenthesized(scope, d),
+                self.substatement(loop_scope, d)
+            ),
+            8 => format!(
+                "while ({}) {}",
+                self.expression(scope, d),
+                self.substatement(loop_scope, d)
+            ),
+            9 => format!(
+                "do {} while ({});",
+                self.substatement(loop_scope, d),
+                self.expression(scope, d)
+            ),
+            10 => format!("{}: {}", self.binding(), self.substatement(scope, d)),
+            11 => {
+                let switch_scope = Scope { r#break: true, ..scope };
+                let mut out = format!("switch ({}) {{ ", self.expression(scope, d));
+                for _ in 0..self.rng.below(3) {
+                    let _ = write!(
+                        out,
+                        "case {}: {} ",
+                        self.parenthesized(scope, d),
+                        self.statement(switch_scope, d)
+                    );
+                }
+                if self.rng.one_in(2) {
+                    let _ = write!(out, "default: {} ", self.statement(switch_scope, d));
+                }
+                out.push('}');
+                out
+            }
+            12 => {
+                let mut out = format!("try {}", self.block(scope, d));
+                let has_finally = self.rng.one_in(2);
+                if !has_finally || self.rng.one_in(2) {
+                    if self.rng.one_in(2) {
+                        let _ = write!(out, " catch {}", self.block(scope, d));
+                    } else {
+                        let _ = write!(out, " catch ({}) {}", self.binding(), self.block(scope, d));
+                    }
+                }
+                if has_finally {
+                    let _ = write!(out, " finally {}", self.block(scope, d));
+                }
+                out
+            }
+            13 => format!("throw {};", self.parenthesized(scope, d)),
+            14 if scope.function => {
+                if self.rng.one_in(3) {
+                    "return;".to_string()
+                } else {
+                    format!("return {};", self.parenthesized(scope, d))
+                }
+            }
+            15 if scope.r#break => "break;".to_string(),
+            16 if scope.r#continue => "continue;".to_string(),
+            17 => ";".to_string(),
+            _ => format!("{};", self.parenthesized(scope, depth)),
+        }
+    }
+
+    /// Expression wrapped in parentheses.
+    fn parenthesized(&mut self, scope: Scope, depth: usize) -> String {
+        format!("({})", self.expression(scope, depth))
+    }
+
+    fn primary(&mut self, scope: Scope) -> String {
+        match self.rng.below(12) {
+            0..=3 => (*self.rng.choose(IDENTIFIERS)).to_string(),
+            4 | 5 => (*self.rng.choose(NUMBERS)).to_string(),
+            6 | 7 => (*self.rng.choose(STRINGS)).to_string(),
+            8 => (*self.rng.choose(REGEXES)).to_string(),
+            9 => (*self.rng.choose(&["this", "null", "true", "false", "[]", "{}", "import.meta"]))
+                .to_string(),
+            10 if scope.new_target => "new.target".to_string(),
+            _ => {
+                let chunk = self.rng.choose(TEMPLATE_CHUNKS);
+                format!("`{chunk}`")
+            }
+        }
+    }
+
+    /// Target of an assignment or update.
+    fn simple_target(&mut self, scope: Scope, depth: usize) -> String {
+        match self.rng.below(4) {
+            0 => format!("{}.{}", self.parenthesized(scope, depth), self.rng.choose(IDENTIFIERS)),
+            1 => format!("{}[{}]", self.parenthesized(scope, depth), self.expression(scope, depth)),
+            2 if scope.super_property => format!("super.{}", self.rng.choose(IDENTIFIERS)),
+            _ => (*self.rng.choose(IDENTIFIERS)).to_string(),
+        }
+    }
+
+    fn arguments(&mut self, scope: Scope, depth: usize) -> String {
+        let mut args = vec![];
+        for _ in 0..self.rng.below(4) {
+            let arg = self.parenthesized(scope, depth);
+            args.push(if self.rng.one_in(4) { format!("...{arg}") } else { arg });
+        }
+        format!("({})", args.join(", "))
+    }
+
+    fn params(&mut self, scope: Scope, depth: usize) -> String {
+        let scope = scope.without_await_and_yield();
+        let mut params = vec![];
+        for _ in 0..self.rng.below(4) {
+            let param = match self.rng.below(4) {
+                0 => format!("{} = {}", self.binding(), self.parenthesized(scope, depth)),
+                1 => format!("{{ {} }}", self.binding()),
+                _ => self.binding(),
+            };
+            params.push(param);
+        }
+        if self.rng.one_in(4) {
+            params.push(format!("...{}", self.binding()));
+        }
+        format!("({})", params.join(", "))
+    }
+
+    fn function_body(&mut self, scope: Scope, depth: usize) -> String {
+        let mut out = String::from("{ ");
+        if self.rng.one_in(8) {
+            out.push_str("'use strict'; ");
+        }
+        for _ in 0..self.rng.below(4) {
+            out.push_str(&self.statement(scope, depth));
+            out.push(' ');
+        }
+        out.push('}');
+        out
+    }
+
+    fn function(&mut self, scope: Scope, depth: usize, anonymous_allowed: bool) -> String {
+        let is_async = self.rng.one_in(3);
+        let is_generator = self.rng.one_in(3);
+        let name =
+            if anonymous_allowed && self.rng.one_in(2) { String::new() } else { self.binding() };
+        let body_scope = scope.function(is_async, is_generator);
+        format!(
+            "{}function{} {name}{} {}",
+            if is_async { "async " } else { "" },
+            if is_generator { "*" } else { "" },
+            self.params(body_scope, depth.saturating_sub(1)),
+            self.function_body(body_scope, depth.saturating_sub(1))
+        )
+    }
+
+    /// Method of a class or object literal, starting with its key.
+    fn method(&mut self, scope: Scope, depth: usize, key: &str) -> String {
+        let d = depth.saturating_sub(1);
+        let method_scope = Scope { super_property: true, ..scope.function(false, false) };
+        match self.rng.below(5) {
+            0 => format!("get {key}() {}", self.function_body(method_scope, d)),
+            1 => format!("set {key}({}) {}", self.binding(), self.function_body(method_scope, d)),
+            2 => {
+                let scope = Scope { r#await: true, ..method_scope };
+                format!("async {key}{} {}", self.params(scope, d), self.function_body(scope, d))
+            }
+            3 => {
+                let scope = Scope { r#yield: true, ..method_scope };
+                format!("*{key}{} {}", self.params(scope, d), self.function_body(scope, d))
+            }
+            _ => format!(
+                "{key}{} {}",
+                self.params(method_scope, d),
+                self.function_body(method_scope, d)
+            ),
+        }
+    }
+
+    fn property_key(&mut self, scope: Scope, depth: usize) -> String {
+        match self.rng.below(6) {
+            0 => format!("[{}]", self.expression(scope.without_await_and_yield(), depth)),
+            1 => (*self.rng.choose(STRINGS)).to_string(),
+            2 => (*self.rng.choose(&["0", "1.5", "1e21", "0x10"])).to_string(),
+            3 => (*self.rng.choose(&["get", "set", "async", "static", "constructor2"])).to_string(),
+            _ => (*self.rng.choose(IDENTIFIERS)).to_string(),
+        }
+    }
+
+    fn class(&mut self, scope: Scope, depth: usize, anonymous_allowed: bool) -> String {
+        let d = depth.saturating_sub(1);
+        let mut out = String::from("class");
+        if !anonymous_allowed || self.rng.one_in(2) {
+            let _ = write!(out, " {}", self.binding());
+        }
+        if self.rng.one_in(3) {
+            let _ = write!(out, " extends {}", self.parenthesized(scope, d));
+        }
+        out.push_str(" { ");
+        let member_scope = Scope { private_names: true, ..scope };
+        let field_scope = Scope { super_property: true, ..member_scope.function(false, false) }
+            .without_await_and_yield();
+        if self.rng.one_in(3) {
+            let body_scope = Scope { super_property: true, ..member_scope.function(false, false) };
+            let _ = write!(
+                out,
+                "constructor{} {} ",
+                self.params(body_scope, d),
+                self.function_body(body_scope, d)
+            );
+        }
+        // Always declared, so `#p` can be used anywhere in the class body
+        out.push_str("#p = 1; ");
+        for _ in 0..self.rng.below(4) {
+            let is_static = if self.rng.one_in(3) { "static " } else { "" };
+            let key = self.property_key(member_scope, d);
+            match self.rng.below(3) {
+                0 => {
+                    let _ =
+                        write!(out, "{is_static}{key} = {}; ", self.parenthesized(field_scope, d));
+                }
+                1 => {
+                    let block_scope = Scope { function: false, ..field_scope };
+                    let _ = write!(out, "static {} ", self.block(block_scope, d));
+                }
+                _ => {
+                    let method = self.method(member_scope, depth, &key);
+                    let _ = write!(out, "{is_static}{method} ");
+                }
+            }
+        }
+        out.push('}');
+        out
+    }
+
+    fn object(&mut self, scope: Scope, depth: usize) -> String {
+        let mut props = vec![];
+        for _ in 0..self.rng.below(4) {
+            let prop = match self.rng.below(5) {
+                0 => (*self.rng.choose(IDENTIFIERS)).to_string(),
+                1 => format!("...{}", self.parenthesized(scope, depth)),
+                2 => {
+                    let key = self.property_key(scope, depth);
+                    self.method(scope, depth, &key)
+                }
+                _ => {
+                    let key = self.property_key(scope, depth);
+                    format!("{key}: {}", self.parenthesized(scope, depth))
+                }
+            };
+            props.push(prop);
+        }
+        if props.is_empty() && self.rng.one_in(2) {
+            props.push("__proto__: null".to_string());
+        }
+        format!("{{ {} }}", props.join(", "))
+    }
+
+    fn template(&mut self, scope: Scope, depth: usize) -> String {
+        let mut out = String::from("`");
+        out.push_str(self.rng.choose::<&str>(TEMPLATE_CHUNKS));
+        for _ in 0..self.rng.below(3) {
+            let _ = write!(out, "${{{}}}", self.expression(scope, depth));
+            out.push_str(self.rng.choose::<&str>(TEMPLATE_CHUNKS));
+        }
+        out.push('`');
+        out
+    }
+
+    fn expression(&mut self, scope: Scope, depth: usize) -> String {
+        if depth == 0 {
+            return self.primary(scope);
+        }
+        let d = depth - 1;
+        match self.rng.below(28) {
+            0..=2 => format!(
+                "{} {} {}",
+                self.parenthesized(scope, d),
+                self.rng.choose(BINARY_OPERATORS),
+                self.parenthesized(scope, d)
+            ),
+            3 => format!("{}{}", self.rng.choose(UNARY_OPERATORS), self.parenthesized(scope, d)),
+            4 => {
+                format!("delete {}.{}", self.parenthesized(scope, d), self.rng.choose(IDENTIFIERS))
+            }
+            5 => {
+                let target = self.simple_target(scope, d);
+                let op = *self.rng.choose(&["++", "--"]);
+                if self.rng.one_in(2) { format!("{op}{target}") } else { format!("{target}{op}") }
+            }
+            6 => format!(
+                "{} {} {}",
+                self.simple_target(scope, d),
+                self.rng.choose(ASSIGNMENT_OPERATORS),
+                self.parenthesized(scope, d)
+            ),
+            7 => format!(
+                "[{}, , ...{}] = {}",
+                self.simple_target(scope, d),
+                self.rng.choose(IDENTIFIERS),
+                self.parenthesized(scope, d)
+            ),
+            8 => format!(
+                "{{ a: {}, b = {} }} = {}",
+                self.simple_target(scope, d),
+                self.parenthesized(scope, d),
+                self.parenthesized(scope, d)
+            ),
+            9 => format!(
+                "{} ? {} : {}",
+                self.parenthesized(scope, d),
+                self.parenthesized(scope, d),
+                self.parenthesized(scope, d)
+            ),
+            10 => format!("{}, {}", self.parenthesized(scope, d), self.parenthesized(scope, d)),
+            11 => {
+                let optional = if self.rng.one_in(3) { "?." } else { "" };
+                format!("{}{optional}{}", self.parenthesized(scope, d), self.arguments(scope, d))
+            }
+            12 => {
+                let accessor = *self.rng.choose(&[".", "?."]);
+                format!(
+                    "{}{accessor}{}",
+                    self.parenthesized(scope, d),
+                    self.rng.choose(IDENTIFIERS)
+                )
+            }
+            13 => {
+                let accessor = *self.rng.choose(&["", "?."]);
+                format!("{}{accessor}[{}]", self.parenthesized(scope, d), self.expression(scope, d))
+            }
+            14 => {
+                if self.rng.one_in(2) {
+                    format!("new {}", self.parenthesized(scope, d))
+                } else {
+                    format!("new {}{}", self.parenthesized(scope, d), self.arguments(scope, d))
+                }
+            }
+            15 => format!("{}{}", self.parenthesized(scope, d), self.template(scope, d)),
+            16 => self.template(scope, d),
+            17 | 18 => {
+                let is_async = self.rng.one_in(3);
+                let body_scope = scope.arrow(is_async);
+                let params = if !is_async && self.rng.one_in(3) {
+                    self.binding()
+                } else {
+                    self.params(body_scope, d)
+                };
+                let body = if self.rng.one_in(2) {
+                    self.parenthesized(body_scope, d)
+                } else {
+                    self.function_body(body_scope, d)
+                };
+                format!("{}{params} => {body}", if is_async { "async " } else { "" })
+            }
+            19 => self.function(scope, d, true),
+            20 => self.class(scope, d, true),
+            21 => {
+                let mut items = vec![];
+                for _ in 0..self.rng.below(4) {
+                    items.push(match self.rng.below(5) {
+                        0 => String::new(),
+                        1 => format!("...{}", self.parenthesized(scope, d)),
+                        _ => self.parenthesized(scope, d),
+                    });
+                }
+                format!("[{}]", items.join(", "))
+            }
+            22 => self.object(scope, d),
+            23 if scope.r#await => format!("await {}", self.parenthesized(scope, d)),
+            24 if scope.r#yield => match self.rng.below(3) {
+                0 => "yield".to_string(),
+                1 => format!("yield* {}", self.parenthesized(scope, d)),
+                _ => format!("yield {}", self.parenthesized(scope, d)),
+            },
+            25 if scope.private_names => {
+                if self.rng.one_in(2) {
+                    format!("#p in {}", self.parenthesized(scope, d))
+                } else {
+                    format!("{}.#p", self.parenthesized(scope, d))
+                }
+            }
+            26 => format!("import({})", self.expression(scope, d)),
+            _ => self.primary(scope),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+#![expect(clippy::print_stdout)]
+//! Differential testing of the parser and codegen.
+//!
+//! Random programs, and random mutations of existing files, are round-tripped through
+//! parse → codegen → parse. The printed code must parse to the same AST as the input,
+//! and printing it again must give the same code. Optionally, whether the parser accepts each
+//! input is compared against a reference parser, e.g. `node --check`.
+//!
+//! Each iteration is seeded separately, so a failure can be reproduced with
+//! `--seed <seed> --iterations 1`.
+
+mod check;
+mod generator;
+mod mutator;
+mod reduce;
+mod rng;
+
+use std::{fs, path::PathBuf};
+
+use rustc_hash::FxHashMap;
+use walkdir::WalkDir;
+
+use oxc_span::SourceType;
+
+use crate::{
+    check::{Checker, Reference, capture_panics},
+    generator::Generator,
+    mutator::mutate,
+    reduce::reduce,
+    rng::Rng,
+};
+
+/// Maximum number of failures of each kind to reduce and report.
+const MAX_FAILURES_PER_KIND: usize = 10;
+
+pub struct FuzzOptions {
+    /// Seed of the first iteration. Iteration `n` uses seed `seed + n`.
+    pub seed: u64,
+    pub iterations: u64,
+    /// Files, or directories of files, to mutate. If empty, only generated programs are tested.
+    pub corpus: Vec<PathBuf>,
+    /// Command to run the reference parser, e.g. `node --check`.
+    pub reference: Option<String>,
+    /// Directory to write reduced failing inputs to.
+    pub out_dir: PathBuf,
+}
+
+/// Run the fuzzer. Returns the number of failures found.
+///
+/// # Panics
+/// Panics if `out_dir` can't be written to, or the reference parser can't be run.
+pub fn run(options: &FuzzOptions) -> usize {
+    fs::create_dir_all(&options.out_dir).unwrap();
+    let reference = options
+        .reference
+        .as_deref()
+        .and_then(|command| Reference::new(command, options.out_dir.clone()));
+
+    // Mutations of corpus files which the reference parser rejects (e.g. because they use
+    // proposals it doesn't support) are only round-tripped, not compared against it
+    let corpus = load_corpus(&options.corpus)
+        .into_iter()
+        .map(|(source_text, source_type)| {
+            let compare = reference.as_ref().is_some_and(|reference| {
+                !source_type.is_typescript()
+                    && !source_type.is_jsx()
+                    && reference.accepts(&source_text, source_type).unwrap()
+            });
+            (source_text, source_type, compare)
+        })
+        .collect::<Vec<_>>();
+
+    let checker = Checker { reference };
+    let round_trip_checker = Checker { reference: None };
+
+    capture_panics();
+
+    let mut failures = FxHashMap::<&'static str, usize>::default();
+    for iteration in 0..options.iterations {
+        let seed = options.seed.wrapping_add(iteration);
+        let mut rng = Rng::new(seed);
+        // With a corpus, alternate between generated programs and mutated files
+        let (source_text, source_type, compare) = if corpus.is_empty() || seed % 2 == 0 {
+            (Generator::new(&mut rng).program(), SourceType::mjs(), true)
+        } else {
+            let (source_text, source_type, compare) = rng.choose(&corpus);
+            (mutate(&mut rng, source_text), *source_type, *compare)
+        };
+        let checker = if compare { &checker } else { &round_trip_checker };
+
+        let Some(failure) = checker.check(&source_text, source_type) else { continue };
+        let kind = failure.kind();
+        let count = failures.entry(kind).or_default();
+        *count += 1;
+        if *count > MAX_FAILURES_PER_KIND {
+            continue;
+        }
+
+        let reduced = reduce(checker, &source_text, source_type, kind);
+        let failure = checker.check(&reduced, source_type).unwrap_or(failure);
+        let extension = source_type_extension(source_type);
+        let path = options.out_dir.join(format!("{kind}-{seed}.{extension}"));
+        fs::write(&path, &reduced).unwrap();
+        println!("Seed {seed}: {kind} ({})\n{reduced}\n{failure}\n", path.display());
+    }
+
+    let total = failures.values().sum();
+    println!("{} iterations, {total} failures", options.iterations);
+    let mut failures = failures.into_iter().collect::<Vec<_>>();
+    failures.sort_unstable();
+    for (kind, count) in failures {
+        println!("  {kind}: {count}");
+    }
+    total
+}
+
+/// Read corpus files. Files which are not JS or TS are skipped.
+fn load_corpus(paths: &[PathBuf]) -> Vec<(String, SourceType)> {
+    paths
+        .iter()
+        .flat_map(WalkDir::new)
+        .filter_map(Result::ok)
+        .filter(|entry| !entry.file_type().is_dir())
+        .filter_map(|entry| {
+            let source_type = SourceType::from_path(entry.path()).ok()?;
+            // Skip files which aren't UTF-8
+            let source_text = fs::read_to_string(entry.path()).ok()?;
+            Some((source_text, source_type))
+        })
+        .collect()
+}
+
+fn source_type_extension(source_type: SourceType) -> &'static str {
+    match (source_type.is_typescript(), source_type.is_jsx(), source_type.is_module()) {
+        (true, true, _) => "tsx",
+        (true, false, _) => "ts",
+        (false, true, _) => "jsx",
+        (false, false, true) => "mjs",
+        (false, false, false) => "cjs",
+    }
+}
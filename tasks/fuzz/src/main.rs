@@ -0,0 +1,35 @@
+#![expect(clippy::print_stdout)]
+use std::{
+    process::ExitCode,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use pico_args::Arguments;
+
+use oxc_fuzz::{FuzzOptions, run};
+use oxc_tasks_common::project_root;
+
+/// Usage:
+///
+/// ```sh
+/// cargo run -p oxc_fuzz -- [--seed N] [--iterations N] [--corpus PATH]... [--reference COMMAND] [--out DIR]
+/// ```
+fn main() -> ExitCode {
+    let mut args = Arguments::from_env();
+    let seed = args.opt_value_from_str("--seed").unwrap().unwrap_or_else(|| {
+        SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs())
+    });
+    let options = FuzzOptions {
+        seed,
+        iterations: args.opt_value_from_str("--iterations").unwrap().unwrap_or(10_000),
+        corpus: args.values_from_str("--corpus").unwrap(),
+        reference: args.opt_value_from_str("--reference").unwrap(),
+        out_dir: args
+            .opt_value_from_str("--out")
+            .unwrap()
+            .unwrap_or_else(|| project_root().join("target/fuzz")),
+    };
+    println!("Seed: {seed}");
+
+    if run(&options) == 0 { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
@@ -0,0 +1,37 @@
+//! Mutation of existing source text, to produce inputs which are almost, but not quite, valid.
+
+use crate::rng::Rng;
+
+/// Fragments inserted into source text. Mostly punctuators and keywords which change the meaning
+/// of what's around them, plus a few characters which are significant to the lexer.
+/// `@` is left out, as Oxc accepts decorators in JavaScript and reference parsers don't.
+const FRAGMENTS: &[&str] = &[
+    "(", ")", "{", "}", "[", "]", ";", ",", ".", "?.", "=>", "=", "/", "*", "**", "++", "<", ">",
+    "`", "${", "'", "\"", "\\", "#", "\n", "\u{2028}", "/*", "*/", "//", "<!--", "-->", "let",
+    "async", "await", "yield", "new", "in", "of", "get", "static", "function", "class", "return",
+    "import", "export", "default", "?", ":", "...", "0", "1n", "a", "\u{FEFF}",
+];
+
+/// Apply 1 to 4 random mutations to `source_text`.
+pub fn mutate(rng: &mut Rng, source_text: &str) -> String {
+    let mut text = source_text.to_string();
+    for _ in 0..=rng.below(4) {
+        let boundaries =
+            text.char_indices().map(|(i, _)| i).chain([text.len()]).collect::<Vec<_>>();
+        let start = *rng.choose(&boundaries);
+        let end_index = boundaries.partition_point(|&i| i < start) + rng.below(16);
+        let end = boundaries[end_index.min(boundaries.len() - 1)];
+        match rng.below(3) {
+            // Delete a range
+            0 => text.replace_range(start..end, ""),
+            // Duplicate a range
+            1 => {
+                let range = text[start..end].to_string();
+                text.insert_str(end, &range);
+            }
+            // Insert a fragment
+            _ => text.insert_str(start, rng.choose::<&str>(FRAGMENTS)),
+        }
+    }
+    text
+}
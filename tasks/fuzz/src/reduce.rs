@@ -0,0 +1,93 @@
+//! Reduction of failing inputs, so they're easier to debug.
+
+use oxc_span::SourceType;
+
+use crate::check::Checker;
+
+/// Remove lines, then the contents of brackets, then characters, from `source_text` for as long as
+/// it keeps failing with a failure of the same `kind`.
+pub fn reduce(checker: &Checker, source_text: &str, source_type: SourceType, kind: &str) -> String {
+    let still_fails =
+        |text: &str| checker.check(text, source_type).is_some_and(|failure| failure.kind() == kind);
+
+    let mut lines = source_text.lines().map(ToString::to_string).collect::<Vec<_>>();
+    let mut i = 0;
+    while i < lines.len() {
+        let mut candidate = lines.clone();
+        candidate.remove(i);
+        if still_fails(&candidate.join("\n")) {
+            lines = candidate;
+        } else {
+            i += 1;
+        }
+    }
+
+    // Replace contents of brackets with `0` or nothing, outermost brackets first.
+    // Generated code is heavily parenthesized, so this removes most of it without breaking syntax.
+    let mut chars = lines.join("\n").chars().collect::<Vec<_>>();
+    let mut i = 0;
+    while let Some(&(start, end)) = bracket_pairs(&chars).get(i) {
+        let contents = &chars[start + 1..end];
+        let replaced = ["", "0"].into_iter().find_map(|replacement| {
+            if replacement.len() >= contents.len() {
+                return None;
+            }
+            let candidate = chars[..=start]
+                .iter()
+                .copied()
+                .chain(replacement.chars())
+                .chain(chars[end..].iter().copied())
+                .collect::<Vec<_>>();
+            still_fails(&candidate.iter().collect::<String>()).then_some(candidate)
+        });
+        match replaced {
+            Some(candidate) => chars = candidate,
+            None => i += 1,
+        }
+    }
+
+    // Remove chunks of characters, halving chunk size each pass
+    let mut chunk = chars.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        while start < chars.len() {
+            let end = (start + chunk).min(chars.len());
+            let candidate = chars[..start].iter().chain(&chars[end..]).collect::<String>();
+            if still_fails(&candidate) {
+                chars.drain(start..end);
+            } else {
+                start += chunk;
+            }
+        }
+        chunk /= 2;
+    }
+    chars.into_iter().collect()
+}
+
+/// Positions of matching brackets, ordered by position of the opening bracket.
+/// Brackets in strings, comments and regexps are not skipped, so pairs may not match the syntax.
+fn bracket_pairs(chars: &[char]) -> Vec<(usize, usize)> {
+    let mut pairs = vec![];
+    let mut open = vec![];
+    for (i, &c) in chars.iter().enumerate() {
+        match c {
+            '(' | '[' | '{' => open.push((i, c)),
+            ')' | ']' | '}' => {
+                let expected = match c {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+                if let Some(&(start, opening)) = open.last()
+                    && opening == expected
+                {
+                    open.pop();
+                    pairs.push((start, i));
+                }
+            }
+            _ => {}
+        }
+    }
+    pairs.sort_unstable();
+    pairs
+}
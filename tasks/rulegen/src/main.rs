@@ -24,6 +24,8 @@ mod json;
 mod template;
 mod util;
 
+const RULES_PATH: &str = "crates/oxc_linter/src/rules.rs";
+
 const ESLINT_TEST_PATH: &str =
     "https://raw.githubusercontent.com/eslint/eslint/main/tests/lib/rules";
 const ESLINT_RULES_PATH: &str = "https://raw.githubusercontent.com/eslint/eslint/main/lib/rules";
@@ -1332,6 +1334,9 @@ pub enum RuleKind {
     Promise,
     Vitest,
     Vue,
+    /// A plugin of this repository without upstream sources to port tests and config from,
+    /// e.g. `security`, or a plugin added by a fork. Holds its module name in `rules.rs`.
+    Local(&'static str),
 }
 
 impl TryFrom<&str> for RuleKind {
@@ -1354,7 +1359,18 @@ impl TryFrom<&str> for RuleKind {
             "promise" => Ok(Self::Promise),
             "vitest" => Ok(Self::Vitest),
             "vue" => Ok(Self::Vue),
-            _ => Err(format!("Invalid `RuleKind`, got `{value}`")),
+            _ => {
+                let mod_name = value.replace('-', "_");
+                let rules = std::fs::read_to_string(RULES_PATH)
+                    .map_err(|err| format!("failed to read {RULES_PATH}: {err}"))?;
+                if !rules.contains(&format!("mod {mod_name} {{")) {
+                    return Err(format!(
+                        "Invalid `RuleKind`, got `{value}`: there is no `{mod_name}` plugin module in {RULES_PATH}. \
+                         Add the plugin to `LintPlugins` and a `pub(crate) mod {mod_name} {{}}` block first."
+                    ));
+                }
+                Ok(Self::Local(Box::leak(mod_name.into_boxed_str())))
+            }
         }
     }
 }
@@ -1377,6 +1393,7 @@ impl Display for RuleKind {
             Self::Promise => "eslint-plugin-promise",
             Self::Vitest => "eslint-plugin-vitest",
             Self::Vue => "eslint-plugin-vue",
+            Self::Local(mod_name) => mod_name,
         };
         f.write_str(kind_name)
     }
@@ -1408,7 +1425,7 @@ fn main() {
         RuleKind::Promise => format!("{PROMISE_TEST_PATH}/{kebab_rule_name}.js"),
         RuleKind::Vitest => format!("{VITEST_TEST_PATH}/{kebab_rule_name}.test.ts"),
         RuleKind::Vue => format!("{VUE_TEST_PATH}/{kebab_rule_name}.js"),
-        RuleKind::Oxc => String::new(),
+        RuleKind::Oxc | RuleKind::Local(_) => String::new(),
     };
     let rule_src_path = match rule_kind {
         RuleKind::ESLint => format!("{ESLINT_RULES_PATH}/{kebab_rule_name}.js"),
@@ -1425,7 +1442,7 @@ fn main() {
         RuleKind::Promise => format!("{PROMISE_RULES_PATH}/{kebab_rule_name}.js"),
         RuleKind::Vitest => format!("{VITEST_RULES_PATH}/{kebab_rule_name}.ts"),
         RuleKind::Vue => format!("{VUE_RULES_PATH}/{kebab_rule_name}.js"),
-        RuleKind::Oxc => String::new(),
+        RuleKind::Oxc | RuleKind::Local(_) => String::new(),
     };
     let language = match rule_kind {
         RuleKind::Typescript | RuleKind::Oxc => "ts",
@@ -1630,13 +1647,14 @@ fn get_mod_name(rule_kind: RuleKind) -> String {
         RuleKind::Vitest => "vitest".into(),
         RuleKind::Node => "node".into(),
         RuleKind::Vue => "vue".into(),
+        RuleKind::Local(mod_name) => mod_name.into(),
     }
 }
 
 /// Adds a module definition for the given rule to the `rules.rs` file, and adds the rule to the
 /// `declare_all_lint_rules!` macro block.
 fn add_rules_entry(ctx: &Context, rule_kind: RuleKind) -> Result<(), Box<dyn std::error::Error>> {
-    let rules_path = "crates/oxc_linter/src/rules.rs";
+    let rules_path = RULES_PATH;
     let mut rules = std::fs::read_to_string(rules_path)?;
 
     let mod_name = get_mod_name(rule_kind);
@@ -1660,17 +1678,19 @@ fn add_rules_entry(ctx: &Context, rule_kind: RuleKind) -> Result<(), Box<dyn std
 
     // Insert the rule mod def if it doesn't exist
     if needs_mod_insertion {
-        // Find the rule name (`pub mod xyz;`) that comes alphabetically before the new rule mod def,
-        // otherwise just append it to the mod.
-        let rule_mod_def_start = mod_rules
-        .lines()
-        .filter_map(|line| line.split_once("pub mod ").map(|(_, rest)| rest))
-        .position(|rule_mod| rule_mod < rule_mod_def.as_str())
-        .map(|i| i + 1)
-        .and_then(|i| rules[mod_start + i..].find("pub mod ").map(|j| i + j))
-        .ok_or(format!(
-            "failed to find where to insert the new rule mod def ({rule_mod_def}) in {rules_path}"
-        ))?;
+        // Insert before the first rule mod def (`pub mod xyz;`) that comes alphabetically after
+        // the new one, otherwise append it to the mod.
+        let new_rule_mod = format!("{};", ctx.snake_rule_name);
+        let mut offset = 0;
+        let mut rule_mod_def_start = mod_rules.len();
+        for line in mod_rules.split_inclusive('\n') {
+            let rule_mod = line.trim().strip_prefix("pub mod ");
+            if rule_mod.is_some_and(|rule_mod| rule_mod > new_rule_mod.as_str()) {
+                rule_mod_def_start = offset;
+                break;
+            }
+            offset += line.len();
+        }
 
         rules.insert_str(
             mod_start + rule_mod_def_start,
@@ -1,7 +1,7 @@
 use std::{
     fs::File,
     io::{Error, Write},
-    path::Path,
+    path::{Path, PathBuf},
     process::{Child, Command},
 };
 
@@ -30,24 +30,25 @@ impl<'a> Template<'a> {
             .unwrap();
 
         let path = match rule_kind {
-            RuleKind::ESLint => Path::new("crates/oxc_linter/src/rules/eslint"),
-            RuleKind::Jest => Path::new("crates/oxc_linter/src/rules/jest"),
-            RuleKind::Typescript => Path::new("crates/oxc_linter/src/rules/typescript"),
-            RuleKind::Unicorn => Path::new("crates/oxc_linter/src/rules/unicorn"),
-            RuleKind::Import => Path::new("crates/oxc_linter/src/rules/import"),
-            RuleKind::React => Path::new("crates/oxc_linter/src/rules/react"),
-            RuleKind::ReactPerf => Path::new("crates/oxc_linter/src/rules/react_perf"),
-            RuleKind::JSXA11y => Path::new("crates/oxc_linter/src/rules/jsx_a11y"),
-            RuleKind::Oxc => Path::new("crates/oxc_linter/src/rules/oxc"),
-            RuleKind::NextJS => Path::new("crates/oxc_linter/src/rules/nextjs"),
-            RuleKind::JSDoc => Path::new("crates/oxc_linter/src/rules/jsdoc"),
-            RuleKind::Node => Path::new("crates/oxc_linter/src/rules/node"),
-            RuleKind::Promise => Path::new("crates/oxc_linter/src/rules/promise"),
-            RuleKind::Vitest => Path::new("crates/oxc_linter/src/rules/vitest"),
-            RuleKind::Vue => Path::new("crates/oxc_linter/src/rules/vue"),
+            RuleKind::ESLint => PathBuf::from("crates/oxc_linter/src/rules/eslint"),
+            RuleKind::Jest => PathBuf::from("crates/oxc_linter/src/rules/jest"),
+            RuleKind::Typescript => PathBuf::from("crates/oxc_linter/src/rules/typescript"),
+            RuleKind::Unicorn => PathBuf::from("crates/oxc_linter/src/rules/unicorn"),
+            RuleKind::Import => PathBuf::from("crates/oxc_linter/src/rules/import"),
+            RuleKind::React => PathBuf::from("crates/oxc_linter/src/rules/react"),
+            RuleKind::ReactPerf => PathBuf::from("crates/oxc_linter/src/rules/react_perf"),
+            RuleKind::JSXA11y => PathBuf::from("crates/oxc_linter/src/rules/jsx_a11y"),
+            RuleKind::Oxc => PathBuf::from("crates/oxc_linter/src/rules/oxc"),
+            RuleKind::NextJS => PathBuf::from("crates/oxc_linter/src/rules/nextjs"),
+            RuleKind::JSDoc => PathBuf::from("crates/oxc_linter/src/rules/jsdoc"),
+            RuleKind::Node => PathBuf::from("crates/oxc_linter/src/rules/node"),
+            RuleKind::Promise => PathBuf::from("crates/oxc_linter/src/rules/promise"),
+            RuleKind::Vitest => PathBuf::from("crates/oxc_linter/src/rules/vitest"),
+            RuleKind::Vue => PathBuf::from("crates/oxc_linter/src/rules/vue"),
+            RuleKind::Local(mod_name) => Path::new("crates/oxc_linter/src/rules").join(mod_name),
         };
 
-        std::fs::create_dir_all(path)?;
+        std::fs::create_dir_all(&path)?;
         let out_path = path.join(format!("{}.rs", self.context.snake_rule_name));
 
         File::create(out_path.clone())?.write_all(rendered.as_bytes())?;
@@ -9,7 +9,7 @@ use std::{
 };
 
 use itertools::Itertools;
-use oxc_linter::{LintPlugins, table::RuleTableRow};
+use oxc_linter::{LintPlugins, rule_docs::RuleExamples, table::RuleTableRow};
 use schemars::{
     JsonSchema, SchemaGenerator,
     schema::{InstanceType, Schema, SchemaObject, SingleOrVec},
@@ -32,7 +32,11 @@ impl Context {
         Self { page: HtmlWriter::with_capacity(1024), schemas, renderer }
     }
 
-    pub fn render_rule_docs_page(&mut self, rule: &RuleTableRow) -> Result<String, fmt::Error> {
+    pub fn render_rule_docs_page(
+        &mut self,
+        rule: &RuleTableRow,
+        examples: Option<&RuleExamples>,
+    ) -> Result<String, fmt::Error> {
         const APPROX_FIX_CATEGORY_AND_PLUGIN_LEN: usize = 512;
         let RuleTableRow {
             name,
@@ -132,6 +136,16 @@ const source = `{}`;{}
             }
         }
 
+        // test cases, folded since some rules have hundreds of them
+        if let Some(examples) = examples.filter(|examples| !examples.is_empty()) {
+            writeln!(self.page, "\n## Tested examples\n")?;
+            writeln!(
+                self.page,
+                "<details>\n<summary>Code checked by the tests of this rule</summary>\n{}\n</details>",
+                examples.to_markdown()
+            )?;
+        }
+
         // how to use
         writeln!(self.page, "\n## How to use\n{}", how_to_use(rule))?;
         writeln!(self.page, "\n## References\n")?;
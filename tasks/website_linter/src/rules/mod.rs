@@ -11,8 +11,14 @@ use std::{
 
 use doc_page::Context;
 use html::HtmlWriter;
-use oxc_linter::{Oxlintrc, table::RuleTable};
+use oxc_linter::{
+    Oxlintrc,
+    rule_docs::{RuleDocs, RuleExamples},
+    rules::{RULES, RuleEnum},
+    table::RuleTable,
+};
 use pico_args::Arguments;
+use rustc_hash::FxHashMap;
 use schemars::{SchemaGenerator, r#gen::SchemaSettings};
 use table::render_rules_table;
 
@@ -23,6 +29,10 @@ Arguments:
     -t,--table <path>     Path to file where rule markdown table will be saved.
     -r,--rule-docs <path> Path to directory where rule doc pages will be saved.
                           A directory will be created if one doesn't exist.
+    -j,--json <path>      Path to file where the documentation of all rules will be saved as JSON.
+    --examples <path>     Directory of test cases recorded by running the rule tests with
+                          `OXC_RULE_EXAMPLES_DIR=<path> cargo test -p oxc_linter`.
+                          The cases are added to the rule doc pages and the JSON.
     --git-ref <ref>       Git commit, branch, or tag to be used in the generated links.
                           If not supplied, `main` will be used.
     -h,--help             Show this help message.
@@ -48,6 +58,9 @@ pub fn print_rules(mut args: Arguments) {
     let git_ref: Option<String> = args.opt_value_from_str("--git-ref").unwrap();
     let table_path = args.opt_value_from_str::<_, PathBuf>(["-t", "--table"]).unwrap();
     let rules_dir = args.opt_value_from_str::<_, PathBuf>(["-r", "--rule-docs"]).unwrap();
+    let json_path = args.opt_value_from_str::<_, PathBuf>(["-j", "--json"]).unwrap();
+    let examples_dir = args.opt_value_from_str::<_, PathBuf>("--examples").unwrap();
+    let examples = examples_dir.map(|dir| read_examples(&pwd.join(dir)));
 
     let prefix =
         rules_dir.as_ref().and_then(|p| p.as_os_str().to_str()).map_or(Cow::Borrowed(""), |p| {
@@ -71,6 +84,21 @@ pub fn print_rules(mut args: Arguments) {
         fs::write(table_path, rules_table).unwrap();
     }
 
+    if let Some(json_path) = json_path {
+        eprintln!("Rendering rules JSON...");
+        let docs = RULES
+            .iter()
+            .map(|rule| {
+                let docs = RuleDocs::new(rule);
+                match examples.as_ref().and_then(|examples| examples.get(&rule_key(rule))) {
+                    Some(examples) => docs.with_examples(examples.clone()),
+                    None => docs,
+                }
+            })
+            .collect::<Vec<_>>();
+        fs::write(pwd.join(json_path), serde_json::to_string_pretty(&docs).unwrap()).unwrap();
+    }
+
     if let Some(rules_dir) = &rules_dir {
         eprintln!("Rendering rule doc pages...");
         let rules_dir = pwd.join(rules_dir);
@@ -82,7 +110,7 @@ pub fn print_rules(mut args: Arguments) {
             !rules_dir.is_file(),
             "Cannot write rule docs to a file. Please specify a directory."
         );
-        write_rule_doc_pages(generator, &table, &rules_dir);
+        write_rule_doc_pages(generator, &table, examples.as_ref(), &rules_dir);
         write_version_data(&rules_dir, git_ref.unwrap_or("main".to_string()).as_str());
     }
 
@@ -92,7 +120,30 @@ pub fn print_rules(mut args: Arguments) {
     );
 }
 
-fn write_rule_doc_pages(g: SchemaGenerator, table: &RuleTable, outdir: &Path) {
+/// Test cases recorded for each rule, by `plugin/name`.
+type Examples = FxHashMap<String, RuleExamples>;
+
+fn rule_key(rule: &RuleEnum) -> String {
+    format!("{}/{}", rule.plugin_name(), rule.name())
+}
+
+fn read_examples(dir: &Path) -> Examples {
+    assert!(dir.is_dir(), "No recorded test cases found in {}", dir.display());
+    RULES
+        .iter()
+        .filter_map(|rule| {
+            let examples = RuleExamples::read(dir, rule.plugin_name(), rule.name()).unwrap()?;
+            Some((rule_key(rule), examples))
+        })
+        .collect()
+}
+
+fn write_rule_doc_pages(
+    g: SchemaGenerator,
+    table: &RuleTable,
+    examples: Option<&Examples>,
+    outdir: &Path,
+) {
     let mut ctx = Context::new::<Oxlintrc>(g);
     for rule in table.sections.iter().flat_map(|section| &section.rows) {
         let plugin_path = outdir.join(&rule.plugin);
@@ -101,7 +152,9 @@ fn write_rule_doc_pages(g: SchemaGenerator, table: &RuleTable, outdir: &Path) {
         if page_path.exists() {
             fs::remove_file(&page_path).unwrap();
         }
-        let docs = ctx.render_rule_docs_page(rule).unwrap();
+        let key = format!("{}/{}", rule.plugin, rule.name);
+        let examples = examples.and_then(|examples| examples.get(&key));
+        let docs = ctx.render_rule_docs_page(rule, examples).unwrap();
         fs::write(&page_path, docs).unwrap();
     }
 }